@@ -187,6 +187,7 @@ impl Into<MinifyOptions> for TransformOptions {
         Default::default()
       },
       unused_symbols,
+      ..MinifyOptions::default()
     }
   }
 }
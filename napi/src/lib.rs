@@ -7,10 +7,12 @@ use lightningcss::bundler::{Bundler, SourceProvider};
 use lightningcss::css_modules::{CssModuleExports, CssModuleReferences, PatternParseError};
 use lightningcss::dependencies::{Dependency, DependencyOptions};
 use lightningcss::error::{Error, ErrorLocation, MinifyErrorKind, ParserError, PrinterErrorKind};
+use lightningcss::properties::custom::TokenList;
 use lightningcss::stylesheet::{
   MinifyOptions, ParserFlags, ParserOptions, PrinterOptions, PseudoClasses, StyleAttribute, StyleSheet,
 };
 use lightningcss::targets::{Browsers, Features, Targets};
+use lightningcss::traits::{IntoOwned, ParseWithOptions};
 use napi::bindgen_prelude::{FromNapiValue, ToNapiValue};
 use napi::{CallContext, Env, JsObject, JsUnknown};
 use parcel_sourcemap::SourceMap;
@@ -576,6 +578,7 @@ struct Config {
   pub analyze_dependencies: Option<AnalyzeDependenciesOption>,
   pub pseudo_classes: Option<OwnedPseudoClasses>,
   pub unused_symbols: Option<HashSet<String>>,
+  pub environment_variables: Option<HashMap<String, String>>,
   pub error_recovery: Option<bool>,
   pub custom_at_rules: Option<HashMap<String, CustomAtRuleConfig>>,
 }
@@ -626,6 +629,7 @@ struct BundleConfig {
   pub analyze_dependencies: Option<AnalyzeDependenciesOption>,
   pub pseudo_classes: Option<OwnedPseudoClasses>,
   pub unused_symbols: Option<HashSet<String>>,
+  pub environment_variables: Option<HashMap<String, String>>,
   pub error_recovery: Option<bool>,
   pub custom_at_rules: Option<HashMap<String, CustomAtRuleConfig>>,
 }
@@ -666,6 +670,26 @@ struct NonStandard {
   deep_selector_combinator: bool,
 }
 
+fn parse_environment_variables<'i, E: std::error::Error>(
+  environment_variables: &Option<HashMap<String, String>>,
+) -> Result<HashMap<String, TokenList<'static>>, CompileError<'i, E>> {
+  let mut res = HashMap::new();
+  if let Some(environment_variables) = environment_variables {
+    for (name, value) in environment_variables {
+      let value = TokenList::parse_string_with_options(value, ParserOptions::default()).map_err(|err| {
+        let err = Error::from(err, String::new());
+        CompileError::ParseError(Error {
+          kind: err.kind.into_owned(),
+          loc: err.loc,
+        })
+      })?;
+      res.insert(name.clone(), value.into_owned());
+    }
+  }
+
+  Ok(res)
+}
+
 fn compile<'i>(
   code: &'i str,
   config: &Config,
@@ -741,6 +765,7 @@ fn compile<'i>(
     stylesheet.minify(MinifyOptions {
       targets,
       unused_symbols: config.unused_symbols.clone().unwrap_or_default(),
+      environment_variables: parse_environment_variables(&config.environment_variables)?,
     })?;
 
     stylesheet.to_css(PrinterOptions {
@@ -872,6 +897,7 @@ fn compile_bundle<
     stylesheet.minify(MinifyOptions {
       targets,
       unused_symbols: config.unused_symbols.clone().unwrap_or_default(),
+      environment_variables: parse_environment_variables(&config.environment_variables)?,
     })?;
 
     stylesheet.to_css(PrinterOptions {
@@ -933,6 +959,7 @@ struct AttrConfig {
   pub minify: bool,
   #[serde(default)]
   pub analyze_dependencies: bool,
+  pub environment_variables: Option<HashMap<String, String>>,
   #[serde(default)]
   pub error_recovery: bool,
 }
@@ -994,6 +1021,7 @@ fn compile_attr<'i>(
 
     attr.minify(MinifyOptions {
       targets,
+      environment_variables: parse_environment_variables(&config.environment_variables)?,
       ..MinifyOptions::default()
     });
     attr.to_css(PrinterOptions {
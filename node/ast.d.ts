@@ -481,6 +481,10 @@ export type TokenOrValue =
       type: "env";
       value: EnvironmentVariable;
     }
+  | {
+      type: "attr";
+      value: Attr;
+    }
   | {
       type: "function";
       value: Function;
@@ -1234,6 +1238,9 @@ export type PropertyId =
   | {
       property: "background";
     }
+  | {
+      property: "background-blend-mode";
+    }
   | {
       property: "box-shadow";
       vendorPrefix: VendorPrefix;
@@ -1293,6 +1300,38 @@ export type PropertyId =
   | {
       property: "aspect-ratio";
     }
+  | {
+      property: "contain-intrinsic-width";
+    }
+  | {
+      property: "contain-intrinsic-height";
+    }
+  | {
+      property: "contain-intrinsic-block-size";
+    }
+  | {
+      property: "contain-intrinsic-inline-size";
+    }
+  | {
+      property: "contain-intrinsic-size";
+    }
+  | {
+      property: "interpolate-size";
+    }
+  | {
+      property: "zoom";
+    }
+  | {
+      property: "object-fit";
+      vendorPrefix: VendorPrefix;
+    }
+  | {
+      property: "object-position";
+      vendorPrefix: VendorPrefix;
+    }
+  | {
+      property: "image-orientation";
+    }
   | {
       property: "overflow";
     }
@@ -1302,10 +1341,35 @@ export type PropertyId =
   | {
       property: "overflow-y";
     }
+  | {
+      property: "overflow-clip-margin";
+    }
   | {
       property: "text-overflow";
       vendorPrefix: VendorPrefix;
     }
+  | {
+      property: "line-clamp";
+      vendorPrefix: VendorPrefix;
+    }
+  | {
+      property: "continue";
+    }
+  | {
+      property: "overscroll-behavior";
+    }
+  | {
+      property: "overscroll-behavior-x";
+    }
+  | {
+      property: "overscroll-behavior-y";
+    }
+  | {
+      property: "overscroll-behavior-block";
+    }
+  | {
+      property: "overscroll-behavior-inline";
+    }
   | {
       property: "position";
     }
@@ -1342,9 +1406,42 @@ export type PropertyId =
   | {
       property: "inset";
     }
+  | {
+      property: "anchor-name";
+    }
+  | {
+      property: "position-anchor";
+    }
+  | {
+      property: "position-area";
+    }
+  | {
+      property: "inset-area";
+    }
+  | {
+      property: "position-try-order";
+    }
+  | {
+      property: "position-try-fallbacks";
+    }
+  | {
+      property: "position-try";
+    }
+  | {
+      property: "border-collapse";
+    }
   | {
       property: "border-spacing";
     }
+  | {
+      property: "caption-side";
+    }
+  | {
+      property: "empty-cells";
+    }
+  | {
+      property: "table-layout";
+    }
   | {
       property: "border-top-color";
     }
@@ -1540,6 +1637,9 @@ export type PropertyId =
   | {
       property: "outline-width";
     }
+  | {
+      property: "outline-offset";
+    }
   | {
       property: "flex-direction";
       vendorPrefix: VendorPrefix;
@@ -1612,6 +1712,33 @@ export type PropertyId =
   | {
       property: "gap";
     }
+  | {
+      property: "column-width";
+    }
+  | {
+      property: "column-count";
+    }
+  | {
+      property: "columns";
+    }
+  | {
+      property: "column-rule-width";
+    }
+  | {
+      property: "column-rule-style";
+    }
+  | {
+      property: "column-rule-color";
+    }
+  | {
+      property: "column-rule";
+    }
+  | {
+      property: "column-span";
+    }
+  | {
+      property: "column-fill";
+    }
   | {
       property: "box-orient";
       vendorPrefix: VendorPrefix;
@@ -1871,6 +1998,33 @@ export type PropertyId =
   | {
       property: "font-variant-caps";
     }
+  | {
+      property: "font-variant-position";
+    }
+  | {
+      property: "font-variant-ligatures";
+    }
+  | {
+      property: "font-variant-numeric";
+    }
+  | {
+      property: "font-variant-alternates";
+    }
+  | {
+      property: "font-variant-east-asian";
+    }
+  | {
+      property: "font-variant";
+    }
+  | {
+      property: "font-feature-settings";
+    }
+  | {
+      property: "font-variation-settings";
+    }
+  | {
+      property: "font-size-adjust";
+    }
   | {
       property: "line-height";
     }
@@ -1883,6 +2037,21 @@ export type PropertyId =
   | {
       property: "font-palette";
     }
+  | {
+      property: "font-synthesis-weight";
+    }
+  | {
+      property: "font-synthesis-style";
+    }
+  | {
+      property: "font-synthesis-small-caps";
+    }
+  | {
+      property: "font-synthesis-position";
+    }
+  | {
+      property: "font-synthesis";
+    }
   | {
       property: "transition-property";
       vendorPrefix: VendorPrefix;
@@ -1935,10 +2104,49 @@ export type PropertyId =
       property: "animation-fill-mode";
       vendorPrefix: VendorPrefix;
     }
+  | {
+      property: "animation-composition";
+    }
+  | {
+      property: "animation-timeline";
+    }
+  | {
+      property: "animation-range-start";
+    }
+  | {
+      property: "animation-range-end";
+    }
+  | {
+      property: "animation-range";
+    }
   | {
       property: "animation";
       vendorPrefix: VendorPrefix;
     }
+  | {
+      property: "scroll-timeline-name";
+    }
+  | {
+      property: "scroll-timeline-axis";
+    }
+  | {
+      property: "scroll-timeline";
+    }
+  | {
+      property: "view-timeline-name";
+    }
+  | {
+      property: "view-timeline-axis";
+    }
+  | {
+      property: "view-timeline-inset";
+    }
+  | {
+      property: "view-timeline";
+    }
+  | {
+      property: "timeline-scope";
+    }
   | {
       property: "transform";
       vendorPrefix: VendorPrefix;
@@ -1975,6 +2183,24 @@ export type PropertyId =
   | {
       property: "scale";
     }
+  | {
+      property: "offset-path";
+    }
+  | {
+      property: "offset-distance";
+    }
+  | {
+      property: "offset-rotate";
+    }
+  | {
+      property: "offset-anchor";
+    }
+  | {
+      property: "offset-position";
+    }
+  | {
+      property: "offset";
+    }
   | {
       property: "text-transform";
     }
@@ -1995,6 +2221,13 @@ export type PropertyId =
       property: "hyphens";
       vendorPrefix: VendorPrefix;
     }
+  | {
+      property: "hyphenate-character";
+      vendorPrefix: VendorPrefix;
+    }
+  | {
+      property: "hyphenate-limit-chars";
+    }
   | {
       property: "overflow-wrap";
     }
@@ -2020,6 +2253,18 @@ export type PropertyId =
   | {
       property: "text-indent";
     }
+  | {
+      property: "white-space-collapse";
+    }
+  | {
+      property: "text-wrap-mode";
+    }
+  | {
+      property: "text-wrap-style";
+    }
+  | {
+      property: "text-wrap";
+    }
   | {
       property: "text-decoration-line";
       vendorPrefix: VendorPrefix;
@@ -2039,10 +2284,20 @@ export type PropertyId =
       property: "text-decoration";
       vendorPrefix: VendorPrefix;
     }
+  | {
+      property: "text-decoration-skip";
+      vendorPrefix: VendorPrefix;
+    }
   | {
       property: "text-decoration-skip-ink";
       vendorPrefix: VendorPrefix;
     }
+  | {
+      property: "text-underline-offset";
+    }
+  | {
+      property: "text-underline-position";
+    }
   | {
       property: "text-emphasis-style";
       vendorPrefix: VendorPrefix;
@@ -2062,6 +2317,47 @@ export type PropertyId =
   | {
       property: "text-shadow";
     }
+  | {
+      property: "text-fill-color";
+      vendorPrefix: VendorPrefix;
+    }
+  | {
+      property: "text-stroke-color";
+      vendorPrefix: VendorPrefix;
+    }
+  | {
+      property: "text-stroke-width";
+      vendorPrefix: VendorPrefix;
+    }
+  | {
+      property: "text-stroke";
+      vendorPrefix: VendorPrefix;
+    }
+  | {
+      property: "ruby-position";
+    }
+  | {
+      property: "ruby-align";
+    }
+  | {
+      property: "ruby-merge";
+    }
+  | {
+      property: "math-style";
+    }
+  | {
+      property: "math-shift";
+    }
+  | {
+      property: "math-depth";
+    }
+  | {
+      property: "initial-letter";
+      vendorPrefix: VendorPrefix;
+    }
+  | {
+      property: "initial-letter-align";
+    }
   | {
       property: "text-size-adjust";
       vendorPrefix: VendorPrefix;
@@ -2070,12 +2366,56 @@ export type PropertyId =
       property: "box-decoration-break";
       vendorPrefix: VendorPrefix;
     }
+  | {
+      property: "break-before";
+    }
+  | {
+      property: "break-after";
+    }
+  | {
+      property: "break-inside";
+    }
+  | {
+      property: "page-break-before";
+    }
+  | {
+      property: "page-break-after";
+    }
+  | {
+      property: "page-break-inside";
+    }
+  | {
+      property: "orphans";
+    }
+  | {
+      property: "widows";
+    }
+  | {
+      property: "page";
+    }
+  | {
+      property: "writing-mode";
+      vendorPrefix: VendorPrefix;
+    }
+  | {
+      property: "direction";
+    }
+  | {
+      property: "unicode-bidi";
+    }
+  | {
+      property: "text-orientation";
+      vendorPrefix: VendorPrefix;
+    }
   | {
       property: "resize";
     }
   | {
       property: "cursor";
     }
+  | {
+      property: "pointer-events";
+    }
   | {
       property: "caret-color";
     }
@@ -2096,6 +2436,13 @@ export type PropertyId =
       property: "appearance";
       vendorPrefix: VendorPrefix;
     }
+  | {
+      property: "field-sizing";
+    }
+  | {
+      property: "touch-action";
+      vendorPrefix: VendorPrefix;
+    }
   | {
       property: "list-style-type";
     }
@@ -2111,6 +2458,21 @@ export type PropertyId =
   | {
       property: "marker-side";
     }
+  | {
+      property: "counter-reset";
+    }
+  | {
+      property: "counter-set";
+    }
+  | {
+      property: "counter-increment";
+    }
+  | {
+      property: "content";
+    }
+  | {
+      property: "quotes";
+    }
   | {
       property: "composes";
     }
@@ -2280,31 +2642,68 @@ export type PropertyId =
       vendorPrefix: VendorPrefix;
     }
   | {
-      property: "filter";
+      property: "shape-outside";
       vendorPrefix: VendorPrefix;
     }
   | {
-      property: "backdrop-filter";
+      property: "shape-margin";
       vendorPrefix: VendorPrefix;
     }
   | {
-      property: "z-index";
+      property: "shape-image-threshold";
+      vendorPrefix: VendorPrefix;
     }
   | {
-      property: "container-type";
+      property: "filter";
+      vendorPrefix: VendorPrefix;
     }
   | {
-      property: "container-name";
+      property: "backdrop-filter";
+      vendorPrefix: VendorPrefix;
     }
   | {
-      property: "container";
+      property: "mix-blend-mode";
+    }
+  | {
+      property: "isolation";
+    }
+  | {
+      property: "z-index";
+    }
+  | {
+      property: "contain";
+    }
+  | {
+      property: "content-visibility";
+    }
+  | {
+      property: "container-type";
+    }
+  | {
+      property: "container-name";
+    }
+  | {
+      property: "container";
     }
   | {
       property: "view-transition-name";
     }
+  | {
+      property: "view-transition-class";
+    }
   | {
       property: "color-scheme";
     }
+  | {
+      property: "print-color-adjust";
+      vendorPrefix: VendorPrefix;
+    }
+  | {
+      property: "color-adjust";
+    }
+  | {
+      property: "forced-color-adjust";
+    }
   | {
       property: "all";
     }
@@ -2359,6 +2758,10 @@ export type Declaration =
       property: "background";
       value: Background[];
     }
+  | {
+      property: "background-blend-mode";
+      value: BlendMode[];
+    }
   | {
       property: "box-shadow";
       value: BoxShadow[];
@@ -2437,6 +2840,48 @@ export type Declaration =
       property: "aspect-ratio";
       value: AspectRatio;
     }
+  | {
+      property: "contain-intrinsic-width";
+      value: ContainIntrinsicLength;
+    }
+  | {
+      property: "contain-intrinsic-height";
+      value: ContainIntrinsicLength;
+    }
+  | {
+      property: "contain-intrinsic-block-size";
+      value: ContainIntrinsicLength;
+    }
+  | {
+      property: "contain-intrinsic-inline-size";
+      value: ContainIntrinsicLength;
+    }
+  | {
+      property: "contain-intrinsic-size";
+      value: ContainIntrinsicSize;
+    }
+  | {
+      property: "interpolate-size";
+      value: InterpolateSize;
+    }
+  | {
+      property: "zoom";
+      value: Zoom;
+    }
+  | {
+      property: "object-fit";
+      value: ObjectFit;
+      vendorPrefix: VendorPrefix;
+    }
+  | {
+      property: "object-position";
+      value: Position;
+      vendorPrefix: VendorPrefix;
+    }
+  | {
+      property: "image-orientation";
+      value: ImageOrientation;
+    }
   | {
       property: "overflow";
       value: Overflow;
@@ -2449,11 +2894,44 @@ export type Declaration =
       property: "overflow-y";
       value: OverflowKeyword;
     }
+  | {
+      property: "overflow-clip-margin";
+      value: OverflowClipMargin;
+    }
   | {
       property: "text-overflow";
       value: TextOverflow;
       vendorPrefix: VendorPrefix;
     }
+  | {
+      property: "line-clamp";
+      value: LineClamp;
+      vendorPrefix: VendorPrefix;
+    }
+  | {
+      property: "continue";
+      value: Continue;
+    }
+  | {
+      property: "overscroll-behavior";
+      value: OverscrollBehavior;
+    }
+  | {
+      property: "overscroll-behavior-x";
+      value: OverscrollBehaviorKeyword;
+    }
+  | {
+      property: "overscroll-behavior-y";
+      value: OverscrollBehaviorKeyword;
+    }
+  | {
+      property: "overscroll-behavior-block";
+      value: OverscrollBehaviorKeyword;
+    }
+  | {
+      property: "overscroll-behavior-inline";
+      value: OverscrollBehaviorKeyword;
+    }
   | {
       property: "position";
       value: Position2;
@@ -2502,10 +2980,54 @@ export type Declaration =
       property: "inset";
       value: Inset;
     }
+  | {
+      property: "anchor-name";
+      value: AnchorName;
+    }
+  | {
+      property: "position-anchor";
+      value: PositionAnchor;
+    }
+  | {
+      property: "position-area";
+      value: PositionArea;
+    }
+  | {
+      property: "inset-area";
+      value: PositionArea;
+    }
+  | {
+      property: "position-try-order";
+      value: PositionTryOrder;
+    }
+  | {
+      property: "position-try-fallbacks";
+      value: PositionTryFallbacks;
+    }
+  | {
+      property: "position-try";
+      value: PositionTry;
+    }
+  | {
+      property: "border-collapse";
+      value: BorderCollapse;
+    }
   | {
       property: "border-spacing";
       value: Size2DFor_Length;
     }
+  | {
+      property: "caption-side";
+      value: CaptionSide;
+    }
+  | {
+      property: "empty-cells";
+      value: EmptyCells;
+    }
+  | {
+      property: "table-layout";
+      value: TableLayout;
+    }
   | {
       property: "border-top-color";
       value: CssColor;
@@ -2764,6 +3286,10 @@ export type Declaration =
       property: "outline-width";
       value: BorderSideWidth;
     }
+  | {
+      property: "outline-offset";
+      value: Length;
+    }
   | {
       property: "flex-direction";
       value: FlexDirection;
@@ -2856,6 +3382,42 @@ export type Declaration =
       property: "gap";
       value: Gap;
     }
+  | {
+      property: "column-width";
+      value: ColumnWidth;
+    }
+  | {
+      property: "column-count";
+      value: ColumnCount;
+    }
+  | {
+      property: "columns";
+      value: Columns;
+    }
+  | {
+      property: "column-rule-width";
+      value: BorderSideWidth;
+    }
+  | {
+      property: "column-rule-style";
+      value: LineStyle;
+    }
+  | {
+      property: "column-rule-color";
+      value: CssColor;
+    }
+  | {
+      property: "column-rule";
+      value: GenericBorderFor_LineStyle;
+    }
+  | {
+      property: "column-span";
+      value: ColumnSpan;
+    }
+  | {
+      property: "column-fill";
+      value: ColumnFill;
+    }
   | {
       property: "box-orient";
       value: BoxOrient;
@@ -3196,6 +3758,42 @@ export type Declaration =
       property: "font-variant-caps";
       value: FontVariantCaps;
     }
+  | {
+      property: "font-variant-position";
+      value: FontVariantPosition;
+    }
+  | {
+      property: "font-variant-ligatures";
+      value: FontVariantLigatures;
+    }
+  | {
+      property: "font-variant-numeric";
+      value: FontVariantNumeric;
+    }
+  | {
+      property: "font-variant-alternates";
+      value: FontVariantAlternates;
+    }
+  | {
+      property: "font-variant-east-asian";
+      value: FontVariantEastAsian;
+    }
+  | {
+      property: "font-variant";
+      value: FontVariant;
+    }
+  | {
+      property: "font-feature-settings";
+      value: FontFeatureSettings;
+    }
+  | {
+      property: "font-variation-settings";
+      value: FontVariationSettings;
+    }
+  | {
+      property: "font-size-adjust";
+      value: FontSizeAdjust;
+    }
   | {
       property: "line-height";
       value: LineHeight;
@@ -3212,6 +3810,26 @@ export type Declaration =
       property: "font-palette";
       value: DashedIdentReference;
     }
+  | {
+      property: "font-synthesis-weight";
+      value: FontSynthesisKeyword;
+    }
+  | {
+      property: "font-synthesis-style";
+      value: FontSynthesisKeyword;
+    }
+  | {
+      property: "font-synthesis-small-caps";
+      value: FontSynthesisKeyword;
+    }
+  | {
+      property: "font-synthesis-position";
+      value: FontSynthesisKeyword;
+    }
+  | {
+      property: "font-synthesis";
+      value: FontSynthesis;
+    }
   | {
       property: "transition-property";
       value: PropertyId[];
@@ -3277,11 +3895,63 @@ export type Declaration =
       value: AnimationFillMode[];
       vendorPrefix: VendorPrefix;
     }
+  | {
+      property: "animation-composition";
+      value: AnimationComposition[];
+    }
+  | {
+      property: "animation-timeline";
+      value: AnimationTimeline[];
+    }
+  | {
+      property: "animation-range-start";
+      value: AnimationAttachmentRange[];
+    }
+  | {
+      property: "animation-range-end";
+      value: AnimationAttachmentRange[];
+    }
+  | {
+      property: "animation-range";
+      value: AnimationRange[];
+    }
   | {
       property: "animation";
       value: Animation[];
       vendorPrefix: VendorPrefix;
     }
+  | {
+      property: "scroll-timeline-name";
+      value: TimelineName[];
+    }
+  | {
+      property: "scroll-timeline-axis";
+      value: ScrollAxis[];
+    }
+  | {
+      property: "scroll-timeline";
+      value: ScrollTimeline[];
+    }
+  | {
+      property: "view-timeline-name";
+      value: TimelineName[];
+    }
+  | {
+      property: "view-timeline-axis";
+      value: ScrollAxis[];
+    }
+  | {
+      property: "view-timeline-inset";
+      value: Size2DFor_LengthPercentageOrAuto[];
+    }
+  | {
+      property: "view-timeline";
+      value: ViewTimeline[];
+    }
+  | {
+      property: "timeline-scope";
+      value: TimelineScope;
+    }
   | {
       property: "transform";
       value: Transform[];
@@ -3328,6 +3998,30 @@ export type Declaration =
       property: "scale";
       value: Scale;
     }
+  | {
+      property: "offset-path";
+      value: OffsetPath;
+    }
+  | {
+      property: "offset-distance";
+      value: LengthPercentage;
+    }
+  | {
+      property: "offset-rotate";
+      value: OffsetRotate;
+    }
+  | {
+      property: "offset-anchor";
+      value: OffsetAnchor;
+    }
+  | {
+      property: "offset-position";
+      value: OffsetPosition;
+    }
+  | {
+      property: "offset";
+      value: Offset;
+    }
   | {
       property: "text-transform";
       value: TextTransform;
@@ -3354,6 +4048,15 @@ export type Declaration =
       value: Hyphens;
       vendorPrefix: VendorPrefix;
     }
+  | {
+      property: "hyphenate-character";
+      value: HyphenateCharacter;
+      vendorPrefix: VendorPrefix;
+    }
+  | {
+      property: "hyphenate-limit-chars";
+      value: HyphenateLimitChars;
+    }
   | {
       property: "overflow-wrap";
       value: OverflowWrap;
@@ -3387,6 +4090,22 @@ export type Declaration =
       property: "text-indent";
       value: TextIndent;
     }
+  | {
+      property: "white-space-collapse";
+      value: WhiteSpaceCollapse;
+    }
+  | {
+      property: "text-wrap-mode";
+      value: TextWrapMode;
+    }
+  | {
+      property: "text-wrap-style";
+      value: TextWrapStyle;
+    }
+  | {
+      property: "text-wrap";
+      value: TextWrap;
+    }
   | {
       property: "text-decoration-line";
       value: TextDecorationLine;
@@ -3411,11 +4130,24 @@ export type Declaration =
       value: TextDecoration;
       vendorPrefix: VendorPrefix;
     }
+  | {
+      property: "text-decoration-skip";
+      value: TextDecorationSkip;
+      vendorPrefix: VendorPrefix;
+    }
   | {
       property: "text-decoration-skip-ink";
       value: TextDecorationSkipInk;
       vendorPrefix: VendorPrefix;
     }
+  | {
+      property: "text-underline-offset";
+      value: TextUnderlineOffset;
+    }
+  | {
+      property: "text-underline-position";
+      value: TextUnderlinePosition;
+    }
   | {
       property: "text-emphasis-style";
       value: TextEmphasisStyle;
@@ -3441,88 +4173,228 @@ export type Declaration =
       value: TextShadow[];
     }
   | {
-      property: "text-size-adjust";
-      value: TextSizeAdjust;
+      property: "text-fill-color";
+      value: CssColor;
       vendorPrefix: VendorPrefix;
     }
   | {
-      property: "box-decoration-break";
-      value: BoxDecorationBreak;
+      property: "text-stroke-color";
+      value: CssColor;
       vendorPrefix: VendorPrefix;
     }
   | {
-      property: "resize";
-      value: Resize;
+      property: "text-stroke-width";
+      value: BorderSideWidth;
+      vendorPrefix: VendorPrefix;
     }
   | {
-      property: "cursor";
-      value: Cursor;
+      property: "text-stroke";
+      value: WebKitTextStroke;
+      vendorPrefix: VendorPrefix;
     }
   | {
-      property: "caret-color";
-      value: ColorOrAuto;
+      property: "ruby-position";
+      value: RubyPosition;
     }
   | {
-      property: "caret-shape";
-      value: CaretShape;
+      property: "ruby-align";
+      value: RubyAlign;
     }
   | {
-      property: "caret";
-      value: Caret;
+      property: "ruby-merge";
+      value: RubyMerge;
     }
   | {
-      property: "user-select";
-      value: UserSelect;
-      vendorPrefix: VendorPrefix;
+      property: "math-style";
+      value: MathStyle;
     }
   | {
-      property: "accent-color";
-      value: ColorOrAuto;
+      property: "math-shift";
+      value: MathShift;
     }
   | {
-      property: "appearance";
-      value: Appearance;
+      property: "math-depth";
+      value: MathDepth;
+    }
+  | {
+      property: "initial-letter";
+      value: InitialLetter;
       vendorPrefix: VendorPrefix;
     }
   | {
-      property: "list-style-type";
-      value: ListStyleType;
+      property: "initial-letter-align";
+      value: InitialLetterAlign;
     }
   | {
-      property: "list-style-image";
-      value: Image;
+      property: "text-size-adjust";
+      value: TextSizeAdjust;
+      vendorPrefix: VendorPrefix;
     }
   | {
-      property: "list-style-position";
-      value: ListStylePosition;
+      property: "box-decoration-break";
+      value: BoxDecorationBreak;
+      vendorPrefix: VendorPrefix;
     }
   | {
-      property: "list-style";
-      value: ListStyle;
+      property: "break-before";
+      value: BreakBetween;
     }
   | {
-      property: "marker-side";
-      value: MarkerSide;
+      property: "break-after";
+      value: BreakBetween;
     }
   | {
-      property: "composes";
-      value: Composes;
+      property: "break-inside";
+      value: BreakInside;
     }
   | {
-      property: "fill";
-      value: SVGPaint;
+      property: "page-break-before";
+      value: PageBreakBetween;
     }
   | {
-      property: "fill-rule";
-      value: FillRule;
+      property: "page-break-after";
+      value: PageBreakBetween;
     }
   | {
-      property: "fill-opacity";
-      value: number;
+      property: "page-break-inside";
+      value: PageBreakInside;
     }
   | {
-      property: "stroke";
-      value: SVGPaint;
+      property: "orphans";
+      value: LineCount;
+    }
+  | {
+      property: "widows";
+      value: LineCount;
+    }
+  | {
+      property: "page";
+      value: Page;
+    }
+  | {
+      property: "writing-mode";
+      value: WritingMode;
+      vendorPrefix: VendorPrefix;
+    }
+  | {
+      property: "direction";
+      value: Direction;
+    }
+  | {
+      property: "unicode-bidi";
+      value: UnicodeBidi;
+    }
+  | {
+      property: "text-orientation";
+      value: TextOrientation;
+      vendorPrefix: VendorPrefix;
+    }
+  | {
+      property: "resize";
+      value: Resize;
+    }
+  | {
+      property: "cursor";
+      value: Cursor;
+    }
+  | {
+      property: "pointer-events";
+      value: PointerEvents;
+    }
+  | {
+      property: "caret-color";
+      value: ColorOrAuto;
+    }
+  | {
+      property: "caret-shape";
+      value: CaretShape;
+    }
+  | {
+      property: "caret";
+      value: Caret;
+    }
+  | {
+      property: "user-select";
+      value: UserSelect;
+      vendorPrefix: VendorPrefix;
+    }
+  | {
+      property: "accent-color";
+      value: ColorOrAuto;
+    }
+  | {
+      property: "appearance";
+      value: Appearance;
+      vendorPrefix: VendorPrefix;
+    }
+  | {
+      property: "field-sizing";
+      value: FieldSizing;
+    }
+  | {
+      property: "touch-action";
+      value: TouchAction;
+      vendorPrefix: VendorPrefix;
+    }
+  | {
+      property: "list-style-type";
+      value: ListStyleType;
+    }
+  | {
+      property: "list-style-image";
+      value: Image;
+    }
+  | {
+      property: "list-style-position";
+      value: ListStylePosition;
+    }
+  | {
+      property: "list-style";
+      value: ListStyle;
+    }
+  | {
+      property: "marker-side";
+      value: MarkerSide;
+    }
+  | {
+      property: "counter-reset";
+      value: CounterReset;
+    }
+  | {
+      property: "counter-set";
+      value: CounterSet;
+    }
+  | {
+      property: "counter-increment";
+      value: CounterIncrement;
+    }
+  | {
+      property: "content";
+      value: Content;
+    }
+  | {
+      property: "quotes";
+      value: Quotes;
+    }
+  | {
+      property: "composes";
+      value: Composes;
+    }
+  | {
+      property: "fill";
+      value: SVGPaint;
+    }
+  | {
+      property: "fill-rule";
+      value: FillRule;
+    }
+  | {
+      property: "fill-opacity";
+      value: number;
+    }
+  | {
+      property: "stroke";
+      value: SVGPaint;
     }
   | {
       property: "stroke-opacity";
@@ -3592,6 +4464,18 @@ export type Declaration =
       property: "image-rendering";
       value: ImageRendering;
     }
+  | {
+      property: "paint-order";
+      value: PaintOrder;
+    }
+  | {
+      property: "vector-effect";
+      value: VectorEffect;
+    }
+  | {
+      property: "dominant-baseline";
+      value: DominantBaseline;
+    }
   | {
       property: "clip-path";
       value: ClipPath;
@@ -3723,6 +4607,21 @@ export type Declaration =
       value: BorderImageRepeat;
       vendorPrefix: VendorPrefix;
     }
+  | {
+      property: "shape-outside";
+      value: ShapeOutside;
+      vendorPrefix: VendorPrefix;
+    }
+  | {
+      property: "shape-margin";
+      value: LengthPercentage;
+      vendorPrefix: VendorPrefix;
+    }
+  | {
+      property: "shape-image-threshold";
+      value: number;
+      vendorPrefix: VendorPrefix;
+    }
   | {
       property: "filter";
       value: FilterList;
@@ -3733,10 +4632,26 @@ export type Declaration =
       value: FilterList;
       vendorPrefix: VendorPrefix;
     }
+  | {
+      property: "mix-blend-mode";
+      value: BlendMode;
+    }
+  | {
+      property: "isolation";
+      value: Isolation;
+    }
   | {
       property: "z-index";
       value: ZIndex;
     }
+  | {
+      property: "contain";
+      value: Contain;
+    }
+  | {
+      property: "content-visibility";
+      value: ContentVisibility;
+    }
   | {
       property: "container-type";
       value: ContainerType;
@@ -3751,12 +4666,33 @@ export type Declaration =
     }
   | {
       property: "view-transition-name";
-      value: String;
+      value: ViewTransitionName;
+    }
+  | {
+      property: "view-transition-class";
+      value: ViewTransitionClass;
     }
   | {
       property: "color-scheme";
       value: ColorScheme;
     }
+  | {
+      property: "print-color-adjust";
+      value: PrintColorAdjust;
+      vendorPrefix: VendorPrefix;
+    }
+  | {
+      property: "color-adjust";
+      value: PrintColorAdjust;
+    }
+  | {
+      property: "forced-color-adjust";
+      value: ForcedColorAdjust;
+    }
+  | {
+      property: "all";
+      value: CSSWideKeyword;
+    }
   | {
       property: "unparsed";
       value: UnparsedProperty;
@@ -3783,6 +4719,14 @@ export type Image =
   | {
       type: "image-set";
       value: ImageSet;
+    }
+  | {
+      type: "cross-fade";
+      value: CrossFade;
+    }
+  | {
+      type: "element";
+      value: Element;
     };
 /**
  * A CSS [`<gradient>`](https://www.w3.org/TR/css-images-3/#gradients) value.
@@ -3793,6 +4737,10 @@ export type Gradient =
        * The direction of the gradient.
        */
       direction: LineDirection;
+      /**
+       * The color space and hue interpolation method used to interpolate colors.
+       */
+      interpolation?: ColorInterpolationMethod | null;
       /**
        * The color stops and transition hints for the gradient.
        */
@@ -3808,6 +4756,10 @@ export type Gradient =
        * The direction of the gradient.
        */
       direction: LineDirection;
+      /**
+       * The color space and hue interpolation method used to interpolate colors.
+       */
+      interpolation?: ColorInterpolationMethod | null;
       /**
        * The color stops and transition hints for the gradient.
        */
@@ -3819,6 +4771,10 @@ export type Gradient =
       vendorPrefix: VendorPrefix;
     }
   | {
+      /**
+       * The color space and hue interpolation method used to interpolate colors.
+       */
+      interpolation?: ColorInterpolationMethod | null;
       /**
        * The color stops and transition hints for the gradient.
        */
@@ -3838,6 +4794,10 @@ export type Gradient =
       vendorPrefix: VendorPrefix;
     }
   | {
+      /**
+       * The color space and hue interpolation method used to interpolate colors.
+       */
+      interpolation?: ColorInterpolationMethod | null;
       /**
        * The color stops and transition hints for the gradient.
        */
@@ -3861,6 +4821,10 @@ export type Gradient =
        * The angle of the gradient.
        */
       angle: Angle;
+      /**
+       * The color space and hue interpolation method used to interpolate colors.
+       */
+      interpolation?: ColorInterpolationMethod | null;
       /**
        * The color stops and transition hints for the gradient.
        */
@@ -3876,6 +4840,10 @@ export type Gradient =
        * The angle of the gradient.
        */
       angle: Angle;
+      /**
+       * The color space and hue interpolation method used to interpolate colors.
+       */
+      interpolation?: ColorInterpolationMethod | null;
       /**
        * The color stops and transition hints for the gradient.
        */
@@ -3928,6 +4896,27 @@ export type Gradient =
           to: WebKitGradientPoint;
         }
     );
+/**
+ * A [`<color-interpolation-method>`](https://drafts.csswg.org/css-color-4/#color-interpolation-method), as used in `color-mix()` and gradients.
+ */
+export interface ColorInterpolationMethod {
+  /**
+   * The color space to interpolate in.
+   */
+  colorSpace: ColorSpaceName;
+  /**
+   * The hue interpolation method. Only valid for polar color spaces.
+   */
+  hue?: HueInterpolationMethod | null;
+}
+/**
+ * A [color space](https://www.w3.org/TR/css-color-4/#interpolation-space) keyword used in interpolation functions such as `color-mix()`.
+ */
+export type ColorSpaceName = "srgb" | "srgb-linear" | "lab" | "oklab" | "xyz" | "xyz-d50" | "xyz-d65" | "hsl" | "hwb" | "lch" | "oklch";
+/**
+ * A hue [interpolation method](https://www.w3.org/TR/css-color-4/#typedef-hue-interpolation-method) used in interpolation functions such as `color-mix()`.
+ */
+export type HueInterpolationMethod = "shorter" | "longer" | "increasing" | "decreasing" | "specified";
 /**
  * The direction of a CSS `linear-gradient()`.
  *
@@ -4588,14 +5577,91 @@ export type MaxSize =
  * A value for the [box-sizing](https://drafts.csswg.org/css-sizing-3/#box-sizing) property.
  */
 export type BoxSizing = "content-box" | "border-box";
+/**
+ * A value for the [interpolate-size](https://drafts.csswg.org/css-values-5/#interpolate-size) property.
+ */
+export type InterpolateSize = "numeric-only" | "allow-keywords";
+/**
+ * A value for the [zoom](https://drafts.csswg.org/css-viewport/#zoom-property) property.
+ */
+export type Zoom =
+  | {
+      type: "normal";
+    }
+  | {
+      type: "number";
+      value: number;
+    }
+  | {
+      type: "percentage";
+      value: number;
+    };
+/**
+ * A value for the [object-fit](https://www.w3.org/TR/css-images-3/#the-object-fit) property.
+ */
+export type ObjectFit = "fill" | "contain" | "cover" | "none" | "scale-down";
+/**
+ * A value for the [image-orientation](https://www.w3.org/TR/css-images-3/#the-image-orientation) property.
+ */
+export type ImageOrientation =
+  | {
+      type: "from-image";
+    }
+  | {
+      type: "none";
+    }
+  | {
+      /**
+       * The angle to rotate the image by.
+       */
+      angle: Angle;
+      /**
+       * Whether the image is flipped horizontally.
+       */
+      flip: boolean;
+      type: "angle";
+    };
 /**
  * An [overflow](https://www.w3.org/TR/css-overflow-3/#overflow-properties) keyword as used in the `overflow-x`, `overflow-y`, and `overflow` properties.
  */
 export type OverflowKeyword = "visible" | "hidden" | "clip" | "scroll" | "auto";
 /**
- * A value for the [text-overflow](https://www.w3.org/TR/css-overflow-3/#text-overflow) property.
+ * A [`<visual-box>`](https://www.w3.org/TR/css-box-4/#typedef-visual-box) value, as used in the `overflow-clip-margin` property.
+ */
+export type VisualBox = "content-box" | "padding-box" | "border-box";
+/**
+ * A value for one side of the [text-overflow](https://www.w3.org/TR/css-overflow-4/#text-overflow) property.
+ */
+export type TextOverflowValue =
+  | {
+      type: "clip";
+    }
+  | {
+      type: "ellipsis";
+    }
+  | {
+      type: "string";
+      value: String;
+    };
+/**
+ * A value for the [line-clamp](https://www.w3.org/TR/css-overflow-4/#line-clamp) property.
+ */
+export type LineClamp =
+  | {
+      type: "none";
+    }
+  | {
+      type: "lines";
+      value: number;
+    };
+/**
+ * A value for the [continue](https://www.w3.org/TR/css-overflow-4/#continue) property.
+ */
+export type Continue = "auto" | "discard";
+/**
+ * An [overscroll-behavior](https://www.w3.org/TR/css-overscroll-1/#overscroll-behavior-properties) keyword, as used in the `overscroll-behavior-*` and `overscroll-behavior` properties.
  */
-export type TextOverflow = "clip" | "ellipsis";
+export type OverscrollBehaviorKeyword = "auto" | "contain" | "none";
 /**
  * A value for the [position](https://www.w3.org/TR/css-position-3/#position-property) property.
  */
@@ -4626,39 +5692,189 @@ export type Position2 =
  */
 export type Size2DFor_Length = [Length, Length];
 /**
- * A [`<line-style>`](https://drafts.csswg.org/css-backgrounds/#typedef-line-style) value, used in the `border-style` property.
- */
-export type LineStyle =
-  | "none"
-  | "hidden"
-  | "inset"
-  | "groove"
-  | "outset"
-  | "ridge"
-  | "dotted"
-  | "dashed"
-  | "solid"
-  | "double";
-/**
- * A value for the [border-width](https://www.w3.org/TR/css-backgrounds-3/#border-width) property.
+ * A value for the [anchor-name](https://drafts.csswg.org/css-anchor-position-1/#name) property.
  */
-export type BorderSideWidth =
+export type AnchorName =
   | {
-      type: "thin";
+      type: "none";
     }
   | {
-      type: "medium";
-    }
+      type: "names";
+      value: String[];
+    };
+/**
+ * A value for the [position-anchor](https://drafts.csswg.org/css-anchor-position-1/#position-anchor) property.
+ */
+export type PositionAnchor =
   | {
-      type: "thick";
+      type: "auto";
     }
   | {
-      type: "length";
-      value: Length;
+      type: "name";
+      value: String;
     };
 /**
- * A generic value that represents a value with two components, e.g. a border radius.
- *
+ * A value for the [position-area](https://drafts.csswg.org/css-anchor-position-1/#position-area) property.
+ */
+export type PositionArea =
+  | {
+      type: "none";
+    }
+  | {
+      /**
+       * The first keyword.
+       */
+      first: PositionAreaKeyword;
+      /**
+       * The second keyword. If omitted, it is implied by the first.
+       */
+      second?: PositionAreaKeyword | null;
+      type: "area";
+    };
+/**
+ * A keyword for the [position-area](https://drafts.csswg.org/css-anchor-position-1/#position-area) property, formerly known as `inset-area`.
+ *
+ * See [PositionArea](PositionArea).
+ */
+export type PositionAreaKeyword =
+  | "center"
+  | "span-all"
+  | "left"
+  | "right"
+  | "span-left"
+  | "span-right"
+  | "x-start"
+  | "x-end"
+  | "span-x-start"
+  | "span-x-end"
+  | "self-x-start"
+  | "self-x-end"
+  | "span-self-x-start"
+  | "span-self-x-end"
+  | "top"
+  | "bottom"
+  | "span-top"
+  | "span-bottom"
+  | "y-start"
+  | "y-end"
+  | "span-y-start"
+  | "span-y-end"
+  | "self-y-start"
+  | "self-y-end"
+  | "span-self-y-start"
+  | "span-self-y-end"
+  | "block-start"
+  | "block-end"
+  | "span-block-start"
+  | "span-block-end"
+  | "inline-start"
+  | "inline-end"
+  | "span-inline-start"
+  | "span-inline-end"
+  | "self-block-start"
+  | "self-block-end"
+  | "span-self-block-start"
+  | "span-self-block-end"
+  | "self-inline-start"
+  | "self-inline-end"
+  | "span-self-inline-start"
+  | "span-self-inline-end"
+  | "start"
+  | "end"
+  | "span-start"
+  | "span-end"
+  | "self-start"
+  | "self-end"
+  | "span-self-start"
+  | "span-self-end";
+/**
+ * A value for the [position-try-order](https://drafts.csswg.org/css-anchor-position-1/#position-try-order-property) property.
+ */
+export type PositionTryOrder = "normal" | "most-width" | "most-height" | "most-block-size" | "most-inline-size";
+/**
+ * A value for the [position-try-fallbacks](https://drafts.csswg.org/css-anchor-position-1/#position-try-fallbacks) property.
+ */
+export type PositionTryFallbacks =
+  | {
+      type: "none";
+    }
+  | {
+      type: "fallbacks";
+      value: PositionTryFallback[];
+    };
+/**
+ * A fallback option for the [position-try-fallbacks](https://drafts.csswg.org/css-anchor-position-1/#position-try-fallbacks) property.
+ */
+export type PositionTryFallback =
+  | {
+      /**
+       * The name of the `@position-try` rule.
+       */
+      name?: String | null;
+      /**
+       * The try tactics to apply, in order.
+       */
+      tactics: TryTactic[];
+      type: "rule";
+    }
+  | {
+      type: "position-area";
+      value: PositionArea;
+    };
+/**
+ * A [`<try-tactic>`](https://drafts.csswg.org/css-anchor-position-1/#typedef-position-try-fallbacks-try-tactic) value, as used in the `position-try-fallbacks` property.
+ */
+export type TryTactic = "flip-block" | "flip-inline" | "flip-start";
+/**
+ * A value for the [border-collapse](https://www.w3.org/TR/css-tables-3/#border-collapse-property) property.
+ */
+export type BorderCollapse = "separate" | "collapse";
+/**
+ * A value for the [caption-side](https://www.w3.org/TR/css-tables-3/#caption-side) property.
+ */
+export type CaptionSide = "top" | "bottom";
+/**
+ * A value for the [empty-cells](https://www.w3.org/TR/css-tables-3/#empty-cells) property.
+ */
+export type EmptyCells = "show" | "hide";
+/**
+ * A value for the [table-layout](https://www.w3.org/TR/css-tables-3/#table-layout-property) property.
+ */
+export type TableLayout = "auto" | "fixed";
+/**
+ * A [`<line-style>`](https://drafts.csswg.org/css-backgrounds/#typedef-line-style) value, used in the `border-style` property.
+ */
+export type LineStyle =
+  | "none"
+  | "hidden"
+  | "inset"
+  | "groove"
+  | "outset"
+  | "ridge"
+  | "dotted"
+  | "dashed"
+  | "solid"
+  | "double";
+/**
+ * A value for the [border-width](https://www.w3.org/TR/css-backgrounds-3/#border-width) property.
+ */
+export type BorderSideWidth =
+  | {
+      type: "thin";
+    }
+  | {
+      type: "medium";
+    }
+  | {
+      type: "thick";
+    }
+  | {
+      type: "length";
+      value: Length;
+    };
+/**
+ * A generic value that represents a value with two components, e.g. a border radius.
+ *
  * When serialized, only a single component will be written if both are equal.
  *
  * @minItems 2
@@ -4974,6 +6190,9 @@ export type JustifyItems =
   | {
       type: "legacy";
       value: LegacyJustify;
+    }
+  | {
+      type: "legacy-inherit";
     };
 /**
  * A legacy justification keyword, as used in the `justify-items` property.
@@ -4990,6 +6209,36 @@ export type GapValue =
       type: "length-percentage";
       value: DimensionPercentageFor_LengthValue;
     };
+/**
+ * A value for the [column-width](https://www.w3.org/TR/css-multicol-1/#cw) property.
+ */
+export type ColumnWidth =
+  | {
+      type: "auto";
+    }
+  | {
+      type: "length";
+      value: Length;
+    };
+/**
+ * A value for the [column-count](https://www.w3.org/TR/css-multicol-1/#cc) property.
+ */
+export type ColumnCount =
+  | {
+      type: "auto";
+    }
+  | {
+      type: "integer";
+      value: number;
+    };
+/**
+ * A value for the [column-span](https://www.w3.org/TR/css-multicol-1/#column-span) property.
+ */
+export type ColumnSpan = "none" | "all";
+/**
+ * A value for the [column-fill](https://www.w3.org/TR/css-multicol-1/#cf) property.
+ */
+export type ColumnFill = "auto" | "balance" | "balance-all";
 /**
  * A value for the legacy (prefixed) [box-orient](https://www.w3.org/TR/2009/WD-css3-flexbox-20090723/#orientation) property. Partially equivalent to `flex-direction` in the standard syntax.
  */
@@ -5039,6 +6288,13 @@ export type TrackSizing =
        */
       lineNames: String[][];
       type: "track-list";
+    }
+  | {
+      /**
+       * A list of line names to apply to the adopted grid lines.
+       */
+      lineNames: String[][];
+      type: "subgrid";
     };
 /**
  * Either a track size or `repeat()` function.
@@ -5320,6 +6576,101 @@ export type FontVariantCaps =
   | "all-petite-caps"
   | "unicase"
   | "titling-caps";
+/**
+ * A value for the [font-variant-position](https://www.w3.org/TR/css-fonts-4/#font-variant-position-prop) property.
+ */
+export type FontVariantPosition = "normal" | "sub" | "super";
+/**
+ * A [`<common-lig-values>`](https://www.w3.org/TR/css-fonts-4/#common-lig-values) keyword.
+ */
+export type CommonLigValues = "common-ligatures" | "no-common-ligatures";
+/**
+ * A [`<contextual-alt-values>`](https://www.w3.org/TR/css-fonts-4/#contextual-alt-values) keyword.
+ */
+export type ContextualAltValues = "contextual" | "no-contextual";
+/**
+ * A [`<discretionary-lig-values>`](https://www.w3.org/TR/css-fonts-4/#discretionary-lig-values) keyword.
+ */
+export type DiscretionaryLigValues = "discretionary-ligatures" | "no-discretionary-ligatures";
+/**
+ * A [`<historical-lig-values>`](https://www.w3.org/TR/css-fonts-4/#historical-lig-values) keyword.
+ */
+export type HistoricalLigValues = "historical-ligatures" | "no-historical-ligatures";
+/**
+ * A [`<numeric-figure-values>`](https://www.w3.org/TR/css-fonts-4/#numeric-figure-values) keyword.
+ */
+export type NumericFigureValues = "lining-nums" | "oldstyle-nums";
+/**
+ * A [`<numeric-fraction-values>`](https://www.w3.org/TR/css-fonts-4/#numeric-fraction-values) keyword.
+ */
+export type NumericFractionValues = "diagonal-fractions" | "stacked-fractions";
+/**
+ * A [`<numeric-spacing-values>`](https://www.w3.org/TR/css-fonts-4/#numeric-spacing-values) keyword.
+ */
+export type NumericSpacingValues = "proportional-nums" | "tabular-nums";
+/**
+ * An [`<east-asian-variant-values>`](https://www.w3.org/TR/css-fonts-4/#east-asian-variant-values) keyword.
+ */
+export type EastAsianVariantValues = "jis78" | "jis83" | "jis90" | "jis04" | "simplified" | "traditional";
+/**
+ * An [`<east-asian-width-values>`](https://www.w3.org/TR/css-fonts-4/#east-asian-width-values) keyword.
+ */
+export type EastAsianWidthValues = "full-width" | "proportional-width";
+/**
+ * A value for the [font-feature-settings](https://www.w3.org/TR/css-fonts-4/#font-feature-settings-prop) property.
+ */
+export type FontFeatureSettings =
+  | {
+      type: "normal";
+    }
+  | {
+      type: "features";
+      value: FontFeature[];
+    };
+/**
+ * A value for the [font-variation-settings](https://www.w3.org/TR/css-fonts-4/#font-variation-settings-def) property.
+ */
+export type FontVariationSettings =
+  | {
+      type: "normal";
+    }
+  | {
+      type: "variations";
+      value: FontVariation[];
+    };
+/**
+ * A value for the [font-size-adjust](https://www.w3.org/TR/css-fonts-5/#font-size-adjust-prop) property.
+ */
+export type FontSizeAdjust =
+  | {
+      type: "none";
+    }
+  | {
+      type: "value";
+      /**
+       * The font metric to normalize.
+       */
+      metric: FontMetric;
+      /**
+       * The aspect value.
+       */
+      value: FontSizeAdjustValue;
+    };
+/**
+ * A font metric, as used in the [font-size-adjust](https://www.w3.org/TR/css-fonts-5/#font-size-adjust-prop) property.
+ */
+export type FontMetric = "ex-height" | "cap-height" | "ch-width" | "ic-width" | "ic-height";
+/**
+ * The aspect value of the [font-size-adjust](https://www.w3.org/TR/css-fonts-5/#font-size-adjust-prop) property.
+ */
+export type FontSizeAdjustValue =
+  | {
+      type: "from-font";
+    }
+  | {
+      type: "number";
+      value: number;
+    };
 /**
  * A value for the [line-height](https://www.w3.org/TR/2020/WD-css-inline-3-20200827/#propdef-line-height) property.
  */
@@ -5359,6 +6710,10 @@ export type VerticalAlignKeyword =
   | "middle"
   | "bottom"
   | "text-bottom";
+/**
+ * A value for the [font-synthesis-weight](https://www.w3.org/TR/css-fonts-4/#font-synthesis-weight), [font-synthesis-style](https://www.w3.org/TR/css-fonts-4/#font-synthesis-style), [font-synthesis-small-caps](https://www.w3.org/TR/css-fonts-4/#font-synthesis-small-caps), and [font-synthesis-position](https://www.w3.org/TR/css-fonts-4/#font-synthesis-position) properties.
+ */
+export type FontSynthesisKeyword = "auto" | "none";
 /**
  * A CSS [easing function](https://www.w3.org/TR/css-easing-1/#easing-functions).
  */
@@ -5462,6 +6817,92 @@ export type AnimationPlayState = "running" | "paused";
  * A value for the [animation-fill-mode](https://drafts.csswg.org/css-animations/#animation-fill-mode) property.
  */
 export type AnimationFillMode = "none" | "forwards" | "backwards" | "both";
+/**
+ * A value for the [animation-composition](https://drafts.csswg.org/css-animations-2/#animation-composition) property.
+ */
+export type AnimationComposition = "replace" | "add" | "accumulate";
+/**
+ * A value for the [animation-timeline](https://drafts.csswg.org/css-animations-2/#animation-timeline) property.
+ */
+export type AnimationTimeline =
+  | {
+      type: "auto";
+    }
+  | {
+      type: "none";
+    }
+  | {
+      type: "dashed-ident";
+      value: String;
+    }
+  | {
+      type: "scroll";
+      value: ScrollFunction;
+    }
+  | {
+      type: "view";
+      value: ViewFunction;
+    };
+/**
+ * A scroller, as used in the `scroll()` function.
+ */
+export type Scroller = "root" | "nearest" | "self";
+/**
+ * A scroll axis, as used in the `scroll()` and `view()` functions and the [scroll-timeline-axis](https://drafts.csswg.org/scroll-animations-1/#scroll-timeline-axis) and [view-timeline-axis](https://drafts.csswg.org/scroll-animations-1/#view-timeline-axis) properties.
+ */
+export type ScrollAxis = "block" | "inline" | "x" | "y";
+export type Size2DFor_LengthPercentageOrAuto = [LengthPercentageOrAuto, LengthPercentageOrAuto];
+/**
+ * A point on an animation's timeline, as used in the `animation-range-start` and `animation-range-end` properties.
+ */
+export type AnimationAttachmentRange =
+  | {
+      type: "normal";
+    }
+  | {
+      type: "length-percentage";
+      value: LengthPercentage;
+    }
+  | {
+      /**
+       * The name of the timeline range.
+       */
+      name: TimelineRangeName;
+      /**
+       * The offset from the start of the named range.
+       */
+      offset: LengthPercentage;
+      type: "timeline-range";
+    };
+/**
+ * A [named timeline range](https://drafts.csswg.org/scroll-animations-1/#named-ranges), as used in the `animation-range-start` and `animation-range-end` properties.
+ */
+export type TimelineRangeName = "cover" | "contain" | "entry" | "exit" | "entry-crossing" | "exit-crossing";
+/**
+ * A timeline name, as used in the [scroll-timeline-name](https://drafts.csswg.org/scroll-animations-1/#scroll-timeline-name) and [view-timeline-name](https://drafts.csswg.org/scroll-animations-1/#view-timeline-name) properties.
+ */
+export type TimelineName =
+  | {
+      type: "none";
+    }
+  | {
+      type: "name";
+      value: String;
+    };
+/**
+ * A value for the [timeline-scope](https://drafts.csswg.org/scroll-animations-1/#timeline-scope) property.
+ */
+export type TimelineScope =
+  | {
+      type: "none";
+    }
+  | {
+      type: "all";
+    }
+  | {
+      type: "names";
+      value: String[];
+    };
 /**
  * An individual [transform function](https://www.w3.org/TR/2019/CR-css-transforms-1-20190214/#two-d-transform-functions).
  */
@@ -5598,38 +7039,134 @@ export type Perspective =
       value: Length;
     };
 /**
- * Defines how text case should be transformed in the [text-transform](https://www.w3.org/TR/2021/CRD-css-text-3-20210422/#text-transform-property) property.
- */
-export type TextTransformCase = "none" | "uppercase" | "lowercase" | "capitalize";
-/**
- * A value for the [white-space](https://www.w3.org/TR/2021/CRD-css-text-3-20210422/#white-space-property) property.
- */
-export type WhiteSpace = "normal" | "pre" | "nowrap" | "pre-wrap" | "break-spaces" | "pre-line";
-/**
- * A value for the [word-break](https://www.w3.org/TR/2021/CRD-css-text-3-20210422/#word-break-property) property.
- */
-export type WordBreak = "normal" | "keep-all" | "break-all" | "break-word";
-/**
- * A value for the [line-break](https://www.w3.org/TR/2021/CRD-css-text-3-20210422/#line-break-property) property.
- */
-export type LineBreak = "auto" | "loose" | "normal" | "strict" | "anywhere";
-/**
- * A value for the [hyphens](https://www.w3.org/TR/2021/CRD-css-text-3-20210422/#hyphenation) property.
- */
-export type Hyphens = "none" | "manual" | "auto";
-/**
- * A value for the [overflow-wrap](https://www.w3.org/TR/2021/CRD-css-text-3-20210422/#overflow-wrap-property) property.
- */
-export type OverflowWrap = "normal" | "anywhere" | "break-word";
-/**
- * A value for the [text-align](https://www.w3.org/TR/2021/CRD-css-text-3-20210422/#text-align-property) property.
- */
-export type TextAlign = "start" | "end" | "left" | "right" | "center" | "justify" | "match-parent" | "justify-all";
-/**
- * A value for the [text-align-last](https://www.w3.org/TR/2021/CRD-css-text-3-20210422/#text-align-last-property) property.
+ * A value for the [offset-path](https://www.w3.org/TR/motion-1/#offset-path-property) property.
  */
-export type TextAlignLast = "auto" | "start" | "end" | "left" | "right" | "center" | "justify" | "match-parent";
-/**
+export type OffsetPath =
+  | {
+      type: "none";
+    }
+  | {
+      type: "url";
+      value: Url;
+    }
+  | {
+      /**
+       * The ray.
+       */
+      ray: Ray;
+      /**
+       * A reference box that the ray is sized according to.
+       */
+      referenceBox: GeometryBox;
+      type: "ray";
+    }
+  | {
+      /**
+       * A reference box that the shape is positioned according to.
+       */
+      referenceBox: GeometryBox;
+      /**
+       * A basic shape.
+       */
+      shape: BasicShape;
+      type: "shape";
+    }
+  | {
+      type: "box";
+      value: GeometryBox;
+    };
+/**
+ * A [`<ray-size>`](https://www.w3.org/TR/motion-1/#typedef-ray-size) value, as used in the `ray()` function.
+ */
+export type RaySize = "closest-side" | "closest-corner" | "farthest-side" | "farthest-corner" | "sides";
+/**
+ * A value for the [offset-anchor](https://www.w3.org/TR/motion-1/#offset-anchor-property) property.
+ */
+export type OffsetAnchor =
+  | {
+      type: "auto";
+    }
+  | {
+      type: "position";
+      value: Position;
+    };
+/**
+ * A value for the [offset-position](https://www.w3.org/TR/motion-1/#offset-position-property) property.
+ */
+export type OffsetPosition =
+  | {
+      type: "normal";
+    }
+  | {
+      type: "auto";
+    }
+  | {
+      type: "position";
+      value: Position;
+    };
+/**
+ * Defines how text case should be transformed in the [text-transform](https://www.w3.org/TR/2021/CRD-css-text-3-20210422/#text-transform-property) property.
+ */
+export type TextTransformCase = "none" | "uppercase" | "lowercase" | "capitalize";
+/**
+ * A value for the [white-space-collapse](https://www.w3.org/TR/css-text-4/#white-space-collapsing) property.
+ */
+export type WhiteSpaceCollapse = "collapse" | "discard" | "preserve" | "preserve-breaks" | "preserve-spaces" | "break-spaces";
+/**
+ * A value for the [text-wrap-mode](https://www.w3.org/TR/css-text-4/#text-wrap-mode) property.
+ */
+export type TextWrapMode = "wrap" | "nowrap";
+/**
+ * A value for the [text-wrap-style](https://www.w3.org/TR/css-text-4/#text-wrap-style) property.
+ */
+export type TextWrapStyle = "auto" | "balance" | "stable" | "pretty";
+/**
+ * A value for the [word-break](https://www.w3.org/TR/2021/CRD-css-text-3-20210422/#word-break-property) property.
+ */
+export type WordBreak = "normal" | "keep-all" | "break-all" | "break-word";
+/**
+ * A value for the [line-break](https://www.w3.org/TR/2021/CRD-css-text-3-20210422/#line-break-property) property.
+ */
+export type LineBreak = "auto" | "loose" | "normal" | "strict" | "anywhere";
+/**
+ * A value for the [hyphens](https://www.w3.org/TR/2021/CRD-css-text-3-20210422/#hyphenation) property.
+ */
+export type Hyphens = "none" | "manual" | "auto";
+/**
+ * A value for the [hyphenate-character](https://www.w3.org/TR/css-text-4/#hyphenate-character) property.
+ */
+export type HyphenateCharacter =
+  | {
+      type: "auto";
+    }
+  | {
+      type: "string";
+      value: String;
+    };
+/**
+ * A limit within the [hyphenate-limit-chars](https://www.w3.org/TR/css-text-4/#hyphenate-char-limits) property.
+ */
+export type HyphenateLimit =
+  | {
+      type: "auto";
+    }
+  | {
+      type: "integer";
+      value: number;
+    };
+/**
+ * A value for the [overflow-wrap](https://www.w3.org/TR/2021/CRD-css-text-3-20210422/#overflow-wrap-property) property.
+ */
+export type OverflowWrap = "normal" | "anywhere" | "break-word";
+/**
+ * A value for the [text-align](https://www.w3.org/TR/2021/CRD-css-text-3-20210422/#text-align-property) property.
+ */
+export type TextAlign = "start" | "end" | "left" | "right" | "center" | "justify" | "match-parent" | "justify-all";
+/**
+ * A value for the [text-align-last](https://www.w3.org/TR/2021/CRD-css-text-3-20210422/#text-align-last-property) property.
+ */
+export type TextAlignLast = "auto" | "start" | "end" | "left" | "right" | "center" | "justify" | "match-parent";
+/**
  * A value for the [text-justify](https://www.w3.org/TR/2021/CRD-css-text-3-20210422/#text-justify-property) property.
  */
 export type TextJustify = "auto" | "none" | "inter-word" | "inter-character";
@@ -5665,10 +7202,51 @@ export type TextDecorationThickness =
       type: "length-percentage";
       value: DimensionPercentageFor_LengthValue;
     };
+export type TextDecorationSkip = ExclusiveTextDecorationSkip | OtherTextDecorationSkip[];
+export type ExclusiveTextDecorationSkip = "none" | "auto" | "ink";
+export type OtherTextDecorationSkip = "objects" | "spaces" | "leading-spaces" | "trailing-spaces" | "edges" | "box-decoration";
 /**
  * A value for the [text-decoration-skip-ink](https://www.w3.org/TR/2020/WD-css-text-decor-4-20200506/#text-decoration-skip-ink-property) property.
  */
 export type TextDecorationSkipInk = "auto" | "none" | "all";
+/**
+ * A value for the [text-underline-offset](https://www.w3.org/TR/2020/WD-css-text-decor-4-20200506/#underline-offset) property.
+ */
+export type TextUnderlineOffset =
+  | {
+      type: "auto";
+    }
+  | {
+      type: "length-percentage";
+      value: DimensionPercentageFor_LengthValue;
+    };
+/**
+ * A value for the [text-underline-position](https://www.w3.org/TR/2020/WD-css-text-decor-4-20200506/#text-underline-position-property) property.
+ */
+export type TextUnderlinePosition =
+  | {
+      type: "auto";
+    }
+  | {
+      type: "from-font";
+    }
+  | {
+      /**
+       * The side of the text the underline is positioned on in vertical typographic modes.
+       */
+      side?: TextUnderlineSide | null;
+      type: "keyword";
+      /**
+       * Whether the underline is positioned under the alphabetic baseline.
+       */
+      under: boolean;
+    };
+/**
+ * A side keyword for the [text-underline-position](https://www.w3.org/TR/2020/WD-css-text-decor-4-20200506/#text-underline-position-property) property.
+ *
+ * See [TextUnderlinePosition](TextUnderlinePosition).
+ */
+export type TextUnderlineSide = "left" | "right";
 /**
  * A value for the [text-emphasis-style](https://www.w3.org/TR/2020/WD-css-text-decor-4-20200506/#text-emphasis-style-property) property.
  */
@@ -5715,6 +7293,85 @@ export type TextEmphasisPositionHorizontal = "left" | "right";
  * See [TextEmphasisPosition](TextEmphasisPosition).
  */
 export type TextEmphasisPositionVertical = "over" | "under";
+/**
+ * A value for the [ruby-position](https://www.w3.org/TR/css-ruby-1/#rubypos) property.
+ */
+export type RubyPosition =
+  | {
+      /**
+       * Whether successive levels of annotation alternate sides.
+       */
+      alternate: boolean;
+      /**
+       * The side to place the annotation on.
+       */
+      side: RubySide;
+      type: "side";
+    }
+  | {
+      type: "inter-character";
+    };
+/**
+ * A side keyword for the [ruby-position](https://www.w3.org/TR/css-ruby-1/#rubypos) property.
+ *
+ * See [RubyPosition](RubyPosition).
+ */
+export type RubySide = "over" | "under";
+/**
+ * A value for the [ruby-align](https://www.w3.org/TR/css-ruby-1/#ruby-align-property) property.
+ */
+export type RubyAlign = "start" | "center" | "space-between" | "space-around";
+/**
+ * A value for the [ruby-merge](https://www.w3.org/TR/css-ruby-1/#ruby-merge-property) property.
+ */
+export type RubyMerge = "separate" | "merge" | "auto";
+/**
+ * A value for the [math-style](https://w3c.github.io/mathml-core/#the-math-style-property) property.
+ */
+export type MathStyle = "normal" | "compact";
+/**
+ * A value for the [math-shift](https://w3c.github.io/mathml-core/#the-math-shift) property.
+ */
+export type MathShift = "normal" | "compact";
+/**
+ * A value for the [math-depth](https://w3c.github.io/mathml-core/#the-math-script-level-property) property.
+ */
+export type MathDepth =
+  | {
+      type: "auto-add";
+    }
+  | {
+      type: "add";
+      value: number;
+    }
+  | {
+      type: "integer";
+      value: number;
+    };
+/**
+ * A value for the [initial-letter](https://www.w3.org/TR/css-inline-3/#sizing-drop-initials) property.
+ */
+export type InitialLetter =
+  | {
+      type: "normal";
+    }
+  | {
+      /**
+       * The number of lines the initial letter should sink. Defaults to the size, rounded down.
+       */
+      sink?: number | null;
+      /**
+       * The size of the initial letter, in number of lines.
+       */
+      size: number;
+      type: "size";
+    };
+/**
+ * An alignment keyword for the [initial-letter-align](https://www.w3.org/TR/css-inline-3/#aligning-initial-letter) property.
+ *
+ * See [InitialLetterAlign](InitialLetterAlign).
+ */
+export type InitialLetterAlignment = "alphabetic" | "ideographic" | "hanging" | "leading";
 /**
  * A value for the [text-size-adjust](https://w3c.github.io/csswg-drafts/css-size-adjust/#adjustment-control) property.
  */
@@ -5733,10 +7390,127 @@ export type TextSizeAdjust =
  * A value for the [box-decoration-break](https://www.w3.org/TR/css-break-3/#break-decoration) property.
  */
 export type BoxDecorationBreak = "slice" | "clone";
+/**
+ * A value for the [break-before](https://www.w3.org/TR/css-break-3/#break-between) and [break-after](https://www.w3.org/TR/css-break-3/#break-between) properties.
+ */
+export type BreakBetween =
+  | "auto"
+  | "avoid"
+  | "always"
+  | "all"
+  | "avoid-page"
+  | "page"
+  | "left"
+  | "right"
+  | "recto"
+  | "verso"
+  | "avoid-column"
+  | "column"
+  | "avoid-region"
+  | "region";
+/**
+ * A value for the [break-inside](https://www.w3.org/TR/css-break-3/#break-within) property.
+ */
+export type BreakInside = "auto" | "avoid" | "avoid-page" | "avoid-column" | "avoid-region";
+/**
+ * A value for the legacy [page-break-before](https://www.w3.org/TR/css-break-3/#page-break-properties) and [page-break-after](https://www.w3.org/TR/css-break-3/#page-break-properties) properties.
+ */
+export type PageBreakBetween = "auto" | "always" | "avoid" | "left" | "right";
+/**
+ * A value for the legacy [page-break-inside](https://www.w3.org/TR/css-break-3/#page-break-properties) property.
+ */
+export type PageBreakInside = "auto" | "avoid";
+/**
+ * A value for the [orphans](https://www.w3.org/TR/css-break-3/#widows-orphans) and [widows](https://www.w3.org/TR/css-break-3/#widows-orphans) properties.
+ *
+ * The value is the minimum number of lines that must be kept at the bottom or top of a fragment, and must be greater than zero.
+ */
+export type LineCount = number;
+/**
+ * A value for the [page](https://www.w3.org/TR/css-page-3/#using-named-pages) property.
+ */
+export type Page =
+  | {
+      type: "auto";
+    }
+  | {
+      type: "name";
+      value: String;
+    };
+/**
+ * A value for the [writing-mode](https://www.w3.org/TR/css-writing-modes-4/#block-flow) property.
+ *
+ * In addition to the standard values, the legacy values from SVG 1.1 and `-ms-writing-mode` are supported. These compute to one of the standard values.
+ */
+export type WritingMode =
+  | "horizontal-tb"
+  | "vertical-rl"
+  | "vertical-lr"
+  | "sideways-rl"
+  | "sideways-lr"
+  | "lr"
+  | "lr-tb"
+  | "rl"
+  | "rl-tb"
+  | "tb"
+  | "tb-rl"
+  | "tb-lr";
+/**
+ * A value for the [direction](https://www.w3.org/TR/css-writing-modes-4/#direction) property.
+ */
+export type Direction = "ltr" | "rtl";
+/**
+ * A value for the [unicode-bidi](https://www.w3.org/TR/css-writing-modes-4/#unicode-bidi) property.
+ *
+ * The `isolate`, `isolate-override`, and `plaintext` values may also be written with a `-webkit-` or `-moz-` prefix for older browsers.
+ */
+export type UnicodeBidi =
+  | {
+      type: "normal";
+    }
+  | {
+      type: "embed";
+    }
+  | {
+      type: "isolate";
+      vendorPrefix: VendorPrefix;
+    }
+  | {
+      type: "bidi-override";
+    }
+  | {
+      type: "isolate-override";
+      vendorPrefix: VendorPrefix;
+    }
+  | {
+      type: "plaintext";
+      vendorPrefix: VendorPrefix;
+    };
+/**
+ * A value for the [text-orientation](https://www.w3.org/TR/css-writing-modes-4/#text-orientation) property.
+ */
+export type TextOrientation = "mixed" | "upright" | "sideways" | "sideways-right";
 /**
  * A value for the [resize](https://www.w3.org/TR/2021/WD-css-ui-4-20210316/#resize) property.
  */
 export type Resize = "none" | "both" | "horizontal" | "vertical" | "block" | "inline";
+/**
+ * A value for the [pointer-events](https://www.w3.org/TR/SVG2/interact.html#PointerEventsProperty) property.
+ *
+ * Only `auto` and `none` apply to HTML elements. The other values apply to SVG elements.
+ */
+export type PointerEvents =
+  | "auto"
+  | "none"
+  | "visiblepainted"
+  | "visiblefill"
+  | "visiblestroke"
+  | "visible"
+  | "painted"
+  | "fill"
+  | "stroke"
+  | "all"
+  | "bounding-box";
 /**
  * A pre-defined [cursor](https://www.w3.org/TR/2021/WD-css-ui-4-20210316/#cursor) value, used in the `cursor` property.
  *
@@ -5799,6 +7573,13 @@ export type CaretShape = "auto" | "bar" | "block" | "underscore";
  */
 export type UserSelect = "auto" | "text" | "none" | "contain" | "all";
 export type Appearance = string;
+/**
+ * A value for the [field-sizing](https://drafts.csswg.org/css-ui-4/#field-sizing) property.
+ */
+export type FieldSizing = "fixed" | "content";
+export type TouchAction = ExclusiveTouchAction | OtherTouchAction[];
+export type ExclusiveTouchAction = "auto" | "none" | "manipulation";
+export type OtherTouchAction = "pan-x" | "pan-left" | "pan-right" | "pan-y" | "pan-up" | "pan-down" | "pinch-zoom";
 /**
  * A value for the [list-style-type](https://www.w3.org/TR/2020/WD-css-lists-3-20201117/#text-markers) property.
  */
@@ -5902,27 +7683,142 @@ export type PredefinedCounterStyle =
  */
 export type Symbol =
   | {
-      type: "string";
+      type: "string";
+      value: String;
+    }
+  | {
+      type: "image";
+      value: Image;
+    };
+/**
+ * A [`<symbols-type>`](https://www.w3.org/TR/css-counter-styles-3/#typedef-symbols-type) value, as used in the `symbols()` function.
+ *
+ * See [CounterStyle](CounterStyle).
+ */
+export type SymbolsType = "cyclic" | "numeric" | "alphabetic" | "symbolic" | "fixed";
+/**
+ * A value for the [list-style-position](https://www.w3.org/TR/2020/WD-css-lists-3-20201117/#list-style-position-property) property.
+ */
+export type ListStylePosition = "inside" | "outside";
+/**
+ * A value for the [marker-side](https://www.w3.org/TR/2020/WD-css-lists-3-20201117/#marker-side) property.
+ */
+export type MarkerSide = "match-self" | "match-parent";
+/**
+ * A value for the [counter-reset](https://www.w3.org/TR/css-lists-3/#propdef-counter-reset) property.
+ */
+export type CounterReset =
+  | {
+      type: "none";
+    }
+  | {
+      type: "counters";
+      value: CounterResetValue[];
+    };
+/**
+ * A value for the [counter-set](https://www.w3.org/TR/css-lists-3/#propdef-counter-set) property.
+ */
+export type CounterSet =
+  | {
+      type: "none";
+    }
+  | {
+      type: "counters";
+      value: CounterValue[];
+    };
+/**
+ * A value for the [counter-increment](https://www.w3.org/TR/css-lists-3/#propdef-counter-increment) property.
+ */
+export type CounterIncrement =
+  | {
+      type: "none";
+    }
+  | {
+      type: "counters";
+      value: CounterValue[];
+    };
+/**
+ * A value for the [content](https://www.w3.org/TR/css-content-3/#content-property) property.
+ */
+export type Content =
+  | {
+      type: "normal";
+    }
+  | {
+      type: "none";
+    }
+  | {
+      type: "list";
+      value: ContentList;
+    };
+/**
+ * An item within the [content](https://www.w3.org/TR/css-content-3/#content-property) property.
+ */
+export type ContentItem =
+  | {
+      type: "string";
+      value: String;
+    }
+  | {
+      type: "image";
+      value: Image;
+    }
+  | {
+      type: "counter";
+      value: {
+        /**
+         * The name of the counter.
+         */
+        name: String;
+        /**
+         * The counter style used to represent the value.
+         */
+        style: CounterStyle;
+      };
+    }
+  | {
+      type: "counters";
+      value: {
+        /**
+         * The name of the counter.
+         */
+        name: String;
+        /**
+         * The string inserted between the values of nested counters.
+         */
+        separator: String;
+        /**
+         * The counter style used to represent the values.
+         */
+        style: CounterStyle;
+      };
+    }
+  | {
+      type: "attr";
       value: String;
     }
   | {
-      type: "image";
-      value: Image;
+      type: "quote";
+      value: Quote;
     };
 /**
- * A [`<symbols-type>`](https://www.w3.org/TR/css-counter-styles-3/#typedef-symbols-type) value, as used in the `symbols()` function.
- *
- * See [CounterStyle](CounterStyle).
- */
-export type SymbolsType = "cyclic" | "numeric" | "alphabetic" | "symbolic" | "fixed";
-/**
- * A value for the [list-style-position](https://www.w3.org/TR/2020/WD-css-lists-3-20201117/#list-style-position-property) property.
+ * A [quote](https://www.w3.org/TR/css-content-3/#typedef-quote) keyword, as used in the `content` property.
  */
-export type ListStylePosition = "inside" | "outside";
+export type Quote = "open-quote" | "close-quote" | "no-open-quote" | "no-close-quote";
 /**
- * A value for the [marker-side](https://www.w3.org/TR/2020/WD-css-lists-3-20201117/#marker-side) property.
+ * A value for the [quotes](https://www.w3.org/TR/css-content-3/#quotes-property) property.
  */
-export type MarkerSide = "match-self" | "match-parent";
+export type Quotes =
+  | {
+      type: "none";
+    }
+  | {
+      type: "auto";
+    }
+  | {
+      type: "pairs";
+      value: QuotePair[];
+    };
 /**
  * An SVG [`<paint>`](https://www.w3.org/TR/SVG2/painting.html#SpecifyingPaint) value used in the `fill` and `stroke` properties.
  */
@@ -6017,9 +7913,43 @@ export type ShapeRendering = "auto" | "optimizespeed" | "crispedges" | "geometri
  */
 export type TextRendering = "auto" | "optimizespeed" | "optimizelegibility" | "geometricprecision";
 /**
- * A value for the [image-rendering](https://www.w3.org/TR/SVG2/painting.html#ImageRendering) property.
+ * A value for the [image-rendering](https://www.w3.org/TR/css-images-3/#the-image-rendering) property.
+ */
+export type ImageRendering = string;
+/**
+ * A value for the [paint-order](https://www.w3.org/TR/SVG2/painting.html#PaintOrder) property.
+ */
+export type PaintOrder =
+  | {
+      type: "normal";
+    }
+  | {
+      type: "order";
+      value: PaintOrderKeyword[];
+    };
+/**
+ * A keyword for the [paint-order](https://www.w3.org/TR/SVG2/painting.html#PaintOrder) property.
+ *
+ * See [PaintOrder](PaintOrder).
+ */
+export type PaintOrderKeyword = "fill" | "stroke" | "markers";
+/**
+ * A value for the [vector-effect](https://www.w3.org/TR/SVG2/coords.html#VectorEffects) property.
+ */
+export type VectorEffect = "none" | "non-scaling-stroke" | "non-scaling-size" | "non-rotation" | "fixed-position";
+/**
+ * A value for the [dominant-baseline](https://drafts.csswg.org/css-inline-3/#dominant-baseline-property) property.
  */
-export type ImageRendering = "auto" | "optimizespeed" | "optimizequality";
+export type DominantBaseline =
+  | "auto"
+  | "text-bottom"
+  | "alphabetic"
+  | "ideographic"
+  | "middle"
+  | "central"
+  | "mathematical"
+  | "hanging"
+  | "text-top";
 /**
  * A value for the [clip-path](https://www.w3.org/TR/css-masking-1/#the-clip-path) property.
  */
@@ -6076,6 +8006,10 @@ export type BasicShape =
   | {
       type: "polygon";
       value: Polygon;
+    }
+  | {
+      type: "path";
+      value: Path;
     };
 /**
  * A generic value that represents a value for four sides of a box, e.g. border-width, margin, padding, etc.
@@ -6149,6 +8083,36 @@ export type WebKitMaskComposite =
  * See also [MaskMode](MaskMode).
  */
 export type WebKitMaskSourceType = "auto" | "luminance" | "alpha";
+/**
+ * A value for the [shape-outside](https://www.w3.org/TR/css-shapes-1/#shape-outside-property) property.
+ */
+export type ShapeOutside =
+  | {
+      type: "none";
+    }
+  | {
+      type: "image";
+      value: Image;
+    }
+  | {
+      /**
+       * A reference box that the shape is positioned according to.
+       */
+      referenceBox: ShapeBox;
+      /**
+       * A basic shape.
+       */
+      shape: BasicShape;
+      type: "shape";
+    }
+  | {
+      type: "box";
+      value: ShapeBox;
+    };
+/**
+ * A [`<shape-box>`](https://www.w3.org/TR/css-shapes-1/#typedef-shape-box) value as used in the `shape-outside` property.
+ */
+export type ShapeBox = "margin-box" | "border-box" | "padding-box" | "content-box";
 /**
  * A value for the [filter](https://drafts.fxtf.org/filter-effects-1/#FilterProperty) and [backdrop-filter](https://drafts.fxtf.org/filter-effects-2/#BackdropFilterProperty) properties.
  */
@@ -6208,6 +8172,31 @@ export type Filter =
       type: "url";
       value: Url;
     };
+/**
+ * A [`<blend-mode>`](https://drafts.fxtf.org/compositing-1/#ltblendmodegt) value, as used in the `mix-blend-mode` and `background-blend-mode` properties.
+ */
+export type BlendMode =
+  | "normal"
+  | "multiply"
+  | "screen"
+  | "overlay"
+  | "darken"
+  | "lighten"
+  | "color-dodge"
+  | "color-burn"
+  | "hard-light"
+  | "soft-light"
+  | "difference"
+  | "exclusion"
+  | "hue"
+  | "saturation"
+  | "color"
+  | "luminosity"
+  | "plus-lighter";
+/**
+ * A value for the [isolation](https://drafts.fxtf.org/compositing-1/#isolation) property.
+ */
+export type Isolation = "auto" | "isolate";
 /**
  * A value for the [z-index](https://drafts.csswg.org/css2/#z-index) property.
  */
@@ -6219,6 +8208,13 @@ export type ZIndex =
       type: "integer";
       value: number;
     };
+/**
+ * A value for the [content-visibility](https://drafts.csswg.org/css-contain-2/#content-visibility) property.
+ */
+export type ContentVisibility = "visible" | "hidden" | "auto";
+export type Contain = ExclusiveContain | OtherContain[];
+export type ExclusiveContain = "none" | "strict" | "content";
+export type OtherContain = "size" | "inline-size" | "layout" | "style" | "paint";
 /**
  * A value for the [container-type](https://drafts.csswg.org/css-contain-3/#container-type) property. Establishes the element as a query container for the purpose of container queries.
  */
@@ -6234,6 +8230,31 @@ export type ContainerNameList =
       type: "names";
       value: String[];
     };
+/**
+ * A value for the [view-transition-name](https://drafts.csswg.org/css-view-transitions-1/#view-transition-name-prop) property.
+ */
+export type ViewTransitionName =
+  | {
+      type: "none";
+    }
+  | {
+      type: "auto";
+    }
+  | {
+      type: "custom";
+      value: String;
+    };
+/**
+ * A value for the [view-transition-class](https://drafts.csswg.org/css-view-transitions-2/#view-transition-class-prop) property.
+ */
+export type ViewTransitionClass =
+  | {
+      type: "none";
+    }
+  | {
+      type: "classes";
+      value: String[];
+    };
 /**
  * A CSS custom property name.
  */
@@ -7365,11 +9386,51 @@ export interface Url {
    * The location where the `url()` was seen in the CSS source file.
    */
   loc: Location;
+  /**
+   * A list of url modifiers, e.g. `crossorigin(anonymous)`.
+   */
+  modifiers?: UrlModifier[];
   /**
    * The url string.
    */
   url: String;
 }
+/**
+ * A [`<url-modifier>`](https://drafts.csswg.org/css-values-4/#typedef-url-modifier) within a `url()`.
+ */
+export type UrlModifier =
+  | {
+      type: "cross-origin";
+      value: CrossOriginModifier;
+    }
+  | {
+      type: "integrity";
+      value: String;
+    }
+  | {
+      type: "referrer-policy";
+      value: ReferrerPolicyModifier;
+    }
+  | {
+      type: "unknown";
+      value: String;
+    };
+/**
+ * A value for the `crossorigin()` [url modifier](https://html.spec.whatwg.org/multipage/urls-and-fetching.html#cors-settings-attributes).
+ */
+export type CrossOriginModifier = "anonymous" | "use-credentials";
+/**
+ * A value for the `referrerpolicy()` [url modifier](https://w3c.github.io/webappsec-referrer-policy/#referrer-policies).
+ */
+export type ReferrerPolicyModifier =
+  | "no-referrer"
+  | "no-referrer-when-downgrade"
+  | "same-origin"
+  | "origin"
+  | "strict-origin"
+  | "origin-when-cross-origin"
+  | "strict-origin-when-cross-origin"
+  | "unsafe-url";
 /**
  * A line and column position within a source file.
  */
@@ -7413,6 +9474,48 @@ export interface DashedIdentReference {
    */
   ident: String;
 }
+/**
+ * A CSS [attr()](https://drafts.csswg.org/css-values-5/#attr-notation) function.
+ */
+export interface Attr {
+  /**
+   * The type the attribute value is parsed as. By default, it is treated as a string.
+   */
+  attrType?: AttrType | null;
+  /**
+   * A fallback value in case the attribute is missing or cannot be parsed as the given type.
+   */
+  fallback?: TokenOrValue[] | null;
+  /**
+   * The attribute name.
+   */
+  name: String;
+  /**
+   * The namespace prefix of the attribute name, if any. An empty prefix (e.g. `|name`) refers to attributes with no namespace.
+   */
+  namespace?: String | null;
+}
+/**
+ * The type of an [attr()](https://drafts.csswg.org/css-values-5/#attr-notation) function, which determines how the attribute value is parsed.
+ */
+export type AttrType =
+  | {
+      type: "type";
+      value: SyntaxString;
+    }
+  | {
+      type: "raw-string";
+    }
+  | {
+      type: "number";
+    }
+  | {
+      type: "percentage";
+    }
+  | {
+      type: "unit";
+      value: String;
+    };
 /**
  * A custom CSS function.
  */
@@ -7558,6 +9661,84 @@ export interface ImageSetOption {
    */
   resolution: Resolution;
 }
+/**
+ * A CSS [`cross-fade()`](https://drafts.csswg.org/css-images-4/#cross-fade-function) value.
+ *
+ * `cross-fade()` blends multiple images or colors together.
+ */
+export interface CrossFade {
+  /**
+   * The images to blend.
+   */
+  images: CrossFadeImages;
+  /**
+   * The vendor prefix for the `cross-fade()` function.
+   */
+  vendorPrefix: VendorPrefix;
+}
+/**
+ * The images within a [cross-fade()](CrossFade) function.
+ */
+export type CrossFadeImages =
+  | {
+      type: "legacy";
+      value: {
+        /**
+         * The image to fade from.
+         */
+        from: Image;
+        /**
+         * How much of the `to` image is used.
+         */
+        percentage: number;
+        /**
+         * The image to fade to.
+         */
+        to: Image;
+      };
+    }
+  | {
+      type: "list";
+      value: CrossFadeImage[];
+    };
+/**
+ * An image or color within the [cross-fade()](CrossFade) function, with an optional percentage.
+ */
+export interface CrossFadeImage {
+  /**
+   * How much of the image is used.
+   */
+  percentage?: number | null;
+  /**
+   * The image or color.
+   */
+  value: CrossFadeImageValue;
+}
+/**
+ * The value of a [CrossFadeImage](CrossFadeImage).
+ */
+export type CrossFadeImageValue =
+  | {
+      type: "image";
+      value: Image;
+    }
+  | {
+      type: "color";
+      value: CssColor;
+    };
+/**
+ * A CSS [`element()`](https://drafts.csswg.org/css-images-4/#element-notation) value, which renders an element in the document as an image.
+ */
+export interface Element {
+  /**
+   * The id of the referenced element, without the leading `#`.
+   */
+  id: String;
+  /**
+   * The vendor prefix for the `element()` function.
+   */
+  vendorPrefix: VendorPrefix;
+}
 /**
  * A value for the [background-position](https://drafts.csswg.org/css-backgrounds/#background-position) shorthand property.
  */
@@ -7663,6 +9844,32 @@ export interface AspectRatio {
    */
   ratio?: Ratio | null;
 }
+/**
+ * A value for the [contain-intrinsic-width](https://drafts.csswg.org/css-sizing-4/#propdef-contain-intrinsic-width), [contain-intrinsic-height](https://drafts.csswg.org/css-sizing-4/#propdef-contain-intrinsic-height), [contain-intrinsic-block-size](https://drafts.csswg.org/css-sizing-4/#propdef-contain-intrinsic-block-size), and [contain-intrinsic-inline-size](https://drafts.csswg.org/css-sizing-4/#propdef-contain-intrinsic-inline-size) properties.
+ */
+export interface ContainIntrinsicLength {
+  /**
+   * The `auto` keyword. If the element has a last remembered size, it is used instead of the length.
+   */
+  auto: boolean;
+  /**
+   * An explicit intrinsic size, or `None` for the `none` keyword.
+   */
+  length?: Length | null;
+}
+/**
+ * A value for the [contain-intrinsic-size](https://drafts.csswg.org/css-sizing-4/#propdef-contain-intrinsic-size) shorthand property.
+ */
+export interface ContainIntrinsicSize {
+  /**
+   * The intrinsic height.
+   */
+  height: ContainIntrinsicLength;
+  /**
+   * The intrinsic width.
+   */
+  width: ContainIntrinsicLength;
+}
 /**
  * A value for the [overflow](https://www.w3.org/TR/css-overflow-3/#overflow-properties) shorthand property.
  */
@@ -7676,6 +9883,47 @@ export interface Overflow {
    */
   y: OverflowKeyword;
 }
+/**
+ * A value for the [overflow-clip-margin](https://www.w3.org/TR/css-overflow-3/#overflow-clip-margin) property.
+ */
+export interface OverflowClipMargin {
+  /**
+   * How far outside the box edge content may paint before being clipped.
+   */
+  offset: Length;
+  /**
+   * The box edge that the clip margin is measured from.
+   */
+  visualBox: VisualBox;
+}
+/**
+ * A value for the [text-overflow](https://www.w3.org/TR/css-overflow-4/#text-overflow) property.
+ *
+ * When a single value is given, it applies to the end line edge and the start edge is clipped. When two values are given, they apply to the left and right line edges respectively.
+ */
+export interface TextOverflow {
+  /**
+   * The first value.
+   */
+  first: TextOverflowValue;
+  /**
+   * The optional second value.
+   */
+  second?: TextOverflowValue | null;
+}
+/**
+ * A value for the [overscroll-behavior](https://www.w3.org/TR/css-overscroll-1/#overscroll-behavior-properties) shorthand property.
+ */
+export interface OverscrollBehavior {
+  /**
+   * The overscroll behavior for the x direction.
+   */
+  x: OverscrollBehaviorKeyword;
+  /**
+   * The overscroll behavior for the y direction.
+   */
+  y: OverscrollBehaviorKeyword;
+}
 /**
  * A value for the [inset-block](https://drafts.csswg.org/css-logical/#propdef-inset-block) shorthand property.
  */
@@ -7723,6 +9971,19 @@ export interface Inset {
    */
   top: LengthPercentageOrAuto;
 }
+/**
+ * A value for the [position-try](https://drafts.csswg.org/css-anchor-position-1/#position-try-prop) shorthand property.
+ */
+export interface PositionTry {
+  /**
+   * The fallback options.
+   */
+  fallbacks: PositionTryFallbacks;
+  /**
+   * The order in which fallback options are tried.
+   */
+  order: PositionTryOrder;
+}
 /**
  * A value for the [border-radius](https://www.w3.org/TR/css-backgrounds-3/#border-radius) property.
  */
@@ -8050,7 +10311,20 @@ export interface Gap {
   /**
    * The row gap.
    */
-  row: GapValue;
+  row: GapValue;
+}
+/**
+ * A value for the [columns](https://www.w3.org/TR/css-multicol-1/#columns) shorthand property.
+ */
+export interface Columns {
+  /**
+   * The column count.
+   */
+  count: ColumnCount;
+  /**
+   * The column width.
+   */
+  width: ColumnWidth;
 }
 /**
  * A [`<track-repeat>`](https://drafts.csswg.org/css-grid-2/#typedef-track-repeat) value, representing the `repeat()` function in a track list.
@@ -8366,6 +10640,165 @@ export interface ScrollPadding {
    */
   top: LengthPercentageOrAuto;
 }
+/**
+ * A value for the [font-variant-ligatures](https://www.w3.org/TR/css-fonts-4/#font-variant-ligatures-prop) property.
+ *
+ * The `normal` keyword is represented by the default value, with no components set. The `none` keyword is equivalent to disabling all ligatures and contextual alternates.
+ */
+export interface FontVariantLigatures {
+  /**
+   * Whether common ligatures are enabled.
+   */
+  common?: CommonLigValues | null;
+  /**
+   * Whether contextual alternates are enabled.
+   */
+  contextual?: ContextualAltValues | null;
+  /**
+   * Whether discretionary ligatures are enabled.
+   */
+  discretionary?: DiscretionaryLigValues | null;
+  /**
+   * Whether historical ligatures are enabled.
+   */
+  historical?: HistoricalLigValues | null;
+}
+/**
+ * A value for the [font-variant-numeric](https://www.w3.org/TR/css-fonts-4/#font-variant-numeric-prop) property.
+ *
+ * The `normal` keyword is represented by the default value, with no components set.
+ */
+export interface FontVariantNumeric {
+  /**
+   * The style of numerals.
+   */
+  figure?: NumericFigureValues | null;
+  /**
+   * How fractions are rendered.
+   */
+  fraction?: NumericFractionValues | null;
+  /**
+   * Whether ordinal forms are used.
+   */
+  ordinal: boolean;
+  /**
+   * Whether a slashed zero is used.
+   */
+  slashedZero: boolean;
+  /**
+   * The width of numerals.
+   */
+  spacing?: NumericSpacingValues | null;
+}
+/**
+ * A value for the [font-variant-alternates](https://www.w3.org/TR/css-fonts-4/#font-variant-alternates-prop) property.
+ *
+ * The `normal` keyword is represented by the default value, with no components set. Apart from `historical-forms`, the alternates refer to names defined by a `@font-feature-values` rule.
+ */
+export interface FontVariantAlternates {
+  /**
+   * The `annotation()` function.
+   */
+  annotation?: String | null;
+  /**
+   * The `character-variant()` function. Empty if not specified.
+   */
+  characterVariant: String[];
+  /**
+   * Whether historical forms are enabled.
+   */
+  historicalForms: boolean;
+  /**
+   * The `ornaments()` function.
+   */
+  ornaments?: String | null;
+  /**
+   * The `styleset()` function. Empty if not specified.
+   */
+  styleset: String[];
+  /**
+   * The `stylistic()` function.
+   */
+  stylistic?: String | null;
+  /**
+   * The `swash()` function.
+   */
+  swash?: String | null;
+}
+/**
+ * A value for the [font-variant-east-asian](https://www.w3.org/TR/css-fonts-4/#font-variant-east-asian-prop) property.
+ *
+ * The `normal` keyword is represented by the default value, with no components set.
+ */
+export interface FontVariantEastAsian {
+  /**
+   * Whether ruby variant glyphs are used.
+   */
+  ruby: boolean;
+  /**
+   * The set of logographic glyph variants.
+   */
+  variant?: EastAsianVariantValues | null;
+  /**
+   * The width of glyphs.
+   */
+  width?: EastAsianWidthValues | null;
+}
+/**
+ * A value for the [font-variant](https://www.w3.org/TR/css-fonts-4/#font-variant-prop) shorthand property.
+ */
+export interface FontVariant {
+  /**
+   * The alternate glyphs that are used.
+   */
+  alternates: FontVariantAlternates;
+  /**
+   * How the text should be capitalized.
+   */
+  caps: FontVariantCaps;
+  /**
+   * The glyphs used for East Asian text.
+   */
+  eastAsian: FontVariantEastAsian;
+  /**
+   * The ligatures and contextual forms that are used.
+   */
+  ligatures: FontVariantLigatures;
+  /**
+   * The glyphs used for numbers, fractions, and ordinal markers.
+   */
+  numeric: FontVariantNumeric;
+  /**
+   * Whether subscript or superscript glyphs are used.
+   */
+  position: FontVariantPosition;
+}
+/**
+ * A feature tag and value within the [font-feature-settings](https://www.w3.org/TR/css-fonts-4/#font-feature-settings-prop) property.
+ */
+export interface FontFeature {
+  /**
+   * The four character OpenType feature tag.
+   */
+  tag: String;
+  /**
+   * The feature value. `on` is equivalent to 1, and `off` is equivalent to 0.
+   */
+  value: number;
+}
+/**
+ * An axis tag and value within the [font-variation-settings](https://www.w3.org/TR/css-fonts-4/#font-variation-settings-def) property.
+ */
+export interface FontVariation {
+  /**
+   * The four character OpenType axis tag.
+   */
+  tag: String;
+  /**
+   * The axis value.
+   */
+  value: number;
+}
 /**
  * A value for the [font](https://www.w3.org/TR/css-fonts-4/#font-prop) shorthand property.
  */
@@ -8399,6 +10832,27 @@ export interface Font {
    */
   weight: FontWeight;
 }
+/**
+ * A value for the [font-synthesis](https://www.w3.org/TR/css-fonts-4/#font-synthesis) shorthand property.
+ */
+export interface FontSynthesis {
+  /**
+   * Whether subscript and superscript faces may be synthesized.
+   */
+  position: FontSynthesisKeyword;
+  /**
+   * Whether small-caps faces may be synthesized.
+   */
+  smallCaps: FontSynthesisKeyword;
+  /**
+   * Whether oblique faces may be synthesized.
+   */
+  style: FontSynthesisKeyword;
+  /**
+   * Whether bold faces may be synthesized.
+   */
+  weight: FontSynthesisKeyword;
+}
 /**
  * A value for the [transition](https://www.w3.org/TR/2018/WD-css-transitions-1-20181011/#transition-shorthand-property) property.
  */
@@ -8457,6 +10911,75 @@ export interface Animation {
    */
   timingFunction: EasingFunction;
 }
+/**
+ * A value for the [animation-range](https://drafts.csswg.org/scroll-animations-1/#animation-range) shorthand property.
+ */
+export interface AnimationRange {
+  /**
+   * The end of the animation's attachment range.
+   */
+  end: AnimationAttachmentRange;
+  /**
+   * The start of the animation's attachment range.
+   */
+  start: AnimationAttachmentRange;
+}
+/**
+ * A [`scroll()`](https://drafts.csswg.org/scroll-animations-1/#scroll-notation) function, as used in the `animation-timeline` property.
+ */
+export interface ScrollFunction {
+  /**
+   * The scroll axis of the timeline.
+   */
+  axis: ScrollAxis;
+  /**
+   * The scroll container whose scroll position drives the timeline.
+   */
+  scroller: Scroller;
+}
+/**
+ * A [`view()`](https://drafts.csswg.org/scroll-animations-1/#view-notation) function, as used in the `animation-timeline` property.
+ */
+export interface ViewFunction {
+  /**
+   * The scroll axis of the timeline.
+   */
+  axis: ScrollAxis;
+  /**
+   * The insets of the view progress visibility range.
+   */
+  inset: Size2DFor_LengthPercentageOrAuto;
+}
+/**
+ * A value for the [scroll-timeline](https://drafts.csswg.org/scroll-animations-1/#scroll-timeline-shorthand) shorthand property.
+ */
+export interface ScrollTimeline {
+  /**
+   * The scroll axis of the timeline.
+   */
+  axis: ScrollAxis;
+  /**
+   * The name of the timeline.
+   */
+  name: TimelineName;
+}
+/**
+ * A value for the [view-timeline](https://drafts.csswg.org/scroll-animations-1/#view-timeline-shorthand) shorthand property.
+ */
+export interface ViewTimeline {
+  /**
+   * The scroll axis of the timeline.
+   */
+  axis: ScrollAxis;
+  /**
+   * The insets of the view progress visibility range.
+   */
+  inset: Size2DFor_LengthPercentageOrAuto;
+  /**
+   * The name of the timeline.
+   */
+  name: TimelineName;
+}
 /**
  * A 2D matrix.
  */
@@ -8544,6 +11067,65 @@ export interface Scale {
    */
   z: NumberOrPercentage;
 }
+/**
+ * A [`ray()`](https://www.w3.org/TR/motion-1/#ray-function) function, as used in the `offset-path` property.
+ */
+export interface Ray {
+  /**
+   * The angle of the ray, where 0deg points up.
+   */
+  angle: Angle;
+  /**
+   * Whether the element is shifted so that it is entirely contained within the path.
+   */
+  contain: boolean;
+  /**
+   * The starting position of the ray. Defaults to the `offset-position`.
+   */
+  position?: Position | null;
+  /**
+   * The length of the path.
+   */
+  size: RaySize;
+}
+/**
+ * A value for the [offset-rotate](https://www.w3.org/TR/motion-1/#offset-rotate-property) property.
+ */
+export interface OffsetRotate {
+  /**
+   * A fixed angle, added to the path direction when `auto` is set.
+   */
+  angle: Angle;
+  /**
+   * Whether the element is rotated according to the direction of the path.
+   */
+  auto: boolean;
+}
+/**
+ * A value for the [offset](https://www.w3.org/TR/motion-1/#offset-shorthand) shorthand property.
+ */
+export interface Offset {
+  /**
+   * The offset anchor.
+   */
+  anchor: OffsetAnchor;
+  /**
+   * The offset distance.
+   */
+  distance: LengthPercentage;
+  /**
+   * The offset path.
+   */
+  path: OffsetPath;
+  /**
+   * The offset position.
+   */
+  position: OffsetPosition;
+  /**
+   * The offset rotation.
+   */
+  rotate: OffsetRotate;
+}
 /**
  * A value for the [text-transform](https://www.w3.org/TR/2021/CRD-css-text-3-20210422/#text-transform-property) property.
  */
@@ -8561,6 +11143,19 @@ export interface TextTransform {
    */
   fullWidth: boolean;
 }
+/**
+ * A value for the [white-space](https://www.w3.org/TR/css-text-4/#white-space-property) shorthand property.
+ */
+export interface WhiteSpace {
+  /**
+   * How white space is collapsed.
+   */
+  collapse: WhiteSpaceCollapse;
+  /**
+   * Whether lines may wrap.
+   */
+  wrapMode: TextWrapMode;
+}
 /**
  * A value for the [text-indent](https://www.w3.org/TR/2021/CRD-css-text-3-20210422/#text-indent-property) property.
  */
@@ -8578,6 +11173,19 @@ export interface TextIndent {
    */
   value: DimensionPercentageFor_LengthValue;
 }
+/**
+ * A value for the [text-wrap](https://www.w3.org/TR/css-text-4/#text-wrap) shorthand property.
+ */
+export interface TextWrap {
+  /**
+   * Whether lines may wrap.
+   */
+  mode: TextWrapMode;
+  /**
+   * How lines are wrapped.
+   */
+  style: TextWrapStyle;
+}
 /**
  * A value for the [text-decoration](https://www.w3.org/TR/2020/WD-css-text-decor-4-20200506/#text-decoration-property) shorthand property.
  */
@@ -8625,6 +11233,36 @@ export interface TextEmphasisPosition {
    */
   vertical: TextEmphasisPositionVertical;
 }
+/**
+ * A value for the [initial-letter-align](https://www.w3.org/TR/css-inline-3/#aligning-initial-letter) property.
+ */
+export interface InitialLetterAlign {
+  /**
+   * The alignment baselines to use.
+   */
+  alignment?: InitialLetterAlignment | null;
+  /**
+   * Whether to use the border box of the initial letter for alignment, rather than its glyph outline.
+   */
+  borderBox: boolean;
+}
+/**
+ * A value for the [hyphenate-limit-chars](https://www.w3.org/TR/css-text-4/#hyphenate-char-limits) property.
+ */
+export interface HyphenateLimitChars {
+  /**
+   * The minimum number of characters after the hyphenation point.
+   */
+  after: HyphenateLimit;
+  /**
+   * The minimum number of characters before the hyphenation point.
+   */
+  before: HyphenateLimit;
+  /**
+   * The minimum number of characters in a hyphenated word.
+   */
+  word: HyphenateLimit;
+}
 /**
  * A value for the [text-shadow](https://www.w3.org/TR/2020/WD-css-text-decor-4-20200506/#text-shadow-property) property.
  */
@@ -8650,6 +11288,19 @@ export interface TextShadow {
    */
   yOffset: Length;
 }
+/**
+ * A value for the [-webkit-text-stroke](https://compat.spec.whatwg.org/#the-webkit-text-stroke) shorthand property.
+ */
+export interface WebKitTextStroke {
+  /**
+   * The color of the stroke.
+   */
+  color: CssColor;
+  /**
+   * The width of the stroke.
+   */
+  width: BorderSideWidth;
+}
 /**
  * A value for the [cursor](https://www.w3.org/TR/2021/WD-css-ui-4-20210316/#cursor) property.
  */
@@ -8677,7 +11328,11 @@ export interface CursorImage {
    */
   hotspot?: [number, number] | null;
   /**
-   * A url to the cursor image.
+   * An `image-set()` to use instead of `url`.
+   */
+  imageSet?: ImageSet | null;
+  /**
+   * A url to the cursor image. If `image_set` is present, this is the url of its first image.
    */
   url: Url;
 }
@@ -8711,6 +11366,62 @@ export interface ListStyle {
    */
   position: ListStylePosition;
 }
+/**
+ * A counter within the [counter-reset](https://www.w3.org/TR/css-lists-3/#propdef-counter-reset) property.
+ */
+export interface CounterResetValue {
+  /**
+   * The name of the counter.
+   */
+  name: String;
+  /**
+   * Whether the counter is reversed, via the `reversed()` function.
+   */
+  reversed: boolean;
+  /**
+   * The initial value of the counter. If omitted, a normal counter starts at 0, and a reversed counter starts at the number of items it counts.
+   */
+  value?: number | null;
+}
+/**
+ * A counter name and integer value, as used in the `counter-increment` and `counter-set` properties.
+ */
+export interface CounterValue {
+  /**
+   * The name of the counter.
+   */
+  name: String;
+  /**
+   * The integer value.
+   */
+  value: number;
+}
+/**
+ * A list of [content](https://www.w3.org/TR/css-content-3/#content-property) items, as used in the `content` property.
+ */
+export interface ContentList {
+  /**
+   * Alternative text for the content, specified after a `/`. Only strings, counters, and `attr()` are allowed.
+   */
+  altText?: ContentItem[] | null;
+  /**
+   * The content items.
+   */
+  items: ContentItem[];
+}
+/**
+ * A pair of opening and closing quotation marks, as used in the `quotes` property.
+ */
+export interface QuotePair {
+  /**
+   * The closing quotation mark.
+   */
+  close: String;
+  /**
+   * The opening quotation mark.
+   */
+  open: String;
+}
 /**
  * A value for the [composes](https://github.com/css-modules/css-modules/#dependencies) property from CSS modules.
  */
@@ -8784,6 +11495,19 @@ export interface Polygon {
    */
   points: Point[];
 }
+/**
+ * A [`path()`](https://www.w3.org/TR/css-shapes-1/#funcdef-basic-shape-path) shape.
+ */
+export interface Path {
+  /**
+   * The fill rule used to determine the interior of the path.
+   */
+  fillRule: FillRule;
+  /**
+   * The SVG path data string.
+   */
+  path: String;
+}
 /**
  * A point within a `polygon()` shape.
  *
@@ -8904,6 +11628,18 @@ export interface ColorScheme {
   light: boolean;
   only: boolean;
 }
+/**
+ * A value for the [print-color-adjust](https://drafts.csswg.org/css-color-adjust/#print-color-adjust) property.
+ */
+export type PrintColorAdjust = "economy" | "exact";
+/**
+ * A value for the [forced-color-adjust](https://drafts.csswg.org/css-color-adjust/#forced-color-adjust-prop) property.
+ */
+export type ForcedColorAdjust = "auto" | "none" | "preserve-parent-color";
+/**
+ * A [CSS-wide keyword](https://drafts.csswg.org/css-cascade-5/#defaulting-keywords), as accepted by the `all` property.
+ */
+export type CSSWideKeyword = "initial" | "inherit" | "unset" | "revert" | "revert-layer";
 /**
  * A known property with an unparsed value.
  *
@@ -51,6 +51,11 @@ export interface TransformOptions<C extends CustomAtRules> {
    * selectors but individual names (without any . or # prefixes).
    */
   unusedSymbols?: string[],
+  /**
+   * Values for environment variables, e.g. `safe-area-inset-top`. References to these
+   * via `env()`, including within custom properties, are replaced with the given values.
+   */
+  environmentVariables?: { [name: string]: string },
   /**
    * Whether to ignore invalid rules and declarations rather than erroring.
    * When enabled, warnings are returned, and the invalid rule or declaration is
@@ -416,6 +421,11 @@ export interface TransformAttributeOptions {
    * Dependencies are returned as part of the result.
    */
   analyzeDependencies?: boolean,
+  /**
+   * Values for environment variables, e.g. `safe-area-inset-top`. References to these
+   * via `env()`, including within custom properties, are replaced with the given values.
+   */
+  environmentVariables?: { [name: string]: string },
   /**
    * Whether to ignore invalid rules and declarations rather than erroring.
    * When enabled, warnings are returned, and the invalid rule or declaration is
@@ -33,6 +33,11 @@ export const Features: {
   DoublePositionGradients: 131072,
   VendorPrefixes: 262144,
   LogicalProperties: 524288,
+  FontVariant: 1048576,
+  Zoom: 2097152,
+  LightDarkMediaQuery: 4194304,
+  ViewportUnits: 8388608,
+  GradientInterpolation: 16777216,
   Selectors: 31,
   MediaQueries: 448,
   Colors: 64512,
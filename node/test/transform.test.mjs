@@ -29,6 +29,20 @@ test('can enable non-standard syntax', () => {
   assert.equal(res.code.toString(), '.foo>>>.bar{color:red}');
 });
 
+test('can substitute environment variables', () => {
+  let res = transform({
+    filename: 'test.css',
+    code: Buffer.from('.foo { padding-top: env(safe-area-inset-top, 0px); --inset: env(safe-area-inset-left) env(safe-area-inset-right) }'),
+    minify: true,
+    environmentVariables: {
+      'safe-area-inset-top': '20px',
+      'safe-area-inset-left': '10px'
+    }
+  });
+
+  assert.equal(res.code.toString(), '.foo{padding-top:20px;--inset:10px env(safe-area-inset-right)}');
+});
+
 test('can enable features without targets', () => {
   let res = transform({
     filename: 'test.css',
@@ -70,6 +70,45 @@ prefixes['any-pseudo'] = {
     })
 }
 
+// Autoprefixer does not include data for hyphenate-character, so use the prefixed versions from MDN.
+prefixes['hyphenate-character'] = {
+  browsers: Object.entries(mdn.css.properties['hyphenate-character'].__compat.support)
+    .flatMap(([key, value]) => {
+      if (Array.isArray(value)) {
+        key = MDN_BROWSER_MAPPING[key] || key;
+        let prefixed = value.find(v => v.prefix === '-webkit-')?.version_added;
+        let supported = value.find(x => x.version_added && !x.prefix)?.version_added;
+        if (prefixed && supported) {
+          let parts = supported.split('.');
+          parts[0]--;
+          supported = parts.join('.');
+          return [`${key} ${prefixed}`, `${key} ${supported}`];
+        }
+      }
+
+      return [];
+    })
+};
+
+// Autoprefixer does not include data for initial-letter or line-clamp either. Some browsers only
+// support these properties with a -webkit- prefix, so use the prefixed versions from MDN.
+for (let prop of ['initial-letter', 'line-clamp']) {
+  prefixes[prop] = {
+    browsers: Object.entries(mdn.css.properties[prop].__compat.support)
+      .flatMap(([key, value]) => {
+        value = Array.isArray(value) ? value : [value];
+        key = MDN_BROWSER_MAPPING[key] || key;
+        let prefixed = value.find(v => v.prefix === '-webkit-' || v.alternative_name === `-webkit-${prop}`)?.version_added;
+        let supported = value.find(x => x.version_added && !x.prefix && !x.alternative_name)?.version_added;
+        if (prefixed && !supported && latestBrowserVersions[key]) {
+          return [`${key} ${prefixed}`, `${key} ${latestBrowserVersions[key]}`];
+        }
+
+        return [];
+      })
+  };
+}
+
 let flexSpec = {};
 let oldGradient = {};
 let p = new Map();
@@ -87,6 +126,11 @@ for (let prop in prefixes) {
       prefix = 'webkit';
     }
 
+    // Firefox supports -webkit-line-clamp rather than -moz-line-clamp.
+    if (prop === 'line-clamp') {
+      prefix = 'webkit';
+    }
+
     let origName = name;
     let isCurrentVersion = version === latestBrowserVersions[name];
     name = BROWSER_MAPPING[name] || name;
@@ -281,6 +325,9 @@ let mdnFeatures = {
   logicalPadding: mdn.css.properties['padding-inline-start'].__compat.support,
   logicalPaddingShorthand: mdn.css.properties['padding-inline'].__compat.support,
   logicalInset: mdn.css.properties['inset-inline-start'].__compat.support,
+  logicalScrollMargin: mdn.css.properties['scroll-margin-inline-start'].__compat.support,
+  logicalScrollPadding: mdn.css.properties['scroll-padding-inline-start'].__compat.support,
+  logicalResize: mdn.css.properties.resize.block_inline_support.__compat.support,
   logicalSize: mdn.css.properties['inline-size'].__compat.support,
   logicalTextAlign: mdn.css.properties['text-align']['flow_relative_values_start_and_end'].__compat.support,
   labColors: mdn.css.types.color.lab.__compat.support,
@@ -319,16 +366,42 @@ let mdnFeatures = {
   absFunction: mdn.css.types.abs.__compat.support,
   signFunction: mdn.css.types.sign.__compat.support,
   hypotFunction: mdn.css.types.hypot.__compat.support,
+  gradientInterpolation: mdn.css.types.image.gradient['linear-gradient'].interpolation_color_space.__compat.support,
   gradientInterpolationHints: mdn.css.types.image.gradient['linear-gradient'].interpolation_hints.__compat.support,
   borderImageRepeatRound: mdn.css.properties['border-image-repeat'].round.__compat.support,
   borderImageRepeatSpace: mdn.css.properties['border-image-repeat'].space.__compat.support,
+  fontSizeAdjustFromFont: mdn.css.properties['font-size-adjust']['from-font'].__compat.support,
+  fontSizeAdjustTwoValues: mdn.css.properties['font-size-adjust']['two-values'].__compat.support,
   fontSizeRem: mdn.css.properties['font-size'].rem_values.__compat.support,
   fontSizeXXXLarge: mdn.css.properties['font-size']['xxx-large'].__compat.support,
   fontStyleObliqueAngle: mdn.css.properties['font-style']['oblique-angle'].__compat.support,
   fontWeightNumber: mdn.css.properties['font-weight'].number.__compat.support,
   fontStretchPercentage: mdn.css.properties['font-stretch'].percentage.__compat.support,
   lightDark: mdn.css.types.color['light-dark'].__compat.support,
+  colorScheme: mdn.css.properties['color-scheme'].__compat.support,
+  allProperty: mdn.css.properties.all.__compat.support,
+  breakProperties: mdn.css.properties['break-before'].paged_context.__compat.support,
+  fontSynthesisLonghands: mdn.css.properties['font-synthesis-weight'].__compat.support,
+  fontSynthesisSmallCaps: mdn.css.properties['font-synthesis']['small-caps'].__compat.support,
+  fontSynthesisPosition: mdn.css.properties['font-synthesis'].position.__compat.support,
+  imageRenderingCrispEdges: mdn.css.properties['image-rendering']['crisp-edges'].__compat.support,
+  imageRenderingPixelated: mdn.css.properties['image-rendering'].pixelated.__compat.support,
+  unicodeBidiIsolate: mdn.css.properties['unicode-bidi'].isolate.__compat.support,
+  unicodeBidiIsolateOverride: mdn.css.properties['unicode-bidi']['isolate-override'].__compat.support,
+  unicodeBidiPlaintext: mdn.css.properties['unicode-bidi'].plaintext.__compat.support,
+  textDecorationSkipInk: mdn.css.properties['text-decoration-skip-ink'].__compat.support,
+  individualTransforms: mdn.css.properties.translate.__compat.support,
   accentSystemColor: mdn.css.types.color['system-color'].accentcolor_accentcolortext.__compat.support,
+  fontVariantCaps: mdn.css.properties['font-variant-caps'].__compat.support,
+  fontVariantLigatures: mdn.css.properties['font-variant-ligatures'].__compat.support,
+  fontVariantAlternates: mdn.css.properties['font-variant-alternates'].__compat.support,
+  fontVariantNumeric: mdn.css.properties['font-variant-numeric'].__compat.support,
+  fontVariantEastAsian: mdn.css.properties['font-variant-east-asian'].__compat.support,
+  fontVariantPosition: mdn.css.properties['font-variant-position'].__compat.support,
+  zoom: mdn.css.properties.zoom.__compat.support,
+  caretShorthand: mdn.css.properties.caret.__compat.support,
+  whiteSpaceCollapse: mdn.css.properties['white-space-collapse'].__compat.support,
+  textWrapMode: mdn.css.properties['text-wrap-mode'].__compat.support,
 };
 
 for (let key in mdn.css.types.length) {
@@ -463,6 +536,11 @@ let flags = [
   'DoublePositionGradients',
   'VendorPrefixes',
   'LogicalProperties',
+  'FontVariant',
+  'Zoom',
+  'LightDarkMediaQuery',
+  'ViewportUnits',
+  'GradientInterpolation',
   ['Selectors', ['Nesting', 'NotSelectorList', 'DirSelector', 'LangSelectorList', 'IsSelector']],
   ['MediaQueries', ['MediaIntervalSyntax', 'MediaRangeSyntax', 'CustomMediaQueries']],
   ['Colors', ['ColorFunction', 'OklabColors', 'LabColors', 'P3Colors', 'HexAlphaColors', 'SpaceSeparatedColorNotation']],
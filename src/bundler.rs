@@ -762,6 +762,11 @@ fn visit_vars<'a, 'b>(
             return Some(name);
           }
         }
+        Some(TokenOrValue::Attr(attr)) => {
+          if let Some(fallback) = &mut attr.fallback {
+            stack.push(fallback.0.iter_mut());
+          }
+        }
         Some(TokenOrValue::UnresolvedColor(color)) => match color {
           UnresolvedColor::RGB { alpha, .. } | UnresolvedColor::HSL { alpha, .. } => {
             stack.push(alpha.0.iter_mut());
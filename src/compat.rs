@@ -8,6 +8,7 @@ pub enum Feature {
   AbsFunction,
   AccentSystemColor,
   AfarListStyleType,
+  AllProperty,
   AmharicAbegedeListStyleType,
   AmharicListStyleType,
   AnyLink,
@@ -20,9 +21,11 @@ pub enum Feature {
   BinaryListStyleType,
   BorderImageRepeatRound,
   BorderImageRepeatSpace,
+  BreakProperties,
   CalcFunction,
   CambodianListStyleType,
   CapUnit,
+  CaretShorthand,
   CaseInsensitive,
   ChUnit,
   CircleListStyleType,
@@ -31,6 +34,7 @@ pub enum Feature {
   CjkHeavenlyStemListStyleType,
   ClampFunction,
   ColorFunction,
+  ColorScheme,
   ConicGradient,
   ContainerQueryLengthUnits,
   Cue,
@@ -71,16 +75,28 @@ pub enum Feature {
   FocusVisible,
   FocusWithin,
   FontFamilySystemUi,
+  FontSizeAdjustFromFont,
+  FontSizeAdjustTwoValues,
   FontSizeRem,
   FontSizeXXXLarge,
   FontStretchPercentage,
   FontStyleObliqueAngle,
+  FontSynthesisLonghands,
+  FontSynthesisPosition,
+  FontSynthesisSmallCaps,
+  FontVariantAlternates,
+  FontVariantCaps,
+  FontVariantEastAsian,
+  FontVariantLigatures,
+  FontVariantNumeric,
+  FontVariantPosition,
   FontWeightNumber,
   FootnotesListStyleType,
   FormValidation,
   Fullscreen,
   Gencontent,
   GeorgianListStyleType,
+  GradientInterpolation,
   GradientInterpolationHints,
   GujaratiListStyleType,
   GurmukhiListStyleType,
@@ -91,9 +107,12 @@ pub enum Feature {
   HiraganaListStyleType,
   HypotFunction,
   IcUnit,
+  ImageRenderingCrispEdges,
+  ImageRenderingPixelated,
   ImageSet,
   InOutOfRange,
   IndeterminatePseudo,
+  IndividualTransforms,
   IsAnimatableSize,
   IsSelector,
   JapaneseFormalListStyleType,
@@ -119,6 +138,9 @@ pub enum Feature {
   LogicalMarginShorthand,
   LogicalPadding,
   LogicalPaddingShorthand,
+  LogicalResize,
+  LogicalScrollMargin,
+  LogicalScrollPadding,
   LogicalSize,
   LogicalTextAlign,
   LowerAlphaListStyleType,
@@ -188,8 +210,10 @@ pub enum Feature {
   SymbolsListStyleType,
   TamilListStyleType,
   TeluguListStyleType,
+  TextDecorationSkipInk,
   TextDecorationThicknessPercent,
   TextDecorationThicknessShorthand,
+  TextWrapMode,
   ThaiListStyleType,
   TibetanListStyleType,
   TigreListStyleType,
@@ -199,6 +223,9 @@ pub enum Feature {
   TigrinyaEtListStyleType,
   TradChineseFormalListStyleType,
   TradChineseInformalListStyleType,
+  UnicodeBidiIsolate,
+  UnicodeBidiIsolateOverride,
+  UnicodeBidiPlaintext,
   UpperAlphaListStyleType,
   UpperArmenianListStyleType,
   UpperHexadecimalListStyleType,
@@ -215,7 +242,9 @@ pub enum Feature {
   VminUnit,
   VwUnit,
   WebkitFillAvailableSize,
+  WhiteSpaceCollapse,
   XResolutionUnit,
+  Zoom,
 }
 
 impl Feature {
@@ -2173,6 +2202,51 @@ impl Feature {
           return false;
         }
       }
+      Feature::LogicalScrollMargin | Feature::LogicalScrollPadding => {
+        if let Some(version) = browsers.chrome {
+          if version < 4521984 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.edge {
+          if version < 5177344 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.firefox {
+          if version < 4456448 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.opera {
+          if version < 3670016 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.safari {
+          if version < 983040 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.ios_saf {
+          if version < 983040 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.samsung {
+          if version < 655360 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.android {
+          if version < 4521984 {
+            return false;
+          }
+        }
+        if browsers.ie.is_some() {
+          return false;
+        }
+      }
       Feature::LogicalSize => {
         if let Some(version) = browsers.chrome {
           if version < 3735552 {
@@ -2398,44 +2472,44 @@ impl Feature {
           return false;
         }
       }
-      Feature::SpaceSeparatedColorNotation => {
+      Feature::BreakProperties => {
         if let Some(version) = browsers.chrome {
-          if version < 4259840 {
+          if version < 3276800 {
             return false;
           }
         }
         if let Some(version) = browsers.edge {
-          if version < 5177344 {
+          if version < 786432 {
             return false;
           }
         }
         if let Some(version) = browsers.firefox {
-          if version < 3407872 {
+          if version < 4259840 {
             return false;
           }
         }
         if let Some(version) = browsers.opera {
-          if version < 3080192 {
+          if version < 2424832 {
             return false;
           }
         }
         if let Some(version) = browsers.safari {
-          if version < 786688 {
+          if version < 655360 {
             return false;
           }
         }
         if let Some(version) = browsers.ios_saf {
-          if version < 786944 {
+          if version < 655360 {
             return false;
           }
         }
         if let Some(version) = browsers.samsung {
-          if version < 589824 {
+          if version < 327680 {
             return false;
           }
         }
         if let Some(version) = browsers.android {
-          if version < 4259840 {
+          if version < 3276800 {
             return false;
           }
         }
@@ -2443,44 +2517,44 @@ impl Feature {
           return false;
         }
       }
-      Feature::TextDecorationThicknessPercent => {
+      Feature::ColorScheme => {
         if let Some(version) = browsers.chrome {
-          if version < 5701632 {
+          if version < 5308416 {
             return false;
           }
         }
         if let Some(version) = browsers.edge {
-          if version < 5701632 {
+          if version < 5308416 {
             return false;
           }
         }
         if let Some(version) = browsers.firefox {
-          if version < 5177344 {
+          if version < 6291456 {
             return false;
           }
         }
         if let Some(version) = browsers.opera {
-          if version < 4063232 {
+          if version < 4456448 {
             return false;
           }
         }
         if let Some(version) = browsers.safari {
-          if version < 1115136 {
+          if version < 851968 {
             return false;
           }
         }
         if let Some(version) = browsers.ios_saf {
-          if version < 1115136 {
+          if version < 851968 {
             return false;
           }
         }
         if let Some(version) = browsers.samsung {
-          if version < 917504 {
+          if version < 851968 {
             return false;
           }
         }
         if let Some(version) = browsers.android {
-          if version < 5701632 {
+          if version < 5308416 {
             return false;
           }
         }
@@ -2488,79 +2562,89 @@ impl Feature {
           return false;
         }
       }
-      Feature::TextDecorationThicknessShorthand => {
+      Feature::AllProperty => {
         if let Some(version) = browsers.chrome {
-          if version < 5701632 {
+          if version < 2424832 {
             return false;
           }
         }
         if let Some(version) = browsers.edge {
-          if version < 5701632 {
+          if version < 5177344 {
             return false;
           }
         }
         if let Some(version) = browsers.firefox {
-          if version < 5177344 {
+          if version < 1769472 {
             return false;
           }
         }
         if let Some(version) = browsers.opera {
-          if version < 4063232 {
+          if version < 1572864 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.safari {
+          if version < 590080 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.ios_saf {
+          if version < 590592 {
             return false;
           }
         }
         if let Some(version) = browsers.samsung {
-          if version < 917504 {
+          if version < 196608 {
             return false;
           }
         }
         if let Some(version) = browsers.android {
-          if version < 5701632 {
+          if version < 2424832 {
             return false;
           }
         }
-        if browsers.ie.is_some() || browsers.ios_saf.is_some() || browsers.safari.is_some() {
+        if browsers.ie.is_some() {
           return false;
         }
       }
-      Feature::Cue => {
+      Feature::FontSynthesisLonghands | Feature::FontSynthesisSmallCaps => {
         if let Some(version) = browsers.chrome {
-          if version < 1703936 {
+          if version < 6356992 {
             return false;
           }
         }
         if let Some(version) = browsers.edge {
-          if version < 5177344 {
+          if version < 6356992 {
             return false;
           }
         }
         if let Some(version) = browsers.firefox {
-          if version < 3604480 {
+          if version < 7274496 {
             return false;
           }
         }
         if let Some(version) = browsers.opera {
-          if version < 917504 {
+          if version < 5439488 {
             return false;
           }
         }
         if let Some(version) = browsers.safari {
-          if version < 458752 {
+          if version < 1049600 {
             return false;
           }
         }
         if let Some(version) = browsers.ios_saf {
-          if version < 458752 {
+          if version < 1049600 {
             return false;
           }
         }
         if let Some(version) = browsers.samsung {
-          if version < 66816 {
+          if version < 1179648 {
             return false;
           }
         }
         if let Some(version) = browsers.android {
-          if version < 263168 {
+          if version < 6356992 {
             return false;
           }
         }
@@ -2568,9 +2652,9 @@ impl Feature {
           return false;
         }
       }
-      Feature::CueFunction => {
+      Feature::UnicodeBidiIsolate | Feature::UnicodeBidiIsolateOverride | Feature::UnicodeBidiPlaintext => {
         if let Some(version) = browsers.chrome {
-          if version < 1703936 {
+          if version < 3145728 {
             return false;
           }
         }
@@ -2579,73 +2663,96 @@ impl Feature {
             return false;
           }
         }
+        if let Some(version) = browsers.firefox {
+          if version < 3276800 {
+            return false;
+          }
+        }
         if let Some(version) = browsers.opera {
-          if version < 917504 {
+          if version < 2293760 {
             return false;
           }
         }
         if let Some(version) = browsers.safari {
-          if version < 458752 {
+          if version < 720896 {
             return false;
           }
         }
         if let Some(version) = browsers.ios_saf {
-          if version < 458752 {
+          if version < 720896 {
             return false;
           }
         }
         if let Some(version) = browsers.samsung {
-          if version < 66816 {
+          if version < 327680 {
             return false;
           }
         }
         if let Some(version) = browsers.android {
-          if version < 263168 {
+          if version < 3145728 {
             return false;
           }
         }
-        if browsers.firefox.is_some() || browsers.ie.is_some() {
+        if browsers.ie.is_some() {
           return false;
         }
       }
-      Feature::AnyPseudo => {
+      Feature::FontSynthesisPosition => {
+        if let Some(version) = browsers.firefox {
+          if version < 7733248 {
+            return false;
+          }
+        }
+        if browsers.android.is_some()
+          || browsers.chrome.is_some()
+          || browsers.edge.is_some()
+          || browsers.ie.is_some()
+          || browsers.ios_saf.is_some()
+          || browsers.opera.is_some()
+          || browsers.safari.is_some()
+          || browsers.samsung.is_some()
+        {
+          return false;
+        }
+      }
+      Feature::LogicalResize => {
         if let Some(version) = browsers.chrome {
-          if version < 1179648 {
+          if version < 7733248 {
             return false;
           }
         }
         if let Some(version) = browsers.edge {
-          if version < 5177344 {
+          if version < 7733248 {
             return false;
           }
         }
         if let Some(version) = browsers.firefox {
-          if version < 262144 {
+          if version < 4128768 {
             return false;
           }
         }
         if let Some(version) = browsers.opera {
-          if version < 917504 {
+          if version < 6815744 {
             return false;
           }
         }
         if let Some(version) = browsers.safari {
-          if version < 327680 {
+          if version < 1048576 {
             return false;
           }
         }
         if let Some(version) = browsers.ios_saf {
-          if version < 327680 {
+          if version < 1048576 {
             return false;
           }
         }
         if let Some(version) = browsers.samsung {
-          if version < 65536 {
+          if version < 1638400 {
             return false;
           }
         }
         if let Some(version) = browsers.android {
-          if version < 2424832 {
+          if version < 7733248 {
             return false;
           }
         }
@@ -2653,9 +2760,35 @@ impl Feature {
           return false;
         }
       }
-      Feature::PartPseudo => {
+      Feature::ImageRenderingCrispEdges => {
+        if let Some(version) = browsers.firefox {
+          if version < 4259840 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.safari {
+          if version < 458752 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.ios_saf {
+          if version < 458752 {
+            return false;
+          }
+        }
+        if browsers.android.is_some()
+          || browsers.chrome.is_some()
+          || browsers.edge.is_some()
+          || browsers.ie.is_some()
+          || browsers.opera.is_some()
+          || browsers.samsung.is_some()
+        {
+          return false;
+        }
+      }
+      Feature::ImageRenderingPixelated => {
         if let Some(version) = browsers.chrome {
-          if version < 4784128 {
+          if version < 2686976 {
             return false;
           }
         }
@@ -2665,32 +2798,32 @@ impl Feature {
           }
         }
         if let Some(version) = browsers.firefox {
-          if version < 5177344 {
+          if version < 6094848 {
             return false;
           }
         }
         if let Some(version) = browsers.opera {
-          if version < 3407872 {
+          if version < 1703936 {
             return false;
           }
         }
         if let Some(version) = browsers.safari {
-          if version < 852224 {
+          if version < 655360 {
             return false;
           }
         }
         if let Some(version) = browsers.ios_saf {
-          if version < 852992 {
+          if version < 655360 {
             return false;
           }
         }
         if let Some(version) = browsers.samsung {
-          if version < 720896 {
+          if version < 262144 {
             return false;
           }
         }
         if let Some(version) = browsers.android {
-          if version < 4784128 {
+          if version < 2686976 {
             return false;
           }
         }
@@ -2698,9 +2831,9 @@ impl Feature {
           return false;
         }
       }
-      Feature::ImageSet => {
+      Feature::SpaceSeparatedColorNotation => {
         if let Some(version) = browsers.chrome {
-          if version < 1638400 {
+          if version < 4259840 {
             return false;
           }
         }
@@ -2710,32 +2843,32 @@ impl Feature {
           }
         }
         if let Some(version) = browsers.firefox {
-          if version < 5767168 {
+          if version < 3407872 {
             return false;
           }
         }
         if let Some(version) = browsers.opera {
-          if version < 917504 {
+          if version < 3080192 {
             return false;
           }
         }
         if let Some(version) = browsers.safari {
-          if version < 393216 {
+          if version < 786688 {
             return false;
           }
         }
         if let Some(version) = browsers.ios_saf {
-          if version < 393216 {
+          if version < 786944 {
             return false;
           }
         }
         if let Some(version) = browsers.samsung {
-          if version < 66816 {
+          if version < 589824 {
             return false;
           }
         }
         if let Some(version) = browsers.android {
-          if version < 263168 {
+          if version < 4259840 {
             return false;
           }
         }
@@ -2743,9 +2876,9 @@ impl Feature {
           return false;
         }
       }
-      Feature::XResolutionUnit => {
+      Feature::TextDecorationSkipInk => {
         if let Some(version) = browsers.chrome {
-          if version < 4456448 {
+          if version < 4194304 {
             return false;
           }
         }
@@ -2755,42 +2888,387 @@ impl Feature {
           }
         }
         if let Some(version) = browsers.firefox {
-          if version < 4063232 {
+          if version < 4587520 {
             return false;
           }
         }
         if let Some(version) = browsers.opera {
-          if version < 3145728 {
+          if version < 3276800 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.safari {
+          if version < 984064 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.ios_saf {
+          if version < 984064 {
             return false;
           }
         }
         if let Some(version) = browsers.samsung {
-          if version < 655360 {
+          if version < 589824 {
             return false;
           }
         }
         if let Some(version) = browsers.android {
-          if version < 4456448 {
+          if version < 4194304 {
             return false;
           }
         }
-        if browsers.ie.is_some() || browsers.ios_saf.is_some() || browsers.safari.is_some() {
+        if browsers.ie.is_some() {
           return false;
         }
       }
-      Feature::NthChildOf => {
+      Feature::TextDecorationThicknessPercent => {
         if let Some(version) = browsers.chrome {
-          if version < 7274496 {
+          if version < 5701632 {
             return false;
           }
         }
         if let Some(version) = browsers.edge {
-          if version < 7274496 {
+          if version < 5701632 {
             return false;
           }
         }
         if let Some(version) = browsers.firefox {
-          if version < 7405568 {
+          if version < 5177344 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.opera {
+          if version < 4063232 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.safari {
+          if version < 1115136 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.ios_saf {
+          if version < 1115136 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.samsung {
+          if version < 917504 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.android {
+          if version < 5701632 {
+            return false;
+          }
+        }
+        if browsers.ie.is_some() {
+          return false;
+        }
+      }
+      Feature::TextDecorationThicknessShorthand => {
+        if let Some(version) = browsers.chrome {
+          if version < 5701632 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.edge {
+          if version < 5701632 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.firefox {
+          if version < 5177344 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.opera {
+          if version < 4063232 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.samsung {
+          if version < 917504 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.android {
+          if version < 5701632 {
+            return false;
+          }
+        }
+        if browsers.ie.is_some() || browsers.ios_saf.is_some() || browsers.safari.is_some() {
+          return false;
+        }
+      }
+      Feature::Cue => {
+        if let Some(version) = browsers.chrome {
+          if version < 1703936 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.edge {
+          if version < 5177344 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.firefox {
+          if version < 3604480 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.opera {
+          if version < 917504 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.safari {
+          if version < 458752 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.ios_saf {
+          if version < 458752 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.samsung {
+          if version < 66816 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.android {
+          if version < 263168 {
+            return false;
+          }
+        }
+        if browsers.ie.is_some() {
+          return false;
+        }
+      }
+      Feature::CueFunction => {
+        if let Some(version) = browsers.chrome {
+          if version < 1703936 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.edge {
+          if version < 5177344 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.opera {
+          if version < 917504 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.safari {
+          if version < 458752 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.ios_saf {
+          if version < 458752 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.samsung {
+          if version < 66816 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.android {
+          if version < 263168 {
+            return false;
+          }
+        }
+        if browsers.firefox.is_some() || browsers.ie.is_some() {
+          return false;
+        }
+      }
+      Feature::AnyPseudo => {
+        if let Some(version) = browsers.chrome {
+          if version < 1179648 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.edge {
+          if version < 5177344 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.firefox {
+          if version < 262144 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.opera {
+          if version < 917504 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.safari {
+          if version < 327680 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.ios_saf {
+          if version < 327680 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.samsung {
+          if version < 65536 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.android {
+          if version < 2424832 {
+            return false;
+          }
+        }
+        if browsers.ie.is_some() {
+          return false;
+        }
+      }
+      Feature::PartPseudo => {
+        if let Some(version) = browsers.chrome {
+          if version < 4784128 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.edge {
+          if version < 5177344 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.firefox {
+          if version < 5177344 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.opera {
+          if version < 3407872 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.safari {
+          if version < 852224 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.ios_saf {
+          if version < 852992 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.samsung {
+          if version < 720896 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.android {
+          if version < 4784128 {
+            return false;
+          }
+        }
+        if browsers.ie.is_some() {
+          return false;
+        }
+      }
+      Feature::ImageSet => {
+        if let Some(version) = browsers.chrome {
+          if version < 1638400 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.edge {
+          if version < 5177344 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.firefox {
+          if version < 5767168 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.opera {
+          if version < 917504 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.safari {
+          if version < 393216 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.ios_saf {
+          if version < 393216 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.samsung {
+          if version < 66816 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.android {
+          if version < 263168 {
+            return false;
+          }
+        }
+        if browsers.ie.is_some() {
+          return false;
+        }
+      }
+      Feature::XResolutionUnit => {
+        if let Some(version) = browsers.chrome {
+          if version < 4456448 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.edge {
+          if version < 5177344 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.firefox {
+          if version < 4063232 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.opera {
+          if version < 3145728 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.samsung {
+          if version < 655360 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.android {
+          if version < 4456448 {
+            return false;
+          }
+        }
+        if browsers.ie.is_some() || browsers.ios_saf.is_some() || browsers.safari.is_some() {
+          return false;
+        }
+      }
+      Feature::NthChildOf => {
+        if let Some(version) = browsers.chrome {
+          if version < 7274496 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.edge {
+          if version < 7274496 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.firefox {
+          if version < 7405568 {
             return false;
           }
         }
@@ -2800,22 +3278,599 @@ impl Feature {
           }
         }
         if let Some(version) = browsers.safari {
-          if version < 589824 {
+          if version < 589824 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.ios_saf {
+          if version < 589824 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.samsung {
+          if version < 1441792 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.android {
+          if version < 7274496 {
+            return false;
+          }
+        }
+        if browsers.ie.is_some() {
+          return false;
+        }
+      }
+      Feature::MinFunction | Feature::MaxFunction => {
+        if let Some(version) = browsers.chrome {
+          if version < 5177344 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.edge {
+          if version < 5177344 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.firefox {
+          if version < 5177344 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.opera {
+          if version < 3735552 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.safari {
+          if version < 721152 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.ios_saf {
+          if version < 721664 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.samsung {
+          if version < 786432 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.android {
+          if version < 5177344 {
+            return false;
+          }
+        }
+        if browsers.ie.is_some() {
+          return false;
+        }
+      }
+      Feature::RoundFunction
+      | Feature::RemFunction
+      | Feature::ModFunction
+      | Feature::AbsFunction
+      | Feature::SignFunction
+      | Feature::HypotFunction => {
+        if let Some(version) = browsers.firefox {
+          if version < 7733248 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.safari {
+          if version < 984064 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.ios_saf {
+          if version < 984064 {
+            return false;
+          }
+        }
+        if browsers.android.is_some()
+          || browsers.chrome.is_some()
+          || browsers.edge.is_some()
+          || browsers.ie.is_some()
+          || browsers.opera.is_some()
+          || browsers.samsung.is_some()
+        {
+          return false;
+        }
+      }
+      Feature::GradientInterpolation => {
+        if let Some(version) = browsers.chrome {
+          if version < 7274496 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.edge {
+          if version < 7274496 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.firefox {
+          if version < 8323072 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.opera {
+          if version < 6356992 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.safari {
+          if version < 1049088 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.ios_saf {
+          if version < 1049088 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.samsung {
+          if version < 1441792 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.android {
+          if version < 7274496 {
+            return false;
+          }
+        }
+        if browsers.ie.is_some() {
+          return false;
+        }
+      }
+      Feature::GradientInterpolationHints => {
+        if let Some(version) = browsers.chrome {
+          if version < 2621440 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.edge {
+          if version < 5177344 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.firefox {
+          if version < 2359296 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.opera {
+          if version < 1769472 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.safari {
+          if version < 458752 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.ios_saf {
+          if version < 458752 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.samsung {
+          if version < 262144 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.android {
+          if version < 2621440 {
+            return false;
+          }
+        }
+        if browsers.ie.is_some() {
+          return false;
+        }
+      }
+      Feature::BorderImageRepeatRound => {
+        if let Some(version) = browsers.chrome {
+          if version < 1966080 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.edge {
+          if version < 786432 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.firefox {
+          if version < 983040 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.ie {
+          if version < 720896 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.opera {
+          if version < 1179648 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.safari {
+          if version < 590080 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.ios_saf {
+          if version < 590592 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.samsung {
+          if version < 131072 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.android {
+          if version < 2424832 {
+            return false;
+          }
+        }
+      }
+      Feature::BorderImageRepeatSpace => {
+        if let Some(version) = browsers.chrome {
+          if version < 3670016 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.edge {
+          if version < 786432 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.firefox {
+          if version < 3276800 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.ie {
+          if version < 720896 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.opera {
+          if version < 2818048 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.safari {
+          if version < 590080 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.ios_saf {
+          if version < 590592 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.samsung {
+          if version < 393216 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.android {
+          if version < 3670016 {
+            return false;
+          }
+        }
+      }
+      Feature::FontSizeAdjustFromFont => {
+        if let Some(version) = browsers.chrome {
+          if version < 8323072 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.edge {
+          if version < 8323072 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.firefox {
+          if version < 6029312 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.opera {
+          if version < 7405568 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.safari {
+          if version < 1114112 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.ios_saf {
+          if version < 1114112 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.samsung {
+          if version < 1835008 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.android {
+          if version < 8323072 {
+            return false;
+          }
+        }
+        if browsers.ie.is_some() {
+          return false;
+        }
+      }
+      Feature::FontSizeAdjustTwoValues => {
+        if let Some(version) = browsers.chrome {
+          if version < 8323072 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.edge {
+          if version < 8323072 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.firefox {
+          if version < 7733248 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.opera {
+          if version < 7405568 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.safari {
+          if version < 1114112 {
             return false;
           }
         }
         if let Some(version) = browsers.ios_saf {
+          if version < 1114112 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.samsung {
+          if version < 1835008 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.android {
+          if version < 8323072 {
+            return false;
+          }
+        }
+        if browsers.ie.is_some() {
+          return false;
+        }
+      }
+      Feature::FontSizeRem => {
+        if let Some(version) = browsers.chrome {
+          if version < 2752512 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.edge {
+          if version < 786432 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.firefox {
+          if version < 2031616 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.ie {
           if version < 589824 {
             return false;
           }
         }
+        if let Some(version) = browsers.opera {
+          if version < 1835008 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.safari {
+          if version < 458752 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.ios_saf {
+          if version < 458752 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.samsung {
+          if version < 262144 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.android {
+          if version < 262144 {
+            return false;
+          }
+        }
+      }
+      Feature::FontSizeXXXLarge => {
+        if let Some(version) = browsers.chrome {
+          if version < 5177344 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.edge {
+          if version < 5177344 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.firefox {
+          if version < 5177344 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.opera {
+          if version < 3735552 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.safari {
+          if version < 1049600 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.ios_saf {
+          if version < 1049600 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.samsung {
+          if version < 786432 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.android {
+          if version < 5177344 {
+            return false;
+          }
+        }
+        if browsers.ie.is_some() {
+          return false;
+        }
+      }
+      Feature::FontStyleObliqueAngle => {
+        if let Some(version) = browsers.chrome {
+          if version < 4063232 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.edge {
+          if version < 5177344 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.firefox {
+          if version < 3997696 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.opera {
+          if version < 3014656 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.safari {
+          if version < 721152 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.ios_saf {
+          if version < 721664 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.samsung {
+          if version < 524288 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.android {
+          if version < 4063232 {
+            return false;
+          }
+        }
+        if browsers.ie.is_some() {
+          return false;
+        }
+      }
+      Feature::FontWeightNumber => {
+        if let Some(version) = browsers.chrome {
+          if version < 4063232 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.edge {
+          if version < 1114112 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.firefox {
+          if version < 3997696 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.opera {
+          if version < 3014656 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.safari {
+          if version < 720896 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.ios_saf {
+          if version < 720896 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.samsung {
+          if version < 524288 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.android {
+          if version < 4063232 {
+            return false;
+          }
+        }
+        if browsers.ie.is_some() {
+          return false;
+        }
+      }
+      Feature::FontStretchPercentage => {
+        if let Some(version) = browsers.chrome {
+          if version < 4063232 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.edge {
+          if version < 1179648 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.firefox {
+          if version < 3997696 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.opera {
+          if version < 3014656 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.safari {
+          if version < 721152 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.ios_saf {
+          if version < 721664 {
+            return false;
+          }
+        }
         if let Some(version) = browsers.samsung {
-          if version < 1441792 {
+          if version < 524288 {
             return false;
           }
         }
         if let Some(version) = browsers.android {
-          if version < 7274496 {
+          if version < 4063232 {
             return false;
           }
         }
@@ -2823,44 +3878,66 @@ impl Feature {
           return false;
         }
       }
-      Feature::MinFunction | Feature::MaxFunction => {
+      Feature::LightDark => {
         if let Some(version) = browsers.chrome {
-          if version < 5177344 {
+          if version < 8060928 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.firefox {
+          if version < 7864320 {
+            return false;
+          }
+        }
+        if browsers.android.is_some()
+          || browsers.edge.is_some()
+          || browsers.ie.is_some()
+          || browsers.ios_saf.is_some()
+          || browsers.opera.is_some()
+          || browsers.safari.is_some()
+          || browsers.samsung.is_some()
+        {
+          return false;
+        }
+      }
+      Feature::IndividualTransforms => {
+        if let Some(version) = browsers.chrome {
+          if version < 6815744 {
             return false;
           }
         }
         if let Some(version) = browsers.edge {
-          if version < 5177344 {
+          if version < 6815744 {
             return false;
           }
         }
         if let Some(version) = browsers.firefox {
-          if version < 5177344 {
+          if version < 4718592 {
             return false;
           }
         }
         if let Some(version) = browsers.opera {
-          if version < 3735552 {
+          if version < 5898240 {
             return false;
           }
         }
         if let Some(version) = browsers.safari {
-          if version < 721152 {
+          if version < 917760 {
             return false;
           }
         }
         if let Some(version) = browsers.ios_saf {
-          if version < 721664 {
+          if version < 918784 {
             return false;
           }
         }
         if let Some(version) = browsers.samsung {
-          if version < 786432 {
+          if version < 1310720 {
             return false;
           }
         }
         if let Some(version) = browsers.android {
-          if version < 5177344 {
+          if version < 6815744 {
             return false;
           }
         }
@@ -2868,24 +3945,19 @@ impl Feature {
           return false;
         }
       }
-      Feature::RoundFunction
-      | Feature::RemFunction
-      | Feature::ModFunction
-      | Feature::AbsFunction
-      | Feature::SignFunction
-      | Feature::HypotFunction => {
+      Feature::AccentSystemColor => {
         if let Some(version) = browsers.firefox {
-          if version < 7733248 {
+          if version < 6750208 {
             return false;
           }
         }
         if let Some(version) = browsers.safari {
-          if version < 984064 {
+          if version < 1049856 {
             return false;
           }
         }
         if let Some(version) = browsers.ios_saf {
-          if version < 984064 {
+          if version < 1049856 {
             return false;
           }
         }
@@ -2899,9 +3971,9 @@ impl Feature {
           return false;
         }
       }
-      Feature::GradientInterpolationHints => {
+      Feature::FontVariantCaps | Feature::FontVariantNumeric => {
         if let Some(version) = browsers.chrome {
-          if version < 2621440 {
+          if version < 3407872 {
             return false;
           }
         }
@@ -2911,32 +3983,32 @@ impl Feature {
           }
         }
         if let Some(version) = browsers.firefox {
-          if version < 2359296 {
+          if version < 2228224 {
             return false;
           }
         }
         if let Some(version) = browsers.opera {
-          if version < 1769472 {
+          if version < 2555904 {
             return false;
           }
         }
         if let Some(version) = browsers.safari {
-          if version < 458752 {
+          if version < 590080 {
             return false;
           }
         }
         if let Some(version) = browsers.ios_saf {
-          if version < 458752 {
+          if version < 590592 {
             return false;
           }
         }
         if let Some(version) = browsers.samsung {
-          if version < 262144 {
+          if version < 393216 {
             return false;
           }
         }
         if let Some(version) = browsers.android {
-          if version < 2621440 {
+          if version < 3407872 {
             return false;
           }
         }
@@ -2944,29 +4016,24 @@ impl Feature {
           return false;
         }
       }
-      Feature::BorderImageRepeatRound => {
+      Feature::FontVariantLigatures => {
         if let Some(version) = browsers.chrome {
-          if version < 1966080 {
+          if version < 2228224 {
             return false;
           }
         }
         if let Some(version) = browsers.edge {
-          if version < 786432 {
+          if version < 5177344 {
             return false;
           }
         }
         if let Some(version) = browsers.firefox {
-          if version < 983040 {
-            return false;
-          }
-        }
-        if let Some(version) = browsers.ie {
-          if version < 720896 {
+          if version < 2228224 {
             return false;
           }
         }
         if let Some(version) = browsers.opera {
-          if version < 1179648 {
+          if version < 1376256 {
             return false;
           }
         }
@@ -2986,34 +4053,32 @@ impl Feature {
           }
         }
         if let Some(version) = browsers.android {
-          if version < 2424832 {
+          if version < 263171 {
             return false;
           }
         }
+        if browsers.ie.is_some() {
+          return false;
+        }
       }
-      Feature::BorderImageRepeatSpace => {
+      Feature::FontVariantAlternates => {
         if let Some(version) = browsers.chrome {
-          if version < 3670016 {
+          if version < 7274496 {
             return false;
           }
         }
         if let Some(version) = browsers.edge {
-          if version < 786432 {
+          if version < 7274496 {
             return false;
           }
         }
         if let Some(version) = browsers.firefox {
-          if version < 3276800 {
-            return false;
-          }
-        }
-        if let Some(version) = browsers.ie {
-          if version < 720896 {
+          if version < 2228224 {
             return false;
           }
         }
         if let Some(version) = browsers.opera {
-          if version < 2818048 {
+          if version < 6356992 {
             return false;
           }
         }
@@ -3028,101 +4093,102 @@ impl Feature {
           }
         }
         if let Some(version) = browsers.samsung {
-          if version < 393216 {
+          if version < 1441792 {
             return false;
           }
         }
         if let Some(version) = browsers.android {
-          if version < 3670016 {
+          if version < 7274496 {
             return false;
           }
         }
+        if browsers.ie.is_some() {
+          return false;
+        }
       }
-      Feature::FontSizeRem => {
+      Feature::FontVariantEastAsian => {
         if let Some(version) = browsers.chrome {
-          if version < 2752512 {
+          if version < 4128768 {
             return false;
           }
         }
         if let Some(version) = browsers.edge {
-          if version < 786432 {
+          if version < 5177344 {
             return false;
           }
         }
         if let Some(version) = browsers.firefox {
-          if version < 2031616 {
-            return false;
-          }
-        }
-        if let Some(version) = browsers.ie {
-          if version < 589824 {
+          if version < 2228224 {
             return false;
           }
         }
         if let Some(version) = browsers.opera {
-          if version < 1835008 {
+          if version < 3276800 {
             return false;
           }
         }
         if let Some(version) = browsers.safari {
-          if version < 458752 {
+          if version < 590080 {
             return false;
           }
         }
         if let Some(version) = browsers.ios_saf {
-          if version < 458752 {
+          if version < 590592 {
             return false;
           }
         }
         if let Some(version) = browsers.samsung {
-          if version < 262144 {
+          if version < 524288 {
             return false;
           }
         }
         if let Some(version) = browsers.android {
-          if version < 262144 {
+          if version < 4128768 {
             return false;
           }
         }
+        if browsers.ie.is_some() {
+          return false;
+        }
       }
-      Feature::FontSizeXXXLarge => {
+      Feature::FontVariantPosition => {
         if let Some(version) = browsers.chrome {
-          if version < 5177344 {
+          if version < 7667712 {
             return false;
           }
         }
         if let Some(version) = browsers.edge {
-          if version < 5177344 {
+          if version < 7667712 {
             return false;
           }
         }
         if let Some(version) = browsers.firefox {
-          if version < 5177344 {
+          if version < 2228224 {
             return false;
           }
         }
         if let Some(version) = browsers.opera {
-          if version < 3735552 {
+          if version < 6750208 {
             return false;
           }
         }
         if let Some(version) = browsers.safari {
-          if version < 1049600 {
+          if version < 590080 {
             return false;
           }
         }
         if let Some(version) = browsers.ios_saf {
-          if version < 1049600 {
+          if version < 590592 {
             return false;
           }
         }
         if let Some(version) = browsers.samsung {
-          if version < 786432 {
+          if version < 1572864 {
             return false;
           }
         }
         if let Some(version) = browsers.android {
-          if version < 5177344 {
+          if version < 7667712 {
             return false;
           }
         }
@@ -3130,89 +4196,105 @@ impl Feature {
           return false;
         }
       }
-      Feature::FontStyleObliqueAngle => {
+      Feature::Zoom => {
         if let Some(version) = browsers.chrome {
-          if version < 4063232 {
+          if version < 65536 {
             return false;
           }
         }
         if let Some(version) = browsers.edge {
-          if version < 5177344 {
+          if version < 786432 {
             return false;
           }
         }
         if let Some(version) = browsers.firefox {
-          if version < 3997696 {
+          if version < 8257536 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.ie {
+          if version < 328960 {
             return false;
           }
         }
         if let Some(version) = browsers.opera {
-          if version < 3014656 {
+          if version < 983040 {
             return false;
           }
         }
         if let Some(version) = browsers.safari {
-          if version < 721152 {
+          if version < 196864 {
             return false;
           }
         }
         if let Some(version) = browsers.ios_saf {
-          if version < 721664 {
+          if version < 196608 {
             return false;
           }
         }
         if let Some(version) = browsers.samsung {
-          if version < 524288 {
+          if version < 65536 {
             return false;
           }
         }
         if let Some(version) = browsers.android {
-          if version < 4063232 {
+          if version < 2424832 {
             return false;
           }
         }
-        if browsers.ie.is_some() {
+      }
+      Feature::CaretShorthand => {
+        if browsers.android.is_some()
+          || browsers.chrome.is_some()
+          || browsers.edge.is_some()
+          || browsers.firefox.is_some()
+          || browsers.ie.is_some()
+          || browsers.ios_saf.is_some()
+          || browsers.opera.is_some()
+          || browsers.safari.is_some()
+          || browsers.samsung.is_some()
+        {
           return false;
         }
       }
-      Feature::FontWeightNumber => {
+      Feature::WhiteSpaceCollapse => {
         if let Some(version) = browsers.chrome {
-          if version < 4063232 {
+          if version < 7471104 {
             return false;
           }
         }
         if let Some(version) = browsers.edge {
-          if version < 1114112 {
+          if version < 7471104 {
             return false;
           }
         }
         if let Some(version) = browsers.firefox {
-          if version < 3997696 {
+          if version < 8126464 {
             return false;
           }
         }
         if let Some(version) = browsers.opera {
-          if version < 3014656 {
+          if version < 6553600 {
             return false;
           }
         }
         if let Some(version) = browsers.safari {
-          if version < 720896 {
+          if version < 1115136 {
             return false;
           }
         }
         if let Some(version) = browsers.ios_saf {
-          if version < 720896 {
+          if version < 1115136 {
             return false;
           }
         }
         if let Some(version) = browsers.samsung {
-          if version < 524288 {
+          if version < 1507328 {
             return false;
           }
         }
         if let Some(version) = browsers.android {
-          if version < 4063232 {
+          if version < 7471104 {
             return false;
           }
         }
@@ -3220,44 +4302,44 @@ impl Feature {
           return false;
         }
       }
-      Feature::FontStretchPercentage => {
+      Feature::TextWrapMode => {
         if let Some(version) = browsers.chrome {
-          if version < 4063232 {
+          if version < 8519680 {
             return false;
           }
         }
         if let Some(version) = browsers.edge {
-          if version < 1179648 {
+          if version < 8519680 {
             return false;
           }
         }
         if let Some(version) = browsers.firefox {
-          if version < 3997696 {
+          if version < 8126464 {
             return false;
           }
         }
         if let Some(version) = browsers.opera {
-          if version < 3014656 {
+          if version < 7536640 {
             return false;
           }
         }
         if let Some(version) = browsers.safari {
-          if version < 721152 {
+          if version < 1115136 {
             return false;
           }
         }
         if let Some(version) = browsers.ios_saf {
-          if version < 721664 {
+          if version < 1115136 {
             return false;
           }
         }
         if let Some(version) = browsers.samsung {
-          if version < 524288 {
+          if version < 1835008 {
             return false;
           }
         }
         if let Some(version) = browsers.android {
-          if version < 4063232 {
+          if version < 8519680 {
             return false;
           }
         }
@@ -3265,54 +4347,6 @@ impl Feature {
           return false;
         }
       }
-      Feature::LightDark => {
-        if let Some(version) = browsers.chrome {
-          if version < 8060928 {
-            return false;
-          }
-        }
-        if let Some(version) = browsers.firefox {
-          if version < 7864320 {
-            return false;
-          }
-        }
-        if browsers.android.is_some()
-          || browsers.edge.is_some()
-          || browsers.ie.is_some()
-          || browsers.ios_saf.is_some()
-          || browsers.opera.is_some()
-          || browsers.safari.is_some()
-          || browsers.samsung.is_some()
-        {
-          return false;
-        }
-      }
-      Feature::AccentSystemColor => {
-        if let Some(version) = browsers.firefox {
-          if version < 6750208 {
-            return false;
-          }
-        }
-        if let Some(version) = browsers.safari {
-          if version < 1049856 {
-            return false;
-          }
-        }
-        if let Some(version) = browsers.ios_saf {
-          if version < 1049856 {
-            return false;
-          }
-        }
-        if browsers.android.is_some()
-          || browsers.chrome.is_some()
-          || browsers.edge.is_some()
-          || browsers.ie.is_some()
-          || browsers.opera.is_some()
-          || browsers.samsung.is_some()
-        {
-          return false;
-        }
-      }
       Feature::QUnit => {
         if let Some(version) = browsers.chrome {
           if version < 4128768 {
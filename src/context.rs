@@ -1,4 +1,4 @@
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 
 use crate::compat::Feature;
 use crate::declaration::DeclarationBlock;
@@ -6,7 +6,7 @@ use crate::media_query::{
   MediaCondition, MediaFeatureId, MediaFeatureName, MediaFeatureValue, MediaList, MediaQuery, MediaType,
   QueryFeature,
 };
-use crate::properties::custom::UnparsedProperty;
+use crate::properties::custom::{TokenList, UnparsedProperty};
 use crate::properties::Property;
 use crate::rules::media::MediaRule;
 use crate::rules::supports::{SupportsCondition, SupportsRule};
@@ -42,10 +42,15 @@ pub(crate) struct PropertyHandlerContext<'i, 'o> {
   dark: Vec<Property<'i>>,
   pub context: DeclarationContext,
   pub unused_symbols: &'o HashSet<String>,
+  pub environment_variables: &'o HashMap<String, TokenList<'static>>,
 }
 
 impl<'i, 'o> PropertyHandlerContext<'i, 'o> {
-  pub fn new(targets: Targets, unused_symbols: &'o HashSet<String>) -> Self {
+  pub fn new(
+    targets: Targets,
+    unused_symbols: &'o HashSet<String>,
+    environment_variables: &'o HashMap<String, TokenList<'static>>,
+  ) -> Self {
     PropertyHandlerContext {
       targets,
       is_important: false,
@@ -55,6 +60,7 @@ impl<'i, 'o> PropertyHandlerContext<'i, 'o> {
       dark: Vec::new(),
       context: DeclarationContext::None,
       unused_symbols,
+      environment_variables,
     }
   }
 
@@ -68,6 +74,7 @@ impl<'i, 'o> PropertyHandlerContext<'i, 'o> {
       dark: Vec::new(),
       context,
       unused_symbols: self.unused_symbols,
+      environment_variables: self.environment_variables,
     }
   }
 
@@ -86,6 +93,30 @@ impl<'i, 'o> PropertyHandlerContext<'i, 'o> {
     self.rtl.push(rtl);
   }
 
+  pub fn logical_rules_len(&self) -> usize {
+    self.ltr.len()
+  }
+
+  /// Removes logical rule declarations added between `start` and `end` that are
+  /// repeated after `end`, e.g. fallback values that ended up unchanged.
+  pub fn dedup_logical_rules(&mut self, start: usize, end: usize) {
+    let mut i = start;
+    let mut end = end;
+    while i < end {
+      let is_duplicate = self.ltr[end..]
+        .iter()
+        .zip(&self.rtl[end..])
+        .any(|(ltr, rtl)| *ltr == self.ltr[i] && *rtl == self.rtl[i]);
+      if is_duplicate {
+        self.ltr.remove(i);
+        self.rtl.remove(i);
+        end -= 1;
+      } else {
+        i += 1;
+      }
+    }
+  }
+
   pub fn add_dark_rule(&mut self, property: Property<'i>) {
     self.dark.push(property);
   }
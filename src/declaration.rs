@@ -4,6 +4,7 @@ use std::borrow::Cow;
 use std::collections::HashMap;
 use std::ops::Range;
 
+use crate::compat::Feature;
 use crate::context::{DeclarationContext, PropertyHandlerContext};
 use crate::error::{ParserError, PrinterError};
 use crate::parser::ParserOptions;
@@ -13,27 +14,34 @@ use crate::properties::custom::{CustomProperty, CustomPropertyName};
 use crate::properties::masking::MaskHandler;
 use crate::properties::{
   align::AlignHandler,
+  anchor::PositionTryHandler,
   animation::AnimationHandler,
   background::BackgroundHandler,
   border::BorderHandler,
+  columns::{ColumnRuleHandler, ColumnsHandler},
   contain::ContainerHandler,
   display::DisplayHandler,
   flex::FlexHandler,
-  font::FontHandler,
+  font::{FontHandler, FontSizeAdjustHandler, FontSynthesisHandler},
+  fragmentation::BreakHandler,
   grid::GridHandler,
   list::ListStyleHandler,
   margin_padding::*,
+  motion::OffsetHandler,
   outline::OutlineHandler,
-  overflow::OverflowHandler,
+  overflow::{LineClampHandler, OverflowHandler, OverscrollBehaviorHandler},
   position::PositionHandler,
   prefix_handler::{FallbackHandler, PrefixHandler},
-  size::SizeHandler,
-  text::TextDecorationHandler,
+  size::{ContainIntrinsicSizeHandler, SizeHandler, ZoomHandler},
+  svg::ImageRenderingHandler,
+  text::{TextDecorationHandler, TextDecorationSkipHandler, WebKitTextStrokeHandler, WhiteSpaceHandler},
   transform::TransformHandler,
   transition::TransitionHandler,
-  ui::ColorSchemeHandler,
+  ui::{split_light_dark, CaretHandler, ColorSchemeHandler, PrintColorAdjustHandler, ResizeHandler},
+  writing_modes::{UnicodeBidiHandler, WritingModeHandler},
 };
 use crate::properties::{Property, PropertyId};
+use crate::targets::Features;
 use crate::traits::{PropertyHandler, ToCss};
 use crate::values::ident::DashedIdent;
 use crate::values::string::CowArcStr;
@@ -193,6 +201,8 @@ impl<'i> DeclarationBlock<'i> {
       ($decls: expr, $handler: expr, $important: literal) => {
         for decl in $decls.iter() {
           context.is_important = $important;
+          let substituted = substitute_environment_variables(decl, context);
+          let decl = substituted.as_ref().unwrap_or(decl);
           let handled = $handler.handle_property(decl, context);
 
           if !handled {
@@ -491,6 +501,9 @@ pub(crate) struct DeclarationHandler<'i> {
   background: BackgroundHandler<'i>,
   border: BorderHandler<'i>,
   outline: OutlineHandler,
+  columns: ColumnsHandler,
+  column_rule: ColumnRuleHandler,
+  breaks: BreakHandler,
   flex: FlexHandler,
   grid: GridHandler<'i>,
   align: AlignHandler,
@@ -500,22 +513,40 @@ pub(crate) struct DeclarationHandler<'i> {
   scroll_margin: ScrollMarginHandler<'i>,
   scroll_padding: ScrollPaddingHandler<'i>,
   font: FontHandler<'i>,
+  font_synthesis: FontSynthesisHandler,
+  font_size_adjust: FontSizeAdjustHandler,
   text: TextDecorationHandler<'i>,
+  text_decoration_skip: TextDecorationSkipHandler,
+  text_stroke: WebKitTextStrokeHandler,
+  white_space: WhiteSpaceHandler,
   list: ListStyleHandler<'i>,
   transition: TransitionHandler<'i>,
   animation: AnimationHandler<'i>,
   display: DisplayHandler<'i>,
   position: PositionHandler,
   inset: InsetHandler<'i>,
+  position_try: PositionTryHandler<'i>,
   overflow: OverflowHandler,
+  overscroll_behavior: OverscrollBehaviorHandler,
+  line_clamp: LineClampHandler,
   transform: TransformHandler,
+  offset: OffsetHandler<'i>,
   box_shadow: BoxShadowHandler,
   mask: MaskHandler<'i>,
   container: ContainerHandler<'i>,
+  resize: ResizeHandler,
+  caret: CaretHandler,
   color_scheme: ColorSchemeHandler,
+  print_color_adjust: PrintColorAdjustHandler,
+  writing_mode: WritingModeHandler,
+  unicode_bidi: UnicodeBidiHandler,
+  image_rendering: ImageRenderingHandler,
+  contain_intrinsic_size: ContainIntrinsicSizeHandler,
+  zoom: ZoomHandler,
   fallback: FallbackHandler,
   prefix: PrefixHandler,
   custom_properties: HashMap<DashedIdent<'i>, usize>,
+  light_dark: Vec<(PropertyId<'i>, Vec<Property<'i>>)>,
   decls: DeclarationList<'i>,
 }
 
@@ -525,9 +556,15 @@ impl<'i> DeclarationHandler<'i> {
     property: &Property<'i>,
     context: &mut PropertyHandlerContext<'i, '_>,
   ) -> bool {
-    self.background.handle_property(property, &mut self.decls, context)
+    self.handle_all(property, context)
+      || self.handle_light_dark(property, context)
+      || self.background.handle_property(property, &mut self.decls, context)
       || self.border.handle_property(property, &mut self.decls, context)
       || self.outline.handle_property(property, &mut self.decls, context)
+      || self.columns.handle_property(property, &mut self.decls, context)
+      || self.column_rule.handle_property(property, &mut self.decls, context)
+      || self.breaks.handle_property(property, &mut self.decls, context)
+      || self.line_clamp.handle_property(property, &mut self.decls, context)
       || self.flex.handle_property(property, &mut self.decls, context)
       || self.grid.handle_property(property, &mut self.decls, context)
       || self.align.handle_property(property, &mut self.decls, context)
@@ -537,24 +574,104 @@ impl<'i> DeclarationHandler<'i> {
       || self.scroll_margin.handle_property(property, &mut self.decls, context)
       || self.scroll_padding.handle_property(property, &mut self.decls, context)
       || self.font.handle_property(property, &mut self.decls, context)
+      || self.font_synthesis.handle_property(property, &mut self.decls, context)
+      || self.font_size_adjust.handle_property(property, &mut self.decls, context)
       || self.text.handle_property(property, &mut self.decls, context)
+      || self.text_decoration_skip.handle_property(property, &mut self.decls, context)
+      || self.text_stroke.handle_property(property, &mut self.decls, context)
+      || self.white_space.handle_property(property, &mut self.decls, context)
       || self.list.handle_property(property, &mut self.decls, context)
       || self.transition.handle_property(property, &mut self.decls, context)
       || self.animation.handle_property(property, &mut self.decls, context)
       || self.display.handle_property(property, &mut self.decls, context)
       || self.position.handle_property(property, &mut self.decls, context)
       || self.inset.handle_property(property, &mut self.decls, context)
+      || self.position_try.handle_property(property, &mut self.decls, context)
       || self.overflow.handle_property(property, &mut self.decls, context)
+      || self.overscroll_behavior.handle_property(property, &mut self.decls, context)
       || self.transform.handle_property(property, &mut self.decls, context)
+      || self.offset.handle_property(property, &mut self.decls, context)
       || self.box_shadow.handle_property(property, &mut self.decls, context)
       || self.mask.handle_property(property, &mut self.decls, context)
       || self.container.handle_property(property, &mut self.decls, context)
+      || self.resize.handle_property(property, &mut self.decls, context)
+      || self.caret.handle_property(property, &mut self.decls, context)
       || self.color_scheme.handle_property(property, &mut self.decls, context)
+      || self.print_color_adjust.handle_property(property, &mut self.decls, context)
+      || self.writing_mode.handle_property(property, &mut self.decls, context)
+      || self.unicode_bidi.handle_property(property, &mut self.decls, context)
+      || self.image_rendering.handle_property(property, &mut self.decls, context)
+      || self.contain_intrinsic_size.handle_property(property, &mut self.decls, context)
+      || self.zoom.handle_property(property, &mut self.decls, context)
       || self.fallback.handle_property(property, &mut self.decls, context)
       || self.prefix.handle_property(property, &mut self.decls, context)
       || self.handle_custom_property(property, context)
   }
 
+  fn handle_all(&mut self, property: &Property<'i>, context: &mut PropertyHandlerContext<'i, '_>) -> bool {
+    if let Property::All(keyword) = property {
+      // Browsers without support for `all` still need the preceding declarations.
+      if !context.targets.is_compatible(Feature::AllProperty) {
+        self.finalize(context);
+        self.decls.push(Property::All(*keyword));
+        return true;
+      }
+
+      // The `all` property resets every property except `direction`, `unicode-bidi`, and custom
+      // properties, so any other declarations before it are redundant and can be dropped.
+      let unicode_bidi = std::mem::take(&mut self.unicode_bidi);
+      let mut decls = std::mem::take(self).decls;
+      self.unicode_bidi = unicode_bidi;
+      decls.retain(|decl| match decl {
+        Property::Direction(..) | Property::UnicodeBidi(..) | Property::Custom(..) => true,
+        Property::Unparsed(unparsed) => {
+          matches!(unparsed.property_id, PropertyId::Direction | PropertyId::UnicodeBidi)
+        }
+        _ => false,
+      });
+
+      for (index, decl) in decls.iter().enumerate() {
+        if let Property::Custom(CustomProperty {
+          name: CustomPropertyName::Custom(name),
+          ..
+        }) = decl
+        {
+          self.custom_properties.insert(name.clone(), index);
+        }
+      }
+
+      self.decls = decls;
+      self.decls.push(Property::All(*keyword));
+      return true;
+    }
+
+    false
+  }
+
+  fn handle_light_dark(&mut self, property: &Property<'i>, context: &mut PropertyHandlerContext<'i, '_>) -> bool {
+    // When opted in, compile light-dark() to a separate rule in a prefers-color-scheme media query
+    // rather than relying on variables defined by the color-scheme property.
+    if context.context != DeclarationContext::StyleRule
+      || context.is_important
+      || !context.targets.include.contains(Features::LightDarkMediaQuery)
+      || context.targets.is_compatible(Feature::LightDark)
+    {
+      return false;
+    }
+
+    if let Some((light, dark)) = split_light_dark(property, context.targets) {
+      let handled = self.handle_property(&light, context);
+      // The dark rule is only added once the whole block is handled, in case a later declaration overrides this one.
+      let property_id = dark.last().unwrap().property_id();
+      self.light_dark.push((property_id, dark));
+      return handled;
+    }
+
+    let property_id = property.property_id();
+    self.light_dark.retain(|(id, _)| !overrides_property(&property_id, id));
+    false
+  }
+
   fn handle_custom_property(
     &mut self,
     property: &Property<'i>,
@@ -610,6 +727,9 @@ impl<'i> DeclarationHandler<'i> {
     self.background.finalize(&mut self.decls, context);
     self.border.finalize(&mut self.decls, context);
     self.outline.finalize(&mut self.decls, context);
+    self.columns.finalize(&mut self.decls, context);
+    self.column_rule.finalize(&mut self.decls, context);
+    self.breaks.finalize(&mut self.decls, context);
     self.flex.finalize(&mut self.decls, context);
     self.grid.finalize(&mut self.decls, context);
     self.align.finalize(&mut self.decls, context);
@@ -618,22 +738,77 @@ impl<'i> DeclarationHandler<'i> {
     self.padding.finalize(&mut self.decls, context);
     self.scroll_margin.finalize(&mut self.decls, context);
     self.scroll_padding.finalize(&mut self.decls, context);
+    // Any font properties still pending were declared after font-size-adjust, which the font shorthand resets.
+    self.font_size_adjust.finalize(&mut self.decls, context);
     self.font.finalize(&mut self.decls, context);
+    self.font_synthesis.finalize(&mut self.decls, context);
     self.text.finalize(&mut self.decls, context);
+    self.text_decoration_skip.finalize(&mut self.decls, context);
+    self.text_stroke.finalize(&mut self.decls, context);
+    self.white_space.finalize(&mut self.decls, context);
     self.list.finalize(&mut self.decls, context);
     self.transition.finalize(&mut self.decls, context);
     self.animation.finalize(&mut self.decls, context);
     self.display.finalize(&mut self.decls, context);
     self.position.finalize(&mut self.decls, context);
     self.inset.finalize(&mut self.decls, context);
+    self.position_try.finalize(&mut self.decls, context);
     self.overflow.finalize(&mut self.decls, context);
+    self.overscroll_behavior.finalize(&mut self.decls, context);
+    self.line_clamp.finalize(&mut self.decls, context);
     self.transform.finalize(&mut self.decls, context);
+    self.offset.finalize(&mut self.decls, context);
     self.box_shadow.finalize(&mut self.decls, context);
     self.mask.finalize(&mut self.decls, context);
     self.container.finalize(&mut self.decls, context);
+    self.resize.finalize(&mut self.decls, context);
+    self.caret.finalize(&mut self.decls, context);
     self.color_scheme.finalize(&mut self.decls, context);
+    self.print_color_adjust.finalize(&mut self.decls, context);
+    self.writing_mode.finalize(&mut self.decls, context);
+    self.unicode_bidi.finalize(&mut self.decls, context);
+    self.image_rendering.finalize(&mut self.decls, context);
+    self.contain_intrinsic_size.finalize(&mut self.decls, context);
+    self.zoom.finalize(&mut self.decls, context);
     self.fallback.finalize(&mut self.decls, context);
     self.prefix.finalize(&mut self.decls, context);
+    for (_, dark) in self.light_dark.drain(..) {
+      for property in dark {
+        context.add_dark_rule(property);
+      }
+    }
     self.custom_properties.clear();
   }
 }
+
+/// Returns a copy of the declaration with environment variables replaced by the values
+/// given in the minify options, or `None` if it does not reference any of them.
+fn substitute_environment_variables<'i>(
+  property: &Property<'i>,
+  context: &PropertyHandlerContext<'i, '_>,
+) -> Option<Property<'i>> {
+  if context.environment_variables.is_empty() {
+    return None;
+  }
+
+  let mut property = property.clone();
+  let changed = match &mut property {
+    Property::Unparsed(unparsed) => unparsed.value.inline_environment_variables(context.environment_variables),
+    Property::Custom(custom) => custom.value.inline_environment_variables(context.environment_variables),
+    _ => false,
+  };
+
+  if changed {
+    Some(property)
+  } else {
+    None
+  }
+}
+
+/// Returns whether a declaration of the given property overrides the given longhand.
+fn overrides_property(property_id: &PropertyId, longhand: &PropertyId) -> bool {
+  property_id == longhand
+    || property_id.longhands().map_or(false, |longhands| {
+      longhands.iter().any(|id| overrides_property(id, longhand))
+    })
+}
@@ -334,6 +334,165 @@ mod tests {
     );
   }
 
+  #[test]
+  fn test_table() {
+    minify_test(".foo { border-collapse: collapse }", ".foo{border-collapse:collapse}");
+    minify_test(".foo { border-collapse: SEPARATE }", ".foo{border-collapse:separate}");
+    minify_test(".foo { caption-side: top }", ".foo{caption-side:top}");
+    minify_test(".foo { caption-side: bottom }", ".foo{caption-side:bottom}");
+    minify_test(".foo { empty-cells: show }", ".foo{empty-cells:show}");
+    minify_test(".foo { empty-cells: hide }", ".foo{empty-cells:hide}");
+    minify_test(".foo { table-layout: auto }", ".foo{table-layout:auto}");
+    minify_test(".foo { table-layout: fixed }", ".foo{table-layout:fixed}");
+    minify_test(".foo { caption-side: left }", ".foo{caption-side:left}"); // invalid
+
+    let property = Property::parse_string("table-layout".into(), "fixed", ParserOptions::default()).unwrap();
+    assert_eq!(
+      property,
+      Property::TableLayout(crate::properties::table::TableLayout::Fixed)
+    );
+    let property = Property::parse_string("empty-cells".into(), "none", ParserOptions::default()).unwrap();
+    assert!(matches!(property, Property::Unparsed(..)));
+  }
+
+  #[test]
+  fn test_ruby() {
+    minify_test(".foo { ruby-position: over }", ".foo{ruby-position:over}");
+    minify_test(".foo { ruby-position: under }", ".foo{ruby-position:under}");
+    minify_test(".foo { ruby-position: alternate }", ".foo{ruby-position:alternate}");
+    minify_test(
+      ".foo { ruby-position: alternate over }",
+      ".foo{ruby-position:alternate}",
+    );
+    minify_test(
+      ".foo { ruby-position: over alternate }",
+      ".foo{ruby-position:alternate}",
+    );
+    minify_test(
+      ".foo { ruby-position: under alternate }",
+      ".foo{ruby-position:alternate under}",
+    );
+    minify_test(
+      ".foo { ruby-position: inter-character }",
+      ".foo{ruby-position:inter-character}",
+    );
+    minify_test(".foo { ruby-position: before }", ".foo{ruby-position:over}");
+    minify_test(".foo { ruby-position: after }", ".foo{ruby-position:under}");
+    minify_test(".foo { ruby-position: over under }", ".foo{ruby-position:over under}"); // invalid
+    minify_test(
+      ".foo { ruby-position: alternate inter-character }",
+      ".foo{ruby-position:alternate inter-character}",
+    ); // invalid
+    minify_test(".foo { ruby-align: start }", ".foo{ruby-align:start}");
+    minify_test(".foo { ruby-align: center }", ".foo{ruby-align:center}");
+    minify_test(".foo { ruby-align: space-between }", ".foo{ruby-align:space-between}");
+    minify_test(".foo { ruby-align: space-around }", ".foo{ruby-align:space-around}");
+    minify_test(".foo { ruby-align: end }", ".foo{ruby-align:end}"); // invalid
+    minify_test(".foo { ruby-merge: separate }", ".foo{ruby-merge:separate}");
+    minify_test(".foo { ruby-merge: merge }", ".foo{ruby-merge:merge}");
+    minify_test(".foo { ruby-merge: auto }", ".foo{ruby-merge:auto}");
+
+    let property = Property::parse_string("ruby-position".into(), "before", ParserOptions::default()).unwrap();
+    assert_eq!(
+      property,
+      Property::RubyPosition(crate::properties::ruby::RubyPosition::Side {
+        side: crate::properties::ruby::RubySide::Over,
+        alternate: false
+      })
+    );
+  }
+
+  #[test]
+  fn test_math() {
+    minify_test(".foo { math-style: normal }", ".foo{math-style:normal}");
+    minify_test(".foo { math-style: compact }", ".foo{math-style:compact}");
+    minify_test(".foo { math-style: auto }", ".foo{math-style:auto}"); // invalid
+    minify_test(".foo { math-shift: normal }", ".foo{math-shift:normal}");
+    minify_test(".foo { math-shift: COMPACT }", ".foo{math-shift:compact}");
+    minify_test(".foo { math-depth: 0 }", ".foo{math-depth:0}");
+    minify_test(".foo { math-depth: -2 }", ".foo{math-depth:-2}");
+    minify_test(".foo { math-depth: auto-add }", ".foo{math-depth:auto-add}");
+    minify_test(".foo { math-depth: add(2) }", ".foo{math-depth:add(2)}");
+    minify_test(".foo { math-depth: ADD( -1 ) }", ".foo{math-depth:add(-1)}");
+    minify_test(".foo { math-depth: 1.5 }", ".foo{math-depth:1.5}"); // invalid
+    minify_test(".foo { math-depth: add(1 2) }", ".foo{math-depth:add(1 2)}"); // invalid
+    minify_test(".foo { math-depth: add() }", ".foo{math-depth:add()}"); // invalid
+  }
+
+  #[test]
+  fn test_initial_letter() {
+    minify_test(".foo { initial-letter: normal }", ".foo{initial-letter:normal}");
+    minify_test(".foo { initial-letter: 3 }", ".foo{initial-letter:3}");
+    minify_test(".foo { initial-letter: 3 2 }", ".foo{initial-letter:3 2}");
+    minify_test(".foo { initial-letter: 3 3 }", ".foo{initial-letter:3}");
+    minify_test(".foo { initial-letter: 2.5 2 }", ".foo{initial-letter:2.5}");
+    minify_test(".foo { initial-letter: 2.5 1 }", ".foo{initial-letter:2.5 1}");
+    minify_test(
+      ".foo { -webkit-initial-letter: 3 2 }",
+      ".foo{-webkit-initial-letter:3 2}",
+    );
+    minify_test(".foo { initial-letter: 0 }", ".foo{initial-letter:0}"); // invalid
+    minify_test(".foo { initial-letter: 3 0 }", ".foo{initial-letter:3 0}"); // invalid
+    minify_test(".foo { initial-letter: 3 1.5 }", ".foo{initial-letter:3 1.5}"); // invalid
+    minify_test(
+      ".foo { initial-letter-align: alphabetic }",
+      ".foo{initial-letter-align:alphabetic}",
+    );
+    minify_test(
+      ".foo { initial-letter-align: hanging }",
+      ".foo{initial-letter-align:hanging}",
+    );
+    minify_test(
+      ".foo { initial-letter-align: border-box }",
+      ".foo{initial-letter-align:border-box}",
+    );
+    minify_test(
+      ".foo { initial-letter-align: border-box ideographic }",
+      ".foo{initial-letter-align:border-box ideographic}",
+    );
+    minify_test(
+      ".foo { initial-letter-align: leading border-box }",
+      ".foo{initial-letter-align:leading border-box}",
+    ); // invalid
+
+    prefix_test(
+      r#"
+      .foo {
+        initial-letter: 3 2;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        -webkit-initial-letter: 3 2;
+        initial-letter: 3 2;
+      }
+    "#},
+      Browsers {
+        safari: Some(16 << 16),
+        chrome: Some(110 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      r#"
+      .foo {
+        -webkit-initial-letter: 3;
+        initial-letter: 3;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        initial-letter: 3;
+      }
+    "#},
+      Browsers {
+        chrome: Some(110 << 16),
+        ..Browsers::default()
+      },
+    );
+  }
+
   #[test]
   pub fn test_border() {
     test(
@@ -593,6 +752,12 @@ mod tests {
     );
 
     minify_test(".foo { border-width: 0 0 1px; }", ".foo{border-width:0 0 1px}");
+    minify_test(
+      ".foo { border-width: -1px 2px -1px 2px; }",
+      ".foo{border-width:-1px 2px -1px 2px}",
+    );
+    minify_test(".foo { border: -1px solid red; }", ".foo{border:-1px solid red}");
+    minify_test(".foo { border-top-width: -2px; }", ".foo{border-top-width:-2px}");
     test(
       r#"
       .foo {
@@ -2337,6 +2502,22 @@ mod tests {
     );
 
     minify_test(".foo { border: none green }", ".foo{border:green}");
+    minify_test(".foo { border-image: none }", ".foo{border-image:none}");
+    minify_test(
+      ".foo { border-image: none 100% / 1 / 0 stretch }",
+      ".foo{border-image:none}",
+    );
+    minify_test(".foo { border-image: 30 round }", ".foo{border-image:30 round}");
+    minify_test(".foo { border-image: space }", ".foo{border-image:space}");
+    minify_test(".foo { border-image-slice: -10 }", ".foo{border-image-slice:-10}");
+    minify_test(
+      ".foo { border-image-width: 1 -1px 1 1 }",
+      ".foo{border-image-width:1 -1px 1 1}",
+    );
+    minify_test(
+      ".foo { border-image: url(a.png) 30 / 1 / -1 round }",
+      ".foo{border-image:url(a.png) 30/1/-1 round}",
+    );
   }
 
   #[test]
@@ -2827,6 +3008,19 @@ mod tests {
         ..Browsers::default()
       },
     );
+
+    minify_test(
+      ".foo { border-radius: 10px -5px 10px -5px }",
+      ".foo{border-radius:10px -5px 10px -5px}",
+    );
+    minify_test(
+      ".foo { border-radius: 10px 10px / -5px 5px }",
+      ".foo{border-radius:10px 10px/-5px 5px}",
+    );
+    minify_test(
+      ".foo { clip-path: inset(10px round -5px 5px) }",
+      ".foo{clip-path:inset(10px round -5px 5px)}",
+    );
   }
 
   #[test]
@@ -2938,6 +3132,270 @@ mod tests {
         ..Browsers::default()
       },
     );
+
+    minify_test(".foo { outline-offset: 0px }", ".foo{outline-offset:0}");
+    minify_test(".foo { outline-offset: -2px }", ".foo{outline-offset:-2px}");
+    minify_test(".foo { outline-offset: calc(1px + 2px) }", ".foo{outline-offset:3px}");
+    minify_test(".foo { outline: 2px auto red }", ".foo{outline:2px auto red}");
+    minify_test(".foo { outline-style: auto }", ".foo{outline-style:auto}");
+    minify_test(".foo { outline-style: hidden }", ".foo{outline-style:hidden}");
+    minify_test(".foo { outline: 1px hidden red }", ".foo{outline:1px hidden red}");
+    minify_test(".foo { outline-color: invert }", ".foo{outline-color:invert}");
+    minify_test(".foo { outline: invert solid 2px }", ".foo{outline:invert solid 2px}");
+  }
+
+  #[test]
+  pub fn test_columns() {
+    minify_test(".foo { column-width: auto }", ".foo{column-width:auto}");
+    minify_test(".foo { column-width: 10em }", ".foo{column-width:10em}");
+    minify_test(".foo { column-width: -10em }", ".foo{column-width:-10em}");
+    minify_test(".foo { column-width: 0 }", ".foo{column-width:0}");
+    minify_test(".foo { column-width: 10 }", ".foo{column-width:10}"); // invalid
+    minify_test(".foo { column-count: AUTO }", ".foo{column-count:auto}");
+    minify_test(".foo { column-count: 3 }", ".foo{column-count:3}");
+    minify_test(".foo { column-count: 0 }", ".foo{column-count:0}");
+    minify_test(".foo { column-count: 1.5 }", ".foo{column-count:1.5}");
+    minify_test(".foo { columns: auto }", ".foo{columns:auto}");
+    minify_test(".foo { columns: auto auto }", ".foo{columns:auto}");
+    minify_test(".foo { columns: 10em }", ".foo{columns:10em}");
+    minify_test(".foo { columns: 3 }", ".foo{columns:3}");
+    minify_test(".foo { columns: 3 10em }", ".foo{columns:10em 3}");
+    minify_test(".foo { columns: auto 3 }", ".foo{columns:3}");
+    minify_test(".foo { columns: 10em auto }", ".foo{columns:10em}");
+    minify_test(".foo { columns: 3 3 }", ".foo{columns:3 3}"); // invalid
+    minify_test(".foo { column-width: 10em; column-count: 3 }", ".foo{columns:10em 3}");
+    minify_test(".foo { columns: 10em 3; column-count: 2 }", ".foo{columns:10em 2}");
+    minify_test(".foo { column-width: 10em }", ".foo{column-width:10em}");
+    minify_test(
+      ".foo { column-width: 10em; column-count: var(--count) }",
+      ".foo{column-width:10em;column-count:var(--count)}",
+    );
+
+    minify_test(".foo { column-rule-width: 2px }", ".foo{column-rule-width:2px}");
+    minify_test(".foo { column-rule-style: dotted }", ".foo{column-rule-style:dotted}");
+    minify_test(".foo { column-rule-color: #ff0000 }", ".foo{column-rule-color:red}");
+    minify_test(
+      ".foo { column-rule: 2px dotted red }",
+      ".foo{column-rule:2px dotted red}",
+    );
+    minify_test(
+      ".foo { column-rule: medium none currentColor }",
+      ".foo{column-rule:none}",
+    );
+    minify_test(".foo { column-rule: red solid }", ".foo{column-rule:solid red}");
+    minify_test(
+      ".foo { column-rule-width: thin; column-rule-style: solid; column-rule-color: blue }",
+      ".foo{column-rule:thin solid #00f}",
+    );
+    minify_test(
+      ".foo { column-rule: 1px solid red; column-rule-color: blue }",
+      ".foo{column-rule:1px solid #00f}",
+    );
+    prefix_test(
+      ".foo { column-rule: 2px solid lab(40% 56.6 39) }",
+      indoc! { r#"
+        .foo {
+          column-rule: 2px solid #b32323;
+          column-rule: 2px solid lab(40% 56.6 39);
+        }
+      "#},
+      Browsers {
+        chrome: Some(90 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    minify_test(".foo { column-span: all }", ".foo{column-span:all}");
+    minify_test(".foo { column-span: NONE }", ".foo{column-span:none}");
+    minify_test(".foo { column-fill: balance-all }", ".foo{column-fill:balance-all}");
+    minify_test(".foo { column-fill: auto }", ".foo{column-fill:auto}");
+  }
+
+  #[test]
+  fn test_fragmentation_break() {
+    minify_test(".foo { break-before: auto }", ".foo{break-before:auto}");
+    minify_test(".foo { break-before: page }", ".foo{break-before:page}");
+    minify_test(".foo { break-after: avoid-column }", ".foo{break-after:avoid-column}");
+    minify_test(".foo { break-after: RECTO }", ".foo{break-after:recto}");
+    minify_test(".foo { break-before: always }", ".foo{break-before:always}");
+    minify_test(".foo { break-before: avoid-inside }", ".foo{break-before:avoid-inside}"); // invalid
+    minify_test(".foo { break-inside: avoid }", ".foo{break-inside:avoid}");
+    minify_test(".foo { break-inside: avoid-page }", ".foo{break-inside:avoid-page}");
+    minify_test(".foo { break-inside: column }", ".foo{break-inside:column}"); // invalid
+    minify_test(".foo { page-break-before: always }", ".foo{page-break-before:always}");
+    minify_test(".foo { page-break-after: left }", ".foo{page-break-after:left}");
+    minify_test(".foo { page-break-after: page }", ".foo{page-break-after:page}"); // invalid
+    minify_test(".foo { page-break-inside: avoid }", ".foo{page-break-inside:avoid}");
+    minify_test(".foo { page-break-inside: always }", ".foo{page-break-inside:always}"); // invalid
+    minify_test(".foo { page: auto }", ".foo{page:auto}");
+    minify_test(".foo { page: rotated }", ".foo{page:rotated}");
+    minify_test(".foo { page: none }", ".foo{page:none}"); // invalid
+    minify_test(".foo { orphans: 3 }", ".foo{orphans:3}");
+    minify_test(".foo { orphans: 1 }", ".foo{orphans:1}");
+    minify_test(".foo { orphans: 0 }", ".foo{orphans:0}"); // invalid
+    minify_test(".foo { orphans: -2 }", ".foo{orphans:-2}"); // invalid
+    minify_test(".foo { orphans: 1.5 }", ".foo{orphans:1.5}"); // invalid
+    minify_test(".foo { widows: 2 }", ".foo{widows:2}");
+    minify_test(".foo { widows: calc(1 + 2) }", ".foo{widows:3}");
+    minify_test(".foo { widows: 0 }", ".foo{widows:0}"); // invalid
+    minify_test(".foo { widows: auto }", ".foo{widows:auto}"); // invalid
+
+    minify_test(
+      ".foo { page-break-before: always; break-before: page }",
+      ".foo{page-break-before:always;break-before:page}",
+    );
+    minify_test(
+      ".foo { page-break-before: always; break-before: column }",
+      ".foo{page-break-before:always;break-before:column}",
+    );
+    minify_test(
+      ".foo { break-before: page; break-before: column }",
+      ".foo{break-before:column}",
+    );
+    minify_test(
+      ".foo { break-inside: avoid; break-inside: auto }",
+      ".foo{break-inside:auto}",
+    );
+
+    prefix_test(
+      r#"
+      .foo {
+        break-before: page;
+        break-after: avoid;
+        break-inside: avoid;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        page-break-before: always;
+        break-before: page;
+        page-break-after: avoid;
+        break-after: avoid;
+        page-break-inside: avoid;
+        break-inside: avoid;
+      }
+    "#},
+      Browsers {
+        firefox: Some(60 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      r#"
+      .foo {
+        break-before: column;
+        break-inside: avoid-page;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        break-before: column;
+        break-inside: avoid-page;
+      }
+    "#},
+      Browsers {
+        firefox: Some(60 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      r#"
+      .foo {
+        page-break-before: always;
+        break-before: column;
+        break-inside: avoid;
+        break-inside: avoid-page;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        page-break-before: always;
+        break-before: column;
+        page-break-inside: avoid;
+        break-inside: avoid-page;
+      }
+    "#},
+      Browsers {
+        firefox: Some(60 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      r#"
+      .foo {
+        page-break-before: always;
+        break-before: column;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        break-before: column;
+      }
+    "#},
+      Browsers {
+        chrome: Some(90 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      r#"
+      .foo {
+        page-break-before: always;
+        page-break-inside: avoid;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        break-before: page;
+        break-inside: avoid;
+      }
+    "#},
+      Browsers {
+        chrome: Some(90 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      r#"
+      .foo {
+        page-break-after: always;
+        break-after: page;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        break-after: page;
+      }
+    "#},
+      Browsers {
+        safari: Some(14 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      r#"
+      .foo {
+        page-break-inside: avoid;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        page-break-inside: avoid;
+        break-inside: avoid;
+      }
+    "#},
+      Browsers {
+        ie: Some(11 << 16),
+        chrome: Some(90 << 16),
+        ..Browsers::default()
+      },
+    );
   }
 
   #[test]
@@ -3341,6 +3799,24 @@ mod tests {
         },
       );
     }
+
+    minify_test(".foo { width: 72pt }", ".foo{width:1in}");
+    minify_test(".foo { width: 2.54cm }", ".foo{width:1in}");
+    minify_test(".foo { width: 10mm }", ".foo{width:1cm}");
+    minify_test(".foo { width: 0.75pt }", ".foo{width:1px}");
+    minify_test(".foo { width: 12pt }", ".foo{width:1pc}");
+    minify_test(".foo { width: 1.5mm }", ".foo{width:1.5mm}");
+    minify_test(".foo { width: 96px }", ".foo{width:96px}");
+    minify_test(".foo { width: 120q }", ".foo{width:3cm}");
+    minify_test(".foo { width: 4q }", ".foo{width:4q}");
+    test(".foo { width: 72pt }", ".foo {\n  width: 72pt;\n}\n");
+    minify_test(".foo { width: 0.0000001px }", ".foo{width:.0000001px}");
+    minify_test(".foo { width: -1.5e-7px }", ".foo{width:-.00000015px}");
+    minify_test(".foo { width: 1e23px }", ".foo{width:100000000000000000000000px}");
+    minify_test(".foo { width: 1.5e10em }", ".foo{width:15000000000em}");
+    minify_test(".foo { opacity: 1e-7 }", ".foo{opacity:.0000001}");
+    minify_test(".foo { width: 1e-7% }", ".foo{width:.0000001%}");
+    test(".foo { width: 1e-7px }", ".foo {\n  width: .0000001px;\n}\n");
   }
 
   #[test]
@@ -3612,33 +4088,260 @@ mod tests {
   }
 
   #[test]
-  fn test_scroll_padding() {
-    prefix_test(
+  fn test_scroll_margin() {
+    minify_test(".foo { scroll-margin: 10px 10px }", ".foo{scroll-margin:10px}");
+    minify_test(
+      ".foo { scroll-margin: 10px 20px 10px }",
+      ".foo{scroll-margin:10px 20px}",
+    );
+    minify_test(".foo { scroll-margin-block: 0px 0px }", ".foo{scroll-margin-block:0}");
+    minify_test(
+      ".foo { scroll-margin-inline: 1px 2px }",
+      ".foo{scroll-margin-inline:1px 2px}",
+    );
+    minify_test(".foo { scroll-padding: auto auto }", ".foo{scroll-padding:auto}");
+    minify_test(
+      ".foo { scroll-padding: 10% 5px 10% 5px }",
+      ".foo{scroll-padding:10% 5px}",
+    );
+    minify_test(
+      ".foo { scroll-padding-inline: auto 0px }",
+      ".foo{scroll-padding-inline:auto 0}",
+    );
+
+    test(
       r#"
       .foo {
-        scroll-padding-inline: 2px;
+        scroll-margin-left: 10px;
+        scroll-margin-right: 10px;
+        scroll-margin-top: 20px;
+        scroll-margin-bottom: 20px;
       }
     "#,
       indoc! {r#"
       .foo {
-        scroll-padding-inline: 2px;
+        scroll-margin: 20px 10px;
       }
     "#
       },
-      Browsers {
-        safari: Some(8 << 16),
-        ..Browsers::default()
-      },
     );
-  }
 
-  #[test]
-  fn test_size() {
-    prefix_test(
+    test(
       r#"
       .foo {
-        block-size: 25px;
-        inline-size: 25px;
+        scroll-margin-block-start: 15px;
+        scroll-margin-block-end: 15px;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        scroll-margin-block: 15px;
+      }
+    "#
+      },
+    );
+
+    test(
+      r#"
+      .foo {
+        scroll-margin-inline-start: 10px;
+        scroll-margin-inline-end: 20px;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        scroll-margin-inline: 10px 20px;
+      }
+    "#
+      },
+    );
+
+    test(
+      r#"
+      .foo {
+        scroll-margin: 10px;
+        scroll-margin-top: 20px;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        scroll-margin: 20px 10px 10px;
+      }
+    "#
+      },
+    );
+
+    test(
+      r#"
+      .foo {
+        scroll-margin: 10px;
+        scroll-margin-top: var(--top);
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        scroll-margin: 10px;
+        scroll-margin-top: var(--top);
+      }
+    "#
+      },
+    );
+
+    test(
+      r#"
+      .foo {
+        scroll-padding-left: 10px;
+        scroll-padding-right: 10px;
+        scroll-padding-top: 20px;
+        scroll-padding-bottom: 20px;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        scroll-padding: 20px 10px;
+      }
+    "#
+      },
+    );
+
+    test(
+      r#"
+      .foo {
+        scroll-padding-block-start: 15px;
+        scroll-padding-block-end: 15px;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        scroll-padding-block: 15px;
+      }
+    "#
+      },
+    );
+
+    test(
+      r#"
+      .foo {
+        scroll-padding: 10px;
+        scroll-padding-left: auto;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        scroll-padding: 10px 10px 10px auto;
+      }
+    "#
+      },
+    );
+  }
+
+  #[test]
+  fn test_scroll_padding() {
+    prefix_test(
+      r#"
+      .foo {
+        scroll-padding-inline: 2px;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        scroll-padding-left: 2px;
+        scroll-padding-right: 2px;
+      }
+    "#
+      },
+      Browsers {
+        safari: Some(8 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      r#"
+      .foo {
+        scroll-padding-inline: 2px;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        scroll-padding-inline: 2px;
+      }
+    "#
+      },
+      Browsers {
+        safari: Some(15 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      r#"
+      .foo {
+        scroll-padding-block: 2px 4px;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        scroll-padding-top: 2px;
+        scroll-padding-bottom: 4px;
+      }
+    "#
+      },
+      Browsers {
+        safari: Some(14 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      r#"
+      .foo {
+        scroll-margin-inline-start: 2px;
+      }
+    "#,
+      indoc! {r#"
+      .foo:not(:lang(ae, ar, arc, bcc, bqi, ckb, dv, fa, glk, he, ku, mzn, nqo, pnb, ps, sd, ug, ur, yi)) {
+        scroll-margin-left: 2px;
+      }
+
+      .foo:lang(ae, ar, arc, bcc, bqi, ckb, dv, fa, glk, he, ku, mzn, nqo, pnb, ps, sd, ug, ur, yi) {
+        scroll-margin-right: 2px;
+      }
+    "#
+      },
+      Browsers {
+        safari: Some(14 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      r#"
+      .foo {
+        scroll-margin-block-start: 2px;
+        scroll-margin-block-end: 2px;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        scroll-margin-block: 2px;
+      }
+    "#
+      },
+      Browsers {
+        chrome: Some(69 << 16),
+        ..Browsers::default()
+      },
+    );
+  }
+
+  #[test]
+  fn test_size() {
+    prefix_test(
+      r#"
+      .foo {
+        block-size: 25px;
+        inline-size: 25px;
         min-block-size: 25px;
         min-inline-size: 25px;
       }
@@ -3952,984 +4655,1129 @@ mod tests {
     minify_test(".foo { aspect-ratio: 2 / 3 }", ".foo{aspect-ratio:2/3}");
     minify_test(".foo { aspect-ratio: auto 2 / 3 }", ".foo{aspect-ratio:auto 2/3}");
     minify_test(".foo { aspect-ratio: 2 / 3 auto }", ".foo{aspect-ratio:auto 2/3}");
-  }
-
-  #[test]
-  pub fn test_background() {
+    minify_test(".foo { aspect-ratio: 1920 / 1080 }", ".foo{aspect-ratio:16/9}");
+    minify_test(".foo { aspect-ratio: 16 / 8 }", ".foo{aspect-ratio:2}");
+    minify_test(".foo { aspect-ratio: 1.5 / 3 }", ".foo{aspect-ratio:1.5/3}");
+    minify_test(".foo { aspect-ratio: 0 / 4 }", ".foo{aspect-ratio:0/4}");
     test(
-      r#"
-      .foo {
-        background: url(img.png);
-        background-position-x: 20px;
-        background-position-y: 10px;
-        background-size: 50px 100px;
-        background-repeat: repeat no-repeat;
-      }
-    "#,
+      ".foo { aspect-ratio: 1920 / 1080 }",
       indoc! {r#"
       .foo {
-        background: url("img.png") 20px 10px / 50px 100px repeat-x;
+        aspect-ratio: 1920 / 1080;
       }
-    "#
-      },
+    "#},
     );
 
-    test(
-      r#"
-      .foo {
-        background-color: red;
-        background-position: 0% 0%;
-        background-size: auto;
-        background-repeat: repeat;
-        background-clip: border-box;
-        background-origin: padding-box;
-        background-attachment: scroll;
-        background-image: none
-      }
-    "#,
-      indoc! {r#"
-      .foo {
-        background: red;
-      }
-    "#
-      },
+    minify_test(
+      ".foo { interpolate-size: numeric-only }",
+      ".foo{interpolate-size:numeric-only}",
     );
-
-    test(
-      r#"
-      .foo {
-        background-color: gray;
-        background-position: 40% 50%;
-        background-size: 10em auto;
-        background-repeat: round;
-        background-clip: border-box;
-        background-origin: border-box;
-        background-attachment: fixed;
-        background-image: url('chess.png');
-      }
-    "#,
-      indoc! {r#"
-      .foo {
-        background: gray url("chess.png") 40% / 10em round fixed border-box;
-      }
-    "#
-      },
+    minify_test(".foo { interpolate-size: auto }", ".foo{interpolate-size:auto}"); // invalid
+    minify_test(
+      ".foo { interpolate-size: ALLOW-KEYWORDS }",
+      ".foo{interpolate-size:allow-keywords}",
     );
 
-    test(
-      r#"
-      .foo {
-        background: url(img.png), url(test.jpg) gray;
-        background-position-x: right 20px, 10px;
-        background-position-y: top 20px, 15px;
-        background-size: 50px 50px, auto;
-        background-repeat: repeat no-repeat, no-repeat;
-      }
-    "#,
+    prefix_test(
+      ".foo { height: 100dvh }",
       indoc! {r#"
-      .foo {
-        background: url("img.png") right 20px top 20px / 50px 50px repeat-x, gray url("test.jpg") 10px 15px no-repeat;
-      }
-    "#
+        .foo {
+          height: 100dvh;
+        }
+      "#},
+      Browsers {
+        chrome: Some(100 << 16),
+        ..Browsers::default()
       },
     );
 
-    minify_test(
-      r#"
-      .foo {
-        background-position: center center;
-      }
-    "#,
-      indoc! {".foo{background-position:50%}"
-      },
-    );
+    let viewport_test = |source: &str, expected: &str, browsers: Browsers| {
+      nesting_test_with_targets(
+        source,
+        expected,
+        Targets {
+          browsers: Some(browsers),
+          include: Features::ViewportUnits,
+          exclude: Features::empty(),
+        },
+      );
+    };
 
-    test(
-      r#"
-      .foo {
-        background: url(img.png) gray;
-        background-clip: content-box;
-        -webkit-background-clip: text;
-      }
-    "#,
+    viewport_test(
+      ".foo { height: 100dvh }",
       indoc! {r#"
-      .foo {
-        background: gray url("img.png") padding-box content-box;
-        -webkit-background-clip: text;
-      }
-    "#
+        .foo {
+          height: 100vh;
+          height: 100dvh;
+        }
+      "#},
+      Browsers {
+        chrome: Some(100 << 16),
+        ..Browsers::default()
       },
     );
-
-    test(
-      r#"
-      .foo {
-        background: url(img.png) gray;
-        -webkit-background-clip: text;
-        background-clip: content-box;
-      }
-    "#,
+    viewport_test(
+      ".foo { height: 100dvh }",
       indoc! {r#"
-      .foo {
-        background: gray url("img.png");
-        -webkit-background-clip: text;
-        background-clip: content-box;
-      }
-    "#
+        .foo {
+          height: 100dvh;
+        }
+      "#},
+      Browsers {
+        chrome: Some(120 << 16),
+        ..Browsers::default()
       },
     );
-
-    test(
-      r#"
-      .foo {
-        background: url(img.png) gray;
-        background-position: var(--pos);
-      }
-    "#,
+    viewport_test(
+      ".foo { height: 100vh; height: 100dvh }",
       indoc! {r#"
-      .foo {
-        background: gray url("img.png");
-        background-position: var(--pos);
-      }
-    "#
+        .foo {
+          height: 100vh;
+          height: 100dvh;
+        }
+      "#},
+      Browsers {
+        chrome: Some(100 << 16),
+        ..Browsers::default()
       },
     );
-
-    minify_test(
-      ".foo { background-position: bottom left }",
-      ".foo{background-position:0 100%}",
-    );
-    minify_test(
-      ".foo { background-position: left 10px center }",
-      ".foo{background-position:10px 50%}",
-    );
-    minify_test(
-      ".foo { background-position: right 10px center }",
-      ".foo{background-position:right 10px center}",
-    );
-    minify_test(
-      ".foo { background-position: right 10px top 20px }",
-      ".foo{background-position:right 10px top 20px}",
-    );
-    minify_test(
-      ".foo { background-position: left 10px top 20px }",
-      ".foo{background-position:10px 20px}",
-    );
-    minify_test(
-      ".foo { background-position: left 10px bottom 20px }",
-      ".foo{background-position:left 10px bottom 20px}",
-    );
-    minify_test(
-      ".foo { background-position: left 10px top }",
-      ".foo{background-position:10px 0}",
-    );
-    minify_test(
-      ".foo { background-position: bottom right }",
-      ".foo{background-position:100% 100%}",
-    );
-
-    minify_test(
-      ".foo { background: url('img-sprite.png') no-repeat bottom right }",
-      ".foo{background:url(img-sprite.png) 100% 100% no-repeat}",
-    );
-    minify_test(".foo { background: transparent }", ".foo{background:0 0}");
-
-    minify_test(".foo { background: url(\"data:image/svg+xml,%3Csvg width='168' height='24' xmlns='http://www.w3.org/2000/svg'%3E%3C/svg%3E\") }", ".foo{background:url(\"data:image/svg+xml,%3Csvg width='168' height='24' xmlns='http://www.w3.org/2000/svg'%3E%3C/svg%3E\")}");
-
-    test(
-      r#"
-      .foo {
-        background: url(img.png);
-        background-clip: text;
-      }
-    "#,
+    viewport_test(
+      ".foo { min-height: calc(100svh - 2rem); max-width: 50lvmin; inline-size: 100vi }",
       indoc! {r#"
-      .foo {
-        background: url("img.png") text;
-      }
-    "#
+        .foo {
+          max-width: 50vmin;
+          max-width: 50lvmin;
+          min-height: calc(100vh - 2rem);
+          min-height: calc(100svh - 2rem);
+          inline-size: 100vw;
+          inline-size: 100vi;
+        }
+      "#},
+      Browsers {
+        chrome: Some(100 << 16),
+        ..Browsers::default()
       },
     );
-
-    prefix_test(
-      r#"
-      .foo {
-        background: url(img.png);
-        background-clip: text;
-      }
-    "#,
+    viewport_test(
+      ".foo { width: min(100dvw, 500px) }",
       indoc! {r#"
-      .foo {
-        background: url("img.png");
-        -webkit-background-clip: text;
-        background-clip: text;
-      }
-    "#
-      },
+        .foo {
+          width: min(100vw, 500px);
+          width: min(100dvw, 500px);
+        }
+      "#},
       Browsers {
-        safari: Some(8 << 16),
+        chrome: Some(100 << 16),
         ..Browsers::default()
       },
     );
-
-    prefix_test(
-      r#"
-      .foo {
-        background: url(img.png);
-        background-clip: text;
-      }
-    "#,
+    viewport_test(
+      ".foo { margin-top: 10dvh }",
       indoc! {r#"
-      .foo {
-        background: url("img.png") text;
-      }
-    "#
-      },
+        .foo {
+          margin-top: 10vh;
+          margin-top: 10dvh;
+        }
+      "#},
       Browsers {
-        safari: Some(14 << 16),
+        chrome: Some(100 << 16),
         ..Browsers::default()
       },
     );
-
-    prefix_test(
-      r#"
-      .foo {
-        background: url(img.png) text;
-      }
-    "#,
+    viewport_test(
+      ".foo { margin-top: 10dvh }",
       indoc! {r#"
-      .foo {
-        background: url("img.png");
-        -webkit-background-clip: text;
-        background-clip: text;
-      }
-    "#
-      },
+        .foo {
+          margin-top: 10dvh;
+        }
+      "#},
       Browsers {
-        chrome: Some(45 << 16),
+        chrome: Some(120 << 16),
         ..Browsers::default()
       },
     );
-
-    prefix_test(
-      r#"
-      .foo {
-        background: url(img.png);
-        -webkit-background-clip: text;
-      }
-    "#,
+    viewport_test(
+      ".foo { padding: 1rem 5svw }",
       indoc! {r#"
-      .foo {
-        background: url("img.png");
-        -webkit-background-clip: text;
-      }
-    "#
-      },
+        .foo {
+          padding: 1rem 5vw;
+          padding: 1rem 5svw;
+        }
+      "#},
       Browsers {
-        chrome: Some(45 << 16),
+        chrome: Some(100 << 16),
         ..Browsers::default()
       },
     );
-
-    prefix_test(
-      r#"
-      .foo {
-        background: url(img.png);
-        background-clip: text;
-      }
-    "#,
+    viewport_test(
+      ".foo { margin-top: 10vh; margin-top: 10dvh }",
       indoc! {r#"
-      .foo {
-        background: url("img.png");
-        -webkit-background-clip: text;
-        background-clip: text;
-      }
-    "#
-      },
+        .foo {
+          margin-top: 10vh;
+          margin-top: 10dvh;
+        }
+      "#},
       Browsers {
-        safari: Some(14 << 16),
-        chrome: Some(95 << 16),
+        chrome: Some(100 << 16),
         ..Browsers::default()
       },
     );
-
-    prefix_test(
-      r#"
-      .foo {
-        background-image: url(img.png);
-        background-clip: text;
-      }
-    "#,
+    viewport_test(
+      ".foo { top: 0; bottom: 10lvh }",
       indoc! {r#"
-      .foo {
-        background-image: url("img.png");
-        -webkit-background-clip: text;
-        background-clip: text;
-      }
-    "#
-      },
+        .foo {
+          bottom: 10vh;
+          top: 0;
+          bottom: 10lvh;
+        }
+      "#},
       Browsers {
-        safari: Some(8 << 16),
+        chrome: Some(100 << 16),
         ..Browsers::default()
       },
     );
-
-    prefix_test(
-      r#"
-      .foo {
-        -webkit-background-clip: text;
-        background-clip: text;
-      }
-    "#,
+    viewport_test(
+      ".foo { inset: 10dvh }",
       indoc! {r#"
-      .foo {
-        -webkit-background-clip: text;
-        background-clip: text;
-      }
-    "#
-      },
+        .foo {
+          inset: 10vh;
+          inset: 10dvh;
+        }
+      "#},
       Browsers {
-        chrome: Some(45 << 16),
+        chrome: Some(100 << 16),
         ..Browsers::default()
       },
     );
-
-    prefix_test(
-      r#"
-      .foo {
-        background-image: url(img.png);
-        background-clip: text;
-      }
-    "#,
+    viewport_test(
+      ".foo { top: 1dvh; left: 1dvh; right: 1px; bottom: 1px }",
       indoc! {r#"
-      .foo {
-        background-image: url("img.png");
-        background-clip: text;
-      }
-    "#
+        .foo {
+          inset: 1vh 1px 1px 1vh;
+          inset: 1dvh 1px 1px 1dvh;
+        }
+      "#},
+      Browsers {
+        chrome: Some(100 << 16),
+        ..Browsers::default()
       },
+    );
+    viewport_test(
+      ".foo { font-size: 10dvmin; gap: 1dvh }",
+      indoc! {r#"
+        .foo {
+          gap: 1dvh;
+          font-size: 10dvmin;
+        }
+      "#},
       Browsers {
-        safari: Some(14 << 16),
+        chrome: Some(100 << 16),
         ..Browsers::default()
       },
     );
-
-    minify_test(".foo { background: none center }", ".foo{background:50%}");
-    minify_test(".foo { background: none }", ".foo{background:0 0}");
-
-    prefix_test(
-      r#"
-      .foo {
-        background: lab(51.5117% 43.3777 -29.0443);
-      }
-    "#,
+    viewport_test(
+      ".foo { padding-block: 2dvh 1svh }",
       indoc! {r#"
-      .foo {
-        background: #af5cae;
-        background: lab(51.5117% 43.3777 -29.0443);
-      }
-    "#
+        .foo {
+          padding-block: 2vh 1vh;
+          padding-block: 2dvh 1svh;
+        }
+      "#},
+      Browsers {
+        chrome: Some(100 << 16),
+        ..Browsers::default()
       },
+    );
+    viewport_test(
+      ".foo { margin-inline: 10dvh 1px }",
+      indoc! {r#"
+        .foo:not(:-webkit-any(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi))) {
+          margin-left: 10vh;
+          margin-left: 10dvh;
+          margin-right: 1px;
+        }
+
+        .foo:not(:is(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi))) {
+          margin-left: 10vh;
+          margin-left: 10dvh;
+          margin-right: 1px;
+        }
+
+        .foo:-webkit-any(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi)) {
+          margin-right: 10vh;
+          margin-left: 1px;
+          margin-right: 10dvh;
+        }
+
+        .foo:is(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi)) {
+          margin-right: 10vh;
+          margin-left: 1px;
+          margin-right: 10dvh;
+        }
+      "#},
       Browsers {
-        chrome: Some(95 << 16),
-        safari: Some(15 << 16),
+        chrome: Some(60 << 16),
         ..Browsers::default()
       },
     );
+  }
 
+  #[test]
+  fn test_zoom() {
+    minify_test(".foo { zoom: normal }", ".foo{zoom:normal}");
+    minify_test(".foo { zoom: 1.5 }", ".foo{zoom:1.5}");
+    minify_test(".foo { zoom: 150% }", ".foo{zoom:1.5}");
+    minify_test(".foo { zoom: 0 }", ".foo{zoom:0}");
+    minify_test(".foo { zoom: -1 }", ".foo{zoom:-1}"); // invalid
+    minify_test(".foo { zoom: -50% }", ".foo{zoom:-50%}"); // invalid
+    minify_test(".foo { zoom: reset }", ".foo{zoom:reset}"); // invalid
+    minify_test(".foo { zoom: 1; zoom: 2 }", ".foo{zoom:2}");
+    test(
+      ".foo { zoom: 150% }",
+      indoc! {r#"
+        .foo {
+          zoom: 150%;
+        }
+      "#},
+    );
     prefix_test(
-      r#"
-      .foo {
-        background: lab(51.5117% 43.3777 -29.0443) url(foo.png);
-      }
-    "#,
+      ".foo { zoom: 2 }",
       indoc! {r#"
-      .foo {
-        background: #af5cae url("foo.png");
-        background: lab(51.5117% 43.3777 -29.0443) url("foo.png");
-      }
-    "#
-      },
+        .foo {
+          zoom: 2;
+        }
+      "#},
       Browsers {
-        chrome: Some(95 << 16),
-        safari: Some(15 << 16),
+        safari: Some(8 << 16),
         ..Browsers::default()
       },
     );
 
-    prefix_test(
-      r#"
-      .foo {
-        background: lab(51.5117% 43.3777 -29.0443) linear-gradient(lab(52.2319% 40.1449 59.9171), lab(47.7776% -34.2947 -7.65904));
-      }
-    "#,
+    let zoom_test = |source: &str, expected: &str, browsers: Browsers| {
+      nesting_test_with_targets(
+        source,
+        expected,
+        Targets {
+          browsers: Some(browsers),
+          include: Features::Zoom,
+          exclude: Features::empty(),
+        },
+      );
+    };
+
+    zoom_test(
+      ".foo { zoom: 150% }",
       indoc! {r#"
-      .foo {
-        background: #af5cae linear-gradient(#c65d07, #00807c);
-        background: lab(51.5117% 43.3777 -29.0443) linear-gradient(lab(52.2319% 40.1449 59.9171), lab(47.7776% -34.2947 -7.65904));
-      }
-    "#
+        .foo {
+          transform: scale(1.5);
+          transform-origin: 0 0;
+          zoom: 150%;
+        }
+      "#},
+      Browsers {
+        firefox: Some(100 << 16),
+        ..Browsers::default()
       },
+    );
+    zoom_test(
+      ".foo { zoom: 2 }",
+      indoc! {r#"
+        .foo {
+          -moz-transform: scale(2);
+          transform: scale(2);
+          -moz-transform-origin: 0 0;
+          transform-origin: 0 0;
+          zoom: 2;
+        }
+      "#},
       Browsers {
-        chrome: Some(95 << 16),
-        safari: Some(15 << 16),
+        firefox: Some(15 << 16),
         ..Browsers::default()
       },
     );
-
-    test(
-      ".foo { background: calc(var(--v) / 0.3)",
+    zoom_test(
+      ".foo { zoom: 2 }",
       indoc! {r#"
-      .foo {
-        background: calc(var(--v) / .3);
-      }
-    "#},
+        .foo {
+          zoom: 2;
+        }
+      "#},
+      Browsers {
+        chrome: Some(100 << 16),
+        ..Browsers::default()
+      },
     );
-
-    prefix_test(
-      r#"
-      .foo {
-        background-color: #4263eb;
-        background-color: color(display-p3 0 .5 1);
-      }
-    "#,
+    zoom_test(
+      ".foo { zoom: 2 }",
       indoc! {r#"
-      .foo {
-        background-color: #4263eb;
-        background-color: color(display-p3 0 .5 1);
-      }
-    "#
+        .foo {
+          zoom: 2;
+        }
+      "#},
+      Browsers {
+        chrome: Some(100 << 16),
+        firefox: Some(100 << 16),
+        ..Browsers::default()
       },
+    );
+    zoom_test(
+      ".foo { zoom: normal }",
+      indoc! {r#"
+        .foo {
+          zoom: normal;
+        }
+      "#},
       Browsers {
-        chrome: Some(99 << 16),
+        firefox: Some(100 << 16),
         ..Browsers::default()
       },
     );
-    prefix_test(
-      r#"
-      .foo {
-        background-color: #4263eb;
-        background-color: color(display-p3 0 .5 1);
-      }
-    "#,
+    zoom_test(
+      ".foo { transform: rotate(45deg); zoom: 2 }",
       indoc! {r#"
-      .foo {
-        background-color: color(display-p3 0 .5 1);
-      }
-    "#
+        .foo {
+          transform: rotate(45deg);
+          zoom: 2;
+        }
+      "#},
+      Browsers {
+        firefox: Some(100 << 16),
+        ..Browsers::default()
       },
+    );
+    zoom_test(
+      ".foo { zoom: 2; transform-origin: center }",
+      indoc! {r#"
+        .foo {
+          transform-origin: center;
+          zoom: 2;
+        }
+      "#},
       Browsers {
-        safari: Some(16 << 16),
+        firefox: Some(100 << 16),
         ..Browsers::default()
       },
     );
-    prefix_test(
+    zoom_test(
+      ".foo { zoom: var(--zoom) }",
+      indoc! {r#"
+        .foo {
+          zoom: var(--zoom);
+        }
+      "#},
+      Browsers {
+        firefox: Some(100 << 16),
+        ..Browsers::default()
+      },
+    );
+  }
+
+  #[test]
+  pub fn test_background() {
+    test(
       r#"
       .foo {
-        background-image: linear-gradient(red, green);
-        background-image: linear-gradient(lch(50% 132 50), lch(50% 130 150));
+        background: url(img.png);
+        background-position-x: 20px;
+        background-position-y: 10px;
+        background-size: 50px 100px;
+        background-repeat: repeat no-repeat;
       }
     "#,
       indoc! {r#"
       .foo {
-        background-image: linear-gradient(red, green);
-        background-image: linear-gradient(lch(50% 132 50), lch(50% 130 150));
+        background: url("img.png") 20px 10px / 50px 100px repeat-x;
       }
     "#
       },
-      Browsers {
-        chrome: Some(99 << 16),
-        ..Browsers::default()
-      },
     );
-    prefix_test(
+
+    test(
       r#"
       .foo {
-        background-image: linear-gradient(red, green);
-        background-image: linear-gradient(lch(50% 132 50), lch(50% 130 150));
+        background-color: red;
+        background-position: 0% 0%;
+        background-size: auto;
+        background-repeat: repeat;
+        background-clip: border-box;
+        background-origin: padding-box;
+        background-attachment: scroll;
+        background-image: none
       }
     "#,
       indoc! {r#"
       .foo {
-        background-image: linear-gradient(lch(50% 132 50), lch(50% 130 150));
+        background: red;
       }
     "#
       },
-      Browsers {
-        safari: Some(16 << 16),
-        ..Browsers::default()
-      },
     );
-    prefix_test(
+
+    test(
       r#"
       .foo {
-        background: #4263eb;
-        background: color(display-p3 0 .5 1);
+        background-color: gray;
+        background-position: 40% 50%;
+        background-size: 10em auto;
+        background-repeat: round;
+        background-clip: border-box;
+        background-origin: border-box;
+        background-attachment: fixed;
+        background-image: url('chess.png');
       }
     "#,
       indoc! {r#"
       .foo {
-        background: #4263eb;
-        background: color(display-p3 0 .5 1);
+        background: gray url("chess.png") 40% / 10em round fixed border-box;
       }
     "#
       },
-      Browsers {
-        chrome: Some(99 << 16),
-        ..Browsers::default()
-      },
     );
-    prefix_test(
+
+    test(
       r#"
       .foo {
-        background: #4263eb;
-        background: color(display-p3 0 .5 1);
+        background: url(img.png), url(test.jpg) gray;
+        background-position-x: right 20px, 10px;
+        background-position-y: top 20px, 15px;
+        background-size: 50px 50px, auto;
+        background-repeat: repeat no-repeat, no-repeat;
       }
     "#,
       indoc! {r#"
       .foo {
-        background: color(display-p3 0 .5 1);
+        background: url("img.png") right 20px top 20px / 50px 50px repeat-x, gray url("test.jpg") 10px 15px no-repeat;
       }
     "#
       },
-      Browsers {
-        safari: Some(16 << 16),
-        ..Browsers::default()
-      },
     );
-    prefix_test(
+
+    minify_test(
       r#"
       .foo {
-        background: linear-gradient(red, green);
-        background: linear-gradient(lch(50% 132 50), lch(50% 130 150));
+        background-position: center center;
+      }
+    "#,
+      indoc! {".foo{background-position:50%}"
+      },
+    );
+
+    test(
+      r#"
+      .foo {
+        background: url(img.png) gray;
+        background-clip: content-box;
+        -webkit-background-clip: text;
       }
     "#,
       indoc! {r#"
       .foo {
-        background: linear-gradient(red, green);
-        background: linear-gradient(lch(50% 132 50), lch(50% 130 150));
+        background: gray url("img.png") padding-box content-box;
+        -webkit-background-clip: text;
       }
     "#
       },
-      Browsers {
-        chrome: Some(99 << 16),
-        ..Browsers::default()
-      },
     );
-    prefix_test(
+
+    test(
       r#"
       .foo {
-        background: red;
-        background: linear-gradient(lch(50% 132 50), lch(50% 130 150));
+        background: url(img.png) gray;
+        -webkit-background-clip: text;
+        background-clip: content-box;
       }
     "#,
       indoc! {r#"
       .foo {
-        background: red;
-        background: linear-gradient(lch(50% 132 50), lch(50% 130 150));
+        background: gray url("img.png");
+        -webkit-background-clip: text;
+        background-clip: content-box;
       }
     "#
       },
-      Browsers {
-        chrome: Some(99 << 16),
-        ..Browsers::default()
-      },
     );
-    prefix_test(
+
+    test(
       r#"
       .foo {
-        background: linear-gradient(red, green);
-        background: linear-gradient(lch(50% 132 50), lch(50% 130 150));
+        background: url(img.png) gray;
+        background-position: var(--pos);
       }
     "#,
       indoc! {r#"
       .foo {
-        background: linear-gradient(lch(50% 132 50), lch(50% 130 150));
+        background: gray url("img.png");
+        background-position: var(--pos);
       }
     "#
       },
-      Browsers {
-        safari: Some(16 << 16),
-        ..Browsers::default()
-      },
     );
-    prefix_test(
+
+    minify_test(
+      ".foo { background-position: bottom left }",
+      ".foo{background-position:0 100%}",
+    );
+    minify_test(
+      ".foo { background-position: left 10px center }",
+      ".foo{background-position:10px 50%}",
+    );
+    minify_test(
+      ".foo { background-position: right 10px center }",
+      ".foo{background-position:right 10px center}",
+    );
+    minify_test(
+      ".foo { background-position: right 10px top 20px }",
+      ".foo{background-position:right 10px top 20px}",
+    );
+    minify_test(
+      ".foo { background-position: left 10px top 20px }",
+      ".foo{background-position:10px 20px}",
+    );
+    minify_test(
+      ".foo { background-position: left 10px bottom 20px }",
+      ".foo{background-position:left 10px bottom 20px}",
+    );
+    minify_test(
+      ".foo { background-position: left 10px top }",
+      ".foo{background-position:10px 0}",
+    );
+    minify_test(
+      ".foo { background-position: bottom right }",
+      ".foo{background-position:100% 100%}",
+    );
+
+    minify_test(
+      ".foo { background: url('img-sprite.png') no-repeat bottom right }",
+      ".foo{background:url(img-sprite.png) 100% 100% no-repeat}",
+    );
+    minify_test(".foo { background: transparent }", ".foo{background:0 0}");
+    minify_test(".foo { background: url(img.png) left top }", ".foo{background:url(img.png)}");
+    minify_test(
+      ".foo { background: url(img.png) top left / auto no-repeat }",
+      ".foo{background:url(img.png) no-repeat}",
+    );
+    minify_test(
+      ".foo { background: url(img.png) left 0 top 0px }",
+      ".foo{background:url(img.png)}",
+    );
+    minify_test(
+      ".foo { background: url(img.png) content-box content-box }",
+      ".foo{background:url(img.png) content-box}",
+    );
+    minify_test(
+      ".foo { background: url(img.png) border-box border-box }",
+      ".foo{background:url(img.png) border-box}",
+    );
+    minify_test(
+      ".foo { background: url(img.png) padding-box content-box }",
+      ".foo{background:url(img.png) padding-box content-box}",
+    );
+    minify_test(
+      ".foo { background: url(img.png) content-box border-box }",
+      ".foo{background:url(img.png) content-box border-box}",
+    );
+    minify_test(".foo { background-size: -10px }", ".foo{background-size:-10px}");
+    minify_test(
+      ".foo { background: url(img.png) 0 0 / 10px -5% }",
+      ".foo{background:url(img.png) 0 0/10px -5%}",
+    );
+
+    minify_test(".foo { background: url(\"data:image/svg+xml,%3Csvg width='168' height='24' xmlns='http://www.w3.org/2000/svg'%3E%3C/svg%3E\") }", ".foo{background:url(\"data:image/svg+xml,%3Csvg width='168' height='24' xmlns='http://www.w3.org/2000/svg'%3E%3C/svg%3E\")}");
+    minify_test(
+      ".foo { background: url('img.png' crossorigin(anonymous)) }",
+      ".foo{background:url(\"img.png\" crossorigin(anonymous))}",
+    );
+    minify_test(
+      ".foo { background: url('img.png' integrity('sha384-abc') referrerpolicy(no-referrer)) }",
+      ".foo{background:url(\"img.png\" integrity(\"sha384-abc\") referrerpolicy(no-referrer))}",
+    );
+    minify_test(
+      ".foo { background: url(\"img.png\" foo bar(1,  2)) }",
+      ".foo{background:url(\"img.png\" foo bar(1,  2))}",
+    );
+    minify_test(
+      ".foo { --foo: url(\"img.png\" crossorigin(use-credentials)) }",
+      ".foo{--foo:url(\"img.png\" crossorigin(use-credentials))}",
+    );
+    minify_test(
+      ".foo { background: url(\"img.png\" crossorigin(foo)) }",
+      ".foo{background:url(\"img.png\" crossorigin(foo))}",
+    );
+    minify_test(
+      ".foo { background: url( \"img.png\"   foo   crossorigin(anonymous) ) }",
+      ".foo{background:url(\"img.png\" foo crossorigin(anonymous))}",
+    );
+    minify_test(".foo { background: url(\"img.png\") }", ".foo{background:url(img.png)}");
+
+    test(
       r#"
       .foo {
-        background: var(--fallback);
-        background: linear-gradient(lch(50% 132 50), lch(50% 130 150));
+        background: url(img.png);
+        background-clip: text;
       }
     "#,
       indoc! {r#"
       .foo {
-        background: var(--fallback);
-        background: linear-gradient(lch(50% 132 50), lch(50% 130 150));
+        background: url("img.png") text;
       }
     "#
       },
-      Browsers {
-        chrome: Some(99 << 16),
-        ..Browsers::default()
-      },
     );
+
     prefix_test(
       r#"
       .foo {
-        background: red url(foo.png);
-        background: lch(50% 132 50) url(foo.png);
+        background: url(img.png);
+        background-clip: text;
       }
     "#,
       indoc! {r#"
       .foo {
-        background: red url("foo.png");
-        background: lch(50% 132 50) url("foo.png");
+        background: url("img.png");
+        -webkit-background-clip: text;
+        background-clip: text;
       }
     "#
       },
       Browsers {
-        chrome: Some(99 << 16),
+        safari: Some(8 << 16),
         ..Browsers::default()
       },
     );
-  }
 
-  #[test]
-  pub fn test_flex() {
-    test(
+    prefix_test(
       r#"
       .foo {
-        flex-direction: column;
-        flex-wrap: wrap;
+        background: url(img.png);
+        background-clip: text;
       }
     "#,
       indoc! {r#"
       .foo {
-        flex-flow: column wrap;
+        background: url("img.png") text;
       }
     "#
       },
+      Browsers {
+        safari: Some(14 << 16),
+        ..Browsers::default()
+      },
     );
 
-    test(
+    prefix_test(
       r#"
       .foo {
-        flex-direction: row;
-        flex-wrap: wrap;
+        background: url(img.png) text;
       }
     "#,
       indoc! {r#"
       .foo {
-        flex-flow: wrap;
+        background: url("img.png");
+        -webkit-background-clip: text;
+        background-clip: text;
       }
     "#
       },
+      Browsers {
+        chrome: Some(45 << 16),
+        ..Browsers::default()
+      },
     );
 
-    test(
+    prefix_test(
       r#"
       .foo {
-        flex-direction: row;
-        flex-wrap: nowrap;
+        background: url(img.png);
+        -webkit-background-clip: text;
       }
     "#,
       indoc! {r#"
       .foo {
-        flex-flow: row;
+        background: url("img.png");
+        -webkit-background-clip: text;
       }
     "#
       },
+      Browsers {
+        chrome: Some(45 << 16),
+        ..Browsers::default()
+      },
     );
 
-    test(
+    prefix_test(
       r#"
       .foo {
-        flex-direction: column;
-        flex-wrap: nowrap;
+        background: url(img.png);
+        background-clip: text;
       }
     "#,
       indoc! {r#"
       .foo {
-        flex-flow: column;
+        background: url("img.png");
+        -webkit-background-clip: text;
+        background-clip: text;
       }
     "#
       },
+      Browsers {
+        safari: Some(14 << 16),
+        chrome: Some(95 << 16),
+        ..Browsers::default()
+      },
     );
 
-    test(
+    prefix_test(
       r#"
       .foo {
-        flex-grow: 1;
-        flex-shrink: 1;
-        flex-basis: 0%;
+        background-image: url(img.png);
+        background-clip: text;
       }
     "#,
       indoc! {r#"
       .foo {
-        flex: 1;
+        background-image: url("img.png");
+        -webkit-background-clip: text;
+        background-clip: text;
       }
     "#
       },
+      Browsers {
+        safari: Some(8 << 16),
+        ..Browsers::default()
+      },
     );
 
-    test(
+    prefix_test(
       r#"
       .foo {
-        flex-grow: 1;
-        flex-shrink: 1;
-        flex-basis: 0;
+        -webkit-background-clip: text;
+        background-clip: text;
       }
     "#,
       indoc! {r#"
       .foo {
-        flex: 1 1 0;
+        -webkit-background-clip: text;
+        background-clip: text;
       }
     "#
       },
+      Browsers {
+        chrome: Some(45 << 16),
+        ..Browsers::default()
+      },
     );
 
-    test(
+    prefix_test(
       r#"
       .foo {
-        flex-grow: 1;
-        flex-shrink: 1;
-        flex-basis: 0px;
+        background-image: url(img.png);
+        background-clip: text;
       }
     "#,
       indoc! {r#"
       .foo {
-        flex: 1 1 0;
+        background-image: url("img.png");
+        background-clip: text;
       }
     "#
       },
+      Browsers {
+        safari: Some(14 << 16),
+        ..Browsers::default()
+      },
     );
 
-    test(
+    minify_test(".foo { background: none center }", ".foo{background:50%}");
+    minify_test(".foo { background: none }", ".foo{background:0 0}");
+
+    prefix_test(
       r#"
       .foo {
-        flex-grow: 1;
-        flex-shrink: 2;
-        flex-basis: 0%;
+        background: lab(51.5117% 43.3777 -29.0443);
       }
     "#,
       indoc! {r#"
       .foo {
-        flex: 1 2;
-      }
+        background: #af5cae;
+        background: lab(51.5117% 43.3777 -29.0443);
+      }
     "#
       },
+      Browsers {
+        chrome: Some(95 << 16),
+        safari: Some(15 << 16),
+        ..Browsers::default()
+      },
     );
 
-    test(
+    prefix_test(
       r#"
       .foo {
-        flex-grow: 2;
-        flex-shrink: 1;
-        flex-basis: 0%;
+        background: lab(51.5117% 43.3777 -29.0443) url(foo.png);
       }
     "#,
       indoc! {r#"
       .foo {
-        flex: 2;
+        background: #af5cae url("foo.png");
+        background: lab(51.5117% 43.3777 -29.0443) url("foo.png");
       }
     "#
       },
+      Browsers {
+        chrome: Some(95 << 16),
+        safari: Some(15 << 16),
+        ..Browsers::default()
+      },
     );
 
-    test(
+    prefix_test(
       r#"
       .foo {
-        flex-grow: 2;
-        flex-shrink: 2;
-        flex-basis: 0%;
+        background: lab(51.5117% 43.3777 -29.0443) linear-gradient(lab(52.2319% 40.1449 59.9171), lab(47.7776% -34.2947 -7.65904));
       }
     "#,
       indoc! {r#"
       .foo {
-        flex: 2 2;
+        background: #af5cae linear-gradient(#c65d07, #00807c);
+        background: lab(51.5117% 43.3777 -29.0443) linear-gradient(lab(52.2319% 40.1449 59.9171), lab(47.7776% -34.2947 -7.65904));
       }
     "#
       },
+      Browsers {
+        chrome: Some(95 << 16),
+        safari: Some(15 << 16),
+        ..Browsers::default()
+      },
     );
 
     test(
+      ".foo { background: calc(var(--v) / 0.3)",
+      indoc! {r#"
+      .foo {
+        background: calc(var(--v) / .3);
+      }
+    "#},
+    );
+
+    prefix_test(
       r#"
       .foo {
-        flex-grow: 1;
-        flex-shrink: 1;
-        flex-basis: 10px;
+        background-color: #4263eb;
+        background-color: color(display-p3 0 .5 1);
       }
     "#,
       indoc! {r#"
       .foo {
-        flex: 10px;
+        background-color: #4263eb;
+        background-color: color(display-p3 0 .5 1);
       }
     "#
       },
+      Browsers {
+        chrome: Some(99 << 16),
+        ..Browsers::default()
+      },
     );
-
-    test(
+    prefix_test(
       r#"
       .foo {
-        flex-grow: 2;
-        flex-shrink: 1;
-        flex-basis: 10px;
+        background-color: #4263eb;
+        background-color: color(display-p3 0 .5 1);
       }
     "#,
       indoc! {r#"
       .foo {
-        flex: 2 10px;
+        background-color: color(display-p3 0 .5 1);
       }
     "#
       },
+      Browsers {
+        safari: Some(16 << 16),
+        ..Browsers::default()
+      },
     );
-
-    test(
+    prefix_test(
       r#"
       .foo {
-        flex-grow: 1;
-        flex-shrink: 0;
-        flex-basis: 0%;
+        background-image: linear-gradient(red, green);
+        background-image: linear-gradient(lch(50% 132 50), lch(50% 130 150));
       }
     "#,
       indoc! {r#"
       .foo {
-        flex: 1 0;
+        background-image: linear-gradient(red, green);
+        background-image: linear-gradient(lch(50% 132 50), lch(50% 130 150));
       }
     "#
       },
+      Browsers {
+        chrome: Some(99 << 16),
+        ..Browsers::default()
+      },
     );
-
-    test(
+    prefix_test(
       r#"
       .foo {
-        flex-grow: 1;
-        flex-shrink: 0;
-        flex-basis: auto;
+        background-image: linear-gradient(red, green);
+        background-image: linear-gradient(lch(50% 132 50), lch(50% 130 150));
       }
     "#,
       indoc! {r#"
       .foo {
-        flex: 1 0 auto;
+        background-image: linear-gradient(lch(50% 132 50), lch(50% 130 150));
       }
     "#
       },
+      Browsers {
+        safari: Some(16 << 16),
+        ..Browsers::default()
+      },
     );
-
-    test(
+    prefix_test(
       r#"
       .foo {
-        flex-grow: 1;
-        flex-shrink: 1;
-        flex-basis: auto;
+        background: #4263eb;
+        background: color(display-p3 0 .5 1);
       }
     "#,
       indoc! {r#"
       .foo {
-        flex: auto;
+        background: #4263eb;
+        background: color(display-p3 0 .5 1);
       }
     "#
       },
+      Browsers {
+        chrome: Some(99 << 16),
+        ..Browsers::default()
+      },
     );
-
-    test(
+    prefix_test(
       r#"
       .foo {
-        flex: 0 0;
-        flex-grow: 1;
+        background: #4263eb;
+        background: color(display-p3 0 .5 1);
       }
     "#,
       indoc! {r#"
       .foo {
-        flex: 1 0;
+        background: color(display-p3 0 .5 1);
       }
     "#
       },
+      Browsers {
+        safari: Some(16 << 16),
+        ..Browsers::default()
+      },
     );
-
-    test(
+    prefix_test(
       r#"
       .foo {
-        flex: 0 0;
-        flex-grow: var(--grow);
+        background: linear-gradient(red, green);
+        background: linear-gradient(lch(50% 132 50), lch(50% 130 150));
       }
     "#,
       indoc! {r#"
       .foo {
-        flex: 0 0;
-        flex-grow: var(--grow);
+        background: linear-gradient(red, green);
+        background: linear-gradient(lch(50% 132 50), lch(50% 130 150));
       }
     "#
       },
+      Browsers {
+        chrome: Some(99 << 16),
+        ..Browsers::default()
+      },
     );
-
-    test(
+    prefix_test(
       r#"
       .foo {
-        align-content: center;
-        justify-content: center;
+        background: red;
+        background: linear-gradient(lch(50% 132 50), lch(50% 130 150));
       }
     "#,
       indoc! {r#"
       .foo {
-        place-content: center;
+        background: red;
+        background: linear-gradient(lch(50% 132 50), lch(50% 130 150));
       }
     "#
       },
+      Browsers {
+        chrome: Some(99 << 16),
+        ..Browsers::default()
+      },
     );
-
-    test(
+    prefix_test(
       r#"
       .foo {
-        align-content: first baseline;
-        justify-content: safe right;
+        background: linear-gradient(red, green);
+        background: linear-gradient(lch(50% 132 50), lch(50% 130 150));
       }
     "#,
       indoc! {r#"
       .foo {
-        place-content: baseline safe right;
+        background: linear-gradient(lch(50% 132 50), lch(50% 130 150));
       }
     "#
       },
+      Browsers {
+        safari: Some(16 << 16),
+        ..Browsers::default()
+      },
     );
-
-    test(
+    prefix_test(
       r#"
       .foo {
-        place-content: first baseline unsafe left;
+        background: var(--fallback);
+        background: linear-gradient(lch(50% 132 50), lch(50% 130 150));
       }
     "#,
       indoc! {r#"
       .foo {
-        place-content: baseline unsafe left;
+        background: var(--fallback);
+        background: linear-gradient(lch(50% 132 50), lch(50% 130 150));
       }
     "#
       },
+      Browsers {
+        chrome: Some(99 << 16),
+        ..Browsers::default()
+      },
     );
-
-    test(
+    prefix_test(
       r#"
       .foo {
-        place-content: center center;
+        background: red url(foo.png);
+        background: lch(50% 132 50) url(foo.png);
       }
     "#,
       indoc! {r#"
       .foo {
-        place-content: center;
+        background: red url("foo.png");
+        background: lch(50% 132 50) url("foo.png");
       }
     "#
       },
+      Browsers {
+        chrome: Some(99 << 16),
+        ..Browsers::default()
+      },
     );
+  }
 
+  #[test]
+  pub fn test_flex() {
     test(
       r#"
       .foo {
-        align-self: center;
-        justify-self: center;
+        flex-direction: column;
+        flex-wrap: wrap;
       }
     "#,
       indoc! {r#"
       .foo {
-        place-self: center;
+        flex-flow: column wrap;
       }
     "#
       },
@@ -4938,13 +5786,13 @@ mod tests {
     test(
       r#"
       .foo {
-        align-self: center;
-        justify-self: unsafe left;
+        flex-direction: row;
+        flex-wrap: wrap;
       }
     "#,
       indoc! {r#"
       .foo {
-        place-self: center unsafe left;
+        flex-flow: wrap;
       }
     "#
       },
@@ -4953,13 +5801,13 @@ mod tests {
     test(
       r#"
       .foo {
-        align-items: center;
-        justify-items: center;
+        flex-direction: row;
+        flex-wrap: nowrap;
       }
     "#,
       indoc! {r#"
       .foo {
-        place-items: center;
+        flex-flow: row;
       }
     "#
       },
@@ -4968,13 +5816,13 @@ mod tests {
     test(
       r#"
       .foo {
-        align-items: center;
-        justify-items: legacy left;
+        flex-direction: column;
+        flex-wrap: nowrap;
       }
     "#,
       indoc! {r#"
       .foo {
-        place-items: center legacy left;
+        flex-flow: column;
       }
     "#
       },
@@ -4983,14 +5831,14 @@ mod tests {
     test(
       r#"
       .foo {
-        place-items: center;
-        justify-items: var(--justify);
+        flex-grow: 1;
+        flex-shrink: 1;
+        flex-basis: 0%;
       }
     "#,
       indoc! {r#"
       .foo {
-        place-items: center;
-        justify-items: var(--justify);
+        flex: 1;
       }
     "#
       },
@@ -4999,13 +5847,14 @@ mod tests {
     test(
       r#"
       .foo {
-        row-gap: 10px;
-        column-gap: 20px;
+        flex-grow: 1;
+        flex-shrink: 1;
+        flex-basis: 0;
       }
     "#,
       indoc! {r#"
       .foo {
-        gap: 10px 20px;
+        flex: 1 1 0;
       }
     "#
       },
@@ -5014,13 +5863,14 @@ mod tests {
     test(
       r#"
       .foo {
-        row-gap: 10px;
-        column-gap: 10px;
+        flex-grow: 1;
+        flex-shrink: 1;
+        flex-basis: 0px;
       }
     "#,
       indoc! {r#"
       .foo {
-        gap: 10px;
+        flex: 1 1 0;
       }
     "#
       },
@@ -5029,13 +5879,14 @@ mod tests {
     test(
       r#"
       .foo {
-        gap: 10px;
-        column-gap: 20px;
+        flex-grow: 1;
+        flex-shrink: 2;
+        flex-basis: 0%;
       }
     "#,
       indoc! {r#"
       .foo {
-        gap: 10px 20px;
+        flex: 1 2;
       }
     "#
       },
@@ -5044,13 +5895,14 @@ mod tests {
     test(
       r#"
       .foo {
-        column-gap: 20px;
-        gap: 10px;
-      }
-    "#,
+        flex-grow: 2;
+        flex-shrink: 1;
+        flex-basis: 0%;
+      }
+    "#,
       indoc! {r#"
       .foo {
-        gap: 10px;
+        flex: 2;
       }
     "#
       },
@@ -5059,13 +5911,14 @@ mod tests {
     test(
       r#"
       .foo {
-        row-gap: normal;
-        column-gap: 20px;
+        flex-grow: 2;
+        flex-shrink: 2;
+        flex-basis: 0%;
       }
     "#,
       indoc! {r#"
       .foo {
-        gap: normal 20px;
+        flex: 2 2;
       }
     "#
       },
@@ -5074,523 +5927,458 @@ mod tests {
     test(
       r#"
       .foo {
-        -webkit-flex-grow: 1;
-        -webkit-flex-shrink: 1;
-        -webkit-flex-basis: auto;
+        flex-grow: 1;
+        flex-shrink: 1;
+        flex-basis: 10px;
       }
     "#,
       indoc! {r#"
       .foo {
-        -webkit-flex: auto;
+        flex: 10px;
       }
     "#
       },
     );
+
     test(
       r#"
       .foo {
-        -webkit-flex-grow: 1;
-        -webkit-flex-shrink: 1;
-        -webkit-flex-basis: auto;
-        flex-grow: 1;
+        flex-grow: 2;
         flex-shrink: 1;
-        flex-basis: auto;
+        flex-basis: 10px;
       }
     "#,
       indoc! {r#"
       .foo {
-        -webkit-flex: auto;
-        flex: auto;
+        flex: 2 10px;
       }
     "#
       },
     );
-    prefix_test(
+
+    test(
       r#"
       .foo {
-        -webkit-box-orient: horizontal;
-        -webkit-box-direction: normal;
-        flex-direction: row;
+        flex-grow: 1;
+        flex-shrink: 0;
+        flex-basis: 0%;
       }
     "#,
       indoc! {r#"
       .foo {
-        -webkit-box-orient: horizontal;
-        -webkit-box-direction: normal;
-        -webkit-flex-direction: row;
-        flex-direction: row;
+        flex: 1 0;
       }
-    "#},
-      Browsers {
-        safari: Some(4 << 16),
-        ..Browsers::default()
+    "#
       },
     );
-    prefix_test(
+
+    test(
       r#"
       .foo {
-        flex-direction: row;
+        flex-grow: 1;
+        flex-shrink: 0;
+        flex-basis: auto;
       }
     "#,
       indoc! {r#"
       .foo {
-        -webkit-box-orient: horizontal;
-        -moz-box-orient: horizontal;
-        -webkit-box-direction: normal;
-        -moz-box-direction: normal;
-        -webkit-flex-direction: row;
-        -ms-flex-direction: row;
-        flex-direction: row;
+        flex: 1 0 auto;
       }
-    "#},
-      Browsers {
-        safari: Some(4 << 16),
-        firefox: Some(4 << 16),
-        ie: Some(10 << 16),
-        ..Browsers::default()
+    "#
       },
     );
-    prefix_test(
+
+    test(
       r#"
       .foo {
-        -webkit-box-orient: horizontal;
-        -webkit-box-direction: normal;
-        -moz-box-orient: horizontal;
-        -moz-box-direction: normal;
-        -webkit-flex-direction: row;
-        -ms-flex-direction: row;
-        flex-direction: row;
+        flex-grow: 1;
+        flex-shrink: 1;
+        flex-basis: auto;
       }
     "#,
       indoc! {r#"
       .foo {
-        flex-direction: row;
+        flex: auto;
       }
-    "#},
-      Browsers {
-        safari: Some(14 << 16),
-        ..Browsers::default()
+    "#
       },
     );
-    prefix_test(
+
+    test(
       r#"
       .foo {
-        flex-wrap: wrap;
+        flex: 0 0;
+        flex-grow: 1;
       }
     "#,
       indoc! {r#"
       .foo {
-        -webkit-box-lines: multiple;
-        -moz-box-lines: multiple;
-        -webkit-flex-wrap: wrap;
-        -ms-flex-wrap: wrap;
-        flex-wrap: wrap;
+        flex: 1 0;
       }
-    "#},
-      Browsers {
-        safari: Some(4 << 16),
-        firefox: Some(4 << 16),
-        ie: Some(10 << 16),
-        ..Browsers::default()
+    "#
       },
     );
-    prefix_test(
+
+    test(
       r#"
       .foo {
-        -webkit-box-lines: multiple;
-        -moz-box-lines: multiple;
-        -webkit-flex-wrap: wrap;
-        -ms-flex-wrap: wrap;
-        flex-wrap: wrap;
+        flex: 0 0;
+        flex-grow: var(--grow);
       }
     "#,
       indoc! {r#"
       .foo {
-        flex-wrap: wrap;
+        flex: 0 0;
+        flex-grow: var(--grow);
       }
-    "#},
-      Browsers {
-        safari: Some(11 << 16),
-        ..Browsers::default()
+    "#
       },
     );
-    prefix_test(
+
+    minify_test(".foo { flex: -1 }", ".foo{flex:-1}");
+    minify_test(".foo { flex: 1 -1 }", ".foo{flex:1 -1}");
+    minify_test(".foo { flex: 0 0 auto; flex: -1 }", ".foo{flex:none;flex:-1}");
+
+    test(
       r#"
       .foo {
-        flex-flow: row wrap;
+        align-content: center;
+        justify-content: center;
       }
     "#,
       indoc! {r#"
       .foo {
-        -webkit-box-orient: horizontal;
-        -moz-box-orient: horizontal;
-        -webkit-box-direction: normal;
-        -moz-box-direction: normal;
-        -webkit-flex-flow: wrap;
-        -ms-flex-flow: wrap;
-        flex-flow: wrap;
+        place-content: center;
       }
-    "#},
-      Browsers {
-        safari: Some(4 << 16),
-        firefox: Some(4 << 16),
-        ie: Some(10 << 16),
-        ..Browsers::default()
+    "#
       },
     );
-    prefix_test(
+
+    test(
       r#"
       .foo {
-        -webkit-box-orient: horizontal;
-        -moz-box-orient: horizontal;
-        -webkit-box-direction: normal;
-        -moz-box-direction: normal;
-        -webkit-flex-flow: wrap;
-        -ms-flex-flow: wrap;
-        flex-flow: wrap;
+        align-content: first baseline;
+        justify-content: safe right;
       }
     "#,
       indoc! {r#"
       .foo {
-        flex-flow: wrap;
+        place-content: baseline safe right;
       }
-    "#},
-      Browsers {
-        safari: Some(11 << 16),
-        ..Browsers::default()
+    "#
       },
     );
-    prefix_test(
+
+    test(
       r#"
       .foo {
-        flex-grow: 1;
+        place-content: first baseline unsafe left;
       }
     "#,
       indoc! {r#"
       .foo {
-        -webkit-box-flex: 1;
-        -moz-box-flex: 1;
-        -ms-flex-positive: 1;
-        -webkit-flex-grow: 1;
-        flex-grow: 1;
+        place-content: baseline unsafe left;
       }
-    "#},
-      Browsers {
-        safari: Some(4 << 16),
-        firefox: Some(4 << 16),
-        ie: Some(10 << 16),
-        ..Browsers::default()
+    "#
       },
     );
-    prefix_test(
+
+    test(
       r#"
       .foo {
-        -webkit-box-flex: 1;
-        -moz-box-flex: 1;
-        -ms-flex-positive: 1;
-        -webkit-flex-grow: 1;
-        flex-grow: 1;
+        place-content: center center;
       }
     "#,
       indoc! {r#"
       .foo {
-        flex-grow: 1;
+        place-content: center;
       }
-    "#},
-      Browsers {
-        safari: Some(11 << 16),
-        ..Browsers::default()
+    "#
       },
     );
-    prefix_test(
+
+    test(
       r#"
       .foo {
-        flex-shrink: 1;
+        align-self: center;
+        justify-self: center;
       }
     "#,
       indoc! {r#"
       .foo {
-        -ms-flex-negative: 1;
-        -webkit-flex-shrink: 1;
-        flex-shrink: 1;
+        place-self: center;
       }
-    "#},
-      Browsers {
-        safari: Some(4 << 16),
-        firefox: Some(4 << 16),
-        ie: Some(10 << 16),
-        ..Browsers::default()
+    "#
       },
     );
-    prefix_test(
+
+    test(
       r#"
       .foo {
-        -ms-flex-negative: 1;
-        -webkit-flex-shrink: 1;
-        flex-shrink: 1;
+        align-self: center;
+        justify-self: unsafe left;
       }
     "#,
       indoc! {r#"
       .foo {
-        flex-shrink: 1;
+        place-self: center unsafe left;
       }
-    "#},
-      Browsers {
-        safari: Some(11 << 16),
-        ..Browsers::default()
+    "#
       },
     );
-    prefix_test(
+
+    test(
       r#"
       .foo {
-        flex-basis: 1px;
+        align-items: center;
+        justify-items: center;
       }
     "#,
       indoc! {r#"
       .foo {
-        -ms-flex-preferred-size: 1px;
-        -webkit-flex-basis: 1px;
-        flex-basis: 1px;
+        place-items: center;
       }
-    "#},
-      Browsers {
-        safari: Some(4 << 16),
-        firefox: Some(4 << 16),
-        ie: Some(10 << 16),
-        ..Browsers::default()
+    "#
       },
     );
-    prefix_test(
+
+    test(
       r#"
       .foo {
-        -ms-flex-preferred-size: 1px;
-        -webkit-flex-basis: 1px;
-        flex-basis: 1px;
+        align-items: center;
+        justify-items: legacy left;
       }
     "#,
       indoc! {r#"
       .foo {
-        flex-basis: 1px;
+        place-items: center legacy left;
       }
-    "#},
-      Browsers {
-        safari: Some(11 << 16),
-        ..Browsers::default()
+    "#
       },
     );
-    prefix_test(
+
+    minify_test(".foo { justify-items: legacy }", ".foo{justify-items:legacy}");
+    minify_test(".foo { justify-items: LEGACY }", ".foo{justify-items:legacy}");
+    minify_test(
+      ".foo { justify-items: right legacy }",
+      ".foo{justify-items:legacy right}",
+    );
+    minify_test(".foo { place-items: center legacy }", ".foo{place-items:center legacy}");
+    minify_test(".foo { place-items: stretch stretch }", ".foo{place-items:stretch}");
+    minify_test(
+      ".foo { place-items: normal stretch }",
+      ".foo{place-items:normal stretch}",
+    );
+    minify_test(".foo { place-self: auto auto }", ".foo{place-self:auto}");
+    minify_test(".foo { place-self: center auto }", ".foo{place-self:center auto}");
+    minify_test(".foo { place-self: stretch stretch }", ".foo{place-self:stretch}");
+    minify_test(".foo { place-self: normal stretch }", ".foo{place-self:normal stretch}");
+
+    test(
       r#"
       .foo {
-        flex: 1;
+        place-items: center;
+        justify-items: var(--justify);
       }
     "#,
       indoc! {r#"
       .foo {
-        -webkit-box-flex: 1;
-        -moz-box-flex: 1;
-        -webkit-flex: 1;
-        -ms-flex: 1;
-        flex: 1;
+        place-items: center;
+        justify-items: var(--justify);
       }
-    "#},
-      Browsers {
-        safari: Some(4 << 16),
-        firefox: Some(4 << 16),
-        ie: Some(10 << 16),
-        ..Browsers::default()
+    "#
       },
     );
-    prefix_test(
+
+    test(
       r#"
       .foo {
-        -webkit-box-flex: 1;
-        -moz-box-flex: 1;
-        -webkit-flex: 1;
-        -ms-flex: 1;
-        flex: 1;
+        row-gap: 10px;
+        column-gap: 20px;
       }
     "#,
       indoc! {r#"
       .foo {
-        flex: 1;
+        gap: 10px 20px;
       }
-    "#},
-      Browsers {
-        safari: Some(11 << 16),
-        ..Browsers::default()
+    "#
       },
     );
-    prefix_test(
+
+    test(
       r#"
       .foo {
-        align-content: space-between;
+        row-gap: 10px;
+        column-gap: 10px;
       }
     "#,
       indoc! {r#"
       .foo {
-        -ms-flex-line-pack: justify;
-        -webkit-align-content: space-between;
-        align-content: space-between;
+        gap: 10px;
       }
-    "#},
-      Browsers {
-        safari: Some(4 << 16),
-        firefox: Some(4 << 16),
-        ie: Some(10 << 16),
-        ..Browsers::default()
+    "#
       },
     );
-    prefix_test(
+
+    test(
       r#"
       .foo {
-        -ms-flex-line-pack: justify;
-        -webkit-align-content: space-between;
-        align-content: space-between;
+        gap: 10px;
+        column-gap: 20px;
       }
     "#,
       indoc! {r#"
       .foo {
-        align-content: space-between;
+        gap: 10px 20px;
       }
-    "#},
-      Browsers {
-        safari: Some(11 << 16),
-        ..Browsers::default()
+    "#
       },
     );
-    prefix_test(
+
+    test(
       r#"
       .foo {
-        justify-content: space-between;
+        column-gap: 20px;
+        gap: 10px;
       }
     "#,
       indoc! {r#"
       .foo {
-        -webkit-box-pack: justify;
-        -moz-box-pack: justify;
-        -ms-flex-pack: justify;
-        -webkit-justify-content: space-between;
-        justify-content: space-between;
+        gap: 10px;
       }
-    "#},
-      Browsers {
-        safari: Some(4 << 16),
-        firefox: Some(4 << 16),
-        ie: Some(10 << 16),
-        ..Browsers::default()
+    "#
       },
     );
-    prefix_test(
+
+    test(
       r#"
       .foo {
-        -webkit-box-pack: justify;
-        -moz-box-pack: justify;
-        -ms-flex-pack: justify;
-        -webkit-justify-content: space-between;
-        justify-content: space-between;
+        row-gap: normal;
+        column-gap: 20px;
       }
     "#,
       indoc! {r#"
       .foo {
-        justify-content: space-between;
+        gap: normal 20px;
       }
-    "#},
-      Browsers {
-        safari: Some(11 << 16),
-        ..Browsers::default()
+    "#
       },
     );
-    prefix_test(
+
+    test(
       r#"
       .foo {
-        place-content: space-between flex-end;
+        -webkit-flex-grow: 1;
+        -webkit-flex-shrink: 1;
+        -webkit-flex-basis: auto;
       }
     "#,
       indoc! {r#"
       .foo {
-        -ms-flex-line-pack: justify;
-        -webkit-box-pack: end;
-        -moz-box-pack: end;
-        -ms-flex-pack: end;
-        -webkit-align-content: space-between;
-        align-content: space-between;
-        -webkit-justify-content: flex-end;
-        justify-content: flex-end;
+        -webkit-flex: auto;
       }
-    "#},
-      Browsers {
-        safari: Some(4 << 16),
-        firefox: Some(4 << 16),
-        ie: Some(10 << 16),
-        ..Browsers::default()
+    "#
+      },
+    );
+    test(
+      r#"
+      .foo {
+        -webkit-flex-grow: 1;
+        -webkit-flex-shrink: 1;
+        -webkit-flex-basis: auto;
+        flex-grow: 1;
+        flex-shrink: 1;
+        flex-basis: auto;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        -webkit-flex: auto;
+        flex: auto;
+      }
+    "#
       },
     );
     prefix_test(
       r#"
       .foo {
-        -ms-flex-line-pack: justify;
-        -webkit-box-pack: end;
-        -moz-box-pack: end;
-        -ms-flex-pack: end;
-        -webkit-align-content: space-between;
-        -webkit-justify-content: flex-end;
-        place-content: space-between flex-end;
+        -webkit-box-orient: horizontal;
+        -webkit-box-direction: normal;
+        flex-direction: row;
       }
     "#,
       indoc! {r#"
       .foo {
-        place-content: space-between flex-end;
+        -webkit-box-orient: horizontal;
+        -webkit-box-direction: normal;
+        -webkit-flex-direction: row;
+        flex-direction: row;
       }
     "#},
       Browsers {
-        safari: Some(11 << 16),
+        safari: Some(4 << 16),
         ..Browsers::default()
       },
     );
     prefix_test(
       r#"
       .foo {
-        place-content: space-between flex-end;
+        flex-direction: row;
       }
     "#,
       indoc! {r#"
       .foo {
-        align-content: space-between;
-        justify-content: flex-end;
+        -webkit-box-orient: horizontal;
+        -moz-box-orient: horizontal;
+        -webkit-box-direction: normal;
+        -moz-box-direction: normal;
+        -webkit-flex-direction: row;
+        -ms-flex-direction: row;
+        flex-direction: row;
       }
     "#},
       Browsers {
-        chrome: Some(30 << 16),
+        safari: Some(4 << 16),
+        firefox: Some(4 << 16),
+        ie: Some(10 << 16),
         ..Browsers::default()
       },
     );
     prefix_test(
       r#"
       .foo {
-        place-content: space-between flex-end;
+        -webkit-box-orient: horizontal;
+        -webkit-box-direction: normal;
+        -moz-box-orient: horizontal;
+        -moz-box-direction: normal;
+        -webkit-flex-direction: row;
+        -ms-flex-direction: row;
+        flex-direction: row;
       }
     "#,
       indoc! {r#"
       .foo {
-        place-content: space-between flex-end;
+        flex-direction: row;
       }
     "#},
       Browsers {
-        chrome: Some(60 << 16),
+        safari: Some(14 << 16),
         ..Browsers::default()
       },
     );
     prefix_test(
       r#"
       .foo {
-        align-self: flex-end;
+        flex-wrap: wrap;
       }
     "#,
       indoc! {r#"
       .foo {
-        -ms-flex-item-align: end;
-        -webkit-align-self: flex-end;
-        align-self: flex-end;
+        -webkit-box-lines: multiple;
+        -moz-box-lines: multiple;
+        -webkit-flex-wrap: wrap;
+        -ms-flex-wrap: wrap;
+        flex-wrap: wrap;
       }
     "#},
       Browsers {
@@ -5603,14 +6391,16 @@ mod tests {
     prefix_test(
       r#"
       .foo {
-        -ms-flex-item-align: end;
-        -webkit-align-self: flex-end;
-        align-self: flex-end;
+        -webkit-box-lines: multiple;
+        -moz-box-lines: multiple;
+        -webkit-flex-wrap: wrap;
+        -ms-flex-wrap: wrap;
+        flex-wrap: wrap;
       }
     "#,
       indoc! {r#"
       .foo {
-        align-self: flex-end;
+        flex-wrap: wrap;
       }
     "#},
       Browsers {
@@ -5621,15 +6411,18 @@ mod tests {
     prefix_test(
       r#"
       .foo {
-        place-self: center flex-end;
+        flex-flow: row wrap;
       }
     "#,
       indoc! {r#"
       .foo {
-        -ms-flex-item-align: center;
-        -webkit-align-self: center;
-        align-self: center;
-        justify-self: flex-end;
+        -webkit-box-orient: horizontal;
+        -moz-box-orient: horizontal;
+        -webkit-box-direction: normal;
+        -moz-box-direction: normal;
+        -webkit-flex-flow: wrap;
+        -ms-flex-flow: wrap;
+        flex-flow: wrap;
       }
     "#},
       Browsers {
@@ -5642,14 +6435,18 @@ mod tests {
     prefix_test(
       r#"
       .foo {
-        -ms-flex-item-align: center;
-        -webkit-align-self: center;
-        place-self: center flex-end;
+        -webkit-box-orient: horizontal;
+        -moz-box-orient: horizontal;
+        -webkit-box-direction: normal;
+        -moz-box-direction: normal;
+        -webkit-flex-flow: wrap;
+        -ms-flex-flow: wrap;
+        flex-flow: wrap;
       }
     "#,
       indoc! {r#"
       .foo {
-        place-self: center flex-end;
+        flex-flow: wrap;
       }
     "#},
       Browsers {
@@ -5660,49 +6457,56 @@ mod tests {
     prefix_test(
       r#"
       .foo {
-        place-self: center flex-end;
+        flex-grow: 1;
       }
     "#,
       indoc! {r#"
       .foo {
-        align-self: center;
-        justify-self: flex-end;
+        -webkit-box-flex: 1;
+        -moz-box-flex: 1;
+        -ms-flex-positive: 1;
+        -webkit-flex-grow: 1;
+        flex-grow: 1;
       }
     "#},
       Browsers {
-        chrome: Some(57 << 16),
+        safari: Some(4 << 16),
+        firefox: Some(4 << 16),
+        ie: Some(10 << 16),
         ..Browsers::default()
       },
     );
     prefix_test(
       r#"
       .foo {
-        place-self: center flex-end;
+        -webkit-box-flex: 1;
+        -moz-box-flex: 1;
+        -ms-flex-positive: 1;
+        -webkit-flex-grow: 1;
+        flex-grow: 1;
       }
     "#,
       indoc! {r#"
       .foo {
-        place-self: center flex-end;
+        flex-grow: 1;
       }
     "#},
       Browsers {
-        chrome: Some(59 << 16),
+        safari: Some(11 << 16),
         ..Browsers::default()
       },
     );
     prefix_test(
       r#"
       .foo {
-        align-items: flex-end;
+        flex-shrink: 1;
       }
     "#,
       indoc! {r#"
       .foo {
-        -webkit-box-align: end;
-        -moz-box-align: end;
-        -ms-flex-align: end;
-        -webkit-align-items: flex-end;
-        align-items: flex-end;
+        -ms-flex-negative: 1;
+        -webkit-flex-shrink: 1;
+        flex-shrink: 1;
       }
     "#},
       Browsers {
@@ -5715,16 +6519,14 @@ mod tests {
     prefix_test(
       r#"
       .foo {
-        -webkit-box-align: end;
-        -moz-box-align: end;
-        -ms-flex-align: end;
-        -webkit-align-items: flex-end;
-        align-items: flex-end;
+        -ms-flex-negative: 1;
+        -webkit-flex-shrink: 1;
+        flex-shrink: 1;
       }
     "#,
       indoc! {r#"
       .foo {
-        align-items: flex-end;
+        flex-shrink: 1;
       }
     "#},
       Browsers {
@@ -5735,17 +6537,14 @@ mod tests {
     prefix_test(
       r#"
       .foo {
-        place-items: flex-end center;
+        flex-basis: 1px;
       }
     "#,
       indoc! {r#"
       .foo {
-        -webkit-box-align: end;
-        -moz-box-align: end;
-        -ms-flex-align: end;
-        -webkit-align-items: flex-end;
-        align-items: flex-end;
-        justify-items: center;
+        -ms-flex-preferred-size: 1px;
+        -webkit-flex-basis: 1px;
+        flex-basis: 1px;
       }
     "#},
       Browsers {
@@ -5758,16 +6557,14 @@ mod tests {
     prefix_test(
       r#"
       .foo {
-        -webkit-box-align: end;
-        -moz-box-align: end;
-        -ms-flex-align: end;
-        -webkit-align-items: flex-end;
-        place-items: flex-end center;
+        -ms-flex-preferred-size: 1px;
+        -webkit-flex-basis: 1px;
+        flex-basis: 1px;
       }
     "#,
       indoc! {r#"
       .foo {
-        place-items: flex-end center;
+        flex-basis: 1px;
       }
     "#},
       Browsers {
@@ -5778,33 +6575,56 @@ mod tests {
     prefix_test(
       r#"
       .foo {
-        place-items: flex-end center;
+        flex: 1;
       }
     "#,
       indoc! {r#"
       .foo {
-        align-items: flex-end;
-        justify-items: center;
+        -webkit-box-flex: 1;
+        -moz-box-flex: 1;
+        -webkit-flex: 1;
+        -ms-flex: 1;
+        flex: 1;
       }
     "#},
       Browsers {
-        safari: Some(10 << 16),
+        safari: Some(4 << 16),
+        firefox: Some(4 << 16),
+        ie: Some(10 << 16),
         ..Browsers::default()
       },
     );
     prefix_test(
       r#"
       .foo {
-        order: 1;
+        -webkit-box-flex: 1;
+        -moz-box-flex: 1;
+        -webkit-flex: 1;
+        -ms-flex: 1;
+        flex: 1;
       }
     "#,
       indoc! {r#"
       .foo {
-        -webkit-box-ordinal-group: 1;
-        -moz-box-ordinal-group: 1;
-        -ms-flex-order: 1;
-        -webkit-order: 1;
-        order: 1;
+        flex: 1;
+      }
+    "#},
+      Browsers {
+        safari: Some(11 << 16),
+        ..Browsers::default()
+      },
+    );
+    prefix_test(
+      r#"
+      .foo {
+        align-content: space-between;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        -ms-flex-line-pack: justify;
+        -webkit-align-content: space-between;
+        align-content: space-between;
       }
     "#},
       Browsers {
@@ -5817,16 +6637,14 @@ mod tests {
     prefix_test(
       r#"
       .foo {
-        -webkit-box-ordinal-group: 1;
-        -moz-box-ordinal-group: 1;
-        -ms-flex-order: 1;
-        -webkit-order: 1;
-        order: 1;
+        -ms-flex-line-pack: justify;
+        -webkit-align-content: space-between;
+        align-content: space-between;
       }
     "#,
       indoc! {r#"
       .foo {
-        order: 1;
+        align-content: space-between;
       }
     "#},
       Browsers {
@@ -5837,1762 +6655,1861 @@ mod tests {
     prefix_test(
       r#"
       .foo {
-        -ms-flex: 0 0 8%;
-        flex: 0 0 5%;
+        justify-content: space-between;
       }
     "#,
       indoc! {r#"
       .foo {
-        -ms-flex: 0 0 8%;
-        flex: 0 0 5%;
+        -webkit-box-pack: justify;
+        -moz-box-pack: justify;
+        -ms-flex-pack: justify;
+        -webkit-justify-content: space-between;
+        justify-content: space-between;
       }
     "#},
       Browsers {
-        safari: Some(11 << 16),
+        safari: Some(4 << 16),
+        firefox: Some(4 << 16),
+        ie: Some(10 << 16),
         ..Browsers::default()
       },
     );
-  }
-
-  #[test]
-  fn test_font() {
-    test(
+    prefix_test(
       r#"
       .foo {
-        font-family: "Helvetica", "Times New Roman", sans-serif;
-        font-size: 12px;
-        font-weight: bold;
-        font-style: italic;
-        font-stretch: expanded;
-        font-variant-caps: small-caps;
-        line-height: 1.2em;
+        -webkit-box-pack: justify;
+        -moz-box-pack: justify;
+        -ms-flex-pack: justify;
+        -webkit-justify-content: space-between;
+        justify-content: space-between;
       }
     "#,
       indoc! {r#"
       .foo {
-        font: italic small-caps bold expanded 12px / 1.2em Helvetica, Times New Roman, sans-serif;
+        justify-content: space-between;
       }
-    "#
+    "#},
+      Browsers {
+        safari: Some(11 << 16),
+        ..Browsers::default()
       },
     );
-
-    minify_test(
+    prefix_test(
       r#"
       .foo {
-        font-family: "Helvetica", "Times New Roman", sans-serif;
-        font-size: 12px;
-        font-weight: bold;
-        font-style: italic;
-        font-stretch: expanded;
-        font-variant-caps: small-caps;
-        line-height: 1.2em;
+        place-content: space-between flex-end;
       }
     "#,
-      indoc! {".foo{font:italic small-caps 700 125% 12px/1.2em Helvetica,Times New Roman,sans-serif}"
+      indoc! {r#"
+      .foo {
+        -ms-flex-line-pack: justify;
+        -webkit-box-pack: end;
+        -moz-box-pack: end;
+        -ms-flex-pack: end;
+        -webkit-align-content: space-between;
+        align-content: space-between;
+        -webkit-justify-content: flex-end;
+        justify-content: flex-end;
+      }
+    "#},
+      Browsers {
+        safari: Some(4 << 16),
+        firefox: Some(4 << 16),
+        ie: Some(10 << 16),
+        ..Browsers::default()
       },
     );
-
-    test(
+    prefix_test(
       r#"
       .foo {
-        font: 12px "Helvetica", "Times New Roman", sans-serif;
-        line-height: 1.2em;
+        -ms-flex-line-pack: justify;
+        -webkit-box-pack: end;
+        -moz-box-pack: end;
+        -ms-flex-pack: end;
+        -webkit-align-content: space-between;
+        -webkit-justify-content: flex-end;
+        place-content: space-between flex-end;
       }
     "#,
       indoc! {r#"
       .foo {
-        font: 12px / 1.2em Helvetica, Times New Roman, sans-serif;
+        place-content: space-between flex-end;
       }
-    "#
+    "#},
+      Browsers {
+        safari: Some(11 << 16),
+        ..Browsers::default()
       },
     );
-
-    test(
+    prefix_test(
       r#"
       .foo {
-        font: 12px "Helvetica", "Times New Roman", sans-serif;
-        line-height: var(--lh);
+        place-content: space-between flex-end;
       }
     "#,
       indoc! {r#"
       .foo {
-        font: 12px Helvetica, Times New Roman, sans-serif;
-        line-height: var(--lh);
+        align-content: space-between;
+        justify-content: flex-end;
       }
-    "#
+    "#},
+      Browsers {
+        chrome: Some(30 << 16),
+        ..Browsers::default()
       },
     );
-
-    minify_test(
+    prefix_test(
       r#"
       .foo {
-        font-family: "Helvetica", "Times New Roman", sans-serif;
-        font-size: 12px;
-        font-stretch: expanded;
+        place-content: space-between flex-end;
       }
     "#,
-      indoc! {".foo{font-family:Helvetica,Times New Roman,sans-serif;font-size:12px;font-stretch:125%}"
+      indoc! {r#"
+      .foo {
+        place-content: space-between flex-end;
+      }
+    "#},
+      Browsers {
+        chrome: Some(60 << 16),
+        ..Browsers::default()
       },
     );
-
-    test(
+    prefix_test(
       r#"
       .foo {
-        font-family: "Helvetica", "Times New Roman", sans-serif;
-        font-size: 12px;
-        font-weight: bold;
-        font-style: italic;
-        font-stretch: expanded;
-        font-variant-caps: all-small-caps;
-        line-height: 1.2em;
+        align-self: flex-end;
       }
     "#,
       indoc! {r#"
       .foo {
-        font: italic bold expanded 12px / 1.2em Helvetica, Times New Roman, sans-serif;
-        font-variant-caps: all-small-caps;
+        -ms-flex-item-align: end;
+        -webkit-align-self: flex-end;
+        align-self: flex-end;
       }
-    "#
-      },
-    );
-
-    minify_test(
-      ".foo { font: normal normal 600 9px/normal Charcoal; }",
-      ".foo{font:600 9px Charcoal}",
-    );
-    minify_test(
-      ".foo { font: normal normal 500 medium/normal Charcoal; }",
-      ".foo{font:500 medium Charcoal}",
-    );
-    minify_test(
-      ".foo { font: normal normal 400 medium Charcoal; }",
-      ".foo{font:400 medium Charcoal}",
-    );
-    minify_test(
-      ".foo { font: normal normal 500 medium/10px Charcoal; }",
-      ".foo{font:500 medium/10px Charcoal}",
-    );
-    minify_test(
-      ".foo { font-family: 'sans-serif'; }",
-      ".foo{font-family:\"sans-serif\"}",
-    );
-    minify_test(".foo { font-family: sans-serif; }", ".foo{font-family:sans-serif}");
-    minify_test(".foo { font-family: 'default'; }", ".foo{font-family:\"default\"}");
-    minify_test(".foo { font-family: default; }", ".foo{font-family:default}");
-    minify_test(".foo { font-family: 'inherit'; }", ".foo{font-family:\"inherit\"}");
-    minify_test(".foo { font-family: inherit; }", ".foo{font-family:inherit}");
-    minify_test(".foo { font-family: inherit test; }", ".foo{font-family:inherit test}");
-    minify_test(
-      ".foo { font-family: 'inherit test'; }",
-      ".foo{font-family:inherit test}",
-    );
-    minify_test(".foo { font-family: revert; }", ".foo{font-family:revert}");
-    minify_test(".foo { font-family: 'revert'; }", ".foo{font-family:\"revert\"}");
-    minify_test(".foo { font-family: revert-layer; }", ".foo{font-family:revert-layer}");
-    minify_test(
-      ".foo { font-family: revert-layer, serif; }",
-      ".foo{font-family:revert-layer,serif}",
-    );
-    minify_test(
-      ".foo { font-family: 'revert', sans-serif; }",
-      ".foo{font-family:\"revert\",sans-serif}",
-    );
-    minify_test(
-      ".foo { font-family: 'revert', foo, sans-serif; }",
-      ".foo{font-family:\"revert\",foo,sans-serif}",
-    );
-    minify_test(".foo { font-family: ''; }", ".foo{font-family:\"\"}");
-
-    // font-family in @font-face
-    minify_test(
-      "@font-face { font-family: 'revert'; }",
-      "@font-face{font-family:\"revert\"}",
-    );
-    minify_test(
-      "@font-face { font-family: 'revert-layer'; }",
-      "@font-face{font-family:\"revert-layer\"}",
+    "#},
+      Browsers {
+        safari: Some(4 << 16),
+        firefox: Some(4 << 16),
+        ie: Some(10 << 16),
+        ..Browsers::default()
+      },
     );
-
     prefix_test(
       r#"
       .foo {
-        font-family: Helvetica, system-ui, sans-serif;
+        -ms-flex-item-align: end;
+        -webkit-align-self: flex-end;
+        align-self: flex-end;
       }
     "#,
       indoc! {r#"
       .foo {
-        font-family: Helvetica, system-ui, -apple-system, BlinkMacSystemFont, Segoe UI, Roboto, Noto Sans, Ubuntu, Cantarell, Helvetica Neue, sans-serif;
+        align-self: flex-end;
       }
-    "#
-      },
+    "#},
       Browsers {
-        safari: Some(8 << 16),
+        safari: Some(11 << 16),
         ..Browsers::default()
       },
     );
-
     prefix_test(
       r#"
       .foo {
-        font: 100%/1.5 Helvetica, system-ui, sans-serif;
+        place-self: center flex-end;
       }
     "#,
       indoc! {r#"
       .foo {
-        font: 100% / 1.5 Helvetica, system-ui, -apple-system, BlinkMacSystemFont, Segoe UI, Roboto, Noto Sans, Ubuntu, Cantarell, Helvetica Neue, sans-serif;
+        -ms-flex-item-align: center;
+        -webkit-align-self: center;
+        align-self: center;
+        justify-self: flex-end;
       }
-    "#
-      },
+    "#},
       Browsers {
-        safari: Some(8 << 16),
+        safari: Some(4 << 16),
+        firefox: Some(4 << 16),
+        ie: Some(10 << 16),
         ..Browsers::default()
       },
     );
-
     prefix_test(
       r#"
       .foo {
-        font-family: ui-sans-serif, system-ui, -apple-system, BlinkMacSystemFont, "Segoe UI", Roboto, "Helvetica Neue", Arial, "Noto Sans", sans-serif, "Apple Color Emoji", "Segoe UI Emoji", "Segoe UI Symbol", "Noto Color Emoji";
+        -ms-flex-item-align: center;
+        -webkit-align-self: center;
+        place-self: center flex-end;
       }
     "#,
       indoc! {r#"
       .foo {
-        font-family: ui-sans-serif, system-ui, -apple-system, BlinkMacSystemFont, Segoe UI, Roboto, Noto Sans, Ubuntu, Cantarell, Helvetica Neue, Arial, sans-serif, Apple Color Emoji, Segoe UI Emoji, Segoe UI Symbol, Noto Color Emoji;
+        place-self: center flex-end;
       }
-    "#
-      },
+    "#},
       Browsers {
-        firefox: Some(91 << 16),
+        safari: Some(11 << 16),
         ..Browsers::default()
       },
     );
-
     prefix_test(
       r#"
       .foo {
-        font-size: 22px;
-        font-size: max(2cqw, 22px);
+        place-self: center flex-end;
       }
     "#,
       indoc! {r#"
       .foo {
-        font-size: 22px;
-        font-size: max(2cqw, 22px);
+        align-self: center;
+        justify-self: flex-end;
       }
-    "#
-      },
+    "#},
       Browsers {
-        safari: Some(14 << 16),
+        chrome: Some(57 << 16),
         ..Browsers::default()
       },
     );
     prefix_test(
       r#"
       .foo {
-        font-size: 22px;
-        font-size: max(2cqw, 22px);
+        place-self: center flex-end;
       }
     "#,
       indoc! {r#"
       .foo {
-        font-size: max(2cqw, 22px);
+        place-self: center flex-end;
       }
-    "#
-      },
+    "#},
       Browsers {
-        safari: Some(16 << 16),
+        chrome: Some(59 << 16),
         ..Browsers::default()
       },
     );
-
     prefix_test(
       r#"
       .foo {
-        font-size: 22px;
-        font-size: xxx-large;
+        align-items: flex-end;
       }
     "#,
       indoc! {r#"
       .foo {
-        font-size: 22px;
-        font-size: xxx-large;
+        -webkit-box-align: end;
+        -moz-box-align: end;
+        -ms-flex-align: end;
+        -webkit-align-items: flex-end;
+        align-items: flex-end;
       }
-    "#
-      },
+    "#},
       Browsers {
-        chrome: Some(70 << 16),
+        safari: Some(4 << 16),
+        firefox: Some(4 << 16),
+        ie: Some(10 << 16),
         ..Browsers::default()
       },
     );
     prefix_test(
       r#"
       .foo {
-        font-size: 22px;
-        font-size: xxx-large;
+        -webkit-box-align: end;
+        -moz-box-align: end;
+        -ms-flex-align: end;
+        -webkit-align-items: flex-end;
+        align-items: flex-end;
       }
     "#,
       indoc! {r#"
       .foo {
-        font-size: xxx-large;
+        align-items: flex-end;
       }
-    "#
-      },
+    "#},
       Browsers {
-        chrome: Some(80 << 16),
+        safari: Some(11 << 16),
         ..Browsers::default()
       },
     );
-
     prefix_test(
       r#"
       .foo {
-        font-weight: 700;
-        font-weight: 789;
+        place-items: flex-end center;
       }
     "#,
       indoc! {r#"
       .foo {
-        font-weight: 700;
-        font-weight: 789;
+        -webkit-box-align: end;
+        -moz-box-align: end;
+        -ms-flex-align: end;
+        -webkit-align-items: flex-end;
+        align-items: flex-end;
+        justify-items: center;
       }
-    "#
-      },
+    "#},
       Browsers {
-        chrome: Some(60 << 16),
+        safari: Some(4 << 16),
+        firefox: Some(4 << 16),
+        ie: Some(10 << 16),
         ..Browsers::default()
       },
     );
     prefix_test(
       r#"
       .foo {
-        font-weight: 700;
-        font-weight: 789;
+        -webkit-box-align: end;
+        -moz-box-align: end;
+        -ms-flex-align: end;
+        -webkit-align-items: flex-end;
+        place-items: flex-end center;
       }
     "#,
       indoc! {r#"
       .foo {
-        font-weight: 789;
+        place-items: flex-end center;
       }
-    "#
-      },
+    "#},
       Browsers {
-        chrome: Some(80 << 16),
+        safari: Some(11 << 16),
         ..Browsers::default()
       },
     );
-
     prefix_test(
       r#"
       .foo {
-        font-family: Helvetica;
-        font-family: system-ui;
+        place-items: flex-end center;
       }
     "#,
       indoc! {r#"
       .foo {
-        font-family: Helvetica;
-        font-family: system-ui;
+        align-items: flex-end;
+        justify-items: center;
       }
-    "#
-      },
+    "#},
       Browsers {
-        chrome: Some(50 << 16),
+        safari: Some(10 << 16),
         ..Browsers::default()
       },
     );
     prefix_test(
       r#"
       .foo {
-        font-family: Helvetica;
-        font-family: system-ui;
+        order: 1;
       }
     "#,
       indoc! {r#"
       .foo {
-        font-family: system-ui;
+        -webkit-box-ordinal-group: 1;
+        -moz-box-ordinal-group: 1;
+        -ms-flex-order: 1;
+        -webkit-order: 1;
+        order: 1;
       }
-    "#
-      },
+    "#},
       Browsers {
-        chrome: Some(80 << 16),
+        safari: Some(4 << 16),
+        firefox: Some(4 << 16),
+        ie: Some(10 << 16),
         ..Browsers::default()
       },
     );
-
     prefix_test(
       r#"
       .foo {
-        font-style: oblique;
-        font-style: oblique 40deg;
+        -webkit-box-ordinal-group: 1;
+        -moz-box-ordinal-group: 1;
+        -ms-flex-order: 1;
+        -webkit-order: 1;
+        order: 1;
       }
     "#,
       indoc! {r#"
       .foo {
-        font-style: oblique;
-        font-style: oblique 40deg;
+        order: 1;
       }
-    "#
-      },
+    "#},
       Browsers {
-        firefox: Some(50 << 16),
+        safari: Some(11 << 16),
         ..Browsers::default()
       },
     );
     prefix_test(
       r#"
       .foo {
-        font-style: oblique;
-        font-style: oblique 40deg;
+        -ms-flex: 0 0 8%;
+        flex: 0 0 5%;
       }
     "#,
       indoc! {r#"
       .foo {
-        font-style: oblique 40deg;
+        -ms-flex: 0 0 8%;
+        flex: 0 0 5%;
       }
-    "#
-      },
+    "#},
       Browsers {
-        firefox: Some(80 << 16),
+        safari: Some(11 << 16),
         ..Browsers::default()
       },
     );
+  }
 
-    prefix_test(
+  #[test]
+  fn test_font() {
+    test(
       r#"
       .foo {
-        font: 22px Helvetica;
-        font: xxx-large system-ui;
-      }
-    "#,
-      indoc! {r#"
-      .foo {
-        font: 22px Helvetica;
-        font: xxx-large system-ui;
+        font-family: "Helvetica", "Times New Roman", sans-serif;
+        font-size: 12px;
+        font-weight: bold;
+        font-style: italic;
+        font-stretch: expanded;
+        font-variant-caps: small-caps;
+        line-height: 1.2em;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        font: italic small-caps bold expanded 12px / 1.2em Helvetica, Times New Roman, sans-serif;
       }
     "#
       },
-      Browsers {
-        chrome: Some(70 << 16),
-        ..Browsers::default()
+    );
+
+    minify_test(
+      r#"
+      .foo {
+        font-family: "Helvetica", "Times New Roman", sans-serif;
+        font-size: 12px;
+        font-weight: bold;
+        font-style: italic;
+        font-stretch: expanded;
+        font-variant-caps: small-caps;
+        line-height: 1.2em;
+      }
+    "#,
+      indoc! {".foo{font:italic small-caps 700 125% 12px/1.2em Helvetica,Times New Roman,sans-serif}"
       },
     );
-    prefix_test(
+
+    test(
       r#"
       .foo {
-        font: 22px Helvetica;
-        font: xxx-large system-ui;
+        font: 12px "Helvetica", "Times New Roman", sans-serif;
+        line-height: 1.2em;
       }
     "#,
       indoc! {r#"
       .foo {
-        font: xxx-large system-ui;
+        font: 12px / 1.2em Helvetica, Times New Roman, sans-serif;
       }
     "#
       },
-      Browsers {
-        chrome: Some(80 << 16),
-        ..Browsers::default()
-      },
     );
 
-    prefix_test(
+    test(
       r#"
       .foo {
-        font: var(--fallback);
-        font: xxx-large system-ui;
+        font: 12px "Helvetica", "Times New Roman", sans-serif;
+        line-height: var(--lh);
       }
     "#,
       indoc! {r#"
       .foo {
-        font: var(--fallback);
-        font: xxx-large system-ui;
+        font: 12px Helvetica, Times New Roman, sans-serif;
+        line-height: var(--lh);
       }
     "#
       },
-      Browsers {
-        chrome: Some(50 << 16),
-        ..Browsers::default()
+    );
+
+    minify_test(
+      r#"
+      .foo {
+        font-family: "Helvetica", "Times New Roman", sans-serif;
+        font-size: 12px;
+        font-stretch: expanded;
+      }
+    "#,
+      indoc! {".foo{font-family:Helvetica,Times New Roman,sans-serif;font-size:12px;font-stretch:125%}"
       },
     );
-  }
 
-  #[test]
-  fn test_vertical_align() {
-    minify_test(".foo { vertical-align: middle }", ".foo{vertical-align:middle}");
-    minify_test(".foo { vertical-align: 0.3em }", ".foo{vertical-align:.3em}");
-  }
+    test(
+      r#"
+      .foo {
+        font-family: "Helvetica", "Times New Roman", sans-serif;
+        font-size: 12px;
+        font-weight: bold;
+        font-style: italic;
+        font-stretch: expanded;
+        font-variant-caps: all-small-caps;
+        line-height: 1.2em;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        font: italic bold expanded 12px / 1.2em Helvetica, Times New Roman, sans-serif;
+        font-variant-caps: all-small-caps;
+      }
+    "#
+      },
+    );
 
-  #[test]
-  fn test_selectors() {
-    minify_test(":nth-col(2n) {width: 20px}", ":nth-col(2n){width:20px}");
-    minify_test(":nth-col(10n-1) {width: 20px}", ":nth-col(10n-1){width:20px}");
-    minify_test(":nth-col(-n+2) {width: 20px}", ":nth-col(-n+2){width:20px}");
-    minify_test(":nth-col(even) {width: 20px}", ":nth-col(2n){width:20px}");
-    minify_test(":nth-col(odd) {width: 20px}", ":nth-col(odd){width:20px}");
-    minify_test(":nth-last-col(2n) {width: 20px}", ":nth-last-col(2n){width:20px}");
-    minify_test(":nth-last-col(10n-1) {width: 20px}", ":nth-last-col(10n-1){width:20px}");
-    minify_test(":nth-last-col(-n+2) {width: 20px}", ":nth-last-col(-n+2){width:20px}");
-    minify_test(":nth-last-col(even) {width: 20px}", ":nth-last-col(2n){width:20px}");
-    minify_test(":nth-last-col(odd) {width: 20px}", ":nth-last-col(odd){width:20px}");
-    minify_test(":nth-child(odd) {width: 20px}", ":nth-child(odd){width:20px}");
-    minify_test(":nth-child(2n) {width: 20px}", ":nth-child(2n){width:20px}");
-    minify_test(":nth-child(2n+1) {width: 20px}", ":nth-child(odd){width:20px}");
-    minify_test(":first-child {width: 20px}", ":first-child{width:20px}");
-    minify_test(":nth-child(1) {width: 20px}", ":first-child{width:20px}");
-    minify_test(":nth-last-child(1) {width: 20px}", ":last-child{width:20px}");
-    minify_test(":nth-of-type(1) {width: 20px}", ":first-of-type{width:20px}");
-    minify_test(":nth-last-of-type(1) {width: 20px}", ":last-of-type{width:20px}");
     minify_test(
-      ":nth-child(even of li.important) {width: 20px}",
-      ":nth-child(2n of li.important){width:20px}",
+      ".foo { font: normal normal 600 9px/normal Charcoal; }",
+      ".foo{font:600 9px Charcoal}",
     );
     minify_test(
-      ":nth-child(1 of li.important) {width: 20px}",
-      ":nth-child(1 of li.important){width:20px}",
+      ".foo { font: normal normal 500 medium/normal Charcoal; }",
+      ".foo{font:500 medium Charcoal}",
     );
     minify_test(
-      ":nth-last-child(even of li.important) {width: 20px}",
-      ":nth-last-child(2n of li.important){width:20px}",
+      ".foo { font: normal normal 400 medium Charcoal; }",
+      ".foo{font:400 medium Charcoal}",
     );
     minify_test(
-      ":nth-last-child(1 of li.important) {width: 20px}",
-      ":nth-last-child(1 of li.important){width:20px}",
+      ".foo { font: normal normal 500 medium/10px Charcoal; }",
+      ".foo{font:500 medium/10px Charcoal}",
     );
     minify_test(
-      ":nth-last-child(1 of.important) {width: 20px}",
-      ":nth-last-child(1 of .important){width:20px}",
+      ".foo { font-variant-position: super }",
+      ".foo{font-variant-position:super}",
     );
-
-    minify_test("[foo=\"baz\"] {color:red}", "[foo=baz]{color:red}");
-    minify_test("[foo=\"foo bar\"] {color:red}", "[foo=foo\\ bar]{color:red}");
-    minify_test("[foo=\"foo bar baz\"] {color:red}", "[foo=\"foo bar baz\"]{color:red}");
-    minify_test("[foo=\"\"] {color:red}", "[foo=\"\"]{color:red}");
+    minify_test(".foo { font-variant-position: SUB }", ".foo{font-variant-position:sub}");
     minify_test(
-      ".test:not([foo=\"bar\"]) {color:red}",
-      ".test:not([foo=bar]){color:red}",
+      ".foo { font-feature-settings: NORMAL }",
+      ".foo{font-feature-settings:normal}",
     );
-    minify_test(".test + .foo {color:red}", ".test+.foo{color:red}");
-    minify_test(".test ~ .foo {color:red}", ".test~.foo{color:red}");
-    minify_test(".test .foo {color:red}", ".test .foo{color:red}");
     minify_test(
-      ".custom-range::-webkit-slider-thumb:active {color:red}",
-      ".custom-range::-webkit-slider-thumb:active{color:red}",
+      ".foo { font-feature-settings: \"liga\" 1, \"smcp\" on, \"swsh\" 2 }",
+      ".foo{font-feature-settings:\"liga\",\"smcp\",\"swsh\" 2}",
     );
-    minify_test(".test:not(.foo, .bar) {color:red}", ".test:not(.foo,.bar){color:red}");
-    minify_test(".test:is(.foo, .bar) {color:red}", ".test:is(.foo,.bar){color:red}");
     minify_test(
-      ".test:where(.foo, .bar) {color:red}",
-      ".test:where(.foo,.bar){color:red}",
+      ".foo { font-feature-settings: \"liga\" OFF, \"kern\" }",
+      ".foo{font-feature-settings:\"liga\" 0,\"kern\"}",
     );
     minify_test(
-      ".test:where(.foo, .bar) {color:red}",
-      ".test:where(.foo,.bar){color:red}",
+      ".foo { font-feature-settings: \"liga\" 0, \"kern\", \"liga\" }",
+      ".foo{font-feature-settings:\"kern\",\"liga\"}",
     );
-    minify_test(":host {color:red}", ":host{color:red}");
-    minify_test(":host(.foo) {color:red}", ":host(.foo){color:red}");
-    minify_test("::slotted(span) {color:red", "::slotted(span){color:red}");
+    minify_test(".foo { font-feature-settings: \"lig\" }", ".foo{font-feature-settings:\"lig\"}");
     minify_test(
-      "custom-element::part(foo) {color:red}",
-      "custom-element::part(foo){color:red}",
+      ".foo { font-feature-settings: \"liga\" -1 }",
+      ".foo{font-feature-settings:\"liga\" -1}",
     );
-    minify_test(".sm\\:text-5xl { font-size: 3rem }", ".sm\\:text-5xl{font-size:3rem}");
-    minify_test("a:has(> img) {color:red}", "a:has(>img){color:red}");
-    minify_test("dt:has(+ dt) {color:red}", "dt:has(+dt){color:red}");
     minify_test(
-      "section:not(:has(h1, h2, h3, h4, h5, h6)) {color:red}",
-      "section:not(:has(h1,h2,h3,h4,h5,h6)){color:red}",
+      ".foo { font-feature-settings: \"liga\" 1.5 }",
+      ".foo{font-feature-settings:\"liga\" 1.5}",
     );
+    minify_test(".foo { font-feature-settings: liga }", ".foo{font-feature-settings:liga}");
     minify_test(
-      ":has(.sibling ~ .target) {color:red}",
-      ":has(.sibling~.target){color:red}",
+      ".foo { font-variation-settings: normal }",
+      ".foo{font-variation-settings:normal}",
     );
-    minify_test(".x:has(> .a > .b) {color:red}", ".x:has(>.a>.b){color:red}");
-    minify_test(".x:has(.bar, #foo) {color:red}", ".x:has(.bar,#foo){color:red}");
-    minify_test(".x:has(span + span) {color:red}", ".x:has(span+span){color:red}");
-    minify_test("a:has(:visited) {color:red}", "a:has(:visited){color:red}");
-    for element in [
-      "-webkit-scrollbar",
-      "-webkit-scrollbar-button",
-      "-webkit-scrollbar-track",
-      "-webkit-scrollbar-track-piece",
-      "-webkit-scrollbar-thumb",
-      "-webkit-scrollbar-corner",
-      "-webkit-resizer",
-    ] {
-      for class in [
-        "enabled",
-        "disabled",
-        "hover",
-        "active",
-        "horizontal",
-        "vertical",
-        "decrement",
-        "increment",
-        "start",
-        "end",
-        "double-button",
-        "single-button",
-        "no-button",
-        "corner-present",
-        "window-inactive",
-      ] {
-        minify_test(
-          &format!("::{}:{} {{color:red}}", element, class),
-          &format!("::{}:{}{{color:red}}", element, class),
-        );
-      }
-    }
-    for class in [
-      "horizontal",
-      "vertical",
-      "decrement",
-      "increment",
-      "start",
-      "end",
-      "double-button",
-      "single-button",
-      "no-button",
-      "corner-present",
-      "window-inactive",
-    ] {
-      error_test(
-        &format!(":{} {{color:red}}", class),
-        ParserError::SelectorError(SelectorError::InvalidPseudoClassBeforeWebKitScrollbar),
-      );
-    }
-    for element in [
-      "-webkit-scrollbar",
-      "-webkit-scrollbar-button",
-      "-webkit-scrollbar-track",
-      "-webkit-scrollbar-track-piece",
-      "-webkit-scrollbar-thumb",
-      "-webkit-scrollbar-corner",
-      "-webkit-resizer",
-    ] {
-      error_test(
-        &format!("::{}:focus {{color:red}}", element),
-        ParserError::SelectorError(SelectorError::InvalidPseudoClassAfterWebKitScrollbar),
-      );
-    }
-
-    error_test(
-      "a::first-letter:last-child {color:red}",
-      ParserError::SelectorError(SelectorError::InvalidPseudoClassAfterPseudoElement),
+    minify_test(
+      ".foo { font-variation-settings: \"wght\" 400, \"wdth\" 1.0 }",
+      ".foo{font-variation-settings:\"wght\" 400,\"wdth\" 1}",
     );
     minify_test(
-      "a:last-child::first-letter {color:red}",
-      "a:last-child:first-letter{color:red}",
+      ".foo { font-variation-settings: \"wght\" 400, \"wdth\" 75, \"wght\" 700 }",
+      ".foo{font-variation-settings:\"wdth\" 75,\"wght\" 700}",
     );
-
-    prefix_test(
-      ".test:not(.foo, .bar) {color:red}",
-      indoc! {r#"
-      .test:not(:-webkit-any(.foo, .bar)) {
-        color: red;
-      }
-
-      .test:not(:is(.foo, .bar)) {
-        color: red;
-      }
-      "#},
-      Browsers {
-        safari: Some(8 << 16),
-        ..Browsers::default()
-      },
+    minify_test(
+      ".foo { font-variation-settings: \"wght\" }",
+      ".foo{font-variation-settings:\"wght\"}",
     );
-    prefix_test(
-      ".test:not(.foo, .bar) {color:red}",
-      indoc! {r#"
-      .test:not(.foo, .bar) {
-        color: red;
-      }
-      "#},
-      Browsers {
-        safari: Some(11 << 16),
-        ..Browsers::default()
-      },
+    minify_test(
+      ".foo { font-variation-settings: \"weight\" 400 }",
+      ".foo{font-variation-settings:\"weight\" 400}",
+    );
+    minify_test(
+      ".foo { font: 12px Charcoal; font-variant-numeric: tabular-nums }",
+      ".foo{font:12px Charcoal;font-variant-numeric:tabular-nums}",
+    );
+    minify_test(
+      ".foo { font: 12px Charcoal; font-variant-position: sub; font-kerning: none }",
+      ".foo{font:12px Charcoal;font-variant-position:sub;font-kerning:none}",
+    );
+    minify_test(
+      ".foo { font-family: Charcoal; font-feature-settings: \"liga\" 0; font-size: 12px }",
+      ".foo{font-family:Charcoal;font-feature-settings:\"liga\" 0;font-size:12px}",
+    );
+    minify_test(".foo { font-weight: 1 }", ".foo{font-weight:1}");
+    minify_test(".foo { font-weight: 1000 }", ".foo{font-weight:1000}");
+    minify_test(".foo { font-weight: 0 }", ".foo{font-weight:0}");
+    minify_test(".foo { font-weight: 1001 }", ".foo{font-weight:1001}");
+    minify_test(".foo { font-size: -1px }", ".foo{font-size:-1px}");
+    minify_test(".foo { font-stretch: -10% }", ".foo{font-stretch:-10%}");
+    minify_test(".foo { line-height: -1 }", ".foo{line-height:-1}");
+    minify_test(
+      ".foo { font: bold 12px/-2 Charcoal; }",
+      ".foo{font:bold 12px/-2 Charcoal}",
+    );
+    minify_test(
+      ".foo { font: bold -12px/2 Charcoal; }",
+      ".foo{font:bold -12px/2 Charcoal}",
+    );
+    minify_test(
+      ".foo { font: normal 1001 12px Charcoal; }",
+      ".foo{font:normal 1001 12px Charcoal}",
+    );
+    minify_test(
+      ".foo { font-family: 'sans-serif'; }",
+      ".foo{font-family:\"sans-serif\"}",
+    );
+    minify_test(".foo { font-family: sans-serif; }", ".foo{font-family:sans-serif}");
+    minify_test(".foo { font-family: 'default'; }", ".foo{font-family:\"default\"}");
+    minify_test(".foo { font-family: default; }", ".foo{font-family:default}");
+    minify_test(".foo { font-family: 'inherit'; }", ".foo{font-family:\"inherit\"}");
+    minify_test(".foo { font-family: inherit; }", ".foo{font-family:inherit}");
+    minify_test(".foo { font-family: inherit test; }", ".foo{font-family:inherit test}");
+    minify_test(
+      ".foo { font-family: 'inherit test'; }",
+      ".foo{font-family:inherit test}",
+    );
+    minify_test(".foo { font-family: revert; }", ".foo{font-family:revert}");
+    minify_test(".foo { font-family: 'revert'; }", ".foo{font-family:\"revert\"}");
+    minify_test(".foo { font-family: revert-layer; }", ".foo{font-family:revert-layer}");
+    minify_test(
+      ".foo { font-family: revert-layer, serif; }",
+      ".foo{font-family:revert-layer,serif}",
+    );
+    minify_test(
+      ".foo { font-family: 'revert', sans-serif; }",
+      ".foo{font-family:\"revert\",sans-serif}",
+    );
+    minify_test(
+      ".foo { font-family: 'revert', foo, sans-serif; }",
+      ".foo{font-family:\"revert\",foo,sans-serif}",
     );
+    minify_test(".foo { font-family: ''; }", ".foo{font-family:\"\"}");
 
-    minify_test("a:lang(en) {color:red}", "a:lang(en){color:red}");
-    minify_test("a:lang(en, fr) {color:red}", "a:lang(en,fr){color:red}");
-    minify_test("a:lang('en') {color:red}", "a:lang(en){color:red}");
+    // font-family in @font-face
     minify_test(
-      "a:-webkit-any(.foo, .bar) {color:red}",
-      "a:-webkit-any(.foo,.bar){color:red}",
+      "@font-face { font-family: 'revert'; }",
+      "@font-face{font-family:\"revert\"}",
+    );
+    minify_test(
+      "@font-face { font-family: 'revert-layer'; }",
+      "@font-face{font-family:\"revert-layer\"}",
     );
-    minify_test("a:-moz-any(.foo, .bar) {color:red}", "a:-moz-any(.foo,.bar){color:red}");
 
     prefix_test(
-      "a:is(.foo, .bar) {color:red}",
-      indoc! {r#"
-      a:-webkit-any(.foo, .bar) {
-        color: red;
-      }
-
-      a:-moz-any(.foo, .bar) {
-        color: red;
+      r#"
+      .foo {
+        font-family: Helvetica, system-ui, sans-serif;
       }
-
-      a:is(.foo, .bar) {
-        color: red;
+    "#,
+      indoc! {r#"
+      .foo {
+        font-family: Helvetica, system-ui, -apple-system, BlinkMacSystemFont, Segoe UI, Roboto, Noto Sans, Ubuntu, Cantarell, Helvetica Neue, sans-serif;
       }
-      "#},
+    "#
+      },
       Browsers {
-        safari: Some(11 << 16),
-        firefox: Some(50 << 16),
+        safari: Some(8 << 16),
         ..Browsers::default()
       },
     );
 
     prefix_test(
-      "a:is(.foo > .bar) {color:red}",
+      r#"
+      .foo {
+        font: 100%/1.5 Helvetica, system-ui, sans-serif;
+      }
+    "#,
       indoc! {r#"
-      a:is(.foo > .bar) {
-        color: red;
+      .foo {
+        font: 100% / 1.5 Helvetica, system-ui, -apple-system, BlinkMacSystemFont, Segoe UI, Roboto, Noto Sans, Ubuntu, Cantarell, Helvetica Neue, sans-serif;
       }
-      "#},
+    "#
+      },
       Browsers {
-        safari: Some(11 << 16),
-        firefox: Some(50 << 16),
+        safari: Some(8 << 16),
         ..Browsers::default()
       },
     );
 
     prefix_test(
-      "a:lang(en, fr) {color:red}",
-      indoc! {r#"
-      a:-webkit-any(:lang(en), :lang(fr)) {
-        color: red;
-      }
-
-      a:-moz-any(:lang(en), :lang(fr)) {
-        color: red;
+      r#"
+      .foo {
+        font-family: ui-sans-serif, system-ui, -apple-system, BlinkMacSystemFont, "Segoe UI", Roboto, "Helvetica Neue", Arial, "Noto Sans", sans-serif, "Apple Color Emoji", "Segoe UI Emoji", "Segoe UI Symbol", "Noto Color Emoji";
       }
-
-      a:is(:lang(en), :lang(fr)) {
-        color: red;
+    "#,
+      indoc! {r#"
+      .foo {
+        font-family: ui-sans-serif, system-ui, -apple-system, BlinkMacSystemFont, Segoe UI, Roboto, Noto Sans, Ubuntu, Cantarell, Helvetica Neue, Arial, sans-serif, Apple Color Emoji, Segoe UI Emoji, Segoe UI Symbol, Noto Color Emoji;
       }
-      "#},
+    "#
+      },
       Browsers {
-        safari: Some(11 << 16),
-        firefox: Some(50 << 16),
+        firefox: Some(91 << 16),
         ..Browsers::default()
       },
     );
 
     prefix_test(
-      "a:lang(en, fr) {color:red}",
+      r#"
+      .foo {
+        font-size: 22px;
+        font-size: max(2cqw, 22px);
+      }
+    "#,
       indoc! {r#"
-      a:is(:lang(en), :lang(fr)) {
-        color: red;
+      .foo {
+        font-size: 22px;
+        font-size: max(2cqw, 22px);
       }
-      "#},
+    "#
+      },
       Browsers {
         safari: Some(14 << 16),
-        firefox: Some(88 << 16),
         ..Browsers::default()
       },
     );
-
     prefix_test(
-      "a:lang(en, fr) {color:red}",
+      r#"
+      .foo {
+        font-size: 22px;
+        font-size: max(2cqw, 22px);
+      }
+    "#,
       indoc! {r#"
-      a:lang(en, fr) {
-        color: red;
+      .foo {
+        font-size: max(2cqw, 22px);
       }
-      "#},
+    "#
+      },
       Browsers {
-        safari: Some(14 << 16),
+        safari: Some(16 << 16),
         ..Browsers::default()
       },
     );
 
     prefix_test(
-      "a:dir(rtl) {color:red}",
-      indoc! {r#"
-      a:-webkit-any(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi)) {
-        color: red;
-      }
-
-      a:-moz-any(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi)) {
-        color: red;
+      r#"
+      .foo {
+        font-size: 22px;
+        font-size: xxx-large;
       }
-
-      a:is(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi)) {
-        color: red;
+    "#,
+      indoc! {r#"
+      .foo {
+        font-size: 22px;
+        font-size: xxx-large;
       }
-      "#},
+    "#
+      },
       Browsers {
-        safari: Some(11 << 16),
-        firefox: Some(50 << 16),
+        chrome: Some(70 << 16),
         ..Browsers::default()
       },
     );
-
     prefix_test(
-      "a:dir(ltr) {color:red}",
-      indoc! {r#"
-      a:not(:-webkit-any(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi))) {
-        color: red;
-      }
-
-      a:not(:-moz-any(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi))) {
-        color: red;
+      r#"
+      .foo {
+        font-size: 22px;
+        font-size: xxx-large;
       }
-
-      a:not(:is(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi))) {
-        color: red;
+    "#,
+      indoc! {r#"
+      .foo {
+        font-size: xxx-large;
       }
-      "#},
+    "#
+      },
       Browsers {
-        safari: Some(11 << 16),
-        firefox: Some(50 << 16),
+        chrome: Some(80 << 16),
         ..Browsers::default()
       },
     );
 
     prefix_test(
-      "a:dir(rtl) {color:red}",
+      r#"
+      .foo {
+        font-weight: 700;
+        font-weight: 789;
+      }
+    "#,
       indoc! {r#"
-      a:is(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi)) {
-        color: red;
+      .foo {
+        font-weight: 700;
+        font-weight: 789;
       }
-      "#},
+    "#
+      },
       Browsers {
-        safari: Some(14 << 16),
-        firefox: Some(88 << 16),
+        chrome: Some(60 << 16),
         ..Browsers::default()
       },
     );
-
     prefix_test(
-      "a:dir(ltr) {color:red}",
+      r#"
+      .foo {
+        font-weight: 700;
+        font-weight: 789;
+      }
+    "#,
       indoc! {r#"
-      a:not(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi)) {
-        color: red;
+      .foo {
+        font-weight: 789;
       }
-      "#},
+    "#
+      },
       Browsers {
-        safari: Some(14 << 16),
-        firefox: Some(88 << 16),
+        chrome: Some(80 << 16),
         ..Browsers::default()
       },
     );
 
     prefix_test(
-      "a:dir(rtl) {color:red}",
-      indoc! {r#"
-      a:lang(ae, ar, arc, bcc, bqi, ckb, dv, fa, glk, he, ku, mzn, nqo, pnb, ps, sd, ug, ur, yi) {
-        color: red;
-      }
-      "#},
-      Browsers {
-        safari: Some(14 << 16),
-        ..Browsers::default()
-      },
-    );
-
-    prefix_test(
-      "a:dir(ltr) {color:red}",
+      r#"
+      .foo {
+        font-family: Helvetica;
+        font-family: system-ui;
+      }
+    "#,
       indoc! {r#"
-      a:not(:lang(ae, ar, arc, bcc, bqi, ckb, dv, fa, glk, he, ku, mzn, nqo, pnb, ps, sd, ug, ur, yi)) {
-        color: red;
+      .foo {
+        font-family: Helvetica;
+        font-family: system-ui;
       }
-      "#},
+    "#
+      },
       Browsers {
-        safari: Some(14 << 16),
+        chrome: Some(50 << 16),
         ..Browsers::default()
       },
     );
-
     prefix_test(
-      "a:is(:dir(rtl)) {color:red}",
+      r#"
+      .foo {
+        font-family: Helvetica;
+        font-family: system-ui;
+      }
+    "#,
       indoc! {r#"
-      a:lang(ae, ar, arc, bcc, bqi, ckb, dv, fa, glk, he, ku, mzn, nqo, pnb, ps, sd, ug, ur, yi) {
-        color: red;
+      .foo {
+        font-family: system-ui;
       }
-      "#},
+    "#
+      },
       Browsers {
-        safari: Some(14 << 16),
+        chrome: Some(80 << 16),
         ..Browsers::default()
       },
     );
 
     prefix_test(
-      "a:where(:dir(rtl)) {color:red}",
+      r#"
+      .foo {
+        font-style: oblique;
+        font-style: oblique 40deg;
+      }
+    "#,
       indoc! {r#"
-      a:where(:lang(ae, ar, arc, bcc, bqi, ckb, dv, fa, glk, he, ku, mzn, nqo, pnb, ps, sd, ug, ur, yi)) {
-        color: red;
+      .foo {
+        font-style: oblique;
+        font-style: oblique 40deg;
       }
-      "#},
+    "#
+      },
       Browsers {
-        safari: Some(14 << 16),
+        firefox: Some(50 << 16),
         ..Browsers::default()
       },
     );
-
     prefix_test(
-      "a:has(:dir(rtl)) {color:red}",
+      r#"
+      .foo {
+        font-style: oblique;
+        font-style: oblique 40deg;
+      }
+    "#,
       indoc! {r#"
-      a:has(:lang(ae, ar, arc, bcc, bqi, ckb, dv, fa, glk, he, ku, mzn, nqo, pnb, ps, sd, ug, ur, yi)) {
-        color: red;
+      .foo {
+        font-style: oblique 40deg;
       }
-      "#},
+    "#
+      },
       Browsers {
-        safari: Some(14 << 16),
+        firefox: Some(80 << 16),
         ..Browsers::default()
       },
     );
 
     prefix_test(
-      "a:not(:dir(rtl)) {color:red}",
+      r#"
+      .foo {
+        font: 22px Helvetica;
+        font: xxx-large system-ui;
+      }
+    "#,
       indoc! {r#"
-      a:not(:lang(ae, ar, arc, bcc, bqi, ckb, dv, fa, glk, he, ku, mzn, nqo, pnb, ps, sd, ug, ur, yi)) {
-        color: red;
+      .foo {
+        font: 22px Helvetica;
+        font: xxx-large system-ui;
       }
-      "#},
+    "#
+      },
       Browsers {
-        safari: Some(14 << 16),
+        chrome: Some(70 << 16),
         ..Browsers::default()
       },
     );
-
     prefix_test(
-      "a:dir(rtl)::after {color:red}",
+      r#"
+      .foo {
+        font: 22px Helvetica;
+        font: xxx-large system-ui;
+      }
+    "#,
       indoc! {r#"
-      a:lang(ae, ar, arc, bcc, bqi, ckb, dv, fa, glk, he, ku, mzn, nqo, pnb, ps, sd, ug, ur, yi):after {
-        color: red;
+      .foo {
+        font: xxx-large system-ui;
       }
-      "#},
+    "#
+      },
       Browsers {
-        safari: Some(14 << 16),
+        chrome: Some(80 << 16),
         ..Browsers::default()
       },
     );
 
     prefix_test(
-      "a:dir(rtl) div {color:red}",
+      r#"
+      .foo {
+        font: var(--fallback);
+        font: xxx-large system-ui;
+      }
+    "#,
       indoc! {r#"
-      a:lang(ae, ar, arc, bcc, bqi, ckb, dv, fa, glk, he, ku, mzn, nqo, pnb, ps, sd, ug, ur, yi) div {
-        color: red;
+      .foo {
+        font: var(--fallback);
+        font: xxx-large system-ui;
       }
-      "#},
+    "#
+      },
       Browsers {
-        safari: Some(14 << 16),
+        chrome: Some(50 << 16),
         ..Browsers::default()
       },
     );
+  }
 
-    minify_test(".foo::cue {color: red}", ".foo::cue{color:red}");
-    minify_test(".foo::cue-region {color: red}", ".foo::cue-region{color:red}");
-    minify_test(".foo::cue(b) {color: red}", ".foo::cue(b){color:red}");
-    minify_test(".foo::cue-region(b) {color: red}", ".foo::cue-region(b){color:red}");
+  #[test]
+  fn test_vertical_align() {
+    minify_test(".foo { vertical-align: middle }", ".foo{vertical-align:middle}");
+    minify_test(".foo { vertical-align: 0.3em }", ".foo{vertical-align:.3em}");
+    minify_test(".foo { vertical-align: 0px }", ".foo{vertical-align:0}");
+    minify_test(".foo { vertical-align: -0.25em }", ".foo{vertical-align:-.25em}");
+    minify_test(".foo { vertical-align: 50% }", ".foo{vertical-align:50%}");
+    minify_test(
+      ".foo { vertical-align: calc(1em + 2px) }",
+      ".foo{vertical-align:calc(1em + 2px)}",
+    );
+    minify_test(".foo { vertical-align: TEXT-TOP }", ".foo{vertical-align:text-top}");
+    minify_test(".foo { vertical-align: baseline }", ".foo{vertical-align:baseline}");
+    minify_test(".foo { vertical-align: sub }", ".foo{vertical-align:sub}");
+    minify_test(".foo { vertical-align: super }", ".foo{vertical-align:super}");
+    minify_test(".foo { vertical-align: bottom }", ".foo{vertical-align:bottom}");
+    minify_test(".foo { vertical-align: center }", ".foo{vertical-align:center}"); // invalid
+    minify_test(".foo { vertical-align: top 2px }", ".foo{vertical-align:top 2px}"); // invalid
+
+    let property = Property::parse_string("vertical-align".into(), "0px", ParserOptions::default()).unwrap();
+    assert!(matches!(
+      property,
+      Property::VerticalAlign(crate::properties::font::VerticalAlign::Length(..))
+    ));
+    let property = Property::parse_string("vertical-align".into(), "center", ParserOptions::default()).unwrap();
+    assert!(matches!(property, Property::Unparsed(..)));
+  }
+
+  #[test]
+  fn test_font_variant() {
     minify_test(
-      "::cue(v[voice='active']) {color: yellow;}",
-      "::cue(v[voice=active]){color:#ff0}",
+      ".foo { font-variant-ligatures: normal }",
+      ".foo{font-variant-ligatures:normal}",
     );
-    minify_test(":foo(bar) { color: yellow }", ":foo(bar){color:#ff0}");
-    minify_test("::foo(bar) { color: yellow }", "::foo(bar){color:#ff0}");
-    minify_test("::foo(*) { color: yellow }", "::foo(*){color:#ff0}");
-
-    minify_test(":is(.foo) { color: yellow }", ".foo{color:#ff0}");
-    minify_test(":is(#foo) { color: yellow }", "#foo{color:#ff0}");
-    minify_test("a:is(.foo) { color: yellow }", "a.foo{color:#ff0}");
-    minify_test("a:is([data-test]) { color: yellow }", "a[data-test]{color:#ff0}");
-    minify_test(".foo:is(a) { color: yellow }", ".foo:is(a){color:#ff0}");
-    minify_test(".foo:is(*|a) { color: yellow }", ".foo:is(*|a){color:#ff0}");
-    minify_test(".foo:is(*) { color: yellow }", ".foo:is(*){color:#ff0}");
     minify_test(
-      "@namespace svg url(http://www.w3.org/2000/svg); .foo:is(svg|a) { color: yellow }",
-      "@namespace svg \"http://www.w3.org/2000/svg\";.foo:is(svg|a){color:#ff0}",
+      ".foo { font-variant-ligatures: NONE }",
+      ".foo{font-variant-ligatures:none}",
     );
-    minify_test("a:is(.foo .bar) { color: yellow }", "a:is(.foo .bar){color:#ff0}");
-    minify_test(":is(.foo, .bar) { color: yellow }", ":is(.foo,.bar){color:#ff0}");
-    minify_test("a:is(:not(.foo)) { color: yellow }", "a:not(.foo){color:#ff0}");
-    minify_test("a:is(:first-child) { color: yellow }", "a:first-child{color:#ff0}");
-    minify_test("a:is(:has(.foo)) { color: yellow }", "a:has(.foo){color:#ff0}");
-    minify_test("a:is(:is(.foo)) { color: yellow }", "a.foo{color:#ff0}");
-    minify_test(":host(:hover) {color: red}", ":host(:hover){color:red}");
-    minify_test("::slotted(:hover) {color: red}", "::slotted(:hover){color:red}");
-
     minify_test(
-      ":root::view-transition {position: fixed}",
-      ":root::view-transition{position:fixed}",
+      ".foo { font-variant-ligatures: no-contextual common-ligatures }",
+      ".foo{font-variant-ligatures:common-ligatures no-contextual}",
     );
-    for name in &[
-      "view-transition-group",
-      "view-transition-image-pair",
-      "view-transition-new",
-      "view-transition-old",
-    ] {
-      minify_test(
-        &format!(":root::{}(*) {{position: fixed}}", name),
-        &format!(":root::{}(*){{position:fixed}}", name),
-      );
-      minify_test(
-        &format!(":root::{}(foo) {{position: fixed}}", name),
-        &format!(":root::{}(foo){{position:fixed}}", name),
-      );
-      minify_test(
-        &format!(":root::{}(foo):only-child {{position: fixed}}", name),
-        &format!(":root::{}(foo):only-child{{position:fixed}}", name),
-      );
-      error_test(
-        &format!(":root::{}(foo):first-child {{position: fixed}}", name),
-        ParserError::SelectorError(SelectorError::InvalidPseudoClassAfterPseudoElement),
-      );
-      error_test(
-        &format!(":root::{}(foo)::before {{position: fixed}}", name),
-        ParserError::SelectorError(SelectorError::InvalidState),
-      );
-    }
-
-    minify_test(".foo ::deep .bar {width: 20px}", ".foo ::deep .bar{width:20px}");
-    minify_test(".foo::deep .bar {width: 20px}", ".foo::deep .bar{width:20px}");
-    minify_test(".foo ::deep.bar {width: 20px}", ".foo ::deep.bar{width:20px}");
-    minify_test(".foo ::unknown .bar {width: 20px}", ".foo ::unknown .bar{width:20px}");
     minify_test(
-      ".foo ::unknown(foo) .bar {width: 20px}",
-      ".foo ::unknown(foo) .bar{width:20px}",
+      ".foo { font-variant-ligatures: no-common-ligatures no-discretionary-ligatures no-historical-ligatures no-contextual }",
+      ".foo{font-variant-ligatures:none}",
     );
     minify_test(
-      ".foo ::unknown:only-child {width: 20px}",
-      ".foo ::unknown:only-child{width:20px}",
+      ".foo { font-variant-ligatures: common-ligatures no-common-ligatures }",
+      ".foo{font-variant-ligatures:common-ligatures no-common-ligatures}",
+    ); // invalid
+    minify_test(
+      ".foo { font-variant-ligatures: none contextual }",
+      ".foo{font-variant-ligatures:none contextual}",
+    ); // invalid
+    minify_test(
+      ".foo { font-variant-numeric: slashed-zero TABULAR-NUMS oldstyle-nums }",
+      ".foo{font-variant-numeric:oldstyle-nums tabular-nums slashed-zero}",
     );
     minify_test(
-      ".foo ::unknown(.foo) .bar {width: 20px}",
-      ".foo ::unknown(.foo) .bar{width:20px}",
+      ".foo { font-variant-numeric: ordinal diagonal-fractions }",
+      ".foo{font-variant-numeric:diagonal-fractions ordinal}",
     );
     minify_test(
-      ".foo ::unknown(.foo .bar / .baz) .bar {width: 20px}",
-      ".foo ::unknown(.foo .bar / .baz) .bar{width:20px}",
+      ".foo { font-variant-numeric: ordinal ordinal }",
+      ".foo{font-variant-numeric:ordinal ordinal}",
+    ); // invalid
+    minify_test(
+      ".foo { font-variant-numeric: lining-nums oldstyle-nums }",
+      ".foo{font-variant-numeric:lining-nums oldstyle-nums}",
+    ); // invalid
+    minify_test(
+      ".foo { font-variant-east-asian: ruby full-width jis04 }",
+      ".foo{font-variant-east-asian:jis04 full-width ruby}",
     );
     minify_test(
-      ".foo ::unknown(something(foo)) .bar {width: 20px}",
-      ".foo ::unknown(something(foo)) .bar{width:20px}",
+      ".foo { font-variant-east-asian: normal }",
+      ".foo{font-variant-east-asian:normal}",
     );
     minify_test(
-      ".foo ::unknown([abc]) .bar {width: 20px}",
-      ".foo ::unknown([abc]) .bar{width:20px}",
+      ".foo { font-variant-east-asian: simplified traditional }",
+      ".foo{font-variant-east-asian:simplified traditional}",
+    ); // invalid
+    minify_test(
+      ".foo { font-variant-alternates: historical-forms }",
+      ".foo{font-variant-alternates:historical-forms}",
     );
-
-    let deep_options = ParserOptions {
-      flags: ParserFlags::DEEP_SELECTOR_COMBINATOR,
-      ..ParserOptions::default()
-    };
-
-    error_test(
-      ".foo >>> .bar {width: 20px}",
-      ParserError::SelectorError(SelectorError::DanglingCombinator),
+    minify_test(
+      ".foo { font-variant-alternates: swash(flowing) styleset(alt-a, alt-b) stylistic(alt) }",
+      ".foo{font-variant-alternates:stylistic(alt) styleset(alt-a,alt-b) swash(flowing)}",
     );
-    error_test(
-      ".foo /deep/ .bar {width: 20px}",
-      ParserError::SelectorError(SelectorError::DanglingCombinator),
+    minify_test(
+      ".foo { font-variant-alternates: character-variant(a, b) historical-forms annotation(circled) ornaments(fleurons) }",
+      ".foo{font-variant-alternates:historical-forms character-variant(a,b) ornaments(fleurons) annotation(circled)}",
     );
-    minify_test_with_options(
-      ".foo >>> .bar {width: 20px}",
-      ".foo>>>.bar{width:20px}",
-      deep_options.clone(),
+    minify_test(
+      ".foo { font-variant-alternates: swash(a) swash(b) }",
+      ".foo{font-variant-alternates:swash(a)swash(b)}",
+    ); // invalid
+    minify_test(
+      ".foo { font-variant-alternates: stylistic() }",
+      ".foo{font-variant-alternates:stylistic()}",
+    ); // invalid
+
+    minify_test(".foo { font-variant: normal }", ".foo{font-variant:normal}");
+    minify_test(".foo { font-variant: none }", ".foo{font-variant:none}");
+    minify_test(".foo { font-variant: small-caps }", ".foo{font-variant:small-caps}");
+    minify_test(
+      ".foo { font-variant: super tabular-nums all-small-caps no-common-ligatures }",
+      ".foo{font-variant:no-common-ligatures all-small-caps tabular-nums super}",
     );
-    minify_test_with_options(
-      ".foo /deep/ .bar {width: 20px}",
-      ".foo /deep/ .bar{width:20px}",
-      deep_options.clone(),
+    minify_test(
+      ".foo { font-variant: ruby historical-forms slashed-zero }",
+      ".foo{font-variant:historical-forms slashed-zero ruby}",
     );
-  }
-
-  #[test]
-  fn test_keyframes() {
     minify_test(
-      r#"
-      @keyframes "test" {
-        100% {
-          background: blue
-        }
-      }
-    "#,
-      "@keyframes test{to{background:#00f}}",
+      ".foo { font-variant: none small-caps }",
+      ".foo{font-variant:none small-caps}",
+    ); // invalid
+    minify_test(
+      ".foo { font-variant: small-caps normal }",
+      ".foo{font-variant:small-caps normal}",
+    ); // invalid
+    minify_test(
+      ".foo { font-variant: small-caps petite-caps }",
+      ".foo{font-variant:small-caps petite-caps}",
+    ); // invalid
+    minify_test(
+      ".foo { font-variant: none; font-variant-caps: small-caps }",
+      ".foo{font-variant:no-common-ligatures no-discretionary-ligatures no-historical-ligatures no-contextual small-caps}",
     );
     minify_test(
-      r#"
-      @keyframes test {
-        100% {
-          background: blue
-        }
-      }
-    "#,
-      "@keyframes test{to{background:#00f}}",
+      ".foo { font-variant-ligatures: none; font-variant-caps: normal; font-variant-alternates: normal; font-variant-numeric: normal; font-variant-east-asian: normal; font-variant-position: normal }",
+      ".foo{font-variant:none}",
     );
-
-    // CSS-wide keywords and `none` cannot remove quotes.
     minify_test(
-      r#"
-      @keyframes "revert" {
-        from {
-          background: green;
-        }
-      }
-    "#,
-      "@keyframes \"revert\"{0%{background:green}}",
+      ".foo { font-variant-ligatures: normal; font-variant-caps: small-caps; font-variant-alternates: normal; font-variant-numeric: tabular-nums; font-variant-east-asian: normal; font-variant-position: sub }",
+      ".foo{font-variant:small-caps tabular-nums sub}",
     );
-
     minify_test(
-      r#"
-      @keyframes "none" {
-        from {
-          background: green;
-        }
-      }
-    "#,
-      "@keyframes \"none\"{0%{background:green}}",
-    );
-
-    // CSS-wide keywords without quotes throws an error.
-    error_test(
-      r#"
-      @keyframes revert {}
-    "#,
-      ParserError::UnexpectedToken(Token::Ident("revert".into())),
+      ".foo { font-variant-numeric: tabular-nums; font-variant-position: sub }",
+      ".foo{font-variant-numeric:tabular-nums;font-variant-position:sub}",
     );
-
-    error_test(
-      r#"
-      @keyframes revert-layer {}
-    "#,
-      ParserError::UnexpectedToken(Token::Ident("revert-layer".into())),
+    minify_test(
+      ".foo { font-variant: small-caps; font-variant-numeric: tabular-nums }",
+      ".foo{font-variant:small-caps tabular-nums}",
     );
-
-    error_test(
-      r#"
-      @keyframes none {}
-    "#,
-      ParserError::UnexpectedToken(Token::Ident("none".into())),
+    minify_test(
+      ".foo { font-variant: small-caps; font-variant-numeric: var(--numeric) }",
+      ".foo{font-variant:small-caps;font-variant-numeric:var(--numeric)}",
     );
-
-    error_test(
-      r#"
-      @keyframes NONE {}
-    "#,
-      ParserError::UnexpectedToken(Token::Ident("NONE".into())),
+    minify_test(
+      ".foo { font-variant-numeric: tabular-nums; font: 12px Charcoal }",
+      ".foo{font:12px Charcoal}",
     );
-
     minify_test(
-      r#"
-      @-webkit-keyframes test {
-        from {
-          background: green;
-          background-color: red;
-        }
-
-        100% {
-          background: blue
-        }
-      }
-    "#,
-      "@-webkit-keyframes test{0%{background:red}to{background:#00f}}",
+      ".foo { font: 12px Charcoal; font-variant: small-caps slashed-zero }",
+      ".foo{font:small-caps 12px Charcoal;font-variant-numeric:slashed-zero}",
     );
     minify_test(
-      r#"
-      @-moz-keyframes test {
-        from {
-          background: green;
-          background-color: red;
-        }
-
-        100% {
-          background: blue
-        }
-      }
-    "#,
-      "@-moz-keyframes test{0%{background:red}to{background:#00f}}",
+      ".foo { font: 12px Charcoal; font-variant: normal }",
+      ".foo{font:12px Charcoal}",
     );
-    minify_test(r#"
-      @-webkit-keyframes test {
-        from {
-          background: green;
-          background-color: red;
-        }
-
-        100% {
-          background: blue
-        }
-      }
-      @-moz-keyframes test {
-        from {
-          background: green;
-          background-color: red;
-        }
-
-        100% {
-          background: blue
-        }
-      }
-    "#, "@-webkit-keyframes test{0%{background:red}to{background:#00f}}@-moz-keyframes test{0%{background:red}to{background:#00f}}");
-
-    prefix_test(
-      r#"
-      @keyframes test {
-        from {
-          background: green;
-        }
-        to {
-          background: blue
-        }
-      }
-    "#,
-      indoc! { r#"
-      @-webkit-keyframes test {
-        from {
-          background: green;
-        }
-
-        to {
-          background: #00f;
-        }
-      }
-
-      @-moz-keyframes test {
-        from {
-          background: green;
-        }
 
-        to {
-          background: #00f;
-        }
-      }
+    let font_variant_test = |source: &str, expected: &str, browsers: Browsers| {
+      nesting_test_with_targets(
+        source,
+        expected,
+        Targets {
+          browsers: Some(browsers),
+          include: Features::FontVariant,
+          exclude: Features::empty(),
+        },
+      );
+    };
 
-      @keyframes test {
-        from {
-          background: green;
+    font_variant_test(
+      ".foo { font-variant-numeric: tabular-nums slashed-zero }",
+      indoc! {r#"
+        .foo {
+          font-feature-settings: "tnum", "zero";
+          font-variant-numeric: tabular-nums slashed-zero;
         }
-
-        to {
-          background: #00f;
+      "#},
+      Browsers {
+        chrome: Some(40 << 16),
+        ..Browsers::default()
+      },
+    );
+    font_variant_test(
+      ".foo { font-variant-numeric: tabular-nums slashed-zero }",
+      indoc! {r#"
+        .foo {
+          font-variant-numeric: tabular-nums slashed-zero;
         }
-      }
-    "#},
+      "#},
       Browsers {
-        safari: Some(5 << 16),
-        firefox: Some(6 << 16),
+        chrome: Some(100 << 16),
         ..Browsers::default()
       },
     );
-    prefix_test(
-      r#"
-      @-webkit-keyframes test {
-        from {
-          background: green;
+    font_variant_test(
+      ".foo { font-variant-position: super; font-variant-ligatures: none }",
+      indoc! {r#"
+        .foo {
+          font-feature-settings: "sups";
+          font-variant-ligatures: none;
+          font-variant-position: super;
         }
-
-        to {
-          background: blue;
+      "#},
+      Browsers {
+        chrome: Some(100 << 16),
+        ..Browsers::default()
+      },
+    );
+    font_variant_test(
+      ".foo { font-feature-settings: \"liga\" 0; font-variant-position: super }",
+      indoc! {r#"
+        .foo {
+          font-feature-settings: "liga" 0, "sups";
+          font-variant-position: super;
         }
-      }
-      @-moz-keyframes test {
-        from {
-          background: green;
+      "#},
+      Browsers {
+        chrome: Some(100 << 16),
+        ..Browsers::default()
+      },
+    );
+    font_variant_test(
+      ".foo { font-feature-settings: \"liga\" 0, \"sups\" 0; font-variant-position: super }",
+      indoc! {r#"
+        .foo {
+          font-feature-settings: "liga" 0, "sups" 0;
+          font-variant-position: super;
         }
-
-        to {
-          background: blue;
+      "#},
+      Browsers {
+        chrome: Some(100 << 16),
+        ..Browsers::default()
+      },
+    );
+    font_variant_test(
+      ".foo { font-variant: no-common-ligatures all-small-caps historical-forms stylistic(fancy) jis78 }",
+      indoc! {r#"
+        .foo {
+          font-feature-settings: "c2sc", "smcp", "liga" 0, "clig" 0, "hist", "jp78";
+          font-variant: no-common-ligatures all-small-caps stylistic(fancy) historical-forms jis78;
         }
-      }
-      @keyframes test {
-        from {
-          background: green;
+      "#},
+      Browsers {
+        chrome: Some(30 << 16),
+        ..Browsers::default()
+      },
+    );
+    font_variant_test(
+      ".foo { font-variant: all-small-caps }",
+      indoc! {r#"
+        .foo {
+          font-feature-settings: "c2sc", "smcp";
+          font-variant: all-small-caps;
         }
-        to {
-          background: blue
+      "#},
+      Browsers {
+        chrome: Some(30 << 16),
+        ..Browsers::default()
+      },
+    );
+    font_variant_test(
+      ".foo { font-variant: all-small-caps }",
+      indoc! {r#"
+        .foo {
+          font-variant: all-small-caps;
         }
-      }
-    "#,
-      indoc! { r#"
-      @keyframes test {
-        from {
-          background: green;
+      "#},
+      Browsers {
+        chrome: Some(100 << 16),
+        ..Browsers::default()
+      },
+    );
+    font_variant_test(
+      ".foo { font-variant: small-caps }",
+      indoc! {r#"
+        .foo {
+          font-variant: small-caps;
         }
-
-        to {
-          background: #00f;
+      "#},
+      Browsers {
+        chrome: Some(30 << 16),
+        ..Browsers::default()
+      },
+    );
+    font_variant_test(
+      ".foo { font: 12px Charcoal; font-variant-caps: small-caps }",
+      indoc! {r#"
+        .foo {
+          font: small-caps 12px Charcoal;
         }
-      }
-    "#},
+      "#},
       Browsers {
-        safari: Some(10 << 16),
-        firefox: Some(17 << 16),
+        chrome: Some(30 << 16),
         ..Browsers::default()
       },
     );
     prefix_test(
-      r#"
-      @-webkit-keyframes test1 {
-        from {
-          background: green;
+      ".foo { font-variant-numeric: tabular-nums }",
+      indoc! {r#"
+        .foo {
+          font-variant-numeric: tabular-nums;
         }
-
-        to {
-          background: blue;
-        }
-      }
-
-      @-moz-keyframes test2 {
-        from {
-          background: green;
-        }
-
-        to {
-          background: blue;
-        }
-      }
-
-      @keyframes test3 {
-        from {
-          background: green;
-        }
-        to {
-          background: blue
-        }
-      }
-    "#,
-      indoc! { r#"
-      @-webkit-keyframes test1 {
-        from {
-          background: green;
-        }
-
-        to {
-          background: #00f;
-        }
-      }
-
-      @-moz-keyframes test2 {
-        from {
-          background: green;
-        }
-
-        to {
-          background: #00f;
-        }
-      }
-
-      @keyframes test3 {
-        from {
-          background: green;
-        }
-
-        to {
-          background: #00f;
-        }
-      }
-    "#},
+      "#},
       Browsers {
-        safari: Some(10 << 16),
-        firefox: Some(17 << 16),
+        chrome: Some(40 << 16),
         ..Browsers::default()
       },
     );
-    prefix_test(
-      r#"
-      @-webkit-keyframes test {
-        from {
-          background: green;
-        }
-
-        to {
-          background: red;
-        }
-      }
-      @-moz-keyframes test {
-        from {
-          background: green;
-        }
-
-        to {
-          background: pink;
-        }
-      }
-      @keyframes test {
-        from {
-          background: green;
-        }
-        to {
-          background: blue
-        }
-      }
-    "#,
-      indoc! { r#"
-      @-webkit-keyframes test {
-        from {
-          background: green;
-        }
-
-        to {
-          background: red;
-        }
-      }
-
-      @-moz-keyframes test {
-        from {
-          background: green;
-        }
-
-        to {
-          background: pink;
-        }
-      }
-
-      @keyframes test {
-        from {
-          background: green;
-        }
+  }
 
-        to {
-          background: #00f;
-        }
-      }
-    "#},
-      Browsers {
-        safari: Some(10 << 16),
-        firefox: Some(17 << 16),
-        ..Browsers::default()
-      },
+  #[test]
+  fn test_font_size_adjust() {
+    minify_test(".foo { font-size-adjust: none }", ".foo{font-size-adjust:none}");
+    minify_test(".foo { font-size-adjust: 0.5 }", ".foo{font-size-adjust:.5}");
+    minify_test(".foo { font-size-adjust: 0 }", ".foo{font-size-adjust:0}");
+    minify_test(".foo { font-size-adjust: ex-height 0.5 }", ".foo{font-size-adjust:.5}");
+    minify_test(
+      ".foo { font-size-adjust: EX-HEIGHT from-font }",
+      ".foo{font-size-adjust:from-font}",
     );
-
     minify_test(
-      r#"
-      @keyframes test {
-        100% {
-          background: blue
-        }
-      }
-
-      @keyframes test {
-        100% {
-          background: red
-        }
-      }
-    "#,
-      "@keyframes test{to{background:red}}",
+      ".foo { font-size-adjust: from-font }",
+      ".foo{font-size-adjust:from-font}",
     );
     minify_test(
-      r#"
-      @keyframes test {
-        100% {
-          background: blue
-        }
-      }
+      ".foo { font-size-adjust: cap-height 0.7 }",
+      ".foo{font-size-adjust:cap-height .7}",
+    );
+    minify_test(
+      ".foo { font-size-adjust: ch-width from-font }",
+      ".foo{font-size-adjust:ch-width from-font}",
+    );
+    minify_test(
+      ".foo { font-size-adjust: ic-width 1.0 }",
+      ".foo{font-size-adjust:ic-width 1}",
+    );
+    minify_test(
+      ".foo { font-size-adjust: ic-height 1.5 }",
+      ".foo{font-size-adjust:ic-height 1.5}",
+    );
+    minify_test(".foo { font-size-adjust: -0.5 }", ".foo{font-size-adjust:-.5}"); // invalid
+    minify_test(
+      ".foo { font-size-adjust: 0.5 cap-height }",
+      ".foo{font-size-adjust:.5 cap-height}",
+    ); // invalid
+    minify_test(
+      ".foo { font-size-adjust: cap-height }",
+      ".foo{font-size-adjust:cap-height}",
+    ); // invalid
+    minify_test(".foo { font-size-adjust: none 0.5 }", ".foo{font-size-adjust:none .5}"); // invalid
+    minify_test(
+      ".foo { font-size: 12px; font-family: serif; font-size-adjust: 0.5; font-style: italic }",
+      ".foo{font-family:serif;font-size:12px;font-size-adjust:.5;font-style:italic}",
+    );
+    minify_test(
+      ".foo { font: 12px serif; font-size-adjust: cap-height 0.5 }",
+      ".foo{font:12px serif;font-size-adjust:cap-height .5}",
+    );
+    minify_test(
+      ".foo { font-size-adjust: 0.5; font-size-adjust: cap-height 0.7 }",
+      ".foo{font-size-adjust:cap-height .7}",
+    );
+    minify_test(
+      ".foo { font-size-adjust: 0.5; font: 12px serif }",
+      ".foo{font-size-adjust:.5;font:12px serif}",
+    );
 
-      @-webkit-keyframes test {
-        100% {
-          background: red
-        }
+    prefix_test(
+      r#"
+      .foo {
+        font-size-adjust: 0.5;
+        font-size-adjust: cap-height 0.7;
       }
     "#,
-      "@keyframes test{to{background:#00f}}@-webkit-keyframes test{to{background:red}}",
+      indoc! {r#"
+      .foo {
+        font-size-adjust: .5;
+        font-size-adjust: cap-height .7;
+      }
+    "#
+      },
+      Browsers {
+        firefox: Some(100 << 16),
+        ..Browsers::default()
+      },
     );
-  }
 
-  #[test]
-  fn test_important() {
-    test(
+    prefix_test(
       r#"
       .foo {
-        align-items: center;
-        justify-items: center !important;
+        font-size-adjust: 0.5;
+        font-size-adjust: cap-height 0.7;
       }
     "#,
       indoc! {r#"
       .foo {
-        align-items: center;
-        justify-items: center !important;
+        font-size-adjust: cap-height .7;
       }
-    "#},
+    "#
+      },
+      Browsers {
+        firefox: Some(118 << 16),
+        ..Browsers::default()
+      },
     );
 
-    test(
+    prefix_test(
       r#"
       .foo {
-        justify-items: center !important;
-        align-items: center;
+        font-size-adjust: 0.5;
+        font-size-adjust: from-font;
       }
     "#,
       indoc! {r#"
       .foo {
-        align-items: center;
-        justify-items: center !important;
+        font-size-adjust: .5;
+        font-size-adjust: from-font;
       }
-    "#},
+    "#
+      },
+      Browsers {
+        firefox: Some(90 << 16),
+        ..Browsers::default()
+      },
     );
 
-    minify_test(
+    prefix_test(
       r#"
       .foo {
-        font-family: SFMono-Regular, Menlo, Monaco, Consolas, "Liberation Mono", "Courier New", monospace !important;
+        font-size-adjust: cap-height 0.7;
+        font-size-adjust: 0.5;
       }
     "#,
-      ".foo{font-family:SFMono-Regular,Menlo,Monaco,Consolas,Liberation Mono,Courier New,monospace!important}",
+      indoc! {r#"
+      .foo {
+        font-size-adjust: .5;
+      }
+    "#
+      },
+      Browsers {
+        firefox: Some(100 << 16),
+        ..Browsers::default()
+      },
     );
   }
 
   #[test]
-  fn test_calc() {
-    minify_test(".foo { width: calc(20px * 2) }", ".foo{width:40px}");
-    minify_test(".foo { font-size: calc(100vw / 35) }", ".foo{font-size:2.85714vw}");
-    minify_test(".foo { width: calc(20px * 2 * 3) }", ".foo{width:120px}");
-    minify_test(".foo { width: calc(20px + 30px) }", ".foo{width:50px}");
-    minify_test(".foo { width: calc(20px + 30px + 40px) }", ".foo{width:90px}");
-    minify_test(".foo { width: calc(100% - 30px) }", ".foo{width:calc(100% - 30px)}");
+  fn test_font_synthesis() {
+    minify_test(".foo { font-synthesis: none }", ".foo{font-synthesis:none}");
+    minify_test(".foo { font-synthesis: weight }", ".foo{font-synthesis:weight}");
     minify_test(
-      ".foo { width: calc(100% - 30px + 20px) }",
-      ".foo{width:calc(100% - 10px)}",
+      ".foo { font-synthesis: style weight }",
+      ".foo{font-synthesis:weight style}",
     );
+    minify_test(".foo { font-synthesis: SMALL-CAPS }", ".foo{font-synthesis:small-caps}");
     minify_test(
-      ".foo { width: calc(20px + 100% - 30px) }",
-      ".foo{width:calc(100% - 10px)}",
+      ".foo { font-synthesis: position small-caps style weight }",
+      ".foo{font-synthesis:weight style small-caps position}",
     );
     minify_test(
-      ".foo { width: calc(20px + 100% + 10vw - 30px) }",
-      ".foo{width:calc(100% - 10px + 10vw)}",
+      ".foo { font-synthesis: weight weight }",
+      ".foo{font-synthesis:weight weight}",
+    ); // invalid
+    minify_test(
+      ".foo { font-synthesis: none weight }",
+      ".foo{font-synthesis:none weight}",
+    ); // invalid
+    minify_test(".foo { font-synthesis: bold }", ".foo{font-synthesis:bold}"); // invalid
+    minify_test(
+      ".foo { font-synthesis-weight: none }",
+      ".foo{font-synthesis-weight:none}",
     );
+    minify_test(".foo { font-synthesis-style: AUTO }", ".foo{font-synthesis-style:auto}");
     minify_test(
-      ".foo { width: calc(20px + 100% - 30px) }",
-      ".foo{width:calc(100% - 10px)}",
+      ".foo { font-synthesis-small-caps: none }",
+      ".foo{font-synthesis-small-caps:none}",
     );
     minify_test(
-      ".foo { width: calc(2 * (100% - 20px)) }",
-      ".foo{width:calc(200% - 40px)}",
+      ".foo { font-synthesis-position: auto }",
+      ".foo{font-synthesis-position:auto}",
     );
     minify_test(
-      ".foo { width: calc((100% - 20px) * 2) }",
-      ".foo{width:calc(200% - 40px)}",
+      ".foo { font-synthesis-weight: weight }",
+      ".foo{font-synthesis-weight:weight}",
+    ); // invalid
+    minify_test(
+      ".foo { font-synthesis-weight: none; font-synthesis-style: auto; font-synthesis-small-caps: none; font-synthesis-position: auto }",
+      ".foo{font-synthesis:style position}",
     );
-    minify_test(".foo { width: calc(100% - 20px * 2) }", ".foo{width:calc(100% - 40px)}");
-    minify_test(".foo { width: calc(1px + 1px) }", ".foo{width:2px}");
-    minify_test(".foo { width: calc(100vw / 2) }", ".foo{width:50vw}");
-    minify_test(".foo { width: calc(50px - (20px - 30px)) }", ".foo{width:60px}");
-    minify_test(".foo { width: calc(100px - (100px - 100%)) }", ".foo{width:100%}");
     minify_test(
-      ".foo { width: calc(100px + (100px - 100%)) }",
-      ".foo{width:calc(200px - 100%)}",
+      ".foo { font-synthesis-weight: none; font-synthesis-style: none; font-synthesis-small-caps: none; font-synthesis-position: none }",
+      ".foo{font-synthesis:none}",
     );
     minify_test(
-      ".foo { width: calc(1px - (2em + 3%)) }",
-      ".foo{width:calc(1px + -2em - 3%)}",
-    ); // TODO: fix sign
+      ".foo { font-synthesis-weight: none; font-synthesis-style: none }",
+      ".foo{font-synthesis-weight:none;font-synthesis-style:none}",
+    );
     minify_test(
-      ".foo { width: calc((100vw - 50em) / 2) }",
-      ".foo{width:calc(50vw - 25em)}",
+      ".foo { font-synthesis: none; font-synthesis-style: auto }",
+      ".foo{font-synthesis:style}",
     );
     minify_test(
-      ".foo { width: calc(1px - (2em + 4vh + 3%)) }",
-      ".foo{width:calc(1px + -2em - 4vh - 3%)}",
-    ); // TODO
-    minify_test(
-      ".foo { width: calc(1px + (2em + (3vh + 4px))) }",
-      ".foo{width:calc(2em + 3vh + 5px)}",
-    );
-    minify_test(
-      ".foo { width: calc(1px - (2em + 4px - 6vh) / 2) }",
-      ".foo{width:calc(-1em - 1px + 3vh)}",
-    );
-    minify_test(
-      ".foo { width: calc(100% - calc(50% + 25px)) }",
-      ".foo{width:calc(50% - 25px)}",
-    );
-    minify_test(".foo { width: calc(1px/100) }", ".foo{width:.01px}");
-    minify_test(
-      ".foo { width: calc(100vw / 2 - 6px + 0px) }",
-      ".foo{width:calc(50vw - 6px)}",
-    );
-    minify_test(".foo { width: calc(1px + 1) }", ".foo{width:calc(1px + 1)}");
-    minify_test(
-      ".foo { width: calc( (1em - calc( 10px + 1em)) / 2) }",
-      ".foo{width:-5px}",
-    );
-    minify_test(
-      ".foo { width: calc((100px - 1em) + (-50px + 1em)) }",
-      ".foo{width:50px}",
-    );
-    minify_test(
-      ".foo { width: calc(100% + (2 * 100px) - ((75.37% - 63.5px) - 900px)) }",
-      ".foo{width:calc(24.63% + 1163.5px)}",
-    );
-    minify_test(
-      ".foo { width: calc(((((100% + (2 * 30px) + 63.5px) / 0.7537) - (100vw - 60px)) / 2) + 30px) }",
-      ".foo{width:calc(66.3394% + 141.929px - 50vw)}",
-    );
-    minify_test(
-      ".foo { width: calc(((75.37% - 63.5px) - 900px) + (2 * 100px)) }",
-      ".foo{width:calc(75.37% - 763.5px)}",
-    );
-    minify_test(
-      ".foo { width: calc((900px - (10% - 63.5px)) + (2 * 100px)) }",
-      ".foo{width:calc(1163.5px - 10%)}",
-    );
-    minify_test(".foo { width: calc(500px/0) }", ".foo{width:calc(500px/0)}");
-    minify_test(".foo { width: calc(500px/2px) }", ".foo{width:calc(500px/2px)}");
-    minify_test(".foo { width: calc(100% / 3 * 3) }", ".foo{width:100%}");
-    minify_test(".foo { width: calc(+100px + +100px) }", ".foo{width:200px}");
-    minify_test(".foo { width: calc(+100px - +100px) }", ".foo{width:0}");
-    minify_test(".foo { width: calc(200px * +1) }", ".foo{width:200px}");
-    minify_test(".foo { width: calc(200px / +1) }", ".foo{width:200px}");
-    minify_test(".foo { width: calc(1.1e+1px + 1.1e+1px) }", ".foo{width:22px}");
-    minify_test(".foo { border-width: calc(1px + 2px) }", ".foo{border-width:3px}");
-    minify_test(
-      ".foo { border-width: calc(1em + 2px + 2em + 3px) }",
-      ".foo{border-width:calc(3em + 5px)}",
+      ".foo { font-synthesis: var(--synthesis); font-synthesis-weight: none }",
+      ".foo{font-synthesis:var(--synthesis);font-synthesis-weight:none}",
     );
 
-    minify_test(
-      ".foo { border-width: min(1em, 2px) }",
-      ".foo{border-width:min(1em,2px)}",
+    prefix_test(
+      r#"
+      .foo {
+        font-synthesis-weight: none;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        font-synthesis: style;
+      }
+    "#},
+      Browsers {
+        chrome: Some(90 << 16),
+        ..Browsers::default()
+      },
     );
-    minify_test(
-      ".foo { border-width: min(1em + 2em, 2px + 2px) }",
-      ".foo{border-width:min(3em,4px)}",
+    prefix_test(
+      r#"
+      .foo {
+        font-synthesis-weight: none;
+        font-synthesis-small-caps: none;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        font-synthesis: style;
+      }
+    "#},
+      Browsers {
+        safari: Some(15 << 16),
+        ..Browsers::default()
+      },
     );
-    minify_test(
-      ".foo { border-width: min(1em + 2px, 2px + 1em) }",
-      ".foo{border-width:min(1em + 2px,2px + 1em)}",
+    prefix_test(
+      r#"
+      .foo {
+        font-synthesis-weight: none;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        font-synthesis-weight: none;
+      }
+    "#},
+      Browsers {
+        chrome: Some(120 << 16),
+        safari: Some(17 << 16),
+        ..Browsers::default()
+      },
     );
-    minify_test(
-      ".foo { border-width: min(1em + 2px + 2px, 2px + 1em + 1px) }",
-      ".foo{border-width:min(1em + 4px,3px + 1em)}",
+    prefix_test(
+      r#"
+      .foo {
+        font-synthesis-weight: none;
+        font-synthesis-style: auto;
+        font-synthesis-small-caps: auto;
+        font-synthesis-position: none;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        font-synthesis: style small-caps;
+      }
+    "#},
+      Browsers {
+        chrome: Some(120 << 16),
+        ..Browsers::default()
+      },
     );
-    minify_test(
-      ".foo { border-width: min(2px + 1px, 3px + 4px) }",
-      ".foo{border-width:3px}",
+    prefix_test(
+      r#"
+      .foo {
+        font-synthesis-weight: none;
+        font-synthesis-style: auto;
+        font-synthesis-small-caps: auto;
+        font-synthesis-position: auto;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        font-synthesis-weight: none;
+        font-synthesis-style: auto;
+        font-synthesis-small-caps: auto;
+        font-synthesis-position: auto;
+      }
+    "#},
+      Browsers {
+        chrome: Some(120 << 16),
+        ..Browsers::default()
+      },
     );
-    minify_test(
-      ".foo { border-width: min(1px, 1em, 2px, 3in) }",
-      ".foo{border-width:min(1px,1em)}",
+    prefix_test(
+      r#"
+      .foo {
+        font-synthesis-weight: auto;
+        font-synthesis-style: auto;
+        font-synthesis-small-caps: auto;
+        font-synthesis-position: none;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        font-synthesis: weight style;
+      }
+    "#},
+      Browsers {
+        safari: Some(15 << 16),
+        ..Browsers::default()
+      },
     );
-
-    minify_test(
-      ".foo { border-width: max(1em, 2px) }",
-      ".foo{border-width:max(1em,2px)}",
+    prefix_test(
+      r#"
+      .foo {
+        font-synthesis: weight style small-caps position;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        font-synthesis: weight style small-caps position;
+      }
+    "#},
+      Browsers {
+        chrome: Some(120 << 16),
+        ..Browsers::default()
+      },
     );
+  }
+
+  #[test]
+  fn test_selectors() {
+    minify_test(":nth-col(2n) {width: 20px}", ":nth-col(2n){width:20px}");
+    minify_test(":nth-col(10n-1) {width: 20px}", ":nth-col(10n-1){width:20px}");
+    minify_test(":nth-col(-n+2) {width: 20px}", ":nth-col(-n+2){width:20px}");
+    minify_test(":nth-col(even) {width: 20px}", ":nth-col(2n){width:20px}");
+    minify_test(":nth-col(odd) {width: 20px}", ":nth-col(odd){width:20px}");
+    minify_test(":nth-last-col(2n) {width: 20px}", ":nth-last-col(2n){width:20px}");
+    minify_test(":nth-last-col(10n-1) {width: 20px}", ":nth-last-col(10n-1){width:20px}");
+    minify_test(":nth-last-col(-n+2) {width: 20px}", ":nth-last-col(-n+2){width:20px}");
+    minify_test(":nth-last-col(even) {width: 20px}", ":nth-last-col(2n){width:20px}");
+    minify_test(":nth-last-col(odd) {width: 20px}", ":nth-last-col(odd){width:20px}");
+    minify_test(":nth-child(odd) {width: 20px}", ":nth-child(odd){width:20px}");
+    minify_test(":nth-child(2n) {width: 20px}", ":nth-child(2n){width:20px}");
+    minify_test(":nth-child(2n+1) {width: 20px}", ":nth-child(odd){width:20px}");
+    minify_test(":first-child {width: 20px}", ":first-child{width:20px}");
+    minify_test(":nth-child(1) {width: 20px}", ":first-child{width:20px}");
+    minify_test(":nth-last-child(1) {width: 20px}", ":last-child{width:20px}");
+    minify_test(":nth-of-type(1) {width: 20px}", ":first-of-type{width:20px}");
+    minify_test(":nth-last-of-type(1) {width: 20px}", ":last-of-type{width:20px}");
     minify_test(
-      ".foo { border-width: max(1em + 2em, 2px + 2px) }",
-      ".foo{border-width:max(3em,4px)}",
+      ":nth-child(even of li.important) {width: 20px}",
+      ":nth-child(2n of li.important){width:20px}",
     );
     minify_test(
-      ".foo { border-width: max(1em + 2px, 2px + 1em) }",
-      ".foo{border-width:max(1em + 2px,2px + 1em)}",
+      ":nth-child(1 of li.important) {width: 20px}",
+      ":nth-child(1 of li.important){width:20px}",
     );
     minify_test(
-      ".foo { border-width: max(1em + 2px + 2px, 2px + 1em + 1px) }",
-      ".foo{border-width:max(1em + 4px,3px + 1em)}",
+      ":nth-last-child(even of li.important) {width: 20px}",
+      ":nth-last-child(2n of li.important){width:20px}",
     );
     minify_test(
-      ".foo { border-width: max(2px + 1px, 3px + 4px) }",
-      ".foo{border-width:7px}",
+      ":nth-last-child(1 of li.important) {width: 20px}",
+      ":nth-last-child(1 of li.important){width:20px}",
     );
     minify_test(
-      ".foo { border-width: max(1px, 1em, 2px, 3in) }",
-      ".foo{border-width:max(3in,1em)}",
+      ":nth-last-child(1 of.important) {width: 20px}",
+      ":nth-last-child(1 of .important){width:20px}",
     );
 
-    minify_test(".foo { border-width: clamp(1px, 2px, 3px) }", ".foo{border-width:2px}");
-    minify_test(".foo { border-width: clamp(1px, 10px, 3px) }", ".foo{border-width:3px}");
-    minify_test(".foo { border-width: clamp(5px, 2px, 10px) }", ".foo{border-width:5px}");
+    minify_test("[foo=\"baz\"] {color:red}", "[foo=baz]{color:red}");
+    minify_test("[foo=\"foo bar\"] {color:red}", "[foo=foo\\ bar]{color:red}");
+    minify_test("[foo=\"foo bar baz\"] {color:red}", "[foo=\"foo bar baz\"]{color:red}");
+    minify_test("[foo=\"\"] {color:red}", "[foo=\"\"]{color:red}");
     minify_test(
-      ".foo { border-width: clamp(100px, 2px, 10px) }",
-      ".foo{border-width:100px}",
+      ".test:not([foo=\"bar\"]) {color:red}",
+      ".test:not([foo=bar]){color:red}",
     );
+    minify_test(".test + .foo {color:red}", ".test+.foo{color:red}");
+    minify_test(".test ~ .foo {color:red}", ".test~.foo{color:red}");
+    minify_test(".test .foo {color:red}", ".test .foo{color:red}");
     minify_test(
-      ".foo { border-width: clamp(5px + 5px, 5px + 7px, 10px + 20px) }",
-      ".foo{border-width:12px}",
+      ".custom-range::-webkit-slider-thumb:active {color:red}",
+      ".custom-range::-webkit-slider-thumb:active{color:red}",
     );
-
+    minify_test(".test:not(.foo, .bar) {color:red}", ".test:not(.foo,.bar){color:red}");
+    minify_test(".test:is(.foo, .bar) {color:red}", ".test:is(.foo,.bar){color:red}");
     minify_test(
-      ".foo { border-width: clamp(1em, 2px, 4vh) }",
-      ".foo{border-width:clamp(1em,2px,4vh)}",
+      ".test:where(.foo, .bar) {color:red}",
+      ".test:where(.foo,.bar){color:red}",
     );
     minify_test(
-      ".foo { border-width: clamp(1em, 2em, 4vh) }",
-      ".foo{border-width:min(2em,4vh)}",
+      ".test:where(.foo, .bar) {color:red}",
+      ".test:where(.foo,.bar){color:red}",
     );
+    minify_test(":host {color:red}", ":host{color:red}");
+    minify_test(":host(.foo) {color:red}", ":host(.foo){color:red}");
+    minify_test("::slotted(span) {color:red", "::slotted(span){color:red}");
     minify_test(
-      ".foo { border-width: clamp(1em, 2vh, 4vh) }",
-      ".foo{border-width:max(1em,2vh)}",
+      "custom-element::part(foo) {color:red}",
+      "custom-element::part(foo){color:red}",
     );
+    minify_test(".sm\\:text-5xl { font-size: 3rem }", ".sm\\:text-5xl{font-size:3rem}");
+    minify_test("a:has(> img) {color:red}", "a:has(>img){color:red}");
+    minify_test("dt:has(+ dt) {color:red}", "dt:has(+dt){color:red}");
     minify_test(
-      ".foo { border-width: clamp(1px, 1px + 2em, 4px) }",
-      ".foo{border-width:clamp(1px,1px + 2em,4px)}",
+      "section:not(:has(h1, h2, h3, h4, h5, h6)) {color:red}",
+      "section:not(:has(h1,h2,h3,h4,h5,h6)){color:red}",
     );
-    minify_test(".foo { border-width: clamp(1px, 2pt, 1in) }", ".foo{border-width:2pt}");
-
     minify_test(
-      ".foo { top: calc(-1 * clamp(1.75rem, 8vw, 4rem)) }",
-      ".foo{top:calc(-1*clamp(1.75rem,8vw,4rem))}",
+      ":has(.sibling ~ .target) {color:red}",
+      ":has(.sibling~.target){color:red}",
     );
-    minify_test(
-      ".foo { top: calc(-1 * min(1.75rem, 8vw, 4rem)) }",
-      ".foo{top:calc(-1*min(1.75rem,8vw))}",
-    );
-    minify_test(
-      ".foo { top: calc(-1 * max(1.75rem, 8vw, 4rem)) }",
-      ".foo{top:calc(-1*max(4rem,8vw))}",
-    );
-    minify_test(
-      ".foo { top: calc(clamp(1.75rem, 8vw, 4rem) * -1) }",
-      ".foo{top:calc(-1*clamp(1.75rem,8vw,4rem))}",
-    );
-    minify_test(
-      ".foo { top: calc(min(1.75rem, 8vw, 4rem) * -1) }",
-      ".foo{top:calc(-1*min(1.75rem,8vw))}",
-    );
-    minify_test(
-      ".foo { top: calc(max(1.75rem, 8vw, 4rem) * -1) }",
-      ".foo{top:calc(-1*max(4rem,8vw))}",
+    minify_test(".x:has(> .a > .b) {color:red}", ".x:has(>.a>.b){color:red}");
+    minify_test(".x:has(.bar, #foo) {color:red}", ".x:has(.bar,#foo){color:red}");
+    minify_test(".x:has(span + span) {color:red}", ".x:has(span+span){color:red}");
+    minify_test("a:has(:visited) {color:red}", "a:has(:visited){color:red}");
+    for element in [
+      "-webkit-scrollbar",
+      "-webkit-scrollbar-button",
+      "-webkit-scrollbar-track",
+      "-webkit-scrollbar-track-piece",
+      "-webkit-scrollbar-thumb",
+      "-webkit-scrollbar-corner",
+      "-webkit-resizer",
+    ] {
+      for class in [
+        "enabled",
+        "disabled",
+        "hover",
+        "active",
+        "horizontal",
+        "vertical",
+        "decrement",
+        "increment",
+        "start",
+        "end",
+        "double-button",
+        "single-button",
+        "no-button",
+        "corner-present",
+        "window-inactive",
+      ] {
+        minify_test(
+          &format!("::{}:{} {{color:red}}", element, class),
+          &format!("::{}:{}{{color:red}}", element, class),
+        );
+      }
+    }
+    for class in [
+      "horizontal",
+      "vertical",
+      "decrement",
+      "increment",
+      "start",
+      "end",
+      "double-button",
+      "single-button",
+      "no-button",
+      "corner-present",
+      "window-inactive",
+    ] {
+      error_test(
+        &format!(":{} {{color:red}}", class),
+        ParserError::SelectorError(SelectorError::InvalidPseudoClassBeforeWebKitScrollbar),
+      );
+    }
+    for element in [
+      "-webkit-scrollbar",
+      "-webkit-scrollbar-button",
+      "-webkit-scrollbar-track",
+      "-webkit-scrollbar-track-piece",
+      "-webkit-scrollbar-thumb",
+      "-webkit-scrollbar-corner",
+      "-webkit-resizer",
+    ] {
+      error_test(
+        &format!("::{}:focus {{color:red}}", element),
+        ParserError::SelectorError(SelectorError::InvalidPseudoClassAfterWebKitScrollbar),
+      );
+    }
+
+    error_test(
+      "a::first-letter:last-child {color:red}",
+      ParserError::SelectorError(SelectorError::InvalidPseudoClassAfterPseudoElement),
     );
     minify_test(
-      ".foo { top: calc(clamp(1.75rem, 8vw, 4rem) / 2) }",
-      ".foo{top:calc(clamp(1.75rem,8vw,4rem)/2)}",
+      "a:last-child::first-letter {color:red}",
+      "a:last-child:first-letter{color:red}",
     );
-    minify_test(
-      ".foo { top: calc(min(1.75rem, 8vw, 4rem) / 2) }",
-      ".foo{top:calc(min(1.75rem,8vw)/2)}",
+
+    prefix_test(
+      ".test:not(.foo, .bar) {color:red}",
+      indoc! {r#"
+      .test:not(:-webkit-any(.foo, .bar)) {
+        color: red;
+      }
+
+      .test:not(:is(.foo, .bar)) {
+        color: red;
+      }
+      "#},
+      Browsers {
+        safari: Some(8 << 16),
+        ..Browsers::default()
+      },
     );
-    minify_test(
-      ".foo { top: calc(max(1.75rem, 8vw, 4rem) / 2) }",
-      ".foo{top:calc(max(4rem,8vw)/2)}",
+    prefix_test(
+      ".test:not(.foo, .bar) {color:red}",
+      indoc! {r#"
+      .test:not(.foo, .bar) {
+        color: red;
+      }
+      "#},
+      Browsers {
+        safari: Some(11 << 16),
+        ..Browsers::default()
+      },
     );
+
+    minify_test("a:lang(en) {color:red}", "a:lang(en){color:red}");
+    minify_test("a:lang(en, fr) {color:red}", "a:lang(en,fr){color:red}");
+    minify_test("a:lang('en') {color:red}", "a:lang(en){color:red}");
     minify_test(
-      ".foo { top: calc(0.5 * clamp(1.75rem, 8vw, 4rem)) }",
-      ".foo{top:calc(clamp(1.75rem,8vw,4rem)/2)}",
+      "a:-webkit-any(.foo, .bar) {color:red}",
+      "a:-webkit-any(.foo,.bar){color:red}",
     );
-    minify_test(
-      ".foo { top: calc(1 * clamp(1.75rem, 8vw, 4rem)) }",
-      ".foo{top:calc(clamp(1.75rem,8vw,4rem))}",
+    minify_test("a:-moz-any(.foo, .bar) {color:red}", "a:-moz-any(.foo,.bar){color:red}");
+
+    prefix_test(
+      "a:is(.foo, .bar) {color:red}",
+      indoc! {r#"
+      a:-webkit-any(.foo, .bar) {
+        color: red;
+      }
+
+      a:-moz-any(.foo, .bar) {
+        color: red;
+      }
+
+      a:is(.foo, .bar) {
+        color: red;
+      }
+      "#},
+      Browsers {
+        safari: Some(11 << 16),
+        firefox: Some(50 << 16),
+        ..Browsers::default()
+      },
     );
-    minify_test(
-      ".foo { top: calc(2 * clamp(1.75rem, 8vw, 4rem) / 2) }",
-      ".foo{top:calc(clamp(1.75rem,8vw,4rem))}",
+
+    prefix_test(
+      "a:is(.foo > .bar) {color:red}",
+      indoc! {r#"
+      a:is(.foo > .bar) {
+        color: red;
+      }
+      "#},
+      Browsers {
+        safari: Some(11 << 16),
+        firefox: Some(50 << 16),
+        ..Browsers::default()
+      },
     );
 
-    minify_test(".foo { width: max(0px, 1vw) }", ".foo{width:max(0px,1vw)}");
+    prefix_test(
+      "a:lang(en, fr) {color:red}",
+      indoc! {r#"
+      a:-webkit-any(:lang(en), :lang(fr)) {
+        color: red;
+      }
+
+      a:-moz-any(:lang(en), :lang(fr)) {
+        color: red;
+      }
+
+      a:is(:lang(en), :lang(fr)) {
+        color: red;
+      }
+      "#},
+      Browsers {
+        safari: Some(11 << 16),
+        firefox: Some(50 << 16),
+        ..Browsers::default()
+      },
+    );
 
     prefix_test(
-      ".foo { border-width: clamp(1em, 2px, 4vh) }",
-      indoc! { r#"
-        .foo {
-          border-width: max(1em, min(2px, 4vh));
-        }
+      "a:lang(en, fr) {color:red}",
+      indoc! {r#"
+      a:is(:lang(en), :lang(fr)) {
+        color: red;
+      }
       "#},
       Browsers {
-        safari: Some(12 << 16),
+        safari: Some(14 << 16),
+        firefox: Some(88 << 16),
         ..Browsers::default()
       },
     );
 
     prefix_test(
-      ".foo { border-width: clamp(1em, 2px, 4vh) }",
-      indoc! { r#"
-        .foo {
-          border-width: clamp(1em, 2px, 4vh);
-        }
+      "a:lang(en, fr) {color:red}",
+      indoc! {r#"
+      a:lang(en, fr) {
+        color: red;
+      }
       "#},
       Browsers {
         safari: Some(14 << 16),
@@ -7600,2645 +8517,2115 @@ mod tests {
       },
     );
 
-    minify_test(".foo { width: calc(1vh + 2vh) }", ".foo{width:3vh}");
-    minify_test(".foo { width: calc(1dvh + 2dvh) }", ".foo{width:3dvh}");
-    minify_test(".foo { width: calc(1lvh + 2lvh) }", ".foo{width:3lvh}");
-    minify_test(".foo { width: calc(1svh + 2svh) }", ".foo{width:3svh}");
-    minify_test(".foo { width: calc(1sVmin + 2Svmin) }", ".foo{width:3svmin}");
-    minify_test(".foo { width: calc(1ic + 2ic) }", ".foo{width:3ic}");
-    minify_test(".foo { width: calc(1ric + 2ric) }", ".foo{width:3ric}");
-    minify_test(".foo { width: calc(1cap + 2cap) }", ".foo{width:3cap}");
-    minify_test(".foo { width: calc(1lh + 2lh) }", ".foo{width:3lh}");
-    minify_test(".foo { width: calc(1x + 2x) }", ".foo{width:calc(1x + 2x)}");
-    minify_test(
-      ".foo { left: calc(50% - 100px + clamp(0px, calc(50vw - 50px), 100px)) }",
-      ".foo{left:calc(50% - 100px + clamp(0px,50vw - 50px,100px))}",
+    prefix_test(
+      "a:dir(rtl) {color:red}",
+      indoc! {r#"
+      a:-webkit-any(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi)) {
+        color: red;
+      }
+
+      a:-moz-any(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi)) {
+        color: red;
+      }
+
+      a:is(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi)) {
+        color: red;
+      }
+      "#},
+      Browsers {
+        safari: Some(11 << 16),
+        firefox: Some(50 << 16),
+        ..Browsers::default()
+      },
     );
-    minify_test(
-      ".foo { left: calc(10px + min(10px, 1rem) + max(2px, 1vw)) }",
-      ".foo{left:calc(10px + min(10px,1rem) + max(2px,1vw))}",
-    );
-    minify_test(".foo { width: round(22px, 5px) }", ".foo{width:20px}");
-    minify_test(".foo { width: round(nearest, 22px, 5px) }", ".foo{width:20px}");
-    minify_test(".foo { width: round(down, 22px, 5px) }", ".foo{width:20px}");
-    minify_test(".foo { width: round(to-zero, 22px, 5px) }", ".foo{width:20px}");
-    minify_test(".foo { width: round(up, 22px, 5px) }", ".foo{width:25px}");
-    minify_test(".foo { width: round(23px, 5px) }", ".foo{width:25px}");
-    minify_test(".foo { width: round(nearest, 23px, 5px) }", ".foo{width:25px}");
-    minify_test(".foo { width: round(down, 23px, 5px) }", ".foo{width:20px}");
-    minify_test(".foo { width: round(to-zero, 23px, 5px) }", ".foo{width:20px}");
-    minify_test(".foo { width: round(up, 23px, 5px) }", ".foo{width:25px}");
-    minify_test(".foo { width: round(22px, 5vw) }", ".foo{width:round(22px,5vw)}");
-    minify_test(".foo { rotate: round(22deg, 5deg) }", ".foo{rotate:20deg}");
-    minify_test(".foo { rotate: round(22deg, 5deg) }", ".foo{rotate:20deg}");
-    minify_test(
-      ".foo { transition-duration: round(22ms, 5ms) }",
-      ".foo{transition-duration:20ms}",
-    );
-    minify_test(".foo { margin: round(to-zero, -23px, 5px) }", ".foo{margin:-20px}");
-    minify_test(".foo { margin: round(nearest, -23px, 5px) }", ".foo{margin:-25px}");
-    minify_test(".foo { margin: calc(10px * round(22, 5)) }", ".foo{margin:200px}");
-    minify_test(".foo { width: rem(18px, 5px) }", ".foo{width:3px}");
-    minify_test(".foo { width: rem(-18px, 5px) }", ".foo{width:-3px}");
-    minify_test(".foo { width: rem(18px, 5vw) }", ".foo{width:rem(18px,5vw)}");
-    minify_test(".foo { rotate: rem(-140deg, -90deg) }", ".foo{rotate:-50deg}");
-    minify_test(".foo { rotate: rem(140deg, -90deg) }", ".foo{rotate:50deg}");
-    minify_test(".foo { width: calc(10px * rem(18, 5)) }", ".foo{width:30px}");
-    minify_test(".foo { width: mod(18px, 5px) }", ".foo{width:3px}");
-    minify_test(".foo { width: mod(-18px, 5px) }", ".foo{width:2px}");
-    minify_test(".foo { rotate: mod(-140deg, -90deg) }", ".foo{rotate:-50deg}");
-    minify_test(".foo { rotate: mod(140deg, -90deg) }", ".foo{rotate:-40deg}");
-    minify_test(".foo { width: mod(18px, 5vw) }", ".foo{width:mod(18px,5vw)}");
-    minify_test(
-      ".foo { transform: rotateX(mod(140deg, -90deg)) rotateY(rem(140deg, -90deg)) }",
-      ".foo{transform:rotateX(-40deg)rotateY(50deg)}",
-    );
-    minify_test(".foo { width: calc(10px * mod(18, 5)) }", ".foo{width:30px}");
-  }
-
-  #[test]
-  fn test_trig() {
-    minify_test(".foo { width: calc(2px * pi); }", ".foo{width:6.28319px}");
-    minify_test(".foo { width: calc(2px / pi); }", ".foo{width:.63662px}");
-    // minify_test(
-    //   ".foo { width: calc(2px * infinity); }",
-    //   ".foo{width:calc(2px*infinity)}",
-    // );
-    // minify_test(
-    //   ".foo { width: calc(2px * -infinity); }",
-    //   ".foo{width:calc(2px*-infinity)}",
-    // );
-    minify_test(".foo { width: calc(100px * sin(45deg))", ".foo{width:70.7107px}");
-    minify_test(".foo { width: calc(100px * sin(.125turn))", ".foo{width:70.7107px}");
-    minify_test(
-      ".foo { width: calc(100px * sin(3.14159265 / 4))",
-      ".foo{width:70.7107px}",
-    );
-    minify_test(".foo { width: calc(100px * sin(pi / 4))", ".foo{width:70.7107px}");
-    minify_test(
-      ".foo { width: calc(100px * sin(22deg + 23deg))",
-      ".foo{width:70.7107px}",
-    );
-
-    minify_test(".foo { width: calc(2px * cos(45deg))", ".foo{width:1.41421px}");
-    minify_test(".foo { width: calc(2px * tan(45deg))", ".foo{width:2px}");
-
-    minify_test(".foo { rotate: asin(sin(45deg))", ".foo{rotate:45deg}");
-    minify_test(".foo { rotate: asin(1)", ".foo{rotate:90deg}");
-    minify_test(".foo { rotate: asin(-1)", ".foo{rotate:-90deg}");
-    minify_test(".foo { rotate: asin(0.5)", ".foo{rotate:30deg}");
-    minify_test(".foo { rotate: asin(45deg)", ".foo{rotate:asin(45deg)}"); // invalid
-    minify_test(".foo { rotate: asin(-20)", ".foo{rotate:asin(-20)}"); // evaluates to NaN
-    minify_test(".foo { width: asin(sin(45deg))", ".foo{width:asin(sin(45deg))}"); // invalid
-
-    minify_test(".foo { rotate: acos(cos(45deg))", ".foo{rotate:45deg}");
-    minify_test(".foo { rotate: acos(-1)", ".foo{rotate:180deg}");
-    minify_test(".foo { rotate: acos(0)", ".foo{rotate:90deg}");
-    minify_test(".foo { rotate: acos(1)", ".foo{rotate:none}");
-    minify_test(".foo { rotate: acos(45deg)", ".foo{rotate:acos(45deg)}"); // invalid
-    minify_test(".foo { rotate: acos(-20)", ".foo{rotate:acos(-20)}"); // evaluates to NaN
-
-    minify_test(".foo { rotate: atan(tan(45deg))", ".foo{rotate:45deg}");
-    minify_test(".foo { rotate: atan(1)", ".foo{rotate:45deg}");
-    minify_test(".foo { rotate: atan(0)", ".foo{rotate:none}");
-    minify_test(".foo { rotate: atan(45deg)", ".foo{rotate:atan(45deg)}"); // invalid
-
-    minify_test(".foo { rotate: atan2(1px, -1px)", ".foo{rotate:135deg}");
-    minify_test(".foo { rotate: atan2(1vw, -1vw)", ".foo{rotate:135deg}");
-    minify_test(".foo { rotate: atan2(1, -1)", ".foo{rotate:135deg}");
-    minify_test(".foo { rotate: atan2(1ms, -1ms)", ".foo{rotate:135deg}");
-    minify_test(".foo { rotate: atan2(1%, -1%)", ".foo{rotate:135deg}");
-    minify_test(".foo { rotate: atan2(1deg, -1deg)", ".foo{rotate:135deg}");
-    minify_test(".foo { rotate: atan2(1cm, 1mm)", ".foo{rotate:84.2894deg}");
-    minify_test(".foo { rotate: atan2(0, -1)", ".foo{rotate:180deg}");
-    minify_test(".foo { rotate: atan2(-1, 1)", ".foo{rotate:-45deg}");
-    // incompatible units
-    minify_test(".foo { rotate: atan2(1px, -1vw)", ".foo{rotate:atan2(1px,-1vw)}");
-  }
-
-  #[test]
-  fn test_exp() {
-    minify_test(".foo { width: hypot()", ".foo{width:hypot()}");
-    minify_test(".foo { width: hypot(1px)", ".foo{width:1px}");
-    minify_test(".foo { width: hypot(1px, 2px)", ".foo{width:2.23607px}");
-    minify_test(".foo { width: hypot(1px, 2px, 3px)", ".foo{width:3.74166px}");
-    minify_test(".foo { width: hypot(1px, 2vw)", ".foo{width:hypot(1px,2vw)}");
-    minify_test(".foo { width: hypot(1px, 2px, 3vw)", ".foo{width:hypot(1px,2px,3vw)}");
-    minify_test(".foo { width: calc(100px * hypot(3, 4))", ".foo{width:500px}");
-    minify_test(".foo { width: calc(1px * pow(2, sqrt(100))", ".foo{width:1024px}");
-    minify_test(".foo { width: calc(100px * pow(2, pow(2, 2)", ".foo{width:1600px}");
-    minify_test(".foo { width: calc(1px * log(1))", ".foo{width:0}");
-    minify_test(".foo { width: calc(1px * log(10, 10))", ".foo{width:1px}");
-    minify_test(".foo { width: calc(1px * exp(0))", ".foo{width:1px}");
-    minify_test(".foo { width: calc(1px * log(e))", ".foo{width:1px}");
-    minify_test(".foo { width: calc(1px * (e - exp(1)))", ".foo{width:0}");
-    minify_test(
-      ".foo { width: calc(1px * (exp(log(1) + exp(0)*2))",
-      ".foo{width:7.38906px}",
-    );
-  }
 
-  #[test]
-  fn test_sign() {
-    minify_test(".foo { width: abs(1px)", ".foo{width:1px}");
-    minify_test(".foo { width: abs(-1px)", ".foo{width:1px}");
-    minify_test(".foo { width: abs(1%)", ".foo{width:abs(1%)}"); // spec says percentages must be against resolved value
-
-    minify_test(".foo { width: calc(10px * sign(-1vw)", ".foo{width:-10px}");
-    minify_test(".foo { width: calc(10px * sign(1%)", ".foo{width:calc(10px*sign(1%))}");
-  }
+    prefix_test(
+      "a:dir(ltr) {color:red}",
+      indoc! {r#"
+      a:not(:-webkit-any(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi))) {
+        color: red;
+      }
 
-  #[test]
-  fn test_box_shadow() {
-    minify_test(
-      ".foo { box-shadow: 64px 64px 12px 40px rgba(0,0,0,0.4) }",
-      ".foo{box-shadow:64px 64px 12px 40px #0006}",
-    );
-    minify_test(
-      ".foo { box-shadow: 12px 12px 0px 8px rgba(0,0,0,0.4) inset }",
-      ".foo{box-shadow:inset 12px 12px 0 8px #0006}",
-    );
-    minify_test(
-      ".foo { box-shadow: inset 12px 12px 0px 8px rgba(0,0,0,0.4) }",
-      ".foo{box-shadow:inset 12px 12px 0 8px #0006}",
-    );
-    minify_test(
-      ".foo { box-shadow: 12px 12px 8px 0px rgba(0,0,0,0.4) }",
-      ".foo{box-shadow:12px 12px 8px #0006}",
-    );
-    minify_test(
-      ".foo { box-shadow: 12px 12px 0px 0px rgba(0,0,0,0.4) }",
-      ".foo{box-shadow:12px 12px #0006}",
-    );
-    minify_test(
-      ".foo { box-shadow: 64px 64px 12px 40px rgba(0,0,0,0.4), 12px 12px 0px 8px rgba(0,0,0,0.4) inset }",
-      ".foo{box-shadow:64px 64px 12px 40px #0006,inset 12px 12px 0 8px #0006}",
-    );
+      a:not(:-moz-any(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi))) {
+        color: red;
+      }
 
-    prefix_test(
-      ".foo { box-shadow: 12px 12px lab(40% 56.6 39) }",
-      indoc! { r#"
-        .foo {
-          box-shadow: 12px 12px #b32323;
-          box-shadow: 12px 12px lab(40% 56.6 39);
-        }
+      a:not(:is(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi))) {
+        color: red;
+      }
       "#},
       Browsers {
-        chrome: Some(90 << 16),
+        safari: Some(11 << 16),
+        firefox: Some(50 << 16),
         ..Browsers::default()
       },
     );
 
     prefix_test(
-      ".foo { box-shadow: 12px 12px lab(40% 56.6 39) }",
-      indoc! { r#"
-        .foo {
-          -webkit-box-shadow: 12px 12px #b32323;
-          box-shadow: 12px 12px #b32323;
-          box-shadow: 12px 12px lab(40% 56.6 39);
-        }
+      "a:dir(rtl) {color:red}",
+      indoc! {r#"
+      a:is(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi)) {
+        color: red;
+      }
       "#},
       Browsers {
-        chrome: Some(4 << 16),
+        safari: Some(14 << 16),
+        firefox: Some(88 << 16),
         ..Browsers::default()
       },
     );
 
     prefix_test(
-      ".foo { box-shadow: 12px 12px lab(40% 56.6 39), 12px 12px yellow }",
-      indoc! { r#"
-        .foo {
-          -webkit-box-shadow: 12px 12px #b32323, 12px 12px #ff0;
-          box-shadow: 12px 12px #b32323, 12px 12px #ff0;
-          box-shadow: 12px 12px lab(40% 56.6 39), 12px 12px #ff0;
-        }
+      "a:dir(ltr) {color:red}",
+      indoc! {r#"
+      a:not(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi)) {
+        color: red;
+      }
       "#},
       Browsers {
-        chrome: Some(4 << 16),
+        safari: Some(14 << 16),
+        firefox: Some(88 << 16),
         ..Browsers::default()
       },
     );
 
     prefix_test(
-      ".foo { -webkit-box-shadow: 12px 12px #0006 }",
-      indoc! { r#"
-        .foo {
-          -webkit-box-shadow: 12px 12px rgba(0, 0, 0, .4);
-        }
+      "a:dir(rtl) {color:red}",
+      indoc! {r#"
+      a:lang(ae, ar, arc, bcc, bqi, ckb, dv, fa, glk, he, ku, mzn, nqo, pnb, ps, sd, ug, ur, yi) {
+        color: red;
+      }
       "#},
       Browsers {
-        chrome: Some(4 << 16),
+        safari: Some(14 << 16),
         ..Browsers::default()
       },
     );
 
     prefix_test(
-      ".foo {
-        -webkit-box-shadow: 12px 12px #0006;
-        -moz-box-shadow: 12px 12px #0009;
-      }",
-      indoc! { r#"
-        .foo {
-          -webkit-box-shadow: 12px 12px rgba(0, 0, 0, .4);
-          -moz-box-shadow: 12px 12px rgba(0, 0, 0, .6);
-        }
+      "a:dir(ltr) {color:red}",
+      indoc! {r#"
+      a:not(:lang(ae, ar, arc, bcc, bqi, ckb, dv, fa, glk, he, ku, mzn, nqo, pnb, ps, sd, ug, ur, yi)) {
+        color: red;
+      }
       "#},
       Browsers {
-        chrome: Some(4 << 16),
+        safari: Some(14 << 16),
         ..Browsers::default()
       },
     );
 
     prefix_test(
-      ".foo {
-        -webkit-box-shadow: 12px 12px #0006;
-        -moz-box-shadow: 12px 12px #0006;
-        box-shadow: 12px 12px #0006;
-      }",
-      indoc! { r#"
-        .foo {
-          box-shadow: 12px 12px #0006;
-        }
+      "a:is(:dir(rtl)) {color:red}",
+      indoc! {r#"
+      a:lang(ae, ar, arc, bcc, bqi, ckb, dv, fa, glk, he, ku, mzn, nqo, pnb, ps, sd, ug, ur, yi) {
+        color: red;
+      }
       "#},
       Browsers {
-        chrome: Some(95 << 16),
+        safari: Some(14 << 16),
         ..Browsers::default()
       },
     );
 
     prefix_test(
-      ".foo { box-shadow: var(--foo) 12px lab(40% 56.6 39) }",
-      indoc! { r#"
-        .foo {
-          box-shadow: var(--foo) 12px #b32323;
-        }
-
-        @supports (color: lab(0% 0 0)) {
-          .foo {
-            box-shadow: var(--foo) 12px lab(40% 56.6 39);
-          }
-        }
-      "#},
-      Browsers {
-        chrome: Some(90 << 16),
-        ..Browsers::default()
-      },
-    );
-
-    prefix_test(
-      r#"
-      .foo {
-        box-shadow: 0px 0px 22px red;
-        box-shadow: 0px 0px max(2cqw, 22px) red;
-      }
-    "#,
+      "a:where(:dir(rtl)) {color:red}",
       indoc! {r#"
-      .foo {
-        box-shadow: 0 0 22px red;
-        box-shadow: 0 0 max(2cqw, 22px) red;
+      a:where(:lang(ae, ar, arc, bcc, bqi, ckb, dv, fa, glk, he, ku, mzn, nqo, pnb, ps, sd, ug, ur, yi)) {
+        color: red;
       }
-    "#
-      },
+      "#},
       Browsers {
         safari: Some(14 << 16),
         ..Browsers::default()
       },
     );
+
     prefix_test(
-      r#"
-      .foo {
-        box-shadow: 0px 0px 22px red;
-        box-shadow: 0px 0px max(2cqw, 22px) red;
-      }
-    "#,
+      "a:has(:dir(rtl)) {color:red}",
       indoc! {r#"
-      .foo {
-        box-shadow: 0 0 max(2cqw, 22px) red;
+      a:has(:lang(ae, ar, arc, bcc, bqi, ckb, dv, fa, glk, he, ku, mzn, nqo, pnb, ps, sd, ug, ur, yi)) {
+        color: red;
       }
-    "#
-      },
+      "#},
       Browsers {
-        safari: Some(16 << 16),
+        safari: Some(14 << 16),
         ..Browsers::default()
       },
     );
 
     prefix_test(
-      r#"
-      .foo {
-        box-shadow: 0px 0px 22px red;
-        box-shadow: 0px 0px 22px lab(40% 56.6 39);
-      }
-    "#,
+      "a:not(:dir(rtl)) {color:red}",
       indoc! {r#"
-      .foo {
-        box-shadow: 0 0 22px red;
-        box-shadow: 0 0 22px lab(40% 56.6 39);
+      a:not(:lang(ae, ar, arc, bcc, bqi, ckb, dv, fa, glk, he, ku, mzn, nqo, pnb, ps, sd, ug, ur, yi)) {
+        color: red;
       }
-    "#
-      },
+      "#},
       Browsers {
         safari: Some(14 << 16),
         ..Browsers::default()
       },
     );
+
     prefix_test(
-      r#"
-      .foo {
-        box-shadow: 0px 0px 22px red;
-        box-shadow: 0px 0px 22px lab(40% 56.6 39);
-      }
-    "#,
+      "a:dir(rtl)::after {color:red}",
       indoc! {r#"
-      .foo {
-        box-shadow: 0 0 22px lab(40% 56.6 39);
+      a:lang(ae, ar, arc, bcc, bqi, ckb, dv, fa, glk, he, ku, mzn, nqo, pnb, ps, sd, ug, ur, yi):after {
+        color: red;
       }
-    "#
-      },
+      "#},
       Browsers {
-        safari: Some(16 << 16),
+        safari: Some(14 << 16),
         ..Browsers::default()
       },
     );
 
     prefix_test(
-      r#"
-      .foo {
-        box-shadow: var(--fallback);
-        box-shadow: 0px 0px 22px lab(40% 56.6 39);
-      }
-    "#,
+      "a:dir(rtl) div {color:red}",
       indoc! {r#"
-      .foo {
-        box-shadow: var(--fallback);
-        box-shadow: 0 0 22px lab(40% 56.6 39);
+      a:lang(ae, ar, arc, bcc, bqi, ckb, dv, fa, glk, he, ku, mzn, nqo, pnb, ps, sd, ug, ur, yi) div {
+        color: red;
       }
-    "#
-      },
+      "#},
       Browsers {
-        safari: Some(16 << 16),
+        safari: Some(14 << 16),
         ..Browsers::default()
       },
     );
-  }
 
-  #[test]
-  fn test_media() {
-    minify_test(
-      "@media (min-width: 240px) { .foo { color: chartreuse }}",
-      "@media (width>=240px){.foo{color:#7fff00}}",
-    );
-    minify_test(
-      "@media (width < 240px) { .foo { color: chartreuse }}",
-      "@media (width<240px){.foo{color:#7fff00}}",
-    );
+    minify_test(".foo::cue {color: red}", ".foo::cue{color:red}");
+    minify_test(".foo::cue-region {color: red}", ".foo::cue-region{color:red}");
+    minify_test(".foo::cue(b) {color: red}", ".foo::cue(b){color:red}");
+    minify_test(".foo::cue-region(b) {color: red}", ".foo::cue-region(b){color:red}");
     minify_test(
-      "@media (width <= 240px) { .foo { color: chartreuse }}",
-      "@media (width<=240px){.foo{color:#7fff00}}",
+      "::cue(v[voice='active']) {color: yellow;}",
+      "::cue(v[voice=active]){color:#ff0}",
     );
+    minify_test(":foo(bar) { color: yellow }", ":foo(bar){color:#ff0}");
+    minify_test("::foo(bar) { color: yellow }", "::foo(bar){color:#ff0}");
+    minify_test("::foo(*) { color: yellow }", "::foo(*){color:#ff0}");
+
+    minify_test(":is(.foo) { color: yellow }", ".foo{color:#ff0}");
+    minify_test(":is(#foo) { color: yellow }", "#foo{color:#ff0}");
+    minify_test("a:is(.foo) { color: yellow }", "a.foo{color:#ff0}");
+    minify_test("a:is([data-test]) { color: yellow }", "a[data-test]{color:#ff0}");
+    minify_test(".foo:is(a) { color: yellow }", ".foo:is(a){color:#ff0}");
+    minify_test(".foo:is(*|a) { color: yellow }", ".foo:is(*|a){color:#ff0}");
+    minify_test(".foo:is(*) { color: yellow }", ".foo:is(*){color:#ff0}");
     minify_test(
-      "@media (width > 240px) { .foo { color: chartreuse }}",
-      "@media (width>240px){.foo{color:#7fff00}}",
+      "@namespace svg url(http://www.w3.org/2000/svg); .foo:is(svg|a) { color: yellow }",
+      "@namespace svg \"http://www.w3.org/2000/svg\";.foo:is(svg|a){color:#ff0}",
     );
+    minify_test("a:is(.foo .bar) { color: yellow }", "a:is(.foo .bar){color:#ff0}");
+    minify_test(":is(.foo, .bar) { color: yellow }", ":is(.foo,.bar){color:#ff0}");
+    minify_test("a:is(:not(.foo)) { color: yellow }", "a:not(.foo){color:#ff0}");
+    minify_test("a:is(:first-child) { color: yellow }", "a:first-child{color:#ff0}");
+    minify_test("a:is(:has(.foo)) { color: yellow }", "a:has(.foo){color:#ff0}");
+    minify_test("a:is(:is(.foo)) { color: yellow }", "a.foo{color:#ff0}");
+    minify_test(":host(:hover) {color: red}", ":host(:hover){color:red}");
+    minify_test("::slotted(:hover) {color: red}", "::slotted(:hover){color:red}");
+
     minify_test(
-      "@media (width >= 240px) { .foo { color: chartreuse }}",
-      "@media (width>=240px){.foo{color:#7fff00}}",
+      ":root::view-transition {position: fixed}",
+      ":root::view-transition{position:fixed}",
     );
+    for name in &[
+      "view-transition-group",
+      "view-transition-image-pair",
+      "view-transition-new",
+      "view-transition-old",
+    ] {
+      minify_test(
+        &format!(":root::{}(*) {{position: fixed}}", name),
+        &format!(":root::{}(*){{position:fixed}}", name),
+      );
+      minify_test(
+        &format!(":root::{}(foo) {{position: fixed}}", name),
+        &format!(":root::{}(foo){{position:fixed}}", name),
+      );
+      minify_test(
+        &format!(":root::{}(foo):only-child {{position: fixed}}", name),
+        &format!(":root::{}(foo):only-child{{position:fixed}}", name),
+      );
+      error_test(
+        &format!(":root::{}(foo):first-child {{position: fixed}}", name),
+        ParserError::SelectorError(SelectorError::InvalidPseudoClassAfterPseudoElement),
+      );
+      error_test(
+        &format!(":root::{}(foo)::before {{position: fixed}}", name),
+        ParserError::SelectorError(SelectorError::InvalidState),
+      );
+    }
+
+    minify_test(".foo ::deep .bar {width: 20px}", ".foo ::deep .bar{width:20px}");
+    minify_test(".foo::deep .bar {width: 20px}", ".foo::deep .bar{width:20px}");
+    minify_test(".foo ::deep.bar {width: 20px}", ".foo ::deep.bar{width:20px}");
+    minify_test(".foo ::unknown .bar {width: 20px}", ".foo ::unknown .bar{width:20px}");
     minify_test(
-      "@media (240px < width) { .foo { color: chartreuse }}",
-      "@media (width>240px){.foo{color:#7fff00}}",
+      ".foo ::unknown(foo) .bar {width: 20px}",
+      ".foo ::unknown(foo) .bar{width:20px}",
     );
     minify_test(
-      "@media (240px <= width) { .foo { color: chartreuse }}",
-      "@media (width>=240px){.foo{color:#7fff00}}",
+      ".foo ::unknown:only-child {width: 20px}",
+      ".foo ::unknown:only-child{width:20px}",
     );
     minify_test(
-      "@media (240px > width) { .foo { color: chartreuse }}",
-      "@media (width<240px){.foo{color:#7fff00}}",
+      ".foo ::unknown(.foo) .bar {width: 20px}",
+      ".foo ::unknown(.foo) .bar{width:20px}",
     );
     minify_test(
-      "@media (240px >= width) { .foo { color: chartreuse }}",
-      "@media (width<=240px){.foo{color:#7fff00}}",
+      ".foo ::unknown(.foo .bar / .baz) .bar {width: 20px}",
+      ".foo ::unknown(.foo .bar / .baz) .bar{width:20px}",
     );
     minify_test(
-      "@media (100px < width < 200px) { .foo { color: chartreuse }}",
-      "@media (100px<width<200px){.foo{color:#7fff00}}",
+      ".foo ::unknown(something(foo)) .bar {width: 20px}",
+      ".foo ::unknown(something(foo)) .bar{width:20px}",
     );
     minify_test(
-      "@media (100px <= width <= 200px) { .foo { color: chartreuse }}",
-      "@media (100px<=width<=200px){.foo{color:#7fff00}}",
+      ".foo ::unknown([abc]) .bar {width: 20px}",
+      ".foo ::unknown([abc]) .bar{width:20px}",
     );
-    minify_test(
-      "@media (min-width: 30em) and (max-width: 50em) { .foo { color: chartreuse }}",
-      "@media (width>=30em) and (width<=50em){.foo{color:#7fff00}}",
+
+    let deep_options = ParserOptions {
+      flags: ParserFlags::DEEP_SELECTOR_COMBINATOR,
+      ..ParserOptions::default()
+    };
+
+    error_test(
+      ".foo >>> .bar {width: 20px}",
+      ParserError::SelectorError(SelectorError::DanglingCombinator),
     );
-    minify_test(
-      "@media screen, print { .foo { color: chartreuse }}",
-      "@media screen,print{.foo{color:#7fff00}}",
+    error_test(
+      ".foo /deep/ .bar {width: 20px}",
+      ParserError::SelectorError(SelectorError::DanglingCombinator),
     );
-    minify_test(
-      "@media (hover: hover) { .foo { color: chartreuse }}",
-      "@media (hover:hover){.foo{color:#7fff00}}",
+    minify_test_with_options(
+      ".foo >>> .bar {width: 20px}",
+      ".foo>>>.bar{width:20px}",
+      deep_options.clone(),
     );
-    minify_test(
-      "@media (hover) { .foo { color: chartreuse }}",
-      "@media (hover){.foo{color:#7fff00}}",
+    minify_test_with_options(
+      ".foo /deep/ .bar {width: 20px}",
+      ".foo /deep/ .bar{width:20px}",
+      deep_options.clone(),
     );
+  }
+
+  #[test]
+  fn test_keyframes() {
     minify_test(
-      "@media (aspect-ratio: 11/5) { .foo { color: chartreuse }}",
-      "@media (aspect-ratio:11/5){.foo{color:#7fff00}}",
+      r#"
+      @keyframes "test" {
+        100% {
+          background: blue
+        }
+      }
+    "#,
+      "@keyframes test{to{background:#00f}}",
     );
     minify_test(
-      "@media (aspect-ratio: 2/1) { .foo { color: chartreuse }}",
-      "@media (aspect-ratio:2){.foo{color:#7fff00}}",
+      r#"
+      @keyframes test {
+        100% {
+          background: blue
+        }
+      }
+    "#,
+      "@keyframes test{to{background:#00f}}",
     );
+
+    // CSS-wide keywords and `none` cannot remove quotes.
     minify_test(
-      "@media (aspect-ratio: 2) { .foo { color: chartreuse }}",
-      "@media (aspect-ratio:2){.foo{color:#7fff00}}",
-    );
-    minify_test(
-      "@media not screen and (color) { .foo { color: chartreuse }}",
-      "@media not screen and (color){.foo{color:#7fff00}}",
-    );
-    minify_test(
-      "@media only screen and (color) { .foo { color: chartreuse }}",
-      "@media only screen and (color){.foo{color:#7fff00}}",
-    );
-    minify_test(
-      "@media (update: slow) or (hover: none) { .foo { color: chartreuse }}",
-      "@media (update:slow) or (hover:none){.foo{color:#7fff00}}",
-    );
-    minify_test(
-      "@media (width < 600px) and (height < 600px) { .foo { color: chartreuse }}",
-      "@media (width<600px) and (height<600px){.foo{color:#7fff00}}",
+      r#"
+      @keyframes "revert" {
+        from {
+          background: green;
+        }
+      }
+    "#,
+      "@keyframes \"revert\"{0%{background:green}}",
     );
+
     minify_test(
-      "@media (not (color)) or (hover) { .foo { color: chartreuse }}",
-      "@media (not (color)) or (hover){.foo{color:#7fff00}}",
+      r#"
+      @keyframes "none" {
+        from {
+          background: green;
+        }
+      }
+    "#,
+      "@keyframes \"none\"{0%{background:green}}",
     );
+
+    // CSS-wide keywords without quotes throws an error.
     error_test(
-      "@media (example, all,), speech { .foo { color: chartreuse }}",
-      ParserError::UnexpectedToken(Token::Comma),
+      r#"
+      @keyframes revert {}
+    "#,
+      ParserError::UnexpectedToken(Token::Ident("revert".into())),
     );
+
     error_test(
-      "@media &test, speech { .foo { color: chartreuse }}",
-      ParserError::UnexpectedToken(Token::Delim('&')),
+      r#"
+      @keyframes revert-layer {}
+    "#,
+      ParserError::UnexpectedToken(Token::Ident("revert-layer".into())),
     );
+
     error_test(
-      "@media &test { .foo { color: chartreuse }}",
-      ParserError::UnexpectedToken(Token::Delim('&')),
-    );
-    minify_test(
-      "@media (min-width: calc(200px + 40px)) { .foo { color: chartreuse }}",
-      "@media (width>=240px){.foo{color:#7fff00}}",
-    );
-    minify_test(
-      "@media (min-width: calc(1em + 5px)) { .foo { color: chartreuse }}",
-      "@media (width>=calc(1em + 5px)){.foo{color:#7fff00}}",
-    );
-    minify_test("@media { .foo { color: chartreuse }}", ".foo{color:#7fff00}");
-    minify_test("@media all { .foo { color: chartreuse }}", ".foo{color:#7fff00}");
-    minify_test(
-      "@media not (((color) or (hover))) { .foo { color: chartreuse }}",
-      "@media not ((color) or (hover)){.foo{color:#7fff00}}",
+      r#"
+      @keyframes none {}
+    "#,
+      ParserError::UnexpectedToken(Token::Ident("none".into())),
     );
-    minify_test(
-      "@media (hover) and ((color) and (test)) { .foo { color: chartreuse }}",
-      "@media (hover) and (color) and (test){.foo{color:#7fff00}}",
+
+    error_test(
+      r#"
+      @keyframes NONE {}
+    "#,
+      ParserError::UnexpectedToken(Token::Ident("NONE".into())),
     );
+
     minify_test(
-      "@media (grid: 1) { .foo { color: chartreuse }}",
-      "@media (grid:1){.foo{color:#7fff00}}",
+      r#"
+      @-webkit-keyframes test {
+        from {
+          background: green;
+          background-color: red;
+        }
+
+        100% {
+          background: blue
+        }
+      }
+    "#,
+      "@-webkit-keyframes test{0%{background:red}to{background:#00f}}",
     );
     minify_test(
-      "@media (width >= calc(2px + 4px)) { .foo { color: chartreuse }}",
-      "@media (width>=6px){.foo{color:#7fff00}}",
-    );
-
-    prefix_test(
       r#"
-        @media (width >= 240px) {
-          .foo {
-            color: chartreuse;
-          }
+      @-moz-keyframes test {
+        from {
+          background: green;
+          background-color: red;
         }
-      "#,
-      indoc! { r#"
-        @media (min-width: 240px) {
-          .foo {
-            color: #7fff00;
-          }
+
+        100% {
+          background: blue
         }
-      "#},
-      Browsers {
-        firefox: Some(60 << 16),
-        ..Browsers::default()
-      },
+      }
+    "#,
+      "@-moz-keyframes test{0%{background:red}to{background:#00f}}",
     );
+    minify_test(r#"
+      @-webkit-keyframes test {
+        from {
+          background: green;
+          background-color: red;
+        }
 
-    prefix_test(
-      r#"
-        @media (width >= 240px) {
-          .foo {
-            color: chartreuse;
-          }
+        100% {
+          background: blue
         }
-      "#,
-      indoc! { r#"
-        @media (width >= 240px) {
-          .foo {
-            color: #7fff00;
-          }
+      }
+      @-moz-keyframes test {
+        from {
+          background: green;
+          background-color: red;
         }
-      "#},
-      Browsers {
-        firefox: Some(64 << 16),
-        ..Browsers::default()
-      },
-    );
+
+        100% {
+          background: blue
+        }
+      }
+    "#, "@-webkit-keyframes test{0%{background:red}to{background:#00f}}@-moz-keyframes test{0%{background:red}to{background:#00f}}");
 
     prefix_test(
       r#"
-        @media (color > 2) {
-          .foo {
-            color: chartreuse;
-          }
+      @keyframes test {
+        from {
+          background: green;
         }
-      "#,
+        to {
+          background: blue
+        }
+      }
+    "#,
       indoc! { r#"
-        @media (min-color: 3) {
-          .foo {
-            color: #7fff00;
-          }
+      @-webkit-keyframes test {
+        from {
+          background: green;
         }
-      "#},
-      Browsers {
-        firefox: Some(60 << 16),
-        ..Browsers::default()
-      },
-    );
 
-    prefix_test(
-      r#"
-        @media (color < 2) {
-          .foo {
-            color: chartreuse;
-          }
+        to {
+          background: #00f;
         }
-      "#,
-      indoc! { r#"
-        @media (max-color: 1) {
-          .foo {
-            color: #7fff00;
-          }
+      }
+
+      @-moz-keyframes test {
+        from {
+          background: green;
         }
-      "#},
-      Browsers {
-        firefox: Some(60 << 16),
-        ..Browsers::default()
-      },
-    );
 
-    prefix_test(
-      r#"
-        @media (width > 240px) {
-          .foo {
-            color: chartreuse;
-          }
+        to {
+          background: #00f;
         }
-      "#,
-      indoc! { r#"
-        @media (min-width: 240.001px) {
-          .foo {
-            color: #7fff00;
-          }
+      }
+
+      @keyframes test {
+        from {
+          background: green;
         }
-      "#},
+
+        to {
+          background: #00f;
+        }
+      }
+    "#},
       Browsers {
-        firefox: Some(60 << 16),
+        safari: Some(5 << 16),
+        firefox: Some(6 << 16),
         ..Browsers::default()
       },
     );
-
     prefix_test(
       r#"
-        @media (width <= 240px) {
-          .foo {
-            color: chartreuse;
-          }
+      @-webkit-keyframes test {
+        from {
+          background: green;
         }
-      "#,
-      indoc! { r#"
-        @media (max-width: 240px) {
-          .foo {
-            color: #7fff00;
-          }
+
+        to {
+          background: blue;
+        }
+      }
+      @-moz-keyframes test {
+        from {
+          background: green;
         }
-      "#},
-      Browsers {
-        firefox: Some(60 << 16),
-        ..Browsers::default()
-      },
-    );
 
-    prefix_test(
-      r#"
-        @media (width <= 240px) {
-          .foo {
-            color: chartreuse;
-          }
+        to {
+          background: blue;
         }
-      "#,
+      }
+      @keyframes test {
+        from {
+          background: green;
+        }
+        to {
+          background: blue
+        }
+      }
+    "#,
       indoc! { r#"
-        @media (width <= 240px) {
-          .foo {
-            color: #7fff00;
-          }
+      @keyframes test {
+        from {
+          background: green;
         }
-      "#},
+
+        to {
+          background: #00f;
+        }
+      }
+    "#},
       Browsers {
-        firefox: Some(64 << 16),
+        safari: Some(10 << 16),
+        firefox: Some(17 << 16),
         ..Browsers::default()
       },
     );
-
     prefix_test(
       r#"
-        @media (width < 240px) {
-          .foo {
-            color: chartreuse;
-          }
+      @-webkit-keyframes test1 {
+        from {
+          background: green;
         }
-      "#,
-      indoc! { r#"
-        @media (max-width: 239.999px) {
-          .foo {
-            color: #7fff00;
-          }
+
+        to {
+          background: blue;
         }
-      "#},
-      Browsers {
-        firefox: Some(60 << 16),
-        ..Browsers::default()
-      },
-    );
+      }
 
-    prefix_test(
-      r#"
-        @media (100px <= width <= 200px) {
-          .foo {
-            color: chartreuse;
-          }
+      @-moz-keyframes test2 {
+        from {
+          background: green;
         }
-      "#,
-      indoc! { r#"
-        @media (min-width: 100px) and (max-width: 200px) {
-          .foo {
-            color: #7fff00;
-          }
+
+        to {
+          background: blue;
         }
-      "#},
-      Browsers {
-        firefox: Some(85 << 16),
-        ..Browsers::default()
-      },
-    );
+      }
 
-    prefix_test(
-      r#"
-        @media not (100px <= width <= 200px) {
-          .foo {
-            color: chartreuse;
-          }
+      @keyframes test3 {
+        from {
+          background: green;
         }
-      "#,
+        to {
+          background: blue
+        }
+      }
+    "#,
       indoc! { r#"
-        @media not ((min-width: 100px) and (max-width: 200px)) {
-          .foo {
-            color: #7fff00;
-          }
+      @-webkit-keyframes test1 {
+        from {
+          background: green;
         }
-      "#},
-      Browsers {
-        firefox: Some(85 << 16),
-        ..Browsers::default()
-      },
-    );
 
-    prefix_test(
-      r#"
-        @media (hover) and (100px <= width <= 200px) {
-          .foo {
-            color: chartreuse;
-          }
+        to {
+          background: #00f;
         }
-      "#,
-      indoc! { r#"
-        @media (hover) and (min-width: 100px) and (max-width: 200px) {
-          .foo {
-            color: #7fff00;
-          }
+      }
+
+      @-moz-keyframes test2 {
+        from {
+          background: green;
         }
-      "#},
-      Browsers {
-        firefox: Some(85 << 16),
-        ..Browsers::default()
-      },
-    );
 
-    prefix_test(
-      r#"
-        @media (hover) or (100px <= width <= 200px) {
-          .foo {
-            color: chartreuse;
-          }
+        to {
+          background: #00f;
         }
-      "#,
-      indoc! { r#"
-        @media (hover) or ((min-width: 100px) and (max-width: 200px)) {
-          .foo {
-            color: #7fff00;
-          }
+      }
+
+      @keyframes test3 {
+        from {
+          background: green;
         }
-      "#},
+
+        to {
+          background: #00f;
+        }
+      }
+    "#},
       Browsers {
-        firefox: Some(85 << 16),
+        safari: Some(10 << 16),
+        firefox: Some(17 << 16),
         ..Browsers::default()
       },
     );
-
     prefix_test(
       r#"
-        @media (100px < width < 200px) {
-          .foo {
-            color: chartreuse;
-          }
+      @-webkit-keyframes test {
+        from {
+          background: green;
         }
-      "#,
-      indoc! { r#"
-        @media (min-width: 100.001px) and (max-width: 199.999px) {
-          .foo {
-            color: #7fff00;
-          }
+
+        to {
+          background: red;
+        }
+      }
+      @-moz-keyframes test {
+        from {
+          background: green;
         }
-      "#},
-      Browsers {
-        firefox: Some(85 << 16),
-        ..Browsers::default()
-      },
-    );
 
-    prefix_test(
-      r#"
-        @media (200px >= width >= 100px) {
-          .foo {
-            color: chartreuse;
-          }
+        to {
+          background: pink;
         }
-      "#,
+      }
+      @keyframes test {
+        from {
+          background: green;
+        }
+        to {
+          background: blue
+        }
+      }
+    "#,
       indoc! { r#"
-        @media (max-width: 200px) and (min-width: 100px) {
-          .foo {
-            color: #7fff00;
-          }
+      @-webkit-keyframes test {
+        from {
+          background: green;
         }
-      "#},
-      Browsers {
-        firefox: Some(85 << 16),
-        ..Browsers::default()
-      },
-    );
 
-    test(
-      r#"
-      @media not all {
-        .a {
-          color: green;
+        to {
+          background: red;
         }
       }
-      "#,
-      "\n",
-    );
 
-    prefix_test(
-      r#"
-      @media (width > calc(1px + 1rem)) {
-        .foo { color: yellow; }
-      }
-      "#,
-      indoc! { r#"
-        @media (min-width: calc(1.001px + 1rem)) {
-          .foo {
-            color: #ff0;
-          }
+      @-moz-keyframes test {
+        from {
+          background: green;
         }
-      "#},
-      Browsers {
-        chrome: Some(85 << 16),
-        ..Browsers::default()
-      },
-    );
-    prefix_test(
-      r#"
-      @media (width > max(10px, 1rem)) {
-        .foo { color: yellow; }
-      }
-      "#,
-      indoc! { r#"
-        @media (min-width: calc(max(10px, 1rem) + .001px)) {
-          .foo {
-            color: #ff0;
-          }
+
+        to {
+          background: pink;
         }
-      "#},
-      Browsers {
-        chrome: Some(85 << 16),
-        ..Browsers::default()
-      },
-    );
-    prefix_test(
-      r#"
-      @media (width > 0) {
-        .foo { color: yellow; }
       }
-      "#,
-      indoc! { r#"
-        @media (min-width: .001px) {
-          .foo {
-            color: #ff0;
-          }
+
+      @keyframes test {
+        from {
+          background: green;
         }
-      "#},
-      Browsers {
-        chrome: Some(85 << 16),
-        ..Browsers::default()
-      },
-    );
-    prefix_test(
-      r#"
-      @media (min-resolution: 2dppx) {
-        .foo { color: yellow; }
-      }
-      "#,
-      indoc! { r#"
-        @media (-webkit-min-device-pixel-ratio: 2), (min-resolution: 2dppx) {
-          .foo {
-            color: #ff0;
-          }
+
+        to {
+          background: #00f;
         }
-      "#},
-      Browsers {
-        safari: Some(15 << 16),
-        ..Browsers::default()
-      },
-    );
-    prefix_test(
-      r#"
-      @media (min-resolution: 2dppx) {
-        .foo { color: yellow; }
       }
-      "#,
-      indoc! { r#"
-        @media (min--moz-device-pixel-ratio: 2), (min-resolution: 2dppx) {
-          .foo {
-            color: #ff0;
-          }
-        }
-      "#},
+    "#},
       Browsers {
-        firefox: Some(10 << 16),
+        safari: Some(10 << 16),
+        firefox: Some(17 << 16),
         ..Browsers::default()
       },
     );
-    prefix_test(
+
+    minify_test(
       r#"
-      @media (resolution > 2dppx) {
-        .foo { color: yellow; }
-      }
-      "#,
-      indoc! { r#"
-        @media (-webkit-min-device-pixel-ratio: 2.001), (min-resolution: 2.001dppx) {
-          .foo {
-            color: #ff0;
-          }
+      @keyframes test {
+        100% {
+          background: blue
         }
-      "#},
-      Browsers {
-        safari: Some(15 << 16),
-        ..Browsers::default()
-      },
-    );
-    prefix_test(
-      r#"
-      @media (resolution >= 300dpi) {
-        .foo { color: yellow; }
       }
-      "#,
-      indoc! { r#"
-        @media (-webkit-min-device-pixel-ratio: 3.125), (min-resolution: 300dpi) {
-          .foo {
-            color: #ff0;
-          }
+
+      @keyframes test {
+        100% {
+          background: red
         }
-      "#},
-      Browsers {
-        safari: Some(15 << 16),
-        ..Browsers::default()
-      },
+      }
+    "#,
+      "@keyframes test{to{background:red}}",
     );
-    prefix_test(
+    minify_test(
       r#"
-      @media (min-resolution: 113.38dpcm) {
-        .foo { color: yellow; }
+      @keyframes test {
+        100% {
+          background: blue
+        }
       }
-      "#,
-      indoc! { r#"
-        @media (-webkit-min-device-pixel-ratio: 2.99985), (min--moz-device-pixel-ratio: 2.99985), (min-resolution: 113.38dpcm) {
-          .foo {
-            color: #ff0;
-          }
+
+      @-webkit-keyframes test {
+        100% {
+          background: red
         }
-      "#},
-      Browsers {
-        safari: Some(15 << 16),
-        firefox: Some(10 << 16),
-        ..Browsers::default()
-      },
+      }
+    "#,
+      "@keyframes test{to{background:#00f}}@-webkit-keyframes test{to{background:red}}",
     );
-    prefix_test(
+  }
+
+  #[test]
+  fn test_important() {
+    test(
       r#"
-      @media (color) and (min-resolution: 2dppx) {
-        .foo { color: yellow; }
+      .foo {
+        align-items: center;
+        justify-items: center !important;
       }
-      "#,
-      indoc! { r#"
-        @media (color) and (-webkit-min-device-pixel-ratio: 2), (color) and (min-resolution: 2dppx) {
-          .foo {
-            color: #ff0;
-          }
-        }
-      "#},
-      Browsers {
-        safari: Some(15 << 16),
-        ..Browsers::default()
-      },
+    "#,
+      indoc! {r#"
+      .foo {
+        align-items: center;
+        justify-items: center !important;
+      }
+    "#},
     );
-    prefix_test(
+
+    test(
       r#"
-      @media (min-resolution: 2dppx),
-             (min-resolution: 192dpi) {
-        .foo { color: yellow; }
+      .foo {
+        justify-items: center !important;
+        align-items: center;
       }
-      "#,
-      indoc! { r#"
-        @media (-webkit-min-device-pixel-ratio: 2), (min--moz-device-pixel-ratio: 2), (min-resolution: 2dppx), (min-resolution: 192dpi) {
-          .foo {
-            color: #ff0;
-          }
-        }
-      "#},
-      Browsers {
-        safari: Some(15 << 16),
-        firefox: Some(10 << 16),
-        ..Browsers::default()
-      },
+    "#,
+      indoc! {r#"
+      .foo {
+        align-items: center;
+        justify-items: center !important;
+      }
+    "#},
     );
-    prefix_test(
+
+    minify_test(
       r#"
-      @media only screen and (min-resolution: 124.8dpi) {
-        .foo { color: yellow; }
+      .foo {
+        font-family: SFMono-Regular, Menlo, Monaco, Consolas, "Liberation Mono", "Courier New", monospace !important;
       }
-      "#,
-      indoc! { r#"
-        @media only screen and (-webkit-min-device-pixel-ratio: 1.3), only screen and (min--moz-device-pixel-ratio: 1.3), only screen and (min-resolution: 124.8dpi) {
-          .foo {
-            color: #ff0;
-          }
-        }
-      "#},
-      Browsers {
-        safari: Some(15 << 16),
-        firefox: Some(10 << 16),
-        ..Browsers::default()
-      },
+    "#,
+      ".foo{font-family:SFMono-Regular,Menlo,Monaco,Consolas,Liberation Mono,Courier New,monospace!important}",
     );
+  }
 
-    error_test(
-      "@media (min-width: hi) { .foo { color: chartreuse }}",
-      ParserError::InvalidMediaQuery,
-    );
-    error_test(
-      "@media (width >= hi) { .foo { color: chartreuse }}",
-      ParserError::InvalidMediaQuery,
-    );
-    error_test(
-      "@media (width >= 2/1) { .foo { color: chartreuse }}",
-      ParserError::UnexpectedToken(Token::Delim('/')),
+  #[test]
+  fn test_calc() {
+    minify_test(".foo { width: calc(20px * 2) }", ".foo{width:40px}");
+    minify_test(".foo { font-size: calc(100vw / 35) }", ".foo{font-size:2.85714vw}");
+    minify_test(".foo { width: calc(20px * 2 * 3) }", ".foo{width:120px}");
+    minify_test(".foo { width: calc(20px + 30px) }", ".foo{width:50px}");
+    minify_test(".foo { width: calc(20px + 30px + 40px) }", ".foo{width:90px}");
+    minify_test(".foo { width: calc(100% - 30px) }", ".foo{width:calc(100% - 30px)}");
+    minify_test(
+      ".foo { width: calc(100% - 30px + 20px) }",
+      ".foo{width:calc(100% - 10px)}",
     );
-    error_test(
-      "@media (600px <= min-height) { .foo { color: chartreuse }}",
-      ParserError::InvalidMediaQuery,
+    minify_test(
+      ".foo { width: calc(20px + 100% - 30px) }",
+      ".foo{width:calc(100% - 10px)}",
     );
-    error_test(
-      "@media (scan >= 1) { .foo { color: chartreuse }}",
-      ParserError::InvalidMediaQuery,
+    minify_test(
+      ".foo { width: calc(20px + 100% + 10vw - 30px) }",
+      ".foo{width:calc(100% - 10px + 10vw)}",
     );
-    error_test(
-      "@media (min-scan: interlace) { .foo { color: chartreuse }}",
-      ParserError::InvalidMediaQuery,
+    minify_test(
+      ".foo { width: calc(20px + 100% - 30px) }",
+      ".foo{width:calc(100% - 10px)}",
     );
-    error_test(
-      "@media (1px <= width <= bar) { .foo { color: chartreuse }}",
-      ParserError::InvalidMediaQuery,
+    minify_test(
+      ".foo { width: calc(2 * (100% - 20px)) }",
+      ".foo{width:calc(200% - 40px)}",
     );
-    error_test(
-      "@media (1px <= min-width <= 2px) { .foo { color: chartreuse }}",
-      ParserError::InvalidMediaQuery,
+    minify_test(
+      ".foo { width: calc((100% - 20px) * 2) }",
+      ".foo{width:calc(200% - 40px)}",
     );
-    error_test(
-      "@media (1px <= scan <= 2px) { .foo { color: chartreuse }}",
-      ParserError::InvalidMediaQuery,
+    minify_test(".foo { width: calc(100% - 20px * 2) }", ".foo{width:calc(100% - 40px)}");
+    minify_test(".foo { width: calc(1px + 1px) }", ".foo{width:2px}");
+    minify_test(".foo { width: calc(100vw / 2) }", ".foo{width:50vw}");
+    minify_test(".foo { width: calc(50px - (20px - 30px)) }", ".foo{width:60px}");
+    minify_test(".foo { width: calc(100px - (100px - 100%)) }", ".foo{width:100%}");
+    minify_test(
+      ".foo { width: calc(100px + (100px - 100%)) }",
+      ".foo{width:calc(200px - 100%)}",
     );
-    error_test(
-      "@media (grid: 10) { .foo { color: chartreuse }}",
-      ParserError::InvalidMediaQuery,
+    minify_test(
+      ".foo { width: calc(1px - (2em + 3%)) }",
+      ".foo{width:calc(1px + -2em - 3%)}",
+    ); // TODO: fix sign
+    minify_test(
+      ".foo { width: calc((100vw - 50em) / 2) }",
+      ".foo{width:calc(50vw - 25em)}",
     );
-    error_test(
-      "@media (prefers-color-scheme = dark) { .foo { color: chartreuse }}",
-      ParserError::InvalidMediaQuery,
+    minify_test(
+      ".foo { width: calc(1px - (2em + 4vh + 3%)) }",
+      ".foo{width:calc(1px + -2em - 4vh - 3%)}",
+    ); // TODO
+    minify_test(
+      ".foo { width: calc(1px + (2em + (3vh + 4px))) }",
+      ".foo{width:calc(2em + 3vh + 5px)}",
     );
-  }
-
-  #[test]
-  fn test_merge_layers() {
-    test(
-      r#"
-      @layer foo {
-        .foo {
-          color: red;
-        }
-      }
-      @layer foo {
-        .foo {
-          background: #fff;
-        }
-
-        .baz {
-          color: #fff;
-        }
-      }
-      "#,
-      indoc! {r#"
-      @layer foo {
-        .foo {
-          color: red;
-          background: #fff;
-        }
-
-        .baz {
-          color: #fff;
-        }
-      }
-    "#},
+    minify_test(
+      ".foo { width: calc(1px - (2em + 4px - 6vh) / 2) }",
+      ".foo{width:calc(-1em - 1px + 3vh)}",
     );
-    test(
-      r#"
-      @layer a {}
-      @layer b {}
-
-      @layer b {
-        foo {
-          color: red;
-        }
-      }
-
-      @layer a {
-        bar {
-          color: yellow;
-        }
-      }
-      "#,
-      indoc! {r#"
-      @layer a {
-        bar {
-          color: #ff0;
-        }
-      }
-
-      @layer b {
-        foo {
-          color: red;
-        }
-      }
-    "#},
+    minify_test(
+      ".foo { width: calc(100% - calc(50% + 25px)) }",
+      ".foo{width:calc(50% - 25px)}",
     );
-
-    test(
-      r#"
-      @layer a {}
-      @layer b {}
-
-      @layer b {
-        foo {
-          color: red;
-        }
-      }
-      "#,
-      indoc! {r#"
-      @layer a;
-
-      @layer b {
-        foo {
-          color: red;
-        }
-      }
-    "#},
+    minify_test(".foo { width: calc(1px/100) }", ".foo{width:.01px}");
+    minify_test(
+      ".foo { width: calc(100vw / 2 - 6px + 0px) }",
+      ".foo{width:calc(50vw - 6px)}",
     );
-
-    test(
-      r#"
-      @layer a;
-      @layer b;
-      @layer c;
-      "#,
-      indoc! {r#"
-      @layer a, b, c;
-    "#},
+    minify_test(".foo { width: calc(1px + 1) }", ".foo{width:calc(1px + 1)}");
+    minify_test(
+      ".foo { width: calc( (1em - calc( 10px + 1em)) / 2) }",
+      ".foo{width:-5px}",
     );
-
-    test(
-      r#"
-      @layer a {}
-      @layer b {}
-      @layer c {}
-      "#,
-      indoc! {r#"
-      @layer a, b, c;
-    "#},
+    minify_test(
+      ".foo { width: calc((100px - 1em) + (-50px + 1em)) }",
+      ".foo{width:50px}",
     );
-
-    test(
-      r#"
-      @layer a;
-      @layer b {
-        .foo {
-          color: red;
-        }
-      }
-      @layer c {}
-      "#,
-      indoc! {r#"
-      @layer a;
-
-      @layer b {
-        .foo {
-          color: red;
-        }
-      }
-
-      @layer c;
-    "#},
+    minify_test(
+      ".foo { width: calc(100% + (2 * 100px) - ((75.37% - 63.5px) - 900px)) }",
+      ".foo{width:calc(24.63% + 1163.5px)}",
     );
-
-    test(
-      r#"
-      @layer a, b;
-      @layer c {}
-
-      @layer d {
-        foo {
-          color: red;
-        }
-      }
-      "#,
-      indoc! {r#"
-      @layer a, b, c;
-
-      @layer d {
-        foo {
-          color: red;
-        }
-      }
-    "#},
+    minify_test(
+      ".foo { width: calc(((((100% + (2 * 30px) + 63.5px) / 0.7537) - (100vw - 60px)) / 2) + 30px) }",
+      ".foo{width:calc(66.3394% + 141.929px - 50vw)}",
     );
-
-    test(
-      r#"
-      @layer a;
-      @layer b;
-      @import "a.css" layer(x);
-      @layer c;
-
-      @layer d {
-        foo {
-          color: red;
-        }
-      }
-      "#,
-      indoc! {r#"
-      @layer a, b;
-      @import "a.css" layer(x);
-      @layer c;
-
-      @layer d {
-        foo {
-          color: red;
-        }
-      }
-    "#},
+    minify_test(
+      ".foo { width: calc(((75.37% - 63.5px) - 900px) + (2 * 100px)) }",
+      ".foo{width:calc(75.37% - 763.5px)}",
     );
-
-    test(
-      r#"
-      @layer a, b, c;
-
-      @layer a {
-        foo {
-          color: red;
-        }
-      }
-      "#,
-      indoc! {r#"
-      @layer a {
-        foo {
-          color: red;
-        }
-      }
-
-      @layer b, c;
-    "#},
+    minify_test(
+      ".foo { width: calc((900px - (10% - 63.5px)) + (2 * 100px)) }",
+      ".foo{width:calc(1163.5px - 10%)}",
+    );
+    minify_test(".foo { width: calc(500px/0) }", ".foo{width:calc(500px/0)}");
+    minify_test(".foo { width: calc(500px/2px) }", ".foo{width:calc(500px/2px)}");
+    minify_test(".foo { width: calc(100% / 3 * 3) }", ".foo{width:100%}");
+    minify_test(".foo { width: calc(+100px + +100px) }", ".foo{width:200px}");
+    minify_test(".foo { width: calc(+100px - +100px) }", ".foo{width:0}");
+    minify_test(".foo { width: calc(200px * +1) }", ".foo{width:200px}");
+    minify_test(".foo { width: calc(200px / +1) }", ".foo{width:200px}");
+    minify_test(".foo { width: calc(1.1e+1px + 1.1e+1px) }", ".foo{width:22px}");
+    minify_test(".foo { border-width: calc(1px + 2px) }", ".foo{border-width:3px}");
+    minify_test(
+      ".foo { border-width: calc(1em + 2px + 2em + 3px) }",
+      ".foo{border-width:calc(3em + 5px)}",
     );
-  }
 
-  #[test]
-  fn test_merge_rules() {
-    test(
-      r#"
-      .foo {
-        color: red;
-      }
-      .bar {
-        color: red;
-      }
-    "#,
-      indoc! {r#"
-      .foo, .bar {
-        color: red;
-      }
-    "#},
+    minify_test(
+      ".foo { border-width: min(1em, 2px) }",
+      ".foo{border-width:min(1em,2px)}",
     );
-    test(
-      r#"
-      .foo {
-        color: red;
-      }
-      .foo {
-        background: green;
-      }
-    "#,
-      indoc! {r#"
-      .foo {
-        color: red;
-        background: green;
-      }
-    "#},
+    minify_test(
+      ".foo { border-width: min(1em + 2em, 2px + 2px) }",
+      ".foo{border-width:min(3em,4px)}",
     );
-    test(
-      r#"
-      .foo {
-        color: red;
-      }
-      .foo {
-        background: green !important;
-      }
-    "#,
-      indoc! {r#"
-      .foo {
-        color: red;
-        background: green !important;
-      }
-    "#},
+    minify_test(
+      ".foo { border-width: min(1em + 2px, 2px + 1em) }",
+      ".foo{border-width:min(1em + 2px,2px + 1em)}",
     );
-    test(
-      r#"
-      .foo {
-        background: red;
-      }
-      .foo {
-        background: green;
-      }
-    "#,
-      indoc! {r#"
-      .foo {
-        background: green;
-      }
-    "#},
+    minify_test(
+      ".foo { border-width: min(1em + 2px + 2px, 2px + 1em + 1px) }",
+      ".foo{border-width:min(1em + 4px,3px + 1em)}",
     );
-    test(
-      r#"
-      .foo {
-        --foo: red;
-        --foo: purple;
-      }
-      .foo {
-        --foo: green;
-      }
-    "#,
-      indoc! {r#"
-      .foo {
-        --foo: green;
-      }
-    "#},
+    minify_test(
+      ".foo { border-width: min(2px + 1px, 3px + 4px) }",
+      ".foo{border-width:3px}",
     );
-    test(
-      r#"
-      .foo {
-        color: red;
-      }
-
-      .bar {
-        background: green;
-      }
-    "#,
-      indoc! {r#"
-      .foo {
-        color: red;
-      }
-
-      .bar {
-        background: green;
-      }
-    "#},
+    minify_test(
+      ".foo { border-width: min(1px, 1em, 2px, 3in) }",
+      ".foo{border-width:min(1px,1em)}",
     );
-    test(
-      r#"
-      .foo {
-        color: red;
-      }
-
-      .baz {
-        color: blue;
-      }
 
-      .bar {
-        color: red;
-      }
-    "#,
-      indoc! {r#"
-      .foo {
-        color: red;
-      }
+    minify_test(
+      ".foo { border-width: max(1em, 2px) }",
+      ".foo{border-width:max(1em,2px)}",
+    );
+    minify_test(
+      ".foo { border-width: max(1em + 2em, 2px + 2px) }",
+      ".foo{border-width:max(3em,4px)}",
+    );
+    minify_test(
+      ".foo { border-width: max(1em + 2px, 2px + 1em) }",
+      ".foo{border-width:max(1em + 2px,2px + 1em)}",
+    );
+    minify_test(
+      ".foo { border-width: max(1em + 2px + 2px, 2px + 1em + 1px) }",
+      ".foo{border-width:max(1em + 4px,3px + 1em)}",
+    );
+    minify_test(
+      ".foo { border-width: max(2px + 1px, 3px + 4px) }",
+      ".foo{border-width:7px}",
+    );
+    minify_test(
+      ".foo { border-width: max(1px, 1em, 2px, 3in) }",
+      ".foo{border-width:max(3in,1em)}",
+    );
 
-      .baz {
-        color: #00f;
-      }
+    minify_test(".foo { border-width: clamp(1px, 2px, 3px) }", ".foo{border-width:2px}");
+    minify_test(".foo { border-width: clamp(1px, 10px, 3px) }", ".foo{border-width:3px}");
+    minify_test(".foo { border-width: clamp(5px, 2px, 10px) }", ".foo{border-width:5px}");
+    minify_test(
+      ".foo { border-width: clamp(100px, 2px, 10px) }",
+      ".foo{border-width:100px}",
+    );
+    minify_test(
+      ".foo { border-width: clamp(5px + 5px, 5px + 7px, 10px + 20px) }",
+      ".foo{border-width:12px}",
+    );
 
-      .bar {
-        color: red;
-      }
-    "#},
+    minify_test(
+      ".foo { border-width: clamp(1em, 2px, 4vh) }",
+      ".foo{border-width:clamp(1em,2px,4vh)}",
     );
-    test(
-      r#"
-      .foo {
-        background: red;
-      }
-      .bar {
-        background: red;
-      }
-      .foo {
-        color: green;
-      }
-      .bar {
-        color: green;
-      }
-    "#,
-      indoc! {r#"
-      .foo, .bar {
-        color: green;
-        background: red;
-      }
-    "#},
+    minify_test(
+      ".foo { border-width: clamp(1em, 2em, 4vh) }",
+      ".foo{border-width:min(2em,4vh)}",
     );
-    test(
-      r#"
-      .foo, .bar {
-        background: red;
-      }
-      .foo {
-        color: green;
-      }
-      .bar {
-        color: green;
-      }
-    "#,
-      indoc! {r#"
-      .foo, .bar {
-        color: green;
-        background: red;
-      }
-    "#},
-    );
-    test(
-      r#"
-      .foo {
-        background: red;
-      }
-      .foo {
-        color: green;
-      }
-      .bar {
-        background: red;
-      }
-      .bar {
-        color: green;
-      }
-    "#,
-      indoc! {r#"
-      .foo, .bar {
-        color: green;
-        background: red;
-      }
-    "#},
+    minify_test(
+      ".foo { border-width: clamp(1em, 2vh, 4vh) }",
+      ".foo{border-width:max(1em,2vh)}",
     );
-    test(
-      r#"
-      [foo="bar"] {
-        color: red;
-      }
-      .bar {
-        color: red;
-      }
-    "#,
-      indoc! {r#"
-      [foo="bar"], .bar {
-        color: red;
-      }
-    "#},
+    minify_test(
+      ".foo { border-width: clamp(1px, 1px + 2em, 4px) }",
+      ".foo{border-width:clamp(1px,1px + 2em,4px)}",
     );
-    test(
-      r#"
-      .a {
-        color: red;
-      }
-      .b {
-        color: green;
-      }
-      .a {
-        color: red;
-      }
-    "#,
-      indoc! {r#"
-      .b {
-        color: green;
-      }
+    minify_test(".foo { border-width: clamp(1px, 2pt, 1in) }", ".foo{border-width:2pt}");
 
-      .a {
-        color: red;
-      }
-    "#},
+    minify_test(
+      ".foo { top: calc(-1 * clamp(1.75rem, 8vw, 4rem)) }",
+      ".foo{top:calc(-1*clamp(1.75rem,8vw,4rem))}",
     );
-    test(
-      r#"
-      .a {
-        color: red;
-      }
-      .b {
-        color: green;
-      }
-      .a {
-        color: pink;
-      }
-    "#,
-      indoc! {r#"
-      .b {
-        color: green;
-      }
-
-      .a {
-        color: pink;
-      }
-    "#},
+    minify_test(
+      ".foo { top: calc(-1 * min(1.75rem, 8vw, 4rem)) }",
+      ".foo{top:calc(-1*min(1.75rem,8vw))}",
     );
-    test(
-      r#"
-      .a:foo(#000) {
-        color: red;
-      }
-      .b {
-        color: green;
-      }
-      .a:foo(#ff0) {
-        color: pink;
-      }
-    "#,
-      indoc! {r#"
-      .a:foo(#000) {
-        color: red;
-      }
-
-      .b {
-        color: green;
-      }
-
-      .a:foo(#ff0) {
-        color: pink;
-      }
-    "#},
+    minify_test(
+      ".foo { top: calc(-1 * max(1.75rem, 8vw, 4rem)) }",
+      ".foo{top:calc(-1*max(4rem,8vw))}",
     );
-    test(
-      r#"
-      .a {
-        border-radius: 10px;
-      }
-      .b {
-        color: green;
-      }
-      .a {
-        border-radius: 10px;
-      }
-    "#,
-      indoc! {r#"
-      .b {
-        color: green;
-      }
-
-      .a {
-        border-radius: 10px;
-      }
-    "#},
+    minify_test(
+      ".foo { top: calc(clamp(1.75rem, 8vw, 4rem) * -1) }",
+      ".foo{top:calc(-1*clamp(1.75rem,8vw,4rem))}",
+    );
+    minify_test(
+      ".foo { top: calc(min(1.75rem, 8vw, 4rem) * -1) }",
+      ".foo{top:calc(-1*min(1.75rem,8vw))}",
+    );
+    minify_test(
+      ".foo { top: calc(max(1.75rem, 8vw, 4rem) * -1) }",
+      ".foo{top:calc(-1*max(4rem,8vw))}",
+    );
+    minify_test(
+      ".foo { top: calc(clamp(1.75rem, 8vw, 4rem) / 2) }",
+      ".foo{top:calc(clamp(1.75rem,8vw,4rem)/2)}",
+    );
+    minify_test(
+      ".foo { top: calc(min(1.75rem, 8vw, 4rem) / 2) }",
+      ".foo{top:calc(min(1.75rem,8vw)/2)}",
+    );
+    minify_test(
+      ".foo { top: calc(max(1.75rem, 8vw, 4rem) / 2) }",
+      ".foo{top:calc(max(4rem,8vw)/2)}",
+    );
+    minify_test(
+      ".foo { top: calc(0.5 * clamp(1.75rem, 8vw, 4rem)) }",
+      ".foo{top:calc(clamp(1.75rem,8vw,4rem)/2)}",
+    );
+    minify_test(
+      ".foo { top: calc(1 * clamp(1.75rem, 8vw, 4rem)) }",
+      ".foo{top:calc(clamp(1.75rem,8vw,4rem))}",
+    );
+    minify_test(
+      ".foo { top: calc(2 * clamp(1.75rem, 8vw, 4rem) / 2) }",
+      ".foo{top:calc(clamp(1.75rem,8vw,4rem))}",
     );
-    test(
-      r#"
-      .a {
-        border-radius: 10px;
-      }
-      .b {
-        color: green;
-      }
-      .a {
-        -webkit-border-radius: 10px;
-      }
-    "#,
-      indoc! {r#"
-      .a {
-        border-radius: 10px;
-      }
 
-      .b {
-        color: green;
-      }
+    minify_test(".foo { width: max(0px, 1vw) }", ".foo{width:max(0px,1vw)}");
 
-      .a {
-        -webkit-border-radius: 10px;
-      }
-    "#},
+    prefix_test(
+      ".foo { border-width: clamp(1em, 2px, 4vh) }",
+      indoc! { r#"
+        .foo {
+          border-width: max(1em, min(2px, 4vh));
+        }
+      "#},
+      Browsers {
+        safari: Some(12 << 16),
+        ..Browsers::default()
+      },
     );
-    test(
-      r#"
-      .a {
-        border-radius: 10px;
-      }
-      .b {
-        color: green;
-      }
-      .a {
-        border-radius: var(--foo);
-      }
-    "#,
-      indoc! {r#"
-      .b {
-        color: green;
-      }
 
-      .a {
-        border-radius: var(--foo);
-      }
-    "#},
+    prefix_test(
+      ".foo { border-width: clamp(1em, 2px, 4vh) }",
+      indoc! { r#"
+        .foo {
+          border-width: clamp(1em, 2px, 4vh);
+        }
+      "#},
+      Browsers {
+        safari: Some(14 << 16),
+        ..Browsers::default()
+      },
     );
-    test(
-      r#"
-      .a {
-        border-radius: 10px;
-      }
-      .b {
-        color: green;
-      }
-      .c {
-        border-radius: 20px;
-      }
-    "#,
-      indoc! {r#"
-      .a {
-        border-radius: 10px;
-      }
-
-      .b {
-        color: green;
-      }
-
-      .c {
-        border-radius: 20px;
-      }
-    "#},
-    );
-    test(
-      r#"
-      @media print {
-        .a {
-          color: red;
-        }
-        .b {
-          color: green;
-        }
-        .a {
-          color: red;
-        }
-      }
-    "#,
-      indoc! {r#"
-      @media print {
-        .b {
-          color: green;
-        }
 
-        .a {
-          color: red;
-        }
-      }
-    "#},
+    minify_test(".foo { width: calc(1vh + 2vh) }", ".foo{width:3vh}");
+    minify_test(".foo { width: calc(1dvh + 2dvh) }", ".foo{width:3dvh}");
+    minify_test(".foo { width: calc(1lvh + 2lvh) }", ".foo{width:3lvh}");
+    minify_test(".foo { width: calc(1svh + 2svh) }", ".foo{width:3svh}");
+    minify_test(".foo { width: calc(1sVmin + 2Svmin) }", ".foo{width:3svmin}");
+    minify_test(".foo { width: calc(1ic + 2ic) }", ".foo{width:3ic}");
+    minify_test(".foo { width: calc(1ric + 2ric) }", ".foo{width:3ric}");
+    minify_test(".foo { width: calc(1cap + 2cap) }", ".foo{width:3cap}");
+    minify_test(".foo { width: calc(1lh + 2lh) }", ".foo{width:3lh}");
+    minify_test(".foo { width: calc(1cqw + 2cqw) }", ".foo{width:3cqw}");
+    minify_test(".foo { width: calc(1CQI + 2cqi) }", ".foo{width:3cqi}");
+    minify_test(".foo { width: calc(1cqmin + 2cqmax) }", ".foo{width:calc(1cqmin + 2cqmax)}");
+    minify_test(".foo { width: calc(1cqh + 2vh) }", ".foo{width:calc(1cqh + 2vh)}");
+    minify_test(".foo { width: max(1cqb, 2cqb, 10px) }", ".foo{width:max(2cqb,10px)}");
+    minify_test(".foo { width: calc(1x + 2x) }", ".foo{width:calc(1x + 2x)}");
+    minify_test(
+      ".foo { left: calc(50% - 100px + clamp(0px, calc(50vw - 50px), 100px)) }",
+      ".foo{left:calc(50% - 100px + clamp(0px,50vw - 50px,100px))}",
     );
-    test(
-      r#"
-      .a {
-        border-radius: 10px;
-      }
-      .b {
-        color: green;
-      }
-      .a {
-        border-radius: 20px;
-        color: pink;
-      }
-    "#,
-      indoc! {r#"
-      .a {
-        border-radius: 10px;
-      }
-
-      .b {
-        color: green;
-      }
-
-      .a {
-        color: pink;
-        border-radius: 20px;
-      }
-    "#},
+    minify_test(
+      ".foo { left: calc(10px + min(10px, 1rem) + max(2px, 1vw)) }",
+      ".foo{left:calc(10px + min(10px,1rem) + max(2px,1vw))}",
     );
-    test(
-      r#"
-      .a {
-        color: red;
-      }
-      .b {
-        color: green;
-      }
-      .a {
-        color: red;
-      }
-      .b {
-        color: green;
-      }
-    "#,
-      indoc! {r#"
-      .a {
-        color: red;
-      }
-
-      .b {
-        color: green;
-      }
-    "#},
+    minify_test(".foo { width: round(22px, 5px) }", ".foo{width:20px}");
+    minify_test(".foo { width: round(nearest, 22px, 5px) }", ".foo{width:20px}");
+    minify_test(".foo { width: round(down, 22px, 5px) }", ".foo{width:20px}");
+    minify_test(".foo { width: round(to-zero, 22px, 5px) }", ".foo{width:20px}");
+    minify_test(".foo { width: round(up, 22px, 5px) }", ".foo{width:25px}");
+    minify_test(".foo { width: round(23px, 5px) }", ".foo{width:25px}");
+    minify_test(".foo { width: round(nearest, 23px, 5px) }", ".foo{width:25px}");
+    minify_test(".foo { width: round(down, 23px, 5px) }", ".foo{width:20px}");
+    minify_test(".foo { width: round(to-zero, 23px, 5px) }", ".foo{width:20px}");
+    minify_test(".foo { width: round(up, 23px, 5px) }", ".foo{width:25px}");
+    minify_test(".foo { width: round(22px, 5vw) }", ".foo{width:round(22px,5vw)}");
+    minify_test(".foo { rotate: round(22deg, 5deg) }", ".foo{rotate:20deg}");
+    minify_test(".foo { rotate: round(22deg, 5deg) }", ".foo{rotate:20deg}");
+    minify_test(
+      ".foo { transition-duration: round(22ms, 5ms) }",
+      ".foo{transition-duration:20ms}",
     );
-
-    prefix_test(
-      r#"
-      [foo="bar"] {
-        color: red;
-      }
-      .bar {
-        color: red;
-      }
-    "#,
-      indoc! {r#"
-      [foo="bar"] {
-        color: red;
-      }
-
-      .bar {
-        color: red;
-      }
-    "#},
-      Browsers {
-        ie: Some(6 << 16),
-        ..Browsers::default()
-      },
+    minify_test(".foo { margin: round(to-zero, -23px, 5px) }", ".foo{margin:-20px}");
+    minify_test(".foo { margin: round(nearest, -23px, 5px) }", ".foo{margin:-25px}");
+    minify_test(".foo { margin: calc(10px * round(22, 5)) }", ".foo{margin:200px}");
+    minify_test(".foo { margin: calc(10px * round(2.5)) }", ".foo{margin:30px}");
+    minify_test(".foo { margin: calc(10px * round(down, 2.5)) }", ".foo{margin:20px}");
+    minify_test(".foo { z-index: round(up, 2.1) }", ".foo{z-index:3}");
+    minify_test(".foo { width: round(22px) }", ".foo{width:round(22px)}"); // invalid
+    minify_test(".foo { width: rem(18px, 5px) }", ".foo{width:3px}");
+    minify_test(".foo { width: rem(-18px, 5px) }", ".foo{width:-3px}");
+    minify_test(".foo { width: rem(18px, 5vw) }", ".foo{width:rem(18px,5vw)}");
+    minify_test(".foo { rotate: rem(-140deg, -90deg) }", ".foo{rotate:-50deg}");
+    minify_test(".foo { rotate: rem(140deg, -90deg) }", ".foo{rotate:50deg}");
+    minify_test(".foo { width: calc(10px * rem(18, 5)) }", ".foo{width:30px}");
+    minify_test(".foo { width: mod(18px, 5px) }", ".foo{width:3px}");
+    minify_test(".foo { width: mod(-18px, 5px) }", ".foo{width:2px}");
+    minify_test(".foo { rotate: mod(-140deg, -90deg) }", ".foo{rotate:-50deg}");
+    minify_test(".foo { rotate: mod(140deg, -90deg) }", ".foo{rotate:-40deg}");
+    minify_test(".foo { width: mod(18px, 5vw) }", ".foo{width:mod(18px,5vw)}");
+    minify_test(
+      ".foo { transform: rotateX(mod(140deg, -90deg)) rotateY(rem(140deg, -90deg)) }",
+      ".foo{transform:rotateX(-40deg)rotateY(50deg)}",
     );
+    minify_test(".foo { width: calc(10px * mod(18, 5)) }", ".foo{width:30px}");
+  }
 
-    prefix_test(
-      r#"
-      [foo="bar"] {
-        color: red;
-      }
-      .bar {
-        color: red;
-      }
-    "#,
-      indoc! {r#"
-      [foo="bar"], .bar {
-        color: red;
-      }
-    "#},
-      Browsers {
-        ie: Some(10 << 16),
-        ..Browsers::default()
-      },
+  #[test]
+  fn test_trig() {
+    minify_test(".foo { width: calc(2px * pi); }", ".foo{width:6.28319px}");
+    minify_test(".foo { width: calc(2px / pi); }", ".foo{width:.63662px}");
+    // minify_test(
+    //   ".foo { width: calc(2px * infinity); }",
+    //   ".foo{width:calc(2px*infinity)}",
+    // );
+    // minify_test(
+    //   ".foo { width: calc(2px * -infinity); }",
+    //   ".foo{width:calc(2px*-infinity)}",
+    // );
+    minify_test(".foo { width: calc(100px * sin(45deg))", ".foo{width:70.7107px}");
+    minify_test(".foo { width: calc(100px * sin(.125turn))", ".foo{width:70.7107px}");
+    minify_test(
+      ".foo { width: calc(100px * sin(3.14159265 / 4))",
+      ".foo{width:70.7107px}",
     );
-
-    test(
-      r#"
-      .foo:-moz-read-only {
-        color: red;
-      }
-    "#,
-      indoc! {r#"
-      .foo:-moz-read-only {
-        color: red;
-      }
-    "#},
+    minify_test(".foo { width: calc(100px * sin(pi / 4))", ".foo{width:70.7107px}");
+    minify_test(
+      ".foo { width: calc(100px * sin(22deg + 23deg))",
+      ".foo{width:70.7107px}",
     );
 
-    test(
-      r#"
-      .foo:-moz-read-only {
-        color: red;
-      }
+    minify_test(".foo { width: calc(2px * cos(45deg))", ".foo{width:1.41421px}");
+    minify_test(".foo { width: calc(2px * tan(45deg))", ".foo{width:2px}");
 
-      .foo:read-only {
-        color: red;
-      }
-    "#,
-      indoc! {r#"
-      .foo:-moz-read-only {
-        color: red;
-      }
+    minify_test(".foo { rotate: asin(sin(45deg))", ".foo{rotate:45deg}");
+    minify_test(".foo { rotate: asin(1)", ".foo{rotate:90deg}");
+    minify_test(".foo { rotate: asin(-1)", ".foo{rotate:-90deg}");
+    minify_test(".foo { rotate: asin(0.5)", ".foo{rotate:30deg}");
+    minify_test(".foo { rotate: asin(45deg)", ".foo{rotate:asin(45deg)}"); // invalid
+    minify_test(".foo { rotate: asin(-20)", ".foo{rotate:asin(-20)}"); // evaluates to NaN
+    minify_test(".foo { width: asin(sin(45deg))", ".foo{width:asin(sin(45deg))}"); // invalid
 
-      .foo:read-only {
-        color: red;
-      }
-    "#},
-    );
+    minify_test(".foo { rotate: acos(cos(45deg))", ".foo{rotate:45deg}");
+    minify_test(".foo { rotate: acos(-1)", ".foo{rotate:180deg}");
+    minify_test(".foo { rotate: acos(0)", ".foo{rotate:90deg}");
+    minify_test(".foo { rotate: acos(1)", ".foo{rotate:none}");
+    minify_test(".foo { rotate: acos(45deg)", ".foo{rotate:acos(45deg)}"); // invalid
+    minify_test(".foo { rotate: acos(-20)", ".foo{rotate:acos(-20)}"); // evaluates to NaN
+
+    minify_test(".foo { rotate: atan(tan(45deg))", ".foo{rotate:45deg}");
+    minify_test(".foo { rotate: atan(1)", ".foo{rotate:45deg}");
+    minify_test(".foo { rotate: atan(0)", ".foo{rotate:none}");
+    minify_test(".foo { rotate: atan(45deg)", ".foo{rotate:atan(45deg)}"); // invalid
+
+    minify_test(".foo { rotate: atan2(1px, -1px)", ".foo{rotate:135deg}");
+    minify_test(".foo { rotate: atan2(1vw, -1vw)", ".foo{rotate:135deg}");
+    minify_test(".foo { rotate: atan2(1, -1)", ".foo{rotate:135deg}");
+    minify_test(".foo { rotate: atan2(1ms, -1ms)", ".foo{rotate:135deg}");
+    minify_test(".foo { rotate: atan2(1%, -1%)", ".foo{rotate:135deg}");
+    minify_test(".foo { rotate: atan2(1deg, -1deg)", ".foo{rotate:135deg}");
+    minify_test(".foo { rotate: atan2(1x, -1x)", ".foo{rotate:135deg}");
+    minify_test(".foo { rotate: atan2(1dppx, 96dpi)", ".foo{rotate:45deg}");
+    minify_test(".foo { rotate: atan2(1khz, 1000hz)", ".foo{rotate:45deg}");
+    minify_test(".foo { rotate: atan2(1hz, -1hz)", ".foo{rotate:135deg}");
+    minify_test(".foo { rotate: atan2(1cm, 1mm)", ".foo{rotate:84.2894deg}");
+    minify_test(".foo { rotate: atan2(0, -1)", ".foo{rotate:180deg}");
+    minify_test(".foo { rotate: atan2(-1, 1)", ".foo{rotate:-45deg}");
+    // incompatible units
+    minify_test(".foo { rotate: atan2(1px, -1vw)", ".foo{rotate:atan2(1px,-1vw)}");
+  }
+
+  #[test]
+  fn test_exp() {
+    minify_test(".foo { width: hypot()", ".foo{width:hypot()}");
+    minify_test(".foo { width: hypot(1px)", ".foo{width:1px}");
+    minify_test(".foo { width: hypot(1px, 2px)", ".foo{width:2.23607px}");
+    minify_test(".foo { width: hypot(1px, 2px, 3px)", ".foo{width:3.74166px}");
+    minify_test(".foo { width: hypot(1px, 2vw)", ".foo{width:hypot(1px,2vw)}");
+    minify_test(".foo { width: hypot(1px, 2px, 3vw)", ".foo{width:hypot(1px,2px,3vw)}");
+    minify_test(".foo { width: calc(100px * hypot(3, 4))", ".foo{width:500px}");
+    minify_test(".foo { width: calc(1px * pow(2, sqrt(100))", ".foo{width:1024px}");
+    minify_test(".foo { width: calc(100px * pow(2, pow(2, 2)", ".foo{width:1600px}");
+    minify_test(".foo { width: calc(1px * log(1))", ".foo{width:0}");
+    minify_test(".foo { width: calc(1px * log(10, 10))", ".foo{width:1px}");
+    minify_test(".foo { width: calc(1px * exp(0))", ".foo{width:1px}");
+    minify_test(".foo { width: calc(1px * log(e))", ".foo{width:1px}");
+    minify_test(".foo { width: calc(1px * (e - exp(1)))", ".foo{width:0}");
+    minify_test(
+      ".foo { width: calc(1px * (exp(log(1) + exp(0)*2))",
+      ".foo{width:7.38906px}",
+    );
+  }
+
+  #[test]
+  fn test_sign() {
+    minify_test(".foo { width: abs(1px)", ".foo{width:1px}");
+    minify_test(".foo { width: abs(-1px)", ".foo{width:1px}");
+    minify_test(".foo { width: abs(1%)", ".foo{width:abs(1%)}"); // spec says percentages must be against resolved value
+
+    minify_test(".foo { width: calc(10px * sign(-1vw)", ".foo{width:-10px}");
+    minify_test(".foo { width: calc(10px * sign(1%)", ".foo{width:calc(10px*sign(1%))}");
+  }
+
+  #[test]
+  fn test_box_shadow() {
+    minify_test(
+      ".foo { box-shadow: 64px 64px 12px 40px rgba(0,0,0,0.4) }",
+      ".foo{box-shadow:64px 64px 12px 40px #0006}",
+    );
+    minify_test(
+      ".foo { box-shadow: 12px 12px 0px 8px rgba(0,0,0,0.4) inset }",
+      ".foo{box-shadow:inset 12px 12px 0 8px #0006}",
+    );
+    minify_test(
+      ".foo { box-shadow: inset 12px 12px 0px 8px rgba(0,0,0,0.4) }",
+      ".foo{box-shadow:inset 12px 12px 0 8px #0006}",
+    );
+    minify_test(
+      ".foo { box-shadow: 12px 12px 8px 0px rgba(0,0,0,0.4) }",
+      ".foo{box-shadow:12px 12px 8px #0006}",
+    );
+    minify_test(
+      ".foo { box-shadow: 12px 12px 0px 0px rgba(0,0,0,0.4) }",
+      ".foo{box-shadow:12px 12px #0006}",
+    );
+    minify_test(
+      ".foo { box-shadow: 64px 64px 12px 40px rgba(0,0,0,0.4), 12px 12px 0px 8px rgba(0,0,0,0.4) inset }",
+      ".foo{box-shadow:64px 64px 12px 40px #0006,inset 12px 12px 0 8px #0006}",
+    );
+    minify_test(
+      ".foo { box-shadow: 12px 12px 0 -4px yellow }",
+      ".foo{box-shadow:12px 12px 0 -4px #ff0}",
+    );
+    minify_test(
+      ".foo { box-shadow: 12px 12px -4px yellow }",
+      ".foo{box-shadow:12px 12px -4px yellow}",
+    );
+    minify_test(
+      ".foo { box-shadow: 1px 1px yellow, 12px 12px -4px yellow }",
+      ".foo{box-shadow:1px 1px yellow,12px 12px -4px yellow}",
+    );
 
     prefix_test(
-      r#"
-      .foo:-moz-read-only {
-        color: red;
-      }
+      ".foo { box-shadow: 12px 12px lab(40% 56.6 39) }",
+      indoc! { r#"
+        .foo {
+          box-shadow: 12px 12px #b32323;
+          box-shadow: 12px 12px lab(40% 56.6 39);
+        }
+      "#},
+      Browsers {
+        chrome: Some(90 << 16),
+        ..Browsers::default()
+      },
+    );
 
-      .foo:read-only {
-        color: red;
-      }
-    "#,
-      indoc! {r#"
-      .foo:read-only {
-        color: red;
-      }
-    "#},
+    prefix_test(
+      ".foo { box-shadow: 12px 12px lab(40% 56.6 39) }",
+      indoc! { r#"
+        .foo {
+          -webkit-box-shadow: 12px 12px #b32323;
+          box-shadow: 12px 12px #b32323;
+          box-shadow: 12px 12px lab(40% 56.6 39);
+        }
+      "#},
       Browsers {
-        firefox: Some(85 << 16),
+        chrome: Some(4 << 16),
         ..Browsers::default()
       },
     );
 
     prefix_test(
-      r#"
-      .foo:-moz-read-only {
-        color: red;
-      }
+      ".foo { box-shadow: 12px 12px lab(40% 56.6 39), 12px 12px yellow }",
+      indoc! { r#"
+        .foo {
+          -webkit-box-shadow: 12px 12px #b32323, 12px 12px #ff0;
+          box-shadow: 12px 12px #b32323, 12px 12px #ff0;
+          box-shadow: 12px 12px lab(40% 56.6 39), 12px 12px #ff0;
+        }
+      "#},
+      Browsers {
+        chrome: Some(4 << 16),
+        ..Browsers::default()
+      },
+    );
 
-      .bar {
-        color: yellow;
-      }
+    prefix_test(
+      ".foo { -webkit-box-shadow: 12px 12px #0006 }",
+      indoc! { r#"
+        .foo {
+          -webkit-box-shadow: 12px 12px rgba(0, 0, 0, .4);
+        }
+      "#},
+      Browsers {
+        chrome: Some(4 << 16),
+        ..Browsers::default()
+      },
+    );
 
-      .foo:read-only {
-        color: red;
-      }
-    "#,
-      indoc! {r#"
-      .foo:-moz-read-only {
-        color: red;
-      }
+    prefix_test(
+      ".foo {
+        -webkit-box-shadow: 12px 12px #0006;
+        -moz-box-shadow: 12px 12px #0009;
+      }",
+      indoc! { r#"
+        .foo {
+          -webkit-box-shadow: 12px 12px rgba(0, 0, 0, .4);
+          -moz-box-shadow: 12px 12px rgba(0, 0, 0, .6);
+        }
+      "#},
+      Browsers {
+        chrome: Some(4 << 16),
+        ..Browsers::default()
+      },
+    );
 
-      .bar {
-        color: #ff0;
-      }
+    prefix_test(
+      ".foo {
+        -webkit-box-shadow: 12px 12px #0006;
+        -moz-box-shadow: 12px 12px #0006;
+        box-shadow: 12px 12px #0006;
+      }",
+      indoc! { r#"
+        .foo {
+          box-shadow: 12px 12px #0006;
+        }
+      "#},
+      Browsers {
+        chrome: Some(95 << 16),
+        ..Browsers::default()
+      },
+    );
 
-      .foo:read-only {
-        color: red;
-      }
-    "#},
+    prefix_test(
+      ".foo { box-shadow: var(--foo) 12px lab(40% 56.6 39) }",
+      indoc! { r#"
+        .foo {
+          box-shadow: var(--foo) 12px #b32323;
+        }
+
+        @supports (color: lab(0% 0 0)) {
+          .foo {
+            box-shadow: var(--foo) 12px lab(40% 56.6 39);
+          }
+        }
+      "#},
       Browsers {
-        firefox: Some(85 << 16),
+        chrome: Some(90 << 16),
         ..Browsers::default()
       },
     );
 
     prefix_test(
       r#"
-      .foo:-moz-read-only {
-        color: red;
-      }
-
-      .foo:read-only {
-        color: red;
+      .foo {
+        box-shadow: 0px 0px 22px red;
+        box-shadow: 0px 0px max(2cqw, 22px) red;
       }
     "#,
       indoc! {r#"
-      .foo:-moz-read-only {
-        color: red;
-      }
-
-      .foo:read-only {
-        color: red;
+      .foo {
+        box-shadow: 0 0 22px red;
+        box-shadow: 0 0 max(2cqw, 22px) red;
       }
-    "#},
+    "#
+      },
       Browsers {
-        firefox: Some(36 << 16),
+        safari: Some(14 << 16),
         ..Browsers::default()
       },
     );
-
     prefix_test(
       r#"
-      .foo:read-only {
-        color: red;
+      .foo {
+        box-shadow: 0px 0px 22px red;
+        box-shadow: 0px 0px max(2cqw, 22px) red;
       }
     "#,
       indoc! {r#"
-      .foo:-moz-read-only {
-        color: red;
-      }
-
-      .foo:read-only {
-        color: red;
+      .foo {
+        box-shadow: 0 0 max(2cqw, 22px) red;
       }
-    "#},
+    "#
+      },
       Browsers {
-        firefox: Some(36 << 16),
+        safari: Some(16 << 16),
         ..Browsers::default()
       },
     );
 
     prefix_test(
       r#"
-      .foo:-webkit-full-screen {
-        color: red;
-      }
-      .foo:-moz-full-screen {
-        color: red;
-      }
-      .foo:-ms-fullscreen {
-        color: red;
-      }
-      .foo:fullscreen {
-        color: red;
+      .foo {
+        box-shadow: 0px 0px 22px red;
+        box-shadow: 0px 0px 22px lab(40% 56.6 39);
       }
     "#,
       indoc! {r#"
-      .foo:fullscreen {
-        color: red;
+      .foo {
+        box-shadow: 0 0 22px red;
+        box-shadow: 0 0 22px lab(40% 56.6 39);
       }
-    "#},
+    "#
+      },
       Browsers {
-        chrome: Some(96 << 16),
+        safari: Some(14 << 16),
         ..Browsers::default()
       },
     );
-
     prefix_test(
       r#"
-      .foo:fullscreen {
-        color: red;
+      .foo {
+        box-shadow: 0px 0px 22px red;
+        box-shadow: 0px 0px 22px lab(40% 56.6 39);
       }
     "#,
       indoc! {r#"
-      .foo:-webkit-full-screen {
-        color: red;
-      }
-
-      .foo:-moz-full-screen {
-        color: red;
-      }
-
-      .foo:-ms-fullscreen {
-        color: red;
-      }
-
-      .foo:fullscreen {
-        color: red;
+      .foo {
+        box-shadow: 0 0 22px lab(40% 56.6 39);
       }
-    "#},
+    "#
+      },
       Browsers {
-        chrome: Some(45 << 16),
-        firefox: Some(45 << 16),
-        ie: Some(11 << 16),
+        safari: Some(16 << 16),
         ..Browsers::default()
       },
     );
 
     prefix_test(
       r#"
-      .foo::placeholder {
-        color: red;
+      .foo {
+        box-shadow: var(--fallback);
+        box-shadow: 0px 0px 22px lab(40% 56.6 39);
       }
     "#,
       indoc! {r#"
-      .foo::-webkit-input-placeholder {
-        color: red;
-      }
-
-      .foo::-moz-placeholder {
-        color: red;
-      }
-
-      .foo::-ms-input-placeholder {
-        color: red;
-      }
-
-      .foo::placeholder {
-        color: red;
+      .foo {
+        box-shadow: var(--fallback);
+        box-shadow: 0 0 22px lab(40% 56.6 39);
       }
-    "#},
+    "#
+      },
       Browsers {
-        chrome: Some(45 << 16),
-        firefox: Some(45 << 16),
-        ie: Some(11 << 16),
+        safari: Some(16 << 16),
         ..Browsers::default()
       },
     );
+  }
+
+  #[test]
+  fn test_media() {
+    minify_test(
+      "@media (min-width: 240px) { .foo { color: chartreuse }}",
+      "@media (width>=240px){.foo{color:#7fff00}}",
+    );
+    minify_test(
+      "@media (width < 240px) { .foo { color: chartreuse }}",
+      "@media (width<240px){.foo{color:#7fff00}}",
+    );
+    minify_test(
+      "@media (width <= 240px) { .foo { color: chartreuse }}",
+      "@media (width<=240px){.foo{color:#7fff00}}",
+    );
+    minify_test(
+      "@media (width > 240px) { .foo { color: chartreuse }}",
+      "@media (width>240px){.foo{color:#7fff00}}",
+    );
+    minify_test(
+      "@media (width >= 240px) { .foo { color: chartreuse }}",
+      "@media (width>=240px){.foo{color:#7fff00}}",
+    );
+    minify_test(
+      "@media (240px < width) { .foo { color: chartreuse }}",
+      "@media (width>240px){.foo{color:#7fff00}}",
+    );
+    minify_test(
+      "@media (240px <= width) { .foo { color: chartreuse }}",
+      "@media (width>=240px){.foo{color:#7fff00}}",
+    );
+    minify_test(
+      "@media (240px > width) { .foo { color: chartreuse }}",
+      "@media (width<240px){.foo{color:#7fff00}}",
+    );
+    minify_test(
+      "@media (240px >= width) { .foo { color: chartreuse }}",
+      "@media (width<=240px){.foo{color:#7fff00}}",
+    );
+    minify_test(
+      "@media (100px < width < 200px) { .foo { color: chartreuse }}",
+      "@media (100px<width<200px){.foo{color:#7fff00}}",
+    );
+    minify_test(
+      "@media (100px <= width <= 200px) { .foo { color: chartreuse }}",
+      "@media (100px<=width<=200px){.foo{color:#7fff00}}",
+    );
+    minify_test(
+      "@media (min-width: 30em) and (max-width: 50em) { .foo { color: chartreuse }}",
+      "@media (width>=30em) and (width<=50em){.foo{color:#7fff00}}",
+    );
+    minify_test(
+      "@media screen, print { .foo { color: chartreuse }}",
+      "@media screen,print{.foo{color:#7fff00}}",
+    );
+    minify_test(
+      "@media (hover: hover) { .foo { color: chartreuse }}",
+      "@media (hover:hover){.foo{color:#7fff00}}",
+    );
+    minify_test(
+      "@media (hover) { .foo { color: chartreuse }}",
+      "@media (hover){.foo{color:#7fff00}}",
+    );
+    minify_test(
+      "@media (aspect-ratio: 11/5) { .foo { color: chartreuse }}",
+      "@media (aspect-ratio:11/5){.foo{color:#7fff00}}",
+    );
+    minify_test(
+      "@media (aspect-ratio: 2/1) { .foo { color: chartreuse }}",
+      "@media (aspect-ratio:2){.foo{color:#7fff00}}",
+    );
+    minify_test(
+      "@media (aspect-ratio: 2) { .foo { color: chartreuse }}",
+      "@media (aspect-ratio:2){.foo{color:#7fff00}}",
+    );
+    minify_test(
+      "@media (aspect-ratio: 1920/1080) { .foo { color: chartreuse }}",
+      "@media (aspect-ratio:16/9){.foo{color:#7fff00}}",
+    );
+    minify_test(
+      "@media (min-device-aspect-ratio: 4/2) { .foo { color: chartreuse }}",
+      "@media (device-aspect-ratio>=2){.foo{color:#7fff00}}",
+    );
+    minify_test(
+      "@container (aspect-ratio > 1920 / 1080) { .foo { color: chartreuse }}",
+      "@container (aspect-ratio>16/9){.foo{color:#7fff00}}",
+    );
+    minify_test(
+      "@media not screen and (color) { .foo { color: chartreuse }}",
+      "@media not screen and (color){.foo{color:#7fff00}}",
+    );
+    minify_test(
+      "@media only screen and (color) { .foo { color: chartreuse }}",
+      "@media only screen and (color){.foo{color:#7fff00}}",
+    );
+    minify_test(
+      "@media (update: slow) or (hover: none) { .foo { color: chartreuse }}",
+      "@media (update:slow) or (hover:none){.foo{color:#7fff00}}",
+    );
+    minify_test(
+      "@media (width < 600px) and (height < 600px) { .foo { color: chartreuse }}",
+      "@media (width<600px) and (height<600px){.foo{color:#7fff00}}",
+    );
+    minify_test(
+      "@media (not (color)) or (hover) { .foo { color: chartreuse }}",
+      "@media (not (color)) or (hover){.foo{color:#7fff00}}",
+    );
+    error_test(
+      "@media (example, all,), speech { .foo { color: chartreuse }}",
+      ParserError::UnexpectedToken(Token::Comma),
+    );
+    error_test(
+      "@media &test, speech { .foo { color: chartreuse }}",
+      ParserError::UnexpectedToken(Token::Delim('&')),
+    );
+    error_test(
+      "@media &test { .foo { color: chartreuse }}",
+      ParserError::UnexpectedToken(Token::Delim('&')),
+    );
+    minify_test(
+      "@media (min-width: calc(200px + 40px)) { .foo { color: chartreuse }}",
+      "@media (width>=240px){.foo{color:#7fff00}}",
+    );
+    minify_test(
+      "@media (min-width: calc(1em + 5px)) { .foo { color: chartreuse }}",
+      "@media (width>=calc(1em + 5px)){.foo{color:#7fff00}}",
+    );
+    minify_test("@media { .foo { color: chartreuse }}", ".foo{color:#7fff00}");
+    minify_test("@media all { .foo { color: chartreuse }}", ".foo{color:#7fff00}");
+    minify_test(
+      "@media not (((color) or (hover))) { .foo { color: chartreuse }}",
+      "@media not ((color) or (hover)){.foo{color:#7fff00}}",
+    );
+    minify_test(
+      "@media (hover) and ((color) and (test)) { .foo { color: chartreuse }}",
+      "@media (hover) and (color) and (test){.foo{color:#7fff00}}",
+    );
+    minify_test(
+      "@media (grid: 1) { .foo { color: chartreuse }}",
+      "@media (grid:1){.foo{color:#7fff00}}",
+    );
+    minify_test(
+      "@media (width >= calc(2px + 4px)) { .foo { color: chartreuse }}",
+      "@media (width>=6px){.foo{color:#7fff00}}",
+    );
 
     prefix_test(
       r#"
-      .foo::file-selector-button {
-        color: red;
-      }
-    "#,
-      indoc! {r#"
-      .foo::-webkit-file-upload-button {
-        color: red;
-      }
-
-      .foo::-ms-browse {
-        color: red;
-      }
-
-      .foo::file-selector-button {
-        color: red;
-      }
-    "#},
+        @media (width >= 240px) {
+          .foo {
+            color: chartreuse;
+          }
+        }
+      "#,
+      indoc! { r#"
+        @media (min-width: 240px) {
+          .foo {
+            color: #7fff00;
+          }
+        }
+      "#},
       Browsers {
-        chrome: Some(84 << 16),
-        ie: Some(10 << 16),
+        firefox: Some(60 << 16),
         ..Browsers::default()
       },
     );
 
     prefix_test(
       r#"
-      .foo::file-selector-button {
-        margin-inline-start: 2px;
-      }
-    "#,
-      indoc! {r#"
-      .foo:not(:-webkit-any(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi)))::-webkit-file-upload-button {
-        margin-left: 2px;
-      }
-
-      .foo:not(:is(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi)))::-ms-browse {
-        margin-left: 2px;
-      }
-
-      .foo:not(:is(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi)))::file-selector-button {
-        margin-left: 2px;
-      }
-
-      .foo:-webkit-any(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi))::-webkit-file-upload-button {
-        margin-right: 2px;
-      }
-
-      .foo:is(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi))::-ms-browse {
-        margin-right: 2px;
-      }
-
-      .foo:is(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi))::file-selector-button {
-        margin-right: 2px;
-      }
-    "#},
+        @media (width >= 240px) {
+          .foo {
+            color: chartreuse;
+          }
+        }
+      "#,
+      indoc! { r#"
+        @media (width >= 240px) {
+          .foo {
+            color: #7fff00;
+          }
+        }
+      "#},
       Browsers {
-        chrome: Some(84 << 16),
-        ie: Some(10 << 16),
+        firefox: Some(64 << 16),
         ..Browsers::default()
       },
     );
 
     prefix_test(
       r#"
-      .foo:placeholder-shown .bar { color: red; }
-      .foo:autofill .baz { color: red; }
+        @media (color > 2) {
+          .foo {
+            color: chartreuse;
+          }
+        }
       "#,
-      indoc! {r#"
-      .foo:placeholder-shown .bar {
-        color: red;
-      }
-
-      .foo:-webkit-autofill .baz {
-        color: red;
-      }
-
-      .foo:autofill .baz {
-        color: red;
-      }
+      indoc! { r#"
+        @media (min-color: 3) {
+          .foo {
+            color: #7fff00;
+          }
+        }
       "#},
       Browsers {
-        chrome: Some(103 << 16),
+        firefox: Some(60 << 16),
         ..Browsers::default()
       },
     );
 
     prefix_test(
       r#"
-      .foo:placeholder-shown .bar,.foo:autofill .baz{color:red}
+        @media (color < 2) {
+          .foo {
+            color: chartreuse;
+          }
+        }
       "#,
-      indoc! {r#"
-      :-webkit-any(.foo:placeholder-shown .bar, .foo:-webkit-autofill .baz) {
-        color: red;
-      }
-
-      :is(.foo:placeholder-shown .bar, .foo:autofill .baz) {
-        color: red;
-      }
+      indoc! { r#"
+        @media (max-color: 1) {
+          .foo {
+            color: #7fff00;
+          }
+        }
       "#},
       Browsers {
-        chrome: Some(103 << 16),
+        firefox: Some(60 << 16),
         ..Browsers::default()
       },
     );
 
     prefix_test(
       r#"
-      .foo:placeholder-shown .bar, .foo:-webkit-autofill .baz {
-        color: red;
-      }
-
-      .foo:placeholder-shown .bar, .foo:autofill .baz {
-        color: red;
-      }
+        @media (width > 240px) {
+          .foo {
+            color: chartreuse;
+          }
+        }
       "#,
-      indoc! {r#"
-      :-webkit-any(.foo:placeholder-shown .bar, .foo:-webkit-autofill .baz) {
-        color: red;
-      }
-
-      :is(.foo:placeholder-shown .bar, .foo:autofill .baz) {
-        color: red;
-      }
+      indoc! { r#"
+        @media (min-width: 240.001px) {
+          .foo {
+            color: #7fff00;
+          }
+        }
       "#},
       Browsers {
-        chrome: Some(103 << 16),
+        firefox: Some(60 << 16),
         ..Browsers::default()
       },
     );
 
-    test(
-      r#"
-      .foo:placeholder-shown .bar, .foo:-webkit-autofill .baz {
-        color: red;
-      }
-
-      .foo:placeholder-shown .bar, .foo:autofill .baz {
-        color: red;
-      }
-      "#,
-      indoc! {r#"
-      .foo:placeholder-shown .bar, .foo:-webkit-autofill .baz {
-        color: red;
-      }
-
-      .foo:placeholder-shown .bar, .foo:autofill .baz {
-        color: red;
-      }
-      "#},
-    );
-
     prefix_test(
       r#"
-      :hover, :focus-visible {
-        color: red;
-      }
+        @media (width <= 240px) {
+          .foo {
+            color: chartreuse;
+          }
+        }
       "#,
-      indoc! {r#"
-      :hover {
-        color: red;
-      }
-
-      :focus-visible {
-        color: red;
-      }
+      indoc! { r#"
+        @media (max-width: 240px) {
+          .foo {
+            color: #7fff00;
+          }
+        }
       "#},
       Browsers {
-        safari: Some(13 << 16),
+        firefox: Some(60 << 16),
         ..Browsers::default()
       },
     );
 
     prefix_test(
       r#"
-      .foo {
-        color: red;
-      }
-
-      :hover, :focus-visible {
-        color: red;
-      }
+        @media (width <= 240px) {
+          .foo {
+            color: chartreuse;
+          }
+        }
       "#,
-      indoc! {r#"
-      .foo, :hover {
-        color: red;
-      }
-
-      :focus-visible {
-        color: red;
-      }
+      indoc! { r#"
+        @media (width <= 240px) {
+          .foo {
+            color: #7fff00;
+          }
+        }
       "#},
       Browsers {
-        safari: Some(13 << 16),
+        firefox: Some(64 << 16),
         ..Browsers::default()
       },
     );
 
     prefix_test(
       r#"
-      :hover, :focus-visible {
-        margin-inline-start: 24px;
-      }
+        @media (width < 240px) {
+          .foo {
+            color: chartreuse;
+          }
+        }
       "#,
-      indoc! {r#"
-      :hover:not(:lang(ae, ar, arc, bcc, bqi, ckb, dv, fa, glk, he, ku, mzn, nqo, pnb, ps, sd, ug, ur, yi)) {
-        margin-left: 24px;
-      }
-
-      :hover:lang(ae, ar, arc, bcc, bqi, ckb, dv, fa, glk, he, ku, mzn, nqo, pnb, ps, sd, ug, ur, yi) {
-        margin-right: 24px;
-      }
-
-      :focus-visible:not(:lang(ae, ar, arc, bcc, bqi, ckb, dv, fa, glk, he, ku, mzn, nqo, pnb, ps, sd, ug, ur, yi)) {
-        margin-left: 24px;
-      }
-
-      :focus-visible:lang(ae, ar, arc, bcc, bqi, ckb, dv, fa, glk, he, ku, mzn, nqo, pnb, ps, sd, ug, ur, yi) {
-        margin-right: 24px;
-      }
+      indoc! { r#"
+        @media (max-width: 239.999px) {
+          .foo {
+            color: #7fff00;
+          }
+        }
       "#},
       Browsers {
-        safari: Some(11 << 16),
+        firefox: Some(60 << 16),
         ..Browsers::default()
       },
     );
 
     prefix_test(
       r#"
-      :focus-within, :focus-visible {
-        color: red;
-      }
+        @media (100px <= width <= 200px) {
+          .foo {
+            color: chartreuse;
+          }
+        }
       "#,
-      indoc! {r#"
-      :focus-within {
-        color: red;
-      }
-
-      :focus-visible {
-        color: red;
-      }
+      indoc! { r#"
+        @media (min-width: 100px) and (max-width: 200px) {
+          .foo {
+            color: #7fff00;
+          }
+        }
       "#},
       Browsers {
-        safari: Some(9 << 16),
+        firefox: Some(85 << 16),
         ..Browsers::default()
       },
     );
 
     prefix_test(
       r#"
-      :hover, :focus-visible {
-        color: red;
-      }
-      "#,
-      indoc! {r#"
-      :is(:hover, :focus-visible) {
-        color: red;
-      }
+        @media not (100px <= width <= 200px) {
+          .foo {
+            color: chartreuse;
+          }
+        }
+      "#,
+      indoc! { r#"
+        @media not ((min-width: 100px) and (max-width: 200px)) {
+          .foo {
+            color: #7fff00;
+          }
+        }
       "#},
       Browsers {
-        safari: Some(14 << 16),
+        firefox: Some(85 << 16),
         ..Browsers::default()
       },
     );
 
     prefix_test(
       r#"
-      a::after:hover, a::after:focus-visible {
-        color: red;
-      }
+        @media (hover) and (100px <= width <= 200px) {
+          .foo {
+            color: chartreuse;
+          }
+        }
       "#,
-      indoc! {r#"
-      a:after:hover {
-        color: red;
-      }
-
-      a:after:focus-visible {
-        color: red;
-      }
+      indoc! { r#"
+        @media (hover) and (min-width: 100px) and (max-width: 200px) {
+          .foo {
+            color: #7fff00;
+          }
+        }
       "#},
       Browsers {
-        safari: Some(14 << 16),
+        firefox: Some(85 << 16),
         ..Browsers::default()
       },
     );
 
     prefix_test(
       r#"
-      a:not(:hover), a:not(:focus-visible) {
-        color: red;
-      }
+        @media (hover) or (100px <= width <= 200px) {
+          .foo {
+            color: chartreuse;
+          }
+        }
       "#,
-      indoc! {r#"
-      :is(a:not(:hover), a:not(:focus-visible)) {
-        color: red;
-      }
+      indoc! { r#"
+        @media (hover) or ((min-width: 100px) and (max-width: 200px)) {
+          .foo {
+            color: #7fff00;
+          }
+        }
       "#},
       Browsers {
-        safari: Some(14 << 16),
+        firefox: Some(85 << 16),
         ..Browsers::default()
       },
     );
 
     prefix_test(
       r#"
-      a:has(:hover), a:has(:focus-visible) {
-        color: red;
-      }
+        @media (100px < width < 200px) {
+          .foo {
+            color: chartreuse;
+          }
+        }
       "#,
-      indoc! {r#"
-      :is(a:has(:hover), a:has(:focus-visible)) {
-        color: red;
-      }
+      indoc! { r#"
+        @media (min-width: 100.001px) and (max-width: 199.999px) {
+          .foo {
+            color: #7fff00;
+          }
+        }
       "#},
       Browsers {
-        safari: Some(14 << 16),
+        firefox: Some(85 << 16),
         ..Browsers::default()
       },
     );
 
     prefix_test(
       r#"
-      .foo.foo:hover, .bar:focus-visible {
-        color: red;
-      }
+        @media (200px >= width >= 100px) {
+          .foo {
+            color: chartreuse;
+          }
+        }
       "#,
-      indoc! {r#"
-      .foo.foo:hover {
-        color: red;
-      }
-
-      .bar:focus-visible {
-        color: red;
-      }
+      indoc! { r#"
+        @media (max-width: 200px) and (min-width: 100px) {
+          .foo {
+            color: #7fff00;
+          }
+        }
       "#},
       Browsers {
-        safari: Some(14 << 16),
+        firefox: Some(85 << 16),
         ..Browsers::default()
       },
     );
 
-    prefix_test(
+    test(
       r#"
-      a::unknown-a, a::unknown-b {
-        color: red;
+      @media not all {
+        .a {
+          color: green;
+        }
       }
       "#,
-      indoc! {r#"
-      a::unknown-a {
-        color: red;
-      }
-
-      a::unknown-b {
-        color: red;
-      }
-      "#},
-      Browsers {
-        safari: Some(14 << 16),
-        ..Browsers::default()
-      },
+      "\n",
     );
 
-    nesting_test_with_targets(
+    prefix_test(
       r#"
-      .foo {
-        padding-inline-start: 3px;
-
-        .bar {
-          padding-inline-start: 5px;
-        }
+      @media (width > calc(1px + 1rem)) {
+        .foo { color: yellow; }
       }
       "#,
-      indoc! {r#"
-      .foo:not(:lang(ae, ar, arc, bcc, bqi, ckb, dv, fa, glk, he, ku, mzn, nqo, pnb, ps, sd, ug, ur, yi)) {
-        padding-left: 3px;
-      }
-
-      .foo:lang(ae, ar, arc, bcc, bqi, ckb, dv, fa, glk, he, ku, mzn, nqo, pnb, ps, sd, ug, ur, yi) {
-        padding-right: 3px;
-      }
-
-      .foo .bar:not(:lang(ae, ar, arc, bcc, bqi, ckb, dv, fa, glk, he, ku, mzn, nqo, pnb, ps, sd, ug, ur, yi)) {
-        padding-left: 5px;
-      }
-
-      .foo .bar:lang(ae, ar, arc, bcc, bqi, ckb, dv, fa, glk, he, ku, mzn, nqo, pnb, ps, sd, ug, ur, yi) {
-        padding-right: 5px;
-      }
+      indoc! { r#"
+        @media (min-width: calc(1.001px + 1rem)) {
+          .foo {
+            color: #ff0;
+          }
+        }
       "#},
       Browsers {
-        safari: Some(12 << 16),
+        chrome: Some(85 << 16),
         ..Browsers::default()
-      }
-      .into(),
+      },
     );
-
     prefix_test(
       r#"
-      .foo::part(header), .foo::part(body) {
-        display: none
+      @media (width > max(10px, 1rem)) {
+        .foo { color: yellow; }
       }
       "#,
-      indoc! {r#"
-      .foo::part(header), .foo::part(body) {
-        display: none;
-      }
+      indoc! { r#"
+        @media (min-width: calc(max(10px, 1rem) + .001px)) {
+          .foo {
+            color: #ff0;
+          }
+        }
       "#},
       Browsers {
-        safari: Some(14 << 16),
+        chrome: Some(85 << 16),
         ..Browsers::default()
       },
     );
-
     prefix_test(
       r#"
-      .foo :is(.bar) {
-        color: red;
+      @media (width > 0) {
+        .foo { color: yellow; }
       }
       "#,
-      indoc! {r#"
-        .foo .bar {
-          color: red;
+      indoc! { r#"
+        @media (min-width: .001px) {
+          .foo {
+            color: #ff0;
+          }
         }
       "#},
       Browsers {
-        chrome: Some(87 << 16),
+        chrome: Some(85 << 16),
         ..Browsers::default()
       },
     );
-
     prefix_test(
       r#"
-      .foo :is(.bar), .bar :is(.baz) {
-        color: red;
+      @media (min-resolution: 2dppx) {
+        .foo { color: yellow; }
       }
       "#,
-      indoc! {r#"
-        .foo .bar, .bar .baz {
-          color: red;
+      indoc! { r#"
+        @media (-webkit-min-device-pixel-ratio: 2), (min-resolution: 2dppx) {
+          .foo {
+            color: #ff0;
+          }
         }
       "#},
       Browsers {
-        chrome: Some(87 << 16),
+        safari: Some(15 << 16),
         ..Browsers::default()
       },
     );
-
     prefix_test(
       r#"
-      .foo :is(.bar:focus-visible), .bar :is(.baz:hover) {
-        color: red;
+      @media (min-resolution: 2dppx) {
+        .foo { color: yellow; }
       }
       "#,
-      indoc! {r#"
-        .bar .baz:hover {
-          color: red;
-        }
-
-        .foo .bar:focus-visible {
-          color: red;
+      indoc! { r#"
+        @media (min--moz-device-pixel-ratio: 2), (min-resolution: 2dppx) {
+          .foo {
+            color: #ff0;
+          }
         }
       "#},
       Browsers {
-        chrome: Some(85 << 16),
+        firefox: Some(10 << 16),
         ..Browsers::default()
       },
     );
-
     prefix_test(
       r#"
-      *,
-      ::before,
-      ::after,
-      ::backdrop {
-        padding: 5px;
+      @media (resolution > 2dppx) {
+        .foo { color: yellow; }
       }
       "#,
-      indoc! {r#"
-        *, :before, :after {
-          padding: 5px;
-        }
-
-        ::-webkit-backdrop {
-          padding: 5px;
-        }
-
-        ::backdrop {
-          padding: 5px;
+      indoc! { r#"
+        @media (-webkit-min-device-pixel-ratio: 2.001), (min-resolution: 2.001dppx) {
+          .foo {
+            color: #ff0;
+          }
         }
       "#},
       Browsers {
-        chrome: Some(33 << 16),
+        safari: Some(15 << 16),
         ..Browsers::default()
       },
     );
-
-    test(
-      r#"
-      .foo:-webkit-any(.bar, .baz):after {
-        color: red;
-      }
-
-      .foo:is(.bar, .baz):after {
-        color: red;
-      }
-      "#,
-      indoc! {r#"
-        .foo:-webkit-any(.bar, .baz):after {
-          color: red;
-        }
-
-        .foo:is(.bar, .baz):after {
-          color: red;
-        }
-      "#},
-    );
-
     prefix_test(
       r#"
-      .foo:-webkit-any(.bar, .baz):after {
-        color: red;
-      }
-
-      .foo:is(.bar, .baz):after {
-        color: red;
+      @media (resolution >= 300dpi) {
+        .foo { color: yellow; }
       }
       "#,
-      indoc! {r#"
-        .foo:is(.bar, .baz):after {
-          color: red;
+      indoc! { r#"
+        @media (-webkit-min-device-pixel-ratio: 3.125), (min-resolution: 300dpi) {
+          .foo {
+            color: #ff0;
+          }
         }
       "#},
       Browsers {
-        safari: Some(16 << 16),
+        safari: Some(15 << 16),
         ..Browsers::default()
       },
     );
-
     prefix_test(
       r#"
-      .foo:-webkit-any(.bar):after {
-        color: red;
-      }
-
-      .foo:is(.bar, .baz):after {
-        color: red;
+      @media (min-resolution: 113.38dpcm) {
+        .foo { color: yellow; }
       }
       "#,
-      indoc! {r#"
-        .foo:-webkit-any(.bar):after {
-          color: red;
-        }
-
-        .foo:is(.bar, .baz):after {
-          color: red;
+      indoc! { r#"
+        @media (-webkit-min-device-pixel-ratio: 2.99985), (min--moz-device-pixel-ratio: 2.99985), (min-resolution: 113.38dpcm) {
+          .foo {
+            color: #ff0;
+          }
         }
       "#},
       Browsers {
-        safari: Some(16 << 16),
+        safari: Some(15 << 16),
+        firefox: Some(10 << 16),
         ..Browsers::default()
       },
     );
-
     prefix_test(
       r#"
-      .foo:-webkit-any(.bar, .baz):after {
-        color: red;
-      }
-
-      .foo:is(.bar, .baz):after {
-        color: red;
+      @media (color) and (min-resolution: 2dppx) {
+        .foo { color: yellow; }
       }
       "#,
-      indoc! {r#"
-        .foo:-webkit-any(.bar, .baz):after {
-          color: red;
-        }
-
-        .foo:is(.bar, .baz):after {
-          color: red;
+      indoc! { r#"
+        @media (color) and (-webkit-min-device-pixel-ratio: 2), (color) and (min-resolution: 2dppx) {
+          .foo {
+            color: #ff0;
+          }
         }
       "#},
       Browsers {
-        safari: Some(12 << 16),
+        safari: Some(15 << 16),
         ..Browsers::default()
       },
     );
-
     prefix_test(
       r#"
-      .foo:-webkit-any(.bar, .baz):after {
-        color: red;
-      }
-
-      .foo:-moz-any(.bar, .baz):after {
-        color: red;
+      @media (min-resolution: 2dppx),
+             (min-resolution: 192dpi) {
+        .foo { color: yellow; }
       }
       "#,
-      indoc! {r#"
-        .foo:-webkit-any(.bar, .baz):after {
-          color: red;
-        }
-
-        .foo:-moz-any(.bar, .baz):after {
-          color: red;
+      indoc! { r#"
+        @media (-webkit-min-device-pixel-ratio: 2), (min--moz-device-pixel-ratio: 2), (min-resolution: 2dppx), (min-resolution: 192dpi) {
+          .foo {
+            color: #ff0;
+          }
         }
       "#},
       Browsers {
-        safari: Some(12 << 16),
-        firefox: Some(67 << 16),
+        safari: Some(15 << 16),
+        firefox: Some(10 << 16),
         ..Browsers::default()
       },
     );
-
     prefix_test(
       r#"
-      .a {
-        padding-inline: var(--foo);
-      }
-
-      .a:-webkit-any(.b, .c) {
-        padding-inline: var(--foo);
+      @media only screen and (min-resolution: 124.8dpi) {
+        .foo { color: yellow; }
       }
       "#,
-      indoc! {r#"
-        .a {
-          padding-inline: var(--foo);
-        }
-
-        .a:-webkit-any(.b, .c) {
-          padding-inline: var(--foo);
+      indoc! { r#"
+        @media only screen and (-webkit-min-device-pixel-ratio: 1.3), only screen and (min--moz-device-pixel-ratio: 1.3), only screen and (min-resolution: 124.8dpi) {
+          .foo {
+            color: #ff0;
+          }
         }
       "#},
       Browsers {
-        safari: Some(12 << 16),
+        safari: Some(15 << 16),
+        firefox: Some(10 << 16),
         ..Browsers::default()
       },
     );
+
+    error_test(
+      "@media (min-width: hi) { .foo { color: chartreuse }}",
+      ParserError::InvalidMediaQuery,
+    );
+    error_test(
+      "@media (width >= hi) { .foo { color: chartreuse }}",
+      ParserError::InvalidMediaQuery,
+    );
+    error_test(
+      "@media (width >= 2/1) { .foo { color: chartreuse }}",
+      ParserError::UnexpectedToken(Token::Delim('/')),
+    );
+    error_test(
+      "@media (600px <= min-height) { .foo { color: chartreuse }}",
+      ParserError::InvalidMediaQuery,
+    );
+    error_test(
+      "@media (scan >= 1) { .foo { color: chartreuse }}",
+      ParserError::InvalidMediaQuery,
+    );
+    error_test(
+      "@media (min-scan: interlace) { .foo { color: chartreuse }}",
+      ParserError::InvalidMediaQuery,
+    );
+    error_test(
+      "@media (1px <= width <= bar) { .foo { color: chartreuse }}",
+      ParserError::InvalidMediaQuery,
+    );
+    error_test(
+      "@media (1px <= min-width <= 2px) { .foo { color: chartreuse }}",
+      ParserError::InvalidMediaQuery,
+    );
+    error_test(
+      "@media (1px <= scan <= 2px) { .foo { color: chartreuse }}",
+      ParserError::InvalidMediaQuery,
+    );
+    error_test(
+      "@media (grid: 10) { .foo { color: chartreuse }}",
+      ParserError::InvalidMediaQuery,
+    );
+    error_test(
+      "@media (prefers-color-scheme = dark) { .foo { color: chartreuse }}",
+      ParserError::InvalidMediaQuery,
+    );
   }
 
   #[test]
-  fn test_merge_media_rules() {
+  fn test_merge_layers() {
     test(
       r#"
-      @media (hover) {
+      @layer foo {
         .foo {
           color: red;
         }
       }
-      @media (hover) {
+      @layer foo {
         .foo {
           background: #fff;
         }
@@ -10249,7 +10636,7 @@ mod tests {
       }
       "#,
       indoc! {r#"
-      @media (hover) {
+      @layer foo {
         .foo {
           color: red;
           background: #fff;
@@ -10261,72 +10648,55 @@ mod tests {
       }
     "#},
     );
-
     test(
       r#"
-      @media (hover) {
-        .foo {
+      @layer a {}
+      @layer b {}
+
+      @layer b {
+        foo {
           color: red;
         }
       }
-      @media (min-width: 250px) {
-        .foo {
-          background: #fff;
-        }
 
-        .baz {
-          color: #fff;
+      @layer a {
+        bar {
+          color: yellow;
         }
       }
       "#,
       indoc! {r#"
-      @media (hover) {
-        .foo {
-          color: red;
+      @layer a {
+        bar {
+          color: #ff0;
         }
       }
 
-      @media (width >= 250px) {
-        .foo {
-          background: #fff;
-        }
-
-        .baz {
-          color: #fff;
+      @layer b {
+        foo {
+          color: red;
         }
       }
     "#},
     );
-  }
 
-  #[test]
-  fn test_merge_supports() {
     test(
       r#"
-      @supports (flex: 1) {
-        .foo {
-          color: red;
-        }
-      }
-      @supports (flex: 1) {
-        .foo {
-          background: #fff;
-        }
+      @layer a {}
+      @layer b {}
 
-        .baz {
-          color: #fff;
+      @layer b {
+        foo {
+          color: red;
         }
       }
       "#,
       indoc! {r#"
-      @supports (flex: 1) {
-        .foo {
-          color: red;
-          background: #fff;
-        }
+      @layer a;
 
-        .baz {
-          color: #fff;
+      @layer b {
+        foo {
+          color: red;
         }
       }
     "#},
@@ -10334,3847 +10704,8108 @@ mod tests {
 
     test(
       r#"
-      @supports (flex: 1) {
-        .foo {
-          color: red;
-        }
-      }
-      @supports (display: grid) {
-        .foo {
-          background: #fff;
-        }
+      @layer a;
+      @layer b;
+      @layer c;
+      "#,
+      indoc! {r#"
+      @layer a, b, c;
+    "#},
+    );
 
-        .baz {
-          color: #fff;
-        }
-      }
+    test(
+      r#"
+      @layer a {}
+      @layer b {}
+      @layer c {}
       "#,
       indoc! {r#"
-      @supports (flex: 1) {
+      @layer a, b, c;
+    "#},
+    );
+
+    test(
+      r#"
+      @layer a;
+      @layer b {
         .foo {
           color: red;
         }
       }
+      @layer c {}
+      "#,
+      indoc! {r#"
+      @layer a;
 
-      @supports (display: grid) {
+      @layer b {
         .foo {
-          background: #fff;
-        }
-
-        .baz {
-          color: #fff;
+          color: red;
         }
       }
+
+      @layer c;
     "#},
     );
-  }
-
-  #[test]
-  fn test_opacity() {
-    minify_test(".foo { opacity: 0 }", ".foo{opacity:0}");
-    minify_test(".foo { opacity: 0% }", ".foo{opacity:0}");
-    minify_test(".foo { opacity: 0.5 }", ".foo{opacity:.5}");
-    minify_test(".foo { opacity: 50% }", ".foo{opacity:.5}");
-    minify_test(".foo { opacity: 1 }", ".foo{opacity:1}");
-    minify_test(".foo { opacity: 100% }", ".foo{opacity:1}");
-  }
 
-  #[test]
-  fn test_transitions() {
-    minify_test(".foo { transition-duration: 500ms }", ".foo{transition-duration:.5s}");
-    minify_test(".foo { transition-duration: .5s }", ".foo{transition-duration:.5s}");
-    minify_test(".foo { transition-duration: 99ms }", ".foo{transition-duration:99ms}");
-    minify_test(".foo { transition-duration: .099s }", ".foo{transition-duration:99ms}");
-    minify_test(".foo { transition-duration: 2000ms }", ".foo{transition-duration:2s}");
-    minify_test(".foo { transition-duration: 2s }", ".foo{transition-duration:2s}");
-    minify_test(
-      ".foo { transition-duration: calc(1s - 50ms) }",
-      ".foo{transition-duration:.95s}",
-    );
-    minify_test(
-      ".foo { transition-duration: calc(1s - 50ms + 2s) }",
-      ".foo{transition-duration:2.95s}",
-    );
-    minify_test(
-      ".foo { transition-duration: calc((1s - 50ms) * 2) }",
-      ".foo{transition-duration:1.9s}",
-    );
-    minify_test(
-      ".foo { transition-duration: calc(2 * (1s - 50ms)) }",
-      ".foo{transition-duration:1.9s}",
-    );
-    minify_test(
-      ".foo { transition-duration: calc((2s + 50ms) - (1s - 50ms)) }",
-      ".foo{transition-duration:1.1s}",
-    );
-    minify_test(
-      ".foo { transition-duration: 500ms, 50ms }",
-      ".foo{transition-duration:.5s,50ms}",
-    );
-    minify_test(".foo { transition-delay: 500ms }", ".foo{transition-delay:.5s}");
-    minify_test(
-      ".foo { transition-property: background }",
-      ".foo{transition-property:background}",
-    );
-    minify_test(
-      ".foo { transition-property: background, opacity }",
-      ".foo{transition-property:background,opacity}",
-    );
-    minify_test(
-      ".foo { transition-timing-function: linear }",
-      ".foo{transition-timing-function:linear}",
-    );
-    minify_test(
-      ".foo { transition-timing-function: ease }",
-      ".foo{transition-timing-function:ease}",
-    );
-    minify_test(
-      ".foo { transition-timing-function: ease-in }",
-      ".foo{transition-timing-function:ease-in}",
-    );
-    minify_test(
-      ".foo { transition-timing-function: ease-out }",
-      ".foo{transition-timing-function:ease-out}",
-    );
-    minify_test(
-      ".foo { transition-timing-function: ease-in-out }",
-      ".foo{transition-timing-function:ease-in-out}",
-    );
-    minify_test(
-      ".foo { transition-timing-function: cubic-bezier(0.25, 0.1, 0.25, 1) }",
-      ".foo{transition-timing-function:ease}",
-    );
-    minify_test(
-      ".foo { transition-timing-function: cubic-bezier(0.42, 0, 1, 1) }",
-      ".foo{transition-timing-function:ease-in}",
-    );
-    minify_test(
-      ".foo { transition-timing-function: cubic-bezier(0, 0, 0.58, 1) }",
-      ".foo{transition-timing-function:ease-out}",
-    );
-    minify_test(
-      ".foo { transition-timing-function: cubic-bezier(0.42, 0, 0.58, 1) }",
-      ".foo{transition-timing-function:ease-in-out}",
-    );
-    minify_test(
-      ".foo { transition-timing-function: cubic-bezier(0.58, 0.2, 0.11, 1.2) }",
-      ".foo{transition-timing-function:cubic-bezier(.58,.2,.11,1.2)}",
-    );
-    minify_test(
-      ".foo { transition-timing-function: step-start }",
-      ".foo{transition-timing-function:step-start}",
-    );
-    minify_test(
-      ".foo { transition-timing-function: step-end }",
-      ".foo{transition-timing-function:step-end}",
-    );
-    minify_test(
-      ".foo { transition-timing-function: steps(1, start) }",
-      ".foo{transition-timing-function:step-start}",
-    );
-    minify_test(
-      ".foo { transition-timing-function: steps(1, jump-start) }",
-      ".foo{transition-timing-function:step-start}",
-    );
-    minify_test(
-      ".foo { transition-timing-function: steps(1, end) }",
-      ".foo{transition-timing-function:step-end}",
-    );
-    minify_test(
-      ".foo { transition-timing-function: steps(1, jump-end) }",
-      ".foo{transition-timing-function:step-end}",
-    );
-    minify_test(
-      ".foo { transition-timing-function: steps(5, jump-start) }",
-      ".foo{transition-timing-function:steps(5,start)}",
-    );
-    minify_test(
-      ".foo { transition-timing-function: steps(5, jump-end) }",
-      ".foo{transition-timing-function:steps(5,end)}",
-    );
-    minify_test(
-      ".foo { transition-timing-function: steps(5, jump-both) }",
-      ".foo{transition-timing-function:steps(5,jump-both)}",
-    );
-    minify_test(
-      ".foo { transition-timing-function: ease-in-out, cubic-bezier(0.42, 0, 1, 1) }",
-      ".foo{transition-timing-function:ease-in-out,ease-in}",
-    );
-    minify_test(
-      ".foo { transition-timing-function: cubic-bezier(0.42, 0, 1, 1), cubic-bezier(0.58, 0.2, 0.11, 1.2) }",
-      ".foo{transition-timing-function:ease-in,cubic-bezier(.58,.2,.11,1.2)}",
-    );
-    minify_test(
-      ".foo { transition-timing-function: step-start, steps(5, jump-start) }",
-      ".foo{transition-timing-function:step-start,steps(5,start)}",
-    );
-    minify_test(".foo { transition: width 2s ease }", ".foo{transition:width 2s}");
-    minify_test(
-      ".foo { transition: width 2s ease, height 1000ms cubic-bezier(0.25, 0.1, 0.25, 1) }",
-      ".foo{transition:width 2s,height 1s}",
-    );
-    minify_test(".foo { transition: width 2s 1s }", ".foo{transition:width 2s 1s}");
-    minify_test(".foo { transition: width 2s ease 1s }", ".foo{transition:width 2s 1s}");
-    minify_test(
-      ".foo { transition: ease-in 1s width 4s }",
-      ".foo{transition:width 1s ease-in 4s}",
-    );
-    minify_test(".foo { transition: opacity 0s .6s }", ".foo{transition:opacity 0s .6s}");
     test(
       r#"
-      .foo {
-        transition-property: opacity;
-        transition-duration: 0.09s;
-        transition-timing-function: ease-in-out;
-        transition-delay: 500ms;
+      @layer a, b;
+      @layer c {}
+
+      @layer d {
+        foo {
+          color: red;
+        }
       }
-    "#,
+      "#,
       indoc! {r#"
-      .foo {
-        transition: opacity 90ms ease-in-out .5s;
+      @layer a, b, c;
+
+      @layer d {
+        foo {
+          color: red;
+        }
       }
     "#},
     );
+
     test(
       r#"
-      .foo {
-        transition: opacity 2s;
-        transition-timing-function: ease;
-        transition-delay: 500ms;
+      @layer a;
+      @layer b;
+      @import "a.css" layer(x);
+      @layer c;
+
+      @layer d {
+        foo {
+          color: red;
+        }
       }
-    "#,
+      "#,
       indoc! {r#"
-      .foo {
-        transition: opacity 2s .5s;
+      @layer a, b;
+      @import "a.css" layer(x);
+      @layer c;
+
+      @layer d {
+        foo {
+          color: red;
+        }
       }
     "#},
     );
+
     test(
       r#"
-      .foo {
-        transition: opacity 500ms;
-        transition-timing-function: var(--ease);
+      @layer a, b, c;
+
+      @layer a {
+        foo {
+          color: red;
+        }
       }
-    "#,
+      "#,
       indoc! {r#"
-      .foo {
-        transition: opacity .5s;
-        transition-timing-function: var(--ease);
+      @layer a {
+        foo {
+          color: red;
+        }
       }
+
+      @layer b, c;
     "#},
     );
+  }
+
+  #[test]
+  fn test_merge_rules() {
     test(
       r#"
       .foo {
-        transition-property: opacity;
-        transition-duration: 0.09s;
-        transition-timing-function: ease-in-out;
-        transition-delay: 500ms;
-        transition: color 2s;
+        color: red;
+      }
+      .bar {
+        color: red;
       }
     "#,
       indoc! {r#"
-      .foo {
-        transition: color 2s;
+      .foo, .bar {
+        color: red;
       }
     "#},
     );
     test(
       r#"
       .foo {
-        transition-property: opacity, color;
-        transition-duration: 2s, 4s;
-        transition-timing-function: ease-in-out, ease-in;
-        transition-delay: 500ms, 0s;
+        color: red;
+      }
+      .foo {
+        background: green;
       }
     "#,
       indoc! {r#"
       .foo {
-        transition: opacity 2s ease-in-out .5s, color 4s ease-in;
+        color: red;
+        background: green;
       }
     "#},
     );
     test(
       r#"
       .foo {
-        transition-property: opacity, color;
-        transition-duration: 2s;
-        transition-timing-function: ease-in-out;
-        transition-delay: 500ms;
+        color: red;
+      }
+      .foo {
+        background: green !important;
       }
     "#,
       indoc! {r#"
       .foo {
-        transition: opacity 2s ease-in-out .5s, color 2s ease-in-out .5s;
+        color: red;
+        background: green !important;
       }
     "#},
     );
     test(
       r#"
       .foo {
-        transition-property: opacity, color, width, height;
-        transition-duration: 2s, 4s;
-        transition-timing-function: ease;
-        transition-delay: 0s;
+        background: red;
+      }
+      .foo {
+        background: green;
       }
     "#,
       indoc! {r#"
       .foo {
-        transition: opacity 2s, color 4s, width 2s, height 4s;
+        background: green;
       }
     "#},
     );
-
     test(
       r#"
       .foo {
-        -webkit-transition-property: opacity, color;
-        -webkit-transition-duration: 2s, 4s;
-        -webkit-transition-timing-function: ease-in-out, ease-in;
-        -webkit-transition-delay: 500ms, 0s;
+        --foo: red;
+        --foo: purple;
+      }
+      .foo {
+        --foo: green;
       }
     "#,
       indoc! {r#"
       .foo {
-        -webkit-transition: opacity 2s ease-in-out .5s, color 4s ease-in;
+        --foo: green;
       }
     "#},
     );
-
     test(
       r#"
       .foo {
-        -webkit-transition-property: opacity, color;
-        -webkit-transition-duration: 2s, 4s;
-        -webkit-transition-timing-function: ease-in-out, ease-in;
-        -webkit-transition-delay: 500ms, 0s;
-        -moz-transition-property: opacity, color;
-        -moz-transition-duration: 2s, 4s;
-        -moz-transition-timing-function: ease-in-out, ease-in;
-        -moz-transition-delay: 500ms, 0s;
-        transition-property: opacity, color;
-        transition-duration: 2s, 4s;
-        transition-timing-function: ease-in-out, ease-in;
-        transition-delay: 500ms, 0s;
+        color: red;
+      }
+
+      .bar {
+        background: green;
       }
     "#,
       indoc! {r#"
       .foo {
-        -webkit-transition: opacity 2s ease-in-out .5s, color 4s ease-in;
-        -moz-transition: opacity 2s ease-in-out .5s, color 4s ease-in;
-        transition: opacity 2s ease-in-out .5s, color 4s ease-in;
+        color: red;
+      }
+
+      .bar {
+        background: green;
       }
     "#},
     );
-
     test(
       r#"
       .foo {
-        -webkit-transition-property: opacity, color;
-        -moz-transition-property: opacity, color;
-        transition-property: opacity, color;
-        -webkit-transition-duration: 2s, 4s;
-        -moz-transition-duration: 2s, 4s;
-        transition-duration: 2s, 4s;
-        -webkit-transition-timing-function: ease-in-out, ease-in;
-        transition-timing-function: ease-in-out, ease-in;
-        -moz-transition-timing-function: ease-in-out, ease-in;
-        -webkit-transition-delay: 500ms, 0s;
-        -moz-transition-delay: 500ms, 0s;
-        transition-delay: 500ms, 0s;
+        color: red;
+      }
+
+      .baz {
+        color: blue;
+      }
+
+      .bar {
+        color: red;
       }
     "#,
       indoc! {r#"
       .foo {
-        -webkit-transition: opacity 2s ease-in-out .5s, color 4s ease-in;
-        -moz-transition: opacity 2s ease-in-out .5s, color 4s ease-in;
-        transition: opacity 2s ease-in-out .5s, color 4s ease-in;
+        color: red;
+      }
+
+      .baz {
+        color: #00f;
+      }
+
+      .bar {
+        color: red;
       }
     "#},
     );
-
     test(
       r#"
       .foo {
-        -webkit-transition-property: opacity;
-        -moz-transition-property: color;
-        transition-property: opacity, color;
-        -webkit-transition-duration: 2s;
-        -moz-transition-duration: 4s;
-        transition-duration: 2s, 4s;
-        -webkit-transition-timing-function: ease-in-out;
-        -moz-transition-timing-function: ease-in-out;
-        transition-timing-function: ease-in-out, ease-in;
-        -webkit-transition-delay: 500ms;
-        -moz-transition-delay: 0s;
-        transition-delay: 500ms, 0s;
+        background: red;
+      }
+      .bar {
+        background: red;
+      }
+      .foo {
+        color: green;
+      }
+      .bar {
+        color: green;
       }
     "#,
       indoc! {r#"
-      .foo {
-        -webkit-transition-property: opacity;
-        -moz-transition-property: color;
-        transition-property: opacity, color;
-        -webkit-transition-duration: 2s;
-        -moz-transition-duration: 4s;
-        transition-duration: 2s, 4s;
-        -webkit-transition-timing-function: ease-in-out;
-        -moz-transition-timing-function: ease-in-out;
-        -webkit-transition-delay: .5s;
-        transition-timing-function: ease-in-out, ease-in;
-        -moz-transition-delay: 0s;
-        transition-delay: .5s, 0s;
+      .foo, .bar {
+        color: green;
+        background: red;
       }
     "#},
     );
-
     test(
       r#"
+      .foo, .bar {
+        background: red;
+      }
       .foo {
-        -webkit-transition-property: opacity;
-        transition-property: opacity, color;
-        -moz-transition-property: color;
-        -webkit-transition-duration: 2s;
-        transition-duration: 2s, 4s;
-        -moz-transition-duration: 4s;
-        -webkit-transition-timing-function: ease-in-out;
-        transition-timing-function: ease-in-out, ease-in;
-        -moz-transition-timing-function: ease-in-out;
-        -webkit-transition-delay: 500ms;
-        transition-delay: 500ms, 0s;
-        -moz-transition-delay: 0s;
+        color: green;
+      }
+      .bar {
+        color: green;
       }
     "#,
       indoc! {r#"
-      .foo {
-        -webkit-transition-property: opacity;
-        transition-property: opacity, color;
-        -moz-transition-property: color;
-        -webkit-transition-duration: 2s;
-        transition-duration: 2s, 4s;
-        -moz-transition-duration: 4s;
-        -webkit-transition-timing-function: ease-in-out;
-        transition-timing-function: ease-in-out, ease-in;
-        -webkit-transition-delay: .5s;
-        -moz-transition-timing-function: ease-in-out;
-        transition-delay: .5s, 0s;
-        -moz-transition-delay: 0s;
+      .foo, .bar {
+        color: green;
+        background: red;
       }
     "#},
     );
-
     test(
       r#"
       .foo {
-        transition: opacity 2s;
-        -webkit-transition-duration: 2s;
+        background: red;
+      }
+      .foo {
+        color: green;
+      }
+      .bar {
+        background: red;
+      }
+      .bar {
+        color: green;
       }
     "#,
       indoc! {r#"
-      .foo {
-        transition: opacity 2s;
-        -webkit-transition-duration: 2s;
+      .foo, .bar {
+        color: green;
+        background: red;
       }
     "#},
     );
-
-    prefix_test(
+    test(
       r#"
-      .foo {
-        transition-property: margin-inline-start;
+      [foo="bar"] {
+        color: red;
+      }
+      .bar {
+        color: red;
       }
     "#,
       indoc! {r#"
-      .foo:not(:-webkit-any(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi))) {
-        transition-property: margin-left;
-      }
-
-      .foo:not(:is(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi))) {
-        transition-property: margin-left;
+      [foo="bar"], .bar {
+        color: red;
       }
-
-      .foo:-webkit-any(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi)) {
-        transition-property: margin-right;
+    "#},
+    );
+    test(
+      r#"
+      .a {
+        color: red;
       }
-
-      .foo:is(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi)) {
-        transition-property: margin-right;
+      .b {
+        color: green;
       }
-    "#
-      },
-      Browsers {
-        safari: Some(8 << 16),
-        ..Browsers::default()
-      },
-    );
-
-    prefix_test(
-      r#"
-      .foo {
-        transition-property: margin-inline-start, padding-inline-start;
+      .a {
+        color: red;
       }
     "#,
       indoc! {r#"
-      .foo:not(:-webkit-any(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi))) {
-        transition-property: margin-left, padding-left;
-      }
-
-      .foo:not(:is(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi))) {
-        transition-property: margin-left, padding-left;
-      }
-
-      .foo:-webkit-any(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi)) {
-        transition-property: margin-right, padding-right;
+      .b {
+        color: green;
       }
 
-      .foo:is(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi)) {
-        transition-property: margin-right, padding-right;
+      .a {
+        color: red;
       }
-    "#
-      },
-      Browsers {
-        safari: Some(8 << 16),
-        ..Browsers::default()
-      },
+    "#},
     );
-
-    prefix_test(
+    test(
       r#"
-      .foo {
-        transition-property: margin-inline-start, opacity, padding-inline-start, color;
+      .a {
+        color: red;
       }
-    "#,
-      indoc! {r#"
-      .foo:not(:-webkit-any(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi))) {
-        transition-property: margin-left, opacity, padding-left, color;
+      .b {
+        color: green;
       }
-
-      .foo:not(:is(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi))) {
-        transition-property: margin-left, opacity, padding-left, color;
+      .a {
+        color: pink;
       }
-
-      .foo:-webkit-any(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi)) {
-        transition-property: margin-right, opacity, padding-right, color;
+    "#,
+      indoc! {r#"
+      .b {
+        color: green;
       }
 
-      .foo:is(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi)) {
-        transition-property: margin-right, opacity, padding-right, color;
+      .a {
+        color: pink;
       }
-    "#
-      },
-      Browsers {
-        safari: Some(8 << 16),
-        ..Browsers::default()
-      },
+    "#},
     );
-
-    prefix_test(
+    test(
       r#"
-      .foo {
-        transition-property: margin-block;
+      .a:foo(#000) {
+        color: red;
       }
-    "#,
-      indoc! {r#"
-      .foo {
-        transition-property: margin-top, margin-bottom;
+      .b {
+        color: green;
       }
-    "#
-      },
-      Browsers {
-        safari: Some(8 << 16),
-        ..Browsers::default()
-      },
-    );
-
-    prefix_test(
-      r#"
-      .foo {
-        transition: margin-inline-start 2s;
+      .a:foo(#ff0) {
+        color: pink;
       }
     "#,
       indoc! {r#"
-      .foo:not(:-webkit-any(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi))) {
-        transition: margin-left 2s;
-      }
-
-      .foo:not(:is(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi))) {
-        transition: margin-left 2s;
+      .a:foo(#000) {
+        color: red;
       }
 
-      .foo:-webkit-any(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi)) {
-        transition: margin-right 2s;
+      .b {
+        color: green;
       }
 
-      .foo:is(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi)) {
-        transition: margin-right 2s;
+      .a:foo(#ff0) {
+        color: pink;
       }
-    "#
-      },
-      Browsers {
-        safari: Some(8 << 16),
-        ..Browsers::default()
-      },
+    "#},
     );
-
-    prefix_test(
+    test(
       r#"
-      .foo {
-        transition: margin-inline-start 2s, padding-inline-start 2s;
+      .a {
+        border-radius: 10px;
       }
-    "#,
-      indoc! {r#"
-      .foo:not(:-webkit-any(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi))) {
-        transition: margin-left 2s, padding-left 2s;
+      .b {
+        color: green;
       }
-
-      .foo:not(:is(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi))) {
-        transition: margin-left 2s, padding-left 2s;
+      .a {
+        border-radius: 10px;
       }
-
-      .foo:-webkit-any(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi)) {
-        transition: margin-right 2s, padding-right 2s;
+    "#,
+      indoc! {r#"
+      .b {
+        color: green;
       }
 
-      .foo:is(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi)) {
-        transition: margin-right 2s, padding-right 2s;
+      .a {
+        border-radius: 10px;
       }
-    "#
-      },
-      Browsers {
-        safari: Some(8 << 16),
-        ..Browsers::default()
-      },
+    "#},
     );
-
-    prefix_test(
+    test(
       r#"
-      .foo {
-        transition: margin-block-start 2s;
+      .a {
+        border-radius: 10px;
+      }
+      .b {
+        color: green;
+      }
+      .a {
+        -webkit-border-radius: 10px;
       }
     "#,
       indoc! {r#"
-      .foo {
-        transition: margin-top 2s;
+      .a {
+        border-radius: 10px;
       }
-    "#
-      },
-      Browsers {
-        safari: Some(8 << 16),
-        ..Browsers::default()
-      },
-    );
 
-    prefix_test(
-      r#"
-      .foo {
-        transition: transform;
+      .b {
+        color: green;
       }
-    "#,
-      indoc! {r#"
-      .foo {
-        -webkit-transition: -webkit-transform, transform;
-        transition: -webkit-transform, transform;
+
+      .a {
+        -webkit-border-radius: 10px;
       }
-    "#
-      },
-      Browsers {
-        safari: Some(6 << 16),
-        ..Browsers::default()
-      },
+    "#},
     );
-
-    prefix_test(
+    test(
       r#"
-      .foo {
-        transition: border-start-start-radius;
+      .a {
+        border-radius: 10px;
+      }
+      .b {
+        color: green;
+      }
+      .a {
+        border-radius: var(--foo);
       }
     "#,
       indoc! {r#"
-      .foo:not(:is(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi))) {
-        -webkit-transition: -webkit-border-top-left-radius, border-top-left-radius;
-        transition: -webkit-border-top-left-radius, border-top-left-radius;
+      .b {
+        color: green;
       }
 
-      .foo:is(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi)) {
-        -webkit-transition: -webkit-border-top-right-radius, border-top-right-radius;
-        transition: -webkit-border-top-right-radius, border-top-right-radius;
+      .a {
+        border-radius: var(--foo);
       }
-    "#
-      },
-      Browsers {
-        safari: Some(4 << 16),
-        ..Browsers::default()
-      },
+    "#},
     );
-
-    prefix_test(
+    test(
       r#"
-      .foo {
-        transition: border-start-start-radius;
+      .a {
+        border-radius: 10px;
+      }
+      .b {
+        color: green;
+      }
+      .c {
+        border-radius: 20px;
       }
     "#,
       indoc! {r#"
-      .foo:not(:lang(ae, ar, arc, bcc, bqi, ckb, dv, fa, glk, he, ku, mzn, nqo, pnb, ps, sd, ug, ur, yi)) {
-        transition: border-top-left-radius;
+      .a {
+        border-radius: 10px;
       }
 
-      .foo:lang(ae, ar, arc, bcc, bqi, ckb, dv, fa, glk, he, ku, mzn, nqo, pnb, ps, sd, ug, ur, yi) {
-        transition: border-top-right-radius;
+      .b {
+        color: green;
       }
-    "#
+
+      .c {
+        border-radius: 20px;
+      }
+    "#},
+    );
+    test(
+      r#"
+      @media print {
+        .a {
+          color: red;
+        }
+        .b {
+          color: green;
+        }
+        .a {
+          color: red;
+        }
+      }
+    "#,
+      indoc! {r#"
+      @media print {
+        .b {
+          color: green;
+        }
+
+        .a {
+          color: red;
+        }
+      }
+    "#},
+    );
+    test(
+      r#"
+      .a {
+        border-radius: 10px;
+      }
+      .b {
+        color: green;
+      }
+      .a {
+        border-radius: 20px;
+        color: pink;
+      }
+    "#,
+      indoc! {r#"
+      .a {
+        border-radius: 10px;
+      }
+
+      .b {
+        color: green;
+      }
+
+      .a {
+        color: pink;
+        border-radius: 20px;
+      }
+    "#},
+    );
+    test(
+      r#"
+      .a {
+        color: red;
+      }
+      .b {
+        color: green;
+      }
+      .a {
+        color: red;
+      }
+      .b {
+        color: green;
+      }
+    "#,
+      indoc! {r#"
+      .a {
+        color: red;
+      }
+
+      .b {
+        color: green;
+      }
+    "#},
+    );
+
+    prefix_test(
+      r#"
+      [foo="bar"] {
+        color: red;
+      }
+      .bar {
+        color: red;
+      }
+    "#,
+      indoc! {r#"
+      [foo="bar"] {
+        color: red;
+      }
+
+      .bar {
+        color: red;
+      }
+    "#},
+      Browsers {
+        ie: Some(6 << 16),
+        ..Browsers::default()
       },
+    );
+
+    prefix_test(
+      r#"
+      [foo="bar"] {
+        color: red;
+      }
+      .bar {
+        color: red;
+      }
+    "#,
+      indoc! {r#"
+      [foo="bar"], .bar {
+        color: red;
+      }
+    "#},
       Browsers {
-        safari: Some(12 << 16),
+        ie: Some(10 << 16),
         ..Browsers::default()
       },
     );
 
     test(
       r#"
-      .foo {
-        -webkit-transition: background 200ms;
-        -moz-transition: background 200ms;
-        transition: background 230ms;
+      .foo:-moz-read-only {
+        color: red;
       }
     "#,
       indoc! {r#"
-      .foo {
-        -webkit-transition: background .2s;
-        -moz-transition: background .2s;
-        transition: background .23s;
+      .foo:-moz-read-only {
+        color: red;
+      }
+    "#},
+    );
+
+    test(
+      r#"
+      .foo:-moz-read-only {
+        color: red;
+      }
+
+      .foo:read-only {
+        color: red;
+      }
+    "#,
+      indoc! {r#"
+      .foo:-moz-read-only {
+        color: red;
+      }
+
+      .foo:read-only {
+        color: red;
       }
     "#},
     );
 
     prefix_test(
       r#"
-      .foo {
-        -webkit-transition: background 200ms;
-        -moz-transition: background 200ms;
-        transition: background 230ms;
+      .foo:-moz-read-only {
+        color: red;
+      }
+
+      .foo:read-only {
+        color: red;
       }
     "#,
       indoc! {r#"
-      .foo {
-        -webkit-transition: background .2s;
-        -moz-transition: background .2s;
-        transition: background .23s;
+      .foo:read-only {
+        color: red;
       }
     "#},
       Browsers {
-        chrome: Some(95 << 16),
+        firefox: Some(85 << 16),
         ..Browsers::default()
       },
     );
-  }
 
-  #[test]
-  fn test_animation() {
-    minify_test(".foo { animation-name: test }", ".foo{animation-name:test}");
-    minify_test(".foo { animation-name: \"test\" }", ".foo{animation-name:test}");
-    minify_test(".foo { animation-name: foo, bar }", ".foo{animation-name:foo,bar}");
-    minify_test(".foo { animation-name: \"none\" }", ".foo{animation-name:\"none\"}");
-    minify_test(
-      ".foo { animation-name: \"none\", foo }",
-      ".foo{animation-name:\"none\",foo}",
-    );
-    let name = crate::properties::animation::AnimationName::parse_string("default");
-    assert!(matches!(name, Err(..)));
+    prefix_test(
+      r#"
+      .foo:-moz-read-only {
+        color: red;
+      }
 
-    minify_test(".foo { animation-name: none }", ".foo{animation-name:none}");
-    minify_test(".foo { animation-name: none, none }", ".foo{animation-name:none,none}");
+      .bar {
+        color: yellow;
+      }
 
-    // Test CSS-wide keywords
-    minify_test(".foo { animation-name: unset }", ".foo{animation-name:unset}");
-    minify_test(".foo { animation-name: \"unset\" }", ".foo{animation-name:\"unset\"}");
-    minify_test(".foo { animation-name: \"revert\" }", ".foo{animation-name:\"revert\"}");
-    minify_test(
-      ".foo { animation-name: \"unset\", \"revert\"}",
-      ".foo{animation-name:\"unset\",\"revert\"}",
+      .foo:read-only {
+        color: red;
+      }
+    "#,
+      indoc! {r#"
+      .foo:-moz-read-only {
+        color: red;
+      }
+
+      .bar {
+        color: #ff0;
+      }
+
+      .foo:read-only {
+        color: red;
+      }
+    "#},
+      Browsers {
+        firefox: Some(85 << 16),
+        ..Browsers::default()
+      },
     );
-    minify_test(
-      ".foo { animation-name: foo, \"revert\"}",
-      ".foo{animation-name:foo,\"revert\"}",
-    );
-    minify_test(
-      ".foo { animation-name: \"string\", \"revert\"}",
-      ".foo{animation-name:string,\"revert\"}",
-    );
-    minify_test(
-      ".foo { animation-name: \"string\", foo, \"revert\"}",
-      ".foo{animation-name:string,foo,\"revert\"}",
-    );
-    minify_test(
-      ".foo { animation-name: \"default\" }",
-      ".foo{animation-name:\"default\"}",
-    );
-    minify_test(".foo { animation-duration: 100ms }", ".foo{animation-duration:.1s}");
-    minify_test(
-      ".foo { animation-duration: 100ms, 2000ms }",
-      ".foo{animation-duration:.1s,2s}",
-    );
-    minify_test(
-      ".foo { animation-timing-function: ease }",
-      ".foo{animation-timing-function:ease}",
-    );
-    minify_test(
-      ".foo { animation-timing-function: cubic-bezier(0.42, 0, 1, 1) }",
-      ".foo{animation-timing-function:ease-in}",
-    );
-    minify_test(
-      ".foo { animation-timing-function: ease, cubic-bezier(0.42, 0, 1, 1) }",
-      ".foo{animation-timing-function:ease,ease-in}",
-    );
-    minify_test(
-      ".foo { animation-iteration-count: 5 }",
-      ".foo{animation-iteration-count:5}",
-    );
-    minify_test(
-      ".foo { animation-iteration-count: 2.5 }",
-      ".foo{animation-iteration-count:2.5}",
-    );
-    minify_test(
-      ".foo { animation-iteration-count: 2.0 }",
-      ".foo{animation-iteration-count:2}",
-    );
-    minify_test(
-      ".foo { animation-iteration-count: infinite }",
-      ".foo{animation-iteration-count:infinite}",
-    );
-    minify_test(
-      ".foo { animation-iteration-count: 1, infinite }",
-      ".foo{animation-iteration-count:1,infinite}",
-    );
-    minify_test(
-      ".foo { animation-direction: reverse }",
-      ".foo{animation-direction:reverse}",
-    );
-    minify_test(
-      ".foo { animation-direction: alternate, reverse }",
-      ".foo{animation-direction:alternate,reverse}",
-    );
-    minify_test(
-      ".foo { animation-play-state: paused }",
-      ".foo{animation-play-state:paused}",
-    );
-    minify_test(
-      ".foo { animation-play-state: running, paused }",
-      ".foo{animation-play-state:running,paused}",
-    );
-    minify_test(".foo { animation-delay: 100ms }", ".foo{animation-delay:.1s}");
-    minify_test(
-      ".foo { animation-delay: 100ms, 2000ms }",
-      ".foo{animation-delay:.1s,2s}",
-    );
-    minify_test(
-      ".foo { animation-fill-mode: forwards }",
-      ".foo{animation-fill-mode:forwards}",
-    );
-    minify_test(
-      ".foo { animation-fill-mode: Backwards,forwards }",
-      ".foo{animation-fill-mode:backwards,forwards}",
-    );
-    minify_test(".foo { animation: none }", ".foo{animation:none}");
-    minify_test(".foo { animation: \"none\" }", ".foo{animation:\"none\"}");
-    minify_test(".foo { animation: \"None\" }", ".foo{animation:\"None\"}");
-    minify_test(".foo { animation: \"none\", none }", ".foo{animation:\"none\",none}");
-    minify_test(".foo { animation: none, none }", ".foo{animation:none,none}");
-    minify_test(".foo { animation: \"none\" none }", ".foo{animation:\"none\"}");
-    minify_test(".foo { animation: none none }", ".foo{animation:none}");
-
-    // Test animation-name + animation-fill-mode
-    minify_test(
-      ".foo { animation: 2s both \"none\"}",
-      ".foo{animation:2s both \"none\"}",
-    );
-    minify_test(
-      ".foo { animation: both \"none\" 2s}",
-      ".foo{animation:2s both \"none\"}",
-    );
-    minify_test(".foo { animation: \"none\" 2s none}", ".foo{animation:2s \"none\"}");
-    minify_test(".foo { animation: none \"none\" 2s}", ".foo{animation:2s \"none\"}");
-    minify_test(
-      ".foo { animation: none, \"none\" 2s forwards}",
-      ".foo{animation:none,2s forwards \"none\"}",
-    );
-
-    minify_test(".foo { animation: \"unset\" }", ".foo{animation:\"unset\"}");
-    minify_test(".foo { animation: \"string\" .5s }", ".foo{animation:.5s string}");
-    minify_test(".foo { animation: \"unset\" .5s }", ".foo{animation:.5s \"unset\"}");
-    minify_test(
-      ".foo { animation: none, \"unset\" .5s}",
-      ".foo{animation:none,.5s \"unset\"}",
-    );
-    minify_test(
-      ".foo { animation: \"unset\" 0s 3s infinite, none }",
-      ".foo{animation:0s 3s infinite \"unset\",none}",
-    );
-
-    minify_test(".foo { animation: \"infinite\" 2s 1 }", ".foo{animation:2s 1 infinite}");
-    minify_test(".foo { animation: \"paused\" 2s }", ".foo{animation:2s running paused}");
-    minify_test(
-      ".foo { animation: \"forwards\" 2s }",
-      ".foo{animation:2s none forwards}",
-    );
-    minify_test(
-      ".foo { animation: \"reverse\" 2s }",
-      ".foo{animation:2s normal reverse}",
-    );
-    minify_test(
-      ".foo { animation: \"reverse\" 2s alternate }",
-      ".foo{animation:2s alternate reverse}",
+
+    prefix_test(
+      r#"
+      .foo:-moz-read-only {
+        color: red;
+      }
+
+      .foo:read-only {
+        color: red;
+      }
+    "#,
+      indoc! {r#"
+      .foo:-moz-read-only {
+        color: red;
+      }
+
+      .foo:read-only {
+        color: red;
+      }
+    "#},
+      Browsers {
+        firefox: Some(36 << 16),
+        ..Browsers::default()
+      },
     );
 
-    minify_test(
-      ".foo { animation: 3s ease-in 1s infinite reverse both running slidein }",
-      ".foo{animation:3s ease-in 1s infinite reverse both slidein}",
-    );
-    minify_test(
-      ".foo { animation: 3s slidein paused ease 1s 1 reverse both }",
-      ".foo{animation:3s 1s reverse both paused slidein}",
-    );
-    minify_test(".foo { animation: 3s ease ease }", ".foo{animation:3s ease ease}");
-    minify_test(
-      ".foo { animation: 3s cubic-bezier(0.25, 0.1, 0.25, 1) foo }",
-      ".foo{animation:3s foo}",
-    );
-    minify_test(
-      ".foo { animation: foo 0s 3s infinite }",
-      ".foo{animation:0s 3s infinite foo}",
-    );
-    test(
+    prefix_test(
       r#"
-      .foo {
-        animation-name: foo;
-        animation-duration: 0.09s;
-        animation-timing-function: ease-in-out;
-        animation-iteration-count: 2;
-        animation-direction: alternate;
-        animation-play-state: running;
-        animation-delay: 100ms;
-        animation-fill-mode: forwards;
+      .foo:read-only {
+        color: red;
       }
     "#,
       indoc! {r#"
-      .foo {
-        animation: 90ms ease-in-out .1s 2 alternate forwards foo;
+      .foo:-moz-read-only {
+        color: red;
+      }
+
+      .foo:read-only {
+        color: red;
       }
     "#},
+      Browsers {
+        firefox: Some(36 << 16),
+        ..Browsers::default()
+      },
     );
-    test(
+
+    prefix_test(
       r#"
-      .foo {
-        animation-name: foo, bar;
-        animation-duration: 0.09s, 200ms;
-        animation-timing-function: ease-in-out, ease;
-        animation-iteration-count: 2, 1;
-        animation-direction: alternate, normal;
-        animation-play-state: running, paused;
-        animation-delay: 100ms, 0s;
-        animation-fill-mode: forwards, none;
+      .foo:-webkit-full-screen {
+        color: red;
       }
-    "#,
-      indoc! {r#"
-      .foo {
-        animation: 90ms ease-in-out .1s 2 alternate forwards foo, .2s paused bar;
+      .foo:-moz-full-screen {
+        color: red;
       }
-    "#},
-    );
-    test(
-      r#"
-      .foo {
-        animation: bar 200ms;
-        animation-timing-function: ease-in-out;
+      .foo:-ms-fullscreen {
+        color: red;
+      }
+      .foo:fullscreen {
+        color: red;
       }
     "#,
       indoc! {r#"
-      .foo {
-        animation: .2s ease-in-out bar;
+      .foo:fullscreen {
+        color: red;
       }
     "#},
+      Browsers {
+        chrome: Some(96 << 16),
+        ..Browsers::default()
+      },
     );
-    test(
+
+    prefix_test(
       r#"
-      .foo {
-        animation: bar 200ms;
-        animation-timing-function: var(--ease);
+      .foo:fullscreen {
+        color: red;
       }
     "#,
       indoc! {r#"
-      .foo {
-        animation: .2s bar;
-        animation-timing-function: var(--ease);
+      .foo:-webkit-full-screen {
+        color: red;
+      }
+
+      .foo:-moz-full-screen {
+        color: red;
+      }
+
+      .foo:-ms-fullscreen {
+        color: red;
+      }
+
+      .foo:fullscreen {
+        color: red;
       }
     "#},
+      Browsers {
+        chrome: Some(45 << 16),
+        firefox: Some(45 << 16),
+        ie: Some(11 << 16),
+        ..Browsers::default()
+      },
     );
-    test(
+
+    prefix_test(
       r#"
-      .foo {
-        animation-name: foo, bar;
-        animation-duration: 0.09s;
-        animation-timing-function: ease-in-out;
-        animation-iteration-count: 2;
-        animation-direction: alternate;
-        animation-play-state: running;
-        animation-delay: 100ms;
-        animation-fill-mode: forwards;
+      .foo::placeholder {
+        color: red;
       }
     "#,
       indoc! {r#"
-      .foo {
-        animation-name: foo, bar;
-        animation-duration: 90ms;
-        animation-timing-function: ease-in-out;
-        animation-iteration-count: 2;
-        animation-direction: alternate;
-        animation-play-state: running;
-        animation-delay: .1s;
-        animation-fill-mode: forwards;
+      .foo::-webkit-input-placeholder {
+        color: red;
+      }
+
+      .foo::-moz-placeholder {
+        color: red;
+      }
+
+      .foo::-ms-input-placeholder {
+        color: red;
+      }
+
+      .foo::placeholder {
+        color: red;
       }
     "#},
+      Browsers {
+        chrome: Some(45 << 16),
+        firefox: Some(45 << 16),
+        ie: Some(11 << 16),
+        ..Browsers::default()
+      },
     );
-    test(
+
+    prefix_test(
       r#"
-      .foo {
-        -webkit-animation-name: foo;
-        -webkit-animation-duration: 0.09s;
-        -webkit-animation-timing-function: ease-in-out;
-        -webkit-animation-iteration-count: 2;
-        -webkit-animation-direction: alternate;
-        -webkit-animation-play-state: running;
-        -webkit-animation-delay: 100ms;
-        -webkit-animation-fill-mode: forwards;
+      .foo::file-selector-button {
+        color: red;
       }
     "#,
       indoc! {r#"
-      .foo {
-        -webkit-animation: 90ms ease-in-out .1s 2 alternate forwards foo;
+      .foo::-webkit-file-upload-button {
+        color: red;
+      }
+
+      .foo::-ms-browse {
+        color: red;
+      }
+
+      .foo::file-selector-button {
+        color: red;
       }
     "#},
+      Browsers {
+        chrome: Some(84 << 16),
+        ie: Some(10 << 16),
+        ..Browsers::default()
+      },
     );
-    test(
+
+    prefix_test(
       r#"
-      .foo {
-        -moz-animation: bar 200ms;
-        -moz-animation-timing-function: ease-in-out;
+      .foo::file-selector-button {
+        margin-inline-start: 2px;
       }
     "#,
       indoc! {r#"
-      .foo {
-        -moz-animation: .2s ease-in-out bar;
+      .foo:not(:-webkit-any(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi)))::-webkit-file-upload-button {
+        margin-left: 2px;
+      }
+
+      .foo:not(:is(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi)))::-ms-browse {
+        margin-left: 2px;
+      }
+
+      .foo:not(:is(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi)))::file-selector-button {
+        margin-left: 2px;
+      }
+
+      .foo:-webkit-any(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi))::-webkit-file-upload-button {
+        margin-right: 2px;
+      }
+
+      .foo:is(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi))::-ms-browse {
+        margin-right: 2px;
+      }
+
+      .foo:is(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi))::file-selector-button {
+        margin-right: 2px;
       }
     "#},
+      Browsers {
+        chrome: Some(84 << 16),
+        ie: Some(10 << 16),
+        ..Browsers::default()
+      },
     );
-    test(
+
+    prefix_test(
       r#"
-      .foo {
-        -webkit-animation: bar 200ms;
-        -webkit-animation-timing-function: ease-in-out;
-        -moz-animation: bar 200ms;
-        -moz-animation-timing-function: ease-in-out;
+      .foo:placeholder-shown .bar { color: red; }
+      .foo:autofill .baz { color: red; }
+      "#,
+      indoc! {r#"
+      .foo:placeholder-shown .bar {
+        color: red;
       }
-    "#,
+
+      .foo:-webkit-autofill .baz {
+        color: red;
+      }
+
+      .foo:autofill .baz {
+        color: red;
+      }
+      "#},
+      Browsers {
+        chrome: Some(103 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      r#"
+      .foo:placeholder-shown .bar,.foo:autofill .baz{color:red}
+      "#,
       indoc! {r#"
-      .foo {
-        -webkit-animation: .2s ease-in-out bar;
-        -moz-animation: .2s ease-in-out bar;
+      :-webkit-any(.foo:placeholder-shown .bar, .foo:-webkit-autofill .baz) {
+        color: red;
       }
-    "#},
+
+      :is(.foo:placeholder-shown .bar, .foo:autofill .baz) {
+        color: red;
+      }
+      "#},
+      Browsers {
+        chrome: Some(103 << 16),
+        ..Browsers::default()
+      },
     );
 
     prefix_test(
       r#"
-      .foo {
-        animation: .2s ease-in-out bar;
+      .foo:placeholder-shown .bar, .foo:-webkit-autofill .baz {
+        color: red;
       }
-    "#,
+
+      .foo:placeholder-shown .bar, .foo:autofill .baz {
+        color: red;
+      }
+      "#,
       indoc! {r#"
-      .foo {
-        -webkit-animation: .2s ease-in-out bar;
-        -moz-animation: .2s ease-in-out bar;
-        animation: .2s ease-in-out bar;
+      :-webkit-any(.foo:placeholder-shown .bar, .foo:-webkit-autofill .baz) {
+        color: red;
       }
-    "#},
+
+      :is(.foo:placeholder-shown .bar, .foo:autofill .baz) {
+        color: red;
+      }
+      "#},
       Browsers {
-        firefox: Some(6 << 16),
-        safari: Some(6 << 16),
+        chrome: Some(103 << 16),
         ..Browsers::default()
       },
     );
 
+    test(
+      r#"
+      .foo:placeholder-shown .bar, .foo:-webkit-autofill .baz {
+        color: red;
+      }
+
+      .foo:placeholder-shown .bar, .foo:autofill .baz {
+        color: red;
+      }
+      "#,
+      indoc! {r#"
+      .foo:placeholder-shown .bar, .foo:-webkit-autofill .baz {
+        color: red;
+      }
+
+      .foo:placeholder-shown .bar, .foo:autofill .baz {
+        color: red;
+      }
+      "#},
+    );
+
     prefix_test(
       r#"
-      .foo {
-        -webkit-animation: .2s ease-in-out bar;
-        -moz-animation: .2s ease-in-out bar;
-        animation: .2s ease-in-out bar;
+      :hover, :focus-visible {
+        color: red;
       }
-    "#,
+      "#,
       indoc! {r#"
-      .foo {
-        animation: .2s ease-in-out bar;
+      :hover {
+        color: red;
       }
-    "#},
+
+      :focus-visible {
+        color: red;
+      }
+      "#},
       Browsers {
-        firefox: Some(20 << 16),
-        safari: Some(14 << 16),
+        safari: Some(13 << 16),
         ..Browsers::default()
       },
     );
+
     prefix_test(
       r#"
       .foo {
-        animation: 200ms var(--ease) bar;
+        color: red;
       }
-    "#,
+
+      :hover, :focus-visible {
+        color: red;
+      }
+      "#,
       indoc! {r#"
-      .foo {
-        -webkit-animation: .2s var(--ease) bar;
-        -moz-animation: .2s var(--ease) bar;
-        animation: .2s var(--ease) bar;
+      .foo, :hover {
+        color: red;
       }
-    "#},
+
+      :focus-visible {
+        color: red;
+      }
+      "#},
       Browsers {
-        firefox: Some(6 << 16),
-        safari: Some(6 << 16),
+        safari: Some(13 << 16),
         ..Browsers::default()
       },
     );
-  }
 
-  #[test]
-  fn test_transform() {
-    minify_test(
-      ".foo { transform: translate(2px, 3px)",
-      ".foo{transform:translate(2px,3px)}",
-    );
-    minify_test(
-      ".foo { transform: translate(2px, 0px)",
-      ".foo{transform:translate(2px)}",
-    );
-    minify_test(
-      ".foo { transform: translate(0px, 2px)",
-      ".foo{transform:translateY(2px)}",
-    );
-    minify_test(".foo { transform: translateX(2px)", ".foo{transform:translate(2px)}");
-    minify_test(".foo { transform: translateY(2px)", ".foo{transform:translateY(2px)}");
-    minify_test(".foo { transform: translateZ(2px)", ".foo{transform:translateZ(2px)}");
-    minify_test(
-      ".foo { transform: translate3d(2px, 3px, 4px)",
-      ".foo{transform:translate3d(2px,3px,4px)}",
-    );
+    prefix_test(
+      r#"
+      :hover, :focus-visible {
+        margin-inline-start: 24px;
+      }
+      "#,
+      indoc! {r#"
+      :hover:not(:lang(ae, ar, arc, bcc, bqi, ckb, dv, fa, glk, he, ku, mzn, nqo, pnb, ps, sd, ug, ur, yi)) {
+        margin-left: 24px;
+      }
+
+      :hover:lang(ae, ar, arc, bcc, bqi, ckb, dv, fa, glk, he, ku, mzn, nqo, pnb, ps, sd, ug, ur, yi) {
+        margin-right: 24px;
+      }
+
+      :focus-visible:not(:lang(ae, ar, arc, bcc, bqi, ckb, dv, fa, glk, he, ku, mzn, nqo, pnb, ps, sd, ug, ur, yi)) {
+        margin-left: 24px;
+      }
+
+      :focus-visible:lang(ae, ar, arc, bcc, bqi, ckb, dv, fa, glk, he, ku, mzn, nqo, pnb, ps, sd, ug, ur, yi) {
+        margin-right: 24px;
+      }
+      "#},
+      Browsers {
+        safari: Some(11 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      r#"
+      :focus-within, :focus-visible {
+        color: red;
+      }
+      "#,
+      indoc! {r#"
+      :focus-within {
+        color: red;
+      }
+
+      :focus-visible {
+        color: red;
+      }
+      "#},
+      Browsers {
+        safari: Some(9 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      r#"
+      :hover, :focus-visible {
+        color: red;
+      }
+      "#,
+      indoc! {r#"
+      :is(:hover, :focus-visible) {
+        color: red;
+      }
+      "#},
+      Browsers {
+        safari: Some(14 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      r#"
+      a::after:hover, a::after:focus-visible {
+        color: red;
+      }
+      "#,
+      indoc! {r#"
+      a:after:hover {
+        color: red;
+      }
+
+      a:after:focus-visible {
+        color: red;
+      }
+      "#},
+      Browsers {
+        safari: Some(14 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      r#"
+      a:not(:hover), a:not(:focus-visible) {
+        color: red;
+      }
+      "#,
+      indoc! {r#"
+      :is(a:not(:hover), a:not(:focus-visible)) {
+        color: red;
+      }
+      "#},
+      Browsers {
+        safari: Some(14 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      r#"
+      a:has(:hover), a:has(:focus-visible) {
+        color: red;
+      }
+      "#,
+      indoc! {r#"
+      :is(a:has(:hover), a:has(:focus-visible)) {
+        color: red;
+      }
+      "#},
+      Browsers {
+        safari: Some(14 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      r#"
+      .foo.foo:hover, .bar:focus-visible {
+        color: red;
+      }
+      "#,
+      indoc! {r#"
+      .foo.foo:hover {
+        color: red;
+      }
+
+      .bar:focus-visible {
+        color: red;
+      }
+      "#},
+      Browsers {
+        safari: Some(14 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      r#"
+      a::unknown-a, a::unknown-b {
+        color: red;
+      }
+      "#,
+      indoc! {r#"
+      a::unknown-a {
+        color: red;
+      }
+
+      a::unknown-b {
+        color: red;
+      }
+      "#},
+      Browsers {
+        safari: Some(14 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    nesting_test_with_targets(
+      r#"
+      .foo {
+        padding-inline-start: 3px;
+
+        .bar {
+          padding-inline-start: 5px;
+        }
+      }
+      "#,
+      indoc! {r#"
+      .foo:not(:lang(ae, ar, arc, bcc, bqi, ckb, dv, fa, glk, he, ku, mzn, nqo, pnb, ps, sd, ug, ur, yi)) {
+        padding-left: 3px;
+      }
+
+      .foo:lang(ae, ar, arc, bcc, bqi, ckb, dv, fa, glk, he, ku, mzn, nqo, pnb, ps, sd, ug, ur, yi) {
+        padding-right: 3px;
+      }
+
+      .foo .bar:not(:lang(ae, ar, arc, bcc, bqi, ckb, dv, fa, glk, he, ku, mzn, nqo, pnb, ps, sd, ug, ur, yi)) {
+        padding-left: 5px;
+      }
+
+      .foo .bar:lang(ae, ar, arc, bcc, bqi, ckb, dv, fa, glk, he, ku, mzn, nqo, pnb, ps, sd, ug, ur, yi) {
+        padding-right: 5px;
+      }
+      "#},
+      Browsers {
+        safari: Some(12 << 16),
+        ..Browsers::default()
+      }
+      .into(),
+    );
+
+    prefix_test(
+      r#"
+      .foo::part(header), .foo::part(body) {
+        display: none
+      }
+      "#,
+      indoc! {r#"
+      .foo::part(header), .foo::part(body) {
+        display: none;
+      }
+      "#},
+      Browsers {
+        safari: Some(14 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      r#"
+      .foo :is(.bar) {
+        color: red;
+      }
+      "#,
+      indoc! {r#"
+        .foo .bar {
+          color: red;
+        }
+      "#},
+      Browsers {
+        chrome: Some(87 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      r#"
+      .foo :is(.bar), .bar :is(.baz) {
+        color: red;
+      }
+      "#,
+      indoc! {r#"
+        .foo .bar, .bar .baz {
+          color: red;
+        }
+      "#},
+      Browsers {
+        chrome: Some(87 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      r#"
+      .foo :is(.bar:focus-visible), .bar :is(.baz:hover) {
+        color: red;
+      }
+      "#,
+      indoc! {r#"
+        .bar .baz:hover {
+          color: red;
+        }
+
+        .foo .bar:focus-visible {
+          color: red;
+        }
+      "#},
+      Browsers {
+        chrome: Some(85 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      r#"
+      *,
+      ::before,
+      ::after,
+      ::backdrop {
+        padding: 5px;
+      }
+      "#,
+      indoc! {r#"
+        *, :before, :after {
+          padding: 5px;
+        }
+
+        ::-webkit-backdrop {
+          padding: 5px;
+        }
+
+        ::backdrop {
+          padding: 5px;
+        }
+      "#},
+      Browsers {
+        chrome: Some(33 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    test(
+      r#"
+      .foo:-webkit-any(.bar, .baz):after {
+        color: red;
+      }
+
+      .foo:is(.bar, .baz):after {
+        color: red;
+      }
+      "#,
+      indoc! {r#"
+        .foo:-webkit-any(.bar, .baz):after {
+          color: red;
+        }
+
+        .foo:is(.bar, .baz):after {
+          color: red;
+        }
+      "#},
+    );
+
+    prefix_test(
+      r#"
+      .foo:-webkit-any(.bar, .baz):after {
+        color: red;
+      }
+
+      .foo:is(.bar, .baz):after {
+        color: red;
+      }
+      "#,
+      indoc! {r#"
+        .foo:is(.bar, .baz):after {
+          color: red;
+        }
+      "#},
+      Browsers {
+        safari: Some(16 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      r#"
+      .foo:-webkit-any(.bar):after {
+        color: red;
+      }
+
+      .foo:is(.bar, .baz):after {
+        color: red;
+      }
+      "#,
+      indoc! {r#"
+        .foo:-webkit-any(.bar):after {
+          color: red;
+        }
+
+        .foo:is(.bar, .baz):after {
+          color: red;
+        }
+      "#},
+      Browsers {
+        safari: Some(16 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      r#"
+      .foo:-webkit-any(.bar, .baz):after {
+        color: red;
+      }
+
+      .foo:is(.bar, .baz):after {
+        color: red;
+      }
+      "#,
+      indoc! {r#"
+        .foo:-webkit-any(.bar, .baz):after {
+          color: red;
+        }
+
+        .foo:is(.bar, .baz):after {
+          color: red;
+        }
+      "#},
+      Browsers {
+        safari: Some(12 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      r#"
+      .foo:-webkit-any(.bar, .baz):after {
+        color: red;
+      }
+
+      .foo:-moz-any(.bar, .baz):after {
+        color: red;
+      }
+      "#,
+      indoc! {r#"
+        .foo:-webkit-any(.bar, .baz):after {
+          color: red;
+        }
+
+        .foo:-moz-any(.bar, .baz):after {
+          color: red;
+        }
+      "#},
+      Browsers {
+        safari: Some(12 << 16),
+        firefox: Some(67 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      r#"
+      .a {
+        padding-inline: var(--foo);
+      }
+
+      .a:-webkit-any(.b, .c) {
+        padding-inline: var(--foo);
+      }
+      "#,
+      indoc! {r#"
+        .a {
+          padding-inline: var(--foo);
+        }
+
+        .a:-webkit-any(.b, .c) {
+          padding-inline: var(--foo);
+        }
+      "#},
+      Browsers {
+        safari: Some(12 << 16),
+        ..Browsers::default()
+      },
+    );
+  }
+
+  #[test]
+  fn test_merge_media_rules() {
+    test(
+      r#"
+      @media (hover) {
+        .foo {
+          color: red;
+        }
+      }
+      @media (hover) {
+        .foo {
+          background: #fff;
+        }
+
+        .baz {
+          color: #fff;
+        }
+      }
+      "#,
+      indoc! {r#"
+      @media (hover) {
+        .foo {
+          color: red;
+          background: #fff;
+        }
+
+        .baz {
+          color: #fff;
+        }
+      }
+    "#},
+    );
+
+    test(
+      r#"
+      @media (hover) {
+        .foo {
+          color: red;
+        }
+      }
+      @media (min-width: 250px) {
+        .foo {
+          background: #fff;
+        }
+
+        .baz {
+          color: #fff;
+        }
+      }
+      "#,
+      indoc! {r#"
+      @media (hover) {
+        .foo {
+          color: red;
+        }
+      }
+
+      @media (width >= 250px) {
+        .foo {
+          background: #fff;
+        }
+
+        .baz {
+          color: #fff;
+        }
+      }
+    "#},
+    );
+  }
+
+  #[test]
+  fn test_merge_supports() {
+    test(
+      r#"
+      @supports (flex: 1) {
+        .foo {
+          color: red;
+        }
+      }
+      @supports (flex: 1) {
+        .foo {
+          background: #fff;
+        }
+
+        .baz {
+          color: #fff;
+        }
+      }
+      "#,
+      indoc! {r#"
+      @supports (flex: 1) {
+        .foo {
+          color: red;
+          background: #fff;
+        }
+
+        .baz {
+          color: #fff;
+        }
+      }
+    "#},
+    );
+
+    test(
+      r#"
+      @supports (flex: 1) {
+        .foo {
+          color: red;
+        }
+      }
+      @supports (display: grid) {
+        .foo {
+          background: #fff;
+        }
+
+        .baz {
+          color: #fff;
+        }
+      }
+      "#,
+      indoc! {r#"
+      @supports (flex: 1) {
+        .foo {
+          color: red;
+        }
+      }
+
+      @supports (display: grid) {
+        .foo {
+          background: #fff;
+        }
+
+        .baz {
+          color: #fff;
+        }
+      }
+    "#},
+    );
+  }
+
+  #[test]
+  fn test_opacity() {
+    minify_test(".foo { opacity: 0 }", ".foo{opacity:0}");
+    minify_test(".foo { opacity: 0% }", ".foo{opacity:0}");
+    minify_test(".foo { opacity: 0.5 }", ".foo{opacity:.5}");
+    minify_test(".foo { opacity: 50% }", ".foo{opacity:.5}");
+    minify_test(".foo { opacity: 1 }", ".foo{opacity:1}");
+    minify_test(".foo { opacity: 100% }", ".foo{opacity:1}");
+  }
+
+  #[test]
+  fn test_transitions() {
+    minify_test(".foo { transition-duration: 500ms }", ".foo{transition-duration:.5s}");
+    minify_test(".foo { transition-duration: .5s }", ".foo{transition-duration:.5s}");
+    minify_test(".foo { transition-duration: 99ms }", ".foo{transition-duration:99ms}");
+    minify_test(".foo { transition-duration: .099s }", ".foo{transition-duration:99ms}");
+    minify_test(".foo { transition-duration: 2000ms }", ".foo{transition-duration:2s}");
+    minify_test(".foo { transition-duration: 2s }", ".foo{transition-duration:2s}");
+    minify_test(
+      ".foo { transition-duration: calc(1s - 50ms) }",
+      ".foo{transition-duration:.95s}",
+    );
+    minify_test(
+      ".foo { transition-duration: calc(1s - 50ms + 2s) }",
+      ".foo{transition-duration:2.95s}",
+    );
+    minify_test(
+      ".foo { transition-duration: calc((1s - 50ms) * 2) }",
+      ".foo{transition-duration:1.9s}",
+    );
+    minify_test(
+      ".foo { transition-duration: calc(2 * (1s - 50ms)) }",
+      ".foo{transition-duration:1.9s}",
+    );
+    minify_test(
+      ".foo { transition-duration: calc((2s + 50ms) - (1s - 50ms)) }",
+      ".foo{transition-duration:1.1s}",
+    );
+    minify_test(
+      ".foo { transition-duration: 500ms, 50ms }",
+      ".foo{transition-duration:.5s,50ms}",
+    );
+    minify_test(".foo { transition-delay: 500ms }", ".foo{transition-delay:.5s}");
+    minify_test(
+      ".foo { transition-property: background }",
+      ".foo{transition-property:background}",
+    );
+    minify_test(
+      ".foo { transition-property: background, opacity }",
+      ".foo{transition-property:background,opacity}",
+    );
+    minify_test(
+      ".foo { transition-timing-function: linear }",
+      ".foo{transition-timing-function:linear}",
+    );
+    minify_test(
+      ".foo { transition-timing-function: ease }",
+      ".foo{transition-timing-function:ease}",
+    );
+    minify_test(
+      ".foo { transition-timing-function: ease-in }",
+      ".foo{transition-timing-function:ease-in}",
+    );
+    minify_test(
+      ".foo { transition-timing-function: ease-out }",
+      ".foo{transition-timing-function:ease-out}",
+    );
+    minify_test(
+      ".foo { transition-timing-function: ease-in-out }",
+      ".foo{transition-timing-function:ease-in-out}",
+    );
+    minify_test(
+      ".foo { transition-timing-function: cubic-bezier(0.25, 0.1, 0.25, 1) }",
+      ".foo{transition-timing-function:ease}",
+    );
+    minify_test(
+      ".foo { transition-timing-function: cubic-bezier(0.42, 0, 1, 1) }",
+      ".foo{transition-timing-function:ease-in}",
+    );
+    minify_test(
+      ".foo { transition-timing-function: cubic-bezier(0, 0, 0.58, 1) }",
+      ".foo{transition-timing-function:ease-out}",
+    );
+    minify_test(
+      ".foo { transition-timing-function: cubic-bezier(0.42, 0, 0.58, 1) }",
+      ".foo{transition-timing-function:ease-in-out}",
+    );
+    minify_test(
+      ".foo { transition-timing-function: cubic-bezier(0.58, 0.2, 0.11, 1.2) }",
+      ".foo{transition-timing-function:cubic-bezier(.58,.2,.11,1.2)}",
+    );
+    minify_test(
+      ".foo { transition-timing-function: step-start }",
+      ".foo{transition-timing-function:step-start}",
+    );
+    minify_test(
+      ".foo { transition-timing-function: step-end }",
+      ".foo{transition-timing-function:step-end}",
+    );
+    minify_test(
+      ".foo { transition-timing-function: steps(1, start) }",
+      ".foo{transition-timing-function:step-start}",
+    );
+    minify_test(
+      ".foo { transition-timing-function: steps(1, jump-start) }",
+      ".foo{transition-timing-function:step-start}",
+    );
+    minify_test(
+      ".foo { transition-timing-function: steps(1, end) }",
+      ".foo{transition-timing-function:step-end}",
+    );
+    minify_test(
+      ".foo { transition-timing-function: steps(1, jump-end) }",
+      ".foo{transition-timing-function:step-end}",
+    );
+    minify_test(
+      ".foo { transition-timing-function: steps(5, jump-start) }",
+      ".foo{transition-timing-function:steps(5,start)}",
+    );
+    minify_test(
+      ".foo { transition-timing-function: steps(5, jump-end) }",
+      ".foo{transition-timing-function:steps(5,end)}",
+    );
+    minify_test(
+      ".foo { transition-timing-function: steps(5, jump-both) }",
+      ".foo{transition-timing-function:steps(5,jump-both)}",
+    );
+    minify_test(
+      ".foo { transition-timing-function: ease-in-out, cubic-bezier(0.42, 0, 1, 1) }",
+      ".foo{transition-timing-function:ease-in-out,ease-in}",
+    );
+    minify_test(
+      ".foo { transition-timing-function: cubic-bezier(0.42, 0, 1, 1), cubic-bezier(0.58, 0.2, 0.11, 1.2) }",
+      ".foo{transition-timing-function:ease-in,cubic-bezier(.58,.2,.11,1.2)}",
+    );
+    minify_test(
+      ".foo { transition-timing-function: step-start, steps(5, jump-start) }",
+      ".foo{transition-timing-function:step-start,steps(5,start)}",
+    );
+    minify_test(".foo { transition: width 2s ease }", ".foo{transition:width 2s}");
+    minify_test(
+      ".foo { transition: width 2s ease, height 1000ms cubic-bezier(0.25, 0.1, 0.25, 1) }",
+      ".foo{transition:width 2s,height 1s}",
+    );
+    minify_test(".foo { transition: width 2s 1s }", ".foo{transition:width 2s 1s}");
+    minify_test(".foo { transition: width 2s ease 1s }", ".foo{transition:width 2s 1s}");
+    minify_test(
+      ".foo { transition: ease-in 1s width 4s }",
+      ".foo{transition:width 1s ease-in 4s}",
+    );
+    minify_test(".foo { transition: opacity 0s .6s }", ".foo{transition:opacity 0s .6s}");
+    minify_test(".foo { transition: all 2s }", ".foo{transition:2s}");
+    minify_test(".foo { transition: all ease-in }", ".foo{transition:ease-in}");
+    minify_test(".foo { transition: all 0s .6s }", ".foo{transition:0s .6s}");
+    minify_test(".foo { transition: all }", ".foo{transition:all}");
+    minify_test(".foo { transition: all 0s ease 0s }", ".foo{transition:all}");
+    minify_test(
+      ".foo { transition: opacity 1s, all 2s ease-in }",
+      ".foo{transition:opacity 1s,2s ease-in}",
+    );
+    minify_test(
+      ".foo { transition: width 2s; transition: width -2s }",
+      ".foo{transition:width 2s;transition:width -2s}",
+    );
+    minify_test(".foo { transition: width 2s -1s }", ".foo{transition:width 2s -1s}");
+    minify_test(
+      ".a { transition: opacity 1s; transition-duration: -1s }",
+      ".a{transition:opacity 1s;transition-duration:-1s}",
+    );
+    test(
+      r#"
+      .foo {
+        transition-property: opacity;
+        transition-duration: 0.09s;
+        transition-timing-function: ease-in-out;
+        transition-delay: 500ms;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        transition: opacity 90ms ease-in-out .5s;
+      }
+    "#},
+    );
+    test(
+      r#"
+      .foo {
+        transition: opacity 2s;
+        transition-timing-function: ease;
+        transition-delay: 500ms;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        transition: opacity 2s .5s;
+      }
+    "#},
+    );
+    test(
+      r#"
+      .foo {
+        transition: opacity 500ms;
+        transition-timing-function: var(--ease);
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        transition: opacity .5s;
+        transition-timing-function: var(--ease);
+      }
+    "#},
+    );
+    test(
+      r#"
+      .foo {
+        transition-property: opacity;
+        transition-duration: 0.09s;
+        transition-timing-function: ease-in-out;
+        transition-delay: 500ms;
+        transition: color 2s;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        transition: color 2s;
+      }
+    "#},
+    );
+    test(
+      r#"
+      .foo {
+        transition-property: opacity, color;
+        transition-duration: 2s, 4s;
+        transition-timing-function: ease-in-out, ease-in;
+        transition-delay: 500ms, 0s;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        transition: opacity 2s ease-in-out .5s, color 4s ease-in;
+      }
+    "#},
+    );
+    test(
+      r#"
+      .foo {
+        transition-property: opacity, color;
+        transition-duration: 2s;
+        transition-timing-function: ease-in-out;
+        transition-delay: 500ms;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        transition: opacity 2s ease-in-out .5s, color 2s ease-in-out .5s;
+      }
+    "#},
+    );
+    test(
+      r#"
+      .foo {
+        transition-property: opacity, color, width, height;
+        transition-duration: 2s, 4s;
+        transition-timing-function: ease;
+        transition-delay: 0s;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        transition: opacity 2s, color 4s, width 2s, height 4s;
+      }
+    "#},
+    );
+
+    test(
+      r#"
+      .foo {
+        -webkit-transition-property: opacity, color;
+        -webkit-transition-duration: 2s, 4s;
+        -webkit-transition-timing-function: ease-in-out, ease-in;
+        -webkit-transition-delay: 500ms, 0s;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        -webkit-transition: opacity 2s ease-in-out .5s, color 4s ease-in;
+      }
+    "#},
+    );
+
+    test(
+      r#"
+      .foo {
+        -webkit-transition-property: opacity, color;
+        -webkit-transition-duration: 2s, 4s;
+        -webkit-transition-timing-function: ease-in-out, ease-in;
+        -webkit-transition-delay: 500ms, 0s;
+        -moz-transition-property: opacity, color;
+        -moz-transition-duration: 2s, 4s;
+        -moz-transition-timing-function: ease-in-out, ease-in;
+        -moz-transition-delay: 500ms, 0s;
+        transition-property: opacity, color;
+        transition-duration: 2s, 4s;
+        transition-timing-function: ease-in-out, ease-in;
+        transition-delay: 500ms, 0s;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        -webkit-transition: opacity 2s ease-in-out .5s, color 4s ease-in;
+        -moz-transition: opacity 2s ease-in-out .5s, color 4s ease-in;
+        transition: opacity 2s ease-in-out .5s, color 4s ease-in;
+      }
+    "#},
+    );
+
+    test(
+      r#"
+      .foo {
+        -webkit-transition-property: opacity, color;
+        -moz-transition-property: opacity, color;
+        transition-property: opacity, color;
+        -webkit-transition-duration: 2s, 4s;
+        -moz-transition-duration: 2s, 4s;
+        transition-duration: 2s, 4s;
+        -webkit-transition-timing-function: ease-in-out, ease-in;
+        transition-timing-function: ease-in-out, ease-in;
+        -moz-transition-timing-function: ease-in-out, ease-in;
+        -webkit-transition-delay: 500ms, 0s;
+        -moz-transition-delay: 500ms, 0s;
+        transition-delay: 500ms, 0s;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        -webkit-transition: opacity 2s ease-in-out .5s, color 4s ease-in;
+        -moz-transition: opacity 2s ease-in-out .5s, color 4s ease-in;
+        transition: opacity 2s ease-in-out .5s, color 4s ease-in;
+      }
+    "#},
+    );
+
+    test(
+      r#"
+      .foo {
+        -webkit-transition-property: opacity;
+        -moz-transition-property: color;
+        transition-property: opacity, color;
+        -webkit-transition-duration: 2s;
+        -moz-transition-duration: 4s;
+        transition-duration: 2s, 4s;
+        -webkit-transition-timing-function: ease-in-out;
+        -moz-transition-timing-function: ease-in-out;
+        transition-timing-function: ease-in-out, ease-in;
+        -webkit-transition-delay: 500ms;
+        -moz-transition-delay: 0s;
+        transition-delay: 500ms, 0s;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        -webkit-transition-property: opacity;
+        -moz-transition-property: color;
+        transition-property: opacity, color;
+        -webkit-transition-duration: 2s;
+        -moz-transition-duration: 4s;
+        transition-duration: 2s, 4s;
+        -webkit-transition-timing-function: ease-in-out;
+        -moz-transition-timing-function: ease-in-out;
+        -webkit-transition-delay: .5s;
+        transition-timing-function: ease-in-out, ease-in;
+        -moz-transition-delay: 0s;
+        transition-delay: .5s, 0s;
+      }
+    "#},
+    );
+
+    test(
+      r#"
+      .foo {
+        -webkit-transition-property: opacity;
+        transition-property: opacity, color;
+        -moz-transition-property: color;
+        -webkit-transition-duration: 2s;
+        transition-duration: 2s, 4s;
+        -moz-transition-duration: 4s;
+        -webkit-transition-timing-function: ease-in-out;
+        transition-timing-function: ease-in-out, ease-in;
+        -moz-transition-timing-function: ease-in-out;
+        -webkit-transition-delay: 500ms;
+        transition-delay: 500ms, 0s;
+        -moz-transition-delay: 0s;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        -webkit-transition-property: opacity;
+        transition-property: opacity, color;
+        -moz-transition-property: color;
+        -webkit-transition-duration: 2s;
+        transition-duration: 2s, 4s;
+        -moz-transition-duration: 4s;
+        -webkit-transition-timing-function: ease-in-out;
+        transition-timing-function: ease-in-out, ease-in;
+        -webkit-transition-delay: .5s;
+        -moz-transition-timing-function: ease-in-out;
+        transition-delay: .5s, 0s;
+        -moz-transition-delay: 0s;
+      }
+    "#},
+    );
+
+    test(
+      r#"
+      .foo {
+        transition: opacity 2s;
+        -webkit-transition-duration: 2s;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        transition: opacity 2s;
+        -webkit-transition-duration: 2s;
+      }
+    "#},
+    );
+
+    prefix_test(
+      r#"
+      .foo {
+        transition-property: margin-inline-start;
+      }
+    "#,
+      indoc! {r#"
+      .foo:not(:-webkit-any(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi))) {
+        transition-property: margin-left;
+      }
+
+      .foo:not(:is(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi))) {
+        transition-property: margin-left;
+      }
+
+      .foo:-webkit-any(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi)) {
+        transition-property: margin-right;
+      }
+
+      .foo:is(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi)) {
+        transition-property: margin-right;
+      }
+    "#
+      },
+      Browsers {
+        safari: Some(8 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      r#"
+      .foo {
+        transition-property: margin-inline-start, padding-inline-start;
+      }
+    "#,
+      indoc! {r#"
+      .foo:not(:-webkit-any(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi))) {
+        transition-property: margin-left, padding-left;
+      }
+
+      .foo:not(:is(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi))) {
+        transition-property: margin-left, padding-left;
+      }
+
+      .foo:-webkit-any(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi)) {
+        transition-property: margin-right, padding-right;
+      }
+
+      .foo:is(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi)) {
+        transition-property: margin-right, padding-right;
+      }
+    "#
+      },
+      Browsers {
+        safari: Some(8 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      r#"
+      .foo {
+        transition-property: margin-inline-start, opacity, padding-inline-start, color;
+      }
+    "#,
+      indoc! {r#"
+      .foo:not(:-webkit-any(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi))) {
+        transition-property: margin-left, opacity, padding-left, color;
+      }
+
+      .foo:not(:is(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi))) {
+        transition-property: margin-left, opacity, padding-left, color;
+      }
+
+      .foo:-webkit-any(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi)) {
+        transition-property: margin-right, opacity, padding-right, color;
+      }
+
+      .foo:is(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi)) {
+        transition-property: margin-right, opacity, padding-right, color;
+      }
+    "#
+      },
+      Browsers {
+        safari: Some(8 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      r#"
+      .foo {
+        transition-property: margin-block;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        transition-property: margin-top, margin-bottom;
+      }
+    "#
+      },
+      Browsers {
+        safari: Some(8 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      r#"
+      .foo {
+        transition: margin-inline-start 2s;
+      }
+    "#,
+      indoc! {r#"
+      .foo:not(:-webkit-any(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi))) {
+        transition: margin-left 2s;
+      }
+
+      .foo:not(:is(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi))) {
+        transition: margin-left 2s;
+      }
+
+      .foo:-webkit-any(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi)) {
+        transition: margin-right 2s;
+      }
+
+      .foo:is(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi)) {
+        transition: margin-right 2s;
+      }
+    "#
+      },
+      Browsers {
+        safari: Some(8 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      r#"
+      .foo {
+        transition: margin-inline-start 2s, padding-inline-start 2s;
+      }
+    "#,
+      indoc! {r#"
+      .foo:not(:-webkit-any(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi))) {
+        transition: margin-left 2s, padding-left 2s;
+      }
+
+      .foo:not(:is(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi))) {
+        transition: margin-left 2s, padding-left 2s;
+      }
+
+      .foo:-webkit-any(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi)) {
+        transition: margin-right 2s, padding-right 2s;
+      }
+
+      .foo:is(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi)) {
+        transition: margin-right 2s, padding-right 2s;
+      }
+    "#
+      },
+      Browsers {
+        safari: Some(8 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      r#"
+      .foo {
+        transition: margin-block-start 2s;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        transition: margin-top 2s;
+      }
+    "#
+      },
+      Browsers {
+        safari: Some(8 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      r#"
+      .foo {
+        transition: transform;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        -webkit-transition: -webkit-transform, transform;
+        transition: -webkit-transform, transform;
+      }
+    "#
+      },
+      Browsers {
+        safari: Some(6 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      r#"
+      .foo {
+        transition: border-start-start-radius;
+      }
+    "#,
+      indoc! {r#"
+      .foo:not(:is(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi))) {
+        -webkit-transition: -webkit-border-top-left-radius, border-top-left-radius;
+        transition: -webkit-border-top-left-radius, border-top-left-radius;
+      }
+
+      .foo:is(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi)) {
+        -webkit-transition: -webkit-border-top-right-radius, border-top-right-radius;
+        transition: -webkit-border-top-right-radius, border-top-right-radius;
+      }
+    "#
+      },
+      Browsers {
+        safari: Some(4 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      r#"
+      .foo {
+        transition: border-start-start-radius;
+      }
+    "#,
+      indoc! {r#"
+      .foo:not(:lang(ae, ar, arc, bcc, bqi, ckb, dv, fa, glk, he, ku, mzn, nqo, pnb, ps, sd, ug, ur, yi)) {
+        transition: border-top-left-radius;
+      }
+
+      .foo:lang(ae, ar, arc, bcc, bqi, ckb, dv, fa, glk, he, ku, mzn, nqo, pnb, ps, sd, ug, ur, yi) {
+        transition: border-top-right-radius;
+      }
+    "#
+      },
+      Browsers {
+        safari: Some(12 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    test(
+      r#"
+      .foo {
+        -webkit-transition: background 200ms;
+        -moz-transition: background 200ms;
+        transition: background 230ms;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        -webkit-transition: background .2s;
+        -moz-transition: background .2s;
+        transition: background .23s;
+      }
+    "#},
+    );
+
+    prefix_test(
+      r#"
+      .foo {
+        -webkit-transition: background 200ms;
+        -moz-transition: background 200ms;
+        transition: background 230ms;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        -webkit-transition: background .2s;
+        -moz-transition: background .2s;
+        transition: background .23s;
+      }
+    "#},
+      Browsers {
+        chrome: Some(95 << 16),
+        ..Browsers::default()
+      },
+    );
+  }
+
+  #[test]
+  fn test_animation() {
+    minify_test(".foo { animation-name: test }", ".foo{animation-name:test}");
+    minify_test(".foo { animation-name: \"test\" }", ".foo{animation-name:test}");
+    minify_test(".foo { animation-name: foo, bar }", ".foo{animation-name:foo,bar}");
+    minify_test(".foo { animation-name: \"none\" }", ".foo{animation-name:\"none\"}");
+    minify_test(
+      ".foo { animation-name: \"none\", foo }",
+      ".foo{animation-name:\"none\",foo}",
+    );
+    let name = crate::properties::animation::AnimationName::parse_string("default");
+    assert!(matches!(name, Err(..)));
+
+    minify_test(".foo { animation-name: none }", ".foo{animation-name:none}");
+    minify_test(".foo { animation-name: none, none }", ".foo{animation-name:none,none}");
+
+    // Test CSS-wide keywords
+    minify_test(".foo { animation-name: unset }", ".foo{animation-name:unset}");
+    minify_test(".foo { animation-name: \"unset\" }", ".foo{animation-name:\"unset\"}");
+    minify_test(".foo { animation-name: \"revert\" }", ".foo{animation-name:\"revert\"}");
+    minify_test(
+      ".foo { animation-name: \"unset\", \"revert\"}",
+      ".foo{animation-name:\"unset\",\"revert\"}",
+    );
+    minify_test(
+      ".foo { animation-name: foo, \"revert\"}",
+      ".foo{animation-name:foo,\"revert\"}",
+    );
+    minify_test(
+      ".foo { animation-name: \"string\", \"revert\"}",
+      ".foo{animation-name:string,\"revert\"}",
+    );
+    minify_test(
+      ".foo { animation-name: \"string\", foo, \"revert\"}",
+      ".foo{animation-name:string,foo,\"revert\"}",
+    );
+    minify_test(
+      ".foo { animation-name: \"default\" }",
+      ".foo{animation-name:\"default\"}",
+    );
+    minify_test(".foo { animation-duration: 100ms }", ".foo{animation-duration:.1s}");
+    minify_test(
+      ".foo { animation-duration: 100ms, 2000ms }",
+      ".foo{animation-duration:.1s,2s}",
+    );
+    minify_test(
+      ".foo { animation-timing-function: ease }",
+      ".foo{animation-timing-function:ease}",
+    );
+    minify_test(
+      ".foo { animation-timing-function: cubic-bezier(0.42, 0, 1, 1) }",
+      ".foo{animation-timing-function:ease-in}",
+    );
+    minify_test(
+      ".foo { animation-timing-function: ease, cubic-bezier(0.42, 0, 1, 1) }",
+      ".foo{animation-timing-function:ease,ease-in}",
+    );
+    minify_test(
+      ".foo { animation-iteration-count: 5 }",
+      ".foo{animation-iteration-count:5}",
+    );
+    minify_test(
+      ".foo { animation-iteration-count: 2.5 }",
+      ".foo{animation-iteration-count:2.5}",
+    );
+    minify_test(
+      ".foo { animation-iteration-count: 2.0 }",
+      ".foo{animation-iteration-count:2}",
+    );
+    minify_test(
+      ".foo { animation-iteration-count: infinite }",
+      ".foo{animation-iteration-count:infinite}",
+    );
+    minify_test(
+      ".foo { animation-iteration-count: 1, infinite }",
+      ".foo{animation-iteration-count:1,infinite}",
+    );
+    minify_test(
+      ".foo { animation-iteration-count: 0 }",
+      ".foo{animation-iteration-count:0}",
+    );
+    minify_test(
+      ".foo { animation-iteration-count: 2; animation-iteration-count: -1 }",
+      ".foo{animation-iteration-count:2;animation-iteration-count:-1}",
+    );
+    minify_test(
+      ".foo { animation-direction: reverse }",
+      ".foo{animation-direction:reverse}",
+    );
+    minify_test(
+      ".foo { animation-direction: alternate, reverse }",
+      ".foo{animation-direction:alternate,reverse}",
+    );
+    minify_test(
+      ".foo { animation-play-state: paused }",
+      ".foo{animation-play-state:paused}",
+    );
+    minify_test(
+      ".foo { animation-play-state: running, paused }",
+      ".foo{animation-play-state:running,paused}",
+    );
+    minify_test(".foo { animation-delay: 100ms }", ".foo{animation-delay:.1s}");
+    minify_test(
+      ".foo { animation-delay: 100ms, 2000ms }",
+      ".foo{animation-delay:.1s,2s}",
+    );
+    minify_test(
+      ".foo { animation-fill-mode: forwards }",
+      ".foo{animation-fill-mode:forwards}",
+    );
+    minify_test(
+      ".foo { animation-fill-mode: Backwards,forwards }",
+      ".foo{animation-fill-mode:backwards,forwards}",
+    );
+    minify_test(".foo { animation: none }", ".foo{animation:none}");
+    minify_test(".foo { animation: \"none\" }", ".foo{animation:\"none\"}");
+    minify_test(".foo { animation: \"None\" }", ".foo{animation:\"None\"}");
+    minify_test(".foo { animation: \"none\", none }", ".foo{animation:\"none\",none}");
+    minify_test(".foo { animation: none, none }", ".foo{animation:none,none}");
+    minify_test(".foo { animation: foo 1s -2s }", ".foo{animation:1s -2s foo}");
+    minify_test(
+      ".foo { animation: foo 1s; animation: foo -1s }",
+      ".foo{animation:1s foo;animation:foo -1s}",
+    );
+    minify_test(
+      ".a { animation: foo 1s; animation-duration: -1s }",
+      ".a{animation:1s foo;animation-duration:-1s}",
+    );
+    minify_test(
+      ".foo { animation: foo 1s; animation: foo -1 }",
+      ".foo{animation:1s foo;animation:foo -1}",
+    );
+    minify_test(".foo { animation: \"none\" none }", ".foo{animation:\"none\"}");
+    minify_test(".foo { animation: none none }", ".foo{animation:none}");
+
+    // Test animation-name + animation-fill-mode
+    minify_test(
+      ".foo { animation: 2s both \"none\"}",
+      ".foo{animation:2s both \"none\"}",
+    );
+    minify_test(
+      ".foo { animation: both \"none\" 2s}",
+      ".foo{animation:2s both \"none\"}",
+    );
+    minify_test(".foo { animation: \"none\" 2s none}", ".foo{animation:2s \"none\"}");
+    minify_test(".foo { animation: none \"none\" 2s}", ".foo{animation:2s \"none\"}");
+    minify_test(
+      ".foo { animation: none, \"none\" 2s forwards}",
+      ".foo{animation:none,2s forwards \"none\"}",
+    );
+
+    minify_test(".foo { animation: \"unset\" }", ".foo{animation:\"unset\"}");
+    minify_test(".foo { animation: \"string\" .5s }", ".foo{animation:.5s string}");
+    minify_test(".foo { animation: \"unset\" .5s }", ".foo{animation:.5s \"unset\"}");
+    minify_test(
+      ".foo { animation: none, \"unset\" .5s}",
+      ".foo{animation:none,.5s \"unset\"}",
+    );
+    minify_test(
+      ".foo { animation: \"unset\" 0s 3s infinite, none }",
+      ".foo{animation:0s 3s infinite \"unset\",none}",
+    );
+
+    minify_test(".foo { animation: \"infinite\" 2s 1 }", ".foo{animation:2s 1 infinite}");
+    minify_test(".foo { animation: \"paused\" 2s }", ".foo{animation:2s running paused}");
+    minify_test(
+      ".foo { animation: \"forwards\" 2s }",
+      ".foo{animation:2s none forwards}",
+    );
+    minify_test(
+      ".foo { animation: \"reverse\" 2s }",
+      ".foo{animation:2s normal reverse}",
+    );
+    minify_test(
+      ".foo { animation: \"reverse\" 2s alternate }",
+      ".foo{animation:2s alternate reverse}",
+    );
+
+    minify_test(
+      ".foo { animation: 3s ease-in 1s infinite reverse both running slidein }",
+      ".foo{animation:3s ease-in 1s infinite reverse both slidein}",
+    );
+    minify_test(
+      ".foo { animation: 3s slidein paused ease 1s 1 reverse both }",
+      ".foo{animation:3s 1s reverse both paused slidein}",
+    );
+    minify_test(".foo { animation: 3s ease ease }", ".foo{animation:3s ease ease}");
+    minify_test(
+      ".foo { animation: 3s cubic-bezier(0.25, 0.1, 0.25, 1) foo }",
+      ".foo{animation:3s foo}",
+    );
+    minify_test(
+      ".foo { animation: foo 0s 3s infinite }",
+      ".foo{animation:0s 3s infinite foo}",
+    );
+    test(
+      r#"
+      .foo {
+        animation-name: foo;
+        animation-duration: 0.09s;
+        animation-timing-function: ease-in-out;
+        animation-iteration-count: 2;
+        animation-direction: alternate;
+        animation-play-state: running;
+        animation-delay: 100ms;
+        animation-fill-mode: forwards;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        animation: 90ms ease-in-out .1s 2 alternate forwards foo;
+      }
+    "#},
+    );
+    test(
+      r#"
+      .foo {
+        animation-name: foo, bar;
+        animation-duration: 0.09s, 200ms;
+        animation-timing-function: ease-in-out, ease;
+        animation-iteration-count: 2, 1;
+        animation-direction: alternate, normal;
+        animation-play-state: running, paused;
+        animation-delay: 100ms, 0s;
+        animation-fill-mode: forwards, none;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        animation: 90ms ease-in-out .1s 2 alternate forwards foo, .2s paused bar;
+      }
+    "#},
+    );
+    test(
+      r#"
+      .foo {
+        animation: bar 200ms;
+        animation-timing-function: ease-in-out;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        animation: .2s ease-in-out bar;
+      }
+    "#},
+    );
+    test(
+      r#"
+      .foo {
+        animation: bar 200ms;
+        animation-timing-function: var(--ease);
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        animation: .2s bar;
+        animation-timing-function: var(--ease);
+      }
+    "#},
+    );
+    test(
+      r#"
+      .foo {
+        animation-name: foo, bar;
+        animation-duration: 0.09s;
+        animation-timing-function: ease-in-out;
+        animation-iteration-count: 2;
+        animation-direction: alternate;
+        animation-play-state: running;
+        animation-delay: 100ms;
+        animation-fill-mode: forwards;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        animation-name: foo, bar;
+        animation-duration: 90ms;
+        animation-timing-function: ease-in-out;
+        animation-iteration-count: 2;
+        animation-direction: alternate;
+        animation-play-state: running;
+        animation-delay: .1s;
+        animation-fill-mode: forwards;
+      }
+    "#},
+    );
+    test(
+      r#"
+      .foo {
+        -webkit-animation-name: foo;
+        -webkit-animation-duration: 0.09s;
+        -webkit-animation-timing-function: ease-in-out;
+        -webkit-animation-iteration-count: 2;
+        -webkit-animation-direction: alternate;
+        -webkit-animation-play-state: running;
+        -webkit-animation-delay: 100ms;
+        -webkit-animation-fill-mode: forwards;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        -webkit-animation: 90ms ease-in-out .1s 2 alternate forwards foo;
+      }
+    "#},
+    );
+    test(
+      r#"
+      .foo {
+        -moz-animation: bar 200ms;
+        -moz-animation-timing-function: ease-in-out;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        -moz-animation: .2s ease-in-out bar;
+      }
+    "#},
+    );
+    test(
+      r#"
+      .foo {
+        -webkit-animation: bar 200ms;
+        -webkit-animation-timing-function: ease-in-out;
+        -moz-animation: bar 200ms;
+        -moz-animation-timing-function: ease-in-out;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        -webkit-animation: .2s ease-in-out bar;
+        -moz-animation: .2s ease-in-out bar;
+      }
+    "#},
+    );
+
+    prefix_test(
+      r#"
+      .foo {
+        animation: .2s ease-in-out bar;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        -webkit-animation: .2s ease-in-out bar;
+        -moz-animation: .2s ease-in-out bar;
+        animation: .2s ease-in-out bar;
+      }
+    "#},
+      Browsers {
+        firefox: Some(6 << 16),
+        safari: Some(6 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      r#"
+      .foo {
+        -webkit-animation: .2s ease-in-out bar;
+        -moz-animation: .2s ease-in-out bar;
+        animation: .2s ease-in-out bar;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        animation: .2s ease-in-out bar;
+      }
+    "#},
+      Browsers {
+        firefox: Some(20 << 16),
+        safari: Some(14 << 16),
+        ..Browsers::default()
+      },
+    );
+    prefix_test(
+      r#"
+      .foo {
+        animation: 200ms var(--ease) bar;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        -webkit-animation: .2s var(--ease) bar;
+        -moz-animation: .2s var(--ease) bar;
+        animation: .2s var(--ease) bar;
+      }
+    "#},
+      Browsers {
+        firefox: Some(6 << 16),
+        safari: Some(6 << 16),
+        ..Browsers::default()
+      },
+    );
+  }
+
+  #[test]
+  fn test_scroll_driven_animations() {
+    minify_test(".foo { animation-timeline: auto }", ".foo{animation-timeline:auto}");
+    minify_test(".foo { animation-timeline: none }", ".foo{animation-timeline:none}");
+    minify_test(".foo { animation-timeline: --foo }", ".foo{animation-timeline:--foo}");
+    minify_test(
+      ".foo { animation-timeline: --foo, auto }",
+      ".foo{animation-timeline:--foo,auto}",
+    );
+    minify_test(
+      ".foo { animation-timeline: scroll() }",
+      ".foo{animation-timeline:scroll()}",
+    );
+    minify_test(
+      ".foo { animation-timeline: scroll(nearest block) }",
+      ".foo{animation-timeline:scroll()}",
+    );
+    minify_test(
+      ".foo { animation-timeline: scroll(root) }",
+      ".foo{animation-timeline:scroll(root)}",
+    );
+    minify_test(
+      ".foo { animation-timeline: scroll(x self) }",
+      ".foo{animation-timeline:scroll(self x)}",
+    );
+    minify_test(".foo { animation-timeline: view() }", ".foo{animation-timeline:view()}");
+    minify_test(
+      ".foo { animation-timeline: view(block auto) }",
+      ".foo{animation-timeline:view()}",
+    );
+    minify_test(
+      ".foo { animation-timeline: view(10px 20% inline) }",
+      ".foo{animation-timeline:view(inline 10px 20%)}",
+    );
+    minify_test(
+      ".foo { animation-timeline: view(10px 10px) }",
+      ".foo{animation-timeline:view(10px)}",
+    );
+    minify_test(
+      ".foo { animation-timeline: scroll(), view(x) }",
+      ".foo{animation-timeline:scroll(),view(x)}",
+    );
+    minify_test(".foo { animation-timeline: foo }", ".foo{animation-timeline:foo}"); // invalid
+    minify_test(
+      ".foo { animation-timeline: scroll(block inline) }",
+      ".foo{animation-timeline:scroll(block inline)}",
+    ); // invalid
+
+    minify_test(
+      ".foo { animation: foo 1s; animation-timeline: scroll() }",
+      ".foo{animation:1s foo;animation-timeline:scroll()}",
+    );
+    minify_test(
+      ".foo { animation-timeline: scroll(); animation: foo 1s }",
+      ".foo{animation-timeline:scroll();animation:1s foo}",
+    );
+
+    minify_test(".foo { scroll-timeline-name: none }", ".foo{scroll-timeline-name:none}");
+    minify_test(
+      ".foo { scroll-timeline-name: --foo, --bar }",
+      ".foo{scroll-timeline-name:--foo,--bar}",
+    );
+    minify_test(".foo { scroll-timeline-name: foo }", ".foo{scroll-timeline-name:foo}"); // invalid
+    minify_test(
+      ".foo { scroll-timeline-axis: block }",
+      ".foo{scroll-timeline-axis:block}",
+    );
+    minify_test(".foo { scroll-timeline-axis: x, y }", ".foo{scroll-timeline-axis:x,y}");
+    minify_test(".foo { scroll-timeline: --foo }", ".foo{scroll-timeline:--foo}");
+    minify_test(".foo { scroll-timeline: --foo block }", ".foo{scroll-timeline:--foo}");
+    minify_test(
+      ".foo { scroll-timeline: --foo inline, --bar y }",
+      ".foo{scroll-timeline:--foo inline,--bar y}",
+    );
+    minify_test(".foo { scroll-timeline: x --foo }", ".foo{scroll-timeline:x --foo}"); // invalid
+
+    minify_test(".foo { view-timeline-name: --foo }", ".foo{view-timeline-name:--foo}");
+    minify_test(".foo { view-timeline-axis: inline }", ".foo{view-timeline-axis:inline}");
+    minify_test(".foo { view-timeline-inset: auto }", ".foo{view-timeline-inset:auto}");
+    minify_test(
+      ".foo { view-timeline-inset: 10px 10px }",
+      ".foo{view-timeline-inset:10px}",
+    );
+    minify_test(
+      ".foo { view-timeline-inset: auto 20%, 10px }",
+      ".foo{view-timeline-inset:auto 20%,10px}",
+    );
+    minify_test(".foo { view-timeline: --foo }", ".foo{view-timeline:--foo}");
+    minify_test(".foo { view-timeline: --foo block auto }", ".foo{view-timeline:--foo}");
+    minify_test(
+      ".foo { view-timeline: --foo 10px inline }",
+      ".foo{view-timeline:--foo inline 10px}",
+    );
+    minify_test(
+      ".foo { view-timeline: --foo x, --bar 0 20% }",
+      ".foo{view-timeline:--foo x,--bar 0 20%}",
+    );
+
+    minify_test(".foo { timeline-scope: none }", ".foo{timeline-scope:none}");
+    minify_test(".foo { timeline-scope: all }", ".foo{timeline-scope:all}");
+    minify_test(
+      ".foo { timeline-scope: --foo, --bar }",
+      ".foo{timeline-scope:--foo,--bar}",
+    );
+    minify_test(".foo { timeline-scope: foo }", ".foo{timeline-scope:foo}"); // invalid
+  }
+
+  #[test]
+  fn test_animation_composition_and_range() {
+    minify_test(
+      ".foo { animation-composition: replace }",
+      ".foo{animation-composition:replace}",
+    );
+    minify_test(
+      ".foo { animation-composition: add, accumulate }",
+      ".foo{animation-composition:add,accumulate}",
+    );
+    minify_test(".foo { animation-composition: foo }", ".foo{animation-composition:foo}"); // invalid
+
+    minify_test(
+      ".foo { animation-range-start: normal }",
+      ".foo{animation-range-start:normal}",
+    );
+    minify_test(".foo { animation-range-start: 10% }", ".foo{animation-range-start:10%}");
+    minify_test(
+      ".foo { animation-range-start: entry }",
+      ".foo{animation-range-start:entry}",
+    );
+    minify_test(
+      ".foo { animation-range-start: entry 0% }",
+      ".foo{animation-range-start:entry}",
+    );
+    minify_test(
+      ".foo { animation-range-start: entry 10% }",
+      ".foo{animation-range-start:entry 10%}",
+    );
+    minify_test(
+      ".foo { animation-range-start: cover 100px, exit-crossing }",
+      ".foo{animation-range-start:cover 100px,exit-crossing}",
+    );
+    minify_test(".foo { animation-range-end: exit }", ".foo{animation-range-end:exit}");
+    minify_test(
+      ".foo { animation-range-end: exit 100% }",
+      ".foo{animation-range-end:exit}",
+    );
+    minify_test(
+      ".foo { animation-range-end: exit 50% }",
+      ".foo{animation-range-end:exit 50%}",
+    );
+    minify_test(".foo { animation-range-end: foo }", ".foo{animation-range-end:foo}"); // invalid
+
+    minify_test(".foo { animation-range: normal }", ".foo{animation-range:normal}");
+    minify_test(
+      ".foo { animation-range: normal normal }",
+      ".foo{animation-range:normal}",
+    );
+    minify_test(".foo { animation-range: entry }", ".foo{animation-range:entry}");
+    minify_test(
+      ".foo { animation-range: entry entry 100% }",
+      ".foo{animation-range:entry}",
+    );
+    minify_test(".foo { animation-range: entry 10% }", ".foo{animation-range:entry 10%}");
+    minify_test(
+      ".foo { animation-range: entry 10% entry 90% }",
+      ".foo{animation-range:entry 10% entry 90%}",
+    );
+    minify_test(
+      ".foo { animation-range: entry exit }",
+      ".foo{animation-range:entry exit}",
+    );
+    minify_test(
+      ".foo { animation-range: entry 0% 50% }",
+      ".foo{animation-range:entry 0% 50%}",
+    );
+    minify_test(".foo { animation-range: 10% 90% }", ".foo{animation-range:10% 90%}");
+    minify_test(".foo { animation-range: 10% normal }", ".foo{animation-range:10%}");
+    minify_test(
+      ".foo { animation-range: entry, cover 20% }",
+      ".foo{animation-range:entry,cover 20%}",
+    );
+
+    minify_test(
+      ".foo { animation-range-start: entry; animation-range-end: exit }",
+      ".foo{animation-range:entry exit}",
+    );
+    minify_test(
+      ".foo { animation-range-start: entry, exit; animation-range-end: exit }",
+      ".foo{animation-range-start:entry,exit;animation-range-end:exit}",
+    );
+    minify_test(
+      ".foo { animation: foo 1s; animation-composition: add }",
+      ".foo{animation:1s foo;animation-composition:add}",
+    );
+    minify_test(
+      ".foo { animation-range: entry; animation: foo 1s }",
+      ".foo{animation-range:entry;animation:1s foo}",
+    );
+  }
+
+  #[test]
+  fn test_transform() {
+    minify_test(
+      ".foo { transform: translate(2px, 3px)",
+      ".foo{transform:translate(2px,3px)}",
+    );
+    minify_test(
+      ".foo { transform: translate(2px, 0px)",
+      ".foo{transform:translate(2px)}",
+    );
+    minify_test(
+      ".foo { transform: translate(0px, 2px)",
+      ".foo{transform:translateY(2px)}",
+    );
+    minify_test(".foo { transform: translateX(2px)", ".foo{transform:translate(2px)}");
+    minify_test(".foo { transform: translateY(2px)", ".foo{transform:translateY(2px)}");
+    minify_test(".foo { transform: translateZ(2px)", ".foo{transform:translateZ(2px)}");
+    minify_test(
+      ".foo { transform: translate3d(2px, 3px, 4px)",
+      ".foo{transform:translate3d(2px,3px,4px)}",
+    );
+    minify_test(
+      ".foo { transform: translate3d(10%, 20%, 4px)",
+      ".foo{transform:translate3d(10%,20%,4px)}",
+    );
+    minify_test(
+      ".foo { transform: translate3d(2px, 0px, 0px)",
+      ".foo{transform:translate(2px)}",
+    );
+    minify_test(
+      ".foo { transform: translate3d(0px, 2px, 0px)",
+      ".foo{transform:translateY(2px)}",
+    );
+    minify_test(
+      ".foo { transform: translate3d(0px, 0px, 2px)",
+      ".foo{transform:translateZ(2px)}",
+    );
+    minify_test(
+      ".foo { transform: translate3d(2px, 3px, 0px)",
+      ".foo{transform:translate(2px,3px)}",
+    );
+    minify_test(".foo { transform: scale(2, 3)", ".foo{transform:scale(2,3)}");
+    minify_test(".foo { transform: scale(10%, 20%)", ".foo{transform:scale(.1,.2)}");
+    minify_test(".foo { transform: scale(2, 2)", ".foo{transform:scale(2)}");
+    minify_test(".foo { transform: scale(2, 1)", ".foo{transform:scaleX(2)}");
+    minify_test(".foo { transform: scale(1, 2)", ".foo{transform:scaleY(2)}");
+    minify_test(".foo { transform: scaleX(2)", ".foo{transform:scaleX(2)}");
+    minify_test(".foo { transform: scaleY(2)", ".foo{transform:scaleY(2)}");
+    minify_test(".foo { transform: scaleZ(2)", ".foo{transform:scaleZ(2)}");
+    minify_test(".foo { transform: scale3d(2, 3, 4)", ".foo{transform:scale3d(2,3,4)}");
+    minify_test(".foo { transform: scale3d(2, 1, 1)", ".foo{transform:scaleX(2)}");
+    minify_test(".foo { transform: scale3d(1, 2, 1)", ".foo{transform:scaleY(2)}");
+    minify_test(".foo { transform: scale3d(1, 1, 2)", ".foo{transform:scaleZ(2)}");
+    minify_test(".foo { transform: scale3d(2, 2, 1)", ".foo{transform:scale(2)}");
+    minify_test(".foo { transform: rotate(20deg)", ".foo{transform:rotate(20deg)}");
+    minify_test(".foo { transform: rotateX(20deg)", ".foo{transform:rotateX(20deg)}");
+    minify_test(".foo { transform: rotateY(20deg)", ".foo{transform:rotateY(20deg)}");
+    minify_test(".foo { transform: rotateZ(20deg)", ".foo{transform:rotate(20deg)}");
+    minify_test(".foo { transform: rotate(360deg)", ".foo{transform:rotate(360deg)}");
+    minify_test(
+      ".foo { transform: rotate3d(2, 3, 4, 20deg)",
+      ".foo{transform:rotate3d(2,3,4,20deg)}",
+    );
+    minify_test(
+      ".foo { transform: rotate3d(1, 0, 0, 20deg)",
+      ".foo{transform:rotateX(20deg)}",
+    );
+    minify_test(
+      ".foo { transform: rotate3d(0, 1, 0, 20deg)",
+      ".foo{transform:rotateY(20deg)}",
+    );
+    minify_test(
+      ".foo { transform: rotate3d(0, 0, 1, 20deg)",
+      ".foo{transform:rotate(20deg)}",
+    );
+    minify_test(".foo { transform: rotate(405deg)}", ".foo{transform:rotate(405deg)}");
+    minify_test(".foo { transform: rotateX(405deg)}", ".foo{transform:rotateX(405deg)}");
+    minify_test(".foo { transform: rotateY(405deg)}", ".foo{transform:rotateY(405deg)}");
+    minify_test(".foo { transform: rotate(-200deg)}", ".foo{transform:rotate(-200deg)}");
+    minify_test(".foo { transform: rotate(0)", ".foo{transform:rotate(0)}");
+    minify_test(".foo { transform: rotate(0deg)", ".foo{transform:rotate(0)}");
+    minify_test(
+      ".foo { transform: rotateX(-200deg)}",
+      ".foo{transform:rotateX(-200deg)}",
+    );
+    minify_test(
+      ".foo { transform: rotateY(-200deg)}",
+      ".foo{transform:rotateY(-200deg)}",
+    );
+    minify_test(
+      ".foo { transform: rotate3d(1, 1, 0, -200deg)",
+      ".foo{transform:rotate3d(1,1,0,-200deg)}",
+    );
+    minify_test(".foo { transform: skew(20deg)", ".foo{transform:skew(20deg)}");
+    minify_test(".foo { transform: skew(20deg, 0deg)", ".foo{transform:skew(20deg)}");
+    minify_test(".foo { transform: skew(0deg, 20deg)", ".foo{transform:skewY(20deg)}");
+    minify_test(".foo { transform: skewX(20deg)", ".foo{transform:skew(20deg)}");
+    minify_test(".foo { transform: skewY(20deg)", ".foo{transform:skewY(20deg)}");
+    minify_test(
+      ".foo { transform: perspective(10px)",
+      ".foo{transform:perspective(10px)}",
+    );
+    minify_test(
+      ".foo { transform: matrix(1, 2, -1, 1, 80, 80)",
+      ".foo{transform:matrix(1,2,-1,1,80,80)}",
+    );
+    minify_test(
+      ".foo { transform: matrix3d(1, 0, 0, 0, 0, 1, 6, 0, 0, 0, 1, 0, 50, 100, 0, 1.1)",
+      ".foo{transform:matrix3d(1,0,0,0,0,1,6,0,0,0,1,0,50,100,0,1.1)}",
+    );
+    minify_test(
+      ".foo { transform: matrix(1, 0, 0, 1, 80, 40)",
+      ".foo{transform:translate(80px,40px)}",
+    );
+    minify_test(
+      ".foo { transform: matrix(1, 0, 0, 1, 0, 40)",
+      ".foo{transform:translateY(40px)}",
+    );
+    minify_test(
+      ".foo { transform: matrix(2, 0, 0, 3, 0, 0)",
+      ".foo{transform:scale(2,3)}",
+    );
+    minify_test(".foo { transform: matrix(2, 0, 0, 2, 0, 0)", ".foo{transform:scale(2)}");
+    minify_test(
+      ".foo { transform: matrix(-1, 0, 0, -1, 0, 0)",
+      ".foo{transform:matrix(-1,0,0,-1,0,0)}",
+    );
+    minify_test(
+      ".foo { transform: matrix(-1, 0, 0, 1, 0, 0)",
+      ".foo{transform:matrix(-1,0,0,1,0,0)}",
+    );
+    minify_test(
+      ".foo { transform: matrix(2, 0, 0, 2, 10, 0)",
+      ".foo{transform:matrix(2,0,0,2,10,0)}",
+    );
+    minify_test(
+      ".foo { transform: matrix3d(1, 2, 0, 0, -1, 1, 0, 0, 0, 0, 1, 0, 80, 80, 0, 1)",
+      ".foo{transform:matrix(1,2,-1,1,80,80)}",
+    );
+    minify_test(
+      ".foo { transform: matrix3d(1, 0, 0, 0, 0, 1, 0, 0, 0, 0, 1, 0, 10, 20, 30, 1)",
+      ".foo{transform:translate3d(10px,20px,30px)}",
+    );
+    minify_test(
+      ".foo { transform: matrix3d(2, 0, 0, 0, 0, 2, 0, 0, 0, 0, 3, 0, 0, 0, 0, 1)",
+      ".foo{transform:scale3d(2,2,3)}",
+    );
+    minify_test(
+      ".foo { transform: matrix3d(2, 0, 0, 0, 0, 2, 0, 0, 0, 0, -1, 0, 0, 0, 0, 1)",
+      ".foo{transform:matrix3d(2,0,0,0,0,2,0,0,0,0,-1,0,0,0,0,1)}",
+    );
+    minify_test(
+      ".foo { transform: matrix(1, 0, 0, 1, 10, 0) rotate(45deg)",
+      ".foo{transform:translate(10px)rotate(45deg)}",
+    );
+    test(
+      ".foo { transform: matrix(1, 0, 0, 1, 80, 40) }",
+      indoc! {r#"
+        .foo {
+          transform: matrix(1, 0, 0, 1, 80, 40);
+        }
+      "#},
+    );
+    // TODO: Re-enable with a better solution
+    //       See: https://github.com/parcel-bundler/lightningcss/issues/288
+    // minify_test(
+    //   ".foo{transform:translate(100px,200px) rotate(45deg) skew(10deg) scale(2)}",
+    //   ".foo{transform:matrix(1.41421,1.41421,-1.16485,1.66358,100,200)}",
+    // );
+    // minify_test(
+    //   ".foo{transform:translate(200px,300px) translate(100px,200px) scale(2)}",
+    //   ".foo{transform:matrix(2,0,0,2,300,500)}",
+    // );
+    minify_test(
+      ".foo{transform:translate(100px,200px) rotate(45deg)}",
+      ".foo{transform:translate(100px,200px)rotate(45deg)}",
+    );
+    minify_test(
+      ".foo{transform:rotate3d(1, 1, 1, 45deg) translate3d(100px, 100px, 10px)}",
+      ".foo{transform:rotate3d(1,1,1,45deg)translate3d(100px,100px,10px)}",
+    );
+    // TODO: Re-enable with a better solution
+    //       See: https://github.com/parcel-bundler/lightningcss/issues/288
+    // minify_test(
+    //   ".foo{transform:translate3d(100px, 100px, 10px) skew(10deg) scale3d(2, 3, 4)}",
+    //   ".foo{transform:matrix3d(2,0,0,0,.528981,3,0,0,0,0,4,0,100,100,10,1)}",
+    // );
+    // minify_test(
+    //   ".foo{transform:matrix3d(0.804737854124365, 0.5058793634016805, -0.31061721752604554, 0, -0.31061721752604554, 0.804737854124365, 0.5058793634016805, 0, 0.5058793634016805, -0.31061721752604554, 0.804737854124365, 0, 100, 100, 10, 1)}",
+    //   ".foo{transform:translate3d(100px,100px,10px)rotate3d(1,1,1,45deg)}"
+    // );
+    // minify_test(
+    //   ".foo{transform:matrix3d(1, 0, 0, 0, 0, 0.7071067811865476, 0.7071067811865475, 0, 0, -0.7071067811865475, 0.7071067811865476, 0, 100, 100, 10, 1)}",
+    //   ".foo{transform:translate3d(100px,100px,10px)rotateX(45deg)}"
+    // );
+    // minify_test(
+    //   ".foo{transform:translate3d(100px, 200px, 10px) translate(100px, 100px)}",
+    //   ".foo{transform:translate3d(200px,300px,10px)}",
+    // );
+    // minify_test(
+    //   ".foo{transform:rotate(45deg) rotate(45deg)}",
+    //   ".foo{transform:rotate(90deg)}",
+    // );
+    // minify_test(
+    //   ".foo{transform:matrix(0.7071067811865476, 0.7071067811865475, -0.7071067811865475, 0.7071067811865476, 100, 100)}",
+    //   ".foo{transform:translate(100px,100px)rotate(45deg)}"
+    // );
+    // minify_test(
+    //   ".foo{transform:translateX(2in) translateX(50px)}",
+    //   ".foo{transform:translate(242px)}",
+    // );
+    minify_test(
+      ".foo{transform:translateX(calc(2in + 50px))}",
+      ".foo{transform:translate(242px)}",
+    );
+    minify_test(".foo{transform:translateX(50%)}", ".foo{transform:translate(50%)}");
+    minify_test(
+      ".foo{transform:translateX(calc(50% - 100px + 20px))}",
+      ".foo{transform:translate(calc(50% - 80px))}",
+    );
+    minify_test(
+      ".foo{transform:rotate(calc(10deg + 20deg))}",
+      ".foo{transform:rotate(30deg)}",
+    );
+    minify_test(
+      ".foo{transform:rotate(calc(10deg + 0.349066rad))}",
+      ".foo{transform:rotate(30deg)}",
+    );
+    minify_test(
+      ".foo{transform:rotate(calc(10deg + 1.5turn))}",
+      ".foo{transform:rotate(550deg)}",
+    );
+    minify_test(
+      ".foo{transform:rotate(calc(10deg * 2))}",
+      ".foo{transform:rotate(20deg)}",
+    );
+    minify_test(
+      ".foo{transform:rotate(calc(-10deg * 2))}",
+      ".foo{transform:rotate(-20deg)}",
+    );
+    minify_test(
+      ".foo{transform:rotate(calc(10deg + var(--test)))}",
+      ".foo{transform:rotate(calc(10deg + var(--test)))}",
+    );
+    minify_test(".foo { transform: scale(calc(10% + 20%))", ".foo{transform:scale(.3)}");
+    minify_test(".foo { transform: scale(calc(.1 + .2))", ".foo{transform:scale(.3)}");
+
+    minify_test(
+      ".foo { -webkit-transform: scale(calc(10% + 20%))",
+      ".foo{-webkit-transform:scale(.3)}",
+    );
+
+    minify_test(".foo { translate: 1px 2px 3px }", ".foo{translate:1px 2px 3px}");
+    minify_test(".foo { translate: 1px 0px 0px }", ".foo{translate:1px}");
+    minify_test(".foo { translate: 1px 2px 0px }", ".foo{translate:1px 2px}");
+    minify_test(".foo { translate: 1px 0px 2px }", ".foo{translate:1px 0 2px}");
+    minify_test(".foo { translate: none }", ".foo{translate:0}");
+    minify_test(".foo { rotate: 10deg }", ".foo{rotate:10deg}");
+    minify_test(".foo { rotate: z 10deg }", ".foo{rotate:10deg}");
+    minify_test(".foo { rotate: 0 0 1 10deg }", ".foo{rotate:10deg}");
+    minify_test(".foo { rotate: x 10deg }", ".foo{rotate:x 10deg}");
+    minify_test(".foo { rotate: 1 0 0 10deg }", ".foo{rotate:x 10deg}");
+    minify_test(".foo { rotate: y 10deg }", ".foo{rotate:y 10deg}");
+    minify_test(".foo { rotate: 0 1 0 10deg }", ".foo{rotate:y 10deg}");
+    minify_test(".foo { rotate: 1 1 1 10deg }", ".foo{rotate:1 1 1 10deg}");
+    minify_test(".foo { rotate: 0 0 1 0deg }", ".foo{rotate:none}");
+    minify_test(".foo { rotate: none }", ".foo{rotate:none}");
+    minify_test(".foo { scale: 1 }", ".foo{scale:1}");
+    minify_test(".foo { scale: 1 1 }", ".foo{scale:1}");
+    minify_test(".foo { scale: 1 1 1 }", ".foo{scale:1}");
+    minify_test(".foo { scale: none }", ".foo{scale:1}");
+    minify_test(".foo { scale: 1 0 }", ".foo{scale:1 0}");
+    minify_test(".foo { scale: 1 0 1 }", ".foo{scale:1 0}");
+    minify_test(".foo { scale: 1 0 0 }", ".foo{scale:1 0 0}");
+
+    // TODO: Re-enable with a better solution
+    //       See: https://github.com/parcel-bundler/lightningcss/issues/288
+    // minify_test(".foo { transform: scale(3); scale: 0.5 }", ".foo{transform:scale(1.5)}");
+    minify_test(".foo { scale: 0.5; transform: scale(3); }", ".foo{transform:scale(3)}");
+    minify_test(".foo { transform: scale(3); scale: 0.5 }", ".foo{transform:scale(3);scale:.5}");
+
+    prefix_test(
+      r#"
+      .foo {
+        translate: 10px 20px;
+        rotate: 45deg;
+        scale: 2;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        transform: translate(10px, 20px)rotate(45deg)scale(2);
+      }
+    "#},
+      Browsers {
+        chrome: Some(90 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      r#"
+      .foo {
+        translate: 10px 20px;
+        rotate: 45deg;
+        scale: 2;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        translate: 10px 20px;
+        rotate: 45deg;
+        scale: 2;
+      }
+    "#},
+      Browsers {
+        chrome: Some(104 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      r#"
+      .foo {
+        transform: skewX(10deg);
+        translate: 1px 2px 3px;
+        rotate: x 10deg;
+        scale: 1 2 3;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        transform: translate3d(1px, 2px, 3px)rotate3d(1, 0, 0, 10deg)scale3d(1, 2, 3)skewX(10deg);
+      }
+    "#},
+      Browsers {
+        safari: Some(13 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      r#"
+      .foo {
+        scale: 0.5;
+        transform: rotate(10deg);
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        -webkit-transform: rotate(10deg);
+        transform: rotate(10deg);
+      }
+    "#},
+      Browsers {
+        safari: Some(8 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      r#"
+      .foo {
+        translate: none;
+        scale: none;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        translate: 0;
+        scale: 1;
+      }
+    "#},
+      Browsers {
+        chrome: Some(90 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      r#"
+      .foo {
+        rotate: 0 0 -1 10deg;
+        transform: translateX(5px);
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        transform: translateX(5px);
+      }
+    "#},
+      Browsers {
+        firefox: Some(60 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      r#"
+      .foo {
+        transform: scale(0.5);
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        -webkit-transform: scale(.5);
+        -moz-transform: scale(.5);
+        transform: scale(.5);
+      }
+    "#},
+      Browsers {
+        firefox: Some(6 << 16),
+        safari: Some(6 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      r#"
+      .foo {
+        transform: var(--transform);
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        -webkit-transform: var(--transform);
+        -moz-transform: var(--transform);
+        transform: var(--transform);
+      }
+    "#},
+      Browsers {
+        firefox: Some(6 << 16),
+        safari: Some(6 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    test(
+      r#"
+      .foo {
+        transform: translateX(-50%);
+        transform: translateX(20px);
+      }
+      "#,
+      indoc! {r#"
+      .foo {
+        transform: translateX(20px);
+      }
+      "#},
+    );
+  }
+
+  #[test]
+  fn test_3d_transform_properties() {
+    minify_test(".foo { perspective: none }", ".foo{perspective:none}");
+    minify_test(".foo { perspective: 100px }", ".foo{perspective:100px}");
+    minify_test(".foo { perspective: 0 }", ".foo{perspective:0}");
+    minify_test(".foo { perspective: 10em }", ".foo{perspective:10em}");
+    minify_test(".foo { perspective: -10px }", ".foo{perspective:-10px}"); // invalid
+    minify_test(".foo { perspective: 50% }", ".foo{perspective:50%}"); // invalid
+    minify_test(".foo { perspective-origin: center }", ".foo{perspective-origin:50%}");
+    minify_test(".foo { perspective-origin: left top }", ".foo{perspective-origin:0 0}");
+    minify_test(
+      ".foo { perspective-origin: right 10px bottom 20px }",
+      ".foo{perspective-origin:right 10px bottom 20px}",
+    );
+    minify_test(
+      ".foo { backface-visibility: visible }",
+      ".foo{backface-visibility:visible}",
+    );
+    minify_test(
+      ".foo { backface-visibility: HIDDEN }",
+      ".foo{backface-visibility:hidden}",
+    );
+    minify_test(".foo { backface-visibility: none }", ".foo{backface-visibility:none}"); // invalid
+    minify_test(".foo { transform-style: flat }", ".foo{transform-style:flat}");
+    minify_test(
+      ".foo { transform-style: preserve-3d }",
+      ".foo{transform-style:preserve-3d}",
+    );
+    minify_test(".foo { transform-style: 3d }", ".foo{transform-style:3d}"); // invalid
+    minify_test(".foo { transform-box: content-box }", ".foo{transform-box:content-box}");
+    minify_test(".foo { transform-box: border-box }", ".foo{transform-box:border-box}");
+    minify_test(".foo { transform-box: fill-box }", ".foo{transform-box:fill-box}");
+    minify_test(".foo { transform-box: stroke-box }", ".foo{transform-box:stroke-box}");
+    minify_test(".foo { transform-box: view-box }", ".foo{transform-box:view-box}");
+    minify_test(".foo { transform-box: margin-box }", ".foo{transform-box:margin-box}"); // invalid
+
+    prefix_test(
+      r#"
+      .foo {
+        perspective: 100px;
+        perspective-origin: left top;
+        backface-visibility: hidden;
+        transform-style: preserve-3d;
+        transform-box: fill-box;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        -webkit-perspective: 100px;
+        perspective: 100px;
+        -webkit-perspective-origin: 0 0;
+        perspective-origin: 0 0;
+        -webkit-backface-visibility: hidden;
+        backface-visibility: hidden;
+        -webkit-transform-style: preserve-3d;
+        transform-style: preserve-3d;
+        transform-box: fill-box;
+      }
+    "#},
+      Browsers {
+        safari: Some(8 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      r#"
+      .foo {
+        perspective: 100px;
+        backface-visibility: hidden;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        -moz-perspective: 100px;
+        perspective: 100px;
+        -moz-backface-visibility: hidden;
+        backface-visibility: hidden;
+      }
+    "#},
+      Browsers {
+        firefox: Some(12 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      r#"
+      .foo {
+        -webkit-perspective: 100px;
+        perspective: 100px;
+        -webkit-transform-style: preserve-3d;
+        transform-style: preserve-3d;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        perspective: 100px;
+        transform-style: preserve-3d;
+      }
+    "#},
+      Browsers {
+        chrome: Some(40 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      r#"
+      .foo {
+        -webkit-backface-visibility: hidden;
+        backface-visibility: hidden;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        -webkit-backface-visibility: hidden;
+        backface-visibility: hidden;
+      }
+    "#},
+      Browsers {
+        safari: Some(14 << 16),
+        ..Browsers::default()
+      },
+    );
+  }
+
+  #[test]
+  pub fn test_gradients() {
+    minify_test(
+      ".foo { background: linear-gradient(yellow, blue) }",
+      ".foo{background:linear-gradient(#ff0,#00f)}",
+    );
+    minify_test(
+      ".foo { background: linear-gradient(to bottom, yellow, blue); }",
+      ".foo{background:linear-gradient(#ff0,#00f)}",
+    );
+    minify_test(
+      ".foo { background: linear-gradient(180deg, yellow, blue); }",
+      ".foo{background:linear-gradient(#ff0,#00f)}",
+    );
+    minify_test(
+      ".foo { background: linear-gradient(0.5turn, yellow, blue); }",
+      ".foo{background:linear-gradient(#ff0,#00f)}",
+    );
+    minify_test(
+      ".foo { background: linear-gradient(yellow 10%, blue 20%) }",
+      ".foo{background:linear-gradient(#ff0 10%,#00f 20%)}",
+    );
+    minify_test(
+      ".foo { background: linear-gradient(to top, blue, yellow); }",
+      ".foo{background:linear-gradient(#ff0,#00f)}",
+    );
+    minify_test(
+      ".foo { background: linear-gradient(to top, blue 10%, yellow 20%); }",
+      ".foo{background:linear-gradient(#ff0 80%,#00f 90%)}",
+    );
+    minify_test(
+      ".foo { background: linear-gradient(to top, blue 10px, yellow 20px); }",
+      ".foo{background:linear-gradient(0deg,#00f 10px,#ff0 20px)}",
+    );
+    minify_test(
+      ".foo { background: linear-gradient(135deg, yellow, blue); }",
+      ".foo{background:linear-gradient(135deg,#ff0,#00f)}",
+    );
+    minify_test(
+      ".foo { background: linear-gradient(yellow, blue 20%, #0f0); }",
+      ".foo{background:linear-gradient(#ff0,#00f 20%,#0f0)}",
+    );
+    minify_test(
+      ".foo { background: linear-gradient(to top right, red, white, blue) }",
+      ".foo{background:linear-gradient(to top right,red,#fff,#00f)}",
+    );
+    minify_test(
+      ".foo { background: linear-gradient(yellow, blue calc(10% * 2), #0f0); }",
+      ".foo{background:linear-gradient(#ff0,#00f 20%,#0f0)}",
+    );
+    minify_test(
+      ".foo { background: linear-gradient(yellow, 20%, blue); }",
+      ".foo{background:linear-gradient(#ff0,20%,#00f)}",
+    );
+    minify_test(
+      ".foo { background: linear-gradient(yellow, 50%, blue); }",
+      ".foo{background:linear-gradient(#ff0,#00f)}",
+    );
+    minify_test(
+      ".foo { background: linear-gradient(yellow, 20px, blue); }",
+      ".foo{background:linear-gradient(#ff0,20px,#00f)}",
+    );
+    minify_test(
+      ".foo { background: linear-gradient(yellow, 50px, blue); }",
+      ".foo{background:linear-gradient(#ff0,50px,#00f)}",
+    );
+    minify_test(
+      ".foo { background: linear-gradient(yellow, 50px, blue); }",
+      ".foo{background:linear-gradient(#ff0,50px,#00f)}",
+    );
+    minify_test(
+      ".foo { background: linear-gradient(yellow, red 30% 40%, blue); }",
+      ".foo{background:linear-gradient(#ff0,red 30% 40%,#00f)}",
+    );
+    minify_test(
+      ".foo { background: linear-gradient(yellow, red 30%, red 40%, blue); }",
+      ".foo{background:linear-gradient(#ff0,red 30% 40%,#00f)}",
+    );
+    minify_test(
+      ".foo { background: linear-gradient(yellow 0%, blue 100%); }",
+      ".foo{background:linear-gradient(#ff0,#00f)}",
+    );
+    minify_test(
+      ".foo { background: linear-gradient(yellow 0%, red 50%, blue 100%); }",
+      ".foo{background:linear-gradient(#ff0,red 50%,#00f)}",
+    );
+    minify_test(
+      ".foo { background: linear-gradient(yellow 0% 20%, blue); }",
+      ".foo{background:linear-gradient(#ff0 0% 20%,#00f)}",
+    );
+    minify_test(
+      ".foo { background: linear-gradient(yellow, blue 80% 100%); }",
+      ".foo{background:linear-gradient(#ff0,#00f 80% 100%)}",
+    );
+    minify_test(
+      ".foo { background: linear-gradient(yellow 10%, blue 0%); }",
+      ".foo{background:linear-gradient(#ff0 10%,#00f 0%)}",
+    );
+    minify_test(
+      ".foo { background: radial-gradient(yellow 0%, blue 100%); }",
+      ".foo{background:radial-gradient(#ff0,#00f)}",
+    );
+    minify_test(
+      ".foo { background: conic-gradient(yellow 0%, blue 100%); }",
+      ".foo{background:conic-gradient(#ff0,#00f)}",
+    );
+    minify_test(
+      ".foo { background: linear-gradient(in oklch, yellow, blue); }",
+      ".foo{background:linear-gradient(in oklch,#ff0,#00f)}",
+    );
+    minify_test(
+      ".foo { background: linear-gradient(in oklch shorter hue, yellow, blue); }",
+      ".foo{background:linear-gradient(in oklch,#ff0,#00f)}",
+    );
+    minify_test(
+      ".foo { background: linear-gradient(to right in oklch longer hue, yellow, blue); }",
+      ".foo{background:linear-gradient(90deg in oklch longer hue,#ff0,#00f)}",
+    );
+    minify_test(
+      ".foo { background: linear-gradient(in hsl to right, yellow, blue); }",
+      ".foo{background:linear-gradient(90deg in hsl,#ff0,#00f)}",
+    );
+    minify_test(
+      ".foo { background: linear-gradient(to top in oklab, yellow, blue); }",
+      ".foo{background:linear-gradient(in oklab,#00f,#ff0)}",
+    );
+    minify_test(
+      ".foo { background: linear-gradient(to top in hsl increasing hue, yellow, blue); }",
+      ".foo{background:linear-gradient(0deg in hsl increasing hue,#ff0,#00f)}",
+    );
+    minify_test(
+      ".foo { background: radial-gradient(in srgb-linear, yellow, blue); }",
+      ".foo{background:radial-gradient(in srgb-linear,#ff0,#00f)}",
+    );
+    minify_test(
+      ".foo { background: radial-gradient(circle at top in lab, yellow, blue); }",
+      ".foo{background:radial-gradient(circle at top in lab,#ff0,#00f)}",
+    );
+    minify_test(
+      ".foo { background: conic-gradient(from 90deg in hsl longer hue, yellow, blue); }",
+      ".foo{background:conic-gradient(from 90deg in hsl longer hue,#ff0,#00f)}",
+    );
+    minify_test(
+      ".foo { background: repeating-conic-gradient(in oklch at top left, yellow, blue 50%); }",
+      ".foo{background:repeating-conic-gradient(at 0 0 in oklch,#ff0,#00f 50%)}",
+    );
+    minify_test(
+      ".foo { background: linear-gradient(in oklch in srgb, yellow, blue); }",
+      ".foo{background:linear-gradient(in oklch in srgb,yellow,blue)}",
+    ); // invalid
+    minify_test(
+      ".foo { background: -webkit-linear-gradient(in oklch, yellow, blue); }",
+      ".foo{background:-webkit-linear-gradient(in oklch,yellow,blue)}",
+    ); // invalid
+    prefix_test(
+      ".foo { background: linear-gradient(in srgb-linear, black, white); }",
+      indoc! { r#"
+        .foo {
+          background: linear-gradient(#000, #898989 25%, #bcbcbc 50%, #e1e1e1 75%, #fff);
+        }
+      "#},
+      Browsers {
+        chrome: Some(100 << 16),
+        ..Browsers::default()
+      },
+    );
+    prefix_test(
+      ".foo { background: linear-gradient(to right in srgb, black, white); }",
+      indoc! { r#"
+        .foo {
+          background: linear-gradient(to right, #000, #fff);
+        }
+      "#},
+      Browsers {
+        chrome: Some(100 << 16),
+        ..Browsers::default()
+      },
+    );
+    prefix_test(
+      ".foo { background: linear-gradient(in oklch, black 10px, white); }",
+      indoc! { r#"
+        .foo {
+          background: linear-gradient(in oklch, #000 10px, #fff);
+        }
+      "#},
+      Browsers {
+        chrome: Some(100 << 16),
+        ..Browsers::default()
+      },
+    );
+    prefix_test(
+      ".foo { background: linear-gradient(in oklch, black, white); }",
+      indoc! { r#"
+        .foo {
+          background: -webkit-gradient(linear, 0 0, 0 100%, from(#000), color-stop(.25, #222), color-stop(.5, #636363), color-stop(.75, #aeaeae), to(#fff));
+          background: -webkit-linear-gradient(#000, #222 25%, #636363 50%, #aeaeae 75%, #fff);
+          background: linear-gradient(#000, #222 25%, #636363 50%, #aeaeae 75%, #fff);
+        }
+      "#},
+      Browsers {
+        safari: Some(6 << 16),
+        ..Browsers::default()
+      },
+    );
+    prefix_test(
+      ".foo { background: linear-gradient(in oklch, black, white); }",
+      indoc! { r#"
+        .foo {
+          background: linear-gradient(in oklch, #000, #fff);
+        }
+      "#},
+      Browsers {
+        chrome: Some(111 << 16),
+        ..Browsers::default()
+      },
+    );
+    minify_test(
+      ".foo { background: linear-gradient(0, yellow, blue); }",
+      ".foo{background:linear-gradient(#00f,#ff0)}",
+    );
+    minify_test(
+      ".foo { background: -webkit-linear-gradient(yellow, blue) }",
+      ".foo{background:-webkit-linear-gradient(#ff0,#00f)}",
+    );
+    minify_test(
+      ".foo { background: -webkit-linear-gradient(bottom, yellow, blue); }",
+      ".foo{background:-webkit-linear-gradient(#ff0,#00f)}",
+    );
+    minify_test(
+      ".foo { background: -webkit-linear-gradient(top right, red, white, blue) }",
+      ".foo{background:-webkit-linear-gradient(top right,red,#fff,#00f)}",
+    );
+    minify_test(
+      ".foo { background: -moz-linear-gradient(yellow, blue) }",
+      ".foo{background:-moz-linear-gradient(#ff0,#00f)}",
+    );
+    minify_test(
+      ".foo { background: -moz-linear-gradient(bottom, yellow, blue); }",
+      ".foo{background:-moz-linear-gradient(#ff0,#00f)}",
+    );
+    minify_test(
+      ".foo { background: -moz-linear-gradient(top right, red, white, blue) }",
+      ".foo{background:-moz-linear-gradient(top right,red,#fff,#00f)}",
+    );
+    minify_test(
+      ".foo { background: -o-linear-gradient(yellow, blue) }",
+      ".foo{background:-o-linear-gradient(#ff0,#00f)}",
+    );
+    minify_test(
+      ".foo { background: -o-linear-gradient(bottom, yellow, blue); }",
+      ".foo{background:-o-linear-gradient(#ff0,#00f)}",
+    );
+    minify_test(
+      ".foo { background: -o-linear-gradient(top right, red, white, blue) }",
+      ".foo{background:-o-linear-gradient(top right,red,#fff,#00f)}",
+    );
+    minify_test(
+      ".foo { background: -webkit-gradient(linear, left top, left bottom, from(blue), to(yellow)) }",
+      ".foo{background:-webkit-gradient(linear,0 0,0 100%,from(#00f),to(#ff0))}",
+    );
+    minify_test(
+      ".foo { background: -webkit-gradient(linear, left top, left bottom, from(blue), color-stop(50%, red), to(yellow)) }",
+      ".foo{background:-webkit-gradient(linear,0 0,0 100%,from(#00f),color-stop(.5,red),to(#ff0))}"
+    );
+    minify_test(
+      ".foo { background: -webkit-gradient(linear, left top, left bottom, color-stop(0%, blue), color-stop(50%, red), color-stop(100%, yellow)) }",
+      ".foo{background:-webkit-gradient(linear,0 0,0 100%,from(#00f),color-stop(.5,red),to(#ff0))}"
+    );
+    minify_test(
+      ".foo { background: repeating-linear-gradient(yellow 10px, blue 50px) }",
+      ".foo{background:repeating-linear-gradient(#ff0 10px,#00f 50px)}",
+    );
+    minify_test(
+      ".foo { background: -webkit-repeating-linear-gradient(yellow 10px, blue 50px) }",
+      ".foo{background:-webkit-repeating-linear-gradient(#ff0 10px,#00f 50px)}",
+    );
+    minify_test(
+      ".foo { background: -moz-repeating-linear-gradient(yellow 10px, blue 50px) }",
+      ".foo{background:-moz-repeating-linear-gradient(#ff0 10px,#00f 50px)}",
+    );
+    minify_test(
+      ".foo { background: -o-repeating-linear-gradient(yellow 10px, blue 50px) }",
+      ".foo{background:-o-repeating-linear-gradient(#ff0 10px,#00f 50px)}",
+    );
+    minify_test(
+      ".foo { background: radial-gradient(yellow, blue) }",
+      ".foo{background:radial-gradient(#ff0,#00f)}",
+    );
+    minify_test(
+      ".foo { background: radial-gradient(at top left, yellow, blue) }",
+      ".foo{background:radial-gradient(at 0 0,#ff0,#00f)}",
+    );
+    minify_test(
+      ".foo { background: radial-gradient(5em circle at top left, yellow, blue) }",
+      ".foo{background:radial-gradient(5em at 0 0,#ff0,#00f)}",
+    );
+    minify_test(
+      ".foo { background: radial-gradient(circle at 100%, #333, #333 50%, #eee 75%, #333 75%) }",
+      ".foo{background:radial-gradient(circle at 100%,#333,#333 50%,#eee 75%,#333 75%)}",
+    );
+    minify_test(
+      ".foo { background: radial-gradient(farthest-corner circle at 100% 50%, #333, #333 50%, #eee 75%, #333 75%) }",
+      ".foo{background:radial-gradient(circle at 100%,#333,#333 50%,#eee 75%,#333 75%)}"
+    );
+    minify_test(
+      ".foo { background: radial-gradient(farthest-corner circle at 50% 50%, #333, #333 50%, #eee 75%, #333 75%) }",
+      ".foo{background:radial-gradient(circle,#333,#333 50%,#eee 75%,#333 75%)}"
+    );
+    minify_test(
+      ".foo { background: radial-gradient(ellipse at top, #e66465, transparent) }",
+      ".foo{background:radial-gradient(at top,#e66465,#0000)}",
+    );
+    minify_test(
+      ".foo { background: radial-gradient(20px, yellow, blue) }",
+      ".foo{background:radial-gradient(20px,#ff0,#00f)}",
+    );
+    minify_test(
+      ".foo { background: radial-gradient(circle 20px, yellow, blue) }",
+      ".foo{background:radial-gradient(20px,#ff0,#00f)}",
+    );
+    minify_test(
+      ".foo { background: radial-gradient(20px 40px, yellow, blue) }",
+      ".foo{background:radial-gradient(20px 40px,#ff0,#00f)}",
+    );
+    minify_test(
+      ".foo { background: radial-gradient(ellipse 20px 40px, yellow, blue) }",
+      ".foo{background:radial-gradient(20px 40px,#ff0,#00f)}",
+    );
+    minify_test(
+      ".foo { background: radial-gradient(ellipse calc(20px + 10px) 40px, yellow, blue) }",
+      ".foo{background:radial-gradient(30px 40px,#ff0,#00f)}",
+    );
+    minify_test(
+      ".foo { background: radial-gradient(circle farthest-side, yellow, blue) }",
+      ".foo{background:radial-gradient(circle farthest-side,#ff0,#00f)}",
+    );
+    minify_test(
+      ".foo { background: radial-gradient(farthest-side circle, yellow, blue) }",
+      ".foo{background:radial-gradient(circle farthest-side,#ff0,#00f)}",
+    );
+    minify_test(
+      ".foo { background: radial-gradient(ellipse farthest-side, yellow, blue) }",
+      ".foo{background:radial-gradient(farthest-side,#ff0,#00f)}",
+    );
+    minify_test(
+      ".foo { background: radial-gradient(farthest-side ellipse, yellow, blue) }",
+      ".foo{background:radial-gradient(farthest-side,#ff0,#00f)}",
+    );
+    minify_test(
+      ".foo { background: -webkit-radial-gradient(yellow, blue) }",
+      ".foo{background:-webkit-radial-gradient(#ff0,#00f)}",
+    );
+    minify_test(
+      ".foo { background: -moz-radial-gradient(yellow, blue) }",
+      ".foo{background:-moz-radial-gradient(#ff0,#00f)}",
+    );
+    minify_test(
+      ".foo { background: -o-radial-gradient(yellow, blue) }",
+      ".foo{background:-o-radial-gradient(#ff0,#00f)}",
+    );
+    minify_test(
+      ".foo { background: repeating-radial-gradient(circle 20px, yellow, blue) }",
+      ".foo{background:repeating-radial-gradient(20px,#ff0,#00f)}",
+    );
+    minify_test(
+      ".foo { background: -webkit-repeating-radial-gradient(circle 20px, yellow, blue) }",
+      ".foo{background:-webkit-repeating-radial-gradient(20px,#ff0,#00f)}",
+    );
+    minify_test(
+      ".foo { background: -moz-repeating-radial-gradient(circle 20px, yellow, blue) }",
+      ".foo{background:-moz-repeating-radial-gradient(20px,#ff0,#00f)}",
+    );
+    minify_test(
+      ".foo { background: -o-repeating-radial-gradient(circle 20px, yellow, blue) }",
+      ".foo{background:-o-repeating-radial-gradient(20px,#ff0,#00f)}",
+    );
+    minify_test(
+      ".foo { background: -webkit-gradient(radial, center center, 0, center center, 100, from(blue), to(yellow)) }",
+      ".foo{background:-webkit-gradient(radial,50% 50%,0,50% 50%,100,from(#00f),to(#ff0))}"
+    );
+    minify_test(
+      ".foo { background: conic-gradient(#f06, gold) }",
+      ".foo{background:conic-gradient(#f06,gold)}",
+    );
+    minify_test(
+      ".foo { background: conic-gradient(at 50% 50%, #f06, gold) }",
+      ".foo{background:conic-gradient(#f06,gold)}",
+    );
+    minify_test(
+      ".foo { background: conic-gradient(from 0deg, #f06, gold) }",
+      ".foo{background:conic-gradient(#f06,gold)}",
+    );
+    minify_test(
+      ".foo { background: conic-gradient(from 0, #f06, gold) }",
+      ".foo{background:conic-gradient(#f06,gold)}",
+    );
+    minify_test(
+      ".foo { background: conic-gradient(from 0deg at center, #f06, gold) }",
+      ".foo{background:conic-gradient(#f06,gold)}",
+    );
+    minify_test(
+      ".foo { background: conic-gradient(white -50%, black 150%) }",
+      ".foo{background:conic-gradient(#fff -50%,#000 150%)}",
+    );
+    minify_test(
+      ".foo { background: conic-gradient(white -180deg, black 540deg) }",
+      ".foo{background:conic-gradient(#fff -180deg,#000 540deg)}",
+    );
+    minify_test(
+      ".foo { background: conic-gradient(from 45deg, white, black, white) }",
+      ".foo{background:conic-gradient(from 45deg,#fff,#000,#fff)}",
+    );
+    minify_test(
+      ".foo { background: repeating-conic-gradient(from 45deg, white, black, white) }",
+      ".foo{background:repeating-conic-gradient(from 45deg,#fff,#000,#fff)}",
+    );
+    minify_test(
+      ".foo { background: repeating-conic-gradient(black 0deg 25%, white 0deg 50%) }",
+      ".foo{background:repeating-conic-gradient(#000 0deg 25%,#fff 0deg 50%)}",
+    );
+
+    test(
+      r#"
+        .foo {
+          background: -webkit-gradient(linear, left top, left bottom, from(red), to(blue));
+          background: -webkit-linear-gradient(red, blue);
+          background: -moz-linear-gradient(red, blue);
+          background: -o-linear-gradient(red, blue);
+          background: linear-gradient(red, blue);
+        }
+      "#,
+      indoc! {r#"
+        .foo {
+          background: -webkit-gradient(linear, left top, left bottom, from(red), to(#00f));
+          background: -webkit-linear-gradient(red, #00f);
+          background: -moz-linear-gradient(red, #00f);
+          background: -o-linear-gradient(red, #00f);
+          background: linear-gradient(red, #00f);
+        }
+      "#},
+    );
+
+    prefix_test(
+      r#"
+      .foo {
+        background: -webkit-gradient(linear, left top, left bottom, from(red), to(blue));
+        background: -webkit-linear-gradient(red, blue);
+        background: -moz-linear-gradient(red, blue);
+        background: -o-linear-gradient(red, blue);
+        background: linear-gradient(red, blue);
+      }
+      "#,
+      indoc! {r#"
+      .foo {
+        background: linear-gradient(red, #00f);
+      }
+      "#},
+      Browsers {
+        chrome: Some(95 << 16),
+        ..Browsers::default()
+      },
+    );
+    prefix_test(
+      r#"
+      .foo {
+        background: -webkit-gradient(linear, left top, left bottom, from(red), to(blue));
+        background: -webkit-linear-gradient(red, blue);
+        background: -moz-linear-gradient(red, blue);
+        background: -o-linear-gradient(red, blue);
+      }
+      "#,
+      indoc! {r#"
+      .foo {
+        background: -webkit-gradient(linear, left top, left bottom, from(red), to(#00f));
+        background: -webkit-linear-gradient(red, #00f);
+        background: -moz-linear-gradient(red, #00f);
+        background: -o-linear-gradient(red, #00f);
+      }
+      "#},
+      Browsers {
+        chrome: Some(95 << 16),
+        ..Browsers::default()
+      },
+    );
+    prefix_test(
+      r#"
+      .foo {
+        background-image: linear-gradient(red, blue);
+      }
+      "#,
+      indoc! {r#"
+      .foo {
+        background-image: -webkit-gradient(linear, 0 0, 0 100%, from(red), to(#00f));
+        background-image: -webkit-linear-gradient(red, #00f);
+        background-image: linear-gradient(red, #00f);
+      }
+      "#},
+      Browsers {
+        chrome: Some(8 << 16),
+        ..Browsers::default()
+      },
+    );
+    prefix_test(
+      r#"
+      .foo {
+        background-image: linear-gradient(to right, red, blue);
+      }
+      "#,
+      indoc! {r#"
+      .foo {
+        background-image: -webkit-gradient(linear, 0 0, 100% 0, from(red), to(#00f));
+        background-image: -webkit-linear-gradient(right, red, #00f);
+        background-image: linear-gradient(to right, red, #00f);
+      }
+      "#},
+      Browsers {
+        chrome: Some(8 << 16),
+        ..Browsers::default()
+      },
+    );
+    prefix_test(
+      r#"
+      .foo {
+        background-image: linear-gradient(red, yellow, blue);
+      }
+      "#,
+      indoc! {r#"
+      .foo {
+        background-image: -webkit-gradient(linear, 0 0, 0 100%, from(red), color-stop(.5, #ff0), to(#00f));
+        background-image: -webkit-linear-gradient(red, #ff0, #00f);
+        background-image: linear-gradient(red, #ff0, #00f);
+      }
+      "#},
+      Browsers {
+        chrome: Some(8 << 16),
+        ..Browsers::default()
+      },
+    );
+    prefix_test(
+      r#"
+      .foo {
+        background-image: linear-gradient(red, yellow 20%, lime, blue);
+      }
+      "#,
+      indoc! {r#"
+      .foo {
+        background-image: -webkit-gradient(linear, 0 0, 0 100%, from(red), color-stop(.2, #ff0), color-stop(.6, #0f0), to(#00f));
+        background-image: -webkit-linear-gradient(red, #ff0 20%, #0f0, #00f);
+        background-image: linear-gradient(red, #ff0 20%, #0f0, #00f);
+      }
+      "#},
+      Browsers {
+        chrome: Some(8 << 16),
+        ..Browsers::default()
+      },
+    );
+    prefix_test(
+      r#"
+      .foo {
+        background-image: linear-gradient(to top, red, blue);
+      }
+      "#,
+      indoc! {r#"
+      .foo {
+        background-image: -webkit-gradient(linear, 0 100%, 0 0, from(red), to(#00f));
+        background-image: -webkit-linear-gradient(top, red, #00f);
+        background-image: linear-gradient(to top, red, #00f);
+      }
+      "#},
+      Browsers {
+        chrome: Some(8 << 16),
+        ..Browsers::default()
+      },
+    );
+    prefix_test(
+      r#"
+      .foo {
+        background-image: linear-gradient(to left, red, blue);
+      }
+      "#,
+      indoc! {r#"
+      .foo {
+        background-image: -webkit-gradient(linear, 100% 0, 0 0, from(red), to(#00f));
+        background-image: -webkit-linear-gradient(left, red, #00f);
+        background-image: linear-gradient(to left, red, #00f);
+      }
+      "#},
+      Browsers {
+        chrome: Some(8 << 16),
+        ..Browsers::default()
+      },
+    );
+    prefix_test(
+      r#"
+      .foo {
+        background-image: linear-gradient(to left bottom, red, blue);
+      }
+      "#,
+      indoc! {r#"
+      .foo {
+        background-image: -webkit-gradient(linear, 100% 0, 0 100%, from(red), to(#00f));
+        background-image: -webkit-linear-gradient(bottom left, red, #00f);
+        background-image: linear-gradient(to bottom left, red, #00f);
+      }
+      "#},
+      Browsers {
+        chrome: Some(8 << 16),
+        ..Browsers::default()
+      },
+    );
+    prefix_test(
+      r#"
+      .foo {
+        background-image: linear-gradient(to top right, red, blue);
+      }
+      "#,
+      indoc! {r#"
+      .foo {
+        background-image: -webkit-gradient(linear, 0 100%, 100% 0, from(red), to(#00f));
+        background-image: -webkit-linear-gradient(top right, red, #00f);
+        background-image: linear-gradient(to top right, red, #00f);
+      }
+      "#},
+      Browsers {
+        chrome: Some(8 << 16),
+        ..Browsers::default()
+      },
+    );
+    prefix_test(
+      r#"
+      .foo {
+        background-image: linear-gradient(90deg, red, blue);
+      }
+      "#,
+      indoc! {r#"
+      .foo {
+        background-image: -webkit-gradient(linear, 0 0, 100% 0, from(red), to(#00f));
+        background-image: -webkit-linear-gradient(90deg, red, #00f);
+        background-image: linear-gradient(90deg, red, #00f);
+      }
+      "#},
+      Browsers {
+        chrome: Some(8 << 16),
+        ..Browsers::default()
+      },
+    );
+    prefix_test(
+      r#"
+      .foo {
+        background-image: linear-gradient(45deg, red, blue);
+      }
+      "#,
+      indoc! {r#"
+      .foo {
+        background-image: -webkit-linear-gradient(45deg, red, #00f);
+        background-image: linear-gradient(45deg, red, #00f);
+      }
+      "#},
+      Browsers {
+        chrome: Some(8 << 16),
+        ..Browsers::default()
+      },
+    );
+    prefix_test(
+      r#"
+      .foo {
+        background-image: linear-gradient(red, blue);
+      }
+      "#,
+      indoc! {r#"
+      .foo {
+        background-image: -webkit-linear-gradient(red, #00f);
+        background-image: linear-gradient(red, #00f);
+      }
+      "#},
+      Browsers {
+        chrome: Some(10 << 16),
+        ..Browsers::default()
+      },
+    );
+    prefix_test(
+      r#"
+      .foo {
+        background-image: radial-gradient(20px, red, blue);
+      }
+      "#,
+      indoc! {r#"
+      .foo {
+        background-image: -webkit-gradient(radial, center center, 0, center center, 20, from(red), to(#00f));
+        background-image: -webkit-radial-gradient(20px, red, #00f);
+        background-image: radial-gradient(20px, red, #00f);
+      }
+      "#},
+      Browsers {
+        chrome: Some(8 << 16),
+        ..Browsers::default()
+      },
+    );
+    prefix_test(
+      r#"
+      .foo {
+        background-image: radial-gradient(20px at top left, red, blue);
+      }
+      "#,
+      indoc! {r#"
+      .foo {
+        background-image: -webkit-gradient(radial, left top, 0, left top, 20, from(red), to(#00f));
+        background-image: -webkit-radial-gradient(20px at 0 0, red, #00f);
+        background-image: radial-gradient(20px at 0 0, red, #00f);
+      }
+      "#},
+      Browsers {
+        chrome: Some(8 << 16),
+        ..Browsers::default()
+      },
+    );
+    prefix_test(
+      r#"
+      .foo {
+        background-image: radial-gradient(red, blue);
+      }
+      "#,
+      indoc! {r#"
+      .foo {
+        background-image: -webkit-radial-gradient(red, #00f);
+        background-image: radial-gradient(red, #00f);
+      }
+      "#},
+      Browsers {
+        chrome: Some(8 << 16),
+        ..Browsers::default()
+      },
+    );
+    prefix_test(
+      r#"
+      .foo {
+        background-image: -webkit-gradient(radial, left top, 0, left top, 20, from(red), to(#00f));
+        background-image: -webkit-radial-gradient(20px at 0% 0%, red, #00f);
+        background-image: radial-gradient(20px at 0% 0%, red, #00f);
+      }
+      "#,
+      indoc! {r#"
+      .foo {
+        background-image: radial-gradient(20px at 0 0, red, #00f);
+      }
+      "#},
+      Browsers {
+        chrome: Some(30 << 16),
+        ..Browsers::default()
+      },
+    );
+    prefix_test(
+      r#"
+      .foo {
+        background: -webkit-gradient(radial, left top, 0, left top, 20, from(red), to(#00f));
+        background: -webkit-radial-gradient(20px at 0% 0%, red, #00f);
+        background: radial-gradient(20px at 0% 0%, red, #00f);
+      }
+      "#,
+      indoc! {r#"
+      .foo {
+        background: radial-gradient(20px at 0 0, red, #00f);
+      }
+      "#},
+      Browsers {
+        chrome: Some(30 << 16),
+        ..Browsers::default()
+      },
+    );
+    prefix_test(
+      r#"
+      .foo {
+        background: radial-gradient(red, blue);
+      }
+      "#,
+      indoc! {r#"
+      .foo {
+        background: -webkit-radial-gradient(red, #00f);
+        background: radial-gradient(red, #00f);
+      }
+      "#},
+      Browsers {
+        chrome: Some(8 << 16),
+        ..Browsers::default()
+      },
+    );
+    prefix_test(
+      r#"
+      .foo {
+        background: radial-gradient(red, blue), linear-gradient(yellow, red), url(bg.jpg);
+      }
+      "#,
+      indoc! {r#"
+      .foo {
+        background: -webkit-gradient(linear, 0 0, 0 100%, from(#ff0), to(red)), url("bg.jpg");
+        background: -webkit-radial-gradient(red, #00f), -webkit-linear-gradient(#ff0, red), url("bg.jpg");
+        background: -moz-radial-gradient(red, #00f), -moz-linear-gradient(#ff0, red), url("bg.jpg");
+        background: -o-radial-gradient(red, #00f), -o-linear-gradient(#ff0, red), url("bg.jpg");
+        background: radial-gradient(red, #00f), linear-gradient(#ff0, red), url("bg.jpg");
+      }
+      "#},
+      Browsers {
+        chrome: Some(8 << 16),
+        firefox: Some(4 << 16),
+        opera: Some(11 << 16 | 5 << 8),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      r#"
+      .foo {
+        background: linear-gradient(yellow, red 30% 40%, blue);
+      }
+      "#,
+      indoc! {r#"
+      .foo {
+        background: linear-gradient(#ff0, red 30%, red 40%, #00f);
+      }
+      "#},
+      Browsers {
+        chrome: Some(70 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      r#"
+      .foo {
+        background: linear-gradient(yellow, red 30% 40%, blue);
+      }
+      "#,
+      indoc! {r#"
+      .foo {
+        background: linear-gradient(#ff0, red 30% 40%, #00f);
+      }
+      "#},
+      Browsers {
+        chrome: Some(71 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      ".foo { background: linear-gradient(lch(56.208% 136.76 46.312), lch(51% 135.366 301.364)) }",
+      indoc! { r#"
+        .foo {
+          background: linear-gradient(#ff0f0e, #7773ff);
+          background: linear-gradient(lch(56.208% 136.76 46.312), lch(51% 135.366 301.364));
+        }
+      "#},
+      Browsers {
+        chrome: Some(90 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      ".foo { background: linear-gradient(lch(56.208% 136.76 46.312), lch(51% 135.366 301.364)) }",
+      indoc! { r#"
+        .foo {
+          background: linear-gradient(#ff0f0e, #7773ff);
+          background: linear-gradient(color(display-p3 1 .0000153435 -.00000303562), color(display-p3 .440289 .28452 1.23485));
+          background: linear-gradient(lch(56.208% 136.76 46.312), lch(51% 135.366 301.364));
+        }
+      "#},
+      Browsers {
+        chrome: Some(90 << 16),
+        safari: Some(14 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      ".foo { background: linear-gradient(lch(56.208% 136.76 46.312), lch(51% 135.366 301.364)) }",
+      indoc! { r#"
+        .foo {
+          background: -webkit-linear-gradient(#ff0f0e, #7773ff);
+          background: linear-gradient(#ff0f0e, #7773ff);
+          background: linear-gradient(lch(56.208% 136.76 46.312), lch(51% 135.366 301.364));
+        }
+      "#},
+      Browsers {
+        chrome: Some(20 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      ".foo { background: linear-gradient(lch(56.208% 136.76 46.312), lch(51% 135.366 301.364)) }",
+      indoc! { r#"
+        .foo {
+          background: -webkit-gradient(linear, 0 0, 0 100%, from(#ff0f0e), to(#7773ff));
+          background: -webkit-linear-gradient(#ff0f0e, #7773ff);
+          background: linear-gradient(#ff0f0e, #7773ff);
+          background: linear-gradient(lch(56.208% 136.76 46.312), lch(51% 135.366 301.364));
+        }
+      "#},
+      Browsers {
+        chrome: Some(8 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      ".foo { background: linear-gradient(lch(56.208% 136.76 46.312), lch(51% 135.366 301.364)) }",
+      indoc! { r#"
+        .foo {
+          background: linear-gradient(lch(56.208% 136.76 46.312), lch(51% 135.366 301.364));
+        }
+      "#},
+      Browsers {
+        safari: Some(15 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      ".foo { background-image: linear-gradient(oklab(59.686% 0.1009 0.1192), oklab(54.0% -0.10 -0.02)); }",
+      indoc! { r#"
+        .foo {
+          background-image: linear-gradient(lab(52.2319% 40.1449 59.9171), lab(47.7776% -34.2947 -7.65904));
+        }
+      "#},
+      Browsers {
+        safari: Some(15 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      ".foo { background-image: linear-gradient(lch(56.208% 136.76 46.312), lch(51% 135.366 301.364)) }",
+      indoc! { r#"
+        .foo {
+          background-image: linear-gradient(#ff0f0e, #7773ff);
+          background-image: linear-gradient(lch(56.208% 136.76 46.312), lch(51% 135.366 301.364));
+        }
+      "#},
+      Browsers {
+        chrome: Some(90 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      ".foo { background-image: linear-gradient(lch(56.208% 136.76 46.312), lch(51% 135.366 301.364)) }",
+      indoc! { r#"
+        .foo {
+          background-image: linear-gradient(#ff0f0e, #7773ff);
+          background-image: linear-gradient(color(display-p3 1 .0000153435 -.00000303562), color(display-p3 .440289 .28452 1.23485));
+          background-image: linear-gradient(lch(56.208% 136.76 46.312), lch(51% 135.366 301.364));
+        }
+      "#},
+      Browsers {
+        chrome: Some(90 << 16),
+        safari: Some(14 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      ".foo { background-image: linear-gradient(lch(56.208% 136.76 46.312), lch(51% 135.366 301.364)) }",
+      indoc! { r#"
+        .foo {
+          background-image: -webkit-linear-gradient(#ff0f0e, #7773ff);
+          background-image: linear-gradient(#ff0f0e, #7773ff);
+          background-image: linear-gradient(lch(56.208% 136.76 46.312), lch(51% 135.366 301.364));
+        }
+      "#},
+      Browsers {
+        chrome: Some(20 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      ".foo { background-image: linear-gradient(lch(56.208% 136.76 46.312), lch(51% 135.366 301.364)) }",
+      indoc! { r#"
+        .foo {
+          background-image: -webkit-gradient(linear, 0 0, 0 100%, from(#ff0f0e), to(#7773ff));
+          background-image: -webkit-linear-gradient(#ff0f0e, #7773ff);
+          background-image: linear-gradient(#ff0f0e, #7773ff);
+          background-image: linear-gradient(lch(56.208% 136.76 46.312), lch(51% 135.366 301.364));
+        }
+      "#},
+      Browsers {
+        chrome: Some(8 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      ".foo { background-image: linear-gradient(lch(56.208% 136.76 46.312), lch(51% 135.366 301.364)) }",
+      indoc! { r#"
+        .foo {
+          background-image: linear-gradient(lch(56.208% 136.76 46.312), lch(51% 135.366 301.364));
+        }
+      "#},
+      Browsers {
+        safari: Some(15 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      ".foo { background-image: linear-gradient(oklab(59.686% 0.1009 0.1192), oklab(54.0% -0.10 -0.02)); }",
+      indoc! { r#"
+        .foo {
+          background-image: linear-gradient(lab(52.2319% 40.1449 59.9171), lab(47.7776% -34.2947 -7.65904));
+        }
+      "#},
+      Browsers {
+        safari: Some(15 << 16),
+        ..Browsers::default()
+      },
+    );
+  }
+
+  #[test]
+  fn test_font_face() {
     minify_test(
-      ".foo { transform: translate3d(10%, 20%, 4px)",
-      ".foo{transform:translate3d(10%,20%,4px)}",
+      r#"@font-face {
+      src: url("test.woff");
+      font-family: "Helvetica";
+      font-weight: bold;
+      font-style: italic;
+    }"#,
+      "@font-face{src:url(test.woff);font-family:Helvetica;font-weight:700;font-style:italic}",
     );
+    minify_test("@font-face {src: url(test.woff);}", "@font-face{src:url(test.woff)}");
+    minify_test("@font-face {src: local(\"Test\");}", "@font-face{src:local(Test)}");
     minify_test(
-      ".foo { transform: translate3d(2px, 0px, 0px)",
-      ".foo{transform:translate(2px)}",
+      "@font-face {src: local(\"Foo Bar\");}",
+      "@font-face{src:local(Foo Bar)}",
     );
+    minify_test("@font-face {src: local(Test);}", "@font-face{src:local(Test)}");
+    minify_test("@font-face {src: local(Foo Bar);}", "@font-face{src:local(Foo Bar)}");
+
     minify_test(
-      ".foo { transform: translate3d(0px, 2px, 0px)",
-      ".foo{transform:translateY(2px)}",
+      "@font-face {src: url(\"test.woff\") format(woff);}",
+      "@font-face{src:url(test.woff)format(\"woff\")}",
+    );
+    minify_test(
+      "@font-face {src: url(\"test.ttc\") format(collection), url(test.ttf) format(truetype);}",
+      "@font-face{src:url(test.ttc)format(\"collection\"),url(test.ttf)format(\"truetype\")}",
+    );
+    minify_test(
+      "@font-face {src: url(\"test.otf\") format(opentype) tech(features-aat);}",
+      "@font-face{src:url(test.otf)format(\"opentype\")tech(features-aat)}",
+    );
+    minify_test(
+      "@font-face {src: url(\"test.woff\") format(woff) tech(color-colrv1);}",
+      "@font-face{src:url(test.woff)format(\"woff\")tech(color-colrv1)}",
+    );
+    minify_test(
+      "@font-face {src: url(\"test.woff2\") format(woff2) tech(variations);}",
+      "@font-face{src:url(test.woff2)format(\"woff2\")tech(variations)}",
+    );
+    minify_test(
+      "@font-face {src: url(\"test.woff\") format(woff) tech(palettes);}",
+      "@font-face{src:url(test.woff)format(\"woff\")tech(palettes)}",
+    );
+    // multiple tech
+    minify_test(
+      "@font-face {src: url(\"test.woff\") format(woff) tech(features-opentype, color-sbix);}",
+      "@font-face{src:url(test.woff)format(\"woff\")tech(features-opentype,color-sbix)}",
+    );
+    minify_test(
+      "@font-face {src: url(\"test.woff\")   format(woff)    tech(incremental, color-svg, features-graphite, features-aat);}",
+      "@font-face{src:url(test.woff)format(\"woff\")tech(incremental,color-svg,features-graphite,features-aat)}",
+    );
+    // format() function must precede tech() if both are present
+    minify_test(
+      "@font-face {src: url(\"foo.ttf\") format(opentype) tech(color-colrv1);}",
+      "@font-face{src:url(foo.ttf)format(\"opentype\")tech(color-colrv1)}",
+    );
+    // only have tech is valid
+    minify_test(
+      "@font-face {src: url(\"foo.ttf\") tech(color-SVG);}",
+      "@font-face{src:url(foo.ttf)tech(color-svg)}",
+    );
+    // CGQAQ: if tech and format both presence, order is matter, tech before format is invalid
+    // but now just return raw token, we don't have strict mode yet.
+    // ref: https://github.com/parcel-bundler/lightningcss/pull/255#issuecomment-1219049998
+    minify_test(
+      "@font-face {src: url(\"foo.ttf\") tech(palettes  color-colrv0  variations) format(opentype);}",
+      "@font-face{src:url(foo.ttf) tech(palettes color-colrv0 variations)format(opentype)}",
+    );
+    // TODO(CGQAQ): make this test pass when we have strict mode
+    // ref: https://github.com/web-platform-tests/wpt/blob/9f8a6ccc41aa725e8f51f4f096f686313bb88d8d/css/css-fonts/parsing/font-face-src-tech.html#L45
+    // error_test(
+    //   "@font-face {src: url(\"foo.ttf\") tech(features-opentype) format(opentype);}",
+    //   ParserError::AtRuleBodyInvalid,
+    // );
+    // error_test(
+    //   "@font-face {src: url(\"foo.ttf\") tech();}",
+    //   ParserError::AtRuleBodyInvalid,
+    // );
+    // error_test(
+    //   "@font-face {src: url(\"foo.ttf\") tech(\"features-opentype\");}",
+    //   ParserError::AtRuleBodyInvalid,
+    // );
+    // error_test(
+    //   "@font-face {src: url(\"foo.ttf\") tech(\"color-colrv0\");}",
+    //   ParserError::AtRuleBodyInvalid,
+    // );
+    minify_test(
+      "@font-face {src: local(\"\") url(\"test.woff\");}",
+      "@font-face{src:local(\"\")url(test.woff)}",
+    );
+    minify_test("@font-face {font-weight: 200 400}", "@font-face{font-weight:200 400}");
+    minify_test("@font-face {font-weight: 400 400}", "@font-face{font-weight:400}");
+    minify_test(
+      "@font-face {font-stretch: 50% 200%}",
+      "@font-face{font-stretch:50% 200%}",
+    );
+    minify_test("@font-face {font-stretch: 50% 50%}", "@font-face{font-stretch:50%}");
+    minify_test("@font-face {unicode-range: U+26;}", "@font-face{unicode-range:U+26}");
+    minify_test("@font-face {unicode-range: u+26;}", "@font-face{unicode-range:U+26}");
+    minify_test(
+      "@font-face {unicode-range: U+0-7F;}",
+      "@font-face{unicode-range:U+0-7F}",
+    );
+    minify_test(
+      "@font-face {unicode-range: U+0025-00FF;}",
+      "@font-face{unicode-range:U+25-FF}",
+    );
+    minify_test("@font-face {unicode-range: U+4??;}", "@font-face{unicode-range:U+4??}");
+    minify_test(
+      "@font-face {unicode-range: U+400-4FF;}",
+      "@font-face{unicode-range:U+4??}",
+    );
+    minify_test(
+      "@font-face {unicode-range: U+0025-00FF, U+4??;}",
+      "@font-face{unicode-range:U+25-FF,U+4??}",
+    );
+    minify_test(
+      "@font-face {unicode-range: U+A5, U+4E00-9FFF, U+30??, U+FF00-FF9F;}",
+      "@font-face{unicode-range:U+A5,U+4E00-9FFF,U+30??,U+FF00-FF9F}",
+    );
+    minify_test(
+      "@font-face {unicode-range: U+????;}",
+      "@font-face{unicode-range:U+????}",
+    );
+    minify_test(
+      "@font-face {unicode-range: U+0000-FFFF;}",
+      "@font-face{unicode-range:U+????}",
+    );
+    minify_test(
+      "@font-face {unicode-range: U+10????;}",
+      "@font-face{unicode-range:U+10????}",
+    );
+    minify_test(
+      "@font-face {unicode-range: U+100000-10FFFF;}",
+      "@font-face{unicode-range:U+10????}",
+    );
+    minify_test(
+      "@font-face {unicode-range: U+1e1e?;}",
+      "@font-face{unicode-range:U+1E1E?}",
+    );
+    minify_test(
+      "@font-face {unicode-range: u+????, U+1????, U+10????;}",
+      "@font-face{unicode-range:U+????,U+1????,U+10????}",
+    );
+    minify_test(r#"
+      @font-face {
+        font-family: Inter;
+        font-style: oblique 0deg 10deg;
+        font-weight: 100 900;
+        src: url("../fonts/Inter.var.woff2?v=3.19") format("woff2");
+        font-display: swap;
+      }
+    "#, "@font-face{font-family:Inter;font-style:oblique 0deg 10deg;font-weight:100 900;src:url(../fonts/Inter.var.woff2?v=3.19)format(\"woff2\");font-display:swap}");
+    minify_test(r#"
+    @font-face {
+      font-family: Inter;
+      font-style: oblique 14deg 14deg;
+      font-weight: 100 900;
+      src: url("../fonts/Inter.var.woff2?v=3.19") format("woff2");
+      font-display: swap;
+    }
+  "#, "@font-face{font-family:Inter;font-style:oblique;font-weight:100 900;src:url(../fonts/Inter.var.woff2?v=3.19)format(\"woff2\");font-display:swap}");
+  }
+
+  #[test]
+  fn test_font_palette_values() {
+    minify_test(
+      r#"@font-palette-values --Cooler {
+      font-family: Bixa;
+      base-palette: 1;
+      override-colors: 1 #7EB7E4;
+    }"#,
+      "@font-palette-values --Cooler{font-family:Bixa;base-palette:1;override-colors:1 #7eb7e4}",
     );
     minify_test(
-      ".foo { transform: translate3d(0px, 0px, 2px)",
-      ".foo{transform:translateZ(2px)}",
+      r#"@font-palette-values --Cooler {
+      font-family: Handover Sans;
+      base-palette: 3;
+      override-colors: 1 rgb(43, 12, 9), 3 lime;
+    }"#,
+      "@font-palette-values --Cooler{font-family:Handover Sans;base-palette:3;override-colors:1 #2b0c09,3 #0f0}",
     );
-    minify_test(
-      ".foo { transform: translate3d(2px, 3px, 0px)",
-      ".foo{transform:translate(2px,3px)}",
+    minify_test(r#"@font-palette-values --Cooler {
+      font-family: Handover Sans;
+      base-palette: 3;
+      override-colors: 1 rgb(43, 12, 9), 3 var(--highlight);
+    }"#, "@font-palette-values --Cooler{font-family:Handover Sans;base-palette:3;override-colors:1 #2b0c09,3 var(--highlight)}");
+    prefix_test(
+      r#"@font-palette-values --Cooler {
+      font-family: Handover Sans;
+      base-palette: 3;
+      override-colors: 1 rgb(43, 12, 9), 3 lch(50.998% 135.363 338);
+    }"#,
+      indoc! {r#"@font-palette-values --Cooler {
+      font-family: Handover Sans;
+      base-palette: 3;
+      override-colors: 1 #2b0c09, 3 #ee00be;
+      override-colors: 1 #2b0c09, 3 lch(50.998% 135.363 338);
+    }
+    "#},
+      Browsers {
+        chrome: Some(90 << 16),
+        ..Browsers::default()
+      },
     );
-    minify_test(".foo { transform: scale(2, 3)", ".foo{transform:scale(2,3)}");
-    minify_test(".foo { transform: scale(10%, 20%)", ".foo{transform:scale(.1,.2)}");
-    minify_test(".foo { transform: scale(2, 2)", ".foo{transform:scale(2)}");
-    minify_test(".foo { transform: scale(2, 1)", ".foo{transform:scaleX(2)}");
-    minify_test(".foo { transform: scale(1, 2)", ".foo{transform:scaleY(2)}");
-    minify_test(".foo { transform: scaleX(2)", ".foo{transform:scaleX(2)}");
-    minify_test(".foo { transform: scaleY(2)", ".foo{transform:scaleY(2)}");
-    minify_test(".foo { transform: scaleZ(2)", ".foo{transform:scaleZ(2)}");
-    minify_test(".foo { transform: scale3d(2, 3, 4)", ".foo{transform:scale3d(2,3,4)}");
-    minify_test(".foo { transform: scale3d(2, 1, 1)", ".foo{transform:scaleX(2)}");
-    minify_test(".foo { transform: scale3d(1, 2, 1)", ".foo{transform:scaleY(2)}");
-    minify_test(".foo { transform: scale3d(1, 1, 2)", ".foo{transform:scaleZ(2)}");
-    minify_test(".foo { transform: scale3d(2, 2, 1)", ".foo{transform:scale(2)}");
-    minify_test(".foo { transform: rotate(20deg)", ".foo{transform:rotate(20deg)}");
-    minify_test(".foo { transform: rotateX(20deg)", ".foo{transform:rotateX(20deg)}");
-    minify_test(".foo { transform: rotateY(20deg)", ".foo{transform:rotateY(20deg)}");
-    minify_test(".foo { transform: rotateZ(20deg)", ".foo{transform:rotate(20deg)}");
-    minify_test(".foo { transform: rotate(360deg)", ".foo{transform:rotate(360deg)}");
-    minify_test(
-      ".foo { transform: rotate3d(2, 3, 4, 20deg)",
-      ".foo{transform:rotate3d(2,3,4,20deg)}",
+    prefix_test(
+      r#"@font-palette-values --Cooler {
+      font-family: Handover Sans;
+      base-palette: 3;
+      override-colors: 1 var(--foo), 3 lch(50.998% 135.363 338);
+    }"#,
+      indoc! {r#"@font-palette-values --Cooler {
+      font-family: Handover Sans;
+      base-palette: 3;
+      override-colors: 1 var(--foo), 3 #ee00be;
+    }
+
+    @supports (color: lab(0% 0 0)) {
+      @font-palette-values --Cooler {
+        font-family: Handover Sans;
+        base-palette: 3;
+        override-colors: 1 var(--foo), 3 lab(50.998% 125.506 -50.7078);
+      }
+    }
+    "#},
+      Browsers {
+        chrome: Some(90 << 16),
+        ..Browsers::default()
+      },
     );
+    minify_test(".foo { font-palette: --Custom; }", ".foo{font-palette:--Custom}");
+  }
+
+  #[test]
+  fn test_page_rule() {
+    minify_test("@page {margin: 0.5cm}", "@page{margin:5mm}");
+    minify_test("@page :left {margin: 0.5cm}", "@page:left{margin:5mm}");
+    minify_test("@page :right {margin: 0.5cm}", "@page:right{margin:5mm}");
     minify_test(
-      ".foo { transform: rotate3d(1, 0, 0, 20deg)",
-      ".foo{transform:rotateX(20deg)}",
+      "@page LandscapeTable {margin: 0.5cm}",
+      "@page LandscapeTable{margin:5mm}",
     );
     minify_test(
-      ".foo { transform: rotate3d(0, 1, 0, 20deg)",
-      ".foo{transform:rotateY(20deg)}",
+      "@page CompanyLetterHead:first {margin: 0.5cm}",
+      "@page CompanyLetterHead:first{margin:5mm}",
     );
+    minify_test("@page:first {margin: 0.5cm}", "@page:first{margin:5mm}");
+    minify_test("@page :blank:first {margin: 0.5cm}", "@page:blank:first{margin:5mm}");
+    minify_test("@page toc, index {margin: 0.5cm}", "@page toc,index{margin:5mm}");
     minify_test(
-      ".foo { transform: rotate3d(0, 0, 1, 20deg)",
-      ".foo{transform:rotate(20deg)}",
+      r#"
+    @page :right {
+      @bottom-left {
+        margin: 10pt;
+      }
+    }
+    "#,
+      "@page:right{@bottom-left{margin:10pt}}",
     );
-    minify_test(".foo { transform: rotate(405deg)}", ".foo{transform:rotate(405deg)}");
-    minify_test(".foo { transform: rotateX(405deg)}", ".foo{transform:rotateX(405deg)}");
-    minify_test(".foo { transform: rotateY(405deg)}", ".foo{transform:rotateY(405deg)}");
-    minify_test(".foo { transform: rotate(-200deg)}", ".foo{transform:rotate(-200deg)}");
-    minify_test(".foo { transform: rotate(0)", ".foo{transform:rotate(0)}");
-    minify_test(".foo { transform: rotate(0deg)", ".foo{transform:rotate(0)}");
     minify_test(
-      ".foo { transform: rotateX(-200deg)}",
-      ".foo{transform:rotateX(-200deg)}",
+      r#"
+    @page :right {
+      margin: 1in;
+
+      @bottom-left {
+        margin: 10pt;
+      }
+    }
+    "#,
+      "@page:right{margin:1in;@bottom-left{margin:10pt}}",
     );
-    minify_test(
-      ".foo { transform: rotateY(-200deg)}",
-      ".foo{transform:rotateY(-200deg)}",
+
+    test(
+      r#"
+    @page :right {
+      @bottom-left {
+        margin: 10pt;
+      }
+    }
+    "#,
+      indoc! {r#"
+      @page :right {
+        @bottom-left {
+          margin: 10pt;
+        }
+      }
+      "#},
     );
-    minify_test(
-      ".foo { transform: rotate3d(1, 1, 0, -200deg)",
-      ".foo{transform:rotate3d(1,1,0,-200deg)}",
+
+    test(
+      r#"
+    @page :right {
+      margin: 1in;
+
+      @bottom-left-corner { content: "Foo"; }
+      @bottom-right-corner { content: "Bar"; }
+    }
+    "#,
+      indoc! {r#"
+      @page :right {
+        margin: 1in;
+
+        @bottom-left-corner {
+          content: "Foo";
+        }
+
+        @bottom-right-corner {
+          content: "Bar";
+        }
+      }
+      "#},
     );
-    minify_test(".foo { transform: skew(20deg)", ".foo{transform:skew(20deg)}");
-    minify_test(".foo { transform: skew(20deg, 0deg)", ".foo{transform:skew(20deg)}");
-    minify_test(".foo { transform: skew(0deg, 20deg)", ".foo{transform:skewY(20deg)}");
-    minify_test(".foo { transform: skewX(20deg)", ".foo{transform:skew(20deg)}");
-    minify_test(".foo { transform: skewY(20deg)", ".foo{transform:skewY(20deg)}");
-    minify_test(
-      ".foo { transform: perspective(10px)",
-      ".foo{transform:perspective(10px)}",
+
+    error_test(
+      r#"
+      @page {
+        @foo {
+          margin: 1in;
+        }
+      }
+      "#,
+      ParserError::AtRuleInvalid("foo".into()),
     );
-    minify_test(
-      ".foo { transform: matrix(1, 2, -1, 1, 80, 80)",
-      ".foo{transform:matrix(1,2,-1,1,80,80)}",
+
+    error_test(
+      r#"
+      @page {
+        @top-left-corner {
+          @bottom-left {
+            margin: 1in;
+          }
+        }
+      }
+      "#,
+      ParserError::AtRuleInvalid("bottom-left".into()),
     );
-    minify_test(
-      ".foo { transform: matrix3d(1, 0, 0, 0, 0, 1, 6, 0, 0, 0, 1, 0, 50, 100, 0, 1.1)",
-      ".foo{transform:matrix3d(1,0,0,0,0,1,6,0,0,0,1,0,50,100,0,1.1)}",
+  }
+
+  #[test]
+  fn test_supports_rule() {
+    test(
+      r#"
+      @supports (foo: bar) {
+        .test {
+          foo: bar;
+        }
+      }
+    "#,
+      indoc! { r#"
+      @supports (foo: bar) {
+        .test {
+          foo: bar;
+        }
+      }
+    "#},
     );
-    // TODO: Re-enable with a better solution
-    //       See: https://github.com/parcel-bundler/lightningcss/issues/288
-    // minify_test(
-    //   ".foo{transform:translate(100px,200px) rotate(45deg) skew(10deg) scale(2)}",
-    //   ".foo{transform:matrix(1.41421,1.41421,-1.16485,1.66358,100,200)}",
-    // );
-    // minify_test(
-    //   ".foo{transform:translate(200px,300px) translate(100px,200px) scale(2)}",
-    //   ".foo{transform:matrix(2,0,0,2,300,500)}",
-    // );
-    minify_test(
-      ".foo{transform:translate(100px,200px) rotate(45deg)}",
-      ".foo{transform:translate(100px,200px)rotate(45deg)}",
+    test(
+      r#"
+      @supports not (foo: bar) {
+        .test {
+          foo: bar;
+        }
+      }
+    "#,
+      indoc! { r#"
+      @supports not (foo: bar) {
+        .test {
+          foo: bar;
+        }
+      }
+    "#},
+    );
+    test(
+      r#"
+      @supports (foo: bar) or (bar: baz) {
+        .test {
+          foo: bar;
+        }
+      }
+    "#,
+      indoc! { r#"
+      @supports (foo: bar) or (bar: baz) {
+        .test {
+          foo: bar;
+        }
+      }
+    "#},
     );
-    minify_test(
-      ".foo{transform:rotate3d(1, 1, 1, 45deg) translate3d(100px, 100px, 10px)}",
-      ".foo{transform:rotate3d(1,1,1,45deg)translate3d(100px,100px,10px)}",
+    test(
+      r#"
+      @supports (((foo: bar) or (bar: baz))) {
+        .test {
+          foo: bar;
+        }
+      }
+    "#,
+      indoc! { r#"
+      @supports (foo: bar) or (bar: baz) {
+        .test {
+          foo: bar;
+        }
+      }
+    "#},
     );
-    // TODO: Re-enable with a better solution
-    //       See: https://github.com/parcel-bundler/lightningcss/issues/288
-    // minify_test(
-    //   ".foo{transform:translate3d(100px, 100px, 10px) skew(10deg) scale3d(2, 3, 4)}",
-    //   ".foo{transform:matrix3d(2,0,0,0,.528981,3,0,0,0,0,4,0,100,100,10,1)}",
-    // );
-    // minify_test(
-    //   ".foo{transform:matrix3d(0.804737854124365, 0.5058793634016805, -0.31061721752604554, 0, -0.31061721752604554, 0.804737854124365, 0.5058793634016805, 0, 0.5058793634016805, -0.31061721752604554, 0.804737854124365, 0, 100, 100, 10, 1)}",
-    //   ".foo{transform:translate3d(100px,100px,10px)rotate3d(1,1,1,45deg)}"
-    // );
-    // minify_test(
-    //   ".foo{transform:matrix3d(1, 0, 0, 0, 0, 0.7071067811865476, 0.7071067811865475, 0, 0, -0.7071067811865475, 0.7071067811865476, 0, 100, 100, 10, 1)}",
-    //   ".foo{transform:translate3d(100px,100px,10px)rotateX(45deg)}"
-    // );
-    // minify_test(
-    //   ".foo{transform:translate3d(100px, 200px, 10px) translate(100px, 100px)}",
-    //   ".foo{transform:translate3d(200px,300px,10px)}",
-    // );
-    // minify_test(
-    //   ".foo{transform:rotate(45deg) rotate(45deg)}",
-    //   ".foo{transform:rotate(90deg)}",
-    // );
-    // minify_test(
-    //   ".foo{transform:matrix(0.7071067811865476, 0.7071067811865475, -0.7071067811865475, 0.7071067811865476, 100, 100)}",
-    //   ".foo{transform:translate(100px,100px)rotate(45deg)}"
-    // );
-    // minify_test(
-    //   ".foo{transform:translateX(2in) translateX(50px)}",
-    //   ".foo{transform:translate(242px)}",
-    // );
-    minify_test(
-      ".foo{transform:translateX(calc(2in + 50px))}",
-      ".foo{transform:translate(242px)}",
+    test(
+      r#"
+      @supports (foo: bar) and (bar: baz) {
+        .test {
+          foo: bar;
+        }
+      }
+    "#,
+      indoc! { r#"
+      @supports (foo: bar) and (bar: baz) {
+        .test {
+          foo: bar;
+        }
+      }
+    "#},
     );
-    minify_test(".foo{transform:translateX(50%)}", ".foo{transform:translate(50%)}");
-    minify_test(
-      ".foo{transform:translateX(calc(50% - 100px + 20px))}",
-      ".foo{transform:translate(calc(50% - 80px))}",
+    test(
+      r#"
+      @supports (((foo: bar) and (bar: baz))) {
+        .test {
+          foo: bar;
+        }
+      }
+    "#,
+      indoc! { r#"
+      @supports (foo: bar) and (bar: baz) {
+        .test {
+          foo: bar;
+        }
+      }
+    "#},
     );
-    minify_test(
-      ".foo{transform:rotate(calc(10deg + 20deg))}",
-      ".foo{transform:rotate(30deg)}",
+    test(
+      r#"
+      @supports (foo: bar) and (((bar: baz) or (test: foo))) {
+        .test {
+          foo: bar;
+        }
+      }
+    "#,
+      indoc! { r#"
+      @supports (foo: bar) and ((bar: baz) or (test: foo)) {
+        .test {
+          foo: bar;
+        }
+      }
+    "#},
     );
-    minify_test(
-      ".foo{transform:rotate(calc(10deg + 0.349066rad))}",
-      ".foo{transform:rotate(30deg)}",
+    test(
+      r#"
+      @supports not (((foo: bar) and (bar: baz))) {
+        .test {
+          foo: bar;
+        }
+      }
+    "#,
+      indoc! { r#"
+      @supports not ((foo: bar) and (bar: baz)) {
+        .test {
+          foo: bar;
+        }
+      }
+    "#},
     );
-    minify_test(
-      ".foo{transform:rotate(calc(10deg + 1.5turn))}",
-      ".foo{transform:rotate(550deg)}",
+    test(
+      r#"
+      @supports selector(a > b) {
+        .test {
+          foo: bar;
+        }
+      }
+    "#,
+      indoc! { r#"
+      @supports selector(a > b) {
+        .test {
+          foo: bar;
+        }
+      }
+    "#},
     );
-    minify_test(
-      ".foo{transform:rotate(calc(10deg * 2))}",
-      ".foo{transform:rotate(20deg)}",
+    test(
+      r#"
+      @supports unknown(test) {
+        .test {
+          foo: bar;
+        }
+      }
+    "#,
+      indoc! { r#"
+      @supports unknown(test) {
+        .test {
+          foo: bar;
+        }
+      }
+    "#},
     );
-    minify_test(
-      ".foo{transform:rotate(calc(-10deg * 2))}",
-      ".foo{transform:rotate(-20deg)}",
+    test(
+      r#"
+      @supports (unknown) {
+        .test {
+          foo: bar;
+        }
+      }
+    "#,
+      indoc! { r#"
+      @supports (unknown) {
+        .test {
+          foo: bar;
+        }
+      }
+    "#},
     );
-    minify_test(
-      ".foo{transform:rotate(calc(10deg + var(--test)))}",
-      ".foo{transform:rotate(calc(10deg + var(--test)))}",
+    test(
+      r#"
+      @supports (display: grid) and (not (display: inline-grid)) {
+        .test {
+          foo: bar;
+        }
+      }
+    "#,
+      indoc! { r#"
+      @supports (display: grid) and (not (display: inline-grid)) {
+        .test {
+          foo: bar;
+        }
+      }
+    "#},
     );
-    minify_test(".foo { transform: scale(calc(10% + 20%))", ".foo{transform:scale(.3)}");
-    minify_test(".foo { transform: scale(calc(.1 + .2))", ".foo{transform:scale(.3)}");
-
-    minify_test(
-      ".foo { -webkit-transform: scale(calc(10% + 20%))",
-      ".foo{-webkit-transform:scale(.3)}",
+    prefix_test(
+      r#"
+      @supports (backdrop-filter: blur(10px)) {
+        div {
+          backdrop-filter: blur(10px);
+        }
+      }
+    "#,
+      indoc! { r#"
+      @supports ((-webkit-backdrop-filter: blur(10px)) or (backdrop-filter: blur(10px))) {
+        div {
+          -webkit-backdrop-filter: blur(10px);
+          backdrop-filter: blur(10px);
+        }
+      }
+    "#},
+      Browsers {
+        safari: Some(14 << 16),
+        ..Default::default()
+      },
+    );
+    prefix_test(
+      r#"
+      @supports ((-webkit-backdrop-filter: blur(10px)) or (backdrop-filter: blur(10px))) {
+        div {
+          backdrop-filter: blur(10px);
+        }
+      }
+    "#,
+      indoc! { r#"
+      @supports ((-webkit-backdrop-filter: blur(10px)) or (backdrop-filter: blur(10px))) {
+        div {
+          -webkit-backdrop-filter: blur(10px);
+          backdrop-filter: blur(10px);
+        }
+      }
+    "#},
+      Browsers {
+        safari: Some(14 << 16),
+        ..Default::default()
+      },
     );
-
-    minify_test(".foo { translate: 1px 2px 3px }", ".foo{translate:1px 2px 3px}");
-    minify_test(".foo { translate: 1px 0px 0px }", ".foo{translate:1px}");
-    minify_test(".foo { translate: 1px 2px 0px }", ".foo{translate:1px 2px}");
-    minify_test(".foo { translate: 1px 0px 2px }", ".foo{translate:1px 0 2px}");
-    minify_test(".foo { translate: none }", ".foo{translate:0}");
-    minify_test(".foo { rotate: 10deg }", ".foo{rotate:10deg}");
-    minify_test(".foo { rotate: z 10deg }", ".foo{rotate:10deg}");
-    minify_test(".foo { rotate: 0 0 1 10deg }", ".foo{rotate:10deg}");
-    minify_test(".foo { rotate: x 10deg }", ".foo{rotate:x 10deg}");
-    minify_test(".foo { rotate: 1 0 0 10deg }", ".foo{rotate:x 10deg}");
-    minify_test(".foo { rotate: y 10deg }", ".foo{rotate:y 10deg}");
-    minify_test(".foo { rotate: 0 1 0 10deg }", ".foo{rotate:y 10deg}");
-    minify_test(".foo { rotate: 1 1 1 10deg }", ".foo{rotate:1 1 1 10deg}");
-    minify_test(".foo { rotate: 0 0 1 0deg }", ".foo{rotate:none}");
-    minify_test(".foo { rotate: none }", ".foo{rotate:none}");
-    minify_test(".foo { scale: 1 }", ".foo{scale:1}");
-    minify_test(".foo { scale: 1 1 }", ".foo{scale:1}");
-    minify_test(".foo { scale: 1 1 1 }", ".foo{scale:1}");
-    minify_test(".foo { scale: none }", ".foo{scale:1}");
-    minify_test(".foo { scale: 1 0 }", ".foo{scale:1 0}");
-    minify_test(".foo { scale: 1 0 1 }", ".foo{scale:1 0}");
-    minify_test(".foo { scale: 1 0 0 }", ".foo{scale:1 0 0}");
-
-    // TODO: Re-enable with a better solution
-    //       See: https://github.com/parcel-bundler/lightningcss/issues/288
-    // minify_test(".foo { transform: scale(3); scale: 0.5 }", ".foo{transform:scale(1.5)}");
-    minify_test(".foo { scale: 0.5; transform: scale(3); }", ".foo{transform:scale(3)}");
-
     prefix_test(
       r#"
-      .foo {
-        transform: scale(0.5);
+      @supports ((-webkit-backdrop-filter: blur(20px)) or (backdrop-filter: blur(10px))) {
+        div {
+          backdrop-filter: blur(10px);
+        }
       }
     "#,
-      indoc! {r#"
-      .foo {
-        -webkit-transform: scale(.5);
-        -moz-transform: scale(.5);
-        transform: scale(.5);
+      indoc! { r#"
+      @supports ((-webkit-backdrop-filter: blur(20px))) or ((-webkit-backdrop-filter: blur(10px)) or (backdrop-filter: blur(10px))) {
+        div {
+          -webkit-backdrop-filter: blur(10px);
+          backdrop-filter: blur(10px);
+        }
       }
     "#},
       Browsers {
-        firefox: Some(6 << 16),
-        safari: Some(6 << 16),
-        ..Browsers::default()
+        safari: Some(14 << 16),
+        ..Default::default()
       },
     );
-
     prefix_test(
       r#"
-      .foo {
-        transform: var(--transform);
+      @supports ((-webkit-backdrop-filter: blur(10px)) or (backdrop-filter: blur(10px))) {
+        div {
+          backdrop-filter: blur(10px);
+        }
       }
     "#,
-      indoc! {r#"
-      .foo {
-        -webkit-transform: var(--transform);
-        -moz-transform: var(--transform);
-        transform: var(--transform);
+      indoc! { r#"
+      @supports (backdrop-filter: blur(10px)) {
+        div {
+          backdrop-filter: blur(10px);
+        }
       }
     "#},
       Browsers {
-        firefox: Some(6 << 16),
-        safari: Some(6 << 16),
-        ..Browsers::default()
+        chrome: Some(80 << 16),
+        ..Default::default()
       },
     );
+    minify_test(
+      r#"
+      @supports (width: calc(10px * 2)) {
+        .test {
+          width: calc(10px * 2);
+        }
+      }
+    "#,
+      "@supports (width:calc(10px * 2)){.test{width:20px}}",
+    );
+    minify_test(
+      r#"
+      @supports (color: hsl(0deg, 0%, 0%)) {
+        .test {
+          color: hsl(0deg, 0%, 0%);
+        }
+      }
+    "#,
+      "@supports (color:hsl(0deg, 0%, 0%)){.test{color:#000}}",
+    );
+  }
 
+  #[test]
+  fn test_counter_style() {
     test(
       r#"
-      .foo {
-        transform: translateX(-50%);
-        transform: translateX(20px);
+      @counter-style circled-alpha {
+        system: fixed;
+        symbols: Ⓐ Ⓑ Ⓒ;
+        suffix: " ";
       }
-      "#,
-      indoc! {r#"
-      .foo {
-        transform: translateX(20px);
+    "#,
+      indoc! { r#"
+      @counter-style circled-alpha {
+        system: fixed;
+        symbols: Ⓐ Ⓑ Ⓒ;
+        suffix: " ";
       }
-      "#},
+    "#},
     );
   }
 
   #[test]
-  pub fn test_gradients() {
-    minify_test(
-      ".foo { background: linear-gradient(yellow, blue) }",
-      ".foo{background:linear-gradient(#ff0,#00f)}",
-    );
-    minify_test(
-      ".foo { background: linear-gradient(to bottom, yellow, blue); }",
-      ".foo{background:linear-gradient(#ff0,#00f)}",
-    );
-    minify_test(
-      ".foo { background: linear-gradient(180deg, yellow, blue); }",
-      ".foo{background:linear-gradient(#ff0,#00f)}",
-    );
-    minify_test(
-      ".foo { background: linear-gradient(0.5turn, yellow, blue); }",
-      ".foo{background:linear-gradient(#ff0,#00f)}",
-    );
-    minify_test(
-      ".foo { background: linear-gradient(yellow 10%, blue 20%) }",
-      ".foo{background:linear-gradient(#ff0 10%,#00f 20%)}",
-    );
-    minify_test(
-      ".foo { background: linear-gradient(to top, blue, yellow); }",
-      ".foo{background:linear-gradient(#ff0,#00f)}",
-    );
-    minify_test(
-      ".foo { background: linear-gradient(to top, blue 10%, yellow 20%); }",
-      ".foo{background:linear-gradient(#ff0 80%,#00f 90%)}",
-    );
-    minify_test(
-      ".foo { background: linear-gradient(to top, blue 10px, yellow 20px); }",
-      ".foo{background:linear-gradient(0deg,#00f 10px,#ff0 20px)}",
-    );
-    minify_test(
-      ".foo { background: linear-gradient(135deg, yellow, blue); }",
-      ".foo{background:linear-gradient(135deg,#ff0,#00f)}",
-    );
-    minify_test(
-      ".foo { background: linear-gradient(yellow, blue 20%, #0f0); }",
-      ".foo{background:linear-gradient(#ff0,#00f 20%,#0f0)}",
-    );
-    minify_test(
-      ".foo { background: linear-gradient(to top right, red, white, blue) }",
-      ".foo{background:linear-gradient(to top right,red,#fff,#00f)}",
-    );
-    minify_test(
-      ".foo { background: linear-gradient(yellow, blue calc(10% * 2), #0f0); }",
-      ".foo{background:linear-gradient(#ff0,#00f 20%,#0f0)}",
-    );
-    minify_test(
-      ".foo { background: linear-gradient(yellow, 20%, blue); }",
-      ".foo{background:linear-gradient(#ff0,20%,#00f)}",
-    );
-    minify_test(
-      ".foo { background: linear-gradient(yellow, 50%, blue); }",
-      ".foo{background:linear-gradient(#ff0,#00f)}",
-    );
-    minify_test(
-      ".foo { background: linear-gradient(yellow, 20px, blue); }",
-      ".foo{background:linear-gradient(#ff0,20px,#00f)}",
-    );
-    minify_test(
-      ".foo { background: linear-gradient(yellow, 50px, blue); }",
-      ".foo{background:linear-gradient(#ff0,50px,#00f)}",
-    );
-    minify_test(
-      ".foo { background: linear-gradient(yellow, 50px, blue); }",
-      ".foo{background:linear-gradient(#ff0,50px,#00f)}",
-    );
-    minify_test(
-      ".foo { background: linear-gradient(yellow, red 30% 40%, blue); }",
-      ".foo{background:linear-gradient(#ff0,red 30% 40%,#00f)}",
-    );
-    minify_test(
-      ".foo { background: linear-gradient(yellow, red 30%, red 40%, blue); }",
-      ".foo{background:linear-gradient(#ff0,red 30% 40%,#00f)}",
-    );
-    minify_test(
-      ".foo { background: linear-gradient(0, yellow, blue); }",
-      ".foo{background:linear-gradient(#00f,#ff0)}",
-    );
-    minify_test(
-      ".foo { background: -webkit-linear-gradient(yellow, blue) }",
-      ".foo{background:-webkit-linear-gradient(#ff0,#00f)}",
-    );
-    minify_test(
-      ".foo { background: -webkit-linear-gradient(bottom, yellow, blue); }",
-      ".foo{background:-webkit-linear-gradient(#ff0,#00f)}",
-    );
-    minify_test(
-      ".foo { background: -webkit-linear-gradient(top right, red, white, blue) }",
-      ".foo{background:-webkit-linear-gradient(top right,red,#fff,#00f)}",
-    );
-    minify_test(
-      ".foo { background: -moz-linear-gradient(yellow, blue) }",
-      ".foo{background:-moz-linear-gradient(#ff0,#00f)}",
-    );
-    minify_test(
-      ".foo { background: -moz-linear-gradient(bottom, yellow, blue); }",
-      ".foo{background:-moz-linear-gradient(#ff0,#00f)}",
-    );
-    minify_test(
-      ".foo { background: -moz-linear-gradient(top right, red, white, blue) }",
-      ".foo{background:-moz-linear-gradient(top right,red,#fff,#00f)}",
-    );
-    minify_test(
-      ".foo { background: -o-linear-gradient(yellow, blue) }",
-      ".foo{background:-o-linear-gradient(#ff0,#00f)}",
-    );
-    minify_test(
-      ".foo { background: -o-linear-gradient(bottom, yellow, blue); }",
-      ".foo{background:-o-linear-gradient(#ff0,#00f)}",
-    );
-    minify_test(
-      ".foo { background: -o-linear-gradient(top right, red, white, blue) }",
-      ".foo{background:-o-linear-gradient(top right,red,#fff,#00f)}",
-    );
+  fn test_namespace() {
     minify_test(
-      ".foo { background: -webkit-gradient(linear, left top, left bottom, from(blue), to(yellow)) }",
-      ".foo{background:-webkit-gradient(linear,0 0,0 100%,from(#00f),to(#ff0))}",
+      "@namespace url(http://toto.example.org);",
+      "@namespace \"http://toto.example.org\";",
     );
     minify_test(
-      ".foo { background: -webkit-gradient(linear, left top, left bottom, from(blue), color-stop(50%, red), to(yellow)) }",
-      ".foo{background:-webkit-gradient(linear,0 0,0 100%,from(#00f),color-stop(.5,red),to(#ff0))}"
+      "@namespace \"http://toto.example.org\";",
+      "@namespace \"http://toto.example.org\";",
     );
     minify_test(
-      ".foo { background: -webkit-gradient(linear, left top, left bottom, color-stop(0%, blue), color-stop(50%, red), color-stop(100%, yellow)) }",
-      ".foo{background:-webkit-gradient(linear,0 0,0 100%,from(#00f),color-stop(.5,red),to(#ff0))}"
+      "@namespace toto \"http://toto.example.org\";",
+      "@namespace toto \"http://toto.example.org\";",
     );
     minify_test(
-      ".foo { background: repeating-linear-gradient(yellow 10px, blue 50px) }",
-      ".foo{background:repeating-linear-gradient(#ff0 10px,#00f 50px)}",
+      "@namespace toto url(http://toto.example.org);",
+      "@namespace toto \"http://toto.example.org\";",
     );
-    minify_test(
-      ".foo { background: -webkit-repeating-linear-gradient(yellow 10px, blue 50px) }",
-      ".foo{background:-webkit-repeating-linear-gradient(#ff0 10px,#00f 50px)}",
+
+    test(
+      r#"
+      @namespace "http://example.com/foo";
+
+      x {
+        color: red;
+      }
+    "#,
+      indoc! {r#"
+      @namespace "http://example.com/foo";
+
+      x {
+        color: red;
+      }
+    "#},
     );
-    minify_test(
-      ".foo { background: -moz-repeating-linear-gradient(yellow 10px, blue 50px) }",
-      ".foo{background:-moz-repeating-linear-gradient(#ff0 10px,#00f 50px)}",
+
+    test(
+      r#"
+      @namespace toto "http://toto.example.org";
+
+      toto|x {
+        color: red;
+      }
+
+      [toto|att=val] {
+        color: blue
+      }
+    "#,
+      indoc! {r#"
+      @namespace toto "http://toto.example.org";
+
+      toto|x {
+        color: red;
+      }
+
+      [toto|att="val"] {
+        color: #00f;
+      }
+    "#},
     );
-    minify_test(
-      ".foo { background: -o-repeating-linear-gradient(yellow 10px, blue 50px) }",
-      ".foo{background:-o-repeating-linear-gradient(#ff0 10px,#00f 50px)}",
+
+    test(
+      r#"
+      @namespace "http://example.com/foo";
+
+      |x {
+        color: red;
+      }
+
+      [|att=val] {
+        color: blue
+      }
+    "#,
+      indoc! {r#"
+      @namespace "http://example.com/foo";
+
+      |x {
+        color: red;
+      }
+
+      [att="val"] {
+        color: #00f;
+      }
+    "#},
     );
-    minify_test(
-      ".foo { background: radial-gradient(yellow, blue) }",
-      ".foo{background:radial-gradient(#ff0,#00f)}",
+
+    test(
+      r#"
+      @namespace "http://example.com/foo";
+
+      *|x {
+        color: red;
+      }
+
+      [*|att=val] {
+        color: blue
+      }
+    "#,
+      indoc! {r#"
+      @namespace "http://example.com/foo";
+
+      *|x {
+        color: red;
+      }
+
+      [*|att="val"] {
+        color: #00f;
+      }
+    "#},
     );
-    minify_test(
-      ".foo { background: radial-gradient(at top left, yellow, blue) }",
-      ".foo{background:radial-gradient(at 0 0,#ff0,#00f)}",
+
+    error_test(
+      ".foo { color: red } @namespace \"http://example.com/foo\";",
+      ParserError::UnexpectedNamespaceRule,
     );
+  }
+
+  #[test]
+  fn test_import() {
+    minify_test("@import url(foo.css);", "@import \"foo.css\";");
+    minify_test("@import \"foo.css\";", "@import \"foo.css\";");
+    minify_test("@import url(foo.css) print;", "@import \"foo.css\" print;");
+    minify_test("@import \"foo.css\" print;", "@import \"foo.css\" print;");
     minify_test(
-      ".foo { background: radial-gradient(5em circle at top left, yellow, blue) }",
-      ".foo{background:radial-gradient(5em at 0 0,#ff0,#00f)}",
+      "@import \"foo.css\" screen and (orientation: landscape);",
+      "@import \"foo.css\" screen and (orientation:landscape);",
     );
     minify_test(
-      ".foo { background: radial-gradient(circle at 100%, #333, #333 50%, #eee 75%, #333 75%) }",
-      ".foo{background:radial-gradient(circle at 100%,#333,#333 50%,#eee 75%,#333 75%)}",
+      "@import url(foo.css) supports(display: flex);",
+      "@import \"foo.css\" supports(display:flex);",
     );
     minify_test(
-      ".foo { background: radial-gradient(farthest-corner circle at 100% 50%, #333, #333 50%, #eee 75%, #333 75%) }",
-      ".foo{background:radial-gradient(circle at 100%,#333,#333 50%,#eee 75%,#333 75%)}"
+      "@import url(foo.css) supports(display: flex) print;",
+      "@import \"foo.css\" supports(display:flex) print;",
     );
     minify_test(
-      ".foo { background: radial-gradient(farthest-corner circle at 50% 50%, #333, #333 50%, #eee 75%, #333 75%) }",
-      ".foo{background:radial-gradient(circle,#333,#333 50%,#eee 75%,#333 75%)}"
+      "@import url(foo.css) supports(not (display: flex));",
+      "@import \"foo.css\" supports(not (display:flex));",
     );
     minify_test(
-      ".foo { background: radial-gradient(ellipse at top, #e66465, transparent) }",
-      ".foo{background:radial-gradient(at top,#e66465,#0000)}",
+      "@import url(foo.css) supports((display: flex));",
+      "@import \"foo.css\" supports(display:flex);",
     );
-    minify_test(
-      ".foo { background: radial-gradient(20px, yellow, blue) }",
-      ".foo{background:radial-gradient(20px,#ff0,#00f)}",
+    minify_test("@charset \"UTF-8\"; @import url(foo.css);", "@import \"foo.css\";");
+    minify_test("@layer foo; @import url(foo.css);", "@layer foo;@import \"foo.css\";");
+    error_test(
+      ".foo { color: red } @import url(bar.css);",
+      ParserError::UnexpectedImportRule,
     );
-    minify_test(
-      ".foo { background: radial-gradient(circle 20px, yellow, blue) }",
-      ".foo{background:radial-gradient(20px,#ff0,#00f)}",
+    error_test(
+      "@namespace \"http://example.com/foo\"; @import url(bar.css);",
+      ParserError::UnexpectedImportRule,
     );
-    minify_test(
-      ".foo { background: radial-gradient(20px 40px, yellow, blue) }",
-      ".foo{background:radial-gradient(20px 40px,#ff0,#00f)}",
+    error_test(
+      "@media print { .foo { color: red }} @import url(bar.css);",
+      ParserError::UnexpectedImportRule,
     );
-    minify_test(
-      ".foo { background: radial-gradient(ellipse 20px 40px, yellow, blue) }",
-      ".foo{background:radial-gradient(20px 40px,#ff0,#00f)}",
+    error_test(
+      "@layer foo; @import url(foo.css); @layer bar; @import url(bar.css)",
+      ParserError::UnexpectedImportRule,
     );
-    minify_test(
-      ".foo { background: radial-gradient(ellipse calc(20px + 10px) 40px, yellow, blue) }",
-      ".foo{background:radial-gradient(30px 40px,#ff0,#00f)}",
+  }
+
+  #[test]
+  fn test_prefixes() {
+    prefix_test(
+      r#"
+      .foo {
+        -webkit-transition: opacity 200ms;
+        -moz-transition: opacity 200ms;
+        transition: opacity 200ms;
+      }
+      "#,
+      indoc! {r#"
+      .foo {
+        transition: opacity .2s;
+      }
+      "#},
+      Browsers {
+        chrome: Some(95 << 16),
+        ..Browsers::default()
+      },
     );
-    minify_test(
-      ".foo { background: radial-gradient(circle farthest-side, yellow, blue) }",
-      ".foo{background:radial-gradient(circle farthest-side,#ff0,#00f)}",
+
+    prefix_test(
+      ".foo{transition:opacity 200ms}",
+      indoc! {r#"
+      .foo {
+        -webkit-transition: opacity .2s;
+        -moz-transition: opacity .2s;
+        transition: opacity .2s;
+      }
+      "#},
+      Browsers {
+        safari: Some(5 << 16),
+        firefox: Some(14 << 16),
+        ..Browsers::default()
+      },
     );
+  }
+
+  #[test]
+  fn test_display() {
+    minify_test(".foo { display: block }", ".foo{display:block}");
+    minify_test(".foo { display: block flow }", ".foo{display:block}");
+    minify_test(".foo { display: flow-root }", ".foo{display:flow-root}");
+    minify_test(".foo { display: block flow-root }", ".foo{display:flow-root}");
+    minify_test(".foo { display: inline }", ".foo{display:inline}");
+    minify_test(".foo { display: inline flow }", ".foo{display:inline}");
+    minify_test(".foo { display: inline-block }", ".foo{display:inline-block}");
+    minify_test(".foo { display: inline flow-root }", ".foo{display:inline-block}");
+    minify_test(".foo { display: run-in }", ".foo{display:run-in}");
+    minify_test(".foo { display: run-in flow }", ".foo{display:run-in}");
+    minify_test(".foo { display: list-item }", ".foo{display:list-item}");
+    minify_test(".foo { display: block flow list-item }", ".foo{display:list-item}");
+    minify_test(".foo { display: inline list-item }", ".foo{display:inline list-item}");
     minify_test(
-      ".foo { background: radial-gradient(farthest-side circle, yellow, blue) }",
-      ".foo{background:radial-gradient(circle farthest-side,#ff0,#00f)}",
+      ".foo { display: inline flow list-item }",
+      ".foo{display:inline list-item}",
     );
+    minify_test(".foo { display: flex }", ".foo{display:flex}");
+    minify_test(".foo { display: block flex }", ".foo{display:flex}");
+    minify_test(".foo { display: inline-flex }", ".foo{display:inline-flex}");
+    minify_test(".foo { display: inline flex }", ".foo{display:inline-flex}");
+    minify_test(".foo { display: grid }", ".foo{display:grid}");
+    minify_test(".foo { display: block grid }", ".foo{display:grid}");
+    minify_test(".foo { display: inline-grid }", ".foo{display:inline-grid}");
+    minify_test(".foo { display: inline grid }", ".foo{display:inline-grid}");
+    minify_test(".foo { display: ruby }", ".foo{display:ruby}");
+    minify_test(".foo { display: inline ruby }", ".foo{display:ruby}");
+    minify_test(".foo { display: block ruby }", ".foo{display:block ruby}");
+    minify_test(".foo { display: table }", ".foo{display:table}");
+    minify_test(".foo { display: block table }", ".foo{display:table}");
+    minify_test(".foo { display: inline-table }", ".foo{display:inline-table}");
+    minify_test(".foo { display: inline table }", ".foo{display:inline-table}");
+    minify_test(".foo { display: table-row-group }", ".foo{display:table-row-group}");
+    minify_test(".foo { display: contents }", ".foo{display:contents}");
+    minify_test(".foo { display: none }", ".foo{display:none}");
+    minify_test(".foo { display: -webkit-flex }", ".foo{display:-webkit-flex}");
+    minify_test(".foo { display: -ms-flexbox }", ".foo{display:-ms-flexbox}");
+    minify_test(".foo { display: -webkit-box }", ".foo{display:-webkit-box}");
+    minify_test(".foo { display: -moz-box }", ".foo{display:-moz-box}");
     minify_test(
-      ".foo { background: radial-gradient(ellipse farthest-side, yellow, blue) }",
-      ".foo{background:radial-gradient(farthest-side,#ff0,#00f)}",
+      ".foo { display: -webkit-flex; display: -moz-box; display: flex }",
+      ".foo{display:-webkit-flex;display:-moz-box;display:flex}",
     );
     minify_test(
-      ".foo { background: radial-gradient(farthest-side ellipse, yellow, blue) }",
-      ".foo{background:radial-gradient(farthest-side,#ff0,#00f)}",
+      ".foo { display: -webkit-flex; display: flex; display: -moz-box }",
+      ".foo{display:-webkit-flex;display:flex;display:-moz-box}",
     );
+    minify_test(".foo { display: flex; display: grid }", ".foo{display:grid}");
     minify_test(
-      ".foo { background: -webkit-radial-gradient(yellow, blue) }",
-      ".foo{background:-webkit-radial-gradient(#ff0,#00f)}",
+      ".foo { display: -webkit-inline-flex; display: -moz-inline-box; display: inline-flex }",
+      ".foo{display:-webkit-inline-flex;display:-moz-inline-box;display:inline-flex}",
     );
     minify_test(
-      ".foo { background: -moz-radial-gradient(yellow, blue) }",
-      ".foo{background:-moz-radial-gradient(#ff0,#00f)}",
+      ".foo { display: flex; display: var(--grid); }",
+      ".foo{display:flex;display:var(--grid)}",
+    );
+    prefix_test(
+      ".foo{ display: flex }",
+      indoc! {r#"
+      .foo {
+        display: -webkit-box;
+        display: -moz-box;
+        display: -webkit-flex;
+        display: -ms-flexbox;
+        display: flex;
+      }
+      "#},
+      Browsers {
+        safari: Some(4 << 16),
+        firefox: Some(14 << 16),
+        ie: Some(10 << 16),
+        ..Browsers::default()
+      },
     );
-    minify_test(
-      ".foo { background: -o-radial-gradient(yellow, blue) }",
-      ".foo{background:-o-radial-gradient(#ff0,#00f)}",
+    prefix_test(
+      ".foo{ display: flex; display: -webkit-box; }",
+      indoc! {r#"
+      .foo {
+        display: -webkit-box;
+      }
+      "#},
+      Browsers {
+        safari: Some(4 << 16),
+        firefox: Some(14 << 16),
+        ie: Some(10 << 16),
+        ..Browsers::default()
+      },
     );
-    minify_test(
-      ".foo { background: repeating-radial-gradient(circle 20px, yellow, blue) }",
-      ".foo{background:repeating-radial-gradient(20px,#ff0,#00f)}",
+    prefix_test(
+      ".foo{ display: -webkit-box; display: flex; }",
+      indoc! {r#"
+      .foo {
+        display: -webkit-box;
+        display: -moz-box;
+        display: -webkit-flex;
+        display: -ms-flexbox;
+        display: flex;
+      }
+      "#},
+      Browsers {
+        safari: Some(4 << 16),
+        firefox: Some(14 << 16),
+        ie: Some(10 << 16),
+        ..Browsers::default()
+      },
     );
-    minify_test(
-      ".foo { background: -webkit-repeating-radial-gradient(circle 20px, yellow, blue) }",
-      ".foo{background:-webkit-repeating-radial-gradient(20px,#ff0,#00f)}",
+    prefix_test(
+      r#"
+      .foo {
+        display: -webkit-box;
+        display: -moz-box;
+        display: -webkit-flex;
+        display: -ms-flexbox;
+        display: flex;
+      }
+      "#,
+      indoc! {r#"
+      .foo {
+        display: flex;
+      }
+      "#},
+      Browsers {
+        safari: Some(14 << 16),
+        ..Browsers::default()
+      },
     );
-    minify_test(
-      ".foo { background: -moz-repeating-radial-gradient(circle 20px, yellow, blue) }",
-      ".foo{background:-moz-repeating-radial-gradient(20px,#ff0,#00f)}",
+    prefix_test(
+      r#"
+      .foo {
+        display: -webkit-box;
+        display: flex;
+        display: -moz-box;
+        display: -webkit-flex;
+        display: -ms-flexbox;
+      }
+      "#,
+      indoc! {r#"
+      .foo {
+        display: -moz-box;
+        display: -webkit-flex;
+        display: -ms-flexbox;
+      }
+      "#},
+      Browsers {
+        safari: Some(14 << 16),
+        ..Browsers::default()
+      },
     );
-    minify_test(
-      ".foo { background: -o-repeating-radial-gradient(circle 20px, yellow, blue) }",
-      ".foo{background:-o-repeating-radial-gradient(20px,#ff0,#00f)}",
+    prefix_test(
+      ".foo{ display: inline-flex }",
+      indoc! {r#"
+      .foo {
+        display: -webkit-inline-box;
+        display: -moz-inline-box;
+        display: -webkit-inline-flex;
+        display: -ms-inline-flexbox;
+        display: inline-flex;
+      }
+      "#},
+      Browsers {
+        safari: Some(4 << 16),
+        firefox: Some(14 << 16),
+        ie: Some(10 << 16),
+        ..Browsers::default()
+      },
     );
-    minify_test(
-      ".foo { background: -webkit-gradient(radial, center center, 0, center center, 100, from(blue), to(yellow)) }",
-      ".foo{background:-webkit-gradient(radial,50% 50%,0,50% 50%,100,from(#00f),to(#ff0))}"
+    prefix_test(
+      r#"
+      .foo {
+        display: -webkit-inline-box;
+        display: -moz-inline-box;
+        display: -webkit-inline-flex;
+        display: -ms-inline-flexbox;
+        display: inline-flex;
+      }
+      "#,
+      indoc! {r#"
+      .foo {
+        display: inline-flex;
+      }
+      "#},
+      Browsers {
+        safari: Some(14 << 16),
+        ..Browsers::default()
+      },
     );
+  }
+
+  #[test]
+  fn test_visibility() {
+    minify_test(".foo { visibility: visible }", ".foo{visibility:visible}");
+    minify_test(".foo { visibility: hidden }", ".foo{visibility:hidden}");
+    minify_test(".foo { visibility: collapse }", ".foo{visibility:collapse}");
+    minify_test(".foo { visibility: Visible }", ".foo{visibility:visible}");
+  }
+
+  #[test]
+  fn test_text_transform() {
+    minify_test(".foo { text-transform: uppercase }", ".foo{text-transform:uppercase}");
+    minify_test(".foo { text-transform: lowercase }", ".foo{text-transform:lowercase}");
+    minify_test(".foo { text-transform: capitalize }", ".foo{text-transform:capitalize}");
+    minify_test(".foo { text-transform: none }", ".foo{text-transform:none}");
+    minify_test(".foo { text-transform: full-width }", ".foo{text-transform:full-width}");
     minify_test(
-      ".foo { background: conic-gradient(#f06, gold) }",
-      ".foo{background:conic-gradient(#f06,gold)}",
+      ".foo { text-transform: full-size-kana }",
+      ".foo{text-transform:full-size-kana}",
     );
     minify_test(
-      ".foo { background: conic-gradient(at 50% 50%, #f06, gold) }",
-      ".foo{background:conic-gradient(#f06,gold)}",
+      ".foo { text-transform: uppercase full-width }",
+      ".foo{text-transform:uppercase full-width}",
     );
     minify_test(
-      ".foo { background: conic-gradient(from 0deg, #f06, gold) }",
-      ".foo{background:conic-gradient(#f06,gold)}",
+      ".foo { text-transform: full-width uppercase }",
+      ".foo{text-transform:uppercase full-width}",
     );
     minify_test(
-      ".foo { background: conic-gradient(from 0, #f06, gold) }",
-      ".foo{background:conic-gradient(#f06,gold)}",
+      ".foo { text-transform: uppercase full-width full-size-kana }",
+      ".foo{text-transform:uppercase full-width full-size-kana}",
     );
     minify_test(
-      ".foo { background: conic-gradient(from 0deg at center, #f06, gold) }",
-      ".foo{background:conic-gradient(#f06,gold)}",
+      ".foo { text-transform: full-width uppercase full-size-kana }",
+      ".foo{text-transform:uppercase full-width full-size-kana}",
     );
+  }
+
+  #[test]
+  fn test_whitespace() {
+    minify_test(".foo { white-space: normal }", ".foo{white-space:normal}");
+    minify_test(".foo { white-space: pre }", ".foo{white-space:pre}");
+    minify_test(".foo { white-space: nowrap }", ".foo{white-space:nowrap}");
+    minify_test(".foo { white-space: pre-wrap }", ".foo{white-space:pre-wrap}");
+    minify_test(".foo { white-space: break-spaces }", ".foo{white-space:break-spaces}");
+    minify_test(".foo { white-space: pre-line }", ".foo{white-space:pre-line}");
+    minify_test(".foo { white-space: NoWrAp }", ".foo{white-space:nowrap}");
+    minify_test(".foo { white-space: collapse }", ".foo{white-space:normal}");
+    minify_test(".foo { white-space: wrap }", ".foo{white-space:normal}");
+    minify_test(".foo { white-space: collapse nowrap }", ".foo{white-space:nowrap}");
+    minify_test(".foo { white-space: preserve nowrap }", ".foo{white-space:pre}");
+    minify_test(".foo { white-space: nowrap preserve }", ".foo{white-space:pre}");
+    minify_test(".foo { white-space: preserve }", ".foo{white-space:pre-wrap}");
+    minify_test(".foo { white-space: preserve-breaks }", ".foo{white-space:pre-line}");
     minify_test(
-      ".foo { background: conic-gradient(white -50%, black 150%) }",
-      ".foo{background:conic-gradient(#fff -50%,#000 150%)}",
+      ".foo { white-space: break-spaces wrap }",
+      ".foo{white-space:break-spaces}",
     );
     minify_test(
-      ".foo { background: conic-gradient(white -180deg, black 540deg) }",
-      ".foo{background:conic-gradient(#fff -180deg,#000 540deg)}",
+      ".foo { white-space: preserve-spaces }",
+      ".foo{white-space:preserve-spaces}",
     );
     minify_test(
-      ".foo { background: conic-gradient(from 45deg, white, black, white) }",
-      ".foo{background:conic-gradient(from 45deg,#fff,#000,#fff)}",
+      ".foo { white-space: preserve-breaks nowrap }",
+      ".foo{white-space:preserve-breaks nowrap}",
     );
+    minify_test(".foo { white-space: discard }", ".foo{white-space:discard}");
+    minify_test(".foo { white-space: pre nowrap }", ".foo{white-space:pre nowrap}"); // invalid
     minify_test(
-      ".foo { background: repeating-conic-gradient(from 45deg, white, black, white) }",
-      ".foo{background:repeating-conic-gradient(from 45deg,#fff,#000,#fff)}",
-    );
+      ".foo { white-space: preserve collapse }",
+      ".foo{white-space:preserve collapse}",
+    ); // invalid
+
     minify_test(
-      ".foo { background: repeating-conic-gradient(black 0deg 25%, white 0deg 50%) }",
-      ".foo{background:repeating-conic-gradient(#000 0deg 25%,#fff 0deg 50%)}",
+      ".foo { white-space-collapse: preserve-breaks }",
+      ".foo{white-space-collapse:preserve-breaks}",
     );
+    minify_test(".foo { text-wrap-mode: nowrap }", ".foo{text-wrap-mode:nowrap}");
+    minify_test(".foo { text-wrap-style: balance }", ".foo{text-wrap-style:balance}");
+    minify_test(".foo { text-wrap: wrap }", ".foo{text-wrap:wrap}");
+    minify_test(".foo { text-wrap: nowrap }", ".foo{text-wrap:nowrap}");
+    minify_test(".foo { text-wrap: balance }", ".foo{text-wrap:balance}");
+    minify_test(".foo { text-wrap: wrap pretty }", ".foo{text-wrap:pretty}");
+    minify_test(".foo { text-wrap: auto wrap }", ".foo{text-wrap:wrap}");
+    minify_test(".foo { text-wrap: stable nowrap }", ".foo{text-wrap:nowrap stable}");
+    minify_test(".foo { text-wrap: wrap nowrap }", ".foo{text-wrap:wrap nowrap}"); // invalid
 
     test(
       r#"
-        .foo {
-          background: -webkit-gradient(linear, left top, left bottom, from(red), to(blue));
-          background: -webkit-linear-gradient(red, blue);
-          background: -moz-linear-gradient(red, blue);
-          background: -o-linear-gradient(red, blue);
-          background: linear-gradient(red, blue);
-        }
-      "#,
+      .foo {
+        white-space-collapse: preserve;
+        text-wrap-mode: nowrap;
+      }
+    "#,
       indoc! {r#"
-        .foo {
-          background: -webkit-gradient(linear, left top, left bottom, from(red), to(#00f));
-          background: -webkit-linear-gradient(red, #00f);
-          background: -moz-linear-gradient(red, #00f);
-          background: -o-linear-gradient(red, #00f);
-          background: linear-gradient(red, #00f);
-        }
-      "#},
+      .foo {
+        white-space: pre;
+      }
+    "#},
     );
 
-    prefix_test(
+    test(
       r#"
       .foo {
-        background: -webkit-gradient(linear, left top, left bottom, from(red), to(blue));
-        background: -webkit-linear-gradient(red, blue);
-        background: -moz-linear-gradient(red, blue);
-        background: -o-linear-gradient(red, blue);
-        background: linear-gradient(red, blue);
+        white-space: normal;
+        white-space-collapse: preserve-breaks;
       }
-      "#,
+    "#,
       indoc! {r#"
       .foo {
-        background: linear-gradient(red, #00f);
+        white-space: pre-line;
       }
-      "#},
-      Browsers {
-        chrome: Some(95 << 16),
-        ..Browsers::default()
-      },
+    "#},
     );
-    prefix_test(
+
+    test(
       r#"
       .foo {
-        background: -webkit-gradient(linear, left top, left bottom, from(red), to(blue));
-        background: -webkit-linear-gradient(red, blue);
-        background: -moz-linear-gradient(red, blue);
-        background: -o-linear-gradient(red, blue);
+        text-wrap-mode: nowrap;
+        text-wrap-style: balance;
       }
-      "#,
+    "#,
       indoc! {r#"
       .foo {
-        background: -webkit-gradient(linear, left top, left bottom, from(red), to(#00f));
-        background: -webkit-linear-gradient(red, #00f);
-        background: -moz-linear-gradient(red, #00f);
-        background: -o-linear-gradient(red, #00f);
+        text-wrap: nowrap balance;
       }
-      "#},
-      Browsers {
-        chrome: Some(95 << 16),
-        ..Browsers::default()
-      },
+    "#},
+    );
+
+    test(
+      r#"
+      .foo {
+        white-space: pre-wrap;
+        text-wrap: balance;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        white-space: pre-wrap;
+        text-wrap: balance;
+      }
+    "#},
     );
-    prefix_test(
+
+    test(
       r#"
       .foo {
-        background-image: linear-gradient(red, blue);
+        white-space: pre;
+        text-wrap-style: pretty;
       }
-      "#,
+    "#,
       indoc! {r#"
       .foo {
-        background-image: -webkit-gradient(linear, 0 0, 0 100%, from(red), to(#00f));
-        background-image: -webkit-linear-gradient(red, #00f);
-        background-image: linear-gradient(red, #00f);
+        white-space: pre;
+        text-wrap-style: pretty;
       }
-      "#},
-      Browsers {
-        chrome: Some(8 << 16),
-        ..Browsers::default()
-      },
+    "#},
     );
-    prefix_test(
+
+    test(
       r#"
       .foo {
-        background-image: linear-gradient(to right, red, blue);
+        white-space-collapse: discard;
+        text-wrap-style: stable;
       }
-      "#,
+    "#,
       indoc! {r#"
       .foo {
-        background-image: -webkit-gradient(linear, 0 0, 100% 0, from(red), to(#00f));
-        background-image: -webkit-linear-gradient(right, red, #00f);
-        background-image: linear-gradient(to right, red, #00f);
+        white-space-collapse: discard;
+        text-wrap-style: stable;
       }
-      "#},
-      Browsers {
-        chrome: Some(8 << 16),
-        ..Browsers::default()
-      },
+    "#},
     );
-    prefix_test(
+
+    test(
       r#"
       .foo {
-        background-image: linear-gradient(to top, red, blue);
+        white-space: pre;
+        white-space-collapse: var(--collapse);
       }
-      "#,
+    "#,
       indoc! {r#"
       .foo {
-        background-image: -webkit-gradient(linear, 0 100%, 0 0, from(red), to(#00f));
-        background-image: -webkit-linear-gradient(top, red, #00f);
-        background-image: linear-gradient(to top, red, #00f);
+        white-space: pre;
+        white-space-collapse: var(--collapse);
       }
-      "#},
-      Browsers {
-        chrome: Some(8 << 16),
-        ..Browsers::default()
-      },
+    "#},
     );
+
     prefix_test(
-      r#"
-      .foo {
-        background-image: linear-gradient(to left, red, blue);
-      }
-      "#,
+      ".foo { white-space: nowrap; text-wrap: balance }",
       indoc! {r#"
       .foo {
-        background-image: -webkit-gradient(linear, 100% 0, 0 0, from(red), to(#00f));
-        background-image: -webkit-linear-gradient(left, red, #00f);
-        background-image: linear-gradient(to left, red, #00f);
+        white-space: nowrap;
+        text-wrap: balance;
       }
-      "#},
+    "#},
       Browsers {
-        chrome: Some(8 << 16),
+        chrome: Some(100 << 16),
         ..Browsers::default()
       },
     );
+
     prefix_test(
-      r#"
-      .foo {
-        background-image: linear-gradient(to left bottom, red, blue);
-      }
-      "#,
+      ".foo { white-space: pre; white-space-collapse: preserve-breaks }",
       indoc! {r#"
       .foo {
-        background-image: -webkit-gradient(linear, 100% 0, 0 100%, from(red), to(#00f));
-        background-image: -webkit-linear-gradient(bottom left, red, #00f);
-        background-image: linear-gradient(to bottom left, red, #00f);
+        white-space: pre;
+        white-space-collapse: preserve-breaks;
       }
-      "#},
+    "#},
       Browsers {
-        chrome: Some(8 << 16),
+        chrome: Some(100 << 16),
         ..Browsers::default()
       },
     );
+
     prefix_test(
-      r#"
-      .foo {
-        background-image: linear-gradient(to top right, red, blue);
-      }
-      "#,
+      ".foo { white-space-collapse: preserve-breaks; text-wrap-mode: nowrap }",
       indoc! {r#"
       .foo {
-        background-image: -webkit-gradient(linear, 0 100%, 100% 0, from(red), to(#00f));
-        background-image: -webkit-linear-gradient(top right, red, #00f);
-        background-image: linear-gradient(to top right, red, #00f);
+        white-space-collapse: preserve-breaks;
+        text-wrap-mode: nowrap;
       }
-      "#},
+    "#},
       Browsers {
-        chrome: Some(8 << 16),
+        chrome: Some(100 << 16),
         ..Browsers::default()
       },
     );
+
     prefix_test(
-      r#"
-      .foo {
-        background-image: linear-gradient(90deg, red, blue);
-      }
-      "#,
+      ".foo { white-space-collapse: preserve; text-wrap-mode: nowrap }",
       indoc! {r#"
       .foo {
-        background-image: -webkit-gradient(linear, 0 0, 100% 0, from(red), to(#00f));
-        background-image: -webkit-linear-gradient(90deg, red, #00f);
-        background-image: linear-gradient(90deg, red, #00f);
+        white-space: pre;
       }
-      "#},
+    "#},
       Browsers {
-        chrome: Some(8 << 16),
+        chrome: Some(100 << 16),
         ..Browsers::default()
       },
     );
+
     prefix_test(
-      r#"
-      .foo {
-        background-image: linear-gradient(45deg, red, blue);
-      }
-      "#,
+      ".foo { white-space: pre; text-wrap-mode: wrap }",
       indoc! {r#"
       .foo {
-        background-image: -webkit-linear-gradient(45deg, red, #00f);
-        background-image: linear-gradient(45deg, red, #00f);
+        white-space: pre-wrap;
       }
-      "#},
+    "#},
       Browsers {
-        chrome: Some(8 << 16),
+        chrome: Some(100 << 16),
         ..Browsers::default()
       },
     );
+
     prefix_test(
-      r#"
-      .foo {
-        background-image: linear-gradient(red, blue);
-      }
-      "#,
+      ".foo { white-space-collapse: break-spaces; text-wrap-mode: wrap }",
       indoc! {r#"
       .foo {
-        background-image: -webkit-linear-gradient(red, #00f);
-        background-image: linear-gradient(red, #00f);
+        white-space: break-spaces;
       }
-      "#},
+    "#},
       Browsers {
-        chrome: Some(10 << 16),
+        chrome: Some(100 << 16),
         ..Browsers::default()
       },
     );
+
     prefix_test(
-      r#"
-      .foo {
-        background-image: radial-gradient(20px, red, blue);
-      }
-      "#,
+      ".foo { white-space: pre; white-space: normal; text-wrap-style: pretty }",
       indoc! {r#"
       .foo {
-        background-image: -webkit-gradient(radial, center center, 0, center center, 20, from(red), to(#00f));
-        background-image: -webkit-radial-gradient(20px, red, #00f);
-        background-image: radial-gradient(20px, red, #00f);
+        white-space: normal;
+        text-wrap-style: pretty;
       }
-      "#},
+    "#},
       Browsers {
-        chrome: Some(8 << 16),
+        chrome: Some(100 << 16),
         ..Browsers::default()
       },
     );
+
     prefix_test(
-      r#"
-      .foo {
-        background-image: radial-gradient(20px at top left, red, blue);
-      }
-      "#,
+      ".foo { white-space: pre; white-space-collapse: preserve-breaks }",
       indoc! {r#"
       .foo {
-        background-image: -webkit-gradient(radial, left top, 0, left top, 20, from(red), to(#00f));
-        background-image: -webkit-radial-gradient(20px at 0 0, red, #00f);
-        background-image: radial-gradient(20px at 0 0, red, #00f);
+        white-space: preserve-breaks nowrap;
       }
-      "#},
+    "#},
       Browsers {
-        chrome: Some(8 << 16),
+        chrome: Some(130 << 16),
         ..Browsers::default()
       },
     );
+  }
+
+  #[test]
+  fn test_tab_size() {
+    minify_test(".foo { tab-size: 8 }", ".foo{tab-size:8}");
+    minify_test(".foo { tab-size: 4px }", ".foo{tab-size:4px}");
+    minify_test(".foo { -moz-tab-size: 4px }", ".foo{-moz-tab-size:4px}");
+    minify_test(".foo { -o-tab-size: 4px }", ".foo{-o-tab-size:4px}");
     prefix_test(
-      r#"
-      .foo {
-        background-image: radial-gradient(red, blue);
-      }
-      "#,
+      ".foo{ tab-size: 4 }",
       indoc! {r#"
       .foo {
-        background-image: -webkit-radial-gradient(red, #00f);
-        background-image: radial-gradient(red, #00f);
+        -moz-tab-size: 4;
+        -o-tab-size: 4;
+        tab-size: 4;
       }
       "#},
       Browsers {
-        chrome: Some(8 << 16),
+        safari: Some(8 << 16),
+        firefox: Some(50 << 16),
+        opera: Some(12 << 16),
         ..Browsers::default()
       },
     );
     prefix_test(
       r#"
       .foo {
-        background-image: -webkit-gradient(radial, left top, 0, left top, 20, from(red), to(#00f));
-        background-image: -webkit-radial-gradient(20px at 0% 0%, red, #00f);
-        background-image: radial-gradient(20px at 0% 0%, red, #00f);
+        -moz-tab-size: 4;
+        -o-tab-size: 4;
+        tab-size: 4;
       }
       "#,
       indoc! {r#"
       .foo {
-        background-image: radial-gradient(20px at 0 0, red, #00f);
+        tab-size: 4;
       }
       "#},
       Browsers {
-        chrome: Some(30 << 16),
+        safari: Some(8 << 16),
+        firefox: Some(94 << 16),
+        opera: Some(30 << 16),
         ..Browsers::default()
       },
     );
     prefix_test(
-      r#"
-      .foo {
-        background: -webkit-gradient(radial, left top, 0, left top, 20, from(red), to(#00f));
-        background: -webkit-radial-gradient(20px at 0% 0%, red, #00f);
-        background: radial-gradient(20px at 0% 0%, red, #00f);
-      }
-      "#,
+      ".foo{ tab-size: 2em }",
       indoc! {r#"
       .foo {
-        background: radial-gradient(20px at 0 0, red, #00f);
+        -moz-tab-size: 2em;
+        tab-size: 2em;
       }
       "#},
       Browsers {
-        chrome: Some(30 << 16),
+        firefox: Some(90 << 16),
         ..Browsers::default()
       },
     );
     prefix_test(
-      r#"
-      .foo {
-        background: radial-gradient(red, blue);
-      }
-      "#,
+      ".foo{ tab-size: 2em }",
       indoc! {r#"
       .foo {
-        background: -webkit-radial-gradient(red, #00f);
-        background: radial-gradient(red, #00f);
+        tab-size: 2em;
       }
       "#},
       Browsers {
-        chrome: Some(8 << 16),
+        firefox: Some(91 << 16),
         ..Browsers::default()
       },
     );
+  }
+
+  #[test]
+  fn test_word_break() {
+    minify_test(".foo { word-break: normal }", ".foo{word-break:normal}");
+    minify_test(".foo { word-break: keep-all }", ".foo{word-break:keep-all}");
+    minify_test(".foo { word-break: break-all }", ".foo{word-break:break-all}");
+    minify_test(".foo { word-break: break-word }", ".foo{word-break:break-word}");
+  }
+
+  #[test]
+  fn test_line_break() {
+    minify_test(".foo { line-break: auto }", ".foo{line-break:auto}");
+    minify_test(".foo { line-break: Loose }", ".foo{line-break:loose}");
+    minify_test(".foo { line-break: anywhere }", ".foo{line-break:anywhere}");
+  }
+
+  #[test]
+  fn test_wrap() {
+    minify_test(".foo { overflow-wrap: nOrmal }", ".foo{overflow-wrap:normal}");
+    minify_test(".foo { overflow-wrap: break-Word }", ".foo{overflow-wrap:break-word}");
+    minify_test(".foo { overflow-wrap: Anywhere }", ".foo{overflow-wrap:anywhere}");
+    minify_test(".foo { word-wrap: Normal }", ".foo{word-wrap:normal}");
+    minify_test(".foo { word-wrap: Break-wOrd }", ".foo{word-wrap:break-word}");
+    minify_test(".foo { word-wrap: Anywhere }", ".foo{word-wrap:anywhere}");
+  }
+
+  #[test]
+  fn test_hyphens() {
+    minify_test(".foo { hyphens: manual }", ".foo{hyphens:manual}");
+    minify_test(".foo { hyphens: auto }", ".foo{hyphens:auto}");
+    minify_test(".foo { hyphens: none }", ".foo{hyphens:none}");
+    minify_test(".foo { -webkit-hyphens: manual }", ".foo{-webkit-hyphens:manual}");
+    minify_test(".foo { -moz-hyphens: manual }", ".foo{-moz-hyphens:manual}");
+    minify_test(".foo { -ms-hyphens: manual }", ".foo{-ms-hyphens:manual}");
     prefix_test(
-      r#"
-      .foo {
-        background: radial-gradient(red, blue), linear-gradient(yellow, red), url(bg.jpg);
-      }
-      "#,
+      ".foo{ hyphens: manual }",
       indoc! {r#"
       .foo {
-        background: -webkit-gradient(linear, 0 0, 0 100%, from(#ff0), to(red)), url("bg.jpg");
-        background: -webkit-radial-gradient(red, #00f), -webkit-linear-gradient(#ff0, red), url("bg.jpg");
-        background: -moz-radial-gradient(red, #00f), -moz-linear-gradient(#ff0, red), url("bg.jpg");
-        background: -o-radial-gradient(red, #00f), -o-linear-gradient(#ff0, red), url("bg.jpg");
-        background: radial-gradient(red, #00f), linear-gradient(#ff0, red), url("bg.jpg");
+        -webkit-hyphens: manual;
+        -moz-hyphens: manual;
+        -ms-hyphens: manual;
+        hyphens: manual;
       }
       "#},
       Browsers {
-        chrome: Some(8 << 16),
-        firefox: Some(4 << 16),
-        opera: Some(11 << 16 | 5 << 8),
+        safari: Some(14 << 16),
+        firefox: Some(40 << 16),
+        ie: Some(10 << 16),
         ..Browsers::default()
       },
     );
-
     prefix_test(
       r#"
       .foo {
-        background: linear-gradient(yellow, red 30% 40%, blue);
+        -webkit-hyphens: manual;
+        -moz-hyphens: manual;
+        -ms-hyphens: manual;
+        hyphens: manual;
       }
       "#,
       indoc! {r#"
       .foo {
-        background: linear-gradient(#ff0, red 30%, red 40%, #00f);
+        -webkit-hyphens: manual;
+        hyphens: manual;
       }
       "#},
       Browsers {
-        chrome: Some(70 << 16),
+        safari: Some(14 << 16),
+        chrome: Some(88 << 16),
+        firefox: Some(88 << 16),
+        edge: Some(79 << 16),
         ..Browsers::default()
       },
     );
-
     prefix_test(
       r#"
       .foo {
-        background: linear-gradient(yellow, red 30% 40%, blue);
+        -webkit-hyphens: manual;
+        -moz-hyphens: manual;
+        -ms-hyphens: manual;
+        hyphens: manual;
       }
       "#,
       indoc! {r#"
       .foo {
-        background: linear-gradient(#ff0, red 30% 40%, #00f);
+        hyphens: manual;
       }
       "#},
       Browsers {
-        chrome: Some(71 << 16),
+        chrome: Some(88 << 16),
+        firefox: Some(88 << 16),
+        edge: Some(79 << 16),
         ..Browsers::default()
       },
     );
+  }
 
-    prefix_test(
-      ".foo { background: linear-gradient(lch(56.208% 136.76 46.312), lch(51% 135.366 301.364)) }",
-      indoc! { r#"
-        .foo {
-          background: linear-gradient(#ff0f0e, #7773ff);
-          background: linear-gradient(lch(56.208% 136.76 46.312), lch(51% 135.366 301.364));
-        }
-      "#},
-      Browsers {
-        chrome: Some(90 << 16),
-        ..Browsers::default()
-      },
+  #[test]
+  fn test_hyphenate() {
+    minify_test(".foo { hyphenate-character: auto }", ".foo{hyphenate-character:auto}");
+    minify_test(".foo { hyphenate-character: \"=\" }", ".foo{hyphenate-character:\"=\"}");
+    minify_test(
+      ".foo { -webkit-hyphenate-character: \"-\" }",
+      ".foo{-webkit-hyphenate-character:\"-\"}",
     );
-
+    minify_test(".foo { hyphenate-character: none }", ".foo{hyphenate-character:none}"); // invalid
     prefix_test(
-      ".foo { background: linear-gradient(lch(56.208% 136.76 46.312), lch(51% 135.366 301.364)) }",
-      indoc! { r#"
-        .foo {
-          background: linear-gradient(#ff0f0e, #7773ff);
-          background: linear-gradient(color(display-p3 1 .0000153435 -.00000303562), color(display-p3 .440289 .28452 1.23485));
-          background: linear-gradient(lch(56.208% 136.76 46.312), lch(51% 135.366 301.364));
-        }
+      ".foo{ hyphenate-character: \"=\" }",
+      indoc! {r#"
+      .foo {
+        -webkit-hyphenate-character: "=";
+        hyphenate-character: "=";
+      }
       "#},
       Browsers {
-        chrome: Some(90 << 16),
-        safari: Some(14 << 16),
+        safari: Some(16 << 16),
+        firefox: Some(98 << 16),
         ..Browsers::default()
       },
     );
-
     prefix_test(
-      ".foo { background: linear-gradient(lch(56.208% 136.76 46.312), lch(51% 135.366 301.364)) }",
-      indoc! { r#"
-        .foo {
-          background: -webkit-linear-gradient(#ff0f0e, #7773ff);
-          background: linear-gradient(#ff0f0e, #7773ff);
-          background: linear-gradient(lch(56.208% 136.76 46.312), lch(51% 135.366 301.364));
-        }
+      r#"
+      .foo {
+        -webkit-hyphenate-character: auto;
+        hyphenate-character: auto;
+      }
+      "#,
+      indoc! {r#"
+      .foo {
+        hyphenate-character: auto;
+      }
       "#},
       Browsers {
-        chrome: Some(20 << 16),
+        safari: Some(17 << 16),
+        chrome: Some(106 << 16),
         ..Browsers::default()
       },
     );
 
-    prefix_test(
-      ".foo { background: linear-gradient(lch(56.208% 136.76 46.312), lch(51% 135.366 301.364)) }",
-      indoc! { r#"
-        .foo {
-          background: -webkit-gradient(linear, 0 0, 0 100%, from(#ff0f0e), to(#7773ff));
-          background: -webkit-linear-gradient(#ff0f0e, #7773ff);
-          background: linear-gradient(#ff0f0e, #7773ff);
-          background: linear-gradient(lch(56.208% 136.76 46.312), lch(51% 135.366 301.364));
-        }
-      "#},
-      Browsers {
-        chrome: Some(8 << 16),
-        ..Browsers::default()
-      },
+    minify_test(
+      ".foo { hyphenate-limit-chars: auto }",
+      ".foo{hyphenate-limit-chars:auto}",
+    );
+    minify_test(
+      ".foo { hyphenate-limit-chars: auto auto }",
+      ".foo{hyphenate-limit-chars:auto}",
+    );
+    minify_test(
+      ".foo { hyphenate-limit-chars: auto auto auto }",
+      ".foo{hyphenate-limit-chars:auto}",
+    );
+    minify_test(".foo { hyphenate-limit-chars: 5 }", ".foo{hyphenate-limit-chars:5}");
+    minify_test(".foo { hyphenate-limit-chars: 5 5 }", ".foo{hyphenate-limit-chars:5 5}");
+    minify_test(
+      ".foo { hyphenate-limit-chars: 5 5 5 }",
+      ".foo{hyphenate-limit-chars:5 5}",
+    );
+    minify_test(
+      ".foo { hyphenate-limit-chars: 5 5 auto }",
+      ".foo{hyphenate-limit-chars:5 5 auto}",
+    );
+    minify_test(
+      ".foo { hyphenate-limit-chars: 5 auto }",
+      ".foo{hyphenate-limit-chars:5}",
+    );
+    minify_test(
+      ".foo { hyphenate-limit-chars: 5 auto auto }",
+      ".foo{hyphenate-limit-chars:5}",
+    );
+    minify_test(
+      ".foo { hyphenate-limit-chars: 5 2 auto }",
+      ".foo{hyphenate-limit-chars:5 2 auto}",
+    );
+    minify_test(
+      ".foo { hyphenate-limit-chars: auto 2 }",
+      ".foo{hyphenate-limit-chars:auto 2}",
+    );
+    minify_test(
+      ".foo { hyphenate-limit-chars: 6 2 3 }",
+      ".foo{hyphenate-limit-chars:6 2 3}",
+    );
+    minify_test(
+      ".foo { hyphenate-limit-chars: 6 3 3 }",
+      ".foo{hyphenate-limit-chars:6 3}",
+    );
+    minify_test(
+      ".foo { hyphenate-limit-chars: 6 auto 3 }",
+      ".foo{hyphenate-limit-chars:6 auto 3}",
     );
+    minify_test(".foo { hyphenate-limit-chars: 0 }", ".foo{hyphenate-limit-chars:0}"); // invalid
+    minify_test(
+      ".foo { hyphenate-limit-chars: 1 2 3 4 }",
+      ".foo{hyphenate-limit-chars:1 2 3 4}",
+    ); // invalid
+  }
+
+  #[test]
+  fn test_text_align() {
+    minify_test(".foo { text-align: left }", ".foo{text-align:left}");
+    minify_test(".foo { text-align: Left }", ".foo{text-align:left}");
+    minify_test(".foo { text-align: END }", ".foo{text-align:end}");
+    minify_test(".foo { text-align: left }", ".foo{text-align:left}");
 
     prefix_test(
-      ".foo { background: linear-gradient(lch(56.208% 136.76 46.312), lch(51% 135.366 301.364)) }",
-      indoc! { r#"
-        .foo {
-          background: linear-gradient(lch(56.208% 136.76 46.312), lch(51% 135.366 301.364));
-        }
-      "#},
+      r#"
+      .foo {
+        text-align: start;
+      }
+    "#,
+      indoc! {r#"
+      .foo:not(:is(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi))) {
+        text-align: left;
+      }
+
+      .foo:is(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi)) {
+        text-align: right;
+      }
+    "#
+      },
       Browsers {
-        safari: Some(15 << 16),
+        safari: Some(2 << 16),
         ..Browsers::default()
       },
     );
 
     prefix_test(
-      ".foo { background-image: linear-gradient(oklab(59.686% 0.1009 0.1192), oklab(54.0% -0.10 -0.02)); }",
-      indoc! { r#"
-        .foo {
-          background-image: linear-gradient(lab(52.2319% 40.1449 59.9171), lab(47.7776% -34.2947 -7.65904));
-        }
-      "#},
+      r#"
+      .foo {
+        text-align: end;
+      }
+    "#,
+      indoc! {r#"
+      .foo:not(:is(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi))) {
+        text-align: right;
+      }
+
+      .foo:is(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi)) {
+        text-align: left;
+      }
+    "#
+      },
       Browsers {
-        safari: Some(15 << 16),
+        safari: Some(2 << 16),
         ..Browsers::default()
       },
     );
 
     prefix_test(
-      ".foo { background-image: linear-gradient(lch(56.208% 136.76 46.312), lch(51% 135.366 301.364)) }",
-      indoc! { r#"
-        .foo {
-          background-image: linear-gradient(#ff0f0e, #7773ff);
-          background-image: linear-gradient(lch(56.208% 136.76 46.312), lch(51% 135.366 301.364));
-        }
-      "#},
+      r#"
+      .foo {
+        text-align: start;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        text-align: start;
+      }
+    "#
+      },
       Browsers {
-        chrome: Some(90 << 16),
+        safari: Some(14 << 16),
         ..Browsers::default()
       },
     );
 
     prefix_test(
-      ".foo { background-image: linear-gradient(lch(56.208% 136.76 46.312), lch(51% 135.366 301.364)) }",
-      indoc! { r#"
-        .foo {
-          background-image: linear-gradient(#ff0f0e, #7773ff);
-          background-image: linear-gradient(color(display-p3 1 .0000153435 -.00000303562), color(display-p3 .440289 .28452 1.23485));
-          background-image: linear-gradient(lch(56.208% 136.76 46.312), lch(51% 135.366 301.364));
-        }
-      "#},
+      r#"
+      .foo > .bar {
+        text-align: start;
+      }
+    "#,
+      indoc! {r#"
+      .foo > .bar:not(:is(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi))) {
+        text-align: left;
+      }
+
+      .foo > .bar:is(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi)) {
+        text-align: right;
+      }
+    "#
+      },
       Browsers {
-        chrome: Some(90 << 16),
-        safari: Some(14 << 16),
+        safari: Some(2 << 16),
         ..Browsers::default()
       },
     );
 
     prefix_test(
-      ".foo { background-image: linear-gradient(lch(56.208% 136.76 46.312), lch(51% 135.366 301.364)) }",
-      indoc! { r#"
-        .foo {
-          background-image: -webkit-linear-gradient(#ff0f0e, #7773ff);
-          background-image: linear-gradient(#ff0f0e, #7773ff);
-          background-image: linear-gradient(lch(56.208% 136.76 46.312), lch(51% 135.366 301.364));
-        }
-      "#},
+      r#"
+      .foo:after {
+        text-align: start;
+      }
+    "#,
+      indoc! {r#"
+      .foo:not(:is(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi))):after {
+        text-align: left;
+      }
+
+      .foo:is(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi)):after {
+        text-align: right;
+      }
+    "#
+      },
       Browsers {
-        chrome: Some(20 << 16),
+        safari: Some(2 << 16),
         ..Browsers::default()
       },
     );
 
     prefix_test(
-      ".foo { background-image: linear-gradient(lch(56.208% 136.76 46.312), lch(51% 135.366 301.364)) }",
-      indoc! { r#"
-        .foo {
-          background-image: -webkit-gradient(linear, 0 0, 0 100%, from(#ff0f0e), to(#7773ff));
-          background-image: -webkit-linear-gradient(#ff0f0e, #7773ff);
-          background-image: linear-gradient(#ff0f0e, #7773ff);
-          background-image: linear-gradient(lch(56.208% 136.76 46.312), lch(51% 135.366 301.364));
-        }
-      "#},
+      r#"
+      .foo:hover {
+        text-align: start;
+      }
+    "#,
+      indoc! {r#"
+      .foo:hover:not(:is(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi))) {
+        text-align: left;
+      }
+
+      .foo:hover:is(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi)) {
+        text-align: right;
+      }
+    "#
+      },
       Browsers {
-        chrome: Some(8 << 16),
+        safari: Some(2 << 16),
         ..Browsers::default()
       },
     );
+  }
 
+  #[test]
+  fn test_text_align_last() {
+    minify_test(".foo { text-align-last: left }", ".foo{text-align-last:left}");
+    minify_test(".foo { text-align-last: justify }", ".foo{text-align-last:justify}");
     prefix_test(
-      ".foo { background-image: linear-gradient(lch(56.208% 136.76 46.312), lch(51% 135.366 301.364)) }",
-      indoc! { r#"
-        .foo {
-          background-image: linear-gradient(lch(56.208% 136.76 46.312), lch(51% 135.366 301.364));
-        }
+      ".foo{ text-align-last: left }",
+      indoc! {r#"
+      .foo {
+        -moz-text-align-last: left;
+        text-align-last: left;
+      }
       "#},
       Browsers {
-        safari: Some(15 << 16),
+        firefox: Some(40 << 16),
         ..Browsers::default()
       },
     );
-
     prefix_test(
-      ".foo { background-image: linear-gradient(oklab(59.686% 0.1009 0.1192), oklab(54.0% -0.10 -0.02)); }",
-      indoc! { r#"
-        .foo {
-          background-image: linear-gradient(lab(52.2319% 40.1449 59.9171), lab(47.7776% -34.2947 -7.65904));
-        }
+      r#"
+      .foo {
+        -moz-text-align-last: left;
+        text-align-last: left;
+      }
+      "#,
+      indoc! {r#"
+      .foo {
+        text-align-last: left;
+      }
       "#},
       Browsers {
-        safari: Some(15 << 16),
+        firefox: Some(88 << 16),
         ..Browsers::default()
       },
     );
   }
 
   #[test]
-  fn test_font_face() {
+  fn test_text_justify() {
+    minify_test(".foo { text-justify: auto }", ".foo{text-justify:auto}");
+    minify_test(".foo { text-justify: inter-word }", ".foo{text-justify:inter-word}");
+  }
+
+  #[test]
+  fn test_word_spacing() {
+    minify_test(".foo { word-spacing: normal }", ".foo{word-spacing:normal}");
+    minify_test(".foo { word-spacing: 3px }", ".foo{word-spacing:3px}");
+  }
+
+  #[test]
+  fn test_letter_spacing() {
+    minify_test(".foo { letter-spacing: normal }", ".foo{letter-spacing:normal}");
+    minify_test(".foo { letter-spacing: 3px }", ".foo{letter-spacing:3px}");
+  }
+
+  #[test]
+  fn test_text_indent() {
+    minify_test(".foo { text-indent: 20px }", ".foo{text-indent:20px}");
+    minify_test(".foo { text-indent: 10% }", ".foo{text-indent:10%}");
+    minify_test(".foo { text-indent: 3em hanging }", ".foo{text-indent:3em hanging}");
+    minify_test(".foo { text-indent: 3em each-line }", ".foo{text-indent:3em each-line}");
     minify_test(
-      r#"@font-face {
-      src: url("test.woff");
-      font-family: "Helvetica";
-      font-weight: bold;
-      font-style: italic;
-    }"#,
-      "@font-face{src:url(test.woff);font-family:Helvetica;font-weight:700;font-style:italic}",
+      ".foo { text-indent: 3em hanging each-line }",
+      ".foo{text-indent:3em hanging each-line}",
     );
-    minify_test("@font-face {src: url(test.woff);}", "@font-face{src:url(test.woff)}");
-    minify_test("@font-face {src: local(\"Test\");}", "@font-face{src:local(Test)}");
     minify_test(
-      "@font-face {src: local(\"Foo Bar\");}",
-      "@font-face{src:local(Foo Bar)}",
+      ".foo { text-indent: 3em each-line hanging }",
+      ".foo{text-indent:3em hanging each-line}",
     );
-    minify_test("@font-face {src: local(Test);}", "@font-face{src:local(Test)}");
-    minify_test("@font-face {src: local(Foo Bar);}", "@font-face{src:local(Foo Bar)}");
-
     minify_test(
-      "@font-face {src: url(\"test.woff\") format(woff);}",
-      "@font-face{src:url(test.woff)format(\"woff\")}",
+      ".foo { text-indent: each-line 3em hanging }",
+      ".foo{text-indent:3em hanging each-line}",
     );
     minify_test(
-      "@font-face {src: url(\"test.ttc\") format(collection), url(test.ttf) format(truetype);}",
-      "@font-face{src:url(test.ttc)format(\"collection\"),url(test.ttf)format(\"truetype\")}",
+      ".foo { text-indent: each-line hanging 3em }",
+      ".foo{text-indent:3em hanging each-line}",
     );
+  }
+
+  #[test]
+  fn test_text_size_adjust() {
+    minify_test(".foo { text-size-adjust: none }", ".foo{text-size-adjust:none}");
+    minify_test(".foo { text-size-adjust: auto }", ".foo{text-size-adjust:auto}");
+    minify_test(".foo { text-size-adjust: 80% }", ".foo{text-size-adjust:80%}");
+    minify_test(".foo { text-size-adjust: 0% }", ".foo{text-size-adjust:0%}");
+    minify_test(".foo { text-size-adjust: NONE }", ".foo{text-size-adjust:none}");
     minify_test(
-      "@font-face {src: url(\"test.otf\") format(opentype) tech(features-aat);}",
-      "@font-face{src:url(test.otf)format(\"opentype\")tech(features-aat)}",
+      ".foo { -webkit-text-size-adjust: 100% }",
+      ".foo{-webkit-text-size-adjust:100%}",
     );
-    minify_test(
-      "@font-face {src: url(\"test.woff\") format(woff) tech(color-colrv1);}",
-      "@font-face{src:url(test.woff)format(\"woff\")tech(color-colrv1)}",
+    minify_test(".foo { text-size-adjust: -10% }", ".foo{text-size-adjust:-10%}"); // invalid
+    minify_test(".foo { text-size-adjust: 10px }", ".foo{text-size-adjust:10px}"); // invalid
+    prefix_test(
+      r#"
+      .foo {
+        text-size-adjust: none;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        -webkit-text-size-adjust: none;
+        -moz-text-size-adjust: none;
+        -ms-text-size-adjust: none;
+        text-size-adjust: none;
+      }
+    "#},
+      Browsers {
+        ios_saf: Some(16 << 16),
+        edge: Some(15 << 16),
+        firefox: Some(20 << 16),
+        ..Browsers::default()
+      },
     );
-    minify_test(
-      "@font-face {src: url(\"test.woff2\") format(woff2) tech(variations);}",
-      "@font-face{src:url(test.woff2)format(\"woff2\")tech(variations)}",
+    prefix_test(
+      r#"
+      .foo {
+        -webkit-text-size-adjust: none;
+        -moz-text-size-adjust: none;
+        -ms-text-size-adjust: none;
+        text-size-adjust: none;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        text-size-adjust: none;
+      }
+    "#},
+      Browsers {
+        chrome: Some(110 << 16),
+        ..Browsers::default()
+      },
     );
-    minify_test(
-      "@font-face {src: url(\"test.woff\") format(woff) tech(palettes);}",
-      "@font-face{src:url(test.woff)format(\"woff\")tech(palettes)}",
+    prefix_test(
+      r#"
+      .foo {
+        text-size-adjust: 120%;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        -webkit-text-size-adjust: 120%;
+        text-size-adjust: 120%;
+      }
+    "#},
+      Browsers {
+        ios_saf: Some(16 << 16),
+        chrome: Some(110 << 16),
+        ..Browsers::default()
+      },
     );
-    // multiple tech
+  }
+
+  #[test]
+  fn test_writing_mode() {
     minify_test(
-      "@font-face {src: url(\"test.woff\") format(woff) tech(features-opentype, color-sbix);}",
-      "@font-face{src:url(test.woff)format(\"woff\")tech(features-opentype,color-sbix)}",
+      ".foo { writing-mode: horizontal-tb }",
+      ".foo{writing-mode:horizontal-tb}",
     );
+    minify_test(".foo { writing-mode: vertical-rl }", ".foo{writing-mode:vertical-rl}");
+    minify_test(".foo { writing-mode: sideways-lr }", ".foo{writing-mode:sideways-lr}");
+    minify_test(".foo { writing-mode: tb-rl }", ".foo{writing-mode:tb-rl}");
     minify_test(
-      "@font-face {src: url(\"test.woff\")   format(woff)    tech(incremental, color-svg, features-graphite, features-aat);}",
-      "@font-face{src:url(test.woff)format(\"woff\")tech(incremental,color-svg,features-graphite,features-aat)}",
+      ".foo { -webkit-writing-mode: vertical-lr }",
+      ".foo{-webkit-writing-mode:vertical-lr}",
     );
-    // format() function must precede tech() if both are present
+    minify_test(".foo { direction: rtl }", ".foo{direction:rtl}");
+    minify_test(".foo { direction: LTR }", ".foo{direction:ltr}");
+    minify_test(".foo { text-orientation: upright }", ".foo{text-orientation:upright}");
     minify_test(
-      "@font-face {src: url(\"foo.ttf\") format(opentype) tech(color-colrv1);}",
-      "@font-face{src:url(foo.ttf)format(\"opentype\")tech(color-colrv1)}",
+      ".foo { text-orientation: sideways-right }",
+      ".foo{text-orientation:sideways-right}",
     );
-    // only have tech is valid
     minify_test(
-      "@font-face {src: url(\"foo.ttf\") tech(color-SVG);}",
-      "@font-face{src:url(foo.ttf)tech(color-svg)}",
+      ".foo { writing-mode: vertical-rl; writing-mode: vertical-lr }",
+      ".foo{writing-mode:vertical-lr}",
     );
-    // CGQAQ: if tech and format both presence, order is matter, tech before format is invalid
-    // but now just return raw token, we don't have strict mode yet.
-    // ref: https://github.com/parcel-bundler/lightningcss/pull/255#issuecomment-1219049998
-    minify_test(
-      "@font-face {src: url(\"foo.ttf\") tech(palettes  color-colrv0  variations) format(opentype);}",
-      "@font-face{src:url(foo.ttf) tech(palettes color-colrv0 variations)format(opentype)}",
+
+    prefix_test(
+      r#"
+      .foo {
+        writing-mode: vertical-rl;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        -ms-writing-mode: tb-rl;
+        -webkit-writing-mode: vertical-rl;
+        writing-mode: vertical-rl;
+      }
+    "#},
+      Browsers {
+        safari: Some(10 << 16),
+        ie: Some(11 << 16),
+        ..Browsers::default()
+      },
     );
-    // TODO(CGQAQ): make this test pass when we have strict mode
-    // ref: https://github.com/web-platform-tests/wpt/blob/9f8a6ccc41aa725e8f51f4f096f686313bb88d8d/css/css-fonts/parsing/font-face-src-tech.html#L45
-    // error_test(
-    //   "@font-face {src: url(\"foo.ttf\") tech(features-opentype) format(opentype);}",
-    //   ParserError::AtRuleBodyInvalid,
-    // );
-    // error_test(
-    //   "@font-face {src: url(\"foo.ttf\") tech();}",
-    //   ParserError::AtRuleBodyInvalid,
-    // );
-    // error_test(
-    //   "@font-face {src: url(\"foo.ttf\") tech(\"features-opentype\");}",
-    //   ParserError::AtRuleBodyInvalid,
-    // );
-    // error_test(
-    //   "@font-face {src: url(\"foo.ttf\") tech(\"color-colrv0\");}",
-    //   ParserError::AtRuleBodyInvalid,
-    // );
-    minify_test(
-      "@font-face {src: local(\"\") url(\"test.woff\");}",
-      "@font-face{src:local(\"\")url(test.woff)}",
+    prefix_test(
+      r#"
+      .foo {
+        writing-mode: horizontal-tb;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        -ms-writing-mode: lr-tb;
+        writing-mode: horizontal-tb;
+      }
+    "#},
+      Browsers {
+        ie: Some(11 << 16),
+        ..Browsers::default()
+      },
     );
-    minify_test("@font-face {font-weight: 200 400}", "@font-face{font-weight:200 400}");
-    minify_test("@font-face {font-weight: 400 400}", "@font-face{font-weight:400}");
-    minify_test(
-      "@font-face {font-stretch: 50% 200%}",
-      "@font-face{font-stretch:50% 200%}",
+    prefix_test(
+      r#"
+      .foo {
+        writing-mode: sideways-rl;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        writing-mode: sideways-rl;
+      }
+    "#},
+      Browsers {
+        ie: Some(11 << 16),
+        ..Browsers::default()
+      },
+    );
+    prefix_test(
+      r#"
+      .foo {
+        -webkit-writing-mode: vertical-lr;
+        writing-mode: vertical-lr;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        writing-mode: vertical-lr;
+      }
+    "#},
+      Browsers {
+        chrome: Some(110 << 16),
+        ..Browsers::default()
+      },
     );
-    minify_test("@font-face {font-stretch: 50% 50%}", "@font-face{font-stretch:50%}");
-    minify_test("@font-face {unicode-range: U+26;}", "@font-face{unicode-range:U+26}");
-    minify_test("@font-face {unicode-range: u+26;}", "@font-face{unicode-range:U+26}");
-    minify_test(
-      "@font-face {unicode-range: U+0-7F;}",
-      "@font-face{unicode-range:U+0-7F}",
+    prefix_test(
+      r#"
+      .foo {
+        text-orientation: upright;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        -webkit-text-orientation: upright;
+        text-orientation: upright;
+      }
+    "#},
+      Browsers {
+        safari: Some(13 << 16),
+        ..Browsers::default()
+      },
     );
-    minify_test(
-      "@font-face {unicode-range: U+0025-00FF;}",
-      "@font-face{unicode-range:U+25-FF}",
+    prefix_test(
+      r#"
+      .foo {
+        direction: rtl;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        direction: rtl;
+      }
+    "#},
+      Browsers {
+        safari: Some(8 << 16),
+        ..Browsers::default()
+      },
     );
-    minify_test("@font-face {unicode-range: U+4??;}", "@font-face{unicode-range:U+4??}");
+  }
+
+  #[test]
+  fn test_unicode_bidi() {
+    minify_test(".foo { unicode-bidi: normal }", ".foo{unicode-bidi:normal}");
+    minify_test(".foo { unicode-bidi: embed }", ".foo{unicode-bidi:embed}");
+    minify_test(".foo { unicode-bidi: isolate }", ".foo{unicode-bidi:isolate}");
     minify_test(
-      "@font-face {unicode-range: U+400-4FF;}",
-      "@font-face{unicode-range:U+4??}",
+      ".foo { unicode-bidi: BIDI-OVERRIDE }",
+      ".foo{unicode-bidi:bidi-override}",
     );
     minify_test(
-      "@font-face {unicode-range: U+0025-00FF, U+4??;}",
-      "@font-face{unicode-range:U+25-FF,U+4??}",
+      ".foo { unicode-bidi: isolate-override }",
+      ".foo{unicode-bidi:isolate-override}",
     );
+    minify_test(".foo { unicode-bidi: plaintext }", ".foo{unicode-bidi:plaintext}");
     minify_test(
-      "@font-face {unicode-range: U+A5, U+4E00-9FFF, U+30??, U+FF00-FF9F;}",
-      "@font-face{unicode-range:U+A5,U+4E00-9FFF,U+30??,U+FF00-FF9F}",
+      ".foo { unicode-bidi: -webkit-isolate }",
+      ".foo{unicode-bidi:-webkit-isolate}",
     );
     minify_test(
-      "@font-face {unicode-range: U+????;}",
-      "@font-face{unicode-range:U+????}",
+      ".foo { unicode-bidi: -moz-plaintext }",
+      ".foo{unicode-bidi:-moz-plaintext}",
     );
     minify_test(
-      "@font-face {unicode-range: U+0000-FFFF;}",
-      "@font-face{unicode-range:U+????}",
+      ".foo { unicode-bidi: -webkit-isolate; unicode-bidi: isolate }",
+      ".foo{unicode-bidi:-webkit-isolate;unicode-bidi:isolate}",
     );
     minify_test(
-      "@font-face {unicode-range: U+10????;}",
-      "@font-face{unicode-range:U+10????}",
+      ".foo { unicode-bidi: -moz-isolate; unicode-bidi: -webkit-isolate; unicode-bidi: isolate }",
+      ".foo{unicode-bidi:-webkit-isolate;unicode-bidi:-moz-isolate;unicode-bidi:isolate}",
     );
     minify_test(
-      "@font-face {unicode-range: U+100000-10FFFF;}",
-      "@font-face{unicode-range:U+10????}",
+      ".foo { unicode-bidi: embed; unicode-bidi: plaintext }",
+      ".foo{unicode-bidi:plaintext}",
     );
     minify_test(
-      "@font-face {unicode-range: U+1e1e?;}",
-      "@font-face{unicode-range:U+1E1E?}",
+      ".foo { unicode-bidi: -webkit-isolate; unicode-bidi: embed }",
+      ".foo{unicode-bidi:embed}",
     );
+    minify_test(".foo { unicode-bidi: -o-isolate }", ".foo{unicode-bidi:-o-isolate}"); // invalid
     minify_test(
-      "@font-face {unicode-range: u+????, U+1????, U+10????;}",
-      "@font-face{unicode-range:U+????,U+1????,U+10????}",
+      ".foo { unicode-bidi: isolate embed }",
+      ".foo{unicode-bidi:isolate embed}",
+    ); // invalid
+
+    prefix_test(
+      r#"
+      .foo {
+        unicode-bidi: isolate;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        unicode-bidi: -webkit-isolate;
+        unicode-bidi: -moz-isolate;
+        unicode-bidi: isolate;
+      }
+    "#},
+      Browsers {
+        safari: Some(8 << 16),
+        firefox: Some(40 << 16),
+        ..Browsers::default()
+      },
     );
-    minify_test(r#"
-      @font-face {
-        font-family: Inter;
-        font-style: oblique 0deg 10deg;
-        font-weight: 100 900;
-        src: url("../fonts/Inter.var.woff2?v=3.19") format("woff2");
-        font-display: swap;
+    prefix_test(
+      r#"
+      .foo {
+        unicode-bidi: plaintext;
       }
-    "#, "@font-face{font-family:Inter;font-style:oblique 0deg 10deg;font-weight:100 900;src:url(../fonts/Inter.var.woff2?v=3.19)format(\"woff2\");font-display:swap}");
-    minify_test(r#"
-    @font-face {
-      font-family: Inter;
-      font-style: oblique 14deg 14deg;
-      font-weight: 100 900;
-      src: url("../fonts/Inter.var.woff2?v=3.19") format("woff2");
-      font-display: swap;
-    }
-  "#, "@font-face{font-family:Inter;font-style:oblique;font-weight:100 900;src:url(../fonts/Inter.var.woff2?v=3.19)format(\"woff2\");font-display:swap}");
-  }
-
-  #[test]
-  fn test_font_palette_values() {
-    minify_test(
-      r#"@font-palette-values --Cooler {
-      font-family: Bixa;
-      base-palette: 1;
-      override-colors: 1 #7EB7E4;
-    }"#,
-      "@font-palette-values --Cooler{font-family:Bixa;base-palette:1;override-colors:1 #7eb7e4}",
+    "#,
+      indoc! {r#"
+      .foo {
+        unicode-bidi: -webkit-plaintext;
+        unicode-bidi: plaintext;
+      }
+    "#},
+      Browsers {
+        safari: Some(9 << 16),
+        chrome: Some(40 << 16),
+        ..Browsers::default()
+      },
     );
-    minify_test(
-      r#"@font-palette-values --Cooler {
-      font-family: Handover Sans;
-      base-palette: 3;
-      override-colors: 1 rgb(43, 12, 9), 3 lime;
-    }"#,
-      "@font-palette-values --Cooler{font-family:Handover Sans;base-palette:3;override-colors:1 #2b0c09,3 #0f0}",
+    prefix_test(
+      r#"
+      .foo {
+        unicode-bidi: isolate-override;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        unicode-bidi: -moz-isolate-override;
+        unicode-bidi: isolate-override;
+      }
+    "#},
+      Browsers {
+        firefox: Some(45 << 16),
+        ..Browsers::default()
+      },
     );
-    minify_test(r#"@font-palette-values --Cooler {
-      font-family: Handover Sans;
-      base-palette: 3;
-      override-colors: 1 rgb(43, 12, 9), 3 var(--highlight);
-    }"#, "@font-palette-values --Cooler{font-family:Handover Sans;base-palette:3;override-colors:1 #2b0c09,3 var(--highlight)}");
     prefix_test(
-      r#"@font-palette-values --Cooler {
-      font-family: Handover Sans;
-      base-palette: 3;
-      override-colors: 1 rgb(43, 12, 9), 3 lch(50.998% 135.363 338);
-    }"#,
-      indoc! {r#"@font-palette-values --Cooler {
-      font-family: Handover Sans;
-      base-palette: 3;
-      override-colors: 1 #2b0c09, 3 #ee00be;
-      override-colors: 1 #2b0c09, 3 lch(50.998% 135.363 338);
-    }
+      r#"
+      .foo {
+        unicode-bidi: embed;
+        unicode-bidi: isolate;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        unicode-bidi: embed;
+        unicode-bidi: isolate;
+      }
     "#},
       Browsers {
-        chrome: Some(90 << 16),
+        ie: Some(11 << 16),
         ..Browsers::default()
       },
     );
     prefix_test(
-      r#"@font-palette-values --Cooler {
-      font-family: Handover Sans;
-      base-palette: 3;
-      override-colors: 1 var(--foo), 3 lch(50.998% 135.363 338);
-    }"#,
-      indoc! {r#"@font-palette-values --Cooler {
-      font-family: Handover Sans;
-      base-palette: 3;
-      override-colors: 1 var(--foo), 3 #ee00be;
-    }
-
-    @supports (color: lab(0% 0 0)) {
-      @font-palette-values --Cooler {
-        font-family: Handover Sans;
-        base-palette: 3;
-        override-colors: 1 var(--foo), 3 lab(50.998% 125.506 -50.7078);
+      r#"
+      .foo {
+        unicode-bidi: embed;
+        unicode-bidi: isolate;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        unicode-bidi: isolate;
       }
-    }
     "#},
       Browsers {
         chrome: Some(90 << 16),
         ..Browsers::default()
       },
     );
-    minify_test(".foo { font-palette: --Custom; }", ".foo{font-palette:--Custom}");
-  }
-
-  #[test]
-  fn test_page_rule() {
-    minify_test("@page {margin: 0.5cm}", "@page{margin:.5cm}");
-    minify_test("@page :left {margin: 0.5cm}", "@page:left{margin:.5cm}");
-    minify_test("@page :right {margin: 0.5cm}", "@page:right{margin:.5cm}");
+    prefix_test(
+      r#"
+      .foo {
+        unicode-bidi: -webkit-isolate;
+        unicode-bidi: -moz-isolate;
+        unicode-bidi: isolate;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        unicode-bidi: isolate;
+      }
+    "#},
+      Browsers {
+        safari: Some(14 << 16),
+        firefox: Some(90 << 16),
+        ..Browsers::default()
+      },
+    );
+    prefix_test(
+      r#"
+      .foo {
+        unicode-bidi: -webkit-plaintext;
+        unicode-bidi: plaintext;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        unicode-bidi: -webkit-plaintext;
+        unicode-bidi: plaintext;
+      }
+    "#},
+      Browsers {
+        safari: Some(9 << 16),
+        ..Browsers::default()
+      },
+    );
+    prefix_test(
+      r#"
+      .foo {
+        unicode-bidi: bidi-override;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        unicode-bidi: bidi-override;
+      }
+    "#},
+      Browsers {
+        safari: Some(8 << 16),
+        firefox: Some(40 << 16),
+        ..Browsers::default()
+      },
+    );
+  }
+
+  #[test]
+  fn test_text_decoration() {
+    minify_test(".foo { text-decoration-line: none }", ".foo{text-decoration-line:none}");
+    minify_test(
+      ".foo { text-decoration-line: underline }",
+      ".foo{text-decoration-line:underline}",
+    );
+    minify_test(
+      ".foo { text-decoration-line: overline }",
+      ".foo{text-decoration-line:overline}",
+    );
+    minify_test(
+      ".foo { text-decoration-line: line-through }",
+      ".foo{text-decoration-line:line-through}",
+    );
+    minify_test(
+      ".foo { text-decoration-line: blink }",
+      ".foo{text-decoration-line:blink}",
+    );
+    minify_test(
+      ".foo { text-decoration-line: underline overline }",
+      ".foo{text-decoration-line:underline overline}",
+    );
+    minify_test(
+      ".foo { text-decoration-line: overline underline }",
+      ".foo{text-decoration-line:underline overline}",
+    );
+    minify_test(
+      ".foo { text-decoration-line: overline line-through underline }",
+      ".foo{text-decoration-line:underline overline line-through}",
+    );
     minify_test(
-      "@page LandscapeTable {margin: 0.5cm}",
-      "@page LandscapeTable{margin:.5cm}",
+      ".foo { text-decoration-line: spelling-error }",
+      ".foo{text-decoration-line:spelling-error}",
     );
     minify_test(
-      "@page CompanyLetterHead:first {margin: 0.5cm}",
-      "@page CompanyLetterHead:first{margin:.5cm}",
+      ".foo { text-decoration-line: grammar-error }",
+      ".foo{text-decoration-line:grammar-error}",
     );
-    minify_test("@page:first {margin: 0.5cm}", "@page:first{margin:.5cm}");
-    minify_test("@page :blank:first {margin: 0.5cm}", "@page:blank:first{margin:.5cm}");
-    minify_test("@page toc, index {margin: 0.5cm}", "@page toc,index{margin:.5cm}");
     minify_test(
-      r#"
-    @page :right {
-      @bottom-left {
-        margin: 10pt;
-      }
-    }
-    "#,
-      "@page:right{@bottom-left{margin:10pt}}",
+      ".foo { -webkit-text-decoration-line: overline underline }",
+      ".foo{-webkit-text-decoration-line:underline overline}",
     );
     minify_test(
-      r#"
-    @page :right {
-      margin: 1in;
-
-      @bottom-left {
-        margin: 10pt;
-      }
-    }
-    "#,
-      "@page:right{margin:1in;@bottom-left{margin:10pt}}",
+      ".foo { -moz-text-decoration-line: overline underline }",
+      ".foo{-moz-text-decoration-line:underline overline}",
     );
 
-    test(
-      r#"
-    @page :right {
-      @bottom-left {
-        margin: 10pt;
-      }
-    }
-    "#,
-      indoc! {r#"
-      @page :right {
-        @bottom-left {
-          margin: 10pt;
-        }
-      }
-      "#},
+    minify_test(
+      ".foo { text-decoration-style: solid }",
+      ".foo{text-decoration-style:solid}",
     );
-
-    test(
-      r#"
-    @page :right {
-      margin: 1in;
-
-      @bottom-left-corner { content: "Foo"; }
-      @bottom-right-corner { content: "Bar"; }
-    }
-    "#,
-      indoc! {r#"
-      @page :right {
-        margin: 1in;
-
-        @bottom-left-corner {
-          content: "Foo";
-        }
-
-        @bottom-right-corner {
-          content: "Bar";
-        }
-      }
-      "#},
+    minify_test(
+      ".foo { text-decoration-style: dotted }",
+      ".foo{text-decoration-style:dotted}",
+    );
+    minify_test(
+      ".foo { -webkit-text-decoration-style: solid }",
+      ".foo{-webkit-text-decoration-style:solid}",
     );
 
-    error_test(
-      r#"
-      @page {
-        @foo {
-          margin: 1in;
-        }
-      }
-      "#,
-      ParserError::AtRuleInvalid("foo".into()),
+    minify_test(
+      ".foo { text-decoration-color: yellow }",
+      ".foo{text-decoration-color:#ff0}",
+    );
+    minify_test(
+      ".foo { -webkit-text-decoration-color: yellow }",
+      ".foo{-webkit-text-decoration-color:#ff0}",
     );
 
-    error_test(
-      r#"
-      @page {
-        @top-left-corner {
-          @bottom-left {
-            margin: 1in;
-          }
-        }
-      }
-      "#,
-      ParserError::AtRuleInvalid("bottom-left".into()),
+    minify_test(".foo { text-decoration: none }", ".foo{text-decoration:none}");
+    minify_test(
+      ".foo { text-decoration: underline dotted }",
+      ".foo{text-decoration:underline dotted}",
+    );
+    minify_test(
+      ".foo { text-decoration: underline dotted yellow }",
+      ".foo{text-decoration:underline dotted #ff0}",
+    );
+    minify_test(
+      ".foo { text-decoration: yellow dotted underline }",
+      ".foo{text-decoration:underline dotted #ff0}",
+    );
+    minify_test(
+      ".foo { text-decoration: underline overline dotted yellow }",
+      ".foo{text-decoration:underline overline dotted #ff0}",
+    );
+    minify_test(
+      ".foo { -webkit-text-decoration: yellow dotted underline }",
+      ".foo{-webkit-text-decoration:underline dotted #ff0}",
+    );
+    minify_test(
+      ".foo { -moz-text-decoration: yellow dotted underline }",
+      ".foo{-moz-text-decoration:underline dotted #ff0}",
     );
-  }
 
-  #[test]
-  fn test_supports_rule() {
     test(
       r#"
-      @supports (foo: bar) {
-        .test {
-          foo: bar;
-        }
+      .foo {
+        text-decoration-line: underline;
+        text-decoration-style: dotted;
+        text-decoration-color: yellow;
+        text-decoration-thickness: 2px;
       }
     "#,
-      indoc! { r#"
-      @supports (foo: bar) {
-        .test {
-          foo: bar;
-        }
+      indoc! {r#"
+      .foo {
+        text-decoration: underline 2px dotted #ff0;
       }
     "#},
     );
+
     test(
       r#"
-      @supports not (foo: bar) {
-        .test {
-          foo: bar;
-        }
+      .foo {
+        text-decoration: underline;
+        text-decoration-style: dotted;
       }
     "#,
-      indoc! { r#"
-      @supports not (foo: bar) {
-        .test {
-          foo: bar;
-        }
+      indoc! {r#"
+      .foo {
+        text-decoration: underline dotted;
       }
     "#},
     );
+
     test(
       r#"
-      @supports (foo: bar) or (bar: baz) {
-        .test {
-          foo: bar;
-        }
+      .foo {
+        text-decoration: underline;
+        text-decoration-style: var(--style);
       }
     "#,
-      indoc! { r#"
-      @supports (foo: bar) or (bar: baz) {
-        .test {
-          foo: bar;
-        }
+      indoc! {r#"
+      .foo {
+        text-decoration: underline;
+        text-decoration-style: var(--style);
       }
     "#},
     );
+
     test(
       r#"
-      @supports (((foo: bar) or (bar: baz))) {
-        .test {
-          foo: bar;
-        }
+      .foo {
+        -webkit-text-decoration: underline;
+        -webkit-text-decoration-style: dotted;
       }
     "#,
-      indoc! { r#"
-      @supports (foo: bar) or (bar: baz) {
-        .test {
-          foo: bar;
-        }
+      indoc! {r#"
+      .foo {
+        -webkit-text-decoration: underline dotted;
       }
     "#},
     );
-    test(
+
+    prefix_test(
       r#"
-      @supports (foo: bar) and (bar: baz) {
-        .test {
-          foo: bar;
-        }
+      .foo {
+        text-decoration: underline dotted;
       }
     "#,
-      indoc! { r#"
-      @supports (foo: bar) and (bar: baz) {
-        .test {
-          foo: bar;
-        }
+      indoc! {r#"
+      .foo {
+        -webkit-text-decoration: underline dotted;
+        text-decoration: underline dotted;
       }
     "#},
+      Browsers {
+        safari: Some(8 << 16),
+        firefox: Some(30 << 16),
+        ..Browsers::default()
+      },
     );
-    test(
+
+    prefix_test(
       r#"
-      @supports (((foo: bar) and (bar: baz))) {
-        .test {
-          foo: bar;
-        }
+      .foo {
+        text-decoration-line: underline;
       }
     "#,
-      indoc! { r#"
-      @supports (foo: bar) and (bar: baz) {
-        .test {
-          foo: bar;
-        }
+      indoc! {r#"
+      .foo {
+        -webkit-text-decoration-line: underline;
+        -moz-text-decoration-line: underline;
+        text-decoration-line: underline;
       }
     "#},
+      Browsers {
+        safari: Some(8 << 16),
+        firefox: Some(30 << 16),
+        ..Browsers::default()
+      },
     );
-    test(
-      r#"
-      @supports (foo: bar) and (((bar: baz) or (test: foo))) {
-        .test {
-          foo: bar;
-        }
+
+    prefix_test(
+      r#"
+      .foo {
+        text-decoration-style: dotted;
       }
     "#,
-      indoc! { r#"
-      @supports (foo: bar) and ((bar: baz) or (test: foo)) {
-        .test {
-          foo: bar;
-        }
+      indoc! {r#"
+      .foo {
+        -webkit-text-decoration-style: dotted;
+        -moz-text-decoration-style: dotted;
+        text-decoration-style: dotted;
       }
     "#},
+      Browsers {
+        safari: Some(8 << 16),
+        firefox: Some(30 << 16),
+        ..Browsers::default()
+      },
     );
-    test(
+
+    prefix_test(
       r#"
-      @supports not (((foo: bar) and (bar: baz))) {
-        .test {
-          foo: bar;
-        }
+      .foo {
+        text-decoration-color: yellow;
       }
     "#,
-      indoc! { r#"
-      @supports not ((foo: bar) and (bar: baz)) {
-        .test {
-          foo: bar;
-        }
+      indoc! {r#"
+      .foo {
+        -webkit-text-decoration-color: #ff0;
+        -moz-text-decoration-color: #ff0;
+        text-decoration-color: #ff0;
       }
     "#},
+      Browsers {
+        safari: Some(8 << 16),
+        firefox: Some(30 << 16),
+        ..Browsers::default()
+      },
     );
-    test(
+
+    prefix_test(
       r#"
-      @supports selector(a > b) {
-        .test {
-          foo: bar;
-        }
+      .foo {
+        text-decoration: underline;
       }
     "#,
-      indoc! { r#"
-      @supports selector(a > b) {
-        .test {
-          foo: bar;
-        }
+      indoc! {r#"
+      .foo {
+        text-decoration: underline;
       }
     "#},
+      Browsers {
+        safari: Some(8 << 16),
+        firefox: Some(30 << 16),
+        ..Browsers::default()
+      },
     );
-    test(
+
+    prefix_test(
       r#"
-      @supports unknown(test) {
-        .test {
-          foo: bar;
-        }
+      .foo {
+        -webkit-text-decoration: underline dotted;
+        text-decoration: underline dotted;
       }
     "#,
-      indoc! { r#"
-      @supports unknown(test) {
-        .test {
-          foo: bar;
-        }
+      indoc! {r#"
+      .foo {
+        -webkit-text-decoration: underline dotted;
+        text-decoration: underline dotted;
       }
     "#},
+      Browsers {
+        safari: Some(14 << 16),
+        firefox: Some(45 << 16),
+        ..Browsers::default()
+      },
     );
-    test(
+
+    prefix_test(
       r#"
-      @supports (unknown) {
-        .test {
-          foo: bar;
-        }
+      .foo {
+        text-decoration: double underline;
       }
     "#,
-      indoc! { r#"
-      @supports (unknown) {
-        .test {
-          foo: bar;
-        }
+      indoc! {r#"
+      .foo {
+        -webkit-text-decoration: underline double;
+        text-decoration: underline double;
       }
     "#},
+      Browsers {
+        safari: Some(16 << 16),
+        ..Browsers::default()
+      },
     );
-    test(
+
+    prefix_test(
       r#"
-      @supports (display: grid) and (not (display: inline-grid)) {
-        .test {
-          foo: bar;
-        }
+      .foo {
+        text-decoration: underline;
+        text-decoration-style: double;
       }
     "#,
-      indoc! { r#"
-      @supports (display: grid) and (not (display: inline-grid)) {
-        .test {
-          foo: bar;
-        }
+      indoc! {r#"
+      .foo {
+        -webkit-text-decoration: underline double;
+        text-decoration: underline double;
       }
     "#},
+      Browsers {
+        safari: Some(16 << 16),
+        ..Browsers::default()
+      },
     );
+
     prefix_test(
       r#"
-      @supports (backdrop-filter: blur(10px)) {
-        div {
-          backdrop-filter: blur(10px);
-        }
+      .foo {
+        text-decoration: underline;
+        text-decoration-color: red;
       }
     "#,
-      indoc! { r#"
-      @supports ((-webkit-backdrop-filter: blur(10px)) or (backdrop-filter: blur(10px))) {
-        div {
-          -webkit-backdrop-filter: blur(10px);
-          backdrop-filter: blur(10px);
-        }
+      indoc! {r#"
+      .foo {
+        -webkit-text-decoration: underline red;
+        text-decoration: underline red;
       }
     "#},
       Browsers {
-        safari: Some(14 << 16),
-        ..Default::default()
+        safari: Some(16 << 16),
+        ..Browsers::default()
       },
     );
+
     prefix_test(
       r#"
-      @supports ((-webkit-backdrop-filter: blur(10px)) or (backdrop-filter: blur(10px))) {
-        div {
-          backdrop-filter: blur(10px);
-        }
+      .foo {
+        text-decoration: var(--test);
       }
     "#,
-      indoc! { r#"
-      @supports ((-webkit-backdrop-filter: blur(10px)) or (backdrop-filter: blur(10px))) {
-        div {
-          -webkit-backdrop-filter: blur(10px);
-          backdrop-filter: blur(10px);
-        }
+      indoc! {r#"
+      .foo {
+        -webkit-text-decoration: var(--test);
+        text-decoration: var(--test);
       }
     "#},
       Browsers {
-        safari: Some(14 << 16),
-        ..Default::default()
+        safari: Some(8 << 16),
+        firefox: Some(30 << 16),
+        ..Browsers::default()
       },
     );
+
+    minify_test(
+      ".foo { text-decoration-skip-ink: all }",
+      ".foo{text-decoration-skip-ink:all}",
+    );
+    minify_test(
+      ".foo { -webkit-text-decoration-skip-ink: all }",
+      ".foo{-webkit-text-decoration-skip-ink:all}",
+    );
+    minify_test(".foo { text-decoration-skip: none }", ".foo{text-decoration-skip:none}");
+    minify_test(".foo { text-decoration-skip: ink }", ".foo{text-decoration-skip:ink}");
+    minify_test(
+      ".foo { text-decoration-skip: objects edges }",
+      ".foo{text-decoration-skip:objects edges}",
+    );
+    minify_test(
+      ".foo { text-decoration-skip: edges objects }",
+      ".foo{text-decoration-skip:objects edges}",
+    );
+    minify_test(
+      ".foo { text-decoration-skip: leading-spaces trailing-spaces }",
+      ".foo{text-decoration-skip:leading-spaces trailing-spaces}",
+    );
+    minify_test(
+      ".foo { text-decoration-skip: trailing-spaces leading-spaces }",
+      ".foo{text-decoration-skip:leading-spaces trailing-spaces}",
+    );
+    minify_test(
+      ".foo { text-decoration-skip: box-decoration spaces objects }",
+      ".foo{text-decoration-skip:objects spaces box-decoration}",
+    );
+    minify_test(
+      ".foo { text-decoration-skip: trailing-spaces box-decoration }",
+      ".foo{text-decoration-skip:trailing-spaces box-decoration}",
+    );
+    minify_test(
+      ".foo { text-decoration-skip: objects objects }",
+      ".foo{text-decoration-skip:objects objects}",
+    ); // invalid
+    minify_test(
+      ".foo { text-decoration-skip: spaces leading-spaces }",
+      ".foo{text-decoration-skip:spaces leading-spaces}",
+    );
+    minify_test(
+      ".foo { text-decoration-skip: trailing-spaces spaces }",
+      ".foo{text-decoration-skip:trailing-spaces spaces}",
+    ); // invalid
+    minify_test(
+      ".foo { text-decoration-skip: ink objects }",
+      ".foo{text-decoration-skip:ink objects}",
+    ); // invalid
+
     prefix_test(
       r#"
-      @supports ((-webkit-backdrop-filter: blur(20px)) or (backdrop-filter: blur(10px))) {
-        div {
-          backdrop-filter: blur(10px);
-        }
+      .foo {
+        text-decoration-skip-ink: auto;
       }
     "#,
-      indoc! { r#"
-      @supports ((-webkit-backdrop-filter: blur(20px))) or ((-webkit-backdrop-filter: blur(10px)) or (backdrop-filter: blur(10px))) {
-        div {
-          -webkit-backdrop-filter: blur(10px);
-          backdrop-filter: blur(10px);
-        }
+      indoc! {r#"
+      .foo {
+        text-decoration-skip: ink;
+        text-decoration-skip-ink: auto;
       }
     "#},
       Browsers {
-        safari: Some(14 << 16),
-        ..Default::default()
+        safari: Some(13 << 16),
+        chrome: Some(90 << 16),
+        ..Browsers::default()
       },
     );
+
     prefix_test(
       r#"
-      @supports ((-webkit-backdrop-filter: blur(10px)) or (backdrop-filter: blur(10px))) {
-        div {
-          backdrop-filter: blur(10px);
-        }
+      .foo {
+        text-decoration-skip-ink: none;
       }
     "#,
-      indoc! { r#"
-      @supports (backdrop-filter: blur(10px)) {
-        div {
-          backdrop-filter: blur(10px);
-        }
+      indoc! {r#"
+      .foo {
+        text-decoration-skip: none;
+        text-decoration-skip-ink: none;
       }
     "#},
       Browsers {
-        chrome: Some(80 << 16),
-        ..Default::default()
+        safari: Some(13 << 16),
+        ..Browsers::default()
       },
     );
-    minify_test(
+
+    prefix_test(
       r#"
-      @supports (width: calc(10px * 2)) {
-        .test {
-          width: calc(10px * 2);
-        }
+      .foo {
+        text-decoration-skip-ink: auto;
       }
     "#,
-      "@supports (width:calc(10px * 2)){.test{width:20px}}",
+      indoc! {r#"
+      .foo {
+        text-decoration-skip-ink: auto;
+      }
+    "#},
+      Browsers {
+        safari: Some(16 << 16),
+        chrome: Some(90 << 16),
+        ..Browsers::default()
+      },
     );
-    minify_test(
+
+    prefix_test(
       r#"
-      @supports (color: hsl(0deg, 0%, 0%)) {
-        .test {
-          color: hsl(0deg, 0%, 0%);
-        }
+      .foo {
+        text-decoration-skip: ink;
       }
     "#,
-      "@supports (color:hsl(0deg, 0%, 0%)){.test{color:#000}}",
+      indoc! {r#"
+      .foo {
+        text-decoration-skip-ink: auto;
+      }
+    "#},
+      Browsers {
+        safari: Some(16 << 16),
+        chrome: Some(90 << 16),
+        ..Browsers::default()
+      },
     );
-  }
 
-  #[test]
-  fn test_counter_style() {
-    test(
+    prefix_test(
       r#"
-      @counter-style circled-alpha {
-        system: fixed;
-        symbols: Ⓐ Ⓑ Ⓒ;
-        suffix: " ";
+      .foo {
+        text-decoration-skip: objects;
+        text-decoration-skip-ink: none;
       }
     "#,
-      indoc! { r#"
-      @counter-style circled-alpha {
-        system: fixed;
-        symbols: Ⓐ Ⓑ Ⓒ;
-        suffix: " ";
+      indoc! {r#"
+      .foo {
+        text-decoration-skip: objects;
+        text-decoration-skip-ink: none;
       }
     "#},
+      Browsers {
+        safari: Some(13 << 16),
+        ..Browsers::default()
+      },
     );
-  }
 
-  #[test]
-  fn test_namespace() {
     minify_test(
-      "@namespace url(http://toto.example.org);",
-      "@namespace \"http://toto.example.org\";",
+      ".foo { text-underline-offset: auto }",
+      ".foo{text-underline-offset:auto}",
     );
+    minify_test(".foo { text-underline-offset: 3px }", ".foo{text-underline-offset:3px}");
     minify_test(
-      "@namespace \"http://toto.example.org\";",
-      "@namespace \"http://toto.example.org\";",
+      ".foo { text-underline-offset: 0.1em }",
+      ".foo{text-underline-offset:.1em}",
     );
+    minify_test(".foo { text-underline-offset: 10% }", ".foo{text-underline-offset:10%}");
     minify_test(
-      "@namespace toto \"http://toto.example.org\";",
-      "@namespace toto \"http://toto.example.org\";",
+      ".foo { text-underline-offset: from-font }",
+      ".foo{text-underline-offset:from-font}",
+    ); // invalid
+    minify_test(
+      ".foo { text-underline-position: auto }",
+      ".foo{text-underline-position:auto}",
     );
     minify_test(
-      "@namespace toto url(http://toto.example.org);",
-      "@namespace toto \"http://toto.example.org\";",
+      ".foo { text-underline-position: from-font }",
+      ".foo{text-underline-position:from-font}",
+    );
+    minify_test(
+      ".foo { text-underline-position: under }",
+      ".foo{text-underline-position:under}",
+    );
+    minify_test(
+      ".foo { text-underline-position: left }",
+      ".foo{text-underline-position:left}",
     );
+    minify_test(
+      ".foo { text-underline-position: right under }",
+      ".foo{text-underline-position:under right}",
+    );
+    minify_test(
+      ".foo { text-underline-position: left right }",
+      ".foo{text-underline-position:left right}",
+    ); // invalid
 
-    test(
+    prefix_test(
       r#"
-      @namespace "http://example.com/foo";
-
-      x {
-        color: red;
+      .foo {
+        text-decoration: lch(50.998% 135.363 338) underline;
       }
     "#,
       indoc! {r#"
-      @namespace "http://example.com/foo";
-
-      x {
-        color: red;
+      .foo {
+        -webkit-text-decoration: underline #ee00be;
+        text-decoration: underline #ee00be;
+        -webkit-text-decoration: underline lch(50.998% 135.363 338);
+        text-decoration: underline lch(50.998% 135.363 338);
       }
     "#},
+      Browsers {
+        safari: Some(8 << 16),
+        firefox: Some(30 << 16),
+        ..Browsers::default()
+      },
     );
 
-    test(
+    prefix_test(
       r#"
-      @namespace toto "http://toto.example.org";
-
-      toto|x {
-        color: red;
-      }
-
-      [toto|att=val] {
-        color: blue
+      .foo {
+        text-decoration-color: lch(50.998% 135.363 338);
       }
     "#,
       indoc! {r#"
-      @namespace toto "http://toto.example.org";
-
-      toto|x {
-        color: red;
-      }
-
-      [toto|att="val"] {
-        color: #00f;
+      .foo {
+        -webkit-text-decoration-color: #ee00be;
+        -moz-text-decoration-color: #ee00be;
+        text-decoration-color: #ee00be;
+        -webkit-text-decoration-color: lch(50.998% 135.363 338);
+        -moz-text-decoration-color: lch(50.998% 135.363 338);
+        text-decoration-color: lch(50.998% 135.363 338);
       }
     "#},
+      Browsers {
+        safari: Some(8 << 16),
+        firefox: Some(30 << 16),
+        ..Browsers::default()
+      },
     );
 
-    test(
+    prefix_test(
       r#"
-      @namespace "http://example.com/foo";
-
-      |x {
-        color: red;
-      }
-
-      [|att=val] {
-        color: blue
+      .foo {
+        text-decoration: lch(50.998% 135.363 338) var(--style);
       }
     "#,
       indoc! {r#"
-      @namespace "http://example.com/foo";
-
-      |x {
-        color: red;
+      .foo {
+        text-decoration: #ee00be var(--style);
       }
 
-      [att="val"] {
-        color: #00f;
+      @supports (color: lab(0% 0 0)) {
+        .foo {
+          text-decoration: lab(50.998% 125.506 -50.7078) var(--style);
+        }
       }
     "#},
+      Browsers {
+        chrome: Some(90 << 16),
+        ..Browsers::default()
+      },
     );
 
-    test(
+    prefix_test(
       r#"
-      @namespace "http://example.com/foo";
-
-      *|x {
-        color: red;
-      }
-
-      [*|att=val] {
-        color: blue
+      .foo {
+        text-decoration: underline 10px;
       }
     "#,
       indoc! {r#"
-      @namespace "http://example.com/foo";
-
-      *|x {
-        color: red;
+      .foo {
+        text-decoration: underline;
+        text-decoration-thickness: 10px;
       }
+    "#},
+      Browsers {
+        safari: Some(15 << 16),
+        ..Browsers::default()
+      },
+    );
 
-      [*|att="val"] {
-        color: #00f;
+    prefix_test(
+      r#"
+      .foo {
+        text-decoration: underline 10px;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        text-decoration: underline 10px;
       }
     "#},
+      Browsers {
+        chrome: Some(90 << 16),
+        ..Browsers::default()
+      },
     );
 
-    error_test(
-      ".foo { color: red } @namespace \"http://example.com/foo\";",
-      ParserError::UnexpectedNamespaceRule,
+    prefix_test(
+      r#"
+      .foo {
+        text-decoration: underline 10%;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        text-decoration: underline;
+        text-decoration-thickness: calc(1em / 10);
+      }
+    "#},
+      Browsers {
+        safari: Some(12 << 16),
+        ..Browsers::default()
+      },
     );
-  }
 
-  #[test]
-  fn test_import() {
-    minify_test("@import url(foo.css);", "@import \"foo.css\";");
-    minify_test("@import \"foo.css\";", "@import \"foo.css\";");
-    minify_test("@import url(foo.css) print;", "@import \"foo.css\" print;");
-    minify_test("@import \"foo.css\" print;", "@import \"foo.css\" print;");
-    minify_test(
-      "@import \"foo.css\" screen and (orientation: landscape);",
-      "@import \"foo.css\" screen and (orientation:landscape);",
-    );
-    minify_test(
-      "@import url(foo.css) supports(display: flex);",
-      "@import \"foo.css\" supports(display:flex);",
-    );
-    minify_test(
-      "@import url(foo.css) supports(display: flex) print;",
-      "@import \"foo.css\" supports(display:flex) print;",
-    );
-    minify_test(
-      "@import url(foo.css) supports(not (display: flex));",
-      "@import \"foo.css\" supports(not (display:flex));",
-    );
-    minify_test(
-      "@import url(foo.css) supports((display: flex));",
-      "@import \"foo.css\" supports(display:flex);",
-    );
-    minify_test("@charset \"UTF-8\"; @import url(foo.css);", "@import \"foo.css\";");
-    minify_test("@layer foo; @import url(foo.css);", "@layer foo;@import \"foo.css\";");
-    error_test(
-      ".foo { color: red } @import url(bar.css);",
-      ParserError::UnexpectedImportRule,
-    );
-    error_test(
-      "@namespace \"http://example.com/foo\"; @import url(bar.css);",
-      ParserError::UnexpectedImportRule,
-    );
-    error_test(
-      "@media print { .foo { color: red }} @import url(bar.css);",
-      ParserError::UnexpectedImportRule,
-    );
-    error_test(
-      "@layer foo; @import url(foo.css); @layer bar; @import url(bar.css)",
-      ParserError::UnexpectedImportRule,
+    prefix_test(
+      r#"
+      .foo {
+        text-decoration: underline 10%;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        text-decoration: underline 10%;
+      }
+    "#},
+      Browsers {
+        firefox: Some(89 << 16),
+        ..Browsers::default()
+      },
     );
-  }
 
-  #[test]
-  fn test_prefixes() {
     prefix_test(
       r#"
       .foo {
-        -webkit-transition: opacity 200ms;
-        -moz-transition: opacity 200ms;
-        transition: opacity 200ms;
+        text-decoration-thickness: 10%;
       }
-      "#,
+    "#,
       indoc! {r#"
       .foo {
-        transition: opacity .2s;
+        text-decoration-thickness: calc(1em / 10);
       }
-      "#},
+    "#},
       Browsers {
-        chrome: Some(95 << 16),
+        safari: Some(12 << 16),
         ..Browsers::default()
       },
     );
 
     prefix_test(
-      ".foo{transition:opacity 200ms}",
+      r#"
+      .foo {
+        text-decoration-thickness: 10%;
+      }
+    "#,
       indoc! {r#"
       .foo {
-        -webkit-transition: opacity .2s;
-        -moz-transition: opacity .2s;
-        transition: opacity .2s;
+        text-decoration-thickness: 10%;
       }
-      "#},
+    "#},
       Browsers {
-        safari: Some(5 << 16),
-        firefox: Some(14 << 16),
+        firefox: Some(89 << 16),
         ..Browsers::default()
       },
     );
   }
 
   #[test]
-  fn test_display() {
-    minify_test(".foo { display: block }", ".foo{display:block}");
-    minify_test(".foo { display: block flow }", ".foo{display:block}");
-    minify_test(".foo { display: flow-root }", ".foo{display:flow-root}");
-    minify_test(".foo { display: block flow-root }", ".foo{display:flow-root}");
-    minify_test(".foo { display: inline }", ".foo{display:inline}");
-    minify_test(".foo { display: inline flow }", ".foo{display:inline}");
-    minify_test(".foo { display: inline-block }", ".foo{display:inline-block}");
-    minify_test(".foo { display: inline flow-root }", ".foo{display:inline-block}");
-    minify_test(".foo { display: run-in }", ".foo{display:run-in}");
-    minify_test(".foo { display: run-in flow }", ".foo{display:run-in}");
-    minify_test(".foo { display: list-item }", ".foo{display:list-item}");
-    minify_test(".foo { display: block flow list-item }", ".foo{display:list-item}");
-    minify_test(".foo { display: inline list-item }", ".foo{display:inline list-item}");
+  fn test_text_emphasis() {
+    minify_test(".foo { text-emphasis-style: none }", ".foo{text-emphasis-style:none}");
     minify_test(
-      ".foo { display: inline flow list-item }",
-      ".foo{display:inline list-item}",
+      ".foo { text-emphasis-style: filled }",
+      ".foo{text-emphasis-style:filled}",
     );
-    minify_test(".foo { display: flex }", ".foo{display:flex}");
-    minify_test(".foo { display: block flex }", ".foo{display:flex}");
-    minify_test(".foo { display: inline-flex }", ".foo{display:inline-flex}");
-    minify_test(".foo { display: inline flex }", ".foo{display:inline-flex}");
-    minify_test(".foo { display: grid }", ".foo{display:grid}");
-    minify_test(".foo { display: block grid }", ".foo{display:grid}");
-    minify_test(".foo { display: inline-grid }", ".foo{display:inline-grid}");
-    minify_test(".foo { display: inline grid }", ".foo{display:inline-grid}");
-    minify_test(".foo { display: ruby }", ".foo{display:ruby}");
-    minify_test(".foo { display: inline ruby }", ".foo{display:ruby}");
-    minify_test(".foo { display: block ruby }", ".foo{display:block ruby}");
-    minify_test(".foo { display: table }", ".foo{display:table}");
-    minify_test(".foo { display: block table }", ".foo{display:table}");
-    minify_test(".foo { display: inline-table }", ".foo{display:inline-table}");
-    minify_test(".foo { display: inline table }", ".foo{display:inline-table}");
-    minify_test(".foo { display: table-row-group }", ".foo{display:table-row-group}");
-    minify_test(".foo { display: contents }", ".foo{display:contents}");
-    minify_test(".foo { display: none }", ".foo{display:none}");
-    minify_test(".foo { display: -webkit-flex }", ".foo{display:-webkit-flex}");
-    minify_test(".foo { display: -ms-flexbox }", ".foo{display:-ms-flexbox}");
-    minify_test(".foo { display: -webkit-box }", ".foo{display:-webkit-box}");
-    minify_test(".foo { display: -moz-box }", ".foo{display:-moz-box}");
+    minify_test(".foo { text-emphasis-style: open }", ".foo{text-emphasis-style:open}");
+    minify_test(".foo { text-emphasis-style: dot }", ".foo{text-emphasis-style:dot}");
     minify_test(
-      ".foo { display: -webkit-flex; display: -moz-box; display: flex }",
-      ".foo{display:-webkit-flex;display:-moz-box;display:flex}",
+      ".foo { text-emphasis-style: filled dot }",
+      ".foo{text-emphasis-style:dot}",
     );
     minify_test(
-      ".foo { display: -webkit-flex; display: flex; display: -moz-box }",
-      ".foo{display:-webkit-flex;display:flex;display:-moz-box}",
+      ".foo { text-emphasis-style: dot filled }",
+      ".foo{text-emphasis-style:dot}",
     );
-    minify_test(".foo { display: flex; display: grid }", ".foo{display:grid}");
     minify_test(
-      ".foo { display: -webkit-inline-flex; display: -moz-inline-box; display: inline-flex }",
-      ".foo{display:-webkit-inline-flex;display:-moz-inline-box;display:inline-flex}",
+      ".foo { text-emphasis-style: open dot }",
+      ".foo{text-emphasis-style:open dot}",
     );
     minify_test(
-      ".foo { display: flex; display: var(--grid); }",
-      ".foo{display:flex;display:var(--grid)}",
+      ".foo { text-emphasis-style: dot open }",
+      ".foo{text-emphasis-style:open dot}",
+    );
+    minify_test(".foo { text-emphasis-style: \"x\" }", ".foo{text-emphasis-style:\"x\"}");
+
+    minify_test(".foo { text-emphasis-color: yellow }", ".foo{text-emphasis-color:#ff0}");
+
+    minify_test(".foo { text-emphasis: none }", ".foo{text-emphasis:none}");
+    minify_test(".foo { text-emphasis: filled }", ".foo{text-emphasis:filled}");
+    minify_test(
+      ".foo { text-emphasis: filled yellow }",
+      ".foo{text-emphasis:filled #ff0}",
+    );
+    minify_test(
+      ".foo { text-emphasis: dot filled yellow }",
+      ".foo{text-emphasis:dot #ff0}",
+    );
+    minify_test(".foo { text-emphasis: \"*\" yellow }", ".foo{text-emphasis:\"*\" #ff0}");
+    minify_test(
+      ".foo { text-emphasis: yellow open sesame }",
+      ".foo{text-emphasis:open sesame #ff0}",
+    );
+    minify_test(
+      ".foo { -webkit-text-emphasis: filled yellow }",
+      ".foo{-webkit-text-emphasis:filled #ff0}",
+    );
+    minify_test(".foo { text-emphasis: \"*\" dot }", ".foo{text-emphasis:\"*\" dot}"); // invalid
+
+    test(
+      r#"
+      .foo {
+        text-emphasis-style: filled;
+        text-emphasis-color: yellow;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        text-emphasis: filled #ff0;
+      }
+    "#},
+    );
+
+    test(
+      r#"
+      .foo {
+        text-emphasis: filled red;
+        text-emphasis-color: yellow;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        text-emphasis: filled #ff0;
+      }
+    "#},
+    );
+
+    test(
+      r#"
+      .foo {
+        text-emphasis: filled yellow;
+        text-emphasis-color: var(--color);
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        text-emphasis: filled #ff0;
+        text-emphasis-color: var(--color);
+      }
+    "#},
     );
+
     prefix_test(
-      ".foo{ display: flex }",
+      r#"
+      .foo {
+        text-emphasis-style: filled;
+      }
+    "#,
       indoc! {r#"
       .foo {
-        display: -webkit-box;
-        display: -moz-box;
-        display: -webkit-flex;
-        display: -ms-flexbox;
-        display: flex;
+        -webkit-text-emphasis-style: filled;
+        text-emphasis-style: filled;
       }
-      "#},
+    "#},
       Browsers {
-        safari: Some(4 << 16),
-        firefox: Some(14 << 16),
-        ie: Some(10 << 16),
+        safari: Some(10 << 16),
+        chrome: Some(30 << 16),
+        firefox: Some(45 << 16),
         ..Browsers::default()
       },
     );
+
     prefix_test(
-      ".foo{ display: flex; display: -webkit-box; }",
+      r#"
+      .foo {
+        text-emphasis: "*" red;
+      }
+    "#,
       indoc! {r#"
       .foo {
-        display: -webkit-box;
+        -webkit-text-emphasis: "*" red;
+        text-emphasis: "*" red;
       }
-      "#},
+    "#},
       Browsers {
-        safari: Some(4 << 16),
-        firefox: Some(14 << 16),
-        ie: Some(10 << 16),
+        safari: Some(10 << 16),
+        chrome: Some(30 << 16),
+        firefox: Some(45 << 16),
         ..Browsers::default()
       },
     );
+
     prefix_test(
-      ".foo{ display: -webkit-box; display: flex; }",
+      r#"
+      .foo {
+        -webkit-text-emphasis: "*" red;
+        text-emphasis: "*" red;
+      }
+    "#,
       indoc! {r#"
       .foo {
-        display: -webkit-box;
-        display: -moz-box;
-        display: -webkit-flex;
-        display: -ms-flexbox;
-        display: flex;
+        text-emphasis: "*" red;
       }
-      "#},
+    "#},
       Browsers {
-        safari: Some(4 << 16),
-        firefox: Some(14 << 16),
-        ie: Some(10 << 16),
+        chrome: Some(100 << 16),
         ..Browsers::default()
       },
     );
+
     prefix_test(
       r#"
       .foo {
-        display: -webkit-box;
-        display: -moz-box;
-        display: -webkit-flex;
-        display: -ms-flexbox;
-        display: flex;
+        -webkit-text-emphasis-style: filled;
+        text-emphasis-style: filled;
       }
-      "#,
+    "#,
       indoc! {r#"
       .foo {
-        display: flex;
+        text-emphasis-style: filled;
+      }
+    "#},
+      Browsers {
+        safari: Some(10 << 16),
+        firefox: Some(45 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    minify_test(
+      ".foo { text-emphasis-position: over }",
+      ".foo{text-emphasis-position:over}",
+    );
+    minify_test(
+      ".foo { text-emphasis-position: under }",
+      ".foo{text-emphasis-position:under}",
+    );
+    minify_test(
+      ".foo { text-emphasis-position: over right }",
+      ".foo{text-emphasis-position:over}",
+    );
+    minify_test(
+      ".foo { text-emphasis-position: over left }",
+      ".foo{text-emphasis-position:over left}",
+    );
+
+    prefix_test(
+      r#"
+      .foo {
+        text-emphasis-position: over;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        -webkit-text-emphasis-position: over;
+        text-emphasis-position: over;
       }
-      "#},
+    "#},
       Browsers {
-        safari: Some(14 << 16),
+        safari: Some(10 << 16),
+        chrome: Some(30 << 16),
+        firefox: Some(45 << 16),
         ..Browsers::default()
       },
     );
+
     prefix_test(
       r#"
       .foo {
-        display: -webkit-box;
-        display: flex;
-        display: -moz-box;
-        display: -webkit-flex;
-        display: -ms-flexbox;
+        text-emphasis-position: over left;
       }
-      "#,
+    "#,
       indoc! {r#"
       .foo {
-        display: -moz-box;
-        display: -webkit-flex;
-        display: -ms-flexbox;
+        text-emphasis-position: over left;
       }
-      "#},
+    "#},
       Browsers {
-        safari: Some(14 << 16),
+        safari: Some(10 << 16),
+        chrome: Some(30 << 16),
+        firefox: Some(45 << 16),
         ..Browsers::default()
       },
     );
+
     prefix_test(
-      ".foo{ display: inline-flex }",
+      r#"
+      .foo {
+        text-emphasis-position: var(--test);
+      }
+    "#,
       indoc! {r#"
       .foo {
-        display: -webkit-inline-box;
-        display: -moz-inline-box;
-        display: -webkit-inline-flex;
-        display: -ms-inline-flexbox;
-        display: inline-flex;
+        -webkit-text-emphasis-position: var(--test);
+        text-emphasis-position: var(--test);
       }
-      "#},
+    "#},
       Browsers {
-        safari: Some(4 << 16),
-        firefox: Some(14 << 16),
-        ie: Some(10 << 16),
+        safari: Some(10 << 16),
+        chrome: Some(30 << 16),
+        firefox: Some(45 << 16),
         ..Browsers::default()
       },
     );
+
     prefix_test(
       r#"
       .foo {
-        display: -webkit-inline-box;
-        display: -moz-inline-box;
-        display: -webkit-inline-flex;
-        display: -ms-inline-flexbox;
-        display: inline-flex;
+        text-emphasis: filled lch(50.998% 135.363 338);
       }
-      "#,
+    "#,
       indoc! {r#"
       .foo {
-        display: inline-flex;
+        -webkit-text-emphasis: filled #ee00be;
+        text-emphasis: filled #ee00be;
+        -webkit-text-emphasis: filled lch(50.998% 135.363 338);
+        text-emphasis: filled lch(50.998% 135.363 338);
       }
-      "#},
+    "#},
       Browsers {
-        safari: Some(14 << 16),
+        chrome: Some(25 << 16),
+        firefox: Some(48 << 16),
         ..Browsers::default()
       },
     );
-  }
-
-  #[test]
-  fn test_visibility() {
-    minify_test(".foo { visibility: visible }", ".foo{visibility:visible}");
-    minify_test(".foo { visibility: hidden }", ".foo{visibility:hidden}");
-    minify_test(".foo { visibility: collapse }", ".foo{visibility:collapse}");
-    minify_test(".foo { visibility: Visible }", ".foo{visibility:visible}");
-  }
-
-  #[test]
-  fn test_text_transform() {
-    minify_test(".foo { text-transform: uppercase }", ".foo{text-transform:uppercase}");
-    minify_test(".foo { text-transform: lowercase }", ".foo{text-transform:lowercase}");
-    minify_test(".foo { text-transform: capitalize }", ".foo{text-transform:capitalize}");
-    minify_test(".foo { text-transform: none }", ".foo{text-transform:none}");
-    minify_test(".foo { text-transform: full-width }", ".foo{text-transform:full-width}");
-    minify_test(
-      ".foo { text-transform: full-size-kana }",
-      ".foo{text-transform:full-size-kana}",
-    );
-    minify_test(
-      ".foo { text-transform: uppercase full-width }",
-      ".foo{text-transform:uppercase full-width}",
-    );
-    minify_test(
-      ".foo { text-transform: full-width uppercase }",
-      ".foo{text-transform:uppercase full-width}",
-    );
-    minify_test(
-      ".foo { text-transform: uppercase full-width full-size-kana }",
-      ".foo{text-transform:uppercase full-width full-size-kana}",
-    );
-    minify_test(
-      ".foo { text-transform: full-width uppercase full-size-kana }",
-      ".foo{text-transform:uppercase full-width full-size-kana}",
-    );
-  }
-
-  #[test]
-  fn test_whitespace() {
-    minify_test(".foo { white-space: normal }", ".foo{white-space:normal}");
-    minify_test(".foo { white-space: pre }", ".foo{white-space:pre}");
-    minify_test(".foo { white-space: nowrap }", ".foo{white-space:nowrap}");
-    minify_test(".foo { white-space: pre-wrap }", ".foo{white-space:pre-wrap}");
-    minify_test(".foo { white-space: break-spaces }", ".foo{white-space:break-spaces}");
-    minify_test(".foo { white-space: pre-line }", ".foo{white-space:pre-line}");
-    minify_test(".foo { white-space: NoWrAp }", ".foo{white-space:nowrap}");
-  }
 
-  #[test]
-  fn test_tab_size() {
-    minify_test(".foo { tab-size: 8 }", ".foo{tab-size:8}");
-    minify_test(".foo { tab-size: 4px }", ".foo{tab-size:4px}");
-    minify_test(".foo { -moz-tab-size: 4px }", ".foo{-moz-tab-size:4px}");
-    minify_test(".foo { -o-tab-size: 4px }", ".foo{-o-tab-size:4px}");
     prefix_test(
-      ".foo{ tab-size: 4 }",
+      r#"
+      .foo {
+        text-emphasis-color: lch(50.998% 135.363 338);
+      }
+    "#,
       indoc! {r#"
       .foo {
-        -moz-tab-size: 4;
-        -o-tab-size: 4;
-        tab-size: 4;
+        -webkit-text-emphasis-color: #ee00be;
+        text-emphasis-color: #ee00be;
+        -webkit-text-emphasis-color: lch(50.998% 135.363 338);
+        text-emphasis-color: lch(50.998% 135.363 338);
       }
-      "#},
+    "#},
       Browsers {
-        safari: Some(8 << 16),
-        firefox: Some(50 << 16),
-        opera: Some(12 << 16),
+        chrome: Some(25 << 16),
+        firefox: Some(48 << 16),
         ..Browsers::default()
       },
     );
+
     prefix_test(
       r#"
       .foo {
-        -moz-tab-size: 4;
-        -o-tab-size: 4;
-        tab-size: 4;
+        text-emphasis: lch(50.998% 135.363 338) var(--style);
       }
-      "#,
+    "#,
       indoc! {r#"
       .foo {
-        tab-size: 4;
+        text-emphasis: #ee00be var(--style);
       }
-      "#},
+
+      @supports (color: lab(0% 0 0)) {
+        .foo {
+          text-emphasis: lab(50.998% 125.506 -50.7078) var(--style);
+        }
+      }
+    "#},
       Browsers {
         safari: Some(8 << 16),
-        firefox: Some(94 << 16),
-        opera: Some(30 << 16),
         ..Browsers::default()
       },
     );
   }
 
   #[test]
-  fn test_word_break() {
-    minify_test(".foo { word-break: normal }", ".foo{word-break:normal}");
-    minify_test(".foo { word-break: keep-all }", ".foo{word-break:keep-all}");
-    minify_test(".foo { word-break: break-all }", ".foo{word-break:break-all}");
-    minify_test(".foo { word-break: break-word }", ".foo{word-break:break-word}");
-  }
-
-  #[test]
-  fn test_line_break() {
-    minify_test(".foo { line-break: auto }", ".foo{line-break:auto}");
-    minify_test(".foo { line-break: Loose }", ".foo{line-break:loose}");
-    minify_test(".foo { line-break: anywhere }", ".foo{line-break:anywhere}");
-  }
-
-  #[test]
-  fn test_wrap() {
-    minify_test(".foo { overflow-wrap: nOrmal }", ".foo{overflow-wrap:normal}");
-    minify_test(".foo { overflow-wrap: break-Word }", ".foo{overflow-wrap:break-word}");
-    minify_test(".foo { overflow-wrap: Anywhere }", ".foo{overflow-wrap:anywhere}");
-    minify_test(".foo { word-wrap: Normal }", ".foo{word-wrap:normal}");
-    minify_test(".foo { word-wrap: Break-wOrd }", ".foo{word-wrap:break-word}");
-    minify_test(".foo { word-wrap: Anywhere }", ".foo{word-wrap:anywhere}");
-  }
-
-  #[test]
-  fn test_hyphens() {
-    minify_test(".foo { hyphens: manual }", ".foo{hyphens:manual}");
-    minify_test(".foo { hyphens: auto }", ".foo{hyphens:auto}");
-    minify_test(".foo { hyphens: none }", ".foo{hyphens:none}");
-    minify_test(".foo { -webkit-hyphens: manual }", ".foo{-webkit-hyphens:manual}");
-    minify_test(".foo { -moz-hyphens: manual }", ".foo{-moz-hyphens:manual}");
-    minify_test(".foo { -ms-hyphens: manual }", ".foo{-ms-hyphens:manual}");
-    prefix_test(
-      ".foo{ hyphens: manual }",
-      indoc! {r#"
-      .foo {
-        -webkit-hyphens: manual;
-        -moz-hyphens: manual;
-        -ms-hyphens: manual;
-        hyphens: manual;
-      }
-      "#},
-      Browsers {
-        safari: Some(14 << 16),
-        firefox: Some(40 << 16),
-        ie: Some(10 << 16),
-        ..Browsers::default()
-      },
+  fn test_text_shadow() {
+    minify_test(
+      ".foo { text-shadow: 1px 1px 2px yellow; }",
+      ".foo{text-shadow:1px 1px 2px #ff0}",
+    );
+    minify_test(
+      ".foo { text-shadow: 1px 1px 2px 3px yellow; }",
+      ".foo{text-shadow:1px 1px 2px 3px #ff0}",
+    );
+    minify_test(
+      ".foo { text-shadow: 1px 1px 0 yellow; }",
+      ".foo{text-shadow:1px 1px #ff0}",
+    );
+    minify_test(
+      ".foo { text-shadow: 1px 1px yellow; }",
+      ".foo{text-shadow:1px 1px #ff0}",
     );
+    minify_test(
+      ".foo { text-shadow: 1px 1px yellow, 2px 3px red; }",
+      ".foo{text-shadow:1px 1px #ff0,2px 3px red}",
+    );
+    minify_test(
+      ".foo { text-shadow: 1px 1px -2px yellow; }",
+      ".foo{text-shadow:1px 1px -2px yellow}",
+    );
+
     prefix_test(
-      r#"
-      .foo {
-        -webkit-hyphens: manual;
-        -moz-hyphens: manual;
-        -ms-hyphens: manual;
-        hyphens: manual;
-      }
-      "#,
-      indoc! {r#"
-      .foo {
-        -webkit-hyphens: manual;
-        hyphens: manual;
-      }
-      "#},
-      Browsers {
-        safari: Some(14 << 16),
-        chrome: Some(88 << 16),
-        firefox: Some(88 << 16),
-        edge: Some(79 << 16),
+      ".foo { text-shadow: 12px 12px lab(40% 56.6 39) }",
+      indoc! { r#"
+        .foo {
+          text-shadow: 12px 12px #b32323;
+          text-shadow: 12px 12px lab(40% 56.6 39);
+        }
+      "#},
+      Browsers {
+        chrome: Some(4 << 16),
         ..Browsers::default()
       },
     );
+
     prefix_test(
-      r#"
-      .foo {
-        -webkit-hyphens: manual;
-        -moz-hyphens: manual;
-        -ms-hyphens: manual;
-        hyphens: manual;
-      }
-      "#,
-      indoc! {r#"
-      .foo {
-        hyphens: manual;
-      }
+      ".foo { text-shadow: 12px 12px lab(40% 56.6 39) }",
+      indoc! { r#"
+        .foo {
+          text-shadow: 12px 12px #b32323;
+          text-shadow: 12px 12px color(display-p3 .643308 .192455 .167712);
+          text-shadow: 12px 12px lab(40% 56.6 39);
+        }
       "#},
       Browsers {
-        chrome: Some(88 << 16),
-        firefox: Some(88 << 16),
-        edge: Some(79 << 16),
+        chrome: Some(90 << 16),
+        safari: Some(14 << 16),
         ..Browsers::default()
       },
     );
-  }
-
-  #[test]
-  fn test_text_align() {
-    minify_test(".foo { text-align: left }", ".foo{text-align:left}");
-    minify_test(".foo { text-align: Left }", ".foo{text-align:left}");
-    minify_test(".foo { text-align: END }", ".foo{text-align:end}");
-    minify_test(".foo { text-align: left }", ".foo{text-align:left}");
 
     prefix_test(
-      r#"
-      .foo {
-        text-align: start;
-      }
-    "#,
-      indoc! {r#"
-      .foo:not(:is(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi))) {
-        text-align: left;
-      }
-
-      .foo:is(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi)) {
-        text-align: right;
-      }
-    "#
-      },
+      ".foo { text-shadow: 12px 12px lab(40% 56.6 39), 12px 12px yellow }",
+      indoc! { r#"
+        .foo {
+          text-shadow: 12px 12px #b32323, 12px 12px #ff0;
+          text-shadow: 12px 12px lab(40% 56.6 39), 12px 12px #ff0;
+        }
+      "#},
       Browsers {
-        safari: Some(2 << 16),
+        chrome: Some(4 << 16),
         ..Browsers::default()
       },
     );
 
     prefix_test(
-      r#"
-      .foo {
-        text-align: end;
-      }
-    "#,
-      indoc! {r#"
-      .foo:not(:is(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi))) {
-        text-align: right;
-      }
+      ".foo { text-shadow: var(--foo) 12px lab(40% 56.6 39) }",
+      indoc! { r#"
+        .foo {
+          text-shadow: var(--foo) 12px #b32323;
+        }
 
-      .foo:is(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi)) {
-        text-align: left;
-      }
-    "#
-      },
+        @supports (color: lab(0% 0 0)) {
+          .foo {
+            text-shadow: var(--foo) 12px lab(40% 56.6 39);
+          }
+        }
+      "#},
       Browsers {
-        safari: Some(2 << 16),
+        chrome: Some(4 << 16),
         ..Browsers::default()
       },
     );
+  }
+
+  #[test]
+  fn test_webkit_text_stroke() {
+    minify_test(
+      ".foo { -webkit-text-fill-color: #ff0000 }",
+      ".foo{-webkit-text-fill-color:red}",
+    );
+    minify_test(
+      ".foo { -webkit-text-stroke-color: rgb(0, 0, 255) }",
+      ".foo{-webkit-text-stroke-color:#00f}",
+    );
+    minify_test(
+      ".foo { -webkit-text-stroke-width: 2px }",
+      ".foo{-webkit-text-stroke-width:2px}",
+    );
+    minify_test(
+      ".foo { -webkit-text-stroke-width: thin }",
+      ".foo{-webkit-text-stroke-width:thin}",
+    );
+    minify_test(
+      ".foo { -webkit-text-stroke: 1px #ff0000 }",
+      ".foo{-webkit-text-stroke:1px red}",
+    );
+    minify_test(
+      ".foo { -webkit-text-stroke: #ff0000 1px }",
+      ".foo{-webkit-text-stroke:1px red}",
+    );
+    minify_test(".foo { -webkit-text-stroke: 1px }", ".foo{-webkit-text-stroke:1px}");
+    minify_test(".foo { -webkit-text-stroke: red }", ".foo{-webkit-text-stroke:red}");
+    minify_test(
+      ".foo { -webkit-text-stroke: 0 currentColor }",
+      ".foo{-webkit-text-stroke:0}",
+    );
+    minify_test(
+      ".foo { -webkit-text-stroke: thick currentColor }",
+      ".foo{-webkit-text-stroke:thick}",
+    );
+    minify_test(".foo { -webkit-text-stroke: -1px }", ".foo{-webkit-text-stroke:-1px}"); // invalid
+    minify_test(
+      ".foo { -webkit-text-stroke: 1px red blue }",
+      ".foo{-webkit-text-stroke:1px red blue}",
+    ); // invalid
+    minify_test(".foo { text-stroke: 1px red }", ".foo{text-stroke:1px red}");
+    minify_test(
+      ".foo { -webkit-text-stroke-width: 2px; -webkit-text-stroke-color: blue }",
+      ".foo{-webkit-text-stroke:2px #00f}",
+    );
+    minify_test(
+      ".foo { -webkit-text-stroke: 2px blue; -webkit-text-stroke-color: red }",
+      ".foo{-webkit-text-stroke:2px red}",
+    );
+    minify_test(
+      ".foo { -webkit-text-fill-color: red; -webkit-text-fill-color: blue }",
+      ".foo{-webkit-text-fill-color:#00f}",
+    );
+    minify_test(
+      ".foo { -webkit-text-stroke: var(--stroke); -webkit-text-stroke-color: red }",
+      ".foo{-webkit-text-stroke:var(--stroke);-webkit-text-stroke-color:red}",
+    );
 
     prefix_test(
       r#"
       .foo {
-        text-align: start;
+        -webkit-text-fill-color: lab(40% 56.6 39);
+        -webkit-text-stroke: 1px lab(40% 56.6 39);
       }
     "#,
       indoc! {r#"
       .foo {
-        text-align: start;
+        -webkit-text-stroke: 1px #b32323;
+        -webkit-text-stroke: 1px lab(40% 56.6 39);
+        -webkit-text-fill-color: #b32323;
+        -webkit-text-fill-color: lab(40% 56.6 39);
       }
-    "#
-      },
+    "#},
       Browsers {
-        safari: Some(14 << 16),
+        chrome: Some(90 << 16),
         ..Browsers::default()
       },
     );
+  }
+
+  #[test]
+  fn test_break() {
+    minify_test(
+      ".foo { box-decoration-break: slice }",
+      ".foo{box-decoration-break:slice}",
+    );
+    minify_test(
+      ".foo { box-decoration-break: CLONE }",
+      ".foo{box-decoration-break:clone}",
+    );
+    minify_test(
+      ".foo { -webkit-box-decoration-break: clone }",
+      ".foo{-webkit-box-decoration-break:clone}",
+    );
+    minify_test(".foo { box-decoration-break: none }", ".foo{box-decoration-break:none}"); // invalid
 
     prefix_test(
       r#"
-      .foo > .bar {
-        text-align: start;
+      .foo {
+        box-decoration-break: clone;
       }
     "#,
       indoc! {r#"
-      .foo > .bar:not(:is(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi))) {
-        text-align: left;
-      }
-
-      .foo > .bar:is(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi)) {
-        text-align: right;
+      .foo {
+        -webkit-box-decoration-break: clone;
+        box-decoration-break: clone;
       }
-    "#
-      },
+    "#},
       Browsers {
-        safari: Some(2 << 16),
+        safari: Some(15 << 16),
         ..Browsers::default()
       },
     );
 
     prefix_test(
       r#"
-      .foo:after {
-        text-align: start;
+      .foo {
+        box-decoration-break: clone;
       }
     "#,
       indoc! {r#"
-      .foo:not(:is(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi))):after {
-        text-align: left;
-      }
-
-      .foo:is(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi)):after {
-        text-align: right;
+      .foo {
+        box-decoration-break: clone;
       }
-    "#
-      },
+    "#},
       Browsers {
-        safari: Some(2 << 16),
+        firefox: Some(95 << 16),
         ..Browsers::default()
       },
     );
 
     prefix_test(
       r#"
-      .foo:hover {
-        text-align: start;
+      .foo {
+        -webkit-box-decoration-break: slice;
+        box-decoration-break: slice;
       }
     "#,
       indoc! {r#"
-      .foo:hover:not(:is(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi))) {
-        text-align: left;
-      }
-
-      .foo:hover:is(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi)) {
-        text-align: right;
+      .foo {
+        box-decoration-break: slice;
       }
-    "#
-      },
+    "#},
       Browsers {
-        safari: Some(2 << 16),
+        firefox: Some(95 << 16),
         ..Browsers::default()
       },
     );
   }
 
   #[test]
-  fn test_text_align_last() {
-    minify_test(".foo { text-align-last: left }", ".foo{text-align-last:left}");
-    minify_test(".foo { text-align-last: justify }", ".foo{text-align-last:justify}");
-    prefix_test(
-      ".foo{ text-align-last: left }",
-      indoc! {r#"
-      .foo {
-        -moz-text-align-last: left;
-        text-align-last: left;
-      }
-      "#},
-      Browsers {
-        firefox: Some(40 << 16),
-        ..Browsers::default()
-      },
-    );
-    prefix_test(
+  fn test_position() {
+    test(
       r#"
       .foo {
-        -moz-text-align-last: left;
-        text-align-last: left;
+        position: relative;
+        position: absolute;
       }
-      "#,
+    "#,
       indoc! {r#"
       .foo {
-        text-align-last: left;
+        position: absolute;
       }
-      "#},
-      Browsers {
-        firefox: Some(88 << 16),
-        ..Browsers::default()
-      },
+    "#},
     );
-  }
-
-  #[test]
-  fn test_text_justify() {
-    minify_test(".foo { text-justify: auto }", ".foo{text-justify:auto}");
-    minify_test(".foo { text-justify: inter-word }", ".foo{text-justify:inter-word}");
-  }
-
-  #[test]
-  fn test_word_spacing() {
-    minify_test(".foo { word-spacing: normal }", ".foo{word-spacing:normal}");
-    minify_test(".foo { word-spacing: 3px }", ".foo{word-spacing:3px}");
-  }
-
-  #[test]
-  fn test_letter_spacing() {
-    minify_test(".foo { letter-spacing: normal }", ".foo{letter-spacing:normal}");
-    minify_test(".foo { letter-spacing: 3px }", ".foo{letter-spacing:3px}");
-  }
 
-  #[test]
-  fn test_text_indent() {
-    minify_test(".foo { text-indent: 20px }", ".foo{text-indent:20px}");
-    minify_test(".foo { text-indent: 10% }", ".foo{text-indent:10%}");
-    minify_test(".foo { text-indent: 3em hanging }", ".foo{text-indent:3em hanging}");
-    minify_test(".foo { text-indent: 3em each-line }", ".foo{text-indent:3em each-line}");
-    minify_test(
-      ".foo { text-indent: 3em hanging each-line }",
-      ".foo{text-indent:3em hanging each-line}",
-    );
-    minify_test(
-      ".foo { text-indent: 3em each-line hanging }",
-      ".foo{text-indent:3em hanging each-line}",
-    );
-    minify_test(
-      ".foo { text-indent: each-line 3em hanging }",
-      ".foo{text-indent:3em hanging each-line}",
-    );
-    minify_test(
-      ".foo { text-indent: each-line hanging 3em }",
-      ".foo{text-indent:3em hanging each-line}",
+    test(
+      r#"
+      .foo {
+        position: -webkit-sticky;
+        position: sticky;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        position: -webkit-sticky;
+        position: sticky;
+      }
+    "#},
     );
-  }
 
-  #[test]
-  fn test_text_size_adjust() {
-    minify_test(".foo { text-size-adjust: none }", ".foo{text-size-adjust:none}");
-    minify_test(".foo { text-size-adjust: auto }", ".foo{text-size-adjust:auto}");
-    minify_test(".foo { text-size-adjust: 80% }", ".foo{text-size-adjust:80%}");
     prefix_test(
       r#"
       .foo {
-        text-size-adjust: none;
+        position: sticky;
       }
     "#,
       indoc! {r#"
       .foo {
-        -webkit-text-size-adjust: none;
-        -moz-text-size-adjust: none;
-        -ms-text-size-adjust: none;
-        text-size-adjust: none;
+        position: -webkit-sticky;
+        position: sticky;
       }
     "#},
       Browsers {
-        ios_saf: Some(16 << 16),
-        edge: Some(15 << 16),
-        firefox: Some(20 << 16),
+        safari: Some(8 << 16),
         ..Browsers::default()
       },
     );
+
     prefix_test(
       r#"
       .foo {
-        -webkit-text-size-adjust: none;
-        -moz-text-size-adjust: none;
-        -ms-text-size-adjust: none;
-        text-size-adjust: none;
+        position: -webkit-sticky;
+        position: sticky;
       }
     "#,
       indoc! {r#"
       .foo {
-        text-size-adjust: none;
+        position: sticky;
       }
     "#},
       Browsers {
-        chrome: Some(110 << 16),
+        safari: Some(13 << 16),
         ..Browsers::default()
       },
     );
-  }
-
-  #[test]
-  fn test_text_decoration() {
-    minify_test(".foo { text-decoration-line: none }", ".foo{text-decoration-line:none}");
-    minify_test(
-      ".foo { text-decoration-line: underline }",
-      ".foo{text-decoration-line:underline}",
-    );
-    minify_test(
-      ".foo { text-decoration-line: overline }",
-      ".foo{text-decoration-line:overline}",
-    );
-    minify_test(
-      ".foo { text-decoration-line: line-through }",
-      ".foo{text-decoration-line:line-through}",
-    );
-    minify_test(
-      ".foo { text-decoration-line: blink }",
-      ".foo{text-decoration-line:blink}",
-    );
-    minify_test(
-      ".foo { text-decoration-line: underline overline }",
-      ".foo{text-decoration-line:underline overline}",
-    );
-    minify_test(
-      ".foo { text-decoration-line: overline underline }",
-      ".foo{text-decoration-line:underline overline}",
-    );
-    minify_test(
-      ".foo { text-decoration-line: overline line-through underline }",
-      ".foo{text-decoration-line:underline overline line-through}",
-    );
-    minify_test(
-      ".foo { text-decoration-line: spelling-error }",
-      ".foo{text-decoration-line:spelling-error}",
-    );
-    minify_test(
-      ".foo { text-decoration-line: grammar-error }",
-      ".foo{text-decoration-line:grammar-error}",
-    );
-    minify_test(
-      ".foo { -webkit-text-decoration-line: overline underline }",
-      ".foo{-webkit-text-decoration-line:underline overline}",
-    );
-    minify_test(
-      ".foo { -moz-text-decoration-line: overline underline }",
-      ".foo{-moz-text-decoration-line:underline overline}",
-    );
-
-    minify_test(
-      ".foo { text-decoration-style: solid }",
-      ".foo{text-decoration-style:solid}",
-    );
-    minify_test(
-      ".foo { text-decoration-style: dotted }",
-      ".foo{text-decoration-style:dotted}",
-    );
-    minify_test(
-      ".foo { -webkit-text-decoration-style: solid }",
-      ".foo{-webkit-text-decoration-style:solid}",
-    );
 
-    minify_test(
-      ".foo { text-decoration-color: yellow }",
-      ".foo{text-decoration-color:#ff0}",
-    );
-    minify_test(
-      ".foo { -webkit-text-decoration-color: yellow }",
-      ".foo{-webkit-text-decoration-color:#ff0}",
+    test(
+      r#"
+      .foo {
+        top: 0;
+        left: 0;
+        bottom: 0;
+        right: 0;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        inset: 0;
+      }
+    "#},
     );
 
-    minify_test(".foo { text-decoration: none }", ".foo{text-decoration:none}");
-    minify_test(
-      ".foo { text-decoration: underline dotted }",
-      ".foo{text-decoration:underline dotted}",
-    );
-    minify_test(
-      ".foo { text-decoration: underline dotted yellow }",
-      ".foo{text-decoration:underline dotted #ff0}",
-    );
-    minify_test(
-      ".foo { text-decoration: yellow dotted underline }",
-      ".foo{text-decoration:underline dotted #ff0}",
-    );
-    minify_test(
-      ".foo { text-decoration: underline overline dotted yellow }",
-      ".foo{text-decoration:underline overline dotted #ff0}",
-    );
-    minify_test(
-      ".foo { -webkit-text-decoration: yellow dotted underline }",
-      ".foo{-webkit-text-decoration:underline dotted #ff0}",
-    );
-    minify_test(
-      ".foo { -moz-text-decoration: yellow dotted underline }",
-      ".foo{-moz-text-decoration:underline dotted #ff0}",
+    test(
+      r#"
+      .foo {
+        top: 2px;
+        left: 4px;
+        bottom: 2px;
+        right: 4px;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        inset: 2px 4px;
+      }
+    "#},
     );
 
     test(
       r#"
       .foo {
-        text-decoration-line: underline;
-        text-decoration-style: dotted;
-        text-decoration-color: yellow;
-        text-decoration-thickness: 2px;
+        top: 1px;
+        left: 2px;
+        bottom: 3px;
+        right: 4px;
       }
     "#,
       indoc! {r#"
       .foo {
-        text-decoration: underline 2px dotted #ff0;
+        inset: 1px 4px 3px 2px;
       }
     "#},
     );
@@ -14182,13 +18813,16 @@ mod tests {
     test(
       r#"
       .foo {
-        text-decoration: underline;
-        text-decoration-style: dotted;
+        inset-block-start: 2px;
+        inset-block-end: 2px;
+        inset-inline-start: 4px;
+        inset-inline-end: 4px;
       }
     "#,
       indoc! {r#"
       .foo {
-        text-decoration: underline dotted;
+        inset-block: 2px;
+        inset-inline: 4px;
       }
     "#},
     );
@@ -14196,14 +18830,16 @@ mod tests {
     test(
       r#"
       .foo {
-        text-decoration: underline;
-        text-decoration-style: var(--style);
+        inset-block-start: 2px;
+        inset-block-end: 3px;
+        inset-inline-start: 4px;
+        inset-inline-end: 5px;
       }
     "#,
       indoc! {r#"
       .foo {
-        text-decoration: underline;
-        text-decoration-style: var(--style);
+        inset-block: 2px 3px;
+        inset-inline: 4px 5px;
       }
     "#},
     );
@@ -14211,92 +18847,100 @@ mod tests {
     test(
       r#"
       .foo {
-        -webkit-text-decoration: underline;
-        -webkit-text-decoration-style: dotted;
+        inset-block-start: 2px;
+        inset-block-end: 3px;
+        inset: 4px;
+        inset-inline-start: 4px;
+        inset-inline-end: 5px;
       }
     "#,
       indoc! {r#"
       .foo {
-        -webkit-text-decoration: underline dotted;
+        inset: 4px;
+        inset-inline: 4px 5px;
       }
     "#},
     );
 
-    prefix_test(
+    test(
       r#"
       .foo {
-        text-decoration: underline dotted;
+        inset: 10px;
+        top: 20px;
       }
     "#,
       indoc! {r#"
       .foo {
-        -webkit-text-decoration: underline dotted;
-        text-decoration: underline dotted;
+        inset: 20px 10px 10px;
       }
     "#},
-      Browsers {
-        safari: Some(8 << 16),
-        firefox: Some(30 << 16),
-        ..Browsers::default()
-      },
     );
 
-    prefix_test(
+    test(
       r#"
       .foo {
-        text-decoration-line: underline;
+        inset: 10px;
+        top: var(--top);
       }
     "#,
       indoc! {r#"
       .foo {
-        -webkit-text-decoration-line: underline;
-        -moz-text-decoration-line: underline;
-        text-decoration-line: underline;
+        inset: 10px;
+        top: var(--top);
       }
     "#},
-      Browsers {
-        safari: Some(8 << 16),
-        firefox: Some(30 << 16),
-        ..Browsers::default()
-      },
     );
 
-    prefix_test(
+    test(
       r#"
       .foo {
-        text-decoration-style: dotted;
+        inset-inline-start: 2px;
+        inset: 4px;
+        top: 8px;
       }
     "#,
       indoc! {r#"
       .foo {
-        -webkit-text-decoration-style: dotted;
-        -moz-text-decoration-style: dotted;
-        text-decoration-style: dotted;
+        inset: 8px 4px 4px;
       }
     "#},
-      Browsers {
-        safari: Some(8 << 16),
-        firefox: Some(30 << 16),
-        ..Browsers::default()
-      },
     );
 
+    minify_test(".foo { inset: 1px 2px 1px 2px }", ".foo{inset:1px 2px}");
+    minify_test(".foo { inset: auto }", ".foo{inset:auto}");
+    minify_test(".foo { inset: 0 auto auto 0 }", ".foo{inset:0 auto auto 0}");
+    minify_test(
+      ".foo { top: 0; right: auto; bottom: auto; left: 0 }",
+      ".foo{inset:0 auto auto 0}",
+    );
+    minify_test(".foo { inset-block: 2px 2px }", ".foo{inset-block:2px}");
+
     prefix_test(
       r#"
       .foo {
-        text-decoration-color: yellow;
+        inset-inline-start: 2px;
       }
     "#,
       indoc! {r#"
-      .foo {
-        -webkit-text-decoration-color: #ff0;
-        -moz-text-decoration-color: #ff0;
-        text-decoration-color: #ff0;
+      .foo:not(:-webkit-any(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi))) {
+        left: 2px;
+      }
+
+      .foo:not(:is(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi))) {
+        left: 2px;
+      }
+
+      .foo:-webkit-any(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi)) {
+        right: 2px;
+      }
+
+      .foo:is(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi)) {
+        right: 2px;
       }
-    "#},
+    "#
+      },
       Browsers {
         safari: Some(8 << 16),
-        firefox: Some(30 << 16),
         ..Browsers::default()
       },
     );
@@ -14304,37 +18948,34 @@ mod tests {
     prefix_test(
       r#"
       .foo {
-        text-decoration: underline;
+        inset-inline-start: 2px;
+        inset-inline-end: 4px;
       }
     "#,
       indoc! {r#"
-      .foo {
-        text-decoration: underline;
+      .foo:not(:-webkit-any(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi))) {
+        left: 2px;
+        right: 4px;
       }
-    "#},
-      Browsers {
-        safari: Some(8 << 16),
-        firefox: Some(30 << 16),
-        ..Browsers::default()
-      },
-    );
 
-    prefix_test(
-      r#"
-      .foo {
-        -webkit-text-decoration: underline dotted;
-        text-decoration: underline dotted;
+      .foo:not(:is(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi))) {
+        left: 2px;
+        right: 4px;
       }
-    "#,
-      indoc! {r#"
-      .foo {
-        -webkit-text-decoration: underline dotted;
-        text-decoration: underline dotted;
+
+      .foo:-webkit-any(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi)) {
+        left: 4px;
+        right: 2px;
       }
-    "#},
+
+      .foo:is(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi)) {
+        left: 4px;
+        right: 2px;
+      }
+    "#
+      },
       Browsers {
-        safari: Some(14 << 16),
-        firefox: Some(45 << 16),
+        safari: Some(8 << 16),
         ..Browsers::default()
       },
     );
@@ -14342,17 +18983,18 @@ mod tests {
     prefix_test(
       r#"
       .foo {
-        text-decoration: double underline;
+        inset-inline: 2px;
       }
     "#,
       indoc! {r#"
       .foo {
-        -webkit-text-decoration: underline double;
-        text-decoration: underline double;
+        left: 2px;
+        right: 2px;
       }
-    "#},
+    "#
+      },
       Browsers {
-        safari: Some(16 << 16),
+        safari: Some(8 << 16),
         ..Browsers::default()
       },
     );
@@ -14360,18 +19002,17 @@ mod tests {
     prefix_test(
       r#"
       .foo {
-        text-decoration: underline;
-        text-decoration-style: double;
+        inset-block-start: 2px;
       }
     "#,
       indoc! {r#"
       .foo {
-        -webkit-text-decoration: underline double;
-        text-decoration: underline double;
+        top: 2px;
       }
-    "#},
+    "#
+      },
       Browsers {
-        safari: Some(16 << 16),
+        safari: Some(8 << 16),
         ..Browsers::default()
       },
     );
@@ -14379,18 +19020,17 @@ mod tests {
     prefix_test(
       r#"
       .foo {
-        text-decoration: underline;
-        text-decoration-color: red;
+        inset-block-end: 2px;
       }
     "#,
       indoc! {r#"
       .foo {
-        -webkit-text-decoration: underline red;
-        text-decoration: underline red;
+        bottom: 2px;
       }
-    "#},
+    "#
+      },
       Browsers {
-        safari: Some(16 << 16),
+        safari: Some(8 << 16),
         ..Browsers::default()
       },
     );
@@ -14398,302 +19038,358 @@ mod tests {
     prefix_test(
       r#"
       .foo {
-        text-decoration: var(--test);
+        top: 1px;
+        left: 2px;
+        bottom: 3px;
+        right: 4px;
       }
     "#,
       indoc! {r#"
       .foo {
-        -webkit-text-decoration: var(--test);
-        text-decoration: var(--test);
+        top: 1px;
+        bottom: 3px;
+        left: 2px;
+        right: 4px;
       }
     "#},
       Browsers {
         safari: Some(8 << 16),
-        firefox: Some(30 << 16),
         ..Browsers::default()
       },
     );
+  }
+
+  #[test]
+  fn test_anchor_position() {
+    minify_test(".foo { anchor-name: none }", ".foo{anchor-name:none}");
+    minify_test(".foo { anchor-name: --foo }", ".foo{anchor-name:--foo}");
+    minify_test(".foo { anchor-name: --foo, --bar }", ".foo{anchor-name:--foo,--bar}");
+    minify_test(".foo { anchor-name: foo }", ".foo{anchor-name:foo}"); // invalid
+
+    minify_test(".foo { position-anchor: auto }", ".foo{position-anchor:auto}");
+    minify_test(".foo { position-anchor: --foo }", ".foo{position-anchor:--foo}");
+    minify_test(".foo { position-anchor: foo }", ".foo{position-anchor:foo}"); // invalid
 
+    minify_test(".foo { position-area: none }", ".foo{position-area:none}");
+    minify_test(".foo { position-area: top }", ".foo{position-area:top}");
+    minify_test(".foo { position-area: top left }", ".foo{position-area:top left}");
+    minify_test(".foo { position-area: top span-all }", ".foo{position-area:top}");
+    minify_test(".foo { position-area: span-all top }", ".foo{position-area:top}");
+    minify_test(".foo { position-area: center top }", ".foo{position-area:center top}");
+    minify_test(".foo { position-area: center center }", ".foo{position-area:center}");
     minify_test(
-      ".foo { text-decoration-skip-ink: all }",
-      ".foo{text-decoration-skip-ink:all}",
+      ".foo { position-area: span-all span-all }",
+      ".foo{position-area:span-all}",
     );
     minify_test(
-      ".foo { -webkit-text-decoration-skip-ink: all }",
-      ".foo{-webkit-text-decoration-skip-ink:all}",
+      ".foo { position-area: block-start span-inline-end }",
+      ".foo{position-area:block-start span-inline-end}",
     );
+    minify_test(".foo { position-area: start end }", ".foo{position-area:start end}");
+    minify_test(".foo { position-area: start start }", ".foo{position-area:start}");
+    minify_test(".foo { position-area: top bottom }", ".foo{position-area:top bottom}"); // invalid
+    minify_test(
+      ".foo { position-area: block-start left }",
+      ".foo{position-area:block-start left}",
+    ); // invalid
+    minify_test(
+      ".foo { position-area: start self-end }",
+      ".foo{position-area:start self-end}",
+    ); // invalid
+    minify_test(".foo { inset-area: span-top left }", ".foo{inset-area:span-top left}");
 
-    prefix_test(
-      r#"
-      .foo {
-        text-decoration: lch(50.998% 135.363 338) underline;
-      }
-    "#,
-      indoc! {r#"
-      .foo {
-        -webkit-text-decoration: underline #ee00be;
-        text-decoration: underline #ee00be;
-        -webkit-text-decoration: underline lch(50.998% 135.363 338);
-        text-decoration: underline lch(50.998% 135.363 338);
-      }
-    "#},
-      Browsers {
-        safari: Some(8 << 16),
-        firefox: Some(30 << 16),
-        ..Browsers::default()
-      },
+    minify_test(".foo { position-try-order: normal }", ".foo{position-try-order:normal}");
+    minify_test(
+      ".foo { position-try-order: most-width }",
+      ".foo{position-try-order:most-width}",
+    );
+    minify_test(
+      ".foo { position-try-fallbacks: none }",
+      ".foo{position-try-fallbacks:none}",
+    );
+    minify_test(
+      ".foo { position-try-fallbacks: flip-block }",
+      ".foo{position-try-fallbacks:flip-block}",
     );
+    minify_test(
+      ".foo { position-try-fallbacks: flip-inline --foo }",
+      ".foo{position-try-fallbacks:--foo flip-inline}",
+    );
+    minify_test(
+      ".foo { position-try-fallbacks: --foo, flip-block flip-inline, top left }",
+      ".foo{position-try-fallbacks:--foo,flip-block flip-inline,top left}",
+    );
+    minify_test(
+      ".foo { position-try-fallbacks: flip-block flip-block }",
+      ".foo{position-try-fallbacks:flip-block flip-block}",
+    ); // invalid
+    minify_test(
+      ".foo { position-try-fallbacks: --foo --bar }",
+      ".foo{position-try-fallbacks:--foo --bar}",
+    ); // invalid
 
-    prefix_test(
+    minify_test(".foo { position-try: none }", ".foo{position-try:none}");
+    minify_test(".foo { position-try: normal --foo }", ".foo{position-try:--foo}");
+    minify_test(
+      ".foo { position-try: most-height --foo, flip-start }",
+      ".foo{position-try:most-height --foo,flip-start}",
+    );
+    minify_test(".foo { position-try: most-height }", ".foo{position-try:most-height}"); // invalid
+
+    test(
       r#"
       .foo {
-        text-decoration-color: lch(50.998% 135.363 338);
+        position-try-order: most-width;
+        position-try-fallbacks: --foo, flip-block;
       }
     "#,
       indoc! {r#"
       .foo {
-        -webkit-text-decoration-color: #ee00be;
-        -moz-text-decoration-color: #ee00be;
-        text-decoration-color: #ee00be;
-        -webkit-text-decoration-color: lch(50.998% 135.363 338);
-        -moz-text-decoration-color: lch(50.998% 135.363 338);
-        text-decoration-color: lch(50.998% 135.363 338);
+        position-try: most-width --foo, flip-block;
       }
     "#},
-      Browsers {
-        safari: Some(8 << 16),
-        firefox: Some(30 << 16),
-        ..Browsers::default()
-      },
     );
 
-    prefix_test(
+    test(
       r#"
       .foo {
-        text-decoration: lch(50.998% 135.363 338) var(--style);
+        position-try: --foo;
+        position-try-order: most-block-size;
       }
     "#,
       indoc! {r#"
       .foo {
-        text-decoration: #ee00be var(--style);
-      }
-
-      @supports (color: lab(0% 0 0)) {
-        .foo {
-          text-decoration: lab(50.998% 125.506 -50.7078) var(--style);
-        }
+        position-try: most-block-size --foo;
       }
     "#},
-      Browsers {
-        chrome: Some(90 << 16),
-        ..Browsers::default()
-      },
     );
+  }
 
-    prefix_test(
+  #[test]
+  fn test_overflow() {
+    minify_test(".foo { overflow: hidden }", ".foo{overflow:hidden}");
+    minify_test(".foo { overflow: hidden hidden }", ".foo{overflow:hidden}");
+    minify_test(".foo { overflow: hidden auto }", ".foo{overflow:hidden auto}");
+
+    test(
       r#"
       .foo {
-        text-decoration: underline 10px;
+        overflow-x: hidden;
+        overflow-y: auto;
       }
     "#,
       indoc! {r#"
       .foo {
-        text-decoration: underline;
-        text-decoration-thickness: 10px;
+        overflow: hidden auto;
       }
     "#},
-      Browsers {
-        safari: Some(15 << 16),
-        ..Browsers::default()
-      },
     );
 
-    prefix_test(
+    test(
       r#"
       .foo {
-        text-decoration: underline 10px;
+        overflow: hidden;
+        overflow-y: auto;
       }
     "#,
       indoc! {r#"
       .foo {
-        text-decoration: underline 10px;
+        overflow: hidden auto;
       }
     "#},
-      Browsers {
-        chrome: Some(90 << 16),
-        ..Browsers::default()
-      },
     );
-
-    prefix_test(
+    test(
       r#"
       .foo {
-        text-decoration: underline 10%;
+        overflow: hidden;
+        overflow-y: var(--y);
       }
     "#,
       indoc! {r#"
       .foo {
-        text-decoration: underline;
-        text-decoration-thickness: calc(1em / 10);
+        overflow: hidden;
+        overflow-y: var(--y);
       }
     "#},
-      Browsers {
-        safari: Some(12 << 16),
-        ..Browsers::default()
-      },
     );
-
     prefix_test(
       r#"
       .foo {
-        text-decoration: underline 10%;
+        overflow: hidden auto;
       }
     "#,
       indoc! {r#"
       .foo {
-        text-decoration: underline 10%;
+        overflow-x: hidden;
+        overflow-y: auto;
       }
     "#},
       Browsers {
-        firefox: Some(89 << 16),
+        chrome: Some(67 << 16),
         ..Browsers::default()
       },
     );
-
     prefix_test(
       r#"
       .foo {
-        text-decoration-thickness: 10%;
+        overflow: hidden hidden;
       }
     "#,
       indoc! {r#"
       .foo {
-        text-decoration-thickness: calc(1em / 10);
+        overflow: hidden;
       }
     "#},
       Browsers {
-        safari: Some(12 << 16),
+        chrome: Some(67 << 16),
         ..Browsers::default()
       },
     );
-
     prefix_test(
       r#"
       .foo {
-        text-decoration-thickness: 10%;
+        overflow: hidden auto;
       }
     "#,
       indoc! {r#"
       .foo {
-        text-decoration-thickness: 10%;
+        overflow: hidden auto;
       }
     "#},
       Browsers {
-        firefox: Some(89 << 16),
+        chrome: Some(68 << 16),
         ..Browsers::default()
       },
     );
-  }
 
-  #[test]
-  fn test_text_emphasis() {
-    minify_test(".foo { text-emphasis-style: none }", ".foo{text-emphasis-style:none}");
+    minify_test(".foo { text-overflow: ellipsis }", ".foo{text-overflow:ellipsis}");
+    minify_test(".foo { text-overflow: CLIP }", ".foo{text-overflow:clip}");
+    minify_test(".foo { text-overflow: '…' }", ".foo{text-overflow:\"…\"}");
     minify_test(
-      ".foo { text-emphasis-style: filled }",
-      ".foo{text-emphasis-style:filled}",
+      ".foo { text-overflow: clip ellipsis }",
+      ".foo{text-overflow:clip ellipsis}",
     );
-    minify_test(".foo { text-emphasis-style: open }", ".foo{text-emphasis-style:open}");
-    minify_test(".foo { text-emphasis-style: dot }", ".foo{text-emphasis-style:dot}");
     minify_test(
-      ".foo { text-emphasis-style: filled dot }",
-      ".foo{text-emphasis-style:dot}",
+      ".foo { text-overflow: ellipsis ellipsis }",
+      ".foo{text-overflow:ellipsis ellipsis}",
     );
+    minify_test(".foo { text-overflow: '[' ']' }", ".foo{text-overflow:\"[\" \"]\"}");
     minify_test(
-      ".foo { text-emphasis-style: dot filled }",
-      ".foo{text-emphasis-style:dot}",
+      ".foo { text-overflow: clip clip clip }",
+      ".foo{text-overflow:clip clip clip}",
     );
+    minify_test(".foo { text-overflow: fade }", ".foo{text-overflow:fade}");
+
+    minify_test(".foo { overflow-clip-margin: 0px }", ".foo{overflow-clip-margin:0}");
+    minify_test(".foo { overflow-clip-margin: 20px }", ".foo{overflow-clip-margin:20px}");
     minify_test(
-      ".foo { text-emphasis-style: open dot }",
-      ".foo{text-emphasis-style:open dot}",
+      ".foo { overflow-clip-margin: padding-box 20px }",
+      ".foo{overflow-clip-margin:20px}",
     );
     minify_test(
-      ".foo { text-emphasis-style: dot open }",
-      ".foo{text-emphasis-style:open dot}",
+      ".foo { overflow-clip-margin: 20px content-box }",
+      ".foo{overflow-clip-margin:content-box 20px}",
+    );
+    minify_test(
+      ".foo { overflow-clip-margin: border-box }",
+      ".foo{overflow-clip-margin:border-box}",
     );
-    minify_test(".foo { text-emphasis-style: \"x\" }", ".foo{text-emphasis-style:\"x\"}");
-
-    minify_test(".foo { text-emphasis-color: yellow }", ".foo{text-emphasis-color:#ff0}");
-
-    minify_test(".foo { text-emphasis: none }", ".foo{text-emphasis:none}");
-    minify_test(".foo { text-emphasis: filled }", ".foo{text-emphasis:filled}");
     minify_test(
-      ".foo { text-emphasis: filled yellow }",
-      ".foo{text-emphasis:filled #ff0}",
+      ".foo { overflow-clip-margin: border-box 0px }",
+      ".foo{overflow-clip-margin:border-box}",
     );
     minify_test(
-      ".foo { text-emphasis: dot filled yellow }",
-      ".foo{text-emphasis:dot #ff0}",
+      ".foo { overflow-clip-margin: -10px }",
+      ".foo{overflow-clip-margin:-10px}",
     );
-
-    test(
+    prefix_test(
       r#"
       .foo {
-        text-emphasis-style: filled;
-        text-emphasis-color: yellow;
+        text-overflow: ellipsis;
       }
     "#,
       indoc! {r#"
       .foo {
-        text-emphasis: filled #ff0;
+        -o-text-overflow: ellipsis;
+        text-overflow: ellipsis;
       }
     "#},
+      Browsers {
+        safari: Some(4 << 16),
+        opera: Some(10 << 16),
+        ..Browsers::default()
+      },
     );
 
-    test(
+    prefix_test(
       r#"
       .foo {
-        text-emphasis: filled red;
-        text-emphasis-color: yellow;
+        -o-text-overflow: ellipsis;
+        text-overflow: ellipsis;
       }
     "#,
       indoc! {r#"
       .foo {
-        text-emphasis: filled #ff0;
+        text-overflow: ellipsis;
       }
     "#},
+      Browsers {
+        safari: Some(4 << 16),
+        opera: Some(14 << 16),
+        ..Browsers::default()
+      },
     );
+  }
 
-    test(
+  #[test]
+  fn test_line_clamp() {
+    minify_test(".foo { line-clamp: none }", ".foo{line-clamp:none}");
+    minify_test(".foo { line-clamp: 3 }", ".foo{line-clamp:3}");
+    minify_test(".foo { -webkit-line-clamp: 3 }", ".foo{-webkit-line-clamp:3}");
+    minify_test(".foo { line-clamp: 0 }", ".foo{line-clamp:0}"); // invalid
+    minify_test(".foo { line-clamp: 1.5 }", ".foo{line-clamp:1.5}"); // invalid
+    minify_test(".foo { continue: auto }", ".foo{continue:auto}");
+    minify_test(".foo { continue: discard }", ".foo{continue:discard}");
+    minify_test(
+      ".foo { -webkit-line-clamp: 3; line-clamp: 3 }",
+      ".foo{-webkit-line-clamp:3;line-clamp:3}",
+    );
+    minify_test(".foo { line-clamp: 2; line-clamp: 3 }", ".foo{line-clamp:3}");
+
+    prefix_test(
       r#"
       .foo {
-        text-emphasis: filled yellow;
-        text-emphasis-color: var(--color);
+        line-clamp: 3;
       }
     "#,
       indoc! {r#"
       .foo {
-        text-emphasis: filled #ff0;
-        text-emphasis-color: var(--color);
+        -webkit-line-clamp: 3;
+        line-clamp: 3;
       }
     "#},
+      Browsers {
+        chrome: Some(100 << 16),
+        safari: Some(16 << 16),
+        firefox: Some(100 << 16),
+        ..Browsers::default()
+      },
     );
 
     prefix_test(
       r#"
       .foo {
-        text-emphasis-style: filled;
+        line-clamp: none;
       }
     "#,
       indoc! {r#"
       .foo {
-        -webkit-text-emphasis-style: filled;
-        text-emphasis-style: filled;
+        -webkit-line-clamp: none;
+        line-clamp: none;
       }
     "#},
       Browsers {
-        safari: Some(10 << 16),
-        chrome: Some(30 << 16),
-        firefox: Some(45 << 16),
+        chrome: Some(100 << 16),
         ..Browsers::default()
       },
     );
@@ -14701,55 +19397,43 @@ mod tests {
     prefix_test(
       r#"
       .foo {
-        -webkit-text-emphasis-style: filled;
-        text-emphasis-style: filled;
+        display: -webkit-box;
+        -webkit-box-orient: vertical;
+        -webkit-line-clamp: 3;
+        line-clamp: 3;
       }
     "#,
       indoc! {r#"
       .foo {
-        text-emphasis-style: filled;
+        -webkit-box-orient: vertical;
+        display: -webkit-box;
+        -webkit-line-clamp: 3;
+        line-clamp: 3;
       }
     "#},
       Browsers {
-        safari: Some(10 << 16),
-        firefox: Some(45 << 16),
+        chrome: Some(100 << 16),
         ..Browsers::default()
       },
     );
 
-    minify_test(
-      ".foo { text-emphasis-position: over }",
-      ".foo{text-emphasis-position:over}",
-    );
-    minify_test(
-      ".foo { text-emphasis-position: under }",
-      ".foo{text-emphasis-position:under}",
-    );
-    minify_test(
-      ".foo { text-emphasis-position: over right }",
-      ".foo{text-emphasis-position:over}",
-    );
-    minify_test(
-      ".foo { text-emphasis-position: over left }",
-      ".foo{text-emphasis-position:over left}",
-    );
-
     prefix_test(
       r#"
       .foo {
-        text-emphasis-position: over;
+        display: -webkit-box;
+        line-clamp: 3;
       }
     "#,
       indoc! {r#"
       .foo {
-        -webkit-text-emphasis-position: over;
-        text-emphasis-position: over;
+        display: -webkit-box;
+        -webkit-box-orient: vertical;
+        -webkit-line-clamp: 3;
+        line-clamp: 3;
       }
     "#},
       Browsers {
-        safari: Some(10 << 16),
-        chrome: Some(30 << 16),
-        firefox: Some(45 << 16),
+        chrome: Some(100 << 16),
         ..Browsers::default()
       },
     );
@@ -14757,18 +19441,19 @@ mod tests {
     prefix_test(
       r#"
       .foo {
-        text-emphasis-position: over left;
+        display: flex;
+        line-clamp: 3;
       }
     "#,
       indoc! {r#"
       .foo {
-        text-emphasis-position: over left;
+        display: flex;
+        -webkit-line-clamp: 3;
+        line-clamp: 3;
       }
     "#},
       Browsers {
-        safari: Some(10 << 16),
-        chrome: Some(30 << 16),
-        firefox: Some(45 << 16),
+        chrome: Some(100 << 16),
         ..Browsers::default()
       },
     );
@@ -14776,226 +19461,261 @@ mod tests {
     prefix_test(
       r#"
       .foo {
-        text-emphasis-position: var(--test);
+        line-clamp: 3;
+        -webkit-box-orient: vertical;
       }
     "#,
       indoc! {r#"
       .foo {
-        -webkit-text-emphasis-position: var(--test);
-        text-emphasis-position: var(--test);
+        -webkit-box-orient: vertical;
+        display: -webkit-box;
+        -webkit-line-clamp: 3;
+        line-clamp: 3;
       }
     "#},
       Browsers {
-        safari: Some(10 << 16),
-        chrome: Some(30 << 16),
-        firefox: Some(45 << 16),
+        chrome: Some(100 << 16),
         ..Browsers::default()
       },
     );
+  }
 
-    prefix_test(
+  #[test]
+  fn test_motion_path() {
+    minify_test(".foo { offset-path: none }", ".foo{offset-path:none}");
+    minify_test(
+      ".foo { offset-path: path('M 0 0 L 100 100') }",
+      ".foo{offset-path:path(\"M 0 0 L 100 100\")}",
+    );
+    minify_test(".foo { offset-path: ray(45deg) }", ".foo{offset-path:ray(45deg)}");
+    minify_test(
+      ".foo { offset-path: ray(45deg closest-side contain at center) }",
+      ".foo{offset-path:ray(45deg contain at 50%)}",
+    );
+    minify_test(
+      ".foo { offset-path: ray(farthest-corner 90deg) }",
+      ".foo{offset-path:ray(90deg farthest-corner)}",
+    );
+    minify_test(
+      ".foo { offset-path: ray(45deg) padding-box }",
+      ".foo{offset-path:ray(45deg) padding-box}",
+    );
+    minify_test(".foo { offset-path: url('#path') }", ".foo{offset-path:url(#path)}");
+    minify_test(
+      ".foo { offset-path: circle(50px at 0 100px) padding-box }",
+      ".foo{offset-path:circle(50px at 0 100px) padding-box}",
+    );
+    minify_test(
+      ".foo { offset-path: content-box circle(50px) }",
+      ".foo{offset-path:circle(50px) content-box}",
+    );
+    minify_test(
+      ".foo { offset-path: circle(50px) border-box }",
+      ".foo{offset-path:circle(50px)}",
+    );
+    minify_test(".foo { offset-path: border-box }", ".foo{offset-path:border-box}");
+    minify_test(".foo { offset-path: margin-box }", ".foo{offset-path:margin-box}"); // invalid
+    minify_test(
+      ".foo { offset-path: ray(45deg) margin-box }",
+      ".foo{offset-path:ray(45deg)margin-box}",
+    ); // invalid
+    minify_test(
+      ".foo { offset-path: ray(closest-side) }",
+      ".foo{offset-path:ray(closest-side)}",
+    ); // invalid
+
+    minify_test(".foo { offset-distance: 50% }", ".foo{offset-distance:50%}");
+    minify_test(".foo { offset-distance: 10px }", ".foo{offset-distance:10px}");
+
+    minify_test(".foo { offset-rotate: auto }", ".foo{offset-rotate:auto}");
+    minify_test(".foo { offset-rotate: auto 0deg }", ".foo{offset-rotate:auto}");
+    minify_test(".foo { offset-rotate: reverse }", ".foo{offset-rotate:reverse}");
+    minify_test(".foo { offset-rotate: auto 180deg }", ".foo{offset-rotate:reverse}");
+    minify_test(".foo { offset-rotate: auto 45deg }", ".foo{offset-rotate:auto 45deg}");
+    minify_test(".foo { offset-rotate: 45deg auto }", ".foo{offset-rotate:auto 45deg}");
+    minify_test(
+      ".foo { offset-rotate: reverse 45deg }",
+      ".foo{offset-rotate:auto 225deg}",
+    );
+    minify_test(".foo { offset-rotate: 30deg }", ".foo{offset-rotate:30deg}");
+    minify_test(".foo { offset-rotate: auto auto }", ".foo{offset-rotate:auto auto}"); // invalid
+
+    minify_test(".foo { offset-anchor: auto }", ".foo{offset-anchor:auto}");
+    minify_test(".foo { offset-anchor: center }", ".foo{offset-anchor:50%}");
+    minify_test(".foo { offset-anchor: right bottom }", ".foo{offset-anchor:100% 100%}");
+    minify_test(".foo { offset-position: normal }", ".foo{offset-position:normal}");
+    minify_test(".foo { offset-position: auto }", ".foo{offset-position:auto}");
+    minify_test(".foo { offset-position: left top }", ".foo{offset-position:0 0}");
+
+    minify_test(".foo { offset: none }", ".foo{offset:none}");
+    minify_test(".foo { offset: auto }", ".foo{offset:auto}");
+    minify_test(
+      ".foo { offset: path('M 0 0 L 100 100') 50% }",
+      ".foo{offset:path(\"M 0 0 L 100 100\") 50%}",
+    );
+    minify_test(
+      ".foo { offset: ray(45deg) reverse 10px }",
+      ".foo{offset:ray(45deg) 10px reverse}",
+    );
+    minify_test(
+      ".foo { offset: 10px 20px path('M 0 0 L 100 100') }",
+      ".foo{offset:10px 20px path(\"M 0 0 L 100 100\")}",
+    );
+    minify_test(".foo { offset: left top }", ".foo{offset:0 0}");
+    minify_test(
+      ".foo { offset: url('#path') 30% auto 45deg / right bottom }",
+      ".foo{offset:url(#path) 30% auto 45deg/100% 100%}",
+    );
+    minify_test(".foo { offset: auto none 0 auto / auto }", ".foo{offset:auto}");
+    minify_test(".foo { offset: 10px 20px 30px }", ".foo{offset:10px 20px 30px}"); // invalid
+
+    test(
       r#"
       .foo {
-        text-emphasis: filled lch(50.998% 135.363 338);
+        offset-path: path('M 0 0 L 100 100');
+        offset-distance: 50%;
+        offset-rotate: auto;
+        offset-anchor: auto;
+        offset-position: normal;
       }
     "#,
       indoc! {r#"
       .foo {
-        -webkit-text-emphasis: filled #ee00be;
-        text-emphasis: filled #ee00be;
-        -webkit-text-emphasis: filled lch(50.998% 135.363 338);
-        text-emphasis: filled lch(50.998% 135.363 338);
+        offset: path("M 0 0 L 100 100") 50%;
       }
     "#},
-      Browsers {
-        chrome: Some(25 << 16),
-        firefox: Some(48 << 16),
-        ..Browsers::default()
-      },
     );
 
-    prefix_test(
+    test(
       r#"
       .foo {
-        text-emphasis-color: lch(50.998% 135.363 338);
+        offset: ray(45deg) 10px;
+        offset-rotate: reverse;
       }
     "#,
       indoc! {r#"
       .foo {
-        -webkit-text-emphasis-color: #ee00be;
-        text-emphasis-color: #ee00be;
-        -webkit-text-emphasis-color: lch(50.998% 135.363 338);
-        text-emphasis-color: lch(50.998% 135.363 338);
+        offset: ray(45deg) 10px reverse;
       }
     "#},
-      Browsers {
-        chrome: Some(25 << 16),
-        firefox: Some(48 << 16),
-        ..Browsers::default()
-      },
     );
 
-    prefix_test(
+    test(
       r#"
       .foo {
-        text-emphasis: lch(50.998% 135.363 338) var(--style);
+        offset-path: ray(45deg);
+        offset-distance: 10px;
       }
     "#,
       indoc! {r#"
       .foo {
-        text-emphasis: #ee00be var(--style);
-      }
-
-      @supports (color: lab(0% 0 0)) {
-        .foo {
-          text-emphasis: lab(50.998% 125.506 -50.7078) var(--style);
-        }
+        offset-path: ray(45deg);
+        offset-distance: 10px;
       }
     "#},
-      Browsers {
-        safari: Some(8 << 16),
-        ..Browsers::default()
-      },
     );
   }
 
   #[test]
-  fn test_text_shadow() {
+  fn test_overscroll_behavior() {
+    minify_test(".foo { overscroll-behavior: none }", ".foo{overscroll-behavior:none}");
     minify_test(
-      ".foo { text-shadow: 1px 1px 2px yellow; }",
-      ".foo{text-shadow:1px 1px 2px #ff0}",
+      ".foo { overscroll-behavior: none none }",
+      ".foo{overscroll-behavior:none}",
     );
     minify_test(
-      ".foo { text-shadow: 1px 1px 2px 3px yellow; }",
-      ".foo{text-shadow:1px 1px 2px 3px #ff0}",
+      ".foo { overscroll-behavior: auto contain }",
+      ".foo{overscroll-behavior:auto contain}",
     );
     minify_test(
-      ".foo { text-shadow: 1px 1px 0 yellow; }",
-      ".foo{text-shadow:1px 1px #ff0}",
+      ".foo { overscroll-behavior-x: contain }",
+      ".foo{overscroll-behavior-x:contain}",
     );
     minify_test(
-      ".foo { text-shadow: 1px 1px yellow; }",
-      ".foo{text-shadow:1px 1px #ff0}",
+      ".foo { overscroll-behavior-y: auto }",
+      ".foo{overscroll-behavior-y:auto}",
     );
     minify_test(
-      ".foo { text-shadow: 1px 1px yellow, 2px 3px red; }",
-      ".foo{text-shadow:1px 1px #ff0,2px 3px red}",
-    );
-
-    prefix_test(
-      ".foo { text-shadow: 12px 12px lab(40% 56.6 39) }",
-      indoc! { r#"
-        .foo {
-          text-shadow: 12px 12px #b32323;
-          text-shadow: 12px 12px lab(40% 56.6 39);
-        }
-      "#},
-      Browsers {
-        chrome: Some(4 << 16),
-        ..Browsers::default()
-      },
+      ".foo { overscroll-behavior-block: none }",
+      ".foo{overscroll-behavior-block:none}",
     );
-
-    prefix_test(
-      ".foo { text-shadow: 12px 12px lab(40% 56.6 39) }",
-      indoc! { r#"
-        .foo {
-          text-shadow: 12px 12px #b32323;
-          text-shadow: 12px 12px color(display-p3 .643308 .192455 .167712);
-          text-shadow: 12px 12px lab(40% 56.6 39);
-        }
-      "#},
-      Browsers {
-        chrome: Some(90 << 16),
-        safari: Some(14 << 16),
-        ..Browsers::default()
-      },
+    minify_test(
+      ".foo { overscroll-behavior-inline: contain }",
+      ".foo{overscroll-behavior-inline:contain}",
     );
+    minify_test(
+      ".foo { overscroll-behavior: auto contain none }",
+      ".foo{overscroll-behavior:auto contain none}",
+    ); // invalid
 
-    prefix_test(
-      ".foo { text-shadow: 12px 12px lab(40% 56.6 39), 12px 12px yellow }",
-      indoc! { r#"
-        .foo {
-          text-shadow: 12px 12px #b32323, 12px 12px #ff0;
-          text-shadow: 12px 12px lab(40% 56.6 39), 12px 12px #ff0;
-        }
-      "#},
-      Browsers {
-        chrome: Some(4 << 16),
-        ..Browsers::default()
-      },
+    test(
+      r#"
+      .foo {
+        overscroll-behavior-x: none;
+        overscroll-behavior-y: contain;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        overscroll-behavior: none contain;
+      }
+    "#},
     );
 
-    prefix_test(
-      ".foo { text-shadow: var(--foo) 12px lab(40% 56.6 39) }",
-      indoc! { r#"
-        .foo {
-          text-shadow: var(--foo) 12px #b32323;
-        }
-
-        @supports (color: lab(0% 0 0)) {
-          .foo {
-            text-shadow: var(--foo) 12px lab(40% 56.6 39);
-          }
-        }
-      "#},
-      Browsers {
-        chrome: Some(4 << 16),
-        ..Browsers::default()
-      },
+    test(
+      r#"
+      .foo {
+        overscroll-behavior-x: contain;
+        overscroll-behavior-y: contain;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        overscroll-behavior: contain;
+      }
+    "#},
     );
-  }
 
-  #[test]
-  fn test_break() {
-    prefix_test(
+    test(
       r#"
       .foo {
-        box-decoration-break: clone;
+        overscroll-behavior: none;
+        overscroll-behavior-y: auto;
       }
     "#,
       indoc! {r#"
       .foo {
-        -webkit-box-decoration-break: clone;
-        box-decoration-break: clone;
+        overscroll-behavior: none auto;
       }
     "#},
-      Browsers {
-        safari: Some(15 << 16),
-        ..Browsers::default()
-      },
     );
 
-    prefix_test(
+    test(
       r#"
       .foo {
-        box-decoration-break: clone;
+        overscroll-behavior-x: none;
       }
     "#,
       indoc! {r#"
       .foo {
-        box-decoration-break: clone;
+        overscroll-behavior-x: none;
       }
     "#},
-      Browsers {
-        firefox: Some(95 << 16),
-        ..Browsers::default()
-      },
     );
-  }
 
-  #[test]
-  fn test_position() {
     test(
       r#"
       .foo {
-        position: relative;
-        position: absolute;
+        overscroll-behavior-x: none;
+        overscroll-behavior-inline: auto;
+        overscroll-behavior-y: none;
       }
     "#,
       indoc! {r#"
       .foo {
-        position: absolute;
+        overscroll-behavior-x: none;
+        overscroll-behavior-inline: auto;
+        overscroll-behavior-y: none;
       }
     "#},
     );
@@ -15003,180 +19723,284 @@ mod tests {
     test(
       r#"
       .foo {
-        position: -webkit-sticky;
-        position: sticky;
+        overscroll-behavior: none;
+        overscroll-behavior-y: var(--y);
       }
     "#,
       indoc! {r#"
       .foo {
-        position: -webkit-sticky;
-        position: sticky;
+        overscroll-behavior: none;
+        overscroll-behavior-y: var(--y);
       }
     "#},
     );
+  }
 
+  #[test]
+  fn test_ui() {
+    minify_test(".foo { resize: both }", ".foo{resize:both}");
+    minify_test(".foo { resize: Horizontal }", ".foo{resize:horizontal}");
+    minify_test(".foo { resize: block }", ".foo{resize:block}");
+    minify_test(".foo { resize: inline }", ".foo{resize:inline}");
     prefix_test(
       r#"
       .foo {
-        position: sticky;
+        resize: block;
+      }
+      .bar {
+        resize: inline;
       }
     "#,
       indoc! {r#"
       .foo {
-        position: -webkit-sticky;
-        position: sticky;
+        resize: vertical;
+      }
+
+      .bar {
+        resize: horizontal;
       }
     "#},
       Browsers {
-        safari: Some(8 << 16),
+        chrome: Some(100 << 16),
         ..Browsers::default()
       },
     );
-
     prefix_test(
       r#"
       .foo {
-        position: -webkit-sticky;
-        position: sticky;
+        resize: block;
       }
     "#,
       indoc! {r#"
       .foo {
-        position: sticky;
+        resize: block;
       }
     "#},
       Browsers {
-        safari: Some(13 << 16),
+        chrome: Some(120 << 16),
+        firefox: Some(100 << 16),
         ..Browsers::default()
       },
     );
-
-    test(
+    minify_test(".foo { cursor: ew-resize }", ".foo{cursor:ew-resize}");
+    minify_test(
+      ".foo { cursor: url(\"test.cur\"), ew-resize }",
+      ".foo{cursor:url(test.cur),ew-resize}",
+    );
+    minify_test(
+      ".foo { cursor: url(\"test.cur\"), url(\"foo.cur\"), ew-resize }",
+      ".foo{cursor:url(test.cur),url(foo.cur),ew-resize}",
+    );
+    minify_test(
+      ".foo { cursor: url(\"test.cur\") 4 12, auto }",
+      ".foo{cursor:url(test.cur) 4 12,auto}",
+    );
+    minify_test(
+      ".foo { cursor: image-set(\"foo.png\" 1x, \"foo-2x.png\" 2x) 4 12, pointer }",
+      ".foo{cursor:image-set(\"foo.png\" 1x,\"foo-2x.png\" 2x) 4 12,pointer}",
+    );
+    minify_test(
+      ".foo { cursor: -webkit-image-set(url(\"foo.png\") 1x), pointer }",
+      ".foo{cursor:-webkit-image-set(url(foo.png) 1x),pointer}",
+    );
+    minify_test(".foo { cursor: url(test.cur) }", ".foo{cursor:url(test.cur)}"); // invalid
+    minify_test(
+      ".foo { cursor: image-set(linear-gradient(red, blue) 1x), pointer }",
+      ".foo{cursor:image-set(linear-gradient(red,blue)1x),pointer}",
+    ); // invalid
+    minify_test(".foo { cursor: none, pointer }", ".foo{cursor:none,pointer}"); // invalid
+    prefix_test(
       r#"
       .foo {
-        top: 0;
-        left: 0;
-        bottom: 0;
-        right: 0;
+        cursor: image-set(url("foo.png") 1x, url("foo-2x.png") 2x) 4 12, pointer;
       }
     "#,
       indoc! {r#"
       .foo {
-        inset: 0;
+        cursor: -webkit-image-set(url("foo.png") 1x, url("foo-2x.png") 2x) 4 12, pointer;
+        cursor: image-set("foo.png" 1x, "foo-2x.png" 2x) 4 12, pointer;
       }
     "#},
+      Browsers {
+        chrome: Some(85 << 16),
+        ..Browsers::default()
+      },
     );
-
-    test(
+    prefix_test(
       r#"
       .foo {
-        top: 2px;
-        left: 4px;
-        bottom: 2px;
-        right: 4px;
+        cursor: url("foo.cur"), pointer;
       }
     "#,
       indoc! {r#"
       .foo {
-        inset: 2px 4px;
+        cursor: url("foo.cur"), pointer;
       }
     "#},
+      Browsers {
+        chrome: Some(85 << 16),
+        ..Browsers::default()
+      },
+    );
+    minify_test(".foo { caret-color: auto }", ".foo{caret-color:auto}");
+    minify_test(".foo { caret-color: yellow }", ".foo{caret-color:#ff0}");
+    minify_test(".foo { caret-shape: block }", ".foo{caret-shape:block}");
+    minify_test(".foo { caret: yellow block }", ".foo{caret:#ff0 block}");
+    minify_test(".foo { caret: block yellow }", ".foo{caret:#ff0 block}");
+    minify_test(".foo { caret: block }", ".foo{caret:block}");
+    minify_test(".foo { caret: yellow }", ".foo{caret:#ff0}");
+    minify_test(".foo { caret: auto auto }", ".foo{caret:auto}");
+    minify_test(".foo { caret: auto }", ".foo{caret:auto}");
+    minify_test(".foo { caret: yellow auto }", ".foo{caret:#ff0}");
+    minify_test(".foo { caret: auto block }", ".foo{caret:block}");
+    minify_test(
+      ".foo { caret-color: yellow; caret-shape: block }",
+      ".foo{caret:#ff0 block}",
+    );
+    minify_test(".foo { caret-shape: bar; caret-color: auto }", ".foo{caret:bar}");
+    minify_test(".foo { caret: block; caret-color: red }", ".foo{caret:red block}");
+    minify_test(
+      ".foo { caret: red bar; caret-shape: underscore }",
+      ".foo{caret:red underscore}",
+    );
+    minify_test(".foo { user-select: none }", ".foo{user-select:none}");
+    minify_test(".foo { -webkit-user-select: none }", ".foo{-webkit-user-select:none}");
+    minify_test(".foo { user-select: ALL }", ".foo{user-select:all}");
+    minify_test(".foo { user-select: contain }", ".foo{user-select:contain}");
+    minify_test(
+      ".foo { -webkit-user-select: none; user-select: none }",
+      ".foo{-webkit-user-select:none;user-select:none}",
+    );
+    minify_test(
+      ".foo { -webkit-user-select: text; -webkit-user-select: none }",
+      ".foo{-webkit-user-select:none}",
+    );
+    minify_test(".foo { accent-color: auto }", ".foo{accent-color:auto}");
+    minify_test(".foo { accent-color: yellow }", ".foo{accent-color:#ff0}");
+    minify_test(".foo { accent-color: AUTO }", ".foo{accent-color:auto}");
+    minify_test(
+      ".foo { accent-color: rgb(255 0 0 / 50%) }",
+      ".foo{accent-color:#ff000080}",
+    );
+    minify_test(".foo { appearance: None }", ".foo{appearance:none}");
+    minify_test(
+      ".foo { -webkit-appearance: textfield }",
+      ".foo{-webkit-appearance:textfield}",
+    );
+    minify_test(".foo { -moz-appearance: none }", ".foo{-moz-appearance:none}");
+    minify_test(".foo { appearance: MenuList-Button }", ".foo{appearance:menulist-button}");
+    minify_test(
+      ".foo { -webkit-appearance: -apple-pay-button }",
+      ".foo{-webkit-appearance:-apple-pay-button}",
+    );
+    minify_test(
+      ".foo { -webkit-appearance: none; appearance: none }",
+      ".foo{-webkit-appearance:none;appearance:none}",
     );
 
-    test(
+    prefix_test(
       r#"
       .foo {
-        top: 1px;
-        left: 2px;
-        bottom: 3px;
-        right: 4px;
+        user-select: none;
       }
     "#,
       indoc! {r#"
       .foo {
-        inset: 1px 4px 3px 2px;
+        -webkit-user-select: none;
+        -moz-user-select: none;
+        -ms-user-select: none;
+        user-select: none;
       }
     "#},
+      Browsers {
+        safari: Some(8 << 16),
+        opera: Some(5 << 16),
+        firefox: Some(10 << 16),
+        ie: Some(10 << 16),
+        ..Browsers::default()
+      },
     );
 
-    test(
+    prefix_test(
       r#"
       .foo {
-        inset-block-start: 2px;
-        inset-block-end: 2px;
-        inset-inline-start: 4px;
-        inset-inline-end: 4px;
+        -webkit-user-select: none;
+        -moz-user-select: none;
+        -ms-user-select: none;
+        user-select: none;
       }
     "#,
       indoc! {r#"
       .foo {
-        inset-block: 2px;
-        inset-inline: 4px;
+        -webkit-user-select: none;
+        user-select: none;
       }
     "#},
+      Browsers {
+        safari: Some(8 << 16),
+        opera: Some(80 << 16),
+        firefox: Some(80 << 16),
+        edge: Some(80 << 16),
+        ..Browsers::default()
+      },
     );
 
-    test(
+    prefix_test(
       r#"
       .foo {
-        inset-block-start: 2px;
-        inset-block-end: 3px;
-        inset-inline-start: 4px;
-        inset-inline-end: 5px;
+        -webkit-user-select: none;
+        -moz-user-select: none;
+        -ms-user-select: none;
+        user-select: none;
       }
     "#,
       indoc! {r#"
       .foo {
-        inset-block: 2px 3px;
-        inset-inline: 4px 5px;
+        user-select: none;
       }
     "#},
+      Browsers {
+        opera: Some(80 << 16),
+        firefox: Some(80 << 16),
+        edge: Some(80 << 16),
+        ..Browsers::default()
+      },
     );
 
-    test(
+    prefix_test(
       r#"
       .foo {
-        inset-block-start: 2px;
-        inset-block-end: 3px;
-        inset: 4px;
-        inset-inline-start: 4px;
-        inset-inline-end: 5px;
+        user-select: text;
       }
     "#,
       indoc! {r#"
       .foo {
-        inset: 4px;
-        inset-inline: 4px 5px;
+        -moz-user-select: text;
+        user-select: text;
       }
     "#},
+      Browsers {
+        firefox: Some(60 << 16),
+        ..Browsers::default()
+      },
     );
 
     prefix_test(
       r#"
       .foo {
-        inset-inline-start: 2px;
+        -webkit-user-select: none;
+        user-select: text;
       }
     "#,
       indoc! {r#"
-      .foo:not(:-webkit-any(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi))) {
-        left: 2px;
-      }
-
-      .foo:not(:is(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi))) {
-        left: 2px;
-      }
-
-      .foo:-webkit-any(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi)) {
-        right: 2px;
-      }
-
-      .foo:is(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi)) {
-        right: 2px;
+      .foo {
+        -webkit-user-select: none;
+        -webkit-user-select: text;
+        user-select: text;
       }
-    "#
-      },
+    "#},
       Browsers {
-        safari: Some(8 << 16),
+        safari: Some(14 << 16),
         ..Browsers::default()
       },
     );
@@ -15184,34 +20008,46 @@ mod tests {
     prefix_test(
       r#"
       .foo {
-        inset-inline-start: 2px;
-        inset-inline-end: 4px;
+        appearance: none;
       }
     "#,
       indoc! {r#"
-      .foo:not(:-webkit-any(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi))) {
-        left: 2px;
-        right: 4px;
-      }
-
-      .foo:not(:is(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi))) {
-        left: 2px;
-        right: 4px;
+      .foo {
+        -webkit-appearance: none;
+        -moz-appearance: none;
+        -ms-appearance: none;
+        appearance: none;
       }
+    "#},
+      Browsers {
+        safari: Some(8 << 16),
+        chrome: Some(80 << 16),
+        firefox: Some(10 << 16),
+        ie: Some(11 << 16),
+        ..Browsers::default()
+      },
+    );
 
-      .foo:-webkit-any(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi)) {
-        left: 4px;
-        right: 2px;
+    prefix_test(
+      r#"
+      .foo {
+        -webkit-appearance: none;
+        -moz-appearance: none;
+        -ms-appearance: none;
+        appearance: none;
       }
-
-      .foo:is(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi)) {
-        left: 4px;
-        right: 2px;
+    "#,
+      indoc! {r#"
+      .foo {
+        -webkit-appearance: none;
+        appearance: none;
       }
-    "#
-      },
+    "#},
       Browsers {
-        safari: Some(8 << 16),
+        safari: Some(15 << 16),
+        chrome: Some(85 << 16),
+        firefox: Some(80 << 16),
+        edge: Some(85 << 16),
         ..Browsers::default()
       },
     );
@@ -15219,18 +20055,21 @@ mod tests {
     prefix_test(
       r#"
       .foo {
-        inset-inline: 2px;
+        -webkit-appearance: none;
+        -moz-appearance: none;
+        -ms-appearance: none;
+        appearance: none;
       }
     "#,
       indoc! {r#"
       .foo {
-        left: 2px;
-        right: 2px;
+        appearance: none;
       }
-    "#
-      },
+    "#},
       Browsers {
-        safari: Some(8 << 16),
+        chrome: Some(85 << 16),
+        firefox: Some(80 << 16),
+        edge: Some(85 << 16),
         ..Browsers::default()
       },
     );
@@ -15238,17 +20077,19 @@ mod tests {
     prefix_test(
       r#"
       .foo {
-        inset-block-start: 2px;
+        appearance: textfield;
       }
     "#,
       indoc! {r#"
       .foo {
-        top: 2px;
+        -webkit-appearance: textfield;
+        -moz-appearance: textfield;
+        appearance: textfield;
       }
-    "#
-      },
+    "#},
       Browsers {
-        safari: Some(8 << 16),
+        safari: Some(14 << 16),
+        firefox: Some(70 << 16),
         ..Browsers::default()
       },
     );
@@ -15256,17 +20097,92 @@ mod tests {
     prefix_test(
       r#"
       .foo {
-        inset-block-end: 2px;
+        -webkit-appearance: none;
+        appearance: none;
       }
     "#,
       indoc! {r#"
       .foo {
-        bottom: 2px;
+        appearance: none;
       }
-    "#
+    "#},
+      Browsers {
+        safari: Some(16 << 16),
+        ..Browsers::default()
       },
+    );
+
+    prefix_test(
+      ".foo { accent-color: lch(50.998% 135.363 338) }",
+      indoc! { r#"
+        .foo {
+          accent-color: #ee00be;
+          accent-color: color(display-p3 .972962 -.362078 .804206);
+          accent-color: lch(50.998% 135.363 338);
+        }
+      "#},
       Browsers {
-        safari: Some(8 << 16),
+        chrome: Some(90 << 16),
+        safari: Some(14 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      ".foo { accent-color: oklch(40% 0.1268735435 34.568626) }",
+      indoc! { r#"
+        .foo {
+          accent-color: #7e250f;
+          accent-color: lab(29.2661% 38.2437 35.3889);
+        }
+      "#},
+      Browsers {
+        chrome: Some(90 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      ".foo { accent-color: lch(50.998% 135.363 338) }",
+      indoc! { r#"
+        .foo {
+          accent-color: lch(50.998% 135.363 338);
+        }
+      "#},
+      Browsers {
+        safari: Some(16 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      ".foo { caret-color: lch(50.998% 135.363 338) }",
+      indoc! { r#"
+        .foo {
+          caret-color: #ee00be;
+          caret-color: color(display-p3 .972962 -.362078 .804206);
+          caret-color: lch(50.998% 135.363 338);
+        }
+      "#},
+      Browsers {
+        chrome: Some(90 << 16),
+        safari: Some(14 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      ".foo { caret: lch(50.998% 135.363 338) block }",
+      indoc! { r#"
+        .foo {
+          caret: #ee00be block;
+          caret: color(display-p3 .972962 -.362078 .804206) block;
+          caret: lch(50.998% 135.363 338) block;
+        }
+      "#},
+      Browsers {
+        chrome: Some(90 << 16),
+        safari: Some(14 << 16),
         ..Browsers::default()
       },
     );
@@ -15274,241 +20190,540 @@ mod tests {
     prefix_test(
       r#"
       .foo {
-        top: 1px;
-        left: 2px;
-        bottom: 3px;
-        right: 4px;
+        caret-color: lch(50.998% 135.363 338);
+        caret-shape: block;
       }
     "#,
-      indoc! {r#"
-      .foo {
-        top: 1px;
-        bottom: 3px;
-        left: 2px;
-        right: 4px;
-      }
-    "#},
+      indoc! { r#"
+        .foo {
+          caret-color: #ee00be;
+          caret-color: color(display-p3 .972962 -.362078 .804206);
+          caret-color: lch(50.998% 135.363 338);
+          caret-shape: block;
+        }
+      "#},
       Browsers {
-        safari: Some(8 << 16),
+        chrome: Some(90 << 16),
+        safari: Some(14 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      ".foo { caret-color: red; caret-shape: auto }",
+      indoc! { r#"
+        .foo {
+          caret-color: red;
+          caret-shape: auto;
+        }
+      "#},
+      Browsers {
+        chrome: Some(100 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      ".foo { caret: lch(50.998% 135.363 338) var(--foo) }",
+      indoc! { r#"
+        .foo {
+          caret: #ee00be var(--foo);
+        }
+
+        @supports (color: lab(0% 0 0)) {
+          .foo {
+            caret: lab(50.998% 125.506 -50.7078) var(--foo);
+          }
+        }
+      "#},
+      Browsers {
+        chrome: Some(90 << 16),
         ..Browsers::default()
       },
     );
   }
 
   #[test]
-  fn test_overflow() {
-    minify_test(".foo { overflow: hidden }", ".foo{overflow:hidden}");
-    minify_test(".foo { overflow: hidden hidden }", ".foo{overflow:hidden}");
-    minify_test(".foo { overflow: hidden auto }", ".foo{overflow:hidden auto}");
+  fn test_field_sizing() {
+    minify_test(".foo { field-sizing: fixed }", ".foo{field-sizing:fixed}");
+    minify_test(".foo { field-sizing: content }", ".foo{field-sizing:content}");
+    minify_test(".foo { field-sizing: CONTENT }", ".foo{field-sizing:content}");
+    minify_test(".foo { field-sizing: auto }", ".foo{field-sizing:auto}"); // invalid
+    minify_test(
+      ".foo { field-sizing: content fixed }",
+      ".foo{field-sizing:content fixed}",
+    ); // invalid
+  }
 
-    test(
+  #[test]
+  fn test_pointer_events() {
+    minify_test(".foo { pointer-events: auto }", ".foo{pointer-events:auto}");
+    minify_test(".foo { pointer-events: none }", ".foo{pointer-events:none}");
+    minify_test(
+      ".foo { pointer-events: visiblePainted }",
+      ".foo{pointer-events:visiblepainted}",
+    );
+    minify_test(
+      ".foo { pointer-events: visibleFill }",
+      ".foo{pointer-events:visiblefill}",
+    );
+    minify_test(
+      ".foo { pointer-events: visibleStroke }",
+      ".foo{pointer-events:visiblestroke}",
+    );
+    minify_test(".foo { pointer-events: visible }", ".foo{pointer-events:visible}");
+    minify_test(".foo { pointer-events: painted }", ".foo{pointer-events:painted}");
+    minify_test(".foo { pointer-events: fill }", ".foo{pointer-events:fill}");
+    minify_test(".foo { pointer-events: stroke }", ".foo{pointer-events:stroke}");
+    minify_test(".foo { pointer-events: all }", ".foo{pointer-events:all}");
+    minify_test(
+      ".foo { pointer-events: bounding-box }",
+      ".foo{pointer-events:bounding-box}",
+    );
+    minify_test(".foo { pointer-events: hidden }", ".foo{pointer-events:hidden}");
+    // invalid
+  }
+
+  #[test]
+  fn test_touch_action() {
+    minify_test(".foo { touch-action: auto }", ".foo{touch-action:auto}");
+    minify_test(".foo { touch-action: none }", ".foo{touch-action:none}");
+    minify_test(".foo { touch-action: manipulation }", ".foo{touch-action:manipulation}");
+    minify_test(".foo { touch-action: pan-x }", ".foo{touch-action:pan-x}");
+    minify_test(".foo { touch-action: pan-y pan-x }", ".foo{touch-action:pan-x pan-y}");
+    minify_test(
+      ".foo { touch-action: pinch-zoom pan-up }",
+      ".foo{touch-action:pan-up pinch-zoom}",
+    );
+    minify_test(
+      ".foo { touch-action: pan-right pan-down pinch-zoom }",
+      ".foo{touch-action:pan-right pan-down pinch-zoom}",
+    );
+    minify_test(
+      ".foo { touch-action: pinch-zoom pan-y pan-x }",
+      ".foo{touch-action:manipulation}",
+    );
+    minify_test(".foo { touch-action: PAN-LEFT }", ".foo{touch-action:pan-left}");
+    minify_test(
+      ".foo { touch-action: pan-x pan-left }",
+      ".foo{touch-action:pan-x pan-left}",
+    ); // invalid
+    minify_test(".foo { touch-action: pan-y pan-y }", ".foo{touch-action:pan-y pan-y}"); // invalid
+    minify_test(".foo { touch-action: auto pan-x }", ".foo{touch-action:auto pan-x}"); // invalid
+    minify_test(".foo { touch-action: none pan-x }", ".foo{touch-action:none pan-x}"); // invalid
+    minify_test(
+      ".foo { touch-action: manipulation pinch-zoom }",
+      ".foo{touch-action:manipulation pinch-zoom}",
+    ); // invalid
+
+    prefix_test(
       r#"
       .foo {
-        overflow-x: hidden;
-        overflow-y: auto;
+        touch-action: pan-x;
       }
     "#,
       indoc! {r#"
       .foo {
-        overflow: hidden auto;
+        -ms-touch-action: pan-x;
+        touch-action: pan-x;
       }
     "#},
+      Browsers {
+        ie: Some(10 << 16),
+        ..Browsers::default()
+      },
     );
-
-    test(
+    prefix_test(
       r#"
       .foo {
-        overflow: hidden;
-        overflow-y: auto;
+        -ms-touch-action: none;
+        touch-action: none;
       }
     "#,
       indoc! {r#"
       .foo {
-        overflow: hidden auto;
+        touch-action: none;
       }
     "#},
+      Browsers {
+        ie: Some(11 << 16),
+        ..Browsers::default()
+      },
+    );
+  }
+
+  #[test]
+  fn test_list() {
+    minify_test(".foo { list-style-type: disc; }", ".foo{list-style-type:disc}");
+    minify_test(".foo { list-style-type: \"★\"; }", ".foo{list-style-type:\"★\"}");
+    minify_test(
+      ".foo { list-style-type: symbols(cyclic '○' '●'); }",
+      ".foo{list-style-type:symbols(cyclic \"○\" \"●\")}",
+    );
+    minify_test(
+      ".foo { list-style-type: symbols('○' '●'); }",
+      ".foo{list-style-type:symbols(\"○\" \"●\")}",
+    );
+    minify_test(
+      ".foo { list-style-type: symbols(symbolic '○' '●'); }",
+      ".foo{list-style-type:symbols(\"○\" \"●\")}",
+    );
+    minify_test(
+      ".foo { list-style-type: symbols(symbolic url('ellipse.png')); }",
+      ".foo{list-style-type:symbols(url(ellipse.png))}",
+    );
+    minify_test(
+      ".foo { list-style-image: url('ellipse.png'); }",
+      ".foo{list-style-image:url(ellipse.png)}",
+    );
+    minify_test(
+      ".foo { list-style-position: outside; }",
+      ".foo{list-style-position:outside}",
+    );
+    minify_test(
+      ".foo { list-style: \"★\" url(ellipse.png) outside; }",
+      ".foo{list-style:\"★\" url(ellipse.png)}",
+    );
+    minify_test(".foo { list-style: none; }", ".foo{list-style:none}");
+    minify_test(".foo { list-style: none none; }", ".foo{list-style:none}");
+    minify_test(".foo { list-style: none inside; }", ".foo{list-style:none inside}");
+    minify_test(
+      ".foo { list-style: none url(ellipse.png); }",
+      ".foo{list-style:none url(ellipse.png)}",
+    );
+    minify_test(
+      ".foo { list-style: url(ellipse.png) none; }",
+      ".foo{list-style:none url(ellipse.png)}",
+    );
+    minify_test(".foo { list-style: square none; }", ".foo{list-style:square}");
+    minify_test(".foo { list-style: none square; }", ".foo{list-style:square}");
+    minify_test(".foo { list-style: inside square; }", ".foo{list-style:square inside}");
+    minify_test(".foo { list-style: outside; }", ".foo{list-style:disc}");
+    minify_test(".foo { list-style: inside; }", ".foo{list-style:inside}");
+    minify_test(
+      ".foo { list-style: outside inside; }",
+      ".foo{list-style:outside inside}",
+    );
+    minify_test(".foo { list-style: my-style; }", ".foo{list-style:my-style}");
+    minify_test(".foo { list-style: \"-\" inside; }", ".foo{list-style:\"-\" inside}");
+    minify_test(
+      ".foo { list-style: none square none; }",
+      ".foo{list-style:none square none}",
+    );
+    minify_test(
+      ".foo { list-style: none url(ellipse.png) none; }",
+      ".foo{list-style:none url(ellipse.png) none}",
+    );
+    minify_test(
+      ".foo { list-style-type: none; list-style-image: none; list-style-position: outside }",
+      ".foo{list-style:none}",
     );
+
     test(
       r#"
       .foo {
-        overflow: hidden;
-        overflow-y: var(--y);
+        list-style-type: disc;
+        list-style-image: url(ellipse.png);
+        list-style-position: outside;
       }
     "#,
       indoc! {r#"
       .foo {
-        overflow: hidden;
-        overflow-y: var(--y);
+        list-style: url("ellipse.png");
       }
     "#},
     );
-    prefix_test(
+
+    test(
       r#"
       .foo {
-        overflow: hidden auto;
+        list-style: \"★\" url(ellipse.png) outside;
+        list-style-image: none;
       }
     "#,
       indoc! {r#"
       .foo {
-        overflow-x: hidden;
-        overflow-y: auto;
+        list-style: \"★\";
       }
     "#},
-      Browsers {
-        chrome: Some(67 << 16),
-        ..Browsers::default()
-      },
     );
-    prefix_test(
+
+    test(
       r#"
       .foo {
-        overflow: hidden hidden;
+        list-style: \"★\" url(ellipse.png) outside;
+        list-style-image: var(--img);
       }
     "#,
       indoc! {r#"
       .foo {
-        overflow: hidden;
+        list-style: \"★\" url("ellipse.png");
+        list-style-image: var(--img);
       }
     "#},
+    );
+
+    prefix_test(
+      ".foo { list-style-image: linear-gradient(lch(56.208% 136.76 46.312), lch(51% 135.366 301.364)) }",
+      indoc! { r#"
+        .foo {
+          list-style-image: -webkit-gradient(linear, 0 0, 0 100%, from(#ff0f0e), to(#7773ff));
+          list-style-image: -webkit-linear-gradient(#ff0f0e, #7773ff);
+          list-style-image: linear-gradient(#ff0f0e, #7773ff);
+          list-style-image: linear-gradient(lch(56.208% 136.76 46.312), lch(51% 135.366 301.364));
+        }
+      "#},
       Browsers {
-        chrome: Some(67 << 16),
+        chrome: Some(8 << 16),
         ..Browsers::default()
       },
     );
+
     prefix_test(
-      r#"
-      .foo {
-        overflow: hidden auto;
-      }
-    "#,
-      indoc! {r#"
-      .foo {
-        overflow: hidden auto;
-      }
-    "#},
+      ".foo { list-style: \"★\" linear-gradient(lch(56.208% 136.76 46.312), lch(51% 135.366 301.364)) }",
+      indoc! { r#"
+        .foo {
+          list-style: "★" linear-gradient(#ff0f0e, #7773ff);
+          list-style: "★" linear-gradient(lch(56.208% 136.76 46.312), lch(51% 135.366 301.364));
+        }
+      "#},
       Browsers {
-        chrome: Some(68 << 16),
+        chrome: Some(90 << 16),
         ..Browsers::default()
       },
     );
 
-    minify_test(".foo { text-overflow: ellipsis }", ".foo{text-overflow:ellipsis}");
     prefix_test(
-      r#"
-      .foo {
-        text-overflow: ellipsis;
-      }
-    "#,
-      indoc! {r#"
-      .foo {
-        -o-text-overflow: ellipsis;
-        text-overflow: ellipsis;
-      }
-    "#},
+      ".foo { list-style: var(--foo) linear-gradient(lch(56.208% 136.76 46.312), lch(51% 135.366 301.364)) }",
+      indoc! { r#"
+        .foo {
+          list-style: var(--foo) linear-gradient(#ff0f0e, #7773ff);
+        }
+
+        @supports (color: lab(0% 0 0)) {
+          .foo {
+            list-style: var(--foo) linear-gradient(lab(56.208% 94.4644 98.8928), lab(51% 70.4544 -115.586));
+          }
+        }
+      "#},
       Browsers {
-        safari: Some(4 << 16),
-        opera: Some(10 << 16),
+        chrome: Some(90 << 16),
         ..Browsers::default()
       },
     );
+  }
 
-    prefix_test(
+  #[test]
+  fn test_content() {
+    minify_test(".foo { content: normal }", ".foo{content:normal}");
+    minify_test(".foo { content: NONE }", ".foo{content:none}");
+    minify_test(".foo { content: 'foo' }", ".foo{content:\"foo\"}");
+    minify_test(".foo { content: \"a\" 'b' \"c\" }", ".foo{content:\"abc\"}");
+    minify_test(
+      ".foo { content: \"a\" counter(item) \"b\" \"c\" }",
+      ".foo{content:\"a\" counter(item) \"bc\"}",
+    );
+    minify_test(
+      ".foo { content: counter(item, decimal) }",
+      ".foo{content:counter(item)}",
+    );
+    minify_test(
+      ".foo { content: counter(item, upper-roman) }",
+      ".foo{content:counter(item,upper-roman)}",
+    );
+    minify_test(
+      ".foo { content: counters(item, '.') }",
+      ".foo{content:counters(item,\".\")}",
+    );
+    minify_test(
+      ".foo { content: counters(item, \".\", lower-alpha) }",
+      ".foo{content:counters(item,\".\",lower-alpha)}",
+    );
+    minify_test(".foo { content: attr(title) }", ".foo{content:attr(title)}");
+    minify_test(
+      ".foo { content: OPEN-QUOTE attr(title) close-quote }",
+      ".foo{content:open-quote attr(title) close-quote}",
+    );
+    minify_test(
+      ".foo { content: no-open-quote no-close-quote }",
+      ".foo{content:no-open-quote no-close-quote}",
+    );
+    minify_test(".foo { content: url('icon.png') }", ".foo{content:url(icon.png)}");
+    minify_test(
+      ".foo { content: image-set('icon.png' 1x, 'icon@2x.png' 2x) }",
+      ".foo{content:image-set(\"icon.png\" 1x,\"icon@2x.png\" 2x)}",
+    );
+    minify_test(
+      ".foo { content: url(icon.png) / 'Icon' }",
+      ".foo{content:url(icon.png)/\"Icon\"}",
+    );
+    minify_test(
+      ".foo { content: '\\25BA' / '' }",
+      ".foo{content:\"►\"/\"\"}",
+    );
+    minify_test(
+      ".foo { content: url(icon.png) / 'Item ' counter(item) attr(title) }",
+      ".foo{content:url(icon.png)/\"Item \" counter(item) attr(title)}",
+    );
+    minify_test(".foo { content: \"a\" none }", ".foo{content:\"a\" none}");
+    minify_test(".foo { content: counter() }", ".foo{content:counter()}");
+    minify_test(".foo { content: counter(none) }", ".foo{content:counter(none)}");
+  }
+
+  #[test]
+  fn test_quotes() {
+    minify_test(".foo { quotes: none }", ".foo{quotes:none}");
+    minify_test(".foo { quotes: AUTO }", ".foo{quotes:auto}");
+    minify_test(".foo { quotes: '«' '»' }", ".foo{quotes:\"«\" \"»\"}");
+    minify_test(
+      ".foo { quotes: \"“\" \"”\" \"‘\" \"’\" }",
+      ".foo{quotes:\"“\" \"”\" \"‘\" \"’\"}",
+    );
+    minify_test(".foo { quotes: '\"' '\"' }", ".foo{quotes:'\"' '\"'}");
+    minify_test(".foo { quotes: \"\\\"\" \"\\\"\" }", ".foo{quotes:'\"' '\"'}");
+    minify_test(
+      ".foo { quotes: '\"' '\"' \"'\" \"'\" }",
+      ".foo{quotes:'\"' '\"' \"'\" \"'\"}",
+    );
+    minify_test(".foo { quotes: '\"\\'' '\"' }", ".foo{quotes:\"\\\"'\" '\"'}");
+    minify_test(".foo { quotes: '«' }", ".foo{quotes:\"«\"}"); // invalid
+    minify_test(".foo { quotes: '«' '»' '‹' }", ".foo{quotes:\"«\" \"»\" \"‹\"}"); // invalid
+    minify_test(".foo { quotes: none '«' '»' }", ".foo{quotes:none \"«\" \"»\"}"); // invalid
+
+    test(
       r#"
       .foo {
-        -o-text-overflow: ellipsis;
-        text-overflow: ellipsis;
+        quotes: '"' '"' "'" "'";
       }
     "#,
       indoc! {r#"
       .foo {
-        text-overflow: ellipsis;
+        quotes: "\"" "\"" "'" "'";
       }
     "#},
-      Browsers {
-        safari: Some(4 << 16),
-        opera: Some(14 << 16),
-        ..Browsers::default()
-      },
     );
   }
 
   #[test]
-  fn test_ui() {
-    minify_test(".foo { resize: both }", ".foo{resize:both}");
-    minify_test(".foo { resize: Horizontal }", ".foo{resize:horizontal}");
-    minify_test(".foo { cursor: ew-resize }", ".foo{cursor:ew-resize}");
+  fn test_counters() {
+    minify_test(".foo { counter-reset: NONE }", ".foo{counter-reset:none}");
+    minify_test(".foo { counter-reset: item }", ".foo{counter-reset:item}");
+    minify_test(".foo { counter-reset: item 0 }", ".foo{counter-reset:item}");
     minify_test(
-      ".foo { cursor: url(\"test.cur\"), ew-resize }",
-      ".foo{cursor:url(test.cur),ew-resize}",
+      ".foo { counter-reset: item 2 other -1 }",
+      ".foo{counter-reset:item 2 other -1}",
     );
     minify_test(
-      ".foo { cursor: url(\"test.cur\"), url(\"foo.cur\"), ew-resize }",
-      ".foo{cursor:url(test.cur),url(foo.cur),ew-resize}",
+      ".foo { counter-reset: item 2 other item 5 }",
+      ".foo{counter-reset:other item 5}",
     );
-    minify_test(".foo { caret-color: auto }", ".foo{caret-color:auto}");
-    minify_test(".foo { caret-color: yellow }", ".foo{caret-color:#ff0}");
-    minify_test(".foo { caret-shape: block }", ".foo{caret-shape:block}");
-    minify_test(".foo { caret: yellow block }", ".foo{caret:#ff0 block}");
-    minify_test(".foo { caret: block yellow }", ".foo{caret:#ff0 block}");
-    minify_test(".foo { caret: block }", ".foo{caret:block}");
-    minify_test(".foo { caret: yellow }", ".foo{caret:#ff0}");
-    minify_test(".foo { caret: auto auto }", ".foo{caret:auto}");
-    minify_test(".foo { caret: auto }", ".foo{caret:auto}");
-    minify_test(".foo { caret: yellow auto }", ".foo{caret:#ff0}");
-    minify_test(".foo { caret: auto block }", ".foo{caret:block}");
-    minify_test(".foo { user-select: none }", ".foo{user-select:none}");
-    minify_test(".foo { -webkit-user-select: none }", ".foo{-webkit-user-select:none}");
-    minify_test(".foo { accent-color: auto }", ".foo{accent-color:auto}");
-    minify_test(".foo { accent-color: yellow }", ".foo{accent-color:#ff0}");
-    minify_test(".foo { appearance: None }", ".foo{appearance:none}");
     minify_test(
-      ".foo { -webkit-appearance: textfield }",
-      ".foo{-webkit-appearance:textfield}",
+      ".foo { counter-reset: reversed(list-item) }",
+      ".foo{counter-reset:reversed(list-item)}",
+    );
+    minify_test(
+      ".foo { counter-reset: REVERSED(list-item) 0 }",
+      ".foo{counter-reset:reversed(list-item) 0}",
+    );
+    minify_test(
+      ".foo { counter-reset: reversed(item) 10 item }",
+      ".foo{counter-reset:item}",
+    );
+    minify_test(".foo { counter-set: item }", ".foo{counter-set:item}");
+    minify_test(".foo { counter-set: item 0 }", ".foo{counter-set:item}");
+    minify_test(".foo { counter-set: item 1 item 3 }", ".foo{counter-set:item 3}");
+    minify_test(".foo { counter-set: none }", ".foo{counter-set:none}");
+    minify_test(".foo { counter-increment: item }", ".foo{counter-increment:item}");
+    minify_test(".foo { counter-increment: item 1 }", ".foo{counter-increment:item}");
+    minify_test(".foo { counter-increment: item 0 }", ".foo{counter-increment:item 0}");
+    minify_test(
+      ".foo { counter-increment: item other 2 item -3 }",
+      ".foo{counter-increment:item -2 other 2}",
     );
+    minify_test(".foo { counter-increment: none }", ".foo{counter-increment:none}");
+    minify_test(".foo { counter-reset: item none }", ".foo{counter-reset:item none}");
+    minify_test(".foo { counter-increment: 1 }", ".foo{counter-increment:1}");
+    minify_test(".foo { counter-set: item 1.5 }", ".foo{counter-set:item 1.5}");
+  }
 
-    prefix_test(
+  #[test]
+  fn test_image_set() {
+    // Spec: https://drafts.csswg.org/css-images-4/#image-set-notation
+    // WPT: https://github.com/web-platform-tests/wpt/blob/master/css/css-images/image-set/image-set-parsing.html
+    // test image-set(<string>)
+    minify_test(
+      ".foo { background: image-set(\"foo.png\" 2x, url(bar.png) 1x) }",
+      ".foo{background:image-set(\"foo.png\" 2x,\"bar.png\" 1x)}",
+    );
+
+    // test image-set(type(<string>))
+    minify_test(
+      ".foo { background: image-set('foo.webp' type('webp'), url(foo.jpg)) }",
+      ".foo{background:image-set(\"foo.webp\" 1x type(\"webp\"),\"foo.jpg\" 1x)}",
+    );
+    minify_test(
+      ".foo { background: image-set('foo.avif' 2x type('image/avif'), url(foo.png)) }",
+      ".foo{background:image-set(\"foo.avif\" 2x type(\"image/avif\"),\"foo.png\" 1x)}",
+    );
+    minify_test(
+      ".foo { background: image-set(url('example.png') 3x type('image/png')) }",
+      ".foo{background:image-set(\"example.png\" 3x type(\"image/png\"))}",
+    );
+
+    minify_test(
+      ".foo { background: image-set(url(example.png) type('image/png') 1x) }",
+      ".foo{background:image-set(\"example.png\" 1x type(\"image/png\"))}",
+    );
+
+    minify_test(
+      ".foo { background: -webkit-image-set(url(\"foo.png\") 2x, url(bar.png) 1x) }",
+      ".foo{background:-webkit-image-set(url(foo.png) 2x,url(bar.png) 1x)}",
+    );
+
+    test(
       r#"
       .foo {
-        user-select: none;
+        background: -webkit-image-set(url("foo.png") 2x, url(bar.png) 1x);
+        background: image-set(url("foo.png") 2x, url(bar.png) 1x);
       }
     "#,
       indoc! {r#"
       .foo {
-        -webkit-user-select: none;
-        -moz-user-select: none;
-        -ms-user-select: none;
-        user-select: none;
+        background: -webkit-image-set(url("foo.png") 2x, url("bar.png") 1x);
+        background: image-set("foo.png" 2x, "bar.png" 1x);
+      }
+    "#},
+    );
+
+    // test image-set(<gradient>)
+    test(
+      r#"
+      .foo {
+        background: image-set(linear-gradient(cornflowerblue, white) 1x, url("detailed-gradient.png") 3x);
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        background: image-set(linear-gradient(#6495ed, #fff) 1x, "detailed-gradient.png" 3x);
       }
     "#},
-      Browsers {
-        safari: Some(8 << 16),
-        opera: Some(5 << 16),
-        firefox: Some(10 << 16),
-        ie: Some(10 << 16),
-        ..Browsers::default()
-      },
     );
 
     prefix_test(
       r#"
       .foo {
-        -webkit-user-select: none;
-        -moz-user-select: none;
-        -ms-user-select: none;
-        user-select: none;
+        background: image-set(url("foo.png") 2x, url(bar.png) 1x);
       }
     "#,
       indoc! {r#"
       .foo {
-        -webkit-user-select: none;
-        user-select: none;
+        background: -webkit-image-set(url("foo.png") 2x, url("bar.png") 1x);
+        background: image-set("foo.png" 2x, "bar.png" 1x);
       }
     "#},
       Browsers {
-        safari: Some(8 << 16),
-        opera: Some(80 << 16),
+        chrome: Some(85 << 16),
         firefox: Some(80 << 16),
-        edge: Some(80 << 16),
         ..Browsers::default()
       },
     );
@@ -15516,21 +20731,18 @@ mod tests {
     prefix_test(
       r#"
       .foo {
-        -webkit-user-select: none;
-        -moz-user-select: none;
-        -ms-user-select: none;
-        user-select: none;
+        background: -webkit-image-set(url("foo.png") 2x, url(bar.png) 1x);
+        background: image-set(url("foo.png") 2x, url(bar.png) 1x);
       }
     "#,
       indoc! {r#"
       .foo {
-        user-select: none;
+        background: -webkit-image-set(url("foo.png") 2x, url("bar.png") 1x);
+        background: image-set("foo.png" 2x, "bar.png" 1x);
       }
     "#},
       Browsers {
-        opera: Some(80 << 16),
         firefox: Some(80 << 16),
-        edge: Some(80 << 16),
         ..Browsers::default()
       },
     );
@@ -15538,22 +20750,16 @@ mod tests {
     prefix_test(
       r#"
       .foo {
-        appearance: none;
+        background: -webkit-image-set(url("foo.png") 2x, url(bar.png) 1x);
       }
     "#,
       indoc! {r#"
       .foo {
-        -webkit-appearance: none;
-        -moz-appearance: none;
-        -ms-appearance: none;
-        appearance: none;
+        background: -webkit-image-set(url("foo.png") 2x, url("bar.png") 1x);
       }
     "#},
       Browsers {
-        safari: Some(8 << 16),
-        chrome: Some(80 << 16),
-        firefox: Some(10 << 16),
-        ie: Some(11 << 16),
+        chrome: Some(95 << 16),
         ..Browsers::default()
       },
     );
@@ -15561,23 +20767,17 @@ mod tests {
     prefix_test(
       r#"
       .foo {
-        -webkit-appearance: none;
-        -moz-appearance: none;
-        -ms-appearance: none;
-        appearance: none;
+        background: image-set("foo.avif" type("image/avif"), "foo.png" type("image/png"), "foo.jpg");
       }
     "#,
       indoc! {r#"
       .foo {
-        -webkit-appearance: none;
-        appearance: none;
+        background: -webkit-image-set(url("foo.jpg") 1x);
+        background: image-set("foo.avif" 1x type("image/avif"), "foo.png" 1x type("image/png"), "foo.jpg" 1x);
       }
     "#},
       Browsers {
-        safari: Some(15 << 16),
         chrome: Some(85 << 16),
-        firefox: Some(80 << 16),
-        edge: Some(85 << 16),
         ..Browsers::default()
       },
     );
@@ -15585,178 +20785,138 @@ mod tests {
     prefix_test(
       r#"
       .foo {
-        -webkit-appearance: none;
-        -moz-appearance: none;
-        -ms-appearance: none;
-        appearance: none;
+        background: image-set("foo.avif" 1x type("image/avif"), "foo-2x.avif" 2x type("image/avif"));
       }
     "#,
       indoc! {r#"
       .foo {
-        appearance: none;
+        background: -webkit-image-set(url("foo.avif") 1x, url("foo-2x.avif") 2x);
+        background: image-set("foo.avif" 1x type("image/avif"), "foo-2x.avif" 2x type("image/avif"));
       }
     "#},
       Browsers {
         chrome: Some(85 << 16),
-        firefox: Some(80 << 16),
-        edge: Some(85 << 16),
-        ..Browsers::default()
-      },
-    );
-
-    prefix_test(
-      ".foo { caret-color: lch(50.998% 135.363 338) }",
-      indoc! { r#"
-        .foo {
-          caret-color: #ee00be;
-          caret-color: color(display-p3 .972962 -.362078 .804206);
-          caret-color: lch(50.998% 135.363 338);
-        }
-      "#},
-      Browsers {
-        chrome: Some(90 << 16),
-        safari: Some(14 << 16),
         ..Browsers::default()
       },
     );
 
-    prefix_test(
-      ".foo { caret: lch(50.998% 135.363 338) block }",
-      indoc! { r#"
-        .foo {
-          caret: #ee00be block;
-          caret: color(display-p3 .972962 -.362078 .804206) block;
-          caret: lch(50.998% 135.363 338) block;
-        }
+    for property in &[
+      "background",
+      "background-image",
+      "border-image-source",
+      "border-image",
+      "border-image-source",
+      "-webkit-mask-image",
+      "-webkit-mask",
+      "list-style-image",
+      "list-style",
+    ] {
+      prefix_test(
+        &format!(
+          r#"
+        .foo {{
+          {}: url(foo.png);
+          {}: image-set(url("foo.png") 2x, url(bar.png) 1x);
+        }}
+      "#,
+          property, property
+        ),
+        &format!(
+          indoc! {r#"
+        .foo {{
+          {}: url("foo.png");
+          {}: image-set("foo.png" 2x, "bar.png" 1x);
+        }}
       "#},
-      Browsers {
-        chrome: Some(90 << 16),
-        safari: Some(14 << 16),
-        ..Browsers::default()
-      },
-    );
-
-    prefix_test(
-      ".foo { caret: lch(50.998% 135.363 338) var(--foo) }",
-      indoc! { r#"
-        .foo {
-          caret: #ee00be var(--foo);
-        }
+          property, property
+        ),
+        Browsers {
+          ie: Some(11 << 16),
+          chrome: Some(95 << 16),
+          ..Browsers::default()
+        },
+      );
 
-        @supports (color: lab(0% 0 0)) {
-          .foo {
-            caret: lab(50.998% 125.506 -50.7078) var(--foo);
-          }
-        }
+      prefix_test(
+        &format!(
+          r#"
+        .foo {{
+          {}: url(foo.png);
+          {}: image-set(url("foo.png") 2x, url(bar.png) 1x);
+        }}
+      "#,
+          property, property
+        ),
+        &format!(
+          indoc! {r#"
+        .foo {{
+          {}: -webkit-image-set(url("foo.png") 2x, url("bar.png") 1x);
+          {}: image-set("foo.png" 2x, "bar.png" 1x);
+        }}
       "#},
-      Browsers {
-        chrome: Some(90 << 16),
-        ..Browsers::default()
-      },
-    );
+          property, property
+        ),
+        Browsers {
+          chrome: Some(95 << 16),
+          ..Browsers::default()
+        },
+      );
+    }
   }
 
   #[test]
-  fn test_list() {
-    minify_test(".foo { list-style-type: disc; }", ".foo{list-style-type:disc}");
-    minify_test(".foo { list-style-type: \"★\"; }", ".foo{list-style-type:\"★\"}");
+  fn test_cross_fade() {
     minify_test(
-      ".foo { list-style-type: symbols(cyclic '○' '●'); }",
-      ".foo{list-style-type:symbols(cyclic \"○\" \"●\")}",
+      ".foo { background-image: cross-fade(url(a.png) 25%, url(b.png)) }",
+      ".foo{background-image:cross-fade(url(a.png) 25%,url(b.png))}",
     );
     minify_test(
-      ".foo { list-style-type: symbols('○' '●'); }",
-      ".foo{list-style-type:symbols(\"○\" \"●\")}",
+      ".foo { background-image: cross-fade(50% url(a.png), rgb(255, 0, 0)) }",
+      ".foo{background-image:cross-fade(url(a.png) 50%,red)}",
     );
     minify_test(
-      ".foo { list-style-type: symbols(symbolic '○' '●'); }",
-      ".foo{list-style-type:symbols(\"○\" \"●\")}",
+      ".foo { background-image: cross-fade(url(a.png), linear-gradient(white, black) 75%) }",
+      ".foo{background-image:cross-fade(url(a.png),linear-gradient(#fff,#000) 75%)}",
     );
     minify_test(
-      ".foo { list-style-type: symbols(symbolic url('ellipse.png')); }",
-      ".foo{list-style-type:symbols(url(ellipse.png))}",
+      ".foo { background-image: -webkit-cross-fade(url(a.png), url(b.png), 0.5) }",
+      ".foo{background-image:-webkit-cross-fade(url(a.png),url(b.png),50%)}",
     );
     minify_test(
-      ".foo { list-style-image: url('ellipse.png'); }",
-      ".foo{list-style-image:url(ellipse.png)}",
+      ".foo { background-image: cross-fade(url(a.png), url(b.png), 30%) }",
+      ".foo{background-image:cross-fade(url(a.png),url(b.png),30%)}",
     );
+    // invalid
     minify_test(
-      ".foo { list-style-position: outside; }",
-      ".foo{list-style-position:outside}",
+      ".foo { background-image: cross-fade(url(a.png) 150%) }",
+      ".foo{background-image:cross-fade(url(a.png) 150%)}",
     );
+    // invalid
     minify_test(
-      ".foo { list-style: \"★\" url(ellipse.png) outside; }",
-      ".foo{list-style:\"★\" url(ellipse.png)}",
-    );
-
-    test(
-      r#"
-      .foo {
-        list-style-type: disc;
-        list-style-image: url(ellipse.png);
-        list-style-position: outside;
-      }
-    "#,
-      indoc! {r#"
-      .foo {
-        list-style: url("ellipse.png");
-      }
-    "#},
+      ".foo { background-image: -webkit-cross-fade(url(a.png) 50%, url(b.png)) }",
+      ".foo{background-image:-webkit-cross-fade(url(a.png) 50%,url(b.png))}",
     );
-
     test(
-      r#"
-      .foo {
-        list-style: \"★\" url(ellipse.png) outside;
-        list-style-image: none;
-      }
-    "#,
+      ".foo { background-image: cross-fade(url(a.png) 25%, url(b.png) 75%) }",
       indoc! {r#"
       .foo {
-        list-style: \"★\";
+        background-image: cross-fade(url("a.png") 25%, url("b.png") 75%);
       }
     "#},
     );
 
-    test(
+    prefix_test(
       r#"
       .foo {
-        list-style: \"★\" url(ellipse.png) outside;
-        list-style-image: var(--img);
+        background-image: cross-fade(url(a.png), url(b.png), 50%);
       }
     "#,
       indoc! {r#"
       .foo {
-        list-style: \"★\" url("ellipse.png");
-        list-style-image: var(--img);
+        background-image: -webkit-cross-fade(url("a.png"), url("b.png"), 50%);
+        background-image: cross-fade(url("a.png"), url("b.png"), 50%);
       }
     "#},
-    );
-
-    prefix_test(
-      ".foo { list-style-image: linear-gradient(lch(56.208% 136.76 46.312), lch(51% 135.366 301.364)) }",
-      indoc! { r#"
-        .foo {
-          list-style-image: -webkit-gradient(linear, 0 0, 0 100%, from(#ff0f0e), to(#7773ff));
-          list-style-image: -webkit-linear-gradient(#ff0f0e, #7773ff);
-          list-style-image: linear-gradient(#ff0f0e, #7773ff);
-          list-style-image: linear-gradient(lch(56.208% 136.76 46.312), lch(51% 135.366 301.364));
-        }
-      "#},
-      Browsers {
-        chrome: Some(8 << 16),
-        ..Browsers::default()
-      },
-    );
-
-    prefix_test(
-      ".foo { list-style: \"★\" linear-gradient(lch(56.208% 136.76 46.312), lch(51% 135.366 301.364)) }",
-      indoc! { r#"
-        .foo {
-          list-style: "★" linear-gradient(#ff0f0e, #7773ff);
-          list-style: "★" linear-gradient(lch(56.208% 136.76 46.312), lch(51% 135.366 301.364));
-        }
-      "#},
       Browsers {
         chrome: Some(90 << 16),
         ..Browsers::default()
@@ -15764,122 +20924,136 @@ mod tests {
     );
 
     prefix_test(
-      ".foo { list-style: var(--foo) linear-gradient(lch(56.208% 136.76 46.312), lch(51% 135.366 301.364)) }",
-      indoc! { r#"
-        .foo {
-          list-style: var(--foo) linear-gradient(#ff0f0e, #7773ff);
-        }
-
-        @supports (color: lab(0% 0 0)) {
-          .foo {
-            list-style: var(--foo) linear-gradient(lab(56.208% 94.4644 98.8928), lab(51% 70.4544 -115.586));
-          }
-        }
-      "#},
-      Browsers {
-        chrome: Some(90 << 16),
-        ..Browsers::default()
-      },
-    );
-  }
-
-  #[test]
-  fn test_image_set() {
-    // Spec: https://drafts.csswg.org/css-images-4/#image-set-notation
-    // WPT: https://github.com/web-platform-tests/wpt/blob/master/css/css-images/image-set/image-set-parsing.html
-    // test image-set(<string>)
-    minify_test(
-      ".foo { background: image-set(\"foo.png\" 2x, url(bar.png) 1x) }",
-      ".foo{background:image-set(\"foo.png\" 2x,\"bar.png\" 1x)}",
-    );
-
-    // test image-set(type(<string>))
-    minify_test(
-      ".foo { background: image-set('foo.webp' type('webp'), url(foo.jpg)) }",
-      ".foo{background:image-set(\"foo.webp\" 1x type(\"webp\"),\"foo.jpg\" 1x)}",
-    );
-    minify_test(
-      ".foo { background: image-set('foo.avif' 2x type('image/avif'), url(foo.png)) }",
-      ".foo{background:image-set(\"foo.avif\" 2x type(\"image/avif\"),\"foo.png\" 1x)}",
-    );
-    minify_test(
-      ".foo { background: image-set(url('example.png') 3x type('image/png')) }",
-      ".foo{background:image-set(\"example.png\" 3x type(\"image/png\"))}",
-    );
-
-    minify_test(
-      ".foo { background: image-set(url(example.png) type('image/png') 1x) }",
-      ".foo{background:image-set(\"example.png\" 1x type(\"image/png\"))}",
+      r#"
+      .foo {
+        background-image: cross-fade(url(a.png) 50%, url(b.png));
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        background-image: cross-fade(url("a.png") 50%, url("b.png"));
+      }
+    "#},
+      Browsers {
+        chrome: Some(90 << 16),
+        ..Browsers::default()
+      },
     );
 
-    minify_test(
-      ".foo { background: -webkit-image-set(url(\"foo.png\") 2x, url(bar.png) 1x) }",
-      ".foo{background:-webkit-image-set(url(foo.png) 2x,url(bar.png) 1x)}",
+    prefix_test(
+      r#"
+      .foo {
+        background-image: cross-fade(url(a.png) 50%, lab(50% 20 30));
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        background-image: cross-fade(url("a.png") 50%, #a16945);
+        background-image: cross-fade(url("a.png") 50%, lab(50% 20 30));
+      }
+    "#},
+      Browsers {
+        chrome: Some(90 << 16),
+        ..Browsers::default()
+      },
     );
 
-    test(
+    prefix_test(
       r#"
       .foo {
-        background: -webkit-image-set(url("foo.png") 2x, url(bar.png) 1x);
-        background: image-set(url("foo.png") 2x, url(bar.png) 1x);
+        background: var(--pos) cross-fade(url(a.png) 50%, lab(50% 20 30));
       }
     "#,
       indoc! {r#"
       .foo {
-        background: -webkit-image-set(url("foo.png") 2x, url("bar.png") 1x);
-        background: image-set("foo.png" 2x, "bar.png" 1x);
+        background: var(--pos) cross-fade(url("a.png") 50%, #a16945);
+      }
+
+      @supports (color: lab(0% 0 0)) {
+        .foo {
+          background: var(--pos) cross-fade(url("a.png") 50%, lab(50% 20 30));
+        }
       }
     "#},
+      Browsers {
+        chrome: Some(90 << 16),
+        ..Browsers::default()
+      },
     );
 
-    // test image-set(<gradient>)
-    test(
+    prefix_test(
       r#"
       .foo {
-        background: image-set(linear-gradient(cornflowerblue, white) 1x, url("detailed-gradient.png") 3x);
+        background: url(a.png);
+        background: -webkit-cross-fade(url(a.png), url(b.png), 50%);
       }
     "#,
       indoc! {r#"
       .foo {
-        background: image-set(linear-gradient(#6495ed, #fff) 1x, "detailed-gradient.png" 3x);
+        background: -webkit-cross-fade(url("a.png"), url("b.png"), 50%);
       }
     "#},
+      Browsers {
+        chrome: Some(90 << 16),
+        ..Browsers::default()
+      },
     );
 
     prefix_test(
       r#"
       .foo {
-        background: image-set(url("foo.png") 2x, url(bar.png) 1x);
+        background: url(a.png);
+        background: -webkit-cross-fade(url(a.png), url(b.png), 50%);
       }
     "#,
       indoc! {r#"
       .foo {
-        background: -webkit-image-set(url("foo.png") 2x, url("bar.png") 1x);
-        background: image-set("foo.png" 2x, "bar.png" 1x);
+        background: url("a.png");
+        background: -webkit-cross-fade(url("a.png"), url("b.png"), 50%);
       }
     "#},
       Browsers {
-        chrome: Some(85 << 16),
-        firefox: Some(80 << 16),
+        chrome: Some(90 << 16),
+        firefox: Some(100 << 16),
         ..Browsers::default()
       },
     );
+  }
+
+  #[test]
+  fn test_element_image() {
+    minify_test(
+      ".foo { background-image: element(#foo) }",
+      ".foo{background-image:element(#foo)}",
+    );
+    minify_test(
+      ".foo { background-image: -moz-element(#foo) }",
+      ".foo{background-image:-moz-element(#foo)}",
+    );
+    minify_test(
+      ".foo { background-image: ELEMENT(#foo), url(a.png) }",
+      ".foo{background-image:element(#foo),url(a.png)}",
+    );
+    // invalid
+    minify_test(
+      ".foo { background-image: element(.foo) }",
+      ".foo{background-image:element(.foo)}",
+    );
 
     prefix_test(
       r#"
       .foo {
-        background: -webkit-image-set(url("foo.png") 2x, url(bar.png) 1x);
-        background: image-set(url("foo.png") 2x, url(bar.png) 1x);
+        background-image: element(#foo);
       }
     "#,
       indoc! {r#"
       .foo {
-        background: -webkit-image-set(url("foo.png") 2x, url("bar.png") 1x);
-        background: image-set("foo.png" 2x, "bar.png" 1x);
+        background-image: -moz-element(#foo);
+        background-image: element(#foo);
       }
     "#},
       Browsers {
-        firefox: Some(80 << 16),
+        firefox: Some(100 << 16),
         ..Browsers::default()
       },
     );
@@ -15887,82 +21061,20 @@ mod tests {
     prefix_test(
       r#"
       .foo {
-        background: -webkit-image-set(url("foo.png") 2x, url(bar.png) 1x);
+        background: url(a.png);
+        background: -moz-element(#foo);
       }
     "#,
       indoc! {r#"
       .foo {
-        background: -webkit-image-set(url("foo.png") 2x, url("bar.png") 1x);
+        background: -moz-element(#foo);
       }
     "#},
       Browsers {
-        chrome: Some(95 << 16),
+        firefox: Some(100 << 16),
         ..Browsers::default()
       },
     );
-
-    for property in &[
-      "background",
-      "background-image",
-      "border-image-source",
-      "border-image",
-      "border-image-source",
-      "-webkit-mask-image",
-      "-webkit-mask",
-      "list-style-image",
-      "list-style",
-    ] {
-      prefix_test(
-        &format!(
-          r#"
-        .foo {{
-          {}: url(foo.png);
-          {}: image-set(url("foo.png") 2x, url(bar.png) 1x);
-        }}
-      "#,
-          property, property
-        ),
-        &format!(
-          indoc! {r#"
-        .foo {{
-          {}: url("foo.png");
-          {}: image-set("foo.png" 2x, "bar.png" 1x);
-        }}
-      "#},
-          property, property
-        ),
-        Browsers {
-          ie: Some(11 << 16),
-          chrome: Some(95 << 16),
-          ..Browsers::default()
-        },
-      );
-
-      prefix_test(
-        &format!(
-          r#"
-        .foo {{
-          {}: url(foo.png);
-          {}: image-set(url("foo.png") 2x, url(bar.png) 1x);
-        }}
-      "#,
-          property, property
-        ),
-        &format!(
-          indoc! {r#"
-        .foo {{
-          {}: -webkit-image-set(url("foo.png") 2x, url("bar.png") 1x);
-          {}: image-set("foo.png" 2x, "bar.png" 1x);
-        }}
-      "#},
-          property, property
-        ),
-        Browsers {
-          chrome: Some(95 << 16),
-          ..Browsers::default()
-        },
-      );
-    }
   }
 
   #[test]
@@ -15993,7 +21105,40 @@ mod tests {
     minify_test(".foo { color: hwb(194 0% 50%) }", ".foo{color:#006280}");
     minify_test(".foo { color: hwb(194 50% 0%) }", ".foo{color:#80e1ff}");
     minify_test(".foo { color: hwb(194 50% 50%) }", ".foo{color:gray}");
-    // minify_test(".foo { color: ActiveText }", ".foo{color:ActiveTet}");
+    minify_test(".foo { color: hwb(554 0% 0%) }", ".foo{color:#00c4ff}");
+    minify_test(".foo { color: hwb(194deg 70% 60%) }", ".foo{color:#898989}");
+    minify_test(".foo { color: hwb(194, 0%, 0%) }", ".foo{color:hwb(194,0%,0%)}"); // invalid
+    minify_test(".foo { color: ActiveText }", ".foo{color:activetext}");
+    minify_test(".foo { color: Canvas }", ".foo{color:canvas}");
+    minify_test(".foo { color: CanvasText }", ".foo{color:canvastext}");
+    minify_test(".foo { color: AccentColorText }", ".foo{color:accentcolortext}");
+    minify_test(".foo { color: SelectedItemText }", ".foo{color:selecteditemtext}");
+    minify_test(".foo { color: ThreeDFace }", ".foo{color:threedface}");
+    prefix_test(
+      ".foo { color: red; color: AccentColor }",
+      indoc! {r#"
+        .foo {
+          color: red;
+          color: accentcolor;
+        }
+      "#},
+      Browsers {
+        chrome: Some(100 << 16),
+        ..Browsers::default()
+      },
+    );
+    prefix_test(
+      ".foo { color: red; color: AccentColor }",
+      indoc! {r#"
+        .foo {
+          color: accentcolor;
+        }
+      "#},
+      Browsers {
+        safari: Some(17 << 16),
+        ..Browsers::default()
+      },
+    );
     minify_test(
       ".foo { color: lab(29.2345% 39.3825 20.0664); }",
       ".foo{color:lab(29.2345% 39.3825 20.0664)}",
@@ -16038,6 +21183,23 @@ mod tests {
       ".foo { color: oklch(40.101% 0.12332 .5turn); }",
       ".foo{color:oklch(40.101% .12332 180)}",
     );
+    minify_test(
+      ".foo { color: oklch(40.101% 0.12332 -90deg); }",
+      ".foo{color:oklch(40.101% .12332 270)}",
+    );
+    minify_test(
+      ".foo { color: lch(29.2345% 44.2 747); }",
+      ".foo{color:lch(29.2345% 44.2 27)}",
+    );
+    minify_test(
+      ".foo { color: lch(29.2345% 44.2 360); }",
+      ".foo{color:lch(29.2345% 44.2 0)}",
+    );
+    minify_test(".foo { color: lch(50% 20 -0.00001); }", ".foo{color:lch(50% 20 0)}");
+    minify_test(
+      ".foo { color: lch(29.2345% 44.2 none); }",
+      ".foo{color:lch(29.2345% 44.2 none)}",
+    );
     minify_test(
       ".foo { color: color(display-p3 1 0.5 0); }",
       ".foo{color:color(display-p3 1 .5)}",
@@ -16878,6 +22040,28 @@ mod tests {
       ".foo{color:lch(from currentColor l c sin(h))}",
       ".foo{color:lch(from currentColor l c sin(h))}",
     );
+    minify_test(
+      ".foo { color: rgb(from var(--c) r g b / 0.5) }",
+      ".foo{color:rgb(from var(--c)r g b/.5)}",
+    );
+    minify_test(
+      ".foo { color: lch(from var(--c) calc(l + 10%) c h) }",
+      ".foo{color:lch(from var(--c)calc(l + 10%)c h)}",
+    );
+    minify_test(
+      ".foo { color: rgb(from indianred r g b / var(--alpha)) }",
+      ".foo{color:rgb(205 92 92/var(--alpha))}",
+    );
+    minify_test(".foo { --c: rgb(from red r g b / 0.5) }", ".foo{--c:#ff000080}");
+    test_with_options(
+      ".foo { color: rgb(from var(--c) r g b / 0.5) }",
+      indoc! {r#"
+        .foo {
+          color: rgb(from var(--c) r g b / .5);
+        }
+      "#},
+      ParserOptions::default(),
+    );
 
     // The following tests were converted from WPT: https://github.com/web-platform-tests/wpt/blob/master/css/css-color/parsing/relative-color-valid.html
     // Find: test_valid_value\(`color`, `(.*?)`,\s*`(.*?)`\)
@@ -18737,6 +23921,18 @@ mod tests {
       ".foo { color: color-mix(in srgb, blue, currentColor); }",
       ".foo{color:color-mix(in srgb,blue,currentColor)}",
     );
+    minify_test(
+      ".foo { color: color-mix(in srgb, red -10%, blue); }",
+      ".foo{color:color-mix(in srgb,red -10%,blue)}",
+    ); // invalid
+    minify_test(
+      ".foo { color: color-mix(in srgb, red, blue 150%); }",
+      ".foo{color:color-mix(in srgb,red,blue 150%)}",
+    ); // invalid
+    minify_test(
+      ".foo { color: color-mix(in srgb, red 0%, blue 100%); }",
+      ".foo{color:#00f}",
+    );
 
     // regex for converting web platform tests:
     // test_computed_value\(.*?, `(.*?)`, `(.*?)`\);
@@ -20191,6 +25387,38 @@ mod tests {
       ".foo { grid-template-rows: [linename1 linename2] 100px repeat(auto-fit, [linename1] 300px) [linename3]; }",
       ".foo{grid-template-rows:[linename1 linename2]100px repeat(auto-fit,[linename1]300px)[linename3]}",
     );
+    minify_test(
+      ".foo { grid-template-rows: subgrid; }",
+      ".foo{grid-template-rows:subgrid}",
+    );
+    minify_test(
+      ".foo { grid-template-columns: subgrid [a] [b c]; }",
+      ".foo{grid-template-columns:subgrid[a][b c]}",
+    );
+    test(
+      ".foo { grid-template-columns: subgrid [a] [b c]; }",
+      indoc! {r#"
+        .foo {
+          grid-template-columns: subgrid [a] [b c];
+        }
+      "#},
+    );
+    minify_test(
+      ".foo { grid-template: subgrid / subgrid [a]; }",
+      ".foo{grid-template:subgrid/subgrid[a]}",
+    );
+    minify_test(
+      ".foo { grid: subgrid / auto-flow 1fr; }",
+      ".foo{grid:subgrid/auto-flow 1fr}",
+    );
+    minify_test(
+      ".foo { grid-template-rows: subgrid; grid-template-columns: subgrid; grid-template-areas: none; }",
+      ".foo{grid-template:subgrid/subgrid}",
+    );
+    minify_test(
+      ".foo { grid-template-areas: \"a b\"; grid-template-rows: 1fr; grid-template-columns: subgrid; }",
+      ".foo{grid-template-rows:1fr;grid-template-columns:subgrid;grid-template-areas:\"a b\"}",
+    );
 
     minify_test(".foo { grid-auto-rows: auto; }", ".foo{grid-auto-rows:auto}");
     minify_test(".foo { grid-auto-rows: 1fr; }", ".foo{grid-auto-rows:1fr}");
@@ -20416,6 +25644,19 @@ mod tests {
       ".foo { grid-row-start: span some-line 5 }",
       ".foo{grid-row-start:span 5 some-line}",
     );
+    minify_test(".foo { grid-row-start: 2 span }", ".foo{grid-row-start:span 2}");
+    minify_test(
+      ".foo { grid-row-start: some-line span }",
+      ".foo{grid-row-start:span some-line}",
+    );
+    minify_test(
+      ".foo { grid-row-start: 5 some-line span }",
+      ".foo{grid-row-start:span 5 some-line}",
+    );
+    minify_test(".foo { grid-row-start: span span }", ".foo{grid-row-start:span span}"); // invalid
+    minify_test(".foo { grid-row-start: 2 auto }", ".foo{grid-row-start:2 auto}"); // invalid
+    minify_test(".foo { grid-row-start: span 0 }", ".foo{grid-row-start:span 0}"); // invalid
+    minify_test(".foo { grid-row-start: inherit 2 }", ".foo{grid-row-start:inherit 2}"); // invalid
 
     minify_test(
       ".foo { grid-row-end: span 1 some-line }",
@@ -20905,15 +26146,96 @@ mod tests {
         }
       }
     "#,
-      "@-moz-document url-prefix(){h1{color:#ff0}}",
-    );
-    error_test(
-      "@-moz-document url-prefix(foo) {}",
-      ParserError::UnexpectedToken(crate::properties::custom::Token::Ident("foo".into())),
-    );
-    error_test(
-      "@-moz-document url-prefix(\"foo\") {}",
-      ParserError::UnexpectedToken(crate::properties::custom::Token::String("foo".into())),
+      "@-moz-document url-prefix(){h1{color:#ff0}}",
+    );
+    error_test(
+      "@-moz-document url-prefix(foo) {}",
+      ParserError::UnexpectedToken(crate::properties::custom::Token::Ident("foo".into())),
+    );
+    error_test(
+      "@-moz-document url-prefix(\"foo\") {}",
+      ParserError::UnexpectedToken(crate::properties::custom::Token::String("foo".into())),
+    );
+  }
+
+  #[test]
+  fn test_all() {
+    minify_test(".foo { all: unset }", ".foo{all:unset}");
+    minify_test(".foo { all: initial }", ".foo{all:initial}");
+    minify_test(".foo { all: inherit }", ".foo{all:inherit}");
+    minify_test(".foo { all: revert }", ".foo{all:revert}");
+    minify_test(".foo { ALL: Revert-Layer }", ".foo{all:revert-layer}");
+    minify_test(".foo { all: red }", ".foo{all:red}"); // invalid
+    minify_test(".foo { all: unset inherit }", ".foo{all:unset inherit}"); // invalid
+    minify_test(".foo { all: var(--all) }", ".foo{all:var(--all)}");
+
+    minify_test(".foo { color: red; all: unset }", ".foo{all:unset}");
+    minify_test(
+      ".foo { margin: 10px; background: red; all: initial; padding: 5px }",
+      ".foo{all:initial;padding:5px}",
+    );
+    minify_test(
+      ".foo { color: red; direction: rtl; --foo: bar; all: initial; color: blue }",
+      ".foo{direction:rtl;--foo:bar;all:initial;color:#00f}",
+    );
+    minify_test(
+      ".foo { unicode-bidi: isolate; color: red; all: revert }",
+      ".foo{all:revert;unicode-bidi:isolate}",
+    );
+    minify_test(
+      ".foo { --foo: bar; all: unset; --foo: baz }",
+      ".foo{--foo:baz;all:unset}",
+    );
+    minify_test(
+      ".foo { color: red !important; all: unset }",
+      ".foo{all:unset;color:red!important}",
+    );
+    minify_test(".foo { color: red; all: var(--all) }", ".foo{color:red;all:var(--all)}");
+    minify_test(".foo { color: red; all: red }", ".foo{color:red;all:red}");
+    prefix_test(
+      ".foo { margin: 10px; color: red; all: initial; padding: 5px }",
+      indoc! {r#"
+      .foo {
+        color: red;
+        margin: 10px;
+        all: initial;
+        padding: 5px;
+      }
+    "#},
+      Browsers {
+        ie: Some(11 << 16),
+        chrome: Some(100 << 16),
+        ..Browsers::default()
+      },
+    );
+    prefix_test(
+      ".foo { margin: 10px; color: red; all: initial; padding: 5px }",
+      indoc! {r#"
+      .foo {
+        all: initial;
+        padding: 5px;
+      }
+    "#},
+      Browsers {
+        chrome: Some(100 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    test(
+      r#"
+      .foo {
+        color: red;
+      }
+      .foo {
+        all: revert;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        all: revert;
+      }
+    "#},
     );
   }
 
@@ -20952,6 +26274,18 @@ mod tests {
       ".foo { color: var(--color, rgb(var(--red), var(--green), 0)); }",
       ".foo{color:var(--color,rgb(var(--red),var(--green),0))}",
     );
+    minify_test(
+      ".foo { color: VAR(--color, rgb(255, 255, 0)); }",
+      ".foo{color:var(--color,#ff0)}",
+    );
+    minify_test(
+      ".foo { padding: Env(safe-area-inset-top, 20px) }",
+      ".foo{padding:env(safe-area-inset-top,20px)}",
+    );
+    minify_test(
+      ".foo { background: URL(\"foo.png\") var(--color) }",
+      ".foo{background:url(foo.png) var(--color)}",
+    );
     minify_test(".foo { --test: .5s; }", ".foo{--test:.5s}");
     minify_test(".foo { --theme-sizes-1\\/12: 2 }", ".foo{--theme-sizes-1\\/12:2}");
     minify_test(".foo { --test: 0px; }", ".foo{--test:0px}");
@@ -21493,6 +26827,78 @@ mod tests {
         ..Browsers::default()
       },
     );
+    minify_test(
+      ".foo { width: attr(data-width type(<length>), 10px) }",
+      ".foo{width:attr(data-width type(<length>),10px)}",
+    );
+    minify_test(
+      ".foo { width: attr(data-width type(<length> | <percentage>)) }",
+      ".foo{width:attr(data-width type(<length>|<percentage>))}",
+    );
+    test(
+      ".foo { width: attr(data-width type(<length> | <percentage>), calc(10px + 2px)) }",
+      indoc! {r#"
+      .foo {
+        width: attr(data-width type(<length> | <percentage>), calc(10px + 2px));
+      }
+    "#},
+    );
+    minify_test(".foo { width: attr(data-width px) }", ".foo{width:attr(data-width px)}");
+    minify_test(
+      ".foo { opacity: attr(data-opacity %, 1) }",
+      ".foo{opacity:attr(data-opacity %,1)}",
+    );
+    minify_test(
+      ".foo { order: ATTR(data-order number, 0) }",
+      ".foo{order:attr(data-order number,0)}",
+    );
+    minify_test(
+      ".foo { --x: attr(data-x raw-string, \"foo\") }",
+      ".foo{--x:attr(data-x raw-string,\"foo\")}",
+    );
+    prefix_test(
+      ".foo { background: attr(data-bg type(<color>), lab(40% 56.6 39)) }",
+      indoc! {r#"
+      .foo {
+        background: attr(data-bg type(<color>), #b32323);
+      }
+
+      @supports (color: lab(0% 0 0)) {
+        .foo {
+          background: attr(data-bg type(<color>), lab(40% 56.6 39));
+        }
+      }
+    "#},
+      Browsers {
+        chrome: Some(90 << 16),
+        ..Browsers::default()
+      },
+    );
+    // attr() functions that do not match the typed syntax are preserved as generic functions.
+    minify_test(
+      ".foo { width: attr(\"data-width\") }",
+      ".foo{width:attr(\"data-width\")}",
+    );
+    minify_test(
+      ".foo { width: attr(data-width foo) }",
+      ".foo{width:attr(data-width foo)}",
+    );
+    minify_test(
+      ".foo { width: ATTR(data-width px foo) }",
+      ".foo{width:ATTR(data-width px foo)}",
+    );
+    minify_test(".a { --x: attr(foo string) }", ".a{--x:attr(foo string)}");
+    minify_test(
+      ".a { --x: attr(foo string); content: attr(data-x string) }",
+      ".a{--x:attr(foo string);content:attr(data-x string)}",
+    );
+    minify_test(".a { --x: attr(svg|foo) }", ".a{--x:attr(svg|foo)}");
+    minify_test(".a { --x: attr(|foo px, 0) }", ".a{--x:attr(|foo px,0)}");
+    minify_test(".a { width: attr(svg|foo px) }", ".a{width:attr(svg|foo px)}");
+    minify_test(
+      ".a { color: attr(data-c color, red) }",
+      ".a{color:attr(data-c color,red)}",
+    );
   }
 
   #[test]
@@ -23897,6 +29303,14 @@ mod tests {
       ".foo { mask: url(masks.svg#star) 0 0 / 50px 50px }",
       ".foo{mask:url(masks.svg#star) 0 0/50px 50px}",
     );
+    minify_test(
+      ".foo { mask: url(masks.svg#star) 0 0 }",
+      ".foo{mask:url(masks.svg#star)}",
+    );
+    minify_test(
+      ".foo { mask: url(masks.svg#star) 0px 0% / auto repeat border-box border-box add match-source }",
+      ".foo{mask:url(masks.svg#star)}",
+    );
     minify_test(
       ".foo { mask: url(masks.svg#star) repeat-x }",
       ".foo{mask:url(masks.svg#star) repeat-x}",
@@ -23918,3150 +29332,4284 @@ mod tests {
       ".foo{mask:url(masks.svg#star) 0/16px repeat-y,url(masks.svg#circle) 100%/16px repeat-y}",
     );
 
-    minify_test(
-      ".foo { mask-border: url('border-mask.png') 25; }",
-      ".foo{mask-border:url(border-mask.png) 25}",
-    );
-    minify_test(
-      ".foo { mask-border: url('border-mask.png') 25 / 35px / 12px space alpha; }",
-      ".foo{mask-border:url(border-mask.png) 25/35px/12px space}",
-    );
-    minify_test(
-      ".foo { mask-border: url('border-mask.png') 25 / 35px / 12px space luminance; }",
-      ".foo{mask-border:url(border-mask.png) 25/35px/12px space luminance}",
+    minify_test(
+      ".foo { mask-border: url('border-mask.png') 25; }",
+      ".foo{mask-border:url(border-mask.png) 25}",
+    );
+    minify_test(
+      ".foo { mask-border: url('border-mask.png') 25 / 35px / 12px space alpha; }",
+      ".foo{mask-border:url(border-mask.png) 25/35px/12px space}",
+    );
+    minify_test(
+      ".foo { mask-border: url('border-mask.png') 25 / 35px / 12px space luminance; }",
+      ".foo{mask-border:url(border-mask.png) 25/35px/12px space luminance}",
+    );
+    minify_test(
+      ".foo { mask-border: url('border-mask.png') luminance 25 / 35px / 12px space; }",
+      ".foo{mask-border:url(border-mask.png) 25/35px/12px space luminance}",
+    );
+
+    minify_test(
+      ".foo { clip-path: url('clip.svg#star'); }",
+      ".foo{clip-path:url(clip.svg#star)}",
+    );
+    minify_test(".foo { clip-path: margin-box; }", ".foo{clip-path:margin-box}");
+    minify_test(
+      ".foo { clip-path: inset(100px 50px); }",
+      ".foo{clip-path:inset(100px 50px)}",
+    );
+    minify_test(
+      ".foo { clip-path: inset(100px 50px round 5px); }",
+      ".foo{clip-path:inset(100px 50px round 5px)}",
+    );
+    minify_test(
+      ".foo { clip-path: inset(100px 50px round 5px 5px 5px 5px); }",
+      ".foo{clip-path:inset(100px 50px round 5px)}",
+    );
+    minify_test(".foo { clip-path: circle(50px); }", ".foo{clip-path:circle(50px)}");
+    minify_test(
+      ".foo { clip-path: circle(50px at center center); }",
+      ".foo{clip-path:circle(50px)}",
+    );
+    minify_test(
+      ".foo { clip-path: circle(50px at 50% 50%); }",
+      ".foo{clip-path:circle(50px)}",
+    );
+    minify_test(
+      ".foo { clip-path: circle(50px at 0 100px); }",
+      ".foo{clip-path:circle(50px at 0 100px)}",
+    );
+    minify_test(
+      ".foo { clip-path: circle(closest-side at 0 100px); }",
+      ".foo{clip-path:circle(at 0 100px)}",
+    );
+    minify_test(
+      ".foo { clip-path: circle(farthest-side at 0 100px); }",
+      ".foo{clip-path:circle(farthest-side at 0 100px)}",
+    );
+    minify_test(
+      ".foo { clip-path: circle(closest-side at 50% 50%); }",
+      ".foo{clip-path:circle()}",
+    );
+    minify_test(
+      ".foo { clip-path: ellipse(50px 60px at 0 10% 20%); }",
+      ".foo{clip-path:ellipse(50px 60px at 0 10% 20%)}",
+    );
+    minify_test(
+      ".foo { clip-path: ellipse(50px 60px at center center); }",
+      ".foo{clip-path:ellipse(50px 60px)}",
+    );
+    minify_test(
+      ".foo { clip-path: ellipse(closest-side closest-side at 50% 50%); }",
+      ".foo{clip-path:ellipse()}",
+    );
+    minify_test(
+      ".foo { clip-path: ellipse(closest-side closest-side at 10% 20%); }",
+      ".foo{clip-path:ellipse(at 10% 20%)}",
+    );
+    minify_test(
+      ".foo { clip-path: ellipse(farthest-side closest-side at 10% 20%); }",
+      ".foo{clip-path:ellipse(farthest-side closest-side at 10% 20%)}",
+    );
+    minify_test(
+      ".foo { clip-path: polygon(50% 0%, 100% 50%, 50% 100%, 0% 50%); }",
+      ".foo{clip-path:polygon(50% 0%,100% 50%,50% 100%,0% 50%)}",
+    );
+    minify_test(
+      ".foo { clip-path: polygon(nonzero, 50% 0%, 100% 50%, 50% 100%, 0% 50%); }",
+      ".foo{clip-path:polygon(50% 0%,100% 50%,50% 100%,0% 50%)}",
+    );
+    minify_test(
+      ".foo { clip-path: polygon(evenodd, 50% 0%, 100% 50%, 50% 100%, 0% 50%); }",
+      ".foo{clip-path:polygon(evenodd,50% 0%,100% 50%,50% 100%,0% 50%)}",
+    );
+    minify_test(
+      ".foo { clip-path: path('M 0 0 L 100 0 L 50 100 Z'); }",
+      ".foo{clip-path:path(\"M 0 0 L 100 0 L 50 100 Z\")}",
+    );
+    minify_test(
+      ".foo { clip-path: path(nonzero, 'M 0 0 L 100 0 L 50 100 Z'); }",
+      ".foo{clip-path:path(\"M 0 0 L 100 0 L 50 100 Z\")}",
+    );
+    minify_test(
+      ".foo { clip-path: path(evenodd, \"M 0 0 L 100 0 L 50 100 Z\") padding-box; }",
+      ".foo{clip-path:path(evenodd,\"M 0 0 L 100 0 L 50 100 Z\") padding-box}",
+    );
+    minify_test(
+      ".foo { clip-path: circle(-10px at center); }",
+      ".foo{clip-path:circle(-10px at center)}",
+    );
+    minify_test(
+      ".foo { clip-path: ellipse(10px -5% at center); }",
+      ".foo{clip-path:ellipse(10px -5% at center)}",
+    );
+    minify_test(
+      ".foo { clip-path: padding-box circle(50px at 0 100px); }",
+      ".foo{clip-path:circle(50px at 0 100px) padding-box}",
+    );
+    minify_test(
+      ".foo { clip-path: circle(50px at 0 100px) padding-box; }",
+      ".foo{clip-path:circle(50px at 0 100px) padding-box}",
+    );
+    minify_test(
+      ".foo { clip-path: circle(50px at 0 100px) border-box; }",
+      ".foo{clip-path:circle(50px at 0 100px)}",
+    );
+
+    prefix_test(
+      ".foo { clip-path: circle(50px); }",
+      indoc! { r#"
+        .foo {
+          -webkit-clip-path: circle(50px);
+          clip-path: circle(50px);
+        }
+      "#},
+      Browsers {
+        chrome: Some(30 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      ".foo { clip-path: circle(50px); }",
+      indoc! { r#"
+        .foo {
+          clip-path: circle(50px);
+        }
+      "#},
+      Browsers {
+        chrome: Some(80 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      ".foo { clip-path: circle(50px); }",
+      indoc! { r#"
+        .foo {
+          -webkit-clip-path: circle(50px);
+          clip-path: circle(50px);
+        }
+      "#},
+      Browsers {
+        safari: Some(8 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      ".foo { clip-path: circle(50px); }",
+      indoc! { r#"
+        .foo {
+          clip-path: circle(50px);
+        }
+      "#},
+      Browsers {
+        safari: Some(14 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      ".foo { fill: lch(50.998% 135.363 338) }",
+      indoc! { r#"
+        .foo {
+          fill: #ee00be;
+          fill: color(display-p3 .972962 -.362078 .804206);
+          fill: lch(50.998% 135.363 338);
+        }
+      "#},
+      Browsers {
+        chrome: Some(90 << 16),
+        safari: Some(14 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      ".foo { stroke: lch(50.998% 135.363 338) }",
+      indoc! { r#"
+        .foo {
+          stroke: #ee00be;
+          stroke: color(display-p3 .972962 -.362078 .804206);
+          stroke: lch(50.998% 135.363 338);
+        }
+      "#},
+      Browsers {
+        chrome: Some(90 << 16),
+        safari: Some(14 << 16),
+        ..Browsers::default()
+      },
     );
-    minify_test(
-      ".foo { mask-border: url('border-mask.png') luminance 25 / 35px / 12px space; }",
-      ".foo{mask-border:url(border-mask.png) 25/35px/12px space luminance}",
+
+    prefix_test(
+      ".foo { fill: url(#foo) lch(50.998% 135.363 338) }",
+      indoc! { r##"
+        .foo {
+          fill: url("#foo") #ee00be;
+          fill: url("#foo") color(display-p3 .972962 -.362078 .804206);
+          fill: url("#foo") lch(50.998% 135.363 338);
+        }
+      "##},
+      Browsers {
+        chrome: Some(90 << 16),
+        safari: Some(14 << 16),
+        ..Browsers::default()
+      },
     );
 
-    minify_test(
-      ".foo { clip-path: url('clip.svg#star'); }",
-      ".foo{clip-path:url(clip.svg#star)}",
+    prefix_test(
+      ".foo { fill: var(--url) lch(50.998% 135.363 338) }",
+      indoc! { r#"
+        .foo {
+          fill: var(--url) #ee00be;
+        }
+
+        @supports (color: lab(0% 0 0)) {
+          .foo {
+            fill: var(--url) lab(50.998% 125.506 -50.7078);
+          }
+        }
+      "#},
+      Browsers {
+        chrome: Some(90 << 16),
+        ..Browsers::default()
+      },
     );
-    minify_test(".foo { clip-path: margin-box; }", ".foo{clip-path:margin-box}");
-    minify_test(
-      ".foo { clip-path: inset(100px 50px); }",
-      ".foo{clip-path:inset(100px 50px)}",
+
+    prefix_test(
+      ".foo { mask-image: linear-gradient(lch(56.208% 136.76 46.312), lch(51% 135.366 301.364)) }",
+      indoc! { r#"
+        .foo {
+          -webkit-mask-image: -webkit-gradient(linear, 0 0, 0 100%, from(#ff0f0e), to(#7773ff));
+          -webkit-mask-image: -webkit-linear-gradient(#ff0f0e, #7773ff);
+          -webkit-mask-image: linear-gradient(#ff0f0e, #7773ff);
+          mask-image: linear-gradient(#ff0f0e, #7773ff);
+          -webkit-mask-image: linear-gradient(lch(56.208% 136.76 46.312), lch(51% 135.366 301.364));
+          mask-image: linear-gradient(lch(56.208% 136.76 46.312), lch(51% 135.366 301.364));
+        }
+      "#},
+      Browsers {
+        chrome: Some(8 << 16),
+        ..Browsers::default()
+      },
     );
-    minify_test(
-      ".foo { clip-path: inset(100px 50px round 5px); }",
-      ".foo{clip-path:inset(100px 50px round 5px)}",
+
+    prefix_test(
+      ".foo { mask-image: linear-gradient(lch(56.208% 136.76 46.312), lch(51% 135.366 301.364)) }",
+      indoc! { r#"
+        .foo {
+          -webkit-mask-image: linear-gradient(#ff0f0e, #7773ff);
+          mask-image: linear-gradient(#ff0f0e, #7773ff);
+          -webkit-mask-image: linear-gradient(lch(56.208% 136.76 46.312), lch(51% 135.366 301.364));
+          mask-image: linear-gradient(lch(56.208% 136.76 46.312), lch(51% 135.366 301.364));
+        }
+      "#},
+      Browsers {
+        chrome: Some(95 << 16),
+        ..Browsers::default()
+      },
     );
-    minify_test(
-      ".foo { clip-path: inset(100px 50px round 5px 5px 5px 5px); }",
-      ".foo{clip-path:inset(100px 50px round 5px)}",
+
+    prefix_test(
+      ".foo { mask-image: linear-gradient(red, green) }",
+      indoc! { r#"
+        .foo {
+          -webkit-mask-image: linear-gradient(red, green);
+          mask-image: linear-gradient(red, green);
+        }
+      "#},
+      Browsers {
+        chrome: Some(95 << 16),
+        ..Browsers::default()
+      },
     );
-    minify_test(".foo { clip-path: circle(50px); }", ".foo{clip-path:circle(50px)}");
-    minify_test(
-      ".foo { clip-path: circle(50px at center center); }",
-      ".foo{clip-path:circle(50px)}",
+
+    prefix_test(
+      ".foo { -webkit-mask-image: url(x.svg); mask-image: url(x.svg); }",
+      indoc! { r#"
+        .foo {
+          -webkit-mask-image: url("x.svg");
+          mask-image: url("x.svg");
+        }
+      "#},
+      Browsers {
+        chrome: Some(95 << 16),
+        ..Browsers::default()
+      },
     );
-    minify_test(
-      ".foo { clip-path: circle(50px at 50% 50%); }",
-      ".foo{clip-path:circle(50px)}",
+
+    prefix_test(
+      ".foo { mask: linear-gradient(lch(56.208% 136.76 46.312), lch(51% 135.366 301.364)) 40px 20px }",
+      indoc! { r#"
+        .foo {
+          -webkit-mask: -webkit-gradient(linear, 0 0, 0 100%, from(#ff0f0e), to(#7773ff)) 40px 20px;
+          -webkit-mask: -webkit-linear-gradient(#ff0f0e, #7773ff) 40px 20px;
+          -webkit-mask: linear-gradient(#ff0f0e, #7773ff) 40px 20px;
+          mask: linear-gradient(#ff0f0e, #7773ff) 40px 20px;
+          -webkit-mask: linear-gradient(lch(56.208% 136.76 46.312), lch(51% 135.366 301.364)) 40px 20px;
+          mask: linear-gradient(lch(56.208% 136.76 46.312), lch(51% 135.366 301.364)) 40px 20px;
+        }
+      "#},
+      Browsers {
+        chrome: Some(8 << 16),
+        ..Browsers::default()
+      },
     );
-    minify_test(
-      ".foo { clip-path: circle(50px at 0 100px); }",
-      ".foo{clip-path:circle(50px at 0 100px)}",
+
+    prefix_test(
+      ".foo { mask: -webkit-linear-gradient(lch(56.208% 136.76 46.312), lch(51% 135.366 301.364)) 40px 20px }",
+      indoc! { r#"
+        .foo {
+          -webkit-mask: -webkit-gradient(linear, 0 0, 0 100%, from(#ff0f0e), to(#7773ff)) 40px 20px;
+          -webkit-mask: -webkit-linear-gradient(#ff0f0e, #7773ff) 40px 20px;
+        }
+      "#},
+      Browsers {
+        chrome: Some(8 << 16),
+        ..Browsers::default()
+      },
     );
-    minify_test(
-      ".foo { clip-path: circle(closest-side at 0 100px); }",
-      ".foo{clip-path:circle(at 0 100px)}",
+
+    prefix_test(
+      ".foo { mask: linear-gradient(lch(56.208% 136.76 46.312), lch(51% 135.366 301.364)) 40px var(--foo) }",
+      indoc! { r#"
+        .foo {
+          -webkit-mask: linear-gradient(#ff0f0e, #7773ff) 40px var(--foo);
+          mask: linear-gradient(#ff0f0e, #7773ff) 40px var(--foo);
+        }
+
+        @supports (color: lab(0% 0 0)) {
+          .foo {
+            -webkit-mask: linear-gradient(lab(56.208% 94.4644 98.8928), lab(51% 70.4544 -115.586)) 40px var(--foo);
+            mask: linear-gradient(lab(56.208% 94.4644 98.8928), lab(51% 70.4544 -115.586)) 40px var(--foo);
+          }
+        }
+      "#},
+      Browsers {
+        chrome: Some(90 << 16),
+        ..Browsers::default()
+      },
     );
-    minify_test(
-      ".foo { clip-path: circle(farthest-side at 0 100px); }",
-      ".foo{clip-path:circle(farthest-side at 0 100px)}",
+
+    prefix_test(
+      ".foo { mask: url(masks.svg#star) luminance }",
+      indoc! { r#"
+        .foo {
+          -webkit-mask: url("masks.svg#star");
+          -webkit-mask-source-type: luminance;
+          mask: url("masks.svg#star") luminance;
+        }
+    "#},
+      Browsers {
+        chrome: Some(90 << 16),
+        ..Browsers::default()
+      },
     );
-    minify_test(
-      ".foo { clip-path: circle(closest-side at 50% 50%); }",
-      ".foo{clip-path:circle()}",
+
+    prefix_test(
+      ".foo { mask-image: url(masks.svg#star) }",
+      indoc! { r#"
+        .foo {
+          -webkit-mask-image: url("masks.svg#star");
+          mask-image: url("masks.svg#star");
+        }
+    "#},
+      Browsers {
+        chrome: Some(90 << 16),
+        ..Browsers::default()
+      },
     );
-    minify_test(
-      ".foo { clip-path: ellipse(50px 60px at 0 10% 20%); }",
-      ".foo{clip-path:ellipse(50px 60px at 0 10% 20%)}",
+
+    prefix_test(
+      r#"
+        .foo {
+          mask-image: url(masks.svg#star);
+          mask-position: 25% 75%;
+          mask-size: cover;
+          mask-repeat: no-repeat;
+          mask-clip: padding-box;
+          mask-origin: content-box;
+          mask-composite: subtract;
+          mask-mode: luminance;
+        }
+      "#,
+      indoc! { r#"
+        .foo {
+          -webkit-mask: url("masks.svg#star") 25% 75% / cover no-repeat content-box padding-box;
+          -webkit-mask-composite: source-out;
+          -webkit-mask-source-type: luminance;
+          mask: url("masks.svg#star") 25% 75% / cover no-repeat content-box padding-box subtract luminance;
+        }
+    "#},
+      Browsers {
+        chrome: Some(90 << 16),
+        ..Browsers::default()
+      },
     );
-    minify_test(
-      ".foo { clip-path: ellipse(50px 60px at center center); }",
-      ".foo{clip-path:ellipse(50px 60px)}",
+
+    prefix_test(
+      r#"
+        .foo {
+          mask-image: linear-gradient(lch(56.208% 136.76 46.312), lch(51% 135.366 301.364));
+          mask-position: 25% 75%;
+          mask-size: cover;
+          mask-repeat: no-repeat;
+          mask-clip: padding-box;
+          mask-origin: content-box;
+          mask-composite: subtract;
+          mask-mode: luminance;
+        }
+      "#,
+      indoc! { r#"
+        .foo {
+          -webkit-mask: linear-gradient(#ff0f0e, #7773ff) 25% 75% / cover no-repeat content-box padding-box;
+          -webkit-mask-composite: source-out;
+          -webkit-mask-source-type: luminance;
+          mask: linear-gradient(#ff0f0e, #7773ff) 25% 75% / cover no-repeat content-box padding-box subtract luminance;
+          -webkit-mask: linear-gradient(lch(56.208% 136.76 46.312), lch(51% 135.366 301.364)) 25% 75% / cover no-repeat content-box padding-box;
+          -webkit-mask-composite: source-out;
+          -webkit-mask-source-type: luminance;
+          mask: linear-gradient(lch(56.208% 136.76 46.312), lch(51% 135.366 301.364)) 25% 75% / cover no-repeat content-box padding-box subtract luminance;
+        }
+    "#},
+      Browsers {
+        chrome: Some(90 << 16),
+        ..Browsers::default()
+      },
     );
-    minify_test(
-      ".foo { clip-path: ellipse(closest-side closest-side at 50% 50%); }",
-      ".foo{clip-path:ellipse()}",
+
+    test(
+      r#"
+        .foo {
+          mask: none center / 100% no-repeat;
+          mask-image: var(--svg);
+        }
+      "#,
+      indoc! { r#"
+        .foo {
+          mask: none center / 100% no-repeat;
+          mask-image: var(--svg);
+        }
+      "#},
     );
-    minify_test(
-      ".foo { clip-path: ellipse(closest-side closest-side at 10% 20%); }",
-      ".foo{clip-path:ellipse(at 10% 20%)}",
+
+    prefix_test(
+      r#"
+        .foo {
+          mask-composite: subtract;
+        }
+      "#,
+      indoc! { r#"
+        .foo {
+          -webkit-mask-composite: source-out;
+          mask-composite: subtract;
+        }
+    "#},
+      Browsers {
+        chrome: Some(90 << 16),
+        ..Browsers::default()
+      },
     );
-    minify_test(
-      ".foo { clip-path: ellipse(farthest-side closest-side at 10% 20%); }",
-      ".foo{clip-path:ellipse(farthest-side closest-side at 10% 20%)}",
+
+    prefix_test(
+      r#"
+        .foo {
+          mask-mode: luminance;
+        }
+      "#,
+      indoc! { r#"
+        .foo {
+          -webkit-mask-source-type: luminance;
+          mask-mode: luminance;
+        }
+    "#},
+      Browsers {
+        chrome: Some(90 << 16),
+        ..Browsers::default()
+      },
     );
-    minify_test(
-      ".foo { clip-path: polygon(50% 0%, 100% 50%, 50% 100%, 0% 50%); }",
-      ".foo{clip-path:polygon(50% 0%,100% 50%,50% 100%,0% 50%)}",
+
+    prefix_test(
+      r#"
+        .foo {
+          mask-border: url('border-mask.png') 25 / 35px / 12px space luminance;
+        }
+      "#,
+      indoc! { r#"
+        .foo {
+          -webkit-mask-box-image: url("border-mask.png") 25 / 35px / 12px space;
+          mask-border: url("border-mask.png") 25 / 35px / 12px space luminance;
+        }
+    "#},
+      Browsers {
+        chrome: Some(90 << 16),
+        ..Browsers::default()
+      },
     );
-    minify_test(
-      ".foo { clip-path: polygon(nonzero, 50% 0%, 100% 50%, 50% 100%, 0% 50%); }",
-      ".foo{clip-path:polygon(50% 0%,100% 50%,50% 100%,0% 50%)}",
+
+    prefix_test(
+      r#"
+        .foo {
+          mask-border: linear-gradient(lch(56.208% 136.76 46.312), lch(51% 135.366 301.364)) 25 / 35px / 12px space luminance;
+        }
+      "#,
+      indoc! { r#"
+        .foo {
+          -webkit-mask-box-image: linear-gradient(#ff0f0e, #7773ff) 25 / 35px / 12px space;
+          mask-border: linear-gradient(#ff0f0e, #7773ff) 25 / 35px / 12px space luminance;
+          -webkit-mask-box-image: linear-gradient(lch(56.208% 136.76 46.312), lch(51% 135.366 301.364)) 25 / 35px / 12px space;
+          mask-border: linear-gradient(lch(56.208% 136.76 46.312), lch(51% 135.366 301.364)) 25 / 35px / 12px space luminance;
+        }
+    "#},
+      Browsers {
+        chrome: Some(90 << 16),
+        ..Browsers::default()
+      },
     );
-    minify_test(
-      ".foo { clip-path: polygon(evenodd, 50% 0%, 100% 50%, 50% 100%, 0% 50%); }",
-      ".foo{clip-path:polygon(evenodd,50% 0%,100% 50%,50% 100%,0% 50%)}",
+
+    prefix_test(
+      r#"
+        .foo {
+          mask-border-source: linear-gradient(lch(56.208% 136.76 46.312), lch(51% 135.366 301.364));
+        }
+      "#,
+      indoc! { r#"
+        .foo {
+          -webkit-mask-box-image-source: linear-gradient(#ff0f0e, #7773ff);
+          mask-border-source: linear-gradient(#ff0f0e, #7773ff);
+          -webkit-mask-box-image-source: linear-gradient(lch(56.208% 136.76 46.312), lch(51% 135.366 301.364));
+          mask-border-source: linear-gradient(lch(56.208% 136.76 46.312), lch(51% 135.366 301.364));
+        }
+    "#},
+      Browsers {
+        chrome: Some(90 << 16),
+        ..Browsers::default()
+      },
     );
-    minify_test(
-      ".foo { clip-path: padding-box circle(50px at 0 100px); }",
-      ".foo{clip-path:circle(50px at 0 100px) padding-box}",
+
+    prefix_test(
+      r#"
+        .foo {
+          mask-border-source: url(foo.png);
+          mask-border-slice: 10 40 10 40;
+          mask-border-width: 10px;
+          mask-border-outset: 0;
+          mask-border-repeat: round round;
+          mask-border-mode: luminance;
+        }
+      "#,
+      indoc! { r#"
+        .foo {
+          -webkit-mask-box-image: url("foo.png") 10 40 / 10px round;
+          mask-border: url("foo.png") 10 40 / 10px round luminance;
+        }
+    "#},
+      Browsers {
+        chrome: Some(90 << 16),
+        ..Browsers::default()
+      },
     );
-    minify_test(
-      ".foo { clip-path: circle(50px at 0 100px) padding-box; }",
-      ".foo{clip-path:circle(50px at 0 100px) padding-box}",
+
+    prefix_test(
+      r#"
+        .foo {
+          -webkit-mask-box-image-source: url(foo.png);
+          -webkit-mask-box-image-slice: 10 40 10 40;
+          -webkit-mask-box-image-width: 10px;
+          -webkit-mask-box-image-outset: 0;
+          -webkit-mask-box-image-repeat: round round;
+        }
+      "#,
+      indoc! { r#"
+        .foo {
+          -webkit-mask-box-image: url("foo.png") 10 40 / 10px round;
+        }
+    "#},
+      Browsers {
+        chrome: Some(90 << 16),
+        ..Browsers::default()
+      },
     );
-    minify_test(
-      ".foo { clip-path: circle(50px at 0 100px) border-box; }",
-      ".foo{clip-path:circle(50px at 0 100px)}",
+
+    prefix_test(
+      r#"
+        .foo {
+          mask-border-slice: 10 40 10 40;
+        }
+      "#,
+      indoc! { r#"
+        .foo {
+          -webkit-mask-box-image-slice: 10 40;
+          mask-border-slice: 10 40;
+        }
+    "#},
+      Browsers {
+        chrome: Some(90 << 16),
+        ..Browsers::default()
+      },
     );
 
     prefix_test(
-      ".foo { clip-path: circle(50px); }",
+      r#"
+        .foo {
+          mask-border-slice: var(--foo);
+        }
+      "#,
       indoc! { r#"
         .foo {
-          -webkit-clip-path: circle(50px);
-          clip-path: circle(50px);
+          -webkit-mask-box-image-slice: var(--foo);
+          mask-border-slice: var(--foo);
+        }
+    "#},
+      Browsers {
+        chrome: Some(90 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      r#"
+        .foo {
+          mask-border: linear-gradient(lch(56.208% 136.76 46.312), lch(51% 135.366 301.364)) var(--foo);
+        }
+      "#,
+      indoc! { r#"
+        .foo {
+          -webkit-mask-box-image: linear-gradient(#ff0f0e, #7773ff) var(--foo);
+          mask-border: linear-gradient(#ff0f0e, #7773ff) var(--foo);
+        }
+
+        @supports (color: lab(0% 0 0)) {
+          .foo {
+            -webkit-mask-box-image: linear-gradient(lab(56.208% 94.4644 98.8928), lab(51% 70.4544 -115.586)) var(--foo);
+            mask-border: linear-gradient(lab(56.208% 94.4644 98.8928), lab(51% 70.4544 -115.586)) var(--foo);
+          }
         }
-      "#},
+    "#},
       Browsers {
-        chrome: Some(30 << 16),
+        chrome: Some(90 << 16),
         ..Browsers::default()
       },
     );
 
     prefix_test(
-      ".foo { clip-path: circle(50px); }",
+      r#"
+        .foo {
+          transition: mask 200ms;
+        }
+      "#,
       indoc! { r#"
         .foo {
-          clip-path: circle(50px);
+          transition: -webkit-mask .2s, mask .2s;
         }
-      "#},
+    "#},
       Browsers {
-        chrome: Some(80 << 16),
+        chrome: Some(90 << 16),
         ..Browsers::default()
       },
     );
 
     prefix_test(
-      ".foo { clip-path: circle(50px); }",
+      r#"
+        .foo {
+          transition: mask-border 200ms;
+        }
+      "#,
       indoc! { r#"
         .foo {
-          -webkit-clip-path: circle(50px);
-          clip-path: circle(50px);
+          transition: -webkit-mask-box-image .2s, mask-border .2s;
         }
-      "#},
+    "#},
       Browsers {
-        safari: Some(8 << 16),
+        chrome: Some(90 << 16),
         ..Browsers::default()
       },
     );
 
     prefix_test(
-      ".foo { clip-path: circle(50px); }",
+      r#"
+        .foo {
+          transition-property: mask;
+        }
+      "#,
       indoc! { r#"
         .foo {
-          clip-path: circle(50px);
+          transition-property: -webkit-mask, mask;
         }
-      "#},
+    "#},
       Browsers {
-        safari: Some(14 << 16),
+        chrome: Some(90 << 16),
         ..Browsers::default()
       },
     );
 
     prefix_test(
-      ".foo { fill: lch(50.998% 135.363 338) }",
+      r#"
+        .foo {
+          transition-property: mask-border;
+        }
+      "#,
       indoc! { r#"
         .foo {
-          fill: #ee00be;
-          fill: color(display-p3 .972962 -.362078 .804206);
-          fill: lch(50.998% 135.363 338);
+          transition-property: -webkit-mask-box-image, mask-border;
         }
-      "#},
+    "#},
       Browsers {
         chrome: Some(90 << 16),
-        safari: Some(14 << 16),
         ..Browsers::default()
       },
     );
 
     prefix_test(
-      ".foo { stroke: lch(50.998% 135.363 338) }",
+      r#"
+        .foo {
+          transition-property: mask-composite, mask-mode;
+        }
+      "#,
       indoc! { r#"
         .foo {
-          stroke: #ee00be;
-          stroke: color(display-p3 .972962 -.362078 .804206);
-          stroke: lch(50.998% 135.363 338);
+          transition-property: -webkit-mask-composite, mask-composite, -webkit-mask-source-type, mask-mode;
         }
-      "#},
+    "#},
       Browsers {
         chrome: Some(90 << 16),
-        safari: Some(14 << 16),
         ..Browsers::default()
       },
     );
+  }
+
+  #[test]
+  fn test_shapes() {
+    minify_test(".foo { shape-outside: none }", ".foo{shape-outside:none}");
+    minify_test(".foo { shape-outside: margin-box }", ".foo{shape-outside:margin-box}");
+    minify_test(".foo { shape-outside: content-box }", ".foo{shape-outside:content-box}");
+    minify_test(
+      ".foo { shape-outside: circle(50px) }",
+      ".foo{shape-outside:circle(50px)}",
+    );
+    minify_test(
+      ".foo { shape-outside: circle(50px at center center) margin-box }",
+      ".foo{shape-outside:circle(50px)}",
+    );
+    minify_test(
+      ".foo { shape-outside: padding-box ellipse(50px 60px at 10% 20%) }",
+      ".foo{shape-outside:ellipse(50px 60px at 10% 20%) padding-box}",
+    );
+    minify_test(
+      ".foo { shape-outside: polygon(50% 0%, 100% 50%, 50% 100%, 0% 50%) border-box }",
+      ".foo{shape-outside:polygon(50% 0%,100% 50%,50% 100%,0% 50%) border-box}",
+    );
+    minify_test(
+      ".foo { shape-outside: inset(10px round 5px) }",
+      ".foo{shape-outside:inset(10px round 5px)}",
+    );
+    minify_test(
+      ".foo { shape-outside: url(foo.png) }",
+      ".foo{shape-outside:url(foo.png)}",
+    );
+    minify_test(
+      ".foo { shape-outside: linear-gradient(red, blue) }",
+      ".foo{shape-outside:linear-gradient(red,#00f)}",
+    );
+    minify_test(".foo { shape-outside: fill-box }", ".foo{shape-outside:fill-box}"); // invalid
+    minify_test(
+      ".foo { shape-outside: content-box circle(50px) }",
+      ".foo{shape-outside:circle(50px) content-box}",
+    );
+    minify_test(
+      ".foo { shape-outside: circle(50px) margin-box content-box }",
+      ".foo{shape-outside:circle(50px)margin-box content-box}",
+    ); // invalid
 
+    minify_test(".foo { shape-margin: 10px }", ".foo{shape-margin:10px}");
+    minify_test(".foo { shape-margin: 5% }", ".foo{shape-margin:5%}");
+    minify_test(".foo { shape-margin: auto }", ".foo{shape-margin:auto}"); // invalid
+    minify_test(".foo { shape-margin: -1px }", ".foo{shape-margin:-1px}"); // invalid
+    minify_test(
+      ".foo { shape-margin: calc(10px - 5%) }",
+      ".foo{shape-margin:calc(10px - 5%)}",
+    );
     prefix_test(
-      ".foo { fill: url(#foo) lch(50.998% 135.363 338) }",
-      indoc! { r##"
-        .foo {
-          fill: url("#foo") #ee00be;
-          fill: url("#foo") color(display-p3 .972962 -.362078 .804206);
-          fill: url("#foo") lch(50.998% 135.363 338);
-        }
-      "##},
+      ".foo { shape-margin: -1px }",
+      indoc! {r#"
+      .foo {
+        shape-margin: -1px;
+      }
+    "#},
       Browsers {
-        chrome: Some(90 << 16),
-        safari: Some(14 << 16),
+        safari: Some(8 << 16),
         ..Browsers::default()
       },
     );
 
-    prefix_test(
-      ".foo { fill: var(--url) lch(50.998% 135.363 338) }",
-      indoc! { r#"
-        .foo {
-          fill: var(--url) #ee00be;
-        }
+    minify_test(".foo { shape-image-threshold: 0.5 }", ".foo{shape-image-threshold:.5}");
+    minify_test(".foo { shape-image-threshold: 30% }", ".foo{shape-image-threshold:.3}");
+    minify_test(
+      ".foo { shape-image-threshold: none }",
+      ".foo{shape-image-threshold:none}",
+    ); // invalid
 
-        @supports (color: lab(0% 0 0)) {
-          .foo {
-            fill: var(--url) lab(50.998% 125.506 -50.7078);
-          }
-        }
-      "#},
+    prefix_test(
+      r#"
+      .foo {
+        shape-outside: circle(50px);
+        shape-margin: 10px;
+        shape-image-threshold: .5;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        -webkit-shape-outside: circle(50px);
+        shape-outside: circle(50px);
+        -webkit-shape-margin: 10px;
+        shape-margin: 10px;
+        -webkit-shape-image-threshold: .5;
+        shape-image-threshold: .5;
+      }
+    "#},
       Browsers {
-        chrome: Some(90 << 16),
+        safari: Some(8 << 16),
         ..Browsers::default()
       },
     );
 
     prefix_test(
-      ".foo { mask-image: linear-gradient(lch(56.208% 136.76 46.312), lch(51% 135.366 301.364)) }",
-      indoc! { r#"
-        .foo {
-          -webkit-mask-image: -webkit-gradient(linear, 0 0, 0 100%, from(#ff0f0e), to(#7773ff));
-          -webkit-mask-image: -webkit-linear-gradient(#ff0f0e, #7773ff);
-          -webkit-mask-image: linear-gradient(#ff0f0e, #7773ff);
-          mask-image: linear-gradient(#ff0f0e, #7773ff);
-          -webkit-mask-image: linear-gradient(lch(56.208% 136.76 46.312), lch(51% 135.366 301.364));
-          mask-image: linear-gradient(lch(56.208% 136.76 46.312), lch(51% 135.366 301.364));
-        }
-      "#},
+      r#"
+      .foo {
+        -webkit-shape-outside: circle(50px);
+        shape-outside: circle(50px);
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        shape-outside: circle(50px);
+      }
+    "#},
       Browsers {
-        chrome: Some(8 << 16),
+        safari: Some(14 << 16),
         ..Browsers::default()
       },
     );
 
     prefix_test(
-      ".foo { mask-image: linear-gradient(lch(56.208% 136.76 46.312), lch(51% 135.366 301.364)) }",
-      indoc! { r#"
-        .foo {
-          -webkit-mask-image: linear-gradient(#ff0f0e, #7773ff);
-          mask-image: linear-gradient(#ff0f0e, #7773ff);
-          -webkit-mask-image: linear-gradient(lch(56.208% 136.76 46.312), lch(51% 135.366 301.364));
-          mask-image: linear-gradient(lch(56.208% 136.76 46.312), lch(51% 135.366 301.364));
-        }
-      "#},
+      ".foo { shape-outside: linear-gradient(lch(56.208% 136.76 46.312), transparent) }",
+      indoc! {r#"
+      .foo {
+        shape-outside: linear-gradient(#ff0f0e, #0000);
+        shape-outside: linear-gradient(lch(56.208% 136.76 46.312), #0000);
+      }
+    "#},
       Browsers {
-        chrome: Some(95 << 16),
+        chrome: Some(90 << 16),
+        ..Browsers::default()
+      },
+    );
+    prefix_test(
+      ".foo { shape-outside: linear-gradient(oklab(59.686% 0.1009 0.1192), transparent) }",
+      indoc! {r#"
+      .foo {
+        shape-outside: linear-gradient(lab(52.2319% 40.1449 59.9171), #0000);
+      }
+    "#},
+      Browsers {
+        safari: Some(15 << 16),
         ..Browsers::default()
       },
     );
+  }
+
+  #[test]
+  fn test_filter() {
+    minify_test(
+      ".foo { filter: url('filters.svg#filter-id'); }",
+      ".foo{filter:url(filters.svg#filter-id)}",
+    );
+    minify_test(".foo { filter: blur(5px); }", ".foo{filter:blur(5px)}");
+    minify_test(".foo { filter: blur(0px); }", ".foo{filter:blur()}");
+    minify_test(".foo { filter: brightness(10%); }", ".foo{filter:brightness(10%)}");
+    minify_test(".foo { filter: brightness(100%); }", ".foo{filter:brightness()}");
+    minify_test(
+      ".foo { filter: drop-shadow(16px 16px 20px yellow); }",
+      ".foo{filter:drop-shadow(16px 16px 20px #ff0)}",
+    );
+    minify_test(
+      ".foo { filter: contrast(175%) brightness(3%); }",
+      ".foo{filter:contrast(175%)brightness(3%)}",
+    );
+    minify_test(".foo { filter: hue-rotate(0) }", ".foo{filter:hue-rotate()}");
+    minify_test(".foo { filter: hue-rotate(-90deg) }", ".foo{filter:hue-rotate(-90deg)}");
+    minify_test(".foo { filter: grayscale(1) sepia(100%) }", ".foo{filter:grayscale()sepia()}");
+    minify_test(".foo { filter: opacity(0.5) saturate(200%) }", ".foo{filter:opacity(.5)saturate(200%)}");
+    minify_test(".foo { filter: invert() contrast() }", ".foo{filter:invert()contrast()}");
+    minify_test(".foo { filter: blur(-5px) }", ".foo{filter:blur(-5px)}");
+    minify_test(".foo { filter: brightness(-50%) }", ".foo{filter:brightness(-50%)}");
+    minify_test(".foo { filter: saturate(-1) }", ".foo{filter:saturate(-1)}");
+    minify_test(
+      ".foo { filter: drop-shadow(1px 1px -2px yellow) }",
+      ".foo{filter:drop-shadow(1px 1px -2px yellow)}",
+    );
+    minify_test(
+      ".foo { backdrop-filter: blur(0) brightness(1) hue-rotate(0deg) }",
+      ".foo{backdrop-filter:blur()brightness()hue-rotate()}",
+    );
 
     prefix_test(
-      ".foo { mask-image: linear-gradient(red, green) }",
+      ".foo { filter: blur(5px) }",
       indoc! { r#"
         .foo {
-          -webkit-mask-image: linear-gradient(red, green);
-          mask-image: linear-gradient(red, green);
+          -webkit-filter: blur(5px);
+          filter: blur(5px);
         }
       "#},
       Browsers {
-        chrome: Some(95 << 16),
+        chrome: Some(20 << 16),
         ..Browsers::default()
       },
     );
 
     prefix_test(
-      ".foo { -webkit-mask-image: url(x.svg); mask-image: url(x.svg); }",
+      ".foo { filter: blur(5px) }",
       indoc! { r#"
         .foo {
-          -webkit-mask-image: url("x.svg");
-          mask-image: url("x.svg");
+          filter: blur(5px);
         }
       "#},
       Browsers {
-        chrome: Some(95 << 16),
+        chrome: Some(80 << 16),
         ..Browsers::default()
       },
     );
 
     prefix_test(
-      ".foo { mask: linear-gradient(lch(56.208% 136.76 46.312), lch(51% 135.366 301.364)) 40px 20px }",
+      ".foo { backdrop-filter: blur(5px) }",
       indoc! { r#"
         .foo {
-          -webkit-mask: -webkit-gradient(linear, 0 0, 0 100%, from(#ff0f0e), to(#7773ff)) 40px 20px;
-          -webkit-mask: -webkit-linear-gradient(#ff0f0e, #7773ff) 40px 20px;
-          -webkit-mask: linear-gradient(#ff0f0e, #7773ff) 40px 20px;
-          mask: linear-gradient(#ff0f0e, #7773ff) 40px 20px;
-          -webkit-mask: linear-gradient(lch(56.208% 136.76 46.312), lch(51% 135.366 301.364)) 40px 20px;
-          mask: linear-gradient(lch(56.208% 136.76 46.312), lch(51% 135.366 301.364)) 40px 20px;
+          backdrop-filter: blur(5px);
         }
       "#},
       Browsers {
-        chrome: Some(8 << 16),
+        chrome: Some(80 << 16),
         ..Browsers::default()
       },
     );
 
     prefix_test(
-      ".foo { mask: -webkit-linear-gradient(lch(56.208% 136.76 46.312), lch(51% 135.366 301.364)) 40px 20px }",
+      ".foo { backdrop-filter: blur(5px) }",
       indoc! { r#"
         .foo {
-          -webkit-mask: -webkit-gradient(linear, 0 0, 0 100%, from(#ff0f0e), to(#7773ff)) 40px 20px;
-          -webkit-mask: -webkit-linear-gradient(#ff0f0e, #7773ff) 40px 20px;
+          -webkit-backdrop-filter: blur(5px);
+          backdrop-filter: blur(5px);
         }
       "#},
       Browsers {
-        chrome: Some(8 << 16),
+        safari: Some(15 << 16),
         ..Browsers::default()
       },
     );
-
     prefix_test(
-      ".foo { mask: linear-gradient(lch(56.208% 136.76 46.312), lch(51% 135.366 301.364)) 40px var(--foo) }",
-      indoc! { r#"
-        .foo {
-          -webkit-mask: linear-gradient(#ff0f0e, #7773ff) 40px var(--foo);
-          mask: linear-gradient(#ff0f0e, #7773ff) 40px var(--foo);
-        }
-
-        @supports (color: lab(0% 0 0)) {
-          .foo {
-            -webkit-mask: linear-gradient(lab(56.208% 94.4644 98.8928), lab(51% 70.4544 -115.586)) 40px var(--foo);
-            mask: linear-gradient(lab(56.208% 94.4644 98.8928), lab(51% 70.4544 -115.586)) 40px var(--foo);
-          }
-        }
+      r#"
+      .foo {
+        -webkit-backdrop-filter: blur(8px);
+        backdrop-filter: blur(8px);
+      }
+      "#,
+      indoc! {r#"
+      .foo {
+        -webkit-backdrop-filter: blur(8px);
+        backdrop-filter: blur(8px);
+      }
       "#},
       Browsers {
-        chrome: Some(90 << 16),
+        safari: Some(16 << 16),
         ..Browsers::default()
       },
     );
 
     prefix_test(
-      ".foo { mask: url(masks.svg#star) luminance }",
+      ".foo { filter: var(--foo) }",
       indoc! { r#"
         .foo {
-          -webkit-mask: url("masks.svg#star");
-          -webkit-mask-source-type: luminance;
-          mask: url("masks.svg#star") luminance;
+          -webkit-filter: var(--foo);
+          filter: var(--foo);
         }
-    "#},
+      "#},
       Browsers {
-        chrome: Some(90 << 16),
+        chrome: Some(20 << 16),
         ..Browsers::default()
       },
     );
 
     prefix_test(
-      ".foo { mask-image: url(masks.svg#star) }",
+      ".foo { filter: drop-shadow(16px 16px 20px lab(40% 56.6 39)) }",
       indoc! { r#"
         .foo {
-          -webkit-mask-image: url("masks.svg#star");
-          mask-image: url("masks.svg#star");
+          -webkit-filter: drop-shadow(16px 16px 20px #b32323);
+          filter: drop-shadow(16px 16px 20px #b32323);
+          filter: drop-shadow(16px 16px 20px lab(40% 56.6 39));
         }
-    "#},
+      "#},
       Browsers {
-        chrome: Some(90 << 16),
+        chrome: Some(20 << 16),
         ..Browsers::default()
       },
     );
 
     prefix_test(
-      r#"
-        .foo {
-          mask-image: url(masks.svg#star);
-          mask-position: 25% 75%;
-          mask-size: cover;
-          mask-repeat: no-repeat;
-          mask-clip: padding-box;
-          mask-origin: content-box;
-          mask-composite: subtract;
-          mask-mode: luminance;
-        }
-      "#,
+      ".foo { filter: contrast(175%) drop-shadow(16px 16px 20px lab(40% 56.6 39)) }",
       indoc! { r#"
         .foo {
-          -webkit-mask: url("masks.svg#star") 25% 75% / cover no-repeat content-box padding-box;
-          -webkit-mask-composite: source-out;
-          -webkit-mask-source-type: luminance;
-          mask: url("masks.svg#star") 25% 75% / cover no-repeat content-box padding-box subtract luminance;
+          filter: contrast(175%) drop-shadow(16px 16px 20px #b32323);
+          filter: contrast(175%) drop-shadow(16px 16px 20px lab(40% 56.6 39));
         }
-    "#},
+      "#},
       Browsers {
-        chrome: Some(90 << 16),
+        chrome: Some(4 << 16),
         ..Browsers::default()
       },
     );
 
     prefix_test(
-      r#"
-        .foo {
-          mask-image: linear-gradient(lch(56.208% 136.76 46.312), lch(51% 135.366 301.364));
-          mask-position: 25% 75%;
-          mask-size: cover;
-          mask-repeat: no-repeat;
-          mask-clip: padding-box;
-          mask-origin: content-box;
-          mask-composite: subtract;
-          mask-mode: luminance;
-        }
-      "#,
+      ".foo { filter: drop-shadow(16px 16px 20px lab(40% 56.6 39)) drop-shadow(16px 16px 20px yellow) }",
       indoc! { r#"
         .foo {
-          -webkit-mask: linear-gradient(#ff0f0e, #7773ff) 25% 75% / cover no-repeat content-box padding-box;
-          -webkit-mask-composite: source-out;
-          -webkit-mask-source-type: luminance;
-          mask: linear-gradient(#ff0f0e, #7773ff) 25% 75% / cover no-repeat content-box padding-box subtract luminance;
-          -webkit-mask: linear-gradient(lch(56.208% 136.76 46.312), lch(51% 135.366 301.364)) 25% 75% / cover no-repeat content-box padding-box;
-          -webkit-mask-composite: source-out;
-          -webkit-mask-source-type: luminance;
-          mask: linear-gradient(lch(56.208% 136.76 46.312), lch(51% 135.366 301.364)) 25% 75% / cover no-repeat content-box padding-box subtract luminance;
+          filter: drop-shadow(16px 16px 20px #b32323) drop-shadow(16px 16px 20px #ff0);
+          filter: drop-shadow(16px 16px 20px lab(40% 56.6 39)) drop-shadow(16px 16px 20px #ff0);
         }
-    "#},
+      "#},
       Browsers {
-        chrome: Some(90 << 16),
+        chrome: Some(4 << 16),
         ..Browsers::default()
       },
     );
 
-    test(
-      r#"
-        .foo {
-          mask: none center / 100% no-repeat;
-          mask-image: var(--svg);
-        }
-      "#,
+    prefix_test(
+      ".foo { filter: var(--foo) drop-shadow(16px 16px 20px lab(40% 56.6 39)) }",
       indoc! { r#"
         .foo {
-          mask: none center / 100% no-repeat;
-          mask-image: var(--svg);
+          filter: var(--foo) drop-shadow(16px 16px 20px #b32323);
         }
-      "#},
-    );
 
-    prefix_test(
-      r#"
-        .foo {
-          mask-composite: subtract;
-        }
-      "#,
-      indoc! { r#"
-        .foo {
-          -webkit-mask-composite: source-out;
-          mask-composite: subtract;
+        @supports (color: lab(0% 0 0)) {
+          .foo {
+            filter: var(--foo) drop-shadow(16px 16px 20px lab(40% 56.6 39));
+          }
         }
-    "#},
+      "#},
       Browsers {
-        chrome: Some(90 << 16),
+        chrome: Some(4 << 16),
         ..Browsers::default()
       },
     );
+  }
 
-    prefix_test(
+  #[test]
+  fn test_blend_modes() {
+    minify_test(".foo { mix-blend-mode: normal }", ".foo{mix-blend-mode:normal}");
+    minify_test(".foo { mix-blend-mode: multiply }", ".foo{mix-blend-mode:multiply}");
+    minify_test(
+      ".foo { mix-blend-mode: COLOR-DODGE }",
+      ".foo{mix-blend-mode:color-dodge}",
+    );
+    minify_test(".foo { mix-blend-mode: luminosity }", ".foo{mix-blend-mode:luminosity}");
+    minify_test(
+      ".foo { mix-blend-mode: PLUS-LIGHTER }",
+      ".foo{mix-blend-mode:plus-lighter}",
+    );
+    minify_test(".foo { mix-blend-mode: multipy }", ".foo{mix-blend-mode:multipy}"); // invalid
+    minify_test(".foo { isolation: auto }", ".foo{isolation:auto}");
+    minify_test(".foo { isolation: isolate }", ".foo{isolation:isolate}");
+    minify_test(".foo { isolation: none }", ".foo{isolation:none}"); // invalid
+
+    minify_test(
+      ".foo { background-blend-mode: screen }",
+      ".foo{background-blend-mode:screen}",
+    );
+    minify_test(
+      ".foo { background-blend-mode: multiply, hard-light, normal }",
+      ".foo{background-blend-mode:multiply,hard-light,normal}",
+    );
+    minify_test(
+      ".foo { background-blend-mode: multiply hard-light }",
+      ".foo{background-blend-mode:multiply hard-light}",
+    ); // invalid
+    minify_test(
+      ".foo { background-blend-mode: multiply, }",
+      ".foo{background-blend-mode:multiply,}",
+    ); // invalid
+
+    test(
       r#"
-        .foo {
-          mask-mode: luminance;
-        }
-      "#,
-      indoc! { r#"
-        .foo {
-          -webkit-mask-source-type: luminance;
-          mask-mode: luminance;
-        }
+      .foo {
+        background-blend-mode: multiply, screen;
+        background: url(a.png), url(b.png) red;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        background: url("a.png"), red url("b.png");
+        background-blend-mode: multiply, screen;
+      }
     "#},
-      Browsers {
-        chrome: Some(90 << 16),
-        ..Browsers::default()
-      },
     );
 
-    prefix_test(
+    test(
       r#"
-        .foo {
-          mask-border: url('border-mask.png') 25 / 35px / 12px space luminance;
-        }
-      "#,
-      indoc! { r#"
-        .foo {
-          -webkit-mask-box-image: url("border-mask.png") 25 / 35px / 12px space;
-          mask-border: url("border-mask.png") 25 / 35px / 12px space luminance;
-        }
+      .foo {
+        background: url(a.png);
+        background-blend-mode: darken;
+        background-repeat: no-repeat;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        background: url("a.png") no-repeat;
+        background-blend-mode: darken;
+      }
     "#},
-      Browsers {
-        chrome: Some(90 << 16),
-        ..Browsers::default()
-      },
     );
 
-    prefix_test(
+    test(
+      r#"
+      .foo {
+        background-image: url(a.png);
+        background-blend-mode: darken;
+        background-blend-mode: lighten;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        background-image: url("a.png");
+        background-blend-mode: lighten;
+      }
+    "#},
+    );
+  }
+
+  #[test]
+  fn test_viewport() {
+    minify_test(
+      r#"
+    @viewport {
+      width: 100vw;
+    }"#,
+      "@viewport{width:100vw}",
+    );
+    minify_test(
+      r#"
+    @-ms-viewport {
+      width: device-width;
+    }"#,
+      "@-ms-viewport{width:device-width}",
+    );
+  }
+
+  #[test]
+  fn test_at_scope() {
+    minify_test(
       r#"
+      @scope {
         .foo {
-          mask-border: linear-gradient(lch(56.208% 136.76 46.312), lch(51% 135.366 301.364)) 25 / 35px / 12px space luminance;
+          display: flex;
         }
+      }
       "#,
-      indoc! { r#"
-        .foo {
-          -webkit-mask-box-image: linear-gradient(#ff0f0e, #7773ff) 25 / 35px / 12px space;
-          mask-border: linear-gradient(#ff0f0e, #7773ff) 25 / 35px / 12px space luminance;
-          -webkit-mask-box-image: linear-gradient(lch(56.208% 136.76 46.312), lch(51% 135.366 301.364)) 25 / 35px / 12px space;
-          mask-border: linear-gradient(lch(56.208% 136.76 46.312), lch(51% 135.366 301.364)) 25 / 35px / 12px space luminance;
+      "@scope{.foo{display:flex}}",
+    );
+    minify_test(
+      r#"
+      @scope {
+        :scope {
+          display: flex;
+          color: lightblue;
         }
-    "#},
-      Browsers {
-        chrome: Some(90 << 16),
-        ..Browsers::default()
-      },
+      }"#,
+      "@scope{:scope{color:#add8e6;display:flex}}",
+    );
+    minify_test(
+      r#"
+      @scope (.light-scheme) {
+        a { color: yellow; }
+      }
+      "#,
+      "@scope(.light-scheme){a{color:#ff0}}",
+    );
+    minify_test(
+      r#"
+      @scope (.media-object) to (.content > *) {
+        a { color: yellow; }
+      }
+      "#,
+      "@scope(.media-object) to (.content>*){a{color:#ff0}}",
+    );
+    minify_test(
+      r#"
+      @scope to (.content > *) {
+        a { color: yellow; }
+      }
+      "#,
+      "@scope to (.content>*){a{color:#ff0}}",
+    );
+    minify_test(
+      r#"
+      @scope (#my-component) {
+        & { color: yellow; }
+      }
+      "#,
+      "@scope(#my-component){&{color:#ff0}}",
     );
-
-    prefix_test(
+    minify_test(
       r#"
-        .foo {
-          mask-border-source: linear-gradient(lch(56.208% 136.76 46.312), lch(51% 135.366 301.364));
+      @scope (.parent-scope) {
+        @scope (:scope > .child-scope) to (:scope .limit) {
+          .content { color: yellow; }
         }
+      }
       "#,
-      indoc! { r#"
-        .foo {
-          -webkit-mask-box-image-source: linear-gradient(#ff0f0e, #7773ff);
-          mask-border-source: linear-gradient(#ff0f0e, #7773ff);
-          -webkit-mask-box-image-source: linear-gradient(lch(56.208% 136.76 46.312), lch(51% 135.366 301.364));
-          mask-border-source: linear-gradient(lch(56.208% 136.76 46.312), lch(51% 135.366 301.364));
-        }
-    "#},
-      Browsers {
-        chrome: Some(90 << 16),
-        ..Browsers::default()
-      },
+      "@scope(.parent-scope){@scope(:scope>.child-scope) to (:scope .limit){.content{color:#ff0}}}",
     );
-
-    prefix_test(
+    minify_test(
       r#"
-        .foo {
-          mask-border-source: url(foo.png);
-          mask-border-slice: 10 40 10 40;
-          mask-border-width: 10px;
-          mask-border-outset: 0;
-          mask-border-repeat: round round;
-          mask-border-mode: luminance;
+      .foo {
+        @scope (.bar) {
+          color: yellow;
         }
+      }
       "#,
-      indoc! { r#"
-        .foo {
-          -webkit-mask-box-image: url("foo.png") 10 40 / 10px round;
-          mask-border: url("foo.png") 10 40 / 10px round luminance;
-        }
-    "#},
-      Browsers {
-        chrome: Some(90 << 16),
-        ..Browsers::default()
-      },
+      ".foo{@scope(.bar){&{color:#ff0}}}",
     );
-
-    prefix_test(
+    nesting_test(
       r#"
-        .foo {
-          -webkit-mask-box-image-source: url(foo.png);
-          -webkit-mask-box-image-slice: 10 40 10 40;
-          -webkit-mask-box-image-width: 10px;
-          -webkit-mask-box-image-outset: 0;
-          -webkit-mask-box-image-repeat: round round;
+      .foo {
+        @scope (.bar) {
+          color: yellow;
         }
+      }
       "#,
-      indoc! { r#"
-        .foo {
-          -webkit-mask-box-image: url("foo.png") 10 40 / 10px round;
+      indoc! {r#"
+        @scope (.bar) {
+          :scope {
+            color: #ff0;
+          }
         }
-    "#},
-      Browsers {
-        chrome: Some(90 << 16),
-        ..Browsers::default()
-      },
+      "#},
     );
-
-    prefix_test(
+    nesting_test(
       r#"
-        .foo {
-          mask-border-slice: 10 40 10 40;
+      .parent {
+        color: blue;
+
+        @scope (& > .scope) to (& .limit) {
+          & .content {
+            color: yellow;
+          }
         }
+      }
       "#,
-      indoc! { r#"
-        .foo {
-          -webkit-mask-box-image-slice: 10 40;
-          mask-border-slice: 10 40;
+      indoc! {r#"
+        .parent {
+          color: #00f;
         }
-    "#},
-      Browsers {
-        chrome: Some(90 << 16),
-        ..Browsers::default()
-      },
+
+        @scope (.parent > .scope) to (.parent > .scope .limit) {
+          :scope .content {
+            color: #ff0;
+          }
+        }
+      "#},
     );
+  }
 
-    prefix_test(
+  #[test]
+  fn test_custom_media() {
+    custom_media_test(
       r#"
-        .foo {
-          mask-border-slice: var(--foo);
+      @custom-media --modern (color), (hover);
+
+      @media (--modern) and (width > 1024px) {
+        .a {
+          color: green;
         }
+      }
       "#,
-      indoc! { r#"
-        .foo {
-          -webkit-mask-box-image-slice: var(--foo);
-          mask-border-slice: var(--foo);
+      indoc! {r#"
+      @media ((color) or (hover)) and (width > 1024px) {
+        .a {
+          color: green;
         }
-    "#},
-      Browsers {
-        chrome: Some(90 << 16),
-        ..Browsers::default()
-      },
+      }
+      "#},
     );
 
-    prefix_test(
+    custom_media_test(
       r#"
-        .foo {
-          mask-border: linear-gradient(lch(56.208% 136.76 46.312), lch(51% 135.366 301.364)) var(--foo);
+      @custom-media --color (color);
+
+      @media (--color) and (width > 1024px) {
+        .a {
+          color: green;
         }
+      }
       "#,
-      indoc! { r#"
-        .foo {
-          -webkit-mask-box-image: linear-gradient(#ff0f0e, #7773ff) var(--foo);
-          mask-border: linear-gradient(#ff0f0e, #7773ff) var(--foo);
-        }
-
-        @supports (color: lab(0% 0 0)) {
-          .foo {
-            -webkit-mask-box-image: linear-gradient(lab(56.208% 94.4644 98.8928), lab(51% 70.4544 -115.586)) var(--foo);
-            mask-border: linear-gradient(lab(56.208% 94.4644 98.8928), lab(51% 70.4544 -115.586)) var(--foo);
-          }
+      indoc! {r#"
+      @media (color) and (width > 1024px) {
+        .a {
+          color: green;
         }
-    "#},
-      Browsers {
-        chrome: Some(90 << 16),
-        ..Browsers::default()
-      },
+      }
+      "#},
     );
 
-    prefix_test(
+    custom_media_test(
       r#"
-        .foo {
-          transition: mask 200ms;
+      @custom-media --a (color);
+      @custom-media --b (--a);
+
+      @media (--b) and (width > 1024px) {
+        .a {
+          color: green;
         }
+      }
       "#,
-      indoc! { r#"
-        .foo {
-          transition: -webkit-mask .2s, mask .2s;
+      indoc! {r#"
+      @media (color) and (width > 1024px) {
+        .a {
+          color: green;
         }
-    "#},
-      Browsers {
-        chrome: Some(90 << 16),
-        ..Browsers::default()
-      },
+      }
+      "#},
     );
 
-    prefix_test(
+    custom_media_test(
       r#"
-        .foo {
-          transition: mask-border 200ms;
+      @custom-media --not-color not (color);
+
+      @media not (--not-color) {
+        .a {
+          color: green;
         }
+      }
       "#,
-      indoc! { r#"
-        .foo {
-          transition: -webkit-mask-box-image .2s, mask-border .2s;
+      indoc! {r#"
+      @media (color) {
+        .a {
+          color: green;
         }
-    "#},
-      Browsers {
-        chrome: Some(90 << 16),
-        ..Browsers::default()
-      },
+      }
+      "#},
     );
 
-    prefix_test(
+    custom_media_test(
       r#"
-        .foo {
-          transition-property: mask;
+      @custom-media --color-print print and (color);
+
+      @media (--color-print) {
+        .a {
+          color: green;
         }
+      }
       "#,
-      indoc! { r#"
-        .foo {
-          transition-property: -webkit-mask, mask;
+      indoc! {r#"
+      @media print and (color) {
+        .a {
+          color: green;
         }
-    "#},
-      Browsers {
-        chrome: Some(90 << 16),
-        ..Browsers::default()
-      },
+      }
+      "#},
     );
 
-    prefix_test(
+    custom_media_test(
       r#"
-        .foo {
-          transition-property: mask-border;
+      @custom-media --color-print print and (color);
+
+      @media print and (--color-print) {
+        .a {
+          color: green;
         }
+      }
       "#,
-      indoc! { r#"
-        .foo {
-          transition-property: -webkit-mask-box-image, mask-border;
+      indoc! {r#"
+      @media print and (color) {
+        .a {
+          color: green;
         }
-    "#},
-      Browsers {
-        chrome: Some(90 << 16),
-        ..Browsers::default()
-      },
+      }
+      "#},
     );
 
-    prefix_test(
+    custom_media_test(
       r#"
-        .foo {
-          transition-property: mask-composite, mask-mode;
+      @custom-media --not-color-print not print and (color);
+
+      @media not print and (--not-color-print) {
+        .a {
+          color: green;
         }
+      }
       "#,
-      indoc! { r#"
-        .foo {
-          transition-property: -webkit-mask-composite, mask-composite, -webkit-mask-source-type, mask-mode;
+      indoc! {r#"
+      @media not print and (color) {
+        .a {
+          color: green;
         }
-    "#},
-      Browsers {
-        chrome: Some(90 << 16),
-        ..Browsers::default()
-      },
+      }
+      "#},
     );
-  }
 
-  #[test]
-  fn test_filter() {
-    minify_test(
-      ".foo { filter: url('filters.svg#filter-id'); }",
-      ".foo{filter:url(filters.svg#filter-id)}",
-    );
-    minify_test(".foo { filter: blur(5px); }", ".foo{filter:blur(5px)}");
-    minify_test(".foo { filter: blur(0px); }", ".foo{filter:blur()}");
-    minify_test(".foo { filter: brightness(10%); }", ".foo{filter:brightness(10%)}");
-    minify_test(".foo { filter: brightness(100%); }", ".foo{filter:brightness()}");
-    minify_test(
-      ".foo { filter: drop-shadow(16px 16px 20px yellow); }",
-      ".foo{filter:drop-shadow(16px 16px 20px #ff0)}",
-    );
-    minify_test(
-      ".foo { filter: contrast(175%) brightness(3%); }",
-      ".foo{filter:contrast(175%)brightness(3%)}",
-    );
-    minify_test(".foo { filter: hue-rotate(0) }", ".foo{filter:hue-rotate()}");
+    custom_media_test(
+      r#"
+      @custom-media --print print;
 
-    prefix_test(
-      ".foo { filter: blur(5px) }",
-      indoc! { r#"
-        .foo {
-          -webkit-filter: blur(5px);
-          filter: blur(5px);
+      @media (--print) {
+        .a {
+          color: green;
         }
-      "#},
-      Browsers {
-        chrome: Some(20 << 16),
-        ..Browsers::default()
-      },
-    );
-
-    prefix_test(
-      ".foo { filter: blur(5px) }",
-      indoc! { r#"
-        .foo {
-          filter: blur(5px);
+      }
+      "#,
+      indoc! {r#"
+      @media print {
+        .a {
+          color: green;
         }
+      }
       "#},
-      Browsers {
-        chrome: Some(80 << 16),
-        ..Browsers::default()
-      },
     );
 
-    prefix_test(
-      ".foo { backdrop-filter: blur(5px) }",
-      indoc! { r#"
-        .foo {
-          backdrop-filter: blur(5px);
+    custom_media_test(
+      r#"
+      @custom-media --print print;
+
+      @media not (--print) {
+        .a {
+          color: green;
+        }
+      }
+      "#,
+      indoc! {r#"
+      @media not print {
+        .a {
+          color: green;
         }
+      }
       "#},
-      Browsers {
-        chrome: Some(80 << 16),
-        ..Browsers::default()
-      },
     );
 
-    prefix_test(
-      ".foo { backdrop-filter: blur(5px) }",
-      indoc! { r#"
-        .foo {
-          -webkit-backdrop-filter: blur(5px);
-          backdrop-filter: blur(5px);
+    custom_media_test(
+      r#"
+      @custom-media --print not print;
+
+      @media not (--print) {
+        .a {
+          color: green;
+        }
+      }
+      "#,
+      indoc! {r#"
+      @media print {
+        .a {
+          color: green;
         }
+      }
       "#},
-      Browsers {
-        safari: Some(15 << 16),
-        ..Browsers::default()
-      },
     );
-    prefix_test(
+
+    custom_media_test(
       r#"
-      .foo {
-        -webkit-backdrop-filter: blur(8px);
-        backdrop-filter: blur(8px);
+      @custom-media --print print;
+
+      @media ((--print)) {
+        .a {
+          color: green;
+        }
       }
       "#,
       indoc! {r#"
-      .foo {
-        -webkit-backdrop-filter: blur(8px);
-        backdrop-filter: blur(8px);
+      @media print {
+        .a {
+          color: green;
+        }
       }
       "#},
-      Browsers {
-        safari: Some(16 << 16),
-        ..Browsers::default()
-      },
     );
 
-    prefix_test(
-      ".foo { filter: var(--foo) }",
-      indoc! { r#"
-        .foo {
-          -webkit-filter: var(--foo);
-          filter: var(--foo);
+    custom_media_test(
+      r#"
+      @custom-media --color (color);
+      @custom-media --print print;
+
+      @media (--print) and (--color) {
+        .a {
+          color: green;
+        }
+      }
+      "#,
+      indoc! {r#"
+      @media print and (color) {
+        .a {
+          color: green;
         }
+      }
       "#},
-      Browsers {
-        chrome: Some(20 << 16),
-        ..Browsers::default()
-      },
     );
 
-    prefix_test(
-      ".foo { filter: drop-shadow(16px 16px 20px lab(40% 56.6 39)) }",
-      indoc! { r#"
-        .foo {
-          -webkit-filter: drop-shadow(16px 16px 20px #b32323);
-          filter: drop-shadow(16px 16px 20px #b32323);
-          filter: drop-shadow(16px 16px 20px lab(40% 56.6 39));
+    custom_media_test(
+      r#"
+      @custom-media --color (color);
+      @custom-media --not-print not print;
+
+      @media (--not-print) and (--color) {
+        .a {
+          color: green;
+        }
+      }
+      "#,
+      indoc! {r#"
+      @media not print and (color) {
+        .a {
+          color: green;
         }
+      }
       "#},
-      Browsers {
-        chrome: Some(20 << 16),
-        ..Browsers::default()
-      },
     );
 
-    prefix_test(
-      ".foo { filter: contrast(175%) drop-shadow(16px 16px 20px lab(40% 56.6 39)) }",
-      indoc! { r#"
-        .foo {
-          filter: contrast(175%) drop-shadow(16px 16px 20px #b32323);
-          filter: contrast(175%) drop-shadow(16px 16px 20px lab(40% 56.6 39));
+    custom_media_test(
+      r#"
+      @custom-media --color (color);
+      @custom-media --screen screen;
+      @custom-media --print print;
+
+      @media (--print) and (--color), (--screen) and (--color) {
+        .a {
+          color: green;
+        }
+      }
+      "#,
+      indoc! {r#"
+      @media print and (color), screen and (color) {
+        .a {
+          color: green;
         }
+      }
       "#},
-      Browsers {
-        chrome: Some(4 << 16),
-        ..Browsers::default()
-      },
     );
 
-    prefix_test(
-      ".foo { filter: drop-shadow(16px 16px 20px lab(40% 56.6 39)) drop-shadow(16px 16px 20px yellow) }",
-      indoc! { r#"
-        .foo {
-          filter: drop-shadow(16px 16px 20px #b32323) drop-shadow(16px 16px 20px #ff0);
-          filter: drop-shadow(16px 16px 20px lab(40% 56.6 39)) drop-shadow(16px 16px 20px #ff0);
+    custom_media_test(
+      r#"
+      @custom-media --color print and (color), print and (script);
+
+      @media (--color) {
+        .a {
+          color: green;
+        }
+      }
+      "#,
+      indoc! {r#"
+      @media print and ((color) or (script)) {
+        .a {
+          color: green;
         }
+      }
       "#},
-      Browsers {
-        chrome: Some(4 << 16),
-        ..Browsers::default()
-      },
     );
 
-    prefix_test(
-      ".foo { filter: var(--foo) drop-shadow(16px 16px 20px lab(40% 56.6 39)) }",
-      indoc! { r#"
-        .foo {
-          filter: var(--foo) drop-shadow(16px 16px 20px #b32323);
-        }
+    custom_media_test(
+      r#"
+      @custom-media --color (color);
+      @custom-media --not-color not all and (--color);
 
-        @supports (color: lab(0% 0 0)) {
-          .foo {
-            filter: var(--foo) drop-shadow(16px 16px 20px lab(40% 56.6 39));
+      @media (--not-color) {
+        .a {
+          color: green;
+        }
+      }
+      "#,
+      indoc! {r#"
+        @media not all and (color) {
+          .a {
+            color: green;
           }
         }
       "#},
-      Browsers {
-        chrome: Some(4 << 16),
-        ..Browsers::default()
-      },
     );
-  }
 
-  #[test]
-  fn test_viewport() {
-    minify_test(
-      r#"
-    @viewport {
-      width: 100vw;
-    }"#,
-      "@viewport{width:100vw}",
-    );
-    minify_test(
+    custom_media_test(
       r#"
-    @-ms-viewport {
-      width: device-width;
-    }"#,
-      "@-ms-viewport{width:device-width}",
-    );
-  }
+      @custom-media --color (color);
 
-  #[test]
-  fn test_at_scope() {
-    minify_test(
-      r#"
-      @scope {
-        .foo {
-          display: flex;
+      @media not all and (--color) {
+        .a {
+          color: green;
         }
       }
       "#,
-      "@scope{.foo{display:flex}}",
-    );
-    minify_test(
-      r#"
-      @scope {
-        :scope {
-          display: flex;
-          color: lightblue;
+      indoc! {r#"
+        @media not all and (color) {
+          .a {
+            color: green;
+          }
         }
-      }"#,
-      "@scope{:scope{color:#add8e6;display:flex}}",
+      "#},
     );
-    minify_test(
+
+    custom_media_test(
       r#"
-      @scope (.light-scheme) {
-        a { color: yellow; }
+      @media (--print) {
+        .a {
+          color: green;
+        }
       }
+
+      @custom-media --print print;
       "#,
-      "@scope(.light-scheme){a{color:#ff0}}",
-    );
-    minify_test(
-      r#"
-      @scope (.media-object) to (.content > *) {
-        a { color: yellow; }
+      indoc! {r#"
+      @media print {
+        .a {
+          color: green;
+        }
       }
-      "#,
-      "@scope(.media-object) to (.content>*){a{color:#ff0}}",
+      "#},
     );
-    minify_test(
+
+    custom_media_test(
       r#"
-      @scope to (.content > *) {
-        a { color: yellow; }
+      @custom-media --not-width not (min-width: 300px);
+      @media screen and ((prefers-color-scheme: dark) or (--not-width)) {
+        .foo {
+          order: 6;
+        }
       }
       "#,
-      "@scope to (.content>*){a{color:#ff0}}",
+      indoc! {r#"
+      @media screen and ((prefers-color-scheme: dark) or (not (width >= 300px))) {
+        .foo {
+          order: 6;
+        }
+      }
+      "#},
     );
-    minify_test(
+
+    fn custom_media_error_test(source: &str, err: Error<MinifyErrorKind>) {
+      let mut stylesheet = StyleSheet::parse(
+        &source,
+        ParserOptions {
+          filename: "test.css".into(),
+          flags: ParserFlags::CUSTOM_MEDIA,
+          ..ParserOptions::default()
+        },
+      )
+      .unwrap();
+      let res = stylesheet.minify(MinifyOptions {
+        targets: Browsers {
+          chrome: Some(95 << 16),
+          ..Browsers::default()
+        }
+        .into(),
+        ..MinifyOptions::default()
+      });
+      assert_eq!(res, Err(err))
+    }
+
+    custom_media_error_test(
       r#"
-      @scope (#my-component) {
-        & { color: yellow; }
+      @custom-media --color-print print and (color);
+
+      @media screen and (--color-print) {
+        .a {
+          color: green;
+        }
       }
       "#,
-      "@scope(#my-component){&{color:#ff0}}",
+      Error {
+        kind: MinifyErrorKind::UnsupportedCustomMediaBooleanLogic {
+          custom_media_loc: Location {
+            source_index: 0,
+            line: 1,
+            column: 7,
+          },
+        },
+        loc: Some(ErrorLocation {
+          filename: "test.css".into(),
+          line: 3,
+          column: 7,
+        }),
+      },
     );
-    minify_test(
+
+    custom_media_error_test(
       r#"
-      @scope (.parent-scope) {
-        @scope (:scope > .child-scope) to (:scope .limit) {
-          .content { color: yellow; }
+      @custom-media --color-print print and (color);
+
+      @media not print and (--color-print) {
+        .a {
+          color: green;
         }
       }
       "#,
-      "@scope(.parent-scope){@scope(:scope>.child-scope) to (:scope .limit){.content{color:#ff0}}}",
+      Error {
+        kind: MinifyErrorKind::UnsupportedCustomMediaBooleanLogic {
+          custom_media_loc: Location {
+            source_index: 0,
+            line: 1,
+            column: 7,
+          },
+        },
+        loc: Some(ErrorLocation {
+          filename: "test.css".into(),
+          line: 3,
+          column: 7,
+        }),
+      },
     );
-    minify_test(
+
+    custom_media_error_test(
       r#"
-      .foo {
-        @scope (.bar) {
-          color: yellow;
-        }
-      }
+      @custom-media --color-print print and (color);
+      @custom-media --color-screen screen and (color);
+
+      @media (--color-print) or (--color-screen) {}
       "#,
-      ".foo{@scope(.bar){&{color:#ff0}}}",
+      Error {
+        kind: MinifyErrorKind::UnsupportedCustomMediaBooleanLogic {
+          custom_media_loc: Location {
+            source_index: 0,
+            line: 2,
+            column: 7,
+          },
+        },
+        loc: Some(ErrorLocation {
+          filename: "test.css".into(),
+          line: 4,
+          column: 7,
+        }),
+      },
     );
-    nesting_test(
+
+    custom_media_error_test(
       r#"
-      .foo {
-        @scope (.bar) {
-          color: yellow;
-        }
-      }
+      @custom-media --color-print print and (color);
+      @custom-media --color-screen screen and (color);
+
+      @media (--color-print) and (--color-screen) {}
       "#,
-      indoc! {r#"
-        @scope (.bar) {
-          :scope {
-            color: #ff0;
-          }
-        }
-      "#},
+      Error {
+        kind: MinifyErrorKind::UnsupportedCustomMediaBooleanLogic {
+          custom_media_loc: Location {
+            source_index: 0,
+            line: 2,
+            column: 7,
+          },
+        },
+        loc: Some(ErrorLocation {
+          filename: "test.css".into(),
+          line: 4,
+          column: 7,
+        }),
+      },
     );
-    nesting_test(
+
+    custom_media_error_test(
       r#"
-      .parent {
-        color: blue;
+      @custom-media --screen screen;
+      @custom-media --print print;
 
-        @scope (& > .scope) to (& .limit) {
-          & .content {
-            color: yellow;
-          }
-        }
-      }
+      @media (--print) and (--screen) {}
       "#,
-      indoc! {r#"
-        .parent {
-          color: #00f;
-        }
-
-        @scope (.parent > .scope) to (.parent > .scope .limit) {
-          :scope .content {
-            color: #ff0;
-          }
-        }
-      "#},
+      Error {
+        kind: MinifyErrorKind::UnsupportedCustomMediaBooleanLogic {
+          custom_media_loc: Location {
+            source_index: 0,
+            line: 1,
+            column: 7,
+          },
+        },
+        loc: Some(ErrorLocation {
+          filename: "test.css".into(),
+          line: 4,
+          column: 7,
+        }),
+      },
     );
-  }
 
-  #[test]
-  fn test_custom_media() {
-    custom_media_test(
+    custom_media_error_test(
       r#"
-      @custom-media --modern (color), (hover);
+      @custom-media --not-print not print and (color);
+      @custom-media --not-screen not screen and (color);
 
-      @media (--modern) and (width > 1024px) {
+      @media ((script) or ((--not-print) and (--not-screen))) {
         .a {
           color: green;
         }
       }
       "#,
-      indoc! {r#"
-      @media ((color) or (hover)) and (width > 1024px) {
-        .a {
-          color: green;
-        }
-      }
-      "#},
+      Error {
+        kind: MinifyErrorKind::UnsupportedCustomMediaBooleanLogic {
+          custom_media_loc: Location {
+            source_index: 0,
+            line: 2,
+            column: 7,
+          },
+        },
+        loc: Some(ErrorLocation {
+          filename: "test.css".into(),
+          line: 4,
+          column: 7,
+        }),
+      },
     );
 
-    custom_media_test(
+    custom_media_error_test(
       r#"
-      @custom-media --color (color);
+      @custom-media --color screen and (color), print and (color);
 
-      @media (--color) and (width > 1024px) {
+      @media (--color) {
         .a {
           color: green;
         }
       }
       "#,
-      indoc! {r#"
-      @media (color) and (width > 1024px) {
-        .a {
-          color: green;
-        }
-      }
-      "#},
+      Error {
+        kind: MinifyErrorKind::UnsupportedCustomMediaBooleanLogic {
+          custom_media_loc: Location {
+            source_index: 0,
+            line: 1,
+            column: 7,
+          },
+        },
+        loc: Some(ErrorLocation {
+          filename: "test.css".into(),
+          line: 3,
+          column: 7,
+        }),
+      },
     );
 
-    custom_media_test(
+    custom_media_error_test(
       r#"
-      @custom-media --a (color);
-      @custom-media --b (--a);
-
-      @media (--b) and (width > 1024px) {
+      @media (--not-defined) {
         .a {
           color: green;
         }
       }
       "#,
-      indoc! {r#"
-      @media (color) and (width > 1024px) {
-        .a {
-          color: green;
-        }
-      }
-      "#},
+      Error {
+        kind: MinifyErrorKind::CustomMediaNotDefined {
+          name: "--not-defined".into(),
+        },
+        loc: Some(ErrorLocation {
+          filename: "test.css".into(),
+          line: 1,
+          column: 7,
+        }),
+      },
     );
 
-    custom_media_test(
+    custom_media_error_test(
       r#"
-      @custom-media --not-color not (color);
+      @custom-media --circular-mq-a (--circular-mq-b);
+      @custom-media --circular-mq-b (--circular-mq-a);
 
-      @media not (--not-color) {
-        .a {
-          color: green;
+      @media (--circular-mq-a) {
+        body {
+          order: 3;
         }
       }
       "#,
-      indoc! {r#"
-      @media (color) {
-        .a {
-          color: green;
-        }
-      }
-      "#},
+      Error {
+        kind: MinifyErrorKind::CircularCustomMedia {
+          name: "--circular-mq-a".into(),
+        },
+        loc: Some(ErrorLocation {
+          filename: "test.css".into(),
+          line: 4,
+          column: 7,
+        }),
+      },
     );
+  }
 
-    custom_media_test(
-      r#"
-      @custom-media --color-print print and (color);
-
-      @media (--color-print) {
-        .a {
-          color: green;
+  #[test]
+  fn test_dependencies() {
+    fn dep_test(source: &str, expected: &str, deps: Vec<(&str, &str)>) {
+      let mut stylesheet = StyleSheet::parse(
+        &source,
+        ParserOptions {
+          filename: "test.css".into(),
+          ..ParserOptions::default()
+        },
+      )
+      .unwrap();
+      stylesheet.minify(MinifyOptions::default()).unwrap();
+      let res = stylesheet
+        .to_css(PrinterOptions {
+          analyze_dependencies: Some(Default::default()),
+          minify: true,
+          ..PrinterOptions::default()
+        })
+        .unwrap();
+      assert_eq!(res.code, expected);
+      let dependencies = res.dependencies.unwrap();
+      assert_eq!(dependencies.len(), deps.len());
+      for (i, (url, placeholder)) in deps.into_iter().enumerate() {
+        match &dependencies[i] {
+          Dependency::Url(dep) => {
+            assert_eq!(dep.url, url);
+            assert_eq!(dep.placeholder, placeholder);
+          }
+          Dependency::Import(dep) => {
+            assert_eq!(dep.url, url);
+            assert_eq!(dep.placeholder, placeholder);
+          }
         }
       }
-      "#,
-      indoc! {r#"
-      @media print and (color) {
-        .a {
-          color: green;
-        }
+    }
+
+    fn dep_error_test(source: &str, error: PrinterErrorKind) {
+      let stylesheet = StyleSheet::parse(&source, ParserOptions::default()).unwrap();
+      let res = stylesheet.to_css(PrinterOptions {
+        analyze_dependencies: Some(Default::default()),
+        ..PrinterOptions::default()
+      });
+      match res {
+        Err(e) => assert_eq!(e.kind, error),
+        _ => unreachable!(),
       }
-      "#},
+    }
+
+    dep_test(
+      ".foo { background: image-set('./img12x.png', './img21x.png' 2x)}",
+      ".foo{background:image-set(\"hXFI8W\" 1x,\"5TkpBa\" 2x)}",
+      vec![("./img12x.png", "hXFI8W"), ("./img21x.png", "5TkpBa")],
+    );
+
+    dep_test(
+      ".foo { background: image-set(url(./img12x.png), url('./img21x.png') 2x)}",
+      ".foo{background:image-set(\"hXFI8W\" 1x,\"5TkpBa\" 2x)}",
+      vec![("./img12x.png", "hXFI8W"), ("./img21x.png", "5TkpBa")],
+    );
+
+    dep_test(
+      ".foo { shape-outside: url(./img12x.png) }",
+      ".foo{shape-outside:url(\"hXFI8W\")}",
+      vec![("./img12x.png", "hXFI8W")],
+    );
+
+    dep_test(
+      ".foo { cursor: url(./img12x.png) 4 12, pointer }",
+      ".foo{cursor:url(\"hXFI8W\") 4 12,pointer}",
+      vec![("./img12x.png", "hXFI8W")],
+    );
+
+    dep_test(
+      ".foo { --test: url(/foo.png) }",
+      ".foo{--test:url(\"lDnnrG\")}",
+      vec![("/foo.png", "lDnnrG")],
+    );
+
+    dep_test(
+      ".foo { --test: url(\"/foo.png\") }",
+      ".foo{--test:url(\"lDnnrG\")}",
+      vec![("/foo.png", "lDnnrG")],
+    );
+
+    dep_test(
+      ".foo { --test: url(\"http://example.com/foo.png\") }",
+      ".foo{--test:url(\"3X1zSW\")}",
+      vec![("http://example.com/foo.png", "3X1zSW")],
+    );
+
+    dep_test(
+      ".foo { --test: url(\"data:image/svg+xml;utf8,<svg></svg>\") }",
+      ".foo{--test:url(\"-vl-rG\")}",
+      vec![("data:image/svg+xml;utf8,<svg></svg>", "-vl-rG")],
+    );
+
+    dep_test(
+      ".foo { background: url(\"foo.png\") var(--test) }",
+      ".foo{background:url(\"Vwkwkq\") var(--test)}",
+      vec![("foo.png", "Vwkwkq")],
+    );
+
+    dep_error_test(
+      ".foo { --test: url(\"foo.png\") }",
+      PrinterErrorKind::AmbiguousUrlInCustomProperty { url: "foo.png".into() },
     );
 
-    custom_media_test(
-      r#"
-      @custom-media --color-print print and (color);
+    dep_error_test(
+      ".foo { --test: url(foo.png) }",
+      PrinterErrorKind::AmbiguousUrlInCustomProperty { url: "foo.png".into() },
+    );
 
-      @media print and (--color-print) {
-        .a {
-          color: green;
-        }
-      }
-      "#,
-      indoc! {r#"
-      @media print and (color) {
-        .a {
-          color: green;
-        }
-      }
-      "#},
+    dep_error_test(
+      ".foo { --test: url(./foo.png) }",
+      PrinterErrorKind::AmbiguousUrlInCustomProperty {
+        url: "./foo.png".into(),
+      },
     );
 
-    custom_media_test(
-      r#"
-      @custom-media --not-color-print not print and (color);
+    dep_test(
+      ".foo { behavior: url(#foo) }",
+      ".foo{behavior:url(\"Zn9-2q\")}",
+      vec![("#foo", "Zn9-2q")],
+    );
 
-      @media not print and (--not-color-print) {
-        .a {
-          color: green;
-        }
-      }
-      "#,
-      indoc! {r#"
-      @media not print and (color) {
-        .a {
-          color: green;
-        }
-      }
-      "#},
+    dep_test(
+      ".foo { --foo: url(#foo) }",
+      ".foo{--foo:url(\"Zn9-2q\")}",
+      vec![("#foo", "Zn9-2q")],
     );
 
-    custom_media_test(
-      r#"
-      @custom-media --print print;
+    dep_test(
+      "@import \"test.css\"; .foo { color: red }",
+      "@import \"hHsogW\";.foo{color:red}",
+      vec![("test.css", "hHsogW")],
+    );
+  }
 
-      @media (--print) {
-        .a {
-          color: green;
-        }
-      }
-      "#,
-      indoc! {r#"
-      @media print {
-        .a {
-          color: green;
-        }
+  #[test]
+  fn test_api() {
+    let stylesheet = StyleSheet::parse(".foo:hover { color: red }", ParserOptions::default()).unwrap();
+    match &stylesheet.rules.0[0] {
+      CssRule::Style(s) => {
+        assert_eq!(&s.selectors.to_string(), ".foo:hover");
       }
-      "#},
-    );
+      _ => unreachable!(),
+    }
 
-    custom_media_test(
-      r#"
-      @custom-media --print print;
+    let color = CssColor::parse_string("#f0f").unwrap();
+    assert_eq!(color.to_css_string(PrinterOptions::default()).unwrap(), "#f0f");
 
-      @media not (--print) {
-        .a {
-          color: green;
-        }
-      }
-      "#,
+    let frequency = crate::values::frequency::Frequency::parse_string("1000hz").unwrap();
+    assert_eq!(frequency.to_css_string(PrinterOptions::default()).unwrap(), "1000hz");
+    assert_eq!(
+      frequency
+        .to_css_string(PrinterOptions {
+          minify: true,
+          ..PrinterOptions::default()
+        })
+        .unwrap(),
+      "1khz"
+    );
+
+    let rule = CssRule::parse_string(".foo { color: red }", ParserOptions::default()).unwrap();
+    assert_eq!(
+      rule.to_css_string(PrinterOptions::default()).unwrap(),
       indoc! {r#"
-      @media not print {
-        .a {
-          color: green;
-        }
-      }
-      "#},
+    .foo {
+      color: red;
+    }"#}
     );
 
-    custom_media_test(
-      r#"
-      @custom-media --print not print;
+    let property = Property::parse_string("color".into(), "#f0f", ParserOptions::default()).unwrap();
+    assert_eq!(
+      property.to_css_string(false, PrinterOptions::default()).unwrap(),
+      "color: #f0f"
+    );
+    assert_eq!(
+      property.to_css_string(true, PrinterOptions::default()).unwrap(),
+      "color: #f0f !important"
+    );
 
-      @media not (--print) {
-        .a {
-          color: green;
-        }
+    let code = indoc! { r#"
+      .foo {
+        color: green;
       }
-      "#,
-      indoc! {r#"
+
+      .bar {
+        color: red;
+        background: pink;
+      }
+
       @media print {
-        .a {
+        .baz {
           color: green;
         }
       }
-      "#},
+    "#};
+    let stylesheet = StyleSheet::parse(code, ParserOptions::default()).unwrap();
+    if let CssRule::Style(style) = &stylesheet.rules.0[1] {
+      let (key, val) = style.property_location(code, 0).unwrap();
+      assert_eq!(
+        key,
+        SourceLocation { line: 5, column: 3 }..SourceLocation { line: 5, column: 8 }
+      );
+      assert_eq!(
+        val,
+        SourceLocation { line: 5, column: 10 }..SourceLocation { line: 5, column: 13 }
+      );
+    }
+
+    if let CssRule::Style(style) = &stylesheet.rules.0[1] {
+      let (key, val) = style.property_location(code, 1).unwrap();
+      assert_eq!(
+        key,
+        SourceLocation { line: 6, column: 3 }..SourceLocation { line: 6, column: 13 }
+      );
+      assert_eq!(
+        val,
+        SourceLocation { line: 6, column: 15 }..SourceLocation { line: 6, column: 19 }
+      );
+    }
+    if let CssRule::Media(media) = &stylesheet.rules.0[2] {
+      if let CssRule::Style(style) = &media.rules.0[0] {
+        let (key, val) = style.property_location(code, 0).unwrap();
+        assert_eq!(
+          key,
+          SourceLocation { line: 11, column: 5 }..SourceLocation { line: 11, column: 10 }
+        );
+        assert_eq!(
+          val,
+          SourceLocation { line: 11, column: 12 }..SourceLocation { line: 11, column: 17 }
+        );
+      }
+    }
+
+    let mut property = Property::Transform(Default::default(), VendorPrefix::WebKit);
+    property.set_prefix(VendorPrefix::None);
+    assert_eq!(property, Property::Transform(Default::default(), VendorPrefix::None));
+    property.set_prefix(VendorPrefix::Moz);
+    assert_eq!(property, Property::Transform(Default::default(), VendorPrefix::Moz));
+    property.set_prefix(VendorPrefix::WebKit | VendorPrefix::Moz);
+    assert_eq!(
+      property,
+      Property::Transform(Default::default(), VendorPrefix::WebKit | VendorPrefix::Moz)
     );
 
-    custom_media_test(
-      r#"
-      @custom-media --print print;
+    let mut property = Property::TextDecorationLine(Default::default(), VendorPrefix::None);
+    property.set_prefix(VendorPrefix::Ms);
+    assert_eq!(
+      property,
+      Property::TextDecorationLine(Default::default(), VendorPrefix::None)
+    );
+    property.set_prefix(VendorPrefix::WebKit | VendorPrefix::Moz | VendorPrefix::Ms);
+    assert_eq!(
+      property,
+      Property::TextDecorationLine(Default::default(), VendorPrefix::WebKit | VendorPrefix::Moz)
+    );
 
-      @media ((--print)) {
-        .a {
-          color: green;
-        }
-      }
-      "#,
-      indoc! {r#"
-      @media print {
-        .a {
-          color: green;
-        }
+    let mut property = Property::AccentColor(Default::default());
+    property.set_prefix(VendorPrefix::WebKit);
+    assert_eq!(property, Property::AccentColor(Default::default()));
+  }
+
+  #[cfg(feature = "substitute_variables")]
+  #[test]
+  fn test_substitute_vars() {
+    use crate::properties::custom::TokenList;
+    use crate::traits::ParseWithOptions;
+
+    fn test(property: Property, vars: HashMap<&str, &str>, expected: &str) {
+      if let Property::Unparsed(unparsed) = property {
+        let vars = vars
+          .into_iter()
+          .map(|(k, v)| {
+            (
+              k,
+              TokenList::parse_string_with_options(v, ParserOptions::default()).unwrap(),
+            )
+          })
+          .collect();
+        let substituted = unparsed.substitute_variables(&vars).unwrap();
+        assert_eq!(
+          substituted.to_css_string(false, PrinterOptions::default()).unwrap(),
+          expected
+        );
+      } else {
+        panic!("Not an unparsed property");
       }
-      "#},
-    );
+    }
+
+    let property = Property::parse_string("color".into(), "var(--test)", ParserOptions::default()).unwrap();
+    test(property, HashMap::from([("--test", "yellow")]), "color: #ff0");
+
+    let property =
+      Property::parse_string("color".into(), "var(--test, var(--foo))", ParserOptions::default()).unwrap();
+    test(property, HashMap::from([("--foo", "yellow")]), "color: #ff0");
+    let property = Property::parse_string(
+      "color".into(),
+      "var(--test, var(--foo, yellow))",
+      ParserOptions::default(),
+    )
+    .unwrap();
+    test(property, HashMap::new(), "color: #ff0");
 
-    custom_media_test(
-      r#"
-      @custom-media --color (color);
-      @custom-media --print print;
+    let property =
+      Property::parse_string("width".into(), "calc(var(--a) + var(--b))", ParserOptions::default()).unwrap();
+    test(property, HashMap::from([("--a", "2px"), ("--b", "4px")]), "width: 6px");
 
-      @media (--print) and (--color) {
-        .a {
-          color: green;
-        }
-      }
-      "#,
-      indoc! {r#"
-      @media print and (color) {
-        .a {
-          color: green;
-        }
-      }
-      "#},
+    let property = Property::parse_string("color".into(), "var(--a)", ParserOptions::default()).unwrap();
+    test(
+      property,
+      HashMap::from([("--a", "var(--b)"), ("--b", "yellow")]),
+      "color: #ff0",
     );
 
-    custom_media_test(
-      r#"
-      @custom-media --color (color);
-      @custom-media --not-print not print;
-
-      @media (--not-print) and (--color) {
-        .a {
-          color: green;
-        }
-      }
-      "#,
-      indoc! {r#"
-      @media not print and (color) {
-        .a {
-          color: green;
-        }
-      }
-      "#},
+    let property = Property::parse_string("color".into(), "var(--a)", ParserOptions::default()).unwrap();
+    test(
+      property,
+      HashMap::from([("--a", "var(--b)"), ("--b", "var(--c)"), ("--c", "var(--a)")]),
+      "color: var(--a)",
     );
+  }
 
-    custom_media_test(
-      r#"
-      @custom-media --color (color);
-      @custom-media --screen screen;
-      @custom-media --print print;
+  #[cfg(feature = "substitute_variables")]
+  #[test]
+  fn test_substitute_env() {
+    use crate::properties::custom::TokenList;
+    use crate::traits::ParseWithOptions;
 
-      @media (--print) and (--color), (--screen) and (--color) {
-        .a {
-          color: green;
-        }
-      }
-      "#,
-      indoc! {r#"
-      @media print and (color), screen and (color) {
-        .a {
-          color: green;
-        }
+    fn test(property: Property, vars: HashMap<&str, &str>, expected: &str) {
+      if let Property::Unparsed(unparsed) = property {
+        let vars = vars
+          .into_iter()
+          .map(|(k, v)| {
+            (
+              k,
+              TokenList::parse_string_with_options(v, ParserOptions::default()).unwrap(),
+            )
+          })
+          .collect();
+        let substituted = unparsed.substitute_environment_variables(&vars).unwrap();
+        assert_eq!(
+          substituted.to_css_string(false, PrinterOptions::default()).unwrap(),
+          expected
+        );
+      } else {
+        panic!("Not an unparsed property");
       }
-      "#},
-    );
+    }
 
-    custom_media_test(
-      r#"
-      @custom-media --color print and (color), print and (script);
+    let property = Property::parse_string(
+      "padding-top".into(),
+      "env(safe-area-inset-top)",
+      ParserOptions::default(),
+    )
+    .unwrap();
+    test(
+      property,
+      HashMap::from([("safe-area-inset-top", "20px")]),
+      "padding-top: 20px",
+    );
 
-      @media (--color) {
-        .a {
-          color: green;
-        }
-      }
-      "#,
-      indoc! {r#"
-      @media print and ((color) or (script)) {
-        .a {
-          color: green;
-        }
-      }
-      "#},
+    let property = Property::parse_string(
+      "padding-top".into(),
+      "calc(env(safe-area-inset-top, 0px) + 10px)",
+      ParserOptions::default(),
+    )
+    .unwrap();
+    test(
+      property,
+      HashMap::from([("safe-area-inset-top", "20px")]),
+      "padding-top: 30px",
     );
 
-    custom_media_test(
-      r#"
-      @custom-media --color (color);
-      @custom-media --not-color not all and (--color);
+    let property = Property::parse_string(
+      "padding-top".into(),
+      "env(safe-area-inset-top, 0px)",
+      ParserOptions::default(),
+    )
+    .unwrap();
+    test(property, HashMap::new(), "padding-top: env(safe-area-inset-top, 0px)");
 
-      @media (--not-color) {
-        .a {
-          color: green;
-        }
-      }
-      "#,
-      indoc! {r#"
-        @media not all and (color) {
-          .a {
-            color: green;
-          }
-        }
-      "#},
+    let property = Property::parse_string(
+      "padding".into(),
+      "env(--padding, env(safe-area-inset-top, 4px))",
+      ParserOptions::default(),
+    )
+    .unwrap();
+    test(
+      property.clone(),
+      HashMap::from([("safe-area-inset-top", "2px")]),
+      "padding: env(--padding, 2px)",
     );
+    test(property, HashMap::from([("--padding", "1px 2px")]), "padding: 1px 2px");
 
-    custom_media_test(
-      r#"
-      @custom-media --color (color);
-
-      @media not all and (--color) {
-        .a {
-          color: green;
-        }
-      }
-      "#,
-      indoc! {r#"
-        @media not all and (color) {
-          .a {
-            color: green;
-          }
-        }
-      "#},
+    let property = Property::parse_string(
+      "width".into(),
+      "env(viewport-segment-width 0 0)",
+      ParserOptions::default(),
+    )
+    .unwrap();
+    test(
+      property,
+      HashMap::from([("viewport-segment-width", "100px")]),
+      "width: env(viewport-segment-width 0 0)",
     );
+  }
 
-    custom_media_test(
-      r#"
-      @media (--print) {
-        .a {
-          color: green;
-        }
-      }
+  #[test]
+  fn test_environment_variables_option() {
+    use crate::properties::custom::TokenList;
+    use crate::traits::ParseWithOptions;
 
-      @custom-media --print print;
-      "#,
-      indoc! {r#"
-      @media print {
-        .a {
-          color: green;
-        }
-      }
-      "#},
+    fn env_test(source: &str, expected: &str, vars: HashMap<&str, &'static str>) {
+      let mut stylesheet = StyleSheet::parse(&source, ParserOptions::default()).unwrap();
+      stylesheet
+        .minify(MinifyOptions {
+          environment_variables: vars
+            .into_iter()
+            .map(|(k, v)| {
+              (
+                k.to_string(),
+                TokenList::parse_string_with_options(v, ParserOptions::default()).unwrap(),
+              )
+            })
+            .collect(),
+          ..MinifyOptions::default()
+        })
+        .unwrap();
+      let res = stylesheet
+        .to_css(PrinterOptions {
+          minify: true,
+          ..PrinterOptions::default()
+        })
+        .unwrap();
+      assert_eq!(res.code, expected);
+    }
+
+    env_test(
+      ".foo { padding-top: env(safe-area-inset-top) }",
+      ".foo{padding-top:20px}",
+      HashMap::from([("safe-area-inset-top", "20px")]),
+    );
+    env_test(
+      ".foo { padding: env(safe-area-inset-top, 0px) env(safe-area-inset-right, 0px) }",
+      ".foo{padding:20px env(safe-area-inset-right,0px)}",
+      HashMap::from([("safe-area-inset-top", "20px")]),
+    );
+    env_test(
+      ".foo { margin-top: calc(env(safe-area-inset-top) + var(--x, env(safe-area-inset-top))) }",
+      ".foo{margin-top:calc(20px + var(--x,20px))}",
+      HashMap::from([("safe-area-inset-top", "20px")]),
+    );
+    env_test(
+      ".foo { --inset: env(safe-area-inset-top); --other: env(--custom) }",
+      ".foo{--inset:20px;--other:red}",
+      HashMap::from([("safe-area-inset-top", "20px"), ("--custom", "red")]),
+    );
+    env_test(
+      ".foo { padding-top: env(safe-area-inset-top) }",
+      ".foo{padding-top:env(safe-area-inset-top)}",
+      HashMap::new(),
     );
+  }
 
-    custom_media_test(
+  #[test]
+  fn test_layer() {
+    minify_test("@layer foo;", "@layer foo;");
+    minify_test("@layer foo, bar;", "@layer foo,bar;");
+    minify_test("@layer foo.bar;", "@layer foo.bar;");
+    minify_test("@layer foo.bar, baz;", "@layer foo.bar,baz;");
+
+    minify_test(
       r#"
-      @custom-media --not-width not (min-width: 300px);
-      @media screen and ((prefers-color-scheme: dark) or (--not-width)) {
-        .foo {
-          order: 6;
-        }
-      }
-      "#,
-      indoc! {r#"
-      @media screen and ((prefers-color-scheme: dark) or (not (width >= 300px))) {
-        .foo {
-          order: 6;
+      @layer foo {
+        .bar {
+          color: red;
         }
       }
-      "#},
+    "#,
+      "@layer foo{.bar{color:red}}",
     );
-
-    fn custom_media_error_test(source: &str, err: Error<MinifyErrorKind>) {
-      let mut stylesheet = StyleSheet::parse(
-        &source,
-        ParserOptions {
-          filename: "test.css".into(),
-          flags: ParserFlags::CUSTOM_MEDIA,
-          ..ParserOptions::default()
-        },
-      )
-      .unwrap();
-      let res = stylesheet.minify(MinifyOptions {
-        targets: Browsers {
-          chrome: Some(95 << 16),
-          ..Browsers::default()
-        }
-        .into(),
-        ..MinifyOptions::default()
-      });
-      assert_eq!(res, Err(err))
-    }
-
-    custom_media_error_test(
+    minify_test(
       r#"
-      @custom-media --color-print print and (color);
-
-      @media screen and (--color-print) {
-        .a {
-          color: green;
+      @layer foo.bar {
+        .bar {
+          color: red;
         }
       }
-      "#,
-      Error {
-        kind: MinifyErrorKind::UnsupportedCustomMediaBooleanLogic {
-          custom_media_loc: Location {
-            source_index: 0,
-            line: 1,
-            column: 7,
-          },
-        },
-        loc: Some(ErrorLocation {
-          filename: "test.css".into(),
-          line: 3,
-          column: 7,
-        }),
-      },
+    "#,
+      "@layer foo.bar{.bar{color:red}}",
     );
+    minify_test(r#"
+      @layer base {
+        p { max-width: 70ch; }
+      }
 
-    custom_media_error_test(
-      r#"
-      @custom-media --color-print print and (color);
+      @layer framework {
+        @layer base {
+          p { margin-block: 0.75em; }
+        }
 
-      @media not print and (--color-print) {
-        .a {
-          color: green;
+        @layer theme {
+          p { color: #222; }
         }
       }
-      "#,
-      Error {
-        kind: MinifyErrorKind::UnsupportedCustomMediaBooleanLogic {
-          custom_media_loc: Location {
-            source_index: 0,
-            line: 1,
-            column: 7,
-          },
-        },
-        loc: Some(ErrorLocation {
-          filename: "test.css".into(),
-          line: 3,
-          column: 7,
-        }),
-      },
+    "#, "@layer base{p{max-width:70ch}}@layer framework{@layer base{p{margin-block:.75em}}@layer theme{p{color:#222}}}");
+    minify_test(
+      r#"
+      @layer {
+        .bar {
+          color: red;
+        }
+      }
+    "#,
+      "@layer{.bar{color:red}}",
     );
-
-    custom_media_error_test(
+    minify_test(
       r#"
-      @custom-media --color-print print and (color);
-      @custom-media --color-screen screen and (color);
-
-      @media (--color-print) or (--color-screen) {}
-      "#,
-      Error {
-        kind: MinifyErrorKind::UnsupportedCustomMediaBooleanLogic {
-          custom_media_loc: Location {
-            source_index: 0,
-            line: 2,
-            column: 7,
-          },
-        },
-        loc: Some(ErrorLocation {
-          filename: "test.css".into(),
-          line: 4,
-          column: 7,
-        }),
-      },
+      @layer foo\20 bar, baz;
+    "#,
+      "@layer foo\\ bar,baz;",
     );
-
-    custom_media_error_test(
+    minify_test(
       r#"
-      @custom-media --color-print print and (color);
-      @custom-media --color-screen screen and (color);
-
-      @media (--color-print) and (--color-screen) {}
-      "#,
-      Error {
-        kind: MinifyErrorKind::UnsupportedCustomMediaBooleanLogic {
-          custom_media_loc: Location {
-            source_index: 0,
-            line: 2,
-            column: 7,
-          },
-        },
-        loc: Some(ErrorLocation {
-          filename: "test.css".into(),
-          line: 4,
-          column: 7,
-        }),
-      },
+      @layer one.two\20 three\#four\.five {
+        .bar {
+          color: red;
+        }
+      }
+    "#,
+      "@layer one.two\\ three\\#four\\.five{.bar{color:red}}",
     );
 
-    custom_media_error_test(
+    error_test("@layer;", ParserError::UnexpectedToken(Token::Semicolon));
+    error_test("@layer foo, bar {};", ParserError::AtRuleBodyInvalid);
+    minify_test("@import 'test.css' layer;", "@import \"test.css\" layer;");
+    minify_test("@import 'test.css' layer(foo);", "@import \"test.css\" layer(foo);");
+    minify_test(
+      "@import 'test.css' layer(foo.bar);",
+      "@import \"test.css\" layer(foo.bar);",
+    );
+    minify_test(
+      "@import 'test.css' layer(foo\\20 bar);",
+      "@import \"test.css\" layer(foo\\ bar);",
+    );
+    error_test(
+      "@import 'test.css' layer(foo, bar) {};",
+      ParserError::UnexpectedToken(Token::Comma),
+    );
+    minify_test(
       r#"
-      @custom-media --screen screen;
-      @custom-media --print print;
+      @layer one {
+        body {
+          background: red;
+        }
+      }
 
-      @media (--print) and (--screen) {}
-      "#,
-      Error {
-        kind: MinifyErrorKind::UnsupportedCustomMediaBooleanLogic {
-          custom_media_loc: Location {
-            source_index: 0,
-            line: 1,
-            column: 7,
-          },
-        },
-        loc: Some(ErrorLocation {
-          filename: "test.css".into(),
-          line: 4,
-          column: 7,
-        }),
-      },
-    );
+      body {
+        background: red;
+      }
 
-    custom_media_error_test(
-      r#"
-      @custom-media --not-print not print and (color);
-      @custom-media --not-screen not screen and (color);
+      @layer two {
+        body {
+          background: green;
+        }
+      }
 
-      @media ((script) or ((--not-print) and (--not-screen))) {
-        .a {
-          color: green;
+      @layer one {
+        body {
+          background: yellow;
         }
       }
       "#,
-      Error {
-        kind: MinifyErrorKind::UnsupportedCustomMediaBooleanLogic {
-          custom_media_loc: Location {
-            source_index: 0,
-            line: 2,
-            column: 7,
-          },
-        },
-        loc: Some(ErrorLocation {
-          filename: "test.css".into(),
-          line: 4,
-          column: 7,
-        }),
-      },
+      "@layer one{body{background:#ff0}}body{background:red}@layer two{body{background:green}}",
     );
+  }
 
-    custom_media_error_test(
+  #[test]
+  fn test_property() {
+    minify_test(
       r#"
-      @custom-media --color screen and (color), print and (color);
-
-      @media (--color) {
-        .a {
-          color: green;
-        }
+      @property --property-name {
+        syntax: '<color>';
+        inherits: false;
+        initial-value: yellow;
       }
-      "#,
-      Error {
-        kind: MinifyErrorKind::UnsupportedCustomMediaBooleanLogic {
-          custom_media_loc: Location {
-            source_index: 0,
-            line: 1,
-            column: 7,
-          },
-        },
-        loc: Some(ErrorLocation {
-          filename: "test.css".into(),
-          line: 3,
-          column: 7,
-        }),
-      },
+    "#,
+      "@property --property-name{syntax:\"<color>\";inherits:false;initial-value:#ff0}",
     );
 
-    custom_media_error_test(
+    test(
       r#"
-      @media (--not-defined) {
-        .a {
-          color: green;
-        }
+      @property --property-name {
+        syntax: '*';
+        inherits: false;
+        initial-value: ;
       }
-      "#,
-      Error {
-        kind: MinifyErrorKind::CustomMediaNotDefined {
-          name: "--not-defined".into(),
-        },
-        loc: Some(ErrorLocation {
-          filename: "test.css".into(),
-          line: 1,
-          column: 7,
-        }),
-      },
+    "#,
+      indoc! {r#"
+      @property --property-name {
+        syntax: "*";
+        inherits: false;
+        initial-value: ;
+      }
+    "#},
     );
 
-    custom_media_error_test(
+    minify_test(
       r#"
-      @custom-media --circular-mq-a (--circular-mq-b);
-      @custom-media --circular-mq-b (--circular-mq-a);
-
-      @media (--circular-mq-a) {
-        body {
-          order: 3;
-        }
+      @property --property-name {
+        syntax: '*';
+        inherits: false;
+        initial-value: ;
       }
-      "#,
-      Error {
-        kind: MinifyErrorKind::CircularCustomMedia {
-          name: "--circular-mq-a".into(),
-        },
-        loc: Some(ErrorLocation {
-          filename: "test.css".into(),
-          line: 4,
-          column: 7,
-        }),
-      },
+    "#,
+      "@property --property-name{syntax:\"*\";inherits:false;initial-value:}",
     );
-  }
 
-  #[test]
-  fn test_dependencies() {
-    fn dep_test(source: &str, expected: &str, deps: Vec<(&str, &str)>) {
-      let mut stylesheet = StyleSheet::parse(
-        &source,
-        ParserOptions {
-          filename: "test.css".into(),
-          ..ParserOptions::default()
-        },
-      )
-      .unwrap();
-      stylesheet.minify(MinifyOptions::default()).unwrap();
-      let res = stylesheet
-        .to_css(PrinterOptions {
-          analyze_dependencies: Some(Default::default()),
-          minify: true,
-          ..PrinterOptions::default()
-        })
-        .unwrap();
-      assert_eq!(res.code, expected);
-      let dependencies = res.dependencies.unwrap();
-      assert_eq!(dependencies.len(), deps.len());
-      for (i, (url, placeholder)) in deps.into_iter().enumerate() {
-        match &dependencies[i] {
-          Dependency::Url(dep) => {
-            assert_eq!(dep.url, url);
-            assert_eq!(dep.placeholder, placeholder);
-          }
-          Dependency::Import(dep) => {
-            assert_eq!(dep.url, url);
-            assert_eq!(dep.placeholder, placeholder);
-          }
-        }
+    test(
+      r#"
+      @property --property-name {
+        syntax: '*';
+        inherits: false;
+        initial-value:;
       }
-    }
-
-    fn dep_error_test(source: &str, error: PrinterErrorKind) {
-      let stylesheet = StyleSheet::parse(&source, ParserOptions::default()).unwrap();
-      let res = stylesheet.to_css(PrinterOptions {
-        analyze_dependencies: Some(Default::default()),
-        ..PrinterOptions::default()
-      });
-      match res {
-        Err(e) => assert_eq!(e.kind, error),
-        _ => unreachable!(),
+    "#,
+      indoc! {r#"
+      @property --property-name {
+        syntax: "*";
+        inherits: false;
+        initial-value: ;
       }
-    }
+    "#},
+    );
 
-    dep_test(
-      ".foo { background: image-set('./img12x.png', './img21x.png' 2x)}",
-      ".foo{background:image-set(\"hXFI8W\" 1x,\"5TkpBa\" 2x)}",
-      vec![("./img12x.png", "hXFI8W"), ("./img21x.png", "5TkpBa")],
+    minify_test(
+      r#"
+      @property --property-name {
+        syntax: '*';
+        inherits: false;
+        initial-value:;
+      }
+    "#,
+      "@property --property-name{syntax:\"*\";inherits:false;initial-value:}",
+    );
+    minify_test(
+      r#"
+      @property --property-name {
+        syntax: '*';
+        inherits: false;
+        initial-value: foo bar;
+      }
+    "#,
+      "@property --property-name{syntax:\"*\";inherits:false;initial-value:foo bar}",
     );
 
-    dep_test(
-      ".foo { background: image-set(url(./img12x.png), url('./img21x.png') 2x)}",
-      ".foo{background:image-set(\"hXFI8W\" 1x,\"5TkpBa\" 2x)}",
-      vec![("./img12x.png", "hXFI8W"), ("./img21x.png", "5TkpBa")],
+    minify_test(
+      r#"
+      @property --property-name {
+        syntax: '<length>';
+        inherits: true;
+        initial-value: 25px;
+      }
+    "#,
+      "@property --property-name{syntax:\"<length>\";inherits:true;initial-value:25px}",
     );
 
-    dep_test(
-      ".foo { --test: url(/foo.png) }",
-      ".foo{--test:url(\"lDnnrG\")}",
-      vec![("/foo.png", "lDnnrG")],
+    error_test(
+      r#"
+      @property --property-name {
+        syntax: '<color>';
+        inherits: false;
+        initial-value: 25px;
+      }
+    "#,
+      ParserError::UnexpectedToken(crate::properties::custom::Token::Dimension {
+        has_sign: false,
+        value: 25.0,
+        int_value: Some(25),
+        unit: "px".into(),
+      }),
     );
 
-    dep_test(
-      ".foo { --test: url(\"/foo.png\") }",
-      ".foo{--test:url(\"lDnnrG\")}",
-      vec![("/foo.png", "lDnnrG")],
+    error_test(
+      r#"
+      @property --property-name {
+        syntax: '<length>';
+        inherits: false;
+        initial-value: var(--some-value);
+      }
+    "#,
+      ParserError::UnexpectedToken(crate::properties::custom::Token::Function("var".into())),
     );
 
-    dep_test(
-      ".foo { --test: url(\"http://example.com/foo.png\") }",
-      ".foo{--test:url(\"3X1zSW\")}",
-      vec![("http://example.com/foo.png", "3X1zSW")],
+    error_test(
+      r#"
+      @property --property-name {
+        syntax: '<color>';
+        inherits: false;
+      }
+    "#,
+      ParserError::AtRuleBodyInvalid,
     );
 
-    dep_test(
-      ".foo { --test: url(\"data:image/svg+xml;utf8,<svg></svg>\") }",
-      ".foo{--test:url(\"-vl-rG\")}",
-      vec![("data:image/svg+xml;utf8,<svg></svg>", "-vl-rG")],
+    minify_test(
+      r#"
+      @property --property-name {
+        syntax: '*';
+        inherits: false;
+      }
+    "#,
+      "@property --property-name{syntax:\"*\";inherits:false}",
     );
 
-    dep_test(
-      ".foo { background: url(\"foo.png\") var(--test) }",
-      ".foo{background:url(\"Vwkwkq\") var(--test)}",
-      vec![("foo.png", "Vwkwkq")],
+    error_test(
+      r#"
+      @property --property-name {
+        syntax: '*';
+      }
+    "#,
+      ParserError::AtRuleBodyInvalid,
     );
 
-    dep_error_test(
-      ".foo { --test: url(\"foo.png\") }",
-      PrinterErrorKind::AmbiguousUrlInCustomProperty { url: "foo.png".into() },
+    error_test(
+      r#"
+      @property --property-name {
+        inherits: false;
+      }
+    "#,
+      ParserError::AtRuleBodyInvalid,
     );
 
-    dep_error_test(
-      ".foo { --test: url(foo.png) }",
-      PrinterErrorKind::AmbiguousUrlInCustomProperty { url: "foo.png".into() },
+    error_test(
+      r#"
+      @property property-name {
+        syntax: '*';
+        inherits: false;
+      }
+    "#,
+      ParserError::UnexpectedToken(crate::properties::custom::Token::Ident("property-name".into())),
     );
 
-    dep_error_test(
-      ".foo { --test: url(./foo.png) }",
-      PrinterErrorKind::AmbiguousUrlInCustomProperty {
-        url: "./foo.png".into(),
-      },
+    minify_test(
+      r#"
+      @property --property-name {
+        syntax: 'custom | <color>';
+        inherits: false;
+        initial-value: yellow;
+      }
+    "#,
+      "@property --property-name{syntax:\"custom|<color>\";inherits:false;initial-value:#ff0}",
     );
 
-    dep_test(
-      ".foo { behavior: url(#foo) }",
-      ".foo{behavior:url(\"Zn9-2q\")}",
-      vec![("#foo", "Zn9-2q")],
+    // TODO: Re-enable with a better solution
+    //       See: https://github.com/parcel-bundler/lightningcss/issues/288
+    // minify_test(r#"
+    //   @property --property-name {
+    //     syntax: '<transform-list>';
+    //     inherits: false;
+    //     initial-value: translate(200px,300px) translate(100px,200px) scale(2);
+    //   }
+    // "#, "@property --property-name{syntax:\"<transform-list>\";inherits:false;initial-value:matrix(2,0,0,2,300,500)}");
+
+    minify_test(
+      r#"
+      @property --property-name {
+        syntax: '<time>';
+        inherits: false;
+        initial-value: 1000ms;
+      }
+    "#,
+      "@property --property-name{syntax:\"<time>\";inherits:false;initial-value:1s}",
     );
 
-    dep_test(
-      ".foo { --foo: url(#foo) }",
-      ".foo{--foo:url(\"Zn9-2q\")}",
-      vec![("#foo", "Zn9-2q")],
+    minify_test(
+      r#"
+      @property --property-name {
+        syntax: '<url>';
+        inherits: false;
+        initial-value: url("foo.png");
+      }
+    "#,
+      "@property --property-name{syntax:\"<url>\";inherits:false;initial-value:url(foo.png)}",
     );
 
-    dep_test(
-      "@import \"test.css\"; .foo { color: red }",
-      "@import \"hHsogW\";.foo{color:red}",
-      vec![("test.css", "hHsogW")],
+    minify_test(
+      r#"
+      @property --property-name {
+        syntax: '<image>';
+        inherits: false;
+        initial-value: linear-gradient(yellow, blue);
+      }
+    "#,
+      "@property --property-name{syntax:\"<image>\";inherits:false;initial-value:linear-gradient(#ff0,#00f)}",
     );
-  }
 
-  #[test]
-  fn test_api() {
-    let stylesheet = StyleSheet::parse(".foo:hover { color: red }", ParserOptions::default()).unwrap();
-    match &stylesheet.rules.0[0] {
-      CssRule::Style(s) => {
-        assert_eq!(&s.selectors.to_string(), ".foo:hover");
+    minify_test(
+      r#"
+      @property --property-name {
+        initial-value: linear-gradient(yellow, blue);
+        inherits: false;
+        syntax: '<image>';
       }
-      _ => unreachable!(),
-    }
-
-    let color = CssColor::parse_string("#f0f").unwrap();
-    assert_eq!(color.to_css_string(PrinterOptions::default()).unwrap(), "#f0f");
+    "#,
+      "@property --property-name{syntax:\"<image>\";inherits:false;initial-value:linear-gradient(#ff0,#00f)}",
+    );
 
-    let rule = CssRule::parse_string(".foo { color: red }", ParserOptions::default()).unwrap();
-    assert_eq!(
-      rule.to_css_string(PrinterOptions::default()).unwrap(),
+    test(
+      r#"
+      @property --property-name {
+        syntax: '<length>|none';
+        inherits: false;
+        initial-value: none;
+      }
+    "#,
       indoc! {r#"
-    .foo {
-      color: red;
-    }"#}
+      @property --property-name {
+        syntax: "<length> | none";
+        inherits: false;
+        initial-value: none;
+      }
+    "#},
     );
 
-    let property = Property::parse_string("color".into(), "#f0f", ParserOptions::default()).unwrap();
-    assert_eq!(
-      property.to_css_string(false, PrinterOptions::default()).unwrap(),
-      "color: #f0f"
-    );
-    assert_eq!(
-      property.to_css_string(true, PrinterOptions::default()).unwrap(),
-      "color: #f0f !important"
+    minify_test(
+      r#"
+      @property --property-name {
+        syntax: '<color>#';
+        inherits: false;
+        initial-value: yellow, blue;
+      }
+    "#,
+      "@property --property-name{syntax:\"<color>#\";inherits:false;initial-value:#ff0,#00f}",
     );
-
-    let code = indoc! { r#"
-      .foo {
-        color: green;
+    minify_test(
+      r#"
+      @property --property-name {
+        syntax: '<color>+';
+        inherits: false;
+        initial-value: yellow blue;
       }
-
-      .bar {
-        color: red;
-        background: pink;
+    "#,
+      "@property --property-name{syntax:\"<color>+\";inherits:false;initial-value:#ff0 #00f}",
+    );
+    minify_test(
+      r#"
+      @property --property-name {
+        syntax: '<color>';
+        inherits: false;
+        initial-value: yellow;
       }
-
-      @media print {
-        .baz {
-          color: green;
-        }
+      .foo {
+        color: var(--property-name)
       }
-    "#};
-    let stylesheet = StyleSheet::parse(code, ParserOptions::default()).unwrap();
-    if let CssRule::Style(style) = &stylesheet.rules.0[1] {
-      let (key, val) = style.property_location(code, 0).unwrap();
-      assert_eq!(
-        key,
-        SourceLocation { line: 5, column: 3 }..SourceLocation { line: 5, column: 8 }
-      );
-      assert_eq!(
-        val,
-        SourceLocation { line: 5, column: 10 }..SourceLocation { line: 5, column: 13 }
-      );
-    }
-
-    if let CssRule::Style(style) = &stylesheet.rules.0[1] {
-      let (key, val) = style.property_location(code, 1).unwrap();
-      assert_eq!(
-        key,
-        SourceLocation { line: 6, column: 3 }..SourceLocation { line: 6, column: 13 }
-      );
-      assert_eq!(
-        val,
-        SourceLocation { line: 6, column: 15 }..SourceLocation { line: 6, column: 19 }
-      );
-    }
-    if let CssRule::Media(media) = &stylesheet.rules.0[2] {
-      if let CssRule::Style(style) = &media.rules.0[0] {
-        let (key, val) = style.property_location(code, 0).unwrap();
-        assert_eq!(
-          key,
-          SourceLocation { line: 11, column: 5 }..SourceLocation { line: 11, column: 10 }
-        );
-        assert_eq!(
-          val,
-          SourceLocation { line: 11, column: 12 }..SourceLocation { line: 11, column: 17 }
-        );
+      @property --property-name {
+        syntax: '<color>';
+        inherits: true;
+        initial-value: blue;
       }
-    }
+    "#,
+      "@property --property-name{syntax:\"<color>\";inherits:true;initial-value:#00f}.foo{color:var(--property-name)}",
+    );
+  }
 
-    let mut property = Property::Transform(Default::default(), VendorPrefix::WebKit);
-    property.set_prefix(VendorPrefix::None);
-    assert_eq!(property, Property::Transform(Default::default(), VendorPrefix::None));
-    property.set_prefix(VendorPrefix::Moz);
-    assert_eq!(property, Property::Transform(Default::default(), VendorPrefix::Moz));
-    property.set_prefix(VendorPrefix::WebKit | VendorPrefix::Moz);
-    assert_eq!(
-      property,
-      Property::Transform(Default::default(), VendorPrefix::WebKit | VendorPrefix::Moz)
+  #[test]
+  fn test_quoting_unquoting_urls() {
+    // Quotes remain double quotes when not minifying
+    test(
+      r#".foo {
+      background-image: url("0123abcd");
+    }"#,
+      r#".foo {
+  background-image: url("0123abcd");
+}
+"#,
     );
 
-    let mut property = Property::TextDecorationLine(Default::default(), VendorPrefix::None);
-    property.set_prefix(VendorPrefix::Ms);
-    assert_eq!(
-      property,
-      Property::TextDecorationLine(Default::default(), VendorPrefix::None)
+    // Quotes removed when minifying
+    minify_test(
+      r#".foo {
+      background-image: url("0123abcd");
+    }"#,
+      r#".foo{background-image:url(0123abcd)}"#,
     );
-    property.set_prefix(VendorPrefix::WebKit | VendorPrefix::Moz | VendorPrefix::Ms);
-    assert_eq!(
-      property,
-      Property::TextDecorationLine(Default::default(), VendorPrefix::WebKit | VendorPrefix::Moz)
+
+    // Doubles quotes added if not present when not minifying
+    test(
+      r#".foo {
+      background-image: url(0123abcd);
+    }"#,
+      r#".foo {
+  background-image: url("0123abcd");
+}
+"#,
     );
 
-    let mut property = Property::AccentColor(Default::default());
-    property.set_prefix(VendorPrefix::WebKit);
-    assert_eq!(property, Property::AccentColor(Default::default()));
+    // No quotes changed if not present when not minifying
+    minify_test(
+      r#".foo {
+      background-image: url(0123abcd);
+    }"#,
+      r#".foo{background-image:url(0123abcd)}"#,
+    );
   }
 
-  #[cfg(feature = "substitute_variables")]
   #[test]
-  fn test_substitute_vars() {
-    use crate::properties::custom::TokenList;
-    use crate::traits::ParseWithOptions;
+  fn test_zindex() {
+    minify_test(".foo { z-index: 2 }", ".foo{z-index:2}");
+    minify_test(".foo { z-index: -2 }", ".foo{z-index:-2}");
+    minify_test(".foo { z-index: 999999 }", ".foo{z-index:999999}");
+    minify_test(".foo { z-index: 9999999 }", ".foo{z-index:9999999}");
+    minify_test(".foo { z-index: -9999999 }", ".foo{z-index:-9999999}");
+    minify_test(".foo { z-index: calc(1 + 2) }", ".foo{z-index:3}");
+    minify_test(".foo { z-index: calc(2.5) }", ".foo{z-index:3}");
+    minify_test(".foo { z-index: calc(-2.5) }", ".foo{z-index:-2}");
+    minify_test(".foo { z-index: calc(10 / 3) }", ".foo{z-index:3}");
+    minify_test(".foo { z-index: max(1, 5) }", ".foo{z-index:5}");
+    minify_test(".foo { z-index: min(1, 5) }", ".foo{z-index:1}");
+    minify_test(".foo { z-index: clamp(1, 10, 5) }", ".foo{z-index:5}");
+    minify_test(".foo { z-index: clamp(1, -2, 5) }", ".foo{z-index:1}");
+    minify_test(".foo { z-index: max(1.5, 2.4) }", ".foo{z-index:2}");
+    minify_test(".foo { opacity: max(0.5, 0.8) }", ".foo{opacity:.8}");
+  }
 
-    fn test(property: Property, vars: HashMap<&str, &str>, expected: &str) {
-      if let Property::Unparsed(unparsed) = property {
-        let vars = vars
-          .into_iter()
-          .map(|(k, v)| {
-            (
-              k,
-              TokenList::parse_string_with_options(v, ParserOptions::default()).unwrap(),
-            )
-          })
-          .collect();
-        let substituted = unparsed.substitute_variables(&vars).unwrap();
-        assert_eq!(
-          substituted.to_css_string(false, PrinterOptions::default()).unwrap(),
-          expected
-        );
-      } else {
-        panic!("Not an unparsed property");
-      }
+  #[test]
+  #[cfg(feature = "sourcemap")]
+  fn test_input_source_map() {
+    let source = r#".imported {
+      content: "yay, file support!";
     }
 
-    let property = Property::parse_string("color".into(), "var(--test)", ParserOptions::default()).unwrap();
-    test(property, HashMap::from([("--test", "yellow")]), "color: #ff0");
-
-    let property =
-      Property::parse_string("color".into(), "var(--test, var(--foo))", ParserOptions::default()).unwrap();
-    test(property, HashMap::from([("--foo", "yellow")]), "color: #ff0");
-    let property = Property::parse_string(
-      "color".into(),
-      "var(--test, var(--foo, yellow))",
-      ParserOptions::default(),
-    )
-    .unwrap();
-    test(property, HashMap::new(), "color: #ff0");
+    .selector {
+      margin: 1em;
+      background-color: #f60;
+    }
 
-    let property =
-      Property::parse_string("width".into(), "calc(var(--a) + var(--b))", ParserOptions::default()).unwrap();
-    test(property, HashMap::from([("--a", "2px"), ("--b", "4px")]), "width: 6px");
+    .selector .nested {
+      margin: 0.5em;
+    }
 
-    let property = Property::parse_string("color".into(), "var(--a)", ParserOptions::default()).unwrap();
-    test(
-      property,
-      HashMap::from([("--a", "var(--b)"), ("--b", "yellow")]),
-      "color: #ff0",
-    );
+    /*# sourceMappingURL=data:application/json;base64,ewoJInZlcnNpb24iOiAzLAoJInNvdXJjZVJvb3QiOiAicm9vdCIsCgkiZmlsZSI6ICJzdGRvdXQiLAoJInNvdXJjZXMiOiBbCgkJInN0ZGluIiwKCQkic2Fzcy9fdmFyaWFibGVzLnNjc3MiLAoJCSJzYXNzL19kZW1vLnNjc3MiCgldLAoJInNvdXJjZXNDb250ZW50IjogWwoJCSJAaW1wb3J0IFwiX3ZhcmlhYmxlc1wiO1xuQGltcG9ydCBcIl9kZW1vXCI7XG5cbi5zZWxlY3RvciB7XG4gIG1hcmdpbjogJHNpemU7XG4gIGJhY2tncm91bmQtY29sb3I6ICRicmFuZENvbG9yO1xuXG4gIC5uZXN0ZWQge1xuICAgIG1hcmdpbjogJHNpemUgLyAyO1xuICB9XG59IiwKCQkiJGJyYW5kQ29sb3I6ICNmNjA7XG4kc2l6ZTogMWVtOyIsCgkJIi5pbXBvcnRlZCB7XG4gIGNvbnRlbnQ6IFwieWF5LCBmaWxlIHN1cHBvcnQhXCI7XG59IgoJXSwKCSJtYXBwaW5ncyI6ICJBRUFBLFNBQVMsQ0FBQztFQUNSLE9BQU8sRUFBRSxvQkFBcUI7Q0FDL0I7O0FGQ0QsU0FBUyxDQUFDO0VBQ1IsTUFBTSxFQ0hELEdBQUc7RURJUixnQkFBZ0IsRUNMTCxJQUFJO0NEVWhCOztBQVBELFNBQVMsQ0FJUCxPQUFPLENBQUM7RUFDTixNQUFNLEVDUEgsS0FBRztDRFFQIiwKCSJuYW1lcyI6IFtdCn0= */"#;
 
-    let property = Property::parse_string("color".into(), "var(--a)", ParserOptions::default()).unwrap();
-    test(
-      property,
-      HashMap::from([("--a", "var(--b)"), ("--b", "var(--c)"), ("--c", "var(--a)")]),
-      "color: var(--a)",
+    let mut stylesheet = StyleSheet::parse(&source, ParserOptions::default()).unwrap();
+    stylesheet.minify(MinifyOptions::default()).unwrap();
+    let mut sm = parcel_sourcemap::SourceMap::new("/");
+    stylesheet
+      .to_css(PrinterOptions {
+        source_map: Some(&mut sm),
+        minify: true,
+        ..PrinterOptions::default()
+      })
+      .unwrap();
+    let map = sm.to_json(None).unwrap();
+    assert_eq!(
+      map,
+      r#"{"version":3,"sourceRoot":null,"mappings":"AAAA,uCCGA,2CAAA","sources":["sass/_demo.scss","stdin"],"sourcesContent":[".imported {\n  content: \"yay, file support!\";\n}","@import \"_variables\";\n@import \"_demo\";\n\n.selector {\n  margin: $size;\n  background-color: $brandColor;\n\n  .nested {\n    margin: $size / 2;\n  }\n}"],"names":[]}"#
     );
   }
 
   #[test]
-  fn test_layer() {
-    minify_test("@layer foo;", "@layer foo;");
-    minify_test("@layer foo, bar;", "@layer foo,bar;");
-    minify_test("@layer foo.bar;", "@layer foo.bar;");
-    minify_test("@layer foo.bar, baz;", "@layer foo.bar,baz;");
-
-    minify_test(
+  fn test_error_recovery() {
+    use std::sync::{Arc, RwLock};
+    let warnings = Some(Arc::new(RwLock::new(Vec::new())));
+    test_with_options(
       r#"
-      @layer foo {
-        .bar {
-          color: red;
-        }
+      h1(>h1) {
+        color: red;
       }
-    "#,
-      "@layer foo{.bar{color:red}}",
-    );
-    minify_test(
-      r#"
-      @layer foo.bar {
-        .bar {
-          color: red;
-        }
+
+      .foo {
+        color: red;
       }
-    "#,
-      "@layer foo.bar{.bar{color:red}}",
-    );
-    minify_test(r#"
-      @layer base {
-        p { max-width: 70ch; }
+
+      .clearfix {
+        *zoom: 1;
+        background: red;
       }
 
-      @layer framework {
-        @layer base {
-          p { margin-block: 0.75em; }
+      @media (hover) {
+        h1(>h1) {
+          color: red;
         }
 
-        @layer theme {
-          p { color: #222; }
-        }
-      }
-    "#, "@layer base{p{max-width:70ch}}@layer framework{@layer base{p{margin-block:.75em}}@layer theme{p{color:#222}}}");
-    minify_test(
-      r#"
-      @layer {
         .bar {
           color: red;
         }
       }
     "#,
-      "@layer{.bar{color:red}}",
-    );
-    minify_test(
-      r#"
-      @layer foo\20 bar, baz;
-    "#,
-      "@layer foo\\ bar,baz;",
-    );
-    minify_test(
-      r#"
-      @layer one.two\20 three\#four\.five {
+      indoc! { r#"
+      .foo {
+        color: red;
+      }
+
+      .clearfix {
+        background: red;
+      }
+
+      @media (hover) {
         .bar {
           color: red;
         }
       }
-    "#,
-      "@layer one.two\\ three\\#four\\.five{.bar{color:red}}",
+    "#},
+      ParserOptions {
+        filename: "test.css".into(),
+        error_recovery: true,
+        warnings: warnings.clone(),
+        ..ParserOptions::default()
+      },
     );
+    let w = warnings.unwrap();
+    let warnings = w.read().unwrap();
+    assert_eq!(
+      *warnings,
+      vec![
+        Error {
+          kind: ParserError::SelectorError(SelectorError::EmptySelector),
+          loc: Some(ErrorLocation {
+            filename: "test.css".into(),
+            line: 1,
+            column: 7
+          })
+        },
+        Error {
+          kind: ParserError::UnexpectedToken(Token::Semicolon),
+          loc: Some(ErrorLocation {
+            filename: "test.css".into(),
+            line: 10,
+            column: 17
+          })
+        },
+        Error {
+          kind: ParserError::SelectorError(SelectorError::EmptySelector),
+          loc: Some(ErrorLocation {
+            filename: "test.css".into(),
+            line: 15,
+            column: 9
+          })
+        },
+      ]
+    )
+  }
 
-    error_test("@layer;", ParserError::UnexpectedToken(Token::Semicolon));
-    error_test("@layer foo, bar {};", ParserError::AtRuleBodyInvalid);
-    minify_test("@import 'test.css' layer;", "@import \"test.css\" layer;");
-    minify_test("@import 'test.css' layer(foo);", "@import \"test.css\" layer(foo);");
-    minify_test(
-      "@import 'test.css' layer(foo.bar);",
-      "@import \"test.css\" layer(foo.bar);",
+  #[test]
+  fn test_invalid() {
+    error_test(
+      ".a{color: hsla(120, 62.32%;}",
+      ParserError::UnexpectedToken(Token::CloseCurlyBracket),
     );
-    minify_test(
-      "@import 'test.css' layer(foo\\20 bar);",
-      "@import \"test.css\" layer(foo\\ bar);",
+    error_test(
+      ".a{color: var(--foo) )}",
+      ParserError::UnexpectedToken(Token::CloseParenthesis),
     );
     error_test(
-      "@import 'test.css' layer(foo, bar) {};",
-      ParserError::UnexpectedToken(Token::Comma),
+      ".a{--foo: url(foo\\) b\\)ar)}",
+      ParserError::UnexpectedToken(Token::BadUrl("foo\\) b\\)ar".into())),
     );
+  }
+
+  #[test]
+  fn test_container_queries() {
+    // with name
     minify_test(
       r#"
-      @layer one {
-        body {
-          background: red;
-        }
-      }
-
-      body {
-        background: red;
-      }
-
-      @layer two {
-        body {
-          background: green;
+      @container my-layout (inline-size > 45em) {
+        .foo {
+          color: red;
         }
       }
+    "#,
+      "@container my-layout (inline-size>45em){.foo{color:red}}",
+    );
 
-      @layer one {
-        body {
-          background: yellow;
+    minify_test(
+      r#"
+      @container my-layout ( not (width > 500px) ) {
+        .foo {
+          color: red;
         }
       }
-      "#,
-      "@layer one{body{background:#ff0}}body{background:red}@layer two{body{background:green}}",
+    "#,
+      "@container my-layout not (width>500px){.foo{color:red}}",
     );
-  }
 
-  #[test]
-  fn test_property() {
     minify_test(
       r#"
-      @property --property-name {
-        syntax: '<color>';
-        inherits: false;
-        initial-value: yellow;
+      @container my-layout not (width > 500px) {
+        .foo {
+          color: red;
+        }
       }
     "#,
-      "@property --property-name{syntax:\"<color>\";inherits:false;initial-value:#ff0}",
+      "@container my-layout not (width>500px){.foo{color:red}}",
     );
 
-    test(
+    minify_test(
       r#"
-      @property --property-name {
-        syntax: '*';
-        inherits: false;
-        initial-value: ;
+      @container not (width > 500px) {
+        .foo {
+          color: red;
+        }
       }
     "#,
-      indoc! {r#"
-      @property --property-name {
-        syntax: "*";
-        inherits: false;
-        initial-value: ;
-      }
-    "#},
+      "@container not (width>500px){.foo{color:red}}",
     );
 
     minify_test(
       r#"
-      @property --property-name {
-        syntax: '*';
-        inherits: false;
-        initial-value: ;
+      @container (inline-size > calc(20cqi + 20cqi)) {
+        .foo {
+          width: calc(50cqw - 1cqw);
+        }
       }
     "#,
-      "@property --property-name{syntax:\"*\";inherits:false;initial-value:}",
+      "@container (inline-size>40cqi){.foo{width:49cqw}}",
     );
 
-    test(
+    minify_test(
       r#"
-      @property --property-name {
-        syntax: '*';
-        inherits: false;
-        initial-value:;
-      }
-    "#,
-      indoc! {r#"
-      @property --property-name {
-        syntax: "*";
-        inherits: false;
-        initial-value: ;
+      @container my-layout ((width: 100px) and (not (height: 100px))) {
+        .foo {
+          color: red;
+        }
       }
-    "#},
+    "#,
+      "@container my-layout (width:100px) and (not (height:100px)){.foo{color:red}}",
     );
 
     minify_test(
       r#"
-      @property --property-name {
-        syntax: '*';
-        inherits: false;
-        initial-value:;
+      @container my-layout (width = max(10px, 10em)) {
+        .foo {
+          color: red;
+        }
       }
     "#,
-      "@property --property-name{syntax:\"*\";inherits:false;initial-value:}",
+      "@container my-layout (width=max(10px,10em)){.foo{color:red}}",
     );
+
+    // without name
     minify_test(
       r#"
-      @property --property-name {
-        syntax: '*';
-        inherits: false;
-        initial-value: foo bar;
+      @container (inline-size > 45em) {
+        .foo {
+          color: red;
+        }
       }
     "#,
-      "@property --property-name{syntax:\"*\";inherits:false;initial-value:foo bar}",
+      "@container (inline-size>45em){.foo{color:red}}",
     );
 
     minify_test(
       r#"
-      @property --property-name {
-        syntax: '<length>';
-        inherits: true;
-        initial-value: 25px;
+      @container (inline-size > 45em) and (inline-size < 100em) {
+        .foo {
+          color: red;
+        }
       }
     "#,
-      "@property --property-name{syntax:\"<length>\";inherits:true;initial-value:25px}",
+      "@container (inline-size>45em) and (inline-size<100em){.foo{color:red}}",
     );
 
-    error_test(
+    // calc()
+    minify_test(
       r#"
-      @property --property-name {
-        syntax: '<color>';
-        inherits: false;
-        initial-value: 25px;
+      @container (width > calc(100vw - 50px)) {
+        .foo {
+          color: red;
+        }
       }
     "#,
-      ParserError::UnexpectedToken(crate::properties::custom::Token::Dimension {
-        has_sign: false,
-        value: 25.0,
-        int_value: Some(25),
-        unit: "px".into(),
-      }),
+      "@container (width>calc(100vw - 50px)){.foo{color:red}}",
     );
 
-    error_test(
+    minify_test(
       r#"
-      @property --property-name {
-        syntax: '<length>';
-        inherits: false;
-        initial-value: var(--some-value);
+      @container (calc(100vh - 50px) <= height ) {
+        .foo {
+          color: red;
+        }
       }
     "#,
-      ParserError::UnexpectedToken(crate::properties::custom::Token::Function("var".into())),
+      "@container (height>=calc(100vh - 50px)){.foo{color:red}}",
     );
 
-    error_test(
+    // merge adjacent
+    minify_test(
       r#"
-      @property --property-name {
-        syntax: '<color>';
-        inherits: false;
+      @container my-layout (inline-size > 45em) {
+        .foo {
+          color: red;
+        }
+      }
+
+      @container my-layout (inline-size > 45em) {
+        .foo {
+          background: yellow;
+        }
+
+        .bar {
+          color: white;
+        }
       }
     "#,
-      ParserError::AtRuleBodyInvalid,
+      "@container my-layout (inline-size>45em){.foo{color:red;background:#ff0}.bar{color:#fff}}",
     );
 
     minify_test(
       r#"
-      @property --property-name {
-        syntax: '*';
-        inherits: false;
-      }
+    .foo {
+      container-name: foo bar;
+      container-type: size;
+    }
     "#,
-      "@property --property-name{syntax:\"*\";inherits:false}",
+      ".foo{container:foo bar/size}",
     );
-
-    error_test(
+    minify_test(
       r#"
-      @property --property-name {
-        syntax: '*';
-      }
+    .foo {
+      container-name: foo bar;
+      container-type: normal;
+    }
     "#,
-      ParserError::AtRuleBodyInvalid,
+      ".foo{container:foo bar}",
+    );
+    minify_test(
+      ".foo{ container-type: inline-size }",
+      ".foo{container-type:inline-size}",
+    );
+    minify_test(".foo{ container-name: none; }", ".foo{container-name:none}");
+    minify_test(".foo{ container-name: foo; }", ".foo{container-name:foo}");
+    minify_test(".foo{ container: foo / normal; }", ".foo{container:foo}");
+    minify_test(
+      ".foo{ container: foo / inline-size; }",
+      ".foo{container:foo/inline-size}",
     );
+    minify_test(".foo { width: calc(1cqw + 2cqw) }", ".foo{width:3cqw}");
+    minify_test(".foo { width: calc(1cqh + 2cqh) }", ".foo{width:3cqh}");
+    minify_test(".foo { width: calc(1cqi + 2cqi) }", ".foo{width:3cqi}");
+    minify_test(".foo { width: calc(1cqb + 2cqb) }", ".foo{width:3cqb}");
+    minify_test(".foo { width: calc(1cqmin + 2cqmin) }", ".foo{width:3cqmin}");
+    minify_test(".foo { width: calc(1cqmax + 2cqmax) }", ".foo{width:3cqmax}");
 
-    error_test(
+    // Unlike in @media, there is no need to convert the range syntax in @container,
+    // because browsers all support this syntax.
+    prefix_test(
       r#"
-      @property --property-name {
-        inherits: false;
+      @container (width > 100px) {
+        .foo { padding: 5px; }
       }
-    "#,
-      ParserError::AtRuleBodyInvalid,
+      "#,
+      indoc! { r#"
+        @container (width > 100px) {
+          .foo {
+            padding: 5px;
+          }
+        }
+      "#},
+      Browsers {
+        chrome: Some(105 << 16),
+        ..Browsers::default()
+      },
     );
-
-    error_test(
+    prefix_test(
       r#"
-      @property property-name {
-        syntax: '*';
-        inherits: false;
+      @container (min-width: 100px) {
+        .foo { padding: 5px; }
       }
-    "#,
-      ParserError::UnexpectedToken(crate::properties::custom::Token::Ident("property-name".into())),
+      "#,
+      indoc! { r#"
+        @container (width >= 100px) {
+          .foo {
+            padding: 5px;
+          }
+        }
+      "#},
+      Browsers {
+        chrome: Some(105 << 16),
+        ..Browsers::default()
+      },
     );
 
     minify_test(
       r#"
-      @property --property-name {
-        syntax: 'custom | <color>';
-        inherits: false;
-        initial-value: yellow;
+      @container style(--responsive: true) {
+        .foo {
+          color: red;
+        }
       }
     "#,
-      "@property --property-name{syntax:\"custom|<color>\";inherits:false;initial-value:#ff0}",
+      "@container style(--responsive:true){.foo{color:red}}",
     );
-
-    // TODO: Re-enable with a better solution
-    //       See: https://github.com/parcel-bundler/lightningcss/issues/288
-    // minify_test(r#"
-    //   @property --property-name {
-    //     syntax: '<transform-list>';
-    //     inherits: false;
-    //     initial-value: translate(200px,300px) translate(100px,200px) scale(2);
-    //   }
-    // "#, "@property --property-name{syntax:\"<transform-list>\";inherits:false;initial-value:matrix(2,0,0,2,300,500)}");
-
     minify_test(
       r#"
-      @property --property-name {
-        syntax: '<time>';
-        inherits: false;
-        initial-value: 1000ms;
+      @container style(--responsive: true) and style(color: yellow) {
+        .foo {
+          color: red;
+        }
       }
     "#,
-      "@property --property-name{syntax:\"<time>\";inherits:false;initial-value:1s}",
+      "@container style(--responsive:true) and style(color:#ff0){.foo{color:red}}",
     );
-
     minify_test(
       r#"
-      @property --property-name {
-        syntax: '<url>';
-        inherits: false;
-        initial-value: url("foo.png");
+      @container not style(--responsive: true) {
+        .foo {
+          color: red;
+        }
       }
     "#,
-      "@property --property-name{syntax:\"<url>\";inherits:false;initial-value:url(foo.png)}",
+      "@container not style(--responsive:true){.foo{color:red}}",
     );
-
     minify_test(
       r#"
-      @property --property-name {
-        syntax: '<image>';
-        inherits: false;
-        initial-value: linear-gradient(yellow, blue);
+      @container (inline-size > 45em) and style(--responsive: true) {
+        .foo {
+          color: red;
+        }
       }
     "#,
-      "@property --property-name{syntax:\"<image>\";inherits:false;initial-value:linear-gradient(#ff0,#00f)}",
+      "@container (inline-size>45em) and style(--responsive:true){.foo{color:red}}",
     );
-
     minify_test(
       r#"
-      @property --property-name {
-        initial-value: linear-gradient(yellow, blue);
-        inherits: false;
-        syntax: '<image>';
+      @container style((accent-color: yellow) or (--bar: 10px)) {
+        .foo {
+          color: red;
+        }
       }
     "#,
-      "@property --property-name{syntax:\"<image>\";inherits:false;initial-value:linear-gradient(#ff0,#00f)}",
+      "@container style((accent-color:#ff0) or (--bar:10px)){.foo{color:red}}",
     );
-
-    test(
+    minify_test(
       r#"
-      @property --property-name {
-        syntax: '<length>|none';
-        inherits: false;
-        initial-value: none;
+      @container style(not ((width: calc(10px + 20px)) and ((--bar: url(x))))) {
+        .foo {
+          color: red;
+        }
       }
     "#,
-      indoc! {r#"
-      @property --property-name {
-        syntax: "<length> | none";
-        inherits: false;
-        initial-value: none;
+      "@container style(not ((width:30px) and (--bar:url(x)))){.foo{color:red}}",
+    );
+    minify_test(
+      r#"
+      @container style(color: yellow !important) {
+        .foo {
+          color: red;
+        }
       }
-    "#},
+    "#,
+      "@container style(color:yellow){.foo{color:red}}",
     );
-
     minify_test(
       r#"
-      @property --property-name {
-        syntax: '<color>#';
-        inherits: false;
-        initial-value: yellow, blue;
+      @container style(--foo:) {
+        .foo {
+          color: red;
+        }
       }
     "#,
-      "@property --property-name{syntax:\"<color>#\";inherits:false;initial-value:#ff0,#00f}",
+      "@container style(--foo:){.foo{color:red}}",
     );
     minify_test(
       r#"
-      @property --property-name {
-        syntax: '<color>+';
-        inherits: false;
-        initial-value: yellow blue;
+      @container style(--foo: ) {
+        .foo {
+          color: red;
+        }
       }
     "#,
-      "@property --property-name{syntax:\"<color>+\";inherits:false;initial-value:#ff0 #00f}",
+      "@container style(--foo:){.foo{color:red}}",
     );
     minify_test(
       r#"
-      @property --property-name {
-        syntax: '<color>';
-        inherits: false;
-        initial-value: yellow;
-      }
-      .foo {
-        color: var(--property-name)
-      }
-      @property --property-name {
-        syntax: '<color>';
-        inherits: true;
-        initial-value: blue;
+      @container style(--my-prop: foo - bar ()) {
+        .foo {
+          color: red;
+        }
       }
     "#,
-      "@property --property-name{syntax:\"<color>\";inherits:true;initial-value:#00f}.foo{color:var(--property-name)}",
+      "@container style(--my-prop:foo - bar ()){.foo{color:red}}",
     );
-  }
 
-  #[test]
-  fn test_quoting_unquoting_urls() {
-    // Quotes remain double quotes when not minifying
-    test(
-      r#".foo {
-      background-image: url("0123abcd");
-    }"#,
-      r#".foo {
-  background-image: url("0123abcd");
-}
-"#,
+    // Disallow 'none', 'not', 'and', 'or' as a `<container-name>`
+    // https://github.com/w3c/csswg-drafts/issues/7203#issuecomment-1144257312
+    // https://chromium-review.googlesource.com/c/chromium/src/+/3698402
+    error_test(
+      "@container none (width < 100vw) {}",
+      ParserError::UnexpectedToken(crate::properties::custom::Token::Ident("none".into())),
     );
 
-    // Quotes removed when minifying
-    minify_test(
-      r#".foo {
-      background-image: url("0123abcd");
-    }"#,
-      r#".foo{background-image:url(0123abcd)}"#,
+    error_test(
+      "@container and (width < 100vw) {}",
+      ParserError::UnexpectedToken(crate::properties::custom::Token::Ident("and".into())),
     );
 
-    // Doubles quotes added if not present when not minifying
-    test(
-      r#".foo {
-      background-image: url(0123abcd);
-    }"#,
-      r#".foo {
-  background-image: url("0123abcd");
-}
-"#,
+    error_test(
+      "@container or (width < 100vw) {}",
+      ParserError::UnexpectedToken(crate::properties::custom::Token::Ident("or".into())),
     );
 
-    // No quotes changed if not present when not minifying
-    minify_test(
-      r#".foo {
-      background-image: url(0123abcd);
-    }"#,
-      r#".foo{background-image:url(0123abcd)}"#,
+    // Disallow CSS wide keywords as a `<container-name>`
+    error_test(
+      "@container revert-layer (width < 100vw) {}",
+      ParserError::UnexpectedToken(crate::properties::custom::Token::Ident("revert-layer".into())),
     );
-  }
 
-  #[test]
-  fn test_zindex() {
-    minify_test(".foo { z-index: 2 }", ".foo{z-index:2}");
-    minify_test(".foo { z-index: -2 }", ".foo{z-index:-2}");
-    minify_test(".foo { z-index: 999999 }", ".foo{z-index:999999}");
-    minify_test(".foo { z-index: 9999999 }", ".foo{z-index:9999999}");
-    minify_test(".foo { z-index: -9999999 }", ".foo{z-index:-9999999}");
-  }
-
-  #[test]
-  #[cfg(feature = "sourcemap")]
-  fn test_input_source_map() {
-    let source = r#".imported {
-      content: "yay, file support!";
-    }
-
-    .selector {
-      margin: 1em;
-      background-color: #f60;
-    }
+    error_test(
+      "@container initial (width < 100vw) {}",
+      ParserError::UnexpectedToken(crate::properties::custom::Token::Ident("initial".into())),
+    );
 
-    .selector .nested {
-      margin: 0.5em;
-    }
+    // <ident> contains spaces
+    // https://github.com/web-platform-tests/wpt/blob/39f0da08fbbe33d0582a35749b6dbf8bd067a52d/css/css-contain/container-queries/at-container-parsing.html#L160-L178
+    error_test(
+      "@container foo bar (width < 100vw) {}",
+      ParserError::UnexpectedToken(crate::properties::custom::Token::Ident("bar".into())),
+    );
 
-    /*# sourceMappingURL=data:application/json;base64,ewoJInZlcnNpb24iOiAzLAoJInNvdXJjZVJvb3QiOiAicm9vdCIsCgkiZmlsZSI6ICJzdGRvdXQiLAoJInNvdXJjZXMiOiBbCgkJInN0ZGluIiwKCQkic2Fzcy9fdmFyaWFibGVzLnNjc3MiLAoJCSJzYXNzL19kZW1vLnNjc3MiCgldLAoJInNvdXJjZXNDb250ZW50IjogWwoJCSJAaW1wb3J0IFwiX3ZhcmlhYmxlc1wiO1xuQGltcG9ydCBcIl9kZW1vXCI7XG5cbi5zZWxlY3RvciB7XG4gIG1hcmdpbjogJHNpemU7XG4gIGJhY2tncm91bmQtY29sb3I6ICRicmFuZENvbG9yO1xuXG4gIC5uZXN0ZWQge1xuICAgIG1hcmdpbjogJHNpemUgLyAyO1xuICB9XG59IiwKCQkiJGJyYW5kQ29sb3I6ICNmNjA7XG4kc2l6ZTogMWVtOyIsCgkJIi5pbXBvcnRlZCB7XG4gIGNvbnRlbnQ6IFwieWF5LCBmaWxlIHN1cHBvcnQhXCI7XG59IgoJXSwKCSJtYXBwaW5ncyI6ICJBRUFBLFNBQVMsQ0FBQztFQUNSLE9BQU8sRUFBRSxvQkFBcUI7Q0FDL0I7O0FGQ0QsU0FBUyxDQUFDO0VBQ1IsTUFBTSxFQ0hELEdBQUc7RURJUixnQkFBZ0IsRUNMTCxJQUFJO0NEVWhCOztBQVBELFNBQVMsQ0FJUCxPQUFPLENBQUM7RUFDTixNQUFNLEVDUEgsS0FBRztDRFFQIiwKCSJuYW1lcyI6IFtdCn0= */"#;
+    error_test("@container (inline-size <= foo) {}", ParserError::InvalidMediaQuery);
+    error_test("@container (orientation <= 10px) {}", ParserError::InvalidMediaQuery);
 
-    let mut stylesheet = StyleSheet::parse(&source, ParserOptions::default()).unwrap();
-    stylesheet.minify(MinifyOptions::default()).unwrap();
-    let mut sm = parcel_sourcemap::SourceMap::new("/");
-    stylesheet
-      .to_css(PrinterOptions {
-        source_map: Some(&mut sm),
-        minify: true,
-        ..PrinterOptions::default()
-      })
-      .unwrap();
-    let map = sm.to_json(None).unwrap();
-    assert_eq!(
-      map,
-      r#"{"version":3,"sourceRoot":null,"mappings":"AAAA,uCCGA,2CAAA","sources":["sass/_demo.scss","stdin"],"sourcesContent":[".imported {\n  content: \"yay, file support!\";\n}","@import \"_variables\";\n@import \"_demo\";\n\n.selector {\n  margin: $size;\n  background-color: $brandColor;\n\n  .nested {\n    margin: $size / 2;\n  }\n}"],"names":[]}"#
+    error_test("@container style(width) {}", ParserError::EndOfInput);
+    error_test(
+      "@container style(style(--foo: bar)) {}",
+      ParserError::UnexpectedToken(crate::properties::custom::Token::Function("style".into())),
     );
   }
 
   #[test]
-  fn test_error_recovery() {
-    use std::sync::{Arc, RwLock};
-    let warnings = Some(Arc::new(RwLock::new(Vec::new())));
-    test_with_options(
-      r#"
-      h1(>h1) {
-        color: red;
-      }
-
-      .foo {
+  fn test_unknown_at_rules() {
+    minify_test("@foo;", "@foo;");
+    minify_test("@foo bar;", "@foo bar;");
+    minify_test("@foo (bar: baz);", "@foo (bar: baz);");
+    test(
+      r#"@foo test {
+      div {
         color: red;
       }
-
-      .clearfix {
-        *zoom: 1;
-        background: red;
-      }
-
-      @media (hover) {
-        h1(>h1) {
-          color: red;
-        }
-
-        .bar {
-          color: red;
-        }
+    }"#,
+      indoc! {r#"
+      @foo test {
+        div { color: red; }
       }
-    "#,
-      indoc! { r#"
-      .foo {
+      "#},
+    );
+    minify_test(
+      r#"@foo test {
+      div {
         color: red;
       }
-
-      .clearfix {
-        background: red;
-      }
-
-      @media (hover) {
-        .bar {
-          color: red;
-        }
-      }
-    "#},
-      ParserOptions {
-        filename: "test.css".into(),
-        error_recovery: true,
-        warnings: warnings.clone(),
-        ..ParserOptions::default()
-      },
+    }"#,
+      "@foo test{div { color: red; }}",
     );
-    let w = warnings.unwrap();
-    let warnings = w.read().unwrap();
-    assert_eq!(
-      *warnings,
-      vec![
-        Error {
-          kind: ParserError::SelectorError(SelectorError::EmptySelector),
-          loc: Some(ErrorLocation {
-            filename: "test.css".into(),
-            line: 1,
-            column: 7
-          })
-        },
-        Error {
-          kind: ParserError::UnexpectedToken(Token::Semicolon),
-          loc: Some(ErrorLocation {
-            filename: "test.css".into(),
-            line: 10,
-            column: 17
-          })
-        },
-        Error {
-          kind: ParserError::SelectorError(SelectorError::EmptySelector),
-          loc: Some(ErrorLocation {
-            filename: "test.css".into(),
-            line: 15,
-            column: 9
-          })
-        },
-      ]
-    )
-  }
-
-  #[test]
-  fn test_invalid() {
-    error_test(
-      ".a{color: hsla(120, 62.32%;}",
-      ParserError::UnexpectedToken(Token::CloseCurlyBracket),
+    minify_test(
+      r#"@foo test {
+        foo: bar;
+      }"#,
+      "@foo test{foo: bar;}",
+    );
+    test(
+      r#"@foo {
+        foo: bar;
+      }"#,
+      indoc! {r#"
+      @foo {
+        foo: bar;
+      }
+      "#},
     );
-    error_test(
-      ".a{--foo: url(foo\\) b\\)ar)}",
-      ParserError::UnexpectedToken(Token::BadUrl("foo\\) b\\)ar".into())),
+    minify_test(
+      r#"@foo {
+        foo: bar;
+      }"#,
+      "@foo{foo: bar;}",
     );
   }
 
   #[test]
-  fn test_container_queries() {
-    // with name
-    minify_test(
+  fn test_resolution() {
+    prefix_test(
       r#"
-      @container my-layout (inline-size > 45em) {
-        .foo {
-          color: red;
+      @media (resolution: 1dppx) {
+        body {
+          background: red;
         }
       }
-    "#,
-      "@container my-layout (inline-size>45em){.foo{color:red}}",
-    );
-
-    minify_test(
-      r#"
-      @container my-layout ( not (width > 500px) ) {
-        .foo {
-          color: red;
+      "#,
+      indoc! { r#"
+      @media (resolution: 1dppx) {
+        body {
+          background: red;
         }
       }
-    "#,
-      "@container my-layout not (width>500px){.foo{color:red}}",
+      "#},
+      Browsers {
+        chrome: Some(50 << 16),
+        ..Browsers::default()
+      },
     );
 
-    minify_test(
+    prefix_test(
       r#"
-      @container my-layout not (width > 500px) {
-        .foo {
-          color: red;
+      @media (resolution: 1dppx) {
+        body {
+          background: red;
         }
       }
-    "#,
-      "@container my-layout not (width>500px){.foo{color:red}}",
-    );
-
-    minify_test(
-      r#"
-      @container not (width > 500px) {
-        .foo {
-          color: red;
+      "#,
+      indoc! { r#"
+      @media (resolution: 1x) {
+        body {
+          background: red;
         }
       }
-    "#,
-      "@container not (width>500px){.foo{color:red}}",
+      "#},
+      Browsers {
+        chrome: Some(95 << 16),
+        ..Browsers::default()
+      },
     );
 
     minify_test(
-      r#"
-      @container my-layout ((width: 100px) and (not (height: 100px))) {
-        .foo {
-          color: red;
-        }
-      }
-    "#,
-      "@container my-layout (width:100px) and (not (height:100px)){.foo{color:red}}",
+      "@media (min-resolution: calc(1x + 1x)) { .foo { color: red }}",
+      "@media (resolution>=2x){.foo{color:red}}",
     );
-
     minify_test(
-      r#"
-      @container my-layout (width = max(10px, 10em)) {
-        .foo {
-          color: red;
-        }
-      }
-    "#,
-      "@container my-layout (width=max(10px,10em)){.foo{color:red}}",
+      "@media (min-resolution: min(3dppx, 96dpi)) { .foo { color: red }}",
+      "@media (resolution>=96dpi){.foo{color:red}}",
     );
-
-    // without name
     minify_test(
-      r#"
-      @container (inline-size > 45em) {
-        .foo {
-          color: red;
-        }
-      }
-    "#,
-      "@container (inline-size>45em){.foo{color:red}}",
+      "@media (resolution < clamp(1x, 3x, 2x)) { .foo { color: red }}",
+      "@media (resolution<2x){.foo{color:red}}",
+    );
+    minify_test(
+      "@media (min-resolution: max(1dpi, 2dpi)) { .foo { color: red }}",
+      "@media (resolution>=2dpi){.foo{color:red}}",
+    );
+    minify_test(
+      "@media (min-resolution: 192dpi) { .foo { color: red }}",
+      "@media (resolution>=192dpi){.foo{color:red}}",
+    );
+    minify_test(
+      "@media (min-resolution: 2dppx) { .foo { color: red }}",
+      "@media (resolution>=2x){.foo{color:red}}",
+    );
+    minify_test(
+      "@media (min-resolution: 5dpcm) { .foo { color: red }}",
+      "@media (resolution>=5dpcm){.foo{color:red}}",
+    );
+
+    fn minify_with_targets(source: &str, expected: &str, targets: Browsers) {
+      let mut stylesheet = StyleSheet::parse(&source, ParserOptions::default()).unwrap();
+      stylesheet
+        .minify(MinifyOptions {
+          targets: targets.into(),
+          ..MinifyOptions::default()
+        })
+        .unwrap();
+      let res = stylesheet
+        .to_css(PrinterOptions {
+          minify: true,
+          targets: targets.into(),
+          ..PrinterOptions::default()
+        })
+        .unwrap();
+      assert_eq!(res.code, expected);
+    }
+
+    let targets = Browsers {
+      chrome: Some(95 << 16),
+      ..Browsers::default()
+    };
+    minify_with_targets(
+      "@media (min-resolution: 192dpi) { .foo { color: red }}",
+      "@media (min-resolution:2x){.foo{color:red}}",
+      targets,
+    );
+    minify_with_targets(
+      "@media (min-resolution: 144dpi) { .foo { color: red }}",
+      "@media (min-resolution:1.5x){.foo{color:red}}",
+      targets,
     );
+    minify_with_targets(
+      "@media (min-resolution: 100dpi) { .foo { color: red }}",
+      "@media (min-resolution:100dpi){.foo{color:red}}",
+      targets,
+    );
+    minify_with_targets(
+      "@media (min-resolution: 192dpi) { .foo { color: red }}",
+      "@media (min-resolution:192dpi){.foo{color:red}}",
+      Browsers {
+        chrome: Some(50 << 16),
+        ..Browsers::default()
+      },
+    );
+  }
 
+  #[test]
+  fn test_environment() {
     minify_test(
       r#"
-      @container (inline-size > 45em) and (inline-size < 100em) {
-        .foo {
-          color: red;
+      @media (max-width: env(--branding-small)) {
+        body {
+          padding: env(--branding-padding);
         }
       }
     "#,
-      "@container (inline-size>45em) and (inline-size<100em){.foo{color:red}}",
+      "@media (width<=env(--branding-small)){body{padding:env(--branding-padding)}}",
     );
 
-    // calc()
     minify_test(
       r#"
-      @container (width > calc(100vw - 50px)) {
-        .foo {
-          color: red;
+      @media (max-width: env(--branding-small 1)) {
+        body {
+          padding: env(--branding-padding 2);
         }
       }
     "#,
-      "@container (width>calc(100vw - 50px)){.foo{color:red}}",
+      "@media (width<=env(--branding-small 1)){body{padding:env(--branding-padding 2)}}",
     );
 
     minify_test(
       r#"
-      @container (calc(100vh - 50px) <= height ) {
-        .foo {
-          color: red;
+      @media (max-width: env(--branding-small 1, 20px)) {
+        body {
+          padding: env(--branding-padding 2, 20px);
         }
       }
     "#,
-      "@container (height>=calc(100vh - 50px)){.foo{color:red}}",
+      "@media (width<=env(--branding-small 1,20px)){body{padding:env(--branding-padding 2,20px)}}",
     );
 
-    // merge adjacent
     minify_test(
       r#"
-      @container my-layout (inline-size > 45em) {
-        .foo {
-          color: red;
-        }
-      }
-
-      @container my-layout (inline-size > 45em) {
-        .foo {
-          background: yellow;
-        }
-
-        .bar {
-          color: white;
+      @media (max-width: env(safe-area-inset-top)) {
+        body {
+          padding: env(safe-area-inset-top);
         }
       }
     "#,
-      "@container my-layout (inline-size>45em){.foo{color:red;background:#ff0}.bar{color:#fff}}",
+      "@media (width<=env(safe-area-inset-top)){body{padding:env(safe-area-inset-top)}}",
     );
 
     minify_test(
       r#"
-    .foo {
-      container-name: foo bar;
-      container-type: size;
-    }
-    "#,
-      ".foo{container:foo bar/size}",
-    );
-    minify_test(
-      r#"
-    .foo {
-      container-name: foo bar;
-      container-type: normal;
-    }
+      @media (max-width: env(unknown)) {
+        body {
+          padding: env(unknown);
+        }
+      }
     "#,
-      ".foo{container:foo bar}",
-    );
-    minify_test(
-      ".foo{ container-type: inline-size }",
-      ".foo{container-type:inline-size}",
-    );
-    minify_test(".foo{ container-name: none; }", ".foo{container-name:none}");
-    minify_test(".foo{ container-name: foo; }", ".foo{container-name:foo}");
-    minify_test(".foo{ container: foo / normal; }", ".foo{container:foo}");
-    minify_test(
-      ".foo{ container: foo / inline-size; }",
-      ".foo{container:foo/inline-size}",
+      "@media (width<=env(unknown)){body{padding:env(unknown)}}",
     );
-    minify_test(".foo { width: calc(1cqw + 2cqw) }", ".foo{width:3cqw}");
-    minify_test(".foo { width: calc(1cqh + 2cqh) }", ".foo{width:3cqh}");
-    minify_test(".foo { width: calc(1cqi + 2cqi) }", ".foo{width:3cqi}");
-    minify_test(".foo { width: calc(1cqb + 2cqb) }", ".foo{width:3cqb}");
-    minify_test(".foo { width: calc(1cqmin + 2cqmin) }", ".foo{width:3cqmin}");
-    minify_test(".foo { width: calc(1cqmax + 2cqmax) }", ".foo{width:3cqmax}");
 
-    // Unlike in @media, there is no need to convert the range syntax in @container,
-    // because browsers all support this syntax.
     prefix_test(
       r#"
-      @container (width > 100px) {
-        .foo { padding: 5px; }
+      .foo {
+        color: env(--brand-color, color(display-p3 0 1 0));
       }
-      "#,
-      indoc! { r#"
-        @container (width > 100px) {
-          .foo {
-            padding: 5px;
-          }
-        }
-      "#},
-      Browsers {
-        chrome: Some(105 << 16),
-        ..Browsers::default()
-      },
-    );
-    prefix_test(
-      r#"
-      @container (min-width: 100px) {
-        .foo { padding: 5px; }
+    "#,
+      indoc! {r#"
+      .foo {
+        color: env(--brand-color, #00f942);
       }
-      "#,
-      indoc! { r#"
-        @container (width >= 100px) {
-          .foo {
-            padding: 5px;
-          }
+
+      @supports (color: color(display-p3 0 0 0)) {
+        .foo {
+          color: env(--brand-color, color(display-p3 0 1 0));
         }
-      "#},
+      }
+    "#},
       Browsers {
-        chrome: Some(105 << 16),
+        safari: Some(15 << 16),
+        chrome: Some(90 << 16),
         ..Browsers::default()
       },
     );
 
-    minify_test(
+    css_modules_test(
       r#"
-      @container style(--responsive: true) {
+      @media (max-width: env(--branding-small)) {
         .foo {
-          color: red;
+          color: env(--brand-color);
         }
       }
     "#,
-      "@container style(--responsive:true){.foo{color:red}}",
-    );
-    minify_test(
-      r#"
-      @container style(--responsive: true) and style(color: yellow) {
-        .foo {
-          color: red;
+      indoc! {r#"
+      @media (width <= env(--EgL3uq_branding-small)) {
+        .EgL3uq_foo {
+          color: env(--EgL3uq_brand-color);
         }
       }
-    "#,
-      "@container style(--responsive:true) and style(color:#ff0){.foo{color:red}}",
+    "#},
+      map! {
+        "foo" => "EgL3uq_foo",
+        "--brand-color" => "--EgL3uq_brand-color" referenced: true,
+        "--branding-small" => "--EgL3uq_branding-small" referenced: true
+      },
+      HashMap::new(),
+      crate::css_modules::Config {
+        dashed_idents: true,
+        ..Default::default()
+      },
     );
+  }
+
+  #[test]
+  fn test_license_comments() {
     minify_test(
       r#"
-      @container not style(--responsive: true) {
-        .foo {
-          color: red;
-        }
+      /*! Copyright 2023 Someone awesome */
+      /* Some other comment */
+      .foo {
+        color: red;
       }
     "#,
-      "@container not style(--responsive:true){.foo{color:red}}",
+      indoc! {r#"
+      /*! Copyright 2023 Someone awesome */
+      .foo{color:red}"#},
     );
+
     minify_test(
       r#"
-      @container (inline-size > 45em) and style(--responsive: true) {
-        .foo {
-          color: red;
-        }
+      /*! Copyright 2023 Someone awesome */
+      /*! Copyright 2023 Someone else */
+      .foo {
+        color: red;
       }
     "#,
-      "@container (inline-size>45em) and style(--responsive:true){.foo{color:red}}",
+      indoc! {r#"
+      /*! Copyright 2023 Someone awesome */
+      /*! Copyright 2023 Someone else */
+      .foo{color:red}"#},
     );
+  }
+
+  #[test]
+  fn test_starting_style() {
     minify_test(
       r#"
-      @container style((accent-color: yellow) or (--bar: 10px)) {
-        .foo {
-          color: red;
+      @starting-style {
+        h1 {
+          background: yellow;
         }
       }
-    "#,
-      "@container style((accent-color:#ff0) or (--bar:10px)){.foo{color:red}}",
+      "#,
+      "@starting-style{h1{background:#ff0}}",
     );
-    minify_test(
+    minify_test("@starting-style {}", "");
+
+    nesting_test(
       r#"
-      @container style(not ((width: calc(10px + 20px)) and ((--bar: url(x))))) {
-        .foo {
-          color: red;
+      h1 {
+        background: red;
+        @starting-style {
+          background: yellow;
         }
       }
-    "#,
-      "@container style(not ((width:30px) and (--bar:url(x)))){.foo{color:red}}",
-    );
-    minify_test(
-      r#"
-      @container style(color: yellow !important) {
-        .foo {
-          color: red;
+      "#,
+      indoc! {r#"
+      h1 {
+        background: red;
+      }
+
+      @starting-style {
+        h1 {
+          background: #ff0;
         }
       }
-    "#,
-      "@container style(color:yellow){.foo{color:red}}",
+      "#},
     );
+  }
+
+  #[test]
+  fn test_color_adjust() {
+    minify_test(".foo { print-color-adjust: exact }", ".foo{print-color-adjust:exact}");
     minify_test(
-      r#"
-      @container style(--foo:) {
-        .foo {
-          color: red;
-        }
-      }
-    "#,
-      "@container style(--foo:){.foo{color:red}}",
+      ".foo { print-color-adjust: ECONOMY }",
+      ".foo{print-color-adjust:economy}",
     );
     minify_test(
-      r#"
-      @container style(--foo: ) {
-        .foo {
-          color: red;
-        }
-      }
-    "#,
-      "@container style(--foo:){.foo{color:red}}",
+      ".foo { -webkit-print-color-adjust: exact }",
+      ".foo{-webkit-print-color-adjust:exact}",
     );
+    minify_test(".foo { color-adjust: exact }", ".foo{color-adjust:exact}");
+    minify_test(".foo { forced-color-adjust: auto }", ".foo{forced-color-adjust:auto}");
+    minify_test(".foo { forced-color-adjust: none }", ".foo{forced-color-adjust:none}");
     minify_test(
+      ".foo { forced-color-adjust: preserve-parent-color }",
+      ".foo{forced-color-adjust:preserve-parent-color}",
+    );
+    minify_test(".foo { print-color-adjust: auto }", ".foo{print-color-adjust:auto}"); // invalid
+
+    prefix_test(
       r#"
-      @container style(--my-prop: foo - bar ()) {
-        .foo {
-          color: red;
-        }
+      .foo {
+        print-color-adjust: exact;
       }
     "#,
-      "@container style(--my-prop:foo - bar ()){.foo{color:red}}",
-    );
-
-    // Disallow 'none', 'not', 'and', 'or' as a `<container-name>`
-    // https://github.com/w3c/csswg-drafts/issues/7203#issuecomment-1144257312
-    // https://chromium-review.googlesource.com/c/chromium/src/+/3698402
-    error_test(
-      "@container none (width < 100vw) {}",
-      ParserError::UnexpectedToken(crate::properties::custom::Token::Ident("none".into())),
+      indoc! {r#"
+      .foo {
+        color-adjust: exact;
+        -webkit-print-color-adjust: exact;
+        print-color-adjust: exact;
+      }
+    "#},
+      Browsers {
+        chrome: Some(100 << 16),
+        firefox: Some(90 << 16),
+        ..Browsers::default()
+      },
     );
 
-    error_test(
-      "@container and (width < 100vw) {}",
-      ParserError::UnexpectedToken(crate::properties::custom::Token::Ident("and".into())),
+    prefix_test(
+      r#"
+      .foo {
+        -webkit-print-color-adjust: exact;
+        color-adjust: exact;
+        print-color-adjust: exact;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        print-color-adjust: exact;
+      }
+    "#},
+      Browsers {
+        firefox: Some(120 << 16),
+        ..Browsers::default()
+      },
     );
 
-    error_test(
-      "@container or (width < 100vw) {}",
-      ParserError::UnexpectedToken(crate::properties::custom::Token::Ident("or".into())),
+    prefix_test(
+      r#"
+      .foo {
+        print-color-adjust: economy;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        -webkit-print-color-adjust: economy;
+        print-color-adjust: economy;
+      }
+    "#},
+      Browsers {
+        safari: Some(15 << 16),
+        ..Browsers::default()
+      },
     );
+  }
 
-    // Disallow CSS wide keywords as a `<container-name>`
-    error_test(
-      "@container revert-layer (width < 100vw) {}",
-      ParserError::UnexpectedToken(crate::properties::custom::Token::Ident("revert-layer".into())),
+  #[test]
+  fn test_object_fit() {
+    minify_test(".foo { object-fit: fill }", ".foo{object-fit:fill}");
+    minify_test(".foo { object-fit: contain }", ".foo{object-fit:contain}");
+    minify_test(".foo { object-fit: COVER }", ".foo{object-fit:cover}");
+    minify_test(".foo { object-fit: none }", ".foo{object-fit:none}");
+    minify_test(".foo { object-fit: scale-down }", ".foo{object-fit:scale-down}");
+    minify_test(".foo { object-fit: auto }", ".foo{object-fit:auto}"); // invalid
+    minify_test(".foo { object-position: center }", ".foo{object-position:50%}");
+    minify_test(".foo { object-position: bottom left }", ".foo{object-position:0 100%}");
+    minify_test(
+      ".foo { object-position: left 10px top 20px }",
+      ".foo{object-position:10px 20px}",
     );
-
-    error_test(
-      "@container initial (width < 100vw) {}",
-      ParserError::UnexpectedToken(crate::properties::custom::Token::Ident("initial".into())),
+    minify_test(
+      ".foo { object-position: right 10px top 20px }",
+      ".foo{object-position:right 10px top 20px}",
     );
+    minify_test(".foo { -o-object-fit: cover }", ".foo{-o-object-fit:cover}");
 
-    // <ident> contains spaces
-    // https://github.com/web-platform-tests/wpt/blob/39f0da08fbbe33d0582a35749b6dbf8bd067a52d/css/css-contain/container-queries/at-container-parsing.html#L160-L178
-    error_test(
-      "@container foo bar (width < 100vw) {}",
-      ParserError::UnexpectedToken(crate::properties::custom::Token::Ident("bar".into())),
+    prefix_test(
+      r#"
+      .foo {
+        object-fit: cover;
+        object-position: 10px 20px;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        -o-object-fit: cover;
+        object-fit: cover;
+        -o-object-position: 10px 20px;
+        object-position: 10px 20px;
+      }
+    "#},
+      Browsers {
+        opera: Some(11 << 16),
+        ..Browsers::default()
+      },
     );
 
-    error_test("@container (inline-size <= foo) {}", ParserError::InvalidMediaQuery);
-    error_test("@container (orientation <= 10px) {}", ParserError::InvalidMediaQuery);
-
-    error_test("@container style(width) {}", ParserError::EndOfInput);
-    error_test(
-      "@container style(style(--foo: bar)) {}",
-      ParserError::UnexpectedToken(crate::properties::custom::Token::Function("style".into())),
+    prefix_test(
+      r#"
+      .foo {
+        -o-object-fit: cover;
+        object-fit: cover;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        object-fit: cover;
+      }
+    "#},
+      Browsers {
+        opera: Some(20 << 16),
+        ..Browsers::default()
+      },
     );
   }
 
   #[test]
-  fn test_unknown_at_rules() {
-    minify_test("@foo;", "@foo;");
-    minify_test("@foo bar;", "@foo bar;");
-    minify_test("@foo (bar: baz);", "@foo (bar: baz);");
-    test(
-      r#"@foo test {
-      div {
-        color: red;
-      }
-    }"#,
-      indoc! {r#"
-      @foo test {
-        div { color: red; }
-      }
-      "#},
+  fn test_image_rendering() {
+    minify_test(".foo { image-rendering: auto }", ".foo{image-rendering:auto}");
+    minify_test(".foo { image-rendering: smooth }", ".foo{image-rendering:smooth}");
+    minify_test(
+      ".foo { image-rendering: high-quality }",
+      ".foo{image-rendering:high-quality}",
     );
     minify_test(
-      r#"@foo test {
-      div {
-        color: red;
-      }
-    }"#,
-      "@foo test{div { color: red; }}",
+      ".foo { image-rendering: crisp-edges }",
+      ".foo{image-rendering:crisp-edges}",
+    );
+    minify_test(".foo { image-rendering: PIXELATED }", ".foo{image-rendering:pixelated}");
+    minify_test(
+      ".foo { image-rendering: optimizeSpeed }",
+      ".foo{image-rendering:optimizespeed}",
     );
     minify_test(
-      r#"@foo test {
-        foo: bar;
-      }"#,
-      "@foo test{foo: bar;}",
+      ".foo { image-rendering: optimizeQuality }",
+      ".foo{image-rendering:optimizequality}",
     );
-    test(
-      r#"@foo {
-        foo: bar;
-      }"#,
-      indoc! {r#"
-      @foo {
-        foo: bar;
-      }
-      "#},
+    minify_test(
+      ".foo { image-rendering: -webkit-optimize-contrast }",
+      ".foo{image-rendering:-webkit-optimize-contrast}",
     );
     minify_test(
-      r#"@foo {
-        foo: bar;
-      }"#,
-      "@foo{foo: bar;}",
+      ".foo { image-rendering: -moz-crisp-edges }",
+      ".foo{image-rendering:-moz-crisp-edges}",
     );
-  }
+    minify_test(
+      ".foo { image-rendering: -o-pixelated }",
+      ".foo{image-rendering:-o-pixelated}",
+    );
+    minify_test(".foo { image-rendering: sharp }", ".foo{image-rendering:sharp}"); // invalid
 
-  #[test]
-  fn test_resolution() {
     prefix_test(
       r#"
-      @media (resolution: 1dppx) {
-        body {
-          background: red;
-        }
+      .foo {
+        image-rendering: crisp-edges;
       }
-      "#,
-      indoc! { r#"
-      @media (resolution: 1dppx) {
-        body {
-          background: red;
-        }
+    "#,
+      indoc! {r#"
+      .foo {
+        image-rendering: -webkit-optimize-contrast;
+        image-rendering: -moz-crisp-edges;
+        image-rendering: crisp-edges;
       }
-      "#},
+    "#},
       Browsers {
-        chrome: Some(50 << 16),
+        safari: Some(6 << 16),
+        firefox: Some(60 << 16),
         ..Browsers::default()
       },
     );
 
     prefix_test(
       r#"
-      @media (resolution: 1dppx) {
-        body {
-          background: red;
-        }
+      .foo {
+        image-rendering: pixelated;
       }
-      "#,
-      indoc! { r#"
-      @media (resolution: 1x) {
-        body {
-          background: red;
-        }
+    "#,
+      indoc! {r#"
+      .foo {
+        image-rendering: pixelated;
       }
-      "#},
+    "#},
       Browsers {
-        chrome: Some(95 << 16),
+        safari: Some(6 << 16),
+        firefox: Some(60 << 16),
         ..Browsers::default()
       },
     );
-  }
 
-  #[test]
-  fn test_environment() {
-    minify_test(
+    prefix_test(
       r#"
-      @media (max-width: env(--branding-small)) {
-        body {
-          padding: env(--branding-padding);
-        }
+      .foo {
+        image-rendering: -webkit-optimize-contrast;
+        image-rendering: -moz-crisp-edges;
+        image-rendering: pixelated;
       }
     "#,
-      "@media (width<=env(--branding-small)){body{padding:env(--branding-padding)}}",
+      indoc! {r#"
+      .foo {
+        image-rendering: -webkit-optimize-contrast;
+        image-rendering: -moz-crisp-edges;
+        image-rendering: pixelated;
+      }
+    "#},
+      Browsers {
+        safari: Some(6 << 16),
+        firefox: Some(60 << 16),
+        ..Browsers::default()
+      },
     );
 
-    minify_test(
+    prefix_test(
       r#"
-      @media (max-width: env(--branding-small 1)) {
-        body {
-          padding: env(--branding-padding 2);
-        }
+      .foo {
+        image-rendering: -webkit-optimize-contrast;
+        image-rendering: -moz-crisp-edges;
+        image-rendering: crisp-edges;
       }
     "#,
-      "@media (width<=env(--branding-small 1)){body{padding:env(--branding-padding 2)}}",
+      indoc! {r#"
+      .foo {
+        image-rendering: crisp-edges;
+      }
+    "#},
+      Browsers {
+        safari: Some(14 << 16),
+        firefox: Some(100 << 16),
+        ..Browsers::default()
+      },
     );
 
-    minify_test(
+    prefix_test(
       r#"
-      @media (max-width: env(--branding-small 1, 20px)) {
-        body {
-          padding: env(--branding-padding 2, 20px);
-        }
+      .foo {
+        image-rendering: -moz-crisp-edges;
       }
     "#,
-      "@media (width<=env(--branding-small 1,20px)){body{padding:env(--branding-padding 2,20px)}}",
+      indoc! {r#"
+      .foo {
+        image-rendering: crisp-edges;
+      }
+    "#},
+      Browsers {
+        firefox: Some(100 << 16),
+        ..Browsers::default()
+      },
     );
 
-    minify_test(
+    prefix_test(
       r#"
-      @media (max-width: env(safe-area-inset-top)) {
-        body {
-          padding: env(safe-area-inset-top);
-        }
+      .foo {
+        image-rendering: -moz-crisp-edges;
       }
     "#,
-      "@media (width<=env(safe-area-inset-top)){body{padding:env(safe-area-inset-top)}}",
+      indoc! {r#"
+      .foo {
+        image-rendering: -moz-crisp-edges;
+      }
+    "#},
+      Browsers {
+        firefox: Some(60 << 16),
+        ..Browsers::default()
+      },
     );
 
-    minify_test(
+    prefix_test(
       r#"
-      @media (max-width: env(unknown)) {
-        body {
-          padding: env(unknown);
-        }
+      .foo {
+        image-rendering: -webkit-optimize-contrast;
       }
     "#,
-      "@media (width<=env(unknown)){body{padding:env(unknown)}}",
+      indoc! {r#"
+      .foo {
+        image-rendering: -webkit-optimize-contrast;
+      }
+    "#},
+      Browsers {
+        chrome: Some(90 << 16),
+        ..Browsers::default()
+      },
     );
 
     prefix_test(
       r#"
       .foo {
-        color: env(--brand-color, color(display-p3 0 1 0));
+        image-rendering: auto;
+        image-rendering: crisp-edges;
       }
     "#,
       indoc! {r#"
       .foo {
-        color: env(--brand-color, #00f942);
-      }
-
-      @supports (color: color(display-p3 0 0 0)) {
-        .foo {
-          color: env(--brand-color, color(display-p3 0 1 0));
-        }
+        image-rendering: auto;
+        image-rendering: crisp-edges;
       }
     "#},
       Browsers {
-        safari: Some(15 << 16),
         chrome: Some(90 << 16),
         ..Browsers::default()
       },
     );
 
-    css_modules_test(
+    prefix_test(
       r#"
-      @media (max-width: env(--branding-small)) {
-        .foo {
-          color: env(--brand-color);
-        }
+      .foo {
+        image-rendering: auto;
+        image-rendering: pixelated;
       }
     "#,
       indoc! {r#"
-      @media (width <= env(--EgL3uq_branding-small)) {
-        .EgL3uq_foo {
-          color: env(--EgL3uq_brand-color);
-        }
+      .foo {
+        image-rendering: pixelated;
       }
     "#},
-      map! {
-        "foo" => "EgL3uq_foo",
-        "--brand-color" => "--EgL3uq_brand-color" referenced: true,
-        "--branding-small" => "--EgL3uq_branding-small" referenced: true
-      },
-      HashMap::new(),
-      crate::css_modules::Config {
-        dashed_idents: true,
-        ..Default::default()
+      Browsers {
+        chrome: Some(90 << 16),
+        ..Browsers::default()
       },
     );
-  }
-
-  #[test]
-  fn test_license_comments() {
+  }
+
+  #[test]
+  fn test_svg_rendering_control() {
+    minify_test(".foo { paint-order: normal }", ".foo{paint-order:normal}");
+    minify_test(".foo { paint-order: fill }", ".foo{paint-order:normal}");
+    minify_test(".foo { paint-order: fill stroke markers }", ".foo{paint-order:normal}");
+    minify_test(".foo { paint-order: fill stroke }", ".foo{paint-order:normal}");
+    minify_test(".foo { paint-order: stroke }", ".foo{paint-order:stroke}");
+    minify_test(".foo { paint-order: stroke fill }", ".foo{paint-order:stroke}");
+    minify_test(".foo { paint-order: stroke fill markers }", ".foo{paint-order:stroke}");
+    minify_test(
+      ".foo { paint-order: stroke markers }",
+      ".foo{paint-order:stroke markers}",
+    );
+    minify_test(".foo { paint-order: markers }", ".foo{paint-order:markers}");
+    minify_test(
+      ".foo { paint-order: markers stroke }",
+      ".foo{paint-order:markers stroke}",
+    );
+    minify_test(
+      ".foo { paint-order: markers stroke fill }",
+      ".foo{paint-order:markers stroke}",
+    );
+    minify_test(".foo { paint-order: fill markers }", ".foo{paint-order:fill markers}");
+    minify_test(
+      ".foo { paint-order: fill markers stroke }",
+      ".foo{paint-order:fill markers}",
+    );
+    minify_test(".foo { paint-order: MARKERS FILL }", ".foo{paint-order:markers}");
+    minify_test(".foo { paint-order: fill fill }", ".foo{paint-order:fill fill}"); // invalid
+    minify_test(".foo { paint-order: normal fill }", ".foo{paint-order:normal fill}"); // invalid
+    minify_test(
+      ".foo { paint-order: fill stroke markers fill }",
+      ".foo{paint-order:fill stroke markers fill}",
+    ); // invalid
+
+    minify_test(".foo { vector-effect: none }", ".foo{vector-effect:none}");
+    minify_test(
+      ".foo { vector-effect: non-scaling-stroke }",
+      ".foo{vector-effect:non-scaling-stroke}",
+    );
+    minify_test(
+      ".foo { vector-effect: non-scaling-size }",
+      ".foo{vector-effect:non-scaling-size}",
+    );
+    minify_test(
+      ".foo { vector-effect: non-rotation }",
+      ".foo{vector-effect:non-rotation}",
+    );
+    minify_test(
+      ".foo { vector-effect: fixed-position }",
+      ".foo{vector-effect:fixed-position}",
+    );
+    minify_test(
+      ".foo { vector-effect: scaling-stroke }",
+      ".foo{vector-effect:scaling-stroke}",
+    ); // invalid
+
+    minify_test(".foo { dominant-baseline: auto }", ".foo{dominant-baseline:auto}");
+    minify_test(
+      ".foo { dominant-baseline: text-bottom }",
+      ".foo{dominant-baseline:text-bottom}",
+    );
+    minify_test(
+      ".foo { dominant-baseline: alphabetic }",
+      ".foo{dominant-baseline:alphabetic}",
+    );
+    minify_test(
+      ".foo { dominant-baseline: ideographic }",
+      ".foo{dominant-baseline:ideographic}",
+    );
+    minify_test(".foo { dominant-baseline: middle }", ".foo{dominant-baseline:middle}");
+    minify_test(".foo { dominant-baseline: central }", ".foo{dominant-baseline:central}");
+    minify_test(
+      ".foo { dominant-baseline: mathematical }",
+      ".foo{dominant-baseline:mathematical}",
+    );
+    minify_test(".foo { dominant-baseline: hanging }", ".foo{dominant-baseline:hanging}");
+    minify_test(
+      ".foo { dominant-baseline: TEXT-TOP }",
+      ".foo{dominant-baseline:text-top}",
+    );
+    minify_test(
+      ".foo { dominant-baseline: baseline }",
+      ".foo{dominant-baseline:baseline}",
+    ); // invalid
+  }
+
+  #[test]
+  fn test_image_orientation() {
+    minify_test(
+      ".foo { image-orientation: from-image }",
+      ".foo{image-orientation:from-image}",
+    );
+    minify_test(".foo { image-orientation: none }", ".foo{image-orientation:none}");
+    minify_test(".foo { image-orientation: 90deg }", ".foo{image-orientation:90deg}");
+    minify_test(".foo { image-orientation: 0deg }", ".foo{image-orientation:0deg}");
+    minify_test(
+      ".foo { image-orientation: 90deg flip }",
+      ".foo{image-orientation:90deg flip}",
+    );
+    minify_test(
+      ".foo { image-orientation: flip 90deg }",
+      ".foo{image-orientation:90deg flip}",
+    );
+    minify_test(".foo { image-orientation: 0deg flip }", ".foo{image-orientation:flip}");
+    minify_test(".foo { image-orientation: flip }", ".foo{image-orientation:flip}");
+    minify_test(
+      ".foo { image-orientation: flip flip }",
+      ".foo{image-orientation:flip flip}",
+    ); // invalid
+    minify_test(
+      ".foo { image-orientation: 90deg 180deg }",
+      ".foo{image-orientation:90deg 180deg}",
+    ); // invalid
+  }
+
+  #[test]
+  fn test_contain() {
+    minify_test(".foo { contain: none }", ".foo{contain:none}");
+    minify_test(".foo { contain: strict }", ".foo{contain:strict}");
+    minify_test(".foo { contain: content }", ".foo{contain:content}");
+    minify_test(".foo { contain: size }", ".foo{contain:size}");
+    minify_test(".foo { contain: inline-size }", ".foo{contain:inline-size}");
+    minify_test(".foo { contain: paint layout }", ".foo{contain:layout paint}");
+    minify_test(".foo { contain: inline-size style }", ".foo{contain:inline-size style}");
+    minify_test(".foo { contain: size layout paint style }", ".foo{contain:strict}");
+    minify_test(".foo { contain: style paint layout }", ".foo{contain:content}");
+    minify_test(".foo { contain: size inline-size }", ".foo{contain:size inline-size}"); // invalid
+    minify_test(".foo { contain: paint paint }", ".foo{contain:paint paint}"); // invalid
+    minify_test(".foo { contain: strict paint }", ".foo{contain:strict paint}"); // invalid
+
+    minify_test(".foo { container: none }", ".foo{container:none}");
+    minify_test(".foo { container: none / size }", ".foo{container:none/size}");
+    minify_test(".foo { container: foo bar / size }", ".foo{container:foo bar/size}");
+  }
+
+  #[test]
+  fn test_view_transition() {
+    minify_test(".foo { view-transition-name: none }", ".foo{view-transition-name:none}");
+    minify_test(".foo { view-transition-name: auto }", ".foo{view-transition-name:auto}");
+    minify_test(".foo { view-transition-name: hero }", ".foo{view-transition-name:hero}");
+    minify_test(
+      ".foo { view-transition-name: foo bar }",
+      ".foo{view-transition-name:foo bar}",
+    ); // invalid
+    minify_test(
+      ".foo { view-transition-name: default }",
+      ".foo{view-transition-name:default}",
+    ); // invalid
+
+    minify_test(
+      ".foo { view-transition-class: none }",
+      ".foo{view-transition-class:none}",
+    );
+    minify_test(
+      ".foo { view-transition-class: card }",
+      ".foo{view-transition-class:card}",
+    );
+    minify_test(
+      ".foo { view-transition-class: card   slide }",
+      ".foo{view-transition-class:card slide}",
+    );
+    minify_test(
+      ".foo { view-transition-class: card none }",
+      ".foo{view-transition-class:card none}",
+    ); // invalid
+    minify_test(
+      ".foo { view-transition-class: none card }",
+      ".foo{view-transition-class:none card}",
+    ); // invalid
+  }
+
+  #[test]
+  fn test_content_visibility() {
+    minify_test(
+      ".foo { content-visibility: visible }",
+      ".foo{content-visibility:visible}",
+    );
+    minify_test(".foo { content-visibility: hidden }", ".foo{content-visibility:hidden}");
+    minify_test(".foo { content-visibility: AUTO }", ".foo{content-visibility:auto}");
+    minify_test(".foo { content-visibility: none }", ".foo{content-visibility:none}"); // invalid
+
+    minify_test(
+      ".foo { contain-intrinsic-size: none }",
+      ".foo{contain-intrinsic-size:none}",
+    );
+    minify_test(
+      ".foo { contain-intrinsic-size: 100px }",
+      ".foo{contain-intrinsic-size:100px}",
+    );
+    minify_test(
+      ".foo { contain-intrinsic-size: 100px 100px }",
+      ".foo{contain-intrinsic-size:100px}",
+    );
+    minify_test(
+      ".foo { contain-intrinsic-size: 100px 200px }",
+      ".foo{contain-intrinsic-size:100px 200px}",
+    );
+    minify_test(
+      ".foo { contain-intrinsic-size: auto 100px }",
+      ".foo{contain-intrinsic-size:auto 100px}",
+    );
     minify_test(
-      r#"
-      /*! Copyright 2023 Someone awesome */
-      /* Some other comment */
-      .foo {
-        color: red;
-      }
-    "#,
-      indoc! {r#"
-      /*! Copyright 2023 Someone awesome */
-      .foo{color:red}"#},
+      ".foo { contain-intrinsic-size: 100px auto }",
+      ".foo{contain-intrinsic-size:100px auto}",
+    ); // invalid
+    minify_test(
+      ".foo { contain-intrinsic-size: 100px auto 200px }",
+      ".foo{contain-intrinsic-size:100px auto 200px}",
+    ); // invalid
+    minify_test(
+      ".foo { contain-intrinsic-size: auto 100px auto 200px }",
+      ".foo{contain-intrinsic-size:auto 100px auto 200px}",
+    );
+    minify_test(
+      ".foo { contain-intrinsic-size: auto none }",
+      ".foo{contain-intrinsic-size:auto none}",
+    );
+    minify_test(
+      ".foo { contain-intrinsic-size: auto }",
+      ".foo{contain-intrinsic-size:auto}",
+    ); // invalid
+    minify_test(
+      ".foo { contain-intrinsic-size: -10px }",
+      ".foo{contain-intrinsic-size:-10px}",
+    ); // invalid
+    minify_test(
+      ".foo { contain-intrinsic-width: auto 300px }",
+      ".foo{contain-intrinsic-width:auto 300px}",
+    );
+    minify_test(
+      ".foo { contain-intrinsic-block-size: auto 300px }",
+      ".foo{contain-intrinsic-block-size:auto 300px}",
     );
-
     minify_test(
+      ".foo { contain-intrinsic-inline-size: none }",
+      ".foo{contain-intrinsic-inline-size:none}",
+    );
+
+    test(
       r#"
-      /*! Copyright 2023 Someone awesome */
-      /*! Copyright 2023 Someone else */
       .foo {
-        color: red;
+        contain-intrinsic-width: 100px;
+        contain-intrinsic-height: auto 200px;
       }
     "#,
       indoc! {r#"
-      /*! Copyright 2023 Someone awesome */
-      /*! Copyright 2023 Someone else */
-      .foo{color:red}"#},
-    );
-  }
-
-  #[test]
-  fn test_starting_style() {
-    minify_test(
-      r#"
-      @starting-style {
-        h1 {
-          background: yellow;
-        }
+      .foo {
+        contain-intrinsic-size: 100px auto 200px;
       }
-      "#,
-      "@starting-style{h1{background:#ff0}}",
+    "#},
     );
-    minify_test("@starting-style {}", "");
 
-    nesting_test(
+    test(
       r#"
-      h1 {
-        background: red;
-        @starting-style {
-          background: yellow;
-        }
+      .foo {
+        contain-intrinsic-size: 100px;
+        contain-intrinsic-height: 200px;
       }
-      "#,
+    "#,
       indoc! {r#"
-      h1 {
-        background: red;
-      }
-
-      @starting-style {
-        h1 {
-          background: #ff0;
-        }
+      .foo {
+        contain-intrinsic-size: 100px 200px;
       }
-      "#},
+    "#},
     );
   }
 
@@ -27144,6 +33692,84 @@ mod tests {
         ..Browsers::default()
       },
     );
+    prefix_test(
+      ".foo { color-scheme: light dark; }",
+      indoc! { r#"
+      .foo {
+        --lightningcss-light: initial;
+        --lightningcss-dark: ;
+        supported-color-schemes: light dark;
+        color-scheme: light dark;
+      }
+
+      @media (prefers-color-scheme: dark) {
+        .foo {
+          --lightningcss-light: ;
+          --lightningcss-dark: initial;
+        }
+      }
+      "#},
+      Browsers {
+        safari: Some((12 << 16) | (1 << 8)),
+        ..Browsers::default()
+      },
+    );
+    prefix_test(
+      ".foo { color-scheme: only dark; }",
+      indoc! { r#"
+      .foo {
+        --lightningcss-light: ;
+        --lightningcss-dark: initial;
+        supported-color-schemes: dark only;
+        color-scheme: dark only;
+      }
+      "#},
+      Browsers {
+        ios_saf: Some((12 << 16) | (2 << 8)),
+        ..Browsers::default()
+      },
+    );
+    prefix_test(
+      ".foo { color-scheme: only dark; }",
+      indoc! { r#"
+      .foo {
+        --lightningcss-light: ;
+        --lightningcss-dark: initial;
+        color-scheme: dark only;
+      }
+      "#},
+      Browsers {
+        chrome: Some(70 << 16),
+        safari: Some(12 << 16),
+        ..Browsers::default()
+      },
+    );
+    prefix_test(
+      ".foo { color-scheme: normal; }",
+      indoc! { r#"
+      .foo {
+        color-scheme: normal;
+      }
+      "#},
+      Browsers {
+        safari: Some(12 << 16),
+        ..Browsers::default()
+      },
+    );
+    prefix_test(
+      ".foo { color-scheme: dark; }",
+      indoc! { r#"
+      .foo {
+        --lightningcss-light: ;
+        --lightningcss-dark: initial;
+        color-scheme: dark;
+      }
+      "#},
+      Browsers {
+        safari: Some(18 << 16),
+        ..Browsers::default()
+      },
+    );
 
     minify_test(
       ".foo { color: light-dark(yellow, red); }",
@@ -27243,5 +33869,166 @@ mod tests {
         ..Browsers::default()
       },
     );
+
+    let media_query_test = |source: &str, expected: &str| {
+      nesting_test_with_targets(
+        source,
+        expected,
+        Targets {
+          browsers: Some(Browsers {
+            chrome: Some(90 << 16),
+            ..Browsers::default()
+          }),
+          include: Features::LightDarkMediaQuery,
+          exclude: Features::empty(),
+        },
+      );
+    };
+
+    media_query_test(
+      ".foo { color: light-dark(yellow, red); }",
+      indoc! { r#"
+      .foo {
+        color: #ff0;
+      }
+
+      @media (prefers-color-scheme: dark) {
+        .foo {
+          color: red;
+        }
+      }
+      "#},
+    );
+    media_query_test(
+      ".foo { color: light-dark(oklch(40% 0.1268735435 34.568626), oklab(59.686% 0.1009 0.1192)); }",
+      indoc! { r#"
+      .foo {
+        color: #7e250f;
+        color: lab(29.2661% 38.2437 35.3889);
+      }
+
+      @media (prefers-color-scheme: dark) {
+        .foo {
+          color: #c65d07;
+          color: lab(52.2319% 40.1449 59.9171);
+        }
+      }
+      "#},
+    );
+    media_query_test(
+      ".foo { background: light-dark(#aaa, #444) url(foo.png); border-top-color: light-dark(white, black); }",
+      indoc! { r#"
+      .foo {
+        background: #aaa url("foo.png");
+        border-top-color: #fff;
+      }
+
+      @media (prefers-color-scheme: dark) {
+        .foo {
+          background-color: #444;
+          border-top-color: #000;
+        }
+      }
+      "#},
+    );
+    media_query_test(
+      ".foo { color: light-dark(yellow, red); color: blue; }",
+      indoc! { r#"
+      .foo {
+        color: #00f;
+      }
+      "#},
+    );
+    media_query_test(
+      ".foo { background: light-dark(#aaa, #444) url(foo.png); background-color: green; }",
+      indoc! { r#"
+      .foo {
+        background: green url("foo.png");
+      }
+      "#},
+    );
+    media_query_test(
+      ".foo { border-top-color: light-dark(white, black); border: 1px solid blue; }",
+      indoc! { r#"
+      .foo {
+        border: 1px solid #00f;
+      }
+      "#},
+    );
+    media_query_test(
+      ".foo { color: light-dark(yellow, red); color: light-dark(blue, green); }",
+      indoc! { r#"
+      .foo {
+        color: #00f;
+      }
+
+      @media (prefers-color-scheme: dark) {
+        .foo {
+          color: green;
+        }
+      }
+      "#},
+    );
+    media_query_test(
+      ".foo { color: light-dark(yellow, red) !important; }",
+      indoc! { r#"
+      .foo {
+        color: var(--lightningcss-light, #ff0) var(--lightningcss-dark, red) !important;
+      }
+      "#},
+    );
+    media_query_test(
+      ".foo { color-scheme: light dark; }",
+      indoc! { r#"
+      .foo {
+        --lightningcss-light: initial;
+        --lightningcss-dark: ;
+        color-scheme: light dark;
+      }
+
+      @media (prefers-color-scheme: dark) {
+        .foo {
+          --lightningcss-light: ;
+          --lightningcss-dark: initial;
+        }
+      }
+      "#},
+    );
+    media_query_test(
+      ".foo { color-scheme: light dark; color: light-dark(yellow, red); border: 1px solid light-dark(red, blue); }",
+      indoc! { r#"
+      .foo {
+        --lightningcss-light: initial;
+        --lightningcss-dark: ;
+        color-scheme: light dark;
+        color: #ff0;
+        border: 1px solid var(--lightningcss-light, red) var(--lightningcss-dark, #00f);
+      }
+
+      @media (prefers-color-scheme: dark) {
+        .foo {
+          --lightningcss-light: ;
+          --lightningcss-dark: initial;
+          color: red;
+        }
+      }
+      "#},
+    );
+    nesting_test_with_targets(
+      ".foo { color: light-dark(yellow, red); }",
+      indoc! { r#"
+      .foo {
+        color: light-dark(#ff0, red);
+      }
+      "#},
+      Targets {
+        browsers: Some(Browsers {
+          firefox: Some(120 << 16),
+          ..Browsers::default()
+        }),
+        include: Features::LightDarkMediaQuery,
+        exclude: Features::empty(),
+      },
+    );
   }
 }
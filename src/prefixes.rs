@@ -93,14 +93,17 @@ pub enum Feature {
   GridTemplateAreas,
   GridTemplateColumns,
   GridTemplateRows,
+  HyphenateCharacter,
   Hyphens,
   ImageRendering,
   ImageSet,
+  InitialLetter,
   InlineFlex,
   InlineGrid,
   Isolate,
   IsolateOverride,
   JustifyContent,
+  LineClamp,
   LinearGradient,
   MarginBlockEnd,
   MarginBlockStart,
@@ -2236,6 +2239,97 @@ impl Feature {
           }
         }
       }
+      Feature::HyphenateCharacter => {
+        if let Some(version) = browsers.chrome {
+          if version >= 393216 && version <= 6881280 {
+            prefixes |= VendorPrefix::WebKit;
+          }
+        }
+        if let Some(version) = browsers.edge {
+          if version >= 5177344 && version <= 6881280 {
+            prefixes |= VendorPrefix::WebKit;
+          }
+        }
+        if let Some(version) = browsers.opera {
+          if version >= 983040 && version <= 5963776 {
+            prefixes |= VendorPrefix::WebKit;
+          }
+        }
+        if let Some(version) = browsers.safari {
+          if version >= 327936 && version <= 1048576 {
+            prefixes |= VendorPrefix::WebKit;
+          }
+        }
+        if let Some(version) = browsers.ios_saf {
+          if version >= 262656 && version <= 1048576 {
+            prefixes |= VendorPrefix::WebKit;
+          }
+        }
+        if let Some(version) = browsers.samsung {
+          if version >= 65536 && version <= 1245184 {
+            prefixes |= VendorPrefix::WebKit;
+          }
+        }
+        if let Some(version) = browsers.android {
+          if version >= 2424832 && version <= 6881280 {
+            prefixes |= VendorPrefix::WebKit;
+          }
+        }
+      }
+      Feature::InitialLetter => {
+        if let Some(version) = browsers.safari {
+          if version >= 589824 {
+            prefixes |= VendorPrefix::WebKit;
+          }
+        }
+        if let Some(version) = browsers.ios_saf {
+          if version >= 589824 {
+            prefixes |= VendorPrefix::WebKit;
+          }
+        }
+      }
+      Feature::LineClamp => {
+        if let Some(version) = browsers.chrome {
+          if version >= 917504 {
+            prefixes |= VendorPrefix::WebKit;
+          }
+        }
+        if let Some(version) = browsers.edge {
+          if version >= 1114112 {
+            prefixes |= VendorPrefix::WebKit;
+          }
+        }
+        if let Some(version) = browsers.firefox {
+          if version >= 4456448 {
+            prefixes |= VendorPrefix::WebKit;
+          }
+        }
+        if let Some(version) = browsers.opera {
+          if version >= 917504 {
+            prefixes |= VendorPrefix::WebKit;
+          }
+        }
+        if let Some(version) = browsers.safari {
+          if version >= 327680 {
+            prefixes |= VendorPrefix::WebKit;
+          }
+        }
+        if let Some(version) = browsers.ios_saf {
+          if version >= 327680 {
+            prefixes |= VendorPrefix::WebKit;
+          }
+        }
+        if let Some(version) = browsers.samsung {
+          if version >= 65536 {
+            prefixes |= VendorPrefix::WebKit;
+          }
+        }
+        if let Some(version) = browsers.android {
+          if version >= 2424832 {
+            prefixes |= VendorPrefix::WebKit;
+          }
+        }
+      }
     }
     prefixes
   }
@@ -593,9 +593,9 @@ impl ToCss for PlaceSelf {
   {
     self.align.to_css(dest)?;
     let is_equal = match &self.justify {
-      JustifySelf::Auto => true,
+      JustifySelf::Auto => self.align == AlignSelf::Auto,
       JustifySelf::Normal => self.align == AlignSelf::Normal,
-      JustifySelf::Stretch => self.align == AlignSelf::Normal,
+      JustifySelf::Stretch => self.align == AlignSelf::Stretch,
       JustifySelf::BaselinePosition(p) if matches!(&self.align, AlignSelf::BaselinePosition(p2) if p == p2) => {
         true
       }
@@ -788,6 +788,8 @@ pub enum JustifyItems {
   /// A legacy justification keyword.
   #[cfg_attr(feature = "serde", serde(with = "ValueWrapper::<LegacyJustify>"))]
   Legacy(LegacyJustify),
+  /// The `legacy` keyword on its own, which inherits a legacy justification from the parent, if any.
+  LegacyInherit,
 }
 
 impl<'i> Parse<'i> for JustifyItems {
@@ -808,6 +810,10 @@ impl<'i> Parse<'i> for JustifyItems {
       return Ok(JustifyItems::Legacy(val));
     }
 
+    if input.try_parse(|input| input.expect_ident_matching("legacy")).is_ok() {
+      return Ok(JustifyItems::LegacyInherit);
+    }
+
     let overflow = input.try_parse(OverflowPosition::parse).ok();
     if let Ok(value) = input.try_parse(SelfPosition::parse) {
       return Ok(JustifyItems::SelfPosition { overflow, value });
@@ -835,6 +841,7 @@ impl ToCss for JustifyItems {
       JustifyItems::Stretch => dest.write_str("stretch"),
       JustifyItems::BaselinePosition(val) => val.to_css(dest),
       JustifyItems::Legacy(val) => val.to_css(dest),
+      JustifyItems::LegacyInherit => dest.write_str("legacy"),
       JustifyItems::SelfPosition { overflow, value } => {
         if let Some(overflow) = overflow {
           overflow.to_css(dest)?;
@@ -904,7 +911,7 @@ impl ToCss for PlaceItems {
     self.align.to_css(dest)?;
     let is_equal = match &self.justify {
       JustifyItems::Normal => self.align == AlignItems::Normal,
-      JustifyItems::Stretch => self.align == AlignItems::Normal,
+      JustifyItems::Stretch => self.align == AlignItems::Stretch,
       JustifyItems::BaselinePosition(p) if matches!(&self.align, AlignItems::BaselinePosition(p2) if p == p2) => {
         true
       }
@@ -0,0 +1,565 @@
+//! CSS properties related to anchor positioning.
+
+use super::{Property, PropertyId};
+use crate::context::PropertyHandlerContext;
+use crate::declaration::{DeclarationBlock, DeclarationList};
+use crate::error::{ParserError, PrinterError};
+use crate::macros::{define_shorthand, enum_property, shorthand_handler};
+use crate::printer::Printer;
+use crate::targets::Browsers;
+use crate::traits::{IsCompatible, Parse, PropertyHandler, Shorthand, ToCss};
+use crate::values::ident::DashedIdent;
+#[cfg(feature = "visitor")]
+use crate::visitor::Visit;
+use cssparser::*;
+use smallvec::SmallVec;
+
+/// A value for the [anchor-name](https://drafts.csswg.org/css-anchor-position-1/#name) property.
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "type", content = "value", rename_all = "kebab-case")
+)]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+pub enum AnchorName<'i> {
+  /// The element is not an anchor.
+  #[default]
+  None,
+  /// A list of anchor names.
+  #[cfg_attr(feature = "serde", serde(borrow))]
+  Names(SmallVec<[DashedIdent<'i>; 1]>),
+}
+
+impl<'i> Parse<'i> for AnchorName<'i> {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    if input.try_parse(|input| input.expect_ident_matching("none")).is_ok() {
+      return Ok(AnchorName::None);
+    }
+
+    let names = input.parse_comma_separated(DashedIdent::parse)?;
+    Ok(AnchorName::Names(names.into()))
+  }
+}
+
+impl<'i> ToCss for AnchorName<'i> {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    match self {
+      AnchorName::None => dest.write_str("none"),
+      AnchorName::Names(names) => {
+        let mut first = true;
+        for name in names {
+          if first {
+            first = false;
+          } else {
+            dest.delim(',', false)?;
+          }
+          name.to_css(dest)?;
+        }
+        Ok(())
+      }
+    }
+  }
+}
+
+/// A value for the [position-anchor](https://drafts.csswg.org/css-anchor-position-1/#position-anchor) property.
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "type", content = "value", rename_all = "kebab-case")
+)]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+pub enum PositionAnchor<'i> {
+  /// The element uses its implicit anchor element, if any.
+  #[default]
+  Auto,
+  /// The element is positioned relative to the anchor with the given name.
+  #[cfg_attr(feature = "serde", serde(borrow))]
+  Name(DashedIdent<'i>),
+}
+
+impl<'i> Parse<'i> for PositionAnchor<'i> {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    if input.try_parse(|input| input.expect_ident_matching("auto")).is_ok() {
+      return Ok(PositionAnchor::Auto);
+    }
+
+    Ok(PositionAnchor::Name(DashedIdent::parse(input)?))
+  }
+}
+
+impl<'i> ToCss for PositionAnchor<'i> {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    match self {
+      PositionAnchor::Auto => dest.write_str("auto"),
+      PositionAnchor::Name(name) => name.to_css(dest),
+    }
+  }
+}
+
+enum_property! {
+  /// A keyword for the [position-area](https://drafts.csswg.org/css-anchor-position-1/#position-area) property,
+  /// formerly known as `inset-area`.
+  ///
+  /// See [PositionArea](PositionArea).
+  pub enum PositionAreaKeyword {
+    /// The center tile, in either axis.
+    "center": Center,
+    /// All three tiles, in either axis.
+    "span-all": SpanAll,
+
+    /// The left tile.
+    "left": Left,
+    /// The right tile.
+    "right": Right,
+    /// The left and center tiles.
+    "span-left": SpanLeft,
+    /// The center and right tiles.
+    "span-right": SpanRight,
+    /// The tile at the start of the x axis, according to the containing block's writing mode.
+    "x-start": XStart,
+    /// The tile at the end of the x axis, according to the containing block's writing mode.
+    "x-end": XEnd,
+    /// The start and center tiles of the x axis, according to the containing block's writing mode.
+    "span-x-start": SpanXStart,
+    /// The center and end tiles of the x axis, according to the containing block's writing mode.
+    "span-x-end": SpanXEnd,
+    /// The tile at the start of the x axis, according to the element's own writing mode.
+    "self-x-start": SelfXStart,
+    /// The tile at the end of the x axis, according to the element's own writing mode.
+    "self-x-end": SelfXEnd,
+    /// The start and center tiles of the x axis, according to the element's own writing mode.
+    "span-self-x-start": SpanSelfXStart,
+    /// The center and end tiles of the x axis, according to the element's own writing mode.
+    "span-self-x-end": SpanSelfXEnd,
+
+    /// The top tile.
+    "top": Top,
+    /// The bottom tile.
+    "bottom": Bottom,
+    /// The top and center tiles.
+    "span-top": SpanTop,
+    /// The center and bottom tiles.
+    "span-bottom": SpanBottom,
+    /// The tile at the start of the y axis, according to the containing block's writing mode.
+    "y-start": YStart,
+    /// The tile at the end of the y axis, according to the containing block's writing mode.
+    "y-end": YEnd,
+    /// The start and center tiles of the y axis, according to the containing block's writing mode.
+    "span-y-start": SpanYStart,
+    /// The center and end tiles of the y axis, according to the containing block's writing mode.
+    "span-y-end": SpanYEnd,
+    /// The tile at the start of the y axis, according to the element's own writing mode.
+    "self-y-start": SelfYStart,
+    /// The tile at the end of the y axis, according to the element's own writing mode.
+    "self-y-end": SelfYEnd,
+    /// The start and center tiles of the y axis, according to the element's own writing mode.
+    "span-self-y-start": SpanSelfYStart,
+    /// The center and end tiles of the y axis, according to the element's own writing mode.
+    "span-self-y-end": SpanSelfYEnd,
+
+    /// The tile at the start of the block axis.
+    "block-start": BlockStart,
+    /// The tile at the end of the block axis.
+    "block-end": BlockEnd,
+    /// The start and center tiles of the block axis.
+    "span-block-start": SpanBlockStart,
+    /// The center and end tiles of the block axis.
+    "span-block-end": SpanBlockEnd,
+    /// The tile at the start of the inline axis.
+    "inline-start": InlineStart,
+    /// The tile at the end of the inline axis.
+    "inline-end": InlineEnd,
+    /// The start and center tiles of the inline axis.
+    "span-inline-start": SpanInlineStart,
+    /// The center and end tiles of the inline axis.
+    "span-inline-end": SpanInlineEnd,
+
+    /// The tile at the start of the block axis, according to the element's own writing mode.
+    "self-block-start": SelfBlockStart,
+    /// The tile at the end of the block axis, according to the element's own writing mode.
+    "self-block-end": SelfBlockEnd,
+    /// The start and center tiles of the block axis, according to the element's own writing mode.
+    "span-self-block-start": SpanSelfBlockStart,
+    /// The center and end tiles of the block axis, according to the element's own writing mode.
+    "span-self-block-end": SpanSelfBlockEnd,
+    /// The tile at the start of the inline axis, according to the element's own writing mode.
+    "self-inline-start": SelfInlineStart,
+    /// The tile at the end of the inline axis, according to the element's own writing mode.
+    "self-inline-end": SelfInlineEnd,
+    /// The start and center tiles of the inline axis, according to the element's own writing mode.
+    "span-self-inline-start": SpanSelfInlineStart,
+    /// The center and end tiles of the inline axis, according to the element's own writing mode.
+    "span-self-inline-end": SpanSelfInlineEnd,
+
+    /// The tile at the start of the block or inline axis.
+    "start": Start,
+    /// The tile at the end of the block or inline axis.
+    "end": End,
+    /// The start and center tiles of the block or inline axis.
+    "span-start": SpanStart,
+    /// The center and end tiles of the block or inline axis.
+    "span-end": SpanEnd,
+    /// The tile at the start of the block or inline axis, according to the element's own writing mode.
+    "self-start": SelfStart,
+    /// The tile at the end of the block or inline axis, according to the element's own writing mode.
+    "self-end": SelfEnd,
+    /// The start and center tiles of the block or inline axis, according to the element's own writing mode.
+    "span-self-start": SpanSelfStart,
+    /// The center and end tiles of the block or inline axis, according to the element's own writing mode.
+    "span-self-end": SpanSelfEnd,
+  }
+}
+
+/// The group of keywords that a position-area keyword belongs to.
+/// Two keywords may only be combined if they come from compatible groups.
+/// `center` and `span-all` may be combined with any keyword.
+#[derive(PartialEq)]
+enum PositionAreaAxis {
+  Any,
+  Horizontal,
+  Vertical,
+  Block,
+  Inline,
+  SelfBlock,
+  SelfInline,
+  Ambiguous,
+  SelfAmbiguous,
+}
+
+impl PositionAreaKeyword {
+  fn axis(&self) -> PositionAreaAxis {
+    use PositionAreaKeyword::*;
+    match self {
+      Center | SpanAll => PositionAreaAxis::Any,
+      Left | Right | SpanLeft | SpanRight | XStart | XEnd | SpanXStart | SpanXEnd | SelfXStart | SelfXEnd
+      | SpanSelfXStart | SpanSelfXEnd => PositionAreaAxis::Horizontal,
+      Top | Bottom | SpanTop | SpanBottom | YStart | YEnd | SpanYStart | SpanYEnd | SelfYStart | SelfYEnd
+      | SpanSelfYStart | SpanSelfYEnd => PositionAreaAxis::Vertical,
+      BlockStart | BlockEnd | SpanBlockStart | SpanBlockEnd => PositionAreaAxis::Block,
+      InlineStart | InlineEnd | SpanInlineStart | SpanInlineEnd => PositionAreaAxis::Inline,
+      SelfBlockStart | SelfBlockEnd | SpanSelfBlockStart | SpanSelfBlockEnd => PositionAreaAxis::SelfBlock,
+      SelfInlineStart | SelfInlineEnd | SpanSelfInlineStart | SpanSelfInlineEnd => PositionAreaAxis::SelfInline,
+      Start | End | SpanStart | SpanEnd => PositionAreaAxis::Ambiguous,
+      SelfStart | SelfEnd | SpanSelfStart | SpanSelfEnd => PositionAreaAxis::SelfAmbiguous,
+    }
+  }
+
+  /// Returns the keyword that is implied for the other axis when only this keyword is specified.
+  fn implied_pair(&self) -> PositionAreaKeyword {
+    match self.axis() {
+      PositionAreaAxis::Any | PositionAreaAxis::Ambiguous | PositionAreaAxis::SelfAmbiguous => *self,
+      _ => PositionAreaKeyword::SpanAll,
+    }
+  }
+}
+
+/// A value for the [position-area](https://drafts.csswg.org/css-anchor-position-1/#position-area) property.
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "type", rename_all = "kebab-case")
+)]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+pub enum PositionArea {
+  /// The element is not positioned using a position area.
+  #[default]
+  None,
+  /// A region of the position-area grid.
+  Area {
+    /// The first keyword.
+    first: PositionAreaKeyword,
+    /// The second keyword. If omitted, it is implied by the first.
+    second: Option<PositionAreaKeyword>,
+  },
+}
+
+impl PositionArea {
+  fn parse_area<'i>(input: &mut Parser<'i, '_>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    let mut first = PositionAreaKeyword::parse(input)?;
+    let mut second = input.try_parse(PositionAreaKeyword::parse).ok();
+
+    if let Some(s) = second {
+      let valid = matches!(
+        (first.axis(), s.axis()),
+        (PositionAreaAxis::Any, _)
+          | (_, PositionAreaAxis::Any)
+          | (PositionAreaAxis::Horizontal, PositionAreaAxis::Vertical)
+          | (PositionAreaAxis::Vertical, PositionAreaAxis::Horizontal)
+          | (PositionAreaAxis::Block, PositionAreaAxis::Inline)
+          | (PositionAreaAxis::Inline, PositionAreaAxis::Block)
+          | (PositionAreaAxis::SelfBlock, PositionAreaAxis::SelfInline)
+          | (PositionAreaAxis::SelfInline, PositionAreaAxis::SelfBlock)
+          | (PositionAreaAxis::Ambiguous, PositionAreaAxis::Ambiguous)
+          | (PositionAreaAxis::SelfAmbiguous, PositionAreaAxis::SelfAmbiguous)
+      );
+
+      if !valid {
+        return Err(input.new_custom_error(ParserError::InvalidValue));
+      }
+
+      // Omit the second keyword if it is the same as the one implied by the first.
+      if s == first.implied_pair() {
+        second = None;
+      } else if first == PositionAreaKeyword::SpanAll && s.implied_pair() == PositionAreaKeyword::SpanAll {
+        first = s;
+        second = None;
+      }
+    }
+
+    Ok(PositionArea::Area { first, second })
+  }
+}
+
+impl<'i> Parse<'i> for PositionArea {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    if input.try_parse(|input| input.expect_ident_matching("none")).is_ok() {
+      return Ok(PositionArea::None);
+    }
+
+    PositionArea::parse_area(input)
+  }
+}
+
+impl ToCss for PositionArea {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    match self {
+      PositionArea::None => dest.write_str("none"),
+      PositionArea::Area { first, second } => {
+        first.to_css(dest)?;
+        if let Some(second) = second {
+          dest.write_char(' ')?;
+          second.to_css(dest)?;
+        }
+        Ok(())
+      }
+    }
+  }
+}
+
+enum_property! {
+  /// A [`<try-tactic>`](https://drafts.csswg.org/css-anchor-position-1/#typedef-position-try-fallbacks-try-tactic)
+  /// value, as used in the `position-try-fallbacks` property.
+  pub enum TryTactic {
+    /// Swaps the values in the block axis.
+    "flip-block": FlipBlock,
+    /// Swaps the values in the inline axis.
+    "flip-inline": FlipInline,
+    /// Swaps the values between the block and inline axes.
+    "flip-start": FlipStart,
+  }
+}
+
+/// A fallback option for the [position-try-fallbacks](https://drafts.csswg.org/css-anchor-position-1/#position-try-fallbacks) property.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "type", rename_all = "kebab-case")
+)]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+pub enum PositionTryFallback<'i> {
+  /// A `@position-try` rule, transformed by a list of try tactics.
+  Rule {
+    /// The name of the `@position-try` rule.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    name: Option<DashedIdent<'i>>,
+    /// The try tactics to apply, in order.
+    tactics: SmallVec<[TryTactic; 1]>,
+  },
+  /// A position area.
+  #[cfg_attr(
+    feature = "serde",
+    serde(with = "crate::serialization::ValueWrapper::<PositionArea>")
+  )]
+  PositionArea(PositionArea),
+}
+
+impl<'i> Parse<'i> for PositionTryFallback<'i> {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    if let Ok(area) = input.try_parse(PositionArea::parse_area) {
+      return Ok(PositionTryFallback::PositionArea(area));
+    }
+
+    let mut name = input.try_parse(DashedIdent::parse).ok();
+    let mut tactics: SmallVec<[TryTactic; 1]> = SmallVec::new();
+    while let Ok(tactic) = input.try_parse(TryTactic::parse) {
+      if tactics.contains(&tactic) {
+        return Err(input.new_custom_error(ParserError::InvalidValue));
+      }
+      tactics.push(tactic);
+    }
+
+    if name.is_none() {
+      name = input.try_parse(DashedIdent::parse).ok();
+    }
+
+    if name.is_none() && tactics.is_empty() {
+      return Err(input.new_error_for_next_token());
+    }
+
+    Ok(PositionTryFallback::Rule { name, tactics })
+  }
+}
+
+impl<'i> ToCss for PositionTryFallback<'i> {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    match self {
+      PositionTryFallback::Rule { name, tactics } => {
+        if let Some(name) = name {
+          name.to_css(dest)?;
+        }
+        for (i, tactic) in tactics.iter().enumerate() {
+          if i > 0 || name.is_some() {
+            dest.write_char(' ')?;
+          }
+          tactic.to_css(dest)?;
+        }
+        Ok(())
+      }
+      PositionTryFallback::PositionArea(area) => area.to_css(dest),
+    }
+  }
+}
+
+/// A value for the [position-try-fallbacks](https://drafts.csswg.org/css-anchor-position-1/#position-try-fallbacks) property.
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "type", content = "value", rename_all = "kebab-case")
+)]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+pub enum PositionTryFallbacks<'i> {
+  /// No fallback options.
+  #[default]
+  None,
+  /// A list of fallback options, tried in order.
+  #[cfg_attr(feature = "serde", serde(borrow))]
+  Fallbacks(SmallVec<[PositionTryFallback<'i>; 1]>),
+}
+
+impl<'i> Parse<'i> for PositionTryFallbacks<'i> {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    if input.try_parse(|input| input.expect_ident_matching("none")).is_ok() {
+      return Ok(PositionTryFallbacks::None);
+    }
+
+    let fallbacks = input.parse_comma_separated(PositionTryFallback::parse)?;
+    Ok(PositionTryFallbacks::Fallbacks(fallbacks.into()))
+  }
+}
+
+impl<'i> ToCss for PositionTryFallbacks<'i> {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    match self {
+      PositionTryFallbacks::None => dest.write_str("none"),
+      PositionTryFallbacks::Fallbacks(fallbacks) => {
+        let mut first = true;
+        for fallback in fallbacks {
+          if first {
+            first = false;
+          } else {
+            dest.delim(',', false)?;
+          }
+          fallback.to_css(dest)?;
+        }
+        Ok(())
+      }
+    }
+  }
+}
+
+impl IsCompatible for PositionTryFallbacks<'_> {
+  fn is_compatible(&self, _browsers: Browsers) -> bool {
+    true
+  }
+}
+
+enum_property! {
+  /// A value for the [position-try-order](https://drafts.csswg.org/css-anchor-position-1/#position-try-order-property) property.
+  #[derive(Default)]
+  pub enum PositionTryOrder {
+    /// Fallback options are tried in the order specified.
+    #[default]
+    "normal": Normal,
+    /// Fallback options are ordered by the width of their containing block, largest first.
+    "most-width": MostWidth,
+    /// Fallback options are ordered by the height of their containing block, largest first.
+    "most-height": MostHeight,
+    /// Fallback options are ordered by the block size of their containing block, largest first.
+    "most-block-size": MostBlockSize,
+    /// Fallback options are ordered by the inline size of their containing block, largest first.
+    "most-inline-size": MostInlineSize,
+  }
+}
+
+impl IsCompatible for PositionTryOrder {
+  fn is_compatible(&self, _browsers: Browsers) -> bool {
+    true
+  }
+}
+
+define_shorthand! {
+  /// A value for the [position-try](https://drafts.csswg.org/css-anchor-position-1/#position-try-prop) shorthand property.
+  pub struct PositionTry<'i> {
+    /// The order in which fallback options are tried.
+    order: PositionTryOrder(PositionTryOrder),
+    /// The fallback options.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    fallbacks: PositionTryFallbacks(PositionTryFallbacks<'i>),
+  }
+}
+
+impl<'i> Parse<'i> for PositionTry<'i> {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    let order = input.try_parse(PositionTryOrder::parse).unwrap_or_default();
+    let fallbacks = PositionTryFallbacks::parse(input)?;
+    Ok(PositionTry { order, fallbacks })
+  }
+}
+
+impl<'i> ToCss for PositionTry<'i> {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    if self.order != PositionTryOrder::default() {
+      self.order.to_css(dest)?;
+      dest.write_char(' ')?;
+    }
+    self.fallbacks.to_css(dest)
+  }
+}
+
+shorthand_handler!(PositionTryHandler -> PositionTry<'i> {
+  order: PositionTryOrder(PositionTryOrder),
+  fallbacks: PositionTryFallbacks(PositionTryFallbacks<'i>),
+});
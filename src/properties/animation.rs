@@ -6,9 +6,13 @@ use crate::error::{ParserError, PrinterError};
 use crate::macros::*;
 use crate::prefixes::Feature;
 use crate::printer::Printer;
+use crate::properties::timeline::{ScrollAxis, ViewTimelineInset};
 use crate::properties::{Property, PropertyId, VendorPrefix};
-use crate::traits::{Parse, PropertyHandler, Shorthand, ToCss, Zero};
+use crate::traits::{Parse, PropertyHandler, Shorthand, Sign, ToCss, Zero};
+use crate::values::ident::DashedIdent;
+use crate::values::length::LengthPercentage;
 use crate::values::number::CSSNumber;
+use crate::values::percentage::Percentage;
 use crate::values::string::CowArcStr;
 use crate::values::{easing::EasingFunction, ident::CustomIdent, time::Time};
 #[cfg(feature = "visitor")]
@@ -119,6 +123,9 @@ impl<'i> Parse<'i> for AnimationIterationCount {
     }
 
     let number = CSSNumber::parse(input)?;
+    if number < 0.0 {
+      return Err(input.new_custom_error(ParserError::InvalidValue));
+    }
     return Ok(AnimationIterationCount::Number(number));
   }
 }
@@ -191,6 +198,431 @@ impl Default for AnimationFillMode {
   }
 }
 
+enum_property! {
+  /// A scroller, as used in the `scroll()` function.
+  #[derive(Default)]
+  pub enum Scroller {
+    /// The document viewport.
+    "root": Root,
+    /// The nearest ancestor scroll container.
+    #[default]
+    "nearest": Nearest,
+    /// The element itself.
+    "self": SelfElement,
+  }
+}
+
+/// A [`scroll()`](https://drafts.csswg.org/scroll-animations-1/#scroll-notation) function,
+/// as used in the `animation-timeline` property.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+pub struct ScrollFunction {
+  /// The scroll container whose scroll position drives the timeline.
+  pub scroller: Scroller,
+  /// The scroll axis of the timeline.
+  pub axis: ScrollAxis,
+}
+
+impl<'i> Parse<'i> for ScrollFunction {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    input.expect_function_matching("scroll")?;
+    input.parse_nested_block(|input| {
+      let mut scroller = None;
+      let mut axis = None;
+      loop {
+        if scroller.is_none() {
+          if let Ok(value) = input.try_parse(Scroller::parse) {
+            scroller = Some(value);
+            continue;
+          }
+        }
+
+        if axis.is_none() {
+          if let Ok(value) = input.try_parse(ScrollAxis::parse) {
+            axis = Some(value);
+            continue;
+          }
+        }
+
+        break;
+      }
+
+      Ok(ScrollFunction {
+        scroller: scroller.unwrap_or_default(),
+        axis: axis.unwrap_or_default(),
+      })
+    })
+  }
+}
+
+impl ToCss for ScrollFunction {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    dest.write_str("scroll(")?;
+
+    let mut needs_space = false;
+    if self.scroller != Scroller::default() {
+      self.scroller.to_css(dest)?;
+      needs_space = true;
+    }
+
+    if self.axis != ScrollAxis::default() {
+      if needs_space {
+        dest.write_char(' ')?;
+      }
+      self.axis.to_css(dest)?;
+    }
+
+    dest.write_char(')')
+  }
+}
+
+/// A [`view()`](https://drafts.csswg.org/scroll-animations-1/#view-notation) function,
+/// as used in the `animation-timeline` property.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+pub struct ViewFunction {
+  /// The scroll axis of the timeline.
+  pub axis: ScrollAxis,
+  /// The insets of the view progress visibility range.
+  pub inset: ViewTimelineInset,
+}
+
+impl<'i> Parse<'i> for ViewFunction {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    input.expect_function_matching("view")?;
+    input.parse_nested_block(|input| {
+      let mut axis = None;
+      let mut inset = None;
+      loop {
+        if axis.is_none() {
+          if let Ok(value) = input.try_parse(ScrollAxis::parse) {
+            axis = Some(value);
+            continue;
+          }
+        }
+
+        if inset.is_none() {
+          if let Ok(value) = input.try_parse(ViewTimelineInset::parse) {
+            inset = Some(value);
+            continue;
+          }
+        }
+
+        break;
+      }
+
+      Ok(ViewFunction {
+        axis: axis.unwrap_or_default(),
+        inset: inset.unwrap_or_default(),
+      })
+    })
+  }
+}
+
+impl ToCss for ViewFunction {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    dest.write_str("view(")?;
+
+    let mut needs_space = false;
+    if self.axis != ScrollAxis::default() {
+      self.axis.to_css(dest)?;
+      needs_space = true;
+    }
+
+    if self.inset != ViewTimelineInset::default() {
+      if needs_space {
+        dest.write_char(' ')?;
+      }
+      self.inset.to_css(dest)?;
+    }
+
+    dest.write_char(')')
+  }
+}
+
+/// A value for the [animation-timeline](https://drafts.csswg.org/css-animations-2/#animation-timeline) property.
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "type", content = "value", rename_all = "kebab-case")
+)]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+pub enum AnimationTimeline<'i> {
+  /// The animation's timeline is the document's default timeline.
+  #[default]
+  Auto,
+  /// The animation is not associated with a timeline.
+  None,
+  /// A named scroll or view progress timeline.
+  #[cfg_attr(feature = "serde", serde(borrow))]
+  DashedIdent(DashedIdent<'i>),
+  /// An anonymous scroll progress timeline.
+  Scroll(ScrollFunction),
+  /// An anonymous view progress timeline.
+  View(ViewFunction),
+}
+
+impl<'i> Parse<'i> for AnimationTimeline<'i> {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    if input.try_parse(|input| input.expect_ident_matching("auto")).is_ok() {
+      return Ok(AnimationTimeline::Auto);
+    }
+
+    if input.try_parse(|input| input.expect_ident_matching("none")).is_ok() {
+      return Ok(AnimationTimeline::None);
+    }
+
+    if let Ok(name) = input.try_parse(DashedIdent::parse) {
+      return Ok(AnimationTimeline::DashedIdent(name));
+    }
+
+    if let Ok(scroll) = input.try_parse(ScrollFunction::parse) {
+      return Ok(AnimationTimeline::Scroll(scroll));
+    }
+
+    Ok(AnimationTimeline::View(ViewFunction::parse(input)?))
+  }
+}
+
+impl<'i> ToCss for AnimationTimeline<'i> {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    match self {
+      AnimationTimeline::Auto => dest.write_str("auto"),
+      AnimationTimeline::None => dest.write_str("none"),
+      AnimationTimeline::DashedIdent(name) => name.to_css(dest),
+      AnimationTimeline::Scroll(scroll) => scroll.to_css(dest),
+      AnimationTimeline::View(view) => view.to_css(dest),
+    }
+  }
+}
+
+enum_property! {
+  /// A value for the [animation-composition](https://drafts.csswg.org/css-animations-2/#animation-composition) property.
+  #[derive(Default)]
+  pub enum AnimationComposition {
+    /// The result of compositing the effect value with the underlying value is simply the effect value.
+    #[default]
+    Replace,
+    /// The effect value is added to the underlying value.
+    Add,
+    /// The effect value is accumulated onto the underlying value.
+    Accumulate,
+  }
+}
+
+enum_property! {
+  /// A [named timeline range](https://drafts.csswg.org/scroll-animations-1/#named-ranges),
+  /// as used in the `animation-range-start` and `animation-range-end` properties.
+  pub enum TimelineRangeName {
+    /// The full range of the view progress timeline.
+    "cover": Cover,
+    /// The range during which the subject is fully contained by, or fully covers, the scrollport.
+    "contain": Contain,
+    /// The range during which the subject is entering the scrollport.
+    "entry": Entry,
+    /// The range during which the subject is exiting the scrollport.
+    "exit": Exit,
+    /// The range during which the subject crosses the starting edge of the scrollport.
+    "entry-crossing": EntryCrossing,
+    /// The range during which the subject crosses the ending edge of the scrollport.
+    "exit-crossing": ExitCrossing,
+  }
+}
+
+/// A point on an animation's timeline, as used in the `animation-range-start` and `animation-range-end` properties.
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "type", rename_all = "kebab-case")
+)]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+pub enum AnimationAttachmentRange {
+  /// The start or end of the animation's timeline.
+  #[default]
+  Normal,
+  /// An offset from the start of the animation's timeline.
+  #[cfg_attr(
+    feature = "serde",
+    serde(with = "crate::serialization::ValueWrapper::<LengthPercentage>")
+  )]
+  LengthPercentage(LengthPercentage),
+  /// An offset within a named timeline range.
+  TimelineRange {
+    /// The name of the timeline range.
+    name: TimelineRangeName,
+    /// The offset from the start of the named range.
+    offset: LengthPercentage,
+  },
+}
+
+impl AnimationAttachmentRange {
+  fn parse<'i>(input: &mut Parser<'i, '_>, default_offset: f32) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    if input.try_parse(|input| input.expect_ident_matching("normal")).is_ok() {
+      return Ok(AnimationAttachmentRange::Normal);
+    }
+
+    if let Ok(val) = input.try_parse(LengthPercentage::parse) {
+      return Ok(AnimationAttachmentRange::LengthPercentage(val));
+    }
+
+    let name = TimelineRangeName::parse(input)?;
+    let offset = input
+      .try_parse(LengthPercentage::parse)
+      .unwrap_or(LengthPercentage::Percentage(Percentage(default_offset)));
+    Ok(AnimationAttachmentRange::TimelineRange { name, offset })
+  }
+
+  fn to_css<W>(&self, dest: &mut Printer<W>, default_offset: f32) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    match self {
+      AnimationAttachmentRange::Normal => dest.write_str("normal"),
+      AnimationAttachmentRange::LengthPercentage(val) => val.to_css(dest),
+      AnimationAttachmentRange::TimelineRange { name, offset } => {
+        name.to_css(dest)?;
+        if *offset != LengthPercentage::Percentage(Percentage(default_offset)) {
+          dest.write_char(' ')?;
+          offset.to_css(dest)?;
+        }
+        Ok(())
+      }
+    }
+  }
+}
+
+/// A value for the [animation-range-start](https://drafts.csswg.org/scroll-animations-1/#animation-range-start) property.
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(transparent))]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+pub struct AnimationRangeStart(pub AnimationAttachmentRange);
+
+impl<'i> Parse<'i> for AnimationRangeStart {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    // A range name without an offset starts at 0%.
+    Ok(AnimationRangeStart(AnimationAttachmentRange::parse(input, 0.0)?))
+  }
+}
+
+impl ToCss for AnimationRangeStart {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    self.0.to_css(dest, 0.0)
+  }
+}
+
+/// A value for the [animation-range-end](https://drafts.csswg.org/scroll-animations-1/#animation-range-end) property.
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(transparent))]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+pub struct AnimationRangeEnd(pub AnimationAttachmentRange);
+
+impl<'i> Parse<'i> for AnimationRangeEnd {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    // A range name without an offset ends at 100%.
+    Ok(AnimationRangeEnd(AnimationAttachmentRange::parse(input, 1.0)?))
+  }
+}
+
+impl ToCss for AnimationRangeEnd {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    self.0.to_css(dest, 1.0)
+  }
+}
+
+define_list_shorthand! {
+  /// A value for the [animation-range](https://drafts.csswg.org/scroll-animations-1/#animation-range) shorthand property.
+  pub struct AnimationRange {
+    /// The start of the animation's attachment range.
+    start: AnimationRangeStart(AnimationRangeStart),
+    /// The end of the animation's attachment range.
+    end: AnimationRangeEnd(AnimationRangeEnd),
+  }
+}
+
+impl AnimationRange {
+  /// Returns the end value that is implied when it is omitted from the shorthand.
+  fn implied_end(start: &AnimationRangeStart) -> AnimationRangeEnd {
+    match &start.0 {
+      AnimationAttachmentRange::TimelineRange { name, .. } => {
+        AnimationRangeEnd(AnimationAttachmentRange::TimelineRange {
+          name: *name,
+          offset: LengthPercentage::Percentage(Percentage(1.0)),
+        })
+      }
+      _ => AnimationRangeEnd(AnimationAttachmentRange::Normal),
+    }
+  }
+}
+
+impl<'i> Parse<'i> for AnimationRange {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    let start = AnimationRangeStart::parse(input)?;
+    let end = match input.try_parse(AnimationRangeEnd::parse) {
+      Ok(end) => end,
+      Err(_) => AnimationRange::implied_end(&start),
+    };
+    Ok(AnimationRange { start, end })
+  }
+}
+
+impl ToCss for AnimationRange {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    match (&self.start.0, &self.end.0) {
+      // The start offset must be written if the end is a bare offset, otherwise it would be parsed as the start offset.
+      (
+        AnimationAttachmentRange::TimelineRange { name, offset },
+        AnimationAttachmentRange::LengthPercentage(_),
+      ) => {
+        name.to_css(dest)?;
+        dest.write_char(' ')?;
+        offset.to_css(dest)?;
+      }
+      _ => self.start.to_css(dest)?,
+    }
+
+    if self.end != AnimationRange::implied_end(&self.start) {
+      dest.write_char(' ')?;
+      self.end.to_css(dest)?;
+    }
+
+    Ok(())
+  }
+}
+
 define_list_shorthand! {
   /// A value for the [animation](https://drafts.csswg.org/css-animations/#animation) shorthand property.
   pub struct Animation<'i>(VendorPrefix) {
@@ -248,6 +680,12 @@ impl<'i> Parse<'i> for Animation<'i> {
       break;
     }
 
+    // animation-duration must not be negative. A negative delay is allowed, but the
+    // first time value in the shorthand always sets the duration.
+    if matches!(&duration, Some(duration) if duration.is_sign_negative()) {
+      return Err(input.new_custom_error(ParserError::InvalidValue));
+    }
+
     Ok(Animation {
       name: name.unwrap_or(AnimationName::None),
       duration: duration.unwrap_or(Time::Seconds(0.0)),
@@ -329,6 +767,10 @@ pub(crate) struct AnimationHandler<'i> {
   play_states: Option<(SmallVec<[AnimationPlayState; 1]>, VendorPrefix)>,
   delays: Option<(SmallVec<[Time; 1]>, VendorPrefix)>,
   fill_modes: Option<(SmallVec<[AnimationFillMode; 1]>, VendorPrefix)>,
+  timelines: Option<SmallVec<[AnimationTimeline<'i>; 1]>>,
+  compositions: Option<SmallVec<[AnimationComposition; 1]>>,
+  range_starts: Option<SmallVec<[AnimationRangeStart; 1]>>,
+  range_ends: Option<SmallVec<[AnimationRangeEnd; 1]>>,
   has_any: bool,
 }
 
@@ -370,6 +812,12 @@ impl<'i> PropertyHandler<'i> for AnimationHandler<'i> {
 
     match property {
       AnimationName(val, vp) => property!(names, val, vp),
+      AnimationDuration(val, _) if val.iter().any(|duration| duration.is_sign_negative()) => {
+        // A negative duration is invalid. Merging it into the shorthand would invalidate the whole
+        // shorthand, so preserve it as is and let the browser drop only this declaration.
+        self.flush(dest, context);
+        dest.push(property.clone());
+      }
       AnimationDuration(val, vp) => property!(durations, val, vp),
       AnimationTimingFunction(val, vp) => property!(timing_functions, val, vp),
       AnimationIterationCount(val, vp) => property!(iteration_counts, val, vp),
@@ -377,7 +825,38 @@ impl<'i> PropertyHandler<'i> for AnimationHandler<'i> {
       AnimationPlayState(val, vp) => property!(play_states, val, vp),
       AnimationDelay(val, vp) => property!(delays, val, vp),
       AnimationFillMode(val, vp) => property!(fill_modes, val, vp),
+      AnimationTimeline(val) => {
+        self.timelines = Some(val.clone());
+        self.has_any = true;
+      }
+      AnimationComposition(val) => {
+        self.compositions = Some(val.clone());
+        self.has_any = true;
+      }
+      Property::AnimationRangeStart(val) => {
+        self.range_starts = Some(val.clone());
+        self.has_any = true;
+      }
+      Property::AnimationRangeEnd(val) => {
+        self.range_ends = Some(val.clone());
+        self.has_any = true;
+      }
+      AnimationRange(val) => {
+        self.range_starts = Some(val.iter().map(|r| r.start.clone()).collect());
+        self.range_ends = Some(val.iter().map(|r| r.end.clone()).collect());
+        self.has_any = true;
+      }
       Animation(val, vp) => {
+        // The animation shorthand resets animation-timeline, animation-composition, and animation-range,
+        // so any of these declared before it must be written first to preserve the cascade.
+        if self.timelines.is_some()
+          || self.compositions.is_some()
+          || self.range_starts.is_some()
+          || self.range_ends.is_some()
+        {
+          self.flush(dest, context);
+        }
+
         let names = val.iter().map(|b| b.name.clone()).collect();
         maybe_flush!(names, &names, vp);
 
@@ -444,6 +923,10 @@ impl<'i> AnimationHandler<'i> {
     let mut play_states = std::mem::take(&mut self.play_states);
     let mut delays = std::mem::take(&mut self.delays);
     let mut fill_modes = std::mem::take(&mut self.fill_modes);
+    let timelines = std::mem::take(&mut self.timelines);
+    let compositions = std::mem::take(&mut self.compositions);
+    let range_starts = std::mem::take(&mut self.range_starts);
+    let range_ends = std::mem::take(&mut self.range_ends);
 
     if let (
       Some((names, names_vp)),
@@ -540,6 +1023,34 @@ impl<'i> AnimationHandler<'i> {
     prop!(play_states, AnimationPlayState);
     prop!(delays, AnimationDelay);
     prop!(fill_modes, AnimationFillMode);
+
+    // These properties must come after the animation shorthand, which resets them.
+    if let Some(timelines) = timelines {
+      dest.push(Property::AnimationTimeline(timelines));
+    }
+
+    if let Some(compositions) = compositions {
+      dest.push(Property::AnimationComposition(compositions));
+    }
+
+    match (range_starts, range_ends) {
+      (Some(starts), Some(ends)) if starts.len() == ends.len() => {
+        let ranges = starts
+          .into_iter()
+          .zip(ends)
+          .map(|(start, end)| AnimationRange { start, end })
+          .collect();
+        dest.push(Property::AnimationRange(ranges));
+      }
+      (starts, ends) => {
+        if let Some(starts) = starts {
+          dest.push(Property::AnimationRangeStart(starts));
+        }
+        if let Some(ends) = ends {
+          dest.push(Property::AnimationRangeEnd(ends));
+        }
+      }
+    }
   }
 }
 
@@ -554,6 +1065,11 @@ fn is_animation_property(property_id: &PropertyId) -> bool {
     | PropertyId::AnimationPlayState(_)
     | PropertyId::AnimationDelay(_)
     | PropertyId::AnimationFillMode(_)
+    | PropertyId::AnimationTimeline
+    | PropertyId::AnimationComposition
+    | PropertyId::AnimationRangeStart
+    | PropertyId::AnimationRangeEnd
+    | PropertyId::AnimationRange
     | PropertyId::Animation(_) => true,
     _ => false,
   }
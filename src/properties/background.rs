@@ -6,9 +6,10 @@ use crate::error::{ParserError, PrinterError};
 use crate::macros::*;
 use crate::prefixes::Feature;
 use crate::printer::Printer;
+use crate::properties::effects::BlendMode;
 use crate::properties::{Property, PropertyId, VendorPrefix};
 use crate::targets::{Browsers, Targets};
-use crate::traits::{FallbackValues, IsCompatible, Parse, PropertyHandler, Shorthand, ToCss};
+use crate::traits::{FallbackValues, IsCompatible, Parse, PropertyHandler, Shorthand, ToCss, TrySign};
 use crate::values::color::ColorFallbackKind;
 use crate::values::image::ImageFallback;
 use crate::values::{color::CssColor, image::Image, length::LengthPercentageOrAuto, position::*};
@@ -57,6 +58,12 @@ impl<'i> Parse<'i> for BackgroundSize {
       let height = input
         .try_parse(LengthPercentageOrAuto::parse)
         .unwrap_or(LengthPercentageOrAuto::Auto);
+      // Negative values are invalid.
+      if matches!(&width, LengthPercentageOrAuto::LengthPercentage(w) if w.is_sign_negative())
+        || matches!(&height, LengthPercentageOrAuto::LengthPercentage(h) if h.is_sign_negative())
+      {
+        return Err(input.new_custom_error(ParserError::InvalidValue));
+      }
       return Ok(BackgroundSize::Explicit { width, height });
     }
 
@@ -504,7 +511,13 @@ impl<'i> ToCss for Background<'i> {
       has_output = true;
     }
 
-    if (output_padding_box && self.clip != self.origin) || self.clip != BackgroundOrigin::BorderBox {
+    // A single box value sets both the origin and clip.
+    let output_clip = if output_padding_box {
+      self.clip != self.origin
+    } else {
+      self.clip != BackgroundOrigin::BorderBox
+    };
+    if output_clip {
       if has_output {
         dest.write_str(" ")?;
       }
@@ -786,6 +799,7 @@ property_bitflags! {
     const BackgroundAttachment = 1 << 6;
     const BackgroundOrigin = 1 << 7;
     const BackgroundClip(_vp) = 1 << 8;
+    const BackgroundBlendMode = 1 << 9;
     const Background = Self::BackgroundColor.bits() | Self::BackgroundImage.bits() | Self::BackgroundPosition.bits() | Self::BackgroundRepeat.bits() | Self::BackgroundSize.bits() | Self::BackgroundAttachment.bits() | Self::BackgroundOrigin.bits() | Self::BackgroundClip.bits();
   }
 }
@@ -802,6 +816,7 @@ pub(crate) struct BackgroundHandler<'i> {
   attachments: Option<SmallVec<[BackgroundAttachment; 1]>>,
   origins: Option<SmallVec<[BackgroundOrigin; 1]>>,
   clips: Option<(SmallVec<[BackgroundClip; 1]>, VendorPrefix)>,
+  blend_modes: Option<SmallVec<[BlendMode; 1]>>,
   decls: Vec<Property<'i>>,
   flushed_properties: BackgroundProperty,
   has_any: bool,
@@ -871,6 +886,7 @@ impl<'i> PropertyHandler<'i> for BackgroundHandler<'i> {
           self.clips = Some((val.clone(), *vendor_prefix))
         }
       }
+      Property::BackgroundBlendMode(val) => self.blend_modes = Some(val.clone()),
       Property::Background(val) => {
         let images: SmallVec<[Image; 1]> = val.iter().map(|b| b.image.clone()).collect();
         background_image!(&images);
@@ -948,6 +964,7 @@ impl<'i> BackgroundHandler<'i> {
     let mut attachments = std::mem::take(&mut self.attachments);
     let mut origins = std::mem::take(&mut self.origins);
     let mut clips = std::mem::take(&mut self.clips);
+    let blend_modes = std::mem::take(&mut self.blend_modes);
 
     if let (
       Some(color),
@@ -1041,6 +1058,12 @@ impl<'i> BackgroundHandler<'i> {
           self.flushed_properties.insert(BackgroundProperty::BackgroundClip);
         }
 
+        // The background shorthand does not reset background-blend-mode,
+        // so it is always output as a longhand.
+        if let Some(blend_modes) = blend_modes {
+          push!(BackgroundBlendMode, blend_modes);
+        }
+
         self.reset();
         return;
       }
@@ -1110,6 +1133,10 @@ impl<'i> BackgroundHandler<'i> {
       self.flushed_properties.insert(BackgroundProperty::BackgroundClip);
     }
 
+    if let Some(blend_modes) = blend_modes {
+      push!(BackgroundBlendMode, blend_modes);
+    }
+
     self.reset();
   }
 
@@ -1122,7 +1149,8 @@ impl<'i> BackgroundHandler<'i> {
     self.sizes = None;
     self.attachments = None;
     self.origins = None;
-    self.clips = None
+    self.clips = None;
+    self.blend_modes = None;
   }
 }
 
@@ -1139,6 +1167,7 @@ fn is_background_property(property_id: &PropertyId) -> bool {
     | PropertyId::BackgroundAttachment
     | PropertyId::BackgroundOrigin
     | PropertyId::BackgroundClip(_)
+    | PropertyId::BackgroundBlendMode
     | PropertyId::Background => true,
     _ => false,
   }
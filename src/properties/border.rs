@@ -13,7 +13,7 @@ use crate::properties::custom::UnparsedProperty;
 use crate::properties::{Property, PropertyId};
 use crate::targets::Browsers;
 use crate::targets::Targets;
-use crate::traits::{FallbackValues, IsCompatible, Parse, PropertyHandler, Shorthand, ToCss};
+use crate::traits::{FallbackValues, IsCompatible, Parse, PropertyHandler, Shorthand, ToCss, TrySign};
 use crate::values::color::{ColorFallbackKind, CssColor};
 use crate::values::length::*;
 use crate::values::rect::Rect;
@@ -61,6 +61,9 @@ impl IsCompatible for BorderSideWidth {
 impl<'i> Parse<'i> for BorderSideWidth {
   fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
     if let Ok(length) = input.try_parse(|i| Length::parse(i)) {
+      if length.is_sign_negative() {
+        return Err(input.new_custom_error(ParserError::InvalidValue));
+      }
       return Ok(BorderSideWidth::Length(length));
     }
     let location = input.current_source_location();
@@ -7,7 +7,7 @@ use crate::prefixes::Feature;
 use crate::printer::Printer;
 use crate::properties::{Property, PropertyId, VendorPrefix};
 use crate::targets::{Browsers, Targets};
-use crate::traits::{IsCompatible, Parse, PropertyHandler, Shorthand, ToCss};
+use crate::traits::{IsCompatible, Parse, PropertyHandler, Shorthand, ToCss, TrySign};
 use crate::values::image::Image;
 use crate::values::number::CSSNumber;
 use crate::values::rect::Rect;
@@ -133,10 +133,16 @@ impl<'i> Parse<'i> for BorderImageSideWidth {
     }
 
     if let Ok(number) = input.try_parse(CSSNumber::parse) {
+      if number < 0.0 {
+        return Err(input.new_custom_error(ParserError::InvalidValue));
+      }
       return Ok(BorderImageSideWidth::Number(number));
     }
 
     if let Ok(percent) = input.try_parse(|input| LengthPercentage::parse(input)) {
+      if percent.is_sign_negative() {
+        return Err(input.new_custom_error(ParserError::InvalidValue));
+      }
       return Ok(BorderImageSideWidth::LengthPercentage(percent));
     }
 
@@ -192,7 +198,13 @@ impl Default for BorderImageSlice {
 impl<'i> Parse<'i> for BorderImageSlice {
   fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
     let mut fill = input.try_parse(|i| i.expect_ident_matching("fill")).is_ok();
-    let offsets = Rect::parse(input)?;
+    let offsets: Rect<NumberOrPercentage> = Rect::parse(input)?;
+    if [&offsets.0, &offsets.1, &offsets.2, &offsets.3].iter().any(|offset| {
+      let value: f32 = (*offset).into();
+      value < 0.0
+    }) {
+      return Err(input.new_custom_error(ParserError::InvalidValue));
+    }
     if !fill {
       fill = input.try_parse(|i| i.expect_ident_matching("fill")).is_ok();
     }
@@ -268,11 +280,18 @@ impl<'i> BorderImage<'i> {
               // Parse border image width, if applicable.
               let w = input.try_parse(|input| Rect::parse(input)).ok();
 
-              // Parse border image outset if applicable.
+              // Parse border image outset if applicable. Negative outsets are invalid.
               let o = input
                 .try_parse(|input| {
                   input.expect_delim('/')?;
-                  Rect::parse(input)
+                  let outset: Rect<LengthOrNumber> = Rect::parse(input)?;
+                  if [&outset.0, &outset.1, &outset.2, &outset.3].iter().any(|v| match v {
+                    LengthOrNumber::Length(l) => l.is_sign_negative(),
+                    LengthOrNumber::Number(n) => *n < 0.0,
+                  }) {
+                    return Err(input.new_custom_error(ParserError::InvalidValue));
+                  }
+                  Ok(outset)
                 })
                 .ok();
               if w.is_none() && o.is_none() {
@@ -334,14 +353,18 @@ impl<'i> BorderImage<'i> {
   where
     W: std::fmt::Write,
   {
+    let mut has_output = false;
     if *source != Image::default() {
       source.to_css(dest)?;
+      has_output = true;
     }
     let has_slice = *slice != BorderImageSlice::default();
     let has_width = *width != Rect::all(BorderImageSideWidth::default());
     let has_outset = *outset != Rect::all(LengthOrNumber::Number(0.0));
     if has_slice || has_width || has_outset {
-      dest.write_str(" ")?;
+      if has_output {
+        dest.write_str(" ")?;
+      }
       slice.to_css(dest)?;
       if has_width || has_outset {
         dest.delim('/', true)?;
@@ -354,11 +377,19 @@ impl<'i> BorderImage<'i> {
         dest.delim('/', true)?;
         outset.to_css(dest)?;
       }
+      has_output = true;
     }
 
     if *repeat != BorderImageRepeat::default() {
-      dest.write_str(" ")?;
+      if has_output {
+        dest.write_str(" ")?;
+      }
       repeat.to_css(dest)?;
+      has_output = true;
+    }
+
+    if !has_output {
+      dest.write_str("none")?;
     }
 
     Ok(())
@@ -9,7 +9,7 @@ use crate::macros::define_shorthand;
 use crate::prefixes::Feature;
 use crate::printer::Printer;
 use crate::properties::{Property, PropertyId, VendorPrefix};
-use crate::traits::{IsCompatible, Parse, PropertyHandler, Shorthand, ToCss, Zero};
+use crate::traits::{IsCompatible, Parse, PropertyHandler, Shorthand, ToCss, TrySign, Zero};
 use crate::values::length::*;
 use crate::values::rect::Rect;
 use crate::values::size::Size2D;
@@ -52,6 +52,14 @@ impl<'i> Parse<'i> for BorderRadius {
       widths.clone()
     };
 
+    // Negative radii are invalid.
+    if [&widths, &heights]
+      .iter()
+      .any(|r| [&r.0, &r.1, &r.2, &r.3].iter().any(|v| v.is_sign_negative()))
+    {
+      return Err(input.new_custom_error(ParserError::InvalidValue));
+    }
+
     Ok(BorderRadius {
       top_left: Size2D(widths.0, heights.0),
       top_right: Size2D(widths.1, heights.1),
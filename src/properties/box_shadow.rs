@@ -8,7 +8,7 @@ use crate::prefixes::Feature;
 use crate::printer::Printer;
 use crate::properties::Property;
 use crate::targets::Browsers;
-use crate::traits::{IsCompatible, Parse, PropertyHandler, ToCss, Zero};
+use crate::traits::{IsCompatible, Parse, PropertyHandler, ToCss, TrySign, Zero};
 use crate::values::color::{ColorFallbackKind, CssColor};
 use crate::values::length::Length;
 use crate::vendor_prefix::VendorPrefix;
@@ -61,6 +61,9 @@ impl<'i> Parse<'i> for BoxShadow {
           let horizontal = Length::parse(input)?;
           let vertical = Length::parse(input)?;
           let blur = input.try_parse(Length::parse).unwrap_or(Length::zero());
+          if blur.is_sign_negative() {
+            return Err(input.new_custom_error(ParserError::InvalidValue));
+          }
           let spread = input.try_parse(Length::parse).unwrap_or(Length::zero());
           Ok((horizontal, vertical, blur, spread))
         });
@@ -0,0 +1,243 @@
+//! CSS properties related to multi-column layout.
+
+use super::border::{BorderSideWidth, GenericBorder, LineStyle};
+use super::{Property, PropertyId};
+use crate::context::PropertyHandlerContext;
+use crate::declaration::{DeclarationBlock, DeclarationList};
+use crate::error::{ParserError, PrinterError};
+use crate::macros::{define_shorthand, enum_property, impl_shorthand, shorthand_handler};
+use crate::printer::Printer;
+use crate::targets::Browsers;
+use crate::traits::{FallbackValues, IsCompatible, Parse, PropertyHandler, Shorthand, ToCss, TrySign, Zero};
+use crate::values::color::CssColor;
+use crate::values::length::Length;
+use crate::values::number::CSSInteger;
+#[cfg(feature = "visitor")]
+use crate::visitor::Visit;
+use cssparser::*;
+
+/// A value for the [column-width](https://www.w3.org/TR/css-multicol-1/#cw) property.
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "type", content = "value", rename_all = "kebab-case")
+)]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+pub enum ColumnWidth {
+  /// The column width is determined by other properties, such as `column-count`.
+  #[default]
+  Auto,
+  /// An explicit column width.
+  Length(Length),
+}
+
+impl<'i> Parse<'i> for ColumnWidth {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    if input.try_parse(|i| i.expect_ident_matching("auto")).is_ok() {
+      return Ok(ColumnWidth::Auto);
+    }
+
+    // Unitless numbers other than zero are not lengths. This also ensures that the count
+    // in the `columns` shorthand is not parsed as a width.
+    let location = input.current_source_location();
+    if let Ok(number) = input.try_parse(|i| i.expect_number()) {
+      if number != 0.0 {
+        return Err(location.new_custom_error(ParserError::InvalidValue));
+      }
+      return Ok(ColumnWidth::Length(Length::zero()));
+    }
+
+    let length = Length::parse(input)?;
+    if length.is_sign_negative() {
+      return Err(input.new_custom_error(ParserError::InvalidValue));
+    }
+    Ok(ColumnWidth::Length(length))
+  }
+}
+
+impl ToCss for ColumnWidth {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    match self {
+      ColumnWidth::Auto => dest.write_str("auto"),
+      ColumnWidth::Length(length) => length.to_css(dest),
+    }
+  }
+}
+
+impl IsCompatible for ColumnWidth {
+  fn is_compatible(&self, browsers: Browsers) -> bool {
+    match self {
+      ColumnWidth::Auto => true,
+      ColumnWidth::Length(length) => length.is_compatible(browsers),
+    }
+  }
+}
+
+/// A value for the [column-count](https://www.w3.org/TR/css-multicol-1/#cc) property.
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "type", content = "value", rename_all = "kebab-case")
+)]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+pub enum ColumnCount {
+  /// The number of columns is determined by other properties, such as `column-width`.
+  #[default]
+  Auto,
+  /// An explicit number of columns. Must be greater than zero.
+  Integer(CSSInteger),
+}
+
+impl<'i> Parse<'i> for ColumnCount {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    if input.try_parse(|i| i.expect_ident_matching("auto")).is_ok() {
+      return Ok(ColumnCount::Auto);
+    }
+
+    let count = CSSInteger::parse(input)?;
+    if count < 1 {
+      return Err(input.new_custom_error(ParserError::InvalidValue));
+    }
+    Ok(ColumnCount::Integer(count))
+  }
+}
+
+impl ToCss for ColumnCount {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    match self {
+      ColumnCount::Auto => dest.write_str("auto"),
+      ColumnCount::Integer(count) => count.to_css(dest),
+    }
+  }
+}
+
+impl IsCompatible for ColumnCount {
+  fn is_compatible(&self, _browsers: Browsers) -> bool {
+    true
+  }
+}
+
+define_shorthand! {
+  /// A value for the [columns](https://www.w3.org/TR/css-multicol-1/#columns) shorthand property.
+  pub struct Columns {
+    /// The column width.
+    width: ColumnWidth(ColumnWidth),
+    /// The column count.
+    count: ColumnCount(ColumnCount),
+  }
+}
+
+impl<'i> Parse<'i> for Columns {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    let mut width = None;
+    let mut count = None;
+    let mut autos = 0;
+
+    for _ in 0..2 {
+      // `auto` applies to whichever of the values is not otherwise set.
+      if input.try_parse(|i| i.expect_ident_matching("auto")).is_ok() {
+        autos += 1;
+        continue;
+      }
+
+      if width.is_none() {
+        if let Ok(value) = input.try_parse(ColumnWidth::parse) {
+          width = Some(value);
+          continue;
+        }
+      }
+
+      if count.is_none() {
+        if let Ok(value) = input.try_parse(ColumnCount::parse) {
+          count = Some(value);
+          continue;
+        }
+      }
+
+      break;
+    }
+
+    if autos == 0 && width.is_none() && count.is_none() {
+      return Err(input.new_custom_error(ParserError::InvalidDeclaration));
+    }
+
+    Ok(Columns {
+      width: width.unwrap_or_default(),
+      count: count.unwrap_or_default(),
+    })
+  }
+}
+
+impl ToCss for Columns {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    match (&self.width, &self.count) {
+      (ColumnWidth::Auto, ColumnCount::Auto) => dest.write_str("auto"),
+      (width, ColumnCount::Auto) => width.to_css(dest),
+      (ColumnWidth::Auto, count) => count.to_css(dest),
+      (width, count) => {
+        width.to_css(dest)?;
+        dest.write_char(' ')?;
+        count.to_css(dest)
+      }
+    }
+  }
+}
+
+shorthand_handler!(ColumnsHandler -> Columns {
+  width: ColumnWidth(ColumnWidth),
+  count: ColumnCount(ColumnCount),
+});
+
+/// A value for the [column-rule](https://www.w3.org/TR/css-multicol-1/#column-rule) shorthand property.
+pub type ColumnRule = GenericBorder<LineStyle, 12>;
+
+impl_shorthand! {
+  ColumnRule(ColumnRule) {
+    width: [ColumnRuleWidth],
+    style: [ColumnRuleStyle],
+    color: [ColumnRuleColor],
+  }
+}
+
+shorthand_handler!(ColumnRuleHandler -> ColumnRule fallbacks: true {
+  width: ColumnRuleWidth(BorderSideWidth),
+  style: ColumnRuleStyle(LineStyle),
+  color: ColumnRuleColor(CssColor, fallback: true),
+});
+
+enum_property! {
+  /// A value for the [column-span](https://www.w3.org/TR/css-multicol-1/#column-span) property.
+  pub enum ColumnSpan {
+    /// The element does not span multiple columns.
+    None,
+    /// The element spans across all columns.
+    All,
+  }
+}
+
+enum_property! {
+  /// A value for the [column-fill](https://www.w3.org/TR/css-multicol-1/#cf) property.
+  pub enum ColumnFill {
+    /// Columns are filled sequentially.
+    "auto": Auto,
+    /// Content is balanced across columns, except in the last fragment.
+    "balance": Balance,
+    /// Content is balanced across columns in all fragments.
+    "balance-all": BalanceAll,
+  }
+}
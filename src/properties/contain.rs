@@ -2,6 +2,7 @@
 
 #![allow(non_upper_case_globals)]
 
+use bitflags::bitflags;
 use cssparser::*;
 use smallvec::SmallVec;
 
@@ -19,6 +20,249 @@ use crate::{
   traits::{IsCompatible, Parse, PropertyHandler, Shorthand, ToCss},
 };
 
+enum_property! {
+  /// A value for the [content-visibility](https://drafts.csswg.org/css-contain-2/#content-visibility) property.
+  pub enum ContentVisibility {
+    /// The element's contents are laid out and rendered as normal.
+    Visible,
+    /// The element skips its contents.
+    Hidden,
+    /// The element skips its contents if it is not relevant to the user.
+    Auto,
+  }
+}
+
+bitflags! {
+  /// A value for the [contain](https://drafts.csswg.org/css-contain-2/#contain-property) property.
+  ///
+  /// An empty value represents `none`. At most one of `Size` and `InlineSize` may be set.
+  #[cfg_attr(feature = "visitor", derive(Visit))]
+  #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(from = "SerializedContain", into = "SerializedContain"))]
+  #[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+  #[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Clone, Copy)]
+  pub struct Contain: u8 {
+    /// Size containment in both axes.
+    const Size       = 1 << 0;
+    /// Size containment in the inline axis.
+    const InlineSize = 1 << 1;
+    /// Layout containment.
+    const Layout     = 1 << 2;
+    /// Style containment.
+    const Style      = 1 << 3;
+    /// Paint containment.
+    const Paint      = 1 << 4;
+    /// All containment except inline size. Equivalent to `size layout paint style`.
+    const Strict     = Self::Size.bits() | Self::Layout.bits() | Self::Paint.bits() | Self::Style.bits();
+    /// All containment except size. Equivalent to `layout paint style`.
+    const Content    = Self::Layout.bits() | Self::Paint.bits() | Self::Style.bits();
+  }
+}
+
+impl Default for Contain {
+  fn default() -> Contain {
+    Contain::empty()
+  }
+}
+
+impl<'i> Parse<'i> for Contain {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    if input.try_parse(|input| input.expect_ident_matching("none")).is_ok() {
+      return Ok(Contain::empty());
+    }
+
+    if input.try_parse(|input| input.expect_ident_matching("strict")).is_ok() {
+      return Ok(Contain::Strict);
+    }
+
+    if input.try_parse(|input| input.expect_ident_matching("content")).is_ok() {
+      return Ok(Contain::Content);
+    }
+
+    let mut value = Contain::empty();
+    loop {
+      let flag: Result<_, ParseError<'i, ParserError<'i>>> = input.try_parse(|input| {
+        let location = input.current_source_location();
+        let ident = input.expect_ident()?;
+        let (flag, group) = match_ignore_ascii_case! { &ident,
+          "size" => (Contain::Size, Contain::Size | Contain::InlineSize),
+          "inline-size" => (Contain::InlineSize, Contain::Size | Contain::InlineSize),
+          "layout" => (Contain::Layout, Contain::Layout),
+          "style" => (Contain::Style, Contain::Style),
+          "paint" => (Contain::Paint, Contain::Paint),
+          _ => return Err(location.new_unexpected_token_error(
+            cssparser::Token::Ident(ident.clone())
+          ))
+        };
+
+        // Each value may only be specified once, and `size` and `inline-size` are exclusive.
+        if value.intersects(group) {
+          return Err(location.new_custom_error(ParserError::InvalidValue));
+        }
+
+        Ok(flag)
+      });
+
+      if let Ok(flag) = flag {
+        value |= flag;
+      } else {
+        break;
+      }
+    }
+
+    if value.is_empty() {
+      return Err(input.new_custom_error(ParserError::InvalidDeclaration));
+    }
+
+    Ok(value)
+  }
+}
+
+impl ToCss for Contain {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    if self.is_empty() {
+      return dest.write_str("none");
+    }
+
+    if *self == Contain::Strict {
+      return dest.write_str("strict");
+    }
+
+    if *self == Contain::Content {
+      return dest.write_str("content");
+    }
+
+    let mut needs_space = false;
+    macro_rules! val {
+      ($val: ident, $str: expr) => {
+        #[allow(unused_assignments)]
+        if self.contains(Contain::$val) {
+          if needs_space {
+            dest.write_char(' ')?;
+          }
+          dest.write_str($str)?;
+          needs_space = true;
+        }
+      };
+    }
+
+    val!(Size, "size");
+    val!(InlineSize, "inline-size");
+    val!(Layout, "layout");
+    val!(Style, "style");
+    val!(Paint, "paint");
+    Ok(())
+  }
+}
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(untagged))]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+enum SerializedContain {
+  Exclusive(ExclusiveContain),
+  Other(Vec<OtherContain>),
+}
+
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(rename_all = "kebab-case")
+)]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+enum ExclusiveContain {
+  None,
+  Strict,
+  Content,
+}
+
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(rename_all = "kebab-case")
+)]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+enum OtherContain {
+  Size,
+  InlineSize,
+  Layout,
+  Style,
+  Paint,
+}
+
+impl From<Contain> for SerializedContain {
+  fn from(c: Contain) -> Self {
+    if c.is_empty() {
+      return Self::Exclusive(ExclusiveContain::None);
+    }
+
+    if c == Contain::Strict {
+      return Self::Exclusive(ExclusiveContain::Strict);
+    }
+
+    if c == Contain::Content {
+      return Self::Exclusive(ExclusiveContain::Content);
+    }
+
+    let mut v = Vec::new();
+    macro_rules! other {
+      ($c: ident) => {
+        if c.contains(Contain::$c) {
+          v.push(OtherContain::$c)
+        }
+      };
+    }
+
+    other!(Size);
+    other!(InlineSize);
+    other!(Layout);
+    other!(Style);
+    other!(Paint);
+    Self::Other(v)
+  }
+}
+
+impl From<SerializedContain> for Contain {
+  fn from(c: SerializedContain) -> Self {
+    match c {
+      SerializedContain::Exclusive(v) => match v {
+        ExclusiveContain::None => Contain::empty(),
+        ExclusiveContain::Strict => Contain::Strict,
+        ExclusiveContain::Content => Contain::Content,
+      },
+      SerializedContain::Other(v) => {
+        let mut res = Contain::empty();
+        for val in v {
+          res |= match val {
+            OtherContain::Size => Contain::Size,
+            OtherContain::InlineSize => Contain::InlineSize,
+            OtherContain::Layout => Contain::Layout,
+            OtherContain::Style => Contain::Style,
+            OtherContain::Paint => Contain::Paint,
+          }
+        }
+        res
+      }
+    }
+  }
+}
+
+#[cfg(feature = "jsonschema")]
+#[cfg_attr(docsrs, doc(cfg(feature = "jsonschema")))]
+impl<'a> schemars::JsonSchema for Contain {
+  fn is_referenceable() -> bool {
+    true
+  }
+
+  fn json_schema(gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+    SerializedContain::json_schema(gen)
+  }
+
+  fn schema_name() -> String {
+    "Contain".into()
+  }
+}
+
 enum_property! {
   /// A value for the [container-type](https://drafts.csswg.org/css-contain-3/#container-type) property.
   /// Establishes the element as a query container for the purpose of container queries.
@@ -0,0 +1,421 @@
+//! CSS properties related to generated content.
+
+use super::list::{parse_counter_name, CounterStyle, PredefinedCounterStyle};
+use crate::error::{ParserError, PrinterError};
+use crate::macros::enum_property;
+use crate::printer::Printer;
+use crate::traits::{Parse, ToCss};
+use crate::values::ident::{CustomIdent, Ident};
+use crate::values::image::Image;
+use crate::values::string::CSSString;
+#[cfg(feature = "visitor")]
+use crate::visitor::Visit;
+use cssparser::*;
+
+/// A value for the [content](https://www.w3.org/TR/css-content-3/#content-property) property.
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "type", content = "value", rename_all = "kebab-case")
+)]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+pub enum Content<'i> {
+  /// For `::before` and `::after`, computes to `none`. Otherwise, the element is rendered normally.
+  #[default]
+  Normal,
+  /// The pseudo-element is not generated.
+  None,
+  /// A list of content items, with optional alternative text.
+  #[cfg_attr(feature = "serde", serde(borrow))]
+  List(ContentList<'i>),
+}
+
+impl<'i> Parse<'i> for Content<'i> {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    if input.try_parse(|input| input.expect_ident_matching("normal")).is_ok() {
+      return Ok(Content::Normal);
+    }
+
+    if input.try_parse(|input| input.expect_ident_matching("none")).is_ok() {
+      return Ok(Content::None);
+    }
+
+    Ok(Content::List(ContentList::parse(input)?))
+  }
+}
+
+impl ToCss for Content<'_> {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    match self {
+      Content::Normal => dest.write_str("normal"),
+      Content::None => dest.write_str("none"),
+      Content::List(list) => list.to_css(dest),
+    }
+  }
+}
+
+/// A list of [content](https://www.w3.org/TR/css-content-3/#content-property) items,
+/// as used in the `content` property.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(rename_all = "camelCase")
+)]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+pub struct ContentList<'i> {
+  /// The content items.
+  #[cfg_attr(feature = "serde", serde(borrow))]
+  pub items: Vec<ContentItem<'i>>,
+  /// Alternative text for the content, specified after a `/`.
+  /// Only strings, counters, and `attr()` are allowed.
+  pub alt_text: Option<Vec<ContentItem<'i>>>,
+}
+
+impl<'i> Parse<'i> for ContentList<'i> {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    let items = parse_items(input)?;
+    let alt_text = if input.try_parse(|input| input.expect_delim('/')).is_ok() {
+      let location = input.current_source_location();
+      let alt_text = parse_items(input)?;
+      if !alt_text.iter().all(ContentItem::is_valid_alt_text) {
+        return Err(location.new_custom_error(ParserError::InvalidValue));
+      }
+      Some(alt_text)
+    } else {
+      None
+    };
+
+    Ok(ContentList { items, alt_text })
+  }
+}
+
+fn parse_items<'i>(input: &mut Parser<'i, '_>) -> Result<Vec<ContentItem<'i>>, ParseError<'i, ParserError<'i>>> {
+  let mut items = vec![ContentItem::parse(input)?];
+  while let Ok(item) = input.try_parse(ContentItem::parse) {
+    items.push(item);
+  }
+  Ok(items)
+}
+
+impl ToCss for ContentList<'_> {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    items_to_css(&self.items, dest)?;
+    if let Some(alt_text) = &self.alt_text {
+      dest.delim('/', true)?;
+      items_to_css(alt_text, dest)?;
+    }
+    Ok(())
+  }
+}
+
+fn items_to_css<W>(items: &[ContentItem], dest: &mut Printer<W>) -> Result<(), PrinterError>
+where
+  W: std::fmt::Write,
+{
+  let mut first = true;
+  let mut iter = items.iter().peekable();
+  while let Some(item) = iter.next() {
+    if first {
+      first = false;
+    } else {
+      dest.write_char(' ')?;
+    }
+
+    // Adjacent strings are concatenated, so they can be written as a single string.
+    if let ContentItem::String(s) = item {
+      if matches!(iter.peek(), Some(ContentItem::String(..))) {
+        let mut string = s.to_string();
+        while let Some(ContentItem::String(s)) = iter.peek() {
+          string.push_str(s);
+          iter.next();
+        }
+        serialize_string(&string, dest)?;
+        continue;
+      }
+    }
+
+    item.to_css(dest)?;
+  }
+  Ok(())
+}
+
+/// An item within the [content](https://www.w3.org/TR/css-content-3/#content-property) property.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "type", content = "value", rename_all = "kebab-case")
+)]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+pub enum ContentItem<'i> {
+  /// A string.
+  #[cfg_attr(feature = "serde", serde(borrow))]
+  String(CSSString<'i>),
+  /// An image, such as a `url()` or `image-set()`.
+  Image(Image<'i>),
+  /// The value of a counter, via the `counter()` function.
+  Counter {
+    /// The name of the counter.
+    name: CustomIdent<'i>,
+    /// The counter style used to represent the value.
+    style: CounterStyle<'i>,
+  },
+  /// The values of all counters with the given name, via the `counters()` function.
+  Counters {
+    /// The name of the counter.
+    name: CustomIdent<'i>,
+    /// The string inserted between the values of nested counters.
+    separator: CSSString<'i>,
+    /// The counter style used to represent the values.
+    style: CounterStyle<'i>,
+  },
+  /// The value of an attribute of the element, via the `attr()` function.
+  Attr(Ident<'i>),
+  /// A quote keyword.
+  Quote(Quote),
+}
+
+impl<'i> ContentItem<'i> {
+  fn is_valid_alt_text(&self) -> bool {
+    matches!(
+      self,
+      ContentItem::String(..) | ContentItem::Counter { .. } | ContentItem::Counters { .. } | ContentItem::Attr(..)
+    )
+  }
+}
+
+impl<'i> Parse<'i> for ContentItem<'i> {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    if let Ok(s) = input.try_parse(CSSString::parse) {
+      return Ok(ContentItem::String(s));
+    }
+
+    if let Ok(quote) = input.try_parse(Quote::parse) {
+      return Ok(ContentItem::Quote(quote));
+    }
+
+    if input.try_parse(|input| input.expect_function_matching("counter")).is_ok() {
+      return input.parse_nested_block(|input| {
+        let name = parse_counter_name(input)?;
+        let style = parse_counter_style(input)?;
+        Ok(ContentItem::Counter { name, style })
+      });
+    }
+
+    if input.try_parse(|input| input.expect_function_matching("counters")).is_ok() {
+      return input.parse_nested_block(|input| {
+        let name = parse_counter_name(input)?;
+        input.expect_comma()?;
+        let separator = CSSString::parse(input)?;
+        let style = parse_counter_style(input)?;
+        Ok(ContentItem::Counters { name, separator, style })
+      });
+    }
+
+    if input.try_parse(|input| input.expect_function_matching("attr")).is_ok() {
+      return input.parse_nested_block(|input| Ok(ContentItem::Attr(Ident::parse(input)?)));
+    }
+
+    let location = input.current_source_location();
+    match Image::parse(input)? {
+      // `none` is only valid as the entire value of the property.
+      Image::None => Err(location.new_custom_error(ParserError::InvalidValue)),
+      image => Ok(ContentItem::Image(image)),
+    }
+  }
+}
+
+fn parse_counter_style<'i>(
+  input: &mut Parser<'i, '_>,
+) -> Result<CounterStyle<'i>, ParseError<'i, ParserError<'i>>> {
+  if input.try_parse(|input| input.expect_comma()).is_ok() {
+    CounterStyle::parse(input)
+  } else {
+    Ok(CounterStyle::Predefined(PredefinedCounterStyle::Decimal))
+  }
+}
+
+impl ToCss for ContentItem<'_> {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    match self {
+      ContentItem::String(s) => s.to_css(dest),
+      ContentItem::Image(image) => image.to_css(dest),
+      ContentItem::Counter { name, style } => {
+        dest.write_str("counter(")?;
+        name.to_css(dest)?;
+        counter_style_to_css(style, dest)?;
+        dest.write_char(')')
+      }
+      ContentItem::Counters { name, separator, style } => {
+        dest.write_str("counters(")?;
+        name.to_css(dest)?;
+        dest.delim(',', false)?;
+        separator.to_css(dest)?;
+        counter_style_to_css(style, dest)?;
+        dest.write_char(')')
+      }
+      ContentItem::Attr(name) => {
+        dest.write_str("attr(")?;
+        name.to_css(dest)?;
+        dest.write_char(')')
+      }
+      ContentItem::Quote(quote) => quote.to_css(dest),
+    }
+  }
+}
+
+fn counter_style_to_css<W>(style: &CounterStyle, dest: &mut Printer<W>) -> Result<(), PrinterError>
+where
+  W: std::fmt::Write,
+{
+  // The default counter style is decimal, so it can be omitted.
+  if *style != CounterStyle::Predefined(PredefinedCounterStyle::Decimal) {
+    dest.delim(',', false)?;
+    style.to_css(dest)?;
+  }
+  Ok(())
+}
+
+enum_property! {
+  /// A [quote](https://www.w3.org/TR/css-content-3/#typedef-quote) keyword, as used in the `content` property.
+  pub enum Quote {
+    /// Inserts an opening quote and increments the nesting level.
+    "open-quote": OpenQuote,
+    /// Inserts a closing quote and decrements the nesting level.
+    "close-quote": CloseQuote,
+    /// Increments the nesting level without inserting a quote.
+    "no-open-quote": NoOpenQuote,
+    /// Decrements the nesting level without inserting a quote.
+    "no-close-quote": NoCloseQuote,
+  }
+}
+
+/// A value for the [quotes](https://www.w3.org/TR/css-content-3/#quotes-property) property.
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "type", content = "value", rename_all = "kebab-case")
+)]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+pub enum Quotes<'i> {
+  /// The `open-quote` and `close-quote` values produce no quotation marks.
+  None,
+  /// Appropriate quotation marks are chosen automatically based on the content language.
+  #[default]
+  Auto,
+  /// A list of opening and closing quotation mark pairs, one for each nesting level.
+  #[cfg_attr(feature = "serde", serde(borrow))]
+  Pairs(Vec<QuotePair<'i>>),
+}
+
+/// A pair of opening and closing quotation marks, as used in the `quotes` property.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+pub struct QuotePair<'i> {
+  /// The opening quotation mark.
+  #[cfg_attr(feature = "serde", serde(borrow))]
+  pub open: CSSString<'i>,
+  /// The closing quotation mark.
+  pub close: CSSString<'i>,
+}
+
+impl<'i> Parse<'i> for Quotes<'i> {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    if input.try_parse(|input| input.expect_ident_matching("none")).is_ok() {
+      return Ok(Quotes::None);
+    }
+
+    if input.try_parse(|input| input.expect_ident_matching("auto")).is_ok() {
+      return Ok(Quotes::Auto);
+    }
+
+    let mut pairs = vec![QuotePair::parse(input)?];
+    while let Ok(pair) = input.try_parse(QuotePair::parse) {
+      pairs.push(pair);
+    }
+
+    Ok(Quotes::Pairs(pairs))
+  }
+}
+
+impl<'i> Parse<'i> for QuotePair<'i> {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    let open = CSSString::parse(input)?;
+    let close = CSSString::parse(input)?;
+    Ok(QuotePair { open, close })
+  }
+}
+
+impl ToCss for Quotes<'_> {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    match self {
+      Quotes::None => dest.write_str("none"),
+      Quotes::Auto => dest.write_str("auto"),
+      Quotes::Pairs(pairs) => {
+        let mut first = true;
+        for pair in pairs {
+          if first {
+            first = false;
+          } else {
+            dest.write_char(' ')?;
+          }
+          pair.to_css(dest)?;
+        }
+        Ok(())
+      }
+    }
+  }
+}
+
+impl ToCss for QuotePair<'_> {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    serialize_quote_string(&self.open, dest)?;
+    dest.write_char(' ')?;
+    serialize_quote_string(&self.close, dest)
+  }
+}
+
+fn serialize_quote_string<W>(s: &str, dest: &mut Printer<W>) -> Result<(), PrinterError>
+where
+  W: std::fmt::Write,
+{
+  // Quotation marks frequently contain a double quote character. When minifying,
+  // use single quotes instead if that avoids escaping and needs no other escapes.
+  if dest.minify && s.contains('"') && !s.chars().any(|c| matches!(c, '\'' | '\\' | '\0'..='\x1F' | '\x7F')) {
+    dest.write_char('\'')?;
+    dest.write_str(s)?;
+    return dest.write_char('\'');
+  }
+
+  serialize_string(s, dest)?;
+  Ok(())
+}
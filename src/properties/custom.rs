@@ -14,12 +14,14 @@ use crate::values::color::{
   parse_hsl_hwb_components, parse_rgb_components, ColorFallbackKind, ComponentParser, CssColor, LightDarkColor,
   HSL, RGBA, SRGB,
 };
+use crate::values::frequency::Frequency;
 use crate::values::ident::{CustomIdent, DashedIdent, DashedIdentReference, Ident};
 use crate::values::length::{serialize_dimension, LengthValue};
 use crate::values::number::CSSInteger;
 use crate::values::percentage::Percentage;
 use crate::values::resolution::Resolution;
 use crate::values::string::CowArcStr;
+use crate::values::syntax::SyntaxString;
 use crate::values::time::Time;
 use crate::values::url::Url;
 #[cfg(feature = "visitor")]
@@ -179,14 +181,30 @@ impl<'i> UnparsedProperty<'i> {
     mut self,
     vars: &std::collections::HashMap<&str, TokenList<'i>>,
   ) -> Result<super::Property<'x>, ()> {
+    // Substitute variables in the token list.
+    self.value.substitute_variables(vars);
+    self.reparse()
+  }
+
+  /// Substitutes environment variables and re-parses the property.
+  #[cfg(feature = "substitute_variables")]
+  #[cfg_attr(docsrs, doc(cfg(feature = "substitute_variables")))]
+  pub fn substitute_environment_variables<'x>(
+    mut self,
+    vars: &std::collections::HashMap<&str, TokenList<'i>>,
+  ) -> Result<super::Property<'x>, ()> {
+    // Substitute environment variables in the token list.
+    self.value.substitute_environment_variables(vars);
+    self.reparse()
+  }
+
+  #[cfg(feature = "substitute_variables")]
+  fn reparse<'x>(self) -> Result<super::Property<'x>, ()> {
     use super::Property;
     use crate::stylesheet::PrinterOptions;
     use static_self::IntoOwned;
 
-    // Substitute variables in the token list.
-    self.value.substitute_variables(vars);
-
-    // Now stringify and re-parse the property to its fully parsed form.
+    // Stringify and re-parse the property to its fully parsed form.
     // Ideally we'd be able to reuse the tokens rather than printing, but cssparser doesn't provide a way to do that.
     let mut css = String::new();
     let mut dest = Printer::new(&mut css, PrinterOptions::default());
@@ -229,6 +247,8 @@ pub enum TokenOrValue<'i> {
   Var(Variable<'i>),
   /// A CSS environment variable reference.
   Env(EnvironmentVariable<'i>),
+  /// An attribute reference.
+  Attr(Attr<'i>),
   /// A custom CSS function.
   Function(Function<'i>),
   /// A length.
@@ -391,12 +411,12 @@ impl<'i> TokenList<'i> {
             tokens.push(TokenOrValue::UnresolvedColor(color));
             last_is_delim = true;
             last_is_whitespace = false;
-          } else if f == "url" {
+          } else if f.eq_ignore_ascii_case("url") {
             input.reset(&state);
             tokens.push(TokenOrValue::Url(Url::parse(input)?));
             last_is_delim = false;
             last_is_whitespace = false;
-          } else if f == "var" {
+          } else if f.eq_ignore_ascii_case("var") {
             let var = input.parse_nested_block(|input| {
               let var = Variable::parse(input, options, depth + 1)?;
               Ok(TokenOrValue::Var(var))
@@ -404,7 +424,7 @@ impl<'i> TokenList<'i> {
             tokens.push(var);
             last_is_delim = true;
             last_is_whitespace = false;
-          } else if f == "env" {
+          } else if f.eq_ignore_ascii_case("env") {
             let env = input.parse_nested_block(|input| {
               let env = EnvironmentVariable::parse_nested(input, options, depth + 1)?;
               Ok(TokenOrValue::Env(env))
@@ -412,6 +432,11 @@ impl<'i> TokenList<'i> {
             tokens.push(env);
             last_is_delim = true;
             last_is_whitespace = false;
+          } else if let Ok(attr) = input.try_parse(|input| Attr::parse_function(&f, input, options, depth + 1)) {
+            // Other attr() forms, e.g. with unknown types, are preserved as generic functions below.
+            tokens.push(TokenOrValue::Attr(attr));
+            last_is_delim = true;
+            last_is_whitespace = false;
           } else {
             let arguments = input.parse_nested_block(|input| TokenList::parse(input, options, depth + 1))?;
             tokens.push(TokenOrValue::Function(Function {
@@ -564,6 +589,10 @@ impl<'i> TokenList<'i> {
           env.to_css(dest, is_custom_property)?;
           self.write_whitespace_if_needed(i, dest)?
         }
+        TokenOrValue::Attr(attr) => {
+          attr.to_css(dest, is_custom_property)?;
+          self.write_whitespace_if_needed(i, dest)?
+        }
         TokenOrValue::Function(f) => {
           f.to_css(dest, is_custom_property)?;
           self.write_whitespace_if_needed(i, dest)?
@@ -1108,6 +1137,11 @@ impl<'i> TokenList<'i> {
             fallbacks |= fallback.get_necessary_fallbacks(targets);
           }
         }
+        TokenOrValue::Attr(v) => {
+          if let Some(fallback) = &v.fallback {
+            fallbacks |= fallback.get_necessary_fallbacks(targets);
+          }
+        }
         _ => {}
       }
     }
@@ -1124,6 +1158,7 @@ impl<'i> TokenList<'i> {
         TokenOrValue::Function(f) => TokenOrValue::Function(f.get_fallback(kind)),
         TokenOrValue::Var(v) => TokenOrValue::Var(v.get_fallback(kind)),
         TokenOrValue::Env(e) => TokenOrValue::Env(e.get_fallback(kind)),
+        TokenOrValue::Attr(a) => TokenOrValue::Attr(a.get_fallback(kind)),
         _ => token.clone(),
       })
       .collect();
@@ -1161,6 +1196,7 @@ impl<'i> TokenList<'i> {
           TokenOrValue::Function(f) => *f = f.get_fallback(lowest_fallback),
           TokenOrValue::Var(v) if v.fallback.is_some() => *v = v.get_fallback(lowest_fallback),
           TokenOrValue::Env(v) if v.fallback.is_some() => *v = v.get_fallback(lowest_fallback),
+          TokenOrValue::Attr(v) if v.fallback.is_some() => *v = v.get_fallback(lowest_fallback),
           _ => {}
         }
       }
@@ -1175,6 +1211,81 @@ impl<'i> TokenList<'i> {
   pub fn substitute_variables(&mut self, vars: &std::collections::HashMap<&str, TokenList<'i>>) {
     self.visit(&mut VarInliner { vars }).unwrap()
   }
+
+  /// Substitutes environment variables with the provided values, e.g. `safe-area-inset-top`.
+  /// Environment variables that are not provided are left as is, so the browser can still resolve them.
+  #[cfg(feature = "substitute_variables")]
+  #[cfg_attr(docsrs, doc(cfg(feature = "substitute_variables")))]
+  pub fn substitute_environment_variables(&mut self, vars: &std::collections::HashMap<&str, TokenList<'i>>) {
+    self.inline_environment_variables(vars);
+  }
+
+  /// Replaces environment variables with the provided values, and returns whether any were replaced.
+  pub(crate) fn inline_environment_variables<'v: 'i, K: std::borrow::Borrow<str> + std::hash::Hash + Eq>(
+    &mut self,
+    vars: &std::collections::HashMap<K, TokenList<'v>>,
+  ) -> bool {
+    let mut changed = false;
+    let mut i = 0;
+    while i < self.0.len() {
+      let token = &mut self.0[i];
+      match token {
+        TokenOrValue::Function(f) => changed |= f.arguments.inline_environment_variables(vars),
+        TokenOrValue::Var(Variable {
+          fallback: Some(fallback),
+          ..
+        })
+        | TokenOrValue::Attr(Attr {
+          fallback: Some(fallback),
+          ..
+        })
+        | TokenOrValue::UnresolvedColor(UnresolvedColor::RGB { alpha: fallback, .. })
+        | TokenOrValue::UnresolvedColor(UnresolvedColor::HSL { alpha: fallback, .. }) => {
+          changed |= fallback.inline_environment_variables(vars)
+        }
+        TokenOrValue::UnresolvedColor(UnresolvedColor::LightDark { light, dark }) => {
+          changed |= light.inline_environment_variables(vars);
+          changed |= dark.inline_environment_variables(vars);
+        }
+        TokenOrValue::Env(env) => {
+          if let Some(fallback) = &mut env.fallback {
+            changed |= fallback.inline_environment_variables(vars);
+          }
+
+          // Indices into multi-dimensional variables are not supported, so only plain names are replaced.
+          let value = if env.indices.is_empty() {
+            vars.get(env.name.name())
+          } else {
+            None
+          };
+
+          // Values are inserted as is, so unlike var(), environment variables in them are not replaced.
+          if let Some(value) = value {
+            let mut value = value.0.clone();
+            // Whitespace after functions is removed during parsing, so add it back if needed.
+            if matches!(
+              self.0.get(i + 1),
+              Some(next) if !next.is_whitespace()
+                && !matches!(
+                  next,
+                  TokenOrValue::Token(Token::Comma | Token::Delim(_) | Token::CloseParenthesis)
+                )
+            ) {
+              value.push(TokenOrValue::Token(Token::WhiteSpace(" ".into())));
+            }
+            let len = value.len();
+            self.0.splice(i..i + 1, value);
+            i += len;
+            changed = true;
+            continue;
+          }
+        }
+        _ => {}
+      }
+      i += 1;
+    }
+    changed
+  }
 }
 
 #[cfg(feature = "substitute_variables")]
@@ -1447,6 +1558,211 @@ impl<'i> EnvironmentVariable<'i> {
   }
 }
 
+/// A CSS [attr()](https://drafts.csswg.org/css-values-5/#attr-notation) function.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(rename_all = "camelCase")
+)]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+pub struct Attr<'i> {
+  /// The namespace prefix of the attribute name, if any. An empty prefix
+  /// (e.g. `|name`) refers to attributes with no namespace.
+  #[cfg_attr(feature = "serde", serde(borrow))]
+  pub namespace: Option<Ident<'i>>,
+  /// The attribute name.
+  pub name: Ident<'i>,
+  /// The type the attribute value is parsed as. By default, it is treated as a string.
+  #[cfg_attr(feature = "visitor", skip_visit)]
+  pub attr_type: Option<AttrType<'i>>,
+  /// A fallback value in case the attribute is missing or cannot be parsed as the given type.
+  pub fallback: Option<TokenList<'i>>,
+}
+
+impl<'i> Attr<'i> {
+  fn parse_function<'t>(
+    name: &CowArcStr<'i>,
+    input: &mut Parser<'i, 't>,
+    options: &ParserOptions<'_, 'i>,
+    depth: usize,
+  ) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    if !name.eq_ignore_ascii_case("attr") {
+      return Err(input.new_custom_error(ParserError::InvalidValue));
+    }
+
+    input.parse_nested_block(|input| Self::parse_nested(input, options, depth))
+  }
+
+  fn parse_nested<'t>(
+    input: &mut Parser<'i, 't>,
+    options: &ParserOptions<'_, 'i>,
+    depth: usize,
+  ) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    let namespace = input.try_parse(Self::parse_namespace_prefix).ok();
+    let name = if namespace.is_some() {
+      // No whitespace is allowed between the namespace separator and the name.
+      let location = input.current_source_location();
+      match input.next_including_whitespace()? {
+        cssparser::Token::Ident(name) => Ident(name.into()),
+        t => return Err(location.new_unexpected_token_error(t.clone())),
+      }
+    } else {
+      Ident::parse(input)?
+    };
+    let attr_type = input.try_parse(AttrType::parse).ok();
+    // Anything other than a fallback after the name and type is invalid, e.g. an unknown type.
+    let fallback = if !input.is_exhausted() {
+      input.expect_comma()?;
+      Some(TokenList::parse(input, options, depth + 1)?)
+    } else {
+      None
+    };
+
+    Ok(Attr {
+      namespace,
+      name,
+      attr_type,
+      fallback,
+    })
+  }
+
+  /// Parses the namespace prefix of a qualified attribute name, including the `|` separator.
+  fn parse_namespace_prefix<'t>(input: &mut Parser<'i, 't>) -> Result<Ident<'i>, ParseError<'i, ParserError<'i>>> {
+    let prefix = input
+      .try_parse(|input| input.expect_ident_cloned())
+      .map(|prefix| Ident(prefix.into()))
+      .unwrap_or_else(|_| Ident("".into()));
+    let location = input.current_source_location();
+    match input.next_including_whitespace()? {
+      cssparser::Token::Delim('|') => Ok(prefix),
+      t => Err(location.new_unexpected_token_error(t.clone())),
+    }
+  }
+
+  fn to_css<W>(&self, dest: &mut Printer<W>, is_custom_property: bool) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    dest.write_str("attr(")?;
+    if let Some(namespace) = &self.namespace {
+      namespace.to_css(dest)?;
+      dest.write_char('|')?;
+    }
+    self.name.to_css(dest)?;
+
+    if let Some(attr_type) = &self.attr_type {
+      dest.write_char(' ')?;
+      attr_type.to_css(dest)?;
+    }
+
+    if let Some(fallback) = &self.fallback {
+      dest.delim(',', false)?;
+      fallback.to_css(dest, is_custom_property)?;
+    }
+    dest.write_char(')')
+  }
+
+  fn get_fallback(&self, kind: ColorFallbackKind) -> Self {
+    Attr {
+      namespace: self.namespace.clone(),
+      name: self.name.clone(),
+      attr_type: self.attr_type.clone(),
+      fallback: self.fallback.as_ref().map(|fallback| fallback.get_fallback(kind)),
+    }
+  }
+}
+
+/// The type of an [attr()](https://drafts.csswg.org/css-values-5/#attr-notation) function,
+/// which determines how the attribute value is parsed.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "type", content = "value", rename_all = "kebab-case")
+)]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+pub enum AttrType<'i> {
+  /// The `type()` function, which parses the value according to a syntax definition.
+  Type(SyntaxString),
+  /// The `raw-string` keyword, which treats the value as a string.
+  RawString,
+  /// The `number` keyword, which parses the value as a number.
+  Number,
+  /// The `%` unit, which parses the value as a number and converts it to a percentage.
+  Percentage,
+  /// A dimension unit, which parses the value as a number and converts it to a dimension with the given unit.
+  #[cfg_attr(feature = "serde", serde(borrow))]
+  Unit(Ident<'i>),
+}
+
+impl<'i> Parse<'i> for AttrType<'i> {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    if input.try_parse(|input| input.expect_function_matching("type")).is_ok() {
+      return input.parse_nested_block(|input| {
+        let start = input.position();
+        while input.next().is_ok() {}
+        SyntaxString::parse_string(input.slice_from(start))
+          .map(AttrType::Type)
+          .map_err(|_| input.new_custom_error(ParserError::InvalidValue))
+      });
+    }
+
+    if input.try_parse(|input| input.expect_delim('%')).is_ok() {
+      return Ok(AttrType::Percentage);
+    }
+
+    let location = input.current_source_location();
+    let ident = input.expect_ident_cloned()?;
+    match_ignore_ascii_case! { &ident,
+      "raw-string" => return Ok(AttrType::RawString),
+      "number" => return Ok(AttrType::Number),
+      "fr" => return Ok(AttrType::Unit(Ident((&ident).into()))),
+      _ => {}
+    }
+
+    // Check that the ident is a known dimension unit.
+    let token = cssparser::Token::Dimension {
+      has_sign: false,
+      value: 0.0,
+      int_value: None,
+      unit: ident.clone(),
+    };
+    if LengthValue::try_from(&token).is_ok()
+      || Angle::try_from(&token).is_ok()
+      || Time::try_from(&token).is_ok()
+      || Resolution::try_from(&token).is_ok()
+      || Frequency::try_from(&token).is_ok()
+    {
+      return Ok(AttrType::Unit(Ident((&ident).into())));
+    }
+
+    Err(location.new_unexpected_token_error(cssparser::Token::Ident(ident)))
+  }
+}
+
+impl<'i> ToCss for AttrType<'i> {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    match self {
+      AttrType::Type(syntax) => {
+        dest.write_str("type(")?;
+        syntax.to_css_unquoted(dest)?;
+        dest.write_char(')')
+      }
+      AttrType::RawString => dest.write_str("raw-string"),
+      AttrType::Number => dest.write_str("number"),
+      AttrType::Percentage => dest.write_char('%'),
+      AttrType::Unit(unit) => unit.to_css(dest),
+    }
+  }
+}
+
 /// A custom CSS function.
 #[derive(Debug, Clone, PartialEq)]
 #[cfg_attr(feature = "visitor", derive(Visit))]
@@ -1,9 +1,10 @@
 //! CSS properties related to filters and effects.
 
 use crate::error::{ParserError, PrinterError};
+use crate::macros::enum_property;
 use crate::printer::Printer;
 use crate::targets::{Browsers, Targets};
-use crate::traits::{FallbackValues, IsCompatible, Parse, ToCss, Zero};
+use crate::traits::{FallbackValues, IsCompatible, Parse, ToCss, TrySign, Zero};
 use crate::values::color::ColorFallbackKind;
 use crate::values::{angle::Angle, color::CssColor, length::Length, percentage::NumberOrPercentage, url::Url};
 #[cfg(feature = "visitor")]
@@ -58,22 +59,22 @@ impl<'i> Parse<'i> for Filter<'i> {
     match_ignore_ascii_case! { &function,
       "blur" => {
         input.parse_nested_block(|input| {
-          Ok(Filter::Blur(input.try_parse(Length::parse).unwrap_or(Length::zero())))
+          Ok(Filter::Blur(input.try_parse(parse_blur_radius).unwrap_or(Length::zero())))
         })
       },
       "brightness" => {
         input.parse_nested_block(|input| {
-          Ok(Filter::Brightness(input.try_parse(NumberOrPercentage::parse).unwrap_or(NumberOrPercentage::Number(1.0))))
+          Ok(Filter::Brightness(input.try_parse(parse_amount).unwrap_or(NumberOrPercentage::Number(1.0))))
         })
       },
       "contrast" => {
         input.parse_nested_block(|input| {
-          Ok(Filter::Contrast(input.try_parse(NumberOrPercentage::parse).unwrap_or(NumberOrPercentage::Number(1.0))))
+          Ok(Filter::Contrast(input.try_parse(parse_amount).unwrap_or(NumberOrPercentage::Number(1.0))))
         })
       },
       "grayscale" => {
         input.parse_nested_block(|input| {
-          Ok(Filter::Grayscale(input.try_parse(NumberOrPercentage::parse).unwrap_or(NumberOrPercentage::Number(1.0))))
+          Ok(Filter::Grayscale(input.try_parse(parse_amount).unwrap_or(NumberOrPercentage::Number(1.0))))
         })
       },
       "hue-rotate" => {
@@ -84,22 +85,22 @@ impl<'i> Parse<'i> for Filter<'i> {
       },
       "invert" => {
         input.parse_nested_block(|input| {
-          Ok(Filter::Invert(input.try_parse(NumberOrPercentage::parse).unwrap_or(NumberOrPercentage::Number(1.0))))
+          Ok(Filter::Invert(input.try_parse(parse_amount).unwrap_or(NumberOrPercentage::Number(1.0))))
         })
       },
       "opacity" => {
         input.parse_nested_block(|input| {
-          Ok(Filter::Opacity(input.try_parse(NumberOrPercentage::parse).unwrap_or(NumberOrPercentage::Number(1.0))))
+          Ok(Filter::Opacity(input.try_parse(parse_amount).unwrap_or(NumberOrPercentage::Number(1.0))))
         })
       },
       "saturate" => {
         input.parse_nested_block(|input| {
-          Ok(Filter::Saturate(input.try_parse(NumberOrPercentage::parse).unwrap_or(NumberOrPercentage::Number(1.0))))
+          Ok(Filter::Saturate(input.try_parse(parse_amount).unwrap_or(NumberOrPercentage::Number(1.0))))
         })
       },
       "sepia" => {
         input.parse_nested_block(|input| {
-          Ok(Filter::Sepia(input.try_parse(NumberOrPercentage::parse).unwrap_or(NumberOrPercentage::Number(1.0))))
+          Ok(Filter::Sepia(input.try_parse(parse_amount).unwrap_or(NumberOrPercentage::Number(1.0))))
         })
       },
       "drop-shadow" => {
@@ -114,6 +115,25 @@ impl<'i> Parse<'i> for Filter<'i> {
   }
 }
 
+/// Parses a filter function amount, which must not be negative.
+fn parse_amount<'i>(input: &mut Parser<'i, '_>) -> Result<NumberOrPercentage, ParseError<'i, ParserError<'i>>> {
+  let amount = NumberOrPercentage::parse(input)?;
+  let value: f32 = (&amount).into();
+  if value < 0.0 {
+    return Err(input.new_custom_error(ParserError::InvalidValue));
+  }
+  Ok(amount)
+}
+
+/// Parses a blur radius, which must not be negative.
+fn parse_blur_radius<'i>(input: &mut Parser<'i, '_>) -> Result<Length, ParseError<'i, ParserError<'i>>> {
+  let radius = Length::parse(input)?;
+  if radius.is_sign_negative() {
+    return Err(input.new_custom_error(ParserError::InvalidValue));
+  }
+  Ok(radius)
+}
+
 impl<'i> ToCss for Filter<'i> {
   fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
   where
@@ -246,7 +266,7 @@ impl<'i> Parse<'i> for DropShadow {
         let value = input.try_parse::<_, _, ParseError<ParserError<'i>>>(|input| {
           let horizontal = Length::parse(input)?;
           let vertical = Length::parse(input)?;
-          let blur = input.try_parse(Length::parse).unwrap_or(Length::zero());
+          let blur = input.try_parse(parse_blur_radius).unwrap_or(Length::zero());
           Ok((horizontal, vertical, blur))
         });
 
@@ -410,3 +430,58 @@ impl IsCompatible for FilterList<'_> {
     true
   }
 }
+
+enum_property! {
+  /// A [`<blend-mode>`](https://drafts.fxtf.org/compositing-1/#ltblendmodegt) value,
+  /// as used in the `mix-blend-mode` and `background-blend-mode` properties.
+  #[derive(Default)]
+  pub enum BlendMode {
+    /// The source color replaces the backdrop.
+    #[default]
+    "normal": Normal,
+    /// The source and backdrop colors are multiplied.
+    "multiply": Multiply,
+    /// The complements of the source and backdrop colors are multiplied.
+    "screen": Screen,
+    /// Multiplies or screens the colors, depending on the backdrop color.
+    "overlay": Overlay,
+    /// The darker of the source and backdrop colors is selected.
+    "darken": Darken,
+    /// The lighter of the source and backdrop colors is selected.
+    "lighten": Lighten,
+    /// Brightens the backdrop color to reflect the source color.
+    "color-dodge": ColorDodge,
+    /// Darkens the backdrop color to reflect the source color.
+    "color-burn": ColorBurn,
+    /// Multiplies or screens the colors, depending on the source color.
+    "hard-light": HardLight,
+    /// Darkens or lightens the colors, depending on the source color.
+    "soft-light": SoftLight,
+    /// Subtracts the darker of the two colors from the lighter color.
+    "difference": Difference,
+    /// Similar to `difference`, but with lower contrast.
+    "exclusion": Exclusion,
+    /// Uses the hue of the source color with the saturation and luminosity of the backdrop color.
+    "hue": Hue,
+    /// Uses the saturation of the source color with the hue and luminosity of the backdrop color.
+    "saturation": Saturation,
+    /// Uses the hue and saturation of the source color with the luminosity of the backdrop color.
+    "color": Color,
+    /// Uses the luminosity of the source color with the hue and saturation of the backdrop color.
+    "luminosity": Luminosity,
+    /// The source and backdrop colors are added together.
+    "plus-lighter": PlusLighter,
+  }
+}
+
+enum_property! {
+  /// A value for the [isolation](https://drafts.fxtf.org/compositing-1/#isolation) property.
+  #[derive(Default)]
+  pub enum Isolation {
+    /// The element only creates a new stacking context if required by other properties.
+    #[default]
+    Auto,
+    /// The element creates a new stacking context.
+    Isolate,
+  }
+}
@@ -155,6 +155,10 @@ impl<'i> Parse<'i> for Flex {
         if let Ok(val) = input.try_parse(CSSNumber::parse) {
           grow = Some(val);
           shrink = input.try_parse(CSSNumber::parse).ok();
+          // Flex factors must be non-negative.
+          if val < 0.0 || matches!(shrink, Some(s) if s < 0.0) {
+            return Err(input.new_custom_error(ParserError::InvalidValue));
+          }
           continue;
         }
       }
@@ -9,10 +9,11 @@ use crate::declaration::{DeclarationBlock, DeclarationList};
 use crate::error::{ParserError, PrinterError};
 use crate::macros::*;
 use crate::printer::Printer;
-use crate::targets::should_compile;
-use crate::traits::{IsCompatible, Parse, PropertyHandler, Shorthand, ToCss};
+use crate::targets::{should_compile, Features};
+use crate::traits::{IsCompatible, Parse, PropertyHandler, Shorthand, ToCss, TrySign};
+use crate::values::ident::CustomIdent;
 use crate::values::length::LengthValue;
-use crate::values::number::CSSNumber;
+use crate::values::number::{CSSInteger, CSSNumber};
 use crate::values::string::CowArcStr;
 use crate::values::{angle::Angle, length::LengthPercentage, percentage::Percentage};
 #[cfg(feature = "visitor")]
@@ -115,6 +116,10 @@ impl Default for AbsoluteFontWeight {
 impl<'i> Parse<'i> for AbsoluteFontWeight {
   fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
     if let Ok(val) = input.try_parse(CSSNumber::parse) {
+      // Numeric weights must be between 1 and 1000, inclusive.
+      if !(1.0..=1000.0).contains(&val) {
+        return Err(input.new_custom_error(ParserError::InvalidValue));
+      }
       return Ok(AbsoluteFontWeight::Weight(val));
     }
 
@@ -218,6 +223,9 @@ pub enum FontSize {
 impl<'i> Parse<'i> for FontSize {
   fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
     if let Ok(val) = input.try_parse(LengthPercentage::parse) {
+      if val.is_sign_negative() {
+        return Err(input.new_custom_error(ParserError::InvalidValue));
+      }
       return Ok(FontSize::Length(val));
     }
 
@@ -334,6 +342,9 @@ impl Default for FontStretch {
 impl<'i> Parse<'i> for FontStretch {
   fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
     if let Ok(val) = input.try_parse(Percentage::parse) {
+      if val.is_sign_negative() {
+        return Err(input.new_custom_error(ParserError::InvalidValue));
+      }
       return Ok(FontStretch::Percentage(val));
     }
 
@@ -643,258 +654,1576 @@ impl IsCompatible for FontVariantCaps {
   }
 }
 
-/// A value for the [line-height](https://www.w3.org/TR/2020/WD-css-inline-3-20200827/#propdef-line-height) property.
-#[derive(Debug, Clone, PartialEq)]
-#[cfg_attr(feature = "visitor", derive(Visit))]
-#[cfg_attr(
-  feature = "serde",
-  derive(serde::Serialize, serde::Deserialize),
-  serde(tag = "type", content = "value", rename_all = "kebab-case")
-)]
-#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
-#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
-pub enum LineHeight {
-  /// The UA sets the line height based on the font.
-  Normal,
-  /// A multiple of the element's font size.
-  Number(CSSNumber),
-  /// An explicit height.
-  Length(LengthPercentage),
+impl FontVariantCaps {
+  fn feature_settings(&self, features: &mut Vec<FontFeature>) {
+    let tags: &[&'static str] = match self {
+      FontVariantCaps::Normal => &[],
+      FontVariantCaps::SmallCaps => &["smcp"],
+      FontVariantCaps::AllSmallCaps => &["c2sc", "smcp"],
+      FontVariantCaps::PetiteCaps => &["pcap"],
+      FontVariantCaps::AllPetiteCaps => &["c2pc", "pcap"],
+      FontVariantCaps::Unicase => &["unic"],
+      FontVariantCaps::TitlingCaps => &["titl"],
+    };
+    for tag in tags {
+      push_feature(features, tag, true);
+    }
+  }
 }
 
-impl Default for LineHeight {
-  fn default() -> LineHeight {
-    LineHeight::Normal
+enum_property! {
+  /// A value for the [font-variant-position](https://www.w3.org/TR/css-fonts-4/#font-variant-position-prop) property.
+  #[derive(Default)]
+  pub enum FontVariantPosition {
+    /// No subscript or superscript glyphs are used.
+    #[default]
+    "normal": Normal,
+    /// Subscript glyphs are used.
+    "sub": Sub,
+    /// Superscript glyphs are used.
+    "super": Super,
   }
 }
 
-impl<'i> Parse<'i> for LineHeight {
-  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
-    if input.try_parse(|input| input.expect_ident_matching("normal")).is_ok() {
-      return Ok(LineHeight::Normal);
+impl FontVariantPosition {
+  fn feature_settings(&self, features: &mut Vec<FontFeature>) {
+    match self {
+      FontVariantPosition::Normal => {}
+      FontVariantPosition::Sub => push_feature(features, "subs", true),
+      FontVariantPosition::Super => push_feature(features, "sups", true),
     }
+  }
+}
 
-    if let Ok(val) = input.try_parse(CSSNumber::parse) {
-      return Ok(LineHeight::Number(val));
-    }
+impl IsCompatible for FontVariantPosition {
+  fn is_compatible(&self, _browsers: crate::targets::Browsers) -> bool {
+    true
+  }
+}
 
-    Ok(LineHeight::Length(LengthPercentage::parse(input)?))
+enum_property! {
+  /// A [`<common-lig-values>`](https://www.w3.org/TR/css-fonts-4/#common-lig-values) keyword.
+  pub enum CommonLigValues {
+    /// Common ligatures such as "fi" are enabled.
+    "common-ligatures": CommonLigatures,
+    /// Common ligatures are disabled.
+    "no-common-ligatures": NoCommonLigatures,
   }
 }
 
-impl ToCss for LineHeight {
-  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
-  where
-    W: std::fmt::Write,
-  {
-    match self {
-      LineHeight::Normal => dest.write_str("normal"),
-      LineHeight::Number(val) => val.to_css(dest),
-      LineHeight::Length(val) => val.to_css(dest),
-    }
+enum_property! {
+  /// A [`<discretionary-lig-values>`](https://www.w3.org/TR/css-fonts-4/#discretionary-lig-values) keyword.
+  pub enum DiscretionaryLigValues {
+    /// Discretionary ligatures are enabled.
+    "discretionary-ligatures": DiscretionaryLigatures,
+    /// Discretionary ligatures are disabled.
+    "no-discretionary-ligatures": NoDiscretionaryLigatures,
   }
 }
 
-impl IsCompatible for LineHeight {
-  fn is_compatible(&self, browsers: crate::targets::Browsers) -> bool {
-    match self {
-      LineHeight::Length(l) => l.is_compatible(browsers),
-      LineHeight::Normal | LineHeight::Number(..) => true,
-    }
+enum_property! {
+  /// A [`<historical-lig-values>`](https://www.w3.org/TR/css-fonts-4/#historical-lig-values) keyword.
+  pub enum HistoricalLigValues {
+    /// Historical ligatures are enabled.
+    "historical-ligatures": HistoricalLigatures,
+    /// Historical ligatures are disabled.
+    "no-historical-ligatures": NoHistoricalLigatures,
   }
 }
 
 enum_property! {
-  /// A keyword for the [vertical align](https://drafts.csswg.org/css2/#propdef-vertical-align) property.
-  pub enum VerticalAlignKeyword {
-    /// Align the baseline of the box with the baseline of the parent box.
-    "baseline": Baseline,
-    /// Lower the baseline of the box to the proper position for subscripts of the parent’s box.
-    "sub": Sub,
-    /// Raise the baseline of the box to the proper position for superscripts of the parent’s box.
-    "super": Super,
-    /// Align the top of the aligned subtree with the top of the line box.
-    "top": Top,
-    /// Align the top of the box with the top of the parent’s content area.
-    "text-top": TextTop,
-    /// Align the vertical midpoint of the box with the baseline of the parent box plus half the x-height of the parent.
-    "middle": Middle,
-    /// Align the bottom of the aligned subtree with the bottom of the line box.
-    "bottom": Bottom,
-    /// Align the bottom of the box with the bottom of the parent’s content area.
-    "text-bottom": TextBottom,
+  /// A [`<contextual-alt-values>`](https://www.w3.org/TR/css-fonts-4/#contextual-alt-values) keyword.
+  pub enum ContextualAltValues {
+    /// Contextual alternates are enabled.
+    "contextual": Contextual,
+    /// Contextual alternates are disabled.
+    "no-contextual": NoContextual,
   }
 }
 
-/// A value for the [vertical align](https://drafts.csswg.org/css2/#propdef-vertical-align) property.
-// TODO: there is a more extensive spec in CSS3 but it doesn't seem any browser implements it? https://www.w3.org/TR/css-inline-3/#transverse-alignment
-#[derive(Debug, Clone, PartialEq)]
+/// Writes a space before each component of a space separated value, except the first.
+fn write_separator<W>(dest: &mut Printer<W>, first: &mut bool) -> Result<(), PrinterError>
+where
+  W: std::fmt::Write,
+{
+  if *first {
+    *first = false;
+    Ok(())
+  } else {
+    dest.write_char(' ')
+  }
+}
+
+/// Parses a `normal` keyword, or one or more components of a font-variant longhand in any order.
+fn parse_variant_components<'i, 't, T: Default>(
+  input: &mut Parser<'i, 't>,
+  parse_component: fn(&mut T, &mut Parser<'i, 't>) -> Result<(), ParseError<'i, ParserError<'i>>>,
+) -> Result<T, ParseError<'i, ParserError<'i>>> {
+  let mut value = T::default();
+  if input.try_parse(|input| input.expect_ident_matching("normal")).is_ok() {
+    return Ok(value);
+  }
+
+  parse_component(&mut value, input)?;
+  while input.try_parse(|input| parse_component(&mut value, input)).is_ok() {}
+  Ok(value)
+}
+
+/// Returns a parse error for an unexpected or repeated identifier.
+fn unexpected_ident<'i>(location: SourceLocation, ident: &CowRcStr<'i>) -> ParseError<'i, ParserError<'i>> {
+  location.new_unexpected_token_error(Token::Ident(ident.clone()))
+}
+
+fn push_feature(features: &mut Vec<FontFeature>, tag: &'static str, enabled: bool) {
+  features.push(FontFeature {
+    tag: tag.into(),
+    value: enabled as CSSInteger,
+  });
+}
+
+/// A value for the [font-variant-ligatures](https://www.w3.org/TR/css-fonts-4/#font-variant-ligatures-prop) property.
+///
+/// The `normal` keyword is represented by the default value, with no components set. The `none`
+/// keyword is equivalent to disabling all ligatures and contextual alternates.
+#[derive(Debug, Clone, PartialEq, Default)]
 #[cfg_attr(feature = "visitor", derive(Visit))]
 #[cfg_attr(
   feature = "serde",
   derive(serde::Serialize, serde::Deserialize),
-  serde(tag = "type", content = "value", rename_all = "kebab-case")
+  serde(rename_all = "camelCase")
 )]
 #[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
-pub enum VerticalAlign {
-  /// A vertical align keyword.
-  Keyword(VerticalAlignKeyword),
-  /// An explicit length.
-  Length(LengthPercentage),
+pub struct FontVariantLigatures {
+  /// Whether common ligatures are enabled.
+  pub common: Option<CommonLigValues>,
+  /// Whether discretionary ligatures are enabled.
+  pub discretionary: Option<DiscretionaryLigValues>,
+  /// Whether historical ligatures are enabled.
+  pub historical: Option<HistoricalLigValues>,
+  /// Whether contextual alternates are enabled.
+  pub contextual: Option<ContextualAltValues>,
 }
 
-impl<'i> Parse<'i> for VerticalAlign {
-  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
-    if let Ok(len) = input.try_parse(LengthPercentage::parse) {
-      return Ok(VerticalAlign::Length(len));
+impl FontVariantLigatures {
+  /// Returns the value of the `none` keyword.
+  pub fn none() -> FontVariantLigatures {
+    FontVariantLigatures {
+      common: Some(CommonLigValues::NoCommonLigatures),
+      discretionary: Some(DiscretionaryLigValues::NoDiscretionaryLigatures),
+      historical: Some(HistoricalLigValues::NoHistoricalLigatures),
+      contextual: Some(ContextualAltValues::NoContextual),
     }
+  }
 
-    let kw = VerticalAlignKeyword::parse(input)?;
-    Ok(VerticalAlign::Keyword(kw))
+  fn parse_component<'i>(&mut self, input: &mut Parser<'i, '_>) -> Result<(), ParseError<'i, ParserError<'i>>> {
+    if self.common.is_none() {
+      if let Ok(value) = input.try_parse(CommonLigValues::parse) {
+        self.common = Some(value);
+        return Ok(());
+      }
+    }
+    if self.discretionary.is_none() {
+      if let Ok(value) = input.try_parse(DiscretionaryLigValues::parse) {
+        self.discretionary = Some(value);
+        return Ok(());
+      }
+    }
+    if self.historical.is_none() {
+      if let Ok(value) = input.try_parse(HistoricalLigValues::parse) {
+        self.historical = Some(value);
+        return Ok(());
+      }
+    }
+    if self.contextual.is_none() {
+      if let Ok(value) = input.try_parse(ContextualAltValues::parse) {
+        self.contextual = Some(value);
+        return Ok(());
+      }
+    }
+    Err(input.new_custom_error(ParserError::InvalidValue))
   }
-}
 
-impl ToCss for VerticalAlign {
-  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  fn components_to_css<W>(&self, dest: &mut Printer<W>, first: &mut bool) -> Result<(), PrinterError>
   where
     W: std::fmt::Write,
   {
-    match self {
-      VerticalAlign::Keyword(kw) => kw.to_css(dest),
-      VerticalAlign::Length(len) => len.to_css(dest),
+    if let Some(common) = &self.common {
+      write_separator(dest, first)?;
+      common.to_css(dest)?;
+    }
+    if let Some(discretionary) = &self.discretionary {
+      write_separator(dest, first)?;
+      discretionary.to_css(dest)?;
+    }
+    if let Some(historical) = &self.historical {
+      write_separator(dest, first)?;
+      historical.to_css(dest)?;
+    }
+    if let Some(contextual) = &self.contextual {
+      write_separator(dest, first)?;
+      contextual.to_css(dest)?;
     }
+    Ok(())
   }
-}
 
-define_shorthand! {
-  /// A value for the [font](https://www.w3.org/TR/css-fonts-4/#font-prop) shorthand property.
-  pub struct Font<'i> {
-    /// The font family.
-    #[cfg_attr(feature = "serde", serde(borrow))]
-    family: FontFamily(Vec<FontFamily<'i>>),
-    /// The font size.
-    size: FontSize(FontSize),
-    /// The font style.
-    style: FontStyle(FontStyle),
-    /// The font weight.
-    weight: FontWeight(FontWeight),
-    /// The font stretch.
-    stretch: FontStretch(FontStretch),
-    /// The line height.
-    line_height: LineHeight(LineHeight),
-    /// How the text should be capitalized. Only CSS 2.1 values are supported.
-    variant_caps: FontVariantCaps(FontVariantCaps),
+  fn feature_settings(&self, features: &mut Vec<FontFeature>) {
+    if let Some(common) = &self.common {
+      let enabled = *common == CommonLigValues::CommonLigatures;
+      push_feature(features, "liga", enabled);
+      push_feature(features, "clig", enabled);
+    }
+    if let Some(discretionary) = &self.discretionary {
+      push_feature(
+        features,
+        "dlig",
+        *discretionary == DiscretionaryLigValues::DiscretionaryLigatures,
+      );
+    }
+    if let Some(historical) = &self.historical {
+      push_feature(
+        features,
+        "hlig",
+        *historical == HistoricalLigValues::HistoricalLigatures,
+      );
+    }
+    if let Some(contextual) = &self.contextual {
+      push_feature(features, "calt", *contextual == ContextualAltValues::Contextual);
+    }
   }
 }
 
-impl<'i> Parse<'i> for Font<'i> {
+impl<'i> Parse<'i> for FontVariantLigatures {
   fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
-    let mut style = None;
-    let mut weight = None;
-    let mut stretch = None;
-    let size;
-    let mut variant_caps = None;
-    let mut count = 0;
-
-    loop {
-      // Skip "normal" since it is valid for several properties, but we don't know which ones it will be used for yet.
-      if input.try_parse(|input| input.expect_ident_matching("normal")).is_ok() {
-        count += 1;
-        continue;
-      }
-      if style.is_none() {
-        if let Ok(value) = input.try_parse(FontStyle::parse) {
-          style = Some(value);
-          count += 1;
-          continue;
-        }
-      }
-      if weight.is_none() {
-        if let Ok(value) = input.try_parse(FontWeight::parse) {
-          weight = Some(value);
-          count += 1;
-          continue;
-        }
-      }
-      if variant_caps.is_none() {
-        if let Ok(value) = input.try_parse(FontVariantCaps::parse_css2) {
-          variant_caps = Some(value);
-          count += 1;
-          continue;
-        }
-      }
-
-      if stretch.is_none() {
-        if let Ok(value) = input.try_parse(FontStretchKeyword::parse) {
-          stretch = Some(FontStretch::Keyword(value));
-          count += 1;
-          continue;
-        }
-      }
-      size = Some(FontSize::parse(input)?);
-      break;
-    }
-
-    if count > 4 {
-      return Err(input.new_custom_error(ParserError::InvalidDeclaration));
+    if input.try_parse(|input| input.expect_ident_matching("none")).is_ok() {
+      return Ok(FontVariantLigatures::none());
     }
 
-    let size = match size {
-      Some(s) => s,
-      None => return Err(input.new_custom_error(ParserError::InvalidDeclaration)),
-    };
-
-    let line_height = if input.try_parse(|input| input.expect_delim('/')).is_ok() {
-      Some(LineHeight::parse(input)?)
-    } else {
-      None
-    };
-
-    let family = input.parse_comma_separated(FontFamily::parse)?;
-    Ok(Font {
-      family,
-      size,
-      style: style.unwrap_or_default(),
-      weight: weight.unwrap_or_default(),
-      stretch: stretch.unwrap_or_default(),
-      line_height: line_height.unwrap_or_default(),
-      variant_caps: variant_caps.unwrap_or_default(),
-    })
+    parse_variant_components(input, FontVariantLigatures::parse_component)
   }
 }
 
-impl<'i> ToCss for Font<'i> {
+impl ToCss for FontVariantLigatures {
   fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
   where
     W: std::fmt::Write,
   {
-    if self.style != FontStyle::default() {
-      self.style.to_css(dest)?;
-      dest.write_char(' ')?;
+    if *self == FontVariantLigatures::default() {
+      return dest.write_str("normal");
     }
 
-    if self.variant_caps != FontVariantCaps::default() {
-      self.variant_caps.to_css(dest)?;
-      dest.write_char(' ')?;
+    if *self == FontVariantLigatures::none() {
+      return dest.write_str("none");
     }
 
-    if self.weight != FontWeight::default() {
-      self.weight.to_css(dest)?;
-      dest.write_char(' ')?;
-    }
+    self.components_to_css(dest, &mut true)
+  }
+}
 
-    if self.stretch != FontStretch::default() {
-      self.stretch.to_css(dest)?;
-      dest.write_char(' ')?;
-    }
+impl IsCompatible for FontVariantLigatures {
+  fn is_compatible(&self, _browsers: crate::targets::Browsers) -> bool {
+    true
+  }
+}
 
-    self.size.to_css(dest)?;
+enum_property! {
+  /// A [`<numeric-figure-values>`](https://www.w3.org/TR/css-fonts-4/#numeric-figure-values) keyword.
+  pub enum NumericFigureValues {
+    /// Lining numerals are used.
+    "lining-nums": LiningNums,
+    /// Old-style numerals are used.
+    "oldstyle-nums": OldstyleNums,
+  }
+}
 
-    if self.line_height != LineHeight::default() {
-      dest.delim('/', true)?;
-      self.line_height.to_css(dest)?;
-    }
+enum_property! {
+  /// A [`<numeric-spacing-values>`](https://www.w3.org/TR/css-fonts-4/#numeric-spacing-values) keyword.
+  pub enum NumericSpacingValues {
+    /// Numerals with varying widths are used.
+    "proportional-nums": ProportionalNums,
+    /// Numerals of uniform width are used.
+    "tabular-nums": TabularNums,
+  }
+}
+
+enum_property! {
+  /// A [`<numeric-fraction-values>`](https://www.w3.org/TR/css-fonts-4/#numeric-fraction-values) keyword.
+  pub enum NumericFractionValues {
+    /// Fractions are rendered with a diagonal slash.
+    "diagonal-fractions": DiagonalFractions,
+    /// Fractions are rendered with the numerator stacked above the denominator.
+    "stacked-fractions": StackedFractions,
+  }
+}
+
+/// A value for the [font-variant-numeric](https://www.w3.org/TR/css-fonts-4/#font-variant-numeric-prop) property.
+///
+/// The `normal` keyword is represented by the default value, with no components set.
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(rename_all = "camelCase")
+)]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+pub struct FontVariantNumeric {
+  /// The style of numerals.
+  pub figure: Option<NumericFigureValues>,
+  /// The width of numerals.
+  pub spacing: Option<NumericSpacingValues>,
+  /// How fractions are rendered.
+  pub fraction: Option<NumericFractionValues>,
+  /// Whether ordinal forms are used.
+  pub ordinal: bool,
+  /// Whether a slashed zero is used.
+  pub slashed_zero: bool,
+}
+
+impl FontVariantNumeric {
+  fn parse_component<'i>(&mut self, input: &mut Parser<'i, '_>) -> Result<(), ParseError<'i, ParserError<'i>>> {
+    if self.figure.is_none() {
+      if let Ok(value) = input.try_parse(NumericFigureValues::parse) {
+        self.figure = Some(value);
+        return Ok(());
+      }
+    }
+    if self.spacing.is_none() {
+      if let Ok(value) = input.try_parse(NumericSpacingValues::parse) {
+        self.spacing = Some(value);
+        return Ok(());
+      }
+    }
+    if self.fraction.is_none() {
+      if let Ok(value) = input.try_parse(NumericFractionValues::parse) {
+        self.fraction = Some(value);
+        return Ok(());
+      }
+    }
+
+    let location = input.current_source_location();
+    let ident = input.expect_ident()?;
+    let value = match_ignore_ascii_case! { ident,
+      "ordinal" => &mut self.ordinal,
+      "slashed-zero" => &mut self.slashed_zero,
+      _ => return Err(unexpected_ident(location, ident))
+    };
+
+    if *value {
+      return Err(unexpected_ident(location, ident));
+    }
+
+    *value = true;
+    Ok(())
+  }
+
+  fn components_to_css<W>(&self, dest: &mut Printer<W>, first: &mut bool) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    if let Some(figure) = &self.figure {
+      write_separator(dest, first)?;
+      figure.to_css(dest)?;
+    }
+    if let Some(spacing) = &self.spacing {
+      write_separator(dest, first)?;
+      spacing.to_css(dest)?;
+    }
+    if let Some(fraction) = &self.fraction {
+      write_separator(dest, first)?;
+      fraction.to_css(dest)?;
+    }
+    if self.ordinal {
+      write_separator(dest, first)?;
+      dest.write_str("ordinal")?;
+    }
+    if self.slashed_zero {
+      write_separator(dest, first)?;
+      dest.write_str("slashed-zero")?;
+    }
+    Ok(())
+  }
+
+  fn feature_settings(&self, features: &mut Vec<FontFeature>) {
+    match self.figure {
+      Some(NumericFigureValues::LiningNums) => push_feature(features, "lnum", true),
+      Some(NumericFigureValues::OldstyleNums) => push_feature(features, "onum", true),
+      None => {}
+    }
+    match self.spacing {
+      Some(NumericSpacingValues::ProportionalNums) => push_feature(features, "pnum", true),
+      Some(NumericSpacingValues::TabularNums) => push_feature(features, "tnum", true),
+      None => {}
+    }
+    match self.fraction {
+      Some(NumericFractionValues::DiagonalFractions) => push_feature(features, "frac", true),
+      Some(NumericFractionValues::StackedFractions) => push_feature(features, "afrc", true),
+      None => {}
+    }
+    if self.ordinal {
+      push_feature(features, "ordn", true);
+    }
+    if self.slashed_zero {
+      push_feature(features, "zero", true);
+    }
+  }
+}
+
+impl<'i> Parse<'i> for FontVariantNumeric {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    parse_variant_components(input, FontVariantNumeric::parse_component)
+  }
+}
+
+impl ToCss for FontVariantNumeric {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    if *self == FontVariantNumeric::default() {
+      return dest.write_str("normal");
+    }
+
+    self.components_to_css(dest, &mut true)
+  }
+}
+
+impl IsCompatible for FontVariantNumeric {
+  fn is_compatible(&self, _browsers: crate::targets::Browsers) -> bool {
+    true
+  }
+}
+
+/// A value for the [font-variant-alternates](https://www.w3.org/TR/css-fonts-4/#font-variant-alternates-prop) property.
+///
+/// The `normal` keyword is represented by the default value, with no components set. Apart from
+/// `historical-forms`, the alternates refer to names defined by a `@font-feature-values` rule.
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(rename_all = "camelCase")
+)]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+pub struct FontVariantAlternates<'i> {
+  /// The `stylistic()` function.
+  #[cfg_attr(feature = "serde", serde(borrow))]
+  pub stylistic: Option<CustomIdent<'i>>,
+  /// Whether historical forms are enabled.
+  pub historical_forms: bool,
+  /// The `styleset()` function. Empty if not specified.
+  pub styleset: Vec<CustomIdent<'i>>,
+  /// The `character-variant()` function. Empty if not specified.
+  pub character_variant: Vec<CustomIdent<'i>>,
+  /// The `swash()` function.
+  pub swash: Option<CustomIdent<'i>>,
+  /// The `ornaments()` function.
+  pub ornaments: Option<CustomIdent<'i>>,
+  /// The `annotation()` function.
+  pub annotation: Option<CustomIdent<'i>>,
+}
+
+impl<'i> FontVariantAlternates<'i> {
+  fn parse_component<'t>(&mut self, input: &mut Parser<'i, 't>) -> Result<(), ParseError<'i, ParserError<'i>>> {
+    let location = input.current_source_location();
+    if !self.historical_forms && input.try_parse(|input| input.expect_ident_matching("historical-forms")).is_ok() {
+      self.historical_forms = true;
+      return Ok(());
+    }
+
+    let name = input.expect_function()?.clone();
+    match_ignore_ascii_case! { &name,
+      "stylistic" if self.stylistic.is_none() => {
+        self.stylistic = Some(input.parse_nested_block(CustomIdent::parse)?);
+      },
+      "styleset" if self.styleset.is_empty() => {
+        self.styleset = input.parse_nested_block(|input| input.parse_comma_separated(CustomIdent::parse))?;
+      },
+      "character-variant" if self.character_variant.is_empty() => {
+        self.character_variant =
+          input.parse_nested_block(|input| input.parse_comma_separated(CustomIdent::parse))?;
+      },
+      "swash" if self.swash.is_none() => {
+        self.swash = Some(input.parse_nested_block(CustomIdent::parse)?);
+      },
+      "ornaments" if self.ornaments.is_none() => {
+        self.ornaments = Some(input.parse_nested_block(CustomIdent::parse)?);
+      },
+      "annotation" if self.annotation.is_none() => {
+        self.annotation = Some(input.parse_nested_block(CustomIdent::parse)?);
+      },
+      _ => return Err(location.new_unexpected_token_error(Token::Function(name)))
+    }
+
+    Ok(())
+  }
+
+  fn components_to_css<W>(&self, dest: &mut Printer<W>, first: &mut bool) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    macro_rules! function {
+      ($name: literal, $value: expr) => {{
+        write_separator(dest, first)?;
+        dest.write_str($name)?;
+        dest.write_char('(')?;
+        $value.to_css(dest)?;
+        dest.write_char(')')?;
+      }};
+    }
+
+    macro_rules! list {
+      ($name: literal, $values: expr) => {{
+        write_separator(dest, first)?;
+        dest.write_str($name)?;
+        dest.write_char('(')?;
+        let mut first = true;
+        for value in $values {
+          if first {
+            first = false;
+          } else {
+            dest.delim(',', false)?;
+          }
+          value.to_css(dest)?;
+        }
+        dest.write_char(')')?;
+      }};
+    }
+
+    if let Some(stylistic) = &self.stylistic {
+      function!("stylistic", stylistic);
+    }
+    if self.historical_forms {
+      write_separator(dest, first)?;
+      dest.write_str("historical-forms")?;
+    }
+    if !self.styleset.is_empty() {
+      list!("styleset", &self.styleset);
+    }
+    if !self.character_variant.is_empty() {
+      list!("character-variant", &self.character_variant);
+    }
+    if let Some(swash) = &self.swash {
+      function!("swash", swash);
+    }
+    if let Some(ornaments) = &self.ornaments {
+      function!("ornaments", ornaments);
+    }
+    if let Some(annotation) = &self.annotation {
+      function!("annotation", annotation);
+    }
+    Ok(())
+  }
+
+  fn feature_settings(&self, features: &mut Vec<FontFeature<'i>>) {
+    // The other alternates depend on the feature indices defined by @font-feature-values rules.
+    if self.historical_forms {
+      push_feature(features, "hist", true);
+    }
+  }
+}
+
+impl<'i> Parse<'i> for FontVariantAlternates<'i> {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    parse_variant_components(input, FontVariantAlternates::parse_component)
+  }
+}
+
+impl<'i> ToCss for FontVariantAlternates<'i> {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    if *self == FontVariantAlternates::default() {
+      return dest.write_str("normal");
+    }
+
+    self.components_to_css(dest, &mut true)
+  }
+}
+
+impl<'i> IsCompatible for FontVariantAlternates<'i> {
+  fn is_compatible(&self, _browsers: crate::targets::Browsers) -> bool {
+    true
+  }
+}
+
+enum_property! {
+  /// An [`<east-asian-variant-values>`](https://www.w3.org/TR/css-fonts-4/#east-asian-variant-values) keyword.
+  pub enum EastAsianVariantValues {
+    /// Glyphs from the JIS X 0208-1978 standard.
+    "jis78": Jis78,
+    /// Glyphs from the JIS X 0208-1983 standard.
+    "jis83": Jis83,
+    /// Glyphs from the JIS X 0208-1990 standard.
+    "jis90": Jis90,
+    /// Glyphs from the JIS X 0213:2004 standard.
+    "jis04": Jis04,
+    /// Simplified Chinese glyphs.
+    "simplified": Simplified,
+    /// Traditional Chinese glyphs.
+    "traditional": Traditional,
+  }
+}
+
+enum_property! {
+  /// An [`<east-asian-width-values>`](https://www.w3.org/TR/css-fonts-4/#east-asian-width-values) keyword.
+  pub enum EastAsianWidthValues {
+    /// Glyphs of roughly equal width are used.
+    "full-width": FullWidth,
+    /// Glyphs with varying widths are used.
+    "proportional-width": ProportionalWidth,
+  }
+}
+
+/// A value for the [font-variant-east-asian](https://www.w3.org/TR/css-fonts-4/#font-variant-east-asian-prop) property.
+///
+/// The `normal` keyword is represented by the default value, with no components set.
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(rename_all = "camelCase")
+)]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+pub struct FontVariantEastAsian {
+  /// The set of logographic glyph variants.
+  pub variant: Option<EastAsianVariantValues>,
+  /// The width of glyphs.
+  pub width: Option<EastAsianWidthValues>,
+  /// Whether ruby variant glyphs are used.
+  pub ruby: bool,
+}
+
+impl FontVariantEastAsian {
+  fn parse_component<'i>(&mut self, input: &mut Parser<'i, '_>) -> Result<(), ParseError<'i, ParserError<'i>>> {
+    if self.variant.is_none() {
+      if let Ok(value) = input.try_parse(EastAsianVariantValues::parse) {
+        self.variant = Some(value);
+        return Ok(());
+      }
+    }
+    if self.width.is_none() {
+      if let Ok(value) = input.try_parse(EastAsianWidthValues::parse) {
+        self.width = Some(value);
+        return Ok(());
+      }
+    }
+    if !self.ruby && input.try_parse(|input| input.expect_ident_matching("ruby")).is_ok() {
+      self.ruby = true;
+      return Ok(());
+    }
+    Err(input.new_custom_error(ParserError::InvalidValue))
+  }
+
+  fn components_to_css<W>(&self, dest: &mut Printer<W>, first: &mut bool) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    if let Some(variant) = &self.variant {
+      write_separator(dest, first)?;
+      variant.to_css(dest)?;
+    }
+    if let Some(width) = &self.width {
+      write_separator(dest, first)?;
+      width.to_css(dest)?;
+    }
+    if self.ruby {
+      write_separator(dest, first)?;
+      dest.write_str("ruby")?;
+    }
+    Ok(())
+  }
+
+  fn feature_settings(&self, features: &mut Vec<FontFeature>) {
+    if let Some(variant) = &self.variant {
+      let tag = match variant {
+        EastAsianVariantValues::Jis78 => "jp78",
+        EastAsianVariantValues::Jis83 => "jp83",
+        EastAsianVariantValues::Jis90 => "jp90",
+        EastAsianVariantValues::Jis04 => "jp04",
+        EastAsianVariantValues::Simplified => "smpl",
+        EastAsianVariantValues::Traditional => "trad",
+      };
+      push_feature(features, tag, true);
+    }
+    match self.width {
+      Some(EastAsianWidthValues::FullWidth) => push_feature(features, "fwid", true),
+      Some(EastAsianWidthValues::ProportionalWidth) => push_feature(features, "pwid", true),
+      None => {}
+    }
+    if self.ruby {
+      push_feature(features, "ruby", true);
+    }
+  }
+}
+
+impl<'i> Parse<'i> for FontVariantEastAsian {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    parse_variant_components(input, FontVariantEastAsian::parse_component)
+  }
+}
+
+impl ToCss for FontVariantEastAsian {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    if *self == FontVariantEastAsian::default() {
+      return dest.write_str("normal");
+    }
+
+    self.components_to_css(dest, &mut true)
+  }
+}
+
+impl IsCompatible for FontVariantEastAsian {
+  fn is_compatible(&self, _browsers: crate::targets::Browsers) -> bool {
+    true
+  }
+}
+
+define_shorthand! {
+  /// A value for the [font-variant](https://www.w3.org/TR/css-fonts-4/#font-variant-prop) shorthand property.
+  pub struct FontVariant<'i> {
+    /// The ligatures and contextual forms that are used.
+    ligatures: FontVariantLigatures(FontVariantLigatures),
+    /// How the text should be capitalized.
+    caps: FontVariantCaps(FontVariantCaps),
+    /// The alternate glyphs that are used.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    alternates: FontVariantAlternates(FontVariantAlternates<'i>),
+    /// The glyphs used for numbers, fractions, and ordinal markers.
+    numeric: FontVariantNumeric(FontVariantNumeric),
+    /// The glyphs used for East Asian text.
+    east_asian: FontVariantEastAsian(FontVariantEastAsian),
+    /// Whether subscript or superscript glyphs are used.
+    position: FontVariantPosition(FontVariantPosition),
+  }
+}
+
+impl<'i> Parse<'i> for FontVariant<'i> {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    let mut variant = FontVariant {
+      ligatures: FontVariantLigatures::default(),
+      caps: FontVariantCaps::default(),
+      alternates: FontVariantAlternates::default(),
+      numeric: FontVariantNumeric::default(),
+      east_asian: FontVariantEastAsian::default(),
+      position: FontVariantPosition::default(),
+    };
+
+    if input.try_parse(|input| input.expect_ident_matching("normal")).is_ok() {
+      return Ok(variant);
+    }
+
+    if input.try_parse(|input| input.expect_ident_matching("none")).is_ok() {
+      variant.ligatures = FontVariantLigatures::none();
+      return Ok(variant);
+    }
+
+    let mut caps = None;
+    let mut position = None;
+    let mut any = false;
+    loop {
+      // The `normal` keyword is only valid on its own, so it must not be parsed as a caps or position value.
+      if caps.is_none() {
+        if let Ok(value) = input.try_parse(|input| match FontVariantCaps::parse(input)? {
+          FontVariantCaps::Normal => Err(input.new_custom_error(ParserError::InvalidValue)),
+          value => Ok(value),
+        }) {
+          caps = Some(value);
+          any = true;
+          continue;
+        }
+      }
+      if position.is_none() {
+        if let Ok(value) = input.try_parse(|input| match FontVariantPosition::parse(input)? {
+          FontVariantPosition::Normal => Err(input.new_custom_error(ParserError::InvalidValue)),
+          value => Ok(value),
+        }) {
+          position = Some(value);
+          any = true;
+          continue;
+        }
+      }
+      if input.try_parse(|input| variant.ligatures.parse_component(input)).is_ok()
+        || input.try_parse(|input| variant.alternates.parse_component(input)).is_ok()
+        || input.try_parse(|input| variant.numeric.parse_component(input)).is_ok()
+        || input.try_parse(|input| variant.east_asian.parse_component(input)).is_ok()
+      {
+        any = true;
+        continue;
+      }
+      break;
+    }
+
+    if !any {
+      return Err(input.new_custom_error(ParserError::InvalidValue));
+    }
+
+    variant.caps = caps.unwrap_or_default();
+    variant.position = position.unwrap_or_default();
+    Ok(variant)
+  }
+}
+
+impl<'i> ToCss for FontVariant<'i> {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    let is_normal = self.caps == FontVariantCaps::Normal
+      && self.alternates == FontVariantAlternates::default()
+      && self.numeric == FontVariantNumeric::default()
+      && self.east_asian == FontVariantEastAsian::default()
+      && self.position == FontVariantPosition::Normal;
+
+    if is_normal && self.ligatures == FontVariantLigatures::default() {
+      return dest.write_str("normal");
+    }
+
+    // The `none` keyword cannot be combined with other values, so the individual
+    // ligature keywords are written instead when other components are set.
+    if is_normal && self.ligatures == FontVariantLigatures::none() {
+      return dest.write_str("none");
+    }
+
+    let mut first = true;
+    self.ligatures.components_to_css(dest, &mut first)?;
+    if self.caps != FontVariantCaps::Normal {
+      write_separator(dest, &mut first)?;
+      self.caps.to_css(dest)?;
+    }
+    self.alternates.components_to_css(dest, &mut first)?;
+    self.numeric.components_to_css(dest, &mut first)?;
+    self.east_asian.components_to_css(dest, &mut first)?;
+    if self.position != FontVariantPosition::Normal {
+      write_separator(dest, &mut first)?;
+      self.position.to_css(dest)?;
+    }
+    Ok(())
+  }
+}
+
+/// Parses an [OpenType feature tag](https://www.w3.org/TR/css-fonts-4/#font-feature-settings-prop),
+/// which must be a string of exactly four ASCII characters in the range U+20–U+7E.
+fn parse_opentype_tag<'i>(input: &mut Parser<'i, '_>) -> Result<CowArcStr<'i>, ParseError<'i, ParserError<'i>>> {
+  let location = input.current_source_location();
+  let tag = input.expect_string_cloned()?;
+  if tag.len() != 4 || !tag.bytes().all(|b| (0x20..=0x7e).contains(&b)) {
+    return Err(location.new_custom_error(ParserError::InvalidValue));
+  }
+  Ok(tag.into())
+}
+
+/// A feature tag and value within the [font-feature-settings](https://www.w3.org/TR/css-fonts-4/#font-feature-settings-prop) property.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(rename_all = "camelCase")
+)]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+pub struct FontFeature<'i> {
+  /// The four character OpenType feature tag.
+  #[cfg_attr(feature = "serde", serde(borrow))]
+  pub tag: CowArcStr<'i>,
+  /// The feature value. `on` is equivalent to 1, and `off` is equivalent to 0.
+  pub value: CSSInteger,
+}
+
+impl<'i> Parse<'i> for FontFeature<'i> {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    let tag = parse_opentype_tag(input)?;
+    let location = input.current_source_location();
+    let value = if let Ok(value) = input.try_parse(CSSInteger::parse) {
+      if value < 0 {
+        return Err(location.new_custom_error(ParserError::InvalidValue));
+      }
+      value
+    } else if let Ok(ident) = input.try_parse(|input| input.expect_ident_cloned()) {
+      match_ignore_ascii_case! { &*ident,
+        "on" => 1,
+        "off" => 0,
+        _ => return Err(location.new_unexpected_token_error(Token::Ident(ident)))
+      }
+    } else {
+      1
+    };
+
+    Ok(FontFeature { tag, value })
+  }
+}
+
+impl<'i> ToCss for FontFeature<'i> {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    serialize_string(&self.tag, dest)?;
+    if self.value != 1 {
+      dest.write_char(' ')?;
+      self.value.to_css(dest)?;
+    }
+    Ok(())
+  }
+}
+
+/// A value for the [font-feature-settings](https://www.w3.org/TR/css-fonts-4/#font-feature-settings-prop) property.
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "type", content = "value", rename_all = "kebab-case")
+)]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+pub enum FontFeatureSettings<'i> {
+  /// No feature settings are applied.
+  #[default]
+  Normal,
+  /// A list of feature settings. Each tag appears at most once.
+  #[cfg_attr(feature = "serde", serde(borrow))]
+  Features(Vec<FontFeature<'i>>),
+}
+
+impl<'i> Parse<'i> for FontFeatureSettings<'i> {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    if input.try_parse(|input| input.expect_ident_matching("normal")).is_ok() {
+      return Ok(FontFeatureSettings::Normal);
+    }
+
+    let mut features: Vec<FontFeature<'i>> = Vec::new();
+    for feature in input.parse_comma_separated(FontFeature::parse)? {
+      // If the same tag appears more than once, the last occurrence wins.
+      features.retain(|f| f.tag != feature.tag);
+      features.push(feature);
+    }
+    Ok(FontFeatureSettings::Features(features))
+  }
+}
+
+impl<'i> ToCss for FontFeatureSettings<'i> {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    match self {
+      FontFeatureSettings::Normal => dest.write_str("normal"),
+      FontFeatureSettings::Features(features) => {
+        let mut first = true;
+        for feature in features {
+          if first {
+            first = false;
+          } else {
+            dest.delim(',', false)?;
+          }
+          feature.to_css(dest)?;
+        }
+        Ok(())
+      }
+    }
+  }
+}
+
+/// An axis tag and value within the [font-variation-settings](https://www.w3.org/TR/css-fonts-4/#font-variation-settings-def) property.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(rename_all = "camelCase")
+)]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+pub struct FontVariation<'i> {
+  /// The four character OpenType axis tag.
+  #[cfg_attr(feature = "serde", serde(borrow))]
+  pub tag: CowArcStr<'i>,
+  /// The axis value.
+  pub value: CSSNumber,
+}
+
+impl<'i> Parse<'i> for FontVariation<'i> {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    let tag = parse_opentype_tag(input)?;
+    let value = CSSNumber::parse(input)?;
+    Ok(FontVariation { tag, value })
+  }
+}
+
+impl<'i> ToCss for FontVariation<'i> {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    serialize_string(&self.tag, dest)?;
+    dest.write_char(' ')?;
+    self.value.to_css(dest)
+  }
+}
+
+/// A value for the [font-variation-settings](https://www.w3.org/TR/css-fonts-4/#font-variation-settings-def) property.
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "type", content = "value", rename_all = "kebab-case")
+)]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+pub enum FontVariationSettings<'i> {
+  /// No variation settings are applied.
+  #[default]
+  Normal,
+  /// A list of axis values. Each tag appears at most once.
+  #[cfg_attr(feature = "serde", serde(borrow))]
+  Variations(Vec<FontVariation<'i>>),
+}
+
+impl<'i> Parse<'i> for FontVariationSettings<'i> {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    if input.try_parse(|input| input.expect_ident_matching("normal")).is_ok() {
+      return Ok(FontVariationSettings::Normal);
+    }
+
+    let mut variations: Vec<FontVariation<'i>> = Vec::new();
+    for variation in input.parse_comma_separated(FontVariation::parse)? {
+      // If the same axis appears more than once, the last occurrence wins.
+      variations.retain(|v| v.tag != variation.tag);
+      variations.push(variation);
+    }
+    Ok(FontVariationSettings::Variations(variations))
+  }
+}
+
+impl<'i> ToCss for FontVariationSettings<'i> {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    match self {
+      FontVariationSettings::Normal => dest.write_str("normal"),
+      FontVariationSettings::Variations(variations) => {
+        let mut first = true;
+        for variation in variations {
+          if first {
+            first = false;
+          } else {
+            dest.delim(',', false)?;
+          }
+          variation.to_css(dest)?;
+        }
+        Ok(())
+      }
+    }
+  }
+}
+
+enum_property! {
+  /// A font metric, as used in the [font-size-adjust](https://www.w3.org/TR/css-fonts-5/#font-size-adjust-prop) property.
+  #[derive(Default)]
+  pub enum FontMetric {
+    /// The x-height of the font.
+    #[default]
+    "ex-height": ExHeight,
+    /// The cap-height of the font.
+    "cap-height": CapHeight,
+    /// The advance width of the "0" glyph.
+    "ch-width": ChWidth,
+    /// The advance width of the CJK water ideograph.
+    "ic-width": IcWidth,
+    /// The advance height of the CJK water ideograph.
+    "ic-height": IcHeight,
+  }
+}
+
+/// The aspect value of the [font-size-adjust](https://www.w3.org/TR/css-fonts-5/#font-size-adjust-prop) property.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "type", content = "value", rename_all = "kebab-case")
+)]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+pub enum FontSizeAdjustValue {
+  /// The aspect value is taken from the first available font.
+  FromFont,
+  /// An explicit aspect value.
+  Number(CSSNumber),
+}
+
+impl<'i> Parse<'i> for FontSizeAdjustValue {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    if input.try_parse(|input| input.expect_ident_matching("from-font")).is_ok() {
+      return Ok(FontSizeAdjustValue::FromFont);
+    }
+
+    let val = CSSNumber::parse(input)?;
+    if val < 0.0 {
+      return Err(input.new_custom_error(ParserError::InvalidValue));
+    }
+    Ok(FontSizeAdjustValue::Number(val))
+  }
+}
+
+impl ToCss for FontSizeAdjustValue {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    match self {
+      FontSizeAdjustValue::FromFont => dest.write_str("from-font"),
+      FontSizeAdjustValue::Number(val) => val.to_css(dest),
+    }
+  }
+}
+
+/// A value for the [font-size-adjust](https://www.w3.org/TR/css-fonts-5/#font-size-adjust-prop) property.
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "type", rename_all = "kebab-case")
+)]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+pub enum FontSizeAdjust {
+  /// The font size is not adjusted.
+  #[default]
+  None,
+  /// The font size is adjusted so that the given font metric matches the aspect value.
+  Value {
+    /// The font metric to normalize.
+    metric: FontMetric,
+    /// The aspect value.
+    value: FontSizeAdjustValue,
+  },
+}
+
+impl<'i> Parse<'i> for FontSizeAdjust {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    if input.try_parse(|input| input.expect_ident_matching("none")).is_ok() {
+      return Ok(FontSizeAdjust::None);
+    }
+
+    let metric = input.try_parse(FontMetric::parse).unwrap_or_default();
+    let value = FontSizeAdjustValue::parse(input)?;
+    Ok(FontSizeAdjust::Value { metric, value })
+  }
+}
+
+impl ToCss for FontSizeAdjust {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    match self {
+      FontSizeAdjust::None => dest.write_str("none"),
+      FontSizeAdjust::Value { metric, value } => {
+        // The `ex-height` metric is the default, and omitting it keeps the value
+        // compatible with browsers that only support the single value syntax.
+        if *metric != FontMetric::ExHeight {
+          metric.to_css(dest)?;
+          dest.write_char(' ')?;
+        }
+        value.to_css(dest)
+      }
+    }
+  }
+}
+
+impl IsCompatible for FontSizeAdjust {
+  fn is_compatible(&self, browsers: crate::targets::Browsers) -> bool {
+    match self {
+      FontSizeAdjust::None => true,
+      FontSizeAdjust::Value { metric, value } => {
+        (*metric == FontMetric::ExHeight || Feature::FontSizeAdjustTwoValues.is_compatible(browsers))
+          && (*value != FontSizeAdjustValue::FromFont || Feature::FontSizeAdjustFromFont.is_compatible(browsers))
+      }
+    }
+  }
+}
+
+/// A value for the [line-height](https://www.w3.org/TR/2020/WD-css-inline-3-20200827/#propdef-line-height) property.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "type", content = "value", rename_all = "kebab-case")
+)]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+pub enum LineHeight {
+  /// The UA sets the line height based on the font.
+  Normal,
+  /// A multiple of the element's font size.
+  Number(CSSNumber),
+  /// An explicit height.
+  Length(LengthPercentage),
+}
+
+impl Default for LineHeight {
+  fn default() -> LineHeight {
+    LineHeight::Normal
+  }
+}
+
+impl<'i> Parse<'i> for LineHeight {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    if input.try_parse(|input| input.expect_ident_matching("normal")).is_ok() {
+      return Ok(LineHeight::Normal);
+    }
+
+    if let Ok(val) = input.try_parse(CSSNumber::parse) {
+      if val < 0.0 {
+        return Err(input.new_custom_error(ParserError::InvalidValue));
+      }
+      return Ok(LineHeight::Number(val));
+    }
+
+    let val = LengthPercentage::parse(input)?;
+    if val.is_sign_negative() {
+      return Err(input.new_custom_error(ParserError::InvalidValue));
+    }
+    Ok(LineHeight::Length(val))
+  }
+}
+
+impl ToCss for LineHeight {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    match self {
+      LineHeight::Normal => dest.write_str("normal"),
+      LineHeight::Number(val) => val.to_css(dest),
+      LineHeight::Length(val) => val.to_css(dest),
+    }
+  }
+}
+
+impl IsCompatible for LineHeight {
+  fn is_compatible(&self, browsers: crate::targets::Browsers) -> bool {
+    match self {
+      LineHeight::Length(l) => l.is_compatible(browsers),
+      LineHeight::Normal | LineHeight::Number(..) => true,
+    }
+  }
+}
+
+enum_property! {
+  /// A keyword for the [vertical align](https://drafts.csswg.org/css2/#propdef-vertical-align) property.
+  pub enum VerticalAlignKeyword {
+    /// Align the baseline of the box with the baseline of the parent box.
+    "baseline": Baseline,
+    /// Lower the baseline of the box to the proper position for subscripts of the parent’s box.
+    "sub": Sub,
+    /// Raise the baseline of the box to the proper position for superscripts of the parent’s box.
+    "super": Super,
+    /// Align the top of the aligned subtree with the top of the line box.
+    "top": Top,
+    /// Align the top of the box with the top of the parent’s content area.
+    "text-top": TextTop,
+    /// Align the vertical midpoint of the box with the baseline of the parent box plus half the x-height of the parent.
+    "middle": Middle,
+    /// Align the bottom of the aligned subtree with the bottom of the line box.
+    "bottom": Bottom,
+    /// Align the bottom of the box with the bottom of the parent’s content area.
+    "text-bottom": TextBottom,
+  }
+}
+
+/// A value for the [vertical align](https://drafts.csswg.org/css2/#propdef-vertical-align) property.
+// TODO: there is a more extensive spec in CSS3 but it doesn't seem any browser implements it? https://www.w3.org/TR/css-inline-3/#transverse-alignment
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "type", content = "value", rename_all = "kebab-case")
+)]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+pub enum VerticalAlign {
+  /// A vertical align keyword.
+  Keyword(VerticalAlignKeyword),
+  /// An explicit length.
+  Length(LengthPercentage),
+}
+
+impl<'i> Parse<'i> for VerticalAlign {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    if let Ok(len) = input.try_parse(LengthPercentage::parse) {
+      return Ok(VerticalAlign::Length(len));
+    }
+
+    let kw = VerticalAlignKeyword::parse(input)?;
+    Ok(VerticalAlign::Keyword(kw))
+  }
+}
+
+impl ToCss for VerticalAlign {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    match self {
+      VerticalAlign::Keyword(kw) => kw.to_css(dest),
+      VerticalAlign::Length(len) => len.to_css(dest),
+    }
+  }
+}
+
+enum_property! {
+  /// A value for the [font-synthesis-weight](https://www.w3.org/TR/css-fonts-4/#font-synthesis-weight),
+  /// [font-synthesis-style](https://www.w3.org/TR/css-fonts-4/#font-synthesis-style),
+  /// [font-synthesis-small-caps](https://www.w3.org/TR/css-fonts-4/#font-synthesis-small-caps), and
+  /// [font-synthesis-position](https://www.w3.org/TR/css-fonts-4/#font-synthesis-position) properties.
+  #[derive(Default)]
+  pub enum FontSynthesisKeyword {
+    /// The user agent may synthesize the face.
+    #[default]
+    Auto,
+    /// The user agent must not synthesize the face.
+    None,
+  }
+}
+
+define_shorthand! {
+  /// A value for the [font-synthesis](https://www.w3.org/TR/css-fonts-4/#font-synthesis) shorthand property.
+  pub struct FontSynthesis {
+    /// Whether bold faces may be synthesized.
+    weight: FontSynthesisWeight(FontSynthesisKeyword),
+    /// Whether oblique faces may be synthesized.
+    style: FontSynthesisStyle(FontSynthesisKeyword),
+    /// Whether small-caps faces may be synthesized.
+    small_caps: FontSynthesisSmallCaps(FontSynthesisKeyword),
+    /// Whether subscript and superscript faces may be synthesized.
+    position: FontSynthesisPosition(FontSynthesisKeyword),
+  }
+}
+
+impl<'i> Parse<'i> for FontSynthesis {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    let mut synthesis = FontSynthesis {
+      weight: FontSynthesisKeyword::None,
+      style: FontSynthesisKeyword::None,
+      small_caps: FontSynthesisKeyword::None,
+      position: FontSynthesisKeyword::None,
+    };
+
+    if input.try_parse(|input| input.expect_ident_matching("none")).is_ok() {
+      return Ok(synthesis);
+    }
+
+    let mut any = false;
+    loop {
+      let location = input.current_source_location();
+      let ident = match input.try_parse(|input| input.expect_ident_cloned()) {
+        Ok(ident) => ident,
+        Err(_) => break,
+      };
+
+      let value = match_ignore_ascii_case! { &*ident,
+        "weight" => &mut synthesis.weight,
+        "style" => &mut synthesis.style,
+        "small-caps" => &mut synthesis.small_caps,
+        "position" => &mut synthesis.position,
+        _ => return Err(location.new_unexpected_token_error(Token::Ident(ident.clone())))
+      };
+
+      // Each keyword may only appear once.
+      if *value == FontSynthesisKeyword::Auto {
+        return Err(location.new_unexpected_token_error(Token::Ident(ident.clone())));
+      }
+
+      *value = FontSynthesisKeyword::Auto;
+      any = true;
+    }
+
+    if !any {
+      return Err(input.new_custom_error(ParserError::InvalidValue));
+    }
+
+    Ok(synthesis)
+  }
+}
+
+impl ToCss for FontSynthesis {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    let mut first = true;
+    macro_rules! keyword {
+      ($key: ident, $name: literal) => {
+        if self.$key == FontSynthesisKeyword::Auto {
+          if first {
+            first = false;
+          } else {
+            dest.write_char(' ')?;
+          }
+          dest.write_str($name)?;
+        }
+      };
+    }
+
+    keyword!(weight, "weight");
+    keyword!(style, "style");
+    keyword!(small_caps, "small-caps");
+    keyword!(position, "position");
+
+    if first {
+      dest.write_str("none")?;
+    }
+
+    Ok(())
+  }
+}
+
+define_shorthand! {
+  /// A value for the [font](https://www.w3.org/TR/css-fonts-4/#font-prop) shorthand property.
+  pub struct Font<'i> {
+    /// The font family.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    family: FontFamily(Vec<FontFamily<'i>>),
+    /// The font size.
+    size: FontSize(FontSize),
+    /// The font style.
+    style: FontStyle(FontStyle),
+    /// The font weight.
+    weight: FontWeight(FontWeight),
+    /// The font stretch.
+    stretch: FontStretch(FontStretch),
+    /// The line height.
+    line_height: LineHeight(LineHeight),
+    /// How the text should be capitalized. Only CSS 2.1 values are supported.
+    variant_caps: FontVariantCaps(FontVariantCaps),
+  }
+}
+
+impl<'i> Parse<'i> for Font<'i> {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    let mut style = None;
+    let mut weight = None;
+    let mut stretch = None;
+    let size;
+    let mut variant_caps = None;
+    let mut count = 0;
+
+    loop {
+      // Skip "normal" since it is valid for several properties, but we don't know which ones it will be used for yet.
+      if input.try_parse(|input| input.expect_ident_matching("normal")).is_ok() {
+        count += 1;
+        continue;
+      }
+      if style.is_none() {
+        if let Ok(value) = input.try_parse(FontStyle::parse) {
+          style = Some(value);
+          count += 1;
+          continue;
+        }
+      }
+      if weight.is_none() {
+        if let Ok(value) = input.try_parse(FontWeight::parse) {
+          weight = Some(value);
+          count += 1;
+          continue;
+        }
+      }
+      if variant_caps.is_none() {
+        if let Ok(value) = input.try_parse(FontVariantCaps::parse_css2) {
+          variant_caps = Some(value);
+          count += 1;
+          continue;
+        }
+      }
+
+      if stretch.is_none() {
+        if let Ok(value) = input.try_parse(FontStretchKeyword::parse) {
+          stretch = Some(FontStretch::Keyword(value));
+          count += 1;
+          continue;
+        }
+      }
+      size = Some(FontSize::parse(input)?);
+      break;
+    }
+
+    if count > 4 {
+      return Err(input.new_custom_error(ParserError::InvalidDeclaration));
+    }
+
+    let size = match size {
+      Some(s) => s,
+      None => return Err(input.new_custom_error(ParserError::InvalidDeclaration)),
+    };
+
+    let line_height = if input.try_parse(|input| input.expect_delim('/')).is_ok() {
+      Some(LineHeight::parse(input)?)
+    } else {
+      None
+    };
+
+    let family = input.parse_comma_separated(FontFamily::parse)?;
+    Ok(Font {
+      family,
+      size,
+      style: style.unwrap_or_default(),
+      weight: weight.unwrap_or_default(),
+      stretch: stretch.unwrap_or_default(),
+      line_height: line_height.unwrap_or_default(),
+      variant_caps: variant_caps.unwrap_or_default(),
+    })
+  }
+}
+
+impl<'i> ToCss for Font<'i> {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    if self.style != FontStyle::default() {
+      self.style.to_css(dest)?;
+      dest.write_char(' ')?;
+    }
+
+    if self.variant_caps != FontVariantCaps::default() {
+      self.variant_caps.to_css(dest)?;
+      dest.write_char(' ')?;
+    }
+
+    if self.weight != FontWeight::default() {
+      self.weight.to_css(dest)?;
+      dest.write_char(' ')?;
+    }
+
+    if self.stretch != FontStretch::default() {
+      self.stretch.to_css(dest)?;
+      dest.write_char(' ')?;
+    }
+
+    self.size.to_css(dest)?;
+
+    if self.line_height != LineHeight::default() {
+      dest.delim('/', true)?;
+      self.line_height.to_css(dest)?;
+    }
 
     dest.write_char(' ')?;
 
@@ -912,7 +2241,7 @@ impl<'i> ToCss for Font<'i> {
 
 property_bitflags! {
   #[derive(Default, Debug)]
-  struct FontProperty: u8 {
+  struct FontProperty: u16 {
     const FontFamily = 1 << 0;
     const FontSize = 1 << 1;
     const FontStyle = 1 << 2;
@@ -920,7 +2249,13 @@ property_bitflags! {
     const FontStretch = 1 << 4;
     const LineHeight = 1 << 5;
     const FontVariantCaps = 1 << 6;
+    const FontVariantLigatures = 1 << 7;
+    const FontVariantAlternates = 1 << 8;
+    const FontVariantNumeric = 1 << 9;
+    const FontVariantEastAsian = 1 << 10;
+    const FontVariantPosition = 1 << 11;
     const Font = Self::FontFamily.bits() | Self::FontSize.bits() | Self::FontStyle.bits() | Self::FontWeight.bits() | Self::FontStretch.bits() | Self::LineHeight.bits() | Self::FontVariantCaps.bits();
+    const FontVariant = Self::FontVariantLigatures.bits() | Self::FontVariantCaps.bits() | Self::FontVariantAlternates.bits() | Self::FontVariantNumeric.bits() | Self::FontVariantEastAsian.bits() | Self::FontVariantPosition.bits();
   }
 }
 
@@ -933,6 +2268,11 @@ pub(crate) struct FontHandler<'i> {
   stretch: Option<FontStretch>,
   line_height: Option<LineHeight>,
   variant_caps: Option<FontVariantCaps>,
+  ligatures: Option<FontVariantLigatures>,
+  alternates: Option<FontVariantAlternates<'i>>,
+  numeric: Option<FontVariantNumeric>,
+  east_asian: Option<FontVariantEastAsian>,
+  position: Option<FontVariantPosition>,
   flushed_properties: FontProperty,
   has_any: bool,
 }
@@ -970,6 +2310,11 @@ impl<'i> PropertyHandler<'i> for FontHandler<'i> {
       FontStretch(val) => property!(stretch, val),
       FontVariantCaps(val) => property!(variant_caps, val),
       LineHeight(val) => property!(line_height, val),
+      FontVariantLigatures(val) => property!(ligatures, val),
+      FontVariantAlternates(val) => property!(alternates, val),
+      FontVariantNumeric(val) => property!(numeric, val),
+      FontVariantEastAsian(val) => property!(east_asian, val),
+      FontVariantPosition(val) => property!(position, val),
       Font(val) => {
         flush!(family, &val.family);
         flush!(size, &val.size);
@@ -985,8 +2330,23 @@ impl<'i> PropertyHandler<'i> for FontHandler<'i> {
         self.stretch = Some(val.stretch.clone());
         self.line_height = Some(val.line_height.clone());
         self.variant_caps = Some(val.variant_caps.clone());
+        // The font shorthand resets the other font-variant longhands to their initial values.
+        self.ligatures = None;
+        self.alternates = None;
+        self.numeric = None;
+        self.east_asian = None;
+        self.position = None;
+        self.has_any = true;
+      }
+      FontVariant(val) => {
+        flush!(variant_caps, &val.caps);
+        self.variant_caps = Some(val.caps);
+        self.ligatures = Some(val.ligatures.clone());
+        self.alternates = Some(val.alternates.clone());
+        self.numeric = Some(val.numeric.clone());
+        self.east_asian = Some(val.east_asian.clone());
+        self.position = Some(val.position);
         self.has_any = true;
-        // TODO: reset other properties
       }
       Unparsed(val) if is_font_property(&val.property_id) => {
         self.flush(dest, context);
@@ -995,6 +2355,12 @@ impl<'i> PropertyHandler<'i> for FontHandler<'i> {
           .insert(FontProperty::try_from(&val.property_id).unwrap());
         dest.push(property.clone());
       }
+      _ if self.has_any && is_reset_by_font(&property.property_id()) => {
+        // The font shorthand resets these properties to their initial values,
+        // so flush what we have to preserve the order of declarations.
+        self.flush(dest, context);
+        return false;
+      }
       _ => return false,
     }
 
@@ -1031,7 +2397,12 @@ impl<'i> FontHandler<'i> {
     let weight = std::mem::take(&mut self.weight);
     let stretch = std::mem::take(&mut self.stretch);
     let line_height = std::mem::take(&mut self.line_height);
-    let variant_caps = std::mem::take(&mut self.variant_caps);
+    let mut variant_caps = std::mem::take(&mut self.variant_caps);
+    let mut ligatures = std::mem::take(&mut self.ligatures);
+    let mut alternates = std::mem::take(&mut self.alternates);
+    let mut numeric = std::mem::take(&mut self.numeric);
+    let mut east_asian = std::mem::take(&mut self.east_asian);
+    let mut position = std::mem::take(&mut self.position);
 
     if let Some(family) = &mut family {
       if family.len() > 1 {
@@ -1041,6 +2412,57 @@ impl<'i> FontHandler<'i> {
       }
     }
 
+    // Older browsers do not support the font-variant longhands, or their values in the font-variant
+    // shorthand, but the same OpenType features can be enabled with font-feature-settings. This overrides any font-feature-settings that
+    // would otherwise be inherited, so the fallback must be explicitly enabled.
+    let mut fallback = None;
+    if context.targets.include.contains(Features::FontVariant) {
+      if let Some(browsers) = context.targets.browsers {
+        let mut features = Vec::new();
+        macro_rules! fallback {
+          ($val: ident, $feature: ident) => {
+            if let Some(val) = &$val {
+              if !Feature::$feature.is_compatible(browsers) {
+                val.feature_settings(&mut features);
+              }
+            }
+          };
+        }
+
+        // CSS 2.1 values are supported by the font and font-variant shorthands in all browsers.
+        if matches!(variant_caps, Some(caps) if !caps.is_css2()) {
+          fallback!(variant_caps, FontVariantCaps);
+        }
+        fallback!(ligatures, FontVariantLigatures);
+        fallback!(alternates, FontVariantAlternates);
+        fallback!(numeric, FontVariantNumeric);
+        fallback!(east_asian, FontVariantEastAsian);
+        fallback!(position, FontVariantPosition);
+
+        if !features.is_empty() {
+          // Merge into the author's own font-feature-settings rather than overriding it.
+          match decls
+            .iter_mut()
+            .rev()
+            .find(|decl| decl.property_id() == PropertyId::FontFeatureSettings)
+          {
+            Some(Property::FontFeatureSettings(FontFeatureSettings::Features(existing))) => {
+              features.retain(|feature| !existing.iter().any(|e| e.tag == feature.tag));
+              existing.extend(features);
+            }
+            Some(Property::FontFeatureSettings(settings)) => {
+              *settings = FontFeatureSettings::Features(features);
+            }
+            // The author's value cannot be merged, e.g. if it contains variables.
+            Some(_) => {}
+            None => {
+              fallback = Some(Property::FontFeatureSettings(FontFeatureSettings::Features(features)));
+            }
+          }
+        }
+      }
+    }
+
     if family.is_some()
       && size.is_some()
       && style.is_some()
@@ -1067,11 +2489,33 @@ impl<'i> FontHandler<'i> {
         }
       );
 
+      if let Some(fallback) = fallback {
+        decls.push(fallback);
+      }
+
       // The `font` property only accepts CSS 2.1 values for font-variant caps.
       // If we have a CSS 3+ value, we need to add a separate property.
       if !caps.is_css2() {
         push!(FontVariantCaps, variant_caps.unwrap());
       }
+
+      // The other font-variant longhands are reset by the `font` property,
+      // so they only need to be added if they have a different value.
+      macro_rules! reset {
+        ($prop: ident, $val: ident) => {
+          if let Some(val) = $val {
+            if val != $prop::default() {
+              push!($prop, val);
+            }
+          }
+        };
+      }
+
+      reset!(FontVariantLigatures, ligatures);
+      reset!(FontVariantAlternates, alternates);
+      reset!(FontVariantNumeric, numeric);
+      reset!(FontVariantEastAsian, east_asian);
+      reset!(FontVariantPosition, position);
     } else {
       if let Some(val) = family {
         push!(FontFamily, val);
@@ -1085,6 +2529,30 @@ impl<'i> FontHandler<'i> {
         push!(FontStyle, val);
       }
 
+      if let Some(fallback) = fallback {
+        decls.push(fallback);
+      }
+
+      if variant_caps.is_some()
+        && ligatures.is_some()
+        && alternates.is_some()
+        && numeric.is_some()
+        && east_asian.is_some()
+        && position.is_some()
+      {
+        push!(
+          FontVariant,
+          FontVariant {
+            ligatures: ligatures.take().unwrap(),
+            caps: variant_caps.take().unwrap(),
+            alternates: alternates.take().unwrap(),
+            numeric: numeric.take().unwrap(),
+            east_asian: east_asian.take().unwrap(),
+            position: position.take().unwrap(),
+          }
+        );
+      }
+
       if let Some(val) = variant_caps {
         push!(FontVariantCaps, val);
       }
@@ -1100,10 +2568,210 @@ impl<'i> FontHandler<'i> {
       if let Some(val) = line_height {
         push!(LineHeight, val);
       }
+
+      if let Some(val) = ligatures {
+        push!(FontVariantLigatures, val);
+      }
+
+      if let Some(val) = alternates {
+        push!(FontVariantAlternates, val);
+      }
+
+      if let Some(val) = numeric {
+        push!(FontVariantNumeric, val);
+      }
+
+      if let Some(val) = east_asian {
+        push!(FontVariantEastAsian, val);
+      }
+
+      if let Some(val) = position {
+        push!(FontVariantPosition, val);
+      }
+    }
+  }
+}
+
+#[derive(Default)]
+pub(crate) struct FontSynthesisHandler {
+  weight: Option<FontSynthesisKeyword>,
+  style: Option<FontSynthesisKeyword>,
+  small_caps: Option<FontSynthesisKeyword>,
+  position: Option<FontSynthesisKeyword>,
+  has_shorthand: bool,
+  has_any: bool,
+}
+
+impl<'i> PropertyHandler<'i> for FontSynthesisHandler {
+  fn handle_property(
+    &mut self,
+    property: &Property<'i>,
+    dest: &mut DeclarationList<'i>,
+    context: &mut PropertyHandlerContext<'i, '_>,
+  ) -> bool {
+    use Property::*;
+
+    match property {
+      FontSynthesisWeight(val) => {
+        self.weight = Some(*val);
+        self.has_shorthand = false;
+      }
+      FontSynthesisStyle(val) => {
+        self.style = Some(*val);
+        self.has_shorthand = false;
+      }
+      FontSynthesisSmallCaps(val) => {
+        self.small_caps = Some(*val);
+        self.has_shorthand = false;
+      }
+      FontSynthesisPosition(val) => {
+        self.position = Some(*val);
+        self.has_shorthand = false;
+      }
+      FontSynthesis(val) => {
+        self.weight = Some(val.weight);
+        self.style = Some(val.style);
+        self.small_caps = Some(val.small_caps);
+        self.position = Some(val.position);
+        self.has_shorthand = true;
+      }
+      Unparsed(val) if is_font_synthesis_property(&val.property_id) => {
+        self.finalize(dest, context);
+        dest.push(property.clone());
+        return true;
+      }
+      _ => return false,
+    }
+
+    self.has_any = true;
+    true
+  }
+
+  fn finalize(&mut self, dest: &mut DeclarationList<'i>, context: &mut PropertyHandlerContext<'i, '_>) {
+    if !self.has_any {
+      return;
+    }
+
+    self.has_any = false;
+
+    let weight = std::mem::take(&mut self.weight);
+    let style = std::mem::take(&mut self.style);
+    let small_caps = std::mem::take(&mut self.small_caps);
+    let position = std::mem::take(&mut self.position);
+    let has_shorthand = std::mem::take(&mut self.has_shorthand);
+
+    if !context.targets.is_compatible(Feature::FontSynthesisLonghands) {
+      // Browsers that only support the shorthand ignore the longhands, so convert them
+      // to the shorthand. Longhands that were not declared are reset to their initial value,
+      // and keywords these browsers do not support are omitted so the declaration is not dropped.
+      let keyword = |value: Option<FontSynthesisKeyword>, feature: Feature| {
+        if context.targets.is_compatible(feature) {
+          value.unwrap_or_default()
+        } else {
+          FontSynthesisKeyword::None
+        }
+      };
+
+      dest.push(Property::FontSynthesis(FontSynthesis {
+        weight: weight.unwrap_or_default(),
+        style: style.unwrap_or_default(),
+        small_caps: keyword(small_caps, Feature::FontSynthesisSmallCaps),
+        position: keyword(position, Feature::FontSynthesisPosition),
+      }));
+      return;
+    }
+
+    if let (Some(weight), Some(style), Some(small_caps), Some(position)) = (weight, style, small_caps, position) {
+      // Only merge into the shorthand if every keyword it would include is supported.
+      // Browsers drop the whole declaration when they see an unknown keyword.
+      let is_supported = |value: FontSynthesisKeyword, feature: Feature| {
+        value == FontSynthesisKeyword::None || context.targets.is_compatible(feature)
+      };
+
+      if has_shorthand
+        || (is_supported(small_caps, Feature::FontSynthesisSmallCaps)
+          && is_supported(position, Feature::FontSynthesisPosition))
+      {
+        dest.push(Property::FontSynthesis(FontSynthesis {
+          weight,
+          style,
+          small_caps,
+          position,
+        }));
+        return;
+      }
+    }
+
+    if let Some(val) = weight {
+      dest.push(Property::FontSynthesisWeight(val));
+    }
+
+    if let Some(val) = style {
+      dest.push(Property::FontSynthesisStyle(val));
+    }
+
+    if let Some(val) = small_caps {
+      dest.push(Property::FontSynthesisSmallCaps(val));
+    }
+
+    if let Some(val) = position {
+      dest.push(Property::FontSynthesisPosition(val));
+    }
+  }
+}
+
+#[derive(Default)]
+pub(crate) struct FontSizeAdjustHandler {
+  value: Option<FontSizeAdjust>,
+}
+
+impl<'i> PropertyHandler<'i> for FontSizeAdjustHandler {
+  fn handle_property(
+    &mut self,
+    property: &Property<'i>,
+    dest: &mut DeclarationList<'i>,
+    context: &mut PropertyHandlerContext<'i, '_>,
+  ) -> bool {
+    match property {
+      Property::FontSizeAdjust(val) => {
+        // Keep the previous declaration as a fallback if some targets do not support the new syntax.
+        if self.value.is_some()
+          && self.value.as_ref() != Some(val)
+          && matches!(context.targets.browsers, Some(targets) if !val.is_compatible(targets))
+        {
+          self.finalize(dest, context);
+        }
+        self.value = Some(val.clone());
+      }
+      Property::Unparsed(val) if val.property_id == PropertyId::FontSizeAdjust => {
+        self.finalize(dest, context);
+        dest.push(property.clone());
+      }
+      _ => return false,
+    }
+
+    true
+  }
+
+  fn finalize(&mut self, dest: &mut DeclarationList<'i>, _: &mut PropertyHandlerContext<'i, '_>) {
+    if let Some(val) = std::mem::take(&mut self.value) {
+      dest.push(Property::FontSizeAdjust(val));
     }
   }
 }
 
+#[inline]
+fn is_font_synthesis_property(property_id: &PropertyId) -> bool {
+  matches!(
+    property_id,
+    PropertyId::FontSynthesisWeight
+      | PropertyId::FontSynthesisStyle
+      | PropertyId::FontSynthesisSmallCaps
+      | PropertyId::FontSynthesisPosition
+      | PropertyId::FontSynthesis
+  )
+}
+
 const SYSTEM_UI: FontFamily = FontFamily::Generic(GenericFontFamily::SystemUI);
 
 const DEFAULT_SYSTEM_FONTS: &[&str] = &[
@@ -1142,6 +2810,21 @@ fn compatible_font_family(mut family: Option<Vec<FontFamily>>, is_supported: boo
   return family;
 }
 
+/// Returns whether the property is reset by the font shorthand, but not handled by the `FontHandler`.
+#[inline]
+fn is_reset_by_font(property_id: &PropertyId) -> bool {
+  matches!(
+    property_id.name(),
+    "font-variant-emoji"
+      | "font-size-adjust"
+      | "font-kerning"
+      | "font-language-override"
+      | "font-feature-settings"
+      | "font-optical-sizing"
+      | "font-variation-settings"
+  )
+}
+
 #[inline]
 fn is_font_property(property_id: &PropertyId) -> bool {
   match property_id {
@@ -1151,6 +2834,12 @@ fn is_font_property(property_id: &PropertyId) -> bool {
     | PropertyId::FontWeight
     | PropertyId::FontStretch
     | PropertyId::FontVariantCaps
+    | PropertyId::FontVariantLigatures
+    | PropertyId::FontVariantAlternates
+    | PropertyId::FontVariantNumeric
+    | PropertyId::FontVariantEastAsian
+    | PropertyId::FontVariantPosition
+    | PropertyId::FontVariant
     | PropertyId::LineHeight
     | PropertyId::Font => true,
     _ => false,
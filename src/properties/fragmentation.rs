@@ -0,0 +1,363 @@
+//! CSS properties related to fragmentation.
+
+use super::{Property, PropertyId};
+use crate::compat::Feature;
+use crate::context::PropertyHandlerContext;
+use crate::declaration::DeclarationList;
+use crate::error::{ParserError, PrinterError};
+use crate::macros::enum_property;
+use crate::printer::Printer;
+use crate::traits::{Parse, PropertyHandler, ToCss};
+use crate::values::ident::CustomIdent;
+use crate::values::number::CSSInteger;
+#[cfg(feature = "visitor")]
+use crate::visitor::Visit;
+use cssparser::*;
+
+enum_property! {
+  /// A value for the [break-before](https://www.w3.org/TR/css-break-3/#break-between) and
+  /// [break-after](https://www.w3.org/TR/css-break-3/#break-between) properties.
+  #[derive(Default)]
+  pub enum BreakBetween {
+    /// Neither forces nor forbids a break.
+    #[default]
+    "auto": Auto,
+    /// Avoids a break.
+    "avoid": Avoid,
+    /// Forces a break through the innermost fragmentation context.
+    "always": Always,
+    /// Forces a break through all fragmentation contexts.
+    "all": All,
+    /// Avoids a page break.
+    "avoid-page": AvoidPage,
+    /// Forces a page break.
+    "page": Page,
+    /// Forces one or two page breaks so that the next page is a left page.
+    "left": Left,
+    /// Forces one or two page breaks so that the next page is a right page.
+    "right": Right,
+    /// Forces one or two page breaks so that the next page is a recto page.
+    "recto": Recto,
+    /// Forces one or two page breaks so that the next page is a verso page.
+    "verso": Verso,
+    /// Avoids a column break.
+    "avoid-column": AvoidColumn,
+    /// Forces a column break.
+    "column": Column,
+    /// Avoids a region break.
+    "avoid-region": AvoidRegion,
+    /// Forces a region break.
+    "region": Region,
+  }
+}
+
+impl From<PageBreakBetween> for BreakBetween {
+  fn from(value: PageBreakBetween) -> BreakBetween {
+    match value {
+      PageBreakBetween::Auto => BreakBetween::Auto,
+      PageBreakBetween::Always => BreakBetween::Page,
+      PageBreakBetween::Avoid => BreakBetween::Avoid,
+      PageBreakBetween::Left => BreakBetween::Left,
+      PageBreakBetween::Right => BreakBetween::Right,
+    }
+  }
+}
+
+impl ToLegacyBreak for BreakBetween {
+  type Legacy = PageBreakBetween;
+
+  fn to_legacy(self) -> Option<PageBreakBetween> {
+    match self {
+      BreakBetween::Auto => Some(PageBreakBetween::Auto),
+      BreakBetween::Page => Some(PageBreakBetween::Always),
+      BreakBetween::Avoid => Some(PageBreakBetween::Avoid),
+      BreakBetween::Left => Some(PageBreakBetween::Left),
+      BreakBetween::Right => Some(PageBreakBetween::Right),
+      _ => None,
+    }
+  }
+}
+
+enum_property! {
+  /// A value for the [break-inside](https://www.w3.org/TR/css-break-3/#break-within) property.
+  #[derive(Default)]
+  pub enum BreakInside {
+    /// Neither forces nor forbids a break within the box.
+    #[default]
+    "auto": Auto,
+    /// Avoids a break within the box.
+    "avoid": Avoid,
+    /// Avoids a page break within the box.
+    "avoid-page": AvoidPage,
+    /// Avoids a column break within the box.
+    "avoid-column": AvoidColumn,
+    /// Avoids a region break within the box.
+    "avoid-region": AvoidRegion,
+  }
+}
+
+impl From<PageBreakInside> for BreakInside {
+  fn from(value: PageBreakInside) -> BreakInside {
+    match value {
+      PageBreakInside::Auto => BreakInside::Auto,
+      PageBreakInside::Avoid => BreakInside::Avoid,
+    }
+  }
+}
+
+impl ToLegacyBreak for BreakInside {
+  type Legacy = PageBreakInside;
+
+  fn to_legacy(self) -> Option<PageBreakInside> {
+    match self {
+      BreakInside::Auto => Some(PageBreakInside::Auto),
+      BreakInside::Avoid => Some(PageBreakInside::Avoid),
+      _ => None,
+    }
+  }
+}
+
+enum_property! {
+  /// A value for the legacy [page-break-before](https://www.w3.org/TR/css-break-3/#page-break-properties) and
+  /// [page-break-after](https://www.w3.org/TR/css-break-3/#page-break-properties) properties.
+  pub enum PageBreakBetween {
+    /// Neither forces nor forbids a page break.
+    Auto,
+    /// Forces a page break.
+    Always,
+    /// Avoids a page break.
+    Avoid,
+    /// Forces one or two page breaks so that the next page is a left page.
+    Left,
+    /// Forces one or two page breaks so that the next page is a right page.
+    Right,
+  }
+}
+
+enum_property! {
+  /// A value for the legacy [page-break-inside](https://www.w3.org/TR/css-break-3/#page-break-properties) property.
+  pub enum PageBreakInside {
+    /// Neither forces nor forbids a page break within the box.
+    Auto,
+    /// Avoids a page break within the box.
+    Avoid,
+  }
+}
+
+/// A value for the [page](https://www.w3.org/TR/css-page-3/#using-named-pages) property.
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "type", content = "value", rename_all = "kebab-case")
+)]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+pub enum Page<'i> {
+  /// The box uses the same page type as its parent.
+  #[default]
+  Auto,
+  /// The box is placed on a page of the given named type.
+  #[cfg_attr(feature = "serde", serde(borrow))]
+  Name(CustomIdent<'i>),
+}
+
+impl<'i> Parse<'i> for Page<'i> {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    if input.try_parse(|input| input.expect_ident_matching("auto")).is_ok() {
+      return Ok(Page::Auto);
+    }
+
+    Ok(Page::Name(CustomIdent::parse(input)?))
+  }
+}
+
+impl<'i> ToCss for Page<'i> {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    match self {
+      Page::Auto => dest.write_str("auto"),
+      Page::Name(name) => name.to_css(dest),
+    }
+  }
+}
+
+/// A value for the [orphans](https://www.w3.org/TR/css-break-3/#widows-orphans) and
+/// [widows](https://www.w3.org/TR/css-break-3/#widows-orphans) properties.
+///
+/// The value is the minimum number of lines that must be kept at the bottom or top
+/// of a fragment, and must be greater than zero.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(transparent))]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+pub struct LineCount(pub CSSInteger);
+
+impl Default for LineCount {
+  fn default() -> LineCount {
+    LineCount(2)
+  }
+}
+
+impl<'i> Parse<'i> for LineCount {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    let location = input.current_source_location();
+    let count = CSSInteger::parse(input)?;
+    if count < 1 {
+      return Err(location.new_custom_error(ParserError::InvalidValue));
+    }
+    Ok(LineCount(count))
+  }
+}
+
+impl ToCss for LineCount {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    self.0.to_css(dest)
+  }
+}
+
+/// A break value that may be converted to a legacy page-break-* value.
+trait ToLegacyBreak: Copy + PartialEq {
+  type Legacy;
+
+  fn to_legacy(self) -> Option<Self::Legacy>;
+}
+
+/// A break value along with the property names it was declared with.
+struct BreakValue<T> {
+  value: T,
+  legacy: bool,
+  modern: bool,
+  /// An earlier value to output as a legacy fallback when `value` has no legacy form,
+  /// along with whether it was declared with a legacy property name.
+  fallback: Option<(T, bool)>,
+}
+
+impl<T: ToLegacyBreak> BreakValue<T> {
+  fn update(slot: &mut Option<BreakValue<T>>, value: T, legacy: bool) {
+    let mut fallback = None;
+    if let Some(cur) = slot {
+      // If the value is the same, the declarations are aliases of each other, e.g.
+      // `page-break-before: always; break-before: page`. Keep track of both names.
+      if cur.value == value {
+        cur.legacy |= legacy;
+        cur.modern |= !legacy;
+        return;
+      }
+
+      // Values without a legacy form, e.g. `break-before: column`, do not override
+      // an earlier page-break-* value in browsers that only support the legacy properties.
+      if value.to_legacy().is_none() {
+        fallback = if cur.value.to_legacy().is_some() {
+          Some((cur.value, cur.legacy))
+        } else {
+          cur.fallback
+        };
+      }
+    }
+
+    *slot = Some(BreakValue {
+      value,
+      legacy,
+      modern: !legacy,
+      fallback,
+    });
+  }
+}
+
+#[derive(Default)]
+pub(crate) struct BreakHandler {
+  before: Option<BreakValue<BreakBetween>>,
+  after: Option<BreakValue<BreakBetween>>,
+  inside: Option<BreakValue<BreakInside>>,
+}
+
+impl<'i> PropertyHandler<'i> for BreakHandler {
+  fn handle_property(
+    &mut self,
+    property: &Property<'i>,
+    dest: &mut DeclarationList<'i>,
+    context: &mut PropertyHandlerContext<'i, '_>,
+  ) -> bool {
+    match property {
+      Property::BreakBefore(val) => BreakValue::update(&mut self.before, *val, false),
+      Property::BreakAfter(val) => BreakValue::update(&mut self.after, *val, false),
+      Property::BreakInside(val) => BreakValue::update(&mut self.inside, *val, false),
+      Property::PageBreakBefore(val) => BreakValue::update(&mut self.before, (*val).into(), true),
+      Property::PageBreakAfter(val) => BreakValue::update(&mut self.after, (*val).into(), true),
+      Property::PageBreakInside(val) => BreakValue::update(&mut self.inside, (*val).into(), true),
+      Property::Unparsed(val) if is_break_property(&val.property_id) => {
+        self.finalize(dest, context);
+        dest.push(property.clone());
+      }
+      _ => return false,
+    }
+
+    true
+  }
+
+  fn finalize(&mut self, dest: &mut DeclarationList<'i>, context: &mut PropertyHandlerContext<'i, '_>) {
+    // When targets are given, output the legacy page-break-* names only for browsers
+    // that do not support the break-* properties, and the modern names otherwise.
+    let is_compatible = context
+      .targets
+      .browsers
+      .map(|_| context.targets.is_compatible(Feature::BreakProperties));
+
+    macro_rules! flush {
+      ($key: ident, $prop: ident, $legacy_prop: ident) => {
+        if let Some(BreakValue {
+          value,
+          legacy,
+          modern,
+          fallback,
+        }) = std::mem::take(&mut self.$key)
+        {
+          let legacy_value = value.to_legacy();
+          let (legacy, modern) = match is_compatible {
+            Some(is_compatible) => (!is_compatible && legacy_value.is_some(), true),
+            None => (legacy && legacy_value.is_some(), modern || legacy_value.is_none()),
+          };
+
+          if let Some((fallback, declared_legacy)) = fallback {
+            if is_compatible.map_or(declared_legacy, |is_compatible| !is_compatible) {
+              dest.push(Property::$legacy_prop(fallback.to_legacy().unwrap()));
+            }
+          }
+
+          if legacy {
+            dest.push(Property::$legacy_prop(legacy_value.unwrap()));
+          }
+
+          if modern {
+            dest.push(Property::$prop(value));
+          }
+        }
+      };
+    }
+
+    flush!(before, BreakBefore, PageBreakBefore);
+    flush!(after, BreakAfter, PageBreakAfter);
+    flush!(inside, BreakInside, PageBreakInside);
+  }
+}
+
+#[inline]
+fn is_break_property(property_id: &PropertyId) -> bool {
+  matches!(
+    property_id,
+    PropertyId::BreakBefore
+      | PropertyId::BreakAfter
+      | PropertyId::BreakInside
+      | PropertyId::PageBreakBefore
+      | PropertyId::PageBreakAfter
+      | PropertyId::PageBreakInside
+  )
+}
@@ -39,6 +39,12 @@ pub enum TrackSizing<'i> {
   /// A list of grid tracks.
   #[cfg_attr(feature = "serde", serde(borrow))]
   TrackList(TrackList<'i>),
+  /// The `subgrid` keyword, which adopts the tracks of the parent grid.
+  Subgrid {
+    /// A list of line names to apply to the adopted grid lines.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    line_names: Vec<CustomIdentList<'i>>,
+  },
 }
 
 /// A [`<track-list>`](https://drafts.csswg.org/css-grid-2/#typedef-track-list) value,
@@ -402,13 +408,18 @@ fn parse_line_names<'i, 't>(
   input.expect_square_bracket_block()?;
   input.parse_nested_block(|input| {
     let mut values = SmallVec::new();
-    while let Ok(ident) = input.try_parse(CustomIdent::parse) {
+    while let Ok(ident) = input.try_parse(parse_line_name) {
       values.push(ident)
     }
     Ok(values)
   })
 }
 
+/// Parses a grid line name, which may not be `span` or `auto`.
+fn parse_line_name<'i>(input: &mut Parser<'i, '_>) -> Result<CustomIdent<'i>, ParseError<'i, ParserError<'i>>> {
+  CustomIdent::parse_except(input, &["span", "auto"])
+}
+
 fn serialize_line_names<W>(names: &[CustomIdent], dest: &mut Printer<W>) -> Result<(), PrinterError>
 where
   W: std::fmt::Write,
@@ -522,6 +533,14 @@ impl<'i> Parse<'i> for TrackSizing<'i> {
       return Ok(TrackSizing::None);
     }
 
+    if input.try_parse(|input| input.expect_ident_matching("subgrid")).is_ok() {
+      let mut line_names = Vec::new();
+      while let Ok(names) = input.try_parse(parse_line_names) {
+        line_names.push(names);
+      }
+      return Ok(TrackSizing::Subgrid { line_names });
+    }
+
     let track_list = TrackList::parse(input)?;
     Ok(TrackSizing::TrackList(track_list))
   }
@@ -535,6 +554,16 @@ impl<'i> ToCss for TrackSizing<'i> {
     match self {
       TrackSizing::None => dest.write_str("none"),
       TrackSizing::TrackList(list) => list.to_css(dest),
+      TrackSizing::Subgrid { line_names } => {
+        dest.write_str("subgrid")?;
+        for names in line_names {
+          if !dest.minify {
+            dest.write_char(' ')?;
+          }
+          serialize_line_names(names, dest)?;
+        }
+        Ok(())
+      }
     }
   }
 }
@@ -544,6 +573,7 @@ impl<'i> TrackSizing<'i> {
     match self {
       TrackSizing::None => true,
       TrackSizing::TrackList(list) => list.is_explicit(),
+      TrackSizing::Subgrid { .. } => false,
     }
   }
 }
@@ -1382,45 +1412,48 @@ impl<'i> Parse<'i> for GridLine<'i> {
       return Ok(GridLine::Auto);
     }
 
-    if input.try_parse(|input| input.expect_ident_matching("span")).is_ok() {
-      // TODO: is calc() supported here??
-      let (index, name) = if let Ok(line_number) = input.try_parse(CSSInteger::parse) {
-        let ident = input.try_parse(CustomIdent::parse).ok();
-        (line_number, ident)
-      } else if let Ok(ident) = input.try_parse(CustomIdent::parse) {
-        let line_number = input.try_parse(CSSInteger::parse).unwrap_or(1);
-        (line_number, Some(ident))
-      } else {
-        return Err(input.new_custom_error(ParserError::InvalidDeclaration));
-      };
-
-      if index == 0 {
-        return Err(input.new_custom_error(ParserError::InvalidDeclaration));
+    // The span keyword, integer, and name may appear in any order, e.g. `2 span` or `span name 2`.
+    // TODO: is calc() supported here??
+    let mut span = false;
+    let mut index = None;
+    let mut name = None;
+    loop {
+      if !span && input.try_parse(|input| input.expect_ident_matching("span")).is_ok() {
+        span = true;
+        continue;
       }
 
-      return Ok(GridLine::Span { index, name });
-    }
+      if index.is_none() {
+        if let Ok(value) = input.try_parse(CSSInteger::parse) {
+          index = Some(value);
+          continue;
+        }
+      }
 
-    if let Ok(index) = input.try_parse(CSSInteger::parse) {
-      if index == 0 {
-        return Err(input.new_custom_error(ParserError::InvalidDeclaration));
+      if name.is_none() {
+        if let Ok(value) = input.try_parse(parse_line_name) {
+          name = Some(value);
+          continue;
+        }
       }
-      let name = input.try_parse(CustomIdent::parse).ok();
-      return Ok(GridLine::Line { index, name });
+
+      break;
     }
 
-    let name = CustomIdent::parse(input)?;
-    if let Ok(index) = input.try_parse(CSSInteger::parse) {
-      if index == 0 {
-        return Err(input.new_custom_error(ParserError::InvalidDeclaration));
-      }
-      return Ok(GridLine::Line {
-        index,
-        name: Some(name),
-      });
+    if index == Some(0) {
+      return Err(input.new_custom_error(ParserError::InvalidDeclaration));
     }
 
-    Ok(GridLine::Area { name })
+    match (span, index, name) {
+      (true, None, None) => Err(input.new_custom_error(ParserError::InvalidDeclaration)),
+      (true, index, name) => Ok(GridLine::Span {
+        index: index.unwrap_or(1),
+        name,
+      }),
+      (false, Some(index), name) => Ok(GridLine::Line { index, name }),
+      (false, None, Some(name)) => Ok(GridLine::Area { name }),
+      (false, None, None) => Err(input.new_error_for_next_token()),
+    }
   }
 }
 
@@ -0,0 +1,149 @@
+//! CSS properties related to inline layout.
+
+use crate::error::{ParserError, PrinterError};
+use crate::macros::enum_property;
+use crate::printer::Printer;
+use crate::traits::{Parse, ToCss};
+use crate::values::number::{CSSInteger, CSSNumber};
+#[cfg(feature = "visitor")]
+use crate::visitor::Visit;
+use cssparser::*;
+
+/// A value for the [initial-letter](https://www.w3.org/TR/css-inline-3/#sizing-drop-initials) property.
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "type", rename_all = "kebab-case")
+)]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+pub enum InitialLetter {
+  /// No special initial-letter effect.
+  #[default]
+  Normal,
+  /// The first letter is sized and sunk by the given number of lines.
+  Size {
+    /// The size of the initial letter, in number of lines.
+    size: CSSNumber,
+    /// The number of lines the initial letter should sink. Defaults to the size, rounded down.
+    sink: Option<CSSInteger>,
+  },
+}
+
+impl<'i> Parse<'i> for InitialLetter {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    if input.try_parse(|input| input.expect_ident_matching("normal")).is_ok() {
+      return Ok(InitialLetter::Normal);
+    }
+
+    let size = CSSNumber::parse(input)?;
+    if size < 1.0 {
+      return Err(input.new_custom_error(ParserError::InvalidValue));
+    }
+
+    let sink = input.try_parse(CSSInteger::parse).ok();
+    if matches!(sink, Some(sink) if sink < 1) {
+      return Err(input.new_custom_error(ParserError::InvalidValue));
+    }
+
+    Ok(InitialLetter::Size { size, sink })
+  }
+}
+
+impl ToCss for InitialLetter {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    match self {
+      InitialLetter::Normal => dest.write_str("normal"),
+      InitialLetter::Size { size, sink } => {
+        size.to_css(dest)?;
+        if let Some(sink) = sink {
+          // The sink may be omitted when it is the same as the default.
+          if *sink != size.floor() as CSSInteger {
+            dest.write_char(' ')?;
+            sink.to_css(dest)?;
+          }
+        }
+        Ok(())
+      }
+    }
+  }
+}
+
+enum_property! {
+  /// An alignment keyword for the [initial-letter-align](https://www.w3.org/TR/css-inline-3/#aligning-initial-letter) property.
+  ///
+  /// See [InitialLetterAlign](InitialLetterAlign).
+  pub enum InitialLetterAlignment {
+    /// Use the alphabetic and cap-height baselines of the surrounding text.
+    Alphabetic,
+    /// Use the ideographic character face bottom and top edge baselines of the surrounding text.
+    Ideographic,
+    /// Use the hanging and alphabetic baselines of the surrounding text.
+    Hanging,
+    /// Use the line-over and line-under edges of the surrounding text.
+    Leading,
+  }
+}
+
+/// A value for the [initial-letter-align](https://www.w3.org/TR/css-inline-3/#aligning-initial-letter) property.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(rename_all = "camelCase")
+)]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+pub struct InitialLetterAlign {
+  /// Whether to use the border box of the initial letter for alignment, rather than its glyph outline.
+  pub border_box: bool,
+  /// The alignment baselines to use.
+  pub alignment: Option<InitialLetterAlignment>,
+}
+
+impl Default for InitialLetterAlign {
+  fn default() -> InitialLetterAlign {
+    InitialLetterAlign {
+      border_box: false,
+      alignment: Some(InitialLetterAlignment::Alphabetic),
+    }
+  }
+}
+
+impl<'i> Parse<'i> for InitialLetterAlign {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    let border_box = input.try_parse(|input| input.expect_ident_matching("border-box")).is_ok();
+    let alignment = input.try_parse(InitialLetterAlignment::parse).ok();
+    if !border_box && alignment.is_none() {
+      return Err(input.new_custom_error(ParserError::InvalidDeclaration));
+    }
+
+    Ok(InitialLetterAlign { border_box, alignment })
+  }
+}
+
+impl ToCss for InitialLetterAlign {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    if self.border_box {
+      dest.write_str("border-box")?;
+    }
+
+    if let Some(alignment) = &self.alignment {
+      if self.border_box {
+        dest.write_char(' ')?;
+      }
+      alignment.to_css(dest)?;
+    }
+
+    Ok(())
+  }
+}
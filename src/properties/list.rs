@@ -4,10 +4,11 @@ use super::{Property, PropertyId};
 use crate::context::PropertyHandlerContext;
 use crate::declaration::{DeclarationBlock, DeclarationList};
 use crate::error::{ParserError, PrinterError};
-use crate::macros::{define_shorthand, enum_property, shorthand_handler, shorthand_property};
+use crate::macros::{define_shorthand, enum_property, shorthand_handler};
 use crate::printer::Printer;
 use crate::targets::{Browsers, Targets};
 use crate::traits::{FallbackValues, IsCompatible, Parse, PropertyHandler, Shorthand, ToCss};
+use crate::values::number::CSSInteger;
 use crate::values::string::CSSString;
 use crate::values::{ident::CustomIdent, image::Image};
 #[cfg(feature = "visitor")]
@@ -380,7 +381,7 @@ enum_property! {
   }
 }
 
-shorthand_property! {
+define_shorthand! {
   /// A value for the [list-style](https://www.w3.org/TR/2020/WD-css-lists-3-20201117/#list-style-property) shorthand property.
   pub struct ListStyle<'i> {
     /// The list style type.
@@ -393,6 +394,119 @@ shorthand_property! {
   }
 }
 
+impl<'i> Parse<'i> for ListStyle<'i> {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    let location = input.current_source_location();
+    let mut nones = 0;
+    let mut list_style_type = None;
+    let mut image = None;
+    let mut position = None;
+
+    loop {
+      // `none` is valid for both list-style-type and list-style-image, so it is resolved after
+      // the other components are known.
+      if input.try_parse(|input| input.expect_ident_matching("none")).is_ok() {
+        nones += 1;
+        continue;
+      }
+
+      // Parse the position first so that `inside` and `outside` are not treated as counter style names.
+      if position.is_none() {
+        if let Ok(value) = input.try_parse(ListStylePosition::parse) {
+          position = Some(value);
+          continue;
+        }
+      }
+
+      if list_style_type.is_none() {
+        if let Ok(value) = input.try_parse(ListStyleType::parse) {
+          list_style_type = Some(value);
+          continue;
+        }
+      }
+
+      if image.is_none() {
+        if let Ok(value) = input.try_parse(Image::parse) {
+          image = Some(value);
+          continue;
+        }
+      }
+
+      break;
+    }
+
+    // A `none` value applies to whichever of list-style-type and list-style-image is not otherwise set.
+    // https://www.w3.org/TR/css-lists-3/#list-style-property
+    match (nones, &list_style_type, &image) {
+      (0, _, _) => {}
+      (1, None, _) => list_style_type = Some(ListStyleType::None),
+      (1, Some(_), None) => image = Some(Image::None),
+      (2, None, None) => {
+        list_style_type = Some(ListStyleType::None);
+        image = Some(Image::None);
+      }
+      _ => return Err(location.new_custom_error(ParserError::InvalidValue)),
+    }
+
+    Ok(ListStyle {
+      list_style_type: list_style_type.unwrap_or_default(),
+      image: image.unwrap_or_default(),
+      position: position.unwrap_or_default(),
+    })
+  }
+}
+
+impl<'i> ToCss for ListStyle<'i> {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    // A counter style named `inside` or `outside` would be parsed as the position,
+    // so the position must be written explicitly before it.
+    let position_first = matches!(
+      &self.list_style_type,
+      ListStyleType::CounterStyle(CounterStyle::Name(name))
+        if name.0.eq_ignore_ascii_case("inside") || name.0.eq_ignore_ascii_case("outside")
+    );
+
+    let mut needs_space = false;
+    if position_first {
+      self.position.to_css(dest)?;
+      needs_space = true;
+    }
+
+    if self.list_style_type != ListStyleType::default() {
+      if needs_space {
+        dest.write_char(' ')?;
+      }
+      self.list_style_type.to_css(dest)?;
+      needs_space = true;
+    }
+
+    // When the type is `none`, a `none` image is implied by the single `none` keyword.
+    if self.image != Image::default() {
+      if needs_space {
+        dest.write_char(' ')?;
+      }
+      self.image.to_css(dest)?;
+      needs_space = true;
+    }
+
+    if !position_first && self.position != ListStylePosition::default() {
+      if needs_space {
+        dest.write_char(' ')?;
+      }
+      self.position.to_css(dest)?;
+      needs_space = true;
+    }
+
+    if !needs_space {
+      self.list_style_type.to_css(dest)?;
+    }
+    Ok(())
+  }
+}
+
 impl<'i> FallbackValues for ListStyle<'i> {
   fn get_fallbacks(&mut self, targets: Targets) -> Vec<Self> {
     self
@@ -409,3 +523,290 @@ shorthand_handler!(ListStyleHandler -> ListStyle<'i> fallbacks: true {
   list_style_type: ListStyleType(ListStyleType<'i>),
   position: ListStylePosition(ListStylePosition),
 });
+
+/// Parses a [`<counter-name>`](https://www.w3.org/TR/css-lists-3/#typedef-counter-name),
+/// which is any custom identifier other than `none`.
+pub(crate) fn parse_counter_name<'i>(
+  input: &mut Parser<'i, '_>,
+) -> Result<CustomIdent<'i>, ParseError<'i, ParserError<'i>>> {
+  let location = input.current_source_location();
+  if input.try_parse(|input| input.expect_ident_matching("none")).is_ok() {
+    return Err(location.new_custom_error(ParserError::InvalidValue));
+  }
+  CustomIdent::parse(input)
+}
+
+/// A counter name and integer value, as used in the `counter-increment` and `counter-set` properties.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(rename_all = "camelCase")
+)]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+pub struct CounterValue<'i> {
+  /// The name of the counter.
+  #[cfg_attr(feature = "serde", serde(borrow))]
+  pub name: CustomIdent<'i>,
+  /// The integer value.
+  pub value: CSSInteger,
+}
+
+fn parse_counter_values<'i>(
+  input: &mut Parser<'i, '_>,
+  default: CSSInteger,
+) -> Result<Vec<CounterValue<'i>>, ParseError<'i, ParserError<'i>>> {
+  let mut counters = Vec::new();
+  loop {
+    let name = if counters.is_empty() {
+      parse_counter_name(input)?
+    } else if let Ok(name) = input.try_parse(parse_counter_name) {
+      name
+    } else {
+      break;
+    };
+    let value = input.try_parse(CSSInteger::parse).unwrap_or(default);
+    counters.push(CounterValue { name, value });
+  }
+  Ok(counters)
+}
+
+fn counter_values_to_css<W>(
+  counters: &[CounterValue],
+  default: CSSInteger,
+  dest: &mut Printer<W>,
+) -> Result<(), PrinterError>
+where
+  W: std::fmt::Write,
+{
+  let mut first = true;
+  for counter in counters {
+    if first {
+      first = false;
+    } else {
+      dest.write_char(' ')?;
+    }
+    counter.name.to_css(dest)?;
+    if counter.value != default {
+      dest.write_char(' ')?;
+      counter.value.to_css(dest)?;
+    }
+  }
+  Ok(())
+}
+
+/// A value for the [counter-increment](https://www.w3.org/TR/css-lists-3/#propdef-counter-increment) property.
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "type", content = "value", rename_all = "kebab-case")
+)]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+pub enum CounterIncrement<'i> {
+  /// No counters are incremented.
+  #[default]
+  None,
+  /// A list of counters to increment. Each counter appears at most once.
+  #[cfg_attr(feature = "serde", serde(borrow))]
+  Counters(Vec<CounterValue<'i>>),
+}
+
+impl<'i> Parse<'i> for CounterIncrement<'i> {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    if input.try_parse(|input| input.expect_ident_matching("none")).is_ok() {
+      return Ok(CounterIncrement::None);
+    }
+
+    // A counter that is named more than once is incremented by each value, so the values can be summed.
+    let mut counters: Vec<CounterValue<'i>> = Vec::new();
+    for counter in parse_counter_values(input, 1)? {
+      if let Some(existing) = counters.iter_mut().find(|c| c.name == counter.name) {
+        existing.value = existing.value.saturating_add(counter.value);
+      } else {
+        counters.push(counter);
+      }
+    }
+    Ok(CounterIncrement::Counters(counters))
+  }
+}
+
+impl ToCss for CounterIncrement<'_> {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    match self {
+      CounterIncrement::None => dest.write_str("none"),
+      CounterIncrement::Counters(counters) => counter_values_to_css(counters, 1, dest),
+    }
+  }
+}
+
+/// A value for the [counter-set](https://www.w3.org/TR/css-lists-3/#propdef-counter-set) property.
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "type", content = "value", rename_all = "kebab-case")
+)]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+pub enum CounterSet<'i> {
+  /// No counters are set.
+  #[default]
+  None,
+  /// A list of counters to set. Each counter appears at most once.
+  #[cfg_attr(feature = "serde", serde(borrow))]
+  Counters(Vec<CounterValue<'i>>),
+}
+
+impl<'i> Parse<'i> for CounterSet<'i> {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    if input.try_parse(|input| input.expect_ident_matching("none")).is_ok() {
+      return Ok(CounterSet::None);
+    }
+
+    // If a counter is named more than once, only the last value is used.
+    let mut counters: Vec<CounterValue<'i>> = Vec::new();
+    for counter in parse_counter_values(input, 0)? {
+      counters.retain(|c| c.name != counter.name);
+      counters.push(counter);
+    }
+    Ok(CounterSet::Counters(counters))
+  }
+}
+
+impl ToCss for CounterSet<'_> {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    match self {
+      CounterSet::None => dest.write_str("none"),
+      CounterSet::Counters(counters) => counter_values_to_css(counters, 0, dest),
+    }
+  }
+}
+
+/// A counter within the [counter-reset](https://www.w3.org/TR/css-lists-3/#propdef-counter-reset) property.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(rename_all = "camelCase")
+)]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+pub struct CounterResetValue<'i> {
+  /// The name of the counter.
+  #[cfg_attr(feature = "serde", serde(borrow))]
+  pub name: CustomIdent<'i>,
+  /// Whether the counter is reversed, via the `reversed()` function.
+  pub reversed: bool,
+  /// The initial value of the counter. If omitted, a normal counter starts at 0,
+  /// and a reversed counter starts at the number of items it counts.
+  pub value: Option<CSSInteger>,
+}
+
+impl<'i> Parse<'i> for CounterResetValue<'i> {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    let (name, reversed) = if input.try_parse(|input| input.expect_function_matching("reversed")).is_ok() {
+      (input.parse_nested_block(parse_counter_name)?, true)
+    } else {
+      (parse_counter_name(input)?, false)
+    };
+
+    let value = input.try_parse(CSSInteger::parse).ok();
+    Ok(CounterResetValue { name, reversed, value })
+  }
+}
+
+impl ToCss for CounterResetValue<'_> {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    if self.reversed {
+      dest.write_str("reversed(")?;
+      self.name.to_css(dest)?;
+      dest.write_char(')')?;
+    } else {
+      self.name.to_css(dest)?;
+    }
+
+    match self.value {
+      Some(0) if !self.reversed => {}
+      Some(value) => {
+        dest.write_char(' ')?;
+        value.to_css(dest)?;
+      }
+      None => {}
+    }
+    Ok(())
+  }
+}
+
+/// A value for the [counter-reset](https://www.w3.org/TR/css-lists-3/#propdef-counter-reset) property.
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "type", content = "value", rename_all = "kebab-case")
+)]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+pub enum CounterReset<'i> {
+  /// No counters are reset.
+  #[default]
+  None,
+  /// A list of counters to reset. Each counter appears at most once.
+  #[cfg_attr(feature = "serde", serde(borrow))]
+  Counters(Vec<CounterResetValue<'i>>),
+}
+
+impl<'i> Parse<'i> for CounterReset<'i> {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    if input.try_parse(|input| input.expect_ident_matching("none")).is_ok() {
+      return Ok(CounterReset::None);
+    }
+
+    // If a counter is named more than once, only the last value is used.
+    let mut counters: Vec<CounterResetValue<'i>> = vec![CounterResetValue::parse(input)?];
+    while let Ok(counter) = input.try_parse(CounterResetValue::parse) {
+      counters.retain(|c| c.name != counter.name);
+      counters.push(counter);
+    }
+    Ok(CounterReset::Counters(counters))
+  }
+}
+
+impl ToCss for CounterReset<'_> {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    match self {
+      CounterReset::None => dest.write_str("none"),
+      CounterReset::Counters(counters) => {
+        let mut first = true;
+        for counter in counters {
+          if first {
+            first = false;
+          } else {
+            dest.write_char(' ')?;
+          }
+          counter.to_css(dest)?;
+        }
+        Ok(())
+      }
+    }
+  }
+}
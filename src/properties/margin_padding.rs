@@ -6,6 +6,7 @@ use crate::logical::PropertyCategory;
 use crate::macros::{define_shorthand, rect_shorthand, size_shorthand};
 use crate::printer::Printer;
 use crate::properties::{Property, PropertyId};
+use crate::targets::Features;
 use crate::traits::{IsCompatible, Parse, PropertyHandler, Shorthand, ToCss};
 use crate::values::{length::LengthPercentageOrAuto, rect::Rect, size::Size2D};
 #[cfg(feature = "visitor")]
@@ -261,6 +262,8 @@ macro_rules! side_handler {
             self.block_end = None;
             self.inline_start = None;
             self.inline_end = None;
+            // The shorthand sets the physical sides, so subsequent physical longhands can be merged into it.
+            self.category = PropertyCategory::Physical;
             self.has_any = true;
           }
           Unparsed(val) if matches!(val.property_id, PropertyId::$top | PropertyId::$bottom | PropertyId::$left | PropertyId::$right | PropertyId::$block_start | PropertyId::$block_end | PropertyId::$inline_start | PropertyId::$inline_end | PropertyId::$block_shorthand | PropertyId::$inline_shorthand | PropertyId::$shorthand) => {
@@ -289,11 +292,84 @@ macro_rules! side_handler {
     }
 
     impl<'i> $name<'i> {
+      fn get_viewport_fallback(&self, context: &PropertyHandlerContext<'i, '_>) -> Option<Self> {
+        // Classic viewport units are only an approximation of the small, large, and dynamic
+        // viewport units, so fallbacks must be explicitly enabled.
+        if !context.targets.include.contains(Features::ViewportUnits) {
+          return None;
+        }
+
+        let targets = context.targets.browsers?;
+        let convert = |val: &LengthPercentageOrAuto| {
+          if val.is_compatible(targets) {
+            val.clone()
+          } else {
+            val.to_classic_viewport_units()
+          }
+        };
+
+        let convert_logical = |property: &Option<Property<'i>>| {
+          property.as_ref().map(|property| match property {
+            Property::$block_start(val) => Property::$block_start(convert(val)),
+            Property::$block_end(val) => Property::$block_end(convert(val)),
+            Property::$inline_start(val) => Property::$inline_start(convert(val)),
+            Property::$inline_end(val) => Property::$inline_end(convert(val)),
+            _ => property.clone()
+          })
+        };
+
+        let fallback = $name {
+          top: self.top.as_ref().map(convert),
+          bottom: self.bottom.as_ref().map(convert),
+          left: self.left.as_ref().map(convert),
+          right: self.right.as_ref().map(convert),
+          block_start: convert_logical(&self.block_start),
+          block_end: convert_logical(&self.block_end),
+          inline_start: convert_logical(&self.inline_start),
+          inline_end: convert_logical(&self.inline_end),
+          has_any: true,
+          category: self.category
+        };
+
+        if fallback.top != self.top
+          || fallback.bottom != self.bottom
+          || fallback.left != self.left
+          || fallback.right != self.right
+          || fallback.block_start != self.block_start
+          || fallback.block_end != self.block_end
+          || fallback.inline_start != self.inline_start
+          || fallback.inline_end != self.inline_end
+        {
+          Some(fallback)
+        } else {
+          None
+        }
+      }
+
       fn flush(&mut self, dest: &mut DeclarationList<'i>, context: &mut PropertyHandlerContext<'i, '_>) {
         if !self.has_any {
           return
         }
 
+        // Output the fallback first, in the same form (e.g. shorthand or longhands) as the original values.
+        let logical_start = context.logical_rules_len();
+        let mut fallback_decls = DeclarationList::new();
+        if let Some(mut fallback) = self.get_viewport_fallback(context) {
+          fallback.flush_values(&mut fallback_decls, context);
+        }
+
+        let logical_end = context.logical_rules_len();
+        let mut decls = DeclarationList::new();
+        self.flush_values(&mut decls, context);
+
+        // Skip fallback declarations that are unchanged or were already declared manually.
+        fallback_decls.retain(|decl| !decls.contains(decl) && !dest.contains(decl));
+        context.dedup_logical_rules(logical_start, logical_end);
+        dest.extend(fallback_decls);
+        dest.extend(decls);
+      }
+
+      fn flush_values(&mut self, dest: &mut DeclarationList<'i>, context: &mut PropertyHandlerContext<'i, '_>) {
         self.has_any = false;
 
         let top = std::mem::take(&mut self.top);
@@ -458,7 +534,9 @@ side_handler!(
   ScrollMargin,
   ScrollMarginBlock,
   ScrollMarginInline,
-  Physical
+  Physical,
+  LogicalScrollMargin,
+  LogicalScrollMargin
 );
 
 side_handler!(
@@ -474,7 +552,9 @@ side_handler!(
   ScrollPadding,
   ScrollPaddingBlock,
   ScrollPaddingInline,
-  Physical
+  Physical,
+  LogicalScrollPadding,
+  LogicalScrollPadding
 );
 
 side_handler!(
@@ -339,7 +339,7 @@ impl<'i> ToCss for Mask<'i> {
   {
     self.image.to_css(dest)?;
 
-    if self.position != Position::default() || self.size != BackgroundSize::default() {
+    if !self.position.is_zero() || self.size != BackgroundSize::default() {
       dest.write_char(' ')?;
       self.position.to_css(dest)?;
 
@@ -411,7 +411,8 @@ pub enum ClipPath<'i> {
   #[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
   Shape {
     /// A basic shape.
-    shape: Box<BasicShape>,
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    shape: Box<BasicShape<'i>>,
     /// A reference box that the shape is positioned according to.
     reference_box: GeometryBox,
   },
@@ -0,0 +1,91 @@
+//! CSS properties related to MathML layout.
+
+use crate::error::{ParserError, PrinterError};
+use crate::macros::enum_property;
+use crate::printer::Printer;
+use crate::traits::{Parse, ToCss};
+use crate::values::number::CSSInteger;
+#[cfg(feature = "visitor")]
+use crate::visitor::Visit;
+use cssparser::*;
+
+enum_property! {
+  /// A value for the [math-style](https://w3c.github.io/mathml-core/#the-math-style-property) property.
+  #[derive(Default)]
+  pub enum MathStyle {
+    /// The initial value, with no change to the layout of math formulas.
+    #[default]
+    Normal,
+    /// Math layout is made more compact, e.g. by reducing the height of formulas.
+    Compact,
+  }
+}
+
+enum_property! {
+  /// A value for the [math-shift](https://w3c.github.io/mathml-core/#the-math-shift) property.
+  #[derive(Default)]
+  pub enum MathShift {
+    /// Superscripts are shifted using the normal superscript shift.
+    #[default]
+    Normal,
+    /// Superscripts are shifted using the smaller compact superscript shift.
+    Compact,
+  }
+}
+
+/// A value for the [math-depth](https://w3c.github.io/mathml-core/#the-math-script-level-property) property.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "type", content = "value", rename_all = "kebab-case")
+)]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+pub enum MathDepth {
+  /// The math depth of the parent, plus one when `math-style` is `compact`.
+  AutoAdd,
+  /// The math depth of the parent, plus the given integer.
+  Add(CSSInteger),
+  /// An absolute math depth.
+  Integer(CSSInteger),
+}
+
+impl Default for MathDepth {
+  fn default() -> MathDepth {
+    MathDepth::Integer(0)
+  }
+}
+
+impl<'i> Parse<'i> for MathDepth {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    if input.try_parse(|input| input.expect_ident_matching("auto-add")).is_ok() {
+      return Ok(MathDepth::AutoAdd);
+    }
+
+    if input.try_parse(|input| input.expect_function_matching("add")).is_ok() {
+      let value = input.parse_nested_block(CSSInteger::parse)?;
+      return Ok(MathDepth::Add(value));
+    }
+
+    Ok(MathDepth::Integer(CSSInteger::parse(input)?))
+  }
+}
+
+impl ToCss for MathDepth {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    match self {
+      MathDepth::AutoAdd => dest.write_str("auto-add"),
+      MathDepth::Add(value) => {
+        dest.write_str("add(")?;
+        value.to_css(dest)?;
+        dest.write_char(')')
+      }
+      MathDepth::Integer(value) => value.to_css(dest),
+    }
+  }
+}
@@ -43,6 +43,7 @@
 //!   Property::Background(smallvec![Background {
 //!     image: Image::Url(Url {
 //!       url: "img.png".into(),
+//!       modifiers: Vec::new(),
 //!       loc: Location { line: 1, column: 1 }
 //!     }),
 //!     color: CssColor::RGBA(RGBA {
@@ -91,38 +92,52 @@
 #![deny(missing_docs)]
 
 pub mod align;
+pub mod anchor;
 pub mod animation;
 pub mod background;
 pub mod border;
 pub mod border_image;
 pub mod border_radius;
 pub mod box_shadow;
+pub mod columns;
 pub mod contain;
+pub mod content;
 pub mod css_modules;
 pub mod custom;
 pub mod display;
 pub mod effects;
 pub mod flex;
 pub mod font;
+pub mod fragmentation;
 #[cfg(feature = "grid")]
 pub mod grid;
+pub mod inline;
 pub mod list;
 pub(crate) mod margin_padding;
 pub mod masking;
+pub mod math;
+pub mod motion;
 pub mod outline;
 pub mod overflow;
 pub mod position;
 pub(crate) mod prefix_handler;
+pub mod ruby;
+pub mod shape;
 pub mod size;
 pub mod svg;
+pub mod table;
 pub mod text;
+pub mod timeline;
 pub mod transform;
 pub mod transition;
 pub mod ui;
+pub mod view_transition;
+pub mod writing_modes;
 
 use crate::declaration::DeclarationBlock;
 use crate::error::{ParserError, PrinterError};
 use crate::logical::{LogicalGroup, PropertyCategory};
+use crate::macros::enum_property;
 use crate::parser::starts_with_ignore_ascii_case;
 use crate::parser::ParserOptions;
 use crate::prefixes::Feature;
@@ -132,20 +147,23 @@ use crate::traits::{Parse, ParseWithOptions, Shorthand, ToCss};
 use crate::values::number::{CSSInteger, CSSNumber};
 use crate::values::string::CowArcStr;
 use crate::values::{
-  alpha::*, color::*, easing::EasingFunction, ident::CustomIdent, ident::DashedIdentReference, image::*,
-  length::*, position::*, rect::*, shape::FillRule, size::Size2D, time::Time,
+  alpha::*, color::*, easing::EasingFunction, ident::DashedIdentReference, image::*, length::*, position::*,
+  rect::*, shape::FillRule, size::Size2D, time::Time,
 };
 use crate::vendor_prefix::VendorPrefix;
 #[cfg(feature = "visitor")]
 use crate::visitor::Visit;
 use align::*;
+use anchor::*;
 use animation::*;
 use background::*;
 use border::*;
 use border_image::*;
 use border_radius::*;
 use box_shadow::*;
+use columns::*;
 use contain::*;
+use content::*;
 use css_modules::*;
 use cssparser::*;
 use custom::*;
@@ -153,22 +171,32 @@ use display::*;
 use effects::*;
 use flex::*;
 use font::*;
+use fragmentation::*;
 #[cfg(feature = "grid")]
 use grid::*;
+use inline::*;
 use list::*;
 use margin_padding::*;
 use masking::*;
+use math::*;
+use motion::*;
 use outline::*;
 use overflow::*;
+use ruby::*;
+use shape::*;
 use size::*;
 use smallvec::{smallvec, SmallVec};
 #[cfg(feature = "into_owned")]
 use static_self::IntoOwned;
 use svg::*;
+use table::*;
 use text::*;
+use timeline::*;
 use transform::*;
 use transition::*;
 use ui::*;
+use view_transition::*;
+use writing_modes::*;
 
 macro_rules! define_properties {
   (
@@ -688,6 +716,8 @@ macro_rules! define_properties {
         $(#[$meta])*
         $property($type, $($vp)?),
       )+
+      /// The `all` property.
+      All(CSSWideKeyword),
       /// An unparsed property.
       Unparsed(UnparsedProperty<'i>),
       /// A custom or unknown property.
@@ -710,6 +740,13 @@ macro_rules! define_properties {
               }
             },
           )+
+          PropertyId::All => {
+            if let Ok(keyword) = CSSWideKeyword::parse(input) {
+              if input.expect_exhausted().is_ok() {
+                return Ok(Property::All(keyword))
+              }
+            }
+          },
           PropertyId::Custom(name) => return Ok(Property::Custom(CustomProperty::parse(name, input, options)?)),
           _ => {}
         };
@@ -731,6 +768,7 @@ macro_rules! define_properties {
             $(#[$meta])*
             $property(_, $(vp_name!($vp, p))?) => PropertyId::$property$((*vp_name!($vp, p)))?,
           )+
+          All(_) => PropertyId::All,
           Unparsed(unparsed) => unparsed.property_id.clone(),
           Custom(custom) => PropertyId::Custom(custom.name.clone())
         }
@@ -779,6 +817,7 @@ macro_rules! define_properties {
               val.to_css(dest)
             }
           )+
+          All(keyword) => keyword.to_css(dest),
           Unparsed(unparsed) => {
             unparsed.value.to_css(dest, false)
           }
@@ -838,6 +877,7 @@ macro_rules! define_properties {
               ($name, get_prefix!($($vp)?))
             },
           )+
+          All(_) => ("all", VendorPrefix::None),
           Unparsed(unparsed) => {
             let mut prefix = unparsed.property_id.prefix();
             if prefix.is_empty() {
@@ -966,6 +1006,9 @@ macro_rules! define_properties {
               s.serialize_field("value", value)?;
             }
           )+
+          All(keyword) => {
+            s.serialize_field("value", keyword)?;
+          }
           _ => unreachable!()
         }
 
@@ -1072,7 +1115,10 @@ macro_rules! define_properties {
               Ok(Property::Custom(value))
             }
           }
-          PropertyId::All => unreachable!()
+          PropertyId::All => {
+            let value = CSSWideKeyword::deserialize(deserializer)?;
+            Ok(Property::All(value))
+          }
         }
       }
     }
@@ -1134,6 +1180,19 @@ macro_rules! define_properties {
                   with_prefix!($($vp)?)
                 },
               )+
+              {
+                property!("all");
+
+                #[derive(schemars::JsonSchema)]
+                struct T<'i> {
+                  #[schemars(rename = "property", schema_with = "property")]
+                  _property: &'i u8,
+                  #[schemars(rename = "value")]
+                  _value: CSSWideKeyword,
+                }
+
+                T::json_schema(gen)
+              },
               {
                 property!("unparsed");
 
@@ -1186,6 +1245,7 @@ define_properties! {
   "background-clip": BackgroundClip(SmallVec<[BackgroundClip; 1]>, VendorPrefix) / WebKit / Moz,
   "background-origin": BackgroundOrigin(SmallVec<[BackgroundOrigin; 1]>),
   "background": Background(SmallVec<[Background<'i>; 1]>) shorthand: true,
+  "background-blend-mode": BackgroundBlendMode(SmallVec<[BlendMode; 1]>),
 
   "box-shadow": BoxShadow(SmallVec<[BoxShadow; 1]>, VendorPrefix) / WebKit / Moz,
   "opacity": Opacity(AlphaValue),
@@ -1208,10 +1268,38 @@ define_properties! {
   "box-sizing": BoxSizing(BoxSizing, VendorPrefix) / WebKit / Moz,
   "aspect-ratio": AspectRatio(AspectRatio),
 
+  // https://drafts.csswg.org/css-sizing-4/#intrinsic-size-override
+  "contain-intrinsic-width": ContainIntrinsicWidth(ContainIntrinsicLength),
+  "contain-intrinsic-height": ContainIntrinsicHeight(ContainIntrinsicLength),
+  "contain-intrinsic-block-size": ContainIntrinsicBlockSize(ContainIntrinsicLength),
+  "contain-intrinsic-inline-size": ContainIntrinsicInlineSize(ContainIntrinsicLength),
+  "contain-intrinsic-size": ContainIntrinsicSize(ContainIntrinsicSize) shorthand: true,
+
+  // https://drafts.csswg.org/css-values-5/#interpolate-size
+  "interpolate-size": InterpolateSize(InterpolateSize),
+
+  // https://drafts.csswg.org/css-viewport/
+  "zoom": Zoom(Zoom),
+
+  // https://www.w3.org/TR/css-images-3/
+  "object-fit": ObjectFit(ObjectFit, VendorPrefix) / O,
+  "object-position": ObjectPosition(Position, VendorPrefix) / O,
+  "image-orientation": ImageOrientation(ImageOrientation),
+
   "overflow": Overflow(Overflow) shorthand: true,
   "overflow-x": OverflowX(OverflowKeyword),
   "overflow-y": OverflowY(OverflowKeyword),
-  "text-overflow": TextOverflow(TextOverflow, VendorPrefix) / O,
+  "overflow-clip-margin": OverflowClipMargin(OverflowClipMargin),
+  "text-overflow": TextOverflow(TextOverflow<'i>, VendorPrefix) / O,
+  "line-clamp": LineClamp(LineClamp, VendorPrefix) / WebKit,
+  "continue": Continue(Continue),
+
+  // https://www.w3.org/TR/css-overscroll-1/
+  "overscroll-behavior": OverscrollBehavior(OverscrollBehavior) shorthand: true,
+  "overscroll-behavior-x": OverscrollBehaviorX(OverscrollBehaviorKeyword),
+  "overscroll-behavior-y": OverscrollBehaviorY(OverscrollBehaviorKeyword),
+  "overscroll-behavior-block": OverscrollBehaviorBlock(OverscrollBehaviorKeyword),
+  "overscroll-behavior-inline": OverscrollBehaviorInline(OverscrollBehaviorKeyword),
 
   // https://www.w3.org/TR/2020/WD-css-position-3-20200519
   "position": Position(position::Position),
@@ -1227,7 +1315,22 @@ define_properties! {
   "inset-inline": InsetInline(InsetInline) shorthand: true,
   "inset": Inset(Inset) shorthand: true,
 
+  // https://drafts.csswg.org/css-anchor-position-1/
+  "anchor-name": AnchorName(AnchorName<'i>),
+  "position-anchor": PositionAnchor(PositionAnchor<'i>),
+  "position-area": PositionArea(PositionArea),
+  // Legacy name for position-area, shipped in Chrome 125-128.
+  "inset-area": InsetArea(PositionArea),
+  "position-try-order": PositionTryOrder(PositionTryOrder),
+  "position-try-fallbacks": PositionTryFallbacks(PositionTryFallbacks<'i>),
+  "position-try": PositionTry(PositionTry<'i>) shorthand: true,
+
+  // https://www.w3.org/TR/css-tables-3/
+  "border-collapse": BorderCollapse(BorderCollapse),
   "border-spacing": BorderSpacing(Size2D<Length>),
+  "caption-side": CaptionSide(CaptionSide),
+  "empty-cells": EmptyCells(EmptyCells),
+  "table-layout": TableLayout(TableLayout),
 
   "border-top-color": BorderTopColor(CssColor) [logical_group: BorderColor, category: Physical],
   "border-bottom-color": BorderBottomColor(CssColor) [logical_group: BorderColor, category: Physical],
@@ -1301,6 +1404,7 @@ define_properties! {
   "outline-color": OutlineColor(CssColor),
   "outline-style": OutlineStyle(OutlineStyle),
   "outline-width": OutlineWidth(BorderSideWidth),
+  "outline-offset": OutlineOffset(Length),
 
   // Flex properties: https://www.w3.org/TR/2018/CR-css-flexbox-1-20181119
   "flex-direction": FlexDirection(FlexDirection, VendorPrefix) / WebKit / Ms,
@@ -1326,6 +1430,17 @@ define_properties! {
   "column-gap": ColumnGap(GapValue),
   "gap": Gap(Gap) shorthand: true,
 
+  // Multi-column layout: https://www.w3.org/TR/css-multicol-1/
+  "column-width": ColumnWidth(ColumnWidth),
+  "column-count": ColumnCount(ColumnCount),
+  "columns": Columns(Columns) shorthand: true,
+  "column-rule-width": ColumnRuleWidth(BorderSideWidth),
+  "column-rule-style": ColumnRuleStyle(LineStyle),
+  "column-rule-color": ColumnRuleColor(CssColor),
+  "column-rule": ColumnRule(ColumnRule) shorthand: true,
+  "column-span": ColumnSpan(ColumnSpan),
+  "column-fill": ColumnFill(ColumnFill),
+
   // Old flex (2009): https://www.w3.org/TR/2009/WD-css3-flexbox-20090723/
   "box-orient": BoxOrient(BoxOrient, VendorPrefix) / WebKit / Moz unprefixed: false,
   "box-direction": BoxDirection(BoxDirection, VendorPrefix) / WebKit / Moz unprefixed: false,
@@ -1448,10 +1563,24 @@ define_properties! {
   "font-family": FontFamily(Vec<FontFamily<'i>>),
   "font-style": FontStyle(FontStyle),
   "font-variant-caps": FontVariantCaps(FontVariantCaps),
+  "font-variant-position": FontVariantPosition(FontVariantPosition),
+  "font-variant-ligatures": FontVariantLigatures(FontVariantLigatures),
+  "font-variant-numeric": FontVariantNumeric(FontVariantNumeric),
+  "font-variant-alternates": FontVariantAlternates(FontVariantAlternates<'i>),
+  "font-variant-east-asian": FontVariantEastAsian(FontVariantEastAsian),
+  "font-variant": FontVariant(FontVariant<'i>) shorthand: true,
+  "font-feature-settings": FontFeatureSettings(FontFeatureSettings<'i>),
+  "font-variation-settings": FontVariationSettings(FontVariationSettings<'i>),
+  "font-size-adjust": FontSizeAdjust(FontSizeAdjust),
   "line-height": LineHeight(LineHeight),
   "font": Font(Font<'i>) shorthand: true,
   "vertical-align": VerticalAlign(VerticalAlign),
   "font-palette": FontPalette(DashedIdentReference<'i>),
+  "font-synthesis-weight": FontSynthesisWeight(FontSynthesisKeyword),
+  "font-synthesis-style": FontSynthesisStyle(FontSynthesisKeyword),
+  "font-synthesis-small-caps": FontSynthesisSmallCaps(FontSynthesisKeyword),
+  "font-synthesis-position": FontSynthesisPosition(FontSynthesisKeyword),
+  "font-synthesis": FontSynthesis(FontSynthesis) shorthand: true,
 
   "transition-property": TransitionProperty(SmallVec<[PropertyId<'i>; 1]>, VendorPrefix) / WebKit / Moz / Ms,
   "transition-duration": TransitionDuration(SmallVec<[Time; 1]>, VendorPrefix) / WebKit / Moz / Ms,
@@ -1467,8 +1596,23 @@ define_properties! {
   "animation-play-state": AnimationPlayState(SmallVec<[AnimationPlayState; 1]>, VendorPrefix) / WebKit / Moz / O,
   "animation-delay": AnimationDelay(SmallVec<[Time; 1]>, VendorPrefix) / WebKit / Moz / O,
   "animation-fill-mode": AnimationFillMode(SmallVec<[AnimationFillMode; 1]>, VendorPrefix) / WebKit / Moz / O,
+  "animation-composition": AnimationComposition(SmallVec<[AnimationComposition; 1]>),
+  "animation-timeline": AnimationTimeline(SmallVec<[AnimationTimeline<'i>; 1]>),
+  "animation-range-start": AnimationRangeStart(SmallVec<[AnimationRangeStart; 1]>),
+  "animation-range-end": AnimationRangeEnd(SmallVec<[AnimationRangeEnd; 1]>),
+  "animation-range": AnimationRange(SmallVec<[AnimationRange; 1]>) shorthand: true,
   "animation": Animation(AnimationList<'i>, VendorPrefix) / WebKit / Moz / O shorthand: true,
 
+  // https://drafts.csswg.org/scroll-animations-1/
+  "scroll-timeline-name": ScrollTimelineName(SmallVec<[TimelineName<'i>; 1]>),
+  "scroll-timeline-axis": ScrollTimelineAxis(SmallVec<[ScrollAxis; 1]>),
+  "scroll-timeline": ScrollTimeline(SmallVec<[ScrollTimeline<'i>; 1]>) shorthand: true,
+  "view-timeline-name": ViewTimelineName(SmallVec<[TimelineName<'i>; 1]>),
+  "view-timeline-axis": ViewTimelineAxis(SmallVec<[ScrollAxis; 1]>),
+  "view-timeline-inset": ViewTimelineInset(SmallVec<[ViewTimelineInset; 1]>),
+  "view-timeline": ViewTimeline(SmallVec<[ViewTimeline<'i>; 1]>) shorthand: true,
+  "timeline-scope": TimelineScope(TimelineScope<'i>),
+
   // https://drafts.csswg.org/css-transforms-2/
   "transform": Transform(TransformList, VendorPrefix) / WebKit / Moz / Ms / O,
   "transform-origin": TransformOrigin(Position, VendorPrefix) / WebKit / Moz / Ms / O, // TODO: handle z offset syntax
@@ -1481,13 +1625,23 @@ define_properties! {
   "rotate": Rotate(Rotate),
   "scale": Scale(Scale),
 
+  // https://www.w3.org/TR/motion-1/
+  "offset-path": OffsetPath(OffsetPath<'i>),
+  "offset-distance": OffsetDistance(LengthPercentage),
+  "offset-rotate": OffsetRotate(OffsetRotate),
+  "offset-anchor": OffsetAnchor(OffsetAnchor),
+  "offset-position": OffsetPosition(OffsetPosition),
+  "offset": Offset(Offset<'i>) shorthand: true,
+
   // https://www.w3.org/TR/2021/CRD-css-text-3-20210422
   "text-transform": TextTransform(TextTransform),
-  "white-space": WhiteSpace(WhiteSpace),
+  "white-space": WhiteSpace(WhiteSpace) shorthand: true,
   "tab-size": TabSize(LengthOrNumber, VendorPrefix) / Moz / O,
   "word-break": WordBreak(WordBreak),
   "line-break": LineBreak(LineBreak),
   "hyphens": Hyphens(Hyphens, VendorPrefix) / WebKit / Moz / Ms,
+  "hyphenate-character": HyphenateCharacter(HyphenateCharacter<'i>, VendorPrefix) / WebKit,
+  "hyphenate-limit-chars": HyphenateLimitChars(HyphenateLimitChars),
   "overflow-wrap": OverflowWrap(OverflowWrap),
   "word-wrap": WordWrap(OverflowWrap),
   "text-align": TextAlign(TextAlign),
@@ -1497,34 +1651,85 @@ define_properties! {
   "letter-spacing": LetterSpacing(Spacing),
   "text-indent": TextIndent(TextIndent),
 
+  // https://www.w3.org/TR/css-text-4/
+  "white-space-collapse": WhiteSpaceCollapse(WhiteSpaceCollapse),
+  "text-wrap-mode": TextWrapMode(TextWrapMode),
+  "text-wrap-style": TextWrapStyle(TextWrapStyle),
+  "text-wrap": TextWrap(TextWrap) shorthand: true,
+
   // https://www.w3.org/TR/2020/WD-css-text-decor-4-20200506
   "text-decoration-line": TextDecorationLine(TextDecorationLine, VendorPrefix) / WebKit / Moz,
   "text-decoration-style": TextDecorationStyle(TextDecorationStyle, VendorPrefix) / WebKit / Moz,
   "text-decoration-color": TextDecorationColor(CssColor, VendorPrefix) / WebKit / Moz,
   "text-decoration-thickness": TextDecorationThickness(TextDecorationThickness),
   "text-decoration": TextDecoration(TextDecoration, VendorPrefix) / WebKit / Moz shorthand: true,
+  "text-decoration-skip": TextDecorationSkip(TextDecorationSkip, VendorPrefix) / WebKit,
   "text-decoration-skip-ink": TextDecorationSkipInk(TextDecorationSkipInk, VendorPrefix) / WebKit,
+  "text-underline-offset": TextUnderlineOffset(TextUnderlineOffset),
+  "text-underline-position": TextUnderlinePosition(TextUnderlinePosition),
   "text-emphasis-style": TextEmphasisStyle(TextEmphasisStyle<'i>, VendorPrefix) / WebKit,
   "text-emphasis-color": TextEmphasisColor(CssColor, VendorPrefix) / WebKit,
   "text-emphasis": TextEmphasis(TextEmphasis<'i>, VendorPrefix) / WebKit shorthand: true,
   "text-emphasis-position": TextEmphasisPosition(TextEmphasisPosition, VendorPrefix) / WebKit,
   "text-shadow": TextShadow(SmallVec<[TextShadow; 1]>),
 
+  // https://compat.spec.whatwg.org/#text-fill-and-stroking
+  "text-fill-color": WebKitTextFillColor(CssColor, VendorPrefix) / WebKit unprefixed: false,
+  "text-stroke-color": WebKitTextStrokeColor(CssColor, VendorPrefix) / WebKit unprefixed: false,
+  "text-stroke-width": WebKitTextStrokeWidth(BorderSideWidth, VendorPrefix) / WebKit unprefixed: false,
+  "text-stroke": WebKitTextStroke(WebKitTextStroke, VendorPrefix) / WebKit unprefixed: false shorthand: true,
+
+  // https://www.w3.org/TR/css-ruby-1/
+  "ruby-position": RubyPosition(RubyPosition),
+  "ruby-align": RubyAlign(RubyAlign),
+  "ruby-merge": RubyMerge(RubyMerge),
+
+  // https://w3c.github.io/mathml-core/#new-css-properties
+  "math-style": MathStyle(MathStyle),
+  "math-shift": MathShift(MathShift),
+  "math-depth": MathDepth(MathDepth),
+
+  // https://www.w3.org/TR/css-inline-3/
+  "initial-letter": InitialLetter(InitialLetter, VendorPrefix) / WebKit,
+  "initial-letter-align": InitialLetterAlign(InitialLetterAlign),
+
   // https://w3c.github.io/csswg-drafts/css-size-adjust/
   "text-size-adjust": TextSizeAdjust(TextSizeAdjust, VendorPrefix) / WebKit / Moz / Ms,
 
   // https://www.w3.org/TR/css-break-3/
   "box-decoration-break": BoxDecorationBreak(BoxDecorationBreak, VendorPrefix) / WebKit,
+  "break-before": BreakBefore(BreakBetween),
+  "break-after": BreakAfter(BreakBetween),
+  "break-inside": BreakInside(BreakInside),
+  "page-break-before": PageBreakBefore(PageBreakBetween),
+  "page-break-after": PageBreakAfter(PageBreakBetween),
+  "page-break-inside": PageBreakInside(PageBreakInside),
+  "orphans": Orphans(LineCount),
+  "widows": Widows(LineCount),
+
+  // https://www.w3.org/TR/css-page-3/
+  "page": Page(Page<'i>),
+
+  // https://www.w3.org/TR/css-writing-modes-4/
+  "writing-mode": WritingMode(WritingMode, VendorPrefix) / WebKit / Ms,
+  "direction": Direction(Direction),
+  "unicode-bidi": UnicodeBidi(UnicodeBidi),
+  "text-orientation": TextOrientation(TextOrientation, VendorPrefix) / WebKit,
 
   // https://www.w3.org/TR/2021/WD-css-ui-4-20210316
   "resize": Resize(Resize),
   "cursor": Cursor(Cursor<'i>),
+  "pointer-events": PointerEvents(PointerEvents),
   "caret-color": CaretColor(ColorOrAuto),
   "caret-shape": CaretShape(CaretShape),
   "caret": Caret(Caret) shorthand: true,
   "user-select": UserSelect(UserSelect, VendorPrefix) / WebKit / Moz / Ms,
   "accent-color": AccentColor(ColorOrAuto),
   "appearance": Appearance(Appearance<'i>, VendorPrefix) / WebKit / Moz / Ms,
+  "field-sizing": FieldSizing(FieldSizing),
+
+  // https://w3c.github.io/pointerevents/
+  "touch-action": TouchAction(TouchAction, VendorPrefix) / Ms,
 
   // https://www.w3.org/TR/2020/WD-css-lists-3-20201117
   "list-style-type": ListStyleType(ListStyleType<'i>),
@@ -1532,6 +1737,13 @@ define_properties! {
   "list-style-position": ListStylePosition(ListStylePosition),
   "list-style": ListStyle(ListStyle<'i>) shorthand: true,
   "marker-side": MarkerSide(MarkerSide),
+  "counter-reset": CounterReset(CounterReset<'i>),
+  "counter-set": CounterSet(CounterSet<'i>),
+  "counter-increment": CounterIncrement(CounterIncrement<'i>),
+
+  // https://www.w3.org/TR/css-content-3
+  "content": Content(Content<'i>),
+  "quotes": Quotes(Quotes<'i>),
 
   // CSS modules
   "composes": Composes(Composes<'i>) if css_modules,
@@ -1558,6 +1770,9 @@ define_properties! {
   "shape-rendering": ShapeRendering(ShapeRendering),
   "text-rendering": TextRendering(TextRendering),
   "image-rendering": ImageRendering(ImageRendering),
+  "paint-order": PaintOrder(PaintOrder),
+  "vector-effect": VectorEffect(VectorEffect),
+  "dominant-baseline": DominantBaseline(DominantBaseline),
 
   // https://www.w3.org/TR/css-masking-1/
   "clip-path": ClipPath(ClipPath<'i>, VendorPrefix) / WebKit,
@@ -1592,23 +1807,38 @@ define_properties! {
   "mask-box-image-outset": WebKitMaskBoxImageOutset(Rect<LengthOrNumber>, VendorPrefix) / WebKit unprefixed: false,
   "mask-box-image-repeat": WebKitMaskBoxImageRepeat(BorderImageRepeat, VendorPrefix) / WebKit unprefixed: false,
 
+  // https://www.w3.org/TR/css-shapes-1/
+  "shape-outside": ShapeOutside(ShapeOutside<'i>, VendorPrefix) / WebKit,
+  "shape-margin": ShapeMargin(ShapeMargin, VendorPrefix) / WebKit,
+  "shape-image-threshold": ShapeImageThreshold(AlphaValue, VendorPrefix) / WebKit,
+
   // https://drafts.fxtf.org/filter-effects-1/
   "filter": Filter(FilterList<'i>, VendorPrefix) / WebKit,
   "backdrop-filter": BackdropFilter(FilterList<'i>, VendorPrefix) / WebKit,
 
+  // https://drafts.fxtf.org/compositing-1/
+  "mix-blend-mode": MixBlendMode(BlendMode),
+  "isolation": Isolation(Isolation),
+
   // https://drafts.csswg.org/css2/
   "z-index": ZIndex(position::ZIndex),
 
   // https://drafts.csswg.org/css-contain-3/
+  "contain": Contain(Contain),
+  "content-visibility": ContentVisibility(ContentVisibility),
   "container-type": ContainerType(ContainerType),
   "container-name": ContainerName(ContainerNameList<'i>),
   "container": Container(Container<'i>) shorthand: true,
 
   // https://w3c.github.io/csswg-drafts/css-view-transitions-1/
-  "view-transition-name": ViewTransitionName(CustomIdent<'i>),
+  "view-transition-name": ViewTransitionName(ViewTransitionName<'i>),
+  "view-transition-class": ViewTransitionClass(ViewTransitionClass<'i>),
 
   // https://drafts.csswg.org/css-color-adjust/
   "color-scheme": ColorScheme(ColorScheme),
+  "print-color-adjust": PrintColorAdjust(PrintColorAdjust, VendorPrefix) / WebKit,
+  "color-adjust": ColorAdjust(PrintColorAdjust),
+  "forced-color-adjust": ForcedColorAdjust(ForcedColorAdjust),
 }
 
 impl<'i, T: smallvec::Array<Item = V>, V: Parse<'i>> Parse<'i> for SmallVec<T> {
@@ -1667,3 +1897,20 @@ impl<T: ToCss> ToCss for Vec<T> {
     Ok(())
   }
 }
+
+enum_property! {
+  /// A [CSS-wide keyword](https://drafts.csswg.org/css-cascade-5/#defaulting-keywords),
+  /// as accepted by the `all` property.
+  pub enum CSSWideKeyword {
+    /// The property's initial value.
+    "initial": Initial,
+    /// The property's computed value on the parent element.
+    "inherit": Inherit,
+    /// Either inherit or initial depending on whether the property is inherited.
+    "unset": Unset,
+    /// Rolls back the cascade to the cascaded value of the earlier origin.
+    "revert": Revert,
+    /// Rolls back the cascade to the value of the previous cascade layer.
+    "revert-layer": RevertLayer,
+  }
+}
@@ -0,0 +1,559 @@
+//! CSS properties related to motion paths.
+
+use super::masking::GeometryBox;
+use super::{Property, PropertyId};
+use crate::context::PropertyHandlerContext;
+use crate::declaration::{DeclarationBlock, DeclarationList};
+use crate::error::{ParserError, PrinterError};
+use crate::macros::{define_shorthand, enum_property, shorthand_handler};
+use crate::printer::Printer;
+use crate::targets::Browsers;
+use crate::traits::{IsCompatible, Parse, PropertyHandler, Shorthand, ToCss, Zero};
+use crate::values::angle::Angle;
+use crate::values::length::LengthPercentage;
+use crate::values::position::Position;
+use crate::values::shape::BasicShape;
+use crate::values::url::Url;
+#[cfg(feature = "visitor")]
+use crate::visitor::Visit;
+use cssparser::*;
+
+enum_property! {
+  /// A [`<ray-size>`](https://www.w3.org/TR/motion-1/#typedef-ray-size) value,
+  /// as used in the `ray()` function.
+  #[derive(Default)]
+  pub enum RaySize {
+    /// The distance from the initial position to the closest side of the containing box.
+    #[default]
+    "closest-side": ClosestSide,
+    /// The distance from the initial position to the closest corner of the containing box.
+    "closest-corner": ClosestCorner,
+    /// The distance from the initial position to the farthest side of the containing box.
+    "farthest-side": FarthestSide,
+    /// The distance from the initial position to the farthest corner of the containing box.
+    "farthest-corner": FarthestCorner,
+    /// The distance from the initial position to the intersection of the ray with the containing box.
+    "sides": Sides,
+  }
+}
+
+/// A [`ray()`](https://www.w3.org/TR/motion-1/#ray-function) function, as used in the `offset-path` property.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(rename_all = "camelCase")
+)]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+pub struct Ray {
+  /// The angle of the ray, where 0deg points up.
+  pub angle: Angle,
+  /// The length of the path.
+  pub size: RaySize,
+  /// Whether the element is shifted so that it is entirely contained within the path.
+  pub contain: bool,
+  /// The starting position of the ray. Defaults to the `offset-position`.
+  pub position: Option<Position>,
+}
+
+impl<'i> Parse<'i> for Ray {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    input.expect_function_matching("ray")?;
+    input.parse_nested_block(|input| {
+      let mut angle = None;
+      let mut size = None;
+      let mut contain = false;
+      let mut position = None;
+
+      loop {
+        if angle.is_none() {
+          if let Ok(value) = input.try_parse(Angle::parse) {
+            angle = Some(value);
+            continue;
+          }
+        }
+
+        if size.is_none() {
+          if let Ok(value) = input.try_parse(RaySize::parse) {
+            size = Some(value);
+            continue;
+          }
+        }
+
+        if !contain && input.try_parse(|input| input.expect_ident_matching("contain")).is_ok() {
+          contain = true;
+          continue;
+        }
+
+        if position.is_none() && input.try_parse(|input| input.expect_ident_matching("at")).is_ok() {
+          position = Some(Position::parse(input)?);
+          continue;
+        }
+
+        break;
+      }
+
+      Ok(Ray {
+        angle: angle.ok_or_else(|| input.new_custom_error(ParserError::InvalidValue))?,
+        size: size.unwrap_or_default(),
+        contain,
+        position,
+      })
+    })
+  }
+}
+
+impl ToCss for Ray {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    dest.write_str("ray(")?;
+    self.angle.to_css(dest)?;
+
+    if self.size != RaySize::default() {
+      dest.write_char(' ')?;
+      self.size.to_css(dest)?;
+    }
+
+    if self.contain {
+      dest.write_str(" contain")?;
+    }
+
+    if let Some(position) = &self.position {
+      dest.write_str(" at ")?;
+      position.to_css(dest)?;
+    }
+
+    dest.write_char(')')
+  }
+}
+
+/// A value for the [offset-path](https://www.w3.org/TR/motion-1/#offset-path-property) property.
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "type", rename_all = "kebab-case")
+)]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+pub enum OffsetPath<'i> {
+  /// No offset path.
+  #[default]
+  None,
+  /// A url reference to an SVG shape element.
+  #[cfg_attr(feature = "serde", serde(borrow, with = "crate::serialization::ValueWrapper::<Url>"))]
+  Url(Url<'i>),
+  /// A ray, positioned according to the reference box.
+  #[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+  Ray {
+    /// The ray.
+    ray: Ray,
+    /// A reference box that the ray is sized according to.
+    reference_box: GeometryBox,
+  },
+  /// A basic shape, positioned according to the reference box.
+  #[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+  Shape {
+    /// A basic shape.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    shape: Box<BasicShape<'i>>,
+    /// A reference box that the shape is positioned according to.
+    reference_box: GeometryBox,
+  },
+  /// A reference box, the edges of which are used as the path.
+  #[cfg_attr(feature = "serde", serde(with = "crate::serialization::ValueWrapper::<GeometryBox>"))]
+  Box(GeometryBox),
+}
+
+impl<'i> Parse<'i> for OffsetPath<'i> {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    if let Ok(url) = input.try_parse(Url::parse) {
+      return Ok(OffsetPath::Url(url));
+    }
+
+    if input.try_parse(|input| input.expect_ident_matching("none")).is_ok() {
+      return Ok(OffsetPath::None);
+    }
+
+    // The reference box may appear either before or after the path.
+    let mut reference_box = input.try_parse(parse_coord_box).ok();
+    let ray = input.try_parse(Ray::parse).ok();
+    let shape = if ray.is_none() {
+      input.try_parse(BasicShape::parse).ok()
+    } else {
+      None
+    };
+
+    if reference_box.is_none() && (ray.is_some() || shape.is_some()) {
+      reference_box = input.try_parse(parse_coord_box).ok();
+    }
+
+    if let Some(ray) = ray {
+      return Ok(OffsetPath::Ray {
+        ray,
+        reference_box: reference_box.unwrap_or_default(),
+      });
+    }
+
+    if let Some(shape) = shape {
+      return Ok(OffsetPath::Shape {
+        shape: Box::new(shape),
+        reference_box: reference_box.unwrap_or_default(),
+      });
+    }
+
+    if let Some(b) = reference_box {
+      return Ok(OffsetPath::Box(b));
+    }
+
+    Err(input.new_custom_error(ParserError::InvalidValue))
+  }
+}
+
+/// Parses a [`<coord-box>`](https://drafts.fxtf.org/motion-1/#typedef-offset-path-coord-box),
+/// which is a `<geometry-box>` other than `margin-box`.
+fn parse_coord_box<'i, 't>(input: &mut Parser<'i, 't>) -> Result<GeometryBox, ParseError<'i, ParserError<'i>>> {
+  let location = input.current_source_location();
+  match GeometryBox::parse(input)? {
+    GeometryBox::MarginBox => Err(location.new_custom_error(ParserError::InvalidValue)),
+    reference_box => Ok(reference_box),
+  }
+}
+
+impl<'i> ToCss for OffsetPath<'i> {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    match self {
+      OffsetPath::None => dest.write_str("none"),
+      OffsetPath::Url(url) => url.to_css(dest),
+      OffsetPath::Ray { ray, reference_box } => {
+        ray.to_css(dest)?;
+        if *reference_box != GeometryBox::default() {
+          dest.write_char(' ')?;
+          reference_box.to_css(dest)?;
+        }
+        Ok(())
+      }
+      OffsetPath::Shape { shape, reference_box } => {
+        shape.to_css(dest)?;
+        if *reference_box != GeometryBox::default() {
+          dest.write_char(' ')?;
+          reference_box.to_css(dest)?;
+        }
+        Ok(())
+      }
+      OffsetPath::Box(b) => b.to_css(dest),
+    }
+  }
+}
+
+impl<'i> IsCompatible for OffsetPath<'i> {
+  fn is_compatible(&self, _browsers: Browsers) -> bool {
+    true
+  }
+}
+
+/// A value for the [offset-rotate](https://www.w3.org/TR/motion-1/#offset-rotate-property) property.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+pub struct OffsetRotate {
+  /// Whether the element is rotated according to the direction of the path.
+  pub auto: bool,
+  /// A fixed angle, added to the path direction when `auto` is set.
+  pub angle: Angle,
+}
+
+impl Default for OffsetRotate {
+  fn default() -> OffsetRotate {
+    OffsetRotate {
+      auto: true,
+      angle: Angle::Deg(0.0),
+    }
+  }
+}
+
+impl<'i> Parse<'i> for OffsetRotate {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    let mut angle = input.try_parse(Angle::parse).ok();
+    let location = input.current_source_location();
+    let keyword: Result<f32, ParseError<'i, ParserError<'i>>> = input.try_parse(|input| {
+      let ident = input.expect_ident()?;
+      match_ignore_ascii_case! { ident,
+        "auto" => Ok(0.0),
+        // `reverse` is equivalent to `auto 180deg`.
+        "reverse" => Ok(180.0),
+        _ => Err(location.new_unexpected_token_error(Token::Ident(ident.clone())))
+      }
+    });
+
+    if angle.is_none() {
+      angle = input.try_parse(Angle::parse).ok();
+    }
+
+    match (keyword, angle) {
+      (Ok(deg), Some(angle)) if deg == 0.0 => Ok(OffsetRotate { auto: true, angle }),
+      (Ok(deg), Some(angle)) => Ok(OffsetRotate {
+        auto: true,
+        angle: Angle::Deg(angle.to_degrees() + deg),
+      }),
+      (Ok(deg), None) => Ok(OffsetRotate {
+        auto: true,
+        angle: Angle::Deg(deg),
+      }),
+      (Err(_), Some(angle)) => Ok(OffsetRotate { auto: false, angle }),
+      (Err(e), None) => Err(e),
+    }
+  }
+}
+
+impl ToCss for OffsetRotate {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    if !self.auto {
+      return self.angle.to_css(dest);
+    }
+
+    if self.angle.to_degrees() == 180.0 {
+      return dest.write_str("reverse");
+    }
+
+    dest.write_str("auto")?;
+    if !self.angle.is_zero() {
+      dest.write_char(' ')?;
+      self.angle.to_css(dest)?;
+    }
+    Ok(())
+  }
+}
+
+impl IsCompatible for OffsetRotate {
+  fn is_compatible(&self, _browsers: Browsers) -> bool {
+    true
+  }
+}
+
+/// A value for the [offset-anchor](https://www.w3.org/TR/motion-1/#offset-anchor-property) property.
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "type", content = "value", rename_all = "kebab-case")
+)]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+pub enum OffsetAnchor {
+  /// The anchor is the same as the `offset-position`, or the center of the box.
+  #[default]
+  Auto,
+  /// An explicit anchor position.
+  Position(Position),
+}
+
+impl<'i> Parse<'i> for OffsetAnchor {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    if input.try_parse(|input| input.expect_ident_matching("auto")).is_ok() {
+      return Ok(OffsetAnchor::Auto);
+    }
+
+    Ok(OffsetAnchor::Position(Position::parse(input)?))
+  }
+}
+
+impl ToCss for OffsetAnchor {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    match self {
+      OffsetAnchor::Auto => dest.write_str("auto"),
+      OffsetAnchor::Position(position) => position.to_css(dest),
+    }
+  }
+}
+
+impl IsCompatible for OffsetAnchor {
+  fn is_compatible(&self, browsers: Browsers) -> bool {
+    match self {
+      OffsetAnchor::Auto => true,
+      OffsetAnchor::Position(position) => position.is_compatible(browsers),
+    }
+  }
+}
+
+/// A value for the [offset-position](https://www.w3.org/TR/motion-1/#offset-position-property) property.
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "type", content = "value", rename_all = "kebab-case")
+)]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+pub enum OffsetPosition {
+  /// The element does not have an offset starting position.
+  #[default]
+  Normal,
+  /// The offset starting position is the element's position in the containing block.
+  Auto,
+  /// An explicit offset starting position.
+  Position(Position),
+}
+
+impl<'i> Parse<'i> for OffsetPosition {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    if input.try_parse(|input| input.expect_ident_matching("normal")).is_ok() {
+      return Ok(OffsetPosition::Normal);
+    }
+
+    if input.try_parse(|input| input.expect_ident_matching("auto")).is_ok() {
+      return Ok(OffsetPosition::Auto);
+    }
+
+    Ok(OffsetPosition::Position(Position::parse(input)?))
+  }
+}
+
+impl ToCss for OffsetPosition {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    match self {
+      OffsetPosition::Normal => dest.write_str("normal"),
+      OffsetPosition::Auto => dest.write_str("auto"),
+      OffsetPosition::Position(position) => position.to_css(dest),
+    }
+  }
+}
+
+impl IsCompatible for OffsetPosition {
+  fn is_compatible(&self, browsers: Browsers) -> bool {
+    match self {
+      OffsetPosition::Normal | OffsetPosition::Auto => true,
+      OffsetPosition::Position(position) => position.is_compatible(browsers),
+    }
+  }
+}
+
+define_shorthand! {
+  /// A value for the [offset](https://www.w3.org/TR/motion-1/#offset-shorthand) shorthand property.
+  pub struct Offset<'i> {
+    /// The offset path.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    path: OffsetPath(OffsetPath<'i>),
+    /// The offset distance.
+    distance: OffsetDistance(LengthPercentage),
+    /// The offset rotation.
+    rotate: OffsetRotate(OffsetRotate),
+    /// The offset anchor.
+    anchor: OffsetAnchor(OffsetAnchor),
+    /// The offset position.
+    position: OffsetPosition(OffsetPosition),
+  }
+}
+
+impl<'i> Parse<'i> for Offset<'i> {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    let position = input.try_parse(OffsetPosition::parse).ok();
+    let path = input.try_parse(OffsetPath::parse).ok();
+    if position.is_none() && path.is_none() {
+      return Err(input.new_custom_error(ParserError::InvalidDeclaration));
+    }
+
+    let mut distance = None;
+    let mut rotate = None;
+    if path.is_some() {
+      loop {
+        if distance.is_none() {
+          if let Ok(value) = input.try_parse(LengthPercentage::parse) {
+            distance = Some(value);
+            continue;
+          }
+        }
+
+        if rotate.is_none() {
+          if let Ok(value) = input.try_parse(OffsetRotate::parse) {
+            rotate = Some(value);
+            continue;
+          }
+        }
+
+        break;
+      }
+    }
+
+    let anchor = if input.try_parse(|input| input.expect_delim('/')).is_ok() {
+      OffsetAnchor::parse(input)?
+    } else {
+      OffsetAnchor::default()
+    };
+
+    Ok(Offset {
+      path: path.unwrap_or_default(),
+      distance: distance.unwrap_or(LengthPercentage::zero()),
+      rotate: rotate.unwrap_or_default(),
+      anchor,
+      position: position.unwrap_or_default(),
+    })
+  }
+}
+
+impl<'i> ToCss for Offset<'i> {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    let has_position = self.position != OffsetPosition::default();
+    if has_position {
+      self.position.to_css(dest)?;
+    }
+
+    let has_distance = !self.distance.is_zero();
+    let has_rotate = self.rotate != OffsetRotate::default();
+    if !has_position || self.path != OffsetPath::default() || has_distance || has_rotate {
+      if has_position {
+        dest.write_char(' ')?;
+      }
+      self.path.to_css(dest)?;
+
+      if has_distance {
+        dest.write_char(' ')?;
+        self.distance.to_css(dest)?;
+      }
+
+      if has_rotate {
+        dest.write_char(' ')?;
+        self.rotate.to_css(dest)?;
+      }
+    }
+
+    if self.anchor != OffsetAnchor::default() {
+      dest.delim('/', true)?;
+      self.anchor.to_css(dest)?;
+    }
+
+    Ok(())
+  }
+}
+
+shorthand_handler!(OffsetHandler -> Offset<'i> {
+  path: OffsetPath(OffsetPath<'i>),
+  distance: OffsetDistance(LengthPercentage),
+  rotate: OffsetRotate(OffsetRotate),
+  anchor: OffsetAnchor(OffsetAnchor),
+  position: OffsetPosition(OffsetPosition),
+});
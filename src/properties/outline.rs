@@ -34,6 +34,10 @@ pub enum OutlineStyle {
 impl<'i> Parse<'i> for OutlineStyle {
   fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
     if let Ok(border_style) = input.try_parse(LineStyle::parse) {
+      // The `hidden` style is not allowed for outlines.
+      if border_style == LineStyle::Hidden {
+        return Err(input.new_custom_error(ParserError::InvalidValue));
+      }
       return Ok(OutlineStyle::LineStyle(border_style));
     }
 
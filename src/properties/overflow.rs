@@ -1,13 +1,20 @@
 //! CSS properties related to overflow.
 
+use super::display::{Display, DisplayInside, DisplayOutside, DisplayPair};
+use super::flex::BoxOrient;
 use super::{Property, PropertyId};
 use crate::compat::Feature;
 use crate::context::PropertyHandlerContext;
 use crate::declaration::{DeclarationBlock, DeclarationList};
 use crate::error::{ParserError, PrinterError};
 use crate::macros::{define_shorthand, enum_property};
+use crate::prefixes;
 use crate::printer::Printer;
-use crate::traits::{Parse, PropertyHandler, Shorthand, ToCss};
+use crate::traits::{Parse, PropertyHandler, Shorthand, ToCss, TrySign, Zero};
+use crate::values::length::Length;
+use crate::values::number::CSSInteger;
+use crate::values::string::CSSString;
+use crate::vendor_prefix::VendorPrefix;
 #[cfg(feature = "visitor")]
 use crate::visitor::Visit;
 use cssparser::*;
@@ -61,13 +68,287 @@ impl ToCss for Overflow {
   }
 }
 
+/// A value for the [overflow-clip-margin](https://www.w3.org/TR/css-overflow-3/#overflow-clip-margin) property.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(rename_all = "camelCase")
+)]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+pub struct OverflowClipMargin {
+  /// The box edge that the clip margin is measured from.
+  pub visual_box: VisualBox,
+  /// How far outside the box edge content may paint before being clipped.
+  pub offset: Length,
+}
+
+impl Default for OverflowClipMargin {
+  fn default() -> OverflowClipMargin {
+    OverflowClipMargin {
+      visual_box: VisualBox::PaddingBox,
+      offset: Length::zero(),
+    }
+  }
+}
+
+impl<'i> Parse<'i> for OverflowClipMargin {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    let mut visual_box = None;
+    let mut offset = None;
+    loop {
+      if visual_box.is_none() {
+        if let Ok(value) = input.try_parse(VisualBox::parse) {
+          visual_box = Some(value);
+          continue;
+        }
+      }
+
+      if offset.is_none() {
+        if let Ok(value) = input.try_parse(Length::parse) {
+          if value.is_sign_negative() {
+            return Err(input.new_custom_error(ParserError::InvalidValue));
+          }
+          offset = Some(value);
+          continue;
+        }
+      }
+
+      break;
+    }
+
+    if visual_box.is_none() && offset.is_none() {
+      return Err(input.new_custom_error(ParserError::InvalidDeclaration));
+    }
+
+    Ok(OverflowClipMargin {
+      visual_box: visual_box.unwrap_or(VisualBox::PaddingBox),
+      offset: offset.unwrap_or_else(Length::zero),
+    })
+  }
+}
+
+impl ToCss for OverflowClipMargin {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    if self.visual_box == VisualBox::PaddingBox {
+      return self.offset.to_css(dest);
+    }
+
+    self.visual_box.to_css(dest)?;
+    if !self.offset.is_zero() {
+      dest.write_char(' ')?;
+      self.offset.to_css(dest)?;
+    }
+    Ok(())
+  }
+}
+
 enum_property! {
-  /// A value for the [text-overflow](https://www.w3.org/TR/css-overflow-3/#text-overflow) property.
-  pub enum TextOverflow {
-    /// Overflowing text is clipped.
-    Clip,
-    /// Overflowing text is truncated with an ellipsis.
-    Ellipsis,
+  /// A [`<visual-box>`](https://www.w3.org/TR/css-box-4/#typedef-visual-box) value,
+  /// as used in the `overflow-clip-margin` property.
+  pub enum VisualBox {
+    /// The content box.
+    "content-box": ContentBox,
+    /// The padding box.
+    "padding-box": PaddingBox,
+    /// The border box.
+    "border-box": BorderBox,
+  }
+}
+
+/// A value for the [text-overflow](https://www.w3.org/TR/css-overflow-4/#text-overflow) property.
+///
+/// When a single value is given, it applies to the end line edge and the start edge is clipped.
+/// When two values are given, they apply to the left and right line edges respectively.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(rename_all = "camelCase")
+)]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+pub struct TextOverflow<'i> {
+  /// The first value.
+  #[cfg_attr(feature = "serde", serde(borrow))]
+  pub first: TextOverflowValue<'i>,
+  /// The optional second value.
+  pub second: Option<TextOverflowValue<'i>>,
+}
+
+impl<'i> Parse<'i> for TextOverflow<'i> {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    let first = TextOverflowValue::parse(input)?;
+    let second = input.try_parse(TextOverflowValue::parse).ok();
+    Ok(TextOverflow { first, second })
+  }
+}
+
+impl<'i> ToCss for TextOverflow<'i> {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    self.first.to_css(dest)?;
+    if let Some(second) = &self.second {
+      dest.write_char(' ')?;
+      second.to_css(dest)?;
+    }
+    Ok(())
+  }
+}
+
+/// A value for one side of the [text-overflow](https://www.w3.org/TR/css-overflow-4/#text-overflow) property.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "type", content = "value", rename_all = "kebab-case")
+)]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+pub enum TextOverflowValue<'i> {
+  /// Overflowing text is clipped.
+  Clip,
+  /// Overflowing text is truncated with an ellipsis.
+  Ellipsis,
+  /// Overflowing text is truncated with the given string.
+  #[cfg_attr(feature = "serde", serde(borrow))]
+  String(CSSString<'i>),
+}
+
+impl<'i> Parse<'i> for TextOverflowValue<'i> {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    if let Ok(s) = input.try_parse(CSSString::parse) {
+      return Ok(TextOverflowValue::String(s));
+    }
+
+    let location = input.current_source_location();
+    let ident = input.expect_ident()?;
+    match_ignore_ascii_case! { ident,
+      "clip" => Ok(TextOverflowValue::Clip),
+      "ellipsis" => Ok(TextOverflowValue::Ellipsis),
+      _ => Err(location.new_unexpected_token_error(Token::Ident(ident.clone())))
+    }
+  }
+}
+
+impl<'i> ToCss for TextOverflowValue<'i> {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    match self {
+      TextOverflowValue::Clip => dest.write_str("clip"),
+      TextOverflowValue::Ellipsis => dest.write_str("ellipsis"),
+      TextOverflowValue::String(s) => s.to_css(dest),
+    }
+  }
+}
+
+/// A value for the [line-clamp](https://www.w3.org/TR/css-overflow-4/#line-clamp) property.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "type", content = "value", rename_all = "kebab-case")
+)]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+pub enum LineClamp {
+  /// The number of lines is not limited.
+  #[default]
+  None,
+  /// The content is clamped after the given number of lines. Must be greater than zero.
+  Lines(CSSInteger),
+}
+
+impl<'i> Parse<'i> for LineClamp {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    if input.try_parse(|i| i.expect_ident_matching("none")).is_ok() {
+      return Ok(LineClamp::None);
+    }
+
+    let lines = CSSInteger::parse(input)?;
+    if lines < 1 {
+      return Err(input.new_custom_error(ParserError::InvalidValue));
+    }
+    Ok(LineClamp::Lines(lines))
+  }
+}
+
+impl ToCss for LineClamp {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    match self {
+      LineClamp::None => dest.write_str("none"),
+      LineClamp::Lines(lines) => lines.to_css(dest),
+    }
+  }
+}
+
+enum_property! {
+  /// A value for the [continue](https://www.w3.org/TR/css-overflow-4/#continue) property.
+  pub enum Continue {
+    /// Content continues to flow normally.
+    Auto,
+    /// Content after the region break is discarded.
+    Discard,
+  }
+}
+
+enum_property! {
+  /// An [overscroll-behavior](https://www.w3.org/TR/css-overscroll-1/#overscroll-behavior-properties) keyword,
+  /// as used in the `overscroll-behavior-*` and `overscroll-behavior` properties.
+  pub enum OverscrollBehaviorKeyword {
+    /// The default scroll overflow behavior, e.g. scroll chaining, occurs.
+    Auto,
+    /// Scroll chaining is prevented, but local overscroll effects are shown.
+    Contain,
+    /// Scroll chaining and local overscroll effects are both prevented.
+    None,
+  }
+}
+
+define_shorthand! {
+  /// A value for the [overscroll-behavior](https://www.w3.org/TR/css-overscroll-1/#overscroll-behavior-properties) shorthand property.
+  pub struct OverscrollBehavior {
+    /// The overscroll behavior for the x direction.
+    x: OverscrollBehaviorX(OverscrollBehaviorKeyword),
+    /// The overscroll behavior for the y direction.
+    y: OverscrollBehaviorY(OverscrollBehaviorKeyword),
+  }
+}
+
+impl<'i> Parse<'i> for OverscrollBehavior {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    let x = OverscrollBehaviorKeyword::parse(input)?;
+    let y = input.try_parse(OverscrollBehaviorKeyword::parse).unwrap_or(x);
+    Ok(OverscrollBehavior { x, y })
+  }
+}
+
+impl ToCss for OverscrollBehavior {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    self.x.to_css(dest)?;
+    if self.y != self.x {
+      dest.write_char(' ')?;
+      self.y.to_css(dest)?;
+    }
+    Ok(())
   }
 }
 
@@ -134,3 +415,163 @@ impl<'i> PropertyHandler<'i> for OverflowHandler {
     }
   }
 }
+
+#[derive(Default)]
+pub(crate) struct OverscrollBehaviorHandler {
+  x: Option<OverscrollBehaviorKeyword>,
+  y: Option<OverscrollBehaviorKeyword>,
+}
+
+impl<'i> PropertyHandler<'i> for OverscrollBehaviorHandler {
+  fn handle_property(
+    &mut self,
+    property: &Property<'i>,
+    dest: &mut DeclarationList<'i>,
+    context: &mut PropertyHandlerContext<'i, '_>,
+  ) -> bool {
+    use Property::*;
+
+    match property {
+      OverscrollBehaviorX(val) => self.x = Some(*val),
+      OverscrollBehaviorY(val) => self.y = Some(*val),
+      OverscrollBehavior(val) => {
+        self.x = Some(val.x);
+        self.y = Some(val.y);
+      }
+      // Logical properties may override the physical ones depending on the writing mode,
+      // so flush what we have to preserve the order.
+      OverscrollBehaviorBlock(..) | OverscrollBehaviorInline(..) => {
+        self.finalize(dest, context);
+        dest.push(property.clone());
+      }
+      Unparsed(val)
+        if matches!(
+          val.property_id,
+          PropertyId::OverscrollBehaviorX
+            | PropertyId::OverscrollBehaviorY
+            | PropertyId::OverscrollBehaviorBlock
+            | PropertyId::OverscrollBehaviorInline
+            | PropertyId::OverscrollBehavior
+        ) =>
+      {
+        self.finalize(dest, context);
+        dest.push(property.clone());
+      }
+      _ => return false,
+    }
+
+    true
+  }
+
+  fn finalize(&mut self, dest: &mut DeclarationList, _: &mut PropertyHandlerContext<'i, '_>) {
+    let x = std::mem::take(&mut self.x);
+    let y = std::mem::take(&mut self.y);
+
+    match (x, y) {
+      (Some(x), Some(y)) => dest.push(Property::OverscrollBehavior(OverscrollBehavior { x, y })),
+      _ => {
+        if let Some(x) = x {
+          dest.push(Property::OverscrollBehaviorX(x))
+        }
+
+        if let Some(y) = y {
+          dest.push(Property::OverscrollBehaviorY(y))
+        }
+      }
+    }
+  }
+}
+
+#[derive(Default)]
+pub(crate) struct LineClampHandler {
+  line_clamp: Option<(LineClamp, VendorPrefix)>,
+  has_display: bool,
+  has_box_display: bool,
+  has_box_orient: bool,
+}
+
+impl<'i> PropertyHandler<'i> for LineClampHandler {
+  fn handle_property(
+    &mut self,
+    property: &Property<'i>,
+    dest: &mut DeclarationList<'i>,
+    context: &mut PropertyHandlerContext<'i, '_>,
+  ) -> bool {
+    match property {
+      Property::LineClamp(val, vp) => {
+        if let Some((cur, prefixes)) = &mut self.line_clamp {
+          // If the value is the same, update the prefix.
+          // If the prefix is the same, then update the value.
+          if val == cur || prefixes.contains(*vp) {
+            *cur = *val;
+            *prefixes |= *vp;
+            return true;
+          }
+          self.finalize(dest, context);
+        }
+        self.line_clamp = Some((*val, *vp));
+      }
+      Property::Unparsed(val) if matches!(val.property_id, PropertyId::LineClamp(_)) => {
+        self.finalize(dest, context);
+        dest.push(property.clone());
+      }
+      // Keep track of whether the author set these, so the legacy fallback doesn't override them.
+      // They are still handled by the display and flex handlers.
+      Property::Display(display) => {
+        self.has_display = true;
+        self.has_box_display = matches!(
+          display,
+          Display::Pair(DisplayPair {
+            inside: DisplayInside::Box(..),
+            ..
+          })
+        );
+        return false;
+      }
+      Property::BoxOrient(..) => {
+        self.has_box_orient = true;
+        return false;
+      }
+      Property::Unparsed(val) => {
+        match val.property_id {
+          PropertyId::Display => {
+            self.has_display = true;
+            self.has_box_display = false;
+          }
+          PropertyId::BoxOrient(..) => self.has_box_orient = true,
+          _ => {}
+        }
+        return false;
+      }
+      _ => return false,
+    }
+
+    true
+  }
+
+  fn finalize(&mut self, dest: &mut DeclarationList<'i>, context: &mut PropertyHandlerContext<'i, '_>) {
+    let has_display = std::mem::take(&mut self.has_display);
+    let has_box_display = std::mem::take(&mut self.has_box_display);
+    let has_box_orient = std::mem::take(&mut self.has_box_orient);
+    if let Some((val, mut prefix)) = std::mem::take(&mut self.line_clamp) {
+      // -webkit-line-clamp only works together with the legacy -webkit-box display and a vertical
+      // -webkit-box-orient. If the author set one of these, add the other one.
+      if prefix == VendorPrefix::None {
+        prefix = context.targets.prefixes(prefix, prefixes::Feature::LineClamp);
+        if prefix.contains(VendorPrefix::WebKit) && matches!(val, LineClamp::Lines(..)) {
+          if has_box_orient && !has_display {
+            dest.push(Property::Display(Display::Pair(DisplayPair {
+              outside: DisplayOutside::Block,
+              inside: DisplayInside::Box(VendorPrefix::WebKit),
+              is_list_item: false,
+            })));
+          } else if has_box_display && !has_box_orient {
+            dest.push(Property::BoxOrient(BoxOrient::Vertical, VendorPrefix::WebKit));
+          }
+        }
+      }
+
+      dest.push(Property::LineClamp(val, prefix));
+    }
+  }
+}
@@ -91,7 +91,7 @@ pub enum ZIndex {
 
 impl<'i> Parse<'i> for ZIndex {
   fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
-    if let Ok(value) = input.expect_integer() {
+    if let Ok(value) = input.try_parse(CSSInteger::parse) {
       return Ok(ZIndex::Integer(value));
     }
 
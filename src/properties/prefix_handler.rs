@@ -65,14 +65,21 @@ define_prefixes! {
   BoxSizing,
   TabSize,
   Hyphens,
+  HyphenateCharacter,
+  InitialLetter,
   TextAlignLast,
-  TextDecorationSkipInk,
   TextOverflow,
   UserSelect,
   Appearance,
   ClipPath,
   BoxDecorationBreak,
   TextSizeAdjust,
+  TextOrientation,
+  TouchAction,
+  ObjectFit,
+  ObjectPosition,
+  ShapeMargin,
+  ShapeImageThreshold,
 }
 
 macro_rules! define_fallbacks {
@@ -181,6 +188,9 @@ define_fallbacks! {
   BackdropFilter(prefix),
   Fill,
   Stroke,
+  AccentColor,
+  Cursor,
+  ShapeOutside(prefix),
   CaretColor,
   Caret,
 }
@@ -0,0 +1,142 @@
+//! CSS properties related to ruby layout.
+
+use crate::error::{ParserError, PrinterError};
+use crate::macros::enum_property;
+use crate::printer::Printer;
+use crate::traits::{Parse, ToCss};
+#[cfg(feature = "visitor")]
+use crate::visitor::Visit;
+use cssparser::*;
+
+enum_property! {
+  /// A side keyword for the [ruby-position](https://www.w3.org/TR/css-ruby-1/#rubypos) property.
+  ///
+  /// See [RubyPosition](RubyPosition).
+  pub enum RubySide {
+    /// The ruby annotation is placed over the base text in horizontal writing modes,
+    /// and to the right in vertical writing modes.
+    Over,
+    /// The ruby annotation is placed under the base text in horizontal writing modes,
+    /// and to the left in vertical writing modes.
+    Under,
+  }
+}
+
+/// A value for the [ruby-position](https://www.w3.org/TR/css-ruby-1/#rubypos) property.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "type", rename_all = "kebab-case")
+)]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+pub enum RubyPosition {
+  /// The ruby annotation is placed on the given side of the base text.
+  Side {
+    /// The side to place the annotation on.
+    side: RubySide,
+    /// Whether successive levels of annotation alternate sides.
+    alternate: bool,
+  },
+  /// The ruby annotation is placed between base characters, on the right in horizontal writing modes.
+  InterCharacter,
+}
+
+impl Default for RubyPosition {
+  fn default() -> RubyPosition {
+    RubyPosition::Side {
+      side: RubySide::Over,
+      alternate: true,
+    }
+  }
+}
+
+fn parse_ruby_side<'i>(input: &mut Parser<'i, '_>) -> Result<RubySide, ParseError<'i, ParserError<'i>>> {
+  if let Ok(side) = input.try_parse(RubySide::parse) {
+    return Ok(side);
+  }
+
+  // Legacy values supported by older WebKit versions.
+  let location = input.current_source_location();
+  let ident = input.expect_ident()?;
+  match_ignore_ascii_case! { ident,
+    "before" => Ok(RubySide::Over),
+    "after" => Ok(RubySide::Under),
+    _ => Err(location.new_unexpected_token_error(
+      cssparser::Token::Ident(ident.clone())
+    ))
+  }
+}
+
+impl<'i> Parse<'i> for RubyPosition {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    if input.try_parse(|input| input.expect_ident_matching("inter-character")).is_ok() {
+      return Ok(RubyPosition::InterCharacter);
+    }
+
+    let mut alternate = input.try_parse(|input| input.expect_ident_matching("alternate")).is_ok();
+    let side = input.try_parse(parse_ruby_side).ok();
+    if !alternate {
+      alternate = input.try_parse(|input| input.expect_ident_matching("alternate")).is_ok();
+    }
+
+    if !alternate && side.is_none() {
+      return Err(input.new_custom_error(ParserError::InvalidDeclaration));
+    }
+
+    Ok(RubyPosition::Side {
+      // If alternate is specified without a side, over is assumed.
+      side: side.unwrap_or(RubySide::Over),
+      alternate,
+    })
+  }
+}
+
+impl ToCss for RubyPosition {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    match self {
+      RubyPosition::InterCharacter => dest.write_str("inter-character"),
+      RubyPosition::Side { side, alternate } => {
+        if *alternate {
+          dest.write_str("alternate")?;
+          if *side == RubySide::Over {
+            return Ok(());
+          }
+          dest.write_char(' ')?;
+        }
+        side.to_css(dest)
+      }
+    }
+  }
+}
+
+enum_property! {
+  /// A value for the [ruby-align](https://www.w3.org/TR/css-ruby-1/#ruby-align-property) property.
+  pub enum RubyAlign {
+    /// The content is aligned to the start edge of the container.
+    "start": Start,
+    /// The content is centered within the container.
+    "center": Center,
+    /// Extra space is distributed between the content.
+    "space-between": SpaceBetween,
+    /// Extra space is distributed between the content, and half of that space at each end.
+    "space-around": SpaceAround,
+  }
+}
+
+enum_property! {
+  /// A value for the [ruby-merge](https://www.w3.org/TR/css-ruby-1/#ruby-merge-property) property.
+  pub enum RubyMerge {
+    /// Each ruby annotation box is placed with its corresponding base box.
+    Separate,
+    /// Ruby annotation boxes are merged and placed over the whole ruby segment.
+    Merge,
+    /// The UA chooses between `separate` and `merge` based on the content.
+    Auto,
+  }
+}
@@ -0,0 +1,180 @@
+//! CSS properties related to shapes.
+
+use crate::error::{ParserError, PrinterError};
+use crate::macros::enum_property;
+use crate::printer::Printer;
+use crate::targets::{Browsers, Targets};
+use crate::traits::{FallbackValues, IsCompatible, Parse, ToCss, TrySign};
+use crate::values::color::ColorFallbackKind;
+use crate::values::length::LengthPercentage;
+use crate::values::{image::Image, shape::BasicShape};
+#[cfg(feature = "visitor")]
+use crate::visitor::Visit;
+use cssparser::*;
+
+enum_property! {
+  /// A [`<shape-box>`](https://www.w3.org/TR/css-shapes-1/#typedef-shape-box) value
+  /// as used in the `shape-outside` property.
+  #[derive(Default)]
+  pub enum ShapeBox {
+    /// The shape is defined by the margin box.
+    #[default]
+    "margin-box": MarginBox,
+    /// The shape is defined by the border box.
+    "border-box": BorderBox,
+    /// The shape is defined by the padding box.
+    "padding-box": PaddingBox,
+    /// The shape is defined by the content box.
+    "content-box": ContentBox,
+  }
+}
+
+/// A value for the [shape-outside](https://www.w3.org/TR/css-shapes-1/#shape-outside-property) property.
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "type", rename_all = "kebab-case")
+)]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+pub enum ShapeOutside<'i> {
+  /// The float area is unaffected.
+  #[default]
+  None,
+  /// An image whose alpha channel is used to extract the shape.
+  #[cfg_attr(
+    feature = "serde",
+    serde(borrow, with = "crate::serialization::ValueWrapper::<Image>")
+  )]
+  Image(Image<'i>),
+  /// A basic shape, positioned according to the reference box.
+  #[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+  Shape {
+    /// A basic shape.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    shape: Box<BasicShape<'i>>,
+    /// A reference box that the shape is positioned according to.
+    reference_box: ShapeBox,
+  },
+  /// A reference box.
+  #[cfg_attr(feature = "serde", serde(with = "crate::serialization::ValueWrapper::<ShapeBox>"))]
+  Box(ShapeBox),
+}
+
+impl<'i> Parse<'i> for ShapeOutside<'i> {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    if input.try_parse(|input| input.expect_ident_matching("none")).is_ok() {
+      return Ok(ShapeOutside::None);
+    }
+
+    if let Ok(shape) = input.try_parse(BasicShape::parse) {
+      let b = input.try_parse(ShapeBox::parse).unwrap_or_default();
+      return Ok(ShapeOutside::Shape {
+        shape: Box::new(shape),
+        reference_box: b,
+      });
+    }
+
+    if let Ok(b) = input.try_parse(ShapeBox::parse) {
+      if let Ok(shape) = input.try_parse(BasicShape::parse) {
+        return Ok(ShapeOutside::Shape {
+          shape: Box::new(shape),
+          reference_box: b,
+        });
+      }
+      return Ok(ShapeOutside::Box(b));
+    }
+
+    Ok(ShapeOutside::Image(Image::parse(input)?))
+  }
+}
+
+impl<'i> ToCss for ShapeOutside<'i> {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    match self {
+      ShapeOutside::None => dest.write_str("none"),
+      ShapeOutside::Image(image) => image.to_css(dest),
+      ShapeOutside::Shape {
+        shape,
+        reference_box: b,
+      } => {
+        shape.to_css(dest)?;
+        if *b != ShapeBox::default() {
+          dest.write_char(' ')?;
+          b.to_css(dest)?;
+        }
+        Ok(())
+      }
+      ShapeOutside::Box(b) => b.to_css(dest),
+    }
+  }
+}
+
+impl<'i> FallbackValues for ShapeOutside<'i> {
+  fn get_fallbacks(&mut self, targets: Targets) -> Vec<Self> {
+    match self {
+      ShapeOutside::Image(image) => {
+        // Only color fallbacks are needed. Prefixed gradients are not supported in shape-outside.
+        let fallbacks = image.get_necessary_fallbacks(targets);
+        let mut res = Vec::new();
+        if fallbacks.contains(ColorFallbackKind::RGB) {
+          res.push(ShapeOutside::Image(image.get_fallback(ColorFallbackKind::RGB)));
+        }
+
+        if fallbacks.contains(ColorFallbackKind::P3) {
+          res.push(ShapeOutside::Image(image.get_fallback(ColorFallbackKind::P3)));
+        }
+
+        if fallbacks.contains(ColorFallbackKind::LAB) {
+          *image = image.get_fallback(ColorFallbackKind::LAB);
+        }
+
+        res
+      }
+      _ => Vec::new(),
+    }
+  }
+}
+
+impl<'i> IsCompatible for ShapeOutside<'i> {
+  fn is_compatible(&self, browsers: Browsers) -> bool {
+    match self {
+      ShapeOutside::Image(image) => image.is_compatible(browsers),
+      _ => true,
+    }
+  }
+}
+
+/// A value for the [shape-margin](https://www.w3.org/TR/css-shapes-1/#shape-margin-property) property.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(transparent))]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+pub struct ShapeMargin(pub LengthPercentage);
+
+impl<'i> Parse<'i> for ShapeMargin {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    let location = input.current_source_location();
+    let value = LengthPercentage::parse(input)?;
+    if value.is_sign_negative() {
+      return Err(location.new_custom_error(ParserError::InvalidValue));
+    }
+
+    Ok(ShapeMargin(value))
+  }
+}
+
+impl ToCss for ShapeMargin {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    self.0.to_css(dest)
+  }
+}
@@ -2,14 +2,22 @@
 
 use crate::compat::Feature;
 use crate::context::PropertyHandlerContext;
-use crate::declaration::DeclarationList;
+use crate::declaration::{DeclarationBlock, DeclarationList};
 use crate::error::{ParserError, PrinterError};
 use crate::logical::PropertyCategory;
-use crate::macros::{enum_property, property_bitflags};
+use crate::macros::{define_shorthand, enum_property, property_bitflags, shorthand_handler};
+use crate::prefixes;
 use crate::printer::Printer;
+use crate::properties::transform::{Transform, TransformList};
 use crate::properties::{Property, PropertyId};
-use crate::traits::{IsCompatible, Parse, PropertyHandler, ToCss};
-use crate::values::length::LengthPercentage;
+use crate::targets::Features;
+use crate::traits::{IsCompatible, Parse, PropertyHandler, Shorthand, ToCss, TrySign};
+use crate::values::length::{Length, LengthPercentage};
+use crate::values::number::CSSNumber;
+use crate::values::percentage::{NumberOrPercentage, Percentage};
+use crate::values::position::{
+  HorizontalPosition, HorizontalPositionKeyword, Position, VerticalPosition, VerticalPositionKeyword,
+};
 use crate::values::ratio::Ratio;
 use crate::vendor_prefix::VendorPrefix;
 #[cfg(feature = "visitor")]
@@ -131,6 +139,24 @@ impl ToCss for Size {
   }
 }
 
+impl Size {
+  /// Returns a copy of the value with viewport units converted to classic `vw`, `vh`, `vmin`, or `vmax` units,
+  /// or `None` if the value does not contain any other viewport units.
+  fn get_viewport_fallback(&self) -> Option<Size> {
+    let fallback = match self {
+      Size::LengthPercentage(l) => Size::LengthPercentage(l.to_classic_viewport_units()),
+      Size::FitContentFunction(l) => Size::FitContentFunction(l.to_classic_viewport_units()),
+      _ => return None,
+    };
+
+    if fallback != *self {
+      Some(fallback)
+    } else {
+      None
+    }
+  }
+}
+
 impl IsCompatible for Size {
   fn is_compatible(&self, browsers: crate::targets::Browsers) -> bool {
     use Size::*;
@@ -265,6 +291,24 @@ impl ToCss for MaxSize {
   }
 }
 
+impl MaxSize {
+  /// Returns a copy of the value with viewport units converted to classic `vw`, `vh`, `vmin`, or `vmax` units,
+  /// or `None` if the value does not contain any other viewport units.
+  fn get_viewport_fallback(&self) -> Option<MaxSize> {
+    let fallback = match self {
+      MaxSize::LengthPercentage(l) => MaxSize::LengthPercentage(l.to_classic_viewport_units()),
+      MaxSize::FitContentFunction(l) => MaxSize::FitContentFunction(l.to_classic_viewport_units()),
+      _ => return None,
+    };
+
+    if fallback != *self {
+      Some(fallback)
+    } else {
+      None
+    }
+  }
+}
+
 impl IsCompatible for MaxSize {
   fn is_compatible(&self, browsers: crate::targets::Browsers) -> bool {
     use MaxSize::*;
@@ -306,6 +350,98 @@ enum_property! {
   }
 }
 
+enum_property! {
+  /// A value for the [interpolate-size](https://drafts.csswg.org/css-values-5/#interpolate-size) property.
+  pub enum InterpolateSize {
+    /// Only numeric sizes, e.g. lengths and percentages, can be interpolated.
+    "numeric-only": NumericOnly,
+    /// Intrinsic sizing keywords such as `auto` and `fit-content` can also be interpolated.
+    "allow-keywords": AllowKeywords,
+  }
+}
+
+/// A value for the [zoom](https://drafts.csswg.org/css-viewport/#zoom-property) property.
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "type", content = "value", rename_all = "kebab-case")
+)]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+pub enum Zoom {
+  /// The element is not magnified.
+  #[default]
+  Normal,
+  /// The element is magnified by the given factor.
+  Number(CSSNumber),
+  /// The element is magnified by the given percentage.
+  Percentage(Percentage),
+}
+
+impl<'i> Parse<'i> for Zoom {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    if input.try_parse(|input| input.expect_ident_matching("normal")).is_ok() {
+      return Ok(Zoom::Normal);
+    }
+
+    if let Ok(number) = input.try_parse(CSSNumber::parse) {
+      if number < 0.0 {
+        return Err(input.new_custom_error(ParserError::InvalidValue));
+      }
+      return Ok(Zoom::Number(number));
+    }
+
+    let percentage = Percentage::parse(input)?;
+    if percentage.is_sign_negative() {
+      return Err(input.new_custom_error(ParserError::InvalidValue));
+    }
+    Ok(Zoom::Percentage(percentage))
+  }
+}
+
+impl ToCss for Zoom {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    match self {
+      Zoom::Normal => dest.write_str("normal"),
+      Zoom::Number(number) => number.to_css(dest),
+      // A percentage is equivalent to a number, which is always shorter.
+      Zoom::Percentage(percentage) if dest.minify => percentage.0.to_css(dest),
+      Zoom::Percentage(percentage) => percentage.to_css(dest),
+    }
+  }
+}
+
+impl Zoom {
+  fn to_number(&self) -> CSSNumber {
+    match self {
+      Zoom::Normal => 1.0,
+      Zoom::Number(number) => *number,
+      Zoom::Percentage(percentage) => percentage.0,
+    }
+  }
+}
+
+enum_property! {
+  /// A value for the [object-fit](https://www.w3.org/TR/css-images-3/#the-object-fit) property.
+  pub enum ObjectFit {
+    /// The replaced content is sized to fill the element's content box, ignoring its aspect ratio.
+    "fill": Fill,
+    /// The replaced content is scaled to fit within the content box, preserving its aspect ratio.
+    "contain": Contain,
+    /// The replaced content is scaled to cover the entire content box, preserving its aspect ratio.
+    "cover": Cover,
+    /// The replaced content is not resized.
+    "none": None,
+    /// The replaced content is sized as if `none` or `contain` were specified, whichever is smaller.
+    "scale-down": ScaleDown,
+  }
+}
+
 /// A value for the [aspect-ratio](https://drafts.csswg.org/css-sizing-4/#aspect-ratio) property.
 #[derive(Debug, Clone, PartialEq)]
 #[cfg_attr(feature = "visitor", derive(Visit))]
@@ -358,6 +494,99 @@ impl ToCss for AspectRatio {
   }
 }
 
+/// A value for the [contain-intrinsic-width](https://drafts.csswg.org/css-sizing-4/#propdef-contain-intrinsic-width),
+/// [contain-intrinsic-height](https://drafts.csswg.org/css-sizing-4/#propdef-contain-intrinsic-height),
+/// [contain-intrinsic-block-size](https://drafts.csswg.org/css-sizing-4/#propdef-contain-intrinsic-block-size),
+/// and [contain-intrinsic-inline-size](https://drafts.csswg.org/css-sizing-4/#propdef-contain-intrinsic-inline-size) properties.
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+pub struct ContainIntrinsicLength {
+  /// The `auto` keyword. If the element has a last remembered size, it is used instead of the length.
+  pub auto: bool,
+  /// An explicit intrinsic size, or `None` for the `none` keyword.
+  pub length: Option<Length>,
+}
+
+impl<'i> Parse<'i> for ContainIntrinsicLength {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    let auto = input.try_parse(|i| i.expect_ident_matching("auto")).is_ok();
+    let length = if input.try_parse(|i| i.expect_ident_matching("none")).is_ok() {
+      None
+    } else {
+      let location = input.current_source_location();
+      let length = Length::parse(input)?;
+      if length.is_sign_negative() {
+        return Err(location.new_custom_error(ParserError::InvalidValue));
+      }
+      Some(length)
+    };
+
+    Ok(ContainIntrinsicLength { auto, length })
+  }
+}
+
+impl ToCss for ContainIntrinsicLength {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    if self.auto {
+      dest.write_str("auto ")?;
+    }
+
+    match &self.length {
+      Some(length) => length.to_css(dest),
+      None => dest.write_str("none"),
+    }
+  }
+}
+
+impl IsCompatible for ContainIntrinsicLength {
+  fn is_compatible(&self, browsers: crate::targets::Browsers) -> bool {
+    self.length.as_ref().map_or(true, |length| length.is_compatible(browsers))
+  }
+}
+
+define_shorthand! {
+  /// A value for the [contain-intrinsic-size](https://drafts.csswg.org/css-sizing-4/#propdef-contain-intrinsic-size) shorthand property.
+  pub struct ContainIntrinsicSize {
+    /// The intrinsic width.
+    width: ContainIntrinsicWidth(ContainIntrinsicLength),
+    /// The intrinsic height.
+    height: ContainIntrinsicHeight(ContainIntrinsicLength),
+  }
+}
+
+impl<'i> Parse<'i> for ContainIntrinsicSize {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    let width = ContainIntrinsicLength::parse(input)?;
+    let height = input.try_parse(ContainIntrinsicLength::parse).unwrap_or_else(|_| width.clone());
+    Ok(ContainIntrinsicSize { width, height })
+  }
+}
+
+impl ToCss for ContainIntrinsicSize {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    self.width.to_css(dest)?;
+    if self.height != self.width {
+      dest.write_char(' ')?;
+      self.height.to_css(dest)?;
+    }
+    Ok(())
+  }
+}
+
+shorthand_handler!(ContainIntrinsicSizeHandler -> ContainIntrinsicSize {
+  width: ContainIntrinsicWidth(ContainIntrinsicLength),
+  height: ContainIntrinsicHeight(ContainIntrinsicLength),
+});
+
 property_bitflags! {
   #[derive(Default)]
   struct SizeProperty: u16 {
@@ -491,6 +720,10 @@ impl SizeHandler {
     self.has_any = false;
     let logical_supported = !context.should_compile_logical(Feature::LogicalSize);
 
+    // Classic viewport units are only an approximation of the small, large, and dynamic
+    // viewport units, so fallbacks must be explicitly enabled.
+    let viewport_fallbacks = context.targets.include.contains(Features::ViewportUnits);
+
     macro_rules! prefix {
       ($prop: ident, $size: ident, $feature: ident) => {
         if !self.flushed_properties.contains(SizeProperty::$prop) {
@@ -513,6 +746,16 @@ impl SizeHandler {
             $size::FitContent(VendorPrefix::None) => prefix!($prop, $size, FitContent),
             _ => {}
           }
+
+          if viewport_fallbacks && matches!(context.targets.browsers, Some(targets) if !val.is_compatible(targets)) {
+            if let Some(fallback) = val.get_viewport_fallback() {
+              // Skip the fallback if it was already declared manually.
+              let fallback = Property::$prop(fallback);
+              if !dest.contains(&fallback) {
+                dest.push(fallback);
+              }
+            }
+          }
           dest.push(Property::$prop(val.clone()));
           self.flushed_properties.insert(SizeProperty::$prop);
         }
@@ -543,3 +786,67 @@ impl SizeHandler {
     logical!(MaxInlineSize, max_inline_size, MaxWidth, MaxSize);
   }
 }
+
+#[derive(Default)]
+pub(crate) struct ZoomHandler {
+  zoom: Option<Zoom>,
+}
+
+impl<'i> PropertyHandler<'i> for ZoomHandler {
+  fn handle_property(
+    &mut self,
+    property: &Property<'i>,
+    dest: &mut DeclarationList<'i>,
+    context: &mut PropertyHandlerContext<'i, '_>,
+  ) -> bool {
+    match property {
+      Property::Zoom(val) => self.zoom = Some(val.clone()),
+      Property::Unparsed(val) if matches!(val.property_id, PropertyId::Zoom) => {
+        self.finalize(dest, context);
+        dest.push(property.clone());
+      }
+      _ => return false,
+    }
+
+    true
+  }
+
+  fn finalize(&mut self, dest: &mut DeclarationList<'i>, context: &mut PropertyHandlerContext<'i, '_>) {
+    if let Some(zoom) = std::mem::take(&mut self.zoom) {
+      // Unlike zoom, transforms do not affect layout, so compiling zoom to a
+      // scale transform must be explicitly enabled. Browsers that support zoom would
+      // apply both, so the fallback is only added when none of the targets do. It would
+      // also override any transform declared in the same rule, so it is skipped in that case.
+      if context.targets.include.contains(Features::Zoom)
+        && matches!(context.targets.browsers, Some(targets) if !Feature::Zoom.is_partially_compatible(targets))
+        && zoom != Zoom::Normal
+        && !dest.iter().any(|property| {
+          matches!(
+            property.property_id(),
+            PropertyId::Transform(_) | PropertyId::TransformOrigin(_)
+          )
+        })
+      {
+        let scale = NumberOrPercentage::Number(zoom.to_number());
+        let transform = TransformList(vec![Transform::Scale(scale.clone(), scale)]);
+        let prefix = context.targets.prefixes(VendorPrefix::None, prefixes::Feature::Transform);
+        dest.push(Property::Transform(transform, prefix));
+
+        let origin = Position {
+          x: HorizontalPosition::Side {
+            side: HorizontalPositionKeyword::Left,
+            offset: None,
+          },
+          y: VerticalPosition::Side {
+            side: VerticalPositionKeyword::Top,
+            offset: None,
+          },
+        };
+        let prefix = context.targets.prefixes(VendorPrefix::None, prefixes::Feature::TransformOrigin);
+        dest.push(Property::TransformOrigin(origin, prefix));
+      }
+
+      dest.push(Property::Zoom(zoom));
+    }
+  }
+}
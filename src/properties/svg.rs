@@ -1,15 +1,25 @@
 //! CSS properties used in SVG.
 
+use super::{Property, PropertyId};
+use crate::compat::Feature;
+use crate::context::PropertyHandlerContext;
+use crate::declaration::DeclarationList;
 use crate::error::{ParserError, PrinterError};
 use crate::macros::enum_property;
 use crate::printer::Printer;
 use crate::targets::{Browsers, Targets};
-use crate::traits::{FallbackValues, IsCompatible, Parse, ToCss};
+use crate::traits::{FallbackValues, IsCompatible, Parse, PropertyHandler, ToCss, Zero};
+use crate::values::angle::Angle;
 use crate::values::length::LengthPercentage;
 use crate::values::{color::CssColor, url::Url};
+use crate::vendor_prefix::VendorPrefix;
 #[cfg(feature = "visitor")]
 use crate::visitor::Visit;
 use cssparser::*;
+use smallvec::SmallVec;
+
+#[cfg(feature = "serde")]
+use crate::values::string::CowArcStr;
 
 /// An SVG [`<paint>`](https://www.w3.org/TR/SVG2/painting.html#SpecifyingPaint) value
 /// used in the `fill` and `stroke` properties.
@@ -352,14 +362,450 @@ enum_property! {
   }
 }
 
+/// A value for the [image-rendering](https://www.w3.org/TR/css-images-3/#the-image-rendering) property.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+pub enum ImageRendering {
+  /// The UA can choose a tradeoff between speed and quality.
+  Auto,
+  /// The image should be scaled with an algorithm that maximizes the appearance of the image.
+  Smooth,
+  /// Same as `smooth`, but with a preference for higher quality scaling.
+  HighQuality,
+  /// The image should be scaled with an algorithm that preserves contrast and edges,
+  /// or the legacy `-webkit-optimize-contrast` or `-moz-crisp-edges` aliases.
+  CrispEdges(VendorPrefix),
+  /// The image should be scaled up with the nearest neighbor algorithm,
+  /// or the legacy `-o-pixelated` alias.
+  Pixelated(VendorPrefix),
+  /// The UA shall optimize speed over quality. Legacy SVG value.
+  OptimizeSpeed,
+  /// The UA shall optimize quality over speed. Legacy SVG value.
+  OptimizeQuality,
+}
+
+impl ImageRendering {
+  fn from_str(s: &str) -> Option<ImageRendering> {
+    Some(match_ignore_ascii_case! { s,
+      "auto" => ImageRendering::Auto,
+      "smooth" => ImageRendering::Smooth,
+      "high-quality" => ImageRendering::HighQuality,
+      "crisp-edges" => ImageRendering::CrispEdges(VendorPrefix::None),
+      "-webkit-optimize-contrast" => ImageRendering::CrispEdges(VendorPrefix::WebKit),
+      "-moz-crisp-edges" => ImageRendering::CrispEdges(VendorPrefix::Moz),
+      "pixelated" => ImageRendering::Pixelated(VendorPrefix::None),
+      "-o-pixelated" => ImageRendering::Pixelated(VendorPrefix::O),
+      "optimizespeed" => ImageRendering::OptimizeSpeed,
+      "optimizequality" => ImageRendering::OptimizeQuality,
+      _ => return None
+    })
+  }
+
+  fn to_str(self) -> &'static str {
+    use ImageRendering::*;
+    match self {
+      Auto => "auto",
+      Smooth => "smooth",
+      HighQuality => "high-quality",
+      CrispEdges(VendorPrefix::WebKit) => "-webkit-optimize-contrast",
+      CrispEdges(VendorPrefix::Moz) => "-moz-crisp-edges",
+      CrispEdges(_) => "crisp-edges",
+      Pixelated(VendorPrefix::O) => "-o-pixelated",
+      Pixelated(_) => "pixelated",
+      OptimizeSpeed => "optimizespeed",
+      OptimizeQuality => "optimizequality",
+    }
+  }
+
+  fn with_prefix(&self, prefix: VendorPrefix) -> ImageRendering {
+    match self {
+      ImageRendering::CrispEdges(_) => ImageRendering::CrispEdges(prefix),
+      ImageRendering::Pixelated(_) => ImageRendering::Pixelated(prefix),
+      _ => *self,
+    }
+  }
+}
+
+impl<'i> Parse<'i> for ImageRendering {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    let location = input.current_source_location();
+    let ident = input.expect_ident()?;
+    ImageRendering::from_str(ident)
+      .ok_or_else(|| location.new_unexpected_token_error(cssparser::Token::Ident(ident.clone())))
+  }
+}
+
+impl ToCss for ImageRendering {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    dest.write_str(self.to_str())
+  }
+}
+
+// Serialized as a plain keyword string, including the legacy aliases.
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl serde::Serialize for ImageRendering {
+  fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+  where
+    S: serde::Serializer,
+  {
+    serializer.serialize_str(self.to_str())
+  }
+}
+
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl<'de> serde::Deserialize<'de> for ImageRendering {
+  fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+  where
+    D: serde::Deserializer<'de>,
+  {
+    let s = CowArcStr::deserialize(deserializer)?;
+    ImageRendering::from_str(s.as_ref()).ok_or_else(|| serde::de::Error::custom("Could not parse value"))
+  }
+}
+
+#[cfg(feature = "jsonschema")]
+#[cfg_attr(docsrs, doc(cfg(feature = "jsonschema")))]
+impl schemars::JsonSchema for ImageRendering {
+  fn is_referenceable() -> bool {
+    true
+  }
+
+  fn json_schema(gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+    str::json_schema(gen)
+  }
+
+  fn schema_name() -> String {
+    "ImageRendering".into()
+  }
+}
+
+impl IsCompatible for ImageRendering {
+  fn is_compatible(&self, browsers: Browsers) -> bool {
+    match self {
+      ImageRendering::CrispEdges(VendorPrefix::None) => Feature::ImageRenderingCrispEdges.is_compatible(browsers),
+      ImageRendering::Pixelated(VendorPrefix::None) => Feature::ImageRenderingPixelated.is_compatible(browsers),
+      // The legacy keywords are only understood by a single browser engine each.
+      ImageRendering::CrispEdges(..) | ImageRendering::Pixelated(..) => false,
+      _ => true,
+    }
+  }
+}
+
+#[derive(Default)]
+pub(crate) struct ImageRenderingHandler {
+  image_rendering: Option<ImageRendering>,
+  flushed_prefixes: VendorPrefix,
+}
+
+impl<'i> PropertyHandler<'i> for ImageRenderingHandler {
+  fn handle_property(
+    &mut self,
+    property: &Property<'i>,
+    dest: &mut DeclarationList<'i>,
+    context: &mut PropertyHandlerContext<'i, '_>,
+  ) -> bool {
+    match property {
+      Property::ImageRendering(val) => {
+        let mut val = *val;
+
+        // Replace the legacy prefixed keywords with the standard ones if all targets support them.
+        if let ImageRendering::CrispEdges(prefix) | ImageRendering::Pixelated(prefix) = val {
+          let unprefixed = val.with_prefix(VendorPrefix::None);
+          if prefix != VendorPrefix::None
+            && matches!(context.targets.browsers, Some(targets) if unprefixed.is_compatible(targets))
+          {
+            val = unprefixed;
+          }
+        }
+
+        // If the value isn't supported across all targets, preserve the previous value as a fallback.
+        if self.image_rendering.is_some()
+          && matches!(context.targets.browsers, Some(targets) if !val.is_compatible(targets))
+        {
+          self.flush(dest, context);
+        }
+
+        self.image_rendering = Some(val);
+      }
+      Property::Unparsed(val) if matches!(val.property_id, PropertyId::ImageRendering) => {
+        self.finalize(dest, context);
+        dest.push(property.clone());
+      }
+      _ => return false,
+    }
+
+    true
+  }
+
+  fn finalize(&mut self, dest: &mut DeclarationList<'i>, context: &mut PropertyHandlerContext<'i, '_>) {
+    self.flush(dest, context);
+    self.flushed_prefixes = VendorPrefix::empty();
+  }
+}
+
+impl ImageRenderingHandler {
+  fn flush<'i>(&mut self, dest: &mut DeclarationList<'i>, context: &mut PropertyHandlerContext<'i, '_>) {
+    if let Some(val) = std::mem::take(&mut self.image_rendering) {
+      if let ImageRendering::CrispEdges(prefix) | ImageRendering::Pixelated(prefix) = val {
+        if prefix == VendorPrefix::None {
+          // The prefix data covers both keywords: `-webkit-optimize-contrast` and `-moz-crisp-edges`
+          // are aliases of `crisp-edges`, and `-o-pixelated` is an alias of `pixelated`.
+          let supported = match val {
+            ImageRendering::CrispEdges(_) => VendorPrefix::WebKit | VendorPrefix::Moz,
+            _ => VendorPrefix::O,
+          };
+          let prefixes = context
+            .targets
+            .prefixes(VendorPrefix::None, crate::prefixes::Feature::Pixelated)
+            & (supported - self.flushed_prefixes);
+          for prefix in prefixes {
+            dest.push(Property::ImageRendering(val.with_prefix(prefix)));
+          }
+        }
+        self.flushed_prefixes |= prefix;
+      }
+
+      dest.push(Property::ImageRendering(val));
+    }
+  }
+}
+
+/// A value for the [image-orientation](https://www.w3.org/TR/css-images-3/#the-image-orientation) property.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "type", rename_all = "kebab-case")
+)]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+pub enum ImageOrientation {
+  /// The orientation from the image's EXIF data is applied.
+  FromImage,
+  /// No additional rotation is applied.
+  None,
+  /// The image is rotated by the given angle, rounded to the nearest quarter turn,
+  /// and optionally flipped horizontally after rotation.
+  Angle {
+    /// The angle to rotate the image by.
+    angle: Angle,
+    /// Whether the image is flipped horizontally.
+    flip: bool,
+  },
+}
+
+impl<'i> Parse<'i> for ImageOrientation {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    if input.try_parse(|input| input.expect_ident_matching("from-image")).is_ok() {
+      return Ok(ImageOrientation::FromImage);
+    }
+
+    if input.try_parse(|input| input.expect_ident_matching("none")).is_ok() {
+      return Ok(ImageOrientation::None);
+    }
+
+    let mut angle = None;
+    let mut flip = false;
+    loop {
+      if angle.is_none() {
+        if let Ok(value) = input.try_parse(Angle::parse) {
+          angle = Some(value);
+          continue;
+        }
+      }
+
+      if !flip && input.try_parse(|input| input.expect_ident_matching("flip")).is_ok() {
+        flip = true;
+        continue;
+      }
+
+      break;
+    }
+
+    if angle.is_none() && !flip {
+      return Err(input.new_custom_error(ParserError::InvalidValue));
+    }
+
+    Ok(ImageOrientation::Angle {
+      angle: angle.unwrap_or(Angle::Deg(0.0)),
+      flip,
+    })
+  }
+}
+
+impl ToCss for ImageOrientation {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    match self {
+      ImageOrientation::FromImage => dest.write_str("from-image"),
+      ImageOrientation::None => dest.write_str("none"),
+      ImageOrientation::Angle { angle, flip } => {
+        if *flip && angle.is_zero() {
+          return dest.write_str("flip");
+        }
+
+        angle.to_css(dest)?;
+        if *flip {
+          dest.write_str(" flip")?;
+        }
+        Ok(())
+      }
+    }
+  }
+}
+
 enum_property! {
-  /// A value for the [image-rendering](https://www.w3.org/TR/SVG2/painting.html#ImageRendering) property.
-  pub enum ImageRendering {
-    /// The UA can choose a tradeoff between speed and quality.
-    Auto,
-    /// The UA shall optimize speed over quality.
-    OptimizeSpeed,
-    /// The UA shall optimize quality over speed.
-    OptimizeQuality,
+  /// A keyword for the [paint-order](https://www.w3.org/TR/SVG2/painting.html#PaintOrder) property.
+  ///
+  /// See [PaintOrder](PaintOrder).
+  pub enum PaintOrderKeyword {
+    /// The fill of the element.
+    Fill,
+    /// The stroke of the element.
+    Stroke,
+    /// The markers of the element.
+    Markers,
+  }
+}
+
+/// A value for the [paint-order](https://www.w3.org/TR/SVG2/painting.html#PaintOrder) property.
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "type", content = "value", rename_all = "kebab-case")
+)]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+pub enum PaintOrder {
+  /// The fill is painted first, followed by the stroke and markers.
+  #[default]
+  Normal,
+  /// The given components are painted first, in order. Any omitted components
+  /// are painted afterward, in their normal order.
+  Order(SmallVec<[PaintOrderKeyword; 3]>),
+}
+
+const DEFAULT_PAINT_ORDER: [PaintOrderKeyword; 3] = [
+  PaintOrderKeyword::Fill,
+  PaintOrderKeyword::Stroke,
+  PaintOrderKeyword::Markers,
+];
+
+impl<'i> Parse<'i> for PaintOrder {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    if input.try_parse(|input| input.expect_ident_matching("normal")).is_ok() {
+      return Ok(PaintOrder::Normal);
+    }
+
+    let mut order: SmallVec<[PaintOrderKeyword; 3]> = SmallVec::new();
+    while let Ok(keyword) = input.try_parse(PaintOrderKeyword::parse) {
+      if order.contains(&keyword) {
+        return Err(input.new_custom_error(ParserError::InvalidValue));
+      }
+      order.push(keyword);
+    }
+
+    if order.is_empty() {
+      return Err(input.new_error_for_next_token());
+    }
+
+    // Omitted components are painted in their normal order, so trailing
+    // components that already follow that order can be dropped.
+    for keyword in DEFAULT_PAINT_ORDER {
+      if !order.contains(&keyword) {
+        order.push(keyword);
+      }
+    }
+
+    while order.len() > 1 {
+      let last = order[order.len() - 1];
+      let implied = DEFAULT_PAINT_ORDER
+        .into_iter()
+        .find(|keyword| !order[..order.len() - 1].contains(keyword));
+      if implied != Some(last) {
+        break;
+      }
+      order.pop();
+    }
+
+    if order.len() == 1 && order[0] == PaintOrderKeyword::Fill {
+      return Ok(PaintOrder::Normal);
+    }
+
+    Ok(PaintOrder::Order(order))
+  }
+}
+
+impl ToCss for PaintOrder {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    match self {
+      PaintOrder::Normal => dest.write_str("normal"),
+      PaintOrder::Order(order) => {
+        let mut first = true;
+        for keyword in order {
+          if first {
+            first = false;
+          } else {
+            dest.write_char(' ')?;
+          }
+          keyword.to_css(dest)?;
+        }
+        Ok(())
+      }
+    }
+  }
+}
+
+enum_property! {
+  /// A value for the [vector-effect](https://www.w3.org/TR/SVG2/coords.html#VectorEffects) property.
+  pub enum VectorEffect {
+    /// No vector effect is applied.
+    "none": None,
+    /// The stroke width is not affected by transforms.
+    "non-scaling-stroke": NonScalingStroke,
+    /// The scale of the element's user coordinate system is not affected by transforms.
+    "non-scaling-size": NonScalingSize,
+    /// The rotation and skew of the element's user coordinate system is not affected by transforms.
+    "non-rotation": NonRotation,
+    /// The position of the element's user coordinate system is not affected by transforms.
+    "fixed-position": FixedPosition,
+  }
+}
+
+enum_property! {
+  /// A value for the [dominant-baseline](https://drafts.csswg.org/css-inline-3/#dominant-baseline-property) property.
+  pub enum DominantBaseline {
+    /// The baseline is determined by the writing mode.
+    "auto": Auto,
+    /// The bottom of the em box.
+    "text-bottom": TextBottom,
+    /// The alphabetic baseline.
+    "alphabetic": Alphabetic,
+    /// The ideographic-under baseline.
+    "ideographic": Ideographic,
+    /// Halfway between the alphabetic baseline and the x-height.
+    "middle": Middle,
+    /// Halfway between the ideographic-over and ideographic-under baselines.
+    "central": Central,
+    /// The mathematical baseline.
+    "mathematical": Mathematical,
+    /// The hanging baseline.
+    "hanging": Hanging,
+    /// The top of the em box.
+    "text-top": TextTop,
   }
 }
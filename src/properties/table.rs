@@ -0,0 +1,49 @@
+//! CSS properties related to table layout.
+
+use crate::error::{ParserError, PrinterError};
+use crate::macros::enum_property;
+use crate::printer::Printer;
+use crate::traits::{Parse, ToCss};
+#[cfg(feature = "visitor")]
+use crate::visitor::Visit;
+use cssparser::*;
+
+enum_property! {
+  /// A value for the [border-collapse](https://www.w3.org/TR/css-tables-3/#border-collapse-property) property.
+  pub enum BorderCollapse {
+    /// Each cell has its own borders.
+    Separate,
+    /// Borders are shared between adjacent cells.
+    Collapse,
+  }
+}
+
+enum_property! {
+  /// A value for the [caption-side](https://www.w3.org/TR/css-tables-3/#caption-side) property.
+  pub enum CaptionSide {
+    /// The caption is positioned above the table.
+    Top,
+    /// The caption is positioned below the table.
+    Bottom,
+  }
+}
+
+enum_property! {
+  /// A value for the [empty-cells](https://www.w3.org/TR/css-tables-3/#empty-cells) property.
+  pub enum EmptyCells {
+    /// Borders and backgrounds are drawn around empty cells.
+    Show,
+    /// No borders or backgrounds are drawn around empty cells.
+    Hide,
+  }
+}
+
+enum_property! {
+  /// A value for the [table-layout](https://www.w3.org/TR/css-tables-3/#table-layout-property) property.
+  pub enum TableLayout {
+    /// The table width and column widths are determined by the contents of the cells.
+    Auto,
+    /// The table width and column widths are determined by the first row of cells.
+    Fixed,
+  }
+}
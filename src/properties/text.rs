@@ -2,6 +2,7 @@
 
 #![allow(non_upper_case_globals)]
 
+use super::border::BorderSideWidth;
 use super::{Property, PropertyId};
 use crate::compat;
 use crate::context::PropertyHandlerContext;
@@ -11,10 +12,11 @@ use crate::macros::{define_shorthand, enum_property};
 use crate::prefixes::Feature;
 use crate::printer::Printer;
 use crate::targets::{should_compile, Browsers, Targets};
-use crate::traits::{FallbackValues, IsCompatible, Parse, PropertyHandler, Shorthand, ToCss, Zero};
+use crate::traits::{FallbackValues, IsCompatible, Parse, PropertyHandler, Shorthand, ToCss, TrySign, Zero};
 use crate::values::calc::{Calc, MathFunction};
 use crate::values::color::{ColorFallbackKind, CssColor};
 use crate::values::length::{Length, LengthPercentage, LengthValue};
+use crate::values::number::CSSInteger;
 use crate::values::percentage::Percentage;
 use crate::values::string::CSSString;
 use crate::vendor_prefix::VendorPrefix;
@@ -217,23 +219,344 @@ impl ToCss for TextTransform {
 }
 
 enum_property! {
-  /// A value for the [white-space](https://www.w3.org/TR/2021/CRD-css-text-3-20210422/#white-space-property) property.
-  pub enum WhiteSpace {
+  /// A value for the [white-space-collapse](https://www.w3.org/TR/css-text-4/#white-space-collapsing) property.
+  pub enum WhiteSpaceCollapse {
     /// Sequences of white space are collapsed into a single character.
-    "normal": Normal,
-    /// White space is not collapsed.
-    "pre": Pre,
-    /// White space is collapsed, but no line wrapping occurs.
-    "nowrap": NoWrap,
-    /// White space is preserved, but line wrapping occurs.
-    "pre-wrap": PreWrap,
-    /// Like pre-wrap, but with different line breaking rules.
+    "collapse": Collapse,
+    /// White space is removed.
+    "discard": Discard,
+    /// White space and segment breaks are preserved.
+    "preserve": Preserve,
+    /// Sequences of white space are collapsed, but segment breaks are preserved.
+    "preserve-breaks": PreserveBreaks,
+    /// White space is preserved, but segment breaks are collapsed.
+    "preserve-spaces": PreserveSpaces,
+    /// Like preserve, but preserved white space always takes up space and allows wrapping.
     "break-spaces": BreakSpaces,
-    /// White space is collapsed, but with different line breaking rules.
-    "pre-line": PreLine,
   }
 }
 
+enum_property! {
+  /// A value for the [text-wrap-mode](https://www.w3.org/TR/css-text-4/#text-wrap-mode) property.
+  pub enum TextWrapMode {
+    /// Lines may break at allowed soft wrap opportunities.
+    "wrap": Wrap,
+    /// Lines do not break, and content that does not fit overflows.
+    "nowrap": NoWrap,
+  }
+}
+
+enum_property! {
+  /// A value for the [text-wrap-style](https://www.w3.org/TR/css-text-4/#text-wrap-style) property.
+  pub enum TextWrapStyle {
+    /// The UA chooses a wrapping strategy that balances speed and quality.
+    "auto": Auto,
+    /// Line lengths are balanced across the block.
+    "balance": Balance,
+    /// Content before the line being edited is not reflowed.
+    "stable": Stable,
+    /// The UA favors a better layout over speed.
+    "pretty": Pretty,
+  }
+}
+
+define_shorthand! {
+  /// A value for the [text-wrap](https://www.w3.org/TR/css-text-4/#text-wrap) shorthand property.
+  pub struct TextWrap {
+    /// Whether lines may wrap.
+    mode: TextWrapMode(TextWrapMode),
+    /// How lines are wrapped.
+    style: TextWrapStyle(TextWrapStyle),
+  }
+}
+
+impl<'i> Parse<'i> for TextWrap {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    let mut mode = None;
+    let mut style = None;
+
+    loop {
+      if mode.is_none() {
+        if let Ok(value) = input.try_parse(TextWrapMode::parse) {
+          mode = Some(value);
+          continue;
+        }
+      }
+
+      if style.is_none() {
+        if let Ok(value) = input.try_parse(TextWrapStyle::parse) {
+          style = Some(value);
+          continue;
+        }
+      }
+
+      break;
+    }
+
+    if mode.is_none() && style.is_none() {
+      return Err(input.new_custom_error(ParserError::InvalidDeclaration));
+    }
+
+    Ok(TextWrap {
+      mode: mode.unwrap_or(TextWrapMode::Wrap),
+      style: style.unwrap_or(TextWrapStyle::Auto),
+    })
+  }
+}
+
+impl ToCss for TextWrap {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    if self.style == TextWrapStyle::Auto {
+      return self.mode.to_css(dest);
+    }
+
+    if self.mode != TextWrapMode::Wrap {
+      self.mode.to_css(dest)?;
+      dest.write_char(' ')?;
+    }
+
+    self.style.to_css(dest)
+  }
+}
+
+define_shorthand! {
+  /// A value for the [white-space](https://www.w3.org/TR/css-text-4/#white-space-property) shorthand property.
+  pub struct WhiteSpace {
+    /// How white space is collapsed.
+    collapse: WhiteSpaceCollapse(WhiteSpaceCollapse),
+    /// Whether lines may wrap.
+    wrap_mode: TextWrapMode(TextWrapMode),
+  }
+}
+
+impl<'i> Parse<'i> for WhiteSpace {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    // Legacy keywords that set both longhands at once.
+    let legacy: Result<_, ParseError<'i, ParserError<'i>>> = input.try_parse(|input| {
+      let location = input.current_source_location();
+      let ident = input.expect_ident()?;
+      Ok(match_ignore_ascii_case! { ident,
+        "normal" => (WhiteSpaceCollapse::Collapse, TextWrapMode::Wrap),
+        "pre" => (WhiteSpaceCollapse::Preserve, TextWrapMode::NoWrap),
+        "pre-wrap" => (WhiteSpaceCollapse::Preserve, TextWrapMode::Wrap),
+        "pre-line" => (WhiteSpaceCollapse::PreserveBreaks, TextWrapMode::Wrap),
+        _ => return Err(location.new_unexpected_token_error(Token::Ident(ident.clone())))
+      })
+    });
+    if let Ok((collapse, wrap_mode)) = legacy {
+      return Ok(WhiteSpace { collapse, wrap_mode });
+    }
+
+    let mut collapse = None;
+    let mut wrap_mode = None;
+
+    loop {
+      if collapse.is_none() {
+        if let Ok(value) = input.try_parse(WhiteSpaceCollapse::parse) {
+          collapse = Some(value);
+          continue;
+        }
+      }
+
+      if wrap_mode.is_none() {
+        if let Ok(value) = input.try_parse(TextWrapMode::parse) {
+          wrap_mode = Some(value);
+          continue;
+        }
+      }
+
+      break;
+    }
+
+    if collapse.is_none() && wrap_mode.is_none() {
+      return Err(input.new_custom_error(ParserError::InvalidDeclaration));
+    }
+
+    Ok(WhiteSpace {
+      collapse: collapse.unwrap_or(WhiteSpaceCollapse::Collapse),
+      wrap_mode: wrap_mode.unwrap_or(TextWrapMode::Wrap),
+    })
+  }
+}
+
+impl WhiteSpace {
+  /// Returns whether the value can be represented by a legacy white-space keyword.
+  fn is_legacy(&self) -> bool {
+    matches!(
+      (&self.collapse, &self.wrap_mode),
+      (WhiteSpaceCollapse::Collapse, _)
+        | (WhiteSpaceCollapse::Preserve, _)
+        | (WhiteSpaceCollapse::PreserveBreaks, TextWrapMode::Wrap)
+        | (WhiteSpaceCollapse::BreakSpaces, TextWrapMode::Wrap)
+    )
+  }
+}
+
+impl ToCss for WhiteSpace {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    // Serialize using the legacy keywords where possible, which are supported by all browsers.
+    match (&self.collapse, &self.wrap_mode) {
+      (WhiteSpaceCollapse::Collapse, TextWrapMode::Wrap) => dest.write_str("normal"),
+      (WhiteSpaceCollapse::Collapse, TextWrapMode::NoWrap) => dest.write_str("nowrap"),
+      (WhiteSpaceCollapse::Preserve, TextWrapMode::Wrap) => dest.write_str("pre-wrap"),
+      (WhiteSpaceCollapse::Preserve, TextWrapMode::NoWrap) => dest.write_str("pre"),
+      (WhiteSpaceCollapse::PreserveBreaks, TextWrapMode::Wrap) => dest.write_str("pre-line"),
+      (collapse, TextWrapMode::Wrap) => collapse.to_css(dest),
+      (collapse, wrap_mode) => {
+        collapse.to_css(dest)?;
+        dest.write_char(' ')?;
+        wrap_mode.to_css(dest)
+      }
+    }
+  }
+}
+
+#[derive(Default)]
+pub(crate) struct WhiteSpaceHandler {
+  white_space: Option<WhiteSpace>,
+  collapse: Option<WhiteSpaceCollapse>,
+  wrap_mode: Option<TextWrapMode>,
+  wrap_style: Option<TextWrapStyle>,
+  has_any: bool,
+}
+
+impl<'i> PropertyHandler<'i> for WhiteSpaceHandler {
+  fn handle_property(
+    &mut self,
+    property: &Property<'i>,
+    dest: &mut DeclarationList<'i>,
+    context: &mut PropertyHandlerContext<'i, '_>,
+  ) -> bool {
+    match property {
+      Property::WhiteSpaceCollapse(val) => self.collapse = Some(*val),
+      Property::TextWrapMode(val) => self.wrap_mode = Some(*val),
+      Property::TextWrapStyle(val) => self.wrap_style = Some(*val),
+      Property::WhiteSpace(val) if !supports_text_4_longhands(context) => {
+        // Browsers without the CSS Text 4 longhands only understand the white-space
+        // keywords, so keep the declaration as is and emit any longhands after it.
+        if self.collapse.is_some() || self.wrap_mode.is_some() || self.wrap_style.is_some() {
+          self.finalize(dest, context);
+        }
+        self.white_space = Some(val.clone());
+      }
+      Property::WhiteSpace(val) => {
+        self.collapse = Some(val.collapse);
+        self.wrap_mode = Some(val.wrap_mode);
+      }
+      Property::TextWrap(val) => {
+        self.wrap_mode = Some(val.mode);
+        self.wrap_style = Some(val.style);
+      }
+      Property::Unparsed(val)
+        if matches!(
+          val.property_id,
+          PropertyId::WhiteSpace
+            | PropertyId::WhiteSpaceCollapse
+            | PropertyId::TextWrap
+            | PropertyId::TextWrapMode
+            | PropertyId::TextWrapStyle
+        ) =>
+      {
+        self.finalize(dest, context);
+        dest.push(property.clone());
+      }
+      _ => return false,
+    }
+
+    self.has_any = true;
+    true
+  }
+
+  fn finalize(&mut self, dest: &mut DeclarationList<'i>, context: &mut PropertyHandlerContext<'i, '_>) {
+    if !self.has_any {
+      return;
+    }
+
+    self.has_any = false;
+
+    let mut white_space = std::mem::take(&mut self.white_space);
+    let mut collapse = std::mem::take(&mut self.collapse);
+    let mut wrap_mode = std::mem::take(&mut self.wrap_mode);
+    let wrap_style = std::mem::take(&mut self.wrap_style);
+
+    if !supports_text_4_longhands(context) && wrap_style.is_none() {
+      // Compile the longhands to a legacy white-space keyword where possible, merging
+      // them into a preceding white-space declaration. When a wrap style is set, the
+      // text-wrap shorthand is output below instead, which has wider support.
+      let base = white_space.clone().or_else(|| match (collapse, wrap_mode) {
+        (Some(collapse), Some(wrap_mode)) => Some(WhiteSpace { collapse, wrap_mode }),
+        _ => None,
+      });
+
+      if let Some(mut base) = base {
+        if let Some(collapse) = collapse {
+          base.collapse = collapse;
+        }
+        if let Some(wrap_mode) = wrap_mode {
+          base.wrap_mode = wrap_mode;
+        }
+
+        if base.is_legacy() {
+          white_space = Some(base);
+          collapse = None;
+          wrap_mode = None;
+        }
+      }
+    }
+
+    if let Some(white_space) = white_space {
+      dest.push(Property::WhiteSpace(white_space));
+    }
+
+    match (collapse, wrap_mode, wrap_style) {
+      (Some(collapse), Some(wrap_mode), wrap_style) if supports_text_4_longhands(context) => {
+        // Prefer the white-space shorthand, which serializes to legacy keywords supported everywhere.
+        dest.push(Property::WhiteSpace(WhiteSpace { collapse, wrap_mode }));
+        match wrap_style {
+          // text-wrap also resets text-wrap-mode to wrap, so use the shorthand when possible
+          // since older browsers only support text-wrap as a single keyword longhand.
+          Some(style) if wrap_mode == TextWrapMode::Wrap => {
+            dest.push(Property::TextWrap(TextWrap { mode: wrap_mode, style }))
+          }
+          Some(style) => dest.push(Property::TextWrapStyle(style)),
+          None => {}
+        }
+      }
+      (collapse, Some(mode), Some(style)) => {
+        if let Some(collapse) = collapse {
+          dest.push(Property::WhiteSpaceCollapse(collapse));
+        }
+
+        dest.push(Property::TextWrap(TextWrap { mode, style }));
+      }
+      (collapse, wrap_mode, wrap_style) => {
+        if let Some(collapse) = collapse {
+          dest.push(Property::WhiteSpaceCollapse(collapse));
+        }
+
+        if let Some(wrap_mode) = wrap_mode {
+          dest.push(Property::TextWrapMode(wrap_mode));
+        }
+
+        if let Some(wrap_style) = wrap_style {
+          dest.push(Property::TextWrapStyle(wrap_style));
+        }
+      }
+    }
+  }
+}
+
+fn supports_text_4_longhands(context: &PropertyHandlerContext) -> bool {
+  context.targets.is_compatible(compat::Feature::WhiteSpaceCollapse)
+    && context.targets.is_compatible(compat::Feature::TextWrapMode)
+}
+
 enum_property! {
   /// A value for the [word-break](https://www.w3.org/TR/2021/CRD-css-text-3-20210422/#word-break-property) property.
   pub enum WordBreak {
@@ -275,6 +598,150 @@ enum_property! {
   }
 }
 
+/// A value for the [hyphenate-character](https://www.w3.org/TR/css-text-4/#hyphenate-character) property.
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "type", content = "value", rename_all = "kebab-case")
+)]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+pub enum HyphenateCharacter<'i> {
+  /// The UA chooses an appropriate string based on the content language.
+  #[default]
+  Auto,
+  /// The given string is rendered at the end of a hyphenated line.
+  #[cfg_attr(feature = "serde", serde(borrow))]
+  String(CSSString<'i>),
+}
+
+impl<'i> Parse<'i> for HyphenateCharacter<'i> {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    if input.try_parse(|input| input.expect_ident_matching("auto")).is_ok() {
+      return Ok(HyphenateCharacter::Auto);
+    }
+
+    Ok(HyphenateCharacter::String(CSSString::parse(input)?))
+  }
+}
+
+impl<'i> ToCss for HyphenateCharacter<'i> {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    match self {
+      HyphenateCharacter::Auto => dest.write_str("auto"),
+      HyphenateCharacter::String(s) => s.to_css(dest),
+    }
+  }
+}
+
+/// A limit within the [hyphenate-limit-chars](https://www.w3.org/TR/css-text-4/#hyphenate-char-limits) property.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "type", content = "value", rename_all = "kebab-case")
+)]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+pub enum HyphenateLimit {
+  /// The UA chooses a value that adapts to the current layout.
+  Auto,
+  /// An explicit number of characters. Must be greater than zero.
+  Integer(CSSInteger),
+}
+
+impl<'i> Parse<'i> for HyphenateLimit {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    if input.try_parse(|i| i.expect_ident_matching("auto")).is_ok() {
+      return Ok(HyphenateLimit::Auto);
+    }
+
+    let count = CSSInteger::parse(input)?;
+    if count < 1 {
+      return Err(input.new_custom_error(ParserError::InvalidValue));
+    }
+    Ok(HyphenateLimit::Integer(count))
+  }
+}
+
+impl ToCss for HyphenateLimit {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    match self {
+      HyphenateLimit::Auto => dest.write_str("auto"),
+      HyphenateLimit::Integer(count) => count.to_css(dest),
+    }
+  }
+}
+
+/// A value for the [hyphenate-limit-chars](https://www.w3.org/TR/css-text-4/#hyphenate-char-limits) property.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(rename_all = "camelCase")
+)]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+pub struct HyphenateLimitChars {
+  /// The minimum number of characters in a hyphenated word.
+  pub word: HyphenateLimit,
+  /// The minimum number of characters before the hyphenation point.
+  pub before: HyphenateLimit,
+  /// The minimum number of characters after the hyphenation point.
+  pub after: HyphenateLimit,
+}
+
+impl Default for HyphenateLimitChars {
+  fn default() -> HyphenateLimitChars {
+    HyphenateLimitChars {
+      word: HyphenateLimit::Auto,
+      before: HyphenateLimit::Auto,
+      after: HyphenateLimit::Auto,
+    }
+  }
+}
+
+impl<'i> Parse<'i> for HyphenateLimitChars {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    let word = HyphenateLimit::parse(input)?;
+    // If the second value is missing, it is auto. If the third is missing, it is the same as the second.
+    let before = input.try_parse(HyphenateLimit::parse).unwrap_or(HyphenateLimit::Auto);
+    let after = input.try_parse(HyphenateLimit::parse).unwrap_or(before);
+    Ok(HyphenateLimitChars { word, before, after })
+  }
+}
+
+impl ToCss for HyphenateLimitChars {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    self.word.to_css(dest)?;
+    if self.after == self.before {
+      if self.before != HyphenateLimit::Auto {
+        dest.write_char(' ')?;
+        self.before.to_css(dest)?;
+      }
+      return Ok(());
+    }
+
+    dest.write_char(' ')?;
+    self.before.to_css(dest)?;
+    dest.write_char(' ')?;
+    self.after.to_css(dest)
+  }
+}
+
 enum_property! {
   /// A value for the [overflow-wrap](https://www.w3.org/TR/2021/CRD-css-text-3-20210422/#overflow-wrap-property) property.
   pub enum OverflowWrap {
@@ -487,6 +954,9 @@ pub enum TextSizeAdjust {
 impl<'i> Parse<'i> for TextSizeAdjust {
   fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
     if let Ok(p) = input.try_parse(Percentage::parse) {
+      if p.is_sign_negative() {
+        return Err(input.new_custom_error(ParserError::InvalidValue));
+      }
       return Ok(TextSizeAdjust::Percentage(p));
     }
 
@@ -909,25 +1379,491 @@ enum_property! {
   }
 }
 
-enum_property! {
-  /// A keyword for the [text-emphasis-style](https://www.w3.org/TR/2020/WD-css-text-decor-4-20200506/#text-emphasis-style-property) property.
-  ///
-  /// See [TextEmphasisStyle](TextEmphasisStyle).
-  pub enum TextEmphasisFillMode {
-    /// The shape is filled with solid color.
-    Filled,
-    /// The shape is hollow.
-    Open,
+impl TextDecorationSkipInk {
+  /// Returns the closest equivalent `text-decoration-skip` value.
+  fn to_skip(self) -> TextDecorationSkip {
+    match self {
+      TextDecorationSkipInk::Auto | TextDecorationSkipInk::All => TextDecorationSkip::Ink,
+      TextDecorationSkipInk::None => TextDecorationSkip::empty(),
+    }
   }
 }
 
-enum_property! {
-  /// A text emphasis shape for the [text-emphasis-style](https://www.w3.org/TR/2020/WD-css-text-decor-4-20200506/#text-emphasis-style-property) property.
+bitflags! {
+  /// A value for the legacy [text-decoration-skip](https://www.w3.org/TR/2020/WD-css-text-decor-4-20200506/#text-decoration-skipping) property.
   ///
-  /// See [TextEmphasisStyle](TextEmphasisStyle).
-  pub enum TextEmphasisShape {
-    /// Display small circles as marks.
-    "dot": Dot,
+  /// An empty value represents `none`. The `Auto` and `Ink` flags may not be combined with any other flags.
+  #[cfg_attr(feature = "visitor", derive(Visit))]
+  #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(from = "SerializedTextDecorationSkip", into = "SerializedTextDecorationSkip"))]
+  #[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+  #[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Clone, Copy)]
+  pub struct TextDecorationSkip: u8 {
+    /// The UA chooses which parts of the text are skipped.
+    const Auto           = 1 << 0;
+    /// Skip where glyphs are drawn, i.e. equivalent to `text-decoration-skip-ink: auto`.
+    const Ink            = 1 << 1;
+    /// Skip atomic inline-level boxes such as images or inline-blocks.
+    const Objects        = 1 << 2;
+    /// Skip spacing at the start of the line.
+    const LeadingSpaces  = 1 << 3;
+    /// Skip spacing at the end of the line.
+    const TrailingSpaces = 1 << 4;
+    /// Skip spacing at the start and end of the line. Equivalent to `leading-spaces trailing-spaces`.
+    const Spaces         = 1 << 7;
+    /// Start and end the line inwards from the content edge of the decorating box.
+    const Edges          = 1 << 5;
+    /// Skip over the box's margin, border, and padding areas.
+    const BoxDecoration  = 1 << 6;
+  }
+}
+
+impl Default for TextDecorationSkip {
+  fn default() -> TextDecorationSkip {
+    TextDecorationSkip::Objects
+  }
+}
+
+impl<'i> Parse<'i> for TextDecorationSkip {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    if input.try_parse(|input| input.expect_ident_matching("none")).is_ok() {
+      return Ok(TextDecorationSkip::empty());
+    }
+
+    if input.try_parse(|input| input.expect_ident_matching("auto")).is_ok() {
+      return Ok(TextDecorationSkip::Auto);
+    }
+
+    if input.try_parse(|input| input.expect_ident_matching("ink")).is_ok() {
+      return Ok(TextDecorationSkip::Ink);
+    }
+
+    let mut value = TextDecorationSkip::empty();
+    loop {
+      let flag: Result<_, ParseError<'i, ParserError<'i>>> = input.try_parse(|input| {
+        let location = input.current_source_location();
+        let ident = input.expect_ident()?;
+        let flag = match_ignore_ascii_case! { &ident,
+          "objects" => TextDecorationSkip::Objects,
+          "spaces" => TextDecorationSkip::Spaces,
+          "leading-spaces" => TextDecorationSkip::LeadingSpaces,
+          "trailing-spaces" => TextDecorationSkip::TrailingSpaces,
+          "edges" => TextDecorationSkip::Edges,
+          "box-decoration" => TextDecorationSkip::BoxDecoration,
+          _ => return Err(location.new_unexpected_token_error(
+            cssparser::Token::Ident(ident.clone())
+          ))
+        };
+
+        // Each value may only be specified once, and `spaces` is exclusive with its components.
+        let components = TextDecorationSkip::LeadingSpaces | TextDecorationSkip::TrailingSpaces;
+        if value.intersects(flag)
+          || (flag == TextDecorationSkip::Spaces && value.intersects(components))
+          || (components.contains(flag) && value.contains(TextDecorationSkip::Spaces))
+        {
+          return Err(location.new_custom_error(ParserError::InvalidValue));
+        }
+
+        Ok(flag)
+      });
+
+      if let Ok(flag) = flag {
+        value |= flag;
+      } else {
+        break;
+      }
+    }
+
+    if value.is_empty() {
+      return Err(input.new_custom_error(ParserError::InvalidDeclaration));
+    }
+
+    Ok(value)
+  }
+}
+
+impl ToCss for TextDecorationSkip {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    if self.is_empty() {
+      return dest.write_str("none");
+    }
+
+    if self.contains(TextDecorationSkip::Auto) {
+      return dest.write_str("auto");
+    }
+
+    if self.contains(TextDecorationSkip::Ink) {
+      return dest.write_str("ink");
+    }
+
+    let mut needs_space = false;
+    macro_rules! val {
+      ($val: ident, $str: expr) => {
+        #[allow(unused_assignments)]
+        if self.contains(TextDecorationSkip::$val) {
+          if needs_space {
+            dest.write_char(' ')?;
+          }
+          dest.write_str($str)?;
+          needs_space = true;
+        }
+      };
+    }
+
+    // Keywords are serialized in the order of the grammar.
+    val!(Objects, "objects");
+    val!(Spaces, "spaces");
+    val!(LeadingSpaces, "leading-spaces");
+    val!(TrailingSpaces, "trailing-spaces");
+    val!(Edges, "edges");
+    val!(BoxDecoration, "box-decoration");
+    Ok(())
+  }
+}
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(untagged))]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+enum SerializedTextDecorationSkip {
+  Exclusive(ExclusiveTextDecorationSkip),
+  Other(Vec<OtherTextDecorationSkip>),
+}
+
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(rename_all = "kebab-case")
+)]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+enum ExclusiveTextDecorationSkip {
+  None,
+  Auto,
+  Ink,
+}
+
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(rename_all = "kebab-case")
+)]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+enum OtherTextDecorationSkip {
+  Objects,
+  Spaces,
+  LeadingSpaces,
+  TrailingSpaces,
+  Edges,
+  BoxDecoration,
+}
+
+impl From<TextDecorationSkip> for SerializedTextDecorationSkip {
+  fn from(skip: TextDecorationSkip) -> Self {
+    if skip.is_empty() {
+      return Self::Exclusive(ExclusiveTextDecorationSkip::None);
+    }
+
+    if skip.contains(TextDecorationSkip::Auto) {
+      return Self::Exclusive(ExclusiveTextDecorationSkip::Auto);
+    }
+
+    if skip.contains(TextDecorationSkip::Ink) {
+      return Self::Exclusive(ExclusiveTextDecorationSkip::Ink);
+    }
+
+    let mut v = Vec::new();
+    macro_rules! other {
+      ($s: ident) => {
+        if skip.contains(TextDecorationSkip::$s) {
+          v.push(OtherTextDecorationSkip::$s)
+        }
+      };
+    }
+
+    other!(Objects);
+    other!(Spaces);
+    other!(LeadingSpaces);
+    other!(TrailingSpaces);
+    other!(Edges);
+    other!(BoxDecoration);
+    Self::Other(v)
+  }
+}
+
+impl From<SerializedTextDecorationSkip> for TextDecorationSkip {
+  fn from(skip: SerializedTextDecorationSkip) -> Self {
+    match skip {
+      SerializedTextDecorationSkip::Exclusive(v) => match v {
+        ExclusiveTextDecorationSkip::None => TextDecorationSkip::empty(),
+        ExclusiveTextDecorationSkip::Auto => TextDecorationSkip::Auto,
+        ExclusiveTextDecorationSkip::Ink => TextDecorationSkip::Ink,
+      },
+      SerializedTextDecorationSkip::Other(v) => {
+        let mut res = TextDecorationSkip::empty();
+        for val in v {
+          res |= match val {
+            OtherTextDecorationSkip::Objects => TextDecorationSkip::Objects,
+            OtherTextDecorationSkip::Spaces => TextDecorationSkip::Spaces,
+            OtherTextDecorationSkip::LeadingSpaces => TextDecorationSkip::LeadingSpaces,
+            OtherTextDecorationSkip::TrailingSpaces => TextDecorationSkip::TrailingSpaces,
+            OtherTextDecorationSkip::Edges => TextDecorationSkip::Edges,
+            OtherTextDecorationSkip::BoxDecoration => TextDecorationSkip::BoxDecoration,
+          }
+        }
+        res
+      }
+    }
+  }
+}
+
+#[cfg(feature = "jsonschema")]
+#[cfg_attr(docsrs, doc(cfg(feature = "jsonschema")))]
+impl<'a> schemars::JsonSchema for TextDecorationSkip {
+  fn is_referenceable() -> bool {
+    true
+  }
+
+  fn json_schema(gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+    SerializedTextDecorationSkip::json_schema(gen)
+  }
+
+  fn schema_name() -> String {
+    "TextDecorationSkip".into()
+  }
+}
+
+#[derive(Default)]
+pub(crate) struct TextDecorationSkipHandler {
+  skip: Option<(TextDecorationSkip, VendorPrefix)>,
+  skip_ink: Option<(TextDecorationSkipInk, VendorPrefix)>,
+}
+
+impl<'i> PropertyHandler<'i> for TextDecorationSkipHandler {
+  fn handle_property(
+    &mut self,
+    property: &Property<'i>,
+    dest: &mut DeclarationList<'i>,
+    context: &mut PropertyHandlerContext<'i, '_>,
+  ) -> bool {
+    macro_rules! property {
+      ($prop: ident, $val: expr, $vp: expr) => {{
+        if let Some((cur, prefixes)) = &mut self.$prop {
+          // If the value is the same, update the prefix.
+          // If the prefix is the same, then update the value.
+          if $val == cur || prefixes.contains(*$vp) {
+            *cur = *$val;
+            *prefixes |= *$vp;
+            return true;
+          }
+          self.finalize(dest, context);
+        }
+        self.$prop = Some((*$val, *$vp));
+      }};
+    }
+
+    match property {
+      Property::TextDecorationSkip(val, vp) => property!(skip, val, vp),
+      Property::TextDecorationSkipInk(val, vp) => property!(skip_ink, val, vp),
+      Property::Unparsed(val)
+        if matches!(
+          val.property_id,
+          PropertyId::TextDecorationSkip(_) | PropertyId::TextDecorationSkipInk(_)
+        ) =>
+      {
+        self.finalize(dest, context);
+        let feature = if matches!(val.property_id, PropertyId::TextDecorationSkip(_)) {
+          Feature::TextDecorationSkip
+        } else {
+          Feature::TextDecorationSkipInk
+        };
+        dest.push(Property::Unparsed(val.get_prefixed(context.targets, feature)));
+      }
+      _ => return false,
+    }
+
+    true
+  }
+
+  fn finalize(&mut self, dest: &mut DeclarationList<'i>, context: &mut PropertyHandlerContext<'i, '_>) {
+    let skip_ink_supported = context
+      .targets
+      .browsers
+      .map(|browsers| compat::Feature::TextDecorationSkipInk.is_compatible(browsers));
+    let mut skip = std::mem::take(&mut self.skip);
+    let mut skip_ink = std::mem::take(&mut self.skip_ink);
+
+    // If all targets support text-decoration-skip-ink, convert `text-decoration-skip: ink` to it.
+    if let Some((TextDecorationSkip::Ink, prefix)) = skip {
+      if skip_ink.is_none() && skip_ink_supported == Some(true) && prefix.contains(VendorPrefix::None) {
+        skip = None;
+        skip_ink = Some((TextDecorationSkipInk::Auto, VendorPrefix::None));
+      }
+    }
+
+    if let Some((val, prefix)) = skip {
+      let prefix = context.targets.prefixes(prefix, Feature::TextDecorationSkip);
+      dest.push(Property::TextDecorationSkip(val, prefix));
+    } else if let Some((val, prefix)) = &skip_ink {
+      // Older Safari versions only support text-decoration-skip, so add a fallback.
+      if skip_ink_supported == Some(false) && prefix.contains(VendorPrefix::None) {
+        let prefix = context.targets.prefixes(VendorPrefix::None, Feature::TextDecorationSkip);
+        dest.push(Property::TextDecorationSkip(val.to_skip(), prefix));
+      }
+    }
+
+    if let Some((val, prefix)) = skip_ink {
+      let prefix = context.targets.prefixes(prefix, Feature::TextDecorationSkipInk);
+      dest.push(Property::TextDecorationSkipInk(val, prefix));
+    }
+  }
+}
+
+/// A value for the [text-underline-offset](https://www.w3.org/TR/2020/WD-css-text-decor-4-20200506/#underline-offset) property.
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "type", content = "value", rename_all = "kebab-case")
+)]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+pub enum TextUnderlineOffset {
+  /// The UA chooses an appropriate offset for underlines.
+  #[default]
+  Auto,
+  /// An explicit offset from the alphabetic baseline.
+  LengthPercentage(LengthPercentage),
+}
+
+impl<'i> Parse<'i> for TextUnderlineOffset {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    if input.try_parse(|input| input.expect_ident_matching("auto")).is_ok() {
+      return Ok(TextUnderlineOffset::Auto);
+    }
+
+    let lp = LengthPercentage::parse(input)?;
+    Ok(TextUnderlineOffset::LengthPercentage(lp))
+  }
+}
+
+impl ToCss for TextUnderlineOffset {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    match self {
+      TextUnderlineOffset::Auto => dest.write_str("auto"),
+      TextUnderlineOffset::LengthPercentage(lp) => lp.to_css(dest),
+    }
+  }
+}
+
+enum_property! {
+  /// A side keyword for the [text-underline-position](https://www.w3.org/TR/2020/WD-css-text-decor-4-20200506/#text-underline-position-property) property.
+  ///
+  /// See [TextUnderlinePosition](TextUnderlinePosition).
+  pub enum TextUnderlineSide {
+    /// In vertical typographic modes, the underline is aligned as for `over`.
+    Left,
+    /// In vertical typographic modes, the underline is aligned as for `under`.
+    Right,
+  }
+}
+
+/// A value for the [text-underline-position](https://www.w3.org/TR/2020/WD-css-text-decor-4-20200506/#text-underline-position-property) property.
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "type", rename_all = "kebab-case")
+)]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+pub enum TextUnderlinePosition {
+  /// The UA chooses an appropriate position for underlines.
+  #[default]
+  Auto,
+  /// Use the position defined in the current font.
+  FromFont,
+  /// The `under` keyword, a side keyword, or both.
+  Keyword {
+    /// Whether the underline is positioned under the alphabetic baseline.
+    under: bool,
+    /// The side of the text the underline is positioned on in vertical typographic modes.
+    side: Option<TextUnderlineSide>,
+  },
+}
+
+impl<'i> Parse<'i> for TextUnderlinePosition {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    if input.try_parse(|input| input.expect_ident_matching("auto")).is_ok() {
+      return Ok(TextUnderlinePosition::Auto);
+    }
+
+    if input.try_parse(|input| input.expect_ident_matching("from-font")).is_ok() {
+      return Ok(TextUnderlinePosition::FromFont);
+    }
+
+    let mut under = input.try_parse(|input| input.expect_ident_matching("under")).is_ok();
+    let side = input.try_parse(TextUnderlineSide::parse).ok();
+    if !under {
+      under = input.try_parse(|input| input.expect_ident_matching("under")).is_ok();
+    }
+
+    if !under && side.is_none() {
+      return Err(input.new_custom_error(ParserError::InvalidDeclaration));
+    }
+
+    Ok(TextUnderlinePosition::Keyword { under, side })
+  }
+}
+
+impl ToCss for TextUnderlinePosition {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    match self {
+      TextUnderlinePosition::Auto => dest.write_str("auto"),
+      TextUnderlinePosition::FromFont => dest.write_str("from-font"),
+      TextUnderlinePosition::Keyword { under, side } => {
+        if *under {
+          dest.write_str("under")?;
+        }
+
+        if let Some(side) = side {
+          if *under {
+            dest.write_char(' ')?;
+          }
+          side.to_css(dest)?;
+        }
+        Ok(())
+      }
+    }
+  }
+}
+
+enum_property! {
+  /// A keyword for the [text-emphasis-style](https://www.w3.org/TR/2020/WD-css-text-decor-4-20200506/#text-emphasis-style-property) property.
+  ///
+  /// See [TextEmphasisStyle](TextEmphasisStyle).
+  pub enum TextEmphasisFillMode {
+    /// The shape is filled with solid color.
+    Filled,
+    /// The shape is hollow.
+    Open,
+  }
+}
+
+enum_property! {
+  /// A text emphasis shape for the [text-emphasis-style](https://www.w3.org/TR/2020/WD-css-text-decor-4-20200506/#text-emphasis-style-property) property.
+  ///
+  /// See [TextEmphasisStyle](TextEmphasisStyle).
+  pub enum TextEmphasisShape {
+    /// Display small circles as marks.
+    "dot": Dot,
     /// Display large circles as marks.
     "circle": Circle,
     /// Display double circles as marks.
@@ -1434,6 +2370,170 @@ impl<'i> PropertyHandler<'i> for TextDecorationHandler<'i> {
   }
 }
 
+define_shorthand! {
+  /// A value for the [-webkit-text-stroke](https://compat.spec.whatwg.org/#the-webkit-text-stroke) shorthand property.
+  pub struct WebKitTextStroke(VendorPrefix) {
+    /// The width of the stroke.
+    width: WebKitTextStrokeWidth(BorderSideWidth, VendorPrefix),
+    /// The color of the stroke.
+    color: WebKitTextStrokeColor(CssColor, VendorPrefix),
+  }
+}
+
+impl<'i> Parse<'i> for WebKitTextStroke {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    let mut width = None;
+    let mut color = None;
+
+    loop {
+      if width.is_none() {
+        if let Ok(w) = input.try_parse(BorderSideWidth::parse) {
+          width = Some(w);
+          continue;
+        }
+      }
+
+      if color.is_none() {
+        if let Ok(c) = input.try_parse(CssColor::parse) {
+          color = Some(c);
+          continue;
+        }
+      }
+
+      break;
+    }
+
+    if width.is_none() && color.is_none() {
+      return Err(input.new_custom_error(ParserError::InvalidDeclaration));
+    }
+
+    Ok(WebKitTextStroke {
+      width: width.unwrap_or(BorderSideWidth::Length(Length::zero())),
+      color: color.unwrap_or(CssColor::current_color()),
+    })
+  }
+}
+
+impl ToCss for WebKitTextStroke {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    let has_width = !matches!(&self.width, BorderSideWidth::Length(width) if width.is_zero());
+    let has_color = self.color != CssColor::current_color();
+    if has_width || !has_color {
+      self.width.to_css(dest)?;
+    }
+
+    if has_color {
+      if has_width {
+        dest.write_char(' ')?;
+      }
+      self.color.to_css(dest)?;
+    }
+
+    Ok(())
+  }
+}
+
+impl FallbackValues for WebKitTextStroke {
+  fn get_fallbacks(&mut self, targets: Targets) -> Vec<Self> {
+    self
+      .color
+      .get_fallbacks(targets)
+      .into_iter()
+      .map(|color| WebKitTextStroke { color, ..self.clone() })
+      .collect()
+  }
+}
+
+#[derive(Default)]
+pub(crate) struct WebKitTextStrokeHandler {
+  width: Option<(BorderSideWidth, VendorPrefix)>,
+  color: Option<(CssColor, VendorPrefix)>,
+  fill_color: Option<(CssColor, VendorPrefix)>,
+  has_any: bool,
+}
+
+impl<'i> PropertyHandler<'i> for WebKitTextStrokeHandler {
+  fn handle_property(
+    &mut self,
+    property: &Property<'i>,
+    dest: &mut DeclarationList<'i>,
+    context: &mut PropertyHandlerContext<'i, '_>,
+  ) -> bool {
+    use Property::*;
+
+    match property {
+      WebKitTextStrokeWidth(val, vp) => self.width = Some((val.clone(), *vp)),
+      WebKitTextStrokeColor(val, vp) => self.color = Some((val.clone(), *vp)),
+      WebKitTextStroke(val, vp) => {
+        self.width = Some((val.width.clone(), *vp));
+        self.color = Some((val.color.clone(), *vp));
+      }
+      WebKitTextFillColor(val, vp) => self.fill_color = Some((val.clone(), *vp)),
+      Unparsed(val)
+        if matches!(
+          val.property_id,
+          PropertyId::WebKitTextStrokeWidth(_)
+            | PropertyId::WebKitTextStrokeColor(_)
+            | PropertyId::WebKitTextStroke(_)
+            | PropertyId::WebKitTextFillColor(_)
+        ) =>
+      {
+        self.finalize(dest, context);
+        dest.push(property.clone());
+        return true;
+      }
+      _ => return false,
+    }
+
+    self.has_any = true;
+    true
+  }
+
+  fn finalize(&mut self, dest: &mut DeclarationList<'i>, context: &mut PropertyHandlerContext<'i, '_>) {
+    if !self.has_any {
+      return;
+    }
+
+    self.has_any = false;
+
+    let width = std::mem::take(&mut self.width);
+    let color = std::mem::take(&mut self.color);
+    let fill_color = std::mem::take(&mut self.fill_color);
+
+    macro_rules! color {
+      ($val: expr, $vp: expr, $prop: ident) => {{
+        let mut val = $val;
+        for fallback in val.get_fallbacks(context.targets) {
+          dest.push(Property::$prop(fallback, $vp))
+        }
+        dest.push(Property::$prop(val, $vp))
+      }};
+    }
+
+    match (width, color) {
+      (Some((width, width_vp)), Some((color, color_vp))) if width_vp == color_vp => {
+        color!(WebKitTextStroke { width, color }, width_vp, WebKitTextStroke)
+      }
+      (width, color) => {
+        if let Some((width, vp)) = width {
+          dest.push(Property::WebKitTextStrokeWidth(width, vp));
+        }
+
+        if let Some((color, vp)) = color {
+          color!(color, vp, WebKitTextStrokeColor);
+        }
+      }
+    }
+
+    if let Some((fill_color, vp)) = fill_color {
+      color!(fill_color, vp, WebKitTextFillColor);
+    }
+  }
+}
+
 /// A value for the [text-shadow](https://www.w3.org/TR/2020/WD-css-text-decor-4-20200506/#text-shadow-property) property.
 #[derive(Debug, Clone, PartialEq)]
 #[cfg_attr(feature = "visitor", derive(Visit))]
@@ -1468,6 +2568,9 @@ impl<'i> Parse<'i> for TextShadow {
           let horizontal = Length::parse(input)?;
           let vertical = Length::parse(input)?;
           let blur = input.try_parse(Length::parse).unwrap_or(Length::zero());
+          if blur.is_sign_negative() {
+            return Err(input.new_custom_error(ParserError::InvalidValue));
+          }
           let spread = input.try_parse(Length::parse).unwrap_or(Length::zero());
           Ok((horizontal, vertical, blur, spread))
         });
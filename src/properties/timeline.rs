@@ -0,0 +1,243 @@
+//! CSS properties related to scroll-driven animation timelines.
+
+use crate::declaration::DeclarationBlock;
+use crate::error::{ParserError, PrinterError};
+use crate::macros::{define_list_shorthand, enum_property};
+use crate::printer::Printer;
+use crate::properties::{Property, PropertyId};
+use crate::traits::{Parse, Shorthand, ToCss};
+use crate::values::ident::DashedIdent;
+use crate::values::length::LengthPercentageOrAuto;
+use crate::values::size::Size2D;
+#[cfg(feature = "visitor")]
+use crate::visitor::Visit;
+use cssparser::*;
+use itertools::izip;
+use smallvec::SmallVec;
+
+enum_property! {
+  /// A scroll axis, as used in the `scroll()` and `view()` functions and the
+  /// [scroll-timeline-axis](https://drafts.csswg.org/scroll-animations-1/#scroll-timeline-axis) and
+  /// [view-timeline-axis](https://drafts.csswg.org/scroll-animations-1/#view-timeline-axis) properties.
+  #[derive(Default)]
+  pub enum ScrollAxis {
+    /// The block axis of the scroll container.
+    #[default]
+    Block,
+    /// The inline axis of the scroll container.
+    Inline,
+    /// The horizontal axis of the scroll container.
+    X,
+    /// The vertical axis of the scroll container.
+    Y,
+  }
+}
+
+/// A timeline name, as used in the [scroll-timeline-name](https://drafts.csswg.org/scroll-animations-1/#scroll-timeline-name)
+/// and [view-timeline-name](https://drafts.csswg.org/scroll-animations-1/#view-timeline-name) properties.
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "type", content = "value", rename_all = "kebab-case")
+)]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+pub enum TimelineName<'i> {
+  /// The timeline has no name.
+  #[default]
+  None,
+  /// The name of the timeline.
+  #[cfg_attr(feature = "serde", serde(borrow))]
+  Name(DashedIdent<'i>),
+}
+
+impl<'i> Parse<'i> for TimelineName<'i> {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    if input.try_parse(|input| input.expect_ident_matching("none")).is_ok() {
+      return Ok(TimelineName::None);
+    }
+
+    Ok(TimelineName::Name(DashedIdent::parse(input)?))
+  }
+}
+
+impl<'i> ToCss for TimelineName<'i> {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    match self {
+      TimelineName::None => dest.write_str("none"),
+      TimelineName::Name(name) => name.to_css(dest),
+    }
+  }
+}
+
+/// A value for the [view-timeline-inset](https://drafts.csswg.org/scroll-animations-1/#view-timeline-inset) property,
+/// and the inset argument of the `view()` function.
+///
+/// The first value is the start inset, and the second value is the end inset.
+pub type ViewTimelineInset = Size2D<LengthPercentageOrAuto>;
+
+impl Default for ViewTimelineInset {
+  fn default() -> Self {
+    Size2D(LengthPercentageOrAuto::Auto, LengthPercentageOrAuto::Auto)
+  }
+}
+
+define_list_shorthand! {
+  /// A value for the [scroll-timeline](https://drafts.csswg.org/scroll-animations-1/#scroll-timeline-shorthand) shorthand property.
+  pub struct ScrollTimeline<'i> {
+    /// The name of the timeline.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    name: ScrollTimelineName(TimelineName<'i>),
+    /// The scroll axis of the timeline.
+    axis: ScrollTimelineAxis(ScrollAxis),
+  }
+}
+
+impl<'i> Parse<'i> for ScrollTimeline<'i> {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    let name = TimelineName::parse(input)?;
+    let axis = input.try_parse(ScrollAxis::parse).unwrap_or_default();
+    Ok(ScrollTimeline { name, axis })
+  }
+}
+
+impl<'i> ToCss for ScrollTimeline<'i> {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    self.name.to_css(dest)?;
+    if self.axis != ScrollAxis::default() {
+      dest.write_char(' ')?;
+      self.axis.to_css(dest)?;
+    }
+    Ok(())
+  }
+}
+
+define_list_shorthand! {
+  /// A value for the [view-timeline](https://drafts.csswg.org/scroll-animations-1/#view-timeline-shorthand) shorthand property.
+  pub struct ViewTimeline<'i> {
+    /// The name of the timeline.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    name: ViewTimelineName(TimelineName<'i>),
+    /// The scroll axis of the timeline.
+    axis: ViewTimelineAxis(ScrollAxis),
+    /// The insets of the view progress visibility range.
+    inset: ViewTimelineInset(ViewTimelineInset),
+  }
+}
+
+impl<'i> Parse<'i> for ViewTimeline<'i> {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    let name = TimelineName::parse(input)?;
+    let mut axis = None;
+    let mut inset = None;
+    loop {
+      if axis.is_none() {
+        if let Ok(value) = input.try_parse(ScrollAxis::parse) {
+          axis = Some(value);
+          continue;
+        }
+      }
+
+      if inset.is_none() {
+        if let Ok(value) = input.try_parse(ViewTimelineInset::parse) {
+          inset = Some(value);
+          continue;
+        }
+      }
+
+      break;
+    }
+
+    Ok(ViewTimeline {
+      name,
+      axis: axis.unwrap_or_default(),
+      inset: inset.unwrap_or_default(),
+    })
+  }
+}
+
+impl<'i> ToCss for ViewTimeline<'i> {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    self.name.to_css(dest)?;
+    if self.axis != ScrollAxis::default() {
+      dest.write_char(' ')?;
+      self.axis.to_css(dest)?;
+    }
+    if self.inset != ViewTimelineInset::default() {
+      dest.write_char(' ')?;
+      self.inset.to_css(dest)?;
+    }
+    Ok(())
+  }
+}
+
+/// A value for the [timeline-scope](https://drafts.csswg.org/scroll-animations-1/#timeline-scope) property.
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "type", content = "value", rename_all = "kebab-case")
+)]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+pub enum TimelineScope<'i> {
+  /// No timeline names are in scope.
+  #[default]
+  None,
+  /// All named timelines of descendants are in scope.
+  All,
+  /// The given timeline names are in scope.
+  #[cfg_attr(feature = "serde", serde(borrow))]
+  Names(SmallVec<[DashedIdent<'i>; 1]>),
+}
+
+impl<'i> Parse<'i> for TimelineScope<'i> {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    if input.try_parse(|input| input.expect_ident_matching("none")).is_ok() {
+      return Ok(TimelineScope::None);
+    }
+
+    if input.try_parse(|input| input.expect_ident_matching("all")).is_ok() {
+      return Ok(TimelineScope::All);
+    }
+
+    let names = input.parse_comma_separated(DashedIdent::parse)?;
+    Ok(TimelineScope::Names(names.into()))
+  }
+}
+
+impl<'i> ToCss for TimelineScope<'i> {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    match self {
+      TimelineScope::None => dest.write_str("none"),
+      TimelineScope::All => dest.write_str("all"),
+      TimelineScope::Names(names) => {
+        let mut first = true;
+        for name in names {
+          if first {
+            first = false;
+          } else {
+            dest.delim(',', false)?;
+          }
+          name.to_css(dest)?;
+        }
+        Ok(())
+      }
+    }
+  }
+}
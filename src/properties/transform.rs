@@ -1,6 +1,7 @@
 //! CSS properties related to 2D and 3D transforms.
 
 use super::{Property, PropertyId};
+use crate::compat;
 use crate::context::PropertyHandlerContext;
 use crate::declaration::DeclarationList;
 use crate::error::{ParserError, PrinterError};
@@ -8,7 +9,7 @@ use crate::macros::enum_property;
 use crate::prefixes::Feature;
 use crate::printer::Printer;
 use crate::stylesheet::PrinterOptions;
-use crate::traits::{Parse, PropertyHandler, ToCss, Zero};
+use crate::traits::{Parse, PropertyHandler, ToCss, TrySign, Zero};
 use crate::values::{
   angle::Angle,
   length::{Length, LengthPercentage},
@@ -254,6 +255,28 @@ impl Matrix<f32> {
       m44: 1.0,
     }
   }
+
+  /// Returns an equivalent translate or scale function, if the matrix represents only one of these.
+  fn to_simple_transform(&self) -> Option<Transform> {
+    if self.b != 0.0 || self.c != 0.0 {
+      return None;
+    }
+
+    if self.a == 1.0 && self.d == 1.0 {
+      Some(Transform::Translate(
+        LengthPercentage::px(self.e),
+        LengthPercentage::px(self.f),
+      ))
+    } else if self.e == 0.0 && self.f == 0.0 && self.a > 0.0 && self.d > 0.0 {
+      // Negative scales are not simplified because a matrix with them may be interpolated as a rotation.
+      Some(Transform::Scale(
+        NumberOrPercentage::Number(self.a),
+        NumberOrPercentage::Number(self.d),
+      ))
+    } else {
+      None
+    }
+  }
 }
 
 /// A 3D matrix.
@@ -491,6 +514,45 @@ impl Matrix3d<f32> {
     None
   }
 
+  /// Returns an equivalent translate3d or scale3d function, if the matrix represents only one of these.
+  fn to_simple_transform(&self) -> Option<Transform> {
+    let is_diagonal = self.m12 == 0.0
+      && self.m13 == 0.0
+      && self.m14 == 0.0
+      && self.m21 == 0.0
+      && self.m23 == 0.0
+      && self.m24 == 0.0
+      && self.m31 == 0.0
+      && self.m32 == 0.0
+      && self.m34 == 0.0
+      && self.m44 == 1.0;
+    if !is_diagonal {
+      return None;
+    }
+
+    if self.m11 == 1.0 && self.m22 == 1.0 && self.m33 == 1.0 {
+      Some(Transform::Translate3d(
+        LengthPercentage::px(self.m41),
+        LengthPercentage::px(self.m42),
+        Length::px(self.m43),
+      ))
+    } else if self.m41 == 0.0
+      && self.m42 == 0.0
+      && self.m43 == 0.0
+      && self.m11 > 0.0
+      && self.m22 > 0.0
+      && self.m33 > 0.0
+    {
+      Some(Transform::Scale3d(
+        NumberOrPercentage::Number(self.m11),
+        NumberOrPercentage::Number(self.m22),
+        NumberOrPercentage::Number(self.m33),
+      ))
+    } else {
+      None
+    }
+  }
+
   /// Scales the matrix by the given factor.
   pub fn scale_by_factor(&mut self, scaling_factor: f32) {
     self.m11 *= scaling_factor;
@@ -1235,7 +1297,14 @@ impl ToCss for Transform {
         len.to_css(dest)?;
         dest.write_char(')')
       }
-      Matrix(super::transform::Matrix { a, b, c, d, e, f }) => {
+      Matrix(matrix) => {
+        if dest.minify {
+          if let Some(transform) = matrix.to_simple_transform() {
+            return transform.to_css(dest);
+          }
+        }
+
+        let super::transform::Matrix { a, b, c, d, e, f } = matrix;
         dest.write_str("matrix(")?;
         a.to_css(dest)?;
         dest.delim(',', false)?;
@@ -1250,24 +1319,35 @@ impl ToCss for Transform {
         f.to_css(dest)?;
         dest.write_char(')')
       }
-      Matrix3d(super::transform::Matrix3d {
-        m11,
-        m12,
-        m13,
-        m14,
-        m21,
-        m22,
-        m23,
-        m24,
-        m31,
-        m32,
-        m33,
-        m34,
-        m41,
-        m42,
-        m43,
-        m44,
-      }) => {
+      Matrix3d(matrix) => {
+        if dest.minify {
+          if let Some(matrix) = matrix.to_matrix2d() {
+            return Matrix(matrix).to_css(dest);
+          }
+
+          if let Some(transform) = matrix.to_simple_transform() {
+            return transform.to_css(dest);
+          }
+        }
+
+        let super::transform::Matrix3d {
+          m11,
+          m12,
+          m13,
+          m14,
+          m21,
+          m22,
+          m23,
+          m24,
+          m31,
+          m32,
+          m33,
+          m34,
+          m41,
+          m42,
+          m43,
+          m44,
+        } = matrix;
         dest.write_str("matrix3d(")?;
         m11.to_css(dest)?;
         dest.delim(',', false)?;
@@ -1435,7 +1515,13 @@ impl<'i> Parse<'i> for Perspective {
       return Ok(Perspective::None);
     }
 
-    Ok(Perspective::Length(Length::parse(input)?))
+    let location = input.current_source_location();
+    let length = Length::parse(input)?;
+    if length.is_sign_negative() {
+      return Err(location.new_custom_error(ParserError::InvalidValue));
+    }
+
+    Ok(Perspective::Length(length))
   }
 }
 
@@ -1691,14 +1777,10 @@ impl<'i> PropertyHandler<'i> for TransformHandler {
     use Property::*;
 
     macro_rules! individual_property {
-      ($prop: ident, $val: ident) => {
-        if let Some((transform, _)) = &mut self.transform {
-          transform.0.push($val.to_transform())
-        } else {
-          self.$prop = Some($val.clone());
-          self.has_any = true;
-        }
-      };
+      ($prop: ident, $val: ident) => {{
+        self.$prop = Some($val.clone());
+        self.has_any = true;
+      }};
     }
 
     match property {
@@ -1765,7 +1847,61 @@ impl TransformHandler {
     let rotate = std::mem::take(&mut self.rotate);
     let scale = std::mem::take(&mut self.scale);
 
-    if let Some((transform, prefix)) = transform {
+    // If the individual transform properties are not supported by all targets,
+    // compile them into the transform property. They are applied in order before
+    // the functions in the transform property itself.
+    if (translate.is_some() || rotate.is_some() || scale.is_some())
+      && !context.targets.is_compatible(compat::Feature::IndividualTransforms)
+    {
+      let mut functions = Vec::new();
+      if let Some(translate) = &translate {
+        if !translate.x.is_zero() || !translate.y.is_zero() || !translate.z.is_zero() {
+          functions.push(if translate.z.is_zero() {
+            Transform::Translate(translate.x.clone(), translate.y.clone())
+          } else {
+            translate.to_transform()
+          });
+        }
+      }
+
+      if let Some(rotate) = &rotate {
+        if !rotate.angle.is_zero() {
+          functions.push(if rotate.x == 0.0 && rotate.y == 0.0 && rotate.z > 0.0 {
+            Transform::Rotate(rotate.angle.clone())
+          } else {
+            rotate.to_transform()
+          });
+        }
+      }
+
+      if let Some(scale) = &scale {
+        let z: f32 = (&scale.z).into();
+        if z != 1.0 {
+          functions.push(scale.to_transform());
+        } else {
+          let x: f32 = (&scale.x).into();
+          let y: f32 = (&scale.y).into();
+          if x != 1.0 || y != 1.0 {
+            functions.push(Transform::Scale(scale.x.clone(), scale.y.clone()));
+          }
+        }
+      }
+
+      match transform {
+        Some((transform, prefix)) => {
+          functions.extend(transform.0);
+          let prefix = context.targets.prefixes(prefix, Feature::Transform);
+          dest.push(Property::Transform(TransformList(functions), prefix));
+          return;
+        }
+        None if !functions.is_empty() => {
+          let prefix = context.targets.prefixes(VendorPrefix::None, Feature::Transform);
+          dest.push(Property::Transform(TransformList(functions), prefix));
+          return;
+        }
+        None => {}
+      }
+    } else if let Some((transform, prefix)) = transform {
       let prefix = context.targets.prefixes(prefix, Feature::Transform);
       dest.push(Property::Transform(transform, prefix))
     }
@@ -9,7 +9,7 @@ use crate::macros::define_list_shorthand;
 use crate::prefixes::Feature;
 use crate::printer::Printer;
 use crate::properties::masking::get_webkit_mask_property;
-use crate::traits::{Parse, PropertyHandler, Shorthand, ToCss, Zero};
+use crate::traits::{Parse, PropertyHandler, Shorthand, Sign, ToCss, Zero};
 use crate::values::{easing::EasingFunction, time::Time};
 use crate::vendor_prefix::VendorPrefix;
 #[cfg(feature = "visitor")]
@@ -72,6 +72,11 @@ impl<'i> Parse<'i> for Transition<'i> {
       break;
     }
 
+    // A negative transition-delay is valid, but a negative transition-duration is not.
+    if matches!(&duration, Some(duration) if duration.is_sign_negative()) {
+      return Err(input.new_custom_error(ParserError::InvalidValue));
+    }
+
     Ok(Transition {
       property: property.unwrap_or(PropertyId::All),
       duration: duration.unwrap_or(Time::Seconds(0.0)),
@@ -86,18 +91,31 @@ impl<'i> ToCss for Transition<'i> {
   where
     W: std::fmt::Write,
   {
-    self.property.to_css(dest)?;
-    if !self.duration.is_zero() || !self.delay.is_zero() {
-      dest.write_char(' ')?;
+    let has_duration = !self.duration.is_zero() || !self.delay.is_zero();
+    let has_timing_function = !self.timing_function.is_ease();
+    let has_delay = !self.delay.is_zero();
+
+    // `all` is the initial value, so it can be omitted unless it is the only component.
+    let has_property = self.property != PropertyId::All || !(has_duration || has_timing_function);
+    if has_property {
+      self.property.to_css(dest)?;
+    }
+
+    if has_duration {
+      if has_property {
+        dest.write_char(' ')?;
+      }
       self.duration.to_css(dest)?;
     }
 
-    if !self.timing_function.is_ease() {
-      dest.write_char(' ')?;
+    if has_timing_function {
+      if has_property || has_duration {
+        dest.write_char(' ')?;
+      }
       self.timing_function.to_css(dest)?;
     }
 
-    if !self.delay.is_zero() {
+    if has_delay {
       dest.write_char(' ')?;
       self.delay.to_css(dest)?;
     }
@@ -155,6 +173,12 @@ impl<'i> PropertyHandler<'i> for TransitionHandler<'i> {
 
     match property {
       TransitionProperty(val, vp) => property!(TransitionProperty, properties, val, vp),
+      TransitionDuration(val, _) if val.iter().any(|duration| duration.is_sign_negative()) => {
+        // Negative durations are invalid, so keep this declaration separate from
+        // the shorthand rather than letting it invalidate the merged transition.
+        self.flush(dest, context);
+        dest.push(property.clone());
+      }
       TransitionDuration(val, vp) => property!(TransitionDuration, durations, val, vp),
       TransitionDelay(val, vp) => property!(TransitionDelay, delays, val, vp),
       TransitionTimingFunction(val, vp) => property!(TransitionTimingFunction, timing_functions, val, vp),
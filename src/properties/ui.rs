@@ -4,15 +4,18 @@ use crate::compat::Feature;
 use crate::context::PropertyHandlerContext;
 use crate::declaration::{DeclarationBlock, DeclarationList};
 use crate::error::{ParserError, PrinterError};
-use crate::macros::{define_shorthand, enum_property, shorthand_property};
+use crate::macros::{define_shorthand, enum_property, shorthand_handler, shorthand_property};
 use crate::printer::Printer;
 use crate::properties::{Property, PropertyId};
 use crate::targets::{Browsers, Targets};
 use crate::traits::{FallbackValues, IsCompatible, Parse, PropertyHandler, Shorthand, ToCss};
 use crate::values::color::CssColor;
+use crate::values::ident::Ident;
+use crate::values::image::{Image, ImageSet};
 use crate::values::number::CSSNumber;
 use crate::values::string::CowArcStr;
 use crate::values::url::Url;
+use crate::vendor_prefix::VendorPrefix;
 #[cfg(feature = "visitor")]
 use crate::visitor::Visit;
 use bitflags::bitflags;
@@ -40,25 +43,100 @@ enum_property! {
   }
 }
 
+enum_property! {
+  /// A value for the [pointer-events](https://www.w3.org/TR/SVG2/interact.html#PointerEventsProperty) property.
+  ///
+  /// Only `auto` and `none` apply to HTML elements. The other values apply to SVG elements.
+  pub enum PointerEvents {
+    /// The element can be the target of pointer events. Behaves like `visiblepainted` on SVG elements.
+    "auto": Auto,
+    /// The element is never the target of pointer events.
+    "none": None,
+    /// The element can be targeted when visible and the pointer is over a painted fill or stroke.
+    "visiblepainted": VisiblePainted,
+    /// The element can be targeted when visible and the pointer is over the fill.
+    "visiblefill": VisibleFill,
+    /// The element can be targeted when visible and the pointer is over the stroke.
+    "visiblestroke": VisibleStroke,
+    /// The element can be targeted when visible and the pointer is over the fill or stroke.
+    "visible": Visible,
+    /// The element can be targeted when the pointer is over a painted fill or stroke.
+    "painted": Painted,
+    /// The element can be targeted when the pointer is over the fill.
+    "fill": Fill,
+    /// The element can be targeted when the pointer is over the stroke.
+    "stroke": Stroke,
+    /// The element can be targeted when the pointer is over the fill or stroke.
+    "all": All,
+    /// The element can be targeted when the pointer is over its bounding box.
+    "bounding-box": BoundingBox,
+  }
+}
+
+#[derive(Default)]
+pub(crate) struct ResizeHandler;
+
+impl<'i> PropertyHandler<'i> for ResizeHandler {
+  fn handle_property(
+    &mut self,
+    property: &Property<'i>,
+    dest: &mut DeclarationList<'i>,
+    context: &mut PropertyHandlerContext<'i, '_>,
+  ) -> bool {
+    match property {
+      Property::Resize(val @ (Resize::Block | Resize::Inline))
+        if context.should_compile_logical(Feature::LogicalResize) =>
+      {
+        // Logical values are mapped to physical values assuming a horizontal writing mode,
+        // consistent with other logical properties.
+        let physical = match val {
+          Resize::Block => Resize::Vertical,
+          _ => Resize::Horizontal,
+        };
+        dest.push(Property::Resize(physical));
+        true
+      }
+      _ => false,
+    }
+  }
+
+  fn finalize(&mut self, _: &mut DeclarationList<'i>, _: &mut PropertyHandlerContext<'i, '_>) {}
+}
+
 /// A [cursor image](https://www.w3.org/TR/2021/WD-css-ui-4-20210316/#cursor) value, used in the `cursor` property.
 ///
 /// See [Cursor](Cursor).
 #[derive(Debug, Clone, PartialEq)]
 #[cfg_attr(feature = "visitor", derive(Visit))]
 #[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(rename_all = "camelCase")
+)]
 #[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
 pub struct CursorImage<'i> {
-  /// A url to the cursor image.
+  /// A url to the cursor image. If `image_set` is present, this is the url of its first image.
   #[cfg_attr(feature = "serde", serde(borrow))]
   pub url: Url<'i>,
   /// The location in the image where the mouse pointer appears.
   pub hotspot: Option<(CSSNumber, CSSNumber)>,
+  /// An `image-set()` to use instead of `url`.
+  #[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "Option::is_none"))]
+  pub image_set: Option<ImageSet<'i>>,
 }
 
 impl<'i> Parse<'i> for CursorImage<'i> {
   fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
-    let url = Url::parse(input)?;
+    let location = input.current_source_location();
+    let (url, image_set) = if let Ok(image_set) = input.try_parse(ImageSet::parse) {
+      match image_set.options.first().map(|option| &option.image) {
+        Some(Image::Url(url)) => (url.clone(), Some(image_set)),
+        _ => return Err(location.new_custom_error(ParserError::InvalidValue)),
+      }
+    } else {
+      (Url::parse(input)?, None)
+    };
     let hotspot = if let Ok(x) = input.try_parse(CSSNumber::parse) {
       let y = CSSNumber::parse(input)?;
       Some((x, y))
@@ -66,7 +144,11 @@ impl<'i> Parse<'i> for CursorImage<'i> {
       None
     };
 
-    Ok(CursorImage { url, hotspot })
+    Ok(CursorImage {
+      url,
+      hotspot,
+      image_set,
+    })
   }
 }
 
@@ -75,7 +157,11 @@ impl<'i> ToCss for CursorImage<'i> {
   where
     W: std::fmt::Write,
   {
-    self.url.to_css(dest)?;
+    if let Some(image_set) = &self.image_set {
+      image_set.to_css(dest)?;
+    } else {
+      self.url.to_css(dest)?;
+    }
 
     if let Some((x, y)) = self.hotspot {
       dest.write_char(' ')?;
@@ -178,6 +264,52 @@ impl<'i> ToCss for Cursor<'i> {
   }
 }
 
+impl<'i> FallbackValues for Cursor<'i> {
+  fn get_fallbacks(&mut self, targets: Targets) -> Vec<Self> {
+    // Only unprefixed image-set() values need a prefixed fallback.
+    let mut prefixes = VendorPrefix::empty();
+    for image in &self.images {
+      if let Some(image_set) = &image.image_set {
+        if image_set.vendor_prefix == VendorPrefix::None {
+          prefixes |= image_set.get_necessary_prefixes(targets);
+        }
+      }
+    }
+
+    let mut res = Vec::new();
+    if prefixes.contains(VendorPrefix::WebKit) {
+      res.push(Cursor {
+        images: self
+          .images
+          .iter()
+          .map(|image| CursorImage {
+            url: image.url.clone(),
+            hotspot: image.hotspot,
+            image_set: image
+              .image_set
+              .as_ref()
+              .map(|image_set| image_set.get_prefixed(VendorPrefix::WebKit)),
+          })
+          .collect(),
+        keyword: self.keyword,
+      });
+    }
+
+    res
+  }
+}
+
+impl<'i> IsCompatible for Cursor<'i> {
+  fn is_compatible(&self, browsers: Browsers) -> bool {
+    self.images.iter().all(|image| {
+      image
+        .image_set
+        .as_ref()
+        .map_or(true, |image_set| image_set.is_compatible(browsers))
+    })
+  }
+}
+
 /// A value for the [caret-color](https://www.w3.org/TR/2021/WD-css-ui-4-20210316/#caret-color) property.
 #[derive(Debug, Clone, PartialEq)]
 #[cfg_attr(feature = "visitor", derive(Visit))]
@@ -266,6 +398,12 @@ impl Default for CaretShape {
   }
 }
 
+impl IsCompatible for CaretShape {
+  fn is_compatible(&self, _browsers: Browsers) -> bool {
+    true
+  }
+}
+
 shorthand_property! {
   /// A value for the [caret](https://www.w3.org/TR/2021/WD-css-ui-4-20210316/#caret) shorthand property.
   pub struct Caret {
@@ -296,6 +434,36 @@ impl IsCompatible for Caret {
   }
 }
 
+shorthand_handler!(CaretShorthandHandler -> Caret fallbacks: true {
+  color: CaretColor(ColorOrAuto, fallback: true),
+  shape: CaretShape(CaretShape),
+});
+
+/// Merges `caret-color` and `caret-shape` into the `caret` shorthand. Browsers that
+/// do not support the shorthand would drop the caret color along with it, so the
+/// longhands are left to the fallback handler for those targets.
+#[derive(Default)]
+pub(crate) struct CaretHandler(CaretShorthandHandler);
+
+impl<'i> PropertyHandler<'i> for CaretHandler {
+  fn handle_property(
+    &mut self,
+    property: &Property<'i>,
+    dest: &mut DeclarationList<'i>,
+    context: &mut PropertyHandlerContext<'i, '_>,
+  ) -> bool {
+    if !context.targets.is_compatible(Feature::CaretShorthand) {
+      return false;
+    }
+
+    self.0.handle_property(property, dest, context)
+  }
+
+  fn finalize(&mut self, dest: &mut DeclarationList<'i>, context: &mut PropertyHandlerContext<'i, '_>) {
+    self.0.finalize(dest, context);
+  }
+}
+
 enum_property! {
   /// A value for the [user-select](https://www.w3.org/TR/2021/WD-css-ui-4-20210316/#content-selection) property.
   pub enum UserSelect {
@@ -312,6 +480,16 @@ enum_property! {
   }
 }
 
+enum_property! {
+  /// A value for the [field-sizing](https://drafts.csswg.org/css-ui-4/#field-sizing) property.
+  pub enum FieldSizing {
+    /// The element's default preferred size is fixed, regardless of its contents.
+    Fixed,
+    /// The element's preferred size is determined by its contents.
+    Content,
+  }
+}
+
 /// A value for the [appearance](https://www.w3.org/TR/2021/WD-css-ui-4-20210316/#appearance-switching) property.
 #[derive(Debug, Clone, PartialEq)]
 #[cfg_attr(feature = "visitor", derive(Visit))]
@@ -571,6 +749,9 @@ impl<'i> PropertyHandler<'i> for ColorSchemeHandler {
   ) -> bool {
     match property {
       Property::ColorScheme(color_scheme) => {
+        // The variables are still needed when light-dark() is compiled to media queries, because
+        // only some properties are split, and `!important` declarations are never split.
+        // These may appear in any rule that inherits the color scheme, so always define them.
         if !context.targets.is_compatible(Feature::LightDark) {
           if color_scheme.contains(ColorScheme::Light) {
             dest.push(define_var("--lightningcss-light", Token::Ident("initial".into())));
@@ -585,6 +766,11 @@ impl<'i> PropertyHandler<'i> for ColorSchemeHandler {
             dest.push(define_var("--lightningcss-dark", Token::Ident("initial".into())));
           }
         }
+
+        if !color_scheme.is_empty() && needs_supported_color_schemes(context.targets) {
+          dest.push(supported_color_schemes(color_scheme));
+        }
+
         dest.push(property.clone());
         true
       }
@@ -602,3 +788,413 @@ fn define_var<'i>(name: &'static str, value: Token<'static>) -> Property<'i> {
     value: TokenList(vec![TokenOrValue::Token(value)]),
   })
 }
+
+/// Splits a declaration containing a `light-dark()` color into a declaration using the light color,
+/// and declarations using the dark color along with any necessary fallbacks.
+pub(crate) fn split_light_dark<'i>(
+  property: &Property<'i>,
+  targets: Targets,
+) -> Option<(Property<'i>, Vec<Property<'i>>)> {
+  macro_rules! split {
+    ($prop: ident, $color: expr $(, $vp: expr)?) => {
+      if let CssColor::LightDark(light, dark) = $color {
+        let mut dark = (**dark).clone();
+        let mut dark_properties: Vec<Property<'i>> = dark
+          .get_fallbacks(targets)
+          .into_iter()
+          .map(|color| Property::$prop(color $(, *$vp)?))
+          .collect();
+        dark_properties.push(Property::$prop(dark $(, *$vp)?));
+        Some((Property::$prop((**light).clone() $(, *$vp)?), dark_properties))
+      } else {
+        None
+      }
+    };
+  }
+
+  match property {
+    Property::Color(color) => split!(Color, color),
+    Property::BackgroundColor(color) => split!(BackgroundColor, color),
+    Property::BorderTopColor(color) => split!(BorderTopColor, color),
+    Property::BorderBottomColor(color) => split!(BorderBottomColor, color),
+    Property::BorderLeftColor(color) => split!(BorderLeftColor, color),
+    Property::BorderRightColor(color) => split!(BorderRightColor, color),
+    Property::OutlineColor(color) => split!(OutlineColor, color),
+    Property::ColumnRuleColor(color) => split!(ColumnRuleColor, color),
+    Property::TextDecorationColor(color, vp) => split!(TextDecorationColor, color, vp),
+    Property::TextEmphasisColor(color, vp) => split!(TextEmphasisColor, color, vp),
+    Property::Background(backgrounds) => {
+      // Only the color of the last layer can differ, so the dark rule only needs to override `background-color`.
+      let (light, dark) = split!(BackgroundColor, &backgrounds.last()?.color)?;
+      let mut backgrounds = backgrounds.clone();
+      if let Property::BackgroundColor(color) = light {
+        backgrounds.last_mut().unwrap().color = color;
+      }
+      Some((Property::Background(backgrounds), dark))
+    }
+    _ => None,
+  }
+}
+
+enum_property! {
+  /// A value for the [print-color-adjust](https://drafts.csswg.org/css-color-adjust/#print-color-adjust) property.
+  pub enum PrintColorAdjust {
+    /// The user agent may make adjustments to the element, e.g. to save ink when printing.
+    Economy,
+    /// The user agent should not make any adjustments to the element.
+    Exact,
+  }
+}
+
+enum_property! {
+  /// A value for the [forced-color-adjust](https://drafts.csswg.org/css-color-adjust/#forced-color-adjust-prop) property.
+  pub enum ForcedColorAdjust {
+    /// The element's colors are affected by forced colors mode.
+    "auto": Auto,
+    /// The element's colors are not affected by forced colors mode.
+    "none": None,
+    /// The element inherits its used color from its parent in forced colors mode.
+    "preserve-parent-color": PreserveParentColor,
+  }
+}
+
+#[derive(Default)]
+pub(crate) struct PrintColorAdjustHandler {
+  print_color_adjust: Option<(PrintColorAdjust, VendorPrefix)>,
+}
+
+impl<'i> PropertyHandler<'i> for PrintColorAdjustHandler {
+  fn handle_property(
+    &mut self,
+    property: &Property<'i>,
+    dest: &mut DeclarationList<'i>,
+    context: &mut PropertyHandlerContext<'i, '_>,
+  ) -> bool {
+    let (val, prefix) = match property {
+      Property::PrintColorAdjust(val, prefix) => (val, *prefix),
+      // Firefox supports the property under the legacy `color-adjust` name rather than with a prefix.
+      Property::ColorAdjust(val) => (val, VendorPrefix::Moz),
+      Property::Unparsed(val)
+        if matches!(
+          val.property_id,
+          PropertyId::PrintColorAdjust(_) | PropertyId::ColorAdjust
+        ) =>
+      {
+        self.finalize(dest, context);
+        dest.push(property.clone());
+        return true;
+      }
+      _ => return false,
+    };
+
+    if let Some((cur, prefixes)) = &mut self.print_color_adjust {
+      // If the value is the same, update the prefix.
+      // If the prefix is the same, then update the value.
+      if val == cur || prefixes.contains(prefix) {
+        *cur = *val;
+        *prefixes |= prefix;
+        return true;
+      }
+      self.finalize(dest, context);
+    }
+
+    self.print_color_adjust = Some((*val, prefix));
+    true
+  }
+
+  fn finalize(&mut self, dest: &mut DeclarationList<'i>, context: &mut PropertyHandlerContext<'i, '_>) {
+    if let Some((val, prefix)) = std::mem::take(&mut self.print_color_adjust) {
+      let mut prefixes = context.targets.prefixes(prefix, crate::prefixes::Feature::PrintColorAdjust);
+
+      if prefixes.contains(VendorPrefix::Moz) {
+        prefixes.remove(VendorPrefix::Moz);
+        dest.push(Property::ColorAdjust(val));
+      }
+
+      if !prefixes.is_empty() {
+        dest.push(Property::PrintColorAdjust(val, prefixes));
+      }
+    }
+  }
+}
+
+/// Safari 12.1 and iOS Safari 12.2 only support `color-scheme` under its legacy `supported-color-schemes` name.
+fn needs_supported_color_schemes(targets: Targets) -> bool {
+  targets.browsers.map_or(false, |browsers| {
+    matches!(browsers.safari, Some(version) if version >= (12 << 16) | (1 << 8) && version < 13 << 16)
+      || matches!(browsers.ios_saf, Some(version) if version >= (12 << 16) | (2 << 8) && version < 13 << 16)
+  })
+}
+
+fn supported_color_schemes<'i>(color_scheme: &ColorScheme) -> Property<'i> {
+  let mut tokens = Vec::new();
+  for (flag, name) in [
+    (ColorScheme::Light, "light"),
+    (ColorScheme::Dark, "dark"),
+    (ColorScheme::Only, "only"),
+  ] {
+    if color_scheme.contains(flag) {
+      if !tokens.is_empty() {
+        tokens.push(TokenOrValue::Token(Token::WhiteSpace(" ".into())));
+      }
+      tokens.push(TokenOrValue::Token(Token::Ident(name.into())));
+    }
+  }
+
+  Property::Custom(CustomProperty {
+    name: CustomPropertyName::Unknown(Ident("supported-color-schemes".into())),
+    value: TokenList(tokens),
+  })
+}
+
+bitflags! {
+  /// A value for the [touch-action](https://w3c.github.io/pointerevents/#the-touch-action-css-property) property.
+  ///
+  /// An empty value represents `none`. The `Auto` flag may not be combined with any other flags,
+  /// and at most one horizontal and one vertical panning flag may be set.
+  #[cfg_attr(feature = "visitor", derive(Visit))]
+  #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(from = "SerializedTouchAction", into = "SerializedTouchAction"))]
+  #[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+  #[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Clone, Copy)]
+  pub struct TouchAction: u8 {
+    /// All touch behaviors are permitted.
+    const Auto         = 1 << 0;
+    /// Horizontal panning is permitted.
+    const PanX         = 1 << 1;
+    /// Panning towards the left is permitted.
+    const PanLeft      = 1 << 2;
+    /// Panning towards the right is permitted.
+    const PanRight     = 1 << 3;
+    /// Vertical panning is permitted.
+    const PanY         = 1 << 4;
+    /// Panning upwards is permitted.
+    const PanUp        = 1 << 5;
+    /// Panning downwards is permitted.
+    const PanDown      = 1 << 6;
+    /// Multi-finger panning and zooming is permitted.
+    const PinchZoom    = 1 << 7;
+    /// Panning and pinch zooming are permitted, but not additional behaviors such as double-tap to zoom.
+    /// Equivalent to `pan-x pan-y pinch-zoom`.
+    const Manipulation = Self::PanX.bits() | Self::PanY.bits() | Self::PinchZoom.bits();
+  }
+}
+
+impl Default for TouchAction {
+  fn default() -> TouchAction {
+    TouchAction::Auto
+  }
+}
+
+impl TouchAction {
+  const HORIZONTAL: TouchAction = TouchAction::PanX.union(TouchAction::PanLeft).union(TouchAction::PanRight);
+  const VERTICAL: TouchAction = TouchAction::PanY.union(TouchAction::PanUp).union(TouchAction::PanDown);
+}
+
+impl<'i> Parse<'i> for TouchAction {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    if input.try_parse(|input| input.expect_ident_matching("auto")).is_ok() {
+      return Ok(TouchAction::Auto);
+    }
+
+    if input.try_parse(|input| input.expect_ident_matching("none")).is_ok() {
+      return Ok(TouchAction::empty());
+    }
+
+    if input.try_parse(|input| input.expect_ident_matching("manipulation")).is_ok() {
+      return Ok(TouchAction::Manipulation);
+    }
+
+    let mut value = TouchAction::empty();
+    loop {
+      let flag: Result<_, ParseError<'i, ParserError<'i>>> = input.try_parse(|input| {
+        let location = input.current_source_location();
+        let ident = input.expect_ident()?;
+        let (flag, group) = match_ignore_ascii_case! { &ident,
+          "pan-x" => (TouchAction::PanX, TouchAction::HORIZONTAL),
+          "pan-left" => (TouchAction::PanLeft, TouchAction::HORIZONTAL),
+          "pan-right" => (TouchAction::PanRight, TouchAction::HORIZONTAL),
+          "pan-y" => (TouchAction::PanY, TouchAction::VERTICAL),
+          "pan-up" => (TouchAction::PanUp, TouchAction::VERTICAL),
+          "pan-down" => (TouchAction::PanDown, TouchAction::VERTICAL),
+          "pinch-zoom" => (TouchAction::PinchZoom, TouchAction::PinchZoom),
+          _ => return Err(location.new_unexpected_token_error(
+            cssparser::Token::Ident(ident.clone())
+          ))
+        };
+
+        // Only one value from each group may be specified.
+        if value.intersects(group) {
+          return Err(location.new_custom_error(ParserError::InvalidValue));
+        }
+
+        Ok(flag)
+      });
+
+      if let Ok(flag) = flag {
+        value |= flag;
+      } else {
+        break;
+      }
+    }
+
+    if value.is_empty() {
+      return Err(input.new_custom_error(ParserError::InvalidDeclaration));
+    }
+
+    Ok(value)
+  }
+}
+
+impl ToCss for TouchAction {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    if self.is_empty() {
+      return dest.write_str("none");
+    }
+
+    if self.contains(TouchAction::Auto) {
+      return dest.write_str("auto");
+    }
+
+    if *self == TouchAction::Manipulation {
+      return dest.write_str("manipulation");
+    }
+
+    let mut needs_space = false;
+    macro_rules! val {
+      ($val: ident, $str: expr) => {
+        #[allow(unused_assignments)]
+        if self.contains(TouchAction::$val) {
+          if needs_space {
+            dest.write_char(' ')?;
+          }
+          dest.write_str($str)?;
+          needs_space = true;
+        }
+      };
+    }
+
+    val!(PanX, "pan-x");
+    val!(PanLeft, "pan-left");
+    val!(PanRight, "pan-right");
+    val!(PanY, "pan-y");
+    val!(PanUp, "pan-up");
+    val!(PanDown, "pan-down");
+    val!(PinchZoom, "pinch-zoom");
+    Ok(())
+  }
+}
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(untagged))]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+enum SerializedTouchAction {
+  Exclusive(ExclusiveTouchAction),
+  Other(Vec<OtherTouchAction>),
+}
+
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(rename_all = "kebab-case")
+)]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+enum ExclusiveTouchAction {
+  Auto,
+  None,
+  Manipulation,
+}
+
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(rename_all = "kebab-case")
+)]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+enum OtherTouchAction {
+  PanX,
+  PanLeft,
+  PanRight,
+  PanY,
+  PanUp,
+  PanDown,
+  PinchZoom,
+}
+
+impl From<TouchAction> for SerializedTouchAction {
+  fn from(t: TouchAction) -> Self {
+    if t.is_empty() {
+      return Self::Exclusive(ExclusiveTouchAction::None);
+    }
+
+    if t.contains(TouchAction::Auto) {
+      return Self::Exclusive(ExclusiveTouchAction::Auto);
+    }
+
+    if t == TouchAction::Manipulation {
+      return Self::Exclusive(ExclusiveTouchAction::Manipulation);
+    }
+
+    let mut v = Vec::new();
+    macro_rules! other {
+      ($t: ident) => {
+        if t.contains(TouchAction::$t) {
+          v.push(OtherTouchAction::$t)
+        }
+      };
+    }
+
+    other!(PanX);
+    other!(PanLeft);
+    other!(PanRight);
+    other!(PanY);
+    other!(PanUp);
+    other!(PanDown);
+    other!(PinchZoom);
+    Self::Other(v)
+  }
+}
+
+impl From<SerializedTouchAction> for TouchAction {
+  fn from(t: SerializedTouchAction) -> Self {
+    match t {
+      SerializedTouchAction::Exclusive(v) => match v {
+        ExclusiveTouchAction::Auto => TouchAction::Auto,
+        ExclusiveTouchAction::None => TouchAction::empty(),
+        ExclusiveTouchAction::Manipulation => TouchAction::Manipulation,
+      },
+      SerializedTouchAction::Other(v) => {
+        let mut res = TouchAction::empty();
+        for val in v {
+          res |= match val {
+            OtherTouchAction::PanX => TouchAction::PanX,
+            OtherTouchAction::PanLeft => TouchAction::PanLeft,
+            OtherTouchAction::PanRight => TouchAction::PanRight,
+            OtherTouchAction::PanY => TouchAction::PanY,
+            OtherTouchAction::PanUp => TouchAction::PanUp,
+            OtherTouchAction::PanDown => TouchAction::PanDown,
+            OtherTouchAction::PinchZoom => TouchAction::PinchZoom,
+          }
+        }
+        res
+      }
+    }
+  }
+}
+
+#[cfg(feature = "jsonschema")]
+#[cfg_attr(docsrs, doc(cfg(feature = "jsonschema")))]
+impl<'a> schemars::JsonSchema for TouchAction {
+  fn is_referenceable() -> bool {
+    true
+  }
+
+  fn json_schema(gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+    SerializedTouchAction::json_schema(gen)
+  }
+
+  fn schema_name() -> String {
+    "TouchAction".into()
+  }
+}
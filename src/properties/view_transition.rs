@@ -0,0 +1,127 @@
+//! CSS properties related to view transitions.
+
+use crate::error::{ParserError, PrinterError};
+use crate::printer::Printer;
+use crate::traits::{Parse, ToCss};
+use crate::values::ident::{CustomIdent, CustomIdentList};
+#[cfg(feature = "visitor")]
+use crate::visitor::Visit;
+use cssparser::*;
+use smallvec::SmallVec;
+
+/// A value for the [view-transition-name](https://drafts.csswg.org/css-view-transitions-1/#view-transition-name-prop) property.
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "type", content = "value", rename_all = "kebab-case")
+)]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+pub enum ViewTransitionName<'i> {
+  /// The element will not participate independently in a view transition.
+  #[default]
+  None,
+  /// The element participates with a name generated by the user agent.
+  Auto,
+  /// The element participates with the given name.
+  #[cfg_attr(feature = "serde", serde(borrow))]
+  Custom(CustomIdent<'i>),
+}
+
+impl<'i> Parse<'i> for ViewTransitionName<'i> {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    if input.try_parse(|input| input.expect_ident_matching("none")).is_ok() {
+      return Ok(ViewTransitionName::None);
+    }
+
+    if input.try_parse(|input| input.expect_ident_matching("auto")).is_ok() {
+      return Ok(ViewTransitionName::Auto);
+    }
+
+    Ok(ViewTransitionName::Custom(CustomIdent::parse(input)?))
+  }
+}
+
+impl<'i> ToCss for ViewTransitionName<'i> {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    match self {
+      ViewTransitionName::None => dest.write_str("none"),
+      ViewTransitionName::Auto => dest.write_str("auto"),
+      ViewTransitionName::Custom(name) => name.to_css(dest),
+    }
+  }
+}
+
+/// A value for the [view-transition-class](https://drafts.csswg.org/css-view-transitions-2/#view-transition-class-prop) property.
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "type", content = "value", rename_all = "kebab-case")
+)]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+pub enum ViewTransitionClass<'i> {
+  /// The element has no view transition classes.
+  #[default]
+  None,
+  /// A list of view transition class names.
+  #[cfg_attr(feature = "serde", serde(borrow))]
+  Classes(CustomIdentList<'i>),
+}
+
+impl<'i> Parse<'i> for ViewTransitionClass<'i> {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    if input.try_parse(|input| input.expect_ident_matching("none")).is_ok() {
+      return Ok(ViewTransitionClass::None);
+    }
+
+    let mut classes = SmallVec::new();
+    while let Ok(class) = input.try_parse(|input| {
+      // `none` is not a valid class name, so it may only appear on its own.
+      let location = input.current_source_location();
+      let class = CustomIdent::parse(input)?;
+      if class.0.eq_ignore_ascii_case("none") {
+        return Err(location.new_custom_error(ParserError::InvalidValue));
+      }
+      Ok(class)
+    }) {
+      classes.push(class);
+    }
+
+    if classes.is_empty() {
+      return Err(input.new_error_for_next_token());
+    }
+
+    Ok(ViewTransitionClass::Classes(classes))
+  }
+}
+
+impl<'i> ToCss for ViewTransitionClass<'i> {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    match self {
+      ViewTransitionClass::None => dest.write_str("none"),
+      ViewTransitionClass::Classes(classes) => {
+        let mut first = true;
+        for class in classes {
+          if first {
+            first = false;
+          } else {
+            dest.write_char(' ')?;
+          }
+          class.to_css(dest)?;
+        }
+        Ok(())
+      }
+    }
+  }
+}
@@ -0,0 +1,334 @@
+//! CSS properties related to writing modes.
+
+use super::{Property, PropertyId};
+use crate::context::PropertyHandlerContext;
+use crate::declaration::DeclarationList;
+use crate::error::{ParserError, PrinterError};
+use crate::macros::enum_property;
+use crate::prefixes::Feature;
+use crate::printer::Printer;
+use crate::targets::Browsers;
+use crate::traits::{IsCompatible, Parse, PropertyHandler, ToCss};
+use crate::vendor_prefix::VendorPrefix;
+#[cfg(feature = "visitor")]
+use crate::visitor::Visit;
+use cssparser::*;
+
+#[cfg(feature = "serde")]
+use crate::serialization::*;
+
+enum_property! {
+  /// A value for the [writing-mode](https://www.w3.org/TR/css-writing-modes-4/#block-flow) property.
+  ///
+  /// In addition to the standard values, the legacy values from SVG 1.1 and
+  /// `-ms-writing-mode` are supported. These compute to one of the standard values.
+  #[derive(Default)]
+  pub enum WritingMode {
+    /// Top-to-bottom block flow direction, with horizontal lines.
+    #[default]
+    "horizontal-tb": HorizontalTb,
+    /// Right-to-left block flow direction, with vertical lines.
+    "vertical-rl": VerticalRl,
+    /// Left-to-right block flow direction, with vertical lines.
+    "vertical-lr": VerticalLr,
+    /// Right-to-left block flow direction, with all glyphs set sideways.
+    "sideways-rl": SidewaysRl,
+    /// Left-to-right block flow direction, with all glyphs set sideways.
+    "sideways-lr": SidewaysLr,
+    /// Legacy value, equivalent to `horizontal-tb`.
+    "lr": Lr,
+    /// Legacy value, equivalent to `horizontal-tb`.
+    "lr-tb": LrTb,
+    /// Legacy value, equivalent to `horizontal-tb`.
+    "rl": Rl,
+    /// Legacy value, equivalent to `horizontal-tb`.
+    "rl-tb": RlTb,
+    /// Legacy value, equivalent to `vertical-rl`.
+    "tb": Tb,
+    /// Legacy value, equivalent to `vertical-rl`.
+    "tb-rl": TbRl,
+    /// Legacy value, equivalent to `vertical-lr`.
+    "tb-lr": TbLr,
+  }
+}
+
+impl WritingMode {
+  /// Returns the equivalent value supported by `-ms-writing-mode`, if any.
+  fn to_ms(self) -> Option<WritingMode> {
+    use WritingMode::*;
+    match self {
+      HorizontalTb => Some(LrTb),
+      VerticalRl => Some(TbRl),
+      VerticalLr => Some(TbLr),
+      SidewaysRl | SidewaysLr => None,
+      Lr | LrTb | Rl | RlTb | Tb | TbRl | TbLr => Some(self),
+    }
+  }
+}
+
+enum_property! {
+  /// A value for the [direction](https://www.w3.org/TR/css-writing-modes-4/#direction) property.
+  #[derive(Default)]
+  pub enum Direction {
+    /// Left-to-right inline base direction.
+    #[default]
+    Ltr,
+    /// Right-to-left inline base direction.
+    Rtl,
+  }
+}
+
+/// A value for the [unicode-bidi](https://www.w3.org/TR/css-writing-modes-4/#unicode-bidi) property.
+///
+/// The `isolate`, `isolate-override`, and `plaintext` values may also be
+/// written with a `-webkit-` or `-moz-` prefix for older browsers.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "type", rename_all = "kebab-case")
+)]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+pub enum UnicodeBidi {
+  /// The box does not open an additional level of embedding.
+  #[default]
+  Normal,
+  /// The box opens an additional level of embedding.
+  Embed,
+  /// The box is isolated from its surrounding content.
+  #[cfg_attr(feature = "serde", serde(with = "PrefixWrapper"))]
+  Isolate(VendorPrefix),
+  /// The box overrides the bidirectional algorithm with its `direction`.
+  BidiOverride,
+  /// Combines the behavior of `isolate` and `bidi-override`.
+  #[cfg_attr(feature = "serde", serde(with = "PrefixWrapper"))]
+  IsolateOverride(VendorPrefix),
+  /// Like `isolate`, but the direction is determined from the content.
+  #[cfg_attr(feature = "serde", serde(with = "PrefixWrapper"))]
+  Plaintext(VendorPrefix),
+}
+
+impl<'i> Parse<'i> for UnicodeBidi {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    let location = input.current_source_location();
+    let ident = input.expect_ident()?;
+    Ok(match_ignore_ascii_case! { ident,
+      "normal" => UnicodeBidi::Normal,
+      "embed" => UnicodeBidi::Embed,
+      "isolate" => UnicodeBidi::Isolate(VendorPrefix::None),
+      "-webkit-isolate" => UnicodeBidi::Isolate(VendorPrefix::WebKit),
+      "-moz-isolate" => UnicodeBidi::Isolate(VendorPrefix::Moz),
+      "bidi-override" => UnicodeBidi::BidiOverride,
+      "isolate-override" => UnicodeBidi::IsolateOverride(VendorPrefix::None),
+      "-webkit-isolate-override" => UnicodeBidi::IsolateOverride(VendorPrefix::WebKit),
+      "-moz-isolate-override" => UnicodeBidi::IsolateOverride(VendorPrefix::Moz),
+      "plaintext" => UnicodeBidi::Plaintext(VendorPrefix::None),
+      "-webkit-plaintext" => UnicodeBidi::Plaintext(VendorPrefix::WebKit),
+      "-moz-plaintext" => UnicodeBidi::Plaintext(VendorPrefix::Moz),
+      _ => return Err(location.new_unexpected_token_error(
+        cssparser::Token::Ident(ident.clone())
+      ))
+    })
+  }
+}
+
+impl ToCss for UnicodeBidi {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    use UnicodeBidi::*;
+    match self {
+      Normal => dest.write_str("normal"),
+      Embed => dest.write_str("embed"),
+      Isolate(vp) => {
+        vp.to_css(dest)?;
+        dest.write_str("isolate")
+      }
+      BidiOverride => dest.write_str("bidi-override"),
+      IsolateOverride(vp) => {
+        vp.to_css(dest)?;
+        dest.write_str("isolate-override")
+      }
+      Plaintext(vp) => {
+        vp.to_css(dest)?;
+        dest.write_str("plaintext")
+      }
+    }
+  }
+}
+
+impl UnicodeBidi {
+  /// Returns the vendor prefixes of the value, along with the feature used to compute
+  /// the prefixes required by the targets, if the value supports prefixes.
+  fn prefix(&self) -> Option<(VendorPrefix, Feature)> {
+    match self {
+      UnicodeBidi::Isolate(vp) => Some((*vp, Feature::Isolate)),
+      UnicodeBidi::IsolateOverride(vp) => Some((*vp, Feature::IsolateOverride)),
+      UnicodeBidi::Plaintext(vp) => Some((*vp, Feature::Plaintext)),
+      _ => None,
+    }
+  }
+
+  fn with_prefix(&self, prefix: VendorPrefix) -> UnicodeBidi {
+    match self {
+      UnicodeBidi::Isolate(_) => UnicodeBidi::Isolate(prefix),
+      UnicodeBidi::IsolateOverride(_) => UnicodeBidi::IsolateOverride(prefix),
+      UnicodeBidi::Plaintext(_) => UnicodeBidi::Plaintext(prefix),
+      _ => *self,
+    }
+  }
+}
+
+impl IsCompatible for UnicodeBidi {
+  fn is_compatible(&self, browsers: Browsers) -> bool {
+    use crate::compat::Feature;
+    match self {
+      UnicodeBidi::Isolate(VendorPrefix::None) => Feature::UnicodeBidiIsolate.is_compatible(browsers),
+      UnicodeBidi::IsolateOverride(VendorPrefix::None) => {
+        Feature::UnicodeBidiIsolateOverride.is_compatible(browsers)
+      }
+      UnicodeBidi::Plaintext(VendorPrefix::None) => Feature::UnicodeBidiPlaintext.is_compatible(browsers),
+      // Prefixed values are only understood by a single browser engine each.
+      UnicodeBidi::Isolate(..) | UnicodeBidi::IsolateOverride(..) | UnicodeBidi::Plaintext(..) => false,
+      _ => true,
+    }
+  }
+}
+
+enum_property! {
+  /// A value for the [text-orientation](https://www.w3.org/TR/css-writing-modes-4/#text-orientation) property.
+  #[derive(Default)]
+  pub enum TextOrientation {
+    /// Typographic characters from horizontal-only scripts are set sideways,
+    /// and characters from vertical scripts are set upright.
+    #[default]
+    "mixed": Mixed,
+    /// All typographic characters are set upright.
+    "upright": Upright,
+    /// All typographic characters are set sideways.
+    "sideways": Sideways,
+    /// Legacy value, equivalent to `sideways`.
+    "sideways-right": SidewaysRight,
+  }
+}
+
+#[derive(Default)]
+pub(crate) struct WritingModeHandler {
+  writing_mode: Option<(WritingMode, VendorPrefix)>,
+}
+
+impl<'i> PropertyHandler<'i> for WritingModeHandler {
+  fn handle_property(
+    &mut self,
+    property: &Property<'i>,
+    dest: &mut DeclarationList<'i>,
+    context: &mut PropertyHandlerContext<'i, '_>,
+  ) -> bool {
+    match property {
+      Property::WritingMode(val, prefix) => {
+        if let Some((cur, prefixes)) = &mut self.writing_mode {
+          // If the value is the same, update the prefix.
+          // If the prefix is the same, then update the value.
+          if val == cur || prefixes.contains(*prefix) {
+            *cur = *val;
+            *prefixes |= *prefix;
+            return true;
+          }
+          self.finalize(dest, context);
+        }
+        self.writing_mode = Some((*val, *prefix));
+      }
+      Property::Unparsed(val) if matches!(val.property_id, PropertyId::WritingMode(_)) => {
+        self.finalize(dest, context);
+        dest.push(property.clone());
+      }
+      _ => return false,
+    }
+
+    true
+  }
+
+  fn finalize(&mut self, dest: &mut DeclarationList<'i>, context: &mut PropertyHandlerContext<'i, '_>) {
+    if let Some((val, prefix)) = std::mem::take(&mut self.writing_mode) {
+      let mut prefixes = context.targets.prefixes(prefix, Feature::WritingMode);
+
+      // `-ms-writing-mode` only supports the legacy values, so it must be
+      // emitted as a separate declaration with the value converted.
+      if prefixes.contains(VendorPrefix::Ms) {
+        prefixes.remove(VendorPrefix::Ms);
+        if let Some(ms) = val.to_ms() {
+          dest.push(Property::WritingMode(ms, VendorPrefix::Ms));
+        }
+      }
+
+      if !prefixes.is_empty() {
+        dest.push(Property::WritingMode(val, prefixes));
+      }
+    }
+  }
+}
+
+#[derive(Default)]
+pub(crate) struct UnicodeBidiHandler {
+  unicode_bidi: Option<UnicodeBidi>,
+}
+
+impl<'i> PropertyHandler<'i> for UnicodeBidiHandler {
+  fn handle_property(
+    &mut self,
+    property: &Property<'i>,
+    dest: &mut DeclarationList<'i>,
+    context: &mut PropertyHandlerContext<'i, '_>,
+  ) -> bool {
+    match property {
+      Property::UnicodeBidi(val) => {
+        // If the value is a prefixed variant of the same keyword, merge the prefixes.
+        if let Some(cur) = &mut self.unicode_bidi {
+          if let (Some((cur_prefix, _)), Some((prefix, _))) = (cur.prefix(), val.prefix()) {
+            if cur.with_prefix(VendorPrefix::None) == val.with_prefix(VendorPrefix::None) {
+              *cur = cur.with_prefix(cur_prefix | prefix);
+              return true;
+            }
+          }
+        }
+
+        // If the value isn't supported across all targets, preserve the previous value as a fallback.
+        // Otherwise, the later value overrides the previous one.
+        if self.unicode_bidi.is_some()
+          && matches!(context.targets.browsers, Some(targets) if !val.is_compatible(targets))
+        {
+          self.finalize(dest, context);
+        }
+
+        self.unicode_bidi = Some(*val);
+      }
+      Property::Unparsed(val) if matches!(val.property_id, PropertyId::UnicodeBidi) => {
+        self.finalize(dest, context);
+        dest.push(property.clone());
+      }
+      _ => return false,
+    }
+
+    true
+  }
+
+  fn finalize(&mut self, dest: &mut DeclarationList<'i>, context: &mut PropertyHandlerContext<'i, '_>) {
+    if let Some(val) = std::mem::take(&mut self.unicode_bidi) {
+      if let Some((prefix, feature)) = val.prefix() {
+        // Prefixed values are output first, so that the unprefixed value takes precedence.
+        let prefixes = context.targets.prefixes(prefix, feature);
+        for prefix in prefixes - VendorPrefix::None {
+          dest.push(Property::UnicodeBidi(val.with_prefix(prefix)));
+        }
+        if prefixes.contains(VendorPrefix::None) {
+          dest.push(Property::UnicodeBidi(val.with_prefix(VendorPrefix::None)));
+        }
+      } else {
+        dest.push(Property::UnicodeBidi(val));
+      }
+    }
+  }
+}
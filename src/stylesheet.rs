@@ -10,6 +10,7 @@ use crate::dependencies::Dependency;
 use crate::error::{Error, ErrorLocation, MinifyErrorKind, ParserError, PrinterError, PrinterErrorKind};
 use crate::parser::{DefaultAtRule, DefaultAtRuleParser, TopLevelRuleParser};
 use crate::printer::Printer;
+use crate::properties::custom::TokenList;
 use crate::rules::{CssRule, CssRuleList, MinifyContext};
 use crate::targets::{should_compile, Targets};
 use crate::traits::{AtRuleParser, ToCss};
@@ -95,6 +96,9 @@ pub struct MinifyOptions {
   /// A list of known unused symbols, including CSS class names,
   /// ids, and `@keyframe` names. The declarations of these will be removed.
   pub unused_symbols: HashSet<String>,
+  /// Values for environment variables, e.g. `safe-area-inset-top`. References to them
+  /// via `env()`, including within custom properties, are replaced with the given values.
+  pub environment_variables: HashMap<String, TokenList<'static>>,
 }
 
 /// A result returned from `to_css`, including the serialize CSS
@@ -205,7 +209,8 @@ where
 
   /// Minify and transform the style sheet for the provided browser targets.
   pub fn minify(&mut self, options: MinifyOptions) -> Result<(), Error<MinifyErrorKind>> {
-    let context = PropertyHandlerContext::new(options.targets, &options.unused_symbols);
+    let context =
+      PropertyHandlerContext::new(options.targets, &options.unused_symbols, &options.environment_variables);
     let mut handler = DeclarationHandler::default();
     let mut important_handler = DeclarationHandler::default();
 
@@ -366,7 +371,8 @@ impl<'i> StyleAttribute<'i> {
 
   /// Minify and transform the style attribute for the provided browser targets.
   pub fn minify(&mut self, options: MinifyOptions) {
-    let mut context = PropertyHandlerContext::new(options.targets, &options.unused_symbols);
+    let mut context =
+      PropertyHandlerContext::new(options.targets, &options.unused_symbols, &options.environment_variables);
     let mut handler = DeclarationHandler::default();
     let mut important_handler = DeclarationHandler::default();
     context.context = DeclarationContext::StyleAttribute;
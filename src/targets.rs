@@ -158,6 +158,11 @@ bitflags! {
     const DoublePositionGradients = 1 << 17;
     const VendorPrefixes = 1 << 18;
     const LogicalProperties = 1 << 19;
+    const FontVariant = 1 << 20;
+    const Zoom = 1 << 21;
+    const LightDarkMediaQuery = 1 << 22;
+    const ViewportUnits = 1 << 23;
+    const GradientInterpolation = 1 << 24;
     const Selectors = Self::Nesting.bits() | Self::NotSelectorList.bits() | Self::DirSelector.bits() | Self::LangSelectorList.bits() | Self::IsSelector.bits();
     const MediaQueries = Self::MediaIntervalSyntax.bits() | Self::MediaRangeSyntax.bits() | Self::CustomMediaQueries.bits();
     const Colors = Self::ColorFunction.bits() | Self::OklabColors.bits() | Self::LabColors.bits() | Self::P3Colors.bits() | Self::HexAlphaColors.bits() | Self::SpaceSeparatedColorNotation.bits();
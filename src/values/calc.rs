@@ -12,9 +12,11 @@ use crate::visitor::Visit;
 use cssparser::*;
 
 use super::angle::Angle;
+use super::frequency::Frequency;
 use super::length::Length;
 use super::number::CSSNumber;
 use super::percentage::Percentage;
+use super::resolution::Resolution;
 use super::time::Time;
 
 /// A CSS [math function](https://www.w3.org/TR/css-values-4/#math-function).
@@ -53,6 +55,25 @@ pub enum MathFunction<V> {
   Hypot(Vec<Calc<V>>),
 }
 
+impl<V: Clone> MathFunction<V> {
+  /// Returns a copy of the function with each value replaced by the result of the given function.
+  pub(crate) fn map_values<F: Fn(&V) -> V + Copy>(&self, f: F) -> MathFunction<V> {
+    let map_all = |args: &Vec<Calc<V>>| args.iter().map(|arg| arg.map_values(f)).collect();
+    match self {
+      MathFunction::Calc(v) => MathFunction::Calc(v.map_values(f)),
+      MathFunction::Min(args) => MathFunction::Min(map_all(args)),
+      MathFunction::Max(args) => MathFunction::Max(map_all(args)),
+      MathFunction::Clamp(a, b, c) => MathFunction::Clamp(a.map_values(f), b.map_values(f), c.map_values(f)),
+      MathFunction::Round(strategy, a, b) => MathFunction::Round(*strategy, a.map_values(f), b.map_values(f)),
+      MathFunction::Rem(a, b) => MathFunction::Rem(a.map_values(f), b.map_values(f)),
+      MathFunction::Mod(a, b) => MathFunction::Mod(a.map_values(f), b.map_values(f)),
+      MathFunction::Abs(v) => MathFunction::Abs(v.map_values(f)),
+      MathFunction::Sign(v) => MathFunction::Sign(v.map_values(f)),
+      MathFunction::Hypot(args) => MathFunction::Hypot(map_all(args)),
+    }
+  }
+}
+
 impl<V: IsCompatible> IsCompatible for MathFunction<V> {
   fn is_compatible(&self, browsers: Browsers) -> bool {
     match self {
@@ -262,6 +283,19 @@ pub enum Calc<V> {
   Function(Box<MathFunction<V>>),
 }
 
+impl<V: Clone> Calc<V> {
+  /// Returns a copy of the expression with each value replaced by the result of the given function.
+  pub(crate) fn map_values<F: Fn(&V) -> V + Copy>(&self, f: F) -> Calc<V> {
+    match self {
+      Calc::Value(v) => Calc::Value(Box::new(f(v))),
+      Calc::Number(n) => Calc::Number(*n),
+      Calc::Sum(a, b) => Calc::Sum(Box::new(a.map_values(f)), Box::new(b.map_values(f))),
+      Calc::Product(n, v) => Calc::Product(*n, Box::new(v.map_values(f))),
+      Calc::Function(func) => Calc::Function(Box::new(func.map_values(f))),
+    }
+  }
+}
+
 impl<V: IsCompatible> IsCompatible for Calc<V> {
   fn is_compatible(&self, browsers: Browsers) -> bool {
     match self {
@@ -382,11 +416,7 @@ impl<
         })?;
 
         // According to the spec, the minimum should "win" over the maximum if they are in the wrong order.
-        let cmp = if let (Some(Calc::Value(max_val)), Calc::Value(center_val)) = (&max, &center) {
-          center_val.partial_cmp(&max_val)
-        } else {
-          None
-        };
+        let cmp = max.as_ref().and_then(|max| center.partial_cmp_constant(max));
 
         // If center is known to be greater than the maximum, replace it with maximum and remove the max argument.
         // Otherwise, if center is known to be less than the maximum, remove the max argument.
@@ -400,11 +430,7 @@ impl<
           None => {}
         }
 
-        let cmp = if let (Some(Calc::Value(min_val)), Calc::Value(center_val)) = (&min, &center) {
-          center_val.partial_cmp(&min_val)
-        } else {
-          None
-        };
+        let cmp = min.as_ref().and_then(|min| center.partial_cmp_constant(min));
 
         // If center is known to be less than the minimum, replace it with minimum and remove the min argument.
         // Otherwise, if center is known to be greater than the minimum, remove the min argument.
@@ -435,11 +461,20 @@ impl<
             RoundingStrategy::default()
           };
 
-          Self::parse_math_fn(
-            input,
-            |a, b| round(a, b, strategy),
-            |a, b| MathFunction::Round(strategy, a, b),
-            parse_ident
+          let a: Calc<V> = Calc::parse_sum(input, parse_ident)?;
+
+          // The rounding interval may be omitted if the value is a number, and defaults to 1.
+          let b: Calc<V> = if input.try_parse(|input| input.expect_comma()).is_ok() {
+            Calc::parse_sum(input, parse_ident)?
+          } else if matches!(a, Calc::Number(_)) {
+            Calc::Number(1.0)
+          } else {
+            return Err(input.new_custom_error(ParserError::InvalidValue));
+          };
+
+          Ok(
+            Self::apply_op(&a, &b, |a, b| round(a, b, strategy))
+              .unwrap_or_else(|| Calc::Function(Box::new(MathFunction::Round(strategy, a, b))))
           )
         })
       },
@@ -651,31 +686,35 @@ impl<
     Ok(Calc::Value(Box::new(value)))
   }
 
+  /// Compares two constant values or numbers, if they are compatible.
+  fn partial_cmp_constant(&self, other: &Calc<V>) -> Option<std::cmp::Ordering> {
+    match (self, other) {
+      (Calc::Value(a), Calc::Value(b)) => a.partial_cmp(b),
+      (Calc::Number(a), Calc::Number(b)) => a.partial_cmp(b),
+      _ => None,
+    }
+  }
+
   fn reduce_args(args: &mut Vec<Calc<V>>, cmp: std::cmp::Ordering) -> Vec<Calc<V>> {
     // Reduces the arguments of a min() or max() expression, combining compatible values.
     // e.g. min(1px, 1em, 2px, 3in) => min(1px, 1em)
     let mut reduced: Vec<Calc<V>> = vec![];
     for arg in args.drain(..) {
       let mut found = None;
-      match &arg {
-        Calc::Value(val) => {
-          for b in reduced.iter_mut() {
-            if let Calc::Value(v) = b {
-              match val.partial_cmp(v) {
-                Some(ord) if ord == cmp => {
-                  found = Some(Some(b));
-                  break;
-                }
-                Some(_) => {
-                  found = Some(None);
-                  break;
-                }
-                None => {}
-              }
+      if matches!(arg, Calc::Value(_) | Calc::Number(_)) {
+        for b in reduced.iter_mut() {
+          match arg.partial_cmp_constant(b) {
+            Some(ord) if ord == cmp => {
+              found = Some(Some(b));
+              break;
             }
+            Some(_) => {
+              found = Some(None);
+              break;
+            }
+            None => {}
           }
         }
-        _ => {}
       }
       if let Some(r) = found {
         if let Some(r) = r {
@@ -816,6 +855,14 @@ impl<
       return Ok(v);
     }
 
+    if let Ok(v) = input.try_parse(|input| Calc::<Resolution>::parse_atan2_args(input, |_| None)) {
+      return Ok(v);
+    }
+
+    if let Ok(v) = input.try_parse(|input| Calc::<Frequency>::parse_atan2_args(input, |_| None)) {
+      return Ok(v);
+    }
+
     Calc::<CSSNumber>::parse_atan2_args(input, |v| {
       parse_ident(v).and_then(|v| match v {
         Calc::Number(v) => Some(Calc::Number(v)),
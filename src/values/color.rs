@@ -247,21 +247,42 @@ bitflags! {
 enum_property! {
   /// A [color space](https://www.w3.org/TR/css-color-4/#interpolation-space) keyword
   /// used in interpolation functions such as `color-mix()`.
-  enum ColorSpaceName {
+  pub enum ColorSpaceName {
+    /// The sRGB color space.
     "srgb": SRGB,
+    /// The linear-light sRGB color space.
     "srgb-linear": SRGBLinear,
+    /// The CIELAB color space.
     "lab": LAB,
+    /// The OKLAB color space.
     "oklab": OKLAB,
+    /// The CIE XYZ color space with a D65 white point.
     "xyz": XYZ,
+    /// The CIE XYZ color space with a D50 white point.
     "xyz-d50": XYZd50,
+    /// The CIE XYZ color space with a D65 white point.
     "xyz-d65": XYZd65,
+    /// The HSL color space.
     "hsl": Hsl,
+    /// The HWB color space.
     "hwb": Hwb,
+    /// The CIELCH color space.
     "lch": LCH,
+    /// The OKLCH color space.
     "oklch": OKLCH,
   }
 }
 
+impl ColorSpaceName {
+  /// Returns whether the color space has a hue component.
+  pub fn is_polar(&self) -> bool {
+    matches!(
+      self,
+      ColorSpaceName::Hsl | ColorSpaceName::Hwb | ColorSpaceName::LCH | ColorSpaceName::OKLCH
+    )
+  }
+}
+
 enum_property! {
   /// A hue [interpolation method](https://www.w3.org/TR/css-color-4/#typedef-hue-interpolation-method)
   /// used in interpolation functions such as `color-mix()`.
@@ -279,6 +300,80 @@ enum_property! {
   }
 }
 
+/// A [`<color-interpolation-method>`](https://drafts.csswg.org/css-color-4/#color-interpolation-method),
+/// as used in `color-mix()` and gradients.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(rename_all = "camelCase")
+)]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+pub struct ColorInterpolationMethod {
+  /// The color space to interpolate in.
+  pub color_space: ColorSpaceName,
+  /// The hue interpolation method. Only valid for polar color spaces.
+  pub hue: Option<HueInterpolationMethod>,
+}
+
+impl<'i> Parse<'i> for ColorInterpolationMethod {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    input.expect_ident_matching("in")?;
+    let color_space = ColorSpaceName::parse(input)?;
+    let hue = if color_space.is_polar() {
+      let hue = input.try_parse(HueInterpolationMethod::parse).ok();
+      if hue.is_some() {
+        input.expect_ident_matching("hue")?;
+      }
+      hue
+    } else {
+      None
+    };
+
+    Ok(ColorInterpolationMethod { color_space, hue })
+  }
+}
+
+impl ToCss for ColorInterpolationMethod {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    dest.write_str("in ")?;
+    self.color_space.to_css(dest)?;
+    // `shorter` is the default.
+    if let Some(hue) = self.hue {
+      if hue != HueInterpolationMethod::Shorter {
+        dest.write_char(' ')?;
+        hue.to_css(dest)?;
+        dest.write_str(" hue")?;
+      }
+    }
+    Ok(())
+  }
+}
+
+impl ColorInterpolationMethod {
+  /// Mixes two colors using this interpolation method, including the specified amount of each.
+  pub(crate) fn interpolate(&self, first: &CssColor, p1: f32, second: &CssColor, p2: f32) -> Result<CssColor, ()> {
+    let hue = self.hue.unwrap_or(HueInterpolationMethod::Shorter);
+    match self.color_space {
+      ColorSpaceName::SRGB => first.interpolate::<SRGB>(p1, second, p2, hue),
+      ColorSpaceName::SRGBLinear => first.interpolate::<SRGBLinear>(p1, second, p2, hue),
+      ColorSpaceName::Hsl => first.interpolate::<HSL>(p1, second, p2, hue),
+      ColorSpaceName::Hwb => first.interpolate::<HWB>(p1, second, p2, hue),
+      ColorSpaceName::LAB => first.interpolate::<LAB>(p1, second, p2, hue),
+      ColorSpaceName::LCH => first.interpolate::<LCH>(p1, second, p2, hue),
+      ColorSpaceName::OKLAB => first.interpolate::<OKLAB>(p1, second, p2, hue),
+      ColorSpaceName::OKLCH => first.interpolate::<OKLCH>(p1, second, p2, hue),
+      ColorSpaceName::XYZ | ColorSpaceName::XYZd65 => first.interpolate::<XYZd65>(p1, second, p2, hue),
+      ColorSpaceName::XYZd50 => first.interpolate::<XYZd50>(p1, second, p2, hue),
+    }
+  }
+}
+
 impl ColorFallbackKind {
   pub(crate) fn lowest(&self) -> ColorFallbackKind {
     // This finds the lowest set bit.
@@ -1105,6 +1200,11 @@ fn parse_lch<'i, 't, T: TryFrom<CssColor> + ColorSpace, F: Fn(f32, f32, f32, f32
       let l = parser.parse_percentage(input)?.clamp(0.0, f32::MAX);
       let c = parser.parse_number(input)?.clamp(0.0, f32::MAX);
       let h = parse_angle_or_number(input, parser)?;
+      // Hues are serialized in the canonical [0, 360) range.
+      // https://www.w3.org/TR/css-color-4/#serializing-lab-lch
+      let h = if h.is_finite() { h.rem_euclid(360.0) } else { h };
+      // rem_euclid rounds up to 360 for tiny negative hues.
+      let h = if h >= 360.0 && h.is_finite() { 0.0 } else { h };
       let alpha = parse_alpha(input, parser)?;
       let lab = f(l, c, h, alpha);
 
@@ -3147,23 +3247,7 @@ where
 }
 
 fn parse_color_mix<'i, 't>(input: &mut Parser<'i, 't>) -> Result<CssColor, ParseError<'i, ParserError<'i>>> {
-  input.expect_ident_matching("in")?;
-  let method = ColorSpaceName::parse(input)?;
-
-  let hue_method = if matches!(
-    method,
-    ColorSpaceName::Hsl | ColorSpaceName::Hwb | ColorSpaceName::LCH | ColorSpaceName::OKLCH
-  ) {
-    let hue_method = input.try_parse(HueInterpolationMethod::parse);
-    if hue_method.is_ok() {
-      input.expect_ident_matching("hue")?;
-    }
-    hue_method
-  } else {
-    Ok(HueInterpolationMethod::Shorter)
-  };
-
-  let hue_method = hue_method.unwrap_or(HueInterpolationMethod::Shorter);
+  let method = ColorInterpolationMethod::parse(input)?;
   input.expect_comma()?;
 
   let first_percent = input.try_parse(|input| input.expect_percentage());
@@ -3179,6 +3263,11 @@ fn parse_color_mix<'i, 't>(input: &mut Parser<'i, 't>) -> Result<CssColor, Parse
     .or_else(|_| input.try_parse(|input| input.expect_percentage()))
     .ok();
 
+  // Percentages outside the range [0%, 100%] are invalid.
+  if first_percent.iter().chain(second_percent.iter()).any(|p| *p < 0.0 || *p > 1.0) {
+    return Err(input.new_custom_error(ParserError::InvalidValue));
+  }
+
   // https://drafts.csswg.org/css-color-5/#color-mix-percent-norm
   let (p1, p2) = if first_percent.is_none() && second_percent.is_none() {
     (0.5, 0.5)
@@ -3192,21 +3281,9 @@ fn parse_color_mix<'i, 't>(input: &mut Parser<'i, 't>) -> Result<CssColor, Parse
     return Err(input.new_custom_error(ParserError::InvalidValue));
   }
 
-  match method {
-    ColorSpaceName::SRGB => first_color.interpolate::<SRGB>(p1, &second_color, p2, hue_method),
-    ColorSpaceName::SRGBLinear => first_color.interpolate::<SRGBLinear>(p1, &second_color, p2, hue_method),
-    ColorSpaceName::Hsl => first_color.interpolate::<HSL>(p1, &second_color, p2, hue_method),
-    ColorSpaceName::Hwb => first_color.interpolate::<HWB>(p1, &second_color, p2, hue_method),
-    ColorSpaceName::LAB => first_color.interpolate::<LAB>(p1, &second_color, p2, hue_method),
-    ColorSpaceName::LCH => first_color.interpolate::<LCH>(p1, &second_color, p2, hue_method),
-    ColorSpaceName::OKLAB => first_color.interpolate::<OKLAB>(p1, &second_color, p2, hue_method),
-    ColorSpaceName::OKLCH => first_color.interpolate::<OKLCH>(p1, &second_color, p2, hue_method),
-    ColorSpaceName::XYZ | ColorSpaceName::XYZd65 => {
-      first_color.interpolate::<XYZd65>(p1, &second_color, p2, hue_method)
-    }
-    ColorSpaceName::XYZd50 => first_color.interpolate::<XYZd50>(p1, &second_color, p2, hue_method),
-  }
-  .map_err(|_| input.new_custom_error(ParserError::InvalidValue))
+  method
+    .interpolate(&first_color, p1, &second_color, p2)
+    .map_err(|_| input.new_custom_error(ParserError::InvalidValue))
 }
 
 impl CssColor {
@@ -0,0 +1,190 @@
+//! CSS frequency values.
+
+use super::angle::impl_try_from_angle;
+use super::calc::Calc;
+use super::length::{serialize_dimension, serialize_shortest_dimension};
+use super::number::CSSNumber;
+use crate::error::{ParserError, PrinterError};
+use crate::printer::Printer;
+use crate::traits::private::AddInternal;
+use crate::traits::{impl_op, Map, Op, Parse, Sign, ToCss, Zero};
+#[cfg(feature = "visitor")]
+use crate::visitor::Visit;
+use cssparser::*;
+
+/// A CSS [`<frequency>`](https://www.w3.org/TR/css-values-4/#frequency) value, in either
+/// hertz or kilohertz.
+///
+/// Frequency values may be explicit or computed by `calc()`, but are always stored and serialized
+/// as their computed value.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "type", content = "value", rename_all = "kebab-case")
+)]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+pub enum Frequency {
+  /// A frequency in hertz.
+  Hz(CSSNumber),
+  /// A frequency in kilohertz.
+  Khz(CSSNumber),
+}
+
+impl Frequency {
+  /// Returns the frequency in hertz.
+  pub fn to_hz(&self) -> CSSNumber {
+    match self {
+      Frequency::Hz(hz) => *hz,
+      Frequency::Khz(khz) => khz * 1000.0,
+    }
+  }
+}
+
+impl Zero for Frequency {
+  fn zero() -> Self {
+    Frequency::Hz(0.0)
+  }
+
+  fn is_zero(&self) -> bool {
+    match self {
+      Frequency::Hz(v) | Frequency::Khz(v) => v.is_zero(),
+    }
+  }
+}
+
+impl<'i> Parse<'i> for Frequency {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    match input.try_parse(Calc::parse) {
+      Ok(Calc::Value(v)) => return Ok(*v),
+      // Frequencies are always compatible, so they will always compute to a value.
+      Ok(_) => return Err(input.new_custom_error(ParserError::InvalidValue)),
+      _ => {}
+    }
+
+    let location = input.current_source_location();
+    match *input.next()? {
+      Token::Dimension { value, ref unit, .. } => {
+        match_ignore_ascii_case! { unit,
+          "hz" => Ok(Frequency::Hz(value)),
+          "khz" => Ok(Frequency::Khz(value)),
+          _ => Err(location.new_unexpected_token_error(Token::Ident(unit.clone())))
+        }
+      }
+      ref t => Err(location.new_unexpected_token_error(t.clone())),
+    }
+  }
+}
+
+impl<'i> TryFrom<&Token<'i>> for Frequency {
+  type Error = ();
+
+  fn try_from(token: &Token) -> Result<Self, Self::Error> {
+    match token {
+      Token::Dimension { value, ref unit, .. } => match_ignore_ascii_case! { unit,
+        "hz" => Ok(Frequency::Hz(*value)),
+        "khz" => Ok(Frequency::Khz(*value)),
+        _ => Err(()),
+      },
+      _ => Err(()),
+    }
+  }
+}
+
+impl ToCss for Frequency {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    let (value, unit, other) = match self {
+      Frequency::Hz(hz) => (*hz, "hz", (*hz / 1000.0, "khz")),
+      Frequency::Khz(khz) => (*khz, "khz", (*khz * 1000.0, "hz")),
+    };
+
+    // When minifying, use the shortest equivalent unit, e.g. 1khz rather than 1000hz.
+    if dest.minify {
+      return serialize_shortest_dimension(&[(value, unit), other], dest);
+    }
+
+    serialize_dimension(value, unit, dest)
+  }
+}
+
+impl std::convert::From<Frequency> for Calc<Frequency> {
+  fn from(f: Frequency) -> Calc<Frequency> {
+    Calc::Value(Box::new(f))
+  }
+}
+
+impl std::convert::From<Calc<Frequency>> for Frequency {
+  fn from(calc: Calc<Frequency>) -> Frequency {
+    match calc {
+      Calc::Value(v) => *v,
+      _ => unreachable!(),
+    }
+  }
+}
+
+impl std::ops::Mul<f32> for Frequency {
+  type Output = Self;
+
+  fn mul(self, other: f32) -> Frequency {
+    self.map(|v| v * other)
+  }
+}
+
+impl AddInternal for Frequency {
+  fn add(self, other: Self) -> Self {
+    self + other
+  }
+}
+
+impl std::cmp::PartialOrd<Frequency> for Frequency {
+  fn partial_cmp(&self, other: &Frequency) -> Option<std::cmp::Ordering> {
+    self.to_hz().partial_cmp(&other.to_hz())
+  }
+}
+
+impl Op for Frequency {
+  fn op<F: FnOnce(f32, f32) -> f32>(&self, to: &Self, op: F) -> Self {
+    match (self, to) {
+      (Frequency::Hz(a), Frequency::Hz(b)) => Frequency::Hz(op(*a, *b)),
+      (Frequency::Khz(a), Frequency::Khz(b)) => Frequency::Khz(op(*a, *b)),
+      (Frequency::Hz(a), Frequency::Khz(b)) => Frequency::Hz(op(*a, b * 1000.0)),
+      (Frequency::Khz(a), Frequency::Hz(b)) => Frequency::Khz(op(*a, b / 1000.0)),
+    }
+  }
+
+  fn op_to<T, F: FnOnce(f32, f32) -> T>(&self, rhs: &Self, op: F) -> T {
+    match (self, rhs) {
+      (Frequency::Hz(a), Frequency::Hz(b)) => op(*a, *b),
+      (Frequency::Khz(a), Frequency::Khz(b)) => op(*a, *b),
+      (Frequency::Hz(a), Frequency::Khz(b)) => op(*a, b * 1000.0),
+      (Frequency::Khz(a), Frequency::Hz(b)) => op(*a, b / 1000.0),
+    }
+  }
+}
+
+impl Map for Frequency {
+  fn map<F: FnOnce(f32) -> f32>(&self, op: F) -> Self {
+    match self {
+      Frequency::Hz(v) => Frequency::Hz(op(*v)),
+      Frequency::Khz(v) => Frequency::Khz(op(*v)),
+    }
+  }
+}
+
+impl Sign for Frequency {
+  fn sign(&self) -> f32 {
+    match self {
+      Frequency::Hz(v) | Frequency::Khz(v) => v.sign(),
+    }
+  }
+}
+
+impl_op!(Frequency, std::ops::Rem, rem);
+impl_op!(Frequency, std::ops::Add, add);
+
+impl_try_from_angle!(Frequency);
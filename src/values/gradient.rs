@@ -1,7 +1,9 @@
 //! CSS gradient values.
 
 use super::angle::{Angle, AnglePercentage};
-use super::color::{ColorFallbackKind, CssColor};
+use super::color::{
+  ColorFallbackKind, ColorInterpolationMethod, ColorSpaceName, CssColor, HueInterpolationMethod,
+};
 use super::length::{Length, LengthPercentage};
 use super::number::CSSNumber;
 use super::percentage::{DimensionPercentage, NumberOrPercentage, Percentage};
@@ -82,21 +84,40 @@ impl Gradient {
 
   /// Returns a copy of the gradient with the given vendor prefix.
   pub fn get_prefixed(&self, prefix: VendorPrefix) -> Gradient {
-    match self {
+    // Prefixed gradients do not support color interpolation methods,
+    // so approximate them with color stops, or drop them if that is not possible.
+    let resolved = if prefix != VendorPrefix::None {
+      self.resolve_interpolation()
+    } else {
+      None
+    };
+    let interpolation = |interpolation: Option<ColorInterpolationMethod>| {
+      if prefix != VendorPrefix::None {
+        None
+      } else {
+        interpolation
+      }
+    };
+
+    match resolved.as_ref().unwrap_or(self) {
       Gradient::Linear(linear) => Gradient::Linear(LinearGradient {
         vendor_prefix: prefix,
+        interpolation: interpolation(linear.interpolation),
         ..linear.clone()
       }),
       Gradient::RepeatingLinear(linear) => Gradient::RepeatingLinear(LinearGradient {
         vendor_prefix: prefix,
+        interpolation: interpolation(linear.interpolation),
         ..linear.clone()
       }),
       Gradient::Radial(radial) => Gradient::Radial(RadialGradient {
         vendor_prefix: prefix,
+        interpolation: interpolation(radial.interpolation),
         ..radial.clone()
       }),
       Gradient::RepeatingRadial(radial) => Gradient::RepeatingRadial(RadialGradient {
         vendor_prefix: prefix,
+        interpolation: interpolation(radial.interpolation),
         ..radial.clone()
       }),
       _ => self.clone(),
@@ -134,6 +155,20 @@ impl Gradient {
     }
   }
 
+  /// Returns a copy of the gradient with its color interpolation method approximated by additional
+  /// color stops, if it has one. Returns `None` if this is not possible.
+  pub(crate) fn resolve_interpolation(&self) -> Option<Gradient> {
+    match self {
+      Gradient::Linear(g) => g.resolve_interpolation().map(Gradient::Linear),
+      Gradient::RepeatingLinear(g) => g.resolve_interpolation().map(Gradient::RepeatingLinear),
+      Gradient::Radial(g) => g.resolve_interpolation().map(Gradient::Radial),
+      Gradient::RepeatingRadial(g) => g.resolve_interpolation().map(Gradient::RepeatingRadial),
+      Gradient::Conic(g) => g.resolve_interpolation().map(Gradient::Conic),
+      Gradient::RepeatingConic(g) => g.resolve_interpolation().map(Gradient::RepeatingConic),
+      Gradient::WebKitGradient(_) => None,
+    }
+  }
+
   /// Returns a fallback gradient for the given color fallback type.
   pub fn get_fallback(&self, kind: ColorFallbackKind) -> Gradient {
     match self {
@@ -228,6 +263,8 @@ pub struct LinearGradient {
   pub vendor_prefix: VendorPrefix,
   /// The direction of the gradient.
   pub direction: LineDirection,
+  /// The color space and hue interpolation method used to interpolate colors.
+  pub interpolation: Option<ColorInterpolationMethod>,
   /// The color stops and transition hints for the gradient.
   pub items: Vec<GradientItem<LengthPercentage>>,
 }
@@ -237,17 +274,21 @@ impl LinearGradient {
     input: &mut Parser<'i, 't>,
     vendor_prefix: VendorPrefix,
   ) -> Result<LinearGradient, ParseError<'i, ParserError<'i>>> {
-    let direction = if let Ok(direction) =
-      input.try_parse(|input| LineDirection::parse(input, vendor_prefix != VendorPrefix::None))
-    {
+    let is_prefixed = vendor_prefix != VendorPrefix::None;
+    let mut interpolation = parse_interpolation(input, is_prefixed);
+    let direction = input.try_parse(|input| LineDirection::parse(input, is_prefixed)).ok();
+    if interpolation.is_none() {
+      interpolation = parse_interpolation(input, is_prefixed);
+    }
+
+    if direction.is_some() || interpolation.is_some() {
       input.expect_comma()?;
-      direction
-    } else {
-      LineDirection::Vertical(VerticalPositionKeyword::Bottom)
-    };
+    }
+
     let items = parse_items(input)?;
     Ok(LinearGradient {
-      direction,
+      direction: direction.unwrap_or(LineDirection::Vertical(VerticalPositionKeyword::Bottom)),
+      interpolation,
       items,
       vendor_prefix,
     })
@@ -266,12 +307,21 @@ impl LinearGradient {
 
     // We can omit `to bottom` or `180deg` because it is the default.
     if angle == 180.0 {
+      serialize_interpolation(&self.interpolation, dest)?;
       serialize_items(&self.items, dest)
 
     // If we have `to top` or `0deg`, and all of the positions and hints are percentages,
     // we can flip the gradient the other direction and omit the direction.
+    // Increasing and decreasing hue interpolation depend on the direction, so they cannot be flipped.
     } else if angle == 0.0
       && dest.minify
+      && !matches!(
+        self.interpolation,
+        Some(ColorInterpolationMethod {
+          hue: Some(HueInterpolationMethod::Increasing | HueInterpolationMethod::Decreasing),
+          ..
+        })
+      )
       && self.items.iter().all(|item| {
         matches!(
           item,
@@ -304,13 +354,20 @@ impl LinearGradient {
           }
         })
         .collect();
+      serialize_interpolation(&self.interpolation, dest)?;
       serialize_items(&items, dest)
     } else {
       if self.direction != LineDirection::Vertical(VerticalPositionKeyword::Bottom)
         && self.direction != LineDirection::Angle(Angle::Deg(180.0))
       {
         self.direction.to_css(dest, is_prefixed)?;
+        if let Some(interpolation) = &self.interpolation {
+          dest.write_char(' ')?;
+          interpolation.to_css(dest)?;
+        }
         dest.delim(',', false)?;
+      } else {
+        serialize_interpolation(&self.interpolation, dest)?;
       }
 
       serialize_items(&self.items, dest)
@@ -320,15 +377,30 @@ impl LinearGradient {
   fn get_fallback(&self, kind: ColorFallbackKind) -> LinearGradient {
     LinearGradient {
       direction: self.direction.clone(),
+      interpolation: self.interpolation,
       items: self.items.iter().map(|item| item.get_fallback(kind)).collect(),
       vendor_prefix: self.vendor_prefix,
     }
   }
+
+  fn resolve_interpolation(&self) -> Option<LinearGradient> {
+    if self.interpolation.is_none() {
+      return None;
+    }
+
+    resolve_interpolation(&self.items, &self.interpolation).map(|items| LinearGradient {
+      direction: self.direction.clone(),
+      interpolation: None,
+      items,
+      vendor_prefix: self.vendor_prefix,
+    })
+  }
 }
 
 impl IsCompatible for LinearGradient {
   fn is_compatible(&self, browsers: Browsers) -> bool {
-    self.items.iter().all(|item| item.is_compatible(browsers))
+    (self.interpolation.is_none() || compat::Feature::GradientInterpolation.is_compatible(browsers))
+      && self.items.iter().all(|item| item.is_compatible(browsers))
   }
 }
 
@@ -349,6 +421,8 @@ pub struct RadialGradient {
   pub shape: EndingShape,
   /// The position of the gradient.
   pub position: Position,
+  /// The color space and hue interpolation method used to interpolate colors.
+  pub interpolation: Option<ColorInterpolationMethod>,
   /// The color stops and transition hints for the gradient.
   pub items: Vec<GradientItem<LengthPercentage>>,
 }
@@ -358,6 +432,8 @@ impl<'i> RadialGradient {
     input: &mut Parser<'i, 't>,
     vendor_prefix: VendorPrefix,
   ) -> Result<RadialGradient, ParseError<'i, ParserError<'i>>> {
+    let is_prefixed = vendor_prefix != VendorPrefix::None;
+    let mut interpolation = parse_interpolation(input, is_prefixed);
     let shape = input.try_parse(EndingShape::parse).ok();
     let position = input
       .try_parse(|input| {
@@ -365,8 +441,11 @@ impl<'i> RadialGradient {
         Position::parse(input)
       })
       .ok();
+    if interpolation.is_none() {
+      interpolation = parse_interpolation(input, is_prefixed);
+    }
 
-    if shape.is_some() || position.is_some() {
+    if shape.is_some() || position.is_some() || interpolation.is_some() {
       input.expect_comma()?;
     }
 
@@ -374,6 +453,7 @@ impl<'i> RadialGradient {
     Ok(RadialGradient {
       shape: shape.unwrap_or_default(),
       position: position.unwrap_or(Position::center()),
+      interpolation,
       items,
       vendor_prefix,
     })
@@ -385,19 +465,28 @@ impl ToCss for RadialGradient {
   where
     W: std::fmt::Write,
   {
-    if self.shape != EndingShape::default() {
+    let has_shape = self.shape != EndingShape::default();
+    let has_position = !self.position.is_center();
+    if has_shape {
       self.shape.to_css(dest)?;
-      if self.position.is_center() {
-        dest.delim(',', false)?;
-      } else {
+      if has_position {
         dest.write_char(' ')?;
       }
     }
 
-    if !self.position.is_center() {
+    if has_position {
       dest.write_str("at ")?;
       self.position.to_css(dest)?;
+    }
+
+    if has_shape || has_position {
+      if let Some(interpolation) = &self.interpolation {
+        dest.write_char(' ')?;
+        interpolation.to_css(dest)?;
+      }
       dest.delim(',', false)?;
+    } else {
+      serialize_interpolation(&self.interpolation, dest)?;
     }
 
     serialize_items(&self.items, dest)
@@ -409,15 +498,31 @@ impl RadialGradient {
     RadialGradient {
       shape: self.shape.clone(),
       position: self.position.clone(),
+      interpolation: self.interpolation,
       items: self.items.iter().map(|item| item.get_fallback(kind)).collect(),
       vendor_prefix: self.vendor_prefix,
     }
   }
+
+  fn resolve_interpolation(&self) -> Option<RadialGradient> {
+    if self.interpolation.is_none() {
+      return None;
+    }
+
+    resolve_interpolation(&self.items, &self.interpolation).map(|items| RadialGradient {
+      shape: self.shape.clone(),
+      position: self.position.clone(),
+      interpolation: None,
+      items,
+      vendor_prefix: self.vendor_prefix,
+    })
+  }
 }
 
 impl IsCompatible for RadialGradient {
   fn is_compatible(&self, browsers: Browsers) -> bool {
-    self.items.iter().all(|item| item.is_compatible(browsers))
+    (self.interpolation.is_none() || compat::Feature::GradientInterpolation.is_compatible(browsers))
+      && self.items.iter().all(|item| item.is_compatible(browsers))
   }
 }
 
@@ -755,12 +860,15 @@ pub struct ConicGradient {
   pub angle: Angle,
   /// The position of the gradient.
   pub position: Position,
+  /// The color space and hue interpolation method used to interpolate colors.
+  pub interpolation: Option<ColorInterpolationMethod>,
   /// The color stops and transition hints for the gradient.
   pub items: Vec<GradientItem<AnglePercentage>>,
 }
 
 impl ConicGradient {
   fn parse<'i, 't>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    let mut interpolation = parse_interpolation(input, false);
     let angle = input.try_parse(|input| {
       input.expect_ident_matching("from")?;
       // Spec allows unitless zero angles for gradients.
@@ -773,7 +881,11 @@ impl ConicGradient {
       Position::parse(input)
     });
 
-    if angle.is_ok() || position.is_ok() {
+    if interpolation.is_none() {
+      interpolation = parse_interpolation(input, false);
+    }
+
+    if angle.is_ok() || position.is_ok() || interpolation.is_some() {
       input.expect_comma()?;
     }
 
@@ -781,6 +893,7 @@ impl ConicGradient {
     Ok(ConicGradient {
       angle: angle.unwrap_or(Angle::Deg(0.0)),
       position: position.unwrap_or(Position::center()),
+      interpolation,
       items,
     })
   }
@@ -791,21 +904,29 @@ impl ToCss for ConicGradient {
   where
     W: std::fmt::Write,
   {
-    if !self.angle.is_zero() {
+    let has_angle = !self.angle.is_zero();
+    let has_position = !self.position.is_center();
+    if has_angle {
       dest.write_str("from ")?;
       self.angle.to_css(dest)?;
-
-      if self.position.is_center() {
-        dest.delim(',', false)?;
-      } else {
+      if has_position {
         dest.write_char(' ')?;
       }
     }
 
-    if !self.position.is_center() {
+    if has_position {
       dest.write_str("at ")?;
       self.position.to_css(dest)?;
+    }
+
+    if has_angle || has_position {
+      if let Some(interpolation) = &self.interpolation {
+        dest.write_char(' ')?;
+        interpolation.to_css(dest)?;
+      }
       dest.delim(',', false)?;
+    } else {
+      serialize_interpolation(&self.interpolation, dest)?;
     }
 
     serialize_items(&self.items, dest)
@@ -817,14 +938,29 @@ impl ConicGradient {
     ConicGradient {
       angle: self.angle.clone(),
       position: self.position.clone(),
+      interpolation: self.interpolation,
       items: self.items.iter().map(|item| item.get_fallback(kind)).collect(),
     }
   }
+
+  fn resolve_interpolation(&self) -> Option<ConicGradient> {
+    if self.interpolation.is_none() {
+      return None;
+    }
+
+    resolve_interpolation(&self.items, &self.interpolation).map(|items| ConicGradient {
+      angle: self.angle.clone(),
+      position: self.position.clone(),
+      interpolation: None,
+      items,
+    })
+  }
 }
 
 impl IsCompatible for ConicGradient {
   fn is_compatible(&self, browsers: Browsers) -> bool {
-    self.items.iter().all(|item| item.is_compatible(browsers))
+    (self.interpolation.is_none() || compat::Feature::GradientInterpolation.is_compatible(browsers))
+      && self.items.iter().all(|item| item.is_compatible(browsers))
   }
 }
 
@@ -977,6 +1113,149 @@ fn parse_items<'i, 't, D: Parse<'i>>(
   Ok(items)
 }
 
+fn parse_interpolation(input: &mut Parser<'_, '_>, is_prefixed: bool) -> Option<ColorInterpolationMethod> {
+  // Vendor prefixed gradients do not support color interpolation methods.
+  if is_prefixed {
+    return None;
+  }
+
+  input.try_parse(ColorInterpolationMethod::parse).ok()
+}
+
+fn serialize_interpolation<W>(
+  interpolation: &Option<ColorInterpolationMethod>,
+  dest: &mut Printer<W>,
+) -> Result<(), PrinterError>
+where
+  W: std::fmt::Write,
+{
+  if let Some(interpolation) = interpolation {
+    interpolation.to_css(dest)?;
+    dest.delim(',', false)?;
+  }
+  Ok(())
+}
+
+/// The number of segments each transition between two color stops is split into
+/// when resolving a color interpolation method.
+const INTERPOLATION_STEPS: usize = 4;
+
+/// Approximates a gradient interpolated using the given color interpolation method
+/// by inserting intermediate color stops, which are interpolated in sRGB by all browsers.
+/// Returns `None` if this is not possible, e.g. the positions are not all percentages.
+fn resolve_interpolation<D: Clone + PartialEq>(
+  items: &Vec<GradientItem<DimensionPercentage<D>>>,
+  interpolation: &Option<ColorInterpolationMethod>,
+) -> Option<Vec<GradientItem<DimensionPercentage<D>>>> {
+  let interpolation = match interpolation {
+    // sRGB is the default in browsers without support for interpolation methods.
+    Some(interpolation) if interpolation.color_space != ColorSpaceName::SRGB => interpolation,
+    _ => return Some(items.clone()),
+  };
+
+  let mut colors = Vec::with_capacity(items.len());
+  let mut positions = Vec::with_capacity(items.len());
+  for item in items {
+    match item {
+      GradientItem::ColorStop(ColorStop { color, position: None }) => {
+        colors.push(color);
+        positions.push(None);
+      }
+      GradientItem::ColorStop(ColorStop {
+        color,
+        position: Some(DimensionPercentage::Percentage(p)),
+      }) => {
+        colors.push(color);
+        positions.push(Some(p.0));
+      }
+      _ => return None,
+    }
+  }
+
+  let len = positions.len();
+  if len < 2 {
+    return None;
+  }
+
+  let positions = fixup_positions(positions);
+  let mut res = Vec::with_capacity(len * INTERPOLATION_STEPS);
+  for i in 0..len {
+    let color = colors[i];
+    let position = positions[i];
+    res.push(GradientItem::ColorStop(ColorStop {
+      color: color.clone(),
+      position: Some(DimensionPercentage::Percentage(Percentage(position))),
+    }));
+
+    if i + 1 < len {
+      let next_color = colors[i + 1];
+      let next_position = positions[i + 1];
+      if next_position <= position || color == next_color {
+        continue;
+      }
+
+      for step in 1..INTERPOLATION_STEPS {
+        let t = step as f32 / INTERPOLATION_STEPS as f32;
+        let color = match interpolation
+          .interpolate(color, 1.0 - t, next_color, t)
+          .and_then(|color| color.to_rgb())
+        {
+          Ok(color) => color,
+          // Colors such as currentColor cannot be interpolated ahead of time.
+          Err(_) => return None,
+        };
+
+        res.push(GradientItem::ColorStop(ColorStop {
+          color,
+          position: Some(DimensionPercentage::Percentage(Percentage(
+            position + (next_position - position) * t,
+          ))),
+        }));
+      }
+    }
+  }
+
+  Some(res)
+}
+
+/// Fixes up missing and out of order color stop positions, given as fractions.
+/// https://drafts.csswg.org/css-images-4/#color-stop-fixup
+fn fixup_positions(mut positions: Vec<Option<f32>>) -> Vec<f32> {
+  let len = positions.len();
+  if len == 0 {
+    return Vec::new();
+  }
+
+  positions[0] = positions[0].or(Some(0.0));
+  positions[len - 1] = positions[len - 1].or(Some(1.0));
+  let mut max = f32::MIN;
+  for position in positions.iter_mut().flatten() {
+    if *position < max {
+      *position = max;
+    } else {
+      max = *position;
+    }
+  }
+
+  // Spread stops without a position evenly between the surrounding stops.
+  let mut i = 1;
+  while i < len {
+    if positions[i].is_none() {
+      let start = i - 1;
+      let end = (i..len).find(|j| positions[*j].is_some()).unwrap();
+      let a = positions[start].unwrap();
+      let b = positions[end].unwrap();
+      for (j, position) in positions.iter_mut().enumerate().take(end).skip(i) {
+        *position = Some(a + (b - a) * (j - start) as f32 / (end - start) as f32);
+      }
+      i = end;
+    }
+    i += 1;
+  }
+
+  positions.into_iter().map(|position| position.unwrap()).collect()
+}
+
 fn serialize_items<
   D: ToCss + std::cmp::PartialEq<D> + std::ops::Mul<f32, Output = D> + TrySign + Clone + std::fmt::Debug,
   W,
@@ -987,9 +1266,10 @@ fn serialize_items<
 where
   W: std::fmt::Write,
 {
+  let double_position = !should_compile!(dest.targets, DoublePositionGradients);
   let mut first = true;
   let mut last: Option<&GradientItem<DimensionPercentage<D>>> = None;
-  for item in items {
+  for (i, item) in items.iter().enumerate() {
     // Skip useless hints
     if *item == GradientItem::Hint(DimensionPercentage::Percentage(Percentage(0.5))) {
       continue;
@@ -997,25 +1277,13 @@ where
 
     // Use double position stop if the last stop is the same color and all targets support it.
     if let Some(prev) = last {
-      if !should_compile!(dest.targets, DoublePositionGradients) {
-        match (prev, item) {
-          (
-            GradientItem::ColorStop(ColorStop {
-              position: Some(_),
-              color: ca,
-            }),
-            GradientItem::ColorStop(ColorStop {
-              position: Some(p),
-              color: cb,
-            }),
-          ) if ca == cb => {
-            dest.write_char(' ')?;
-            p.to_css(dest)?;
-            last = None;
-            continue;
-          }
-          _ => {}
+      if double_position && is_double_position(prev, item) {
+        if let GradientItem::ColorStop(ColorStop { position: Some(p), .. }) = item {
+          dest.write_char(' ')?;
+          p.to_css(dest)?;
         }
+        last = None;
+        continue;
       }
     }
 
@@ -1024,12 +1292,44 @@ where
     } else {
       dest.delim(',', false)?;
     }
-    item.to_css(dest)?;
+
+    // Omit the position of the first stop if it is 0%, and of the last stop if it is 100%,
+    // since these are the defaults. The first position must be kept if the next stop
+    // would be merged into a double position stop.
+    match item {
+      GradientItem::ColorStop(ColorStop {
+        color,
+        position: Some(DimensionPercentage::Percentage(Percentage(p))),
+      }) if (i == 0
+        && *p == 0.0
+        && !(double_position && items.get(1).map_or(false, |next| is_double_position(item, next))))
+        || (i == items.len() - 1 && *p == 1.0) =>
+      {
+        color.to_css(dest)?
+      }
+      _ => item.to_css(dest)?,
+    }
     last = Some(item)
   }
   Ok(())
 }
 
+fn is_double_position<D: PartialEq>(a: &GradientItem<D>, b: &GradientItem<D>) -> bool {
+  matches!(
+    (a, b),
+    (
+      GradientItem::ColorStop(ColorStop {
+        position: Some(_),
+        color: ca,
+      }),
+      GradientItem::ColorStop(ColorStop {
+        position: Some(_),
+        color: cb,
+      }),
+    ) if ca == cb
+  )
+}
+
 /// A legacy `-webkit-gradient()`.
 #[derive(Debug, Clone, PartialEq)]
 #[cfg_attr(feature = "visitor", derive(Visit))]
@@ -1448,33 +1748,34 @@ impl WebKitGradient {
 }
 
 fn convert_stops_to_webkit(items: &Vec<GradientItem<LengthPercentage>>) -> Result<Vec<WebKitColorStop>, ()> {
-  let mut stops = Vec::with_capacity(items.len());
-  for (i, item) in items.iter().enumerate() {
+  let mut colors = Vec::with_capacity(items.len());
+  let mut positions = Vec::with_capacity(items.len());
+  for item in items {
     match item {
       GradientItem::ColorStop(stop) => {
         // webkit stops must always be percentage based, not length based.
-        let position = if let Some(pos) = &stop.position {
-          if let LengthPercentage::Percentage(position) = pos {
-            position.0
-          } else {
-            return Err(());
-          }
-        } else if i == 0 {
-          0.0
-        } else if i == items.len() - 1 {
-          1.0
-        } else {
-          return Err(());
+        let position = match &stop.position {
+          Some(LengthPercentage::Percentage(position)) => Some(position.0),
+          Some(_) => return Err(()),
+          None => None,
         };
 
-        stops.push(WebKitColorStop {
-          color: stop.color.clone(),
-          position,
-        })
+        colors.push(&stop.color);
+        positions.push(position);
       }
       _ => return Err(()),
     }
   }
 
-  Ok(stops)
+  // Resolve omitted positions, since webkit requires them for all stops.
+  Ok(
+    colors
+      .into_iter()
+      .zip(fixup_positions(positions))
+      .map(|(color, position)| WebKitColorStop {
+        color: color.clone(),
+        position,
+      })
+      .collect(),
+  )
 }
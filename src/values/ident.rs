@@ -23,20 +23,26 @@ use super::string::impl_string_type;
 #[cfg_attr(feature = "visitor", derive(Visit))]
 #[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
 #[cfg_attr(feature = "visitor", visit(visit_custom_ident, CUSTOM_IDENTS))]
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(transparent))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde(transparent))]
 #[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
 pub struct CustomIdent<'i>(#[cfg_attr(feature = "serde", serde(borrow))] pub CowArcStr<'i>);
 
 impl<'i> Parse<'i> for CustomIdent<'i> {
   fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    Self::parse_except(input, &[])
+  }
+}
+
+impl<'i> CustomIdent<'i> {
+  /// Parses a custom ident, additionally excluding the given keywords, which are
+  /// reserved in the context where the custom ident is used.
+  pub fn parse_except<'t>(
+    input: &mut Parser<'i, 't>,
+    excluded: &[&str],
+  ) -> Result<Self, ParseError<'i, ParserError<'i>>> {
     let location = input.current_source_location();
     let ident = input.expect_ident()?;
-    let valid = match_ignore_ascii_case! { &ident,
-      "initial" | "inherit" | "unset" | "default" | "revert" | "revert-layer" => false,
-      _ => true
-    };
-
-    if !valid {
+    if is_reserved(ident) || excluded.iter().any(|keyword| ident.eq_ignore_ascii_case(keyword)) {
       return Err(location.new_unexpected_token_error(Token::Ident(ident.clone())));
     }
 
@@ -44,6 +50,32 @@ impl<'i> Parse<'i> for CustomIdent<'i> {
   }
 }
 
+/// Returns whether the identifier is a CSS-wide keyword or `default`,
+/// which are excluded from all custom idents.
+fn is_reserved(ident: &str) -> bool {
+  match_ignore_ascii_case! { ident,
+    "initial" | "inherit" | "unset" | "default" | "revert" | "revert-layer" => true,
+    _ => false
+  }
+}
+
+#[cfg(feature = "serde")]
+impl<'i, 'de: 'i> serde::Deserialize<'de> for CustomIdent<'i> {
+  fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+  where
+    D: serde::Deserializer<'de>,
+  {
+    let ident = CowArcStr::deserialize(deserializer)?;
+    if is_reserved(&ident) {
+      return Err(serde::de::Error::custom(
+        "Custom idents must not be a CSS-wide keyword or `default`",
+      ));
+    }
+
+    Ok(CustomIdent(ident))
+  }
+}
+
 impl<'i> ToCss for CustomIdent<'i> {
   fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
   where
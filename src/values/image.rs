@@ -1,14 +1,16 @@
 //! CSS image values.
 
-use super::color::ColorFallbackKind;
+use super::color::{ColorFallbackKind, CssColor};
 use super::gradient::*;
+use super::number::CSSNumber;
+use super::percentage::Percentage;
 use super::resolution::Resolution;
 use crate::compat;
 use crate::dependencies::{Dependency, UrlDependency};
 use crate::error::{ParserError, PrinterError};
 use crate::prefixes::{is_webkit_gradient, Feature};
 use crate::printer::Printer;
-use crate::targets::{Browsers, Targets};
+use crate::targets::{should_compile, Browsers, Targets};
 use crate::traits::{FallbackValues, IsCompatible, Parse, ToCss};
 use crate::values::string::CowArcStr;
 use crate::values::url::Url;
@@ -39,6 +41,10 @@ pub enum Image<'i> {
   Gradient(Box<Gradient>),
   /// An `image-set()`.
   ImageSet(ImageSet<'i>),
+  /// A `cross-fade()`.
+  CrossFade(CrossFade<'i>),
+  /// An `element()`.
+  Element(Element<'i>),
 }
 
 impl<'i> Default for Image<'i> {
@@ -59,6 +65,8 @@ impl<'i> Image<'i> {
     match self {
       Image::Gradient(a) => a.get_vendor_prefix(),
       Image::ImageSet(a) => a.get_vendor_prefix(),
+      Image::CrossFade(a) => a.vendor_prefix,
+      Image::Element(a) => a.vendor_prefix,
       _ => VendorPrefix::empty(),
     }
   }
@@ -68,6 +76,8 @@ impl<'i> Image<'i> {
     match self {
       Image::Gradient(grad) => grad.get_necessary_prefixes(targets),
       Image::ImageSet(image_set) => image_set.get_necessary_prefixes(targets),
+      Image::CrossFade(cross_fade) => cross_fade.get_necessary_prefixes(targets),
+      Image::Element(element) => targets.prefixes(element.vendor_prefix, Feature::Element),
       _ => VendorPrefix::None,
     }
   }
@@ -77,6 +87,11 @@ impl<'i> Image<'i> {
     match self {
       Image::Gradient(grad) => Image::Gradient(Box::new(grad.get_prefixed(prefix))),
       Image::ImageSet(image_set) => Image::ImageSet(image_set.get_prefixed(prefix)),
+      Image::CrossFade(cross_fade) => Image::CrossFade(cross_fade.get_prefixed(prefix)),
+      Image::Element(element) => Image::Element(Element {
+        id: element.id.clone(),
+        vendor_prefix: prefix,
+      }),
       _ => self.clone(),
     }
   }
@@ -95,14 +110,25 @@ impl<'i> Image<'i> {
   pub fn get_necessary_fallbacks(&self, targets: Targets) -> ColorFallbackKind {
     match self {
       Image::Gradient(grad) => grad.get_necessary_fallbacks(targets),
+      Image::CrossFade(cross_fade) => cross_fade.get_necessary_fallbacks(targets),
       _ => ColorFallbackKind::empty(),
     }
   }
 
+  /// Returns a copy of the image with the gradient's color interpolation method approximated by
+  /// additional color stops. Returns `None` if this is not possible.
+  pub(crate) fn resolve_interpolation(&self) -> Option<Image<'i>> {
+    match self {
+      Image::Gradient(grad) => grad.resolve_interpolation().map(|grad| Image::Gradient(Box::new(grad))),
+      _ => None,
+    }
+  }
+
   /// Returns a fallback version of the image for the given color fallback type.
   pub fn get_fallback(&self, kind: ColorFallbackKind) -> Image<'i> {
     match self {
       Image::Gradient(grad) => Image::Gradient(Box::new(grad.get_fallback(kind))),
+      Image::CrossFade(cross_fade) => Image::CrossFade(cross_fade.get_fallback(kind)),
       _ => self.clone(),
     }
   }
@@ -131,11 +157,39 @@ impl<'i> IsCompatible for Image<'i> {
         Gradient::WebKitGradient(..) => is_webkit_gradient(browsers),
       },
       Image::ImageSet(i) => i.is_compatible(browsers),
+      Image::CrossFade(cross_fade) => cross_fade.is_compatible(browsers),
+      // Only the -moz- prefixed version is supported.
+      Image::Element(element) => {
+        element.vendor_prefix == VendorPrefix::Moz
+          && is_prefix_compatible(Feature::Element, element.vendor_prefix, browsers)
+      }
       Image::Url(..) | Image::None => true,
     }
   }
 }
 
+/// Returns whether all of the given browsers support a feature with the given vendor prefix.
+fn is_prefix_compatible(feature: Feature, prefix: VendorPrefix, browsers: Browsers) -> bool {
+  macro_rules! check {
+    ($($browser: ident),*) => {
+      $(
+        if let Some(version) = browsers.$browser {
+          let browser = Browsers {
+            $browser: Some(version),
+            ..Browsers::default()
+          };
+          if !feature.prefixes_for(browser).contains(prefix) {
+            return false;
+          }
+        }
+      )*
+    };
+  }
+
+  check!(android, chrome, edge, firefox, ie, ios_saf, opera, safari, samsung);
+  true
+}
+
 pub(crate) trait ImageFallback<'i>: Sized {
   fn get_image(&self) -> &Image<'i>;
   fn with_image(&self, image: Image<'i>) -> Self;
@@ -165,6 +219,13 @@ impl<'i> ImageFallback<'i> for Image<'i> {
 
 impl<'i> FallbackValues for Image<'i> {
   fn get_fallbacks(&mut self, targets: Targets) -> Vec<Self> {
+    // Approximate color interpolation methods unsupported by the targets with color stops.
+    if should_compile!(targets, GradientInterpolation) {
+      if let Some(resolved) = self.resolve_interpolation() {
+        *self = resolved;
+      }
+    }
+
     // Determine which prefixes and color fallbacks are needed.
     let prefixes = self.get_necessary_prefixes(targets);
     let fallbacks = self.get_necessary_fallbacks(targets);
@@ -231,6 +292,15 @@ impl<'i> FallbackValues for Image<'i> {
 
 impl<'i, T: ImageFallback<'i>> FallbackValues for SmallVec<[T; 1]> {
   fn get_fallbacks(&mut self, targets: Targets) -> Vec<Self> {
+    // Approximate color interpolation methods unsupported by the targets with color stops.
+    if should_compile!(targets, GradientInterpolation) {
+      for item in self.iter_mut() {
+        if let Some(resolved) = item.get_image().resolve_interpolation() {
+          *item = item.with_image(resolved);
+        }
+      }
+    }
+
     // Determine what vendor prefixes and color fallbacks are needed.
     let mut prefixes = VendorPrefix::empty();
     let mut fallbacks = ColorFallbackKind::empty();
@@ -327,6 +397,14 @@ impl<'i> Parse<'i> for Image<'i> {
       return Ok(Image::ImageSet(image_set));
     }
 
+    if let Ok(cross_fade) = input.try_parse(CrossFade::parse) {
+      return Ok(Image::CrossFade(cross_fade));
+    }
+
+    if let Ok(element) = input.try_parse(Element::parse) {
+      return Ok(Image::Element(element));
+    }
+
     Err(input.new_error_for_next_token())
   }
 }
@@ -341,6 +419,8 @@ impl<'i> ToCss for Image<'i> {
       Image::Url(url) => url.to_css(dest),
       Image::Gradient(grad) => grad.to_css(dest),
       Image::ImageSet(image_set) => image_set.to_css(dest),
+      Image::CrossFade(cross_fade) => cross_fade.to_css(dest),
+      Image::Element(element) => element.to_css(dest),
     }
   }
 }
@@ -379,8 +459,22 @@ impl<'i> ImageSet<'i> {
 
   /// Returns the `image-set()` value with the given vendor prefix.
   pub fn get_prefixed(&self, prefix: VendorPrefix) -> ImageSet<'i> {
+    let mut options = self.options.clone();
+    if prefix != VendorPrefix::None {
+      // -webkit-image-set() does not support type(). Browsers that only support the prefixed
+      // syntax likely do not support newer image formats either, so drop options with a type hint
+      // in favor of untyped ones. If every option is typed, keep them all without the hint.
+      if options.iter().any(|option| option.file_type.is_none()) {
+        options.retain(|option| option.file_type.is_none());
+      } else {
+        for option in &mut options {
+          option.file_type = None;
+        }
+      }
+    }
+
     ImageSet {
-      options: self.options.clone(),
+      options,
       vendor_prefix: prefix,
     }
   }
@@ -457,6 +551,7 @@ impl<'i> Parse<'i> for ImageSetOption<'i> {
     let image = if let Ok(url) = input.try_parse(|input| input.expect_url_or_string()) {
       Image::Url(Url {
         url: url.into(),
+        modifiers: Vec::new(),
         loc: loc.into(),
       })
     } else {
@@ -508,7 +603,7 @@ impl<'i> ImageSetOption<'i> {
 
     // TODO: Throwing an error when `self.resolution = Resolution::Dppx(0.0)`
     // TODO: -webkit-image-set() does not support `<image()> | <image-set()> |
-    // <cross-fade()> | <element()> | <gradient>` and `type(<string>)`.
+    // <cross-fade()> | <element()> | <gradient>`.
     dest.write_char(' ')?;
 
     // Safari only supports the x resolution unit in image-set().
@@ -532,3 +627,353 @@ fn parse_file_type<'i, 't>(input: &mut Parser<'i, 't>) -> Result<CowRcStr<'i>, P
   input.expect_function_matching("type")?;
   input.parse_nested_block(|input| Ok(input.expect_string_cloned()?))
 }
+
+/// A CSS [`cross-fade()`](https://drafts.csswg.org/css-images-4/#cross-fade-function) value.
+///
+/// `cross-fade()` blends multiple images or colors together.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(rename_all = "camelCase")
+)]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+pub struct CrossFade<'i> {
+  /// The images to blend.
+  #[cfg_attr(feature = "serde", serde(borrow))]
+  pub images: CrossFadeImages<'i>,
+  /// The vendor prefix for the `cross-fade()` function.
+  pub vendor_prefix: VendorPrefix,
+}
+
+/// The images within a [cross-fade()](CrossFade) function.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "type", content = "value", rename_all = "kebab-case")
+)]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+pub enum CrossFadeImages<'i> {
+  /// The legacy syntax, which blends two images by the given percentage.
+  /// This is the only syntax supported by `-webkit-cross-fade()`.
+  Legacy {
+    /// The image to fade from.
+    #[cfg_attr(feature = "visitor", skip_type)]
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    from: Box<Image<'i>>,
+    /// The image to fade to.
+    #[cfg_attr(feature = "visitor", skip_type)]
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    to: Box<Image<'i>>,
+    /// How much of the `to` image is used.
+    percentage: Percentage,
+  },
+  /// A list of images or colors with optional percentages.
+  List(Vec<CrossFadeImage<'i>>),
+}
+
+impl<'i> CrossFade<'i> {
+  /// Returns the vendor prefixes needed for the given browser targets.
+  pub fn get_necessary_prefixes(&self, targets: Targets) -> VendorPrefix {
+    match self.images {
+      // Prefixed versions only support the legacy syntax.
+      CrossFadeImages::Legacy { .. } => targets.prefixes(self.vendor_prefix, Feature::CrossFade),
+      CrossFadeImages::List(..) => self.vendor_prefix,
+    }
+  }
+
+  /// Returns the `cross-fade()` value with the given vendor prefix.
+  pub fn get_prefixed(&self, prefix: VendorPrefix) -> CrossFade<'i> {
+    CrossFade {
+      images: self.images.clone(),
+      vendor_prefix: prefix,
+    }
+  }
+
+  /// Returns the color fallbacks that are needed for the given browser targets.
+  pub fn get_necessary_fallbacks(&self, targets: Targets) -> ColorFallbackKind {
+    match &self.images {
+      CrossFadeImages::Legacy { from, to, .. } => {
+        from.get_necessary_fallbacks(targets) | to.get_necessary_fallbacks(targets)
+      }
+      CrossFadeImages::List(images) => {
+        let mut fallbacks = ColorFallbackKind::empty();
+        for image in images {
+          fallbacks |= match &image.value {
+            CrossFadeImageValue::Image(image) => image.get_necessary_fallbacks(targets),
+            CrossFadeImageValue::Color(color) => color.get_necessary_fallbacks(targets),
+          };
+        }
+        fallbacks
+      }
+    }
+  }
+
+  /// Returns a fallback version of the `cross-fade()` value for the given color fallback type.
+  pub fn get_fallback(&self, kind: ColorFallbackKind) -> CrossFade<'i> {
+    let images = match &self.images {
+      CrossFadeImages::Legacy { from, to, percentage } => CrossFadeImages::Legacy {
+        from: Box::new(from.get_fallback(kind)),
+        to: Box::new(to.get_fallback(kind)),
+        percentage: percentage.clone(),
+      },
+      CrossFadeImages::List(images) => CrossFadeImages::List(
+        images
+          .iter()
+          .map(|image| CrossFadeImage {
+            value: match &image.value {
+              CrossFadeImageValue::Image(image) => CrossFadeImageValue::Image(image.get_fallback(kind)),
+              CrossFadeImageValue::Color(color) => CrossFadeImageValue::Color(color.get_fallback(kind)),
+            },
+            percentage: image.percentage.clone(),
+          })
+          .collect(),
+      ),
+    };
+
+    CrossFade {
+      images,
+      vendor_prefix: self.vendor_prefix,
+    }
+  }
+}
+
+impl<'i> IsCompatible for CrossFade<'i> {
+  fn is_compatible(&self, browsers: Browsers) -> bool {
+    match &self.images {
+      // Only the legacy syntax is supported, with a -webkit- prefix.
+      CrossFadeImages::Legacy { from, to, .. } => {
+        self.vendor_prefix == VendorPrefix::WebKit
+          && is_prefix_compatible(Feature::CrossFade, self.vendor_prefix, browsers)
+          && from.is_compatible(browsers)
+          && to.is_compatible(browsers)
+      }
+      CrossFadeImages::List(..) => false,
+    }
+  }
+}
+
+impl<'i> Parse<'i> for CrossFade<'i> {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    let location = input.current_source_location();
+    let f = input.expect_function()?;
+    let vendor_prefix = match_ignore_ascii_case! { f,
+      "cross-fade" => VendorPrefix::None,
+      "-webkit-cross-fade" => VendorPrefix::WebKit,
+      _ => return Err(location.new_unexpected_token_error(
+        cssparser::Token::Ident(f.clone())
+      ))
+    };
+
+    let images = input.parse_nested_block(|input| {
+      if let Ok(images) = input.try_parse(parse_legacy_cross_fade) {
+        return Ok(images);
+      }
+
+      if vendor_prefix != VendorPrefix::None {
+        return Err(input.new_custom_error(ParserError::InvalidValue));
+      }
+
+      let images = input.parse_comma_separated(CrossFadeImage::parse)?;
+      Ok(CrossFadeImages::List(images))
+    })?;
+
+    Ok(CrossFade { images, vendor_prefix })
+  }
+}
+
+fn parse_legacy_cross_fade<'i>(
+  input: &mut Parser<'i, '_>,
+) -> Result<CrossFadeImages<'i>, ParseError<'i, ParserError<'i>>> {
+  let from = Image::parse(input)?;
+  input.expect_comma()?;
+  let to = Image::parse(input)?;
+  input.expect_comma()?;
+  let location = input.current_source_location();
+  let percentage = if let Ok(number) = input.try_parse(CSSNumber::parse) {
+    Percentage(number)
+  } else {
+    Percentage::parse(input)?
+  };
+  if percentage.0 < 0.0 || percentage.0 > 1.0 {
+    return Err(location.new_custom_error(ParserError::InvalidValue));
+  }
+  input.expect_exhausted()?;
+
+  Ok(CrossFadeImages::Legacy {
+    from: Box::new(from),
+    to: Box::new(to),
+    percentage,
+  })
+}
+
+impl<'i> ToCss for CrossFade<'i> {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    self.vendor_prefix.to_css(dest)?;
+    dest.write_str("cross-fade(")?;
+    match &self.images {
+      CrossFadeImages::Legacy { from, to, percentage } => {
+        from.to_css(dest)?;
+        dest.delim(',', false)?;
+        to.to_css(dest)?;
+        dest.delim(',', false)?;
+        percentage.to_css(dest)?;
+      }
+      CrossFadeImages::List(images) => {
+        let mut first = true;
+        for image in images {
+          if first {
+            first = false;
+          } else {
+            dest.delim(',', false)?;
+          }
+          image.to_css(dest)?;
+        }
+      }
+    }
+    dest.write_char(')')
+  }
+}
+
+/// An image or color within the [cross-fade()](CrossFade) function, with an optional percentage.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+pub struct CrossFadeImage<'i> {
+  /// The image or color.
+  #[cfg_attr(feature = "serde", serde(borrow))]
+  pub value: CrossFadeImageValue<'i>,
+  /// How much of the image is used.
+  pub percentage: Option<Percentage>,
+}
+
+/// The value of a [CrossFadeImage](CrossFadeImage).
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "type", content = "value", rename_all = "kebab-case")
+)]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+pub enum CrossFadeImageValue<'i> {
+  /// An image.
+  #[cfg_attr(feature = "visitor", skip_type)]
+  #[cfg_attr(feature = "serde", serde(borrow))]
+  Image(Image<'i>),
+  /// A solid color.
+  Color(CssColor),
+}
+
+impl<'i> Parse<'i> for CrossFadeImage<'i> {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    let mut percentage = input.try_parse(parse_cross_fade_percentage).ok();
+    let value = if let Ok(image) = input.try_parse(Image::parse) {
+      CrossFadeImageValue::Image(image)
+    } else {
+      CrossFadeImageValue::Color(CssColor::parse(input)?)
+    };
+
+    if percentage.is_none() {
+      percentage = input.try_parse(parse_cross_fade_percentage).ok();
+    }
+
+    Ok(CrossFadeImage { value, percentage })
+  }
+}
+
+fn parse_cross_fade_percentage<'i>(
+  input: &mut Parser<'i, '_>,
+) -> Result<Percentage, ParseError<'i, ParserError<'i>>> {
+  let location = input.current_source_location();
+  let percentage = Percentage::parse(input)?;
+  if percentage.0 < 0.0 || percentage.0 > 1.0 {
+    return Err(location.new_custom_error(ParserError::InvalidValue));
+  }
+  Ok(percentage)
+}
+
+impl<'i> ToCss for CrossFadeImage<'i> {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    match &self.value {
+      CrossFadeImageValue::Image(image) => image.to_css(dest)?,
+      CrossFadeImageValue::Color(color) => color.to_css(dest)?,
+    }
+
+    if let Some(percentage) = &self.percentage {
+      dest.write_char(' ')?;
+      percentage.to_css(dest)?;
+    }
+
+    Ok(())
+  }
+}
+
+/// A CSS [`element()`](https://drafts.csswg.org/css-images-4/#element-notation) value,
+/// which renders an element in the document as an image.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(rename_all = "camelCase")
+)]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+pub struct Element<'i> {
+  /// The id of the referenced element, without the leading `#`.
+  #[cfg_attr(feature = "serde", serde(borrow))]
+  pub id: CowArcStr<'i>,
+  /// The vendor prefix for the `element()` function.
+  pub vendor_prefix: VendorPrefix,
+}
+
+impl<'i> Parse<'i> for Element<'i> {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    let location = input.current_source_location();
+    let f = input.expect_function()?;
+    let vendor_prefix = match_ignore_ascii_case! { f,
+      "element" => VendorPrefix::None,
+      "-moz-element" => VendorPrefix::Moz,
+      _ => return Err(location.new_unexpected_token_error(
+        cssparser::Token::Ident(f.clone())
+      ))
+    };
+
+    let id = input.parse_nested_block(|input| {
+      let location = input.current_source_location();
+      match input.next()? {
+        cssparser::Token::IDHash(id) => Ok(id.into()),
+        t => Err(location.new_unexpected_token_error(t.clone())),
+      }
+    })?;
+
+    Ok(Element { id, vendor_prefix })
+  }
+}
+
+impl<'i> ToCss for Element<'i> {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    self.vendor_prefix.to_css(dest)?;
+    dest.write_str("element(#")?;
+    serialize_identifier(&self.id, dest)?;
+    dest.write_char(')')
+  }
+}
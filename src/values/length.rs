@@ -2,10 +2,10 @@
 
 use super::angle::impl_try_from_angle;
 use super::calc::{Calc, MathFunction};
-use super::number::CSSNumber;
+use super::number::{serialize_number, CSSNumber};
 use super::percentage::DimensionPercentage;
 use crate::error::{ParserError, PrinterError};
-use crate::printer::Printer;
+use crate::printer::{Printer, PrinterOptions};
 use crate::targets::Browsers;
 use crate::traits::{
   private::{AddInternal, TryAdd},
@@ -36,6 +36,16 @@ impl LengthPercentage {
       _ => self.to_css(dest),
     }
   }
+
+  /// Returns a copy of the value with viewport units converted to the closest `vw`, `vh`,
+  /// `vmin`, or `vmax` unit. See [LengthValue::to_classic_viewport_units](LengthValue::to_classic_viewport_units).
+  pub(crate) fn to_classic_viewport_units(&self) -> LengthPercentage {
+    match self {
+      LengthPercentage::Dimension(d) => LengthPercentage::Dimension(d.to_classic_viewport_units()),
+      LengthPercentage::Calc(c) => LengthPercentage::Calc(Box::new(c.map_values(Self::to_classic_viewport_units))),
+      LengthPercentage::Percentage(p) => LengthPercentage::Percentage(p.clone()),
+    }
+  }
 }
 
 impl IsCompatible for LengthPercentage {
@@ -92,6 +102,19 @@ impl ToCss for LengthPercentageOrAuto {
   }
 }
 
+impl LengthPercentageOrAuto {
+  /// Returns a copy of the value with viewport units converted to the closest `vw`, `vh`,
+  /// `vmin`, or `vmax` unit. See [LengthValue::to_classic_viewport_units](LengthValue::to_classic_viewport_units).
+  pub(crate) fn to_classic_viewport_units(&self) -> LengthPercentageOrAuto {
+    match self {
+      LengthPercentageOrAuto::LengthPercentage(l) => {
+        LengthPercentageOrAuto::LengthPercentage(l.to_classic_viewport_units())
+      }
+      LengthPercentageOrAuto::Auto => LengthPercentageOrAuto::Auto,
+    }
+  }
+}
+
 impl IsCompatible for LengthPercentageOrAuto {
   fn is_compatible(&self, browsers: Browsers) -> bool {
     match self {
@@ -494,6 +517,29 @@ impl ToCss for LengthValue {
       return dest.write_char('0');
     }
 
+    // When minifying, convert absolute lengths to the shortest equivalent absolute unit,
+    // e.g. 1in rather than 72pt. Only exact conversions are used, and px values are left
+    // as is. The q unit is not supported by all browsers, so it is never converted to.
+    if dest.minify && !matches!(self, LengthValue::Px(_)) {
+      if let Some(px) = self.to_px() {
+        let mut candidates = vec![(value, unit)];
+        for (factor, unit) in [
+          (1.0, "px"),
+          (PX_PER_IN, "in"),
+          (PX_PER_CM, "cm"),
+          (PX_PER_MM, "mm"),
+          (PX_PER_PT, "pt"),
+          (PX_PER_PC, "pc"),
+        ] {
+          let converted = (px / factor * 10000.0).round() / 10000.0;
+          if (converted * factor - px).abs() <= px.abs() * 1e-6 {
+            candidates.push((converted, unit));
+          }
+        }
+        return serialize_shortest_dimension(&candidates, dest);
+      }
+    }
+
     serialize_dimension(value, unit, dest)
   }
 }
@@ -514,27 +560,39 @@ pub(crate) fn serialize_dimension<W>(value: f32, unit: &str, dest: &mut Printer<
 where
   W: std::fmt::Write,
 {
-  use cssparser::ToCss;
-  let int_value = if value.fract() == 0.0 { Some(value as i32) } else { None };
-  let token = Token::Dimension {
-    has_sign: value < 0.0,
-    value,
-    int_value,
-    unit: CowRcStr::from(unit),
-  };
-  if value != 0.0 && value.abs() < 1.0 {
+  serialize_number(value, dest)?;
+  // Disambiguate with scientific notation.
+  if unit == "e" || unit == "E" || unit.starts_with("e-") || unit.starts_with("E-") {
+    dest.write_str("\\65 ")?;
+    serialize_name(&unit[1..], dest)?;
+  } else {
+    serialize_identifier(unit, dest)?;
+  }
+  Ok(())
+}
+
+/// Serializes the shortest of the given equivalent dimensions.
+/// Earlier candidates are preferred when there is a tie.
+pub(crate) fn serialize_shortest_dimension<W>(
+  candidates: &[(f32, &str)],
+  dest: &mut Printer<W>,
+) -> Result<(), PrinterError>
+where
+  W: std::fmt::Write,
+{
+  let mut shortest: Option<String> = None;
+  for (value, unit) in candidates {
     let mut s = String::new();
-    token.to_css(&mut s)?;
-    if value < 0.0 {
-      dest.write_char('-')?;
-      dest.write_str(s.trim_start_matches("-0"))
-    } else {
-      dest.write_str(s.trim_start_matches('0'))
+    serialize_dimension(*value, unit, &mut Printer::new(&mut s, PrinterOptions::default()))?;
+    if shortest.as_ref().map_or(true, |shortest| s.len() < shortest.len()) {
+      shortest = Some(s);
     }
-  } else {
-    token.to_css(dest)?;
-    Ok(())
   }
+
+  if let Some(shortest) = shortest {
+    dest.write_str(&shortest)?;
+  }
+  Ok(())
 }
 
 impl LengthValue {
@@ -553,6 +611,20 @@ impl LengthValue {
       _ => None,
     }
   }
+
+  /// Returns a copy of the value with small, large, and dynamic viewport units, as well as the
+  /// logical `vi` and `vb` units, converted to the closest `vw`, `vh`, `vmin`, or `vmax` unit.
+  /// Logical units are converted assuming a horizontal writing mode.
+  pub(crate) fn to_classic_viewport_units(&self) -> LengthValue {
+    use LengthValue::*;
+    match *self {
+      Svw(value) | Lvw(value) | Dvw(value) | Vi(value) | Svi(value) | Lvi(value) | Dvi(value) => Vw(value),
+      Svh(value) | Lvh(value) | Dvh(value) | Vb(value) | Svb(value) | Lvb(value) | Dvb(value) => Vh(value),
+      Svmin(value) | Lvmin(value) | Dvmin(value) => Vmin(value),
+      Svmax(value) | Lvmax(value) | Dvmax(value) => Vmax(value),
+      _ => self.clone(),
+    }
+  }
 }
 
 /// A CSS [`<length>`](https://www.w3.org/TR/css-values-4/#lengths) value, with support for `calc()`.
@@ -32,6 +32,7 @@ pub mod angle;
 pub mod calc;
 pub mod color;
 pub mod easing;
+pub mod frequency;
 pub mod gradient;
 pub mod ident;
 pub mod image;
@@ -34,19 +34,54 @@ impl ToCss for CSSNumber {
   where
     W: std::fmt::Write,
   {
-    let number = *self;
-    if number != 0.0 && number.abs() < 1.0 {
-      let mut s = String::new();
-      cssparser::ToCss::to_css(self, &mut s)?;
-      if number < 0.0 {
-        dest.write_char('-')?;
-        dest.write_str(s.trim_start_matches("-0"))
-      } else {
-        dest.write_str(s.trim_start_matches('0'))
-      }
+    serialize_number(*self, dest)?;
+    Ok(())
+  }
+}
+
+/// Serializes a number in its shortest form without scientific notation,
+/// omitting the leading zero for numbers between -1 and 1.
+pub(crate) fn serialize_number<W>(number: f32, dest: &mut W) -> std::fmt::Result
+where
+  W: std::fmt::Write,
+{
+  let mut s = String::new();
+  cssparser::ToCss::to_css(&number, &mut s)?;
+  if let Some((mantissa, exponent)) = s.split_once('e') {
+    s = expand_exponent(mantissa, exponent.parse().unwrap_or(0));
+  }
+
+  if number != 0.0 && number.abs() < 1.0 {
+    if number < 0.0 {
+      dest.write_char('-')?;
+      dest.write_str(s.trim_start_matches("-0"))
     } else {
-      cssparser::ToCss::to_css(self, dest)?;
-      Ok(())
+      dest.write_str(s.trim_start_matches('0'))
+    }
+  } else {
+    dest.write_str(&s)
+  }
+}
+
+/// Converts a number in scientific notation, e.g. `-1.5e-7`, to a plain decimal.
+fn expand_exponent(mantissa: &str, exponent: i32) -> String {
+  let (sign, mantissa) = match mantissa.strip_prefix('-') {
+    Some(mantissa) => ("-", mantissa),
+    None => ("", mantissa),
+  };
+  let (int, fract) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+  let digits = format!("{}{}", int, fract);
+  let point = int.len() as i32 + exponent;
+  if point <= 0 {
+    let digits = digits.trim_end_matches('0');
+    format!("{}0.{}{}", sign, "0".repeat(-point as usize), digits)
+  } else if point as usize >= digits.len() {
+    format!("{}{}{}", sign, digits, "0".repeat(point as usize - digits.len()))
+  } else {
+    let (int, fract) = digits.split_at(point as usize);
+    match fract.trim_end_matches('0') {
+      "" => format!("{}{}", sign, int),
+      fract => format!("{}{}.{}", sign, int, fract),
     }
   }
 }
@@ -115,7 +150,16 @@ pub type CSSInteger = i32;
 
 impl<'i> Parse<'i> for CSSInteger {
   fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
-    // TODO: calc??
+    // Math functions that resolve to a number are rounded to the nearest integer,
+    // with halfway values rounded towards positive infinity.
+    // https://www.w3.org/TR/css-values-4/#calc-range
+    match input.try_parse(Calc::<CSSNumber>::parse) {
+      Ok(Calc::Value(v)) => return Ok((*v + 0.5).floor() as CSSInteger),
+      Ok(Calc::Number(n)) => return Ok((n + 0.5).floor() as CSSInteger),
+      Ok(_) => return Err(input.new_custom_error(ParserError::InvalidValue)),
+      _ => {}
+    }
+
     let integer = input.expect_integer()?;
     Ok(integer)
   }
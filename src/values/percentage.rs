@@ -2,7 +2,7 @@
 
 use super::angle::{impl_try_from_angle, Angle};
 use super::calc::{Calc, MathFunction};
-use super::number::CSSNumber;
+use super::number::{serialize_number, CSSNumber};
 use crate::error::{ParserError, PrinterError};
 use crate::printer::Printer;
 use crate::traits::private::AddInternal;
@@ -41,30 +41,9 @@ impl ToCss for Percentage {
   where
     W: std::fmt::Write,
   {
-    use cssparser::ToCss;
-    let int_value = if (self.0 * 100.0).fract() == 0.0 {
-      Some(self.0 as i32)
-    } else {
-      None
-    };
-    let percent = Token::Percentage {
-      has_sign: self.0 < 0.0,
-      unit_value: self.0,
-      int_value,
-    };
-    if self.0 != 0.0 && self.0.abs() < 0.01 {
-      let mut s = String::new();
-      percent.to_css(&mut s)?;
-      if self.0 < 0.0 {
-        dest.write_char('-')?;
-        dest.write_str(s.trim_start_matches("-0"))
-      } else {
-        dest.write_str(s.trim_start_matches('0'))
-      }
-    } else {
-      percent.to_css(dest)?;
-      Ok(())
-    }
+    serialize_number(self.0 * 100.0, dest)?;
+    dest.write_char('%')?;
+    Ok(())
   }
 }
 
@@ -317,8 +317,18 @@ impl<S> PositionComponent<S> {
     }
   }
 
-  fn is_zero(&self) -> bool {
-    matches!(self, PositionComponent::Length(len) if len.is_zero())
+  fn is_zero(&self) -> bool
+  where
+    S: Clone + Into<LengthPercentage>,
+  {
+    match self {
+      PositionComponent::Length(len) => len.is_zero(),
+      PositionComponent::Side { side, offset } => {
+        let start: LengthPercentage = side.clone().into();
+        start.is_zero() && offset.as_ref().into_iter().all(|offset| offset.is_zero())
+      }
+      PositionComponent::Center => false,
+    }
   }
 }
 
@@ -41,11 +41,39 @@ impl Ratio {
   }
 }
 
+impl Ratio {
+  /// Reduces the ratio to lowest terms, if both operands are integers.
+  /// Degenerate ratios (where either operand is zero) are returned unchanged.
+  pub fn reduce(&self) -> Ratio {
+    // Larger integers cannot be represented exactly.
+    const MAX_EXACT: CSSNumber = (1 << f32::MANTISSA_DIGITS) as CSSNumber;
+    let is_integer = |n: CSSNumber| n > 0.0 && n <= MAX_EXACT && n.fract() == 0.0;
+    if !is_integer(self.0) || !is_integer(self.1) {
+      return self.clone();
+    }
+
+    let mut a = self.0 as u32;
+    let mut b = self.1 as u32;
+    while b != 0 {
+      (a, b) = (b, a % b);
+    }
+
+    Ratio(self.0 / a as CSSNumber, self.1 / a as CSSNumber)
+  }
+}
+
 impl ToCss for Ratio {
   fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
   where
     W: std::fmt::Write,
   {
+    if dest.minify {
+      let reduced = self.reduce();
+      if reduced != *self {
+        return reduced.to_css(dest);
+      }
+    }
+
     self.0.to_css(dest)?;
     if self.1 != 1.0 {
       dest.delim('/', true)?;
@@ -1,16 +1,22 @@
 //! CSS resolution values.
 
-use super::length::serialize_dimension;
+use super::angle::impl_try_from_angle;
+use super::calc::Calc;
+use super::length::{serialize_dimension, serialize_shortest_dimension};
 use super::number::CSSNumber;
 use crate::compat::Feature;
 use crate::error::{ParserError, PrinterError};
 use crate::printer::Printer;
-use crate::traits::{Parse, ToCss};
+use crate::traits::private::AddInternal;
+use crate::traits::{Map, Op, Parse, Sign, ToCss};
 #[cfg(feature = "visitor")]
 use crate::visitor::Visit;
 use cssparser::*;
 
 /// A CSS [`<resolution>`](https://www.w3.org/TR/css-values-4/#resolution) value.
+///
+/// Resolution values may be explicit or computed by `calc()`, but are always stored and serialized
+/// as their computed value.
 #[derive(Debug, Clone, PartialEq)]
 #[cfg_attr(feature = "visitor", derive(Visit))]
 #[cfg_attr(feature = "visitor", visit(visit_resolution, RESOLUTIONS))]
@@ -32,7 +38,13 @@ pub enum Resolution {
 
 impl<'i> Parse<'i> for Resolution {
   fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
-    // TODO: calc?
+    match input.try_parse(Calc::parse) {
+      Ok(Calc::Value(v)) => return Ok(*v),
+      // Resolutions are always compatible, so they will always compute to a value.
+      Ok(_) => return Err(input.new_custom_error(ParserError::InvalidValue)),
+      _ => {}
+    }
+
     let location = input.current_source_location();
     match *input.next()? {
       Token::Dimension { value, ref unit, .. } => {
@@ -69,18 +81,29 @@ impl ToCss for Resolution {
   where
     W: std::fmt::Write,
   {
+    let dppx_unit = if dest.targets.is_compatible(Feature::XResolutionUnit) {
+      "x"
+    } else {
+      "dppx"
+    };
+
     let (value, unit) = match self {
       Resolution::Dpi(dpi) => (*dpi, "dpi"),
       Resolution::Dpcm(dpcm) => (*dpcm, "dpcm"),
-      Resolution::Dppx(dppx) => {
-        if dest.targets.is_compatible(Feature::XResolutionUnit) {
-          (*dppx, "x")
-        } else {
-          (*dppx, "dppx")
-        }
-      }
+      Resolution::Dppx(dppx) => (*dppx, dppx_unit),
     };
 
+    // When minifying, use the shortest equivalent unit, e.g. 1x rather than 96dpi.
+    // Other units are only converted to `x` when the targets are known to support it.
+    if dest.minify {
+      let dppx = self.to_dppx();
+      let mut candidates = vec![(value, unit), (dppx * 96.0, "dpi")];
+      if dest.targets.browsers.is_some() && dest.targets.is_compatible(Feature::XResolutionUnit) {
+        candidates.push((dppx, "x"));
+      }
+      return serialize_shortest_dimension(&candidates, dest);
+    }
+
     serialize_dimension(value, unit, dest)
   }
 }
@@ -96,3 +119,97 @@ impl std::ops::Add<CSSNumber> for Resolution {
     }
   }
 }
+
+impl Resolution {
+  /// Returns the resolution in dots per px.
+  pub fn to_dppx(&self) -> CSSNumber {
+    match self {
+      Resolution::Dpi(dpi) => *dpi / 96.0,
+      Resolution::Dpcm(dpcm) => *dpcm * 2.54 / 96.0,
+      Resolution::Dppx(dppx) => *dppx,
+    }
+  }
+
+  /// Converts a number of dots per px to the same unit as this resolution.
+  fn convert_dppx(&self, dppx: CSSNumber) -> CSSNumber {
+    match self {
+      Resolution::Dpi(_) => dppx * 96.0,
+      Resolution::Dpcm(_) => dppx * 96.0 / 2.54,
+      Resolution::Dppx(_) => dppx,
+    }
+  }
+
+  fn value(&self) -> CSSNumber {
+    match self {
+      Resolution::Dpi(v) | Resolution::Dpcm(v) | Resolution::Dppx(v) => *v,
+    }
+  }
+}
+
+impl std::convert::From<Resolution> for Calc<Resolution> {
+  fn from(r: Resolution) -> Calc<Resolution> {
+    Calc::Value(Box::new(r))
+  }
+}
+
+impl std::convert::From<Calc<Resolution>> for Resolution {
+  fn from(calc: Calc<Resolution>) -> Resolution {
+    match calc {
+      Calc::Value(v) => *v,
+      _ => unreachable!(),
+    }
+  }
+}
+
+impl std::ops::Mul<f32> for Resolution {
+  type Output = Self;
+
+  fn mul(self, other: f32) -> Resolution {
+    self.map(|v| v * other)
+  }
+}
+
+impl AddInternal for Resolution {
+  fn add(self, other: Self) -> Self {
+    self.op(&other, |a, b| a + b)
+  }
+}
+
+impl std::cmp::PartialOrd<Resolution> for Resolution {
+  fn partial_cmp(&self, other: &Resolution) -> Option<std::cmp::Ordering> {
+    self.to_dppx().partial_cmp(&other.to_dppx())
+  }
+}
+
+impl Op for Resolution {
+  fn op<F: FnOnce(f32, f32) -> f32>(&self, to: &Self, op: F) -> Self {
+    let value = op(self.value(), self.convert_dppx(to.to_dppx()));
+    match self {
+      Resolution::Dpi(_) => Resolution::Dpi(value),
+      Resolution::Dpcm(_) => Resolution::Dpcm(value),
+      Resolution::Dppx(_) => Resolution::Dppx(value),
+    }
+  }
+
+  fn op_to<T, F: FnOnce(f32, f32) -> T>(&self, rhs: &Self, op: F) -> T {
+    op(self.value(), self.convert_dppx(rhs.to_dppx()))
+  }
+}
+
+impl Map for Resolution {
+  fn map<F: FnOnce(f32) -> f32>(&self, op: F) -> Self {
+    match self {
+      Resolution::Dpi(v) => Resolution::Dpi(op(*v)),
+      Resolution::Dpcm(v) => Resolution::Dpcm(op(*v)),
+      Resolution::Dppx(v) => Resolution::Dppx(op(*v)),
+    }
+  }
+}
+
+impl Sign for Resolution {
+  fn sign(&self) -> f32 {
+    self.value().sign()
+  }
+}
+
+impl_try_from_angle!(Resolution);
@@ -7,7 +7,8 @@ use crate::error::{ParserError, PrinterError};
 use crate::macros::enum_property;
 use crate::printer::Printer;
 use crate::properties::border_radius::BorderRadius;
-use crate::traits::{Parse, ToCss};
+use crate::traits::{Parse, ToCss, TrySign};
+use crate::values::string::CowArcStr;
 #[cfg(feature = "visitor")]
 use crate::visitor::Visit;
 use cssparser::*;
@@ -22,7 +23,7 @@ use cssparser::*;
 )]
 #[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
-pub enum BasicShape {
+pub enum BasicShape<'i> {
   /// An inset rectangle.
   Inset(InsetRect),
   /// A circle.
@@ -31,6 +32,9 @@ pub enum BasicShape {
   Ellipse(Ellipse),
   /// A polygon.
   Polygon(Polygon),
+  /// An SVG path.
+  #[cfg_attr(feature = "serde", serde(borrow))]
+  Path(Path<'i>),
 }
 
 /// An [`inset()`](https://www.w3.org/TR/css-shapes-1/#funcdef-inset) rectangle shape.
@@ -38,6 +42,7 @@ pub enum BasicShape {
 #[cfg_attr(feature = "visitor", derive(Visit))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
 pub struct InsetRect {
   /// The rectangle.
   pub rect: Rect<LengthPercentage>,
@@ -50,6 +55,7 @@ pub struct InsetRect {
 #[cfg_attr(feature = "visitor", derive(Visit))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
 pub struct Circle {
   /// The radius of the circle.
   pub radius: ShapeRadius,
@@ -85,6 +91,7 @@ pub enum ShapeRadius {
   serde(rename_all = "camelCase")
 )]
 #[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
 pub struct Ellipse {
   /// The x-radius of the ellipse.
   pub radius_x: ShapeRadius,
@@ -103,6 +110,7 @@ pub struct Ellipse {
   serde(rename_all = "camelCase")
 )]
 #[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
 pub struct Polygon {
   /// The fill rule used to determine the interior of the polygon.
   pub fill_rule: FillRule,
@@ -110,6 +118,24 @@ pub struct Polygon {
   pub points: Vec<Point>,
 }
 
+/// A [`path()`](https://www.w3.org/TR/css-shapes-1/#funcdef-basic-shape-path) shape.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(rename_all = "camelCase")
+)]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+pub struct Path<'i> {
+  /// The fill rule used to determine the interior of the path.
+  pub fill_rule: FillRule,
+  /// The SVG path data string.
+  #[cfg_attr(feature = "serde", serde(borrow))]
+  pub path: CowArcStr<'i>,
+}
+
 /// A point within a `polygon()` shape.
 ///
 /// See [Polygon](Polygon).
@@ -143,7 +169,7 @@ impl Default for FillRule {
   }
 }
 
-impl<'i> Parse<'i> for BasicShape {
+impl<'i> Parse<'i> for BasicShape<'i> {
   fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
     let location = input.current_source_location();
     let f = input.expect_function()?;
@@ -152,6 +178,7 @@ impl<'i> Parse<'i> for BasicShape {
       "circle" => Ok(BasicShape::Circle(input.parse_nested_block(Circle::parse)?)),
       "ellipse" => Ok(BasicShape::Ellipse(input.parse_nested_block(Ellipse::parse)?)),
       "polygon" => Ok(BasicShape::Polygon(input.parse_nested_block(Polygon::parse)?)),
+      "path" => Ok(BasicShape::Path(input.parse_nested_block(Path::parse)?)),
       _ => Err(location.new_unexpected_token_error(Token::Ident(f.clone()))),
     }
   }
@@ -185,6 +212,9 @@ impl<'i> Parse<'i> for Circle {
 impl<'i> Parse<'i> for ShapeRadius {
   fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
     if let Ok(len) = input.try_parse(LengthPercentage::parse) {
+      if len.is_sign_negative() {
+        return Err(input.new_custom_error(ParserError::InvalidValue));
+      }
       return Ok(ShapeRadius::LengthPercentage(len));
     }
 
@@ -240,6 +270,21 @@ impl<'i> Parse<'i> for Polygon {
   }
 }
 
+impl<'i> Parse<'i> for Path<'i> {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    let fill_rule = input.try_parse(FillRule::parse);
+    if fill_rule.is_ok() {
+      input.expect_comma()?;
+    }
+
+    let path = input.expect_string_cloned()?.into();
+    Ok(Path {
+      fill_rule: fill_rule.unwrap_or_default(),
+      path,
+    })
+  }
+}
+
 impl<'i> Parse<'i> for Point {
   fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
     let x = LengthPercentage::parse(input)?;
@@ -248,7 +293,7 @@ impl<'i> Parse<'i> for Point {
   }
 }
 
-impl ToCss for BasicShape {
+impl<'i> ToCss for BasicShape<'i> {
   fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
   where
     W: std::fmt::Write,
@@ -274,6 +319,11 @@ impl ToCss for BasicShape {
         poly.to_css(dest)?;
         dest.write_char(')')
       }
+      BasicShape::Path(path) => {
+        dest.write_str("path(")?;
+        path.to_css(dest)?;
+        dest.write_char(')')
+      }
     }
   }
 }
@@ -377,6 +427,21 @@ impl ToCss for Polygon {
   }
 }
 
+impl<'i> ToCss for Path<'i> {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    if self.fill_rule != FillRule::default() {
+      self.fill_rule.to_css(dest)?;
+      dest.delim(',', false)?;
+    }
+
+    serialize_string(&self.path, dest)?;
+    Ok(())
+  }
+}
+
 impl ToCss for Point {
   fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
   where
@@ -395,8 +395,19 @@ impl ToCss for SyntaxString {
     W: std::fmt::Write,
   {
     dest.write_char('"')?;
+    self.to_css_unquoted(dest)?;
+    dest.write_char('"')
+  }
+}
+
+impl SyntaxString {
+  /// Writes the syntax string without surrounding quotes, e.g. as used in the `type()` function.
+  pub(crate) fn to_css_unquoted<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
     match self {
-      SyntaxString::Universal => dest.write_char('*')?,
+      SyntaxString::Universal => dest.write_char('*'),
       SyntaxString::Components(components) => {
         let mut first = true;
         for component in components {
@@ -408,10 +419,9 @@ impl ToCss for SyntaxString {
 
           component.to_css(dest)?;
         }
+        Ok(())
       }
     }
-
-    dest.write_char('"')
   }
 }
 
@@ -2,6 +2,7 @@
 
 use crate::dependencies::{Dependency, Location, UrlDependency};
 use crate::error::{ParserError, PrinterError};
+use crate::macros::enum_property;
 use crate::printer::Printer;
 use crate::traits::{Parse, ToCss};
 use crate::values::string::CowArcStr;
@@ -20,21 +21,53 @@ pub struct Url<'i> {
   /// The url string.
   #[cfg_attr(feature = "serde", serde(borrow))]
   pub url: CowArcStr<'i>,
+  /// A list of url modifiers, e.g. `crossorigin(anonymous)`.
+  ///
+  /// Note that this field was added after `url` and `loc`, so code that constructs a `Url` with a
+  /// struct literal must now set it, usually to an empty list. It is omitted from serialized
+  /// output when empty, and defaults to an empty list when deserializing.
+  #[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "Vec::is_empty"))]
+  #[cfg_attr(feature = "visitor", skip_visit)]
+  pub modifiers: Vec<UrlModifier<'i>>,
   /// The location where the `url()` was seen in the CSS source file.
   pub loc: Location,
 }
 
 impl<'i> PartialEq for Url<'i> {
   fn eq(&self, other: &Self) -> bool {
-    self.url == other.url
+    self.url == other.url && self.modifiers == other.modifiers
   }
 }
 
 impl<'i> Parse<'i> for Url<'i> {
   fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
     let loc = input.current_source_location();
-    let url = input.expect_url()?.into();
-    Ok(Url { url, loc: loc.into() })
+    match input.next()? {
+      Token::UnquotedUrl(url) => {
+        return Ok(Url {
+          url: url.into(),
+          modifiers: Vec::new(),
+          loc: loc.into(),
+        })
+      }
+      Token::Function(name) if name.eq_ignore_ascii_case("url") => {}
+      t => return Err(loc.new_unexpected_token_error(t.clone())),
+    }
+
+    // Modifiers are only allowed in the quoted form, e.g. `url("foo.png" crossorigin(anonymous))`.
+    input.parse_nested_block(|input| {
+      let url = input.expect_string()?.into();
+      let mut modifiers = Vec::new();
+      while !input.is_exhausted() {
+        modifiers.push(UrlModifier::parse(input)?);
+      }
+
+      Ok(Url {
+        url,
+        modifiers,
+        loc: loc.into(),
+      })
+    })
   }
 }
 
@@ -54,6 +87,7 @@ impl<'i> ToCss for Url<'i> {
     if let Some(dep) = dep {
       dest.write_str("url(")?;
       serialize_string(&dep.placeholder, dest)?;
+      self.write_modifiers(dest)?;
       dest.write_char(')')?;
 
       if let Some(dependencies) = &mut dest.dependencies {
@@ -64,7 +98,8 @@ impl<'i> ToCss for Url<'i> {
     }
 
     use cssparser::ToCss;
-    if dest.minify {
+    // Unquoted urls cannot have modifiers.
+    if dest.minify && self.modifiers.is_empty() {
       let mut buf = String::new();
       Token::UnquotedUrl(CowRcStr::from(self.url.as_ref())).to_css(&mut buf)?;
 
@@ -84,6 +119,7 @@ impl<'i> ToCss for Url<'i> {
     } else {
       dest.write_str("url(")?;
       serialize_string(&self.url, dest)?;
+      self.write_modifiers(dest)?;
       dest.write_char(')')?;
     }
 
@@ -92,6 +128,17 @@ impl<'i> ToCss for Url<'i> {
 }
 
 impl<'i> Url<'i> {
+  fn write_modifiers<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    for modifier in &self.modifiers {
+      dest.write_char(' ')?;
+      modifier.to_css(dest)?;
+    }
+    Ok(())
+  }
+
   /// Returns whether the URL is absolute, and not relative.
   pub fn is_absolute(&self) -> bool {
     let url = self.url.as_ref();
@@ -132,3 +179,119 @@ impl<'i> Url<'i> {
     false
   }
 }
+
+enum_property! {
+  /// A value for the `crossorigin()` [url modifier](https://html.spec.whatwg.org/multipage/urls-and-fetching.html#cors-settings-attributes).
+  pub enum CrossOriginModifier {
+    /// Requests are made without credentials.
+    "anonymous": Anonymous,
+    /// Requests are made with credentials.
+    "use-credentials": UseCredentials,
+  }
+}
+
+enum_property! {
+  /// A value for the `referrerpolicy()` [url modifier](https://w3c.github.io/webappsec-referrer-policy/#referrer-policies).
+  pub enum ReferrerPolicyModifier {
+    /// No referrer information is sent.
+    "no-referrer": NoReferrer,
+    /// The full URL is sent as the referrer, unless the request is downgraded from HTTPS to HTTP.
+    "no-referrer-when-downgrade": NoReferrerWhenDowngrade,
+    /// The full URL is sent as the referrer for same-origin requests only.
+    "same-origin": SameOrigin,
+    /// Only the origin is sent as the referrer.
+    "origin": Origin,
+    /// Only the origin is sent as the referrer, unless the request is downgraded from HTTPS to HTTP.
+    "strict-origin": StrictOrigin,
+    /// The full URL is sent for same-origin requests, and only the origin otherwise.
+    "origin-when-cross-origin": OriginWhenCrossOrigin,
+    /// The full URL is sent for same-origin requests, the origin for cross-origin requests,
+    /// and nothing when the request is downgraded from HTTPS to HTTP.
+    "strict-origin-when-cross-origin": StrictOriginWhenCrossOrigin,
+    /// The full URL is always sent as the referrer.
+    "unsafe-url": UnsafeUrl,
+  }
+}
+
+/// A [`<url-modifier>`](https://drafts.csswg.org/css-values-4/#typedef-url-modifier) within a `url()`.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "type", content = "value", rename_all = "kebab-case")
+)]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+pub enum UrlModifier<'i> {
+  /// A `crossorigin()` modifier.
+  CrossOrigin(CrossOriginModifier),
+  /// An `integrity()` modifier, containing a resource hash.
+  #[cfg_attr(feature = "serde", serde(borrow))]
+  Integrity(CowArcStr<'i>),
+  /// A `referrerpolicy()` modifier.
+  ReferrerPolicy(ReferrerPolicyModifier),
+  /// An unknown modifier, preserved exactly as written.
+  Unknown(CowArcStr<'i>),
+}
+
+impl<'i> Parse<'i> for UrlModifier<'i> {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    if let Ok(modifier) = input.try_parse(|input| {
+      let location = input.current_source_location();
+      let f = input.expect_function()?.clone();
+      input.parse_nested_block(|input| {
+        match_ignore_ascii_case! { &f,
+          "crossorigin" => Ok(UrlModifier::CrossOrigin(CrossOriginModifier::parse(input)?)),
+          "integrity" => Ok(UrlModifier::Integrity(input.expect_string()?.into())),
+          "referrerpolicy" => Ok(UrlModifier::ReferrerPolicy(ReferrerPolicyModifier::parse(input)?)),
+          _ => Err(location.new_unexpected_token_error(Token::Function(f.clone())))
+        }
+      })
+    }) {
+      return Ok(modifier);
+    }
+
+    // Future modifiers may be identifiers or functions with arbitrary arguments.
+    // Skip leading whitespace so that it is not included in the preserved source.
+    input.skip_whitespace();
+    let start = input.position();
+    let location = input.current_source_location();
+    match input.next()? {
+      Token::Ident(_) => {}
+      Token::Function(_) => input.parse_nested_block(|input| {
+        while input.next().is_ok() {}
+        Ok::<_, ParseError<'i, ParserError<'i>>>(())
+      })?,
+      t => return Err(location.new_unexpected_token_error(t.clone())),
+    }
+
+    Ok(UrlModifier::Unknown(input.slice_from(start).into()))
+  }
+}
+
+impl<'i> ToCss for UrlModifier<'i> {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    match self {
+      UrlModifier::CrossOrigin(value) => {
+        dest.write_str("crossorigin(")?;
+        value.to_css(dest)?;
+        dest.write_char(')')
+      }
+      UrlModifier::Integrity(value) => {
+        dest.write_str("integrity(")?;
+        serialize_string(value, dest)?;
+        dest.write_char(')')
+      }
+      UrlModifier::ReferrerPolicy(value) => {
+        dest.write_str("referrerpolicy(")?;
+        value.to_css(dest)?;
+        dest.write_char(')')
+      }
+      UrlModifier::Unknown(value) => dest.write_str(value),
+    }
+  }
+}